@@ -0,0 +1,382 @@
+// Copyright © 2026 Pathway
+
+//! Benchmarks of representative pipelines: parsing the input, projections, joins,
+//! group-bys, windowing and writing the output.
+//!
+//! Run them with `cargo bench --bench pipelines`. The throughput is reported in rows
+//! per second (bytes per second for parsing). To compare two revisions, save a baseline
+//! on the first one with `cargo bench --bench pipelines -- --save-baseline <name>` and
+//! run `cargo bench --bench pipelines -- --baseline <name>` on the second one.
+//!
+//! The dataflow pipelines are run on the number of threads given in `PATHWAY_THREADS`,
+//! one by default.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+
+use pathway_engine::connectors::data_format::{
+    FieldSource, InnerSchemaField, JsonLinesFormatter, JsonLinesParser, Parser, ValueField,
+};
+use pathway_engine::connectors::data_storage::{DataEventType, NullWriter, ReaderContext};
+use pathway_engine::connectors::SessionType;
+use pathway_engine::engine::dataflow::config::Config;
+use pathway_engine::engine::error::{DynResult, Trace};
+use pathway_engine::engine::graph::{JoinExactlyOnce, OutputWriters};
+use pathway_engine::engine::license::License;
+use pathway_engine::engine::lifecycle::RunCallbacks;
+use pathway_engine::engine::progress_reporter::MonitoringLevel;
+use pathway_engine::engine::telemetry::Config as TelemetryConfig;
+use pathway_engine::engine::{
+    run_with_new_dataflow_graph, AnyExpression, BoolExpression, ColumnPath, ColumnProperties,
+    DataRow, Expression, ExpressionData, FloatExpression, Graph, IntExpression, JoinData, JoinType,
+    Key, Reducer, ReducerData, RunLineage, ShardPolicy, TableHandle, TableProperties, Timestamp,
+    Type, Value, WindowProperties,
+};
+
+const ROWS: usize = 100_000;
+const USERS: usize = 1_000;
+const ROWS_PER_MINIBATCH: usize = 10_000;
+const MAX_EXPRESSION_BATCH_SIZE: usize = 1024;
+
+/// Orders with the columns `user`, `amount`, `price` and `t`, split into minibatches.
+fn order_rows() -> Arc<Vec<DataRow>> {
+    let rows = (0..ROWS)
+        .map(|i| {
+            let i = i64::try_from(i).unwrap();
+            let minibatch = u64::try_from(i).unwrap() / ROWS_PER_MINIBATCH as u64;
+            DataRow {
+                key: Key::for_value(&Value::Int(i)),
+                values: vec![
+                    Value::Int(i % USERS as i64),
+                    Value::Int(i * 7 % 101),
+                    Value::from(f64::from(u32::try_from(i % 1000).unwrap()) / 8.0),
+                    Value::Int(i / 10),
+                ],
+                time: Timestamp(2 * minibatch),
+                diff: 1,
+                shard: None,
+            }
+        })
+        .collect();
+    Arc::new(rows)
+}
+
+/// Users with the columns `user` and `name`.
+fn user_rows() -> Arc<Vec<DataRow>> {
+    let rows = (0..USERS)
+        .map(|i| {
+            let i = i64::try_from(i).unwrap();
+            DataRow {
+                key: Key::for_value(&Value::from(format!("user-{i}").as_str())),
+                values: vec![Value::Int(i), Value::from(format!("user-{i}").as_str())],
+                time: Timestamp(0),
+                diff: 1,
+                shard: None,
+            }
+        })
+        .collect();
+    Arc::new(rows)
+}
+
+fn column_properties() -> Arc<ColumnProperties> {
+    Arc::new(ColumnProperties {
+        dtype: Type::Any,
+        append_only: false,
+        trace: Arc::new(Trace::Empty),
+    })
+}
+
+fn table_properties(columns: usize) -> Arc<TableProperties> {
+    Arc::new(TableProperties::flat(vec![column_properties(); columns]))
+}
+
+fn path(index: usize) -> ColumnPath {
+    ColumnPath::ValuePath(vec![index])
+}
+
+fn argument(index: usize) -> Arc<Expression> {
+    Arc::new(Expression::Any(AnyExpression::Argument(index)))
+}
+
+fn static_table(graph: &dyn Graph, rows: &[DataRow], columns: usize) -> DynResult<TableHandle> {
+    Ok(graph.static_table(rows.to_vec(), table_properties(columns))?)
+}
+
+fn run_pipeline(logic: impl Fn(&dyn Graph) -> DynResult<()> + Send + Sync + 'static) {
+    let config = Config::from_env().expect("worker configuration should be valid");
+    run_with_new_dataflow_graph(
+        logic,
+        |()| (),
+        config,
+        None,
+        None,
+        false,
+        MonitoringLevel::None,
+        false,
+        None,
+        &License::NoLicenseKey,
+        TelemetryConfig::Disabled,
+        Arc::new(RunLineage::default()),
+        true,
+        MAX_EXPRESSION_BATCH_SIZE,
+        0,
+        None,
+        None,
+        None,
+        RunCallbacks::default(),
+    )
+    .expect("pipeline should not fail");
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let lines: Vec<Vec<u8>> = (0..ROWS)
+        .map(|i| {
+            format!(
+                r#"{{"user": {}, "amount": {}, "price": {}.5, "name": "user-{}"}}"#,
+                i % USERS,
+                i * 7 % 101,
+                i % 1000,
+                i % USERS
+            )
+            .into_bytes()
+        })
+        .collect();
+    let total_bytes: usize = lines.iter().map(Vec::len).sum();
+    let schema = [
+        ("user".to_string(), InnerSchemaField::new(Type::Int, None)),
+        ("amount".to_string(), InnerSchemaField::new(Type::Int, None)),
+        (
+            "price".to_string(),
+            InnerSchemaField::new(Type::Float, None),
+        ),
+        (
+            "name".to_string(),
+            InnerSchemaField::new(Type::String, None),
+        ),
+    ];
+    let value_fields: Vec<_> = schema
+        .iter()
+        .map(|(name, field)| {
+            ValueField::new(name.clone(), field.type_.clone(), FieldSource::Payload)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("ingest");
+    group.throughput(Throughput::Bytes(total_bytes.try_into().unwrap()));
+    group.bench_function("parse_jsonlines", |b| {
+        b.iter_batched(
+            || {
+                let parser = JsonLinesParser::new(
+                    None,
+                    value_fields.clone(),
+                    HashMap::new(),
+                    true,
+                    schema.clone().into(),
+                    SessionType::Native,
+                    None,
+                )
+                .unwrap();
+                let contexts: Vec<_> = lines
+                    .iter()
+                    .map(|line| ReaderContext::from_raw_bytes(DataEventType::Insert, line.clone()))
+                    .collect();
+                (parser, contexts)
+            },
+            |(mut parser, contexts)| {
+                for context in &contexts {
+                    parser.parse(context).unwrap();
+                }
+            },
+            BatchSize::LargeInput,
+        );
+    });
+    group.finish();
+}
+
+fn bench_projection(c: &mut Criterion) {
+    let orders = order_rows();
+    let mut group = c.benchmark_group("dataflow");
+    group.throughput(Throughput::Elements(ROWS.try_into().unwrap()));
+    group.bench_function("projection", |b| {
+        b.iter(|| {
+            let orders = orders.clone();
+            run_pipeline(move |graph| {
+                let table = static_table(graph, &orders, 4)?;
+                let column = Arc::new(TableProperties::Column(column_properties()));
+                // user + amount * 2, price * amount, amount > 50
+                let expressions = [
+                    Expression::Int(IntExpression::Add(
+                        argument(0),
+                        Arc::new(Expression::Int(IntExpression::Mul(
+                            argument(1),
+                            Arc::new(Expression::Int(IntExpression::Const(2))),
+                        ))),
+                    )),
+                    Expression::Float(FloatExpression::Mul(
+                        argument(2),
+                        Arc::new(Expression::Float(FloatExpression::CastFromInt(argument(1)))),
+                    )),
+                    Expression::Bool(BoolExpression::IntGt(
+                        argument(1),
+                        Arc::new(Expression::Int(IntExpression::Const(50))),
+                    )),
+                ]
+                .into_iter()
+                .map(|expression| ExpressionData {
+                    expression: Arc::new(expression),
+                    properties: column.clone(),
+                    append_only: false,
+                    deterministic: true,
+                    gil: false,
+                })
+                .collect();
+                graph.expression_table(
+                    table,
+                    vec![path(0), path(1), path(2)],
+                    expressions,
+                    true,
+                )?;
+                Ok(())
+            });
+        });
+    });
+    group.finish();
+}
+
+fn bench_join(c: &mut Criterion) {
+    let orders = order_rows();
+    let users = user_rows();
+    let mut group = c.benchmark_group("dataflow");
+    group.throughput(Throughput::Elements((ROWS + USERS).try_into().unwrap()));
+    group.bench_function("join", |b| {
+        b.iter(|| {
+            let orders = orders.clone();
+            let users = users.clone();
+            run_pipeline(move |graph| {
+                let orders = static_table(graph, &orders, 4)?;
+                let users = static_table(graph, &users, 2)?;
+                graph.join_tables(
+                    JoinData::new(orders, vec![path(0)]),
+                    JoinData::new(users, vec![path(0)]),
+                    ShardPolicy::WholeKey,
+                    JoinType::Inner,
+                    JoinExactlyOnce::new(false, false),
+                    table_properties(4),
+                )?;
+                Ok(())
+            });
+        });
+    });
+    group.finish();
+}
+
+fn sum_and_max(column_path: &ColumnPath) -> Vec<ReducerData> {
+    [Reducer::IntSum, Reducer::Max]
+        .into_iter()
+        .map(|reducer| ReducerData {
+            reducer,
+            skip_errors: false,
+            append_only: false,
+            column_paths: vec![column_path.clone()],
+            trace: Trace::Empty,
+        })
+        .collect()
+}
+
+fn bench_groupby(c: &mut Criterion) {
+    let orders = order_rows();
+    let mut group = c.benchmark_group("dataflow");
+    group.throughput(Throughput::Elements(ROWS.try_into().unwrap()));
+    group.bench_function("groupby", |b| {
+        b.iter(|| {
+            let orders = orders.clone();
+            run_pipeline(move |graph| {
+                let orders = static_table(graph, &orders, 4)?;
+                graph.group_by_table(
+                    orders,
+                    vec![path(0)],
+                    ShardPolicy::WholeKey,
+                    sum_and_max(&path(1)),
+                    false,
+                    table_properties(2),
+                )?;
+                Ok(())
+            });
+        });
+    });
+    group.finish();
+}
+
+fn bench_windowing(c: &mut Criterion) {
+    let orders = order_rows();
+    let mut group = c.benchmark_group("dataflow");
+    group.throughput(Throughput::Elements(ROWS.try_into().unwrap()));
+    group.bench_function("sliding_window", |b| {
+        b.iter(|| {
+            let orders = orders.clone();
+            run_pipeline(move |graph| {
+                let orders = static_table(graph, &orders, 4)?;
+                // each row is assigned to three windows of length 300, starting every 100
+                let windowed = graph.assign_windows(
+                    orders,
+                    path(3),
+                    WindowProperties {
+                        hop: Value::Int(100),
+                        ratio: None,
+                        duration: Some(Value::Int(300)),
+                        origin: Value::Int(0),
+                    },
+                    table_properties(3),
+                )?;
+                graph.group_by_table(
+                    windowed,
+                    vec![path(1)],
+                    ShardPolicy::WholeKey,
+                    sum_and_max(&ColumnPath::ValuePath(vec![0, 1])),
+                    false,
+                    table_properties(2),
+                )?;
+                Ok(())
+            });
+        });
+    });
+    group.finish();
+}
+
+fn bench_output(c: &mut Criterion) {
+    let orders = order_rows();
+    let mut group = c.benchmark_group("dataflow");
+    group.throughput(Throughput::Elements(ROWS.try_into().unwrap()));
+    group.bench_function("output_jsonlines", |b| {
+        b.iter(|| {
+            let orders = orders.clone();
+            run_pipeline(move |graph| {
+                let orders = static_table(graph, &orders, 4)?;
+                let field_names = ["user", "amount", "price", "t"].map(str::to_string);
+                graph.output_table(
+                    OutputWriters::single(
+                        Box::new(NullWriter::new()),
+                        Box::new(JsonLinesFormatter::new(field_names.to_vec(), None)),
+                    ),
+                    orders,
+                    (0..field_names.len()).map(path).collect(),
+                    None,
+                    None,
+                    None,
+                )?;
+                Ok(())
+            });
+        });
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    // a single run of a pipeline takes long enough to make fewer samples sufficient
+    config = Criterion::default().sample_size(10).noise_threshold(0.05);
+    targets = bench_parse, bench_projection, bench_join, bench_groupby, bench_windowing, bench_output
+}
+criterion_main!(benches);
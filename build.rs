@@ -3,5 +3,7 @@
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
 
-    pyo3_build_config::add_extension_module_link_args();
+    if std::env::var_os("CARGO_FEATURE_PYTHON").is_some() {
+        pyo3_build_config::add_extension_module_link_args();
+    }
 }
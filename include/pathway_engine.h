@@ -0,0 +1,112 @@
+/* Copyright © 2026 Pathway */
+
+/*
+ * The C API of the Pathway engine, available when the engine is built with the
+ * `c-api` cargo feature.
+ *
+ * The graph is built by a callback passed to `pw_run`, called by each worker with a
+ * graph valid for the duration of the call. The tables are identified by their index,
+ * in the order of creation within the callback. Expressions are built bottom-up; the
+ * functions taking expressions take their ownership, also on failure.
+ *
+ * The functions returning `PwStatus` return `PW_ERROR` on failure; the message of the
+ * error is then returned by `pw_last_error_message`. A panic of the engine is reported
+ * the same way, or as NULL by the functions returning pointers.
+ */
+
+#ifndef PATHWAY_ENGINE_H
+#define PATHWAY_ENGINE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef int32_t PwStatus;
+#define PW_OK 0
+#define PW_ERROR (-1)
+
+typedef struct PwGraph PwGraph;
+typedef struct PwExpression PwExpression;
+typedef size_t PwTable;
+
+/* column types */
+#define PW_TYPE_ANY 0u
+#define PW_TYPE_BOOL 1u
+#define PW_TYPE_INT 2u
+#define PW_TYPE_FLOAT 3u
+#define PW_TYPE_STRING 4u
+
+/* binary operators */
+#define PW_OP_ADD 0u
+#define PW_OP_SUB 1u
+#define PW_OP_MUL 2u
+#define PW_OP_EQ 3u
+#define PW_OP_NE 4u
+#define PW_OP_LT 5u
+#define PW_OP_LE 6u
+#define PW_OP_GT 7u
+#define PW_OP_GE 8u
+
+/* reducers */
+#define PW_REDUCER_COUNT 0u
+#define PW_REDUCER_INT_SUM 1u
+#define PW_REDUCER_FLOAT_SUM 2u
+#define PW_REDUCER_MIN 3u
+#define PW_REDUCER_MAX 4u
+#define PW_REDUCER_ANY 5u
+
+typedef PwStatus (*PwBuildFn)(const PwGraph *graph, void *user_data);
+
+/* The message of the last error on the calling thread, or NULL. Valid until the next
+ * call of a function of this API on the same thread. */
+const char *pw_last_error_message(void);
+
+/* Runs the computation on the workers configured by the PATHWAY_THREADS,
+ * PATHWAY_PROCESSES and related environment variables. `build` is called
+ * concurrently by all workers. */
+PwStatus pw_run(PwBuildFn build, void *user_data);
+
+/* A table read from the JSON Lines files matching `path`. */
+PwStatus pw_connector_table_jsonlines(const PwGraph *graph, const char *path,
+                                      const char *const *column_names,
+                                      const uint32_t *column_types, size_t n_columns,
+                                      bool streaming, uint64_t autocommit_duration_ms,
+                                      PwTable *out);
+
+/* A table with the values of `expressions` computed from the columns `columns` of
+ * `table`. The arguments of the expressions refer to the positions in `columns`. */
+PwStatus pw_expression_table(const PwGraph *graph, PwTable table, const size_t *columns,
+                             size_t n_columns, PwExpression *const *expressions,
+                             size_t n_expressions, PwTable *out);
+
+/* A table with a row per group of `table` and a column per reducer. `reducers[i]` is
+ * applied to the column `reduced_columns[i]`, ignored by PW_REDUCER_COUNT. */
+PwStatus pw_group_by_table(const PwGraph *graph, PwTable table,
+                           const size_t *grouping_columns, size_t n_grouping_columns,
+                           const uint32_t *reducers, const size_t *reduced_columns,
+                           size_t n_reducers, PwTable *out);
+
+/* Writes the changes of `table` to the file `path` in the JSON Lines format. */
+PwStatus pw_output_table_jsonlines(const PwGraph *graph, PwTable table, const char *path,
+                                   const char *const *column_names, size_t n_columns);
+
+PwExpression *pw_expression_argument(size_t index);
+PwExpression *pw_expression_int(int64_t value);
+PwExpression *pw_expression_float(double value);
+/* NULL if `value` isn't a valid UTF-8 string. */
+PwExpression *pw_expression_string(const char *value);
+/* NULL if the operator isn't supported for `operand_type`. */
+PwExpression *pw_expression_binary(uint32_t op, uint32_t operand_type, PwExpression *lhs,
+                                   PwExpression *rhs);
+/* Frees an expression that hasn't been passed to any other function. */
+void pw_expression_free(PwExpression *expression);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PATHWAY_ENGINE_H */
@@ -0,0 +1,113 @@
+# Copyright © 2026 Pathway
+
+import json
+import os
+import time
+import uuid
+
+import pytest
+from azure.storage.blob import BlobServiceClient
+
+import pathway as pw
+from pathway.internals.parse_graph import G
+
+
+@pytest.fixture
+def container_client():
+    account = os.environ["AZURE_BLOB_STORAGE_ACCOUNT"]
+    blob_service_client = BlobServiceClient(
+        account_url=f"https://{account}.blob.core.windows.net",
+        credential=os.environ["AZURE_BLOB_STORAGE_PASSWORD"],
+    )
+    return blob_service_client.get_container_client(
+        os.environ["AZURE_BLOB_STORAGE_CONTAINER"]
+    )
+
+
+@pytest.fixture
+def blob_prefix(container_client):
+    prefix = f"connector-integration-tests/{time.time()}-{uuid.uuid4()}"
+    yield prefix
+    for blob in container_client.list_blobs(name_starts_with=prefix):
+        container_client.delete_blob(blob.name)
+
+
+def read_output(path):
+    with open(path) as f:
+        return [json.loads(line) for line in f]
+
+
+def azure_read(path, **kwargs):
+    return pw.io.azure.read(
+        path,
+        account=os.environ["AZURE_BLOB_STORAGE_ACCOUNT"],
+        container=os.environ["AZURE_BLOB_STORAGE_CONTAINER"],
+        account_key=os.environ["AZURE_BLOB_STORAGE_PASSWORD"],
+        **kwargs,
+    )
+
+
+def test_nested_blobs_in_modification_order(tmp_path, container_client, blob_prefix):
+    # The blobs are uploaded one by one, so their modification times differ
+    blob_names = ["b.txt", "nested/a.txt", "nested/deeper/c.txt", "a.txt"]
+    for blob_name in blob_names:
+        container_client.upload_blob(f"{blob_prefix}/{blob_name}", blob_name)
+        time.sleep(1.1)
+    container_client.upload_blob(f"{blob_prefix}/skipped.bin", "skipped")
+
+    table = azure_read(
+        f"{blob_prefix}/",
+        format="plaintext_by_object",
+        mode="static",
+        path_filter="*.txt",
+        with_metadata=True,
+    )
+    output_path = tmp_path / "output.jsonl"
+    pw.io.jsonlines.write(table, output_path)
+    pw.run()
+
+    rows = read_output(output_path)
+    assert sorted(row["data"] for row in rows) == sorted(blob_names)
+    rows.sort(key=lambda row: row["_metadata"]["modified_at"])
+    assert [row["data"] for row in rows] == blob_names
+    for row in rows:
+        assert row["_metadata"]["path"] == f"{blob_prefix}/{row['data']}"
+
+
+def test_only_metadata(tmp_path, container_client, blob_prefix):
+    container_client.upload_blob(f"{blob_prefix}/input.csv", "key,value\n1,one\n")
+
+    table = azure_read(blob_prefix, format="only_metadata", mode="static")
+    output_path = tmp_path / "output.jsonl"
+    pw.io.jsonlines.write(table, output_path)
+    pw.run()
+
+    [row] = read_output(output_path)
+    assert row["_metadata"]["path"] == f"{blob_prefix}/input.csv"
+    assert row["_metadata"]["size"] == len("key,value\n1,one\n")
+
+
+def test_csv_persisted(tmp_path, container_client, blob_prefix):
+    persistence_config = pw.persistence.Config(
+        pw.persistence.Backend.filesystem(tmp_path / "PStorage")
+    )
+
+    def run_and_read(run_id):
+        G.clear()
+        table = azure_read(
+            blob_prefix,
+            format="csv",
+            schema=pw.schema_from_types(key=int, value=str),
+            mode="static",
+            name="azure-input",
+        )
+        output_path = tmp_path / f"output-{run_id}.jsonl"
+        pw.io.jsonlines.write(table, output_path)
+        pw.run(persistence_config=persistence_config)
+        return sorted((row["key"], row["value"]) for row in read_output(output_path))
+
+    container_client.upload_blob(f"{blob_prefix}/1.csv", "key,value\n1,one\n")
+    assert run_and_read(1) == [(1, "one")]
+
+    container_client.upload_blob(f"{blob_prefix}/2.csv", "key,value\n2,two\n")
+    assert run_and_read(2) == [(2, "two")]
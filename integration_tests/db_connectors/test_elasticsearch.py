@@ -432,6 +432,35 @@ def test_elasticsearch_write_splits_batch_larger_than_max_content_length(
     assert elasticsearch.document_count(index_name) == n_rows
 
 
+@pytest.mark.parametrize("linger_ms", [None, 200])
+def test_elasticsearch_write_with_batching(tmp_path, elasticsearch, linger_ms):
+    index_name = elasticsearch.generate_index_name()
+    elasticsearch.create_index(
+        index_name,
+        {"doc_id": {"type": "keyword"}, "ts": {"type": "long"}},
+    )
+
+    class InputSchema(pw.Schema):
+        doc_id: str
+        ts: int
+
+    G.clear()
+    table = pw.debug.table_from_rows(
+        InputSchema, [(f"d{i}", 1000 + i) for i in range(100)]
+    )
+    pw.io.elasticsearch.write(
+        table=table,
+        host=ELASTICSEARCH_URL,
+        auth=pw.io.elasticsearch.ElasticSearchAuth.basic("admin", "admin"),
+        index_name=index_name,
+        max_batch_size=7,
+        linger_ms=linger_ms,
+    )
+    run()
+
+    assert elasticsearch.document_count(index_name) == 100
+
+
 @pytest.mark.parametrize(
     ("make_documents", "read_batch_size"),
     [
@@ -1503,3 +1503,91 @@ def test_mysql_write_selects_expected_strategy(mysql_either_strategy):
     # The data still lands regardless of the strategy.
     contents = ctx.get_table_contents(table_name, ["row_id", "v"], sort_by="row_id")
     assert contents == [{"row_id": 1, "v": 10}, {"row_id": 2, "v": 20}]
+
+
+def test_mysql_cursor_reads_table_and_query(tmp_path, mysql):
+    class InputSchema(pw.Schema):
+        id: int
+        value: str
+
+    table_name = mysql.random_table_name()
+    mysql.cursor.execute(
+        f"CREATE TABLE {table_name} "
+        "(id INT AUTO_INCREMENT PRIMARY KEY, value VARCHAR(64) NOT NULL)"
+    )
+    for i in range(5):
+        mysql.insert_row(table_name, {"value": f"row_{i}"})
+
+    table_path = tmp_path / "table.jsonl"
+    table = pw.io.mysql.read(
+        MYSQL_CONNECTION_STRING,
+        table_name,
+        InputSchema,
+        mode="static",
+        cursor_column="id",
+        read_batch_size=2,
+    )
+    pw.io.jsonlines.write(table, table_path)
+    query_path = tmp_path / "query.jsonl"
+    query_table = pw.io.mysql.read(
+        MYSQL_CONNECTION_STRING,
+        None,
+        InputSchema,
+        mode="static",
+        cursor_column="id",
+        query=f"SELECT id, value FROM {table_name} WHERE value <> 'row_2'",
+    )
+    pw.io.jsonlines.write(query_table, query_path)
+    run()
+
+    def rows(path):
+        return sorted((row["id"], row["value"]) for row in read_jsonlines(path))
+
+    assert rows(table_path) == [(i + 1, f"row_{i}") for i in range(5)]
+    assert rows(query_path) == [(i + 1, f"row_{i}") for i in range(5) if i != 2]
+
+
+def test_mysql_cursor_streaming_reads_new_rows(tmp_path, mysql):
+    class InputSchema(pw.Schema):
+        id: int
+        value: str
+
+    table_name = mysql.random_table_name()
+    mysql.cursor.execute(
+        f"CREATE TABLE {table_name} "
+        "(id BIGINT AUTO_INCREMENT PRIMARY KEY, value VARCHAR(64) NOT NULL)"
+    )
+    mysql.insert_row(table_name, {"value": "row_0"})
+
+    output_path = tmp_path / "output.jsonl"
+    table = pw.io.mysql.read(
+        MYSQL_CONNECTION_STRING,
+        table_name,
+        InputSchema,
+        cursor_column="id",
+        poll_interval=0.1,
+        autocommit_duration_ms=10,
+    )
+    pw.io.jsonlines.write(table, output_path)
+
+    def stream_target():
+        writer = MySQLContext()
+        try:
+            wait_result_with_checker(
+                FileLinesNumberChecker(output_path, 1), 30, target=None
+            )
+            for i in range(1, 10):
+                writer.insert_row(table_name, {"value": f"row_{i}"})
+                wait_result_with_checker(
+                    FileLinesNumberChecker(output_path, i + 1), 30, target=None
+                )
+        finally:
+            writer.close()
+
+    stream_thread = ExceptionAwareThread(target=stream_target, daemon=True)
+    stream_thread.start()
+    wait_result_with_checker(FileLinesNumberChecker(output_path, 10), 60)
+    stream_thread.join()
+
+    rows = sorted((row["id"], row["value"]) for row in read_jsonlines(output_path))
+    assert rows == [(i + 1, f"row_{i}") for i in range(10)]
@@ -627,6 +627,71 @@ def test_psql_json_datetimes(postgres):
     assert result["c"] == expected
 
 
+def test_psql_date_time_zoned_roundtrip(tmp_path, postgres):
+    class InputSchema(pw.Schema):
+        k: int = pw.column_definition(primary_key=True)
+        t: pw.DateTimeZoned
+
+    output_path = tmp_path / "output.jsonl"
+    table_name = postgres.random_table_name()
+    postgres.execute_sql(
+        f"""
+        CREATE TABLE {table_name} (
+            k BIGINT PRIMARY KEY,
+            t TEXT NOT NULL
+        );
+        """
+    )
+
+    table = pw.debug.table_from_markdown(
+        """
+        k | t                         | timezone
+         1 | 2023-10-29T00:30:00+00:00 | Europe/Warsaw
+         2 | 2023-10-29T01:30:00+00:00 | Europe/Warsaw
+         3 | 2024-07-01T16:00:00+00:00 | Asia/Tokyo
+        """
+    ).select(
+        pw.this.k,
+        t=pw.this.t.dt.strptime("%Y-%m-%dT%H:%M:%S%z").dt.to_zoned(pw.this.timezone),
+    )
+    pw.io.postgres.write(
+        table,
+        POSTGRES_SETTINGS,
+        table_name,
+        output_table_type="snapshot",
+        primary_key=[table.k],
+    )
+    run()
+
+    # the zone name is kept next to the offset, which alone would be ambiguous
+    expected = [
+        {"k": 1, "t": "2023-10-29T02:30:00.000000000+02:00[Europe/Warsaw]"},
+        {"k": 2, "t": "2023-10-29T02:30:00.000000000+01:00[Europe/Warsaw]"},
+        {"k": 3, "t": "2024-07-02T01:00:00.000000000+09:00[Asia/Tokyo]"},
+    ]
+    assert postgres.get_table_contents(table_name, ["k", "t"], "k") == expected
+
+    G.clear()
+    table = pw.io.postgres.read(
+        postgres_settings=POSTGRES_SETTINGS,
+        table_name=table_name,
+        schema=InputSchema,
+        mode="static",
+    )
+    table = table.select(pw.this.k, pw.this.t, timezone=pw.this.t.dt.timezone())
+    pw.io.jsonlines.write(table, output_path)
+    run()
+
+    with open(output_path) as f:
+        rows = sorted((json.loads(line) for line in f), key=lambda row: row["k"])
+    assert [{"k": r["k"], "t": r["t"]} for r in rows] == expected
+    assert [r["timezone"] for r in rows] == [
+        "Europe/Warsaw",
+        "Europe/Warsaw",
+        "Asia/Tokyo",
+    ]
+
+
 # TODO: remove when pw.io.postgres.write_snapshot is fully deprecated.
 def test_psql_external_diff_column_legacy(tmp_path, postgres):
     class InputSchema(pw.Schema):
@@ -5025,3 +5090,256 @@ def test_psql_copy_writer_ingests_500k_rows_within_30s(tmp_path, postgres):
             max_batch_size=10_000,
         )
         wait_result_with_checker(RowCountChecker(n_rows, postgres, table_name), 30)
+
+
+def test_psql_write_with_parallel_writers(postgres):
+    class InputSchema(pw.Schema):
+        k: int
+        bucket: int
+
+    table_name = postgres.random_table_name()
+    table = pw.debug.table_from_rows(InputSchema, [(i, i % 3) for i in range(100)])
+    pw.io.postgres.write(
+        table,
+        postgres_settings=POSTGRES_SETTINGS,
+        table_name=table_name,
+        init_mode="create_if_not_exists",
+        parallel_writers=4,
+        partition_by=[table.bucket],
+    )
+    run()
+
+    rows = postgres.get_table_contents(table_name, ["k", "bucket", "diff"], "k")
+    assert rows == [{"k": i, "bucket": i % 3, "diff": 1} for i in range(100)]
+
+
+def test_psql_write_snapshot_with_parallel_writers(postgres):
+    class InputSchema(pw.Schema):
+        k: int
+        v: str
+
+    table_name = postgres.random_table_name()
+    for value in ["old", "new"]:
+        G.clear()
+        table = pw.debug.table_from_rows(InputSchema, [(i, value) for i in range(50)])
+        pw.io.postgres.write(
+            table,
+            postgres_settings=POSTGRES_SETTINGS,
+            table_name=table_name,
+            init_mode="create_if_not_exists",
+            output_table_type="snapshot",
+            primary_key=[table.k],
+            parallel_writers=4,
+        )
+        run()
+
+    rows = postgres.get_table_contents(table_name, InputSchema.column_names(), "k")
+    assert rows == [{"k": i, "v": "new"} for i in range(50)]
+
+
+def test_psql_write_rejects_zero_parallel_writers(postgres):
+    class InputSchema(pw.Schema):
+        k: int
+
+    table = pw.debug.table_from_rows(InputSchema, [(1,)])
+    with pytest.raises(ValueError, match="parallel_writers must be positive"):
+        pw.io.postgres.write(
+            table,
+            postgres_settings=POSTGRES_SETTINGS,
+            table_name=postgres.random_table_name(),
+            parallel_writers=0,
+        )
+
+
+@pytest.mark.parametrize(
+    ("delivery_semantics", "expected_copies"),
+    [("exactly_once", 1), ("at_least_once", 2)],
+)
+def test_psql_write_delivery_semantics_after_restart(
+    tmp_path, postgres, delivery_semantics, expected_copies
+):
+    class InputSchema(pw.Schema):
+        k: int
+
+    input_path = tmp_path / "input.jsonl"
+    with open(input_path, "w") as f:
+        for i in range(3):
+            f.write(json.dumps({"k": i}) + "\n")
+    table_name = postgres.random_table_name()
+
+    for _ in range(2):
+        G.clear()
+        table = pw.io.jsonlines.read(
+            input_path, schema=InputSchema, mode="static", name="input"
+        )
+        pw.io.postgres.write(
+            table,
+            postgres_settings=POSTGRES_SETTINGS,
+            table_name=table_name,
+            init_mode="create_if_not_exists",
+            delivery_semantics=delivery_semantics,
+        )
+        run(
+            persistence_config=pw.persistence.Config(
+                pw.persistence.Backend.filesystem(tmp_path / "pstorage")
+            )
+        )
+
+    rows = postgres.get_table_contents(table_name, ["k"], "k")
+    assert rows == [{"k": i} for i in range(3) for _ in range(expected_copies)]
+
+
+def test_psql_write_watermark_atomic(postgres):
+    class InputSchema(pw.Schema):
+        k: int
+        v: int
+
+    class InputSubject(pw.io.python.ConnectorSubject):
+        def run(self) -> None:
+            for batch in range(3):
+                for k in range(10):
+                    self.next(k=k, v=batch)
+                self.commit()
+
+    table_name = postgres.random_table_name()
+    table = pw.io.python.read(InputSubject(), schema=InputSchema)
+    totals = table.groupby(pw.this.v).reduce(pw.this.v, count=pw.reducers.count())
+    pw.io.postgres.write(
+        totals,
+        postgres_settings=POSTGRES_SETTINGS,
+        table_name=table_name,
+        init_mode="create_if_not_exists",
+        output_mode="watermark_atomic",
+    )
+    run()
+
+    rows = postgres.get_table_contents(table_name, ["v", "count", "diff"], "v")
+    assert rows == [{"v": v, "count": 10, "diff": 1} for v in range(3)]
+
+
+@pytest.mark.parametrize(
+    ("kwargs", "message"),
+    [
+        ({"max_batch_size": 10}, "max_batch_size can't be set"),
+        ({"parallel_writers": 2}, "requires a single writer"),
+    ],
+)
+def test_psql_write_watermark_atomic_rejects_splitting(postgres, kwargs, message):
+    class InputSchema(pw.Schema):
+        k: int
+
+    table = pw.debug.table_from_rows(InputSchema, [(1,)])
+    with pytest.raises(ValueError, match=message):
+        pw.io.postgres.write(
+            table,
+            postgres_settings=POSTGRES_SETTINGS,
+            table_name=postgres.random_table_name(),
+            output_mode="watermark_atomic",
+            **kwargs,
+        )
+        run()
+
+
+def _read_output_rows(output_path) -> list[tuple[int, str]]:
+    with open(output_path) as f:
+        rows = [json.loads(line) for line in f]
+    assert all(row["diff"] == 1 for row in rows)
+    return sorted((row["id"], row["value"]) for row in rows)
+
+
+def test_postgres_cursor_static(tmp_path, postgres):
+    class InputSchema(pw.Schema):
+        id: int
+        value: str
+
+    output_path = tmp_path / "output.jsonl"
+    table_name = postgres.random_table_name()
+    # An `integer` cursor checks that the bound parameters are cast
+    postgres.execute_sql(
+        f"CREATE TABLE {table_name} (id SERIAL PRIMARY KEY, value TEXT NOT NULL);"
+    )
+    for i in range(5):
+        postgres.execute_sql(f"INSERT INTO {table_name} (value) VALUES ('row_{i}');")
+
+    table = pw.io.postgres.read(
+        postgres_settings=POSTGRES_SETTINGS,
+        table_name=table_name,
+        schema=InputSchema,
+        mode="static",
+        cursor_column="id",
+        read_batch_size=2,
+    )
+    pw.io.jsonlines.write(table, output_path)
+    run()
+
+    assert _read_output_rows(output_path) == [(i + 1, f"row_{i}") for i in range(5)]
+
+
+def test_postgres_cursor_static_query(tmp_path, postgres):
+    class InputSchema(pw.Schema):
+        id: int
+        value: str
+
+    output_path = tmp_path / "output.jsonl"
+    table_name = postgres.random_table_name()
+    postgres.execute_sql(
+        f"CREATE TABLE {table_name} (id BIGSERIAL PRIMARY KEY, value TEXT NOT NULL);"
+    )
+    for i in range(5):
+        postgres.execute_sql(f"INSERT INTO {table_name} (value) VALUES ('row_{i}');")
+
+    table = pw.io.postgres.read(
+        postgres_settings=POSTGRES_SETTINGS,
+        table_name=None,
+        schema=InputSchema,
+        mode="static",
+        cursor_column="id",
+        query=f"SELECT id, value FROM {table_name} WHERE value <> 'row_2'",
+    )
+    pw.io.jsonlines.write(table, output_path)
+    run()
+
+    assert _read_output_rows(output_path) == [
+        (i + 1, f"row_{i}") for i in range(5) if i != 2
+    ]
+
+
+def test_postgres_cursor_streaming_reads_new_rows(tmp_path, postgres):
+    class InputSchema(pw.Schema):
+        id: int
+        value: str
+
+    output_path = tmp_path / "output.jsonl"
+    table_name = postgres.random_table_name()
+    postgres.execute_sql(
+        f"CREATE TABLE {table_name} (id BIGSERIAL PRIMARY KEY, value TEXT NOT NULL);"
+    )
+    postgres.execute_sql(f"INSERT INTO {table_name} (value) VALUES ('row_0');")
+
+    table = pw.io.postgres.read(
+        postgres_settings=POSTGRES_SETTINGS,
+        table_name=table_name,
+        schema=InputSchema,
+        cursor_column="id",
+        poll_interval=0.1,
+        autocommit_duration_ms=10,
+    )
+    pw.io.jsonlines.write(table, output_path)
+
+    def stream_target():
+        wait_result_with_checker(
+            FileLinesNumberChecker(output_path, 1), 30, target=None
+        )
+        for i in range(1, 10):
+            postgres.execute_sql(
+                f"INSERT INTO {table_name} (value) VALUES ('row_{i}');"
+            )
+            wait_result_with_checker(
+                FileLinesNumberChecker(output_path, i + 1), 30, target=None
+            )
+
+    stream_thread = threading.Thread(target=stream_target, daemon=True)
+    stream_thread.start()
+    wait_result_with_checker(FileLinesNumberChecker(output_path, 10), 60)
+
+    assert _read_output_rows(output_path) == [(i + 1, f"row_{i}") for i in range(10)]
@@ -1756,3 +1756,42 @@ def test_iceberg_preflight_writer_struct_inner_type_mismatch(tmp_path):
         ),
     )
     _run_expecting_error("payload")
+
+
+def test_iceberg_snapshot_output(tmp_path):
+    namespace = uuid.uuid4().hex
+    table_name = uuid.uuid4().hex
+
+    class InputSchema(pw.Schema):
+        user_id: int
+        name: str
+
+    table = pw.debug.table_from_markdown(
+        """
+        id | user_id | name  | __time__ | __diff__
+        1  | 1       | John  | 2        | 1
+        2  | 2       | Jane  | 2        | 1
+        3  | 3       | Alice | 2        | 1
+        1  | 1       | John  | 4        | -1
+        1  | 1       | Johny | 4        | 1
+        2  | 2       | Jane  | 6        | -1
+        4  | 4       | Bob   | 6        | 1
+        """
+    )
+    pw.io.iceberg.write(
+        table,
+        catalog=_get_catalog(LOCAL_BACKEND_NAME),
+        namespace=[namespace],
+        table_name=table_name,
+        min_commit_frequency=None,
+        output_table_type="snapshot",
+    )
+    run()
+
+    # Every minibatch is committed as a separate Iceberg snapshot
+    qualified_name = f"{namespace}.{table_name}"
+    assert _count_iceberg_snapshots(LOCAL_BACKEND_NAME, qualified_name) == 3
+
+    G.clear()
+    names = _read_back_column(namespace, table_name, InputSchema, "name")
+    assert sorted(names) == ["Alice", "Bob", "Johny"]
@@ -6,6 +6,7 @@ import pytest
 
 from .utils import (
     KINESIS_ENDPOINT_URL,
+    FirehoseMock,
     KafkaTestContext,
     KinesisTestContext,
     MqttTestContext,
@@ -42,3 +43,22 @@ def kinesis_context(monkeypatch):
     monkeypatch.setenv("AWS_SECRET_ACCESS_KEY", "placeholder")
     kinesis_context = KinesisTestContext()
     yield kinesis_context
+
+
+@pytest.fixture
+def firehose_mock(monkeypatch):
+    mocks: list[FirehoseMock] = []
+
+    def start(reject):
+        mock = FirehoseMock(reject)
+        mocks.append(mock)
+        monkeypatch.setenv("AWS_ENDPOINT_URL", mock.endpoint_url)
+        monkeypatch.setenv("AWS_REGION", "us-east-1")
+        monkeypatch.setenv("AWS_DEFAULT_REGION", "us-east-1")
+        monkeypatch.setenv("AWS_ACCESS_KEY_ID", "placeholder")
+        monkeypatch.setenv("AWS_SECRET_ACCESS_KEY", "placeholder")
+        return mock
+
+    yield start
+    for mock in mocks:
+        mock.stop()
@@ -0,0 +1,54 @@
+# Copyright © 2026 Pathway
+
+import pytest
+
+import pathway as pw
+
+VALUES = [b"one", b"two", b"three", b"four", b"five"]
+
+
+def write_values(delivery_stream_name: str) -> None:
+    table = pw.debug.table_from_markdown(
+        """
+        | value
+     1  | one
+     2  | two
+     3  | three
+     4  | four
+     5  | five
+    """
+    )
+    pw.io.kinesis.write_firehose(
+        table, delivery_stream_name, format="plaintext", data=table.value
+    )
+
+
+def test_firehose_retries_only_rejected_records(firehose_mock):
+    rejected = {b"two": "ServiceUnavailableException", b"four": "InternalFailure"}
+
+    def reject(attempt: int, data: bytes) -> str | None:
+        return rejected.get(data) if attempt == 0 else None
+
+    mock = firehose_mock(reject)
+    write_values(mock.delivery_stream_name)
+    pw.run()
+
+    assert len(mock.batches) == 2
+    assert sorted(mock.batches[0]) == sorted(VALUES)
+    # only the rejected records are sent again, in the order they were sent first
+    assert mock.batches[1] == [data for data in mock.batches[0] if data in rejected]
+    assert sorted(mock.delivered) == sorted(VALUES)
+
+
+def test_firehose_fails_on_records_rejected_in_every_attempt(firehose_mock):
+    def reject(attempt: int, data: bytes) -> str | None:
+        return "InternalFailure" if data == b"three" else None
+
+    mock = firehose_mock(reject)
+    write_values(mock.delivery_stream_name)
+    with pytest.raises(Exception, match="1 items haven't been saved"):
+        pw.run()
+
+    assert len(mock.batches) == 5
+    assert mock.batches[1:] == [[b"three"]] * 4
+    assert sorted(mock.delivered) == sorted(set(VALUES) - {b"three"})
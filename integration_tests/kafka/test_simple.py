@@ -257,6 +257,177 @@ def test_null_header(tmp_path, kafka_context):
     assert n_rows == 3
 
 
+@pytest.mark.flaky(reruns=3)
+def test_kafka_headers_column(tmp_path, kafka_context):
+    kafka_context.fill(
+        [json.dumps({"k": 0, "v": "foo"})],
+        headers=[
+            ("traceparent", b"00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"),
+            ("X-Trace-ID", b"first"),
+            ("X-Trace-ID", b"second"),
+            ("X-Origin", b""),
+        ],
+    )
+    kafka_context.fill([json.dumps({"k": 1, "v": "bar"})])
+
+    class InputSchema(pw.Schema):
+        k: int = pw.column_definition(primary_key=True)
+        v: str
+
+    table = pw.io.kafka.read(
+        rdkafka_settings=kafka_context.default_rdkafka_settings(),
+        topic=kafka_context.input_topic,
+        format="json",
+        mode="static",
+        schema=InputSchema,
+        with_headers=True,
+    )
+    pw.io.kafka.write(
+        table.select(
+            pw.this.k,
+            pw.this.v,
+            trace=pw.this["_headers"].get("traceparent").as_str(),
+        ),
+        rdkafka_settings=kafka_context.default_rdkafka_settings(),
+        topic_name=kafka_context.output_topic,
+        format="json",
+        headers={"traceparent": pw.this.trace},
+    )
+    output_path = tmp_path / "output.jsonl"
+    pw.io.jsonlines.write(table, output_path)
+    pw.run()
+
+    with open(output_path, "r") as f:
+        rows = {row["k"]: row for row in map(json.loads, f)}
+    assert rows[0]["_headers"] == {
+        "traceparent": "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+        "X-Trace-ID": "second",
+        "X-Origin": "",
+    }
+    assert rows[1]["_headers"] == {}
+
+    output_topic_contents = kafka_context.read_output_topic(
+        expected_headers=["pathway_time", "pathway_diff", "traceparent"]
+    )
+    assert len(output_topic_contents) == 2
+
+
+@pytest.mark.flaky(reruns=3)
+def test_kafka_headers_column_raw(tmp_path, kafka_context):
+    kafka_context.fill(["foo"], headers=[("X-Sender-ID", b"pathway")])
+
+    table = pw.io.kafka.read(
+        rdkafka_settings=kafka_context.default_rdkafka_settings(),
+        topic=kafka_context.input_topic,
+        format="plaintext",
+        mode="static",
+        with_headers=True,
+    )
+    output_path = tmp_path / "output.jsonl"
+    pw.io.jsonlines.write(table, output_path)
+    pw.run()
+
+    with open(output_path, "r") as f:
+        rows = [json.loads(row) for row in f]
+    assert [(row["data"], row["_headers"]) for row in rows] == [
+        ("foo", {"X-Sender-ID": "pathway"})
+    ]
+
+
+@pytest.mark.flaky(reruns=3)
+def test_kafka_topic_pattern(tmp_path, kafka_context):
+    prefix = f"KafkaPattern.{uuid.uuid4()}"
+    kafka_context._create_topic(f"{prefix}.first")
+    kafka_context._create_topic(f"{prefix}.second")
+    kafka_context.send("foo", topic=f"{prefix}.first")
+    kafka_context.send("bar", topic=f"{prefix}.second")
+    kafka_context.send("ignored", topic=kafka_context.input_topic)
+
+    rdkafka_settings = kafka_context.default_rdkafka_settings()
+    rdkafka_settings["topic.metadata.refresh.interval.ms"] = "500"
+    table = pw.io.kafka.read(
+        rdkafka_settings=rdkafka_settings,
+        topic_pattern=prefix.replace(".", "\\.") + "\\..*",
+        format="plaintext",
+        autocommit_duration_ms=100,
+        with_metadata=True,
+    )
+    table = table.select(pw.this.data, topic=pw.this._metadata["topic"].as_str())
+    output_path = tmp_path / "output.jsonl"
+    pw.io.jsonlines.write(table, output_path)
+
+    def create_topic_at_runtime():
+        time.sleep(5)
+        kafka_context._create_topic(f"{prefix}.third")
+        kafka_context.send("baz", topic=f"{prefix}.third")
+
+    t = threading.Thread(target=create_topic_at_runtime, daemon=True)
+    t.start()
+    wait_result_with_checker(FileLinesNumberChecker(output_path, 3), 60)
+
+    with open(output_path, "r") as f:
+        rows = {(row["data"], row["topic"]) for row in map(json.loads, f)}
+    assert rows == {
+        ("foo", f"{prefix}.first"),
+        ("bar", f"{prefix}.second"),
+        ("baz", f"{prefix}.third"),
+    }
+
+
+def test_kafka_topic_pattern_rejected_options(kafka_context):
+    rdkafka_settings = kafka_context.default_rdkafka_settings()
+    with pytest.raises(ValueError, match="can't be used together"):
+        pw.io.kafka.read(rdkafka_settings, "topic", topic_pattern="^topic-.*")
+    with pytest.raises(ValueError, match="streaming mode"):
+        pw.io.kafka.read(rdkafka_settings, topic_pattern="^topic-.*", mode="static")
+    with pytest.raises(ValueError, match="start_from_offsets"):
+        pw.io.kafka.read(
+            rdkafka_settings, topic_pattern="^topic-.*", start_from_offsets={0: 1}
+        )
+
+
+@pytest.mark.parametrize("mode", ["streaming", "static"])
+@pytest.mark.flaky(reruns=3)
+def test_kafka_partition_aligned(tmp_path, kafka_context, monkeypatch, mode):
+    monkeypatch.setenv("PATHWAY_THREADS", "4")
+    kafka_context.set_input_topic_partitions(6)
+    messages = [(f"key_{i}", f"value_{i}") for i in range(60)]
+    kafka_context.fill(messages)
+
+    table = pw.io.kafka.read(
+        rdkafka_settings=kafka_context.default_rdkafka_settings(),
+        topic=kafka_context.input_topic,
+        format="plaintext",
+        mode=mode,
+        autocommit_duration_ms=100,
+        partition_aligned=True,
+    )
+    result = table.groupby(pw.this.key).reduce(
+        pw.this.key, data=pw.reducers.any(pw.this.data)
+    )
+    output_path = tmp_path / "output.jsonl"
+    pw.io.jsonlines.write(result, output_path)
+    if mode == "static":
+        pw.run()
+    else:
+        wait_result_with_checker(FileLinesNumberChecker(output_path, 60), 30)
+
+    with open(output_path, "r") as f:
+        rows = {(row["key"], row["data"]) for row in map(json.loads, f)}
+    assert rows == set(messages)
+
+
+def test_kafka_partition_aligned_with_parallel_readers_rejected(kafka_context):
+    with pytest.raises(ValueError, match="partition_aligned"):
+        pw.io.kafka.read(
+            rdkafka_settings=kafka_context.default_rdkafka_settings(),
+            topic=kafka_context.input_topic,
+            format="plaintext",
+            parallel_readers=2,
+            partition_aligned=True,
+        )
+
+
 @pytest.mark.parametrize("with_metadata", [False, True])
 @pytest.mark.flaky(reruns=3)
 def test_kafka_json(tmp_path, kafka_context, with_metadata):
@@ -510,6 +681,44 @@ def test_kafka_output(tmp_path: pathlib.Path, kafka_context: KafkaTestContext):
     assert len(output_topic_contents) == 2
 
 
+@pytest.mark.flaky(reruns=3)
+def test_kafka_output_commit_group(
+    tmp_path: pathlib.Path, kafka_context: KafkaTestContext
+):
+    payments_topic = kafka_context.create_additional_topic()
+    orders = pw.debug.table_from_markdown(
+        """
+        order_id | amount
+        1        | 10
+        2        | 20
+        """
+    )
+    payments = orders.select(pw.this.order_id, paid=pw.this.amount)
+    settings = kafka_context.default_rdkafka_settings()
+    pw.io.kafka.write(
+        orders,
+        {**settings, "transactional.id": f"{kafka_context.output_topic}-orders"},
+        topic_name=kafka_context.output_topic,
+    )
+    pw.io.kafka.write(
+        payments,
+        {**settings, "transactional.id": f"{payments_topic}-payments"},
+        topic_name=payments_topic,
+    )
+    pw.io.register_output_commit_group(orders, payments)
+    pw.run()
+
+    # the consumers of librdkafka read only the committed messages by default
+    for topic in (kafka_context.output_topic, payments_topic):
+        G.clear()
+        table = pw.io.kafka.simple_read(
+            settings["bootstrap.servers"], topic, format="plaintext"
+        )
+        output_path = tmp_path / f"{topic}.jsonl"
+        pw.io.jsonlines.write(table, output_path)
+        wait_result_with_checker(FileLinesNumberChecker(output_path, 2), 30)
+
+
 @pytest.mark.flaky(reruns=3)
 def test_kafka_raw_bytes_output(
     tmp_path: pathlib.Path, kafka_context: KafkaTestContext
@@ -850,6 +1059,113 @@ def test_start_from_timestamp_ms_seek_to_end(
     )
 
 
+@pytest.mark.flaky(reruns=3)
+def test_kafka_start_from_end(tmp_path: pathlib.Path, kafka_context: KafkaTestContext):
+    kafka_context.fill(["foo", "bar"])
+
+    table = pw.io.kafka.read(
+        rdkafka_settings=kafka_context.default_rdkafka_settings(),
+        topic=kafka_context.input_topic,
+        format="plaintext",
+        autocommit_duration_ms=100,
+        start_from="end",
+    )
+    output_path = tmp_path / "output.jsonl"
+    pw.io.jsonlines.write(table, output_path)
+
+    def stream_inputs():
+        time.sleep(5)
+        kafka_context.fill(["baz", "qux"])
+
+    t = threading.Thread(target=stream_inputs, daemon=True)
+    t.start()
+    wait_result_with_checker(FileLinesNumberChecker(output_path, 2), 30)
+
+    with open(output_path, "r") as f:
+        assert {json.loads(row)["data"] for row in f} == {"baz", "qux"}
+
+
+@pytest.mark.flaky(reruns=3)
+def test_kafka_start_from_beginning(
+    tmp_path: pathlib.Path, kafka_context: KafkaTestContext
+):
+    kafka_context.fill(["foo", "bar"])
+    rdkafka_settings = kafka_context.default_rdkafka_settings()
+    rdkafka_settings["auto.offset.reset"] = "latest"
+
+    table = pw.io.kafka.read(
+        rdkafka_settings=rdkafka_settings,
+        topic=kafka_context.input_topic,
+        format="plaintext",
+        autocommit_duration_ms=100,
+        start_from="beginning",
+    )
+    output_path = tmp_path / "output.jsonl"
+    pw.io.jsonlines.write(table, output_path)
+    wait_result_with_checker(FileLinesNumberChecker(output_path, 2), 30)
+
+    with open(output_path, "r") as f:
+        assert {json.loads(row)["data"] for row in f} == {"foo", "bar"}
+
+
+@pytest.mark.flaky(reruns=3)
+def test_kafka_start_from_per_partition(
+    tmp_path: pathlib.Path, kafka_context: KafkaTestContext
+):
+    kafka_context.fill(["foo", "bar"])
+
+    table = pw.io.kafka.read(
+        rdkafka_settings=kafka_context.default_rdkafka_settings(),
+        topic=kafka_context.input_topic,
+        format="plaintext",
+        autocommit_duration_ms=100,
+        start_from={0: "end"},
+    )
+    output_path = tmp_path / "output.jsonl"
+    pw.io.jsonlines.write(table, output_path)
+
+    def stream_inputs():
+        time.sleep(5)
+        kafka_context.fill(["baz", "qux"])
+
+    t = threading.Thread(target=stream_inputs, daemon=True)
+    t.start()
+    wait_result_with_checker(FileLinesNumberChecker(output_path, 2), 30)
+
+    with open(output_path, "r") as f:
+        assert {json.loads(row)["data"] for row in f} == {"baz", "qux"}
+
+
+def test_kafka_start_from_conflicting_options_rejected(kafka_context):
+    rdkafka_settings = kafka_context.default_rdkafka_settings()
+    with pytest.raises(ValueError, match="start_from_timestamp_ms"):
+        pw.io.kafka.read(
+            rdkafka_settings, kafka_context.input_topic, start_from="timestamp"
+        )
+    with pytest.raises(ValueError, match="start_from_timestamp_ms"):
+        pw.io.kafka.read(
+            rdkafka_settings,
+            kafka_context.input_topic,
+            start_from="end",
+            start_from_timestamp_ms=0,
+        )
+    with pytest.raises(ValueError, match="start_from_offsets"):
+        pw.io.kafka.read(
+            rdkafka_settings,
+            kafka_context.input_topic,
+            start_from="beginning",
+            start_from_offsets={0: 1},
+        )
+    with pytest.raises(ValueError, match="start_from_timestamp_ms"):
+        pw.io.kafka.read(
+            rdkafka_settings, kafka_context.input_topic, start_from={0: "timestamp"}
+        )
+    with pytest.raises(ValueError, match="non-negative partition numbers"):
+        pw.io.kafka.read(
+            rdkafka_settings, kafka_context.input_topic, start_from={-1: "end"}
+        )
+
+
 @pytest.mark.flaky(reruns=3)
 def test_kafka_json_key(tmp_path, kafka_context):
     input_path = tmp_path / "input.jsonl"
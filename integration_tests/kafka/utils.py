@@ -1,6 +1,7 @@
 # Copyright © 2026 Pathway
 
 import asyncio
+import base64
 import dataclasses
 import json
 import os
@@ -8,7 +9,8 @@ import pathlib
 import threading
 import time
 import uuid
-from collections.abc import Iterable
+from collections.abc import Callable, Iterable
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
 from typing import Mapping
 from uuid import uuid4
 
@@ -644,6 +646,90 @@ class KinesisTestContext:
             time.sleep(self._RETRY_INTERVAL)
 
 
+class FirehoseMock:
+    """A local Amazon Data Firehose endpoint, as the emulator doesn't reject records.
+
+    ``reject(attempt, data)`` gives the error code with which the record ``data`` is
+    rejected in the ``attempt``-th batch, counted from zero, or ``None`` if the record
+    is accepted. The records of each batch are kept in ``batches`` and the accepted ones
+    in ``delivered``.
+    """
+
+    delivery_stream_name = "firehose-test"
+
+    def __init__(self, reject: Callable[[int, bytes], str | None]) -> None:
+        self.reject = reject
+        self.batches: list[list[bytes]] = []
+        self.delivered: list[bytes] = []
+        self._lock = threading.Lock()
+        mock = self
+
+        class Handler(BaseHTTPRequestHandler):
+            protocol_version = "HTTP/1.1"
+
+            def do_POST(self) -> None:
+                body = self.rfile.read(int(self.headers["Content-Length"]))
+                operation = self.headers["X-Amz-Target"].split(".")[-1]
+                response = mock._handle(operation, json.loads(body))
+                payload = json.dumps(response).encode("utf-8")
+                self.send_response(200)
+                self.send_header("Content-Type", "application/x-amz-json-1.1")
+                self.send_header("Content-Length", str(len(payload)))
+                self.end_headers()
+                self.wfile.write(payload)
+
+            def log_message(self, format, *args) -> None:
+                pass
+
+        self._server = ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+        self._thread = threading.Thread(target=self._server.serve_forever, daemon=True)
+        self._thread.start()
+
+    @property
+    def endpoint_url(self) -> str:
+        host, port = self._server.server_address[:2]
+        return f"http://{host}:{port}"
+
+    def _handle(self, operation: str, request: dict) -> dict:
+        if operation == "DescribeDeliveryStream":
+            return {
+                "DeliveryStreamDescription": {
+                    "DeliveryStreamName": self.delivery_stream_name,
+                    "DeliveryStreamARN": "arn:aws:firehose:us-east-1:000000000000:"
+                    f"deliverystream/{self.delivery_stream_name}",
+                    "DeliveryStreamStatus": "ACTIVE",
+                    "DeliveryStreamType": "DirectPut",
+                    "VersionId": "1",
+                    "Destinations": [],
+                    "HasMoreDestinations": False,
+                }
+            }
+        assert operation == "PutRecordBatch", operation
+        records = [base64.b64decode(record["Data"]) for record in request["Records"]]
+        with self._lock:
+            attempt = len(self.batches)
+            self.batches.append(records)
+            responses = []
+            for data in records:
+                error_code = self.reject(attempt, data)
+                if error_code is None:
+                    self.delivered.append(data)
+                    responses.append({"RecordId": str(uuid4())})
+                else:
+                    responses.append(
+                        {"ErrorCode": error_code, "ErrorMessage": "rejected"}
+                    )
+        return {
+            "FailedPutCount": sum("ErrorCode" in response for response in responses),
+            "Encrypted": False,
+            "RequestResponses": responses,
+        }
+
+    def stop(self) -> None:
+        self._server.shutdown()
+        self._server.server_close()
+
+
 def create_schema_in_registry(
     column_types: Mapping[str, str], required_columns: list[str]
 ) -> str:
@@ -14,6 +14,7 @@ from pathway.internals import (
     ColumnReference,
     DateTimeNaive,
     DateTimeUtc,
+    DateTimeZoned,
     Duration,
     GroupedJoinResult,
     GroupedTable,
@@ -40,6 +41,7 @@ from pathway.internals import (
     column_definition,
     declare_type,
     enable_interactive_mode,
+    explain,
     fill_error,
     global_error_log,
     groupby,
@@ -55,6 +57,7 @@ from pathway.internals import (
     load_yaml,
     local_error_log,
     make_tuple,
+    request_shutdown,
     require,
     right,
     run,
@@ -88,6 +91,7 @@ from pathway.stdlib import (
 from pathway.stdlib.utils.async_transformer import AsyncTransformer
 from pathway.stdlib.utils.pandas_transformer import pandas_transformer
 
+import pathway.metrics as metrics  # isort: skip
 import pathway.persistence as persistence  # isort: skip
 
 __all__ = [
@@ -134,6 +138,8 @@ __all__ = [
     "sql",
     "run",
     "run_all",
+    "explain",
+    "request_shutdown",
     "if_else",
     "make_tuple",
     "Type",
@@ -158,6 +164,7 @@ __all__ = [
     "assert_table_has_schema",
     "DateTimeNaive",
     "DateTimeUtc",
+    "DateTimeZoned",
     "Duration",
     "Json",
     "table_transformer",
@@ -173,6 +180,7 @@ __all__ = [
     "groupby",
     "enable_interactive_mode",
     "LiveTable",
+    "metrics",
     "persistence",
     "set_license_key",
     "set_monitoring_config",
@@ -212,3 +220,4 @@ Table._repr_mimebundle_ = viz._repr_mimebundle_
 
 Table.inactivity_detection = temporal.inactivity_detection
 Table.add_update_timestamp_utc = temporal.add_update_timestamp_utc
+Table.assert_references = temporal.assert_references
@@ -26,6 +26,9 @@ DOWNSCALING_FACTOR = 0.5  # Must be < 1
 
 MAX_PORT = 65535
 
+DEFAULT_RESTART_DELAY = 1.0  # seconds
+DEFAULT_RESTART_RESET_AFTER = 600.0  # seconds
+
 
 def plural(n, singular, plural):
     if n == 1:
@@ -220,6 +223,9 @@ def spawn_program(
     program,
     arguments,
     env_base,
+    max_restarts=0,
+    restart_delay=DEFAULT_RESTART_DELAY,
+    restart_reset_after=DEFAULT_RESTART_RESET_AFTER,
 ):
     temp_root_directory = checkout_repository(repository_url, branch)
     if temp_root_directory is not None:
@@ -259,8 +265,9 @@ def spawn_program(
             arguments=arguments,
             env_base=env_base,
         )
-        handles_state = ProcessHandlesState()
-        while not handles_state.has_process_with_error:
+        restarts = 0
+        started_at = time.monotonic()
+        while True:
             handles_state = wait_for_process_handles(process_handles, timeout=1.0)
 
             if handles_state.needs_upscaling or handles_state.needs_downscaling:
@@ -291,6 +298,36 @@ def spawn_program(
                     arguments=arguments,
                     env_base=env_base,
                 )
+            elif handles_state.has_process_with_error:
+                # a failure after a long enough healthy run isn't counted with the
+                # earlier ones
+                if time.monotonic() - started_at >= restart_reset_after:
+                    restarts = 0
+                if restarts >= max_restarts:
+                    break
+                restarts += 1
+                # The other processes can't continue without the failed one, so the
+                # whole cluster is restarted. With persistence enabled, the restarted
+                # processes resume from the last frontier persisted by all of them.
+                terminate_process_handles(process_handles)
+                click.echo(
+                    f"A process has failed, restarting all processes "
+                    f"(restart {restarts} of {max_restarts})",
+                    err=True,
+                )
+                time.sleep(restart_delay)
+                process_handles = create_process_handles(
+                    processes=processes,
+                    threads=threads,
+                    first_port=first_port,
+                    addresses=addresses,
+                    process_id=process_id,
+                    run_id=run_id,
+                    program=program,
+                    arguments=arguments,
+                    env_base=env_base,
+                )
+                started_at = time.monotonic()
             elif not handles_state.has_working_process:
                 break
     finally:
@@ -352,6 +389,36 @@ def cli() -> None:
     default=None,
     help="index of the process on the current machine (required with --addresses)",
 )
+@click.option(
+    "--max-restarts",
+    metavar="N",
+    type=int,
+    default=0,
+    help=(
+        "number of times all processes are restarted after one of them fails; "
+        "with persistence enabled, the computation resumes from the last "
+        "persisted state; only the processes spawned on the current machine are "
+        "restarted, so with --addresses the processes on the other machines have "
+        "to be restarted by their own supervisor"
+    ),
+)
+@click.option(
+    "--restart-delay",
+    metavar="SECONDS",
+    type=float,
+    default=DEFAULT_RESTART_DELAY,
+    help="delay before restarting the processes after a failure",
+)
+@click.option(
+    "--restart-reset-after",
+    metavar="SECONDS",
+    type=float,
+    default=DEFAULT_RESTART_RESET_AFTER,
+    help=(
+        "time the processes have to run without failing for the count of "
+        "restarts to be reset"
+    ),
+)
 @click.option("--record", is_flag=True, help="record data in the input connectors")
 @click.option(
     "--record-path",
@@ -377,6 +444,9 @@ def spawn(
     first_port,
     addresses,
     process_id,
+    max_restarts,
+    restart_delay,
+    restart_reset_after,
     record,
     record_path,
     repository_url,
@@ -384,6 +454,12 @@ def spawn(
     program,
     arguments,
 ):
+    if max_restarts < 0:
+        raise click.UsageError("--max-restarts must be non-negative")
+    if restart_delay < 0:
+        raise click.UsageError("--restart-delay must be non-negative")
+    if restart_reset_after < 0:
+        raise click.UsageError("--restart-reset-after must be non-negative")
     processes, process_id = validate_and_resolve_spawn_args(
         threads=threads,
         processes=processes,
@@ -407,6 +483,9 @@ def spawn(
         program=program,
         arguments=arguments,
         env_base=env,
+        max_restarts=max_restarts,
+        restart_delay=restart_delay,
+        restart_reset_after=restart_reset_after,
     )
 
 
@@ -19,11 +19,12 @@ from __future__ import annotations
 
 import csv
 import time
-from datetime import datetime
+from datetime import datetime, timedelta, timezone
 from os import PathLike
 from typing import Any
 
 import pathway as pw
+from pathway.internals import api
 
 
 def generate_custom_stream(
@@ -335,3 +336,58 @@ def replay_csv_with_time(
         autocommit_duration_ms=autocommit_ms,
         format="json",
     ).cast_to_types(**schema.typehints())
+
+
+def freeze_clock(at: datetime | None = None) -> None:
+    """Stops the clock of the engine, so that it only moves when advanced with
+    ``pw.demo.advance_clock``.
+
+    The engine clock sets the times of the data read by the input connectors and
+    triggers their commits every ``autocommit_duration_ms``, so with a frozen clock
+    these are deterministic. The clock is global to the process and stays frozen
+    until ``pw.demo.reset_clock`` is called.
+
+    Args:
+        at: The time at which the clock is stopped. A naive datetime is interpreted
+            as a local time. Defaults to the current time of the clock.
+
+    Example:
+
+    >>> import datetime
+    >>> utc = datetime.timezone.utc
+    >>> pw.demo.freeze_clock(datetime.datetime(2026, 1, 1, tzinfo=utc))
+    >>> pw.demo.advance_clock(datetime.timedelta(seconds=1))
+    >>> pw.demo.reset_clock()
+    """
+    if at is not None:
+        at = at.astimezone(timezone.utc)
+    api.freeze_clock(at=at)
+
+
+def accelerate_clock(speedup: float) -> None:
+    """Makes the clock of the engine run ``speedup`` times faster than the real time,
+    starting from its current time.
+
+    With an accelerated clock, the commits every ``autocommit_duration_ms`` happen
+    ``speedup`` times more often, which allows for replaying long streams quickly.
+
+    Args:
+        speedup: How many times faster than the real time the clock runs.
+    """
+    api.accelerate_clock(speedup=speedup)
+
+
+def advance_clock(by: timedelta) -> None:
+    """Moves the clock of the engine forward by ``by``.
+
+    If the clock wasn't frozen or accelerated before, it keeps running at the real
+    speed, ahead of the real time by ``by``.
+    """
+    if by < timedelta(0):
+        raise ValueError("demo.advance_clock: the clock can't be moved backwards.")
+    api.advance_clock(by=by)
+
+
+def reset_clock() -> None:
+    """Makes the clock of the engine follow the real time again."""
+    api.reset_clock()
@@ -40,6 +40,7 @@ class PathwayType:
     POINTER: PathwayType
     DATE_TIME_NAIVE: PathwayType
     DATE_TIME_UTC: PathwayType
+    DATE_TIME_ZONED: PathwayType
     DURATION: PathwayType
     @staticmethod
     def array(dim: int | None, wrapped: PathwayType) -> PathwayType: ...
@@ -54,6 +55,10 @@ class PathwayType:
     def optional(arg: PathwayType) -> PathwayType: ...
     @staticmethod
     def future(arg: PathwayType) -> PathwayType: ...
+    @staticmethod
+    def map(value_type: PathwayType) -> PathwayType: ...
+    @staticmethod
+    def struct(fields: list[tuple[str, PathwayType]]) -> PathwayType: ...
 
 class ConnectorMode(Enum):
     STATIC: ConnectorMode
@@ -71,6 +76,11 @@ class KeyGenerationPolicy(Enum):
     ALWAYS_AUTOGENERATE: KeyGenerationPolicy
     PREFER_MESSAGE_KEY: KeyGenerationPolicy
 
+class SchemaEvolutionPolicy(Enum):
+    IGNORE_NEW_FIELDS: SchemaEvolutionPolicy
+    FILL_MISSING: SchemaEvolutionPolicy
+    FAIL: SchemaEvolutionPolicy
+
 class SslMode(Enum):
     DISABLE: SslMode
     ALLOW: SslMode
@@ -127,6 +137,19 @@ class ConnectorProperties:
     unique_name: str | None = None
     synchronization_group: ConnectorGroupDescriptor | None = None
     max_backlog_size: int | None = None
+    start_from: list[ConnectorOffset] = []
+    max_rows_per_second: int | None = None
+    max_bytes_per_second: int | None = None
+    watermark_strategy: WatermarkStrategy | None = None
+    backlog_target_latency: datetime.timedelta | None = None
+
+class ConnectorOffset:
+    @staticmethod
+    def kafka(topic: str, partition: int, offset: int) -> ConnectorOffset: ...
+    @staticmethod
+    def mysql_binlog(filename: str, position: int) -> ConnectorOffset: ...
+    @staticmethod
+    def mssql_lsn(lsn: bytes) -> ConnectorOffset: ...
 
 class Column:
     """A Column holds data and conceptually is a Dict[Universe elems, dt]
@@ -371,6 +394,20 @@ class Expression:
     @staticmethod
     def date_time_naive_floor(expr: Expression, duration: Expression) -> Expression: ...
     @staticmethod
+    def date_time_naive_truncate_to_unit(
+        expr: Expression, unit: Expression
+    ) -> Expression: ...
+    @staticmethod
+    def date_time_naive_add_calendar_units(
+        expr: Expression, amount: Expression, unit: Expression
+    ) -> Expression: ...
+    @staticmethod
+    def date_time_naive_quarter(expr: Expression) -> Expression: ...
+    @staticmethod
+    def date_time_naive_day_of_year(expr: Expression) -> Expression: ...
+    @staticmethod
+    def date_time_naive_iso_week(expr: Expression) -> Expression: ...
+    @staticmethod
     def date_time_utc_nanosecond(expr: Expression) -> Expression: ...
     @staticmethod
     def date_time_utc_microsecond(expr: Expression) -> Expression: ...
@@ -407,6 +444,38 @@ class Expression:
     @staticmethod
     def date_time_utc_floor(expr: Expression, duration: Expression) -> Expression: ...
     @staticmethod
+    def date_time_utc_truncate_to_unit(
+        expr: Expression, unit: Expression
+    ) -> Expression: ...
+    @staticmethod
+    def date_time_utc_add_calendar_units(
+        expr: Expression, amount: Expression, unit: Expression
+    ) -> Expression: ...
+    @staticmethod
+    def date_time_utc_quarter(expr: Expression) -> Expression: ...
+    @staticmethod
+    def date_time_utc_day_of_year(expr: Expression) -> Expression: ...
+    @staticmethod
+    def date_time_utc_iso_week(expr: Expression) -> Expression: ...
+    @staticmethod
+    def date_time_utc_to_zoned(expr: Expression, timezone: Expression) -> Expression: ...
+    @staticmethod
+    def date_time_naive_to_zoned(
+        expr: Expression, timezone: Expression
+    ) -> Expression: ...
+    @staticmethod
+    def date_time_zoned_convert_timezone(
+        expr: Expression, timezone: Expression
+    ) -> Expression: ...
+    @staticmethod
+    def date_time_zoned_strftime(expr: Expression, fmt: Expression) -> Expression: ...
+    @staticmethod
+    def date_time_zoned_timezone(expr: Expression) -> Expression: ...
+    @staticmethod
+    def date_time_zoned_to_utc(expr: Expression) -> Expression: ...
+    @staticmethod
+    def date_time_zoned_to_naive(expr: Expression) -> Expression: ...
+    @staticmethod
     def to_duration(expr: Expression, unit: Expression) -> Expression: ...
     @staticmethod
     def duration_nanoseconds(expr: Expression) -> Expression: ...
@@ -425,6 +494,8 @@ class Expression:
     @staticmethod
     def duration_weeks(expr: Expression) -> Expression: ...
     @staticmethod
+    def duration_format(expr: Expression, fmt: Expression) -> Expression: ...
+    @staticmethod
     def parse_int(expr: Expression, optional: bool) -> Expression: ...
     @staticmethod
     def parse_float(expr: Expression, optional: bool) -> Expression: ...
@@ -460,6 +531,68 @@ class Expression:
     def to_string(expr: Expression) -> Expression: ...
     @staticmethod
     def fill_error(expr: Expression, replacement: Expression) -> Expression: ...
+    @staticmethod
+    def map_get_item_checked(
+        expr: Expression, key: Expression, default: Expression
+    ) -> Expression: ...
+    @staticmethod
+    def map_get_item_unchecked(expr: Expression, key: Expression) -> Expression: ...
+    @staticmethod
+    def map_contains(expr: Expression, key: Expression) -> Expression: ...
+    @staticmethod
+    def map_keys(expr: Expression) -> Expression: ...
+    @staticmethod
+    def map_values(expr: Expression) -> Expression: ...
+    @staticmethod
+    def map_items(expr: Expression) -> Expression: ...
+    @staticmethod
+    def json_query(expr: Expression, path: Expression) -> Expression: ...
+    @staticmethod
+    def regex_match(expr: Expression, pattern: Expression) -> Expression: ...
+    @staticmethod
+    def regex_extract(
+        expr: Expression, pattern: Expression, group: Expression
+    ) -> Expression: ...
+    @staticmethod
+    def regex_replace(
+        expr: Expression, pattern: Expression, replacement: Expression
+    ) -> Expression: ...
+    @staticmethod
+    def regex_split(expr: Expression, pattern: Expression) -> Expression: ...
+    @staticmethod
+    def levenshtein(lhs: Expression, rhs: Expression) -> Expression: ...
+    @staticmethod
+    def jaro_winkler(lhs: Expression, rhs: Expression) -> Expression: ...
+    @staticmethod
+    def trigram_similarity(lhs: Expression, rhs: Expression) -> Expression: ...
+    @staticmethod
+    def base64_encode(expr: Expression) -> Expression: ...
+    @staticmethod
+    def base64_decode(expr: Expression) -> Expression: ...
+    @staticmethod
+    def hex_encode(expr: Expression) -> Expression: ...
+    @staticmethod
+    def hex_decode(expr: Expression) -> Expression: ...
+    @staticmethod
+    def url_encode(expr: Expression) -> Expression: ...
+    @staticmethod
+    def url_decode(expr: Expression) -> Expression: ...
+    @staticmethod
+    def digest(lhs: Expression, rhs: Expression) -> Expression: ...
+    @staticmethod
+    def hex_digest(lhs: Expression, rhs: Expression) -> Expression: ...
+    @staticmethod
+    def parse_ip_address(expr: Expression, optional: bool) -> Expression: ...
+    @staticmethod
+    def ip_address_version(expr: Expression) -> Expression: ...
+    @staticmethod
+    def ip_in_network(lhs: Expression, rhs: Expression) -> Expression: ...
+    @staticmethod
+    def ip_network(lhs: Expression, rhs: Expression) -> Expression: ...
+    @staticmethod
+    def url_part(expr: Expression, part: str) -> Expression: ...
+    @staticmethod
+    def url_query_param(lhs: Expression, rhs: Expression) -> Expression: ...
 
 class MonitoringLevel(Enum):
     NONE = 0
@@ -588,7 +721,8 @@ class Scope:
         instance_path: ColumnPath,
         mark_forgetting_records: bool,
         table_properties: TableProperties,
-    ) -> Table: ...
+        late_table_properties: TableProperties | None = None,
+    ) -> tuple[Table, Table | None]: ...
     def forget_immediately(
         self,
         table: Table,
@@ -615,6 +749,15 @@ class Scope:
         current_time_path: ColumnPath,
         instance_path: ColumnPath,
         table_properties: TableProperties,
+        late_table_properties: TableProperties | None = None,
+    ) -> tuple[Table, Table | None]: ...
+    def retain_last_versions(
+        self,
+        table: Table,
+        current_time_path: ColumnPath,
+        instance_path: ColumnPath,
+        max_versions: int,
+        table_properties: TableProperties,
     ) -> Table: ...
     def intersect_tables(
         self, table: Table, tables: Iterable[Table], table_properties: TableProperties
@@ -658,6 +801,8 @@ class Scope:
         reducers: list[ReducerData],
         by_id: bool,
         table_properties: TableProperties,
+        *,
+        shard_column: int | None = None,
     ) -> Table: ...
     def deduplicate(
         self,
@@ -668,6 +813,13 @@ class Scope:
         unique_name: str | None,
         table_properties: TableProperties,
     ) -> Table: ...
+    def version_rows(
+        self,
+        table: Table,
+        column_paths: list[ColumnPath],
+        unique_name: str | None,
+        table_properties: TableProperties,
+    ) -> Table: ...
     def ix_table(
         self,
         to_ix_table: Table,
@@ -691,6 +843,7 @@ class Scope:
         right_ear: bool = False,
         left_exactly_once: bool = False,
         right_exactly_once: bool = False,
+        shard_column: int | None = None,
     ) -> Table: ...
     def use_external_index_as_of_now(
         self,
@@ -747,11 +900,23 @@ class Scope:
         column_paths: Iterable[ColumnPath],
         skip_persisted_batch: bool,
         skip_errors: bool,
-        on_change: Callable,
+        on_change: Callable | None,
         on_time_end: Callable,
         on_end: Callable,
         unique_name: str | None = None,
-        sort_by_indices: Iterable[int] | None = None,
+        sort_by_indices: Iterable[int | SortColumn] | None = None,
+        on_batch: Callable | None = None,
+        max_batch_size: int | None = None,
+    ): ...
+    def subscribe_table_websocket(
+        self,
+        table: Table,
+        column_paths: Iterable[ColumnPath],
+        column_names: list[str],
+        skip_persisted_batch: bool,
+        skip_errors: bool,
+        unique_name: str,
+        sort_by_indices: Iterable[int | SortColumn] | None = None,
     ): ...
     def output_table(
         self,
@@ -760,15 +925,41 @@ class Scope:
         data_sink: DataStorage,
         data_format: DataFormat,
         unique_name: str | None = None,
-        sort_by_indices: Iterable[int] | None = None,
+        sort_by_indices: Iterable[int | SortColumn] | None = None,
+        alignment_group: SinkAlignmentGroupDescriptor | None = None,
+        metadata_columns: Iterable[SinkMetadataColumn] = (),
+        parallel_writers: int = 1,
+        partition_by_indices: Iterable[int] | None = None,
+        max_batch_size: int | None = None,
+        linger_ms: int | None = None,
+        delivery_semantics: DeliverySemantics | None = None,
+        output_mode: OutputMode | None = None,
+        commit_group: str | None = None,
+        queue_capacity: int | None = None,
+        collapse_updates: bool = False,
     ): ...
     def export_table(
-        self, table: Table, column_paths: Iterable[ColumnPath]
+        self,
+        table: Table,
+        column_paths: Iterable[ColumnPath],
+        flight_endpoint: FlightEndpoint | None = None,
+    ) -> ExportedTable: ...
+    def serve_table(
+        self, table: ExportedTable, route: str, column_names: list[str]
+    ) -> None: ...
+    def export_to_shared_memory(
+        self,
+        table: ExportedTable,
+        directory: str,
+        capacity: int,
+        column_names: list[str],
     ) -> ExportedTable: ...
     def import_table(self, table: ExportedTable) -> Table: ...
     def error_log(self, properties: ConnectorProperties) -> tuple[Table, ErrorLog]: ...
     def set_error_log(self, error_log: ErrorLog | None) -> None: ...
-    def set_operator_properties(self, id: int, depends_on_error_log: bool) -> None: ...
+    def set_operator_properties(
+        self, id: int, depends_on_error_log: bool, stable_id: str | None = None
+    ) -> None: ...
     def remove_value_from_table(
         self,
         table: Table,
@@ -816,6 +1007,16 @@ class Scope:
         column_paths: Iterable[ColumnPath],
         table_properties: TableProperties,
     ) -> Table: ...
+    def assert_monotonic(
+        self,
+        table: Table,
+        column_paths: Iterable[ColumnPath],
+        sequence_path: ColumnPath,
+        instance_path: ColumnPath | None,
+        strict: bool,
+        on_violation: str,
+        table_properties: TableProperties,
+    ) -> Table: ...
     def unpack_snapshots(
         self,
         table: Table,
@@ -845,6 +1046,12 @@ class ExportedTable:
     def frontier(self) -> Frontier: ...
     def snapshot_at(self, frontier: Frontier) -> list[tuple[Pointer, list[Value]]]: ...
 
+class DryRunReport:
+    def __init__(self, *, check_connectors: bool = True) -> None: ...
+    @property
+    def ok(self) -> bool: ...
+    def to_json(self) -> str: ...
+
 def run_with_new_graph(
     logic: Callable[[Scope], Iterable[tuple[Table, list[ColumnPath]]]],
     event_loop: asyncio.AbstractEventLoop,
@@ -858,7 +1065,10 @@ def run_with_new_graph(
     license_key: str | None = None,
     terminate_on_error: bool = True,
     max_expression_batch_size: int,
+    deterministic_expression_cache_size: int = 0,
     udf_cache_directory: str | None = None,
+    step_mode: str | None = None,
+    dry_run_report: DryRunReport | None = None,
 ) -> list[CapturedStream]: ...
 def unsafe_make_pointer(arg) -> Pointer: ...
 
@@ -900,11 +1110,123 @@ class DeltaOptimizerRule:
 class MqttSettings:
     def __init__(self, qos: int, retain: bool): ...
 
+class SqsSettings:
+    def __init__(
+        self,
+        wait_time_s: int,
+        visibility_timeout_s: int,
+        shard_by_message_group: bool,
+    ): ...
+
+class RedisStreamSettings:
+    def __init__(
+        self,
+        consumer_group: str,
+        consumer_name: str,
+        data_field: str,
+    ): ...
+
+class RedisKeyValueSettings:
+    def __init__(
+        self,
+        key_prefix: str,
+        use_hashes: bool,
+        ttl_ms: int | None = None,
+    ): ...
+
+class SqlCursorSettings:
+    def __init__(
+        self,
+        cursor_column: str,
+        id_column: str,
+        query: str | None = None,
+        max_cursor_lag: int = 0,
+        read_batch_size: int = 10_000,
+        poll_interval_ms: int = 1000,
+    ): ...
+
+class SseSettings:
+    def __init__(
+        self,
+        headers: list[tuple[str, str]] = [],
+        event_types: list[str] | None = None,
+        reconnection_time_ms: int = 3000,
+    ): ...
+
+class GrpcSettings:
+    def __init__(
+        self,
+        method: str,
+        request: str = "{}",
+        descriptor_set_path: str | None = None,
+        metadata: list[tuple[str, str]] = [],
+        offset_field: str | None = None,
+        resume_field: str | None = None,
+    ): ...
+
+class SftpSettings:
+    def __init__(
+        self,
+        host: str,
+        user: str,
+        port: int = 22,
+        password: str | None = None,
+        private_key_path: str | None = None,
+        private_key_passphrase: str | None = None,
+        known_hosts_path: str | None = None,
+    ): ...
+
+class HdfsSettings:
+    def __init__(
+        self,
+        url: str,
+        user: str | None = None,
+        delegation_token: str | None = None,
+    ): ...
+
+class WebhookSettings:
+    def __init__(
+        self,
+        url: str,
+        method: str = "POST",
+        headers: dict[str, str] | None = None,
+        content_type: str | None = None,
+        batch_format: str | None = None,
+        max_batch_size: int | None = None,
+        n_retries: int = 0,
+        first_retry_delay_ms: int = 1000,
+        retry_backoff_factor: float = 1.5,
+        retry_jitter_ms: int = 300,
+        retry_codes: list[int] | None = None,
+        max_concurrent_requests: int = 1,
+        connect_timeout_ms: int | None = None,
+        request_timeout_ms: int | None = None,
+    ): ...
+
+class LanceSettings:
+    def __init__(self, vector_columns: dict[str, int] | None = None): ...
+
+class CassandraSettings:
+    def __init__(
+        self,
+        hosts: list[str],
+        username: str | None = None,
+        password: str | None = None,
+    ): ...
+
 class TableWriterInitMode(Enum):
     DEFAULT: TableWriterInitMode
     CREATE_IF_NOT_EXISTS: TableWriterInitMode
     REPLACE: TableWriterInitMode
 
+class DeliverySemantics(Enum):
+    AT_LEAST_ONCE: DeliverySemantics
+    EXACTLY_ONCE: DeliverySemantics
+
+class OutputMode(Enum):
+    STREAMING: OutputMode
+    WATERMARK_ATOMIC: OutputMode
+
 class PsqlReplicationSettings:
     def __init__(
         self,
@@ -953,6 +1275,17 @@ class DataStorage:
         azure_blob_storage_settings: AzureBlobStorageSettings | None = None,
         delta_optimizer_rule: DeltaOptimizerRule | None = None,
         mqtt_settings: MqttSettings | None = None,
+        sqs_settings: SqsSettings | None = None,
+        redis_stream_settings: RedisStreamSettings | None = None,
+        redis_key_value_settings: RedisKeyValueSettings | None = None,
+        cassandra_settings: CassandraSettings | None = None,
+        sql_cursor_settings: SqlCursorSettings | None = None,
+        sse_settings: SseSettings | None = None,
+        grpc_settings: GrpcSettings | None = None,
+        sftp_settings: SftpSettings | None = None,
+        hdfs_settings: HdfsSettings | None = None,
+        webhook_settings: WebhookSettings | None = None,
+        lance_settings: LanceSettings | None = None,
         only_provide_metadata: bool = False,
         sort_key_index: int | None = None,
         legacy_mode: bool = False,
@@ -967,6 +1300,9 @@ class DataStorage:
         qdrant_params: QdrantParams | None = None,
         pinecone_params: PineconeParams | None = None,
         detach_between_batches: bool = False,
+        partition_aligned: bool = False,
+        start_position: str | None = None,
+        partition_start_positions: dict[int, str] | None = None,
     ) -> None: ...
     def delta_s3_storage_options(self, *args, **kwargs): ...
 
@@ -983,6 +1319,22 @@ class IcebergCatalogSettings:
 class CsvParserSettings:
     def __init__(self, *args, **kwargs): ...
 
+class CsvFormatterSettings:
+    def __init__(
+        self,
+        quoting: str = "always",
+        escape: str | None = None,
+        write_header: bool = True,
+        null_value: str | None = None,
+    ): ...
+
+class JsonFormatterSettings:
+    def __init__(
+        self,
+        field_paths: dict[str, str] = {},
+        datetime_format: str = "default",
+    ): ...
+
 class AwsS3Settings:
     def __init__(self, *args, **kwargs): ...
 
@@ -992,6 +1344,7 @@ class AzureBlobStorageSettings:
 class FieldSource(Enum):
     KEY: FieldSource
     PAYLOAD: FieldSource
+    HEADERS: FieldSource
 
 class ValueField:
     name: str
@@ -1045,6 +1398,34 @@ class PersistenceConfig:
 class ConnectorGroupDescriptor:
     def __init__(self, *args, **kwargs): ...
 
+class WatermarkStrategy:
+    def __init__(
+        self,
+        time_column: int | None = None,
+        max_out_of_orderness: int = 0,
+        idle_timeout: datetime.timedelta | None = None,
+    ): ...
+    @property
+    def time_column(self) -> int | None: ...
+    @property
+    def max_out_of_orderness(self) -> int: ...
+    @property
+    def idle_timeout(self) -> datetime.timedelta | None: ...
+
+class SinkAlignmentGroupDescriptor:
+    def __init__(self, name: str, max_skew_ms: int): ...
+
+class SinkMetadataColumn:
+    def __init__(self, name: str, field: str): ...
+
+class SortColumn:
+    def __init__(
+        self, index: int, descending: bool = False, nulls_first: bool | None = None
+    ): ...
+
+class FlightEndpoint:
+    def __init__(self, address: str, name: str, column_names: list[str]): ...
+
 class PersistenceMode(Enum):
     BATCH: PersistenceMode
     SPEEDRUN_REPLAY: PersistenceMode
@@ -1093,6 +1474,7 @@ class TelemetryConfig:
     service_namespace: str | None
     service_instance_id: str | None
     run_id: str
+    graph_version: str | None
     license_key: str | None
     metrics_reader_interval_secs: int | None
     trace_parent: str | None
@@ -1102,6 +1484,7 @@ class TelemetryConfig:
     def create(
         *,
         run_id: str,
+        graph_version: str | None = None,
         license_key: str | None = None,
         monitoring_server: str | None = None,
         detailed_metrics_dir: str | None = None,
@@ -1176,6 +1559,17 @@ def check_entitlements(
     license_key: str | None,
     entitlements: list[str],
 ): ...
+def request_savepoint(*, timeout: float) -> int: ...
+def control_connector(unique_name: str, *, command: str) -> str: ...
+def request_graceful_shutdown() -> None: ...
+def request_suspend() -> None: ...
+def increment_counter(name: str, value: int = 1) -> None: ...
+def record_histogram(name: str, value: float) -> None: ...
+def migrate_persistence_metadata(*, backend: DataStorage) -> int: ...
+def freeze_clock(*, at: datetime.datetime | None = None) -> None: ...
+def accelerate_clock(*, speedup: float) -> None: ...
+def advance_clock(*, by: datetime.timedelta) -> None: ...
+def reset_clock() -> None: ...
 def deserialize(data: bytes) -> Value: ...
 def serialize(value: Value) -> bytes: ...
 
@@ -27,7 +27,12 @@ from pathway.internals.common import (
 )
 from pathway.internals.config import set_license_key, set_monitoring_config
 from pathway.internals.custom_reducers import BaseCustomAccumulator
-from pathway.internals.datetime_types import DateTimeNaive, DateTimeUtc, Duration
+from pathway.internals.datetime_types import (
+    DateTimeNaive,
+    DateTimeUtc,
+    DateTimeZoned,
+    Duration,
+)
 from pathway.internals.decorators import (
     attribute,
     input_attribute,
@@ -58,7 +63,7 @@ from pathway.internals.json import Json
 from pathway.internals.monitoring import MonitoringLevel
 from pathway.internals.operator import iterate_universe
 from pathway.internals.row_transformer import ClassArg
-from pathway.internals.run import run, run_all
+from pathway.internals.run import explain, request_shutdown, run, run_all
 from pathway.internals.schema import (
     ColumnDefinition,
     Schema,
@@ -124,6 +129,8 @@ __all__ = [
     "sql",
     "run",
     "run_all",
+    "explain",
+    "request_shutdown",
     "__version__",
     "universes",
     "udfs",
@@ -139,6 +146,7 @@ __all__ = [
     "assert_table_has_schema",
     "DateTimeNaive",
     "DateTimeUtc",
+    "DateTimeZoned",
     "Duration",
     "Json",
     "table_transformer",
@@ -30,6 +30,7 @@ def groupby_handler(
     sort_by=None,
     _filter_out_results_of_forgetting=False,
     instance=None,
+    shard_by=None,
     _skip_errors=True,
     _is_window=False,
     **kwargs,
@@ -44,6 +45,7 @@ def groupby_handler(
         "sort_by": sort_by,
         "_filter_out_results_of_forgetting": _filter_out_results_of_forgetting,
         "instance": instance,
+        "shard_by": shard_by,
         "_skip_errors": _skip_errors,
         "_is_window": _is_window,
     }
@@ -167,6 +169,9 @@ def join_kwargs_handler(*, allow_how: bool, allow_id: bool):
         if "right_exactly_once" in kwargs:
             processed_kwargs["right_exactly_once"] = kwargs.pop("right_exactly_once")
 
+        if "shard_by" in kwargs:
+            processed_kwargs["shard_by"] = kwargs.pop("shard_by")
+
         if kwargs:
             raise ValueError(
                 "Join received extra kwargs.\n"
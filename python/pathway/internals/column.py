@@ -442,6 +442,35 @@ class GradualBroadcastContext(Context):
         return self.orig_id_column.universe
 
 
+@dataclass(eq=False, frozen=True)
+class VersionRowsContext(Context):
+    """Context of `table.with_row_versions() operation."""
+
+    orig_id_column: IdColumn
+    columns: tuple[Column, ...]
+    unique_name: str | None
+
+    def column_dependencies_internal(self) -> Iterable[Column]:
+        return self.columns
+
+    def column_dependencies_external(self) -> Iterable[Column]:
+        return [self.orig_id_column]
+
+    def input_universe(self) -> Universe:
+        return self.orig_id_column.universe
+
+    def id_column_type(self) -> dt.DType:
+        return self.orig_id_column.dtype
+
+    @cached_property
+    def version_column(self):
+        return MaterializedColumn(self.universe, cp.ColumnProperties(dtype=dt.INT))
+
+    @property
+    def universe(self) -> Universe:
+        return self.orig_id_column.universe
+
+
 @dataclass(eq=False, frozen=True)
 class ExternalIndexAsOfNowContext(Context):
     _index_id_column: IdColumn
@@ -535,6 +564,7 @@ class GroupedContext(Context):
     """Original context of grouped table."""
     skip_errors: bool
     sort_by: InternalColRef | None = None
+    shard_column: int | None = None
 
     def _get_type_interpreter(self):
         from pathway.internals.type_interpreter import ReducerInterprerer
@@ -648,6 +678,7 @@ class ForgetContext(TimeColumnContext):
     """Context of `table._forget() operation."""
 
     mark_forgetting_records: bool
+    route_late: bool = False
 
 
 @dataclass(eq=False, frozen=True)
@@ -704,6 +735,60 @@ class BufferContext(
 ):
     """Context of `table._buffer() operation."""
 
+    route_late: bool = False
+
+
+@dataclass(eq=False, frozen=True)
+class RetainLastVersionsContext(
+    Context, column_properties_evaluator=cp.PreserveDependenciesPropsEvaluator
+):
+    """Context of `table._retain_last_versions() operation."""
+
+    orig_id_column: IdColumn
+    time_column: ColumnWithExpression
+    instance_column: ColumnWithExpression
+    max_versions: int
+
+    def column_dependencies_internal(self) -> Iterable[Column]:
+        return [self.time_column, self.instance_column]
+
+    def column_dependencies_external(self) -> Iterable[Column]:
+        return [self.orig_id_column]
+
+    def input_universe(self) -> Universe:
+        return self.orig_id_column.universe
+
+    def id_column_type(self) -> dt.DType:
+        return self.orig_id_column.dtype
+
+    @cached_property
+    def universe(self) -> Universe:
+        return self.orig_id_column.universe.subset()
+
+
+@dataclass(eq=False, frozen=True)
+class LateEntriesContext(
+    Context, column_properties_evaluator=cp.PreserveDependenciesPropsEvaluator
+):
+    """Context of the late entries of `table._forget_with_late()` and
+    `table._buffer_with_late()` operations."""
+
+    orig_id_column: IdColumn
+    routing_context: ForgetContext | BufferContext
+
+    def column_dependencies_external(self) -> Iterable[Column]:
+        return [self.orig_id_column]
+
+    def input_universe(self) -> Universe:
+        return self.orig_id_column.universe
+
+    def id_column_type(self) -> dt.DType:
+        return self.orig_id_column.dtype
+
+    @cached_property
+    def universe(self) -> Universe:
+        return self.orig_id_column.universe.subset()
+
 
 @dataclass(eq=False, frozen=True)
 class ReindexContext(
@@ -976,6 +1061,7 @@ class JoinContext(Context, column_properties_evaluator=cp.JoinPropsEvaluator):
     exact_match: bool
     left_exactly_once: bool
     right_exactly_once: bool
+    shard_column: int | None = None
 
     def column_dependencies_external(self) -> Iterable[Column]:
         return (self.left_table._id_column, self.right_table._id_column)
@@ -1394,6 +1480,39 @@ class AssertAppendOnlyContext(
         return ret
 
 
+@dataclass(eq=False, frozen=True)
+class AssertMonotonicContext(
+    Context, column_properties_evaluator=cp.PreserveDependenciesPropsEvaluator
+):
+    """Context of `table.assert_monotonic() operation."""
+
+    sequence: ColumnWithExpression
+    instance: ColumnWithExpression | None
+    orig_id_column: IdColumn
+    strict: bool
+    on_violation: str
+
+    def column_dependencies_internal(self) -> Iterable[Column]:
+        if self.instance is None:
+            return [self.sequence]
+        return [self.sequence, self.instance]
+
+    def column_dependencies_external(self) -> Iterable[Column]:
+        return [self.orig_id_column]
+
+    def input_universe(self) -> Universe:
+        return self.orig_id_column.universe
+
+    def id_column_type(self) -> dt.DType:
+        return self.orig_id_column.dtype
+
+    @cached_property
+    def universe(self) -> Universe:
+        ret = Universe()
+        G.universe_solver.register_as_equal(self.orig_id_column.universe, ret)
+        return ret
+
+
 @dataclass(eq=False, frozen=True)
 class UnpackSnapshotsContext(Context):
     """Context of `table.unpack_snapshots() operation."""
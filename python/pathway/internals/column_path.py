@@ -10,6 +10,7 @@ from typing import ClassVar
 class ColumnPath:
     path: tuple[int, ...]
     is_key: bool = False
+    fields: tuple[str, ...] = ()
 
     EMPTY: ClassVar[ColumnPath]
     KEY: ClassVar[ColumnPath]
@@ -23,6 +24,9 @@ class ColumnPath:
     def __len__(self) -> int:
         return len(self.path)
 
+    def field(self, name: str) -> ColumnPath:
+        return ColumnPath(self.path, fields=self.fields + (name,))
+
 
 ColumnPath.EMPTY = ColumnPath(())
 ColumnPath.KEY = ColumnPath(path=(), is_key=True)
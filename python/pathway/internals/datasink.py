@@ -2,9 +2,11 @@
 
 from __future__ import annotations
 
+import dataclasses
 from abc import ABC
 from collections.abc import Callable
-from dataclasses import dataclass
+from contextvars import ContextVar
+from dataclasses import dataclass, field
 from typing import TYPE_CHECKING, Awaitable, Iterable
 
 from pathway.internals import api
@@ -13,6 +15,52 @@ from pathway.internals.expression import ColumnReference
 if TYPE_CHECKING:
     from pathway.internals.table import Table
 
+# The metadata columns of the output connectors defined within
+# `pw.io.output_metadata_columns`
+_output_metadata_columns: ContextVar[list[api.SinkMetadataColumn]] = ContextVar(
+    "output_metadata_columns", default=[]
+)
+
+
+@dataclass(frozen=True)
+class SortColumn:
+    """
+    Defines how the output is sorted by a column given in ``sort_by``.
+
+    Arguments:
+        column: Reference to the column the output is sorted by.
+        descending: Whether the output is sorted in descending order. Defaults to
+            ``False``.
+        nulls_first: Whether the ``None`` values go before all the other values. If not
+            specified, they are treated as the smallest values, so they go first in
+            ascending order and last in descending order.
+    """
+
+    column: ColumnReference
+    descending: bool = False
+    nulls_first: bool | None = None
+
+
+def _sort_by_indices(
+    sort_by: Iterable[ColumnReference | SortColumn] | None,
+    column_index: dict[str, int],
+) -> list[int | api.SortColumn] | None:
+    if sort_by is None:
+        return None
+    indices: list[int | api.SortColumn] = []
+    for column in sort_by:
+        if isinstance(column, SortColumn):
+            indices.append(
+                api.SortColumn(
+                    column_index[column.column.name],
+                    descending=column.descending,
+                    nulls_first=column.nulls_first,
+                )
+            )
+        else:
+            indices.append(column_index[column.name])
+    return indices
+
 
 class DataSink(ABC):
     @property
@@ -20,14 +68,18 @@ class DataSink(ABC):
         return type(self).__qualname__.lower().removesuffix("datasink")
 
     def check_sort_by_columns(self, table: Table):
-        sort_by = getattr(self, "sort_by", None)
-        if sort_by is None:
-            return
-        for column in sort_by:
-            if column._table != table:
-                raise ValueError(
-                    f"The column {column} doesn't belong to the target table {table}"
-                )
+        for attribute in ("sort_by", "partition_by"):
+            columns = getattr(self, attribute, None)
+            if columns is None:
+                continue
+            for column in columns:
+                if isinstance(column, SortColumn):
+                    column = column.column
+                if column._table != table:
+                    raise ValueError(
+                        f"The column {column} doesn't belong to the target "
+                        f"table {table}"
+                    )
 
 
 @dataclass(frozen=True)
@@ -36,44 +88,93 @@ class GenericDataSink(DataSink):
     dataformat: api.DataFormat
     datasink_name: str
     unique_name: str | None
-    sort_by: Iterable[ColumnReference] | None = None
+    sort_by: Iterable[ColumnReference | SortColumn] | None = None
     on_pipeline_finished: Callable | None = None
+    alignment_group: api.SinkAlignmentGroupDescriptor | None = None
+    metadata_columns: list[api.SinkMetadataColumn] = field(default_factory=list)
+    parallel_writers: int = 1
+    partition_by: Iterable[ColumnReference] | None = None
+    max_batch_size: int | None = None
+    linger_ms: int | None = None
+    delivery_semantics: api.DeliverySemantics | None = None
+    output_mode: api.OutputMode | None = None
+    commit_group: str | None = None
+    queue_capacity: int | None = None
+    collapse_updates: bool = False
 
     @property
     def name(self) -> str:
         return self.datasink_name
 
-    @property
-    def sort_by_indices(self):
-        if self.sort_by is None:
-            return None
+    def with_requested_metadata_columns(self, table: Table) -> GenericDataSink:
+        metadata_columns = _output_metadata_columns.get()
+        if not metadata_columns:
+            return self
+        for column in metadata_columns:
+            if column.name in table.column_names():
+                raise ValueError(
+                    f"The metadata column {column.name!r} clashes with a column "
+                    "of the table"
+                )
+        return dataclasses.replace(self, metadata_columns=metadata_columns)
+
+    def _value_field_index(self) -> dict[str, int]:
         column_index: dict[str, int] = {}
         for index, column in enumerate(self.dataformat.value_fields):
             column_index[column.name] = index
-        return [column_index[column.name] for column in self.sort_by]
+        return column_index
+
+    @property
+    def sort_by_indices(self):
+        return _sort_by_indices(self.sort_by, self._value_field_index())
+
+    @property
+    def partition_by_indices(self):
+        if self.partition_by is None:
+            return None
+        column_index = self._value_field_index()
+        return [column_index[column.name] for column in self.partition_by]
 
 
 @dataclass(frozen=True, kw_only=True)
 class CallbackDataSink(DataSink):
-    on_change: Callable[
-        [api.Pointer, list[api.Value], int, int], None | Awaitable[None]
-    ]
+    on_change: (
+        Callable[[api.Pointer, list[api.Value], int, int], None | Awaitable[None]]
+        | None
+    )
     on_time_end: Callable[[int], None]
     on_end: Callable[[], None]
     skip_persisted_batch: bool
     skip_errors: bool
     unique_name: str | None
-    sort_by: Iterable[ColumnReference] | None = None
+    sort_by: Iterable[ColumnReference | SortColumn] | None = None
+    on_batch: (
+        Callable[[list[tuple[api.Pointer, list[api.Value], int]], int], None] | None
+    ) = None
+    max_batch_size: int | None = None
+
+    def sort_by_indices(self, table: Table):
+        column_index: dict[str, int] = {}
+        for index, column in enumerate(table._columns):
+            column_index[column] = index
+        return _sort_by_indices(self.sort_by, column_index)
+
+
+@dataclass(frozen=True, kw_only=True)
+class WebSocketDataSink(DataSink):
+    skip_persisted_batch: bool
+    skip_errors: bool
+    unique_name: str
+    sort_by: Iterable[ColumnReference | SortColumn] | None = None
 
     def sort_by_indices(self, table: Table):
-        if self.sort_by is None:
-            return None
         column_index: dict[str, int] = {}
         for index, column in enumerate(table._columns):
             column_index[column] = index
-        return [column_index[column.name] for column in self.sort_by]
+        return _sort_by_indices(self.sort_by, column_index)
 
 
 @dataclass(frozen=True)
 class ExportDataSink(DataSink):
     callback: Callable[[api.Scope, api.ExportedTable], None]
+    flight_endpoint: api.FlightEndpoint | None = None
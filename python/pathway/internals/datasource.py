@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import datetime
 from abc import ABC, abstractmethod
 from collections.abc import Callable
 from dataclasses import dataclass
@@ -20,6 +21,11 @@ class DataSourceOptions:
     unique_name: str | None = None
     synchronization_group: api.ConnectorGroupDescriptor | None = None
     max_backlog_size: int | None = None
+    start_from: list[api.ConnectorOffset] | None = None
+    max_rows_per_second: int | None = None
+    max_bytes_per_second: int | None = None
+    watermark_strategy: api.WatermarkStrategy | None = None
+    backlog_target_latency: datetime.timedelta | None = None
 
     def set_synchronization_group(self, group: api.ConnectorGroupDescriptor | None):
         if self.synchronization_group is None:
@@ -27,6 +33,18 @@ class DataSourceOptions:
         else:
             raise ValueError("synchronization_group can only be set once")
 
+    def set_watermark_strategy(self, strategy: api.WatermarkStrategy):
+        if self.watermark_strategy is None:
+            object.__setattr__(self, "watermark_strategy", strategy)
+        else:
+            raise ValueError("watermark_strategy can only be set once")
+
+    def set_backlog_target_latency(self, target_latency: datetime.timedelta):
+        if self.backlog_target_latency is None:
+            object.__setattr__(self, "backlog_target_latency", target_latency)
+        else:
+            raise ValueError("backlog_target_latency can only be set once")
+
 
 @dataclass(frozen=True, kw_only=True)
 class DataSource(ABC):
@@ -51,6 +69,11 @@ class DataSource(ABC):
             unique_name=self.data_source_options.unique_name,
             synchronization_group=self.data_source_options.synchronization_group,
             max_backlog_size=self.data_source_options.max_backlog_size,
+            start_from=self.data_source_options.start_from or [],
+            max_rows_per_second=self.data_source_options.max_rows_per_second,
+            max_bytes_per_second=self.data_source_options.max_bytes_per_second,
+            watermark_strategy=self.data_source_options.watermark_strategy,
+            backlog_target_latency=self.data_source_options.backlog_target_latency,
         )
 
     def get_effective_schema(self) -> type[Schema]:
@@ -27,6 +27,19 @@ class DateTimeUtc(pd.Timestamp):
         return obj
 
 
+class DateTimeZoned(pd.Timestamp):
+    """Type for storing datetime together with its time zone, e.g. ``Europe/Warsaw``.
+    Unlike in `DateTimeUtc`, the time zone is kept. Extends `pandas.Timestamp` type."""
+
+    def __new__(cls, *args, **kwargs):
+        obj = super().__new__(cls, *args, **kwargs)
+        if obj.tz is None:
+            raise ValueError(
+                "DateTimeZoned must contain timezone information. Use pw.DateTimeNaive for naive datetimes."
+            )
+        return obj
+
+
 class Duration(pd.Timedelta):
     """Type for storing duration of time. Extends `pandas.Timedelta` type."""
 
@@ -493,6 +493,40 @@ class List(DType):
         return math.inf
 
 
+class Map(DType):
+    """A dictionary with string keys and values of a single type."""
+
+    wrapped: DType
+
+    def __repr__(self):
+        return f"Map({self.wrapped})"
+
+    def to_dict(self) -> dict:
+        return {"type": "MAP", "wrapped": self.wrapped.to_dict()}
+
+    def __new__(cls, wrapped: DType) -> Map:
+        return super().__new__(cls, wrap(wrapped))
+
+    def _set_args(self, wrapped):
+        self.wrapped = wrapped
+
+    def to_engine(self) -> PathwayType:
+        return api.PathwayType.map(self.wrapped.to_engine())
+
+    def is_value_compatible(self, arg):
+        return isinstance(arg, dict) and all(
+            isinstance(key, str) and self.wrapped.is_value_compatible(val)
+            for key, val in arg.items()
+        )
+
+    @cached_property
+    def typehint(self) -> type[dict]:
+        return dict[str, self.wrapped.typehint]  # type: ignore[name-defined]
+
+    def max_size(self) -> float:
+        return math.inf
+
+
 class Future(DType):
     wrapped: DType
 
@@ -579,6 +613,33 @@ class _DateTimeUtc(DType):
 DATE_TIME_UTC = _DateTimeUtc()
 
 
+class _DateTimeZoned(DType):
+    def __repr__(self):
+        return "DATE_TIME_ZONED"
+
+    def _set_args(self):
+        pass
+
+    def __new__(cls) -> _DateTimeZoned:
+        return super().__new__(cls)
+
+    def to_engine(self) -> api.PathwayType:
+        return api.PathwayType.DATE_TIME_ZONED
+
+    def is_value_compatible(self, arg):
+        return isinstance(arg, datetime.datetime) and arg.tzinfo is not None
+
+    @property
+    def typehint(self) -> type[datetime_types.DateTimeZoned]:
+        return datetime_types.DateTimeZoned
+
+    def max_size(self) -> float:
+        return 1
+
+
+DATE_TIME_ZONED = _DateTimeZoned()
+
+
 class _Duration(DType):
     def __repr__(self):
         return "DURATION"
@@ -646,6 +707,7 @@ def wrap(input_type) -> DType:
     assert input_type != Callable
     assert input_type != Array
     assert input_type != List
+    assert input_type != Map
     assert input_type != Json
     assert input_type != PyObjectWrapper
     assert input_type != ...
@@ -742,6 +804,7 @@ def wrap(input_type) -> DType:
             datetime_types.Duration: DURATION,
             datetime_types.DateTimeNaive: DATE_TIME_NAIVE,
             datetime_types.DateTimeUtc: DATE_TIME_UTC,
+            datetime_types.DateTimeZoned: DATE_TIME_ZONED,
             np.int32: INT,
             np.int64: INT,
             np.float32: FLOAT,
@@ -864,6 +927,10 @@ def dtype_issubclass(
         )
     elif isinstance(left, Array) and isinstance(right, Array):
         return dtype_array_equivalence(left, right)
+    elif isinstance(left, Map) and isinstance(right, Map):
+        return dtype_issubclass(
+            left.wrapped, right.wrapped, int_float_compatible=int_float_compatible
+        )
     elif isinstance(left, Pointer) and isinstance(right, Pointer):
         return dtype_pointer_issubclass(left, right)
     elif isinstance(left, _SimpleDType) and isinstance(right, _SimpleDType):
@@ -1038,6 +1105,7 @@ _HASHABLE_DTYPES = {
     NONE,
     DATE_TIME_NAIVE,
     DATE_TIME_UTC,
+    DATE_TIME_ZONED,
     DURATION,
 }
 
@@ -1057,6 +1125,7 @@ def is_hashable_in_python(dtype: DType) -> bool:
 COMPOSITE_DTYPES = {
     "ARRAY": lambda d: Array(d["n_dim"], parse_dtype_from_dict(d["wrapped"])),
     "LIST": lambda d: List(parse_dtype_from_dict(d["wrapped"])),
+    "MAP": lambda d: Map(parse_dtype_from_dict(d["wrapped"])),
     "OPTIONAL": lambda d: Optional(parse_dtype_from_dict(d["wrapped"])),
     "TUPLE": lambda d: Tuple(*(parse_dtype_from_dict(w) for w in d["wrapped"])),
     "FUTURE": lambda d: Future(parse_dtype_from_dict(d["wrapped"])),
@@ -1069,6 +1138,7 @@ NON_COMPOSITE_DTYPES = {
     "BYTES": BYTES,
     "DATE_TIME_NAIVE": DATE_TIME_NAIVE,
     "DATE_TIME_UTC": DATE_TIME_UTC,
+    "DATE_TIME_ZONED": DATE_TIME_ZONED,
     "DURATION": DURATION,
     "Json": JSON,
     "NONE": NONE,
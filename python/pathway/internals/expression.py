@@ -18,6 +18,7 @@ if TYPE_CHECKING:
     from pathway.internals.column import Column, ColumnWithExpression
     from pathway.internals.expressions import (
         DateTimeNamespace,
+        MapNamespace,
         NumericalNamespace,
         StringNamespace,
     )
@@ -398,12 +399,67 @@ class ColumnExpression(OperatorInput, ABC):
         """
         return GetExpression(self, index, default, check_if_exists=True)
 
+    def json_query(self, path: ColumnExpression | str) -> MethodCallExpression:
+        """Evaluates a JSONPath query on a Json value and returns the list of the
+        matched values. The query syntax follows RFC 9535, including wildcards
+        (``$.items[*]``), recursive descent (``$..price``), and filters
+        (``$.items[?@.price > 10]``).
+
+        The query is evaluated in the engine and compiled once per distinct path, so it
+        is much faster than an equivalent Python UDF. For a ``None`` value the result
+        is ``None``.
+
+        Args:
+            path: The JSONPath query.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> import pandas as pd
+        >>> class InputSchema(pw.Schema):
+        ...     data: dict
+        >>> df = pd.DataFrame(
+        ...     data={"data": [{"items": [{"price": 5}, {"price": 12}]}, {"items": []}]}
+        ... )
+        >>> table = pw.debug.table_from_pandas(df, schema=InputSchema)
+        >>> result = table.select(
+        ...     prices=pw.this.data.json_query("$.items[?@.price > 10].price")
+        ... )
+        >>> pw.debug.compute_and_print(result, include_id=False)
+        prices
+        ()
+        (pw.Json(12),)
+        """
+        return MethodCallExpression(
+            (
+                (
+                    (dt.JSON, dt.STR),
+                    dt.List(dt.JSON),
+                    api.Expression.json_query,
+                ),
+                (
+                    (dt.Optional(dt.JSON), dt.STR),
+                    dt.Optional(dt.List(dt.JSON)),
+                    api.Expression.json_query,
+                ),
+            ),
+            "json_query",
+            self,
+            path,
+        )
+
     @property
     def dt(self) -> DateTimeNamespace:
         from pathway.internals.expressions import DateTimeNamespace
 
         return DateTimeNamespace(self)
 
+    @property
+    def map(self) -> MapNamespace:
+        from pathway.internals.expressions import MapNamespace
+
+        return MapNamespace(self)
+
     @property
     def num(self) -> NumericalNamespace:
         from pathway.internals.expressions import NumericalNamespace
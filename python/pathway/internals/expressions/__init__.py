@@ -1,7 +1,8 @@
 # Copyright © 2026 Pathway
 
 from pathway.internals.expressions.date_time import DateTimeNamespace
+from pathway.internals.expressions.map import MapNamespace
 from pathway.internals.expressions.numerical import NumericalNamespace
 from pathway.internals.expressions.string import StringNamespace
 
-__all__ = ["DateTimeNamespace", "NumericalNamespace", "StringNamespace"]
+__all__ = ["DateTimeNamespace", "MapNamespace", "NumericalNamespace", "StringNamespace"]
@@ -546,6 +546,11 @@ class DateTimeNamespace:
                     dt.STR,
                     api.Expression.date_time_utc_strftime,
                 ),
+                (
+                    (dt.DATE_TIME_ZONED, dt.STR),
+                    dt.STR,
+                    api.Expression.date_time_zoned_strftime,
+                ),
             ),
             "dt.strftime",
             self._expression,
@@ -658,16 +663,17 @@ class DateTimeNamespace:
         )
 
     def to_utc(
-        self, from_timezone: expr.ColumnExpression | str
+        self, from_timezone: expr.ColumnExpression | str | None = None
     ) -> expr.ColumnExpression:
         """Converts DateTimeNaive to UTC from time zone provided as `from_timezone`
         argument. If the given DateTime doesn't exist in the provided time zone it is
         mapped to the first existing DateTime after it. If a given DateTime corresponds
         to more than one moments in the provided time zone, it is mapped to a later
-        moment.
+        moment. DateTimeZoned is converted without `from_timezone`, as it has its own
+        time zone.
 
         Args:
-            from_timezone: The time zone to convert from.
+            from_timezone: The time zone to convert from. Only for DateTimeNaive.
 
         Returns:
             DateTimeUtc
@@ -734,6 +740,18 @@ class DateTimeNamespace:
         2023-11-05 01:00:00 | 2023-11-05 09:00:00+00:00
         """
 
+        if from_timezone is None:
+            return expr.MethodCallExpression(
+                (
+                    (
+                        dt.DATE_TIME_ZONED,
+                        dt.DATE_TIME_UTC,
+                        api.Expression.date_time_zoned_to_utc,
+                    ),
+                ),
+                "dt.to_utc",
+                self._expression,
+            )
         return expr.MethodCallExpression(
             (
                 (
@@ -837,6 +855,201 @@ class DateTimeNamespace:
             timezone,
         )
 
+    def to_zoned(self, timezone: expr.ColumnExpression | str) -> expr.ColumnExpression:
+        """Converts DateTimeUtc or DateTimeNaive to DateTimeZoned in the time zone
+        specified as `timezone` argument. DateTimeNaive is the local time in that time
+        zone and is mapped the same way as in `to_utc`. Unlike DateTimeUtc, the result
+        keeps the time zone, so that the calendar methods follow its rules.
+
+        Args:
+            timezone: The IANA name of the time zone, e.g. ``Europe/Warsaw``.
+
+        Returns:
+            DateTimeZoned
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |        date_utc
+        ...    1 | 2023-03-26T00:59:00+00:00
+        ...    2 | 2023-03-26T01:00:00+00:00
+        ...    3 | 2023-10-29T00:30:00+00:00
+        ...    4 | 2023-10-29T01:30:00+00:00
+        ... '''
+        ... )
+        >>> fmt = "%Y-%m-%dT%H:%M:%S%z"
+        >>> table_utc = table.select(date_utc=pw.this.date_utc.dt.strptime(fmt=fmt))
+        >>> table_zoned = table_utc.select(
+        ...     date=pw.this.date_utc.dt.to_zoned("Europe/Warsaw")
+        ... )
+        >>> pw.debug.compute_and_print(
+        ...     table_zoned.select(
+        ...         date=pw.this.date.dt.strftime("%Y-%m-%d %H:%M:%S%z"),
+        ...         timezone=pw.this.date.dt.timezone(),
+        ...     ),
+        ...     include_id=False,
+        ... )
+        date                     | timezone
+        2023-03-26 01:59:00+0100 | Europe/Warsaw
+        2023-03-26 03:00:00+0200 | Europe/Warsaw
+        2023-10-29 02:30:00+0100 | Europe/Warsaw
+        2023-10-29 02:30:00+0200 | Europe/Warsaw
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.DATE_TIME_UTC, dt.STR),
+                    dt.DATE_TIME_ZONED,
+                    api.Expression.date_time_utc_to_zoned,
+                ),
+                (
+                    (dt.DATE_TIME_NAIVE, dt.STR),
+                    dt.DATE_TIME_ZONED,
+                    api.Expression.date_time_naive_to_zoned,
+                ),
+            ),
+            "dt.to_zoned",
+            self._expression,
+            timezone,
+        )
+
+    def convert_timezone(
+        self, timezone: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Converts DateTimeZoned to the time zone specified as `timezone` argument.
+        The moment in time stays the same, only the local time changes.
+
+        Args:
+            timezone: The IANA name of the time zone to convert to.
+
+        Returns:
+            DateTimeZoned
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |        date_utc
+        ...    1 | 2024-07-01T16:00:00+00:00
+        ... '''
+        ... )
+        >>> fmt = "%Y-%m-%dT%H:%M:%S%z"
+        >>> table_zoned = table.select(
+        ...     date=pw.this.date_utc.dt.strptime(fmt=fmt).dt.to_zoned("America/New_York")
+        ... )
+        >>> table_tokyo = table_zoned.select(
+        ...     date=pw.this.date.dt.convert_timezone("Asia/Tokyo")
+        ... )
+        >>> pw.debug.compute_and_print(
+        ...     table_tokyo.select(
+        ...         date=pw.this.date.dt.strftime("%Y-%m-%d %H:%M %Z"),
+        ...         utc=pw.this.date.dt.to_utc(),
+        ...     ),
+        ...     include_id=False,
+        ... )
+        date                 | utc
+        2024-07-02 01:00 JST | 2024-07-01 16:00:00+00:00
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.DATE_TIME_ZONED, dt.STR),
+                    dt.DATE_TIME_ZONED,
+                    api.Expression.date_time_zoned_convert_timezone,
+                ),
+            ),
+            "dt.convert_timezone",
+            self._expression,
+            timezone,
+        )
+
+    def timezone(self) -> expr.ColumnExpression:
+        """Returns the IANA name of the time zone of a DateTimeZoned.
+
+        Returns:
+            str
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |        date_utc         | timezone
+        ...    1 | 2024-07-01T16:00:00+00:00 | Europe/Warsaw
+        ...    2 | 2024-07-01T16:00:00+00:00 | Asia/Tokyo
+        ... '''
+        ... )
+        >>> fmt = "%Y-%m-%dT%H:%M:%S%z"
+        >>> table_zoned = table.select(
+        ...     date=pw.this.date_utc.dt.strptime(fmt=fmt).dt.to_zoned(pw.this.timezone)
+        ... )
+        >>> pw.debug.compute_and_print(
+        ...     table_zoned.select(timezone=pw.this.date.dt.timezone()), include_id=False
+        ... )
+        timezone
+        Asia/Tokyo
+        Europe/Warsaw
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    dt.DATE_TIME_ZONED,
+                    dt.STR,
+                    api.Expression.date_time_zoned_timezone,
+                ),
+            ),
+            "dt.timezone",
+            self._expression,
+        )
+
+    def to_naive_local(self) -> expr.ColumnExpression:
+        """Converts DateTimeZoned to DateTimeNaive holding its local time, i.e. drops
+        the time zone.
+
+        Returns:
+            DateTimeNaive
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |        date_utc
+        ...    1 | 2023-03-26T00:59:00+00:00
+        ...    2 | 2023-03-26T01:00:00+00:00
+        ... '''
+        ... )
+        >>> fmt = "%Y-%m-%dT%H:%M:%S%z"
+        >>> table_zoned = table.select(
+        ...     date=pw.this.date_utc.dt.strptime(fmt=fmt).dt.to_zoned("Europe/Warsaw")
+        ... )
+        >>> pw.debug.compute_and_print(
+        ...     table_zoned.select(local=pw.this.date.dt.to_naive_local()),
+        ...     include_id=False,
+        ... )
+        local
+        2023-03-26 01:59:00
+        2023-03-26 03:00:00
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    dt.DATE_TIME_ZONED,
+                    dt.DATE_TIME_NAIVE,
+                    api.Expression.date_time_zoned_to_naive,
+                ),
+            ),
+            "dt.to_naive_local",
+            self._expression,
+        )
+
     def add_duration_in_timezone(
         self,
         duration: expr.ColumnExpression | pd.Timedelta,
@@ -1649,3 +1862,312 @@ class DateTimeNamespace:
             "dt.weekday",
             self._expression,
         )
+
+    def quarter(self) -> expr.ColumnExpression:
+        """Extracts the quarter of the year from a DateTime.
+
+        Returns:
+            Quarter as int, from 1 to 4.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |               t1
+        ...    1 | 1974-03-12T00:00:00
+        ...    2 | 2023-04-01T12:00:00
+        ...    3 | 2023-12-31T14:13:00
+        ... '''
+        ... )
+        >>> table_with_datetime = table.select(t1=table.t1.dt.strptime("%Y-%m-%dT%H:%M:%S"))
+        >>> table_with_quarters = table_with_datetime.select(quarter=pw.this.t1.dt.quarter())
+        >>> pw.debug.compute_and_print(table_with_quarters, include_id=False)
+        quarter
+        1
+        2
+        4
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (dt.DATE_TIME_NAIVE, dt.INT, api.Expression.date_time_naive_quarter),
+                (dt.DATE_TIME_UTC, dt.INT, api.Expression.date_time_utc_quarter),
+            ),
+            "dt.quarter",
+            self._expression,
+        )
+
+    def day_of_year(self) -> expr.ColumnExpression:
+        """Extracts the day of the year from a DateTime, where 1 denotes January 1.
+
+        Returns:
+            Day of the year as int.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |               t1
+        ...    1 | 1974-01-01T00:00:00
+        ...    2 | 2023-03-01T12:00:00
+        ...    3 | 2024-03-01T14:13:00
+        ... '''
+        ... )
+        >>> table_with_datetime = table.select(t1=table.t1.dt.strptime("%Y-%m-%dT%H:%M:%S"))
+        >>> table_with_days = table_with_datetime.select(day=pw.this.t1.dt.day_of_year())
+        >>> pw.debug.compute_and_print(table_with_days, include_id=False)
+        day
+        1
+        60
+        61
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    dt.DATE_TIME_NAIVE,
+                    dt.INT,
+                    api.Expression.date_time_naive_day_of_year,
+                ),
+                (dt.DATE_TIME_UTC, dt.INT, api.Expression.date_time_utc_day_of_year),
+            ),
+            "dt.day_of_year",
+            self._expression,
+        )
+
+    def iso_week(self) -> expr.ColumnExpression:
+        """Extracts the ISO 8601 week number from a DateTime. The weeks start on
+        Mondays and the first week of a year is the one containing its first Thursday,
+        so the first days of January can belong to the last week of the previous year.
+
+        Returns:
+            Week number as int, from 1 to 53.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |               t1
+        ...    1 | 2021-01-01T00:00:00
+        ...    2 | 2023-01-02T12:00:00
+        ...    3 | 2023-05-15T14:13:00
+        ... '''
+        ... )
+        >>> table_with_datetime = table.select(t1=table.t1.dt.strptime("%Y-%m-%dT%H:%M:%S"))
+        >>> table_with_weeks = table_with_datetime.select(week=pw.this.t1.dt.iso_week())
+        >>> pw.debug.compute_and_print(table_with_weeks, include_id=False)
+        week
+        1
+        20
+        53
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (dt.DATE_TIME_NAIVE, dt.INT, api.Expression.date_time_naive_iso_week),
+                (dt.DATE_TIME_UTC, dt.INT, api.Expression.date_time_utc_iso_week),
+            ),
+            "dt.iso_week",
+            self._expression,
+        )
+
+    def truncate(self, unit: expr.ColumnExpression | str) -> expr.ColumnExpression:
+        """Truncates a DateTime to the start of the calendar unit it's in. Unlike
+        ``floor``, it also accepts the units of varying length, like months.
+
+        Args:
+            unit: One of "year", "quarter", "month", "week", "day", "hour", "minute"
+                and "second", or their plural forms. The weeks start on Mondays.
+
+        Returns:
+            DateTimeNaive or DateTimeUtc depending on the type of an object \
+                the method was called on
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |               t1
+        ...    1 | 2023-05-15T14:13:23
+        ...    2 | 2023-08-02T00:00:10
+        ... '''
+        ... )
+        >>> fmt = "%Y-%m-%dT%H:%M:%S"
+        >>> table_with_datetime = table.select(t1=pw.this.t1.dt.strptime(fmt=fmt))
+        >>> res = table_with_datetime.with_columns(
+        ...     quarter=pw.this.t1.dt.truncate("quarter"),
+        ...     week=pw.this.t1.dt.truncate("week"),
+        ... )
+        >>> pw.debug.compute_and_print(res, include_id=False)
+        t1                  | quarter             | week
+        2023-05-15 14:13:23 | 2023-04-01 00:00:00 | 2023-05-15 00:00:00
+        2023-08-02 00:00:10 | 2023-07-01 00:00:00 | 2023-07-31 00:00:00
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.DATE_TIME_NAIVE, dt.STR),
+                    dt.DATE_TIME_NAIVE,
+                    api.Expression.date_time_naive_truncate_to_unit,
+                ),
+                (
+                    (dt.DATE_TIME_UTC, dt.STR),
+                    dt.DATE_TIME_UTC,
+                    api.Expression.date_time_utc_truncate_to_unit,
+                ),
+            ),
+            "dt.truncate",
+            self._expression,
+            unit,
+        )
+
+    def add_calendar(
+        self, amount: expr.ColumnExpression | int, unit: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Adds a number of calendar units to a DateTime. If the resulting month has
+        no such day, the last day of the month is used instead, e.g. one month after
+        January 31 is the last day of February. For DateTimeUtc, the calendar of UTC
+        is used; to use a different timezone, convert to DateTimeNaive first.
+
+        Args:
+            amount: the number of units to add, can be negative.
+            unit: One of "year", "quarter", "month", "week" and "day", or their plural
+                forms.
+
+        Returns:
+            DateTimeNaive or DateTimeUtc depending on the type of an object \
+                the method was called on
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |                  t1 | months
+        ...    1 | 2023-01-31T10:00:00 |      1
+        ...    2 | 2024-01-31T10:00:00 |      1
+        ...    3 | 2023-05-15T14:13:23 |     -5
+        ... '''
+        ... )
+        >>> fmt = "%Y-%m-%dT%H:%M:%S"
+        >>> table_with_datetime = table.with_columns(t1=pw.this.t1.dt.strptime(fmt=fmt))
+        >>> res = table_with_datetime.select(
+        ...     t2=pw.this.t1.dt.add_calendar(pw.this.months, "months")
+        ... )
+        >>> pw.debug.compute_and_print(res, include_id=False)
+        t2
+        2022-12-15 14:13:23
+        2023-02-28 10:00:00
+        2024-02-29 10:00:00
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.DATE_TIME_NAIVE, dt.INT, dt.STR),
+                    dt.DATE_TIME_NAIVE,
+                    api.Expression.date_time_naive_add_calendar_units,
+                ),
+                (
+                    (dt.DATE_TIME_UTC, dt.INT, dt.STR),
+                    dt.DATE_TIME_UTC,
+                    api.Expression.date_time_utc_add_calendar_units,
+                ),
+            ),
+            "dt.add_calendar",
+            self._expression,
+            amount,
+            unit,
+        )
+
+    def subtract_calendar(
+        self, amount: expr.ColumnExpression | int, unit: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Subtracts a number of calendar units from a DateTime. If the resulting month
+        has no such day, the last day of the month is used instead, e.g. one month
+        before March 31 is the last day of February.
+
+        Args:
+            amount: the number of units to subtract, can be negative.
+            unit: One of "year", "quarter", "month", "week" and "day", or their plural
+                forms.
+
+        Returns:
+            DateTimeNaive or DateTimeUtc depending on the type of an object \
+                the method was called on
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |               t1
+        ...    1 | 2023-03-31T10:00:00
+        ...    2 | 2024-02-29T10:00:00
+        ... '''
+        ... )
+        >>> fmt = "%Y-%m-%dT%H:%M:%S"
+        >>> table_with_datetime = table.select(t1=pw.this.t1.dt.strptime(fmt=fmt))
+        >>> res = table_with_datetime.select(
+        ...     month_before=pw.this.t1.dt.subtract_calendar(1, "month"),
+        ...     year_before=pw.this.t1.dt.subtract_calendar(1, "year"),
+        ... )
+        >>> pw.debug.compute_and_print(res, include_id=False)
+        month_before        | year_before
+        2023-02-28 10:00:00 | 2022-03-31 10:00:00
+        2024-01-29 10:00:00 | 2023-02-28 10:00:00
+        """
+
+        return self.add_calendar(-amount, unit)
+
+    def format_duration(
+        self, fmt: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Converts a Duration to a string.
+
+        Args:
+            fmt: Format string. It can use the directives "%d" for the number of
+                whole days, "%H", "%M" and "%S" for the hours, minutes and seconds
+                after them, padded to two digits, "%f" for the nanoseconds after the
+                whole seconds, padded to nine digits, "%h", "%m" and "%s" for the total
+                number of whole hours, minutes and seconds, and "%%" for a literal
+                "%". A negative duration is preceded by "-".
+
+        Returns:
+            str
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      |                  t1 |                  t2
+        ...    1 | 2023-05-15T10:13:00 | 2023-05-17T12:15:30
+        ...    2 | 2023-05-15T10:13:00 | 2023-05-15T09:13:00
+        ... '''
+        ... )
+        >>> fmt = "%Y-%m-%dT%H:%M:%S"
+        >>> table_with_datetimes = table.select(
+        ...     t1=pw.this.t1.dt.strptime(fmt=fmt), t2=pw.this.t2.dt.strptime(fmt=fmt)
+        ... )
+        >>> res = table_with_datetimes.select(
+        ...     elapsed=(pw.this.t2 - pw.this.t1).dt.format_duration("%dd %H:%M:%S"),
+        ...     minutes=(pw.this.t2 - pw.this.t1).dt.format_duration("%m min"),
+        ... )
+        >>> pw.debug.compute_and_print(res, include_id=False)
+        elapsed      | minutes
+        -0d 01:00:00 | -60 min
+        2d 02:02:30  | 3002 min
+        """
+
+        return expr.MethodCallExpression(
+            (((dt.DURATION, dt.STR), dt.STR, api.Expression.duration_format),),
+            "dt.format_duration",
+            self._expression,
+            fmt,
+        )
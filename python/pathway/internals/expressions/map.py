@@ -0,0 +1,88 @@
+# Copyright © 2026 Pathway
+
+import pathway.internals.expression as expr
+from pathway.internals import api, dtype as dt
+
+
+class MapNamespace:
+    """A module containing methods related to maps, i.e. dictionaries with string keys.
+    They can be called using a `map` attribute of an expression.
+
+    A value of a given key can be obtained with the ``get`` method of an expression,
+    which returns the default value if the key is missing.
+    """
+
+    _expression: expr.ColumnExpression
+
+    def __init__(self, expression: expr.ColumnExpression):
+        self._expression = expression
+
+    def contains(self, key: expr.ColumnExpression | str) -> expr.ColumnExpression:
+        """Checks whether the map contains the given key.
+
+        Args:
+            key: the key to look for.
+
+        Returns:
+            True if the key is present in the map, False otherwise. A missing map
+            (``None``) contains no keys.
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.ANY, dt.STR),
+                    dt.BOOL,
+                    api.Expression.map_contains,
+                ),
+            ),
+            "map.contains",
+            self._expression,
+            key,
+        )
+
+    def keys(self) -> expr.ColumnExpression:
+        """Returns the keys of the map as a list, in the ascending order.
+
+        Returns:
+            List of keys
+        """
+
+        return expr.MethodCallExpression(
+            ((dt.ANY, dt.List(dt.STR), api.Expression.map_keys),),
+            "map.keys",
+            self._expression,
+        )
+
+    def values(self) -> expr.ColumnExpression:
+        """Returns the values of the map as a list, ordered by their keys.
+
+        Returns:
+            List of values
+        """
+
+        return expr.MethodCallExpression(
+            ((dt.ANY, dt.List(dt.ANY), api.Expression.map_values),),
+            "map.values",
+            self._expression,
+        )
+
+    def items(self) -> expr.ColumnExpression:
+        """Returns the ``(key, value)`` pairs of the map as a list, ordered by keys.
+        The result can be flattened to get a row per map entry.
+
+        Returns:
+            List of key-value tuples
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    dt.ANY,
+                    dt.List(dt.Tuple(dt.STR, dt.ANY)),
+                    api.Expression.map_items,
+                ),
+            ),
+            "map.items",
+            self._expression,
+        )
@@ -5,6 +5,16 @@ from collections.abc import Iterable
 import pathway.internals.expression as expr
 from pathway.internals import api, dtype as dt
 
+_URL_PART_DTYPES: dict[str, dt.DType] = {
+    "scheme": dt.STR,
+    "username": dt.Optional(dt.STR),
+    "host": dt.Optional(dt.STR),
+    "port": dt.Optional(dt.INT),
+    "path": dt.STR,
+    "query": dt.Optional(dt.STR),
+    "fragment": dt.Optional(dt.STR),
+}
+
 
 class StringNamespace:
     """A module containing methods related to string.
@@ -929,3 +939,776 @@ class StringNamespace:
             "str.parse_bool",
             self._expression,
         )
+
+    def regex_match(
+        self, pattern: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Checks whether the regular expression matches anywhere in the string.
+        Use ``^`` and ``$`` to match the whole string.
+
+        The pattern follows the syntax of the Rust ``regex`` crate, which doesn't
+        support look-around and backreferences. Each distinct pattern is compiled
+        once, so using a constant pattern is much cheaper than a Python UDF.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | email
+        ...    1 | alice@example.com
+        ...    2 | bob
+        ...    3 | carol@pathway.com
+        ... '''
+        ... )
+        >>> table += table.select(valid=table.email.str.regex_match(r"^[^@]+@[^@]+$"))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        email             | valid
+        alice@example.com | True
+        bob               | False
+        carol@pathway.com | True
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.STR, dt.STR),
+                    dt.BOOL,
+                    api.Expression.regex_match,
+                ),
+            ),
+            "str.regex_match",
+            self._expression,
+            pattern,
+        )
+
+    def regex_extract(
+        self,
+        pattern: expr.ColumnExpression | str,
+        group: expr.ColumnExpression | int = 0,
+    ) -> expr.ColumnExpression:
+        """Returns the substring matched by the given group of the first match of the
+        regular expression. Group ``0`` is the whole match. If there is no match, or
+        the group doesn't take part in the match, ``None`` is returned.
+
+        The pattern follows the syntax of the Rust ``regex`` crate.
+
+        Args:
+            pattern: the regular expression to search for.
+            group: index of the capture group to return. Defaults to 0.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | log
+        ...    1 | user=alice;id=17
+        ...    2 | user=bob;id=5
+        ...    3 | no_user
+        ... '''
+        ... )
+        >>> table += table.select(user=table.log.str.regex_extract(r"user=(\\w+)", 1))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        log              | user
+        no_user          |
+        user=alice;id=17 | alice
+        user=bob;id=5    | bob
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.STR, dt.STR, dt.INT),
+                    dt.Optional(dt.STR),
+                    api.Expression.regex_extract,
+                ),
+            ),
+            "str.regex_extract",
+            self._expression,
+            pattern,
+            group,
+        )
+
+    def regex_replace(
+        self,
+        pattern: expr.ColumnExpression | str,
+        replacement: expr.ColumnExpression | str,
+    ) -> expr.ColumnExpression:
+        """Replaces all matches of the regular expression with the replacement.
+        The replacement can refer to capture groups with ``$1`` or ``${name}``.
+
+        The pattern follows the syntax of the Rust ``regex`` crate.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | email
+        ...    1 | alice@example
+        ...    2 | bob@pathway
+        ... '''
+        ... )
+        >>> table += table.select(
+        ...     swapped=table.email.str.regex_replace(r"(\\w+)@(\\w+)", "$2/$1")
+        ... )
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        email         | swapped
+        alice@example | example/alice
+        bob@pathway   | pathway/bob
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.STR, dt.STR, dt.STR),
+                    dt.STR,
+                    api.Expression.regex_replace,
+                ),
+            ),
+            "str.regex_replace",
+            self._expression,
+            pattern,
+            replacement,
+        )
+
+    def regex_split(
+        self, pattern: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Splits the string on the matches of the regular expression.
+
+        The pattern follows the syntax of the Rust ``regex`` crate.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | path
+        ...    1 | a-b_c
+        ...    2 | d__e
+        ... '''
+        ... )
+        >>> table += table.select(parts=table.path.str.regex_split(r"[-_]"))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        path  | parts
+        a-b_c | ('a', 'b', 'c')
+        d__e  | ('d', '', 'e')
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.STR, dt.STR),
+                    dt.List(dt.STR),
+                    api.Expression.regex_split,
+                ),
+            ),
+            "str.regex_split",
+            self._expression,
+            pattern,
+        )
+
+    def levenshtein(
+        self, other: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Returns the Levenshtein distance between the strings, i.e. the minimal number
+        of single-character insertions, deletions and substitutions turning one of them
+        into the other. The distance is computed natively, without calling Python for
+        each row.
+
+        Args:
+            other: the string to compare with.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | a       | b
+        ...    1 | kitten  | sitting
+        ...    2 | Pathway | pathway
+        ...    3 | crate   | crate
+        ... '''
+        ... )
+        >>> table += table.select(distance=table.a.str.levenshtein(table.b))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        a       | b       | distance
+        Pathway | pathway | 1
+        crate   | crate   | 0
+        kitten  | sitting | 3
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.STR, dt.STR),
+                    dt.INT,
+                    api.Expression.levenshtein,
+                ),
+            ),
+            "str.levenshtein",
+            self._expression,
+            other,
+        )
+
+    def jaro_winkler(
+        self, other: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Returns the Jaro-Winkler similarity of the strings, from 0.0 for the strings
+        with no common characters to 1.0 for the equal ones. The strings sharing a
+        prefix of up to four characters are rated as more similar, which suits short
+        strings like names. The similarity is computed natively, without calling Python
+        for each row.
+
+        Args:
+            other: the string to compare with.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | a      | b
+        ...    1 | martha | marhta
+        ...    2 | dwayne | duane
+        ...    3 | crate  | crate
+        ... '''
+        ... )
+        >>> table += table.select(similarity=table.a.str.jaro_winkler(table.b))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        a      | b      | similarity
+        crate  | crate  | 1.0
+        dwayne | duane  | 0.8400000000000001
+        martha | marhta | 0.9611111111111111
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.STR, dt.STR),
+                    dt.FLOAT,
+                    api.Expression.jaro_winkler,
+                ),
+            ),
+            "str.jaro_winkler",
+            self._expression,
+            other,
+        )
+
+    def trigram_similarity(
+        self, other: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Returns the trigram similarity of the strings, compatible with the
+        ``similarity`` function of the ``pg_trgm`` extension of PostgreSQL. The words of
+        the strings, i.e. the runs of alphanumeric characters, are lowercased and split
+        into the sequences of three consecutive characters, and the similarity is the
+        number of such trigrams shared by the strings divided by the number of distinct
+        trigrams in both of them. It's 0.0 if either string has no alphanumeric
+        characters. As it ignores the order of words, it suits longer strings, like
+        addresses or product names. The similarity is computed natively, without calling
+        Python for each row.
+
+        Args:
+            other: the string to compare with.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | a       | b
+        ...    1 | word    | two_words
+        ...    2 | Pathway | pathway
+        ...    3 | crate   | trace
+        ... '''
+        ... )
+        >>> table += table.select(similarity=table.a.str.trigram_similarity(table.b))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        a       | b         | similarity
+        Pathway | pathway   | 1.0
+        crate   | trace     | 0.0
+        word    | two_words | 0.36363636363636365
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    (dt.STR, dt.STR),
+                    dt.FLOAT,
+                    api.Expression.trigram_similarity,
+                ),
+            ),
+            "str.trigram_similarity",
+            self._expression,
+            other,
+        )
+
+    def base64_encode(self) -> expr.ColumnExpression:
+        """Encodes a string or bytes with the standard, padded base64. The strings are
+        encoded as UTF-8.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | text
+        ...    1 | Pathway
+        ...    2 | a+b
+        ... '''
+        ... )
+        >>> table += table.select(encoded=table.text.str.base64_encode())
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        text    | encoded
+        Pathway | UGF0aHdheQ==
+        a+b     | YSti
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (dt.STR, dt.STR, api.Expression.base64_encode),
+                (dt.BYTES, dt.STR, api.Expression.base64_encode),
+            ),
+            "str.base64_encode",
+            self._expression,
+        )
+
+    def base64_decode(self) -> expr.ColumnExpression:
+        """Decodes a string encoded with the standard, padded base64 into bytes. Fails
+        on the strings that aren't valid base64.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | encoded
+        ...    1 | UGF0aHdheQ==
+        ...    2 | /w==
+        ... '''
+        ... )
+        >>> table += table.select(
+        ...     hex=table.encoded.str.base64_decode().str.hex_encode()
+        ... )
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        encoded      | hex
+        /w==         | ff
+        UGF0aHdheQ== | 50617468776179
+        """
+
+        return expr.MethodCallExpression(
+            ((dt.STR, dt.BYTES, api.Expression.base64_decode),),
+            "str.base64_decode",
+            self._expression,
+        )
+
+    def hex_encode(self) -> expr.ColumnExpression:
+        """Encodes a string or bytes as lowercase hex digits, two per byte. The strings
+        are encoded as UTF-8.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | text
+        ...    1 | Pathway
+        ...    2 | a+b
+        ... '''
+        ... )
+        >>> table += table.select(encoded=table.text.str.hex_encode())
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        text    | encoded
+        Pathway | 50617468776179
+        a+b     | 612b62
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (dt.STR, dt.STR, api.Expression.hex_encode),
+                (dt.BYTES, dt.STR, api.Expression.hex_encode),
+            ),
+            "str.hex_encode",
+            self._expression,
+        )
+
+    def hex_decode(self) -> expr.ColumnExpression:
+        """Decodes a string of hex digits of either case, two per byte, into bytes.
+        Fails on the strings that aren't valid hex.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | encoded
+        ...    1 | cafe
+        ...    2 | FF00
+        ... '''
+        ... )
+        >>> table += table.select(
+        ...     base64=table.encoded.str.hex_decode().str.base64_encode()
+        ... )
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        encoded | base64
+        FF00    | /wA=
+        cafe    | yv4=
+        """
+
+        return expr.MethodCallExpression(
+            ((dt.STR, dt.BYTES, api.Expression.hex_decode),),
+            "str.hex_decode",
+            self._expression,
+        )
+
+    def url_encode(self) -> expr.ColumnExpression:
+        """Percent-encodes a string or bytes, so that it can be used in any part of a
+        URL. All the bytes except ASCII letters, digits, ``-``, ``.``, ``_`` and ``~``
+        are encoded, including spaces, which become ``%20``. The strings are encoded as
+        UTF-8.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | query
+        ...    1 | a+b=c
+        ...    2 | zażółć
+        ... '''
+        ... )
+        >>> table += table.select(encoded=table.query.str.url_encode())
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        query  | encoded
+        a+b=c  | a%2Bb%3Dc
+        zażółć | za%C5%BC%C3%B3%C5%82%C4%87
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (dt.STR, dt.STR, api.Expression.url_encode),
+                (dt.BYTES, dt.STR, api.Expression.url_encode),
+            ),
+            "str.url_encode",
+            self._expression,
+        )
+
+    def url_decode(self) -> expr.ColumnExpression:
+        """Decodes a percent-encoded string. A ``+`` is kept as is, as in the paths of
+        URLs. Fails if an escape sequence is invalid or the decoded bytes aren't valid
+        UTF-8.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | encoded
+        ...    1 | a%2Bb%3Dc
+        ...    2 | za%C5%BC%C3%B3%C5%82%C4%87
+        ... '''
+        ... )
+        >>> table += table.select(query=table.encoded.str.url_decode())
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        encoded                    | query
+        a%2Bb%3Dc                  | a+b=c
+        za%C5%BC%C3%B3%C5%82%C4%87 | zażółć
+        """
+
+        return expr.MethodCallExpression(
+            ((dt.STR, dt.STR, api.Expression.url_decode),),
+            "str.url_decode",
+            self._expression,
+        )
+
+    def hexdigest(
+        self, algorithm: expr.ColumnExpression | str = "sha256"
+    ) -> expr.ColumnExpression:
+        """Hashes a string or bytes and returns the digest as lowercase hex digits. The
+        strings are hashed as UTF-8.
+
+        Args:
+            algorithm: the hash function, one of ``"sha256"``, ``"md5"``, ``"blake3"``
+                and ``"xxh3"`` (the 64-bit XXH3). Only ``"sha256"`` and ``"blake3"`` are
+                cryptographic hashes, suitable for pseudonymization, while ``"xxh3"`` is
+                the fastest, suitable for bucketing. Defaults to ``"sha256"``.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | user
+        ...    1 | alice
+        ...    2 | bob
+        ... '''
+        ... )
+        >>> table += table.select(hash=table.user.str.hexdigest("md5"))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        user  | hash
+        alice | 6384e2b2184bcbf58eccf10ca7a6563c
+        bob   | 9f9d51bc70ef21ca5c14f307980a29d8
+        """
+
+        return expr.MethodCallExpression(
+            (
+                ((dt.STR, dt.STR), dt.STR, api.Expression.hex_digest),
+                ((dt.BYTES, dt.STR), dt.STR, api.Expression.hex_digest),
+            ),
+            "str.hexdigest",
+            self._expression,
+            algorithm,
+        )
+
+    def digest(
+        self, algorithm: expr.ColumnExpression | str = "sha256"
+    ) -> expr.ColumnExpression:
+        """Hashes a string or bytes and returns the digest as bytes. The strings are
+        hashed as UTF-8. The XXH3 hash is returned as eight big-endian bytes.
+
+        Args:
+            algorithm: the hash function, one of ``"sha256"``, ``"md5"``, ``"blake3"``
+                and ``"xxh3"`` (the 64-bit XXH3). Defaults to ``"sha256"``.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> table = pw.debug.table_from_markdown(
+        ...     '''
+        ...      | user
+        ...    1 | alice
+        ...    2 | bob
+        ... '''
+        ... )
+        >>> table += table.select(
+        ...     hash=table.user.str.digest().str.base64_encode()
+        ... )
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        user  | hash
+        alice | K9gGyX8OAK8aH8Myj6djqSaXI8jbj6xPk69x2xhtbpA=
+        bob   | gbY32PzSxtpjWeaWMROhFw3nleS3JbhNHgtM/Z7FjOk=
+        """
+
+        return expr.MethodCallExpression(
+            (
+                ((dt.STR, dt.STR), dt.BYTES, api.Expression.digest),
+                ((dt.BYTES, dt.STR), dt.BYTES, api.Expression.digest),
+            ),
+            "str.digest",
+            self._expression,
+            algorithm,
+        )
+
+    def parse_ip(self, optional: bool = False) -> expr.ColumnExpression:
+        """Parses the string as an IPv4 or IPv6 address and returns its canonical form,
+        e.g. with the zeros of an IPv6 address compressed. If optional argument is set
+        to True, then the return type is Optional[str] and if some string is not a
+        valid address, None is returned.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> import pandas as pd
+        >>> df = pd.DataFrame({"a": ["192.168.0.1", "2001:DB8:0:0::1", "1.2.3"]})
+        >>> table = pw.debug.table_from_pandas(df)
+        >>> table += table.select(ip=table.a.str.parse_ip(optional=True))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        a               | ip
+        1.2.3           |
+        192.168.0.1     | 192.168.0.1
+        2001:DB8:0:0::1 | 2001:db8::1
+        """
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    dt.STR,
+                    dt.Optional(dt.STR) if optional else dt.STR,
+                    lambda x: api.Expression.parse_ip_address(x, optional),
+                ),
+            ),
+            "str.parse_ip",
+            self._expression,
+        )
+
+    def ip_version(self) -> expr.ColumnExpression:
+        """Returns 4 for the IPv4 addresses, 6 for the IPv6 ones and None for the
+        strings that aren't valid IP addresses.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> import pandas as pd
+        >>> df = pd.DataFrame({"a": ["192.168.0.1", "::1", "localhost"]})
+        >>> table = pw.debug.table_from_pandas(df)
+        >>> table += table.select(version=table.a.str.ip_version())
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        a           | version
+        192.168.0.1 | 4
+        ::1         | 6
+        localhost   |
+        """
+
+        return expr.MethodCallExpression(
+            ((dt.STR, dt.Optional(dt.INT), api.Expression.ip_address_version),),
+            "str.ip_version",
+            self._expression,
+        )
+
+    def ip_in_network(
+        self, network: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Checks whether the IP address belongs to the network given in the CIDR
+        notation, e.g. ``10.0.0.0/8``. An IPv4 address never belongs to an IPv6 network
+        and vice versa. Fails if the address or the network is invalid.
+
+        Args:
+            network: the network, a single address is a network with the full-length
+                prefix.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> import pandas as pd
+        >>> df = pd.DataFrame({"a": ["10.1.2.3", "192.168.0.1", "::1"]})
+        >>> table = pw.debug.table_from_pandas(df)
+        >>> table += table.select(private=table.a.str.ip_in_network("10.0.0.0/8"))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        a           | private
+        10.1.2.3    | True
+        192.168.0.1 | False
+        ::1         | False
+        """
+
+        return expr.MethodCallExpression(
+            (((dt.STR, dt.STR), dt.BOOL, api.Expression.ip_in_network),),
+            "str.ip_in_network",
+            self._expression,
+            network,
+        )
+
+    def ip_network(
+        self, prefix_len: expr.ColumnExpression | int
+    ) -> expr.ColumnExpression:
+        """Returns the network with the prefix of the given length the IP address
+        belongs to, in the CIDR notation, e.g. to group the addresses by their /24
+        networks. Fails if the address is invalid or the prefix is longer than the
+        address.
+
+        Args:
+            prefix_len: the length of the prefix in bits, at most 32 for the IPv4
+                addresses and 128 for the IPv6 ones.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> import pandas as pd
+        >>> df = pd.DataFrame({"a": ["192.168.17.5", "2001:db8:ab:cd::1"]})
+        >>> table = pw.debug.table_from_pandas(df)
+        >>> table += table.select(network=table.a.str.ip_network(24))
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        a                 | network
+        192.168.17.5      | 192.168.17.0/24
+        2001:db8:ab:cd::1 | 2001:d00::/24
+        """
+
+        return expr.MethodCallExpression(
+            (((dt.STR, dt.INT), dt.STR, api.Expression.ip_network),),
+            "str.ip_network",
+            self._expression,
+            prefix_len,
+        )
+
+    def url_part(self, part: str) -> expr.ColumnExpression:
+        """Extracts a part of an absolute URL, parsed the same way web browsers do.
+        Fails on the strings that aren't valid absolute URLs.
+
+        Args:
+            part: One of the following parts:
+                - "scheme", lowercased, e.g. ``https``
+                - "username", or None if there is none
+                - "host", with a domain lowercased, or None if there is none
+                - "port" as int, or None if there is none or it's the default port of
+                  the scheme
+                - "path", e.g. ``/`` for ``https://pathway.com``
+                - "query" without the leading ``?``, or None if there is none
+                - "fragment" without the leading ``#``, or None if there is none
+
+        Example:
+
+        >>> import pathway as pw
+        >>> import pandas as pd
+        >>> df = pd.DataFrame(
+        ...     {"url": ["https://Shop.Example.com:8443/cart?id=17#top", "http://a.io"]}
+        ... )
+        >>> table = pw.debug.table_from_pandas(df)
+        >>> table = table.select(
+        ...     host=pw.this.url.str.url_part("host"),
+        ...     port=pw.this.url.str.url_part("port"),
+        ...     path=pw.this.url.str.url_part("path"),
+        ...     query=pw.this.url.str.url_part("query"),
+        ... )
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        host             | port | path  | query
+        a.io             |      | /     |
+        shop.example.com | 8443 | /cart | id=17
+        """
+        if part not in _URL_PART_DTYPES:
+            raise ValueError(
+                f"unknown URL part {part!r}, it has to be one of "
+                + ", ".join(repr(part) for part in _URL_PART_DTYPES)
+            )
+
+        return expr.MethodCallExpression(
+            (
+                (
+                    dt.STR,
+                    _URL_PART_DTYPES[part],
+                    lambda x: api.Expression.url_part(x, part),
+                ),
+            ),
+            "str.url_part",
+            self._expression,
+        )
+
+    def url_query_param(
+        self, name: expr.ColumnExpression | str
+    ) -> expr.ColumnExpression:
+        """Returns the first value of the query parameter of an absolute URL, decoded,
+        or None if the URL has no such parameter. Fails on the strings that aren't
+        valid absolute URLs.
+
+        Args:
+            name: the name of the parameter.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> import pandas as pd
+        >>> df = pd.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://pathway.com/?utm_source=news%20letter&id=1",
+        ...             "https://pathway.com/blog?id=2",
+        ...         ]
+        ...     }
+        ... )
+        >>> table = pw.debug.table_from_pandas(df)
+        >>> table = table.select(
+        ...     id=pw.this.url.str.url_query_param("id"),
+        ...     source=pw.this.url.str.url_query_param("utm_source"),
+        ... )
+        >>> pw.debug.compute_and_print(table, include_id=False)
+        id | source
+        1  | news letter
+        2  |
+        """
+
+        return expr.MethodCallExpression(
+            (((dt.STR, dt.STR), dt.Optional(dt.STR), api.Expression.url_query_param),),
+            "str.url_query_param",
+            self._expression,
+            name,
+        )
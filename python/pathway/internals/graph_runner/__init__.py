@@ -3,12 +3,15 @@
 from __future__ import annotations
 
 import asyncio
+import contextlib
 import json
 import os
+import signal
 import sys
+import threading
 import uuid
 import warnings
-from collections.abc import Callable, Collection, Iterable
+from collections.abc import Callable, Collection, Iterable, Iterator
 from itertools import chain
 
 import pathway.internals.graph_runner.telemetry as telemetry
@@ -36,6 +39,34 @@ from pathway.persistence import (
 )
 
 
+@contextlib.contextmanager
+def _shutdown_gracefully_on_sigterm() -> Iterator[None]:
+    # The handlers can only be set in the main thread. The handler is called through
+    # the signal wakeup of the engine while the computation is running.
+    if threading.current_thread() is not threading.main_thread():
+        yield
+        return
+
+    previous_handler = signal.getsignal(signal.SIGTERM)
+    if previous_handler is None:
+        # set outside of Python, it can't be restored
+        previous_handler = signal.SIG_DFL
+
+    def on_sigterm(signum, frame):
+        # the second SIGTERM is handled as it would be without the computation
+        signal.signal(signal.SIGTERM, previous_handler)
+        try:
+            api.request_graceful_shutdown()
+        except RuntimeError:
+            signal.raise_signal(signal.SIGTERM)
+
+    signal.signal(signal.SIGTERM, on_sigterm)
+    try:
+        yield
+    finally:
+        signal.signal(signal.SIGTERM, previous_handler)
+
+
 class GraphRunner:
     """Runs evaluation of ParseGraph."""
 
@@ -59,8 +90,12 @@ class GraphRunner:
         runtime_typechecking: bool | None = None,
         terminate_on_error: bool | None = None,
         max_expression_batch_size: int = 1024,
+        deterministic_expression_cache_size: int = 0,
         event_loop: asyncio.AbstractEventLoop | None = None,
         udf_cache_directory: str | None = None,
+        step_mode: str | None = None,
+        dry_run: bool = False,
+        check_connectors: bool = True,
         _stacklevel: int = 1,
     ) -> None:
         pathway_config = get_pathway_config()
@@ -82,8 +117,13 @@ class GraphRunner:
             terminate_on_error = pathway_config.terminate_on_error
         self.terminate_on_error = terminate_on_error
         self.max_expression_batch_size = max_expression_batch_size
+        self.deterministic_expression_cache_size = deterministic_expression_cache_size
         self.event_loop = event_loop
         self.udf_cache_directory = udf_cache_directory
+        self.step_mode = step_mode
+        self.dry_run_report = (
+            api.DryRunReport(check_connectors=check_connectors) if dry_run else None
+        )
         if not self.terminate_on_error:
             warnings.warn(
                 "terminate_on_error=False mode is experimental",
@@ -210,6 +250,7 @@ class GraphRunner:
                     get_persistence_engine_config(
                         self.persistence_config
                     ) as persistence_engine_config,
+                    _shutdown_gracefully_on_sigterm(),
                 ):
                     try:
                         return api.run_with_new_graph(
@@ -224,7 +265,12 @@ class GraphRunner:
                             license_key=self.license_key,
                             terminate_on_error=self.terminate_on_error,
                             max_expression_batch_size=self.max_expression_batch_size,
+                            deterministic_expression_cache_size=(
+                                self.deterministic_expression_cache_size
+                            ),
                             udf_cache_directory=self.udf_cache_directory,
+                            step_mode=self.step_mode,
+                            dry_run_report=self.dry_run_report,
                         )
                     except api.EngineErrorWithTrace as e:
                         error, frame = e.args
@@ -247,7 +293,8 @@ class GraphRunner:
                     finally:
                         for node in graph.G._current_scope.nodes:
                             if (
-                                isinstance(node, OutputOperator)
+                                self.dry_run_report is None
+                                and isinstance(node, OutputOperator)
                                 and isinstance(node.datasink, datasink.GenericDataSink)
                                 and node.datasink.on_pipeline_finished is not None
                             ):
@@ -261,9 +308,15 @@ class GraphRunner:
 
     def _get_run_id(self):
         run_id = os.environ.get("PATHWAY_RUN_ID")
-        if run_id is None:
-            run_id = str(uuid.uuid4())
-        return run_id
+        if run_id is not None:
+            return run_id
+        # a persisted pipeline keeps its run id across restarts, so that all the data
+        # it produces can be traced back to it
+        if self.persistence_config is not None:
+            storage_id = self.persistence_config.backend.storage_id
+            if storage_id is not None:
+                return str(uuid.uuid5(uuid.NAMESPACE_URL, storage_id))
+        return str(uuid.uuid4())
 
     def tree_shake_tables(
         self, graph_scope: graph.Scope, tables: Iterable[table.Table]
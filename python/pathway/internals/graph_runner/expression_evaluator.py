@@ -755,6 +755,11 @@ class RowwiseEvaluator(
                 return api.Expression.json_get_item_checked(object, index, default)
             else:
                 return api.Expression.json_get_item_unchecked(object, index)
+        elif isinstance(dt.unoptionalize(object_dtype), dt.Map):
+            if expression._check_if_exists:
+                return api.Expression.map_get_item_checked(object, index, default)
+            else:
+                return api.Expression.map_get_item_unchecked(object, index)
         else:
             assert not object_dtype.equivalent_to(dt.Optional(dt.JSON))
             if expression._check_if_exists:
@@ -867,14 +872,19 @@ class ForgetEvaluator(ExpressionEvaluator, context_type=clmn.ForgetContext):
         instance_column_path = input_storage.get_path(self.context.instance_column)
         properties = self._table_properties(output_storage)
 
-        return self.scope.forget(
+        # the late entries are stored in the same layout as the other ones
+        table, late_table = self.scope.forget(
             self.state.get_table(input_storage._universe),
             threshold_column_path,
             time_column_path,
             instance_column_path,
             self.context.mark_forgetting_records,
             properties,
+            late_table_properties=properties if self.context.route_late else None,
         )
+        if late_table is not None:
+            self.state.set_late_table(self.context, late_table)
+        return table
 
 
 class GradualBroadcastEvaluator(
@@ -899,6 +909,23 @@ class GradualBroadcastEvaluator(
         )
 
 
+class VersionRowsEvaluator(ExpressionEvaluator, context_type=clmn.VersionRowsContext):
+    context: clmn.VersionRowsContext
+
+    def run(self, output_storage: Storage) -> api.Table:
+        input_storage = self.state.get_storage(self.context.input_universe())
+        column_paths = [
+            input_storage.get_path(column) for column in self.context.columns
+        ]
+        properties = self._table_properties(output_storage)
+        return self.scope.version_rows(
+            self.state.get_table(input_storage._universe),
+            column_paths,
+            self.context.unique_name,
+            properties,
+        )
+
+
 class ExternalIndexAsOfNowEvaluator(
     ExpressionEvaluator, context_type=clmn.ExternalIndexAsOfNowContext
 ):
@@ -1005,13 +1032,48 @@ class BufferEvaluator(ExpressionEvaluator, context_type=clmn.BufferContext):
         instance_column_path = input_storage.get_path(self.context.instance_column)
         properties = self._table_properties(output_storage)
 
-        return self.scope.buffer(
+        # the late entries are stored in the same layout as the other ones
+        table, late_table = self.scope.buffer(
             self.state.get_table(input_storage._universe),
             threshold_column_path,
             time_column_path,
             instance_column_path,
             properties,
+            late_table_properties=properties if self.context.route_late else None,
         )
+        if late_table is not None:
+            self.state.set_late_table(self.context, late_table)
+        return table
+
+
+class RetainLastVersionsEvaluator(
+    ExpressionEvaluator, context_type=clmn.RetainLastVersionsContext
+):
+    context: clmn.RetainLastVersionsContext
+
+    def run(self, output_storage: Storage) -> api.Table:
+        input_storage = self.state.get_storage(self.context.input_universe())
+        time_column_path = input_storage.get_path(self.context.time_column)
+        instance_column_path = input_storage.get_path(self.context.instance_column)
+        properties = self._table_properties(output_storage)
+
+        return self.scope.retain_last_versions(
+            self.state.get_table(input_storage._universe),
+            time_column_path,
+            instance_column_path,
+            self.context.max_versions,
+            properties,
+        )
+
+
+class LateEntriesEvaluator(
+    ExpressionEvaluator, context_type=clmn.LateEntriesContext
+):
+    context: clmn.LateEntriesContext
+
+    def run(self, output_storage: Storage) -> api.Table:
+        # computed together with the other output of the routing operator
+        return self.state.get_late_table(self.context.routing_context)
 
 
 class IntersectEvaluator(ExpressionEvaluator, context_type=clmn.IntersectContext):
@@ -1186,6 +1248,7 @@ class JoinEvaluator(ExpressionEvaluator, context_type=clmn.JoinContext):
             right_ear=self.context.right_ear,
             left_exactly_once=self.context.left_exactly_once,
             right_exactly_once=self.context.right_exactly_once,
+            shard_column=self.context.shard_column,
         )
         self.state.set_table(join_storage, output_engine_table)
 
@@ -1260,6 +1323,7 @@ class GroupedEvaluator(ExpressionEvaluator, context_type=clmn.GroupedContext):
             reducers,
             self.context.set_id,
             properties,
+            shard_column=self.context.shard_column,
         )
 
         return reduced_engine_table
@@ -1578,6 +1642,36 @@ class AssertAppendOnlyEvaluator(
         )
 
 
+class AssertMonotonicEvaluator(
+    ExpressionEvaluator, context_type=clmn.AssertMonotonicContext
+):
+    context: clmn.AssertMonotonicContext
+
+    def run(self, output_storage: Storage) -> api.Table:
+        input_storage = self.state.get_storage(self.context.input_universe())
+        column_paths = []
+        for column in output_storage.get_columns():
+            assert isinstance(column, clmn.ColumnWithReference)
+            path = input_storage.get_path(column.expression._column)
+            column_paths.append(path)
+        sequence_path = input_storage.get_path(self.context.sequence)
+        instance_path = (
+            input_storage.get_path(self.context.instance)
+            if self.context.instance is not None
+            else None
+        )
+        properties = self._table_properties(output_storage)
+        return self.scope.assert_monotonic(
+            self.state.get_table(input_storage._universe),
+            column_paths,
+            sequence_path,
+            instance_path,
+            self.context.strict,
+            self.context.on_violation,
+            properties,
+        )
+
+
 class UnpackSnapshotsEvaluator(
     ExpressionEvaluator, context_type=clmn.UnpackSnapshotsContext
 ):
@@ -12,7 +12,12 @@ from abc import ABC, abstractmethod
 from typing import TYPE_CHECKING, ClassVar, Generic, TypeVar
 
 from pathway.internals import api, trace
-from pathway.internals.datasink import CallbackDataSink, ExportDataSink, GenericDataSink
+from pathway.internals.datasink import (
+    CallbackDataSink,
+    ExportDataSink,
+    GenericDataSink,
+    WebSocketDataSink,
+)
 from pathway.internals.datasource import (
     EmptyDataSource,
     ErrorLogDataSource,
@@ -81,7 +86,9 @@ class OperatorHandler(ABC, Generic[T]):
     ):
         with trace.custom_trace(operator.trace):
             self.scope.set_operator_properties(
-                self.operator_id, operator.depends_on_error_log
+                self.operator_id,
+                operator.depends_on_error_log,
+                self.storage_graph.stable_operator_ids.get(operator),
             )
             if operator.error_log and not self.scope_context.inside_iterate:
                 self.scope.set_error_log(self.state.get_error_log(operator.error_log))
@@ -189,6 +196,17 @@ class OutputOperatorHandler(
                 data_format=datasink.dataformat,
                 unique_name=datasink.unique_name,
                 sort_by_indices=datasink.sort_by_indices,
+                alignment_group=datasink.alignment_group,
+                metadata_columns=datasink.metadata_columns,
+                parallel_writers=datasink.parallel_writers,
+                partition_by_indices=datasink.partition_by_indices,
+                max_batch_size=datasink.max_batch_size,
+                linger_ms=datasink.linger_ms,
+                delivery_semantics=datasink.delivery_semantics,
+                output_mode=datasink.output_mode,
+                commit_group=datasink.commit_group,
+                queue_capacity=datasink.queue_capacity,
+                collapse_updates=datasink.collapse_updates,
             )
         elif isinstance(datasink, CallbackDataSink):
             self.scope.subscribe_table(
@@ -201,10 +219,24 @@ class OutputOperatorHandler(
                 skip_errors=datasink.skip_errors,
                 unique_name=datasink.unique_name,
                 sort_by_indices=datasink.sort_by_indices(table),
+                on_batch=datasink.on_batch,
+                max_batch_size=datasink.max_batch_size,
+            )
+        elif isinstance(datasink, WebSocketDataSink):
+            self.scope.subscribe_table_websocket(
+                table=engine_table,
+                column_paths=column_paths,
+                column_names=list(table._columns.keys()),
+                skip_persisted_batch=datasink.skip_persisted_batch,
+                skip_errors=datasink.skip_errors,
+                unique_name=datasink.unique_name,
+                sort_by_indices=datasink.sort_by_indices(table),
             )
         elif isinstance(datasink, ExportDataSink):
             exported_table = self.scope.export_table(
-                table=engine_table, column_paths=column_paths
+                table=engine_table,
+                column_paths=column_paths,
+                flight_endpoint=datasink.flight_endpoint,
             )
             datasink.callback(self.scope, exported_table)
         else:
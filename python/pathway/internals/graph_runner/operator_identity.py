@@ -0,0 +1,102 @@
+# Copyright © 2026 Pathway
+
+"""Identities of the operators that are stable across the edits of the graph.
+
+The engine names the persisted states of the operators after these identities, so that
+the state of an operator is found again after the program is changed, as long as the
+operator and its inputs are the same. An identity is a hash of the type of the operator,
+the unique name of its data source, if any, what the operator computes and the
+identities of its inputs. What an operator computes is given by the names and the types
+of the columns of its output tables, the expressions and the reducers computing them
+and the settings of the operation, such as the grouping columns or the filtering
+condition. Changing any of them gives the operator a new identity, so that a state
+computed differently is never restored. The operators with the same hash are told apart
+by the order they were created in.
+"""
+
+from __future__ import annotations
+
+import dataclasses
+import hashlib
+from collections import Counter
+from collections.abc import Iterable
+
+from pathway.internals.column import ColumnWithExpression
+from pathway.internals.expression import ColumnExpression, InternalColRef
+from pathway.internals.expression_printer import ExpressionFormatter
+from pathway.internals.operator import InputOperator, Operator
+
+
+def _label(operator: Operator) -> str:
+    label = operator.operator_type()
+    if isinstance(operator, InputOperator):
+        unique_name = operator.datasource.data_source_options.unique_name
+        if unique_name is not None:
+            label += f"[{unique_name}]"
+    return label
+
+
+def _expression_signature(
+    expression: ColumnExpression, formatter: ExpressionFormatter
+) -> str:
+    try:
+        return formatter.eval_expression(expression)
+    except (KeyError, AttributeError):
+        # an expression the formatter can't describe, only its type is hashed
+        return type(expression).__name__
+
+
+def _setting_signature(value: object, formatter: ExpressionFormatter) -> str | None:
+    if value is None or isinstance(value, (bool, int, float, str)):
+        return repr(value)
+    if isinstance(value, InternalColRef):
+        return _expression_signature(value.to_column_expression(), formatter)
+    if isinstance(value, ColumnWithExpression):
+        return _expression_signature(value.expression, formatter)
+    if isinstance(value, tuple):
+        items = (_setting_signature(item, formatter) for item in value)
+        return "(" + ", ".join(str(item) for item in items) + ")"
+    # the tables, the universes and the nested contexts are described by the inputs
+    return None
+
+
+def _signature(operator: Operator) -> str:
+    # the tables are numbered in the order they appear in the operator, so the numbers
+    # don't depend on the rest of the graph
+    formatter = ExpressionFormatter()
+    parts = [_label(operator)]
+    for output in operator.outputs:
+        table = output.value
+        context = table._id_column.context
+        parts.append(f"{output.name}:{type(context).__name__}")
+        if dataclasses.is_dataclass(context):
+            for field in dataclasses.fields(context):
+                setting = _setting_signature(getattr(context, field.name), formatter)
+                if setting is not None:
+                    parts.append(f"{field.name}={setting}")
+        for name, column in table._columns.items():
+            column_signature = f"{name}: {column.dtype}"
+            if isinstance(column, ColumnWithExpression):
+                expression = _expression_signature(column.expression, formatter)
+                column_signature += f" = {expression}"
+            parts.append(column_signature)
+    return "\n".join(parts)
+
+
+def stable_operator_ids(operators: Iterable[Operator]) -> dict[Operator, str]:
+    """Computes the identities of ``operators``, given in the order they were created.
+
+    The inputs outside of ``operators`` contribute to the identities as unknown ones.
+    """
+    ids: dict[Operator, str] = {}
+    occurrences: Counter[str] = Counter()
+    for operator in operators:
+        digest = hashlib.blake2b(digest_size=8)
+        digest.update(_signature(operator).encode())
+        for dependency in operator.input_operators():
+            digest.update(b"\0")
+            digest.update(ids.get(dependency, "").encode())
+        base_id = digest.hexdigest()
+        ids[operator] = f"{base_id}.{occurrences[base_id]}"
+        occurrences[base_id] += 1
+    return ids
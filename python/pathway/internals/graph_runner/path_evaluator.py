@@ -99,6 +99,8 @@ class FlatStoragePathEvaluator(
         clmn.GroupedContext,
         clmn.FilterOutValueContext,
         clmn.AssertAppendOnlyContext,
+        clmn.AssertMonotonicContext,
+        clmn.VersionRowsContext,
     ],
 ):
     def compute(
@@ -300,6 +302,8 @@ NoNewColumnsContext = (
     | clmn.FilterOutForgettingContext
     | clmn.FreezeContext
     | clmn.BufferContext
+    | clmn.LateEntriesContext
+    | clmn.RetainLastVersionsContext
     | clmn.SetSchemaContext
     | clmn.RemoveRetractionsContext
     | clmn.StreamToTableContext
@@ -317,6 +321,8 @@ class NoNewColumnsPathEvaluator(
         clmn.FilterOutForgettingContext,
         clmn.FreezeContext,
         clmn.BufferContext,
+        clmn.LateEntriesContext,
+        clmn.RetainLastVersionsContext,
         clmn.SetSchemaContext,
         clmn.RemoveRetractionsContext,
         clmn.StreamToTableContext,
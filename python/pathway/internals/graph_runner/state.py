@@ -20,6 +20,7 @@ class ScopeState:
     tables: dict[universe.Universe, api.Table]
     storages: dict[universe.Universe, Storage]
     error_logs: dict[table.Table, api.ErrorLog]
+    late_tables: dict[column.Context, api.Table]
 
     def __init__(self, scope: api.Scope) -> None:
         self.scope = scope
@@ -30,6 +31,7 @@ class ScopeState:
         self.tables = {}
         self.storages = {}
         self.error_logs = {}
+        self.late_tables = {}
 
     def extract_universe(self, univ: universe.Universe) -> api.Universe:
         engine_table = self.get_table(univ)
@@ -149,6 +151,14 @@ class ScopeState:
         self.tables[storage._universe] = table
         self.storages[storage._universe] = storage
 
+    def set_late_table(self, context: column.Context, table: api.Table) -> None:
+        self.late_tables[context] = table
+
+    def get_late_table(self, context: column.Context) -> api.Table:
+        if context not in self.late_tables:
+            raise OutOfScopeError("late table out of scope")
+        return self.late_tables[context]
+
     def get_storage(self, key: universe.Universe) -> Storage:
         if key not in self.storages:
             raise OutOfScopeError("path storage out of scope")
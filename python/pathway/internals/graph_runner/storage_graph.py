@@ -12,6 +12,7 @@ from pathway.internals import api
 from pathway.internals.column import Column, IdColumn, MaterializedColumn
 from pathway.internals.column_path import ColumnPath
 from pathway.internals.graph_runner import path_evaluator
+from pathway.internals.graph_runner.operator_identity import stable_operator_ids
 from pathway.internals.graph_runner.path_storage import Storage
 from pathway.internals.graph_runner.scope_context import ScopeContext
 from pathway.internals.graph_runner.state import ScopeState
@@ -40,6 +41,7 @@ class OperatorStorageGraph:
     )
     final_storages: dict[Universe, Storage] | None = None
     table_to_storage: dict[Table, Storage] = field(default_factory=dict)
+    stable_operator_ids: dict[Operator, str] = field(default_factory=dict)
 
     def get_iterate_subgraph(self, operator: Operator) -> OperatorStorageGraph:
         return self.iterate_subgraphs[operator]
@@ -86,6 +88,7 @@ class OperatorStorageGraph:
             scope_context=scope_context,
             iterate_subgraphs=iterate_subgraphs,
             is_outer_graph=is_outer_graph,
+            stable_operator_ids=stable_operator_ids(scope_context.nodes),
         )
         return graph
 
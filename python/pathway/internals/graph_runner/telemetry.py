@@ -70,6 +70,7 @@ class Telemetry:
                 SERVICE_NAMESPACE: self.config.service_namespace or "",
                 SERVICE_INSTANCE_ID: self.config.service_instance_id or "",
                 "run.id": self.config.run_id,
+                "graph.version": self.config.graph_version or "",
                 "python.version": sys.version,
                 "license.key": self.config.license_key or "",
             }
@@ -89,6 +90,7 @@ class Telemetry:
     ) -> Telemetry:
         config = api.TelemetryConfig.create(
             run_id=run_id,
+            graph_version=graph.sig(),
             license_key=license_key,
             monitoring_server=monitoring_server,
             detailed_metrics_dir=detailed_metrics_dir,
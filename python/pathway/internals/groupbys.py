@@ -91,6 +91,7 @@ class GroupedTable(GroupedJoinable, OperatorInput):
     _joinable_to_group: table.Table
     _set_id: bool
     _sort_by: expr.InternalColRef | None
+    _shard_column: int | None
     _filter_out_results_of_forgetting: bool
     _skip_errors: bool
     _is_window: bool
@@ -102,6 +103,7 @@ class GroupedTable(GroupedJoinable, OperatorInput):
         _last_column_is_instance: bool,
         _set_id: bool = False,
         _sort_by: expr.InternalColRef | None = None,
+        _shard_column: int | None = None,
         _filter_out_results_of_forgetting: bool = False,
         _skip_errors: bool = True,
         _is_window: bool = False,
@@ -111,6 +113,7 @@ class GroupedTable(GroupedJoinable, OperatorInput):
         self._last_column_is_instance = _last_column_is_instance
         self._set_id = _set_id
         self._sort_by = _sort_by
+        self._shard_column = _shard_column
         self._filter_out_results_of_forgetting = _filter_out_results_of_forgetting
         self._skip_errors = _skip_errors
         self._is_window = _is_window
@@ -123,6 +126,7 @@ class GroupedTable(GroupedJoinable, OperatorInput):
         last_column_is_instance: bool,
         set_id: bool = False,
         sort_by: expr.ColumnReference | None = None,
+        shard_column: int | None = None,
         _filter_out_results_of_forgetting: bool = False,
         _skip_errors: bool = True,
         _is_window: bool = False,
@@ -131,7 +135,7 @@ class GroupedTable(GroupedJoinable, OperatorInput):
         col_sort_by = (
             sort_by._to_original()._to_internal() if sort_by is not None else None
         )
-        key = (cls.__name__, table, cols, set_id, col_sort_by)
+        key = (cls.__name__, table, cols, set_id, col_sort_by, shard_column)
         if key not in G.cache:
             result = GroupedTable(
                 _table=table,
@@ -139,6 +143,7 @@ class GroupedTable(GroupedJoinable, OperatorInput):
                 _last_column_is_instance=last_column_is_instance,
                 _set_id=set_id,
                 _sort_by=col_sort_by,
+                _shard_column=shard_column,
                 _filter_out_results_of_forgetting=_filter_out_results_of_forgetting,
                 _skip_errors=_skip_errors,
                 _is_window=_is_window,
@@ -231,6 +236,7 @@ class GroupedTable(GroupedJoinable, OperatorInput):
             inner_context=self._joinable_to_group._rowwise_context,
             sort_by=self._sort_by,
             skip_errors=self._skip_errors,
+            shard_column=self._shard_column,
         )
 
         for column_name, value in kwargs.items():
@@ -142,6 +142,7 @@ class Joinable(TableLike, DesugaringContext):
         right_instance: expr.ColumnReference | None = None,
         left_exactly_once: bool = False,
         right_exactly_once: bool = False,
+        shard_by: expr.ColumnReference | None = None,
     ) -> JoinResult:
         """Join self with other using the given join expression.
 
@@ -162,6 +163,9 @@ class Joinable(TableLike, DesugaringContext):
               joined at most once, then you can set this parameter to ``True``. Then each row after
               getting a match is removed from the join state. As a result, less memory is needed.
               Works only for append-only tables.
+            shard_by: optional column of one of the join conditions or of the instance, whose
+              value decides on the worker joining the rows. The rows with the same value are
+              joined by the same worker, so that e.g. the data of a tenant is kept together.
 
         Returns:
             JoinResult: an object on which `.select()` may be called to extract relevant
@@ -199,6 +203,7 @@ class Joinable(TableLike, DesugaringContext):
             right_instance=right_instance,
             left_exactly_once=left_exactly_once,
             right_exactly_once=right_exactly_once,
+            shard_by=shard_by,
         )
 
     @trace_user_frame
@@ -213,6 +218,7 @@ class Joinable(TableLike, DesugaringContext):
         right_instance: expr.ColumnReference | None = None,
         left_exactly_once: bool = False,
         right_exactly_once: bool = False,
+        shard_by: expr.ColumnReference | None = None,
     ) -> JoinResult:
         """Inner-joins two tables or join results.
 
@@ -231,6 +237,9 @@ class Joinable(TableLike, DesugaringContext):
               joined at most once, then you can set this parameter to ``True``. Then each row after
               getting a match is removed from the join state. As a result, less memory is needed.
               Works only for append-only tables.
+            shard_by: optional column of one of the join conditions or of the instance, whose
+              value decides on the worker joining the rows. The rows with the same value are
+              joined by the same worker, so that e.g. the data of a tenant is kept together.
 
         Returns:
             JoinResult: an object on which `.select()` may be called to extract relevant
@@ -268,6 +277,7 @@ class Joinable(TableLike, DesugaringContext):
             right_instance=right_instance,
             left_exactly_once=left_exactly_once,
             right_exactly_once=right_exactly_once,
+            shard_by=shard_by,
         )
 
     @trace_user_frame
@@ -282,6 +292,7 @@ class Joinable(TableLike, DesugaringContext):
         right_instance: expr.ColumnReference | None = None,
         left_exactly_once: bool = False,
         right_exactly_once: bool = False,
+        shard_by: expr.ColumnReference | None = None,
     ) -> JoinResult:
         """
         Left-joins two tables or join results.
@@ -300,6 +311,9 @@ class Joinable(TableLike, DesugaringContext):
               joined at most once, then you can set this parameter to ``True``. Then each row after
               getting a match is removed from the join state. As a result, less memory is needed.
               Works only for append-only tables.
+            shard_by: optional column of one of the join conditions or of the instance, whose
+              value decides on the worker joining the rows. The rows with the same value are
+              joined by the same worker, so that e.g. the data of a tenant is kept together.
 
         Remarks:
         args cannot contain id column from either of tables, \
@@ -357,6 +371,7 @@ class Joinable(TableLike, DesugaringContext):
             right_instance=right_instance,
             left_exactly_once=left_exactly_once,
             right_exactly_once=right_exactly_once,
+            shard_by=shard_by,
         )
 
     @trace_user_frame
@@ -371,6 +386,7 @@ class Joinable(TableLike, DesugaringContext):
         right_instance: expr.ColumnReference | None = None,
         left_exactly_once: bool = False,
         right_exactly_once: bool = False,
+        shard_by: expr.ColumnReference | None = None,
     ) -> JoinResult:
         """
         Outer-joins two tables or join results.
@@ -389,6 +405,9 @@ class Joinable(TableLike, DesugaringContext):
               joined at most once, then you can set this parameter to ``True``. Then each row after
               getting a match is removed from the join state. As a result, less memory is needed.
               Works only for append-only tables.
+            shard_by: optional column of one of the join conditions or of the instance, whose
+              value decides on the worker joining the rows. The rows with the same value are
+              joined by the same worker, so that e.g. the data of a tenant is kept together.
 
         Remarks: args cannot contain id column from either of tables, \
         as the result table has id column with auto-generated ids; \
@@ -449,6 +468,7 @@ class Joinable(TableLike, DesugaringContext):
             right_instance=right_instance,
             left_exactly_once=left_exactly_once,
             right_exactly_once=right_exactly_once,
+            shard_by=shard_by,
         )
 
     @trace_user_frame
@@ -463,6 +483,7 @@ class Joinable(TableLike, DesugaringContext):
         right_instance: expr.ColumnReference | None = None,
         left_exactly_once: bool = False,
         right_exactly_once: bool = False,
+        shard_by: expr.ColumnReference | None = None,
     ) -> JoinResult:
         """Outer-joins two tables or join results.
 
@@ -479,6 +500,9 @@ class Joinable(TableLike, DesugaringContext):
               joined at most once, then you can set this parameter to ``True``. Then each row after
               getting a match is removed from the join state. As a result, less memory is needed.
               Works only for append-only tables.
+            shard_by: optional column of one of the join conditions or of the instance, whose
+              value decides on the worker joining the rows. The rows with the same value are
+              joined by the same worker, so that e.g. the data of a tenant is kept together.
 
         Remarks: args cannot contain id column from either of tables, \
             as the result table has id column with auto-generated ids; \
@@ -538,6 +562,7 @@ class Joinable(TableLike, DesugaringContext):
             right_instance=right_instance,
             left_exactly_once=left_exactly_once,
             right_exactly_once=right_exactly_once,
+            shard_by=shard_by,
         )
 
     @property
@@ -1004,6 +1029,7 @@ class JoinResult(Joinable, OperatorInput):
         exact_match: bool = False,  # if True do not optionalize output columns even if other than inner join is used
         left_exactly_once: bool = False,
         right_exactly_once: bool = False,
+        shard_by: expr.ColumnReference | None = None,
     ) -> JoinResult:
         if left == right:
             raise ValueError(
@@ -1044,6 +1070,24 @@ class JoinResult(Joinable, OperatorInput):
         for cond in on_:
             validate_join_condition(cond, left_table, right_table)
 
+        shard_column = None
+        if shard_by is not None:
+            shard_by = chained_join_desugaring.eval_expression(shard_by)
+            shard_column = next(
+                (
+                    i
+                    for i, cond in enumerate(on_)
+                    if shard_by._column
+                    in (cond._left._column, cond._right._column)  # type: ignore
+                ),
+                None,
+            )
+            if shard_column is None:
+                raise ValueError(
+                    "Table.join() shard_by argument has to be a column of one of the join"
+                    + " conditions or the instance."
+                )
+
         on_left = tuple(
             left_table._eval(cond._left, left_table._table_restricted_context)
             for cond in on_
@@ -1085,6 +1129,7 @@ class JoinResult(Joinable, OperatorInput):
                 exact_match=exact_match,
                 left_exactly_once=left_exactly_once,
                 right_exactly_once=right_exactly_once,
+                shard_column=shard_column,
             )
         else:
             context = clmn.JoinContext(
@@ -1100,6 +1145,7 @@ class JoinResult(Joinable, OperatorInput):
                 exact_match=exact_match,
                 left_exactly_once=left_exactly_once,
                 right_exactly_once=right_exactly_once,
+                shard_column=shard_column,
             )
         inner_table, columns_mapping = JoinResult._prepare_inner_table_with_mapping(
             context,
@@ -1178,6 +1224,7 @@ def join(
     right_instance: expr.ColumnReference | None = None,
     left_exactly_once: bool = False,
     right_exactly_once: bool = False,
+    shard_by: expr.ColumnReference | None = None,
 ) -> JoinResult:
     """Join self with other using the given join expression.
 
@@ -1199,6 +1246,9 @@ def join(
             joined at most once, then you can set this parameter to ``True``. Then each row after
             getting a match is removed from the join state. As a result, less memory is needed.
             Works only for append-only tables.
+        shard_by: optional column of one of the join conditions or of the instance, whose
+            value decides on the worker joining the rows. The rows with the same value are
+            joined by the same worker, so that e.g. the data of a tenant is kept together.
 
     Returns:
         JoinResult: an object on which `.select()` may be called to extract relevant
@@ -1235,6 +1285,7 @@ def join(
         right_instance=right_instance,
         left_exactly_once=left_exactly_once,
         right_exactly_once=right_exactly_once,
+        shard_by=shard_by,
     )
 
 
@@ -1247,6 +1298,7 @@ def join_inner(
     right_instance: expr.ColumnReference | None = None,
     left_exactly_once: bool = False,
     right_exactly_once: bool = False,
+    shard_by: expr.ColumnReference | None = None,
 ) -> JoinResult:
     """Inner-joins two tables or join results.
 
@@ -1266,6 +1318,9 @@ def join_inner(
             joined at most once, then you can set this parameter to ``True``. Then each row after
             getting a match is removed from the join state. As a result, less memory is needed.
             Works only for append-only tables.
+        shard_by: optional column of one of the join conditions or of the instance, whose
+            value decides on the worker joining the rows. The rows with the same value are
+            joined by the same worker, so that e.g. the data of a tenant is kept together.
 
     Returns:
         JoinResult: an object on which `.select()` may be called to extract relevant
@@ -1301,6 +1356,7 @@ def join_inner(
         right_instance=right_instance,
         left_exactly_once=left_exactly_once,
         right_exactly_once=right_exactly_once,
+        shard_by=shard_by,
     )
 
 
@@ -1313,6 +1369,7 @@ def join_left(
     right_instance: expr.ColumnReference | None = None,
     left_exactly_once: bool = False,
     right_exactly_once: bool = False,
+    shard_by: expr.ColumnReference | None = None,
 ) -> JoinResult:
     """
     Left-joins two tables or join results.
@@ -1332,6 +1389,9 @@ def join_left(
             joined at most once, then you can set this parameter to ``True``. Then each row after
             getting a match is removed from the join state. As a result, less memory is needed.
             Works only for append-only tables.
+        shard_by: optional column of one of the join conditions or of the instance, whose
+            value decides on the worker joining the rows. The rows with the same value are
+            joined by the same worker, so that e.g. the data of a tenant is kept together.
 
     Remarks:
     args cannot contain id column from either of tables, \
@@ -1387,6 +1447,7 @@ def join_left(
         right_instance=right_instance,
         left_exactly_once=left_exactly_once,
         right_exactly_once=right_exactly_once,
+        shard_by=shard_by,
     )
 
 
@@ -1399,6 +1460,7 @@ def join_right(
     right_instance: expr.ColumnReference | None = None,
     left_exactly_once: bool = False,
     right_exactly_once: bool = False,
+    shard_by: expr.ColumnReference | None = None,
 ) -> JoinResult:
     """
     Outer-joins two tables or join results.
@@ -1418,6 +1480,9 @@ def join_right(
             joined at most once, then you can set this parameter to ``True``. Then each row after
             getting a match is removed from the join state. As a result, less memory is needed.
             Works only for append-only tables.
+        shard_by: optional column of one of the join conditions or of the instance, whose
+            value decides on the worker joining the rows. The rows with the same value are
+            joined by the same worker, so that e.g. the data of a tenant is kept together.
 
     Remarks: args cannot contain id column from either of tables, \
     as the result table has id column with auto-generated ids; \
@@ -1476,6 +1541,7 @@ def join_right(
         right_instance=right_instance,
         left_exactly_once=left_exactly_once,
         right_exactly_once=right_exactly_once,
+        shard_by=shard_by,
     )
 
 
@@ -1488,6 +1554,7 @@ def join_outer(
     right_instance: expr.ColumnReference | None = None,
     left_exactly_once: bool = False,
     right_exactly_once: bool = False,
+    shard_by: expr.ColumnReference | None = None,
 ) -> JoinResult:
     """Outer-joins two tables or join results.
 
@@ -1506,6 +1573,9 @@ def join_outer(
             joined at most once, then you can set this parameter to ``True``. Then each row after
             getting a match is removed from the join state. As a result, less memory is needed.
             Works only for append-only tables.
+        shard_by: optional column of one of the join conditions or of the instance, whose
+            value decides on the worker joining the rows. The rows with the same value are
+            joined by the same worker, so that e.g. the data of a tenant is kept together.
 
     Remarks: args cannot contain id column from either of tables, \
         as the result table has id column with auto-generated ids; \
@@ -1563,4 +1633,5 @@ def join_outer(
         right_instance=right_instance,
         left_exactly_once=left_exactly_once,
         right_exactly_once=right_exactly_once,
+        shard_by=shard_by,
     )
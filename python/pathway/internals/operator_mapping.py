@@ -147,6 +147,9 @@ _binary_operators_mapping: BinaryOperatorMapping = {
     (operator.sub, dt.DATE_TIME_UTC, dt.DATE_TIME_UTC): dt.DURATION,
     (operator.add, dt.DATE_TIME_UTC, dt.DURATION): dt.DATE_TIME_UTC,
     (operator.sub, dt.DATE_TIME_UTC, dt.DURATION): dt.DATE_TIME_UTC,
+    (operator.sub, dt.DATE_TIME_ZONED, dt.DATE_TIME_ZONED): dt.DURATION,
+    (operator.add, dt.DATE_TIME_ZONED, dt.DURATION): dt.DATE_TIME_ZONED,
+    (operator.sub, dt.DATE_TIME_ZONED, dt.DURATION): dt.DATE_TIME_ZONED,
     (operator.eq, dt.DURATION, dt.DURATION): dt.BOOL,
     (operator.ne, dt.DURATION, dt.DURATION): dt.BOOL,
     (operator.lt, dt.DURATION, dt.DURATION): dt.BOOL,
@@ -160,6 +163,7 @@ _binary_operators_mapping: BinaryOperatorMapping = {
     (operator.mod, dt.DURATION, dt.DURATION): dt.DURATION,
     (operator.add, dt.DURATION, dt.DATE_TIME_NAIVE): dt.DATE_TIME_NAIVE,
     (operator.add, dt.DURATION, dt.DATE_TIME_UTC): dt.DATE_TIME_UTC,
+    (operator.add, dt.DURATION, dt.DATE_TIME_ZONED): dt.DATE_TIME_ZONED,
     (operator.mul, dt.DURATION, dt.INT): dt.DURATION,
     (operator.mul, dt.INT, dt.DURATION): dt.DURATION,
     (operator.floordiv, dt.DURATION, dt.INT): dt.DURATION,
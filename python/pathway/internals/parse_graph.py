@@ -238,6 +238,7 @@ class ErrorLogSchema(Schema):
     operator_id: int
     message: str
     trace: str
+    run_id: str
 
 
 G = ParseGraph()
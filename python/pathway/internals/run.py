@@ -1,8 +1,10 @@
 # Copyright © 2026 Pathway
 
 import asyncio
+import json
+from typing import Any
 
-from pathway.internals import parse_graph
+from pathway.internals import api, parse_graph
 from pathway.internals.graph_runner import GraphRunner
 from pathway.internals.monitoring import MonitoringLevel
 from pathway.internals.runtime_type_check import check_arg_types
@@ -20,9 +22,12 @@ def run(
     runtime_typechecking: bool | None = None,
     terminate_on_error: bool | None = None,
     max_expression_batch_size: int = 1024,
+    deterministic_expression_cache_size: int = 0,
     event_loop: asyncio.AbstractEventLoop | None = None,
     udf_cache_directory: str | None = None,
-) -> None:
+    step_mode: str | None = None,
+    dry_run: bool = False,
+) -> dict[str, Any] | None:
     """Runs the computation graph.
 
     Args:
@@ -43,6 +48,13 @@ def run(
         max_expression_batch_size: the maximal number of rows for which the expressions
             are computed at once. You might want to decrease it if the intermediate state
             in one of your expressions is large.
+        deterministic_expression_cache_size: if positive, the results of deterministic
+            expressions, including deterministic UDFs, are memoized by their arguments
+            and reused for all the rows with the same arguments, whatever their keys.
+            At most that many distinct tuples of arguments are kept for each
+            ``select`` (and each worker), the least recently used ones being evicted.
+            Useful when expensive expressions, e.g. UDFs calling a geocoding service,
+            are computed over a small domain of values. Disabled by default.
         event_loop: an externally created event loop to use for the duration of the run.
             If not specified, a new event loop is created and closed automatically.
             When running the graph multiple times with async UDFs that use ``InMemoryCache``,
@@ -56,8 +68,33 @@ def run(
             that on many systems ``/tmp`` is a RAM-backed ``tmpfs`` — point this at a
             real disk to actually save memory. If ``None`` (default), the cache is kept
             in memory.
+        step_mode: if set, the computation is executed step by step: it pauses each
+            time all the changes up to some time are passed to the outputs and waits
+            for a command before processing the next commit. With ``"stdin"``, the
+            commands are read from the standard input: an empty line or
+            ``step [n]`` processes ``n`` more commits (one by default), while
+            ``continue`` (or closing the standard input) leaves the step mode. With
+            ``"http"``, they are sent as ``POST /step?count=n`` and
+            ``POST /continue`` requests to a server listening on ``localhost``, at
+            the port given by the ``PATHWAY_STEP_HTTP_PORT`` environment variable
+            (20100 by default) plus the process id; ``GET /status`` returns the
+            current state. Combined with
+            ``debug=True``, the changes of the tables marked with ``table.debug()`` are
+            printed before each pause. Meant for debugging only.
+        dry_run: if set, the computation isn't run. The dataflow is built, which
+            validates the pipeline as in a regular run, and the connectors are set up,
+            which checks their configuration, but no data is read or written. Instead,
+            a report is returned, of the form
+            ``{"ok": ..., "connectors": [...], "operators": [...]}``, with an entry
+            for each connector giving its ``direction`` (``"input"`` or
+            ``"output"``), its ``name``, the ``worker_index`` of the worker setting it
+            up and the ``error`` raised while setting it up, ``None`` on success.
+            The ``operators`` are described in :py:func:`explain`.
+
+    Returns:
+        The report of the dry run if ``dry_run`` is set, ``None`` otherwise.
     """
-    GraphRunner(
+    runner = GraphRunner(
         parse_graph.G,
         debug=debug,
         monitoring_level=monitoring_level,
@@ -67,10 +104,17 @@ def run(
         runtime_typechecking=runtime_typechecking,
         terminate_on_error=terminate_on_error,
         max_expression_batch_size=max_expression_batch_size,
+        deterministic_expression_cache_size=deterministic_expression_cache_size,
         event_loop=event_loop,
         udf_cache_directory=udf_cache_directory,
+        step_mode=step_mode,
+        dry_run=dry_run,
         _stacklevel=4,
-    ).run_outputs()
+    )
+    runner.run_outputs()
+    if runner.dry_run_report is not None:
+        return json.loads(runner.dry_run_report.to_json())
+    return None
 
 
 @check_arg_types
@@ -84,9 +128,12 @@ def run_all(
     runtime_typechecking: bool | None = None,
     terminate_on_error: bool | None = None,
     max_expression_batch_size: int = 1024,
+    deterministic_expression_cache_size: int = 0,
     event_loop: asyncio.AbstractEventLoop | None = None,
     udf_cache_directory: str | None = None,
-) -> None:
+    step_mode: str | None = None,
+    dry_run: bool = False,
+) -> dict[str, Any] | None:
     """Runs the computation graph with disabled tree-shaking optimization.
 
     Args:
@@ -107,6 +154,13 @@ def run_all(
         max_expression_batch_size: the maximal number of rows for which the expressions
             are computed at once. You might want to decrease it if the intermediate state
             in one of your expressions is large.
+        deterministic_expression_cache_size: if positive, the results of deterministic
+            expressions, including deterministic UDFs, are memoized by their arguments
+            and reused for all the rows with the same arguments, whatever their keys.
+            At most that many distinct tuples of arguments are kept for each
+            ``select`` (and each worker), the least recently used ones being evicted.
+            Useful when expensive expressions, e.g. UDFs calling a geocoding service,
+            are computed over a small domain of values. Disabled by default.
         event_loop: an externally created event loop to use for the duration of the run.
             If not specified, a new event loop is created and closed automatically.
             When running the graph multiple times with async UDFs that use ``InMemoryCache``,
@@ -120,8 +174,33 @@ def run_all(
             that on many systems ``/tmp`` is a RAM-backed ``tmpfs`` — point this at a
             real disk to actually save memory. If ``None`` (default), the cache is kept
             in memory.
+        step_mode: if set, the computation is executed step by step: it pauses each
+            time all the changes up to some time are passed to the outputs and waits
+            for a command before processing the next commit. With ``"stdin"``, the
+            commands are read from the standard input: an empty line or
+            ``step [n]`` processes ``n`` more commits (one by default), while
+            ``continue`` (or closing the standard input) leaves the step mode. With
+            ``"http"``, they are sent as ``POST /step?count=n`` and
+            ``POST /continue`` requests to a server listening on ``localhost``, at
+            the port given by the ``PATHWAY_STEP_HTTP_PORT`` environment variable
+            (20100 by default) plus the process id; ``GET /status`` returns the
+            current state. Combined with
+            ``debug=True``, the changes of the tables marked with ``table.debug()`` are
+            printed before each pause. Meant for debugging only.
+        dry_run: if set, the computation isn't run. The dataflow is built, which
+            validates the pipeline as in a regular run, and the connectors are set up,
+            which checks their configuration, but no data is read or written. Instead,
+            a report is returned, of the form
+            ``{"ok": ..., "connectors": [...], "operators": [...]}``, with an entry
+            for each connector giving its ``direction`` (``"input"`` or
+            ``"output"``), its ``name``, the ``worker_index`` of the worker setting it
+            up and the ``error`` raised while setting it up, ``None`` on success.
+            The ``operators`` are described in :py:func:`explain`.
+
+    Returns:
+        The report of the dry run if ``dry_run`` is set, ``None`` otherwise.
     """
-    GraphRunner(
+    runner = GraphRunner(
         parse_graph.G,
         debug=debug,
         monitoring_level=monitoring_level,
@@ -131,7 +210,110 @@ def run_all(
         runtime_typechecking=runtime_typechecking,
         terminate_on_error=terminate_on_error,
         max_expression_batch_size=max_expression_batch_size,
+        deterministic_expression_cache_size=deterministic_expression_cache_size,
         event_loop=event_loop,
         udf_cache_directory=udf_cache_directory,
+        step_mode=step_mode,
+        dry_run=dry_run,
         _stacklevel=4,
-    ).run_all()
+    )
+    runner.run_all()
+    if runner.dry_run_report is not None:
+        return json.loads(runner.dry_run_report.to_json())
+    return None
+
+
+@check_arg_types
+def explain(
+    *,
+    persistence_config: PersistenceConfig | None = None,
+    runtime_typechecking: bool | None = None,
+) -> list[dict[str, Any]]:
+    """Explains the cost of running the computation graph, without running it.
+
+    The dataflow is built as in a dry run of :py:func:`run` (see its ``dry_run``
+    argument), but the connectors aren't set up: no reader or writer is created, so
+    nothing connects to the sources or the destinations, and no data is read or
+    written. For each operator of the graph, the result tells:
+
+    - ``exchange``: whether the operator induces a data exchange, that is, its input
+      is shuffled between the workers, possibly over the network;
+    - ``arrangement``: whether the operator keeps an indexed copy of its input in
+      memory, which grows with the size of the input;
+    - ``persisted``: whether the state of the operator is persisted, which depends on
+      ``persistence_config``.
+
+    Args:
+        persistence_config: the config for persisting the state that would be used
+            when running the computation.
+        runtime_typechecking: enables additional strict type checking at runtime
+
+    Returns:
+        A list with an entry for each operator, ordered by ``operator_id``, of the form
+        ``{"operator_id": ..., "operator": ..., "trace": ..., "exchange": ...,
+        "arrangement": ..., "persisted": ...}``, where ``operator`` is the name of the
+        operator and ``trace`` is the place in the user code where it was created,
+        ``None`` if unknown.
+
+    Example:
+
+    >>> import pathway as pw
+    >>> t = pw.debug.table_from_markdown('''
+    ... owner | pet
+    ... Alice | dog
+    ... Bob   | cat
+    ... Alice | cat
+    ... ''')
+    >>> counts = t.groupby(pw.this.owner).reduce(pw.this.owner, n=pw.reducers.count())
+    >>> pw.io.null.write(counts)
+    >>> [entry["operator"] for entry in pw.explain() if entry["arrangement"]]
+    ['_reduce']
+    """
+    runner = GraphRunner(
+        parse_graph.G,
+        monitoring_level=MonitoringLevel.NONE,
+        persistence_config=persistence_config,
+        runtime_typechecking=runtime_typechecking,
+        dry_run=True,
+        check_connectors=False,
+        _stacklevel=4,
+    )
+    runner.run_outputs()
+    assert runner.dry_run_report is not None
+    report = json.loads(runner.dry_run_report.to_json())
+    operators = {
+        operator.id: operator
+        for scope in parse_graph.G.scopes
+        for operator in scope.nodes
+    }
+    plan = []
+    for entry in report["operators"]:
+        operator = operators[entry["operator_id"]]
+        user_frame = operator.trace.user_frame
+        trace = (
+            None
+            if user_frame is None
+            else f"{user_frame.filename}:{user_frame.line_number}"
+        )
+        plan.append({**entry, "operator": operator.label(), "trace": trace})
+    return plan
+
+
+def request_shutdown() -> None:
+    """Requests a graceful shutdown of the computation running in this process.
+
+    The input connectors commit the rows they have read and stop reading, as if their
+    sources had ended. The computation keeps running until everything that has been
+    read reaches all the outputs, and, with persistence enabled, commits its state one
+    last time, so that a computation restarted later continues right where this one
+    has stopped. Then ``pw.run`` returns. The function returns right away and has to
+    be called from another thread than the one running ``pw.run``.
+
+    The same happens when the process running ``pw.run`` in its main thread receives
+    ``SIGTERM``. Sending ``SIGTERM`` again terminates the process right away. In a
+    multi-process run, each process has to be asked.
+
+    Raises:
+        RuntimeError: if no computation is running.
+    """
+    api.request_graceful_shutdown()
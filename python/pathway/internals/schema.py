@@ -30,6 +30,7 @@ if TYPE_CHECKING:
 
 KEY_SOURCE_COMPONENT = "key"
 PAYLOAD_SOURCE_COMPONENT = "payload"
+HEADERS_SOURCE_COMPONENT = "headers"
 
 
 def _cls_fields(cls):
@@ -730,6 +731,8 @@ class ColumnSchema:
             return api.FieldSource.PAYLOAD
         elif self.source_component == KEY_SOURCE_COMPONENT:
             return api.FieldSource.KEY
+        elif self.source_component == HEADERS_SOURCE_COMPONENT:
+            return api.FieldSource.HEADERS
         else:
             raise ValueError(f"Unknown source component: {self.source_component}")
 
@@ -762,7 +765,7 @@ def column_definition(
     append_only: bool | None = None,
     description: str | None = None,
     example: Any = None,
-    source_component: Literal["key", "payload"] = "payload",
+    source_component: Literal["key", "payload", "headers"] = "payload",
     _serialized_default_value: Any | None = None,
     _serialized_example: Any | None = None,
 ) -> Any:  # Return any so that mypy does not complain
@@ -785,7 +788,9 @@ def column_definition(
             OpenAPI schema generation.
         source_component: the part of the input message from which the value should be
             parsed when JSON format is used. In Kafka, this can be used to specify that
-            certain fields must be parsed from the message key.
+            certain fields must be parsed from the message key. With ``"headers"``,
+            the column holds the headers of the Kafka message, as a JSON object mapping
+            the header names to their values.
 
     Returns:
         Column definition.
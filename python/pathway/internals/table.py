@@ -5,7 +5,7 @@ from __future__ import annotations
 import functools
 import warnings
 from collections.abc import Callable, Mapping
-from typing import TYPE_CHECKING, Any, Generic, TypeVar, cast, overload
+from typing import TYPE_CHECKING, Any, Generic, Literal, TypeVar, cast, overload
 
 import pathway.internals.column as clmn
 import pathway.internals.expression as expr
@@ -83,6 +83,7 @@ class Table(
             asof_now_join,
             asof_now_join_inner,
             asof_now_join_left,
+            assert_references,
             inactivity_detection,
             interval_join,
             interval_join_inner,
@@ -776,6 +777,178 @@ id_type=<class 'pathway.engine.Pointer'>>
         )
         return self._table_with_context(context)
 
+    @trace_user_frame
+    @desugar
+    def with_retention(
+        self,
+        time_column: expr.ColumnReference,
+        *,
+        max_age: IntervalType | None = None,
+        max_versions: int | None = None,
+        instance: expr.ColumnReference | None = None,
+    ) -> Table[TSchema]:
+        """Keeps only the recent entries of the table, according to a retention policy.
+        The older entries are removed from the table and from the stateful operators
+        downstream, as with ``forget``, so that their state doesn't grow indefinitely.
+
+        Args:
+            time_column: The column with the event time.
+            max_age: If set, the entries are removed once they satisfy
+                ``time_column <= max(time_column) - max_age``. Should match the type of
+                the ``time_column`` (``int -> int``, ``float -> float``,
+                ``datetime -> timedelta``).
+            max_versions: If set, only the ``max_versions`` entries with the greatest
+                ``time_column`` are kept in each instance. A removed entry isn't
+                restored if a newer one is deleted later.
+            instance: The column with the instances, for example the keys of the
+                versioned objects. The policy is then applied to each instance
+                separately: ``max(time_column)`` is computed per instance as well.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> t = pw.debug.table_from_markdown(
+        ...     '''
+        ...     k | t | __time__
+        ...     a | 1 |     2
+        ...     a | 2 |     4
+        ...     b | 1 |     4
+        ...     a | 3 |     6
+        ... '''
+        ... )
+        >>> res = t.with_retention(pw.this.t, max_versions=2, instance=pw.this.k)
+        >>> pw.debug.compute_and_print(res, include_id=False)
+        k | t
+        a | 2
+        a | 3
+        b | 1
+        """
+        if max_age is None and max_versions is None:
+            raise ValueError("Either max_age or max_versions has to be set")
+        if max_versions is not None and max_versions <= 0:
+            raise ValueError(f"max_versions must be positive, got {max_versions}")
+
+        # each operator gets the columns of its own input table
+        result = self
+        if max_versions is not None:
+            result = result._retain_last_versions(
+                result[time_column.name],
+                max_versions,
+                instance_column=(
+                    result[instance.name] if instance is not None else None
+                ),
+            )
+        if max_age is not None:
+            result = result._forget(
+                result[time_column.name] + max_age,
+                result[time_column.name],
+                mark_forgetting_records=False,
+                instance_column=(
+                    result[instance.name] if instance is not None else None
+                ),
+            )
+        return result
+
+    @trace_user_frame
+    @desugar
+    @check_arg_types
+    @contextualized_operator
+    def _retain_last_versions(
+        self,
+        time_column: expr.ColumnExpression,
+        max_versions: int,
+        instance_column: expr.ColumnExpression | None = None,
+    ) -> Table[TSchema]:
+        if instance_column is None:
+            instance_column = expr.ColumnConstExpression(None)
+        context = clmn.RetainLastVersionsContext(
+            self._id_column,
+            self._eval(time_column),
+            self._eval(instance_column),
+            max_versions,
+        )
+        return self._table_with_context(context)
+
+    @trace_user_frame
+    @desugar
+    def forget_with_late(
+        self,
+        time_column: expr.ColumnExpression,
+        threshold: IntervalType,
+        mark_forgetting_records: bool = False,
+    ) -> tuple[Table[TSchema], Table[TSchema]]:
+        """Like ``forget``, but the entries that are late already when they arrive, that
+        is, that satisfy ``time_column <= max(time_column) - threshold``, are returned
+        in a separate table instead of being passed through and forgotten right away.
+
+        The late entries can then be audited or processed in a different way than the
+        other ones.
+
+        Args:
+            time_column: ``ColumnExpression`` that specifies the event time.
+            threshold: value used to determine which entries are old enough to be
+                removed. Should match the type of the ``time_column``
+                (``int -> int``, ``float -> float``, ``datetime -> timedelta``).
+            mark_forgetting_records: See ``forget``.
+
+        Returns:
+            on_time, late: tuple of tables, with the same schemas as ``self``.
+            The first one is the result of ``forget`` without the late entries,
+            the second one contains the late entries.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> t = pw.debug.table_from_markdown(
+        ...     '''
+        ...     t | v | __time__
+        ...     1 | 1 |     2
+        ...     2 | 2 |     4
+        ...     5 | 3 |     6
+        ...     2 | 4 |     8
+        ...     7 | 5 |    10
+        ... '''
+        ... )
+        >>> on_time, late = t.forget_with_late(pw.this.t, 3)
+        >>> pw.debug.compute_and_print(late, include_id=False)
+        t | v
+        2 | 4
+        """
+        on_time, late = self._forget_with_late(
+            time_column + threshold,
+            time_column,
+            mark_forgetting_records=mark_forgetting_records,
+        )
+        universes.promise_are_pairwise_disjoint(on_time, late)
+        return on_time, late
+
+    @trace_user_frame
+    @desugar
+    @check_arg_types
+    @contextualized_operator
+    def _forget_with_late(
+        self,
+        threshold_column: expr.ColumnExpression,
+        time_column: expr.ColumnExpression,
+        mark_forgetting_records: bool,
+        instance_column: expr.ColumnExpression | None = None,
+    ) -> tuple[Table[TSchema], Table[TSchema]]:
+        if instance_column is None:
+            instance_column = expr.ColumnConstExpression(None)
+        context = clmn.ForgetContext(
+            self._id_column,
+            self._eval(threshold_column),
+            self._eval(time_column),
+            self._eval(instance_column),
+            mark_forgetting_records,
+            route_late=True,
+        )
+        late_context = clmn.LateEntriesContext(self._id_column, context)
+        return (
+            self._table_with_context(context),
+            self._table_with_context(late_context),
+        )
+
     @trace_user_frame
     @desugar
     @check_arg_types
@@ -983,6 +1156,79 @@ id_type=<class 'pathway.engine.Pointer'>>
         )
         return self._table_with_context(context)
 
+    @trace_user_frame
+    @desugar
+    def buffer_with_late(
+        self, time_column: expr.ColumnExpression, threshold: IntervalType
+    ) -> tuple[Table[TSchema], Table[TSchema]]:
+        """Like ``buffer``, but the entries that are late already when they arrive, that
+        is, that satisfy ``time_column <= max(time_column) - threshold``, are returned
+        in a separate table instead of being passed through immediately.
+
+        Args:
+            time_column: ``ColumnExpression`` that specifies the event time.
+            threshold: value used to determine which entries are old enough to be sent
+                for further processing. Should match the type of the ``time_column``
+                (``int -> int``, ``float -> float``, ``datetime -> timedelta``).
+
+        Returns:
+            on_time, late: tuple of tables, with the same schemas as ``self``.
+            The first one is the result of ``buffer`` without the late entries,
+            the second one contains the late entries.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> t = pw.debug.table_from_markdown(
+        ...     '''
+        ...     t | v | __time__
+        ...     1 | 1 |     2
+        ...     2 | 2 |     4
+        ...     5 | 3 |     6
+        ...     2 | 4 |     8
+        ...     7 | 5 |    10
+        ... '''
+        ... )
+        >>> on_time, late = t.buffer_with_late(pw.this.t, 3)
+        >>> pw.debug.compute_and_print(on_time, include_id=False)
+        t | v
+        1 | 1
+        2 | 2
+        5 | 3
+        7 | 5
+        >>> pw.debug.compute_and_print(late, include_id=False)
+        t | v
+        2 | 4
+        """
+        on_time, late = self._buffer_with_late(time_column + threshold, time_column)
+        universes.promise_are_pairwise_disjoint(on_time, late)
+        return on_time, late
+
+    @trace_user_frame
+    @desugar
+    @check_arg_types
+    @contextualized_operator
+    def _buffer_with_late(
+        self,
+        threshold_column: expr.ColumnExpression,
+        time_column: expr.ColumnExpression,
+        instance_column: expr.ColumnExpression | None = None,
+    ) -> tuple[Table[TSchema], Table[TSchema]]:
+        if instance_column is None:
+            instance_column = expr.ColumnConstExpression(None)
+        context = clmn.BufferContext(
+            self._id_column,
+            self._eval(threshold_column),
+            self._eval(time_column),
+            self._eval(instance_column),
+            route_late=True,
+        )
+        late_context = clmn.LateEntriesContext(self._id_column, context)
+        return (
+            self._table_with_context(context),
+            self._table_with_context(late_context),
+        )
+
     @contextualized_operator
     @check_arg_types
     def difference(self, other: Table) -> Table[TSchema]:
@@ -1196,6 +1442,7 @@ id_type=<class 'pathway.engine.Pointer'>>
         sort_by: expr.ColumnReference | None = None,
         _filter_out_results_of_forgetting: bool = False,
         instance: expr.ColumnReference | None = None,
+        shard_by: expr.ColumnReference | None = None,
         _skip_errors: bool = True,
         _is_window: bool = False,
     ) -> groupbys.GroupedTable:
@@ -1209,6 +1456,10 @@ id_type=<class 'pathway.engine.Pointer'>>
             id: if provided, is the column used to set id's of the rows of the result
             sort_by: if provided, column values are used as sorting keys for particular reducers
             instance: optional argument describing partitioning of the data into separate instances
+            shard_by: optional grouping column or instance, whose value decides on the worker
+                reducing a group. The groups with the same value are reduced by the same worker,
+                so that e.g. the groups of a tenant are kept together. The ids of the result are
+                the same as without it. Can't be used with operator persistence.
 
         Returns:
             GroupedTable: Groupby object.
@@ -1258,6 +1509,22 @@ id_type=<class 'pathway.engine.Pointer'>>
                         "All Table.groupby() arguments have to be a ColumnReference."
                     )
 
+        shard_column = None
+        if shard_by is not None:
+            if id is not None:
+                raise ValueError(
+                    "Table.groupby() cannot have both id and shard_by arguments."
+                )
+            shard_column = next(
+                (i for i, arg in enumerate(args) if arg._column == shard_by._column),
+                None,
+            )
+            if shard_column is None:
+                raise ValueError(
+                    "Table.groupby() shard_by argument has to be one of the grouping columns"
+                    + " or the instance."
+                )
+
         self._check_for_disallowed_types(*args)
         return groupbys.GroupedTable.create(
             table=self,
@@ -1265,6 +1532,7 @@ id_type=<class 'pathway.engine.Pointer'>>
             last_column_is_instance=instance is not None,
             set_id=id is not None,
             sort_by=sort_by,
+            shard_column=shard_column,
             _filter_out_results_of_forgetting=_filter_out_results_of_forgetting,
             _skip_errors=_skip_errors,
             _is_window=_is_window,
@@ -1412,6 +1680,71 @@ id_type=<class 'pathway.engine.Pointer'>>
 
         return self._table_with_context(context)
 
+    @trace_user_frame
+    @desugar
+    @check_arg_types
+    def with_row_versions(
+        self, version_column: str = "version", *, name: str | None = None
+    ) -> Table:
+        """Adds a column with the version number of each row.
+
+        The version of a row starts at 1 and grows by one with each update of the
+        row with the same id. A deletion counts as an update too, so a row that is
+        deleted and inserted again gets a larger version than before. The versions can
+        be used by the sinks for optimistic concurrency control in downstream stores,
+        e.g. to ignore writes carrying an older version than the stored one.
+
+        The versions only grow, but they are not consecutive as seen in the output.
+        All the changes of a row at the same time count as a single update, and the
+        deletion of a row is not visible in the column, so a row deleted at one time
+        and inserted again at a later time skips a version. The last version of each id
+        is kept in memory, also after the deletion of the row, so the state grows with
+        the number of distinct ids ever present in the table.
+
+        Args:
+            version_column: The name of the added column.
+            name:  An identifier, under which the versions will be persisted or
+                ``None``, if there is no need to persist them. When a program restarts,
+                it restores the versions saved for their ``name``, so that they keep
+                growing from where they were.
+
+        Returns:
+            Table: The same table with the ``version_column`` column added.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> t = pw.debug.table_from_markdown(
+        ...     '''
+        ...     id | owner | pet | __time__ | __diff__
+        ...      1 | Alice | dog |     2    |     1
+        ...      2 | Bob   | dog |     2    |     1
+        ...      1 | Alice | dog |     4    |    -1
+        ...      1 | Alice | cat |     4    |     1
+        ...     '''
+        ... )
+        >>> versioned = t.with_row_versions()
+        >>> pw.debug.compute_and_print(versioned, include_id=False)
+        owner | pet | version
+        Alice | cat | 2
+        Bob   | dog | 1
+        """
+        if version_column in self._columns:
+            raise ValueError(
+                f"Column {version_column!r} is already present in the table."
+            )
+        return self + self.__row_versions(version_column, name)
+
+    @trace_user_frame
+    @contextualized_operator
+    def __row_versions(self, version_column: str, name: str | None) -> Table:
+        context = clmn.VersionRowsContext(
+            self._id_column, tuple(self._columns.values()), name
+        )
+        return Table(
+            _columns={version_column: context.version_column}, _context=context
+        )
+
     @trace_user_frame
     def ix(
         self,
@@ -3051,6 +3384,81 @@ id_type=<class 'pathway.engine.Pointer'>>
         context = clmn.AssertAppendOnlyContext(self._id_column)
         return self._table_with_context(context)
 
+    @trace_user_frame
+    @contextualized_operator
+    def assert_monotonic(
+        self,
+        column: expr.ColumnExpression,
+        *,
+        instance: expr.ColumnExpression | Value | None = None,
+        strict: bool = False,
+        on_violation: Literal["fail", "log"] = "fail",
+    ) -> Table[TSchema]:
+        """
+        Checks at runtime that the values of ``column`` never decrease over time.
+
+        The values are checked separately for each row id, or for each value of
+        ``instance`` if it is given. A value inserted at some time is compared with the
+        largest value inserted for the same key at the last earlier time it got values,
+        so after a violation reported with ``on_violation="log"`` the check goes on
+        from the new value. The values inserted at the same time are not ordered with
+        respect to each other, so they are not compared with each other, and deletions
+        are not checked. The rows of a time are passed on only after they are checked.
+        This makes it possible to catch upstream producers that send the events out of
+        order.
+
+        Args:
+            column: The sequence or time column that should be monotonic.
+            instance: The column that identifies the sequences. Defaults to the row id.
+                A constant makes the whole table a single sequence.
+            strict: If ``True``, the values have to increase, not only not decrease.
+            on_violation: ``"fail"`` stops the computation with an error naming the
+                offending key and the place in the code, ``"log"`` only logs it.
+
+        Returns:
+            Table: The same table as the input one.
+
+        Example:
+
+        >>> import pathway as pw
+        >>> t = pw.debug.table_from_markdown(
+        ...     '''
+        ...     sensor | seq | __time__
+        ...          1 |   1 |     2
+        ...          2 |   7 |     2
+        ...          1 |   2 |     4
+        ...          2 |   8 |     4
+        ...     '''
+        ... )
+        >>> checked = t.assert_monotonic(pw.this.seq, instance=pw.this.sensor)
+        >>> pw.debug.compute_and_print(checked, include_id=False)
+        sensor | seq
+        1      | 1
+        1      | 2
+        2      | 7
+        2      | 8
+        """
+        if on_violation not in ("fail", "log"):
+            raise ValueError(
+                f"on_violation has to be either 'fail' or 'log', got {on_violation!r}"
+            )
+        self._validate_expression(column)
+        sequence_col = self._eval(column)
+        instance_col = None
+        if instance is not None:
+            if not isinstance(instance, expr.ColumnExpression):
+                instance = expr.ColumnConstExpression(instance)
+            self._validate_expression(instance)
+            instance_col = self._eval(instance)
+        context = clmn.AssertMonotonicContext(
+            sequence_col,
+            instance_col,
+            self._id_column,
+            strict,
+            on_violation,
+        )
+        return self._table_with_context(context)
+
     @trace_user_frame
     @contextualized_operator
     def unpack_snapshots(self) -> Table:
@@ -65,6 +65,8 @@ def table_to_datasink(
     table: tables.Table, datasink: datasinks.DataSink, *, special: bool = False
 ) -> operators.OutputOperator:
     datasink.check_sort_by_columns(table)
+    if isinstance(datasink, datasinks.GenericDataSink):
+        datasink = datasink.with_requested_metadata_columns(table)
     return parse_graphs.G.add_operator(
         lambda id: operators.OutputOperator(datasink, id),
         lambda operator: operator(table),
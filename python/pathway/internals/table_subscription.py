@@ -94,6 +94,34 @@ class OnChangeCallbackAsync(Protocol):
         ...
 
 
+class OnBatchCallback(Protocol):
+    """
+    The callback to be called with the changes in the table in batches, instead of once
+    per change. It is required to be callable and to accept two parameters: the list of
+    the changes and the time of the changes.
+    """
+
+    def __call__(
+        self,
+        changes: list[tuple[Pointer, dict[str, Any], bool]],
+        time: int,
+    ) -> None:
+        """
+        The callable part of the callback.
+
+        Args:
+            changes: the changes as triples of the key of the changed row, the changed
+                row as a dict mapping from the field name to the value and the flag
+                stating if the change was an addition of the row;
+            time: the processing time of the changes, also can be referred as
+                minibatch ID of the changes;
+
+        Returns:
+            None
+        """
+        ...
+
+
 class OnTimeEndCallback(Protocol):
     """
     The callback to be called on every time finished. It is required
@@ -175,16 +203,39 @@ def _on_change_wrapper(
         return _async_wrapper
 
 
+def _on_batch_wrapper(on_batch: OnBatchCallback, table: Table) -> Callable[..., None]:
+    """
+    Wraps a batch of changes from the engine in the format of
+    :py:class:`OnBatchCallback`, the same as the one of :py:class:`OnChangeCallback`.
+    """
+
+    column_names = list(table._columns.keys())
+
+    @functools.wraps(on_batch)
+    def _wrapper(changes: list[tuple[Pointer, list[Any], int]], time: int) -> None:
+        on_batch(
+            changes=[
+                (key, dict(zip(column_names, values)), diff >= 1)
+                for key, values, diff in changes
+            ],
+            time=time,
+        )
+
+    return _wrapper
+
+
 def subscribe(
     table: Table,
     *,
     skip_persisted_batch: bool,
-    on_change: OnChangeCallback | OnChangeCallbackAsync,
+    on_change: OnChangeCallback | OnChangeCallbackAsync | None = None,
     on_time_end: OnTimeEndCallback = lambda time: None,
     on_end: OnFinishCallback = lambda: None,
     skip_errors: bool = True,
     name: str | None = None,
-    sort_by: Iterable[ColumnReference] | None = None,
+    sort_by: Iterable[ColumnReference | datasink.SortColumn] | None = None,
+    on_batch: OnBatchCallback | None = None,
+    max_batch_size: int | None = None,
 ) -> None:
     """
     Calls a callback function on_change on every change happening in table. This method
@@ -202,6 +253,10 @@ def subscribe(
           of the change in milliseconds and the flag stating if the change had been an
           addition of the row. These parameters of the callback are expected to have
           names row, time and is_addition respectively.
+        on_batch: the callback function to be called with the changes in the table in
+          batches, instead of ``on_change``.
+        max_batch_size: the maximal number of changes passed to ``on_batch`` at once.
+          If not set, all the changes of a minibatch are passed at once.
         on_time_end: the callback function to be called on each closed time of computation.
         on_end: the callback function to be called when the stream of changes ends.
         skip_errors: whether to skip rows containing errors
@@ -210,20 +265,35 @@ def subscribe(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+            A column can be wrapped in ``pw.io.SortColumn`` to sort by it in
+            descending order or to place its ``None`` values explicitly.
             Incompatible with async callbacks.
     Returns:
         None
     """
 
+    if (on_change is None) == (on_batch is None):
+        raise ValueError("exactly one of on_change and on_batch has to be set")
+    if max_batch_size is not None and max_batch_size < 1:
+        raise ValueError(
+            f"max_batch_size has to be positive, got {max_batch_size} instead"
+        )
+
     table_to_datasink(
         table,
         datasink.CallbackDataSink(
-            on_change=_on_change_wrapper(on_change, table),
+            on_change=(
+                _on_change_wrapper(on_change, table) if on_change is not None else None
+            ),
             on_time_end=on_time_end,
             on_end=on_end,
             skip_persisted_batch=skip_persisted_batch,
             skip_errors=skip_errors,
             unique_name=name,
             sort_by=sort_by,
+            on_batch=(
+                _on_batch_wrapper(on_batch, table) if on_batch is not None else None
+            ),
+            max_batch_size=max_batch_size,
         ),
     )
@@ -523,6 +523,18 @@ class TypeInterpreter(IdentityTransform):
         elif object_dtype.equivalent_to(dt.Optional(dt.JSON)):
             # optional json
             raise TypeError(f"Cannot get from {Json | None}.")
+        elif isinstance(dt.unoptionalize(object_dtype), dt.Map):
+            # map, missing keys and None maps are replaced with the default
+            value_dtype = dt.unoptionalize(object_dtype).wrapped
+            if index_dtype != dt.STR:
+                raise TypeError(f"Key in {expression!r} has to be a str.")
+            if not expression._check_if_exists:
+                if isinstance(object_dtype, dt.Optional):
+                    raise TypeError(f"Cannot get from {object_dtype.typehint}.")
+                return _wrap(expression, value_dtype)
+            return _wrap(
+                expression, dt.types_lca(value_dtype, default_dtype, raising=True)
+            )
         else:
             # sequence
             if (
@@ -45,6 +45,27 @@ def _pd_timestamp_from_utc_ns(timestamp: int) -> pd.Timestamp:
     return pd.Timestamp(timestamp, tz=tz.UTC)
 
 
+def _pd_timestamp_from_zoned_ns(timestamp: int, timezone: str) -> pd.Timestamp:
+    """Accepts timestamp in ns and an IANA time zone name"""
+    return pd.Timestamp(timestamp, tz=tz.UTC).tz_convert(timezone)
+
+
+def _datetime_timezone_name(dt: datetime | pd.Timestamp) -> str:
+    """Returns the IANA name of the time zone of a timezone-aware datetime"""
+    tzinfo = dt.tzinfo
+    if tzinfo is None:
+        raise ValueError(
+            "cannot create DateTimeZoned from a datetime without timezone information"
+        )
+    for attribute in ("key", "zone"):  # zoneinfo, pytz
+        name = getattr(tzinfo, attribute, None)
+        if isinstance(name, str):
+            return name
+    if dt.utcoffset() == timedelta(0):
+        return "UTC"
+    raise ValueError(f"cannot determine the time zone name of {tzinfo!r}")
+
+
 def _pd_timedelta_from_ns(duration: int) -> pd.Timedelta:
     """Accepts duration in ns"""
     return pd.Timedelta(duration)
@@ -3,7 +3,9 @@
 from pathway.internals._io_helpers import TLSSettings
 from pathway.io import (
     airbyte,
+    azure,
     bigquery,
+    cassandra,
     chroma,
     clickhouse,
     csv,
@@ -12,13 +14,18 @@ from pathway.io import (
     duckdb,
     dynamodb,
     elasticsearch,
+    eventhubs,
+    flight,
     fs,
     gdrive,
+    hdfs,
+    grpc,
     http,
     iceberg,
     jsonlines,
     kafka,
     kinesis,
+    lance,
     leann,
     logstash,
     milvus,
@@ -38,37 +45,62 @@ from pathway.io import (
     qdrant,
     questdb,
     rabbitmq,
+    redis,
     redpanda,
     s3,
+    sftp,
+    shared_memory,
     slack,
     sqlite,
+    sqs,
+    sse,
     weaviate,
+    webhook,
 )
+from pathway.io._backpressure import register_adaptive_backpressure
+from pathway.io._control import drain_connector, pause_connector, resume_connector
+from pathway.io._output_metadata import output_metadata_columns
 from pathway.io._subscribe import (
+    OnBatchCallback,
     OnChangeCallback,
     OnChangeCallbackAsync,
     OnFinishCallback,
+    SortColumn,
     subscribe,
 )
 from pathway.io._synchronization import (
     SynchronizedColumn,
     register_input_synchronization_group,
+    register_output_alignment_group,
+    register_output_commit_group,
 )
-from pathway.io._utils import CsvParserSettings, DurationLike
+from pathway.io._utils import (
+    CsvFormatterSettings,
+    CsvParserSettings,
+    DurationLike,
+    JsonFormatterSettings,
+)
+from pathway.io._watermarks import register_watermark_strategy
 
 __all__ = [
     "airbyte",
+    "azure",
     "bigquery",
+    "cassandra",
     "chroma",
     "clickhouse",
     "csv",
+    "CsvFormatterSettings",
     "CsvParserSettings",
     "DurationLike",
     "debezium",
     "elasticsearch",
+    "eventhubs",
+    "flight",
     "fs",
     "http",
     "jsonlines",
+    "JsonFormatterSettings",
     "kafka",
     "logstash",
     "milvus",
@@ -79,22 +111,40 @@ __all__ = [
     "postgres",
     "pyfilesystem",
     "python",
+    "OnBatchCallback",
     "OnChangeCallback",
     "OnChangeCallbackAsync",
     "OnFinishCallback",
+    "redis",
     "redpanda",
     "slack",
+    "SortColumn",
     "subscribe",
     "s3",
+    "sftp",
+    "shared_memory",
     "gdrive",
+    "hdfs",
+    "grpc",
     "sqlite",
+    "sqs",
+    "sse",
     "pubsub",
     "deltalake",
     "duckdb",
     "iceberg",
+    "lance",
     "mongodb",
     "nats",
+    "register_adaptive_backpressure",
+    "pause_connector",
+    "resume_connector",
+    "drain_connector",
     "register_input_synchronization_group",
+    "register_output_alignment_group",
+    "register_output_commit_group",
+    "output_metadata_columns",
+    "register_watermark_strategy",
     "mqtt",
     "questdb",
     "rabbitmq",
@@ -107,4 +157,5 @@ __all__ = [
     "leann",
     "qdrant",
     "weaviate",
+    "webhook",
 ]
@@ -0,0 +1,76 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+import datetime
+from typing import TYPE_CHECKING
+
+from pathway.internals.datasource import GenericDataSource
+from pathway.internals.operator import InputOperator
+from pathway.internals.parse_graph import G
+from pathway.io._utils import DurationLike, as_duration_seconds
+
+if TYPE_CHECKING:
+    from pathway.internals.table import Table
+
+
+def register_adaptive_backpressure(
+    table: Table,
+    *,
+    target_latency: DurationLike,
+) -> None:
+    """
+    Makes the input connector of ``table`` adjust the number of rows it keeps in
+    flight, that is, the rows already read from the source but not processed yet, to
+    the speed of the processing, so that the rows don't wait for longer than
+    ``target_latency`` to be processed.
+
+    The connector watches for how long the oldest row in flight has been waiting.
+    Once it's longer than ``target_latency``, the computation doesn't keep up with the
+    source, so the connector halves the number of rows it passes to the computation at
+    once. While the latency stays within the target, the number is raised step by
+    step, so that the connector doesn't slow down the computation that could process
+    more. The ``max_backlog_size`` of the connector, if set, remains the upper limit.
+
+    The target is not a guarantee: a single row taking longer than
+    ``target_latency`` to be processed still makes the latency exceed it. The rows
+    kept back are stored in memory, so for the sources that can be slowed down, it's
+    advisable to also set ``max_backlog_size``, which pauses the reading.
+
+    Args:
+        table: A table created by an input connector.
+        target_latency: The time the rows are expected to wait at most to be
+            processed, either a number of seconds or a ``datetime.timedelta``.
+
+    Example:
+
+    >>> import datetime
+    >>> import pathway as pw
+    >>> class InputSchema(pw.Schema):
+    ...     value: int
+    >>> events = pw.io.kafka.simple_read(  # doctest: +SKIP
+    ...     "kafka:8082", "events", format="json", schema=InputSchema
+    ... )
+    >>> pw.io.register_adaptive_backpressure(  # doctest: +SKIP
+    ...     events, target_latency=datetime.timedelta(seconds=2)
+    ... )
+    """
+    target_latency = datetime.timedelta(
+        seconds=as_duration_seconds(target_latency, "target_latency", allow_zero=False)
+    )
+
+    for node in G._current_scope.nodes:
+        if (
+            isinstance(node, InputOperator)
+            and isinstance(node.datasource, GenericDataSource)
+            and node.outputs[0].value == table
+        ):
+            node.datasource.data_source_options.set_backlog_target_latency(
+                target_latency
+            )
+            return
+
+    raise ValueError(
+        "Adaptive backpressure can only be registered for the tables created by "
+        "input connectors"
+    )
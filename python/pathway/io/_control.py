@@ -0,0 +1,61 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+from pathway.internals import api
+
+
+def pause_connector(name: str) -> None:
+    """
+    Pauses the input connector with the unique name ``name`` in the computation
+    running in this process. The connector stops reading from its source, while the
+    rows it has already read are still processed. The function has to be called from
+    another thread than the one running ``pw.run``. The same can be done by sending
+    ``POST /connectors/<name>/pause`` to the monitoring HTTP server of the process.
+
+    A connector waiting for its source to return new data notices the pause once the
+    source returns. In a multi-process run, only the readers of the calling process
+    are paused, so the function has to be called in every process.
+
+    Args:
+        name: The ``name`` given to the input connector.
+
+    Raises:
+        ValueError: if no running input connector has the name ``name``.
+        RuntimeError: if the connector has been drained.
+    """
+    api.control_connector(name, command="pause")
+
+
+def resume_connector(name: str) -> None:
+    """
+    Resumes the input connector with the unique name ``name`` paused with
+    ``pw.io.pause_connector``, so that it continues reading from where it has stopped.
+    The same can be done by sending ``POST /connectors/<name>/resume`` to the
+    monitoring HTTP server of the process.
+
+    Args:
+        name: The ``name`` given to the input connector.
+
+    Raises:
+        ValueError: if no running input connector has the name ``name``.
+        RuntimeError: if the connector has been drained.
+    """
+    api.control_connector(name, command="resume")
+
+
+def drain_connector(name: str) -> None:
+    """
+    Drains the input connector with the unique name ``name``: the connector stops
+    reading from its source for good and finishes once the rows it has already read
+    are processed, as if its source had ended, while the rest of the computation keeps
+    running. A drained connector can't be resumed. The same can be done by sending
+    ``POST /connectors/<name>/drain`` to the monitoring HTTP server of the process.
+
+    Args:
+        name: The ``name`` given to the input connector.
+
+    Raises:
+        ValueError: if no running input connector has the name ``name``.
+    """
+    api.control_connector(name, command="drain")
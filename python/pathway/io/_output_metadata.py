@@ -0,0 +1,79 @@
+from collections.abc import Iterator
+from contextlib import contextmanager
+
+from pathway.internals import api
+from pathway.internals.datasink import _output_metadata_columns
+
+
+@contextmanager
+def output_metadata_columns(
+    *,
+    time: str | None = None,
+    diff: str | None = None,
+    worker: str | None = None,
+    run_id: str | None = None,
+    graph_version: str | None = None,
+) -> Iterator[None]:
+    """
+    Makes the output connectors defined within the block append engine metadata
+    to every row they write.
+
+    The metadata is added during formatting, after the columns of the table and in
+    the order of the arguments below, so it is written by the connector exactly as
+    if the table had these columns. This gives the downstream systems the provenance
+    of each row without extra expressions in the pipeline.
+
+    Only the output connectors created inside the ``with`` block get the metadata
+    columns, so the other connectors writing the same tables are left unchanged.
+    The blocks can't be nested.
+
+    Args:
+        time: The name of the column with the time of the minibatch in which the
+            change was committed.
+        diff: The name of the column with the diff of the change: ``1`` for an
+            insertion and ``-1`` for a deletion.
+        worker: The name of the column with the index of the worker that
+            processed the change.
+        run_id: The name of the column with the identifier of the pipeline run. It
+            can be set with the ``PATHWAY_RUN_ID`` environment variable. Otherwise,
+            a persisted pipeline gets an identifier derived from the location of the
+            persisted data, which stays the same across restarts, and the other
+            pipelines get a random one.
+        graph_version: The name of the column with the hash of the structure of the
+            pipeline. It stays the same across restarts as long as the pipeline isn't
+            changed, so together with the run id it identifies the code that has
+            produced the row.
+
+    Example:
+
+    >>> import pathway as pw
+    >>> orders = pw.debug.table_from_markdown("order_id | amount\n1 | 10")
+    >>> with pw.io.output_metadata_columns(time="committed_at", run_id="run"):
+    ...     pw.io.jsonlines.write(orders, "orders.jsonl")
+    """
+
+    requested = {
+        "time": time,
+        "diff": diff,
+        "worker": worker,
+        "run_id": run_id,
+        "graph_version": graph_version,
+    }
+    metadata_columns = [
+        api.SinkMetadataColumn(name, field)
+        for field, name in requested.items()
+        if name is not None
+    ]
+    if not metadata_columns:
+        raise ValueError("At least one metadata column must be specified")
+    names = [name for name in requested.values() if name is not None]
+    if len(set(names)) != len(names):
+        raise ValueError("The names of the metadata columns must be distinct")
+    if _output_metadata_columns.get():
+        raise ValueError("The blocks with output metadata columns can't be nested")
+
+    token = _output_metadata_columns.set(metadata_columns)
+    try:
+        yield
+    finally:
+        _output_metadata_columns.reset(token)
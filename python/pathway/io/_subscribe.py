@@ -4,8 +4,10 @@ from __future__ import annotations
 
 from typing import Iterable
 
+from pathway.internals.datasink import SortColumn
 from pathway.internals.expression import ColumnReference
 from pathway.internals.table_subscription import (
+    OnBatchCallback,
     OnChangeCallback,
     OnChangeCallbackAsync,
     OnFinishCallback,
@@ -16,12 +18,14 @@ from pathway.internals.table_subscription import (
 
 def subscribe(
     table,
-    on_change: OnChangeCallback | OnChangeCallbackAsync,
+    on_change: OnChangeCallback | OnChangeCallbackAsync | None = None,
     on_end: OnFinishCallback = lambda: None,
     on_time_end: OnTimeEndCallback = lambda time: None,
     *,
     name: str | None = None,
-    sort_by: Iterable[ColumnReference] | None = None,
+    sort_by: Iterable[ColumnReference | SortColumn] | None = None,
+    on_batch: OnBatchCallback | None = None,
+    max_batch_size: int | None = None,
 ):
     """
     Calls a callback function ``on_change`` on every change happening in table.
@@ -40,7 +44,17 @@ def subscribe(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+            A column can be wrapped in ``pw.io.SortColumn`` to sort by it in
+            descending order or to place its ``None`` values explicitly.
             Incompatible with async callbacks.
+        on_batch: the callback to be called with the changes in the table in batches,
+          instead of ``on_change``, so that the changes of a minibatch don't have to be
+          passed to Python one by one. The function is required to accept two
+          parameters: the list of ``(key, row, is_addition)`` triples, as in
+          ``on_change``, and the time of the changes, named ``changes`` and ``time``
+          respectively. Exactly one of ``on_change`` and ``on_batch`` has to be set.
+        max_batch_size: the maximal number of changes passed to ``on_batch`` at once.
+          If not set, all the changes of a minibatch are passed at once.
     Returns:
         None
 
@@ -81,4 +95,6 @@ def subscribe(
         on_end=on_end,
         name=name,
         sort_by=sort_by,
+        on_batch=on_batch,
+        max_batch_size=max_batch_size,
     )
@@ -1,13 +1,18 @@
 import dataclasses
 import datetime
+from typing import TYPE_CHECKING
 
 from pathway.internals import api, dtype
+from pathway.internals.datasink import GenericDataSink
 from pathway.internals.datasource import GenericDataSource
 from pathway.internals.expression import ColumnReference
 from pathway.internals.operator import InputOperator
 from pathway.internals.parse_graph import G
 from pathway.io._utils import DurationLike, as_duration_seconds
 
+if TYPE_CHECKING:
+    from pathway.internals.table import Table
+
 _SUPPORTED_COLUMN_DTYPES = [
     dtype.INT,
     dtype.DATE_TIME_NAIVE,
@@ -82,6 +87,12 @@ def register_input_synchronization_group(
       until the slower sources (i.e., those with lower values in their specified columns) \
       catch up.
 
+    The alignment is kept during the whole run, not only when the reading starts: a
+    source that gets ahead is paused whenever it happens. This bounds the amount of
+    state that the temporal operators joining the tables, such as the interval and
+    the ASOF joins, have to keep while waiting for the matching rows of the other
+    tables.
+
     Limitations:
     - This mechanism currently works only in runs that use a single Pathway Live Data Framework process. The \
       multi-processing support will be added soon.
@@ -329,3 +340,131 @@ def _check_column_type(column, max_difference):
             f"DateTimeUtc, DateTimeNaive or Duration. However, the column '{column}' "
             f"has type '{column_type.typehint}'"
         )
+
+
+def register_output_alignment_group(
+    *tables: Table,
+    max_skew: DurationLike,
+    name: str = "default",
+):
+    """
+    Creates an alignment group for the output connectors writing the given tables.
+
+    Each output connector writes its minibatches independently, so a fast sink can get
+    far ahead of a slow one. This is a problem for consumers that read several outputs
+    and join them: they observe a state in which some tables are already updated while
+    others are still stale. Within an alignment group, a sink is held back before
+    writing a minibatch whose time is more than ``max_skew`` ahead of the least advanced
+    sink of the group. The least advanced sink is never held back, so the group can't
+    deadlock, and a sink that has finished no longer holds the others back.
+
+    The function must be called after the output connectors for the tables are defined.
+    Every output connector writing one of the given tables joins the group. The sinks
+    are aligned across all the worker threads of a process. The alignment groups can't
+    be used in a computation running in several processes.
+
+    Args:
+        tables: The tables whose output connectors are aligned. At least two
+            output connectors must write them.
+        max_skew: The maximum allowed difference between the times of minibatches
+            written by the sinks in the group. Given as a number of seconds or a
+            ``datetime.timedelta`` / ``pw.Duration``.
+        name: The name of the alignment group. Groups with the same name are merged.
+
+    Example:
+
+    >>> import pathway as pw
+    >>> orders = pw.debug.table_from_markdown("order_id | amount\n1 | 10")
+    >>> payments = pw.debug.table_from_markdown("order_id | paid\n1 | 10")
+    >>> pw.io.csv.write(orders, "orders.csv")
+    >>> pw.io.csv.write(payments, "payments.csv")
+    >>> pw.io.register_output_alignment_group(orders, payments, max_skew=5)
+    """
+
+    max_skew_seconds = as_duration_seconds(max_skew, "max_skew")
+    if max_skew_seconds < 0:
+        raise ValueError("The 'max_skew' can't be negative")
+    group = api.SinkAlignmentGroupDescriptor(name, int(max_skew_seconds * 1000))
+
+    n_aligned_sinks = 0
+    for node in G._current_scope.output_nodes:
+        if not isinstance(node.datasink, GenericDataSink) or not any(
+            node.table is table for table in tables
+        ):
+            continue
+        if node.datasink.alignment_group is not None:
+            raise ValueError(
+                "An output connector can belong to one alignment group only"
+            )
+        node.datasink = dataclasses.replace(node.datasink, alignment_group=group)
+        n_aligned_sinks += 1
+
+    if n_aligned_sinks < 2:
+        raise ValueError(
+            "At least two output connectors must participate in an alignment group"
+        )
+
+
+def register_output_commit_group(*tables: Table, name: str = "default"):
+    """
+    Creates a commit group for the output connectors writing the given tables.
+
+    Each output connector commits its minibatches independently, so if one of the
+    destinations fails, the others may already have the minibatch, and the systems
+    reading them diverge after the computation is restarted. Within a commit group,
+    the output connectors commit each minibatch in two phases. First, each of them
+    sends the minibatch to its destination without making it visible and waits for the
+    others to do the same. Then, all of them commit it. If any of them fails before the
+    minibatch is sent, the others don't commit it either, and the computation fails.
+    The minibatch is considered written, which matters for the persisted state, only
+    once all of them have committed it.
+
+    Only the output connectors able to commit in two phases can join a group; currently
+    these are ``pw.io.kafka.write`` connectors with a ``transactional.id`` in their
+    ``rdkafka_settings``. The others are rejected when the computation starts. The group
+    coordinates the output connectors of a single worker, which also keeps its own
+    persisted state.
+
+    The function must be called after the output connectors for the tables are defined.
+    Every output connector writing one of the given tables joins the group, and so do
+    all of its parallel writers.
+
+    Args:
+        tables: The tables whose output connectors commit together. At least two
+            output connectors must write them.
+        name: The name of the commit group. Groups with the same name are merged.
+
+    Example:
+
+    >>> import pathway as pw
+    >>> orders = pw.debug.table_from_markdown("order_id | amount\n1 | 10")
+    >>> payments = pw.debug.table_from_markdown("order_id | paid\n1 | 10")
+    >>> settings = {"bootstrap.servers": "localhost:9092"}
+    >>> pw.io.kafka.write(
+    ...     orders,
+    ...     {**settings, "transactional.id": "orders"},
+    ...     topic_name="orders",
+    ... )
+    >>> pw.io.kafka.write(
+    ...     payments,
+    ...     {**settings, "transactional.id": "payments"},
+    ...     topic_name="payments",
+    ... )
+    >>> pw.io.register_output_commit_group(orders, payments)
+    """
+
+    n_committing_sinks = 0
+    for node in G._current_scope.output_nodes:
+        if not isinstance(node.datasink, GenericDataSink) or not any(
+            node.table is table for table in tables
+        ):
+            continue
+        if node.datasink.commit_group is not None:
+            raise ValueError("An output connector can belong to one commit group only")
+        node.datasink = dataclasses.replace(node.datasink, commit_group=name)
+        n_committing_sinks += 1
+
+    if n_committing_sinks < 2:
+        raise ValueError(
+            "At least two output connectors must participate in a commit group"
+        )
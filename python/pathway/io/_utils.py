@@ -8,7 +8,7 @@ import inspect
 import math
 import warnings
 from dataclasses import KW_ONLY, dataclass
-from typing import TYPE_CHECKING, Any, Iterable
+from typing import TYPE_CHECKING, Any, Iterable, Literal, Mapping
 
 import pathway.internals as pw
 import pathway.internals.dtype as dt
@@ -34,6 +34,7 @@ SNAPSHOT_MODE_NAME = "streaming_with_deletions"  # deprecated
 SNAPSHOT_OUTPUT_TABLE_TYPE = "snapshot"
 
 METADATA_COLUMN_NAME = "_metadata"
+HEADERS_COLUMN_NAME = "_headers"
 MESSAGE_QUEUE_KEY_COLUMN_NAME = "key"
 
 STATUS_SIZE_LIMIT_EXCEEDED = "size_limit_exceeded"
@@ -55,6 +56,15 @@ _DATA_FORMAT_MAPPING = {
     "plaintext_by_file": "identity",
     "plaintext_by_object": "identity",
     "only_metadata": "identity",
+    "orc": "orc",
+}
+
+SchemaEvolution = Literal["ignore_new_fields", "fill_missing", "fail"]
+
+_SCHEMA_EVOLUTION_POLICIES_MAPPING = {
+    "ignore_new_fields": api.SchemaEvolutionPolicy.IGNORE_NEW_FIELDS,
+    "fill_missing": api.SchemaEvolutionPolicy.FILL_MISSING,
+    "fail": api.SchemaEvolutionPolicy.FAIL,
 }
 
 _PATHWAY_TYPE_MAPPING: dict[PathwayType, dt.DType] = {
@@ -66,6 +76,7 @@ _PATHWAY_TYPE_MAPPING: dict[PathwayType, dt.DType] = {
     PathwayType.POINTER: dt.ANY_POINTER,
     PathwayType.DATE_TIME_NAIVE: dt.DATE_TIME_NAIVE,
     PathwayType.DATE_TIME_UTC: dt.DATE_TIME_UTC,
+    PathwayType.DATE_TIME_ZONED: dt.DATE_TIME_ZONED,
     PathwayType.DURATION: dt.DURATION,
     PathwayType.JSON: dt.JSON,
     PathwayType.BYTES: dt.BYTES,
@@ -81,6 +92,7 @@ SUPPORTED_INPUT_FORMATS: set[str] = {
     "plaintext_by_file",
     "plaintext_by_object",
     "only_metadata",
+    "orc",
 }
 
 
@@ -130,6 +142,47 @@ def as_duration_seconds(
     return seconds
 
 
+def construct_sql_cursor_settings(
+    connector_name: str,
+    schema: type[Schema],
+    *,
+    cursor_column: str,
+    id_column: str | None,
+    query: str | None,
+    max_cursor_lag: int,
+    read_batch_size: int,
+    poll_interval: DurationLike,
+) -> tuple[api.SqlCursorSettings, str]:
+    """Validates the parameters of the cursor mode of an SQL reader and packs
+    them into the engine settings.
+
+    Returns:
+        The settings and the id column, which defaults to ``cursor_column``.
+    """
+    if id_column is None:
+        id_column = cursor_column
+    column_names = schema.column_names()
+    for column in (cursor_column, id_column):
+        if column not in column_names:
+            raise ValueError(f"column {column!r} is not present in the schema")
+    if schema.primary_key_columns():
+        raise ValueError(
+            "Defining a primary key in the schema is not supported in the cursor "
+            f"mode of {connector_name}. The connector keys the resulting table by "
+            "id_column."
+        )
+    poll_interval_ms = round(as_duration_seconds(poll_interval, "poll_interval") * 1000)
+    settings = api.SqlCursorSettings(
+        cursor_column=cursor_column,
+        id_column=id_column,
+        query=query,
+        max_cursor_lag=max_cursor_lag,
+        read_batch_size=read_batch_size,
+        poll_interval_ms=poll_interval_ms,
+    )
+    return settings, id_column
+
+
 class RawDataSchema(pw.Schema):
     data: bytes
 
@@ -142,6 +195,10 @@ class MetadataSchema(Schema):
     _metadata: dict
 
 
+class HeadersSchema(Schema):
+    _headers: dict = pw.column_definition(source_component="headers")
+
+
 def get_data_format_type(format: str, supported_formats: set[str]):
     if format not in _DATA_FORMAT_MAPPING or format not in supported_formats:
         raise ValueError(f"data format `{format}` not supported")
@@ -183,6 +240,21 @@ def internal_connector_mode(mode: str | api.ConnectorMode) -> api.ConnectorMode:
     return internal_mode
 
 
+def internal_schema_evolution_policy(
+    schema_evolution: SchemaEvolution | None,
+) -> api.SchemaEvolutionPolicy:
+    if schema_evolution is None:
+        return api.SchemaEvolutionPolicy.IGNORE_NEW_FIELDS
+    policy = _SCHEMA_EVOLUTION_POLICIES_MAPPING.get(schema_evolution)
+    if policy is None:
+        raise ValueError(
+            "Unknown schema evolution policy: {}. Only {} are supported".format(
+                schema_evolution, ", ".join(_SCHEMA_EVOLUTION_POLICIES_MAPPING.keys())
+            )
+        )
+    return policy
+
+
 def internal_read_method(format: str) -> ReadMethod:
     if format in (
         "binary",
@@ -228,6 +300,96 @@ class CsvParserSettings:
         )
 
 
+class CsvFormatterSettings:
+    """
+    Class representing settings for the CSV output.
+
+    Args:
+        delimiter: Field delimiter to use when writing CSV.
+        quoting: Which fields are quoted. With ``"always"``, every field is quoted.
+            With ``"necessary"``, only the fields containing the delimiter, a quote
+            or a line break are quoted. With ``"non_numeric"``, every field except
+            the numbers is quoted. With ``"never"``, no field is quoted, and the
+            delimiters, the line breaks and the escape characters within the fields
+            are preceded by the escape character.
+        escape: The character escaping the quotes within the quoted fields. If not
+            specified, the quotes are escaped by doubling them. It must be specified
+            for the ``"never"`` quoting if the fields may contain the delimiter or the
+            line breaks.
+        write_header: Whether the output starts with a header containing the column
+            names.
+        null_value: If specified, the ``None`` values are written as this text,
+            without quotes, so that they can be told apart from the strings.
+            Otherwise, they are written as ``None``.
+    """
+
+    def __init__(
+        self,
+        delimiter: str = ",",
+        quoting: Literal["always", "necessary", "non_numeric", "never"] = "always",
+        escape: str | None = None,
+        write_header: bool = True,
+        null_value: str | None = None,
+    ):
+        self.delimiter = delimiter
+        self.api_settings = api.CsvFormatterSettings(
+            quoting,
+            escape,
+            write_header,
+            null_value,
+        )
+
+
+class JsonFormatterSettings:
+    """
+    Class representing settings for the JSON output.
+
+    Args:
+        field_paths: The locations of the columns in the output objects, given as
+            `JSON Pointers (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_ and
+            keyed by the column names. The nested objects on the way are created as
+            needed, so ``{"city": "/address/city"}`` places the value of the column
+            ``city`` into the field ``city`` of the object in the field ``address``. A
+            pointer with a single component, such as ``"/town"``, renames the column.
+            The columns not listed stay in the top-level fields named after them.
+        include_time_and_diff: Whether the ``time`` and ``diff`` fields are added to
+            the output objects.
+        datetime_format: How the datetime values are encoded. With ``"default"``,
+            they are strings with nanosecond precision, the same as in the other
+            connectors. With ``"iso8601"``, they are ISO-8601 strings, with the offset
+            in the ``+HH:MM`` form for the datetimes with a time zone. The
+            ``"epoch_seconds"``, ``"epoch_milliseconds"``, ``"epoch_microseconds"``
+            and ``"epoch_nanoseconds"`` formats encode them as integer numbers of
+            the respective units since the Unix epoch, treating the naive datetimes
+            as if they were in UTC.
+    """
+
+    def __init__(
+        self,
+        field_paths: dict[str, str] | None = None,
+        include_time_and_diff: bool = True,
+        datetime_format: Literal[
+            "default",
+            "iso8601",
+            "epoch_seconds",
+            "epoch_milliseconds",
+            "epoch_microseconds",
+            "epoch_nanoseconds",
+        ] = "default",
+    ):
+        for field_name, path in (field_paths or {}).items():
+            if not path.startswith("/"):
+                raise ValueError(
+                    f"Invalid JSON Pointer for field {field_name!r}: {path!r}. "
+                    "The output field paths must start with '/' (e.g. '/foo/bar')."
+                )
+        self.include_time_and_diff = include_time_and_diff
+        self.api_settings = api.JsonFormatterSettings(
+            field_paths or {},
+            datetime_format,
+        )
+
+
 def read_schema(
     schema: type[Schema],
 ) -> tuple[type[Schema], dict[str, Any]]:
@@ -266,7 +428,11 @@ class RawKeySchema(pw.Schema):
 
 
 def construct_raw_data_schema_by_flags(
-    *, with_native_record_key: bool, parse_utf8: bool, with_metadata: bool
+    *,
+    with_native_record_key: bool,
+    parse_utf8: bool,
+    with_metadata: bool,
+    with_headers: bool = False,
 ) -> type[pw.Schema]:
     Schema: Any
     if parse_utf8:
@@ -279,6 +445,8 @@ def construct_raw_data_schema_by_flags(
             Schema = Schema | RawKeySchema
     if with_metadata:
         Schema = Schema | MetadataSchema
+    if with_headers:
+        Schema = Schema | HeadersSchema
     return Schema
 
 
@@ -287,11 +455,13 @@ def construct_schema_and_data_format(
     *,
     schema: type[Schema] | None = None,
     with_metadata: bool = False,
+    with_headers: bool = False,
     autogenerate_key: bool = False,
     csv_settings: CsvParserSettings | None = None,
     json_field_paths: dict[str, str] | None = None,
     schema_registry_settings: SchemaRegistrySettings | None = None,
     with_native_record_key: bool = False,
+    schema_evolution: SchemaEvolution | None = None,
     _stacklevel: int = 1,
 ) -> tuple[type[Schema], api.DataFormat]:
     data_format_type = get_data_format_type(format, SUPPORTED_INPUT_FORMATS)
@@ -303,6 +473,7 @@ def construct_schema_and_data_format(
             "csv_settings",
             "json_field_paths",
             "schema_registry_settings",
+            "schema_evolution",
         ]
         for param in unexpected_params:
             if param in kwargs and kwargs[param] is not None:
@@ -313,6 +484,7 @@ def construct_schema_and_data_format(
             with_native_record_key=with_native_record_key,
             parse_utf8=parse_utf8,
             with_metadata=with_metadata,
+            with_headers=with_headers,
         )
         schema, api_schema = read_schema(schema)
 
@@ -351,8 +523,18 @@ def construct_schema_and_data_format(
         )
     if with_metadata:
         schema |= MetadataSchema
+    if with_headers:
+        if HEADERS_COLUMN_NAME in schema.column_names():
+            raise ValueError(
+                f"The schema already declares a {HEADERS_COLUMN_NAME!r} column, "
+                "which conflicts with 'with_headers=True'. Either remove "
+                f"{HEADERS_COLUMN_NAME!r} from the schema or set "
+                "'with_headers=False'."
+            )
+        schema |= HeadersSchema
 
     schema, api_schema = read_schema(schema)
+    schema_evolution_policy = internal_schema_evolution_policy(schema_evolution)
     if data_format_type == "dsv":
         if json_field_paths is not None:
             raise ValueError("Unexpected argument for csv format: json_field_paths")
@@ -363,6 +545,7 @@ def construct_schema_and_data_format(
             schema_registry_settings=maybe_schema_registry_settings(
                 schema_registry_settings
             ),
+            schema_evolution_policy=schema_evolution_policy,
         )
     elif data_format_type == "jsonlines":
         if csv_settings is not None:
@@ -402,6 +585,18 @@ def construct_schema_and_data_format(
             schema_registry_settings=maybe_schema_registry_settings(
                 schema_registry_settings
             ),
+            schema_evolution_policy=schema_evolution_policy,
+        )
+    elif data_format_type == "orc":
+        if csv_settings is not None:
+            raise ValueError("Unexpected argument for orc format: csv_settings")
+        if json_field_paths is not None:
+            raise ValueError("Unexpected argument for orc format: json_field_paths")
+        if schema_evolution is not None:
+            raise ValueError("Unexpected argument for orc format: schema_evolution")
+        return schema, api.DataFormat(
+            **api_schema,
+            format_type=data_format_type,
         )
     else:
         raise ValueError(f"data format `{format}` not supported")
@@ -442,7 +637,9 @@ class MessageQueueOutputFormat:
         delimiter: str = ",",
         key: ColumnReference | None = None,
         value: ColumnReference | None = None,
-        headers: Iterable[ColumnReference] | None = None,
+        headers: (
+            Iterable[ColumnReference] | Mapping[str, ColumnReference] | None
+        ) = None,
         topic_name: ColumnReference | None = None,
         schema_registry_settings: SchemaRegistrySettings | None = None,
         subject: str | None = None,
@@ -513,22 +710,26 @@ class MessageQueueOutputFormat:
             )
         if headers is not None:
             reserved_header_names = {"pathway_time", "pathway_diff"}
-            for header in headers:
-                if header.name in reserved_header_names:
+            if isinstance(headers, Mapping):
+                named_headers = list(headers.items())
+            else:
+                named_headers = [(header.name, header) for header in headers]
+            for header_name, header in named_headers:
+                if header_name in reserved_header_names:
                     raise ValueError(
-                        f"{header.name!r} is reserved for the Pathway-injected "
+                        f"{header_name!r} is reserved for the Pathway-injected "
                         "headers (pathway_time / pathway_diff) and cannot be "
                         "used as a user header name. Alias the column to "
                         "another name with `table.select(<new_name>=...)`."
                     )
-                if header.name in header_fields:
+                if header_name in header_fields:
                     raise ValueError(
-                        f"Duplicate header name {header.name!r}: two columns "
+                        f"Duplicate header name {header_name!r}: two columns "
                         "produce a header with the same name. Alias one of "
                         "them to a different name (e.g. via `table.select(...)`) "
                         "to keep both as separate Kafka headers."
                     )
-                header_fields[header.name] = cls.add_column_reference_to_extract(
+                header_fields[header_name] = cls.add_column_reference_to_extract(
                     header, columns_to_extract, extracted_field_indices
                 )
 
@@ -729,3 +930,30 @@ def init_mode_from_str(init_mode: str) -> api.TableWriterInitMode:
             return api.TableWriterInitMode.REPLACE
         case _:
             raise ValueError(f"Invalid init_mode: {init_mode}")
+
+
+def delivery_semantics_from_str(delivery_semantics: str) -> api.DeliverySemantics:
+    match delivery_semantics:
+        case "at_least_once":
+            return api.DeliverySemantics.AT_LEAST_ONCE
+        case "exactly_once":
+            return api.DeliverySemantics.EXACTLY_ONCE
+        case _:
+            raise ValueError(f"Invalid delivery_semantics: {delivery_semantics}")
+
+
+def output_mode_from_str(
+    output_mode: str, *, max_batch_size: int | None = None
+) -> api.OutputMode:
+    match output_mode:
+        case "streaming":
+            return api.OutputMode.STREAMING
+        case "watermark_atomic":
+            # the writer would commit a minibatch in several transactions
+            if max_batch_size is not None:
+                raise ValueError(
+                    "max_batch_size can't be set with output_mode='watermark_atomic'"
+                )
+            return api.OutputMode.WATERMARK_ATOMIC
+        case _:
+            raise ValueError(f"Invalid output_mode: {output_mode}")
@@ -0,0 +1,124 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+import datetime
+from typing import TYPE_CHECKING
+
+from pathway.internals import api, dtype
+from pathway.internals.datasource import GenericDataSource
+from pathway.internals.expression import ColumnReference
+from pathway.internals.operator import InputOperator
+from pathway.internals.parse_graph import G
+from pathway.io._utils import DurationLike, as_duration_seconds
+
+if TYPE_CHECKING:
+    from pathway.internals.table import Table
+
+_DATETIME_DTYPES = (dtype.DATE_TIME_NAIVE, dtype.DATE_TIME_UTC)
+
+
+def register_watermark_strategy(
+    table: Table,
+    *,
+    event_time: ColumnReference | None = None,
+    max_out_of_orderness: int | datetime.timedelta = 0,
+    idle_timeout: DurationLike | None = None,
+) -> None:
+    """
+    Makes the input connector of ``table`` commit the rows it has read as soon as they
+    are complete according to a watermark, instead of only every
+    ``autocommit_duration_ms``.
+
+    With ``event_time``, the watermark is the greatest event time read so far minus
+    ``max_out_of_orderness``: the rows are assumed to arrive at most that late. Each
+    time the watermark advances, the rows read so far are committed, so that the
+    results for the earlier event times are produced right away.
+
+    With ``idle_timeout``, the rows read so far are committed once the source has not
+    produced any new rows for that time. The source is then also assumed not to
+    produce any more late rows, so the watermark advances to the greatest event time.
+
+    Args:
+        table: A table created by an input connector.
+        event_time: The column of ``table`` with the event times, of the type ``int``,
+            ``DateTimeNaive`` or ``DateTimeUtc``.
+        max_out_of_orderness: How late the rows can arrive relative to the latest one:
+            an ``int`` for an ``int`` column and a ``datetime.timedelta`` for a
+            datetime column.
+        idle_timeout: The time after which a source without new rows is considered
+            idle, either a number of seconds or a ``datetime.timedelta``.
+
+    Example:
+
+    >>> import datetime
+    >>> import pathway as pw
+    >>> class InputSchema(pw.Schema):
+    ...     event_time: pw.DateTimeUtc
+    ...     value: int
+    >>> events = pw.io.kafka.simple_read(  # doctest: +SKIP
+    ...     "kafka:8082", "events", format="json", schema=InputSchema
+    ... )
+    >>> pw.io.register_watermark_strategy(  # doctest: +SKIP
+    ...     events,
+    ...     event_time=events.event_time,
+    ...     max_out_of_orderness=datetime.timedelta(seconds=5),
+    ...     idle_timeout=datetime.timedelta(seconds=30),
+    ... )
+    """
+    if event_time is None and idle_timeout is None:
+        raise ValueError("Either 'event_time' or 'idle_timeout' must be specified")
+
+    time_column = None
+    max_out_of_orderness_value = 0
+    if event_time is not None:
+        if event_time._table is not table:
+            raise ValueError(
+                f"The column '{event_time._name}' doesn't belong to the table {table}"
+            )
+        column_type = event_time._column.dtype
+        if column_type == dtype.INT:
+            if not isinstance(max_out_of_orderness, int):
+                raise ValueError(
+                    "For a column of type int, 'max_out_of_orderness' must be an int"
+                )
+            max_out_of_orderness_value = max_out_of_orderness
+        elif column_type in _DATETIME_DTYPES:
+            if isinstance(max_out_of_orderness, datetime.timedelta):
+                max_out_of_orderness_value = (
+                    max_out_of_orderness // datetime.timedelta(microseconds=1) * 1_000
+                )
+            elif max_out_of_orderness != 0:
+                raise ValueError(
+                    "For a datetime column, 'max_out_of_orderness' must be "
+                    "a datetime.timedelta"
+                )
+        else:
+            raise ValueError(
+                f"Event times of type {column_type.typehint} are not supported"
+            )
+        if max_out_of_orderness_value < 0:
+            raise ValueError("The 'max_out_of_orderness' can't be negative")
+        time_column = table._schema.column_names().index(event_time._name)
+
+    if idle_timeout is not None:
+        idle_timeout = datetime.timedelta(
+            seconds=as_duration_seconds(idle_timeout, "idle_timeout", allow_zero=False)
+        )
+
+    for node in G._current_scope.nodes:
+        if (
+            isinstance(node, InputOperator)
+            and isinstance(node.datasource, GenericDataSource)
+            and node.outputs[0].value == table
+        ):
+            strategy = api.WatermarkStrategy(
+                time_column, max_out_of_orderness_value, idle_timeout
+            )
+            node.datasource.data_source_options.set_watermark_strategy(strategy)
+            return
+
+    raise ValueError(
+        "Watermark strategies can only be registered for the tables created by "
+        "input connectors"
+    )
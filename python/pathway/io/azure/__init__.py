@@ -0,0 +1,198 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+from typing import Any, Literal
+
+from pathway.internals import api, datasource
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.schema import Schema
+from pathway.internals.table import Table
+from pathway.internals.table_io import table_from_datasource
+from pathway.internals.trace import trace_user_frame
+from pathway.io._utils import (
+    CsvParserSettings,
+    _get_unique_name,
+    construct_schema_and_data_format,
+    internal_connector_mode,
+    internal_read_method,
+)
+
+
+@check_arg_types
+@trace_user_frame
+def read(
+    path: str,
+    format: Literal[
+        "csv", "json", "plaintext", "plaintext_by_object", "binary", "only_metadata"
+    ],
+    *,
+    account: str,
+    container: str,
+    account_key: str | None = None,
+    sas_token: str | None = None,
+    schema: type[Schema] | None = None,
+    mode: Literal["streaming", "static"] = "streaming",
+    with_metadata: bool = False,
+    csv_settings: CsvParserSettings | None = None,
+    json_field_paths: dict[str, str] | None = None,
+    path_filter: str | None = None,
+    downloader_threads_count: int | None = None,
+    autocommit_duration_ms: int | None = 1500,
+    name: str | None = None,
+    max_backlog_size: int | None = None,
+    debug_data: Any = None,
+    **kwargs,
+) -> Table:
+    """Reads a table from the blobs in an Azure Blob Storage container, including the
+    containers of the Azure Data Lake Storage Gen2 accounts, in the given format.
+
+    The blobs under the ``path`` prefix are listed one virtual directory at a time, so
+    the directories of the accounts with the hierarchical namespace are traversed as
+    well. The blobs are read in the order of their modification times: the smaller the
+    modification time is, the earlier the blob is passed to the engine.
+
+    If neither ``account_key`` nor ``sas_token`` is given, the connector authenticates
+    with the identity of its environment, such as a service principal configured with
+    the ``AZURE_CLIENT_ID``, ``AZURE_TENANT_ID`` and ``AZURE_CLIENT_SECRET`` environment
+    variables, a managed identity, or the Azure CLI login.
+
+    Note that if you only need to monitor changes in the container, you can use the
+    ``"only_metadata"`` format, in which case the table will contain only metadata, and
+    no time or traffic will be spent on downloading the blobs.
+
+    Args:
+        path: The prefix of the names of the blobs to be read, for example the path of a
+            directory, or the name of a single blob.
+        format: Format of data to be read. Currently ``csv``, ``json``, ``plaintext``,
+            ``plaintext_by_object``, ``binary`` and ``only_metadata`` formats are
+            supported. The difference between ``plaintext`` and ``plaintext_by_object``
+            is how the input is tokenized: if the ``plaintext`` option is chosen, it's
+            split by the newlines. Otherwise, the blobs are split in full and one row
+            will correspond to one blob. In case the ``binary`` format is specified, the
+            data is read as raw bytes without UTF-8 parsing.
+        account: The name of the storage account.
+        container: The name of the container.
+        account_key: The access key of the storage account.
+        sas_token: The shared access signature token granting access to the container.
+            Can't be specified together with ``account_key``.
+        schema: Schema of the resulting table. Not required for ``plaintext_by_object``
+            and ``binary`` formats: if they are chosen, the contents of the read blobs
+            are stored in the column ``data``.
+        mode: If set to ``streaming``, the engine waits for the new blobs under the
+            given path prefix, and tracks the changes and the deletions of the blobs
+            already read. Set it to ``static``, it only considers the available data and
+            ingest all of it. Default value is ``streaming``.
+        with_metadata: When set to true, the connector will add an additional column
+            named ``_metadata`` to the table. This column will be a JSON field that will
+            contain the fields ``created_at`` and ``modified_at`` with the UNIX
+            timestamps of the creation and the last modification of the blob, ``size``
+            with its size in bytes, and ``path`` with the name of the blob from which a
+            row was filled.
+        csv_settings: Settings for the CSV parser. This parameter is used only in case
+            the specified format is ``csv``.
+        json_field_paths: If the format is ``json``, this field allows to map field names
+            into path in the read json object. For the field which require such mapping,
+            it should be given in the format ``<field_name>: <path to be mapped>``,
+            where the path to be mapped needs to be a
+            `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        path_filter: A wildcard pattern used to match full blob names. Supports ``*``
+            (any number of any characters, including none) and ``?`` (any single
+            character). If specified, only the blobs matching this pattern will be read.
+        downloader_threads_count: The number of blobs downloaded concurrently. It
+            defaults to the number of cores available on the machine.
+        autocommit_duration_ms: The maximum time between two commits. Every
+            autocommit_duration_ms milliseconds, the updates received by the connector are
+            committed and pushed into Pathway Live Data Framework's computation graph.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards. Additionally, if persistence is enabled, it
+            will be used as the name for the snapshot that stores the connector's progress.
+        max_backlog_size: Limit on the number of entries read from the input source and kept
+            in processing at any moment. Reading pauses when the limit is reached and resumes
+            as processing of some entries completes. Useful with large sources that
+            emit an initial burst of data to avoid memory spikes.
+        debug_data: Static data replacing original one when debug mode is active.
+
+    Returns:
+        Table: The table read.
+
+    Example:
+
+    Suppose that the storage account ``analytics`` has the container ``datasets``
+    with the JSON Lines files under the directory ``animals/``. The files can be read
+    with the account key as follows:
+
+    >>> import os
+    >>> import pathway as pw
+    >>> class InputSchema(pw.Schema):
+    ...   owner: str
+    ...   pet: str
+    >>> t = pw.io.azure.read(
+    ...     "animals/",
+    ...     format="json",
+    ...     account="analytics",
+    ...     container="datasets",
+    ...     account_key=os.environ["AZURE_STORAGE_KEY"],
+    ...     schema=InputSchema,
+    ... )
+
+    When the program runs with a managed identity having access to the container, the
+    key can be omitted:
+
+    >>> t = pw.io.azure.read(
+    ...     "animals/",
+    ...     format="json",
+    ...     account="analytics",
+    ...     container="datasets",
+    ...     schema=InputSchema,
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    only_provide_metadata = format == "only_metadata"
+    data_storage = api.DataStorage(
+        storage_type="azure",
+        path=path,
+        azure_blob_storage_settings=api.AzureBlobStorageSettings(
+            account=account,
+            password=account_key,
+            container=container,
+            sas_token=sas_token,
+        ),
+        csv_parser_settings=csv_settings.api_settings if csv_settings else None,
+        object_pattern=path_filter or "*",
+        mode=internal_connector_mode(mode),
+        read_method=internal_read_method(format),
+        downloader_threads_count=downloader_threads_count,
+        only_provide_metadata=only_provide_metadata,
+    )
+
+    schema, data_format = construct_schema_and_data_format(
+        format,
+        schema=schema,
+        csv_settings=csv_settings,
+        json_field_paths=json_field_paths,
+        with_metadata=with_metadata or only_provide_metadata,
+    )
+    data_source_options = datasource.DataSourceOptions(
+        commit_duration_ms=autocommit_duration_ms,
+        unique_name=_get_unique_name(name, kwargs),
+        max_backlog_size=max_backlog_size,
+    )
+    table = table_from_datasource(
+        datasource.GenericDataSource(
+            datastorage=data_storage,
+            dataformat=data_format,
+            schema=schema,
+            data_source_options=data_source_options,
+            datasource_name="azure",
+        ),
+        debug_datasource=datasource.debug_datasource(debug_data),
+    )
+    if only_provide_metadata:
+        table = table.select(_metadata=table._metadata)
+    return table
+
+
+__all__ = ["read"]
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import json
 import logging
+import threading
 from typing import TYPE_CHECKING, Any, Iterable
 
 if TYPE_CHECKING:
@@ -18,25 +19,54 @@ class _OutputBuffer:
     MAX_BUFFER_SIZE = 1024
 
     def __init__(
-        self, dataset_name: str, table_name: str, credentials: ServiceCredentials
+        self,
+        dataset_name: str,
+        table_name: str,
+        credentials: ServiceCredentials | None,
+        max_batch_size: int | None = None,
+        linger_ms: int | None = None,
     ) -> None:
         from google.cloud import bigquery
 
         self._client = bigquery.Client(credentials=credentials)
         self._table_ref = self._client.dataset(dataset_name).table(table_name)
         self._buffer: list[dict] = []
-
-    def on_change(
-        self, key: Pointer, row: dict[str, Any], time: int, is_addition: bool
+        self._max_batch_size = max_batch_size or self.MAX_BUFFER_SIZE
+        self._linger_ms = linger_ms
+        # the rows are inserted by the timer if no minibatch ends before it fires
+        self._lock = threading.Lock()
+        self._timer: threading.Timer | None = None
+
+    def on_batch(
+        self, changes: list[tuple[Pointer, dict[str, Any], bool]], time: int
     ) -> None:
-        row["time"] = time
-        row["diff"] = 1 if is_addition else -1
-        self._buffer.append(self._convert_to_compatible_format(row))
-        if len(self._buffer) == self.MAX_BUFFER_SIZE:
+        with self._lock:
+            for _key, row, is_addition in changes:
+                row["time"] = time
+                row["diff"] = 1 if is_addition else -1
+                self._buffer.append(self._convert_to_compatible_format(row))
+            while len(self._buffer) >= self._max_batch_size:
+                if not self._flush_buffer(self._max_batch_size):
+                    return
+            if self._linger_ms is None:
+                self._flush_buffer()
+            elif self._buffer and self._timer is None:
+                self._timer = threading.Timer(
+                    self._linger_ms / 1000, self._on_linger_end
+                )
+                self._timer.daemon = True
+                self._timer.start()
+
+    def on_end(self) -> None:
+        with self._lock:
+            if self._timer is not None:
+                self._timer.cancel()
+                self._timer = None
             self._flush_buffer()
 
-    def on_time_end(self, time: int) -> None:
-        if self._buffer:
+    def _on_linger_end(self) -> None:
+        with self._lock:
+            self._timer = None
             self._flush_buffer()
 
     def _convert_to_compatible_format(self, row: dict[str, Any]):
@@ -48,14 +78,23 @@ class _OutputBuffer:
 
         return {key: maybe_dump_json_value(value) for key, value in row.items()}
 
-    def _flush_buffer(self) -> None:
-        errors = self._client.insert_rows_json(self._table_ref, self._buffer)
+    # Inserts the first `batch_size` buffered rows, all of them by default. The rows
+    # that fail to be inserted stay in the buffer and are inserted with the next batch.
+    def _flush_buffer(self, batch_size: int | None = None) -> bool:
+        if not self._buffer:
+            return True
+        if batch_size is None:
+            batch_size = len(self._buffer)
+        errors = self._client.insert_rows_json(
+            self._table_ref, self._buffer[:batch_size]
+        )
         if errors:
             logging.error(
                 f"Failed to insert rows into BigQuery table. Errors: {json.dumps(errors)}"
             )
-        else:
-            self._buffer = []
+            return False
+        self._buffer = self._buffer[batch_size:]
+        return True
 
 
 def write(
@@ -66,6 +105,8 @@ def write(
     *,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes ``table``'s stream of changes into the specified BigQuery table. Please note
     that the schema of the target table must correspond to the schema of the table that is
@@ -89,6 +130,12 @@ to obtain them.
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        max_batch_size: The maximal number of rows inserted in a single request. The
+            larger minibatches are split into several requests. Defaults to 1024.
+        linger_ms: For how long, in milliseconds, the rows can be held back so that the
+            rows of the next minibatches are inserted in the same request, unless it
+            exceeds ``max_batch_size``. If not specified, the rows are inserted after
+            each minibatch.
 
     Returns:
         None
@@ -109,17 +156,28 @@ to obtain them.
     ...     service_user_credentials_file="./credentials.json"
     ... )
     """
+    if max_batch_size is not None and max_batch_size <= 0:
+        raise ValueError(f"max_batch_size must be positive, got {max_batch_size}")
+    if linger_ms is not None and linger_ms < 0:
+        raise ValueError(f"linger_ms must be non-negative, got {linger_ms}")
     _check_entitlements("bigquery")
     from google.oauth2.service_account import Credentials as ServiceCredentials
 
     credentials = ServiceCredentials.from_service_account_file(
         service_user_credentials_file
     )
-    output_buffer = _OutputBuffer(dataset_name, table_name, credentials)
+    output_buffer = _OutputBuffer(
+        dataset_name,
+        table_name,
+        credentials,
+        max_batch_size=max_batch_size,
+        linger_ms=linger_ms,
+    )
     subscribe(
         table,
-        on_change=output_buffer.on_change,
-        on_time_end=output_buffer.on_time_end,
+        on_batch=output_buffer.on_batch,
+        on_end=output_buffer.on_end,
         name=name,
         sort_by=sort_by,
+        max_batch_size=max_batch_size,
     )
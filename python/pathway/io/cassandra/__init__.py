@@ -0,0 +1,155 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+from typing import Iterable
+
+from pathway.internals import api, datasink
+from pathway.internals._io_helpers import _format_output_value_fields
+from pathway.internals.expression import ColumnReference
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.table import Table
+from pathway.internals.trace import trace_user_frame
+
+
+@check_arg_types
+@trace_user_frame
+def write(
+    table: Table,
+    *,
+    hosts: list[str],
+    keyspace: str,
+    table_name: str,
+    primary_key: list[ColumnReference],
+    username: str | None = None,
+    password: str | None = None,
+    max_batch_size: int | None = None,
+    name: str | None = None,
+    sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
+) -> None:
+    """
+    Maintains the current state of ``table`` in a table of an
+    `Apache Cassandra <https://cassandra.apache.org/>`_ or a
+    `ScyllaDB <https://www.scylladb.com/>`_ cluster, so that it can be served from
+    there under a high write rate.
+
+    The target table must exist, and its columns must have the names of the columns of
+    ``table``. When a row is inserted or updated, it's written with an ``INSERT``
+    statement, which replaces the row with the same primary key. When a row is deleted,
+    the row with its primary key is deleted with a ``DELETE`` statement. Both statements
+    are prepared once, when the connector starts.
+
+    The changes of a minibatch are grouped by the partitions they belong to and sent in
+    unlogged batches, one or more per partition, so that each batch is handled by the
+    replicas of a single partition. Note that a batch isn't isolated from the readers, so
+    a minibatch may be seen partially applied.
+
+    The values are written as follows: ``bool`` as ``boolean``, ``int`` as ``bigint``,
+    ``float`` as ``double``, ``str``, ``Json`` and ``Pointer`` as ``text``, ``bytes`` as
+    ``blob``, ``DateTimeNaive`` and ``DateTimeUtc`` as ``timestamp``, ``Duration`` as
+    ``duration``, and tuples and lists as ``list`` collections. ``None`` is written as
+    ``null``.
+
+    Args:
+        table: The table to write.
+        hosts: The addresses of the nodes of the cluster, for example
+            ``["127.0.0.1:9042"]``. The other nodes are discovered from them.
+        keyspace: The keyspace of the target table.
+        table_name: The name of the target table.
+        primary_key: The columns of ``table`` that form the primary key of the target
+            table, both the partition key and the clustering columns.
+        username: The username to authenticate with, if the cluster requires the
+            authentication.
+        password: The password to authenticate with.
+        max_batch_size: If specified, the changes are sent once this many rows are
+            pending, even if the minibatch isn't over yet.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards.
+        sort_by: If specified, the output will be sorted in ascending order based on the
+            values of the given columns within each minibatch. When multiple columns are provided,
+            the corresponding value tuples will be compared lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
+
+    Returns:
+        None
+
+    Example:
+
+    Suppose that a Cassandra node runs locally on the default port, and the keyspace
+    ``serving`` contains a table created with:
+
+    .. code-block:: sql
+
+        CREATE TABLE serving.prices (
+            product text PRIMARY KEY,
+            price double
+        );
+
+    You can maintain the latest price of each product there:
+
+    >>> import pathway as pw
+    >>> prices = pw.debug.table_from_markdown(
+    ...     '''
+    ...     product | price
+    ...     apple   | 1.5
+    ...     banana  | 0.5
+    ... '''
+    ... )
+    >>> pw.io.cassandra.write(
+    ...     prices,
+    ...     hosts=["127.0.0.1:9042"],
+    ...     keyspace="serving",
+    ...     table_name="prices",
+    ...     primary_key=[prices.product],
+    ... )
+
+    Once the program is run with ``pw.run()``, the table ``serving.prices`` contains
+    the current price of each product.
+    """
+
+    if not primary_key:
+        raise ValueError("'primary_key' must contain at least one column")
+    if (username is None) != (password is None):
+        raise ValueError("'username' and 'password' must be specified together")
+
+    data_storage = api.DataStorage(
+        storage_type="cassandra",
+        database=keyspace,
+        table_name=table_name,
+        max_batch_size=max_batch_size,
+        cassandra_settings=api.CassandraSettings(
+            hosts=hosts,
+            username=username,
+            password=password,
+        ),
+    )
+    data_format = api.DataFormat(
+        format_type="identity",
+        key_field_names=[column.name for column in primary_key],
+        value_fields=_format_output_value_fields(table),
+    )
+
+    table.to(
+        datasink.GenericDataSink(
+            data_storage,
+            data_format,
+            datasink_name="cassandra",
+            unique_name=name,
+            sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
+        )
+    )
+
+
+__all__ = ["write"]
@@ -40,6 +40,9 @@ def write(
     database: str = "default_database",
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes a Pathway Live Data Framework table to a `Chroma <https://www.trychroma.com/>`_
     collection over the server's HTTP API.
@@ -93,6 +96,18 @@ def write(
             in ascending order by the given columns. When multiple columns are
             provided, the corresponding value tuples are compared
             lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -178,6 +193,9 @@ def write(
             datasink_name="chroma.sink",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
         )
     )
 
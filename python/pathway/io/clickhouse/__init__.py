@@ -27,6 +27,8 @@ def write(
     max_batch_size: int | None = None,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes ``table`` to a ClickHouse table.
 
@@ -147,6 +149,15 @@ def write(
             values of the given columns within each minibatch. When multiple
             columns are provided, the corresponding value tuples are compared
             lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
 
     Returns:
         None
@@ -277,5 +288,7 @@ def write(
             datasink_name=f"clickhouse.{datasink_type}",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
         )
     )
@@ -10,7 +10,12 @@ from pathway.internals.expression import ColumnReference
 from pathway.internals.runtime_type_check import check_arg_types
 from pathway.internals.table import Table
 from pathway.internals.trace import trace_user_frame
-from pathway.io._utils import CsvParserSettings, check_deprecated_kwargs
+from pathway.io._utils import (
+    CsvFormatterSettings,
+    CsvParserSettings,
+    SchemaEvolution,
+    check_deprecated_kwargs,
+)
 
 
 @check_arg_types
@@ -20,6 +25,7 @@ def read(
     *,
     schema: type[pw.Schema] | None = None,
     csv_settings: CsvParserSettings | None = None,
+    schema_evolution: SchemaEvolution | None = None,
     mode: Literal["streaming", "static"] = "streaming",
     object_pattern: str = "*",
     with_metadata: bool = False,
@@ -43,6 +49,13 @@ def read(
             as recursively read the contents of all matching folders.
         schema: Schema of the resulting table.
         csv_settings: Settings for the CSV parser.
+        schema_evolution: How the columns of the header that don't match the schema
+            are handled. With ``"ignore_new_fields"``, the columns not present in the
+            schema are ignored. With ``"fill_missing"``, additionally the columns
+            missing from the header are ``None``. With ``"fail"``, both the columns not
+            present in the schema and the missing columns are errors. A missing column
+            always takes the default value of its column if the schema declares one.
+            Defaults to ``"ignore_new_fields"``.
         mode: Denotes how the engine polls the new data from the source. Currently
             ``"streaming"`` and ``"static"`` are supported. If set to ``"streaming"`` the engine will wait for
             the updates in the specified directory. It will track file additions, deletions, and
@@ -159,6 +172,7 @@ def read(
         csv_settings=csv_settings,
         autocommit_duration_ms=autocommit_duration_ms,
         json_field_paths=None,
+        schema_evolution=schema_evolution,
         name=name,
         max_backlog_size=max_backlog_size,
         debug_data=debug_data,
@@ -175,6 +189,10 @@ def write(
     *,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    csv_settings: CsvFormatterSettings | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes ``table``'s stream of updates to a file in delimiter-separated values format.
 
@@ -186,6 +204,22 @@ def write(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        csv_settings: The dialect of the output: the delimiter, the quoting of the
+            fields, the escape character, whether the header is written and how the
+            ``None`` values are represented. If not specified, every field is quoted,
+            the delimiter is a comma and the output starts with a header.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -223,6 +257,22 @@ def write(
     the input. All in all, the extra information in ``time`` and ``diff`` columns - in this case -
     shows us that in the initial minibatch (``time = 0``), you have read three rows and all of
     them were added to the collection (``diff = 1``).
+
+    The dialect of the output can be adjusted to what the loader of the file expects.
+    For instance, the table can be written as tab-separated values without the header,
+    quoting only the fields that need it, and with the ``None`` values written as
+    ``NULL``:
+
+    >>> pw.io.csv.write(
+    ...     t,
+    ...     "table.tsv",
+    ...     csv_settings=pw.io.CsvFormatterSettings(
+    ...         delimiter="\\t",
+    ...         quoting="necessary",
+    ...         write_header=False,
+    ...         null_value="NULL",
+    ...     ),
+    ... )
     """
 
     pw.io.fs.write(
@@ -231,4 +281,8 @@ def write(
         format="csv",
         name=name,
         sort_by=sort_by,
+        csv_settings=csv_settings,
+        queue_capacity=queue_capacity,
+        max_batch_size=max_batch_size,
+        linger_ms=linger_ms,
     )
@@ -537,6 +537,9 @@ def write(
     sort_by: Iterable[ColumnReference] | None = None,
     output_table_type: Literal["stream_of_changes", "snapshot"] = "stream_of_changes",
     table_optimizer: TableOptimizer | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """
     Writes the stream of changes from ``table`` into `Delta Lake <https://delta.io/>_` data
@@ -591,6 +594,18 @@ def write(
             the output. Please also note that this method is not suitable for the tables that don't
             fit in memory.**
         table_optimizer: The optimization parameters for the output table.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -696,6 +711,9 @@ def write(
                 if table_optimizer is not None
                 else None
             ),
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
         )
     )
 
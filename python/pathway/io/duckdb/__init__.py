@@ -51,6 +51,8 @@ def write(
     detach_between_batches: bool = False,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes ``table`` into a table of a `DuckDB <https://duckdb.org/>`_ database
     file. DuckDB is an in-process analytical database, so this connector needs no
@@ -152,6 +154,15 @@ def write(
             on the values of the given columns within each minibatch. When multiple
             columns are provided, the corresponding value tuples will be compared
             lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
 
     Returns:
         None
@@ -389,6 +400,8 @@ def write(
             datasink_name=f"duckdb.{datasink_type}",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
         )
     )
 
@@ -24,6 +24,9 @@ def write(
     sort_key: ColumnReference | None = None,
     init_mode: Literal["default", "create_if_not_exists", "replace"] = "default",
     name: str | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """
     Writes ``table`` into a DynamoDB table. The connection settings are retrieved from
@@ -54,6 +57,11 @@ def write(
     `Postgres </developers/api-docs/pathway-io/postgres#pathway.io.postgres.write_snapshot>`_
     output connector.
 
+    The changes of a minibatch are sent when it's committed, in ``BatchWriteItem``
+    requests of up to 25 items. The items throttled by DynamoDB are retried with an
+    exponential backoff, so a table with the provisioned capacity lower than the write
+    rate slows the pipeline down instead of losing the changes.
+
     Args:
         table: The table to write.
         table_name: The name of the destination table in DynamoDB.
@@ -72,6 +80,18 @@ def write(
         init_mode: The table initialization mode, one of the three described above.
         name: A unique name for the connector. If provided, this name will be used in
             logs and monitoring dashboards.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -266,5 +286,8 @@ def write(
             data_format,
             datasink_name="dynamodb",
             unique_name=name,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
         )
     )
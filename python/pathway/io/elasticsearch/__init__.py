@@ -102,6 +102,9 @@ def write(
     *,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
+    queue_capacity: int | None = None,
 ) -> None:
     """Write a table to a given index in ElasticSearch.
 
@@ -122,6 +125,17 @@ def write(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        max_batch_size: The maximal number of documents sent in a single bulk request.
+            The larger minibatches are split into several requests.
+        linger_ms: For how long, in milliseconds, the documents can be held back so
+            that the documents of the next minibatches are sent in the same bulk
+            request, unless it exceeds ``max_batch_size``. A minibatch is considered
+            written only once it has been sent. If not specified, the documents are
+            sent after each minibatch.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
 
     Returns:
         None
@@ -181,6 +195,9 @@ def write(
             datasink_name="elasticsearch",
             unique_name=name,
             sort_by=sort_by,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
+            queue_capacity=queue_capacity,
         )
     )
 
@@ -0,0 +1,268 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+from typing import Iterable, Literal
+
+from pathway.internals import api, datasink, datasource
+from pathway.internals.expression import ColumnReference
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.schema import Schema
+from pathway.internals.table import Table
+from pathway.internals.table_io import table_from_datasource
+from pathway.internals.trace import trace_user_frame
+from pathway.io._utils import (
+    MessageQueueOutputFormat,
+    _get_unique_name,
+    construct_schema_and_data_format,
+)
+
+
+@check_arg_types
+@trace_user_frame
+def read(
+    namespace: str,
+    eventhub_name: str,
+    *,
+    schema: type[Schema] | None = None,
+    format: Literal["plaintext", "raw", "json"] = "raw",
+    autocommit_duration_ms: int = 1500,
+    json_field_paths: dict[str, str] | None = None,
+    name: str | None = None,
+    max_backlog_size: int | None = None,
+    debug_data=None,
+    **kwargs,
+) -> Table:
+    """
+    Reads a table from an
+    `Azure Event Hub <https://learn.microsoft.com/en-us/azure/event-hubs/event-hubs-about>`_
+    over the AMQP protocol. The credentials are retrieved from the environment, in the
+    same way as by the ``DefaultAzureCredential`` of the Azure SDK, for example from
+    the ``AZURE_CLIENT_ID``, ``AZURE_TENANT_ID`` and ``AZURE_CLIENT_SECRET`` variables.
+
+    The partitions of the event hub are distributed between the workers. The connector
+    starts reading each partition from its earliest retained event. If persistence is
+    enabled, the sequence number of the last event read from each partition is saved
+    with the connector's progress, and after a restart the partitions are read from the
+    next events.
+
+    There are three supported formats: ``"plaintext"``, ``"raw"``, and ``"json"``.
+
+    For the ``"raw"`` format, the event body is read as raw bytes and added directly
+    to the table. In the ``"plaintext"`` format, the event body is stored as plain
+    text. In both cases, the table will have an autogenerated primary key and a single
+    ``"data"`` column representing the event body.
+
+    If you select the ``"json"`` format, the connector parses the event body as JSON
+    and creates table columns based on the schema provided in the ``schema`` parameter.
+    The column values come from the corresponding JSON fields.
+
+    Args:
+        namespace: The fully qualified name of the Event Hubs namespace, for example
+            ``"my-namespace.servicebus.windows.net"``.
+        eventhub_name: The name of the event hub to be read.
+        schema: The table schema, used only when the format is set to ``"json"``.
+        format: The input data format, which can be ``"raw"``, ``"plaintext"``, or
+            ``"json"``.
+        autocommit_duration_ms: The time interval (in milliseconds) between commits.
+            After this time, the updates received by the connector are committed and
+            added to Pathway Live Data Framework's computation graph.
+        json_field_paths: For the ``"json"`` format, this allows mapping field names to
+            paths within the JSON structure. Use the format ``<field_name>: <path>``
+            where the path follows the
+            `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards. Additionally, if persistence is enabled, it
+            will be used as the name for the snapshot that stores the connector's progress.
+        max_backlog_size: Limit on the number of entries read from the input source and kept
+            in processing at any moment. Reading pauses when the limit is reached and resumes
+            as processing of some entries completes. Useful with large sources that
+            emit an initial burst of data to avoid memory spikes.
+        debug_data: Static data replacing original one when debug mode is active.
+
+    Returns:
+        Table: The table read.
+
+    Example:
+
+    Suppose that the namespace ``"my-namespace.servicebus.windows.net"`` has the event
+    hub ``"events"``, whose events are JSON objects with the fields ``user`` and
+    ``action``:
+
+    >>> import pathway as pw
+    >>> class InputSchema(pw.Schema):
+    ...     user: str
+    ...     action: str
+
+    You can read them as follows:
+
+    >>> table = pw.io.eventhubs.read(
+    ...     "my-namespace.servicebus.windows.net",
+    ...     "events",
+    ...     format="json",
+    ...     schema=InputSchema,
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    data_storage = api.DataStorage(
+        storage_type="eventhubs",
+        path=namespace,
+        topic=eventhub_name,
+        mode=api.ConnectorMode.STREAMING,
+    )
+    schema, data_format = construct_schema_and_data_format(
+        "binary" if format == "raw" else format,
+        schema=schema,
+        csv_settings=None,
+        json_field_paths=json_field_paths,
+    )
+    data_source_options = datasource.DataSourceOptions(
+        commit_duration_ms=autocommit_duration_ms,
+        unique_name=_get_unique_name(name, kwargs),
+        max_backlog_size=max_backlog_size,
+    )
+    return table_from_datasource(
+        datasource.GenericDataSource(
+            datastorage=data_storage,
+            dataformat=data_format,
+            data_source_options=data_source_options,
+            schema=schema,
+            datasource_name="eventhubs",
+        ),
+        debug_datasource=datasource.debug_datasource(debug_data),
+    )
+
+
+@check_arg_types
+@trace_user_frame
+def write(
+    table: Table,
+    namespace: str,
+    eventhub_name: str,
+    *,
+    format: Literal["json", "dsv", "plaintext", "raw"] = "json",
+    delimiter: str = ",",
+    data: ColumnReference | None = None,
+    partition_key: ColumnReference | None = None,
+    headers: Iterable[ColumnReference] | None = None,
+    name: str | None = None,
+    sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
+) -> None:
+    """Sends the stream of changes of ``table`` to an
+    `Azure Event Hub <https://learn.microsoft.com/en-us/azure/event-hubs/event-hubs-about>`_
+    over the AMQP protocol. The credentials are retrieved from the environment, in the
+    same way as by ``pw.io.eventhubs.read``.
+
+    The events consist of the body, corresponding to the values of the table that are
+    serialized according to the chosen format, and two properties: ``pathway_time``,
+    corresponding to the processing time of the entry, and ``pathway_diff`` that is
+    either ``1`` or ``-1``. If the ``headers`` parameter is used, additional properties
+    can be added to the events.
+
+    The events are sent in batches at the end of each minibatch. If ``partition_key``
+    is given, the events with the same key are sent to the same partition, so they are
+    read in the order they were written.
+
+    Args:
+        table: The table to write.
+        namespace: The fully qualified name of the Event Hubs namespace, for example
+            ``"my-namespace.servicebus.windows.net"``.
+        eventhub_name: The name of the event hub the events are sent to.
+        format: The format in which the events are serialized: ``"json"``, ``"dsv"``,
+            ``"plaintext"`` or ``"raw"``. In the ``"plaintext"`` and ``"raw"`` formats,
+            the event body is taken from the single column of the table, or from the
+            ``data`` column.
+        delimiter: The field delimiter, used only with the ``"dsv"`` format.
+        data: Reference to the column used as the event body in the ``"plaintext"``
+            and ``"raw"`` formats. It has to be specified if the table has more than one
+            column.
+        partition_key: Reference to the column whose values are used as the partition
+            keys of the events. If the values aren't strings, their string
+            representations are used.
+        headers: References to the columns whose values are added to the event
+            properties, under the names of the columns.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards.
+        sort_by: If specified, the output will be sorted in ascending order based on the
+            values of the given columns within each minibatch. When multiple columns are provided,
+            the corresponding value tuples will be compared lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
+
+    Returns:
+        None
+
+    Example:
+
+    Suppose that you want to send the rows of a table ``events`` with a ``user``
+    column to the event hub ``"events"`` in the namespace
+    ``"my-namespace.servicebus.windows.net"``, so that the events of each user are read
+    in order:
+
+    >>> import pathway as pw
+    >>> events = pw.debug.table_from_markdown(
+    ...     '''
+    ...     user  | action
+    ...     alice | login
+    ...     bob   | logout
+    ... '''
+    ... )
+    >>> pw.io.eventhubs.write(
+    ...     events,
+    ...     "my-namespace.servicebus.windows.net",
+    ...     "events",
+    ...     partition_key=events.user,
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    output_format = MessageQueueOutputFormat.construct(
+        table,
+        format=format,
+        delimiter=delimiter,
+        key=partition_key,
+        value=data,
+        headers=headers,
+        allowed_key_types=None,
+    )
+    table = output_format.table
+
+    data_storage = api.DataStorage(
+        storage_type="eventhubs",
+        path=namespace,
+        topic=eventhub_name,
+        key_field_index=output_format.key_field_index,
+        header_fields=list(output_format.header_fields.items()),
+    )
+
+    table.to(
+        datasink.GenericDataSink(
+            data_storage,
+            output_format.data_format,
+            datasink_name="eventhubs",
+            unique_name=name,
+            sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
+        )
+    )
+
+
+__all__ = ["read", "write"]
@@ -0,0 +1,73 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+from pathway.internals import api, datasink
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.table import Table
+from pathway.internals.trace import trace_user_frame
+
+DEFAULT_FLIGHT_PORT = 8815
+
+
+@check_arg_types
+@trace_user_frame
+def serve_table(
+    table: Table,
+    name: str,
+    *,
+    host: str = "127.0.0.1",
+    port: int = DEFAULT_FLIGHT_PORT,
+) -> None:
+    """Serves ``table`` over Arrow Flight, so that other processes can consume it as
+    Arrow record batches. See the
+    `Arrow Flight documentation <https://arrow.apache.org/docs/format/Flight.html>`_.
+
+    The server listens on ``host``, on ``port`` increased by the index of the process,
+    and each process serves the rows computed by its own workers. A ``DoGet`` request
+    with ``name`` as the ticket streams the table: the first batch is the state of the
+    table at the time of the request and each next batch contains the changes done
+    since the previous one, sent once a time of the computation is finished. The
+    stream ends when the computation is finished.
+
+    Besides the columns of the table, the batches have the ``id`` column with the ids
+    of the rows and the ``time`` and ``diff`` columns, with the time of the change and
+    ``1`` for an insertion or ``-1`` for a deletion. In the first batch, ``time`` is
+    the time of the last change of the row. The columns of types with no Arrow
+    counterpart are sent as strings and the errors are sent as nulls. The schema of
+    the served tables can be retrieved with ``ListFlights``, ``GetFlightInfo`` and
+    ``GetSchema``.
+
+    Args:
+        table: Table to be served.
+        name: Name of the table on the server, used as the ticket of its stream.
+        host: Host on which the server listens.
+        port: Port on which the server of the first process listens. The tables
+            served on the same port share the server.
+
+    Returns:
+        None
+
+    Example:
+
+    >>> import pathway as pw
+    >>> pets = pw.debug.table_from_markdown("owner pet \\n Alice dog \\n Bob cat")
+    >>> pw.io.flight.serve_table(pets, "pets")
+
+    After ``pw.run()`` is started, the table can be read with ``pyarrow``:
+
+    >>> import pyarrow.flight  # doctest: +SKIP
+    >>> client = pyarrow.flight.connect("grpc://localhost:8815")  # doctest: +SKIP
+    >>> reader = client.do_get(pyarrow.flight.Ticket(b"pets"))  # doctest: +SKIP
+    >>> for chunk in reader:  # doctest: +SKIP
+    ...     print(chunk.data.to_pandas())
+    """
+    if not name:
+        raise ValueError("the name of a served table must be non-empty")
+    # IPv6 addresses have to be bracketed to be followed by a port
+    address = f"[{host}]:{port}" if ":" in host else f"{host}:{port}"
+    flight_endpoint = api.FlightEndpoint(address, name, table.column_names())
+    table.to(datasink.ExportDataSink(lambda _scope, _table: None, flight_endpoint))
+
+
+__all__ = ["serve_table"]
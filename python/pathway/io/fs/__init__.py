@@ -14,7 +14,10 @@ from pathway.internals.table import Table
 from pathway.internals.table_io import table_from_datasource
 from pathway.internals.trace import trace_user_frame
 from pathway.io._utils import (
+    CsvFormatterSettings,
     CsvParserSettings,
+    JsonFormatterSettings,
+    SchemaEvolution,
     _get_unique_name,
     construct_schema_and_data_format,
     internal_connector_mode,
@@ -24,6 +27,7 @@ from pathway.io._utils import (
 SUPPORTED_OUTPUT_FORMATS: set[str] = {
     "csv",
     "json",
+    "template",
 }
 
 
@@ -32,13 +36,20 @@ SUPPORTED_OUTPUT_FORMATS: set[str] = {
 def read(
     path: str | PathLike,
     format: Literal[
-        "csv", "json", "plaintext", "plaintext_by_file", "binary", "only_metadata"
+        "csv",
+        "json",
+        "plaintext",
+        "plaintext_by_file",
+        "binary",
+        "only_metadata",
+        "orc",
     ],
     *,
     schema: type[Schema] | None = None,
     mode: Literal["streaming", "static"] = "streaming",
     csv_settings: CsvParserSettings | None = None,
     json_field_paths: dict[str, str] | None = None,
+    schema_evolution: SchemaEvolution | None = None,
     object_pattern: str = "*",
     with_metadata: bool = False,
     name: str | None = None,
@@ -60,6 +71,11 @@ def read(
     opening and without reading the contents of the files. The metadata is then available
     in the ``_metadata`` column.
 
+    If the format is ``"orc"``, the files are decoded as
+    `Apache ORC <https://orc.apache.org/>`_ files, each row of a file becoming a row of
+    the table. Only the columns listed in the schema are decoded, so wide tables
+    written by Hive can be ingested without reading the columns that aren't needed.
+
     Args:
         path: Path to the file or to the folder with files or
             `glob <https://en.wikipedia.org/wiki/Glob_(programming)>`_ pattern for the
@@ -71,9 +87,11 @@ def read(
             how the input is tokenized: if the ``"plaintext"`` option is chosen, it's split
             by the newlines. Otherwise, the files are split in full and one row will
             correspond to one file. In case the ``"binary"`` format is specified,
-            the data is read as raw bytes without UTF-8 parsing. Finally, if ``"only_metadata"``
+            the data is read as raw bytes without UTF-8 parsing. If ``"only_metadata"``
             is chosen, the connector only scans the filesystem for file additions,
-            changes, modifications, and provides them in the metadata column.
+            changes, modifications, and provides them in the metadata column. Finally,
+            the ``"orc"`` format reads the rows of ORC files, decoding only the columns
+            of the schema.
         schema: Schema of the resulting table.
         mode: Denotes how the engine polls the new data from the source. Currently
             ``"streaming"`` and ``"static"`` are supported. If set to ``"streaming"`` the engine will wait for
@@ -89,6 +107,13 @@ def read(
             it should be given in the format ``<field_name>: <path to be mapped>``,
             where the path to be mapped needs to be a
             `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        schema_evolution: How the data not matching the schema is handled, if the format
+            is ``"csv"`` or ``"json"``. With ``"ignore_new_fields"``, the fields (the
+            columns of the CSV header) not present in the schema are ignored. With
+            ``"fill_missing"``, additionally the missing fields are ``None``. With
+            ``"fail"``, both the fields not present in the schema and the missing fields
+            are errors. A missing field always takes the default value of its column if
+            the schema declares one. Defaults to ``"ignore_new_fields"``.
         object_pattern: Unix shell style pattern for filtering only certain files in the
             directory. Ignored in case a path to a single file is specified. This value will be
             deprecated soon, please use glob pattern in ``path`` instead.
@@ -244,6 +269,7 @@ def read(
         with_metadata=with_metadata,
         csv_settings=csv_settings,
         json_field_paths=json_field_paths,
+        schema_evolution=schema_evolution,
         _stacklevel=_stacklevel + 4,
     )
 
@@ -274,23 +300,73 @@ def read(
 def write(
     table: Table,
     filename: str | PathLike,
-    format: Literal["json", "csv"],
+    format: Literal["json", "csv", "template"],
     *,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    template: str | None = None,
+    template_escaping: Literal["none", "html", "json", "single_line"] = "none",
+    csv_settings: CsvFormatterSettings | None = None,
+    json_settings: JsonFormatterSettings | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes ``table``'s stream of updates to a file in the given format.
 
     Args:
         table: Table to be written.
         filename: Path to the target output file.
-        format: Format to use for data output. Currently, there are two supported
-            formats: ``"json"`` and ``"csv"``.
+        format: Format to use for data output. Currently, there are three supported
+            formats: ``"json"``, ``"csv"``, and ``"template"``. The latter renders
+            each change through the text given in the ``template`` parameter.
         name: A unique name for the connector. If provided, this name will be used in
             logs and monitoring dashboards.
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        template: The text template used by the ``"template"`` format. The
+            placeholders in double curly braces, such as ``{{ owner }}``, are replaced
+            with the values of the columns, and ``{{ time }}`` and ``{{ diff }}`` refer
+            to the time and the diff of the change. A value can be passed through
+            filters: ``upper``, ``lower``, ``trim``, ``default("text")``,
+            ``ljust(width)``, ``rjust(width)``, ``center(width)`` (the fill character
+            can be given as the second argument, as in ``rjust(5, "0")``),
+            ``truncate(width)``, ``json``, ``escape(mode)``, which escapes the value
+            in one of the modes of ``template_escaping`` (``"html"`` if the mode is
+            not given), and ``safe``, which exempts the value from the escaping set
+            by ``template_escaping``. Each change is written as the rendered text
+            followed by a newline.
+        template_escaping: How the values rendered by the ``template`` are escaped.
+            With ``"none"``, they are inserted as they are. With ``"html"``, the
+            characters ``&``, ``<``, ``>``, ``"`` and ``'`` are replaced with the HTML
+            entities. With ``"json"``, the values are escaped as the contents of a
+            JSON string, without the quotes. With ``"single_line"``, the backslashes
+            and the line breaks are escaped with a backslash, so that a value
+            containing a newline can't split the output line in two, which is useful
+            for the syslog-like outputs. The string literals of the template and the
+            placeholders with the ``escape`` or the ``safe`` filter aren't escaped.
+        csv_settings: The dialect of the ``"csv"`` output: the delimiter, the quoting
+            of the fields, the escape character, whether the header is written and how
+            the ``None`` values are represented. If not specified, every field is
+            quoted, the delimiter is a comma and the output starts with a header.
+        json_settings: The shape of the ``"json"`` output: the locations of the
+            columns in the output objects, whether the ``time`` and ``diff`` fields are
+            added, and how the datetime values are encoded. If not specified, each
+            column is a top-level field named after it, the ``time`` and ``diff``
+            fields are added, and the datetimes are encoded as strings.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -347,6 +423,37 @@ def write(
 
     As one can easily see, the values remain the same, while the format has changed to
     a plain JSON.
+
+    Finally, for a fixed format expected by a legacy consumer, you can render each
+    change through a template:
+
+    >>> pw.io.fs.write(
+    ...     t,
+    ...     "table.txt",
+    ...     format="template",
+    ...     template=(
+    ...         '{{ owner | upper | ljust(6) }}{{ age | rjust(3, "0") }} {{ pet }}'
+    ...     ),
+    ... )
+
+    The output file then looks as follows:
+
+    .. code-block:: text
+
+        ALICE 010 dog
+        BOB   009 cat
+        ALICE 008 cat
+
+    If the values may contain line breaks, for instance free-text comments, they can be
+    escaped so that each change still occupies exactly one line of the output:
+
+    >>> pw.io.fs.write(
+    ...     t,
+    ...     "pets.log",
+    ...     format="template",
+    ...     template="owner={{ owner }} pet={{ pet }}",
+    ...     template_escaping="single_line",
+    ... )
     """
 
     if format not in SUPPORTED_OUTPUT_FORMATS:
@@ -356,19 +463,52 @@ def write(
             )
         )
 
+    if (format == "template") != (template is not None):
+        raise ValueError(
+            "The template parameter must be provided if and only if "
+            "the format is 'template'"
+        )
+    if template_escaping != "none" and format != "template":
+        raise ValueError(
+            "The template_escaping parameter is only supported by 'template' format"
+        )
+    if csv_settings is not None and format != "csv":
+        raise ValueError("The csv_settings parameter is only supported by 'csv' format")
+    if json_settings is not None and format != "json":
+        raise ValueError(
+            "The json_settings parameter is only supported by 'json' format"
+        )
+
     data_storage = api.DataStorage(storage_type="fs", path=fspath(filename))
     if format == "csv":
         data_format = api.DataFormat(
             format_type="dsv",
             key_field_names=[],
             value_fields=_format_output_value_fields(table),
-            delimiter=",",
+            delimiter=csv_settings.delimiter if csv_settings else ",",
+            csv_formatter_settings=(
+                csv_settings.api_settings if csv_settings else None
+            ),
         )
     elif format == "json":
         data_format = api.DataFormat(
             format_type="jsonlines",
             key_field_names=[],
             value_fields=_format_output_value_fields(table),
+            with_special_fields=(
+                json_settings.include_time_and_diff if json_settings else True
+            ),
+            json_formatter_settings=(
+                json_settings.api_settings if json_settings else None
+            ),
+        )
+    elif format == "template":
+        data_format = api.DataFormat(
+            format_type="template",
+            key_field_names=[],
+            value_fields=_format_output_value_fields(table),
+            template=template,
+            template_escaping=template_escaping,
         )
 
     table.to(
@@ -378,5 +518,8 @@ def write(
             datasink_name="fs",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
         )
     )
@@ -0,0 +1,182 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+import json
+from typing import Any
+
+from pathway.internals import api, datasource
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.schema import Schema
+from pathway.internals.table import Table
+from pathway.internals.table_io import table_from_datasource
+from pathway.internals.trace import trace_user_frame
+from pathway.io._utils import _get_unique_name, construct_schema_and_data_format
+
+
+@check_arg_types
+@trace_user_frame
+def read(
+    url: str,
+    method: str,
+    schema: type[Schema],
+    *,
+    request: dict[str, Any] | None = None,
+    descriptor_set_path: str | None = None,
+    metadata: dict[str, str] | None = None,
+    offset_field: str | None = None,
+    resume_field: str | None = None,
+    autocommit_duration_ms: int = 1500,
+    json_field_paths: dict[str, str] | None = None,
+    name: str | None = None,
+    max_backlog_size: int | None = None,
+    debug_data=None,
+    **kwargs,
+) -> Table:
+    """
+    Reads a table from the messages of a server-streaming
+    `gRPC <https://grpc.io/>`_ call.
+
+    The method doesn't need to be compiled in: its descriptors are loaded from
+    ``descriptor_set_path``, a file written by
+    ``protoc --descriptor_set_out=<path> --include_imports``, or, if it's not given,
+    are requested from the server with the
+    `server reflection <https://grpc.io/docs/guides/reflection/>`_. The request is
+    given in its JSON representation.
+
+    Each response message becomes a row. The message is converted to JSON with the
+    field names as in the ``.proto`` file, and the columns of the ``schema`` are taken
+    from the corresponding fields. The fields set to their default values are included,
+    so every message has all the columns.
+
+    If the call fails, for example because the server restarts, it is opened again.
+    To continue the stream instead of starting it over, name a field of the response
+    messages identifying their position in the stream in ``offset_field``, and a field
+    of the request in ``resume_field``. The value of ``offset_field`` of the last
+    message read is then sent in ``resume_field`` when the call is opened again, and
+    the server is expected to send the messages following it. If persistence is
+    enabled, this value is also saved with the connector's progress, so the stream is
+    continued from it when the program is restarted. If the server completes the call,
+    the reading finishes.
+
+    The call is made by a single worker.
+
+    Args:
+        url: The address of the server, for example ``"http://localhost:50051"``. For
+            an ``https://`` address, the connection is secured with TLS.
+        method: The full name of the server-streaming method, in the form
+            ``"package.Service/Method"``.
+        schema: The schema of the table read.
+        request: The request message in its JSON representation. If not specified,
+            the request is empty.
+        descriptor_set_path: The path of the encoded ``FileDescriptorSet`` describing
+            the method. If not specified, the server reflection is used.
+        metadata: The metadata sent with the call, for example the
+            ``authorization`` header.
+        offset_field: The field of the response messages bookmarking their position in
+            the stream. Must be specified together with ``resume_field``.
+        resume_field: The field of the request receiving the value of ``offset_field``
+            when the call is opened again.
+        autocommit_duration_ms: The time interval (in milliseconds) between commits.
+            After this time, the updates received by the connector are committed and
+            added to Pathway Live Data Framework's computation graph.
+        json_field_paths: This allows mapping field names to paths within the JSON
+            representation of the messages. Use the format ``<field_name>: <path>``
+            where the path follows the
+            `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards. Additionally, if persistence is enabled, it
+            will be used as the name for the snapshot that stores the connector's progress.
+        max_backlog_size: Limit on the number of entries read from the input source and kept
+            in processing at any moment. Reading pauses when the limit is reached and resumes
+            as processing of some entries completes. Useful with large sources that
+            emit an initial burst of data to avoid memory spikes.
+        debug_data: Static data replacing original one when debug mode is active.
+
+    Returns:
+        Table: The table read.
+
+    Example:
+
+    Suppose that a service running on ``localhost:50051`` with the server reflection
+    enabled is defined as follows:
+
+    .. code-block:: protobuf
+
+        package prices;
+
+        message SubscribeRequest {
+          string symbol = 1;
+          int64 after_sequence = 2;
+        }
+
+        message PriceUpdate {
+          int64 sequence = 1;
+          string symbol = 2;
+          double price = 3;
+        }
+
+        service Prices {
+          rpc Subscribe(SubscribeRequest) returns (stream PriceUpdate);
+        }
+
+    The price updates can be read, continuing after the last update read when the call
+    is opened again, as follows:
+
+    >>> import pathway as pw
+    >>> class PriceSchema(pw.Schema):
+    ...     sequence: int
+    ...     symbol: str
+    ...     price: float
+    >>> prices = pw.io.grpc.read(
+    ...     "http://localhost:50051",
+    ...     "prices.Prices/Subscribe",
+    ...     PriceSchema,
+    ...     request={"symbol": "ACME"},
+    ...     offset_field="sequence",
+    ...     resume_field="after_sequence",
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    if (offset_field is None) != (resume_field is None):
+        raise ValueError("offset_field and resume_field must be specified together")
+
+    data_storage = api.DataStorage(
+        storage_type="grpc",
+        path=url,
+        mode=api.ConnectorMode.STREAMING,
+        grpc_settings=api.GrpcSettings(
+            method=method,
+            request=json.dumps(request or {}),
+            descriptor_set_path=descriptor_set_path,
+            metadata=list((metadata or {}).items()),
+            offset_field=offset_field,
+            resume_field=resume_field,
+        ),
+    )
+    schema, data_format = construct_schema_and_data_format(
+        "json",
+        schema=schema,
+        csv_settings=None,
+        json_field_paths=json_field_paths,
+    )
+    data_source_options = datasource.DataSourceOptions(
+        commit_duration_ms=autocommit_duration_ms,
+        unique_name=_get_unique_name(name, kwargs),
+        max_backlog_size=max_backlog_size,
+    )
+    return table_from_datasource(
+        datasource.GenericDataSource(
+            datastorage=data_storage,
+            dataformat=data_format,
+            data_source_options=data_source_options,
+            schema=schema,
+            datasource_name="grpc",
+        ),
+        debug_datasource=datasource.debug_datasource(debug_data),
+    )
+
+
+__all__ = ["read"]
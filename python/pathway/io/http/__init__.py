@@ -3,9 +3,11 @@
 from __future__ import annotations
 
 from collections.abc import Callable
-from typing import Any, Literal
+from typing import Any, Iterable, Literal
 
+from pathway.internals import api, datasink
 from pathway.internals.api import Pointer
+from pathway.internals.expression import ColumnReference
 from pathway.internals.runtime_type_check import check_arg_types
 from pathway.internals.schema import Schema
 from pathway.internals.table import Table
@@ -13,7 +15,15 @@ from pathway.internals.trace import trace_user_frame
 from pathway.io import python
 
 from .._subscribe import subscribe
-from ._common import RetryPolicy, Sender, prepare_request_payload, unescape
+from ._common import (
+    RetryPolicy,
+    Sender,
+    compress_payload,
+    default_content_type,
+    prepare_batch_payload,
+    prepare_request_payload,
+    unescape,
+)
 from ._server import (
     EndpointDocumentation,
     EndpointExamples,
@@ -163,6 +173,9 @@ def write(
     headers: dict[str, str] | None = None,
     allow_redirects: bool = True,
     retry_codes: tuple | None = (429, 500, 502, 503, 504),
+    batch_format: Literal["ndjson", "json_array"] | None = None,
+    max_batch_size: int | None = None,
+    compression: Literal["gzip", "zstd"] | None = None,
     name: str | None = None,
 ) -> None:
     """Sends the stream of updates from the table to the specified HTTP API.
@@ -186,9 +199,23 @@ def write(
         allow_redirects: Whether to allow redirects.
         retry_codes: HTTP status codes that trigger retries.
         content_type: content type of the data to send. In case the chosen format is
-          JSON, it will be defaulted to "application/json".
+          JSON, it will be defaulted to "application/json". For the batches sent in
+          the "ndjson" format, the default is "application/x-ndjson".
         headers: request headers in the form of dict. Wildcards are allowed both, in
           keys and in values.
+        batch_format: if specified, the rows changed within the same minibatch are
+          sent together in a single request instead of one request per row. It can
+          be either "ndjson", with one JSON object per line, or "json_array", with
+          the body being a JSON array of objects. Batching requires the "json" format.
+          The rows are grouped by the URL and headers obtained after resolving the
+          wildcards, so each request goes to the right destination.
+        max_batch_size: the maximum number of rows sent in a single batch request.
+          Bigger minibatches are split into several requests. If not specified, the
+          whole minibatch is sent at once. Can only be used together with
+          ``batch_format``.
+        compression: the compression of the request body, either "gzip" or "zstd".
+          The ``Content-Encoding`` header is set accordingly. The "zstd" compression
+          requires the ``zstandard`` package.
         name: A unique name for the connector. If provided, this name will be used in
             logs and monitoring dashboards.
 
@@ -249,8 +276,30 @@ def write(
     ...     format="custom",
     ...     request_payload_template=message_template
     ... )
+
+    Finally, if the endpoint accepts many events at once, the changes can be sent in
+    batches. Here, each minibatch is sent as a gzip-compressed NDJSON body, split into
+    requests of at most 1000 rows:
+
+    >>> pw.io.http.write(
+    ...     pets,
+    ...     "http://www.example.com/api/events",
+    ...     batch_format="ndjson",
+    ...     max_batch_size=1000,
+    ...     compression="gzip",
+    ... )
     """
 
+    if batch_format is not None and format != "json":
+        raise ValueError("batch_format can only be used with the 'json' format")
+    if max_batch_size is not None:
+        if batch_format is None:
+            raise ValueError(
+                "max_batch_size can only be used together with batch_format"
+            )
+        if max_batch_size <= 0:
+            raise ValueError("max_batch_size must be positive")
+
     sender = Sender(
         request_method=method,
         n_retries=n_retries,
@@ -261,11 +310,9 @@ def write(
         retry_codes=retry_codes,
     )
 
-    def callback(key: Pointer, row: dict[str, Any], time: int, is_addition: bool):
-        payload = prepare_request_payload(
-            row, time, is_addition, format, request_payload_template
-        )
+    request_content_type = content_type or default_content_type(format, batch_format)
 
+    def prepare_headers(row: dict[str, Any], time: int, is_addition: bool):
         patched_headers = {}
         if headers:
             for k, v in headers.items():
@@ -273,23 +320,184 @@ def write(
                 unescaped_value = unescape(v, row, time, is_addition)
                 patched_headers[unescaped_key] = unescaped_value
 
-        if content_type:
-            patched_headers["Content-Type"] = content_type
-        elif format == "json":
-            patched_headers["Content-Type"] = "application/json"
+        if request_content_type:
+            patched_headers["Content-Type"] = request_content_type
+        if compression:
+            patched_headers["Content-Encoding"] = compression
 
+        return patched_headers
+
+    def send(url: str, headers: dict[str, str], payload: str):
         sender.send(
-            url=unescape(url, row, time, is_addition),
-            headers=patched_headers,
-            data=payload,
+            url=url,
+            headers=headers,
+            data=compress_payload(payload, compression),
         )
 
-    subscribe(table, callback, name=name)
+    if batch_format is None:
+
+        def callback(key: Pointer, row: dict[str, Any], time: int, is_addition: bool):
+            payload = prepare_request_payload(
+                row, time, is_addition, format, request_payload_template
+            )
+            send(
+                unescape(url, row, time, is_addition),
+                prepare_headers(row, time, is_addition),
+                payload,
+            )
+
+        subscribe(table, callback, name=name)
+        return
+
+    # rows of the current minibatch, grouped by the resolved URL and headers
+    pending_batches: dict[tuple[str, tuple[tuple[str, str], ...]], list[str]] = {}
+
+    def on_change(key: Pointer, row: dict[str, Any], time: int, is_addition: bool):
+        destination = (
+            unescape(url, row, time, is_addition),
+            tuple(prepare_headers(row, time, is_addition).items()),
+        )
+        payload = prepare_request_payload(row, time, is_addition, format, None)
+        pending_batches.setdefault(destination, []).append(payload)
+
+    def flush():
+        for (batch_url, batch_headers), rows in pending_batches.items():
+            chunk_size = max_batch_size or len(rows)
+            for start in range(0, len(rows), chunk_size):
+                chunk = rows[start : start + chunk_size]
+                send(
+                    batch_url,
+                    dict(batch_headers),
+                    prepare_batch_payload(chunk, batch_format),
+                )
+        pending_batches.clear()
+
+    def on_time_end(time: int):
+        flush()
+
+    subscribe(table, on_change, on_end=flush, on_time_end=on_time_end, name=name)
+
+
+@check_arg_types
+@trace_user_frame
+def serve_table(table: Table, route: str) -> None:
+    """Serves the current state of ``table`` over HTTP, under ``/tables/<route>``.
+
+    The table is served by the monitoring HTTP server, so the computation has to be
+    started with ``pw.run(with_http_server=True)``. The server listens on
+    ``localhost``, on the port given by the ``PATHWAY_MONITORING_HTTP_PORT`` environment
+    variable (``20000`` by default) increased by the index of the process. The following
+    requests are supported:
+
+    - ``GET /tables`` lists the served routes together with the columns of the tables;
+    - ``GET /tables/<route>`` returns all rows of the table. The rows can be filtered by
+      equality on columns with query parameters, e.g. ``?owner=Alice&age=3``. The
+      values are parsed as JSON and taken as strings if they are not valid JSON;
+    - ``GET /tables/<route>/<id>`` returns the row with the given id. Query parameters
+      given with it are applied as filters, in the same way.
+
+    Each answer is computed from a consistent state of the table, that is, the state
+    with all the changes up to the time given in the ``frontier`` field of the response
+    and none after it (``null`` means that the computation has finished). The rows are
+    returned as JSON objects with the columns of the table and the ``id`` of the row.
+
+    Args:
+        table: Table to be served.
+        route: Name of the route of the table. It must be non-empty and must not
+            contain ``/``.
+
+    Returns:
+        None
+
+    Example:
+
+    >>> import pathway as pw
+    >>> pets = pw.debug.table_from_markdown("owner pet \\n Alice dog \\n Bob cat")
+    >>> pw.io.http.serve_table(pets, "pets")
+
+    After ``pw.run(with_http_server=True)`` is started, the pets of Alice can be
+    retrieved with ``curl "http://localhost:20000/tables/pets?owner=Alice"``.
+    """
+    if not route or "/" in route:
+        raise ValueError(
+            f"invalid route {route!r}, it must be non-empty and must not contain '/'"
+        )
+    column_names = table.column_names()
+
+    def register(scope: api.Scope, exported_table: api.ExportedTable) -> None:
+        scope.serve_table(exported_table, route, column_names)
+
+    table.to(datasink.ExportDataSink(register))
+
+
+@check_arg_types
+@trace_user_frame
+def serve_websocket(
+    table: Table,
+    name: str,
+    *,
+    sort_by: Iterable[ColumnReference] | None = None,
+    skip_persisted_batch: bool = True,
+    skip_errors: bool = True,
+) -> None:
+    """Streams the changes of ``table`` to WebSocket clients, on the channel
+    ``/subscriptions/<name>`` of the monitoring HTTP server.
+
+    The computation has to be started with ``pw.run(with_http_server=True)``. The
+    server listens on ``localhost``, on the port given by the
+    ``PATHWAY_MONITORING_HTTP_PORT`` environment variable (``20000`` by default)
+    increased by the index of the process. For each closed time, the clients receive a
+    text message with a JSON object holding the ``time`` and the list of ``changes``.
+    Each change has the ``id`` of the row, its ``diff`` (``1`` for an insertion and
+    ``-1`` for a deletion) and the ``values`` of the columns. When the table is
+    finished by all the workers of the process, the message ``{"end": true}`` is sent
+    once. Connections to the names that aren't served are rejected.
+
+    The clients receive only the changes done after they have connected, so they can
+    be used to follow the table live without a Python callback in the pipeline. A
+    client that can't keep up with the changes is disconnected.
+
+    Args:
+        table: Table to be streamed.
+        name: A unique name of the channel. It is also used as the name of the
+            connector in logs and monitoring dashboards.
+        sort_by: If specified, the changes within each message will be sorted in
+            ascending order based on the values of the given columns.
+        skip_persisted_batch: Whether the changes already processed in the previous
+            runs should be skipped when the computation is restarted with persistence.
+        skip_errors: Whether the rows containing errors should be skipped.
+
+    Returns:
+        None
+
+    Example:
+
+    >>> import pathway as pw
+    >>> pets = pw.debug.table_from_markdown("owner pet \\n Alice dog \\n Bob cat")
+    >>> pw.io.http.serve_websocket(pets, "pets")
+
+    After ``pw.run(with_http_server=True)`` is started, the changes can be received by
+    connecting to ``ws://localhost:20000/subscriptions/pets``.
+    """
+    if not name or "/" in name:
+        raise ValueError(
+            f"invalid name {name!r}, it must be non-empty and must not contain '/'"
+        )
+    table.to(
+        datasink.WebSocketDataSink(
+            skip_persisted_batch=skip_persisted_batch,
+            skip_errors=skip_errors,
+            unique_name=name,
+            sort_by=sort_by,
+        )
+    )
 
 
 __all__ = [
     "read",
     "write",
+    "serve_table",
+    "serve_websocket",
     "RetryPolicy",
     "rest_connector",
     "PathwayWebserver",
@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import gzip
 import json
 import random
 import time
@@ -11,6 +12,7 @@ if TYPE_CHECKING:
     import requests
 
 import pathway as pw
+from pathway.optional_import import optional_imports
 
 
 class RetryPolicy:
@@ -27,6 +29,8 @@ class RetryPolicy:
     """
 
     def __init__(self, first_delay_ms: int, backoff_factor: float, jitter_ms: int):
+        self._first_delay_ms = first_delay_ms
+        self._jitter_ms = jitter_ms
         self._next_retry_duration = first_delay_ms * 1e-3
         self._backoff_factor = backoff_factor
         self._jitter = jitter_ms * 1e-3
@@ -144,3 +148,37 @@ def prepare_request_payload(
         return unescape(text or "", row, time, is_addition)
     else:
         raise ValueError(f"Unknown payload format: {req_format}")
+
+
+def prepare_batch_payload(rows: list[str], batch_format: str) -> str:
+    if batch_format == "ndjson":
+        return "".join(row + "\n" for row in rows)
+    elif batch_format == "json_array":
+        return "[" + ",".join(rows) + "]"
+    else:
+        raise ValueError(f"Unknown batch format: {batch_format}")
+
+
+def default_content_type(req_format: str, batch_format: str | None) -> str | None:
+    if batch_format == "ndjson":
+        return "application/x-ndjson"
+    elif batch_format == "json_array" or req_format == "json":
+        return "application/json"
+    else:
+        return None
+
+
+def compress_payload(payload: str | bytes, compression: str | None) -> str | bytes:
+    if compression is None:
+        return payload
+    if isinstance(payload, str):
+        payload = payload.encode("utf-8")
+    if compression == "gzip":
+        return gzip.compress(payload)
+    elif compression == "zstd":
+        with optional_imports("zstd"):
+            import zstandard
+
+        return zstandard.ZstdCompressor().compress(payload)
+    else:
+        raise ValueError(f"Unknown compression: {compression}")
@@ -39,6 +39,7 @@ _ENGINE_TO_OPENAPI_TYPE = {
     api.PathwayType.POINTER: "string",
     api.PathwayType.DATE_TIME_NAIVE: "string",
     api.PathwayType.DATE_TIME_UTC: "string",
+    api.PathwayType.DATE_TIME_ZONED: "string",
     api.PathwayType.DURATION: "string",
     api.PathwayType.BYTES: "bytes",
 }
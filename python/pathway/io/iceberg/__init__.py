@@ -12,7 +12,12 @@ from pathway.internals.schema import Schema
 from pathway.internals.table import Table
 from pathway.internals.table_io import table_from_datasource
 from pathway.internals.trace import trace_user_frame
-from pathway.io._utils import _get_unique_name, internal_connector_mode, read_schema
+from pathway.io._utils import (
+    SNAPSHOT_OUTPUT_TABLE_TYPE,
+    _get_unique_name,
+    internal_connector_mode,
+    read_schema,
+)
 
 _REST_CATALOG_TYPE_NAME = "rest"
 _GLUE_CATALOG_TYPE_NAME = "glue"
@@ -235,6 +240,10 @@ def write(
     min_commit_frequency: int | None = 60_000,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    output_table_type: Literal["stream_of_changes", "snapshot"] = "stream_of_changes",
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ):
     """
     Writes the stream of changes from ``table`` into `Iceberg <https://iceberg.apache.org/>`_
@@ -269,6 +278,32 @@ def write(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        output_table_type: Defines how the output table manages its data. If set to
+            ``"stream_of_changes"`` (the default), the system outputs a stream of
+            modifications to the target table. This stream includes two additional
+            integer columns: ``time``, representing the computation minibatch, and
+            ``diff``, indicating the type of change (``1`` for row addition and ``-1``
+            for row deletion). If set to ``"snapshot"``, the table maintains the
+            current state of the data instead, with an additional ``_id`` column
+            holding the Pathway key of each row. Each commit adds the new versions
+            of the changed rows together with an equality delete file removing their
+            previous versions by ``_id``, all in a single Iceberg snapshot, so the
+            readers never see a partially applied minibatch. Unlike the Delta Lake
+            snapshot, this mode never rewrites the existing data files and doesn't
+            keep the table in memory. It requires the table to use the Iceberg
+            format version 2 or newer, and the readers to support equality deletes.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -309,6 +344,17 @@ def write(
     Don't forget to run your program with ``pw.run`` once you define all necessary
     computations. After execution, you will be able to see the users' data in the
     Iceberg storage.
+
+    If the ``users`` table should instead always hold the current list of users, the
+    ``"snapshot"`` output table type can be used:
+
+    >>> pw.io.iceberg.write(
+    ...     users,
+    ...     catalog=pw.io.iceberg.RestCatalog(uri="http://localhost:8181/"),
+    ...     namespace=["app"],
+    ...     table_name="users_snapshot",
+    ...     output_table_type="snapshot",
+    ... )
     """
     _check_entitlements("iceberg")
     data_storage = api.DataStorage(
@@ -317,6 +363,7 @@ def write(
         min_commit_frequency=min_commit_frequency,
         table_name=table_name,
         namespace=namespace,
+        snapshot_maintenance_on_output=output_table_type == SNAPSHOT_OUTPUT_TABLE_TYPE,
     )
 
     data_format = api.DataFormat(
@@ -333,5 +380,8 @@ def write(
             datasink_name="iceberg",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
         )
     )
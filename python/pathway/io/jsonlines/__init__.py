@@ -11,6 +11,7 @@ from pathway.internals.runtime_type_check import check_arg_types
 from pathway.internals.schema import Schema
 from pathway.internals.table import Table
 from pathway.internals.trace import trace_user_frame
+from pathway.io._utils import JsonFormatterSettings, SchemaEvolution
 
 
 @check_arg_types
@@ -21,6 +22,7 @@ def read(
     schema: type[Schema] | None = None,
     mode: Literal["streaming", "static"] = "streaming",
     json_field_paths: dict[str, str] | None = None,
+    schema_evolution: SchemaEvolution | None = None,
     object_pattern: str = "*",
     with_metadata: bool = False,
     autocommit_duration_ms: int | None = 1500,
@@ -58,6 +60,13 @@ def read(
             For the field which require such mapping, it should be given in the format
             ``<field_name>: <path to be mapped>``, where the path to be mapped needs to be a
             `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        schema_evolution: How the fields of the read objects that don't match the schema
+            are handled. With ``"ignore_new_fields"``, the fields not present in the
+            schema are ignored. With ``"fill_missing"``, additionally the fields missing
+            from an object are ``None``. With ``"fail"``, both the fields not present in
+            the schema and the missing fields are errors. A missing field always takes
+            the default value of its column if the schema declares one. Defaults to
+            ``"ignore_new_fields"``.
         object_pattern: Unix shell style pattern for filtering only certain files in the
             directory. Ignored in case a path to a single file is specified. This value will be
             deprecated soon, please use glob pattern in ``path`` instead.
@@ -166,6 +175,7 @@ def read(
         format="json",
         mode=mode,
         json_field_paths=json_field_paths,
+        schema_evolution=schema_evolution,
         debug_data=debug_data,
         name=name,
         autocommit_duration_ms=autocommit_duration_ms,
@@ -185,6 +195,10 @@ def write(
     *,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    json_settings: JsonFormatterSettings | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes ``table``'s stream of updates to a file in jsonlines format.
 
@@ -196,6 +210,23 @@ def write(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        json_settings: The shape of the output objects: the locations of the columns
+            in them, whether the ``time`` and ``diff`` fields are added, and how the
+            datetime values are encoded. See ``pw.io.JsonFormatterSettings`` for
+            details. If not specified, each column is a top-level field named after
+            it and the ``time`` and ``diff`` fields are added.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -232,6 +263,31 @@ def write(
     three rows were read from the input. All in all, the extra information in ``time`` and
     ``diff`` columns - in this case - shows us that in the initial minibatch (``time = 0``),
     you have read three rows and all of them were added to the collection (``diff = 1``).
+
+    The shape of the output objects can be adjusted to the format expected by the
+    consumer. For instance, the owner and the pet can be grouped into a nested object,
+    with the ``age`` column renamed, and the ``time`` and ``diff`` fields omitted:
+
+    >>> pw.io.jsonlines.write(
+    ...     t,
+    ...     "pets.jsonlines",
+    ...     json_settings=pw.io.JsonFormatterSettings(
+    ...         field_paths={
+    ...             "owner": "/pet/owner",
+    ...             "pet": "/pet/kind",
+    ...             "age": "/pet_age",
+    ...         },
+    ...         include_time_and_diff=False,
+    ...     ),
+    ... )
+
+    The output then looks as follows:
+
+    .. code-block:: json
+
+        {"pet":{"kind":"dog","owner":"Alice"},"pet_age":10}
+        {"pet":{"kind":"cat","owner":"Bob"},"pet_age":9}
+        {"pet":{"kind":"cat","owner":"Alice"},"pet_age":8}
     """
 
     pw.io.fs.write(
@@ -240,4 +296,8 @@ def write(
         format="json",
         name=name,
         sort_by=sort_by,
+        json_settings=json_settings,
+        queue_capacity=queue_capacity,
+        max_batch_size=max_batch_size,
+        linger_ms=linger_ms,
     )
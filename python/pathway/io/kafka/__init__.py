@@ -11,6 +11,7 @@ from pathway.internals._io_helpers import SchemaRegistryHeader, SchemaRegistrySe
 from pathway.internals.expression import ColumnReference
 from pathway.internals.runtime_type_check import check_arg_types
 from pathway.internals.schema import (
+    HEADERS_SOURCE_COMPONENT,
     KEY_SOURCE_COMPONENT,
     PAYLOAD_SOURCE_COMPONENT,
     Schema,
@@ -20,10 +21,12 @@ from pathway.internals.table_io import table_from_datasource
 from pathway.internals.trace import trace_user_frame
 from pathway.io._utils import (
     MessageQueueOutputFormat,
+    SchemaEvolution,
     _get_unique_name,
     check_deprecated_kwargs,
     check_raw_and_plaintext_only_kwargs_for_message_queues,
     construct_schema_and_data_format,
+    delivery_semantics_from_str,
     internal_connector_mode,
 )
 
@@ -34,6 +37,7 @@ def read(
     rdkafka_settings: dict,
     topic: str | list[str] | None = None,
     *,
+    topic_pattern: str | None = None,
     schema: type[Schema] | None = None,
     mode: Literal["streaming", "static"] = "streaming",
     format: Literal["plaintext", "raw", "json"] = "raw",
@@ -41,12 +45,23 @@ def read(
     debug_data=None,
     autocommit_duration_ms: int | None = 1500,
     json_field_paths: dict[str, str] | None = None,
+    schema_evolution: SchemaEvolution | None = None,
     autogenerate_key: bool = False,
     with_metadata: bool = False,
+    with_headers: bool = False,
+    start_from: (
+        Literal["beginning", "end", "timestamp"]
+        | dict[int, Literal["beginning", "end", "timestamp"]]
+        | None
+    ) = None,
     start_from_timestamp_ms: int | None = None,
+    start_from_offsets: dict[int, int] | None = None,
     parallel_readers: int | None = None,
+    partition_aligned: bool = False,
     name: str | None = None,
     max_backlog_size: int | None = None,
+    max_rows_per_second: int | None = None,
+    max_bytes_per_second: int | None = None,
     _stacklevel: int = 1,
     **kwargs,
 ) -> Table:
@@ -68,6 +83,16 @@ def read(
         rdkafka_settings: Connection settings in the format of `librdkafka
             <https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md>`_.
         topic: Name of topic in Kafka from which the data should be read.
+        topic_pattern: A regular expression the names of the topics to be read must
+            match. It can be used instead of ``topic``, in which case the data is
+            read from all the matching topics. The topics created while the program
+            runs are picked up when the consumer refreshes the cluster metadata,
+            which by default happens every 5 minutes and can be tuned with the
+            ``topic.metadata.refresh.interval.ms`` setting in ``rdkafka_settings``.
+            The name of the topic a row comes from is available in the ``topic``
+            field of the ``_metadata`` column if ``with_metadata`` is set. Only the
+            streaming mode is supported, and neither ``start_from_timestamp_ms``
+            nor ``start_from_offsets`` can be used together with it.
         schema: Schema of the resulting table.
         mode: Specifies how the engine retrieves data from the topic. The default value is
             ``"streaming"``, which means the engine will constantly wait for new messages,
@@ -86,6 +111,13 @@ def read(
             given in the format ``<field_name>: <path to be mapped>``, where the path to
             be mapped needs to be a
             `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        schema_evolution: How the fields of the read objects that don't match the schema
+            are handled. With ``"ignore_new_fields"``, the fields not present in the
+            schema are ignored. With ``"fill_missing"``, additionally the fields missing
+            from an object are ``None``. With ``"fail"``, both the fields not present in
+            the schema and the missing fields are errors. A missing field always takes
+            the default value of its column if the schema declares one. Defaults to
+            ``"ignore_new_fields"``.
         autogenerate_key: If ``True``, Pathway Live Data Framework automatically generates unique primary key
             for the entries read. Otherwise it first tries to use the key from the message.
             This parameter is used only if the ``format`` is "raw" or "plaintext".
@@ -100,13 +132,56 @@ def read(
             (the header value). The header value is ``null`` only when the header body is
             absent (the Kafka protocol distinguishes a missing body from an empty one); a
             present-but-empty body is encoded as an empty string.
+        with_headers: When set to ``True``, the connector will add an additional JSON
+            column named ``_headers`` to the table. It maps the names of the headers of
+            the Kafka message that produced the row to their values decoded as UTF-8,
+            which makes it easy to propagate, for instance, the tracing context. The
+            value of a header is ``null`` when its body is absent. If a header name is
+            repeated, the last value is taken; all of them can still be found in the
+            ``headers`` array of the ``_metadata`` column. With the ``"json"`` format,
+            the headers can also be placed in a column of the schema declared with
+            ``pw.column_definition(source_component="headers")``.
+        start_from: Where the partitions without persisted offsets are read from.
+            ``"beginning"`` starts from the earliest message of each partition,
+            ``"end"`` reads only the messages produced after the start, and
+            ``"timestamp"`` starts from the messages at or after the time given in
+            ``start_from_timestamp_ms``. Unlike ``auto.offset.reset``, the starting
+            position takes precedence over the offsets committed by the consumer
+            group. If not set, the read starts from the entries with the timestamp
+            ``start_from_timestamp_ms`` if it's given, and from the position
+            determined by the consumer group and ``rdkafka_settings`` otherwise.
+            It can also be a dictionary mapping partition numbers to their starting
+            positions. The partitions that aren't in the dictionary are then read
+            from ``start_from_timestamp_ms`` if it's given, and from the position
+            determined by the consumer group otherwise.
         start_from_timestamp_ms: If defined, the read starts from entries with the given
-            timestamp in the past, specified in milliseconds.
+            timestamp in the past, specified in milliseconds. Must not be set if
+            ``start_from`` is ``"beginning"`` or ``"end"``. If ``start_from`` is a
+            dictionary, it applies to the partitions set to ``"timestamp"`` and to
+            the partitions that aren't in it.
+        start_from_offsets: If defined, the read starts from the given offsets, as a
+            dictionary mapping a partition number to the offset of the first message
+            to be read from it. The partitions that aren't in the dictionary are read
+            from the beginning. It allows recomputing the results from a known point
+            in the topic. The persisted state of the connector, if any, is then
+            neither used nor updated. Can't be combined with ``start_from`` and
+            ``start_from_timestamp_ms``.
         parallel_readers: number of copies of the reader to work in parallel. In case
             the number is not specified, min{pathway_threads, total number of partitions}
             will be taken. This number also can't be greater than the number of Pathway Live Data Framework
             engine threads, and will be reduced to the number of engine threads, if it
             exceeds.
+        partition_aligned: When set to ``True``, the partitions of the topic are
+            assigned to the Pathway workers statically, the partition ``p`` being read
+            by the worker ``p % n``, where ``n`` is the number of workers, instead of
+            being balanced by the Kafka consumer group. Each row is then placed in the
+            shard of its partition and stays on the worker that read it, so the
+            network exchange that otherwise distributes the rows read among the
+            workers is skipped. It pays off in the pipelines partitioned the same way
+            as the topic, for instance, when the rows are keyed by the Kafka message
+            key. A reader must run on every worker, so ``parallel_readers`` can't be
+            set, and the partitions added to the topic while the program runs aren't
+            read. Can't be used with ``topic_pattern``.
         name: A unique name for the connector. If provided, this name will be used in
             logs and monitoring dashboards. Additionally, if persistence is enabled, it
             will be used as the name for the snapshot that stores the connector's progress.
@@ -114,6 +189,12 @@ def read(
             in processing at any moment. Reading pauses when the limit is reached and resumes
             as processing of some entries completes. Useful with large sources that
             emit an initial burst of data to avoid memory spikes.
+        max_rows_per_second: Limit on the number of messages read from the topic per
+            second, shared by all the parallel readers. Useful to keep a backfill of a
+            large topic from slowing down the other sources processed by the same
+            workers.
+        max_bytes_per_second: Limit on the total size of the keys and payloads of the
+            messages read from the topic per second, shared by all the parallel readers.
 
     Returns:
         Table: The table read.
@@ -244,6 +325,66 @@ def read(
 
     Note that you would not need to provide the JSONPath for ``pet_id`` if it is
     at the top level of the key JSON.
+
+    Finally, the headers of the messages can be read as well. For instance, if the
+    producers attach a ``traceparent`` header with the tracing context, it can be
+    extracted from the ``_headers`` column:
+
+    >>> class InputSchema(pw.Schema):
+    ...     owner: str
+    ...     pet: str
+    >>> t = pw.io.kafka.read(
+    ...     rdkafka_settings,
+    ...     topic="animals",
+    ...     format="json",
+    ...     schema=InputSchema,
+    ...     with_headers=True,
+    ... )
+    >>> t = t.select(
+    ...     pw.this.owner,
+    ...     pw.this.pet,
+    ...     traceparent=pw.this["_headers"].get("traceparent").as_str(),
+    ... )
+
+    The ``headers`` parameter of ``pw.io.kafka.write`` can then pass it on to the
+    messages produced from this table.
+
+    If the events are spread across several topics, for instance, one per region,
+    all of them can be read at once with a pattern. The topic of each row can then
+    be taken from the metadata:
+
+    >>> t = pw.io.kafka.read(
+    ...     rdkafka_settings,
+    ...     topic_pattern="^events-.*",
+    ...     format="json",
+    ...     schema=InputSchema,
+    ...     with_metadata=True,
+    ... )
+    >>> t = t.with_columns(topic=pw.this._metadata["topic"].as_str())
+
+    To skip the messages already in the topic and process only the new ones,
+    regardless of the offsets committed by the consumer group, the read can start
+    from the end of the partitions:
+
+    >>> t = pw.io.kafka.read(
+    ...     rdkafka_settings,
+    ...     topic="animals",
+    ...     format="json",
+    ...     schema=InputSchema,
+    ...     start_from="end",
+    ... )
+
+    The starting positions can also be given per partition. For instance, the
+    partition ``0`` can be read from its beginning and the partition ``1`` only
+    from the messages produced after the start:
+
+    >>> t = pw.io.kafka.read(
+    ...     rdkafka_settings,
+    ...     topic="animals",
+    ...     format="json",
+    ...     schema=InputSchema,
+    ...     start_from={0: "beginning", 1: "end"},
+    ... )
     """
     # The data_storage is common to all kafka connectors
 
@@ -271,6 +412,22 @@ def read(
         raise ValueError(
             f"'parallel_readers' must be positive; got {parallel_readers}."
         )
+    if partition_aligned:
+        if parallel_readers is not None:
+            raise ValueError(
+                "'parallel_readers' can't be set together with 'partition_aligned', "
+                "since the partitions are then read by all the workers."
+            )
+        if topic_pattern is not None:
+            raise ValueError(
+                "'topic_pattern' can't be used together with 'partition_aligned'."
+            )
+    for limit_name, limit in [
+        ("max_rows_per_second", max_rows_per_second),
+        ("max_bytes_per_second", max_bytes_per_second),
+    ]:
+        if limit is not None and limit <= 0:
+            raise ValueError(f"'{limit_name}' must be positive; got {limit}.")
     if start_from_timestamp_ms is not None and start_from_timestamp_ms < 0:
         raise ValueError(
             f"'start_from_timestamp_ms' must be non-negative; got "
@@ -278,6 +435,52 @@ def read(
             f"milliseconds — negative values are pre-epoch and not "
             f"meaningful for Kafka."
         )
+    if start_from_offsets is not None:
+        if start_from_timestamp_ms is not None:
+            raise ValueError(
+                "'start_from_offsets' and 'start_from_timestamp_ms' can't be used "
+                "together."
+            )
+        for partition, offset in start_from_offsets.items():
+            if partition < 0 or offset < 0:
+                raise ValueError(
+                    f"'start_from_offsets' must map non-negative partition numbers "
+                    f"to non-negative offsets; got {partition}: {offset}."
+                )
+    if isinstance(start_from, dict):
+        if start_from_offsets is not None:
+            raise ValueError(
+                "'start_from_offsets' and 'start_from' can't be used together."
+            )
+        for partition, position in start_from.items():
+            if partition < 0:
+                raise ValueError(
+                    f"'start_from' must map non-negative partition numbers to "
+                    f"starting positions; got {partition}: {position!r}."
+                )
+            if position not in ("beginning", "end", "timestamp"):
+                raise ValueError(
+                    f"Unknown starting position {position!r} for the partition "
+                    f"{partition}; it must be 'beginning', 'end' or 'timestamp'."
+                )
+            if position == "timestamp" and start_from_timestamp_ms is None:
+                raise ValueError(
+                    "'start_from_timestamp_ms' is required when a partition "
+                    "is read from 'timestamp'."
+                )
+    elif start_from == "timestamp" and start_from_timestamp_ms is None:
+        raise ValueError(
+            "'start_from_timestamp_ms' is required when start_from='timestamp'."
+        )
+    elif start_from in ("beginning", "end"):
+        for option_name, option in [
+            ("start_from_timestamp_ms", start_from_timestamp_ms),
+            ("start_from_offsets", start_from_offsets),
+        ]:
+            if option is not None:
+                raise ValueError(
+                    f"'{option_name}' must not be set when start_from='{start_from}'."
+                )
     if autocommit_duration_ms is not None and autocommit_duration_ms <= 0:
         raise ValueError(
             f"'autocommit_duration_ms' must be positive; got "
@@ -286,7 +489,7 @@ def read(
             f"from happening."
         )
 
-    # When 'start_from_timestamp_ms' is set, the engine seeks lazily after
+    # When a starting position is set, the engine seeks lazily after
     # the consumer is positioned at the partition's earliest offset, so any
     # user-supplied 'auto.offset.reset' value that doesn't already mean
     # "start at the beginning" is silently rewritten on the Rust side.
@@ -297,18 +500,45 @@ def read(
     _START_FROM_BEGINNING_ALIASES = {"earliest", "beginning", "smallest"}
     user_offset_reset = rdkafka_settings.get("auto.offset.reset")
     if (
-        start_from_timestamp_ms is not None
+        (start_from is not None or start_from_timestamp_ms is not None)
         and user_offset_reset is not None
         and user_offset_reset not in _START_FROM_BEGINNING_ALIASES
     ):
         warnings.warn(
             "'auto.offset.reset' is overridden to 'earliest' whenever "
-            "'start_from_timestamp_ms' is set, so the seek can fall back "
-            f"to the start of the partition. Your value "
+            "'start_from' or 'start_from_timestamp_ms' is set, so the seek can "
+            f"fall back to the start of the partition. Your value "
             f"{user_offset_reset!r} is being ignored.",
             stacklevel=_stacklevel + 4,
         )
 
+    if topic_pattern is not None:
+        if topic is not None or "topic_names" in kwargs:
+            raise ValueError("'topic' and 'topic_pattern' can't be used together.")
+        if not topic_pattern.lstrip("^"):
+            raise ValueError(
+                f"'topic_pattern' must be a non-empty regular expression; "
+                f"got {topic_pattern!r}."
+            )
+        if mode != "streaming":
+            raise ValueError(
+                "'topic_pattern' can only be used in the streaming mode, since the "
+                "set of the topics to be read isn't known in advance."
+            )
+        for option_name, option in [
+            ("start_from", start_from),
+            ("start_from_timestamp_ms", start_from_timestamp_ms),
+            ("start_from_offsets", start_from_offsets),
+        ]:
+            if option is not None:
+                raise ValueError(
+                    f"'{option_name}' can't be used together with 'topic_pattern'."
+                )
+        # librdkafka subscribes by a regular expression when the name starts with "^"
+        if not topic_pattern.startswith("^"):
+            topic_pattern = "^" + topic_pattern
+        topic = topic_pattern
+
     # Distinguish "missing topic" from "explicitly empty topic" — the former
     # is a user typo (rename to 'topic='), the latter is an invalid value
     # that Kafka itself would reject with a less actionable error.
@@ -372,23 +602,39 @@ def read(
         parallel_readers=parallel_readers,
         start_from_timestamp_ms=start_from_timestamp_ms,
         mode=internal_connector_mode(mode),
+        partition_aligned=partition_aligned,
+        start_position=start_from if isinstance(start_from, str) else None,
+        partition_start_positions=(
+            start_from if isinstance(start_from, dict) else None
+        ),
     )
 
     # TODO: support case when the key is scalar and the value is json
     schema, data_format = construct_schema_and_data_format(
         format,
         with_metadata=with_metadata,
+        with_headers=with_headers,
         autogenerate_key=autogenerate_key,
         schema=schema,
         json_field_paths=json_field_paths,
         schema_registry_settings=schema_registry_settings,
         with_native_record_key=True,
+        schema_evolution=schema_evolution,
         _stacklevel=5,
     )
+    start_offsets = None
+    if start_from_offsets is not None:
+        start_offsets = [
+            api.ConnectorOffset.kafka(topic, partition, offset)
+            for partition, offset in start_from_offsets.items()
+        ]
     data_source_options = datasource.DataSourceOptions(
         commit_duration_ms=autocommit_duration_ms,
         unique_name=_get_unique_name(name, kwargs, stacklevel=_stacklevel + 5),
         max_backlog_size=max_backlog_size,
+        start_from=start_offsets,
+        max_rows_per_second=max_rows_per_second,
+        max_bytes_per_second=max_bytes_per_second,
     )
     return table_from_datasource(
         datasource.GenericDataSource(
@@ -403,6 +649,7 @@ def read(
         supported_components=(
             KEY_SOURCE_COMPONENT,
             PAYLOAD_SOURCE_COMPONENT,
+            HEADERS_SOURCE_COMPONENT,
         ),
     )
 
@@ -549,9 +796,16 @@ def write(
     delimiter: str = ",",
     key: ColumnReference | None = None,
     value: ColumnReference | None = None,
-    headers: Iterable[ColumnReference] | None = None,
+    headers: Iterable[ColumnReference] | dict[str, ColumnReference] | None = None,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    parallel_writers: int = 1,
+    partition_by: Iterable[ColumnReference] | None = None,
+    delivery_semantics: Literal["at_least_once", "exactly_once"] = "exactly_once",
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
+    collapse_updates: bool = False,
 ) -> None:
     """Write a table to a given topic on a Kafka instance.
 
@@ -573,6 +827,13 @@ def write(
     serializing the extracted fields into UTF-8 strings and passing them as additional
     Kafka headers.
 
+    If ``rdkafka_settings`` contain a ``transactional.id``, the messages are sent in
+    transactions, each committed when the output of a minibatch is flushed, so that the
+    consumers reading only the committed messages never see a part of it. Each producer
+    gets its own transactional id, made of the given one followed by the indices of the
+    worker and of the parallel writer. Such a connector can also join a commit group,
+    see ``pw.io.register_output_commit_group``.
+
     Args:
         table: the table to output.
         rdkafka_settings: Connection settings in the format of
@@ -604,11 +865,47 @@ def write(
             headers. These headers are named in the same way as fields that are forwarded and correspond
             to the string representations of the respective values encoded in UTF-8. If a binary
             column is requested, it will be produced "as is" in the respective header.
+            It can also be a dictionary mapping the header names to the columns, if the
+            headers must be named differently than the columns, for instance to
+            propagate a ``traceparent`` header. A ``None`` value produces a header
+            without a body.
         name: A unique name for the connector. If provided, this name will be used in
             logs and monitoring dashboards.
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        parallel_writers: The number of producers each worker runs. The rows of a
+            minibatch are split between them, and the minibatch is considered written
+            once all of them have flushed it. The ordering given by ``sort_by`` then
+            only holds within each producer.
+        partition_by: The columns deciding which producer a row goes to when
+            ``parallel_writers`` is greater than one: the messages produced from the
+            rows with the same values in these columns keep their order. If not
+            specified, the rows are split by the ``key`` column if it's set and by
+            their Pathway keys otherwise.
+        delivery_semantics: What happens to the minibatches replayed from the
+            persisted state when the program restarts. With ``"exactly_once"`` (the
+            default), the messages produced before the restart are not produced
+            again. With ``"at_least_once"``, they are produced again, so that the
+            consumers reading the topic from a later offset get the whole state.
+        queue_capacity: The maximal number of minibatches waiting to be produced. When
+            a producer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
+        collapse_updates: If set to ``True``, the deletion and the insertion of a row
+            with the same key in a minibatch are produced as a single message with
+            ``diff`` equal to ``1``, holding the new values and the old ones in the
+            ``before`` field. It's only supported by the "json" format without the
+            Schema Registry and without a column named ``before``, otherwise the
+            deletion and the insertion are produced as usual.
 
     Returns:
         None
@@ -706,6 +1003,19 @@ def write(
     ...     value=t2.foo,
     ...     headers=[t2.baz],
     ... )
+
+    If the consumers expect the header under a different name, for instance
+    ``x-baz``, the header names can be given explicitly:
+
+    >>> pw.io.kafka.write(
+    ...     t2,
+    ...     rdkafka_settings,
+    ...     "test",
+    ...     format="raw",
+    ...     key=t2.bar,
+    ...     value=t2.foo,
+    ...     headers={"x-baz": t2.baz},
+    ... )
     """
     if not rdkafka_settings.get("bootstrap.servers"):
         raise ValueError(
@@ -718,6 +1028,10 @@ def write(
             "'topic_name' must be a non-empty string; got an empty string. "
             "Kafka does not allow empty topic names."
         )
+    if parallel_writers < 1:
+        raise ValueError("parallel_writers must be positive")
+    if partition_by is None and key is not None:
+        partition_by = [key]
 
     output_format = MessageQueueOutputFormat.construct(
         table,
@@ -731,7 +1045,10 @@ def write(
         subject=subject,
     )
     output_table = output_format.table
-    remapped_sort_by = _remap_sort_by(sort_by, table, output_table)
+    remapped_sort_by = _remap_columns(sort_by, table, output_table, "sort_by")
+    remapped_partition_by = _remap_columns(
+        partition_by, table, output_table, "partition_by"
+    )
 
     data_storage = api.DataStorage(
         storage_type="kafka",
@@ -749,30 +1066,38 @@ def write(
             datasink_name="kafka",
             unique_name=name,
             sort_by=remapped_sort_by,
+            parallel_writers=parallel_writers,
+            partition_by=remapped_partition_by,
+            delivery_semantics=delivery_semantics_from_str(delivery_semantics),
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
+            collapse_updates=collapse_updates,
         )
     )
 
 
-def _remap_sort_by(
-    sort_by: Iterable[ColumnReference] | None,
+def _remap_columns(
+    columns: Iterable[ColumnReference] | None,
     original_table: Table,
     output_table: Table,
+    argument_name: str,
 ) -> list[ColumnReference] | None:
-    if sort_by is None:
+    if columns is None:
         return None
     remapped: list[ColumnReference] = []
-    for column in sort_by:
+    for column in columns:
         if column._table is output_table:
             remapped.append(column)
             continue
         if column._table is not original_table:
             raise ValueError(
-                f"The sort_by column {column} doesn't belong to the table "
+                f"The {argument_name} column {column} doesn't belong to the table "
                 "passed to pw.io.kafka.write."
             )
         if column.name not in output_table._columns:
             raise ValueError(
-                f"The sort_by column {column.name!r} is not part of the "
+                f"The {argument_name} column {column.name!r} is not part of the "
                 "data being written. For 'raw' or 'plaintext' format, only "
                 "the 'value', 'key', 'topic_name' and 'headers' columns "
                 "are forwarded."
@@ -186,6 +186,9 @@ def write(
     data: ColumnReference | None = None,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """
     Streams ``table`` into an
@@ -221,6 +224,18 @@ def write(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -399,5 +414,114 @@ def write(
             datasink_name="kinesis",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
+        )
+    )
+
+
+@check_arg_types
+@trace_user_frame
+def write_firehose(
+    table: Table,
+    delivery_stream_name: str | ColumnReference,
+    *,
+    format: Literal["raw", "plaintext", "json"] = "json",
+    data: ColumnReference | None = None,
+    name: str | None = None,
+    sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
+) -> None:
+    """
+    Streams ``table`` into an
+    `Amazon Data Firehose delivery stream <https://docs.aws.amazon.com/firehose/latest/dev/what-is-this-service.html>`_.
+    The connection settings are retrieved from the environment.
+
+    The records are sent in batches. The records rejected by the delivery stream, for
+    instance because it's throttled, are retried with a backoff, and the run fails if
+    they can't be delivered after several attempts.
+
+    Args:
+        table: The table to write.
+        delivery_stream_name: The delivery stream where data will be written. This can
+            be a specific stream name or a reference to a column whose values will be
+            used as the delivery stream for each record. If using a column reference,
+            the column must contain string values.
+        format: Format in which the data is put into the delivery stream. Currently
+            ``"json"``, ``"plaintext"``, and ``"raw"`` are supported, in the same way as
+            in ``pw.io.kinesis.write``.
+        data: Reference to the column that should be used as data in the produced
+            record in ``"plaintext"`` or ``"raw"`` format. It can be deduced
+            automatically if the table has exactly one column. Note that the maximum
+            length of a record in Firehose is 1000 KiB.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards.
+        sort_by: If specified, the output will be sorted in ascending order based on the
+            values of the given columns within each minibatch. When multiple columns are
+            provided, the corresponding value tuples will be compared lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
+
+    Returns:
+        None
+
+    Example:
+
+    Suppose a delivery stream ``"events"`` has been created in Firehose and the
+    credentials and the region are set in the environment. A table can be delivered
+    to it in the JSON format as follows:
+
+    >>> import pathway as pw
+    >>> table = pw.debug.table_from_markdown(
+    ...     '''
+    ...     | key | value
+    ...  1  |   1 | one
+    ...  2  |   2 | two
+    ... '''
+    ... )
+    >>> pw.io.kinesis.write_firehose(table, delivery_stream_name="events")
+    """
+
+    output_format = MessageQueueOutputFormat.construct(
+        table,
+        format=format,
+        value=data,
+        topic_name=(
+            delivery_stream_name
+            if isinstance(delivery_stream_name, ColumnReference)
+            else None
+        ),
+    )
+    table = output_format.table
+
+    data_storage = api.DataStorage(
+        storage_type="firehose",
+        topic=delivery_stream_name if isinstance(delivery_stream_name, str) else None,
+        topic_name_index=output_format.topic_name_index,
+    )
+
+    table.to(
+        datasink.GenericDataSink(
+            data_storage,
+            output_format.data_format,
+            datasink_name="firehose",
+            unique_name=name,
+            sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
         )
     )
@@ -0,0 +1,171 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+from os import PathLike, fspath
+from typing import Iterable
+
+from pathway.internals import api, datasink
+from pathway.internals._io_helpers import (
+    AwsS3Settings,
+    _format_output_value_fields,
+    is_s3_path,
+)
+from pathway.internals.expression import ColumnReference
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.table import Table
+from pathway.internals.trace import trace_user_frame
+from pathway.io._utils import _prepare_s3_connection_settings
+from pathway.io.minio import MinIOSettings
+from pathway.io.s3 import DigitalOceanS3Settings, WasabiS3Settings
+
+
+@check_arg_types
+@trace_user_frame
+def write(
+    table: Table,
+    uri: str | PathLike,
+    *,
+    vector_columns: dict[ColumnReference, int] | None = None,
+    s3_connection_settings: (
+        AwsS3Settings | MinIOSettings | WasabiS3Settings | DigitalOceanS3Settings | None
+    ) = None,
+    min_commit_frequency: int | None = 60_000,
+    name: str | None = None,
+    sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
+) -> None:
+    """
+    Writes the stream of changes from ``table`` into a
+    `Lance <https://lancedb.github.io/lance/>`_ dataset at the location specified by
+    ``uri``. Supported storage types are S3 and the local filesystem.
+
+    If the dataset doesn't exist, it is created on the first commit, with the schema
+    inferred from the ``table``'s schema. Otherwise, the changes are appended to it.
+    Besides the columns of the ``table``, the dataset contains two additional integer
+    columns: ``time``, representing the computation minibatch, and ``diff``,
+    indicating the type of change (``1`` for row addition and ``-1`` for row
+    deletion). Each commit adds a new version of the dataset.
+
+    The columns holding embeddings can be stored as vectors, that is, as fixed-size
+    lists of ``float32`` values. This is the layout the Lance vector indices are
+    built on, so the vector tooling reading the dataset can index and search it
+    without converting it first.
+
+    Args:
+        table: Table to be written.
+        uri: URI of the dataset, either a local path or an ``s3://`` path.
+        vector_columns: The columns to be stored as vectors, mapped to the dimensions
+            of their vectors. The columns must be lists or one-dimensional arrays of
+            floats, and each value must have exactly the given number of elements,
+            otherwise the program terminates with an error.
+        s3_connection_settings: Configuration for S3 credentials when using S3
+            storage. If not specified for an ``s3://`` path, the credentials are
+            deduced from the environment.
+        min_commit_frequency: Specifies the minimum time interval between two data
+            commits in storage, measured in milliseconds. If set to ``None``,
+            finalized minibatches will be committed as soon as possible. Each commit
+            adds a new data file and a new version of the dataset, so it is advisable
+            to limit the frequency of commits.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards.
+        sort_by: If specified, the output will be sorted in ascending order based on
+            the values of the given columns within each minibatch. When multiple
+            columns are provided, the corresponding value tuples will be compared
+            lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
+
+    Returns:
+        None
+
+    Example:
+
+    Consider a table ``documents`` with the texts and their embeddings computed by a
+    model producing vectors of 384 elements:
+
+    >>> import pathway as pw
+    >>> documents = pw.debug.table_from_markdown('''
+    ... doc_id | text
+    ... 1      | Pathway processes data streams
+    ... 2      | Lance stores vectors
+    ... ''')
+    >>> def embed(text: str) -> list[float]:
+    ...     return [0.0] * 384
+    >>> documents = documents.with_columns(embedding=pw.apply(embed, pw.this.text))
+
+    The table can be saved into a local Lance dataset, with the embeddings stored as
+    vectors:
+
+    >>> pw.io.lance.write(
+    ...     documents,
+    ...     "./documents.lance",
+    ...     vector_columns={documents.embedding: 384},
+    ... )
+
+    Once the program is run with ``pw.run()``, the dataset can be opened, indexed
+    and searched with the ``lance`` Python package or with LanceDB.
+    """
+    prepared_vector_columns = {}
+    for column, dimension in (vector_columns or {}).items():
+        if column._table != table:
+            raise ValueError(
+                f"The vector column {column} doesn't belong to the table {table}"
+            )
+        if dimension <= 0:
+            raise ValueError(
+                f"The dimension of the vector column {column} must be positive"
+            )
+        prepared_vector_columns[column._name] = dimension
+
+    uri = fspath(uri)
+    prepared_connection_settings = _prepare_s3_connection_settings(
+        s3_connection_settings
+    )
+    if is_s3_path(uri) and prepared_connection_settings is None:
+        prepared_connection_settings = AwsS3Settings.new_from_path(uri)
+    engine_connection_settings = None
+    if prepared_connection_settings is not None:
+        prepared_connection_settings.authorize()
+        engine_connection_settings = prepared_connection_settings.settings
+
+    data_storage = api.DataStorage(
+        storage_type="lance",
+        path=uri,
+        aws_s3_settings=engine_connection_settings,
+        min_commit_frequency=min_commit_frequency,
+        lance_settings=api.LanceSettings(vector_columns=prepared_vector_columns),
+    )
+    data_format = api.DataFormat(
+        format_type="identity",
+        key_field_names=None,
+        value_fields=_format_output_value_fields(table),
+    )
+
+    table.to(
+        datasink.GenericDataSink(
+            data_storage,
+            data_format,
+            datasink_name="lance",
+            unique_name=name,
+            sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
+        )
+    )
+
+
+__all__ = ["write"]
@@ -328,6 +328,8 @@ def write(
     max_batch_size: int | None = None,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes ``table`` to a MongoDB table.
 
@@ -380,6 +382,15 @@ for the details.
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
 
     Returns:
         None
@@ -684,5 +695,7 @@ for the details.
             datasink_name=f"mongodb.{datasink_type}",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
         )
     )
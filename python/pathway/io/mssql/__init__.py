@@ -17,6 +17,7 @@ from pathway.io._utils import (
     SNAPSHOT_OUTPUT_TABLE_TYPE,
     get_column_index,
     init_mode_from_str,
+    output_mode_from_str,
     read_schema,
 )
 
@@ -42,6 +43,7 @@ def read(
     *,
     mode: Literal["static", "streaming"] = "streaming",
     schema_name: str = "dbo",
+    start_from_lsn: bytes | None = None,
     autocommit_duration_ms: int | None = 1500,
     name: str | None = None,
     max_backlog_size: int | None = None,
@@ -112,6 +114,14 @@ def read(
             snapshot, then terminates; no CDC setup is needed.
         schema_name: Name of the database schema containing the table. Defaults to
             ``"dbo"``, which is the default schema in MSSQL.
+        start_from_lsn: If defined, the snapshot is skipped and only the changes
+            recorded by CDC strictly after the given log sequence number are read,
+            e.g. an LSN returned by ``sys.fn_cdc_map_time_to_lsn``. It must be given
+            in the 10-byte binary form used by SQL Server and requires CDC on the
+            table. It allows recomputing the results from a known point. The
+            changes of the rows inserted before that point are read as insertions
+            of the new values, and their deletions are skipped. The persisted state
+            of the connector, if any, is then neither used nor updated.
         autocommit_duration_ms: The maximum time between two commits. Every
             autocommit_duration_ms milliseconds, the updates received by the connector are
             committed and pushed into Pathway Live Data Framework's computation graph.
@@ -257,6 +267,11 @@ def read(
         commit_duration_ms=autocommit_duration_ms,
         unique_name=name,
         max_backlog_size=max_backlog_size,
+        start_from=(
+            None
+            if start_from_lsn is None
+            else [api.ConnectorOffset.mssql_lsn(start_from_lsn)]
+        ),
     )
     return table_from_datasource(
         datasource.GenericDataSource(
@@ -285,6 +300,9 @@ def write(
     primary_key: list[ColumnReference] | None = None,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    output_mode: Literal["streaming", "watermark_atomic"] = "streaming",
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes ``table`` to a Microsoft SQL Server table.
 
@@ -330,6 +348,21 @@ def write(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        output_mode: When the changes are written. With ``"streaming"`` (the default),
+            each worker writes its part of a minibatch as soon as it's computed. With
+            ``"watermark_atomic"``, the changes are withheld until the whole minibatch
+            is computed, and then written by a single worker in a single transaction,
+            so that the readers of the MSSQL table never see a part of a minibatch.
+            It can't be used together with ``max_batch_size``.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
 
     Returns:
         None
@@ -490,5 +523,10 @@ def write(
             datasink_name=f"mssql.{datasink_type}",
             unique_name=name,
             sort_by=sort_by,
+            output_mode=output_mode_from_str(
+                output_mode, max_batch_size=max_batch_size
+            ),
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
         )
     )
@@ -139,6 +139,8 @@ def write(
     batch_size: int = 100,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes a Pathway Live Data Framework table to a `Pinecone <https://www.pinecone.io/>`_ index.
 
@@ -220,6 +222,15 @@ def write(
             in logs and monitoring dashboards.
         sort_by: If specified, the output within each mini-batch will be sorted in
             ascending order by the given columns.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
 
     Returns:
         None
@@ -429,6 +440,8 @@ def write(
             datasink_name="pinecone",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
         )
     )
 
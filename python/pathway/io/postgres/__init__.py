@@ -3,6 +3,7 @@
 from __future__ import annotations
 
 import copy
+import datetime
 import urllib.parse
 import warnings
 from typing import Any, Iterable, Literal
@@ -17,8 +18,12 @@ from pathway.internals.table_io import table_from_datasource
 from pathway.internals.trace import trace_user_frame
 from pathway.io._utils import (
     SNAPSHOT_OUTPUT_TABLE_TYPE,
+    DurationLike,
+    construct_sql_cursor_settings,
+    delivery_semantics_from_str,
     get_column_index,
     init_mode_from_str,
+    output_mode_from_str,
     read_schema,
 )
 
@@ -283,13 +288,19 @@ def _construct_replication_settings(
 @trace_user_frame
 def read(
     postgres_settings: dict,
-    table_name: str,
+    table_name: str | None,
     schema: type[Schema],
     *,
     mode: Literal["streaming", "static"] = "streaming",
     is_append_only: bool = False,
     publication_name: str | None = None,
     schema_name: str | None = "public",
+    cursor_column: str | None = None,
+    id_column: str | None = None,
+    query: str | None = None,
+    max_cursor_lag: int = 0,
+    read_batch_size: int = 10_000,
+    poll_interval: DurationLike = datetime.timedelta(seconds=1),
     autocommit_duration_ms: int | None = 1500,
     name: str | None = None,
     max_backlog_size: int | None = None,
@@ -322,6 +333,21 @@ def read(
 
         CREATE PUBLICATION {publication_name} FOR TABLE {table_name};
 
+    If logical replication isn't available, e.g. on a read replica or for a user
+    without the replication privileges, an append-only table can be polled by a
+    **cursor column** instead: pass ``cursor_column``, an integer column growing
+    with the inserted rows, such as a ``SERIAL`` id or a Unix timestamp. The
+    connector then periodically runs a ``SELECT`` for the rows whose cursor is at
+    or above the last one read, ordered by the cursor, in blocks of at most
+    ``read_batch_size`` rows. The rows with the same cursor are told apart by
+    ``id_column``, which also becomes the key of the resulting table, so a schema
+    primary key is not allowed in this mode. Updates and deletions of the rows
+    already read are not observed. Instead of a table, the rows can come from an
+    arbitrary ``SELECT`` given as ``query``, as long as it returns the columns of
+    the schema. In this mode the position of the reader is persisted, so the
+    pipeline can be resumed with ``pw.persistence.Config``; in ``"static"`` mode
+    the rows present at the start are read and the connector stops.
+
     Args:
         postgres_settings: Connection parameters for PostgreSQL, provided as a
             dictionary of key-value pairs. The connection string is assembled by joining
@@ -336,7 +362,8 @@ def read(
         table_name: Name of the PostgreSQL table to read from. Any PostgreSQL
             identifier is accepted — the connector quotes the name before
             interpolating it into generated SQL, so hyphens, mixed case, and
-            reserved words round-trip as-is.
+            reserved words round-trip as-is. Must be ``None`` if ``query`` is
+            given.
         schema: Pathway Live Data Framework schema describing the table's columns and their types.
             Column names may be any PostgreSQL identifier for the same reason
             as ``table_name``.
@@ -357,6 +384,24 @@ def read(
         schema_name: Name of the PostgreSQL schema in which the table resides.
             Defaults to ``"public"``; only needs to be changed when using a non-default
             schema.
+        cursor_column: If given, the table is polled by the values of this integer
+            column instead of being read from the WAL (see above).
+        id_column: When polling by a cursor column, the column identifying the rows.
+            It defaults to ``cursor_column``, which fits a unique cursor such as a
+            ``SERIAL`` id.
+        query: When polling by a cursor column, a ``SELECT`` returning the rows to
+            be read, used instead of ``table_name``.
+        max_cursor_lag: When polling by a cursor column, how far behind the largest
+            cursor value a row can still be committed, in the units of the cursor
+            column. The rows within this distance from the largest cursor are
+            re-read on each poll until they settle. Concurrent transactions commit
+            ``SERIAL`` ids out of order, so a busy table needs a lag covering the
+            ids allocated during the longest transaction.
+        read_batch_size: When polling by a cursor column, the maximum number of rows
+            read by a single query.
+        poll_interval: When polling by a cursor column, how long to wait between two
+            polls once all the rows are read. Given as a number of seconds or a
+            ``datetime.timedelta`` / ``pw.Duration``.
         autocommit_duration_ms: the maximum time between two commits. Every
             ``autocommit_duration_ms`` milliseconds, the updates received by the connector
             are committed and pushed into Pathway Live Data Framework's computation graph.
@@ -552,7 +597,49 @@ def read(
     ...     mode="streaming",
     ...     publication_name="order_items_pub",
     ... )
+
+    Finally, suppose that the ``events`` table is only ever appended to, with a
+    ``SERIAL`` column ``id``, and that logical replication can't be enabled. The new
+    rows can then be polled by the ``id`` column. The schema must not declare a
+    primary key, as the resulting table is keyed by ``id``:
+
+    >>> class EventsSchema(pw.Schema):
+    ...     id: int
+    ...     payload: str
+
+    >>> table = pw.io.postgres.read(
+    ...     postgres_settings=connection_string_parts,
+    ...     table_name="events",
+    ...     schema=EventsSchema,
+    ...     cursor_column="id",
+    ...     max_cursor_lag=100,
+    ... )
     """
+    if (table_name is None) == (query is None):
+        raise ValueError("exactly one of 'table_name' and 'query' must be specified")
+    if cursor_column is not None:
+        return _read_by_cursor(
+            postgres_settings,
+            table_name,
+            schema,
+            mode=mode,
+            schema_name=schema_name,
+            cursor_column=cursor_column,
+            id_column=id_column,
+            query=query,
+            max_cursor_lag=max_cursor_lag,
+            read_batch_size=read_batch_size,
+            poll_interval=poll_interval,
+            autocommit_duration_ms=autocommit_duration_ms,
+            name=name,
+            max_backlog_size=max_backlog_size,
+            debug_data=debug_data,
+        )
+    if query is not None or id_column is not None:
+        raise ValueError(
+            "'query' and 'id_column' can only be used with 'cursor_column'"
+        )
+
     _check_entitlements("postgres-wal-reader")
 
     postgres_settings = _augment_postgres_settings(postgres_settings, name)
@@ -600,6 +687,76 @@ def read(
     )
 
 
+def _read_by_cursor(
+    postgres_settings: dict,
+    table_name: str | None,
+    schema: type[Schema],
+    *,
+    mode: Literal["streaming", "static"],
+    schema_name: str | None,
+    cursor_column: str,
+    id_column: str | None,
+    query: str | None,
+    max_cursor_lag: int,
+    read_batch_size: int,
+    poll_interval: DurationLike,
+    autocommit_duration_ms: int | None,
+    name: str | None,
+    max_backlog_size: int | None,
+    debug_data: Any,
+) -> Table:
+    cursor_settings, id_column = construct_sql_cursor_settings(
+        "pw.io.postgres.read",
+        schema,
+        cursor_column=cursor_column,
+        id_column=id_column,
+        query=query,
+        max_cursor_lag=max_cursor_lag,
+        read_batch_size=read_batch_size,
+        poll_interval=poll_interval,
+    )
+    postgres_settings = _augment_postgres_settings(postgres_settings, name)
+    owned_postgres_settings = copy.copy(postgres_settings)
+    tls = _build_tls_settings(owned_postgres_settings)
+    data_storage = api.DataStorage(
+        storage_type="postgres",
+        connection_string=_connection_string_from_settings(owned_postgres_settings),
+        table_name=table_name,
+        schema_name=schema_name,
+        tls_settings=tls.settings,
+        mode=(
+            api.ConnectorMode.STATIC
+            if mode == "static"
+            else api.ConnectorMode.STREAMING
+        ),
+        sql_cursor_settings=cursor_settings,
+    )
+
+    schema, api_schema = read_schema(schema)
+    api_schema["key_field_names"] = [id_column]
+    data_format = api.DataFormat(
+        format_type="jsonlines",
+        session_type=api.SessionType.UPSERT,
+        **api_schema,
+    )
+
+    data_source_options = datasource.DataSourceOptions(
+        commit_duration_ms=autocommit_duration_ms,
+        unique_name=name,
+        max_backlog_size=max_backlog_size,
+    )
+    return table_from_datasource(
+        datasource.GenericDataSource(
+            datastorage=data_storage,
+            dataformat=data_format,
+            schema=schema,
+            data_source_options=data_source_options,
+            datasource_name="postgres",
+        ),
+        debug_datasource=datasource.debug_datasource(debug_data),
+    )
+
+
 @check_arg_types
 @trace_user_frame
 def write(
@@ -614,6 +771,12 @@ def write(
     primary_key: list[ColumnReference] | None = None,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    parallel_writers: int = 1,
+    partition_by: Iterable[ColumnReference] | None = None,
+    delivery_semantics: Literal["at_least_once", "exactly_once"] = "exactly_once",
+    output_mode: Literal["streaming", "watermark_atomic"] = "streaming",
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
     _external_diff_column: ColumnReference | None = None,
 ) -> None:
     """Writes ``table`` to a Postgres table. Two types of output tables are supported:
@@ -671,6 +834,36 @@ def write(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        parallel_writers: The number of writers each worker runs, each with its own
+            connection to Postgres. The rows of a minibatch are split between them, and
+            the minibatch is considered written once all of them have committed it.
+            The ordering given by ``sort_by`` then only holds within each writer.
+        partition_by: The columns deciding which writer a row goes to when
+            ``parallel_writers`` is greater than one: the rows with the same values in
+            these columns are always written by the same writer. If not specified,
+            the rows are split by the ``primary_key`` columns in the snapshot mode and
+            by their Pathway keys otherwise.
+        delivery_semantics: What happens to the minibatches replayed from the
+            persisted state when the program restarts. With ``"exactly_once"`` (the
+            default), the minibatches that had been committed to Postgres before the
+            restart are not written again. With ``"at_least_once"``, they are written
+            again, which is useful when the table is recreated on each start with
+            ``init_mode="replace"``.
+        output_mode: When the changes are written. With ``"streaming"`` (the default),
+            each worker writes its part of a minibatch as soon as it's computed. With
+            ``"watermark_atomic"``, the changes are withheld until the whole minibatch
+            is computed, and then written by a single worker in a single transaction,
+            so that the readers of the Postgres table never see a part of a minibatch.
+            It can't be used together with ``max_batch_size`` or ``parallel_writers``.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            a writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
 
     Returns:
         None
@@ -952,6 +1145,12 @@ def write(
         external_diff_column_index=external_diff_column_index,
     )
 
+    if parallel_writers < 1:
+        raise ValueError("parallel_writers must be positive")
+    if partition_by is None and is_snapshot_mode:
+        # the updates of a row have to be applied by a single writer in order
+        partition_by = primary_key
+
     datasink_type = "snapshot" if is_snapshot_mode else "sink"
     table.to(
         datasink.GenericDataSink(
@@ -960,6 +1159,14 @@ def write(
             datasink_name=f"postgres.{datasink_type}",
             unique_name=name,
             sort_by=sort_by,
+            parallel_writers=parallel_writers,
+            partition_by=partition_by,
+            delivery_semantics=delivery_semantics_from_str(delivery_semantics),
+            output_mode=output_mode_from_str(
+                output_mode, max_batch_size=max_batch_size
+            ),
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
         )
     )
 
@@ -975,6 +1182,8 @@ def write_snapshot(
     init_mode: Literal["default", "create_if_not_exists", "replace"] = "default",
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
     _external_diff_column: ColumnReference | None = None,
 ) -> None:
     """**WARNING**: This method is deprecated. Please use ``pw.io.postgres.write`` with
@@ -1002,6 +1211,15 @@ def write_snapshot(
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
 
     Returns:
         None
@@ -1118,5 +1336,7 @@ def write_snapshot(
             datasink_name="postgres.snapshot",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
         )
     )
@@ -1,15 +1,26 @@
 from __future__ import annotations
 
 import logging
-from typing import TYPE_CHECKING, Any, Iterable
+from typing import TYPE_CHECKING, Any, Iterable, Literal
 
 if TYPE_CHECKING:
     from google.cloud import pubsub_v1  # type: ignore
 
 import pathway.internals.dtype as dt
+from pathway.internals import api, datasink, datasource
 from pathway.internals.api import Pointer
 from pathway.internals.expression import ColumnReference
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.schema import Schema
+from pathway.internals.table import Table
+from pathway.internals.table_io import table_from_datasource
+from pathway.internals.trace import trace_user_frame
 from pathway.io._subscribe import subscribe
+from pathway.io._utils import (
+    MessageQueueOutputFormat,
+    _get_unique_name,
+    construct_schema_and_data_format,
+)
 
 
 class _OutputBuffer:
@@ -50,24 +61,162 @@ class _OutputBuffer:
         self._publish_futures = []
 
 
+@check_arg_types
+@trace_user_frame
+def read(
+    project_id: str,
+    subscription_id: str,
+    *,
+    schema: type[Schema] | None = None,
+    format: Literal["plaintext", "raw", "json"] = "raw",
+    autocommit_duration_ms: int = 1500,
+    json_field_paths: dict[str, str] | None = None,
+    name: str | None = None,
+    max_backlog_size: int | None = None,
+    debug_data=None,
+    **kwargs,
+) -> Table:
+    """
+    Reads a table from a
+    `Google Cloud Pub/Sub subscription <https://cloud.google.com/pubsub/docs/subscription-overview>`_.
+    The credentials are retrieved from the environment, for example from the file
+    pointed by the ``GOOGLE_APPLICATION_CREDENTIALS`` variable. If the
+    ``PUBSUB_EMULATOR_HOST`` variable is set, the connector uses the emulator instead.
+
+    The messages are received with a streaming pull. A message is acknowledged only
+    after the minibatch containing it is committed. If the program stops before that,
+    or if the commit takes longer than the acknowledgement deadline of the
+    subscription, the message is delivered again.
+
+    All workers pull the messages from the subscription in parallel. If the
+    subscription has the message ordering enabled, the messages with the same ordering
+    key are delivered to the same worker, in the order they were published.
+
+    There are three supported formats: ``"plaintext"``, ``"raw"``, and ``"json"``.
+
+    For the ``"raw"`` format, the message data is read as raw bytes and added directly
+    to the table. In the ``"plaintext"`` format, the message data is stored as plain
+    text. In both cases, the table will have an autogenerated primary key and a single
+    ``"data"`` column representing the message data.
+
+    If you select the ``"json"`` format, the connector parses the message data as JSON
+    and creates table columns based on the schema provided in the ``schema`` parameter.
+    The column values come from the corresponding JSON fields.
+
+    Args:
+        project_id: The ID of the project the subscription belongs to.
+        subscription_id: The ID of the subscription to be read.
+        schema: The table schema, used only when the format is set to ``"json"``.
+        format: The input data format, which can be ``"raw"``, ``"plaintext"``, or
+            ``"json"``.
+        autocommit_duration_ms: The time interval (in milliseconds) between commits.
+            After this time, the updates received by the connector are committed and
+            added to Pathway Live Data Framework's computation graph.
+        json_field_paths: For the ``"json"`` format, this allows mapping field names to
+            paths within the JSON structure. Use the format ``<field_name>: <path>``
+            where the path follows the
+            `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards. Additionally, if persistence is enabled, it
+            will be used as the name for the snapshot that stores the connector's progress.
+        max_backlog_size: Limit on the number of entries read from the input source and kept
+            in processing at any moment. Reading pauses when the limit is reached and resumes
+            as processing of some entries completes. Useful with large sources that
+            emit an initial burst of data to avoid memory spikes.
+        debug_data: Static data replacing original one when debug mode is active.
+
+    Returns:
+        Table: The table read.
+
+    Example:
+
+    Suppose that the project ``"my-project"`` has the subscription ``"events-sub"``,
+    whose messages are JSON objects with the fields ``user`` and ``action``:
+
+    >>> import pathway as pw
+    >>> class InputSchema(pw.Schema):
+    ...     user: str
+    ...     action: str
+
+    You can read them as follows:
+
+    >>> table = pw.io.pubsub.read(
+    ...     "my-project",
+    ...     "events-sub",
+    ...     format="json",
+    ...     schema=InputSchema,
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    data_storage = api.DataStorage(
+        storage_type="pubsub",
+        topic=f"projects/{project_id}/subscriptions/{subscription_id}",
+        mode=api.ConnectorMode.STREAMING,
+    )
+    schema, data_format = construct_schema_and_data_format(
+        "binary" if format == "raw" else format,
+        schema=schema,
+        csv_settings=None,
+        json_field_paths=json_field_paths,
+    )
+    data_source_options = datasource.DataSourceOptions(
+        commit_duration_ms=autocommit_duration_ms,
+        unique_name=_get_unique_name(name, kwargs),
+        max_backlog_size=max_backlog_size,
+    )
+    return table_from_datasource(
+        datasource.GenericDataSource(
+            datastorage=data_storage,
+            dataformat=data_format,
+            data_source_options=data_source_options,
+            schema=schema,
+            datasource_name="pubsub",
+        ),
+        debug_datasource=datasource.debug_datasource(debug_data),
+    )
+
+
 def write(
     table,
-    publisher: pubsub_v1.PublisherClient,
+    publisher: pubsub_v1.PublisherClient | None,
     project_id: str,
     topic_id: str,
     *,
+    format: Literal["json", "dsv", "plaintext", "raw"] | None = None,
+    delimiter: str = ",",
+    data: ColumnReference | None = None,
+    ordering_key: ColumnReference | None = None,
+    headers: Iterable[ColumnReference] | None = None,
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
-    """Publish the ``table``'s stream of changes into the specified PubSub topic. Please note
-    that ``table`` must consist of a single column of the binary type. In addition, the connector
-    adds two attributes: ``pathway_time`` containing the logical time of the change and
-    ``pathway_diff`` corresponding to the change type: either addition (``pathway_diff = 1``)
-    or deletion (``pathway_diff = -1``).
+    """Publish the ``table``'s stream of changes into the specified PubSub topic. In
+    addition to the message data, the connector adds two attributes: ``pathway_time``
+    containing the logical time of the change and ``pathway_diff`` corresponding to the
+    change type: either addition (``pathway_diff = 1``) or deletion
+    (``pathway_diff = -1``).
+
+    If ``publisher`` is ``None``, the messages are published by the engine. The
+    credentials are then retrieved from the environment, for example from the file
+    pointed by the ``GOOGLE_APPLICATION_CREDENTIALS`` variable, or the emulator is used
+    if the ``PUBSUB_EMULATOR_HOST`` variable is set. The messages can be serialized in
+    one of the ``"json"``, ``"dsv"``, ``"plaintext"`` and ``"raw"`` formats, and given
+    an ordering key, so that the messages with the same key are delivered in the order
+    they were published.
+
+    If a ``publisher`` is given, it publishes the messages instead, and ``table`` must
+    consist of a single column of the binary type. The serialization and the ordering
+    key aren't supported then.
 
     Args:
         table: The table to publish.
-        publisher: The configured ``pubsub_v1.PublisherClient`` object. You can refer to \
+        publisher: The configured ``pubsub_v1.PublisherClient`` object, or ``None`` if \
+the messages should be published by the engine. You can refer to \
 the Google Cloud `documentation \
 <https://cloud.google.com/pubsub/docs/samples/pubsub-quickstart-publisher?hl=en>`_ for \
 the example of a simple publisher configuration. You can also see the examples for \
@@ -77,11 +226,39 @@ the example of a simple publisher configuration. You can also see the examples f
 <https://cloud.google.com/pubsub/docs/samples/pubsub-publisher-flow-control?hl=en>`_.
         project_id: The ID of the project where the changes are published.
         topic_id: The topic ID where the changes are published.
+        format: The format in which the messages are serialized, if ``publisher`` is
+            ``None``: ``"json"``, ``"dsv"``, ``"plaintext"`` or ``"raw"``. Defaults to
+            ``"raw"`` for the tables consisting of a single binary column and to
+            ``"json"`` otherwise. In the ``"plaintext"`` and ``"raw"`` formats, the
+            message data is taken from the single column of the table, or from the
+            ``data`` column.
+        delimiter: The field delimiter, used only with the ``"dsv"`` format.
+        data: Reference to the column used as the message data in the ``"plaintext"``
+            and ``"raw"`` formats. It has to be specified if the table has more than one
+            column.
+        ordering_key: Reference to the column whose values are used as the ordering
+            keys of the messages. The subscriptions with the message ordering enabled
+            receive the messages with the same key in the order they were published.
+            If the values aren't strings, their string representations are used.
+        headers: References to the columns whose values are added to the message
+            attributes, under the names of the columns.
         name: A unique name for the connector. If provided, this name will be used in
             logs and monitoring dashboards.
         sort_by: If specified, the output will be sorted in ascending order based on the
             values of the given columns within each minibatch. When multiple columns are provided,
             the corresponding value tuples will be compared lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be published, if
+            ``publisher`` is ``None``. When the engine falls behind, the computation is
+            paused until it catches up instead of keeping the pending output in memory.
+            If not specified, there's no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -119,9 +296,56 @@ the example of a simple publisher configuration. You can also see the examples f
     >>> import pathway as pw
     >>> pw.io.pubsub.write(table, publisher, project_id, topic_id)  # doctest: +SKIP
 
+    Alternatively, the engine can publish the messages with the credentials from the
+    environment. Then the table doesn't have to consist of a single binary column. For
+    example, the rows of a table ``events`` with a ``user`` column can be published as
+    JSON objects, so that the events of each user are delivered in order:
+
+    >>> pw.io.pubsub.write(  # doctest: +SKIP
+    ...     events,
+    ...     None,
+    ...     project_id,
+    ...     "events",
+    ...     format="json",
+    ...     ordering_key=events.user,
+    ... )
+
     At last, don't forget to add ``pw.run()`` to run your pipeline.
     """
 
+    if publisher is None:
+        _write_natively(
+            table,
+            topic=f"projects/{project_id}/topics/{topic_id}",
+            format=format,
+            delimiter=delimiter,
+            data=data,
+            ordering_key=ordering_key,
+            headers=headers,
+            name=name,
+            sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
+        )
+        return
+
+    if (
+        format is not None
+        or delimiter != ","
+        or data is not None
+        or ordering_key is not None
+        or headers is not None
+        or queue_capacity is not None
+        or max_batch_size is not None
+        or linger_ms is not None
+    ):
+        raise ValueError(
+            "'format', 'delimiter', 'data', 'ordering_key', 'headers', "
+            "'queue_capacity', 'max_batch_size' and 'linger_ms' are only supported "
+            "if 'publisher' is None"
+        )
+
     columns = list(table._columns.values())
     if len(columns) != 1:
         raise ValueError(
@@ -140,3 +364,58 @@ the example of a simple publisher configuration. You can also see the examples f
         name=name,
         sort_by=sort_by,
     )
+
+
+def _write_natively(
+    table: Table,
+    *,
+    topic: str,
+    format: Literal["json", "dsv", "plaintext", "raw"] | None,
+    delimiter: str,
+    data: ColumnReference | None,
+    ordering_key: ColumnReference | None,
+    headers: Iterable[ColumnReference] | None,
+    name: str | None,
+    sort_by: Iterable[ColumnReference] | None,
+    queue_capacity: int | None,
+    max_batch_size: int | None,
+    linger_ms: int | None,
+) -> None:
+    if format is None:
+        columns = list(table._columns.values())
+        is_single_binary_column = len(columns) == 1 and columns[0].dtype == dt.BYTES
+        format = "raw" if is_single_binary_column else "json"
+
+    output_format = MessageQueueOutputFormat.construct(
+        table,
+        format=format,
+        delimiter=delimiter,
+        key=ordering_key,
+        value=data,
+        headers=headers,
+        allowed_key_types=None,
+    )
+    table = output_format.table
+
+    data_storage = api.DataStorage(
+        storage_type="pubsub",
+        topic=topic,
+        key_field_index=output_format.key_field_index,
+        header_fields=list(output_format.header_fields.items()),
+    )
+
+    table.to(
+        datasink.GenericDataSink(
+            data_storage,
+            output_format.data_format,
+            datasink_name="pubsub",
+            unique_name=name,
+            sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
+        )
+    )
+
+
+__all__ = ["read", "write"]
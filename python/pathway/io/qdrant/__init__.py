@@ -20,6 +20,9 @@ def write(
     api_key: str | None = None,
     batch_size: int = 256,
     name: str | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes a Pathway Live Data Framework table to a `Qdrant <https://qdrant.tech/>`_ collection.
 
@@ -73,6 +76,18 @@ def write(
             requests, keeping each request bounded for high-dimensional vectors.
         name: A unique name for the connector. If provided, this name will be
             used in logs and monitoring dashboards.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Returns:
         None
@@ -158,5 +173,8 @@ def write(
             data_format,
             datasink_name="qdrant",
             unique_name=name,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
         )
     )
@@ -260,6 +260,9 @@ def write(
     name: str | None = None,
     sort_by: Iterable[ColumnReference] | None = None,
     tls_settings: TLSSettings | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes data into the specified RabbitMQ stream.
 
@@ -304,6 +307,18 @@ def write(
             values of the given columns within each minibatch.
         tls_settings: TLS connection settings. Use ``TLSSettings`` to configure
             root certificates, client certificates, and verification mode.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
 
     Examples:
 
@@ -383,5 +398,8 @@ def write(
             datasink_name="rabbitmq",
             unique_name=name,
             sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
         )
     )
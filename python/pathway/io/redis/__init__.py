@@ -0,0 +1,312 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+import datetime
+from typing import Iterable, Literal
+
+from pathway.internals import api, datasink, datasource
+from pathway.internals._io_helpers import _format_output_value_fields
+from pathway.internals.expression import ColumnReference
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.schema import Schema
+from pathway.internals.table import Table
+from pathway.internals.table_io import table_from_datasource
+from pathway.internals.trace import trace_user_frame
+from pathway.io._utils import (
+    MessageQueueOutputFormat,
+    _get_unique_name,
+    construct_schema_and_data_format,
+    get_column_index,
+)
+
+
+@check_arg_types
+@trace_user_frame
+def read(
+    uri: str,
+    stream_key: str,
+    *,
+    consumer_group: str,
+    consumer_name: str = "pathway",
+    data_field: str = "data",
+    schema: type[Schema] | None = None,
+    format: Literal["plaintext", "raw", "json"] = "raw",
+    autocommit_duration_ms: int = 1500,
+    json_field_paths: dict[str, str] | None = None,
+    name: str | None = None,
+    max_backlog_size: int | None = None,
+    debug_data=None,
+    **kwargs,
+) -> Table:
+    """
+    Reads a table from a `Redis stream <https://redis.io/docs/latest/develop/data-types/streams/>`_
+    as a member of a consumer group.
+
+    Each worker joins the consumer group ``consumer_group`` as a separate consumer,
+    named ``<consumer_name>-<worker_index>``, so the entries are distributed between the
+    workers by Redis. If the group doesn't exist, it's created so that it delivers the
+    whole stream, and the stream itself is created if it doesn't exist either.
+
+    An entry is acknowledged only after the minibatch containing it is committed. The
+    entries delivered to a consumer but not acknowledged, for example because the
+    program stopped before the commit, are read again first when the program is
+    restarted with the same number of workers. If persistence is enabled, the id of the
+    last committed entry of each worker is saved with the connector's progress, so the
+    entries that were committed but not acknowledged are only acknowledged on restart,
+    not read again.
+
+    The payload of an entry is the value of its ``data_field`` field. There are three
+    supported formats: ``"plaintext"``, ``"raw"``, and ``"json"``.
+
+    For the ``"raw"`` format, the payload is read as raw bytes and added directly to the
+    table. In the ``"plaintext"`` format, the payload is stored as plain text. In both
+    cases, the table will have an autogenerated primary key and a single ``"data"``
+    column representing the payload.
+
+    If you select the ``"json"`` format, the connector parses the payload as JSON and
+    creates table columns based on the schema provided in the ``schema`` parameter. The
+    column values come from the corresponding JSON fields.
+
+    Args:
+        uri: The URI of the Redis server, for example ``"redis://localhost:6379"``.
+        stream_key: The key of the stream to be read.
+        consumer_group: The name of the consumer group the workers join.
+        consumer_name: The prefix of the consumer names of the workers.
+        data_field: The field of the entries containing the payload.
+        schema: The table schema, used only when the format is set to ``"json"``.
+        format: The input data format, which can be ``"raw"``, ``"plaintext"``, or
+            ``"json"``.
+        autocommit_duration_ms: The time interval (in milliseconds) between commits.
+            After this time, the updates received by the connector are committed and
+            added to Pathway Live Data Framework's computation graph.
+        json_field_paths: For the ``"json"`` format, this allows mapping field names to
+            paths within the JSON structure. Use the format ``<field_name>: <path>``
+            where the path follows the
+            `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards. Additionally, if persistence is enabled, it
+            will be used as the name for the snapshot that stores the connector's progress.
+        max_backlog_size: Limit on the number of entries read from the input source and kept
+            in processing at any moment. Reading pauses when the limit is reached and resumes
+            as processing of some entries completes. Useful with large sources that
+            emit an initial burst of data to avoid memory spikes.
+        debug_data: Static data replacing original one when debug mode is active.
+
+    Returns:
+        Table: The table read.
+
+    Example:
+
+    Suppose that a Redis server runs locally on the default port, and the entries of
+    its stream ``"events"`` have a ``data`` field containing JSON objects with the
+    fields ``user`` and ``action``, for example added with
+    ``XADD events * data '{"user": "alice", "action": "login"}'``:
+
+    >>> import pathway as pw
+    >>> class InputSchema(pw.Schema):
+    ...     user: str
+    ...     action: str
+
+    You can read them as the consumer group ``"analytics"`` as follows:
+
+    >>> table = pw.io.redis.read(
+    ...     "redis://localhost:6379",
+    ...     "events",
+    ...     consumer_group="analytics",
+    ...     format="json",
+    ...     schema=InputSchema,
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    data_storage = api.DataStorage(
+        storage_type="redis",
+        path=uri,
+        topic=stream_key,
+        mode=api.ConnectorMode.STREAMING,
+        redis_stream_settings=api.RedisStreamSettings(
+            consumer_group=consumer_group,
+            consumer_name=consumer_name,
+            data_field=data_field,
+        ),
+    )
+    schema, data_format = construct_schema_and_data_format(
+        "binary" if format == "raw" else format,
+        schema=schema,
+        csv_settings=None,
+        json_field_paths=json_field_paths,
+    )
+    data_source_options = datasource.DataSourceOptions(
+        commit_duration_ms=autocommit_duration_ms,
+        unique_name=_get_unique_name(name, kwargs),
+        max_backlog_size=max_backlog_size,
+    )
+    return table_from_datasource(
+        datasource.GenericDataSource(
+            datastorage=data_storage,
+            dataformat=data_format,
+            data_source_options=data_source_options,
+            schema=schema,
+            datasource_name="redis",
+        ),
+        debug_datasource=datasource.debug_datasource(debug_data),
+    )
+
+
+@check_arg_types
+@trace_user_frame
+def write(
+    table: Table,
+    uri: str,
+    key: ColumnReference,
+    *,
+    key_prefix: str = "",
+    value_type: Literal["hash", "string"] = "hash",
+    format: Literal["json", "plaintext", "raw"] = "json",
+    value: ColumnReference | None = None,
+    ttl: datetime.timedelta | None = None,
+    name: str | None = None,
+    sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    max_batch_size: int | None = None,
+    linger_ms: int | None = None,
+) -> None:
+    """
+    Maintains the current state of ``table`` in a
+    `Redis <https://redis.io/docs/latest/>`_ server, so that it can be served directly
+    from there, for example as a cache.
+
+    Each row is stored under the key made of ``key_prefix`` and the value of the ``key``
+    column. When a row is inserted or updated, the value under its key is replaced, and
+    when a row is deleted, its key is deleted too. If the values of the ``key`` column
+    aren't strings or bytes, their string representations are used. The changes of a
+    minibatch are applied together in a single transaction once the minibatch ends, so
+    a replaced value is never seen partially updated, and a key moved from one row to
+    another is never seen deleted.
+
+    There are two types of the values stored:
+
+    - ``"hash"``: each row is a hash, whose fields are the columns of the table. The
+      string and binary values are stored as they are, and the other values in their
+      JSON representation. The fields with the ``None`` values are omitted.
+    - ``"string"``: each row is a string, which is the row serialized in the given
+      ``format``. In the ``"json"`` format, the row is serialized as a JSON object. In
+      the ``"plaintext"`` and ``"raw"`` formats, the value is taken from the ``value``
+      column, or from the single column of the table.
+
+    Args:
+        table: The table to write.
+        uri: The URI of the Redis server, for example ``"redis://localhost:6379"``.
+        key: Reference to the column whose values identify the rows.
+        key_prefix: The prefix added to the keys, for example to separate the rows of
+            several tables.
+        value_type: The type of the values stored under the keys, either ``"hash"`` or
+            ``"string"``.
+        format: The format in which the rows are serialized if ``value_type`` is
+            ``"string"``: ``"json"``, ``"plaintext"`` or ``"raw"``.
+        value: Reference to the column used as the value in the ``"plaintext"`` and
+            ``"raw"`` formats. It has to be specified if the table has more than one
+            column.
+        ttl: If specified, the keys expire after this time since the last update of
+            their rows.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards.
+        sort_by: If specified, the output will be sorted in ascending order based on the
+            values of the given columns within each minibatch. When multiple columns are provided,
+            the corresponding value tuples will be compared lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        max_batch_size: The maximal number of changes written between two flushes of
+            the writer. The larger minibatches are split into several flushes. If not
+            specified, each minibatch is flushed at once.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them, unless there are more than ``max_batch_size`` of them.
+            A minibatch is considered written only once it has been flushed. If not
+            specified, the changes are flushed after each minibatch.
+
+    Returns:
+        None
+
+    Example:
+
+    Suppose that a Redis server runs locally on the default port, and you want to serve
+    the latest status of each user from there:
+
+    >>> import pathway as pw
+    >>> statuses = pw.debug.table_from_markdown(
+    ...     '''
+    ...     user  | status
+    ...     alice | online
+    ...     bob   | away
+    ... '''
+    ... )
+    >>> pw.io.redis.write(
+    ...     statuses,
+    ...     "redis://localhost:6379",
+    ...     statuses.user,
+    ...     key_prefix="status:",
+    ... )
+
+    Once the program is run with ``pw.run()``, ``HGETALL status:alice`` returns the
+    fields ``user`` and ``status`` of the row of ``alice``. If the row is deleted from
+    the table, the key ``status:alice`` is deleted as well.
+    """
+
+    if ttl is not None and ttl <= datetime.timedelta(0):
+        raise ValueError(f"'ttl' must be positive, got {ttl}")
+    if value_type == "hash" and (format != "json" or value is not None):
+        raise ValueError(
+            "'format' and 'value' are only supported if 'value_type' is 'string'"
+        )
+
+    ttl_ms = None if ttl is None else max(ttl // datetime.timedelta(milliseconds=1), 1)
+    if value_type == "hash":
+        key_field_index = get_column_index(table, key)
+        data_format = api.DataFormat(
+            format_type="identity",
+            key_field_names=[],
+            value_fields=_format_output_value_fields(table),
+        )
+    else:
+        output_format = MessageQueueOutputFormat.construct(
+            table,
+            format=format,
+            key=key,
+            value=value,
+            allowed_key_types=None,
+        )
+        table = output_format.table
+        key_field_index = output_format.key_field_index
+        data_format = output_format.data_format
+
+    data_storage = api.DataStorage(
+        storage_type="redis",
+        path=uri,
+        key_field_index=key_field_index,
+        redis_key_value_settings=api.RedisKeyValueSettings(
+            key_prefix=key_prefix,
+            use_hashes=value_type == "hash",
+            ttl_ms=ttl_ms,
+        ),
+    )
+
+    table.to(
+        datasink.GenericDataSink(
+            data_storage,
+            data_format,
+            datasink_name="redis",
+            unique_name=name,
+            sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            max_batch_size=max_batch_size,
+            linger_ms=linger_ms,
+        )
+    )
+
+
+__all__ = ["read", "write"]
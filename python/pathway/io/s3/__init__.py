@@ -95,7 +95,13 @@ class WasabiS3Settings:
 def read(
     path: str,
     format: Literal[
-        "csv", "json", "plaintext", "plaintext_by_object", "binary", "only_metadata"
+        "csv",
+        "json",
+        "plaintext",
+        "plaintext_by_object",
+        "binary",
+        "only_metadata",
+        "orc",
     ],
     *,
     aws_s3_settings: AwsS3Settings | None = None,
@@ -129,8 +135,8 @@ def read(
         path: Path to an object or to a folder of objects in Amazon S3 bucket.
         aws_s3_settings: Connection parameters for the S3 account and the bucket.
         format: Format of data to be read. Currently ``csv``, ``json``, ``plaintext``,
-            ``plaintext_by_object``, ``binary`` and ``only_metadata`` formats are
-            supported. The difference
+            ``plaintext_by_object``, ``binary``, ``only_metadata`` and ``orc`` formats
+            are supported. The difference
             between ``plaintext`` and ``plaintext_by_object`` is how the input is
             tokenized: if the ``plaintext`` option is chosen, it's split by the newlines.
             Otherwise, the files are split in full and one row will correspond to one
@@ -139,6 +145,8 @@ def read(
             objects are not downloaded at all: the resulting table contains only the
             ``_metadata`` column, which is useful when you only need to track changes in
             the bucket without spending time and traffic on fetching the objects' contents.
+            The ``orc`` format decodes the objects as ORC files, reading only the
+            columns of the schema.
         schema: Schema of the resulting table. Not required for ``plaintext_by_object``
             and ``binary`` formats: if they are chosen, the contents of the read objects
             are stored in the column ``data``.
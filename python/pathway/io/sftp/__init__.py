@@ -0,0 +1,188 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+from typing import Any, Literal
+
+from pathway.internals import Schema, api, datasource
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.table import Table
+from pathway.internals.table_io import table_from_datasource
+from pathway.internals.trace import trace_user_frame
+from pathway.io._utils import (
+    CsvParserSettings,
+    _get_unique_name,
+    construct_schema_and_data_format,
+    internal_connector_mode,
+    internal_read_method,
+)
+
+
+@check_arg_types
+@trace_user_frame
+def read(
+    host: str,
+    path: str,
+    format: Literal[
+        "csv", "json", "plaintext", "plaintext_by_file", "binary", "only_metadata"
+    ],
+    *,
+    user: str,
+    port: int = 22,
+    password: str | None = None,
+    private_key_path: str | None = None,
+    private_key_passphrase: str | None = None,
+    known_hosts_path: str | None = None,
+    schema: type[Schema] | None = None,
+    mode: Literal["streaming", "static"] = "streaming",
+    csv_settings: CsvParserSettings | None = None,
+    json_field_paths: dict[str, str] | None = None,
+    object_pattern: str = "*",
+    with_metadata: bool = False,
+    name: str | None = None,
+    autocommit_duration_ms: int | None = 1500,
+    max_backlog_size: int | None = None,
+    debug_data: Any = None,
+    **kwargs,
+) -> Table:
+    """Reads a table from the files in a directory of an SFTP server.
+
+    The connector lists the directory ``path`` recursively and reads the files whose
+    names match ``object_pattern``. The files are read in the order of their
+    modification time, so the files dropped to the directory earlier are processed
+    first. In the ``"streaming"`` mode, the directory is polled for new files, and the
+    files that were modified or deleted on the server are reflected in the table in the
+    same way as in ``pw.io.fs.read``. If persistence is enabled, the files already
+    processed aren't read again after a restart.
+
+    The files are read by a single worker. The formats and the resulting columns are
+    the same as for ``pw.io.fs.read``.
+
+    If neither ``password`` nor ``private_key_path`` is given, the connector
+    authenticates with the keys provided by the SSH agent.
+
+    Args:
+        host: The host name or the address of the SFTP server.
+        path: The path of the directory on the server, or of a single file to be read.
+        format: Format of data to be read. Currently ``"csv"``, ``"json"``, ``"plaintext"``,
+            ``"plaintext_by_file"``, ``"binary"``, and ``"only_metadata"`` formats are
+            supported. Their meaning is the same as in ``pw.io.fs.read``.
+        user: The name of the user on the server.
+        port: The port of the SFTP server.
+        password: The password of the user.
+        private_key_path: The path of the private key file used for authentication.
+            Can't be specified together with ``password``.
+        private_key_passphrase: The passphrase of the private key, if it's encrypted.
+        known_hosts_path: The path of an OpenSSH ``known_hosts`` file. If specified,
+            the host key presented by the server must match the one in this file.
+        schema: Schema of the resulting table.
+        mode: Denotes how the engine polls the new data from the source. Currently
+            ``"streaming"`` and ``"static"`` are supported. If set to ``"streaming"``,
+            the engine will wait for the new files in the directory and track their
+            modifications and deletions. The ``"static"`` mode will only consider the
+            files available at the start and ingest all of them in one commit. The
+            default value is ``"streaming"``.
+        csv_settings: Settings for the CSV parser. This parameter is used only in case
+            the specified format is ``"csv"``.
+        json_field_paths: If the format is ``"json"``, this field allows to map field names
+            into path in the read json object. For the field which require such mapping,
+            it should be given in the format ``<field_name>: <path to be mapped>``,
+            where the path to be mapped needs to be a
+            `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        object_pattern: Unix shell style pattern for the names of the files to be read.
+            Ignored in case a path to a single file is specified.
+        with_metadata: When set to true, the connector will add an additional column
+            named ``_metadata`` to the table. This JSON field may contain: (1)
+            ``modified_at`` - UNIX timestamp of last modification; (2) ``seen_at`` is a
+            UNIX timestamp of when they file was found by the engine; (3) ``owner`` -
+            The numeric id of the file owner; (4) ``path`` - Full path of the file on the
+            server. (5) ``size`` - File size in bytes.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards. Additionally, if persistence is enabled, it
+            will be used as the name for the snapshot that stores the connector's progress.
+        max_backlog_size: Limit on the number of entries read from the input source and kept
+            in processing at any moment. Reading pauses when the limit is reached and resumes
+            as processing of some entries completes. Useful with large sources that
+            emit an initial burst of data to avoid memory spikes.
+        debug_data: Static data replacing original one when debug mode is active.
+
+    Returns:
+        Table: The table read.
+
+    Example:
+
+    Suppose that the partners upload CSV files with orders to the directory
+    ``/uploads/orders`` on the server ``sftp.example.com``. The orders can be read as
+    they arrive, authenticating with a private key:
+
+    >>> import pathway as pw
+    >>> class OrderSchema(pw.Schema):
+    ...     order_id: int
+    ...     amount: float
+    >>> orders = pw.io.sftp.read(
+    ...     "sftp.example.com",
+    ...     "/uploads/orders",
+    ...     format="csv",
+    ...     schema=OrderSchema,
+    ...     user="pathway",
+    ...     private_key_path="/home/pathway/.ssh/id_ed25519",
+    ...     known_hosts_path="/home/pathway/.ssh/known_hosts",
+    ...     object_pattern="*.csv",
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    only_provide_metadata = format == "only_metadata"
+    with_metadata = with_metadata or only_provide_metadata
+
+    data_storage = api.DataStorage(
+        storage_type="sftp",
+        csv_parser_settings=csv_settings.api_settings if csv_settings else None,
+        path=path,
+        mode=internal_connector_mode(mode),
+        read_method=internal_read_method(format),
+        object_pattern=object_pattern,
+        only_provide_metadata=only_provide_metadata,
+        sftp_settings=api.SftpSettings(
+            host=host,
+            user=user,
+            port=port,
+            password=password,
+            private_key_path=private_key_path,
+            private_key_passphrase=private_key_passphrase,
+            known_hosts_path=known_hosts_path,
+        ),
+    )
+
+    schema, data_format = construct_schema_and_data_format(
+        format,
+        schema=schema,
+        with_metadata=with_metadata,
+        csv_settings=csv_settings,
+        json_field_paths=json_field_paths,
+    )
+
+    data_source_options = datasource.DataSourceOptions(
+        commit_duration_ms=autocommit_duration_ms,
+        max_backlog_size=max_backlog_size,
+        unique_name=_get_unique_name(name, kwargs),
+    )
+
+    table = table_from_datasource(
+        datasource.GenericDataSource(
+            datastorage=data_storage,
+            dataformat=data_format,
+            data_source_options=data_source_options,
+            schema=schema,
+            datasource_name="sftp",
+        ),
+        debug_datasource=datasource.debug_datasource(debug_data),
+    )
+    if only_provide_metadata:
+        table = table.select(_metadata=table._metadata)
+
+    return table
+
+
+__all__ = ["read"]
@@ -0,0 +1,104 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+import os
+
+from pathway.internals import api, datasink
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.table import Table
+from pathway.internals.trace import trace_user_frame
+
+DEFAULT_CAPACITY = 64 * 1024 * 1024
+
+
+@check_arg_types
+@trace_user_frame
+def write(
+    table: Table,
+    directory: str | os.PathLike,
+    *,
+    capacity: int = DEFAULT_CAPACITY,
+) -> None:
+    """Writes the changes of ``table`` to ring buffers in memory-mapped files, so that
+    the processes running on the same host can consume them with a lower latency
+    than over a network connection.
+
+    Each worker writes the changes it computes to its own file,
+    ``<directory>/<worker index>.ring``. Each time a time of the computation is
+    finished, the consolidated changes done up to it are written as a single record
+    holding an Arrow IPC stream with one record batch. Besides the columns of the
+    table, the batch has the ``id`` column with the ids of the rows and the ``time``
+    and ``diff`` columns, with the time of the change and ``1`` for an insertion or
+    ``-1`` for a deletion. The columns of types with no Arrow counterpart are written
+    as strings and the errors are written as nulls.
+
+    The file starts with a header of 64 bytes, followed by the data area holding the
+    records. All the numbers are little-endian:
+
+    - bytes 0-8: the magic ``PWRING01``;
+    - bytes 8-16: the size of the data area;
+    - bytes 16-20 and 20-24: the index of the worker and the number of workers;
+    - bytes 24-32: the write position, the number of bytes written to the data area
+      so far, updated after each record;
+    - bytes 32-40: the time up to which all the changes are written, updated after
+      the write position, ``2**64 - 1`` once the table is finished;
+    - bytes 40-48: the state, ``0`` while the table is computed, ``1`` once it's
+      finished and ``2`` if the computation or the writing has failed;
+    - bytes 48-56: the reserved position, the write position at which the record
+      being written ends, updated before any of its bytes are written.
+
+    A record starts in the data area at the write position modulo the size of the
+    data area. It consists of the length of its payload (4 bytes), its kind (4 bytes),
+    ``1`` for a batch and ``0`` for a padding filling the end of the data area, and
+    the payload. The records are aligned to 8 bytes.
+
+    The writer doesn't wait for the consumers, so a record can be overwritten while a
+    consumer reads it, which the consumer detects afterwards, like with a seqlock. A
+    consumer keeps its own read position and reads the records up to the write
+    position. Having copied a record out of the file, it reads the reserved position
+    again. If it's more than the size of the data area past the read position, the
+    record may have been overwritten while it was copied, so the consumer has to drop
+    the copy and skip to the current write position. On architectures with weak memory
+    ordering, the consumer has to issue an acquire fence between copying the record and
+    reading the reserved position.
+
+    Args:
+        table: Table to be written.
+        directory: Directory in which the files are created. On Linux, a directory in
+            ``/dev/shm`` keeps the files in memory only.
+        capacity: Size of the data area of each file in bytes. It has to be a multiple
+            of 8 and large enough to hold the changes of a single time.
+
+    Returns:
+        None
+
+    Example:
+
+    >>> import pathway as pw
+    >>> pets = pw.debug.table_from_markdown("owner pet \\n Alice dog \\n Bob cat")
+    >>> pw.io.shared_memory.write(pets, "/dev/shm/pets")  # doctest: +SKIP
+
+    Once ``pw.run()`` is started, the records can be read by another process, e.g.
+    the first one with ``pyarrow``:
+
+    >>> import mmap, pyarrow  # doctest: +SKIP
+    >>> with open("/dev/shm/pets/0.ring", "rb") as f:  # doctest: +SKIP
+    ...     buffer = mmap.mmap(f.fileno(), 0, access=mmap.ACCESS_READ)
+    >>> length = int.from_bytes(buffer[64:68], "little")  # doctest: +SKIP
+    >>> pyarrow.ipc.open_stream(buffer[72 : 72 + length]).read_all()  # doctest: +SKIP
+    """
+    if capacity <= 0 or capacity % 8 != 0:
+        raise ValueError(
+            f"capacity has to be a positive multiple of 8, got {capacity}"
+        )
+    directory = os.fspath(directory)
+    column_names = table.column_names()
+
+    def register(scope: api.Scope, exported_table: api.ExportedTable) -> None:
+        scope.export_to_shared_memory(exported_table, directory, capacity, column_names)
+
+    table.to(datasink.ExportDataSink(register))
+
+
+__all__ = ["write"]
@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import datetime
 import os
 from os import PathLike, fspath
 from typing import Any, Literal
@@ -16,6 +17,8 @@ from pathway.internals.table_io import table_from_datasource
 from pathway.internals.trace import trace_user_frame
 from pathway.io._utils import (
     SNAPSHOT_OUTPUT_TABLE_TYPE,
+    DurationLike,
+    construct_sql_cursor_settings,
     get_column_index,
     init_mode_from_str,
     read_schema,
@@ -42,9 +45,15 @@ def _reject_directory_path(path_str: str) -> None:
 @trace_user_frame
 def read(
     path: PathLike | str,
-    table_name: str,
+    table_name: str | None,
     schema: type[Schema],
     *,
+    cursor_column: str | None = None,
+    id_column: str | None = None,
+    query: str | None = None,
+    max_cursor_lag: int = 0,
+    read_batch_size: int = 10_000,
+    poll_interval: DurationLike = datetime.timedelta(seconds=1),
     autocommit_duration_ms: int | None = 1500,
     name: str | None = None,
     max_backlog_size: int | None = None,
@@ -123,7 +132,23 @@ def read(
     **Persistence is not supported.** SQLite has no change-log history
     to replay, so a pipeline that uses ``pw.io.sqlite.read`` cannot be
     resumed from a Pathway Live Data Framework snapshot. Enabling ``pw.persistence.Config``
-    against such a pipeline raises ``ValueError`` at startup.
+    against such a pipeline raises ``ValueError`` at startup. The cursor
+    mode described below is the exception.
+
+    **Cursor mode.** Re-reading the whole table on every change gets
+    expensive as the table grows. If the table is append-only, pass
+    ``cursor_column``: an integer column that grows with the inserted rows,
+    such as an autoincremented id or a Unix timestamp. The reader then
+    polls only the rows whose cursor is at or above the last one read,
+    ordered by the cursor, in blocks of at most ``read_batch_size`` rows.
+    The rows with the same cursor are told apart by ``id_column``, which
+    also becomes the key of the resulting table, so a schema primary key
+    is not allowed in this mode. Updates and deletions of the rows already
+    read are not observed. Instead of a table, the rows can come from an
+    arbitrary ``SELECT`` given as ``query``, e.g. a join, as long as it
+    returns the columns of the schema. In this mode the position of the
+    reader is persisted, so the pipeline can be resumed with
+    ``pw.persistence.Config``.
 
     Args:
         path: Path to the database file. If the path resolves to an
@@ -132,10 +157,30 @@ def read(
         table_name: Name of the table in the database to be read.
             SQLite resolves identifiers case-insensitively, so a
             mixed-case ``table_name`` (e.g. ``"Users"``) matches a
-            table created as ``users``.
+            table created as ``users``. Must be ``None`` if ``query``
+            is given.
         schema: Pathway Live Data Framework schema. Optionally annotate one or more
             columns with ``pw.column_definition(primary_key=True)`` to
             drive row-identity tracking (see above).
+        cursor_column: If given, the reader works in the cursor mode (see
+            above) and polls the rows by the values of this integer column.
+        id_column: In the cursor mode, the column identifying the rows. It
+            defaults to ``cursor_column``, which fits a unique cursor such
+            as an autoincremented id.
+        query: In the cursor mode, a ``SELECT`` returning the rows to be
+            read, used instead of ``table_name``.
+        max_cursor_lag: In the cursor mode, how far behind the largest
+            cursor value a row can still be inserted, in the units of the
+            cursor column. The rows within this distance from the largest
+            cursor are re-read on each poll until they settle. The default
+            of ``0`` fits a cursor assigned in the order of the commits,
+            such as an autoincremented id; a timestamp set by the
+            application before a longer transaction needs a larger value.
+        read_batch_size: In the cursor mode, the maximum number of rows
+            read by a single query.
+        poll_interval: In the cursor mode, how long to wait between two
+            polls once all the rows are read. Given as a number of seconds
+            or a ``datetime.timedelta`` / ``pw.Duration``.
         autocommit_duration_ms: The maximum time between two commits. Every
             autocommit_duration_ms milliseconds, the updates received by the connector are
             committed and pushed into Pathway Live Data Framework's computation graph.
@@ -148,22 +193,124 @@ def read(
 
     Returns:
         Table: The table read.
+
+    Example:
+
+    Suppose that the application appends the readings of the sensors to the
+    table ``readings`` of the database ``sensors.db``, with an
+    ``INTEGER PRIMARY KEY`` column ``id``. The new readings can be polled by
+    this column:
+
+    >>> import pathway as pw
+    >>> class ReadingSchema(pw.Schema):
+    ...     id: int
+    ...     sensor: str
+    ...     value: float
+    >>> readings = pw.io.sqlite.read(  # doctest: +SKIP
+    ...     "sensors.db",
+    ...     "readings",
+    ...     ReadingSchema,
+    ...     cursor_column="id",
+    ... )
     """
     path_str = fspath(path)
     _reject_directory_path(path_str)
-    schema, api_schema = read_schema(schema)
 
+    if (table_name is None) == (query is None):
+        raise ValueError("exactly one of 'table_name' and 'query' must be specified")
+    if cursor_column is None:
+        if query is not None or id_column is not None:
+            raise ValueError(
+                "'query' and 'id_column' can only be used with 'cursor_column'"
+            )
+        return _read_table(
+            path_str,
+            table_name,
+            schema,
+            autocommit_duration_ms=autocommit_duration_ms,
+            name=name,
+            max_backlog_size=max_backlog_size,
+            debug_data=debug_data,
+        )
+
+    cursor_settings, id_column = construct_sql_cursor_settings(
+        "pw.io.sqlite.read",
+        schema,
+        cursor_column=cursor_column,
+        id_column=id_column,
+        query=query,
+        max_cursor_lag=max_cursor_lag,
+        read_batch_size=read_batch_size,
+        poll_interval=poll_interval,
+    )
     data_storage = api.DataStorage(
         storage_type="sqlite",
         path=path_str,
         table_name=table_name,
         mode=api.ConnectorMode.STREAMING,
+        sql_cursor_settings=cursor_settings,
+    )
+    schema, api_schema = read_schema(schema)
+    api_schema["key_field_names"] = [id_column]
+    data_format = api.DataFormat(
+        format_type="jsonlines",
+        session_type=api.SessionType.UPSERT,
+        **api_schema,
+    )
+    return _table_from_sqlite(
+        data_storage,
+        data_format,
+        schema,
+        autocommit_duration_ms=autocommit_duration_ms,
+        name=name,
+        max_backlog_size=max_backlog_size,
+        debug_data=debug_data,
+    )
+
+
+def _read_table(
+    path: str,
+    table_name: str,
+    schema: type[Schema],
+    *,
+    autocommit_duration_ms: int | None,
+    name: str | None,
+    max_backlog_size: int | None,
+    debug_data: Any,
+) -> Table:
+    schema, api_schema = read_schema(schema)
+
+    data_storage = api.DataStorage(
+        storage_type="sqlite",
+        path=path,
+        table_name=table_name,
+        mode=api.ConnectorMode.STREAMING,
     )
     data_format = api.DataFormat(
         format_type="transparent",
         **api_schema,
     )
+    return _table_from_sqlite(
+        data_storage,
+        data_format,
+        schema,
+        autocommit_duration_ms=autocommit_duration_ms,
+        name=name,
+        max_backlog_size=max_backlog_size,
+        debug_data=debug_data,
+    )
 
+
+def _table_from_sqlite(
+    data_storage: api.DataStorage,
+    data_format: api.DataFormat,
+    schema: type[Schema],
+    *,
+    autocommit_duration_ms: int | None,
+    name: str | None,
+    max_backlog_size: int | None,
+    debug_data: Any,
+) -> Table:
     data_source_options = datasource.DataSourceOptions(
         commit_duration_ms=autocommit_duration_ms,
         unique_name=name,
@@ -193,6 +340,8 @@ def write(
     output_table_type: Literal["stream_of_changes", "snapshot"] = "stream_of_changes",
     primary_key: list[ColumnReference] | None = None,
     name: str | None = None,
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
 ) -> None:
     """Writes ``table`` to a table in a `SQLite <https://www.sqlite.org/>`_
     database file. Two types of output tables are supported: **stream of
@@ -249,6 +398,15 @@ def write(
             nullability and the matching destination constraint.
         name: A unique name for the connector. If provided, this name will
             be used in logs and monitoring dashboards.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
 
     Examples:
 
@@ -412,5 +570,7 @@ def write(
             data_format,
             datasink_name=f"sqlite.{datasink_type}",
             unique_name=name,
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
         )
     )
@@ -0,0 +1,164 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+from typing import Literal
+
+from pathway.internals import api, datasource
+from pathway.internals.config import _check_entitlements
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.schema import Schema
+from pathway.internals.table import Table
+from pathway.internals.table_io import table_from_datasource
+from pathway.internals.trace import trace_user_frame
+from pathway.io._utils import _get_unique_name, construct_schema_and_data_format
+
+
+@check_arg_types
+@trace_user_frame
+def read(
+    queue_url: str,
+    *,
+    schema: type[Schema] | None = None,
+    format: Literal["plaintext", "raw", "json"] = "raw",
+    wait_time_s: int = 20,
+    visibility_timeout_s: int = 60,
+    shard_by_message_group: bool = False,
+    autocommit_duration_ms: int = 1500,
+    json_field_paths: dict[str, str] | None = None,
+    name: str | None = None,
+    max_backlog_size: int | None = None,
+    debug_data=None,
+    **kwargs,
+) -> Table:
+    """
+    Reads a table from an
+    `Amazon SQS queue <https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/welcome.html>`_.
+    The connection settings are retrieved from the environment.
+
+    The queue is read with long polling. A message is deleted from the queue only after
+    the minibatch containing it is committed. Until then, its visibility timeout is
+    extended, so that the message isn't delivered to another consumer. If the program
+    stops before the commit, the message becomes visible again once its visibility
+    timeout expires, and it is delivered once more.
+
+    All workers read the queue in parallel. For a FIFO queue, you can set
+    ``shard_by_message_group`` so that each message group is processed by a single
+    worker, preserving the order of the messages within the group. The messages of the
+    groups owned by other workers are returned to the queue as soon as they are
+    received.
+
+    There are three supported formats: ``"plaintext"``, ``"raw"``, and ``"json"``.
+
+    For the ``"raw"`` format, the message body is read as raw bytes and added directly
+    to the table. In the ``"plaintext"`` format, the message body is stored as plain
+    text. In both cases, the table will have an autogenerated primary key and a single
+    ``"data"`` column representing the message body.
+
+    If you select the ``"json"`` format, the connector parses the message body as JSON
+    and creates table columns based on the schema provided in the ``schema`` parameter.
+    The column values come from the corresponding JSON fields.
+
+    Args:
+        queue_url: The URL of the queue to be read.
+        schema: The table schema, used only when the format is set to ``"json"``.
+        format: The input data format, which can be ``"raw"``, ``"plaintext"``, or
+            ``"json"``.
+        wait_time_s: The time (in seconds) for which a single receive request waits
+            for the messages to arrive. Must be between ``0`` and ``20``.
+        visibility_timeout_s: The visibility timeout (in seconds) of the received
+            messages. It's extended for the messages that aren't committed yet, so it
+            doesn't bound the processing time, but it's the time after which the
+            messages are delivered again if the program stops. Must be greater than
+            twice ``wait_time_s``.
+        shard_by_message_group: If set to ``True``, the message groups of a FIFO queue
+            are distributed between the workers, so that each group is processed by a
+            single worker. The queue must be a FIFO queue then.
+        autocommit_duration_ms: The time interval (in milliseconds) between commits.
+            After this time, the updates received by the connector are committed and
+            added to Pathway Live Data Framework's computation graph.
+        json_field_paths: For the ``"json"`` format, this allows mapping field names to
+            paths within the JSON structure. Use the format ``<field_name>: <path>``
+            where the path follows the
+            `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards. Additionally, if persistence is enabled, it
+            will be used as the name for the snapshot that stores the connector's progress.
+        max_backlog_size: Limit on the number of entries read from the input source and kept
+            in processing at any moment. Reading pauses when the limit is reached and resumes
+            as processing of some entries completes. Useful with large sources that
+            emit an initial burst of data to avoid memory spikes.
+        debug_data: Static data replacing original one when debug mode is active.
+
+    Returns:
+        Table: The table read.
+
+    Example:
+
+    Suppose that you have an SQS queue with the URL
+    ``"https://sqs.us-east-1.amazonaws.com/123456789012/events"``, and the AWS
+    credentials and region are set in the environment, for example with the
+    ``AWS_ACCESS_KEY_ID``, ``AWS_SECRET_ACCESS_KEY`` and ``AWS_REGION`` variables. The
+    messages of the queue are JSON objects with the fields ``user`` and ``action``:
+
+    >>> import pathway as pw
+    >>> class InputSchema(pw.Schema):
+    ...     user: str
+    ...     action: str
+
+    You can read them as follows:
+
+    >>> table = pw.io.sqs.read(
+    ...     "https://sqs.us-east-1.amazonaws.com/123456789012/events",
+    ...     format="json",
+    ...     schema=InputSchema,
+    ... )
+
+    If it's a FIFO queue whose messages are grouped by user, you can make each user's
+    messages processed by a single worker, in the order they were sent:
+
+    >>> table = pw.io.sqs.read(
+    ...     "https://sqs.us-east-1.amazonaws.com/123456789012/events.fifo",
+    ...     format="json",
+    ...     schema=InputSchema,
+    ...     shard_by_message_group=True,
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    _check_entitlements("sqs")
+    data_storage = api.DataStorage(
+        storage_type="sqs",
+        topic=queue_url,
+        mode=api.ConnectorMode.STREAMING,
+        sqs_settings=api.SqsSettings(
+            wait_time_s=wait_time_s,
+            visibility_timeout_s=visibility_timeout_s,
+            shard_by_message_group=shard_by_message_group,
+        ),
+    )
+    schema, data_format = construct_schema_and_data_format(
+        "binary" if format == "raw" else format,
+        schema=schema,
+        csv_settings=None,
+        json_field_paths=json_field_paths,
+    )
+    data_source_options = datasource.DataSourceOptions(
+        commit_duration_ms=autocommit_duration_ms,
+        unique_name=_get_unique_name(name, kwargs),
+        max_backlog_size=max_backlog_size,
+    )
+    return table_from_datasource(
+        datasource.GenericDataSource(
+            datastorage=data_storage,
+            dataformat=data_format,
+            data_source_options=data_source_options,
+            schema=schema,
+            datasource_name="sqs",
+        ),
+        debug_datasource=datasource.debug_datasource(debug_data),
+    )
+
+
+__all__ = ["read"]
@@ -0,0 +1,158 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+import datetime
+from typing import Iterable, Literal
+
+from pathway.internals import api, datasource
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.schema import Schema
+from pathway.internals.table import Table
+from pathway.internals.table_io import table_from_datasource
+from pathway.internals.trace import trace_user_frame
+from pathway.io._utils import (
+    DurationLike,
+    _get_unique_name,
+    as_duration_seconds,
+    construct_schema_and_data_format,
+)
+
+
+@check_arg_types
+@trace_user_frame
+def read(
+    url: str,
+    *,
+    headers: dict[str, str] | None = None,
+    event_types: Iterable[str] | None = None,
+    reconnection_time: DurationLike = datetime.timedelta(seconds=3),
+    schema: type[Schema] | None = None,
+    format: Literal["plaintext", "raw", "json"] = "plaintext",
+    autocommit_duration_ms: int = 1500,
+    json_field_paths: dict[str, str] | None = None,
+    name: str | None = None,
+    max_backlog_size: int | None = None,
+    debug_data=None,
+    **kwargs,
+) -> Table:
+    """
+    Reads a table from a
+    `Server-Sent Events <https://html.spec.whatwg.org/multipage/server-sent-events.html>`_
+    stream served over HTTP.
+
+    The connector sends a ``GET`` request to ``url`` and reads the events from the
+    response. The data of each event becomes an entry of the table. If ``event_types``
+    is given, only the events of these types are read. The events without the
+    ``event`` field have the type ``"message"``.
+
+    When the server closes the stream, the connector reconnects after
+    ``reconnection_time``, unless the server sets another time with the ``retry``
+    field. On reconnection, the id of the last event received is sent in the
+    ``Last-Event-ID`` header, so a server supporting it continues the stream after this
+    event. If persistence is enabled, the id is also saved with the connector's
+    progress, and the stream is resumed from it when the program is restarted. If the
+    server responds with the status ``204 No Content``, the reading finishes.
+
+    The stream is read by a single worker. There are three supported formats:
+    ``"plaintext"``, ``"raw"``, and ``"json"``.
+
+    For the ``"plaintext"`` format, the data of an event is stored as plain text. In the
+    ``"raw"`` format, it is stored as bytes. In both cases, the table will have an
+    autogenerated primary key and a single ``"data"`` column.
+
+    If you select the ``"json"`` format, the connector parses the data of the events as
+    JSON and creates table columns based on the schema provided in the ``schema``
+    parameter. The column values come from the corresponding JSON fields.
+
+    Args:
+        url: The URL of the event stream.
+        headers: The additional HTTP headers of the request, for example the
+            ``Authorization`` header.
+        event_types: The types of the events to be read. If not specified, the events
+            of all types are read.
+        reconnection_time: The time to wait before reconnecting when the server closes
+            the stream, in seconds or as a ``datetime.timedelta``.
+        schema: The table schema, used only when the format is set to ``"json"``.
+        format: The input data format, which can be ``"raw"``, ``"plaintext"``, or
+            ``"json"``.
+        autocommit_duration_ms: The time interval (in milliseconds) between commits.
+            After this time, the updates received by the connector are committed and
+            added to Pathway Live Data Framework's computation graph.
+        json_field_paths: For the ``"json"`` format, this allows mapping field names to
+            paths within the JSON structure. Use the format ``<field_name>: <path>``
+            where the path follows the
+            `JSON Pointer (RFC 6901) <https://www.rfc-editor.org/rfc/rfc6901>`_.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards. Additionally, if persistence is enabled, it
+            will be used as the name for the snapshot that stores the connector's progress.
+        max_backlog_size: Limit on the number of entries read from the input source and kept
+            in processing at any moment. Reading pauses when the limit is reached and resumes
+            as processing of some entries completes. Useful with large sources that
+            emit an initial burst of data to avoid memory spikes.
+        debug_data: Static data replacing original one when debug mode is active.
+
+    Returns:
+        Table: The table read.
+
+    Example:
+
+    Suppose that a service at ``http://localhost:8080/orders`` streams the events of the
+    type ``"order"``, with the data containing JSON objects with the fields
+    ``order_id`` and ``amount``, and requires a bearer token:
+
+    >>> import pathway as pw
+    >>> class OrderSchema(pw.Schema):
+    ...     order_id: int = pw.column_definition(primary_key=True)
+    ...     amount: float
+
+    You can read the orders as follows:
+
+    >>> orders = pw.io.sse.read(
+    ...     "http://localhost:8080/orders",
+    ...     headers={"Authorization": "Bearer <token>"},
+    ...     event_types=["order"],
+    ...     format="json",
+    ...     schema=OrderSchema,
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    reconnection_time_ms = round(
+        as_duration_seconds(reconnection_time, "reconnection_time") * 1000
+    )
+    data_storage = api.DataStorage(
+        storage_type="sse",
+        path=url,
+        mode=api.ConnectorMode.STREAMING,
+        sse_settings=api.SseSettings(
+            headers=list((headers or {}).items()),
+            event_types=list(event_types) if event_types is not None else None,
+            reconnection_time_ms=reconnection_time_ms,
+        ),
+    )
+    schema, data_format = construct_schema_and_data_format(
+        "binary" if format == "raw" else format,
+        schema=schema,
+        csv_settings=None,
+        json_field_paths=json_field_paths,
+    )
+    data_source_options = datasource.DataSourceOptions(
+        commit_duration_ms=autocommit_duration_ms,
+        unique_name=_get_unique_name(name, kwargs),
+        max_backlog_size=max_backlog_size,
+    )
+    return table_from_datasource(
+        datasource.GenericDataSource(
+            datastorage=data_storage,
+            dataformat=data_format,
+            data_source_options=data_source_options,
+            schema=schema,
+            datasource_name="sse",
+        ),
+        debug_datasource=datasource.debug_datasource(debug_data),
+    )
+
+
+__all__ = ["read"]
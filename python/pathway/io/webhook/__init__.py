@@ -0,0 +1,240 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+from typing import Iterable, Literal
+
+from pathway.internals import api, datasink
+from pathway.internals._io_helpers import _format_output_value_fields
+from pathway.internals.expression import ColumnReference
+from pathway.internals.runtime_type_check import check_arg_types
+from pathway.internals.table import Table
+from pathway.internals.trace import trace_user_frame
+from pathway.io.http._common import RetryPolicy
+
+
+@check_arg_types
+@trace_user_frame
+def write(
+    table: Table,
+    url: str,
+    *,
+    method: str = "POST",
+    format: Literal["json", "template"] = "json",
+    template: str | None = None,
+    template_escaping: Literal["none", "html", "json", "single_line"] = "none",
+    headers: dict[str, str] | None = None,
+    content_type: str | None = None,
+    batch_format: Literal["ndjson", "json_array"] | None = None,
+    max_batch_size: int | None = None,
+    n_retries: int = 3,
+    retry_policy: RetryPolicy = RetryPolicy.default(),
+    retry_codes: Iterable[int] = (429, 500, 502, 503, 504),
+    max_concurrent_requests: int = 1,
+    connect_timeout_ms: int | None = None,
+    request_timeout_ms: int | None = None,
+    name: str | None = None,
+    sort_by: Iterable[ColumnReference] | None = None,
+    queue_capacity: int | None = None,
+    linger_ms: int | None = None,
+) -> None:
+    """Sends the stream of changes of the table to an HTTP endpoint, such as the
+    webhook of an alerting or an automation service.
+
+    Unlike ``pw.io.http.write``, the requests are formed and sent by the engine. The
+    changes of a minibatch are sent when the minibatch is closed, with up to
+    ``max_concurrent_requests`` requests in flight at once, and the requests of the
+    next minibatch are only sent after all requests of the current one have
+    succeeded. If a request still fails after the retries, the program terminates
+    with an error.
+
+    Args:
+        table: The table whose changes are sent.
+        url: The URL of the endpoint. It's a template: the placeholders in double
+            curly braces, such as ``{{ team }}``, are replaced with the values of the
+            columns of the changed row, and ``{{ time }}`` and ``{{ diff }}`` refer
+            to the time and the diff of the change. The values are inserted as is,
+            so the columns used in the URL must not contain characters that need
+            escaping. The filters described for the ``"template"`` format of
+            ``pw.io.fs.write`` can be used as well.
+        method: The HTTP method of the requests.
+        format: The format of the request payload. If it's ``"json"``, the row is
+            sent as a JSON object with the additional ``time`` and ``diff`` fields.
+            If it's ``"template"``, the payload is the ``template`` rendered for the
+            changed row.
+        template: The template of the payload, used by the ``"template"`` format. It
+            has the same syntax as the ``url``.
+        template_escaping: How the values rendered by the payload ``template`` are
+            escaped. The modes are described for the ``"template"`` format of
+            ``pw.io.fs.write``. For instance, with ``"json"``, the columns can be
+            placed inside the string literals of a JSON payload. The ``url`` and the
+            ``headers`` aren't affected.
+        headers: The headers of the requests. Both the names and the values are
+            templates with the same syntax as the ``url``.
+        content_type: The content type of the payload. It defaults to
+            ``"application/json"`` for the ``"json"`` format and for the batches in
+            the ``"json_array"`` format, and to ``"application/x-ndjson"`` for the
+            batches in the ``"ndjson"`` format.
+        batch_format: If specified, the changes of a minibatch that resolve to the
+            same URL and headers are sent together in a single request instead of one
+            request per change. It can be either ``"ndjson"``, with one payload per
+            line, or ``"json_array"``, with the body being a JSON array of the
+            payloads. The ``"json_array"`` batches require the ``"json"`` format.
+        max_batch_size: The maximum number of changes sent in a single request.
+            Bigger batches are split into several requests. Can only be used
+            together with ``batch_format``.
+        n_retries: How many times a failed request is repeated. The requests are
+            repeated if no response was received, for example because of a timeout,
+            or if the response status is one of ``retry_codes``.
+        retry_policy: The delays between the repeated requests.
+        retry_codes: The HTTP status codes of the responses for which the request is
+            repeated. The other unsuccessful responses terminate the program at once.
+        max_concurrent_requests: The maximum number of requests in flight at once.
+            With the default value of ``1``, the requests are sent one by one in the
+            order of the changes.
+        connect_timeout_ms: The connection timeout in milliseconds. If it's not
+            specified, the connection duration isn't limited.
+        request_timeout_ms: The timeout of the whole request, including the
+            retrieval of the response, in milliseconds. If it's not specified, the
+            request duration isn't limited.
+        name: A unique name for the connector. If provided, this name will be used in
+            logs and monitoring dashboards.
+        sort_by: If specified, the output will be sorted in ascending order based on
+            the values of the given columns within each minibatch. When multiple
+            columns are provided, the corresponding value tuples will be compared
+            lexicographically.
+        queue_capacity: The maximal number of minibatches waiting to be written. When
+            the writer falls behind, the computation is paused until it catches up
+            instead of keeping the pending output in memory. If not specified, there's
+            no limit.
+        linger_ms: For how long, in milliseconds, the flush of the written changes
+            can be delayed, so that the changes of the next minibatches are flushed
+            together with them. A minibatch is considered written only once it has
+            been flushed. If not specified, the changes are flushed after each
+            minibatch.
+
+    Returns:
+        None
+
+    Example:
+
+    Suppose that the table ``alerts`` contains the alerts to be delivered to the
+    on-call channels of several teams:
+
+    >>> import pathway as pw
+    >>> alerts = pw.debug.table_from_markdown('''
+    ... team     | severity | message
+    ... payments | critical | Checkout latency is above the threshold
+    ... search   | warning  | Index lag is growing
+    ... ''')
+
+    Each alert can be posted to the webhook of its team, with the severity passed in
+    a header:
+
+    >>> pw.io.webhook.write(
+    ...     alerts,
+    ...     "https://hooks.example.com/teams/{{ team }}",
+    ...     headers={"X-Severity": "{{ severity | upper }}"},
+    ... )
+
+    A chat service expecting its own payload can be given a template instead:
+
+    >>> pw.io.webhook.write(
+    ...     alerts,
+    ...     "https://chat.example.com/hooks/on-call",
+    ...     format="template",
+    ...     template='{"text": {{ message | json }}}',
+    ...     content_type="application/json",
+    ... )
+
+    Finally, an ingestion endpoint accepting many events at once can receive each
+    minibatch as NDJSON, in requests of at most 500 rows, four at a time:
+
+    >>> pw.io.webhook.write(
+    ...     alerts,
+    ...     "https://ingest.example.com/events",
+    ...     batch_format="ndjson",
+    ...     max_batch_size=500,
+    ...     max_concurrent_requests=4,
+    ... )
+
+    Do not forget to call ``pw.run()`` to start the pipeline.
+    """
+
+    if (format == "template") != (template is not None):
+        raise ValueError(
+            "The template parameter must be provided if and only if "
+            "the format is 'template'"
+        )
+    if template_escaping != "none" and format != "template":
+        raise ValueError(
+            "The template_escaping parameter is only supported by 'template' format"
+        )
+    if batch_format == "json_array" and format != "json":
+        raise ValueError("The 'json_array' batches require the 'json' format")
+    if max_batch_size is not None:
+        if batch_format is None:
+            raise ValueError(
+                "max_batch_size can only be used together with batch_format"
+            )
+        if max_batch_size <= 0:
+            raise ValueError("max_batch_size must be positive")
+    if max_concurrent_requests <= 0:
+        raise ValueError("max_concurrent_requests must be positive")
+    if n_retries < 0:
+        raise ValueError("n_retries can't be negative")
+
+    if content_type is None:
+        if batch_format == "ndjson":
+            content_type = "application/x-ndjson"
+        elif format == "json":
+            content_type = "application/json"
+
+    data_storage = api.DataStorage(
+        storage_type="webhook",
+        webhook_settings=api.WebhookSettings(
+            url=url,
+            method=method,
+            headers=headers,
+            content_type=content_type,
+            batch_format=batch_format,
+            max_batch_size=max_batch_size,
+            n_retries=n_retries,
+            first_retry_delay_ms=retry_policy._first_delay_ms,
+            retry_backoff_factor=retry_policy._backoff_factor,
+            retry_jitter_ms=retry_policy._jitter_ms,
+            retry_codes=list(retry_codes),
+            max_concurrent_requests=max_concurrent_requests,
+            connect_timeout_ms=connect_timeout_ms,
+            request_timeout_ms=request_timeout_ms,
+        ),
+    )
+    if format == "json":
+        data_format = api.DataFormat(
+            format_type="jsonlines",
+            key_field_names=[],
+            value_fields=_format_output_value_fields(table),
+        )
+    else:
+        data_format = api.DataFormat(
+            format_type="template",
+            key_field_names=[],
+            value_fields=_format_output_value_fields(table),
+            template=template,
+            template_escaping=template_escaping,
+        )
+
+    table.to(
+        datasink.GenericDataSink(
+            data_storage,
+            data_format,
+            datasink_name="webhook",
+            unique_name=name,
+            sort_by=sort_by,
+            queue_capacity=queue_capacity,
+            linger_ms=linger_ms,
+        )
+    )
+
+
+__all__ = ["write"]
@@ -0,0 +1,66 @@
+# Copyright © 2026 Pathway
+
+"""Counters and histograms defined by the user, exported to the monitoring server.
+
+The code run by the computation, like UDFs or the callbacks of ``pw.io.subscribe``, can
+increment named counters and record values of named histograms. The metrics are shipped
+to the server set with ``pw.set_monitoring_config`` through the same OTLP pipeline as
+the metrics of the engine, with the same period. They are only collected while a
+computation with a monitoring server runs in this process, the ones recorded at other
+times are dropped.
+
+A metric name has to start with a letter and consist of letters, digits, ``_``, ``.``,
+``-`` and ``/``. A name identifies a single metric, so it can't be used for both a
+counter and a histogram.
+"""
+
+from __future__ import annotations
+
+from pathway.internals import api
+
+
+def increment_counter(name: str, value: int = 1) -> None:
+    """Adds ``value`` to the counter ``name``.
+
+    Args:
+        name: The name of the counter.
+        value: A non-negative number to be added to the counter.
+
+    Raises:
+        ValueError: if the name is invalid or used by a histogram.
+
+    Example:
+
+    >>> import pathway as pw
+    >>> @pw.udf
+    ... def normalize(email: str) -> str:
+    ...     if email != email.lower():
+    ...         pw.metrics.increment_counter("emails.normalized")
+    ...     return email.lower()
+    """
+    api.increment_counter(name, value)
+
+
+def record_histogram(name: str, value: float) -> None:
+    """Records ``value`` in the histogram ``name``.
+
+    Args:
+        name: The name of the histogram.
+        value: The recorded value.
+
+    Raises:
+        ValueError: if the name is invalid or used by a counter.
+
+    Example:
+
+    >>> import pathway as pw
+    >>> @pw.udf
+    ... def order_total(price: float, quantity: int) -> float:
+    ...     total = price * quantity
+    ...     pw.metrics.record_histogram("orders.total", total)
+    ...     return total
+    """
+    api.record_histogram(name, value)
+
+
+__all__ = ["increment_counter", "record_histogram"]
@@ -4,7 +4,7 @@ import contextlib
 import os
 import warnings
 from collections.abc import Generator
-from dataclasses import KW_ONLY, dataclass
+from dataclasses import KW_ONLY, dataclass, field
 
 from pathway.internals import api
 from pathway.internals._io_helpers import AwsS3Settings
@@ -19,9 +19,11 @@ class Backend:
         self,
         engine_data_storage: api.DataStorage,
         fs_path: str | os.PathLike[str] | None = None,
+        storage_id: str | None = None,
     ):
         self._engine_data_storage = engine_data_storage
         self._fs_path = fs_path
+        self._storage_id = storage_id
 
     @classmethod
     def filesystem(cls, path: str | os.PathLike[str]):
@@ -42,6 +44,7 @@ at ``path``.
                 path=os.fspath(path),
             ),
             fs_path=path,
+            storage_id=f"fs:{os.path.abspath(path)}",
         )
 
     @classmethod
@@ -65,6 +68,7 @@ as they are used by S3 connectors.
                 aws_s3_settings=bucket_settings.settings,
                 path=root_path,
             ),
+            storage_id=f"s3:{bucket_settings._bucket_name}/{root_path}",
         )
 
     @classmethod
@@ -93,6 +97,7 @@ as they are used by S3 connectors.
                     container=container,
                 ),
             ),
+            storage_id=f"azure:{account}/{container}/{root_path}",
         )
 
     @classmethod
@@ -103,6 +108,11 @@ as they are used by S3 connectors.
     def engine_data_storage(self):
         return self._engine_data_storage
 
+    @property
+    def storage_id(self) -> str | None:
+        """The location of the persisted data, if it is known."""
+        return self._storage_id
+
     def store_path_in_env_variable(self):
         if self._fs_path:
             os.environ["PATHWAY_PERSISTENT_STORAGE"] = os.fspath(self._fs_path)
@@ -142,6 +152,37 @@ class Config:
         workload_tracking_window_ms: Specifies the time window (in milliseconds) used to evaluate
             pipeline load when worker scaling is enabled. The load condition (overload or underload)
             must persist throughout this entire window before a scaling decision is made.
+        gc_interval_ms: If set, the obsolete persisted data, superseded by the newer
+            checkpoints, is removed in the background every ``gc_interval_ms`` milliseconds
+            once these checkpoints are durable: the metadata of the former runs and the
+            chunks of the input offsets kept with ``pw.PersistenceMode.OPERATOR_PERSISTING``.
+            Otherwise, the obsolete data is only removed when the program restarts.
+        encryption_key: A 32-byte key encrypting the input and operator snapshots with
+            AES-256-GCM, e.g. a data key obtained from a key management service. If not
+            set, the key is taken from the ``PATHWAY_PERSISTENCE_ENCRYPTION_KEY``
+            environment variable, encoded in base64. Without a key, the snapshots are
+            not encrypted. The unencrypted snapshots are rejected with a key, unless
+            ``accept_unencrypted_snapshots`` is set.
+        accept_unencrypted_snapshots: If set, the snapshots written before the
+            encryption was enabled are read as they are and replaced by the encrypted
+            ones as they get compacted. It's meant for migrating the existing snapshots
+            and should be turned off afterwards, as anyone with access to the storage
+            could write the unencrypted snapshots.
+        full_snapshot_interval_ms: The operator snapshots are saved as the changes of the
+            state since the previous checkpoint, which are compacted in the background.
+            If set, all the changes are also merged into a single full snapshot every
+            ``full_snapshot_interval_ms`` milliseconds, which bounds the amount of data
+            read on restart. Otherwise, the changes are only compacted gradually.
+        lock_ttl_ms: If set, the run holds a lease on the persistent storage, so that
+            the runs started with the same storage, e.g. in several processes sharing an
+            S3 bucket, don't overwrite each other's metadata. The other runs wait until
+            the lease is released, which makes them standbys for the running one. None
+            of the workers of a run uses the storage before the run holds the lease and
+            they stop saving the metadata once it's lost. The lease is renewed in the
+            background and expires ``lock_ttl_ms`` milliseconds after the last renewal,
+            so the storage is taken over after a crash too. It has to be much longer
+            than the clock skew between the machines. The runs with many processes
+            have to be started with ``pathway spawn``.
     """
 
     backend: Backend
@@ -152,6 +193,12 @@ class Config:
     continue_after_replay: bool = True
     worker_scaling_enabled: bool = False
     workload_tracking_window_ms: int = 120000
+    gc_interval_ms: int | None = None
+    # not a part of the representation, so that it doesn't end up in the logs
+    encryption_key: bytes | None = field(default=None, repr=False)
+    accept_unencrypted_snapshots: bool = False
+    full_snapshot_interval_ms: int | None = None
+    lock_ttl_ms: int | None = None
 
     @classmethod
     def simple_config(
@@ -214,6 +261,11 @@ class Config:
             continue_after_replay=self.continue_after_replay,
             worker_scaling_enabled=self.worker_scaling_enabled,
             workload_tracking_window_ms=self.workload_tracking_window_ms,
+            gc_interval_ms=self.gc_interval_ms,
+            encryption_key=self.encryption_key,
+            accept_unencrypted_snapshots=self.accept_unencrypted_snapshots,
+            full_snapshot_interval_ms=self.full_snapshot_interval_ms,
+            lock_ttl_ms=self.lock_ttl_ms,
         )
 
     def on_before_run(self):
@@ -236,3 +288,79 @@ def get_persistence_engine_config(
         yield persistence_config.engine_config
     finally:
         persistence_config.on_after_run()
+
+
+def savepoint(timeout: float = 60.0) -> int:
+    """
+    Commits the persisted state of the computation running in this process right away,
+    instead of waiting for the end of the current snapshot interval. It can be used to
+    snapshot the state just before a deployment. The function has to be called from
+    another thread than the one running ``pw.run``, and the computation has to be run
+    with persistence enabled. The same can be done by sending ``POST /savepoint`` to the
+    monitoring HTTP server of the process.
+
+    In a multi-process run, the function can be called in any of the processes, the
+    state of the workers of all of them is committed up to the same time, the earliest
+    time finalized by all of them.
+
+    Args:
+        timeout: the maximal time to wait for the savepoint to be committed, in seconds.
+
+    Returns:
+        The identifier of the savepoint, which is the time up to which the state is
+        committed.
+
+    Raises:
+        TimeoutError: if the savepoint isn't committed within ``timeout``.
+        RuntimeError: if no computation with persistence is running or committing the
+            savepoint has failed.
+    """
+    return api.request_savepoint(timeout=timeout)
+
+
+def suspend() -> None:
+    """
+    Suspends the computation running in this process, so that it can be resumed later,
+    e.g. to scale a pipeline that processes data in batches to zero when there is no
+    data. The input connectors stop reading, the data they have already read is
+    processed and the outputs are flushed. Then the input offsets and the operator state
+    are committed and ``pw.run`` returns. The function returns right away and has to be
+    called from another thread than the one running ``pw.run``. The same can be done by
+    sending ``POST /suspend`` to the monitoring HTTP server of the process.
+
+    Starting the same computation later with the same persistence config resumes it from
+    the committed state. Its output is then the same, byte for byte, as the output of a
+    computation that has never been suspended, provided that the computation is
+    deterministic and all its input connectors have their offsets persisted. With
+    ``SELECTIVE_PERSISTING`` mode, only the connectors with a ``name`` are persisted.
+    If the state can't be committed, ``pw.run`` fails instead of returning.
+
+    In a multi-process run, only the workers of the calling process are suspended, so
+    the function has to be called in every process.
+
+    Raises:
+        RuntimeError: if no computation with persistence is running.
+    """
+    api.request_suspend()
+
+
+def migrate_metadata(backend: Backend) -> int:
+    """
+    Rewrites the persisted metadata written by the older versions of Pathway in the
+    format of the current version. The older formats are still read by the engine, but
+    after the migration the persisted state no longer depends on it. The metadata
+    written by a newer version of Pathway is never read or rewritten: the engine refuses
+    to start with it instead of misinterpreting it. The migration should be done while
+    no computation uses the persisted state.
+
+    Args:
+        backend: the backend of the persisted state, the same as in ``pw.persistence.Config``.
+
+    Returns:
+        The number of rewritten metadata blocks.
+
+    Raises:
+        OSError: if the metadata can't be read or written, or it was written by a newer
+            version of Pathway.
+    """
+    return api.migrate_persistence_metadata(backend=backend.engine_data_storage)
@@ -40,7 +40,12 @@ from .temporal_behavior import (
     common_behavior,
     exactly_once_behavior,
 )
-from .time_utils import add_update_timestamp_utc, inactivity_detection, utc_now
+from .time_utils import (
+    add_update_timestamp_utc,
+    assert_references,
+    inactivity_detection,
+    utc_now,
+)
 
 __all__ = [
     "AsofJoinResult",
@@ -80,4 +85,5 @@ __all__ = [
     "utc_now",
     "inactivity_detection",
     "add_update_timestamp_utc",
+    "assert_references",
 ]
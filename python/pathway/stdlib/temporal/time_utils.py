@@ -186,6 +186,108 @@ def inactivity_detection(
     return inactivities
 
 
+@check_arg_types
+@trace_user_frame
+def assert_references(
+    self: pw.Table,
+    column: pw.ColumnReference,
+    referenced_column: pw.ColumnReference,
+    *,
+    grace_period: pw.Duration | None = None,
+    refresh_rate: pw.Duration = pw.Duration(seconds=1),
+) -> pw.Table:
+    """Continuously checks that every value of ``column`` (a foreign key) is present
+    in ``referenced_column`` of another table, and reports the dangling references.
+
+    The check is kept up to date as both tables change: a reference becomes a
+    violation as soon as the referenced value is missing and stops being one once the
+    value appears. With ``grace_period`` set, a missing value is reported only if it
+    hasn't appeared within ``grace_period`` from the moment the referencing row was
+    seen, which gives the upstream producers some time to deliver the referenced rows.
+    ``None`` values of ``column`` are not references and are never reported.
+
+    Note: the grace period is measured in processing time, with the precision given by
+    ``refresh_rate``. When it is set, the current time is refreshed continuously, so
+    the computation doesn't finish on its own.
+
+    Args:
+        column (pw.ColumnReference): the column of this table with the foreign keys.
+        referenced_column (pw.ColumnReference): the column of the referenced table
+            that the values of ``column`` have to be present in.
+        grace_period (pw.Duration | None, optional): time a missing value is given to
+            appear before it is reported. Defaults to None, meaning that the missing
+            values are reported immediately.
+        refresh_rate (pw.Duration, optional): frequency with which the expiry of the
+            grace periods is checked. Defaults to 1 second.
+
+    Returns:
+        Table: the violations table with the ids of the rows of this table having a
+        dangling reference, the missing ``value`` and the ``seen_timestamp_utc`` at
+        which the row was seen.
+
+    Example:
+
+    >>> import pathway as pw
+    >>> users = pw.debug.table_from_markdown('''
+    ... name
+    ... Alice
+    ... Bob
+    ... ''')
+    >>> orders = pw.debug.table_from_markdown('''
+    ... user  | amount
+    ... Alice | 10
+    ... Carol | 20
+    ... Bob   | 30
+    ... ''')
+    >>> violations = orders.assert_references(orders.user, users.name)
+    >>> pw.debug.compute_and_print(violations.select(pw.this.value), include_id=False)
+    value
+    Carol
+    """
+
+    @pw.udf(deterministic=True)
+    def get_now_timestamp_utc(for_test_only: pw.Pointer) -> pw.DateTimeUtc:
+        return _get_now_timestamp_utc(for_test_only)
+
+    referenced_values = referenced_column.table.groupby(referenced_column).reduce(
+        value=referenced_column
+    )
+    references = (
+        self.select(value=column, seen_timestamp_utc=get_now_timestamp_utc(pw.this.id))
+        .filter(pw.this.value.is_not_none())
+        .with_columns(value=pw.unwrap(pw.this.value))
+    )
+    violations = (
+        references.join_left(
+            referenced_values, pw.left.value == pw.right.value, id=pw.left.id
+        )
+        .select(
+            pw.left.value,
+            pw.left.seen_timestamp_utc,
+            found=pw.right.value.is_not_none(),
+        )
+        .filter(~pw.this.found)
+        .without(pw.this.found)
+    )
+
+    if grace_period is not None:
+        utc_now_table = utc_now(refresh_rate=refresh_rate).reduce(
+            timestamp_utc=pw.reducers.latest(pw.this.timestamp_utc)
+        )
+        violations = (
+            violations.join(utc_now_table, id=pw.left.id)
+            .select(
+                pw.left.value,
+                pw.left.seen_timestamp_utc,
+                now_utc=pw.right.timestamp_utc,
+            )
+            .filter(pw.this.seen_timestamp_utc + grace_period <= pw.this.now_utc)
+            .without(pw.this.now_utc)
+        )
+
+    return violations
+
+
 @check_arg_types
 @trace_user_frame
 def add_update_timestamp_utc(
@@ -6,13 +6,14 @@ import asyncio
 import collections
 import functools
 import inspect
+import itertools
 import logging
 import re
 from abc import ABCMeta, abstractmethod
 from collections.abc import Awaitable, Callable
 from dataclasses import dataclass, field
 from enum import Enum
-from typing import Any, ClassVar
+from typing import Any, ClassVar, Literal
 
 import pathway.internals as pw
 import pathway.internals.column as clmn
@@ -33,6 +34,8 @@ class _AsyncStatus(Enum):
 _ASYNC_STATUS_COLUMN = "_async_status"
 _AsyncStatusSchema = schema_from_types(**{_ASYNC_STATUS_COLUMN: dt.Future(dt.STR)})
 _INSTANCE_COLUMN = "_pw_instance"
+# the number of calls made for a row with on_timeout="retry" and no retry_strategy
+_TIMEOUT_ATTEMPTS = 4
 
 
 @dataclass(frozen=True)
@@ -65,6 +68,8 @@ class _AsyncConnector(io.python.ConnectorSubject):
     _tasks: dict[Pointer, asyncio.Task]
     _invoke: Callable[..., Awaitable[dict[str, Any]]]
     _instances: dict[api.Value, _Instance]
+    _groups: collections.deque[tuple[api.Value, int]]
+    _in_flight: asyncio.Semaphore | None
     _time_finished: int | None
     _logger: logging.Logger
     _own_event_loop: bool
@@ -89,22 +94,75 @@ class _AsyncConnector(io.python.ConnectorSubject):
         timeout: float | None = None,
         retry_strategy: udfs.AsyncRetryStrategy | None = None,
         cache_strategy: udfs.CacheStrategy | None = None,
+        max_in_flight: int | None = None,
+        preserve_order: bool = False,
+        fallback: dict[str, Any] | None = None,
+        on_timeout: Literal["error", "retry"] = "error",
     ):
+        if on_timeout not in ("error", "retry"):
+            raise ValueError(
+                f"on_timeout has to be either 'error' or 'retry', got {on_timeout!r}"
+            )
+        if max_in_flight is not None and max_in_flight < 1:
+            raise ValueError(
+                f"max_in_flight has to be positive, got {max_in_flight} instead"
+            )
+        if (
+            fallback is not None
+            and fallback.keys() != self._transformer.output_schema.keys()
+        ):
+            raise ValueError("fallback value does not match output schema")
+        invoke: Callable[..., Awaitable[dict[str, Any]]] = self._transformer.invoke
+        # with a retry_strategy, the timed out calls are retried by it like any
+        # other failed calls
+        if timeout is not None and on_timeout == "retry" and retry_strategy is None:
+            invoke = self._retrying_on_timeout(udfs.with_timeout(invoke, timeout))
+            timeout = None
         self._invoke = udfs.async_options(
             capacity=capacity,
             timeout=timeout,
             retry_strategy=retry_strategy,
             cache_strategy=cache_strategy,
-        )(self._transformer.invoke)
+        )(invoke)
+        self._max_in_flight = max_in_flight
+        self._preserve_order = preserve_order
+        self._fallback = fallback
+
+    def _retrying_on_timeout(
+        self, invoke: Callable[..., Awaitable[dict[str, Any]]]
+    ) -> Callable[..., Awaitable[dict[str, Any]]]:
+        @functools.wraps(invoke)
+        async def wrapper(*args, **kwargs) -> dict[str, Any]:
+            for attempt in range(1, _TIMEOUT_ATTEMPTS + 1):
+                try:
+                    return await invoke(*args, **kwargs)
+                except asyncio.TimeoutError:
+                    if attempt == _TIMEOUT_ATTEMPTS:
+                        raise
+                    self._logger.warning(
+                        "AsyncTransformer call timed out, retrying"
+                        f" (attempt {attempt} of {_TIMEOUT_ATTEMPTS})"
+                    )
+            raise AssertionError("unreachable")
+
+        return wrapper
 
     def run(self) -> None:
         self._tasks = {}
         self._transformer.open()
         self._instances = collections.defaultdict(_Instance)
+        # (instance, time) pairs in the order of their first rows, used to emit the
+        # results in the input order if preserve_order is set
+        self._groups = collections.deque()
         self._time_finished = None
 
         async def loop_forever(event_loop: asyncio.AbstractEventLoop):
             self._maybe_create_queue()
+            self._in_flight = (
+                asyncio.Semaphore(self._max_in_flight)
+                if self._max_in_flight is not None
+                else None
+            )
 
             while True:
                 request = await self._requests.get()
@@ -134,7 +192,15 @@ class _AsyncConnector(io.python.ConnectorSubject):
                 entry = _Entry(
                     key=key, time=time, is_addition=addition, task_id=task_id
                 )
-                self._instances[instance].pending.append(entry)
+                instance_data = self._instances[instance]
+                if self._preserve_order and (
+                    not instance_data.pending or instance_data.pending[-1].time != time
+                ):
+                    self._groups.append((instance, time))
+                instance_data.pending.append(entry)
+                if addition and self._in_flight is not None:
+                    # the next rows are not read until there is a free slot
+                    await self._in_flight.acquire()
 
                 previous_task = self._tasks.get(key, None)
 
@@ -167,7 +233,12 @@ class _AsyncConnector(io.python.ConnectorSubject):
                             self._logger.error(
                                 "Exception in AsyncTransformer:", exc_info=True
                             )
-                            result = _AsyncStatus.FAILURE
+                            if self._fallback is None:
+                                result = _AsyncStatus.FAILURE
+                            else:
+                                result = dict(self._fallback)
+                        if self._in_flight is not None:
+                            self._in_flight.release()
                         # If there is a task pending for this key,
                         # let's wait for it and discard result to preserve order
                         # for this key (the instance may change)
@@ -199,6 +270,9 @@ class _AsyncConnector(io.python.ConnectorSubject):
     def _on_time_end(self, time: int) -> None:
         self._time_finished = time
         self.commit()
+        if self._preserve_order:
+            self._maybe_produce_in_order()
+            return
         instances = list(self._instances)
         # it creates a separate list for iteration because _maybe_produce_instance
         # can remove entries from self._instances
@@ -218,15 +292,37 @@ class _AsyncConnector(io.python.ConnectorSubject):
         instance_data = self._instances[instance]
         entry = _Entry(key=key, time=time, is_addition=is_addition, task_id=task_id)
         instance_data.finished[entry] = result
-        self._maybe_produce_instance(instance)
+        if self._preserve_order:
+            self._maybe_produce_in_order()
+        else:
+            self._maybe_produce_instance(instance)
+
+    def _maybe_produce_in_order(self) -> None:
+        # The results of an (instance, time) pair are emitted only when all the
+        # results of the pairs with earlier first rows are emitted.
+        while self._groups:
+            instance, time = self._groups[0]
+            instance_data = self._instances[instance]
+            if self._time_finished is None or time > self._time_finished:
+                break
+            group = itertools.takewhile(
+                lambda entry: entry.time == time, instance_data.pending
+            )
+            if any(entry not in instance_data.finished for entry in group):
+                break
+            self._groups.popleft()
+            self._maybe_produce_instance(instance, max_time=time)
 
-    def _maybe_produce_instance(self, instance: api.Value) -> None:
+    def _maybe_produce_instance(
+        self, instance: api.Value, max_time: int | None = None
+    ) -> None:
         instance_data = self._instances[instance]
         while instance_data.pending:
             entry = instance_data.pending[0]
             if (
                 self._time_finished is None
                 or entry.time > self._time_finished
+                or (max_time is not None and entry.time > max_time)
                 or entry not in instance_data.finished
             ):
                 break
@@ -479,6 +575,10 @@ class AsyncTransformer(_BaseAsyncTransformer, metaclass=ABCMeta):
         timeout: float | None = None,
         retry_strategy: udfs.AsyncRetryStrategy | None = None,
         cache_strategy: udfs.CacheStrategy | None = None,
+        max_in_flight: int | None = None,
+        preserve_order: bool = False,
+        fallback: dict[str, Any] | None = None,
+        on_timeout: Literal["error", "retry"] = "error",
     ) -> AsyncTransformer:
         """
         Sets async options.
@@ -488,15 +588,46 @@ class AsyncTransformer(_BaseAsyncTransformer, metaclass=ABCMeta):
                 Defaults to None, indicating no specific limit.
             timeout: Maximum time (in seconds) to wait for the function result.
                 Defaults to None, indicating no time limit.
-            retry_strategy: Strategy for handling retries in case of failures.
-                Defaults to None, meaning no retries.
+            retry_strategy: Strategy for handling retries in case of failures, e.g.
+                ``pw.udfs.ExponentialBackoffRetryStrategy()`` retrying each call with
+                exponentially growing delays. Defaults to None, meaning no retries.
             cache_strategy: Defines the caching mechanism. If set to None
                 and a persistency is enabled, operations will be cached using the
                 persistence layer. Defaults to None.
+            max_in_flight: Maximum number of rows being processed at once. Unlike with
+                ``capacity``, the next rows are not even read until one of the
+                invocations finishes, so that the rows waiting for a free slot don't
+                pile up as pending tasks. Defaults to None, indicating no limit.
+            preserve_order: If set, the results are emitted in the order of the input
+                rows within each timestamp, with the results of an instance emitted
+                together when the result of its first row is due. Otherwise, the
+                results are emitted as soon as they are ready. Defaults to False.
+            fallback: The result, matching :py:attr:`output_schema`, used for the rows
+                for which :py:meth:`invoke` still fails after all the retries. Such rows
+                are then treated as successful. Defaults to None, meaning that they
+                fail.
+            on_timeout: What happens to the calls taking longer than ``timeout``. With
+                ``"error"``, the call is canceled and fails, so that the row is retried
+                according to ``retry_strategy`` and then fails or gets the ``fallback``
+                result, and a hung call doesn't hold back the results of the rows after
+                it. With ``"retry"``, the call is canceled and made again, with the row
+                staying pending meanwhile. The calls are then retried according to
+                ``retry_strategy`` if it's given, and otherwise up to 4 calls are made
+                before the row fails or gets the ``fallback`` result. Defaults to
+                ``"error"``.
         Returns:
             self
         """
-        self._connector.set_options(capacity, timeout, retry_strategy, cache_strategy)
+        self._connector.set_options(
+            capacity,
+            timeout,
+            retry_strategy,
+            cache_strategy,
+            max_in_flight=max_in_flight,
+            preserve_order=preserve_order,
+            fallback=fallback,
+            on_timeout=on_timeout,
+        )
         return self
 
     @functools.cached_property
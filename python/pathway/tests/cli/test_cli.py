@@ -2,6 +2,7 @@
 
 import os
 import pathlib
+import sys
 
 from click.testing import CliRunner
 
@@ -81,3 +82,87 @@ def test_record_replay_through_cli(tmp_path: pathlib.Path):
 
     # Without replay (and with empty input connector), there are no rows
     run_record(replay_dir, timestamp_file, 0, 0)
+
+
+FAILING_ONCE_SCRIPT = """
+import os
+import pathlib
+import sys
+
+marker = pathlib.Path(sys.argv[1])
+if os.environ["PATHWAY_PROCESS_ID"] == "0" and not marker.exists():
+    marker.touch()
+    sys.exit(1)
+"""
+
+
+def run_failing_once(tmp_path: pathlib.Path, *options: str) -> int:
+    script_path = tmp_path / "failing_once.py"
+    script_path.write_text(FAILING_ONCE_SCRIPT)
+    runner = CliRunner()
+    result = runner.invoke(
+        cli.spawn,
+        [
+            "--processes",
+            "2",
+            "--restart-delay",
+            "0",
+            *options,
+            sys.executable,
+            str(script_path),
+            str(tmp_path / "marker"),
+        ],
+    )
+    return result.exit_code
+
+
+def test_spawn_restarts_processes_after_failure(tmp_path: pathlib.Path):
+    assert run_failing_once(tmp_path, "--max-restarts", "1") == 0
+    assert (tmp_path / "marker").exists()
+
+
+def test_spawn_without_restarts_fails(tmp_path: pathlib.Path):
+    assert run_failing_once(tmp_path) == 1
+
+
+FAILING_TWICE_SCRIPT = """
+import os
+import pathlib
+import sys
+
+counter = pathlib.Path(sys.argv[1])
+failures = int(counter.read_text()) if counter.exists() else 0
+if os.environ["PATHWAY_PROCESS_ID"] == "0" and failures < 2:
+    counter.write_text(str(failures + 1))
+    sys.exit(1)
+"""
+
+
+def test_spawn_resets_restart_count_after_healthy_run(tmp_path: pathlib.Path):
+    script_path = tmp_path / "failing_twice.py"
+    script_path.write_text(FAILING_TWICE_SCRIPT)
+
+    def run(restart_reset_after: str) -> int:
+        (tmp_path / "counter").unlink(missing_ok=True)
+        result = CliRunner().invoke(
+            cli.spawn,
+            [
+                "--processes",
+                "2",
+                "--max-restarts",
+                "1",
+                "--restart-delay",
+                "0",
+                "--restart-reset-after",
+                restart_reset_after,
+                sys.executable,
+                str(script_path),
+                str(tmp_path / "counter"),
+            ],
+        )
+        return result.exit_code
+
+    # the second failure exceeds the single restart allowed
+    assert run("600") == 1
+    # each run counts as healthy, so the second failure is allowed a restart too
+    assert run("0") == 0
@@ -1029,6 +1029,189 @@ def test_weekday(is_naive: bool) -> None:
     table_pd = table_from_pandas(df_new)
     assert_table_equality(table_pw, table_pd)
 
+@pytest.mark.parametrize("is_naive", [True, False])
+def test_date_parts(is_naive: bool) -> None:
+    data = [
+        "2021-01-01 10:00:00",
+        "2023-05-15 14:13:23",
+        "2024-03-01 00:00:00",
+        "2024-12-31 23:59:59",
+    ]
+    fmt_in = "%Y-%m-%d %H:%M:%S"
+    if not is_naive:
+        data = [entry + "+00:00" for entry in data]
+        fmt_in += "%z"
+    df = pd.DataFrame({"ts": pd.to_datetime(data, format=fmt_in)})
+    table = table_from_pandas(df)
+    result = table.select(
+        quarter=pw.this.ts.dt.quarter(),
+        day_of_year=pw.this.ts.dt.day_of_year(),
+        iso_week=pw.this.ts.dt.iso_week(),
+    )
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "quarter": df.ts.dt.quarter,
+                "day_of_year": df.ts.dt.dayofyear,
+                "iso_week": df.ts.dt.isocalendar().week.astype(int),
+            }
+        )
+    )
+    assert_table_equality(result, expected)
+
+
+@pytest.mark.parametrize("is_naive", [True, False])
+@pytest.mark.parametrize(
+    "unit,freq",
+    [
+        ("year", "Y"),
+        ("quarter", "Q"),
+        ("months", "M"),
+        ("day", pd.Timedelta(days=1)),
+        ("hours", pd.Timedelta(hours=1)),
+        ("minute", pd.Timedelta(minutes=1)),
+        ("second", pd.Timedelta(seconds=1)),
+    ],
+)
+def test_date_time_truncate(
+    is_naive: bool, unit: str, freq: str | pd.Timedelta
+) -> None:
+    data = [
+        "2023-02-28 23:59:59.999999999",
+        "2023-05-15 14:13:23.123456789",
+        "2024-08-02 00:00:10.000000000",
+        "2024-12-31 12:00:00.000000001",
+    ]
+    fmt_in = "%Y-%m-%d %H:%M:%S.%f"
+    if not is_naive:
+        data = [entry + "+00:00" for entry in data]
+        fmt_in += "%z"
+    df = pd.DataFrame({"ts": pd.to_datetime(data, format=fmt_in)})
+    table = table_from_pandas(df)
+    result = table.select(ts=pw.this.ts.dt.truncate(unit))
+    naive_ts = df.ts.dt.tz_localize(None)
+    if isinstance(freq, str):
+        expected_ts = naive_ts.dt.to_period(freq).dt.start_time
+    else:
+        expected_ts = naive_ts.dt.floor(freq)
+    if not is_naive:
+        expected_ts = expected_ts.dt.tz_localize(tz.UTC)
+    expected = table_from_pandas(pd.DataFrame({"ts": expected_ts}))
+    assert_table_equality(result, expected)
+
+
+def test_date_time_truncate_to_week() -> None:
+    table = table_from_markdown(
+        """
+          | ts
+        1 | 2023-05-14T10:00:00
+        2 | 2023-05-15T10:00:00
+        3 | 2023-05-21T23:59:59
+    """
+    ).select(ts=pw.this.ts.dt.strptime("%Y-%m-%dT%H:%M:%S"))
+    result = table.select(week=pw.this.ts.dt.truncate("week"))
+    expected = table_from_markdown(
+        """
+          | week
+        1 | 2023-05-08T00:00:00
+        2 | 2023-05-15T00:00:00
+        3 | 2023-05-15T00:00:00
+    """
+    ).select(week=pw.this.week.dt.strptime("%Y-%m-%dT%H:%M:%S"))
+    assert_table_equality(result, expected)
+
+
+@pytest.mark.parametrize("is_naive", [True, False])
+def test_date_time_add_calendar(is_naive: bool) -> None:
+    data = [
+        "2023-01-31 10:00:00",
+        "2024-01-31 10:00:00",
+        "2024-02-29 10:00:00",
+        "2023-05-15 14:13:23",
+    ]
+    fmt_in = "%Y-%m-%d %H:%M:%S"
+    if not is_naive:
+        data = [entry + "+00:00" for entry in data]
+        fmt_in += "%z"
+    df = pd.DataFrame(
+        {"ts": pd.to_datetime(data, format=fmt_in), "amount": [1, 1, -1, 5]}
+    )
+    table = table_from_pandas(df)
+    result = table.select(
+        months=pw.this.ts.dt.add_calendar(pw.this.amount, "months"),
+        years=pw.this.ts.dt.add_calendar(pw.this.amount, "year"),
+        days=pw.this.ts.dt.subtract_calendar(pw.this.amount, "days"),
+    )
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "months": [
+                    ts + pd.DateOffset(months=amount)
+                    for ts, amount in zip(df.ts, df.amount)
+                ],
+                "years": [
+                    ts + pd.DateOffset(years=amount)
+                    for ts, amount in zip(df.ts, df.amount)
+                ],
+                "days": [
+                    ts - pd.DateOffset(days=amount)
+                    for ts, amount in zip(df.ts, df.amount)
+                ],
+            }
+        )
+    )
+    assert_table_equality(result, expected)
+
+
+def test_date_time_calendar_errors_on_wrong_unit() -> None:
+    table_from_markdown(
+        """
+        ts
+        2023-05-15T10:00:00
+    """
+    ).select(
+        ts=pw.this.ts.dt.strptime("%Y-%m-%dT%H:%M:%S").dt.add_calendar(1, "hour")
+    )
+    with pytest.raises(
+        ValueError,
+        match=re.escape('unit has to be a valid calendar unit but is "hour"'),
+    ):
+        run_all()
+
+
+def test_format_duration() -> None:
+    df = pd.DataFrame(
+        {
+            "d": [
+                pd.Timedelta(days=1, hours=2, minutes=3, seconds=4, nanoseconds=5),
+                pd.Timedelta(seconds=-90),
+                pd.Timedelta(0),
+            ]
+        }
+    )
+    table = table_from_pandas(df)
+    result = table.select(
+        full=pw.this.d.dt.format_duration("%dd %H:%M:%S.%f"),
+        total=pw.this.d.dt.format_duration("%h h = %m min = %s s, 100%%"),
+    )
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "full": [
+                    "1d 02:03:04.000000005",
+                    "-0d 00:01:30.000000000",
+                    "0d 00:00:00.000000000",
+                ],
+                "total": [
+                    "26 h = 1563 min = 93784 s, 100%",
+                    "-0 h = 1 min = 90 s, 100%",
+                    "0 h = 0 min = 0 s, 100%",
+                ],
+            }
+        )
+    )
+    assert_table_equality(result, expected)
+
 
 def test_pathway_duration():
     values = [
@@ -1140,3 +1323,149 @@ def test_python_types():
     ):
         pw.DateTimeUtc("2025-05-01T12:00:00")
     pw.DateTimeUtc("2025-05-01T12:00:00+00:00")
+
+
+def test_date_time_zoned_conversions() -> None:
+    table = table_from_markdown(
+        """
+          |         date_string          | timezone
+        1 | 2023-03-26T00:59:00.000+0000 | Europe/Warsaw
+        2 | 2023-03-26T01:00:00.000+0000 | Europe/Warsaw
+        3 | 2023-10-29T00:30:00.000+0000 | Europe/Warsaw
+        4 | 2023-10-29T01:30:00.000+0000 | Europe/Warsaw
+        5 | 2023-11-05T08:30:00.000+0000 | America/Los_Angeles
+        6 | 2023-11-05T09:30:00.000+0000 | America/Los_Angeles
+    """
+    )
+    expected = table_from_markdown(
+        """
+          |           local            | timezone            | hour | same_utc
+        1 | 2023-03-26T01:59:00+0100 | Europe/Warsaw       |    1 | True
+        2 | 2023-03-26T03:00:00+0200 | Europe/Warsaw       |    3 | True
+        3 | 2023-10-29T02:30:00+0200 | Europe/Warsaw       |    2 | True
+        4 | 2023-10-29T02:30:00+0100 | Europe/Warsaw       |    2 | True
+        5 | 2023-11-05T01:30:00-0700 | America/Los_Angeles |    1 | True
+        6 | 2023-11-05T01:30:00-0800 | America/Los_Angeles |    1 | True
+    """
+    )
+    table_utc = table.select(
+        pw.this.timezone, t=pw.this.date_string.dt.strptime("%Y-%m-%dT%H:%M:%S.%f%z")
+    )
+    table_zoned = table_utc.select(
+        t_utc=pw.this.t, t=pw.this.t.dt.to_zoned(pw.this.timezone)
+    )
+    res = table_zoned.select(
+        local=pw.this.t.dt.strftime("%Y-%m-%dT%H:%M:%S%z"),
+        timezone=pw.this.t.dt.timezone(),
+        hour=pw.this.t.dt.to_naive_local().dt.hour(),
+        same_utc=pw.this.t.dt.to_utc() == pw.this.t_utc,
+    )
+
+    assert_table_equality(res, expected)
+
+
+def test_date_time_zoned_from_naive_and_convert_timezone() -> None:
+    table = table_from_markdown(
+        """
+          |     date_string
+        1 | 2024-07-01T12:00:00
+        2 | 2024-12-01T12:00:00
+    """
+    )
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "new_york": [
+                    "2024-07-01T12:00:00-0400 EDT",
+                    "2024-12-01T12:00:00-0500 EST",
+                ],
+                "tokyo": [
+                    "2024-07-02T01:00:00+0900 JST",
+                    "2024-12-02T02:00:00+0900 JST",
+                ],
+            }
+        )
+    )
+    fmt = "%Y-%m-%dT%H:%M:%S%z %Z"
+    table_zoned = table.select(
+        t=pw.this.date_string.dt.strptime("%Y-%m-%dT%H:%M:%S").dt.to_zoned(
+            "America/New_York"
+        )
+    )
+    res = table_zoned.select(
+        new_york=pw.this.t.dt.strftime(fmt),
+        tokyo=pw.this.t.dt.convert_timezone("Asia/Tokyo").dt.strftime(fmt),
+    )
+
+    assert_table_equality_wo_index(res, expected)
+
+
+def test_date_time_zoned_arithmetic() -> None:
+    table = table_from_markdown(
+        """
+          |         date_string
+        1 | 2024-03-31T00:30:00.000+0000
+    """
+    )
+    table_zoned = table.select(
+        t=pw.this.date_string.dt.strptime("%Y-%m-%dT%H:%M:%S.%f%z").dt.to_zoned(
+            "Europe/Warsaw"
+        )
+    )
+    res = table_zoned.select(
+        later=(pw.this.t + pd.Timedelta(hours=1)).dt.strftime("%H:%M%z"),
+        earlier=(pw.this.t - pd.Timedelta(hours=1)).dt.strftime("%H:%M%z"),
+        diff=(pw.this.t + pd.Timedelta(hours=1)) - pw.this.t,
+    )
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "later": ["03:30+0200"],
+                "earlier": ["00:30+0100"],
+                "diff": [pd.Timedelta(hours=1)],
+            }
+        )
+    )
+
+    assert_table_equality_wo_index(res, expected)
+
+
+def test_date_time_zoned_to_and_from_python() -> None:
+    @pw.udf
+    def to_zoned(year: int, month: int, day: int) -> pw.DateTimeZoned:
+        return pw.DateTimeZoned(year=year, month=month, day=day, tz="Europe/Warsaw")
+
+    @pw.udf
+    def describe(t: pw.DateTimeZoned) -> str:
+        return f"{t.isoformat()} {t.tzinfo}"
+
+    t = table_from_markdown(
+        """
+        year | month | day
+        2023 |   8   |  12
+    """
+    )
+    zoned = t.select(t=to_zoned(pw.this.year, pw.this.month, pw.this.day))
+    assert zoned.schema._dtypes() == {"t": dt.DATE_TIME_ZONED}
+    res = zoned.select(timezone=pw.this.t.dt.timezone(), described=describe(pw.this.t))
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "timezone": ["Europe/Warsaw"],
+                "described": ["2023-08-12T00:00:00+02:00 Europe/Warsaw"],
+            }
+        )
+    )
+
+    assert_table_equality_wo_index(res, expected)
+
+
+def test_date_time_zoned_python_type() -> None:
+    pw.DateTimeZoned("2025-05-01T12:00:00", tz="Europe/Warsaw")
+    with pytest.raises(
+        ValueError,
+        match=re.escape(
+            "DateTimeZoned must contain timezone information. Use pw.DateTimeNaive for naive datetimes."
+        ),
+    ):
+        pw.DateTimeZoned("2025-05-01T12:00:00")
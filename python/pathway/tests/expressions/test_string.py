@@ -1,11 +1,18 @@
 # Copyright © 2026 Pathway
 
+import hashlib
+
 import pandas as pd
 import pytest
 
 import pathway as pw
 from pathway.debug import table_from_pandas
-from pathway.tests.utils import T, assert_table_equality, run_all
+from pathway.tests.utils import (
+    T,
+    assert_table_equality,
+    assert_table_equality_wo_index,
+    run_all,
+)
 
 
 def test_strip():
@@ -499,3 +506,208 @@ def test_to_string_for_datetime_utc():
         t.select(t=pw.this.t.dt.strptime("%Y-%m-%dT%H:%M:%S.%f%z").to_string()),
         expected,
     )
+
+
+def test_string_similarity():
+    t = table_from_pandas(
+        pd.DataFrame(
+            {
+                "a": ["kitten", "martha", "Main Street", "", "same"],
+                "b": ["sitting", "marhta", "main st.", "abc", "same"],
+            }
+        )
+    )
+    result = t.select(
+        levenshtein=pw.this.a.str.levenshtein(pw.this.b),
+        jaro_winkler=pw.this.a.str.jaro_winkler(pw.this.b),
+        trigram=pw.this.a.str.trigram_similarity(pw.this.b),
+        constant=pw.this.a.str.levenshtein("same"),
+    )
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "levenshtein": [3, 2, 6, 3, 0],
+                "jaro_winkler": [
+                    0.746031746031746,
+                    17.3 / 18,
+                    0.6931818181818182,
+                    0.0,
+                    1.0,
+                ],
+                "trigram": [1 / 14, 3 / 11, 7 / 13, 0.0, 1.0],
+                "constant": [5, 5, 9, 4, 0],
+            }
+        )
+    )
+    assert_table_equality(result, expected)
+
+
+def test_encodings():
+    t = table_from_pandas(pd.DataFrame({"a": ["Pathway", "a+b=c d", "zażółć", ""]}))
+    result = t.select(
+        base64=pw.this.a.str.base64_encode(),
+        hex=pw.this.a.str.hex_encode(),
+        url=pw.this.a.str.url_encode(),
+        base64_to_hex=(
+            pw.this.a.str.base64_encode().str.base64_decode().str.hex_encode()
+        ),
+        hex_to_url=pw.this.a.str.hex_encode().str.hex_decode().str.url_encode(),
+        url_round_trip=pw.this.a.str.url_encode().str.url_decode(),
+    )
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "base64": ["UGF0aHdheQ==", "YStiPWMgZA==", "emHFvMOzxYLEhw==", ""],
+                "hex": [
+                    "50617468776179",
+                    "612b623d632064",
+                    "7a61c5bcc3b3c582c487",
+                    "",
+                ],
+                "url": [
+                    "Pathway",
+                    "a%2Bb%3Dc%20d",
+                    "za%C5%BC%C3%B3%C5%82%C4%87",
+                    "",
+                ],
+                "base64_to_hex": [
+                    "50617468776179",
+                    "612b623d632064",
+                    "7a61c5bcc3b3c582c487",
+                    "",
+                ],
+                "hex_to_url": [
+                    "Pathway",
+                    "a%2Bb%3Dc%20d",
+                    "za%C5%BC%C3%B3%C5%82%C4%87",
+                    "",
+                ],
+                "url_round_trip": ["Pathway", "a+b=c d", "zażółć", ""],
+            }
+        )
+    )
+    assert_table_equality(result, expected)
+
+
+@pytest.mark.parametrize(
+    "method,value",
+    [
+        ("base64_decode", "not base64!"),
+        ("hex_decode", "caf"),
+        ("url_decode", "100%"),
+    ],
+)
+def test_decoding_fails_on_invalid_input(method: str, value: str):
+    t = table_from_pandas(pd.DataFrame({"a": [value]}))
+    t.select(b=getattr(pw.this.a.str, method)())
+    with pytest.raises(ValueError, match="cannot decode"):
+        run_all()
+
+
+def test_digest():
+    values = ["alice", "", "zażółć"]
+    t = table_from_pandas(pd.DataFrame({"a": values}))
+    result = t.select(
+        sha256=pw.this.a.str.hexdigest(),
+        md5=pw.this.a.str.hexdigest("md5"),
+        sha256_bytes=pw.this.a.str.digest().str.hex_encode(),
+        xxh3_length=pw.this.a.str.hexdigest("xxh3").str.len(),
+        blake3_length=pw.this.a.str.digest("blake3").str.base64_encode().str.len(),
+    )
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "sha256": [hashlib.sha256(s.encode()).hexdigest() for s in values],
+                "md5": [hashlib.md5(s.encode()).hexdigest() for s in values],
+                "sha256_bytes": [
+                    hashlib.sha256(s.encode()).hexdigest() for s in t_values
+                ],
+                "xxh3_length": [16, 16, 16],
+                "blake3_length": [44, 44, 44],
+            }
+        )
+    )
+    assert_table_equality(result, expected)
+
+
+def test_digest_fails_on_unknown_algorithm():
+    t = table_from_pandas(pd.DataFrame({"a": ["alice"]}))
+    t.select(b=pw.this.a.str.hexdigest("sha1"))
+    with pytest.raises(ValueError, match='unknown hash algorithm "sha1"'):
+        run_all()
+
+
+def test_ip_addresses():
+    t = table_from_pandas(
+        pd.DataFrame({"a": ["10.1.2.3", "192.168.17.5", "2001:DB8:0:0::1"]})
+    )
+    result = t.select(
+        ip=pw.this.a.str.parse_ip(),
+        version=pw.this.a.str.ip_version(),
+        private=pw.this.a.str.ip_in_network("10.0.0.0/8"),
+        network=pw.this.a.str.ip_network(16),
+    )
+    expected = table_from_pandas(
+        pd.DataFrame(
+            {
+                "ip": ["10.1.2.3", "192.168.17.5", "2001:db8::1"],
+                "version": [4, 4, 6],
+                "private": [True, False, False],
+                "network": ["10.1.0.0/16", "192.168.0.0/16", "2001::/16"],
+            }
+        )
+    ).update_types(version=int | None)
+    assert_table_equality(result, expected)
+
+
+def test_parse_ip_optional():
+    t = table_from_pandas(pd.DataFrame({"a": ["::1", "1.2.3", "localhost"]}))
+    result = t.select(ip=pw.this.a.str.parse_ip(optional=True))
+    expected = table_from_pandas(pd.DataFrame({"ip": ["::1", None, None]}))
+    expected = expected.update_types(ip=str | None)
+    assert_table_equality(result, expected)
+
+
+def test_parse_ip_fails_on_invalid_address():
+    t = table_from_pandas(pd.DataFrame({"a": ["1.2.3"]}))
+    t.select(ip=pw.this.a.str.parse_ip())
+    with pytest.raises(ValueError, match='cannot parse "1.2.3" as an IP address'):
+        run_all()
+
+
+def test_url_parts():
+    t = table_from_pandas(
+        pd.DataFrame(
+            {
+                "url": [
+                    "https://Shop.Example.com:8443/cart?id=17&ref=mail#top",
+                    "http://pathway.com",
+                ]
+            }
+        )
+    )
+    result = t.select(
+        scheme=pw.this.url.str.url_part("scheme"),
+        host=pw.this.url.str.url_part("host"),
+        port=pw.this.url.str.url_part("port"),
+        path=pw.this.url.str.url_part("path"),
+        query=pw.this.url.str.url_part("query"),
+        fragment=pw.this.url.str.url_part("fragment"),
+        ref=pw.this.url.str.url_query_param("ref"),
+    )
+    expected = T(
+        """
+          | scheme | host             | port | path  | query           | fragment | ref
+        0 | https  | shop.example.com | 8443 | /cart | id=17&ref=mail  | top      | mail
+        1 | http   | pathway.com      |      | /     |                 |          |
+        """
+    ).update_types(
+        port=int | None, query=str | None, fragment=str | None, ref=str | None
+    )
+    assert_table_equality_wo_index(result, expected)
+
+
+def test_url_part_rejects_unknown_part():
+    t = table_from_pandas(pd.DataFrame({"url": ["https://pathway.com"]}))
+    with pytest.raises(ValueError, match="unknown URL part 'netloc'"):
+        t.select(netloc=pw.this.url.str.url_part("netloc"))
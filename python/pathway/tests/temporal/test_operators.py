@@ -237,6 +237,159 @@ def test_buffer_non_append_only():
     assert_stream_equality_wo_index(res, expected)
 
 
+def test_forget_with_late():
+    t = pw.debug.table_from_markdown(
+        """
+        a |  t | __time__
+        1 |  2 |     2
+        1 |  3 |     2
+        1 |  5 |     4
+        1 | 15 |     8
+        1 |  7 |    10
+        2 |  4 |     2
+        2 |  8 |     4
+        2 |  9 |     8
+        3 |  1 |     6
+    """
+    )
+
+    on_time, late = t.forget_with_late(pw.this.t, 2)
+    expected_on_time = pw.debug.table_from_markdown(
+        """
+        a |  t | __time__ | __diff__
+        1 |  2 |     2    |     1
+        1 |  3 |     2    |     1
+        1 |  5 |     4    |     1
+        1 |  2 |     4    |    -1
+        1 |  3 |     6    |    -1
+        1 |  5 |     6    |    -1
+        1 | 15 |     8    |     1
+        2 |  4 |     2    |     1
+        2 |  8 |     4    |     1
+        2 |  4 |     6    |    -1
+        2 |  9 |     8    |     1
+        2 |  8 |    10    |    -1
+        2 |  9 |    10    |    -1
+    """
+    )
+    expected_late = pw.debug.table_from_markdown(
+        """
+        a |  t | __time__
+        3 |  1 |     6
+        1 |  7 |    10
+    """
+    )
+    assert_stream_equality_wo_index(
+        (on_time, late), (expected_on_time, expected_late)
+    )
+
+
+def test_buffer_with_late():
+    t = pw.debug.table_from_markdown(
+        """
+        a |  t | __time__
+        1 |  2 |     2
+        1 |  3 |     2
+        1 |  5 |     4
+        1 | 15 |     8
+        1 |  7 |    10
+        2 |  4 |     2
+        2 |  8 |     4
+        2 |  9 |     8
+        3 |  1 |     6
+    """
+    )
+
+    on_time, late = t.buffer_with_late(pw.this.t, 2)
+    expected_on_time = pw.debug.table_from_markdown(
+        """
+        a |  t | __time__
+        1 |  2 |     2
+        1 |  3 |     4
+        1 |  5 |     4
+        1 | 15 |   18446744073709551614
+        2 |  4 |     4
+        2 |  8 |     8
+        2 |  9 |     8
+    """
+    )
+    expected_late = pw.debug.table_from_markdown(
+        """
+        a |  t | __time__
+        3 |  1 |     6
+        1 |  7 |    10
+    """
+    )
+    assert_stream_equality_wo_index(
+        (on_time, late), (expected_on_time, expected_late)
+    )
+
+
+def test_with_retention_max_versions():
+    t = pw.debug.table_from_markdown(
+        """
+        k | t | __time__
+        a | 1 |     2
+        a | 2 |     4
+        b | 1 |     4
+        a | 3 |     6
+    """
+    )
+
+    res = t.with_retention(pw.this.t, max_versions=2, instance=pw.this.k)
+    expected = pw.debug.table_from_markdown(
+        """
+        k | t | __time__ | __diff__
+        a | 1 |     2    |     1
+        a | 2 |     4    |     1
+        b | 1 |     4    |     1
+        a | 3 |     6    |     1
+        a | 1 |     6    |    -1
+    """
+    )
+    assert_stream_equality_wo_index(res, expected)
+
+
+def test_with_retention_max_age():
+    t = pw.debug.table_from_markdown(
+        """
+        a |  t | __time__
+        1 |  2 |     2
+        1 |  3 |     2
+        1 |  5 |     4
+        1 | 15 |     8
+        1 |  7 |    10
+        2 |  4 |     2
+        2 |  8 |     4
+        2 |  9 |     8
+        3 |  1 |     6
+    """
+    )
+
+    res = t.with_retention(pw.this.t, max_age=2, instance=pw.this.a)
+    expected = t._forget(
+        pw.this.t + 2,
+        pw.this.t,
+        mark_forgetting_records=False,
+        instance_column=pw.this.a,
+    )
+    assert_stream_equality_wo_index(res, expected)
+
+
+def test_with_retention_errors():
+    t = pw.debug.table_from_markdown(
+        """
+        k | t
+        a | 1
+    """
+    )
+
+    with pytest.raises(ValueError, match="Either max_age or max_versions"):
+        t.with_retention(pw.this.t)
+    with pytest.raises(ValueError, match="max_versions must be positive"):
+        t.with_retention(pw.this.t, max_versions=0)
+
+
 def test_freeze_instance():
     t = pw.debug.table_from_markdown(
         """
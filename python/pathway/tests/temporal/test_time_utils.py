@@ -271,3 +271,81 @@ def test_add_update_timestamp_utc(utc_now_mock):
         )
     )
     assert_stream_equality(result, expected)
+
+
+@patch("pathway.stdlib.temporal.time_utils._get_now_timestamp_utc")
+def test_assert_references(get_now_timestamp_utc_mock):
+    get_now_timestamp_utc_mock.side_effect = lambda ptr: pd.Timestamp(
+        0, unit="ms", tz=datetime.timezone.utc
+    )
+    orders = T(
+        """
+  | user  | __time__
+1 | Alice |        2
+2 | Carol |        2
+3 |       |        2
+4 | Bob   |        4
+        """
+    )
+    users = T(
+        """
+  | name  | __time__
+1 | Alice |        2
+2 | Carol |        6
+3 | Bob   |        2
+        """
+    )
+
+    violations = orders.assert_references(orders.user, users.name)
+
+    expected = T(
+        """
+value | __time__ | __diff__
+Carol |        2 |        1
+Carol |        6 |       -1
+        """
+    )
+    assert_stream_equality_wo_index(violations.select(pw.this.value), expected)
+
+
+@patch("pathway.stdlib.temporal.time_utils._get_now_timestamp_utc")
+@patch("pathway.stdlib.temporal.time_utils.utc_now")
+def test_assert_references_grace_period(utc_now_mock, get_now_timestamp_utc_mock):
+    orders = T(
+        """
+  | user  | now_utc | __time__
+1 | Alice |       0 |        0
+2 | Carol |     100 |      100
+3 | Dave  |     200 |      200
+        """
+    )
+    users = T(
+        """
+  | name  | __time__
+1 | Alice |        0
+2 | Dave  |      400
+        """
+    )
+
+    now_utc = pw.debug.table_to_pandas(orders).now_utc.to_dict()
+
+    utc_now_mock.side_effect = fake_utc_now(1000)
+    get_now_timestamp_utc_mock.side_effect = lambda ptr: pd.Timestamp(
+        now_utc[ptr], unit="ms", tz=datetime.timezone.utc
+    )
+    violations = orders.assert_references(
+        orders.user,
+        users.name,
+        grace_period=pw.Duration(milliseconds=300),
+        refresh_rate=pw.Duration(milliseconds=100),
+    )
+
+    expected = T(
+        """
+value | seen_timestamp_utc | __time__ | __diff__
+Carol |                100 |      400 |        1
+        """
+    ).with_columns(
+        seen_timestamp_utc=pw.this.seen_timestamp_utc.dt.utc_from_timestamp(unit="ms")
+    )
+    assert_stream_equality_wo_index(violations, expected)
@@ -27,6 +27,7 @@ from pathway.tests.utils import (
     run,
     wait_result_with_checker,
     write_csv,
+    xfail_on_multiple_threads,
 )
 from pathway.udfs import InMemoryCache
 
@@ -704,3 +705,357 @@ def test_datetimes():
             split_on_whitespace=False,
         ).select(ret=pw.this.ret.dt.strptime("%Y-%m-%d %H:%M:%S")),
     )
+
+
+def test_max_in_flight():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    running = 0
+    max_running = 0
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            nonlocal running, max_running
+            running += 1
+            max_running = max(max_running, running)
+            await asyncio.sleep(0.05)
+            running -= 1
+            return dict(ret=value + 1)
+
+    input_table = T(
+        """
+            | value
+        1   | 1
+        2   | 2
+        3   | 3
+        4   | 4
+        5   | 5
+        """
+    )
+
+    result = (
+        TestAsyncTransformer(input_table=input_table)
+        .with_options(max_in_flight=2)
+        .successful
+    )
+
+    assert_table_equality(
+        result,
+        T(
+            """
+            | ret
+        1   | 2
+        2   | 3
+        3   | 4
+        4   | 5
+        5   | 6
+        """
+        ),
+    )
+    assert max_running == 2
+
+
+def test_max_in_flight_has_to_be_positive():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            return dict(ret=value + 1)
+
+    input_table = T(
+        """
+        value
+        1
+        """
+    )
+
+    with pytest.raises(ValueError, match="max_in_flight has to be positive"):
+        TestAsyncTransformer(input_table=input_table).with_options(max_in_flight=0)
+
+
+@xfail_on_multiple_threads
+def test_preserve_order():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            # the rows read first take the longest
+            await asyncio.sleep(0.3 / value)
+            return dict(ret=value)
+
+    input_table = T(
+        """
+        value | __time__
+          1   |     2
+          2   |     2
+          3   |     2
+          4   |     2
+          5   |     4
+          6   |     4
+        """
+    )
+
+    result = (
+        TestAsyncTransformer(input_table=input_table)
+        .with_options(preserve_order=True)
+        .successful
+    )
+
+    input_order: list[api.Pointer] = []
+    output_order: list[api.Pointer] = []
+    pw.io.subscribe(
+        input_table,
+        on_change=lambda key, row, time, is_addition: input_order.append(key),
+    )
+    pw.io.subscribe(
+        result,
+        on_change=lambda key, row, time, is_addition: output_order.append(key),
+    )
+    run()
+
+    assert output_order == input_order
+
+
+def test_retries_and_fallback():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    attempts: dict[int, int] = {}
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            attempts[value] = attempts.get(value, 0) + 1
+            # value 2 fails transiently, value 3 always fails
+            if value == 3 or (value == 2 and attempts[value] < 3):
+                raise ValueError("service unavailable")
+            return dict(ret=value + 1)
+
+    input_table = T(
+        """
+            | value
+        1   | 1
+        2   | 2
+        3   | 3
+        """
+    )
+
+    result = (
+        TestAsyncTransformer(input_table=input_table)
+        .with_options(
+            retry_strategy=pw.udfs.ExponentialBackoffRetryStrategy(
+                max_retries=2, initial_delay=10, jitter_ms=0
+            ),
+            fallback=dict(ret=-1),
+        )
+        .successful
+    )
+
+    assert_table_equality(
+        result,
+        T(
+            """
+            | ret
+        1   | 2
+        2   | 3
+        3   | -1
+        """
+        ),
+    )
+    assert attempts == {1: 1, 2: 3, 3: 3}
+
+
+def test_fallback_has_to_match_output_schema():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            return dict(ret=value + 1)
+
+    input_table = T(
+        """
+        value
+        1
+        """
+    )
+
+    with pytest.raises(ValueError, match="fallback value does not match"):
+        TestAsyncTransformer(input_table=input_table).with_options(
+            fallback=dict(result=-1)
+        )
+
+
+def test_timeout_retry():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    attempts: dict[int, int] = {}
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            attempts[value] = attempts.get(value, 0) + 1
+            # the first call for value 2 hangs
+            if value == 2 and attempts[value] == 1:
+                await asyncio.sleep(100)
+            return dict(ret=value + 1)
+
+    input_table = T(
+        """
+            | value
+        1   | 1
+        2   | 2
+        """
+    )
+
+    result = (
+        TestAsyncTransformer(input_table=input_table)
+        .with_options(timeout=0.2, on_timeout="retry")
+        .successful
+    )
+
+    assert_table_equality(
+        result,
+        T(
+            """
+            | ret
+        1   | 2
+        2   | 3
+        """
+        ),
+    )
+    assert attempts == {1: 1, 2: 2}
+
+
+def test_timeout_retry_is_bounded():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    attempts: dict[int, int] = {}
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            attempts[value] = attempts.get(value, 0) + 1
+            if value == 2:
+                await asyncio.sleep(100)
+            return dict(ret=value + 1)
+
+    input_table = T(
+        """
+            | value
+        1   | 1
+        2   | 2
+        """
+    )
+
+    transformer = TestAsyncTransformer(input_table=input_table).with_options(
+        timeout=0.1, on_timeout="retry"
+    )
+
+    assert_table_equality(
+        transformer.failed,
+        T(
+            """
+            | ret
+        2   |
+        """
+        ).update_types(ret=int | None),
+    )
+    assert attempts == {1: 1, 2: 4}
+
+
+def test_timeout_retry_follows_retry_strategy():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    attempts: dict[int, int] = {}
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            attempts[value] = attempts.get(value, 0) + 1
+            if value == 2:
+                await asyncio.sleep(100)
+            return dict(ret=value + 1)
+
+    input_table = T(
+        """
+            | value
+        1   | 1
+        2   | 2
+        """
+    )
+
+    transformer = TestAsyncTransformer(input_table=input_table).with_options(
+        timeout=0.1,
+        on_timeout="retry",
+        retry_strategy=pw.udfs.FixedDelayRetryStrategy(max_retries=1, delay_ms=0),
+        fallback=dict(ret=-1),
+    )
+
+    assert_table_equality(
+        transformer.successful,
+        T(
+            """
+            | ret
+        1   | 2
+        2   | -1
+        """
+        ),
+    )
+    assert attempts == {1: 1, 2: 2}
+
+
+def test_timeout_error():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            if value == 2:
+                await asyncio.sleep(100)
+            return dict(ret=value + 1)
+
+    input_table = T(
+        """
+            | value
+        1   | 1
+        2   | 2
+        """
+    )
+
+    transformer = TestAsyncTransformer(input_table=input_table).with_options(
+        timeout=0.2, on_timeout="error"
+    )
+
+    assert_table_equality(
+        transformer.failed,
+        T(
+            """
+            | ret
+        2   |
+        """
+        ).update_types(ret=int | None),
+    )
+
+
+def test_on_timeout_has_to_be_known():
+    class OutputSchema(pw.Schema):
+        ret: int
+
+    class TestAsyncTransformer(pw.AsyncTransformer, output_schema=OutputSchema):
+        async def invoke(self, value: int) -> dict[str, Any]:
+            return dict(ret=value + 1)
+
+    input_table = T(
+        """
+        value
+        1
+        """
+    )
+
+    with pytest.raises(ValueError, match="on_timeout has to be either"):
+        TestAsyncTransformer(input_table=input_table).with_options(
+            timeout=1.0, on_timeout="ignore"  # type: ignore[arg-type]
+        )
@@ -31,6 +31,7 @@ from pathway.tests.utils import (
     assert_table_equality_wo_index_types,
     assert_table_equality_wo_types,
     needs_multiprocessing_fork,
+    only_with_license_key,
     run_all,
     warns_here,
     xfail_on_multiple_threads,
@@ -4054,6 +4055,119 @@ def test_groupby_instance():
     assert_table_equality(res, expected)
 
 
+def test_groupby_shard_by():
+    t = T(
+        """
+        tenant | user | col
+             1 |    1 |   1
+             1 |    1 |   2
+             1 |    2 |   3
+             2 |    1 |   4
+             2 |    3 |   5
+        """
+    )
+    expected = T(
+        """
+        tenant | user | col
+             1 |    1 |   3
+             1 |    2 |   3
+             2 |    1 |   4
+             2 |    3 |   5
+        """
+    ).with_id_from(pw.this.tenant, pw.this.user)
+
+    res = t.groupby(pw.this.tenant, pw.this.user, shard_by=pw.this.tenant).reduce(
+        pw.this.tenant, pw.this.user, col=pw.reducers.sum(pw.this.col)
+    )
+    assert_table_equality(res, expected)
+
+    looked_up = t.select(
+        pw.this.col, total=res.ix_ref(pw.this.tenant, pw.this.user).col
+    )
+    assert_table_equality_wo_index(
+        looked_up,
+        T(
+            """
+            col | total
+              1 |     3
+              2 |     3
+              3 |     3
+              4 |     4
+              5 |     5
+            """
+        ),
+    )
+
+
+def test_groupby_shard_by_instance():
+    t = T(
+        """
+        a | b | col
+        0 | 0 |   1
+        0 | 0 |   2
+        1 | 0 |   3
+        0 | 1 |   4
+        """
+    )
+    expected = T(
+        """
+        a | b | col
+        0 | 0 |   3
+        1 | 0 |   3
+        0 | 1 |   4
+        """
+    ).with_id_from(pw.this.b, instance=pw.this.a)
+
+    res = t.groupby(pw.this.b, instance=pw.this.a, shard_by=pw.this.a).reduce(
+        pw.this.a, pw.this.b, col=pw.reducers.sum(pw.this.col)
+    )
+    assert_table_equality(res, expected)
+
+
+def test_groupby_shard_by_reducers(monkeypatch):
+    monkeypatch.setenv("PATHWAY_THREADS", "2")
+    t = T(
+        """
+        tenant | user | col
+             1 |    1 |   1
+             1 |    1 |   2
+             1 |    2 |   3
+             2 |    1 |   4
+             2 |    3 |   5
+             2 |    3 |   5
+             3 |    2 |   6
+        """
+    )
+
+    def reduce(grouped: pw.GroupedTable) -> pw.Table:
+        return grouped.reduce(
+            pw.this.tenant,
+            pw.this.user,
+            count=pw.reducers.count(),
+            count_distinct=pw.reducers.count_distinct(pw.this.col),
+            min=pw.reducers.min(pw.this.col),
+            sum=pw.reducers.sum(pw.this.col),
+            tuple=pw.reducers.sorted_tuple(pw.this.col),
+        )
+
+    res = reduce(t.groupby(pw.this.tenant, pw.this.user, shard_by=pw.this.tenant))
+    expected = reduce(t.groupby(pw.this.tenant, pw.this.user))
+    assert_table_equality(res, expected)
+
+
+def test_groupby_shard_by_errors():
+    t = T(
+        """
+        a | b | col
+        0 | 0 |   1
+        """
+    )
+    with pytest.raises(ValueError, match="one of the grouping columns"):
+        t.groupby(pw.this.a, shard_by=pw.this.b)
+    with pytest.raises(ValueError, match="both id and shard_by"):
+        t.groupby(id=pw.this.a, shard_by=pw.this.a)
+
+
 def test_join_ix():
     left = T(
         """
@@ -6803,6 +6917,176 @@ def test_debug_operator():
     assert p.stdout.decode() == expected_output
 
 
+@pytest.mark.parametrize("commands", ["\n" * 10, "step 2\nstep 10\n", "continue\n"])
+def test_debug_operator_step_mode(commands):
+    test_dir = os.path.dirname(os.path.abspath(__file__))
+    p = sp.run(
+        ["python3", f"{test_dir}/programs/debug_stepping.py"],
+        input=commands.encode(),
+        capture_output=True,
+        check=True,
+        timeout=60,
+    )
+    expected_output = """[0][foo] @Timestamp(2) +1 id=^X1MXHYYG4YM0DB900V28XN5T4W, a=Int(1), t=Int(2)
+[0][foo] @Timestamp(2) +1 id=^YYY4HABTRW7T8VX2Q429ZYV70W, a=Int(2), t=Int(2)
+[0][foo] @Timestamp(6) +1 id=^Z3QWT294JQSHPSR8KTPG9ECE4W, a=Int(10), t=Int(5)
+[0][foo] @Timestamp(8) +1 id=^3CZ78B48PASGNT231ZECWPER90, a=Int(3), t=Int(8)
+[0][foo] @Timestamp(8) +1 id=^3HN31E1PBT7YHH5PWVKTZCPRJ8, a=Int(5), t=Int(7)
+"""
+    assert p.stdout.decode() == expected_output
+
+
+def test_unknown_step_mode():
+    pw.debug.table_from_markdown(
+        """
+        a
+        1
+        """
+    ).debug("foo")
+    with pytest.raises(ValueError, match="unknown step mode"):
+        pw.run(monitoring_level=pw.MonitoringLevel.NONE, step_mode="next")
+
+
+def test_dry_run(tmp_path):
+    input_path = tmp_path / "input.csv"
+    input_path.write_text("a\n1\n2\n")
+    output_path = tmp_path / "output.csv"
+    output_path.write_text("previous content\n")
+
+    class InputSchema(pw.Schema):
+        a: int
+
+    t = pw.io.csv.read(input_path, schema=InputSchema, mode="static")
+    pw.io.csv.write(t.select(b=pw.this.a + 1), output_path)
+    report = pw.run(monitoring_level=pw.MonitoringLevel.NONE, dry_run=True)
+
+    assert report is not None
+    assert report["ok"]
+    assert [check["direction"] for check in report["connectors"]] == [
+        "input",
+        "output",
+    ]
+    assert all(check["error"] is None for check in report["connectors"])
+    # nothing is written
+    assert output_path.read_text() == "previous content\n"
+
+
+def test_dry_run_reports_failed_connectors(tmp_path):
+    class InputSchema(pw.Schema):
+        a: int
+
+    t = pw.io.csv.read(tmp_path / "input.csv", schema=InputSchema, mode="static")
+    pw.io.csv.write(t, tmp_path / "missing_directory" / "output.csv")
+    report = pw.run(monitoring_level=pw.MonitoringLevel.NONE, dry_run=True)
+
+    assert report is not None
+    assert not report["ok"]
+    [output_check] = [
+        check for check in report["connectors"] if check["direction"] == "output"
+    ]
+    assert "Filesystem operation (create) failed" in output_check["error"]
+
+
+def test_run_without_dry_run_returns_nothing():
+    t = T(
+        """
+        a
+        1
+        """
+    )
+    pw.io.null.write(t)
+    assert pw.run(monitoring_level=pw.MonitoringLevel.NONE) is None
+
+
+def test_explain():
+    t = T(
+        """
+        owner | pet
+        Alice | dog
+        Bob   | cat
+        """
+    )
+    selected = t.select(pw.this.owner)
+    counts = selected.groupby(pw.this.owner).reduce(
+        pw.this.owner, n=pw.reducers.count()
+    )
+    pw.io.null.write(counts)
+    plan = {entry["operator_id"]: entry for entry in pw.explain()}
+
+    reduce_entry = plan[counts._source.operator.id]
+    assert reduce_entry["exchange"]
+    assert reduce_entry["arrangement"]
+    assert not reduce_entry["persisted"]
+    assert reduce_entry["trace"] is not None
+    assert "test_common.py" in reduce_entry["trace"]
+
+    select_entry = plan[selected._source.operator.id]
+    assert not select_entry["exchange"]
+    assert not select_entry["arrangement"]
+
+
+@pytest.mark.parametrize(
+    "persistence_mode",
+    [pw.PersistenceMode.PERSISTING, pw.PersistenceMode.OPERATOR_PERSISTING],
+)
+@only_with_license_key("persistence_mode", [api.PersistenceMode.OPERATOR_PERSISTING])
+def test_explain_persistence(persistence_mode, tmp_path):
+    t = T(
+        """
+        owner | pet
+        Alice | dog
+        Bob   | cat
+        """
+    )
+    counts = t.groupby(pw.this.owner).reduce(pw.this.owner, n=pw.reducers.count())
+    pw.io.null.write(counts)
+    persistence_config = pw.persistence.Config(
+        pw.persistence.Backend.filesystem(tmp_path),
+        persistence_mode=persistence_mode,
+    )
+    plan = {
+        entry["operator_id"]: entry
+        for entry in pw.explain(persistence_config=persistence_config)
+    }
+    # only the operator persistence keeps the state of a reduce
+    assert plan[counts._source.operator.id]["persisted"] == (
+        persistence_mode == pw.PersistenceMode.OPERATOR_PERSISTING
+    )
+
+
+def test_explain_does_not_set_up_connectors(tmp_path):
+    class InputSchema(pw.Schema):
+        owner: str
+
+    missing_path = tmp_path / "missing" / "db.sqlite"
+    t = pw.io.sqlite.read(missing_path, "owners", InputSchema)
+    counts = t.groupby(pw.this.owner).reduce(pw.this.owner, n=pw.reducers.count())
+    pw.io.sqlite.write(counts, missing_path, "counts")
+
+    # neither the reader nor the writer could be created
+    report = pw.run(monitoring_level=pw.MonitoringLevel.NONE, dry_run=True)
+    assert report is not None
+    assert not report["ok"]
+
+    plan = {entry["operator_id"]: entry for entry in pw.explain()}
+    assert plan[counts._source.operator.id]["arrangement"]
+    assert not missing_path.parent.exists()
+
+
+def test_dry_run_reports_operators():
+    t = T(
+        """
+        owner | pet
+        Alice | dog
+        """
+    )
+    pw.io.null.write(t.groupby(pw.this.owner).reduce(pw.this.owner))
+    report = pw.run(monitoring_level=pw.MonitoringLevel.NONE, dry_run=True)
+
+    assert report is not None
+    assert any(operator["arrangement"] for operator in report["operators"])
+
+
 def test_table_to_stream():
     t = pw.debug.table_from_markdown(
         """
@@ -6945,3 +7229,126 @@ def test_assert_append_only():
         id_from=["a"],
     )
     assert_stream_equality(t_append_only, expected)
+
+
+def test_assert_monotonic():
+    t = pw.debug.table_from_markdown(
+        """
+        sensor | seq | __time__
+             1 |   1 |     2
+             2 |   7 |     2
+             1 |   1 |     4
+             2 |   9 |     4
+             1 |   3 |     6
+             2 |   9 |     6
+        """
+    )
+    checked = t.assert_monotonic(pw.this.seq, instance=pw.this.sensor)
+    assert_stream_equality(checked, t)
+
+
+def test_assert_monotonic_violation():
+    t = pw.debug.table_from_markdown(
+        """
+        sensor | seq | __time__
+             1 |   5 |     2
+             2 |   1 |     2
+             1 |   3 |     4
+             2 |   2 |     4
+        """
+    )
+    t.assert_monotonic(pw.this.seq, instance=pw.this.sensor)
+    with pytest.raises(
+        ValueError,
+        match=re.escape("Expected non-decreasing values, but got 3 after 5 for key: "),
+    ):
+        run_all()
+
+
+def test_assert_monotonic_per_row_id():
+    t = pw.debug.table_from_markdown(
+        """
+        a | b | __time__ | __diff__
+        1 | 2 |    2     |    1
+        3 | 4 |    2     |    1
+        3 | 4 |    4     |   -1
+        3 | 1 |    4     |    1
+        """,
+        id_from=["a"],
+    )
+    t.assert_monotonic(pw.this.b)
+    with pytest.raises(
+        ValueError,
+        match=re.escape(
+            "Expected non-decreasing values, but got 1 after 4 for key: "
+            "^3CZ78B48PASGNT231ZECWPER90"
+        ),
+    ):
+        run_all()
+
+
+def test_assert_monotonic_strict():
+    t = pw.debug.table_from_markdown(
+        """
+        seq | __time__
+          1 |     2
+          1 |     4
+        """
+    )
+    t.assert_monotonic(pw.this.seq, instance=0, strict=True)
+    with pytest.raises(
+        ValueError,
+        match=re.escape("Expected increasing values, but got 1 after 1 for key: "),
+    ):
+        run_all()
+
+
+def test_assert_monotonic_same_time_not_compared():
+    t = pw.debug.table_from_markdown(
+        """
+        seq | __time__
+          2 |     2
+          1 |     2
+          2 |     4
+        """
+    )
+    checked = t.assert_monotonic(pw.this.seq, instance=0, strict=False)
+    assert_stream_equality(checked, t)
+
+
+def test_assert_monotonic_log(caplog):
+    t = pw.debug.table_from_markdown(
+        """
+        seq | __time__
+          5 |     2
+          3 |     4
+        """
+    )
+    checked = t.assert_monotonic(pw.this.seq, instance=0, on_violation="log")
+    assert_stream_equality(checked, t)
+    assert "Expected non-decreasing values, but got 3 after 5" in caplog.text
+
+
+def test_assert_monotonic_log_compares_with_last_value(caplog):
+    t = pw.debug.table_from_markdown(
+        """
+        seq | __time__
+          5 |     2
+          3 |     4
+          4 |     6
+        """
+    )
+    checked = t.assert_monotonic(pw.this.seq, instance=0, on_violation="log")
+    assert_stream_equality(checked, t)
+    assert caplog.text.count("Expected non-decreasing values") == 1
+
+
+def test_assert_monotonic_invalid_on_violation():
+    t = T(
+        """
+        seq
+          1
+        """
+    )
+    with pytest.raises(ValueError, match="on_violation has to be either"):
+        t.assert_monotonic(pw.this.seq, on_violation="ignore")  # type: ignore
@@ -15,7 +15,7 @@ import pytest
 from deltalake import DeltaTable, write_deltalake
 
 import pathway as pw
-from pathway.internals import api
+from pathway.internals import api, dtype as dt
 from pathway.internals.parse_graph import G
 from pathway.io.deltalake import _PATHWAY_COLUMN_META_FIELD
 from pathway.tests.utils import (
@@ -23,6 +23,7 @@ from pathway.tests.utils import (
     ExceptionAwareThread,
     T,
     assert_table_equality,
+    assert_table_equality_wo_index,
     needs_multiprocessing_fork,
     only_with_license_key,
     run,
@@ -140,6 +141,55 @@ def test_deltalake_roundtrip(
     assert final.equals(original)
 
 
+@only_with_license_key
+@pytest.mark.parametrize("use_stored_schema", [True, False])
+def test_deltalake_date_time_zoned_roundtrip(
+    use_stored_schema: bool, tmp_path: pathlib.Path
+):
+    lake_path = tmp_path / "lake"
+
+    class InputSchema(pw.Schema):
+        k: int = pw.column_definition(primary_key=True)
+        t: pw.DateTimeZoned
+
+    def zoned_table() -> pw.Table:
+        return T(
+            """
+            k | t                         | timezone
+            1 | 2023-10-29T00:30:00+00:00 | Europe/Warsaw
+            2 | 2023-10-29T01:30:00+00:00 | Europe/Warsaw
+            3 | 2024-07-01T16:00:00+00:00 | Asia/Tokyo
+            """
+        ).select(
+            pw.this.k,
+            t=pw.this.t.dt.strptime("%Y-%m-%dT%H:%M:%S%z").dt.to_zoned(
+                pw.this.timezone
+            ),
+        )
+
+    pw.io.deltalake.write(zoned_table(), str(lake_path))
+    run_all()
+
+    # the zone name is kept next to the offset, which alone would be ambiguous
+    assert sorted(DeltaTable(lake_path).to_pandas()["t"]) == [
+        "2023-10-29T02:30:00.000000000+01:00[Europe/Warsaw]",
+        "2023-10-29T02:30:00.000000000+02:00[Europe/Warsaw]",
+        "2024-07-02T01:00:00.000000000+09:00[Asia/Tokyo]",
+    ]
+
+    G.clear()
+    if use_stored_schema:
+        table = pw.io.deltalake.read(lake_path, mode="static")
+    else:
+        table = pw.io.deltalake.read(lake_path, schema=InputSchema, mode="static")
+    assert table.schema._dtypes()["t"] == dt.DATE_TIME_ZONED
+    result = table.select(pw.this.k, pw.this.t, timezone=pw.this.t.dt.timezone())
+    expected = zoned_table().select(
+        pw.this.k, pw.this.t, timezone=pw.this.t.dt.timezone()
+    )
+    assert_table_equality_wo_index(result, expected)
+
+
 @pytest.mark.parametrize(
     "snapshot_access", [api.SnapshotAccess.FULL, api.SnapshotAccess.OFFSETS_ONLY]
 )
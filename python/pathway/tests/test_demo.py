@@ -1,11 +1,12 @@
 # Copyright © 2026 Pathway
 
+import datetime
 import pathlib
 
 import pytest
 
 import pathway as pw
-from pathway.tests.utils import T, assert_table_equality_wo_index, write_csv
+from pathway.tests.utils import T, assert_table_equality_wo_index, run, write_csv
 
 
 def test_generate_custom_stream():
@@ -165,3 +166,40 @@ def test_demo_replay_with_time_wrong_schema():
             unit="ns",
             autocommit_ms=10,
         )
+
+
+def test_frozen_clock():
+    frozen_at = datetime.datetime(2026, 1, 1, tzinfo=datetime.timezone.utc)
+    frozen_at_ms = int(frozen_at.timestamp() * 1000)
+
+    class InputSchema(pw.Schema):
+        value: int
+
+    class Subject(pw.io.python.ConnectorSubject):
+        def run(self):
+            for value in range(3):
+                self.next(value=value)
+                self.commit()
+
+    table = pw.io.python.read(Subject(), schema=InputSchema)
+    times = []
+    pw.io.subscribe(
+        table, on_change=lambda key, row, time, is_addition: times.append(time)
+    )
+
+    pw.demo.freeze_clock(frozen_at)
+    try:
+        run()
+    finally:
+        pw.demo.reset_clock()
+
+    # with the clock frozen, the times only move to keep the batches separate
+    assert len(set(times)) == 3
+    assert all(frozen_at_ms <= time <= frozen_at_ms + 10 for time in times)
+
+
+def test_clock_errors():
+    with pytest.raises(ValueError, match="can't be moved backwards"):
+        pw.demo.advance_clock(datetime.timedelta(seconds=-1))
+    with pytest.raises(ValueError, match="speedup has to be a positive finite number"):
+        pw.demo.accelerate_clock(0.0)
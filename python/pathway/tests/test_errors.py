@@ -1602,6 +1602,35 @@ def test_global_error_first_operator():
     )
 
 
+def test_error_log_run_id(monkeypatch):
+    monkeypatch.setenv("PATHWAY_RUN_ID", "test-run")
+    t1 = T(
+        """
+        a | b
+        1 | 0
+    """
+    )
+    res = t1.select(x=pw.fill_error(pw.this.a // pw.this.b, -1))
+    expected = T(
+        """
+        x
+        -1
+    """
+    )
+    expected_errors = T(
+        """
+        message          | run_id
+        division by zero | test-run
+    """,
+        split_on_whitespace=False,
+    )
+    assert_table_equality_wo_index(
+        (res, pw.global_error_log().select(pw.this.message, pw.this.run_id)),
+        (expected, expected_errors),
+        terminate_on_error=False,
+    )
+
+
 def test_clear():
     t1 = T(
         """
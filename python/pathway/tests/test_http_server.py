@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import json
 import os
 import time
 import urllib
@@ -64,3 +65,69 @@ def test_http_server_doesnt_run_when_disabled():
         G, with_http_server=False, monitoring_level=pw.MonitoringLevel.NONE
     ).run_tables(response_code)[0]
     assert updates_stream[0].values[0] == -1
+
+
+def served_pets(query: str, max_retries: int = 6) -> str:
+    port = os.environ.get("PATHWAY_MONITORING_HTTP_PORT", "20000")
+
+    for n_attempt in range(max_retries):
+        time.sleep(2**n_attempt * 0.1)
+        try:
+            with urllib.request.urlopen(
+                f"http://localhost:{port}/tables/pets{query}"
+            ) as response:
+                body = json.loads(response.read())
+        except urllib.error.URLError:
+            continue
+        if body["rows"]:
+            return ",".join(sorted(row["pet"] for row in body["rows"]))
+    return ""
+
+
+@pytest.mark.xdist_group(name="http_server_tests")
+def test_http_server_serves_tables():
+    pets = T(
+        """
+            | owner | pet
+        1   | Alice | dog
+        2   | Bob   | cat
+        3   | Alice | cat
+        """
+    )
+    pw.io.http.serve_table(pets, "pets")
+    queries = T(
+        """
+            | query
+        1   | ?owner=Alice
+        2   | ?owner=Bob
+        """
+    )
+
+    result = queries.select(pets=pw.apply_async(served_pets, queries.query))
+
+    updates_stream = graph_runner.GraphRunner(
+        G, with_http_server=True, monitoring_level=pw.MonitoringLevel.NONE
+    ).run_tables(result)[0]
+    assert sorted(update.values[0] for update in updates_stream) == ["cat", "cat,dog"]
+
+
+def test_serve_table_rejects_invalid_routes():
+    pets = T(
+        """
+            | owner | pet
+        1   | Alice | dog
+        """
+    )
+    with pytest.raises(ValueError, match="invalid route"):
+        pw.io.http.serve_table(pets, "pets/all")
+
+
+def test_serve_websocket_rejects_invalid_names():
+    pets = T(
+        """
+            | owner | pet
+        1   | Alice | dog
+        """
+    )
+    with pytest.raises(ValueError, match="invalid name"):
+        pw.io.http.serve_websocket(pets, "")
@@ -0,0 +1,101 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+import time
+from unittest import mock
+
+import pytest
+
+import pathway as pw
+from pathway.io.bigquery import _OutputBuffer
+from pathway.tests.utils import T
+
+
+def _make_buffer(mock_bigquery_module, **kwargs):
+    with mock.patch.dict(
+        "sys.modules",
+        {
+            "google": mock.MagicMock(),
+            "google.cloud": mock.MagicMock(bigquery=mock_bigquery_module),
+            "google.cloud.bigquery": mock_bigquery_module,
+        },
+    ):
+        return _OutputBuffer("dataset", "table", None, **kwargs)
+
+
+def _inserted_batches(mock_bigquery_module) -> list[list[dict]]:
+    client = mock_bigquery_module.Client.return_value
+    return [call.args[1] for call in client.insert_rows_json.call_args_list]
+
+
+def _changes(values: list[int]):
+    return [(mock.MagicMock(), {"value": value}, True) for value in values]
+
+
+def test_minibatches_split_by_max_batch_size():
+    mock_bigquery_module = mock.MagicMock()
+    mock_bigquery_module.Client.return_value.insert_rows_json.return_value = []
+    output_buffer = _make_buffer(mock_bigquery_module, max_batch_size=2)
+
+    output_buffer.on_batch(changes=_changes([1, 2, 3]), time=2)
+    output_buffer.on_end()
+
+    assert _inserted_batches(mock_bigquery_module) == [
+        [{"value": 1, "time": 2, "diff": 1}, {"value": 2, "time": 2, "diff": 1}],
+        [{"value": 3, "time": 2, "diff": 1}],
+    ]
+
+
+def test_minibatches_coalesced_within_linger():
+    mock_bigquery_module = mock.MagicMock()
+    mock_bigquery_module.Client.return_value.insert_rows_json.return_value = []
+    output_buffer = _make_buffer(mock_bigquery_module, linger_ms=100)
+
+    output_buffer.on_batch(changes=_changes([1]), time=2)
+    output_buffer.on_batch(changes=_changes([2]), time=4)
+    assert _inserted_batches(mock_bigquery_module) == []
+
+    deadline = time.monotonic() + 10
+    while not _inserted_batches(mock_bigquery_module):
+        assert time.monotonic() < deadline, "the rows haven't been inserted"
+        time.sleep(0.01)
+    assert _inserted_batches(mock_bigquery_module) == [
+        [{"value": 1, "time": 2, "diff": 1}, {"value": 2, "time": 4, "diff": 1}],
+    ]
+
+
+def test_failed_rows_inserted_with_next_batch():
+    mock_bigquery_module = mock.MagicMock()
+    client = mock_bigquery_module.Client.return_value
+    client.insert_rows_json.side_effect = [[{"error": "unavailable"}], []]
+    output_buffer = _make_buffer(mock_bigquery_module)
+
+    output_buffer.on_batch(changes=_changes([1]), time=2)
+    output_buffer.on_batch(changes=_changes([2]), time=4)
+
+    assert _inserted_batches(mock_bigquery_module)[-1] == [
+        {"value": 1, "time": 2, "diff": 1},
+        {"value": 2, "time": 4, "diff": 1},
+    ]
+
+
+@pytest.mark.parametrize(
+    "options",
+    [dict(max_batch_size=0), dict(linger_ms=-1)],
+)
+def test_batching_options_validated(options):
+    table = T(
+        """
+        value
+        1
+        """
+    )
+    with pytest.raises(ValueError):
+        pw.io.bigquery.write(
+            table,
+            dataset_name="dataset",
+            table_name="table",
+            service_user_credentials_file="credentials.json",
+            **options,
+        )
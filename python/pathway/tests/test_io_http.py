@@ -0,0 +1,223 @@
+# Copyright © 2026 Pathway
+
+from __future__ import annotations
+
+import gzip
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+
+import pytest
+
+import pathway as pw
+from pathway.io.http._common import Sender
+from pathway.tests.utils import T, run
+
+
+@pytest.fixture
+def webhook_server():
+    received = []
+    failures_left = {"/flaky": 2}
+
+    class Handler(BaseHTTPRequestHandler):
+        def do_POST(self):
+            body = self.rfile.read(int(self.headers["Content-Length"]))
+            if failures_left.get(self.path, 0) > 0:
+                failures_left[self.path] -= 1
+                self.send_response(503)
+            else:
+                received.append((self.path, dict(self.headers), body.decode()))
+                self.send_response(200)
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+
+        def log_message(self, *args):
+            pass
+
+    server = ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_port}", received
+    server.shutdown()
+
+
+@pytest.fixture
+def sent_requests(monkeypatch):
+    requests = []
+
+    def send(self, url, headers=None, data=None, stream=False):
+        requests.append((url, headers, data))
+
+    monkeypatch.setattr(Sender, "send", send)
+    return requests
+
+
+def test_http_write_ndjson_batches(sent_requests):
+    table = T(
+        """
+        owner | pet
+        Alice | dog
+        Bob   | cat
+        Alice | cat
+        """
+    )
+    pw.io.http.write(
+        table,
+        "http://localhost/{table.owner}",
+        batch_format="ndjson",
+        max_batch_size=1,
+    )
+    run()
+
+    assert sorted(url for url, _, _ in sent_requests) == [
+        "http://localhost/Alice",
+        "http://localhost/Alice",
+        "http://localhost/Bob",
+    ]
+    for _, headers, data in sent_requests:
+        assert headers["Content-Type"] == "application/x-ndjson"
+        assert data.endswith("\n")
+        assert len(data.splitlines()) == 1
+
+
+def test_http_write_json_array_gzip(sent_requests):
+    table = T(
+        """
+        owner | pet
+        Alice | dog
+        Bob   | cat
+        """
+    )
+    pw.io.http.write(
+        table,
+        "http://localhost/events",
+        batch_format="json_array",
+        compression="gzip",
+    )
+    run()
+
+    assert len(sent_requests) == 1
+    _, headers, data = sent_requests[0]
+    assert headers["Content-Type"] == "application/json"
+    assert headers["Content-Encoding"] == "gzip"
+    rows = json.loads(gzip.decompress(data))
+    assert sorted((row["owner"], row["pet"], row["diff"]) for row in rows) == [
+        ("Alice", "dog", 1),
+        ("Bob", "cat", 1),
+    ]
+
+
+def test_http_write_custom_content_type(sent_requests):
+    table = T(
+        """
+        owner | pet
+        Alice | dog
+        """
+    )
+    pw.io.http.write(
+        table,
+        "http://localhost/events",
+        content_type="application/vnd.pets+json",
+        compression="gzip",
+    )
+    run()
+
+    [(_, headers, data)] = sent_requests
+    assert headers["Content-Type"] == "application/vnd.pets+json"
+    assert json.loads(gzip.decompress(data))["pet"] == "dog"
+
+
+def test_http_write_batching_requires_json_format():
+    table = T(
+        """
+        owner | pet
+        Alice | dog
+        """
+    )
+    with pytest.raises(ValueError, match="batch_format"):
+        pw.io.http.write(
+            table,
+            "http://localhost/events",
+            format="custom",
+            request_payload_template="{table.pet}",
+            batch_format="ndjson",
+        )
+    with pytest.raises(ValueError, match="max_batch_size"):
+        pw.io.http.write(table, "http://localhost/events", max_batch_size=10)
+
+
+def test_webhook_write_templated_requests(webhook_server):
+    url, received = webhook_server
+    table = T(
+        """
+        team     | severity | message
+        payments | critical | slow
+        search   | warning  | lagging
+        """
+    )
+    pw.io.webhook.write(
+        table,
+        url + "/teams/{{ team }}",
+        headers={"X-Severity": "{{ severity | upper }}"},
+    )
+    run()
+
+    assert sorted(
+        (path, headers["X-Severity"], json.loads(body)["message"])
+        for path, headers, body in received
+    ) == [
+        ("/teams/payments", "CRITICAL", "slow"),
+        ("/teams/search", "WARNING", "lagging"),
+    ]
+    for _, headers, _ in received:
+        assert headers["Content-Type"] == "application/json"
+
+
+def test_webhook_write_batches_with_retries(webhook_server):
+    url, received = webhook_server
+    table = T(
+        """
+        owner | pet
+        Alice | dog
+        Bob   | cat
+        Alice | cat
+        """
+    )
+    pw.io.webhook.write(
+        table,
+        url + "/flaky",
+        format="template",
+        template="{{ owner }}:{{ pet }}",
+        batch_format="ndjson",
+        max_batch_size=2,
+        retry_policy=pw.io.http.RetryPolicy(
+            first_delay_ms=10, backoff_factor=1.0, jitter_ms=0
+        ),
+    )
+    run()
+
+    assert [len(body.splitlines()) for _, _, body in received] == [2, 1]
+    lines = [line for _, _, body in received for line in body.splitlines()]
+    assert sorted(lines) == ["Alice:cat", "Alice:dog", "Bob:cat"]
+    assert received[0][1]["Content-Type"] == "application/x-ndjson"
+
+
+def test_webhook_write_argument_validation():
+    table = T(
+        """
+        owner | pet
+        Alice | dog
+        """
+    )
+    with pytest.raises(ValueError, match="template"):
+        pw.io.webhook.write(table, "http://localhost/events", format="template")
+    with pytest.raises(ValueError, match="json_array"):
+        pw.io.webhook.write(
+            table,
+            "http://localhost/events",
+            format="template",
+            template="{{ pet }}",
+            batch_format="json_array",
+        )
+    with pytest.raises(ValueError, match="max_batch_size"):
+        pw.io.webhook.write(table, "http://localhost/events", max_batch_size=10)
@@ -1648,3 +1648,37 @@ def test_inner_join_exactly_once_with_repeats():
 
     with pytest.raises(ValueError, match=re.escape("Repeated entry in a batch.")):
         run_all()
+
+
+@pytest.mark.parametrize("how", [pw.JoinMode.INNER, pw.JoinMode.OUTER])
+def test_join_shard_by(how: pw.JoinMode):
+    left = T(
+        """
+        tenant | user | a
+             1 |    1 | 11
+             1 |    2 | 12
+             2 |    1 | 13
+        """
+    )
+    right = T(
+        """
+        tenant | user | b
+             1 |    1 | 21
+             2 |    1 | 22
+             2 |    2 | 23
+        """
+    )
+
+    def join(**kwargs):
+        return left.join(
+            right,
+            left.tenant == right.tenant,
+            left.user == right.user,
+            how=how,
+            **kwargs,
+        ).select(pw.left.a, pw.right.b)
+
+    assert_table_equality_wo_index(join(shard_by=left.tenant), join())
+    assert_table_equality_wo_index(join(shard_by=pw.right.tenant), join())
+    with pytest.raises(ValueError, match="shard_by argument has to be a column"):
+        join(shard_by=left.a)
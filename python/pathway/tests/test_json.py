@@ -13,6 +13,7 @@ import pytest
 
 import pathway as pw
 from pathway.debug import table_from_pandas
+from pathway.internals import dtype as dt
 from pathway.internals.column import G
 from pathway.tests.utils import (
     T,
@@ -1337,3 +1338,43 @@ def test_json_doesnt_enforce_ascii(capsys):
 
     captured = capsys.readouterr()
     assert '{"data": "szczęśliwość"}' in captured.out
+
+
+def test_json_query():
+    input = _json_table(
+        data=[
+            {"items": [{"name": "a", "price": 5}, {"name": "b", "price": 12}]},
+            {"items": [{"name": "c", "price": 20}, {"name": "d", "price": 30}]},
+            {"items": []},
+        ]
+    )
+
+    result = input.select(
+        names=pw.this.data.json_query("$.items[?@.price > 10].name"),
+        prices=pw.this.data.json_query("$..price"),
+    )
+
+    df = pw.debug.table_to_pandas(result)
+    names = sorted(tuple(name.as_str() for name in row) for row in df["names"])
+    assert names == [(), ("b",), ("c", "d")]
+    prices = sorted(sorted(price.as_int() for price in row) for row in df["prices"])
+    assert prices == [[], [5, 12], [20, 30]]
+
+
+def test_json_query_optional():
+    input = _optional_json_table(data=[{"a": 1}, None])
+
+    result = input.select(ret=pw.this.data.json_query("$.a"))
+
+    assert result.schema._dtypes() == {"ret": dt.Optional(dt.List(dt.JSON))}
+    df = pw.debug.table_to_pandas(result)
+    assert sorted(df["ret"], key=lambda row: row is None) == [(pw.Json(1),), None]
+
+
+def test_json_query_invalid_path():
+    input = _json_table(data=[{"a": 1}])
+
+    result = input.select(ret=pw.this.data.json_query("$[?"))
+
+    with pytest.raises(ValueError, match="invalid JSONPath query"):
+        pw.debug.table_to_pandas(result)
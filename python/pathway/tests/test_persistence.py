@@ -1,6 +1,7 @@
 # Copyright © 2026 Pathway
 
 import asyncio
+import csv
 import json
 import multiprocessing
 import os
@@ -11,8 +12,9 @@ from typing import Callable
 import pytest
 
 import pathway as pw
-from pathway.internals import api
+from pathway.internals import api, graph_runner
 from pathway.internals.api import SessionType
+from pathway.internals.graph_runner.operator_identity import stable_operator_ids
 from pathway.internals.parse_graph import G
 from pathway.tests.utils import (
     CsvPathwayChecker,
@@ -581,6 +583,64 @@ def test_groupby(tmp_path, mode):
     run(["a,b,c", "2,0,3"], {"2,2,9,5,1,-1", "2,3,9,5,2,1"})
 
 
+@pytest.mark.parametrize("mode", [api.PersistenceMode.OPERATOR_PERSISTING])
+@only_with_license_key("mode", [api.PersistenceMode.OPERATOR_PERSISTING])
+def test_operator_state_kept_after_inserting_unrelated_operator(tmp_path, mode):
+    class InputSchema(pw.Schema):
+        a: int
+        b: int
+
+    inserts_operator = False
+
+    def logic(t_1: pw.Table) -> pw.Table:
+        if inserts_operator:
+            # built before the reduction, it would shift the names of the states
+            # numbered in the order the operators are built
+            counts = t_1.filter(pw.this.b > 0).groupby(pw.this.b).reduce(
+                pw.this.b, c=pw.reducers.count()
+            )
+            pw.io.null.write(counts)
+        return t_1.groupby(pw.this.a).reduce(pw.this.a, s=pw.reducers.sum(pw.this.b))
+
+    run, _ = get_one_table_runner(tmp_path, mode, logic, InputSchema)
+
+    run(["a,b", "1,3", "2,4"], {"1,3,1", "2,4,1"})
+    inserts_operator = True
+    run(["a,b", "1,1"], {"1,3,-1", "1,4,1"})
+
+
+def test_stable_operator_ids_depend_on_what_operators_compute():
+    def stable_id(build: Callable[[pw.Table], pw.Table]) -> str:
+        G.clear()
+        table = pw.debug.table_from_markdown(
+            """
+            a | b
+            1 | 2
+            """
+        )
+        result = build(table)
+        return stable_operator_ids(G.global_scope.nodes)[result._source.operator]
+
+    def reduction(grouping: str, reducer, name: str = "s") -> Callable:
+        def build(table: pw.Table) -> pw.Table:
+            return table.groupby(pw.this[grouping]).reduce(
+                pw.this[grouping], **{name: reducer(pw.this.b)}
+            )
+
+        return build
+
+    sum_by_a = reduction("a", pw.reducers.sum)
+    base_id = stable_id(sum_by_a)
+    assert stable_id(sum_by_a) == base_id
+    assert stable_id(reduction("a", pw.reducers.max)) != base_id
+    assert stable_id(reduction("b", pw.reducers.sum)) != base_id
+    assert stable_id(reduction("a", pw.reducers.sum, name="c")) != base_id
+    # the operators reading a differently computed table are different too
+    assert stable_id(lambda t: sum_by_a(t.filter(t.b > 0))) != stable_id(
+        lambda t: sum_by_a(t.filter(t.b > 1))
+    )
+
+
 @pytest.mark.parametrize(
     "mode", [api.PersistenceMode.OPERATOR_PERSISTING]
 )  # can't use api.PersistenceMode.PERSISTING because it is not compatible with stateful_reduce
@@ -1300,3 +1360,240 @@ def test_ix_two_sources(tmp_path, mode):
     # Run 3: right-side row modified
     _rewrite_file(bonus_path / "1.csv", ["label,add", "a,90", "b,50"])
     do_run({"a,40,-1", "a,100,1"})
+
+
+def test_savepoint(tmp_path):
+    output_path = tmp_path / "out.csv"
+    persistent_storage_path = tmp_path / "p"
+    savepoints = []
+
+    class InputSchema(pw.Schema):
+        a: int
+
+    class InputSubject(pw.io.python.ConnectorSubject):
+        def run(self) -> None:
+            self.next(a=1)
+            self.commit()
+            # let the row reach the output before committing the state
+            time.sleep(1)
+            savepoints.append(pw.persistence.savepoint(timeout=30))
+
+    G.clear()
+    res = pw.io.python.read(InputSubject(), schema=InputSchema)
+    pw.io.csv.write(res, output_path)
+    run(
+        persistence_config=pw.persistence.Config(
+            pw.persistence.Backend.filesystem(persistent_storage_path),
+            # without the savepoint, nothing would be committed before the end
+            snapshot_interval_ms=3_600_000,
+        )
+    )
+    assert len(savepoints) == 1
+    assert savepoints[0] > 0
+
+
+def test_savepoint_without_persistence():
+    with pytest.raises(RuntimeError, match="no computation with persistence"):
+        pw.persistence.savepoint(timeout=1)
+
+
+def test_graceful_shutdown(tmp_path):
+    inputs_path = tmp_path / "inputs"
+    inputs_path.mkdir()
+    persistent_storage_path = tmp_path / "p"
+
+    def run_computation(new_line: str, output_path: pathlib.Path) -> None:
+        write_lines(inputs_path / f"{new_line}.txt", new_line)
+
+        class ShutdownSubject(pw.io.python.ConnectorSubject):
+            def run(self) -> None:
+                deadline = time.monotonic() + 30
+                while (
+                    not output_path.exists() or new_line not in output_path.read_text()
+                ):
+                    assert time.monotonic() < deadline
+                    time.sleep(0.1)
+                pw.request_shutdown()
+
+        class InputSchema(pw.Schema):
+            a: int
+
+        G.clear()
+        lines = pw.io.plaintext.read(
+            inputs_path, mode="streaming", autocommit_duration_ms=10, name="lines"
+        )
+        pw.io.csv.write(lines, output_path)
+        pw.io.null.write(pw.io.python.read(ShutdownSubject(), schema=InputSchema))
+        # without the shutdown, the streaming computation would never finish
+        run(
+            persistence_config=pw.persistence.Config(
+                pw.persistence.Backend.filesystem(persistent_storage_path),
+                # the state is committed by the shutdown only
+                snapshot_interval_ms=3_600_000,
+            )
+        )
+
+    run_computation("a", tmp_path / "out_1.csv")
+    assert_sets_equality_from_path(tmp_path / "out_1.csv", {"a,1"})
+    # the restarted computation continues where the stopped one has ended
+    run_computation("b", tmp_path / "out_2.csv")
+    assert_sets_equality_from_path(tmp_path / "out_2.csv", {"b,1"})
+
+
+def test_request_shutdown_without_computation():
+    with pytest.raises(RuntimeError, match="no computation is running"):
+        pw.request_shutdown()
+
+
+def test_suspend(tmp_path):
+    inputs_path = tmp_path / "inputs"
+    inputs_path.mkdir()
+    persistent_storage_path = tmp_path / "p"
+
+    def run_computation(new_values: list[int], output_path: pathlib.Path) -> None:
+        for value in new_values:
+            write_lines(inputs_path / f"{value}.txt", str(value))
+
+        def all_values_written() -> bool:
+            if not output_path.exists():
+                return False
+            written = {line.split(",")[0] for line in output_path.read_text().split()}
+            return all(str(value) in written for value in new_values)
+
+        class SuspendSubject(pw.io.python.ConnectorSubject):
+            def run(self) -> None:
+                deadline = time.monotonic() + 30
+                while not all_values_written():
+                    assert time.monotonic() < deadline
+                    time.sleep(0.1)
+                pw.persistence.suspend()
+
+        class InputSchema(pw.Schema):
+            a: int
+
+        G.clear()
+        lines = pw.io.plaintext.read(
+            inputs_path, mode="streaming", autocommit_duration_ms=10, name="lines"
+        )
+        total = lines.reduce(total=pw.reducers.sum(pw.this.data.str.parse_int()))
+        pw.io.csv.write(lines, output_path)
+        pw.io.csv.write(total, tmp_path / f"total_{output_path.name}")
+        pw.io.null.write(pw.io.python.read(SuspendSubject(), schema=InputSchema))
+        run(
+            persistence_config=pw.persistence.Config(
+                pw.persistence.Backend.filesystem(persistent_storage_path),
+                snapshot_interval_ms=3_600_000,
+                persistence_mode=pw.PersistenceMode.OPERATOR_PERSISTING,
+            )
+        )
+
+    run_computation([1, 2], tmp_path / "out_1.csv")
+    assert_sets_equality_from_path(tmp_path / "out_1.csv", {"1,1", "2,1"})
+    # the resumed computation neither rereads the old data nor loses the state
+    run_computation([3], tmp_path / "out_2.csv")
+    assert_sets_equality_from_path(tmp_path / "out_2.csv", {"3,1"})
+    with open(tmp_path / "total_out_2.csv") as f:
+        totals = [row["total"] for row in csv.DictReader(f) if row["diff"] == "1"]
+    assert totals == ["6"]
+
+
+def test_suspend_without_persistence():
+    class SuspendSubject(pw.io.python.ConnectorSubject):
+        def run(self) -> None:
+            with pytest.raises(RuntimeError, match="it can't be suspended"):
+                pw.persistence.suspend()
+
+    class InputSchema(pw.Schema):
+        a: int
+
+    pw.io.null.write(pw.io.python.read(SuspendSubject(), schema=InputSchema))
+    run()
+
+
+def test_gc_removes_metadata_of_former_runs(tmp_path):
+    persistent_storage_path = tmp_path / "p"
+    output_path = tmp_path / "out.csv"
+
+    class InputSchema(pw.Schema):
+        a: int
+
+    def run_computation(value):
+        class InputSubject(pw.io.python.ConnectorSubject):
+            def run(self) -> None:
+                self.next(a=value)
+                self.commit()
+                # let the checkpoint become durable and the garbage collector run
+                time.sleep(1)
+
+        G.clear()
+        res = pw.io.python.read(InputSubject(), schema=InputSchema, name="input")
+        pw.io.csv.write(res, output_path)
+        run(
+            persistence_config=pw.persistence.Config(
+                pw.persistence.Backend.filesystem(persistent_storage_path),
+                gc_interval_ms=10,
+            )
+        )
+
+    def metadata_versions():
+        return {
+            path.name.split("-")[0]
+            for path in persistent_storage_path.iterdir()
+            if path.is_file() and path.name.count("-") == 2
+        }
+
+    run_computation(1)
+    assert len(metadata_versions()) == 1
+    run_computation(2)
+    assert len(metadata_versions()) == 1
+    assert_sets_equality_from_path(output_path, {"2,1"})
+
+
+def test_lease_released_after_run(tmp_path):
+    persistent_storage_path = tmp_path / "p"
+    output_path = tmp_path / "out.csv"
+
+    class InputSchema(pw.Schema):
+        a: int
+
+    def run_computation(value):
+        class InputSubject(pw.io.python.ConnectorSubject):
+            def run(self) -> None:
+                self.next(a=value)
+
+        G.clear()
+        res = pw.io.python.read(InputSubject(), schema=InputSchema, name="input")
+        pw.io.csv.write(res, output_path)
+        run(
+            persistence_config=pw.persistence.Config(
+                pw.persistence.Backend.filesystem(persistent_storage_path),
+                # a crashed run would block the next one for an hour
+                lock_ttl_ms=3_600_000,
+            )
+        )
+
+    run_computation(1)
+    assert not (persistent_storage_path / "locks" / "lease").exists()
+    # the lease of the first run doesn't block the second one
+    run_computation(2)
+    assert_sets_equality_from_path(output_path, {"2,1"})
+
+
+def test_run_id_derived_from_persistent_storage(tmp_path, monkeypatch):
+    monkeypatch.delenv("PATHWAY_RUN_ID", raising=False)
+
+    def run_id(path):
+        persistence_config = pw.persistence.Config(
+            pw.persistence.Backend.filesystem(path)
+        )
+        return graph_runner.GraphRunner(
+            G, persistence_config=persistence_config
+        )._get_run_id()
+
+    # a restarted pipeline keeps its run id, other pipelines get other ids
+    assert run_id(tmp_path / "p") == run_id(tmp_path / "p")
+    assert run_id(tmp_path / "p") != run_id(tmp_path / "q")
+    assert graph_runner.GraphRunner(G)._get_run_id() != run_id(tmp_path / "p")
+
+    monkeypatch.setenv("PATHWAY_RUN_ID", "test-run")
+    assert run_id(tmp_path / "p") == "test-run"
@@ -0,0 +1,136 @@
+# Copyright © 2026 Pathway
+
+import pathlib
+
+import pytest
+
+import pathway as pw
+from pathway.internals.parse_graph import G
+from pathway.tests.utils import T, assert_stream_equality
+
+
+def test_row_versions():
+    t = pw.debug.table_from_markdown(
+        """
+        id | owner | pet | __time__ | __diff__
+         1 | Alice | dog |     2    |     1
+         2 | Bob   | dog |     2    |     1
+         1 | Alice | dog |     4    |    -1
+         1 | Alice | cat |     4    |     1
+         1 | Alice | cat |     6    |    -1
+         1 | Alice | eel |     6    |     1
+        """
+    )
+    expected = pw.debug.table_from_markdown(
+        """
+        id | owner | pet | version | __time__ | __diff__
+         1 | Alice | dog |    1    |     2    |     1
+         2 | Bob   | dog |    1    |     2    |     1
+         1 | Alice | dog |    1    |     4    |    -1
+         1 | Alice | cat |    2    |     4    |     1
+         1 | Alice | cat |    2    |     6    |    -1
+         1 | Alice | eel |    3    |     6    |     1
+        """
+    )
+    assert_stream_equality(t.with_row_versions(), expected)
+
+
+def test_row_versions_grow_after_deletion():
+    t = pw.debug.table_from_markdown(
+        """
+        id | pet | __time__ | __diff__
+         1 | dog |     2    |     1
+         1 | dog |     4    |    -1
+         1 | dog |     6    |     1
+        """
+    )
+    expected = pw.debug.table_from_markdown(
+        """
+        id | pet | v | __time__ | __diff__
+         1 | dog | 1 |     2    |     1
+         1 | dog | 1 |     4    |    -1
+         1 | dog | 3 |     6    |     1
+        """
+    )
+    assert_stream_equality(t.with_row_versions("v"), expected)
+
+
+def test_row_versions_only_count_table_columns():
+    t = pw.debug.table_from_markdown(
+        """
+        id | owner | pet | __time__ | __diff__
+         1 | Alice | dog |     2    |     1
+         1 | Alice | dog |     4    |    -1
+         1 | Alice | cat |     4    |     1
+        """
+    )
+    # the changes of the columns that are not in the table don't create new versions
+    owners = t.select(pw.this.owner)
+    expected = pw.debug.table_from_markdown(
+        """
+        id | owner | version | __time__ | __diff__
+         1 | Alice |    1    |     2    |     1
+        """
+    )
+    assert_stream_equality(owners.with_row_versions(), expected)
+
+
+def test_row_versions_column_already_present():
+    t = T(
+        """
+        version
+           1
+        """
+    )
+    with pytest.raises(ValueError, match="Column 'version' is already present"):
+        t.with_row_versions()
+
+
+def test_row_versions_keep_state(tmp_path: pathlib.Path):
+    persistence_config = pw.persistence.Config(
+        pw.persistence.Backend.filesystem(tmp_path / "persistence")
+    )
+    data_1 = """
+        id | pet | __time__ | __diff__
+         1 | dog |     2    |     1
+         1 | dog |     4    |    -1
+         1 | cat |     4    |     1
+    """
+    # values with __time__ == 0 simulate the input persistence of a regular connector
+    data_2 = """
+        id | pet  | __time__ | __diff__
+         1 | cat  |     0    |     1
+         1 | cat  |     6    |    -1
+         1 | fish |     6    |     1
+    """
+
+    t = pw.debug.table_from_markdown(data_1)
+    expected_1 = pw.debug.table_from_markdown(
+        """
+        id | pet | version | __time__ | __diff__
+         1 | dog |    1    |     2    |     1
+         1 | dog |    1    |     4    |    -1
+         1 | cat |    2    |     4    |     1
+        """
+    )
+    assert_stream_equality(
+        t.with_row_versions(name="versions"),
+        expected_1,
+        persistence_config=persistence_config,
+    )
+    G.clear()
+
+    t = pw.debug.table_from_markdown(data_2)
+    expected_2 = pw.debug.table_from_markdown(
+        """
+        id | pet  | version | __time__ | __diff__
+         1 | cat  |    2    |     0    |     1
+         1 | cat  |    2    |     6    |    -1
+         1 | fish |    3    |     6    |     1
+        """
+    )
+    assert_stream_equality(
+        t.with_row_versions(name="versions"),
+        expected_2,
+        persistence_config=persistence_config,
+    )
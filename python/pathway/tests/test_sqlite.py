@@ -1623,3 +1623,30 @@ def test_sqlite_read_schema_primary_key_duplicate_keys_surface_per_row_error(
     with open(output_path) as f:
         rows = [json.loads(line) for line in f]
     assert [(r["k"], r["v"]) for r in rows] == [(1, "alpha")]
+
+
+def test_sqlite_dry_run_keeps_the_existing_table(tmp_path: pathlib.Path):
+    db = tmp_path / "existing.db"
+    connection = sqlite3.connect(db)
+    connection.execute("CREATE TABLE t (k INTEGER, v TEXT)")
+    connection.execute("INSERT INTO t VALUES (1, 'kept')")
+    connection.commit()
+    connection.close()
+
+    table = pw.debug.table_from_markdown(
+        """
+        a
+        1
+        """
+    )
+    pw.io.sqlite.write(table, db, "t", init_mode="replace")
+    report = pw.run(monitoring_level=pw.MonitoringLevel.NONE, dry_run=True)
+
+    assert report is not None
+    assert report["ok"]
+    connection = sqlite3.connect(db)
+    columns = [row[1] for row in connection.execute("PRAGMA table_info(t)")]
+    rows = connection.execute("SELECT k, v FROM t").fetchall()
+    connection.close()
+    assert columns == ["k", "v"]
+    assert rows == [(1, "kept")]
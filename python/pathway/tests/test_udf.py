@@ -2090,6 +2090,58 @@ def test_udf_cache_directory_multiple_processes(
     assert list(cache_dir.glob("*.sqlite")) == []
 
 
+@xfail_on_multiple_threads
+@pytest.mark.parametrize(
+    "cache_size,deterministic,expected_counts",
+    [
+        (0, True, {1: 4, 2: 2}),
+        (16, True, {1: 1, 2: 1}),
+        (1, True, {1: 3, 2: 2}),
+        (16, False, {1: 4, 2: 2}),
+    ],
+)
+def test_deterministic_expression_cache(
+    cache_size: int, deterministic: bool, expected_counts: dict[int, int]
+) -> None:
+    counts: dict[int, int] = {}
+
+    @pw.udf(deterministic=deterministic)
+    def f(a: int) -> int:
+        counts[a] = counts.get(a, 0) + 1
+        return 10 * a
+
+    input = T(
+        """
+        a | __time__
+        1 |     2
+        2 |     4
+        1 |     6
+        1 |     8
+        2 |    10
+        1 |    12
+        """
+    )
+
+    result = input.select(ret=f(pw.this.a))
+
+    assert_table_equality_wo_index(
+        result,
+        T(
+            """
+            ret
+            10
+            20
+            10
+            10
+            20
+            10
+            """
+        ),
+        deterministic_expression_cache_size=cache_size,
+    )
+    assert counts == expected_counts
+
+
 def test_run_async_in_thread_returns_result():
     async def coroutine():
         return 42
@@ -2110,3 +2162,39 @@ def test_run_async_in_thread_reraises_exception():
             _run_async(coroutine())
 
     asyncio.run(main())
+
+
+def test_udf_with_user_metrics():
+    t = T(
+        """
+        a
+        1
+        2
+        3
+        """
+    )
+
+    @pw.udf
+    def double(a: int) -> int:
+        pw.metrics.increment_counter("test.doubled")
+        pw.metrics.record_histogram("test.doubled.input", a)
+        return 2 * a
+
+    result = t.select(a=double(pw.this.a))
+    expected = T(
+        """
+        a
+        2
+        4
+        6
+        """
+    )
+    assert_table_equality(result, expected)
+
+
+def test_user_metrics_invalid_names():
+    with pytest.raises(ValueError, match="invalid metric name"):
+        pw.metrics.increment_counter("1st.counter")
+    pw.metrics.increment_counter("test.user_metrics.kind")
+    with pytest.raises(ValueError, match="can't be used as a histogram"):
+        pw.metrics.record_histogram("test.user_metrics.kind", 1.0)
@@ -0,0 +1,619 @@
+// Copyright © 2026 Pathway
+
+//! A C ABI for building and running dataflow graphs, for the bindings of languages
+//! other than Python. The declarations are in `include/pathway_engine.h`.
+//!
+//! The graph is built by a callback passed to [`pw_run`], called once by each worker
+//! with a [`PwGraph`] valid for the duration of the call. The tables created within the
+//! callback are identified by their index, in the order of creation. Expressions are
+//! built bottom-up, each `pw_expression_*` function returning a new expression owned by
+//! the caller, and the functions taking expressions take their ownership.
+//!
+//! The functions returning a [`PwStatus`] return [`PW_ERROR`] on failure, and the
+//! message of the error can be then retrieved with [`pw_last_error_message`]. A panic
+//! within a function is caught and reported as a failure as well, or as NULL by the
+//! functions returning pointers.
+
+#![allow(clippy::missing_safety_doc)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::fs::File;
+use std::io::BufWriter;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::connectors::backlog::BacklogLimit;
+use crate::connectors::data_format::{
+    FieldSource, InnerSchemaField, JsonLinesFormatter, JsonLinesParser, ValueField,
+};
+use crate::connectors::data_storage::scanner::FilesystemScanner;
+use crate::connectors::data_storage::sharding::ShardSelector;
+use crate::connectors::data_storage::{ConnectorMode, FileWriter, ReadMethod};
+use crate::connectors::data_tokenize::BufReaderTokenizer;
+use crate::connectors::posix_like::PosixLikeReader;
+use crate::connectors::rate_limit::RateLimit;
+use crate::connectors::SessionType;
+use crate::engine::dataflow::config::Config;
+use crate::engine::error::{DynError, DynResult, Error, Trace};
+use crate::engine::graph::OutputWriters;
+use crate::engine::license::License;
+use crate::engine::lifecycle::RunCallbacks;
+use crate::engine::progress_reporter::MonitoringLevel;
+use crate::engine::telemetry::Config as TelemetryConfig;
+use crate::engine::{
+    run_with_new_dataflow_graph, AnyExpression, BoolExpression, ColumnPath, ColumnProperties,
+    Expression, ExpressionData, FloatExpression, Graph, IntExpression, Reducer, ReducerData,
+    RunLineage, ShardPolicy, StringExpression, TableHandle, TableProperties, Timestamp, Type,
+    Value,
+};
+
+pub type PwStatus = i32;
+pub const PW_OK: PwStatus = 0;
+pub const PW_ERROR: PwStatus = -1;
+
+pub type PwTable = usize;
+
+pub type PwBuildFn =
+    unsafe extern "C" fn(graph: *const PwGraph<'_>, user_data: *mut c_void) -> PwStatus;
+
+const PW_TYPE_ANY: u32 = 0;
+const PW_TYPE_BOOL: u32 = 1;
+const PW_TYPE_INT: u32 = 2;
+const PW_TYPE_FLOAT: u32 = 3;
+const PW_TYPE_STRING: u32 = 4;
+
+const PW_OP_ADD: u32 = 0;
+const PW_OP_SUB: u32 = 1;
+const PW_OP_MUL: u32 = 2;
+const PW_OP_EQ: u32 = 3;
+const PW_OP_NE: u32 = 4;
+const PW_OP_LT: u32 = 5;
+const PW_OP_LE: u32 = 6;
+const PW_OP_GT: u32 = 7;
+const PW_OP_GE: u32 = 8;
+
+const PW_REDUCER_COUNT: u32 = 0;
+const PW_REDUCER_INT_SUM: u32 = 1;
+const PW_REDUCER_FLOAT_SUM: u32 = 2;
+const PW_REDUCER_MIN: u32 = 3;
+const PW_REDUCER_MAX: u32 = 4;
+const PW_REDUCER_ANY: u32 = 5;
+
+const MAX_EXPRESSION_BATCH_SIZE: usize = 1024;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: &str) {
+    // the message is cut at the first NUL, as C strings can't contain it
+    let message = message.split('\0').next().unwrap_or_default();
+    let message = CString::new(message).expect("the message should not contain NUL");
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+fn take_last_error() -> Option<String> {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow_mut()
+            .take()
+            .map(|message| message.to_string_lossy().into_owned())
+    })
+}
+
+// Runs the body of an entry point, returning `on_panic` if it panics, as unwinding
+// into the C caller is undefined behavior.
+fn guard<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|panic_payload| {
+        set_last_error(&Error::from_panic_payload(panic_payload).to_string());
+        on_panic
+    })
+}
+
+fn status(body: impl FnOnce() -> DynResult<()>) -> PwStatus {
+    guard(PW_ERROR, || match body() {
+        Ok(()) => PW_OK,
+        Err(error) => {
+            set_last_error(&error.to_string());
+            PW_ERROR
+        }
+    })
+}
+
+/// Returns the message of the last error on the calling thread, or NULL if there was
+/// none. The message is valid until the next call of a function of this API on the
+/// same thread.
+#[no_mangle]
+pub extern "C" fn pw_last_error_message() -> *const c_char {
+    guard(ptr::null(), || {
+        LAST_ERROR.with(|last_error| {
+            last_error
+                .borrow()
+                .as_ref()
+                .map_or(ptr::null(), |message| message.as_ptr())
+        })
+    })
+}
+
+/// The graph being built by a worker, passed to the build callback of [`pw_run`].
+pub struct PwGraph<'a> {
+    graph: &'a dyn Graph,
+    tables: RefCell<Vec<TableHandle>>,
+    timestamp_at_start: Timestamp,
+}
+
+impl PwGraph<'_> {
+    fn table(&self, table: PwTable) -> DynResult<TableHandle> {
+        self.tables
+            .borrow()
+            .get(table)
+            .copied()
+            .ok_or_else(|| format!("unknown table {table}").into())
+    }
+
+    fn add_table(&self, handle: TableHandle) -> PwTable {
+        let mut tables = self.tables.borrow_mut();
+        tables.push(handle);
+        tables.len() - 1
+    }
+}
+
+/// An expression computing a value from the columns of a table.
+pub struct PwExpression(Arc<Expression>);
+
+struct UserData(*mut c_void);
+
+// The user data is passed to the build callback, called concurrently by all workers.
+// It's up to the caller to make it safe.
+unsafe impl Send for UserData {}
+unsafe impl Sync for UserData {}
+
+unsafe fn str_arg<'a>(ptr: *const c_char, name: &str) -> DynResult<&'a str> {
+    if ptr.is_null() {
+        return Err(format!("{name} must not be NULL").into());
+    }
+    Ok(CStr::from_ptr(ptr).to_str()?)
+}
+
+unsafe fn slice_arg<'a, T>(ptr: *const T, len: usize, name: &str) -> DynResult<&'a [T]> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if ptr.is_null() {
+        return Err(format!("{name} must not be NULL").into());
+    }
+    Ok(slice::from_raw_parts(ptr, len))
+}
+
+unsafe fn graph_arg<'a, 'b>(graph: *const PwGraph<'b>) -> DynResult<&'a PwGraph<'b>> {
+    graph
+        .as_ref()
+        .ok_or_else(|| DynError::from("graph must not be NULL"))
+}
+
+unsafe fn write_out<T>(out: *mut T, value: T) -> DynResult<()> {
+    if out.is_null() {
+        return Err("the output pointer must not be NULL".into());
+    }
+    out.write(value);
+    Ok(())
+}
+
+fn type_from_c(type_: u32) -> DynResult<Type> {
+    match type_ {
+        PW_TYPE_ANY => Ok(Type::Any),
+        PW_TYPE_BOOL => Ok(Type::Bool),
+        PW_TYPE_INT => Ok(Type::Int),
+        PW_TYPE_FLOAT => Ok(Type::Float),
+        PW_TYPE_STRING => Ok(Type::String),
+        _ => Err(format!("unknown type {type_}").into()),
+    }
+}
+
+fn column_properties(dtype: Type) -> Arc<ColumnProperties> {
+    Arc::new(ColumnProperties {
+        dtype,
+        append_only: false,
+        trace: Arc::new(Trace::Empty),
+    })
+}
+
+fn table_properties(types: impl IntoIterator<Item = Type>) -> Arc<TableProperties> {
+    let column_properties = types.into_iter().map(column_properties).collect();
+    Arc::new(TableProperties::flat(column_properties))
+}
+
+fn column_paths(columns: &[usize]) -> Vec<ColumnPath> {
+    columns
+        .iter()
+        .map(|column| ColumnPath::ValuePath(vec![*column]))
+        .collect()
+}
+
+unsafe fn into_expression(expression: *mut PwExpression) -> DynResult<Arc<Expression>> {
+    if expression.is_null() {
+        return Err("expression must not be NULL".into());
+    }
+    Ok(Box::from_raw(expression).0)
+}
+
+fn new_expression(expression: Expression) -> *mut PwExpression {
+    Box::into_raw(Box::new(PwExpression(Arc::new(expression))))
+}
+
+/// Runs the computation on the workers configured by the `PATHWAY_THREADS`,
+/// `PATHWAY_PROCESSES` and related environment variables. `build` is called by each
+/// worker to build its graph, concurrently, with `user_data` passed as is.
+#[no_mangle]
+pub unsafe extern "C" fn pw_run(build: Option<PwBuildFn>, user_data: *mut c_void) -> PwStatus {
+    status(|| {
+        let build = build.ok_or("build must not be NULL")?;
+        let user_data = UserData(user_data);
+        let timestamp_at_start = Timestamp::new_from_current_time();
+        let config = Config::from_env()?;
+        run_with_new_dataflow_graph(
+            move |graph| {
+                let graph = PwGraph {
+                    graph,
+                    tables: RefCell::new(Vec::new()),
+                    timestamp_at_start,
+                };
+                let user_data = &user_data;
+                if unsafe { build(&graph, user_data.0) } == PW_OK {
+                    Ok(())
+                } else {
+                    let message =
+                        take_last_error().unwrap_or_else(|| "building the graph failed".into());
+                    Err(message.into())
+                }
+            },
+            |()| (),
+            config,
+            None,
+            None,
+            false,
+            MonitoringLevel::None,
+            false,
+            None,
+            &License::NoLicenseKey,
+            TelemetryConfig::Disabled,
+            Arc::new(RunLineage::default()),
+            true,
+            MAX_EXPRESSION_BATCH_SIZE,
+            0,
+            None,
+            None,
+            None,
+            RunCallbacks::default(),
+        )?;
+        Ok(())
+    })
+}
+
+/// Creates a table from the JSON Lines files matching `path`, with the given columns
+/// read from the fields of the same names. In the streaming mode the files are
+/// watched for changes, and the changes are committed every `autocommit_duration_ms`
+/// milliseconds.
+#[no_mangle]
+pub unsafe extern "C" fn pw_connector_table_jsonlines(
+    graph: *const PwGraph<'_>,
+    path: *const c_char,
+    column_names: *const *const c_char,
+    column_types: *const u32,
+    n_columns: usize,
+    streaming: bool,
+    autocommit_duration_ms: u64,
+    out: *mut PwTable,
+) -> PwStatus {
+    status(|| {
+        let graph = graph_arg(graph)?;
+        let path = str_arg(path, "path")?;
+        let mut columns = Vec::with_capacity(n_columns);
+        for (name, type_) in slice_arg(column_names, n_columns, "column_names")?
+            .iter()
+            .zip(slice_arg(column_types, n_columns, "column_types")?)
+        {
+            columns.push((
+                str_arg(*name, "column name")?.to_string(),
+                type_from_c(*type_)?,
+            ));
+        }
+
+        let n_readers = graph.graph.worker_count();
+        let scanner = FilesystemScanner::new(
+            path,
+            "*",
+            ShardSelector::new(graph.graph.worker_index(), n_readers),
+        )?;
+        let mode = if streaming {
+            ConnectorMode::Streaming
+        } else {
+            ConnectorMode::Static
+        };
+        let reader = PosixLikeReader::new(
+            Box::new(scanner),
+            Box::new(BufReaderTokenizer::new(ReadMethod::ByLine)),
+            mode,
+            false,
+            false,
+        )?;
+        let value_fields = columns
+            .iter()
+            .map(|(name, type_)| ValueField::new(name.clone(), type_.clone(), FieldSource::Payload))
+            .collect();
+        let schema: HashMap<_, _> = columns
+            .iter()
+            .map(|(name, type_)| (name.clone(), InnerSchemaField::new(type_.clone(), None)))
+            .collect();
+        let parser = JsonLinesParser::new(
+            None,
+            value_fields,
+            HashMap::new(),
+            true,
+            schema,
+            SessionType::Native,
+            None,
+        )?;
+        let commit_duration =
+            (autocommit_duration_ms > 0).then(|| Duration::from_millis(autocommit_duration_ms));
+        let table = graph.graph.connector_table(
+            Box::new(reader),
+            Box::new(parser),
+            commit_duration,
+            n_readers,
+            table_properties(columns.into_iter().map(|(_name, type_)| type_)),
+            None,
+            None,
+            BacklogLimit::default(),
+            RateLimit::default(),
+            None,
+            graph.timestamp_at_start,
+            None,
+        )?;
+        write_out(out, graph.add_table(table))
+    })
+}
+
+/// Creates a table with the values of `expressions`, computed from the columns
+/// `columns` of `table`. The expression arguments refer to the positions in `columns`.
+/// The expressions are consumed, also on failure.
+#[no_mangle]
+pub unsafe extern "C" fn pw_expression_table(
+    graph: *const PwGraph<'_>,
+    table: PwTable,
+    columns: *const usize,
+    n_columns: usize,
+    expressions: *const *mut PwExpression,
+    n_expressions: usize,
+    out: *mut PwTable,
+) -> PwStatus {
+    status(|| {
+        // all the expressions are consumed before any error is returned
+        let expressions: Vec<_> = slice_arg(expressions, n_expressions, "expressions")?
+            .iter()
+            .map(|expression| into_expression(*expression))
+            .collect();
+        let expressions: Vec<_> = expressions.into_iter().collect::<DynResult<_>>()?;
+        let graph = graph_arg(graph)?;
+        let columns = slice_arg(columns, n_columns, "columns")?;
+        let properties = Arc::new(TableProperties::Column(column_properties(Type::Any)));
+        let expressions = expressions
+            .into_iter()
+            .map(|expression| ExpressionData {
+                expression,
+                properties: properties.clone(),
+                append_only: false,
+                deterministic: true,
+                gil: false,
+            })
+            .collect();
+        let table = graph.graph.expression_table(
+            graph.table(table)?,
+            column_paths(columns),
+            expressions,
+            false,
+        )?;
+        write_out(out, graph.add_table(table))
+    })
+}
+
+/// Groups the rows of `table` by the values of the columns `grouping_columns` and
+/// creates a table with a row per group and a column per reducer. The reducer
+/// `reducers[i]` is applied to the column `reduced_columns[i]`, which is ignored by
+/// `PW_REDUCER_COUNT`.
+#[no_mangle]
+pub unsafe extern "C" fn pw_group_by_table(
+    graph: *const PwGraph<'_>,
+    table: PwTable,
+    grouping_columns: *const usize,
+    n_grouping_columns: usize,
+    reducers: *const u32,
+    reduced_columns: *const usize,
+    n_reducers: usize,
+    out: *mut PwTable,
+) -> PwStatus {
+    status(|| {
+        let graph = graph_arg(graph)?;
+        let grouping_columns = slice_arg(grouping_columns, n_grouping_columns, "grouping_columns")?;
+        let mut reducer_data = Vec::with_capacity(n_reducers);
+        for (reducer, column) in slice_arg(reducers, n_reducers, "reducers")?
+            .iter()
+            .zip(slice_arg(reduced_columns, n_reducers, "reduced_columns")?)
+        {
+            let (reducer, column_paths) = match *reducer {
+                PW_REDUCER_COUNT => (Reducer::Count, Vec::new()),
+                PW_REDUCER_INT_SUM => (Reducer::IntSum, column_paths(&[*column])),
+                PW_REDUCER_FLOAT_SUM => (
+                    Reducer::FloatSum { strict: false },
+                    column_paths(&[*column]),
+                ),
+                PW_REDUCER_MIN => (Reducer::Min, column_paths(&[*column])),
+                PW_REDUCER_MAX => (Reducer::Max, column_paths(&[*column])),
+                PW_REDUCER_ANY => (Reducer::Any, column_paths(&[*column])),
+                _ => return Err(format!("unknown reducer {reducer}").into()),
+            };
+            reducer_data.push(ReducerData {
+                reducer,
+                skip_errors: false,
+                append_only: false,
+                column_paths,
+                trace: Trace::Empty,
+            });
+        }
+        let table = graph.graph.group_by_table(
+            graph.table(table)?,
+            column_paths(grouping_columns),
+            ShardPolicy::WholeKey,
+            None,
+            reducer_data,
+            false,
+            table_properties(vec![Type::Any; n_reducers]),
+        )?;
+        write_out(out, graph.add_table(table))
+    })
+}
+
+/// Writes the changes of `table` to the file `path` in the JSON Lines format, with the
+/// columns named `column_names`.
+#[no_mangle]
+pub unsafe extern "C" fn pw_output_table_jsonlines(
+    graph: *const PwGraph<'_>,
+    table: PwTable,
+    path: *const c_char,
+    column_names: *const *const c_char,
+    n_columns: usize,
+) -> PwStatus {
+    status(|| {
+        let graph = graph_arg(graph)?;
+        let path = str_arg(path, "path")?;
+        let column_names: Vec<_> = slice_arg(column_names, n_columns, "column_names")?
+            .iter()
+            .map(|name| Ok(str_arg(*name, "column name")?.to_string()))
+            .collect::<DynResult<_>>()?;
+        let writer = FileWriter::new(BufWriter::new(File::create(path)?), path.to_string());
+        graph.graph.output_table(
+            OutputWriters::single(
+                Box::new(writer),
+                Box::new(JsonLinesFormatter::new(column_names, None)),
+            ),
+            graph.table(table)?,
+            column_paths(&(0..n_columns).collect::<Vec<_>>()),
+            None,
+            None,
+            None,
+        )?;
+        Ok(())
+    })
+}
+
+/// The value of the argument `index` of the expression.
+#[no_mangle]
+pub extern "C" fn pw_expression_argument(index: usize) -> *mut PwExpression {
+    guard(ptr::null_mut(), || {
+        new_expression(Expression::Any(AnyExpression::Argument(index)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn pw_expression_int(value: i64) -> *mut PwExpression {
+    guard(ptr::null_mut(), || {
+        new_expression(Expression::Int(IntExpression::Const(value)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn pw_expression_float(value: f64) -> *mut PwExpression {
+    guard(ptr::null_mut(), || {
+        new_expression(Expression::Float(FloatExpression::Const(value)))
+    })
+}
+
+/// A string constant, or NULL if `value` isn't a valid UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn pw_expression_string(value: *const c_char) -> *mut PwExpression {
+    guard(ptr::null_mut(), || match str_arg(value, "value") {
+        Ok(value) => new_expression(Expression::Any(AnyExpression::Const(Value::from(value)))),
+        Err(error) => {
+            set_last_error(&error.to_string());
+            ptr::null_mut()
+        }
+    })
+}
+
+fn binary_expression(
+    op: u32,
+    operand_type: u32,
+    lhs: Arc<Expression>,
+    rhs: Arc<Expression>,
+) -> DynResult<Expression> {
+    let expression = match (type_from_c(operand_type)?, op) {
+        (Type::Int, PW_OP_ADD) => Expression::Int(IntExpression::Add(lhs, rhs)),
+        (Type::Int, PW_OP_SUB) => Expression::Int(IntExpression::Sub(lhs, rhs)),
+        (Type::Int, PW_OP_MUL) => Expression::Int(IntExpression::Mul(lhs, rhs)),
+        (Type::Int, PW_OP_EQ) => Expression::Bool(BoolExpression::IntEq(lhs, rhs)),
+        (Type::Int, PW_OP_NE) => Expression::Bool(BoolExpression::IntNe(lhs, rhs)),
+        (Type::Int, PW_OP_LT) => Expression::Bool(BoolExpression::IntLt(lhs, rhs)),
+        (Type::Int, PW_OP_LE) => Expression::Bool(BoolExpression::IntLe(lhs, rhs)),
+        (Type::Int, PW_OP_GT) => Expression::Bool(BoolExpression::IntGt(lhs, rhs)),
+        (Type::Int, PW_OP_GE) => Expression::Bool(BoolExpression::IntGe(lhs, rhs)),
+        (Type::Float, PW_OP_ADD) => Expression::Float(FloatExpression::Add(lhs, rhs)),
+        (Type::Float, PW_OP_SUB) => Expression::Float(FloatExpression::Sub(lhs, rhs)),
+        (Type::Float, PW_OP_MUL) => Expression::Float(FloatExpression::Mul(lhs, rhs)),
+        (Type::Float, PW_OP_EQ) => Expression::Bool(BoolExpression::FloatEq(lhs, rhs)),
+        (Type::Float, PW_OP_NE) => Expression::Bool(BoolExpression::FloatNe(lhs, rhs)),
+        (Type::Float, PW_OP_LT) => Expression::Bool(BoolExpression::FloatLt(lhs, rhs)),
+        (Type::Float, PW_OP_LE) => Expression::Bool(BoolExpression::FloatLe(lhs, rhs)),
+        (Type::Float, PW_OP_GT) => Expression::Bool(BoolExpression::FloatGt(lhs, rhs)),
+        (Type::Float, PW_OP_GE) => Expression::Bool(BoolExpression::FloatGe(lhs, rhs)),
+        (Type::String, PW_OP_ADD) => Expression::String(StringExpression::Add(lhs, rhs)),
+        (Type::String, PW_OP_EQ) => Expression::Bool(BoolExpression::StringEq(lhs, rhs)),
+        (Type::String, PW_OP_NE) => Expression::Bool(BoolExpression::StringNe(lhs, rhs)),
+        (Type::String, PW_OP_LT) => Expression::Bool(BoolExpression::StringLt(lhs, rhs)),
+        (Type::String, PW_OP_LE) => Expression::Bool(BoolExpression::StringLe(lhs, rhs)),
+        (Type::String, PW_OP_GT) => Expression::Bool(BoolExpression::StringGt(lhs, rhs)),
+        (Type::String, PW_OP_GE) => Expression::Bool(BoolExpression::StringGe(lhs, rhs)),
+        (type_, op) => {
+            return Err(format!("operator {op} is not supported for the type {type_:?}").into())
+        }
+    };
+    Ok(expression)
+}
+
+/// Applies the binary operator `op` to the values of `lhs` and `rhs`, both of the type
+/// `operand_type`. Consumes `lhs` and `rhs`, also on failure, in which case it returns
+/// NULL.
+#[no_mangle]
+pub unsafe extern "C" fn pw_expression_binary(
+    op: u32,
+    operand_type: u32,
+    lhs: *mut PwExpression,
+    rhs: *mut PwExpression,
+) -> *mut PwExpression {
+    guard(ptr::null_mut(), || {
+        let lhs = into_expression(lhs);
+        let rhs = into_expression(rhs);
+        let expression = lhs.and_then(|lhs| binary_expression(op, operand_type, lhs, rhs?));
+        match expression {
+            Ok(expression) => new_expression(expression),
+            Err(error) => {
+                set_last_error(&error.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Frees an expression that hasn't been passed to any other function.
+#[no_mangle]
+pub unsafe extern "C" fn pw_expression_free(expression: *mut PwExpression) {
+    guard((), || {
+        if !expression.is_null() {
+            drop(Box::from_raw(expression));
+        }
+    });
+}
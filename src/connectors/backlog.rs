@@ -1,13 +1,36 @@
 use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
 use timely::dataflow::ProbeHandle;
 
 use crate::engine::Timestamp;
 
+// The smallest limit the adaptive controller can go down to, so that a connector
+// always makes progress, however slow the processing is. It's also the number of events
+// the limit grows by each time the backlog reaches it while the latency is fine.
+const MIN_ADAPTIVE_BACKLOG_SIZE: usize = 1_000;
+
+// The limit the adaptive controller starts with.
+const INITIAL_ADAPTIVE_BACKLOG_SIZE: usize = 100_000;
+
+/// The limit on the number of entries a connector keeps in flight, that is, read from
+/// the source and not processed yet.
+///
+/// `max_size` is a hard cap: the reading thread of the connector stops reading once it
+/// is reached. With `target_latency` set, the connector also adjusts the number of
+/// entries it passes to the computation so that the oldest entry in flight doesn't wait
+/// for longer than `target_latency` to be processed, see [`AdaptiveBacklogLimit`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BacklogLimit {
+    pub max_size: Option<usize>,
+    pub target_latency: Option<Duration>,
+}
+
 #[derive(Debug)]
 pub struct BacklogEntry {
     timestamp: Timestamp,
     n_events: usize,
+    started_at: Instant,
 }
 
 impl BacklogEntry {
@@ -15,6 +38,7 @@ impl BacklogEntry {
         Self {
             timestamp,
             n_events: 1,
+            started_at: Instant::now(),
         }
     }
 
@@ -53,6 +77,14 @@ impl BacklogTracker {
         self.backlog.back().map(|entry| entry.timestamp)
     }
 
+    /// For how long the oldest minibatch in flight has been waiting for the output
+    /// probe to pass it. `None` if everything that has been read is processed.
+    pub fn oldest_entry_age(&self, now: Instant) -> Option<Duration> {
+        self.backlog
+            .front()
+            .map(|entry| now.saturating_duration_since(entry.started_at))
+    }
+
     pub fn advance_with_probe(&mut self, output_probe: &ProbeHandle<Timestamp>) {
         while let Some(group) = self.backlog.front() {
             if output_probe.less_equal(&group.timestamp) {
@@ -63,3 +95,52 @@ impl BacklogTracker {
         }
     }
 }
+
+/// Adjusts the number of entries a connector keeps in flight to the processing speed,
+/// in the additive increase, multiplicative decrease manner.
+///
+/// When the oldest entry in flight has been waiting for longer than the target
+/// latency, the computation doesn't keep up with the input, so the limit is halved,
+/// at most once per the target latency period to let the previous decrease take
+/// effect. When the backlog reaches the limit while the latency is within the target,
+/// the limit is raised, so that the connector doesn't throttle the computation that
+/// could process more.
+#[derive(Debug)]
+pub struct AdaptiveBacklogLimit {
+    target_latency: Duration,
+    limit: usize,
+    last_decrease_at: Option<Instant>,
+}
+
+impl AdaptiveBacklogLimit {
+    pub fn new(target_latency: Duration) -> Self {
+        Self {
+            target_latency,
+            limit: INITIAL_ADAPTIVE_BACKLOG_SIZE,
+            last_decrease_at: None,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn update(
+        &mut self,
+        oldest_entry_age: Option<Duration>,
+        backlog_size: usize,
+        now: Instant,
+    ) {
+        if oldest_entry_age.is_some_and(|age| age > self.target_latency) {
+            let decrease_allowed = self.last_decrease_at.is_none_or(|last_decrease_at| {
+                now.saturating_duration_since(last_decrease_at) >= self.target_latency
+            });
+            if decrease_allowed {
+                self.limit = (self.limit / 2).max(MIN_ADAPTIVE_BACKLOG_SIZE);
+                self.last_decrease_at = Some(now);
+            }
+        } else if backlog_size >= self.limit {
+            self.limit += MIN_ADAPTIVE_BACKLOG_SIZE;
+        }
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright © 2026 Pathway
+
+//! Runtime control of the input connectors.
+//!
+//! The input connectors with a unique name can be paused, resumed and drained while the
+//! computation is running, without restarting it:
+//! - a paused connector stops reading from its source, the entries it has already read
+//!   are still processed and committed;
+//! - a resumed connector continues reading from where it has stopped;
+//! - a drained connector stops reading from its source for good, and finishes once the
+//!   entries it has already read are processed, as if its source had ended.
+//!
+//! The commands are sent with [`control_connector`], either directly or through
+//! `POST /connectors/<name>/<command>` on the monitoring HTTP server, and apply to all the
+//! parallel readers of the connector in this process. In a multi-process run, each
+//! process has to be asked. A reader waiting for its source to return the next entry
+//! notices the command once the source returns.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Condvar, Mutex, Weak};
+
+use crate::persistence::UniqueName;
+
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConnectorControlError {
+    #[error("unknown command {0:?}, the supported ones are \"pause\", \"resume\" and \"drain\"")]
+    UnknownCommand(String),
+
+    #[error("no running input connector is named {0:?}")]
+    UnknownConnector(String),
+
+    #[error("input connector {0:?} has been drained, it can't be paused or resumed")]
+    Drained(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectorCommand {
+    Pause,
+    Resume,
+    Drain,
+}
+
+impl FromStr for ConnectorCommand {
+    type Err = ConnectorControlError;
+
+    fn from_str(command: &str) -> Result<Self, Self::Err> {
+        match command {
+            "pause" => Ok(Self::Pause),
+            "resume" => Ok(Self::Resume),
+            "drain" => Ok(Self::Drain),
+            _ => Err(ConnectorControlError::UnknownCommand(command.to_string())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectorState {
+    Running,
+    Paused,
+    Drained,
+}
+
+impl fmt::Display for ConnectorState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Running => write!(f, "running"),
+            Self::Paused => write!(f, "paused"),
+            Self::Drained => write!(f, "drained"),
+        }
+    }
+}
+
+/// The state of a single reader of a controlled connector, shared between its reading
+/// thread and the senders of the commands.
+#[derive(Debug)]
+pub struct ConnectorControl {
+    state: Mutex<ConnectorState>,
+    state_changed: Condvar,
+}
+
+impl ConnectorControl {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(ConnectorState::Running),
+            state_changed: Condvar::new(),
+        }
+    }
+
+    pub fn state(&self) -> ConnectorState {
+        *self.state.lock().unwrap()
+    }
+
+    fn apply(&self, command: ConnectorCommand) -> ConnectorState {
+        let mut state = self.state.lock().unwrap();
+        if *state != ConnectorState::Drained {
+            *state = match command {
+                ConnectorCommand::Pause => ConnectorState::Paused,
+                ConnectorCommand::Resume => ConnectorState::Running,
+                ConnectorCommand::Drain => ConnectorState::Drained,
+            };
+            self.state_changed.notify_all();
+        }
+        *state
+    }
+
+    /// Blocks the reading thread while the connector is paused. Returns `true` if the
+    /// connector has been drained, so that the reading thread has to stop.
+    pub fn wait_until_resumed(&self) -> bool {
+        let state = self
+            .state_changed
+            .wait_while(self.state.lock().unwrap(), |state| {
+                *state == ConnectorState::Paused
+            })
+            .unwrap();
+        *state == ConnectorState::Drained
+    }
+}
+
+impl Default for ConnectorControl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// the readers of the connectors of the computations running in this process
+static CONTROLS: Mutex<Vec<(UniqueName, Weak<ConnectorControl>)>> = Mutex::new(Vec::new());
+
+/// Makes the reader with `control` receive the commands sent to the connector
+/// `unique_name` until `control` is dropped.
+pub fn register_connector_control(unique_name: &UniqueName, control: &Arc<ConnectorControl>) {
+    let mut controls = CONTROLS.lock().unwrap();
+    controls.retain(|(_name, control)| control.strong_count() > 0);
+    controls.push((unique_name.clone(), Arc::downgrade(control)));
+}
+
+fn running_controls(unique_name: &str) -> Vec<Arc<ConnectorControl>> {
+    CONTROLS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(name, _control)| name == unique_name)
+        .filter_map(|(_name, control)| control.upgrade())
+        .collect()
+}
+
+/// Sends `command` to all the readers of the connector `unique_name` in this process.
+/// Returns the state of the connector after the command.
+pub fn control_connector(
+    unique_name: &str,
+    command: ConnectorCommand,
+) -> Result<ConnectorState, ConnectorControlError> {
+    let controls = running_controls(unique_name);
+    if controls.is_empty() {
+        return Err(ConnectorControlError::UnknownConnector(
+            unique_name.to_string(),
+        ));
+    }
+    let drained = controls
+        .iter()
+        .any(|control| control.state() == ConnectorState::Drained);
+    if drained && command != ConnectorCommand::Drain {
+        return Err(ConnectorControlError::Drained(unique_name.to_string()));
+    }
+    let mut state = ConnectorState::Running;
+    for control in controls {
+        state = control.apply(command);
+    }
+    Ok(state)
+}
+
+/// Returns the states of the controlled connectors running in this process.
+pub fn connector_states() -> HashMap<UniqueName, ConnectorState> {
+    CONTROLS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|(name, control)| Some((name.clone(), control.upgrade()?.state())))
+        .collect()
+}
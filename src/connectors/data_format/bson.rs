@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::iter::zip;
 
+use arcstr::ArcStr;
 use mongodb::bson::{
     bson, spec::BinarySubtype as BsonBinarySubtype, Binary as BsonBinaryContents, Bson,
     DateTime as BsonDateTime, Document,
@@ -19,7 +20,8 @@ use crate::connectors::{SPECIAL_FIELD_DIFF, SPECIAL_FIELD_TIME};
 use crate::engine::time::DateTime as DateTimeTrait;
 use crate::engine::value::parse_pathway_pointer;
 use crate::engine::{
-    DateTimeNaive, DateTimeUtc, Duration, Key, Result as EngineResult, Timestamp, Type, Value,
+    DateTimeNaive, DateTimeUtc, DateTimeZoned, Duration, Key, Result as EngineResult, Timestamp,
+    Type, Value,
 };
 
 pub struct BsonParser {
@@ -174,6 +176,20 @@ impl BsonParser {
                 _ => Err(err("expected datetime")),
             },
 
+            Type::DateTimeZoned => match bson {
+                Bson::String(s) => DateTimeZoned::parse(s)
+                    .map(Value::DateTimeZoned)
+                    .map_err(|e| {
+                        Box::new(make_conversion_err(
+                            s.clone(),
+                            &e.to_string(),
+                            field_name,
+                            type_,
+                        ))
+                    }),
+                _ => Err(err("expected string with a zoned datetime")),
+            },
+
             Type::Duration => match bson {
                 Bson::Int64(ns) => Duration::new_with_unit(*ns, "ms")
                     .map(Value::Duration)
@@ -280,6 +296,31 @@ impl BsonParser {
                 _ => Err(err("expected array for list")),
             },
 
+            Type::Struct(fields) => match bson {
+                Bson::Document(doc) => fields
+                    .iter()
+                    .map(|(name, field_type)| {
+                        let field_bson = doc.get(name.as_str()).unwrap_or(&Bson::Null);
+                        Self::bson_to_value(field_bson, field_name, field_type)
+                            .map(|value| (name.clone(), value))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(Value::from),
+                _ => Err(err("expected document for struct")),
+            },
+
+            Type::Map(value_type) => match bson {
+                Bson::Document(doc) => doc
+                    .iter()
+                    .map(|(key, value_bson)| {
+                        Self::bson_to_value(value_bson, field_name, value_type)
+                            .map(|value| (ArcStr::from(key.as_str()), value))
+                    })
+                    .collect::<Result<BTreeMap<_, _>, _>>()
+                    .map(Value::from),
+                _ => Err(err("expected document for map")),
+            },
+
             _ => unreachable!("bson_to_complex_value called with non-complex type: {type_:?}"),
         }
     }
@@ -307,10 +348,11 @@ impl BsonParser {
             | Type::Pointer
             | Type::DateTimeNaive
             | Type::DateTimeUtc
+            | Type::DateTimeZoned
             | Type::Duration
             | Type::PyObjectWrapper => Self::bson_to_scalar_value(bson, field_name, type_),
 
-            Type::Json | Type::Tuple(_) | Type::List(_) => {
+            Type::Json | Type::Tuple(_) | Type::List(_) | Type::Struct(_) | Type::Map(_) => {
                 Self::bson_to_complex_value(bson, field_name, type_)
             }
 
@@ -571,6 +613,20 @@ pub fn serialize_value_to_bson(value: &Value) -> Result<Bson, FormatterError> {
             }
             Ok(Bson::Array(items))
         }
+        Value::Struct(fields) => {
+            let mut document = Document::new();
+            for (name, field_value) in fields.iter() {
+                document.insert(name.to_string(), serialize_value_to_bson(field_value)?);
+            }
+            Ok(Bson::Document(document))
+        }
+        Value::Map(map) => {
+            let mut document = Document::new();
+            for (key, item) in map.iter() {
+                document.insert(key.to_string(), serialize_value_to_bson(item)?);
+            }
+            Ok(Bson::Document(document))
+        }
         Value::IntArray(a) => Ok(int_ndarray_to_bson(&a.view())),
         Value::FloatArray(a) => Ok(float_ndarray_to_bson(&a.view())),
         Value::Bytes(b) => Ok(Bson::Binary(BsonBinaryContents {
@@ -586,6 +642,8 @@ pub fn serialize_value_to_bson(value: &Value) -> Result<Bson, FormatterError> {
         Value::DateTimeUtc(dt) => Ok(Bson::DateTime(BsonDateTime::from_millis(
             dt.timestamp_milliseconds(),
         ))),
+        // BSON DateTime can't hold a time zone, so it's kept in the textual form
+        Value::DateTimeZoned(dt) => Ok(bson!(dt.to_string())),
 
         // We use milliseconds in durations to be consistent with the granularity
         // of the BSON DateTime type
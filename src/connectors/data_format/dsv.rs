@@ -1,7 +1,10 @@
 // Copyright © 2026 Pathway
 
 use std::clone::Clone;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::iter::zip;
+use std::sync::Arc;
 
 use crate::connectors::metadata::SourceMetadata;
 use crate::connectors::ReaderContext::{
@@ -12,18 +15,83 @@ use crate::connectors::{SPECIAL_FIELD_DIFF, SPECIAL_FIELD_TIME};
 use crate::engine::{Key, Result, Timestamp, Value};
 
 use base64::Engine;
+use deltalake::arrow::array::{ArrayRef, RecordBatch, StringBuilder};
+use deltalake::arrow::csv::{QuoteStyle, WriterBuilder as CsvWriterBuilder};
+use deltalake::arrow::datatypes::{
+    DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema,
+};
 use serde_json::Value as JsonValue;
 
 use super::{
     create_bincoded_value, ensure_all_fields_in_schema, parse_with_type, serialize_value_to_json,
     Formatter, FormatterContext, FormatterError, InnerSchemaField, ParseError, ParseResult,
-    ParsedEventWithErrors, Parser, ValueFieldsWithErrors, COMMIT_LITERAL, METADATA_FIELD_NAME,
+    ParsedEventWithErrors, Parser, SchemaEvolutionPolicy, ValueFieldsWithErrors, COMMIT_LITERAL,
+    METADATA_FIELD_NAME,
 };
 
+/// How the fields of the rows written by the `DsvFormatter` are quoted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DsvQuoting {
+    /// Every field is quoted.
+    #[default]
+    Always,
+    /// Only the fields containing the separator, a quote or a line break are quoted.
+    Necessary,
+    /// Every field except the numbers is quoted.
+    NonNumeric,
+    /// No field is quoted. The separators, the line breaks and the escape
+    /// characters within a field are preceded by the escape character.
+    Never,
+}
+
+/// The dialect of the rows written by the `DsvFormatter`. The default one
+/// quotes every field, escapes the quotes by doubling them and starts the
+/// output with a header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DsvDialect {
+    pub quoting: DsvQuoting,
+    /// If set, the quotes within the quoted fields are preceded by this
+    /// character instead of being doubled.
+    pub escape: Option<u8>,
+    pub write_header: bool,
+    /// If set, the missing values are written as this text, never quoted, so
+    /// that the loaders can tell them apart from the strings.
+    pub null_value: Option<String>,
+}
+
+impl Default for DsvDialect {
+    fn default() -> Self {
+        Self {
+            quoting: DsvQuoting::Always,
+            escape: None,
+            write_header: true,
+            null_value: None,
+        }
+    }
+}
+
+// A field of a row written by the `DsvFormatter`
+enum DsvField {
+    Text(String),
+    Numeric(String),
+    Null,
+}
+
+impl DsvField {
+    fn text(&self) -> &str {
+        match self {
+            Self::Text(text) | Self::Numeric(text) => text,
+            Self::Null => "",
+        }
+    }
+}
+
 pub struct DsvSettings {
     key_column_names: Option<Vec<String>>,
     value_column_names: Vec<String>,
     separator: char,
+    schema_evolution_policy: SchemaEvolutionPolicy,
+    dialect: DsvDialect,
 }
 
 impl DsvSettings {
@@ -36,9 +104,25 @@ impl DsvSettings {
             key_column_names,
             value_column_names,
             separator,
+            schema_evolution_policy: SchemaEvolutionPolicy::default(),
+            dialect: DsvDialect::default(),
         }
     }
 
+    /// Sets the dialect of the rows written by the formatter.
+    #[must_use]
+    pub fn with_dialect(mut self, dialect: DsvDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Sets how the parser handles the header not matching the schema.
+    #[must_use]
+    pub fn with_schema_evolution_policy(mut self, policy: SchemaEvolutionPolicy) -> Self {
+        self.schema_evolution_policy = policy;
+        self
+    }
+
     pub fn formatter(self) -> Box<dyn Formatter> {
         Box::new(DsvFormatter::new(self))
     }
@@ -52,6 +136,8 @@ impl DsvSettings {
 enum DsvColumnIndex {
     IndexWithSchema(usize, InnerSchemaField),
     Metadata,
+    // a column missing from the header, with the value used in its place
+    Missing(Value),
 }
 
 pub struct DsvParser {
@@ -96,14 +182,13 @@ impl DsvParser {
         tokenized_entries: &[String],
         sought_names: &[String],
         schema: &HashMap<String, InnerSchemaField>,
+        policy: SchemaEvolutionPolicy,
     ) -> Result<Vec<DsvColumnIndex>, ParseError> {
-        let mut value_indices_found = 0;
-
-        let mut column_indices = vec![DsvColumnIndex::Metadata; sought_names.len()];
+        let mut column_indices = vec![None; sought_names.len()];
         let mut requested_indices = HashMap::<String, Vec<usize>>::new();
         for (index, field) in sought_names.iter().enumerate() {
             if field == METADATA_FIELD_NAME {
-                value_indices_found += 1;
+                column_indices[index] = Some(DsvColumnIndex::Metadata);
                 continue;
             }
             match requested_indices.get_mut(field) {
@@ -119,28 +204,55 @@ impl DsvParser {
                 let schema_item = &schema[value];
                 for requested_index in indices {
                     column_indices[*requested_index] =
-                        DsvColumnIndex::IndexWithSchema(index, schema_item.clone());
-                    value_indices_found += 1;
+                        Some(DsvColumnIndex::IndexWithSchema(index, schema_item.clone()));
                 }
             }
         }
 
-        if value_indices_found == sought_names.len() {
-            Ok(column_indices)
-        } else {
-            Err(ParseError::FieldsNotFoundInHeader {
-                parsed: tokenized_entries.to_vec(),
-                requested: sought_names.to_vec(),
+        let fields_not_found = || ParseError::FieldsNotFoundInHeader {
+            parsed: tokenized_entries.to_vec(),
+            requested: sought_names.to_vec(),
+        };
+        zip(sought_names, column_indices)
+            .map(|(name, index)| match index {
+                Some(index) => Ok(index),
+                None => policy
+                    .missing_field_value(&schema[name])
+                    .map(DsvColumnIndex::Missing)
+                    .ok_or_else(fields_not_found),
             })
+            .collect()
+    }
+
+    fn check_no_unexpected_columns(&self, tokenized_entries: &[String]) -> Result<(), ParseError> {
+        if self.settings.schema_evolution_policy.allows_new_fields() {
+            return Ok(());
+        }
+        let key_column_names = self.settings.key_column_names.iter().flatten();
+        let known_columns: HashSet<&String> = key_column_names
+            .chain(self.settings.value_column_names.iter())
+            .collect();
+        let fields: Vec<String> = tokenized_entries
+            .iter()
+            .filter(|column| !known_columns.contains(column))
+            .cloned()
+            .collect();
+        if fields.is_empty() {
+            Ok(())
+        } else {
+            Err(ParseError::UnexpectedFields { fields })
         }
     }
 
     fn parse_dsv_header(&mut self, tokenized_entries: &[String]) -> Result<(), ParseError> {
+        self.check_no_unexpected_columns(tokenized_entries)?;
+        let policy = self.settings.schema_evolution_policy;
         self.key_column_indices = match &self.settings.key_column_names {
             Some(names) => Some(Self::column_indices_by_names(
                 tokenized_entries,
                 names,
                 &self.schema,
+                policy,
             )?),
             None => None,
         };
@@ -148,6 +260,7 @@ impl DsvParser {
             tokenized_entries,
             &self.settings.value_column_names,
             &self.schema,
+            policy,
         )?;
 
         self.min_tokens_in_row = self
@@ -157,7 +270,7 @@ impl DsvParser {
             .chain(self.value_column_indices.iter())
             .filter_map(|index| match index {
                 DsvColumnIndex::IndexWithSchema(index, _) => Some(*index + 1),
-                DsvColumnIndex::Metadata => None,
+                DsvColumnIndex::Metadata | DsvColumnIndex::Missing(_) => None,
             })
             .max()
             .unwrap_or(0);
@@ -196,6 +309,7 @@ impl DsvParser {
                     parse_with_type(get_token(*index), schema_item, &self.header[*index])
                 }
                 DsvColumnIndex::Metadata => Ok(self.metadata_column_value.clone()),
+                DsvColumnIndex::Missing(value) => Ok(value.clone()),
             };
             parsed_tokens.push(token);
         }
@@ -285,6 +399,9 @@ impl Parser for DsvParser {
     }
 }
 
+// Initial guess of the rendered field length, used to preallocate column buffers
+const ESTIMATED_FIELD_LENGTH: usize = 16;
+
 pub struct DsvFormatter {
     settings: DsvSettings,
 
@@ -293,39 +410,240 @@ pub struct DsvFormatter {
 
 impl DsvFormatter {
     pub fn new(settings: DsvSettings) -> DsvFormatter {
+        let dsv_header_written = !settings.dialect.write_header;
         DsvFormatter {
             settings,
 
-            dsv_header_written: false,
+            dsv_header_written,
         }
     }
 
-    fn format_csv_row(tokens: &[String], separator: u8) -> Vec<u8> {
-        // Mirrors `csv`'s `QuoteStyle::Always` with the default double-quote
-        // escaping: every field is wrapped in double quotes and any embedded
-        // double quote is doubled. Doing it by hand avoids constructing a
-        // `csv::Writer` (with its internal buffers) for every single row.
-        let escaped_len: usize = tokens
+    fn format_csv_row(
+        &self,
+        fields: &[DsvField],
+        separator: u8,
+    ) -> Result<Vec<u8>, FormatterError> {
+        // With the default dialect, mirrors `csv`'s `QuoteStyle::Always` with the
+        // default double-quote escaping: every field is wrapped in double quotes and
+        // any embedded double quote is doubled. Doing it by hand avoids constructing
+        // a `csv::Writer` (with its internal buffers) for every single row.
+        let dialect = &self.settings.dialect;
+        let escape_char = dialect.escape.map(char::from);
+        let is_special =
+            |byte: u8| byte == separator || byte == b'"' || byte == b'\n' || byte == b'\r';
+        let escaped_len: usize = fields
             .iter()
-            .map(|token| token.len() + token.matches('"').count())
+            .map(|field| field.text().len() + field.text().matches('"').count())
             .sum();
-        let mut out = Vec::with_capacity(escaped_len + 3 * tokens.len());
-        for (column, token) in tokens.iter().enumerate() {
+        let mut out = Vec::with_capacity(escaped_len + 3 * fields.len());
+        for (column, field) in fields.iter().enumerate() {
             if column > 0 {
                 out.push(separator);
             }
-            out.push(b'"');
-            let bytes = token.as_bytes();
-            let mut copied_until = 0;
-            for (quote_pos, _) in token.match_indices('"') {
-                out.extend_from_slice(&bytes[copied_until..quote_pos]);
-                out.extend_from_slice(b"\"\"");
-                copied_until = quote_pos + 1;
+            let token = match field {
+                DsvField::Null => {
+                    let null_value = dialect.null_value.as_deref().unwrap_or_default();
+                    out.extend_from_slice(null_value.as_bytes());
+                    continue;
+                }
+                DsvField::Text(token) | DsvField::Numeric(token) => token,
+            };
+            let is_quoted = match dialect.quoting {
+                DsvQuoting::Always => true,
+                DsvQuoting::Necessary => token.bytes().any(is_special),
+                DsvQuoting::NonNumeric => !matches!(field, DsvField::Numeric(_)),
+                DsvQuoting::Never => false,
+            };
+            if is_quoted {
+                out.push(b'"');
+                let bytes = token.as_bytes();
+                let mut copied_until = 0;
+                // The escape character itself is escaped too, so that it isn't
+                // taken for an escaped quote
+                for (escaped_pos, _) in
+                    token.match_indices(|c: char| c == '"' || Some(c) == escape_char)
+                {
+                    out.extend_from_slice(&bytes[copied_until..escaped_pos]);
+                    out.push(dialect.escape.unwrap_or(b'"'));
+                    copied_until = escaped_pos;
+                }
+                out.extend_from_slice(&bytes[copied_until..]);
+                out.push(b'"');
+            } else if dialect.quoting == DsvQuoting::Never {
+                for byte in token.bytes() {
+                    let needs_escape = byte == separator
+                        || byte == b'\n'
+                        || byte == b'\r'
+                        || Some(byte) == dialect.escape;
+                    if needs_escape {
+                        let Some(escape) = dialect.escape else {
+                            return Err(FormatterError::UnescapedCsvField(token.clone()));
+                        };
+                        out.push(escape);
+                    }
+                    out.push(byte);
+                }
+            } else {
+                out.extend_from_slice(token.as_bytes());
+            }
+        }
+        Ok(out)
+    }
+
+    fn separator(&self) -> Result<u8, FormatterError> {
+        self.settings
+            .separator
+            .try_into()
+            .map_err(|_| FormatterError::UnsupportedCsvSeparator(self.settings.separator))
+    }
+
+    fn header_payload(&mut self, separator: u8) -> Result<Option<Vec<u8>>, FormatterError> {
+        if self.dsv_header_written {
+            return Ok(None);
+        }
+        self.dsv_header_written = true;
+        let header: Vec<_> = self
+            .settings
+            .value_column_names
+            .iter()
+            .map(std::string::ToString::to_string)
+            .chain([
+                SPECIAL_FIELD_TIME.to_string(),
+                SPECIAL_FIELD_DIFF.to_string(),
+            ])
+            .map(DsvField::Text)
+            .collect();
+        self.format_csv_row(&header, separator).map(Some)
+    }
+
+    fn prepare_field(&self, value: &Value) -> Result<DsvField, FormatterError> {
+        let field = match value {
+            Value::None if self.settings.dialect.null_value.is_some() => DsvField::Null,
+            Value::Int(_) | Value::Float(_) => DsvField::Numeric(format!("{value}")),
+            _ => DsvField::Text(Self::prepare_value(value)?),
+        };
+        Ok(field)
+    }
+
+    fn prepare_value(value: &Value) -> Result<String, FormatterError> {
+        let prepared = match value {
+            Value::String(v) => v.to_string(),
+            Value::PyObjectWrapper(_) => create_bincoded_value(value)?,
+            Value::Bytes(b) => base64::engine::general_purpose::STANDARD.encode(b),
+            Value::Duration(d) => format!("{}", d.nanoseconds()),
+            Value::IntArray(_)
+            | Value::FloatArray(_)
+            | Value::Tuple(_)
+            | Value::Struct(_)
+            | Value::Map(_) => {
+                let json_value = serialize_value_to_json(value)?;
+                json_value.to_string()
+            }
+            _ => format!("{value}"),
+        };
+        Ok(prepared)
+    }
+
+    fn append_prepared_value(
+        builder: &mut StringBuilder,
+        value: &Value,
+    ) -> Result<(), FormatterError> {
+        // The values that are rendered via `Display` are written straight into the
+        // builder's buffer, which saves an intermediate allocation per field
+        match value {
+            Value::String(v) => builder.append_value(v.as_str()),
+            Value::Duration(d) => {
+                write!(builder, "{}", d.nanoseconds()).expect("writing to a buffer can't fail");
+                builder.append_value("");
+            }
+            Value::PyObjectWrapper(_)
+            | Value::Bytes(_)
+            | Value::IntArray(_)
+            | Value::FloatArray(_)
+            | Value::Tuple(_)
+            | Value::Struct(_)
+            | Value::Map(_) => builder.append_value(Self::prepare_value(value)?),
+            _ => {
+                write!(builder, "{value}").expect("writing to a buffer can't fail");
+                builder.append_value("");
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders the rows column by column into Arrow string arrays and passes them to the
+    /// Arrow CSV writer at once. Returns the rendered rows, each without the line terminator.
+    fn format_rows_columnar(
+        &self,
+        entries: &[(Key, &[Value], isize)],
+        time: Timestamp,
+        separator: u8,
+    ) -> Result<Vec<Vec<u8>>, FormatterError> {
+        let n_rows = entries.len();
+        let n_columns = self.settings.value_column_names.len();
+        let mut fields = Vec::with_capacity(n_columns + 2);
+        let mut columns: Vec<ArrayRef> = Vec::with_capacity(n_columns + 2);
+        for (index, name) in self.settings.value_column_names.iter().enumerate() {
+            let mut builder = StringBuilder::with_capacity(n_rows, n_rows * ESTIMATED_FIELD_LENGTH);
+            for (_, values, _) in entries {
+                Self::append_prepared_value(&mut builder, &values[index])?;
+            }
+            fields.push(ArrowField::new(name, ArrowDataType::Utf8, false));
+            columns.push(Arc::new(builder.finish()));
+        }
+
+        let time = time.to_string();
+        let mut time_builder = StringBuilder::with_capacity(n_rows, n_rows * time.len());
+        let mut diff_builder = StringBuilder::with_capacity(n_rows, n_rows * 2);
+        for (_, _, diff) in entries {
+            time_builder.append_value(&time);
+            write!(diff_builder, "{diff}").expect("writing to a buffer can't fail");
+            diff_builder.append_value("");
+        }
+        fields.push(ArrowField::new(
+            SPECIAL_FIELD_TIME,
+            ArrowDataType::Utf8,
+            false,
+        ));
+        columns.push(Arc::new(time_builder.finish()));
+        fields.push(ArrowField::new(
+            SPECIAL_FIELD_DIFF,
+            ArrowDataType::Utf8,
+            false,
+        ));
+        columns.push(Arc::new(diff_builder.finish()));
+
+        let batch = RecordBatch::try_new(Arc::new(ArrowSchema::new(fields)), columns)?;
+        let buffer_capacity = batch.get_array_memory_size() + n_rows * 3 * (n_columns + 2);
+        let mut writer = CsvWriterBuilder::new()
+            .with_header(false)
+            .with_delimiter(separator)
+            .with_quote_style(QuoteStyle::Always)
+            .build(Vec::with_capacity(buffer_capacity));
+        writer.write(&batch)?;
+        let buffer = writer.into_inner();
+
+        Ok(Self::split_rows(&buffer, n_rows))
+    }
+
+    fn split_rows(buffer: &[u8], n_rows: usize) -> Vec<Vec<u8>> {
+        // Since every field is quoted, a line break is a row terminator
+        // if and only if it's not inside the quotes.
+        let mut rows = Vec::with_capacity(n_rows);
+        let mut row_start = 0;
+        let mut inside_quotes = false;
+        for (position, byte) in buffer.iter().enumerate() {
+            match byte {
+                b'"' => inside_quotes = !inside_quotes,
+                b'\n' if !inside_quotes => {
+                    let row = &buffer[row_start..position];
+                    rows.push(row.strip_suffix(b"\r").unwrap_or(row).to_vec());
+                    row_start = position + 1;
+                }
+                _ => {}
             }
-            out.extend_from_slice(&bytes[copied_until..]);
-            out.push(b'"');
         }
-        out
+        rows
     }
 }
 
@@ -341,48 +659,17 @@ impl Formatter for DsvFormatter {
             return Err(FormatterError::ColumnsValuesCountMismatch);
         }
 
-        let Ok(separator) = self.settings.separator.try_into() else {
-            return Err(FormatterError::UnsupportedCsvSeparator(
-                self.settings.separator,
-            ));
-        };
+        let separator = self.separator()?;
         let mut payloads = Vec::with_capacity(2);
+        payloads.extend(self.header_payload(separator)?);
 
-        if !self.dsv_header_written {
-            let header: Vec<_> = self
-                .settings
-                .value_column_names
-                .iter()
-                .map(std::string::ToString::to_string)
-                .chain([
-                    SPECIAL_FIELD_TIME.to_string(),
-                    SPECIAL_FIELD_DIFF.to_string(),
-                ])
-                .collect();
-            payloads.push(Self::format_csv_row(&header, separator));
-            self.dsv_header_written = true;
-        }
-
-        let mut prepared_values = Vec::with_capacity(values.len());
+        let mut line = Vec::with_capacity(values.len() + 2);
         for v in values {
-            let prepared = match v {
-                Value::String(v) => v.to_string(),
-                Value::PyObjectWrapper(_) => create_bincoded_value(v)?,
-                Value::Bytes(b) => base64::engine::general_purpose::STANDARD.encode(b),
-                Value::Duration(d) => format!("{}", d.nanoseconds()),
-                Value::IntArray(_) | Value::FloatArray(_) | Value::Tuple(_) => {
-                    let json_value = serialize_value_to_json(v)?;
-                    json_value.to_string()
-                }
-                _ => format!("{v}"),
-            };
-            prepared_values.push(prepared);
+            line.push(self.prepare_field(v)?);
         }
-        let line: Vec<_> = prepared_values
-            .into_iter()
-            .chain([format!("{time}").to_string(), format!("{diff}").to_string()])
-            .collect();
-        payloads.push(Self::format_csv_row(&line, separator));
+        line.push(DsvField::Numeric(format!("{time}")));
+        line.push(DsvField::Numeric(format!("{diff}")));
+        payloads.push(self.format_csv_row(&line, separator)?);
 
         Ok(FormatterContext::new(
             payloads,
@@ -392,4 +679,46 @@ impl Formatter for DsvFormatter {
             diff,
         ))
     }
+
+    fn format_batch(
+        &mut self,
+        entries: &[(Key, &[Value], isize)],
+        time: Timestamp,
+    ) -> Result<Vec<FormatterContext>, FormatterError> {
+        if entries.is_empty() {
+            return Ok(Vec::new());
+        }
+        if entries
+            .iter()
+            .any(|(_, values, _)| values.len() != self.settings.value_column_names.len())
+        {
+            return Err(FormatterError::ColumnsValuesCountMismatch);
+        }
+        if self.settings.dialect != DsvDialect::default() {
+            // The columnar path relies on every field being quoted, so the other
+            // dialects are formatted row by row
+            return entries
+                .iter()
+                .map(|(key, values, diff)| self.format(key, values, time, *diff))
+                .collect();
+        }
+        let separator = self.separator()?;
+
+        let rows = self.format_rows_columnar(entries, time, separator)?;
+        let mut header = self.header_payload(separator)?;
+        let mut result = Vec::with_capacity(entries.len());
+        for ((key, values, diff), row) in entries.iter().zip(rows) {
+            let mut payloads = Vec::with_capacity(2);
+            payloads.extend(header.take());
+            payloads.push(row);
+            result.push(FormatterContext::new(
+                payloads,
+                *key,
+                values.to_vec(),
+                time,
+                *diff,
+            ));
+        }
+        Ok(result)
+    }
 }
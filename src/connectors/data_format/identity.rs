@@ -14,7 +14,7 @@ use serde_json::Value as JsonValue;
 
 use super::{
     is_commit_literal, value_from_bytes, Formatter, FormatterContext, FormatterError, ParseError,
-    ParseResult, ParsedEventWithErrors, Parser, METADATA_FIELD_NAME,
+    ParseResult, ParsedEventWithErrors, Parser, HEADERS_FIELD_NAME, METADATA_FIELD_NAME,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -37,12 +37,14 @@ impl KeyGenerationPolicy {
 pub struct IdentityParser {
     parse_utf8: bool,
     metadata_column_value: Value,
+    headers_column_value: Value,
     session_type: SessionType,
     key_generation_policy: KeyGenerationPolicy,
 
     n_value_fields: usize,
     key_field_index: Option<usize>,
     metadata_field_index: Option<usize>,
+    headers_field_index: Option<usize>,
     value_field_index: usize,
 }
 
@@ -56,11 +58,15 @@ impl IdentityParser {
     ) -> IdentityParser {
         let mut key_field_index = None;
         let mut metadata_field_index = None;
+        let mut headers_field_index = None;
         let mut value_field_index = None;
         for (index, value_field) in value_fields.iter().enumerate() {
             if value_field == METADATA_FIELD_NAME {
                 assert!(metadata_field_index.is_none());
                 metadata_field_index = Some(index);
+            } else if value_field == HEADERS_FIELD_NAME {
+                assert!(headers_field_index.is_none());
+                headers_field_index = Some(index);
             } else if Some(value_field) == message_queue_key_field {
                 assert!(key_field_index.is_none());
                 key_field_index = Some(index);
@@ -74,10 +80,12 @@ impl IdentityParser {
             n_value_fields: value_fields.len(),
             parse_utf8,
             metadata_column_value: Value::None,
+            headers_column_value: Value::None,
             key_generation_policy,
             session_type,
             key_field_index,
             metadata_field_index,
+            headers_field_index,
             value_field_index: value_field_index
                 .expect("value field must be present in the schema"),
         }
@@ -131,6 +139,9 @@ impl Parser for IdentityParser {
                 values[metadata_field_index] =
                     metadata.map(|metadata| metadata.unwrap_or(self.metadata_column_value.clone()));
             }
+            if let Some(headers_field_index) = self.headers_field_index {
+                values[headers_field_index] = Ok(self.headers_column_value.clone());
+            }
             values[self.value_field_index] = value;
             ParsedEventWithErrors::new(self.session_type(), event, key, values)
         };
@@ -141,6 +152,7 @@ impl Parser for IdentityParser {
     fn on_new_source_started(&mut self, metadata: &SourceMetadata) {
         let metadata_serialized: JsonValue = metadata.serialize();
         self.metadata_column_value = metadata_serialized.into();
+        self.headers_column_value = metadata.message_headers().map_or(Value::None, Into::into);
     }
 
     fn column_count(&self) -> usize {
@@ -2,7 +2,7 @@
 
 use std::borrow::Cow;
 use std::clone::Clone;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::iter::zip;
 
@@ -11,10 +11,10 @@ use crate::connectors::ReaderContext::{
     Bson, CsvRecord, Diff, Empty, KeyValue, RawBytes, TokenizedEntries,
 };
 use crate::connectors::{DataEventType, ReaderContext, SessionType};
-use crate::connectors::{SPECIAL_FIELD_DIFF, SPECIAL_FIELD_TIME};
+use crate::connectors::{SPECIAL_FIELD_BEFORE, SPECIAL_FIELD_DIFF, SPECIAL_FIELD_TIME};
 use crate::engine::error::DynResult;
+use crate::engine::time::DateTime;
 use crate::engine::{Key, Result, Timestamp, Type, Value};
-use crate::python_api::ValueField;
 
 use schema_registry_converter::blocking::json::JsonDecoder as RegistryJsonDecoder;
 use schema_registry_converter::blocking::json::JsonEncoder as RegistryJsonEncoder;
@@ -23,13 +23,14 @@ use serde::de::{IgnoredAny, MapAccess, SeqAccess, Visitor};
 use serde::ser::{SerializeMap, Serializer};
 use serde::Deserializer as _;
 use serde_json::json;
+use serde_json::Map as JsonMap;
 use serde_json::Value as JsonValue;
 
 use super::{
     ensure_all_fields_in_schema, parse_value_from_json, prepare_plaintext_str,
     serialize_value_to_json, values_by_names_from_json, Formatter, FormatterContext,
     FormatterError, InnerSchemaField, ParseError, ParseResult, ParsedEventWithErrors, Parser,
-    ValueFieldsWithErrors, COMMIT_LITERAL,
+    SchemaEvolutionPolicy, ValueField, ValueFieldsWithErrors, COMMIT_LITERAL,
 };
 
 #[derive(Debug, Copy, Clone)]
@@ -37,6 +38,7 @@ pub enum FieldSource {
     Key,
     Payload,
     Metadata,
+    Headers,
 }
 
 #[derive(Default, Debug)]
@@ -60,7 +62,7 @@ impl FieldSourceLists {
         match source {
             FieldSource::Key => self.to_parse_from_key.push(name),
             FieldSource::Payload => self.to_parse_from_payload.push(name),
-            FieldSource::Metadata => {}
+            FieldSource::Metadata | FieldSource::Headers => {}
         }
     }
 }
@@ -72,9 +74,14 @@ pub struct JsonLinesParser {
     field_absence_is_error: bool,
     schema: HashMap<String, InnerSchemaField>,
     metadata_column_value: Value,
+    headers_column_value: Value,
     session_type: SessionType,
     schema_registry_decoder: Option<RegistryJsonDecoder>,
 
+    // The top-level fields of the payload that are read, set if the fields not present
+    // in the schema are errors.
+    known_payload_fields: Option<HashSet<String>>,
+
     // Fast-path state: when all value fields come from the payload as top-level
     // keys (no JSON-pointer paths, no key fields, no schema-registry decoder),
     // the payload is extracted with a single streaming pass that never
@@ -144,13 +151,69 @@ impl JsonLinesParser {
             field_absence_is_error,
             schema,
             metadata_column_value: Value::None,
+            headers_column_value: Value::None,
             session_type,
             schema_registry_decoder,
+            known_payload_fields: None,
             can_use_fast_json,
             payload_field_index,
         })
     }
 
+    /// Sets how the fields of the payload not matching the schema are handled.
+    #[must_use]
+    pub fn with_schema_evolution_policy(mut self, policy: SchemaEvolutionPolicy) -> Self {
+        match policy {
+            SchemaEvolutionPolicy::IgnoreNewFields => {}
+            SchemaEvolutionPolicy::FillMissing => self.field_absence_is_error = false,
+            SchemaEvolutionPolicy::Fail => {
+                self.field_absence_is_error = true;
+                self.known_payload_fields = self.top_level_payload_fields();
+                // the fast path skips the fields it doesn't need without looking at them
+                self.can_use_fast_json = false;
+            }
+        }
+        self
+    }
+
+    // The top-level fields of the payload the values are read from, `None` if a value
+    // is the whole payload.
+    fn top_level_payload_fields(&self) -> Option<HashSet<String>> {
+        let key_fields = self
+            .key_field_source_lists
+            .iter()
+            .flat_map(|lists| lists.to_parse_from_payload.iter());
+        let value_fields = self.value_field_source_lists.to_parse_from_payload.iter();
+        key_fields
+            .chain(value_fields)
+            .map(|name| match self.column_paths.get(name) {
+                Some(path) => {
+                    let first_token = path.strip_prefix('/')?.split('/').next()?;
+                    Some(first_token.replace("~1", "/").replace("~0", "~"))
+                }
+                None => Some(name.clone()),
+            })
+            .collect()
+    }
+
+    fn check_no_unexpected_fields(&self, payload: &JsonValue) -> Result<(), ParseError> {
+        let (Some(known_fields), JsonValue::Object(payload)) =
+            (&self.known_payload_fields, payload)
+        else {
+            return Ok(());
+        };
+        let fields: Vec<String> = payload
+            .keys()
+            .filter(|field| !known_fields.contains(*field))
+            .cloned()
+            .collect();
+        if fields.is_empty() {
+            Ok(())
+        } else {
+            Err(ParseError::UnexpectedFields { fields })
+        }
+    }
+
     /// Streaming extraction of the payload fields, used when
     /// [`Self::can_use_fast_json`] holds. Returns the value-field results
     /// aligned to `value_field_source_lists.sources_order`, or `None` to
@@ -183,6 +246,7 @@ impl JsonLinesParser {
             match source {
                 FieldSource::Payload => result.push(payload_values.next().unwrap()),
                 FieldSource::Metadata => result.push(Ok(self.metadata_column_value.clone())),
+                FieldSource::Headers => result.push(Ok(self.headers_column_value.clone())),
                 // Excluded by `can_use_fast_json`.
                 FieldSource::Key => unreachable!("key fields disable the fast JSON path"),
             }
@@ -219,6 +283,7 @@ impl JsonLinesParser {
                 FieldSource::Key => result.push(fields_from_key_iter.next().unwrap()),
                 FieldSource::Payload => result.push(fields_from_payload_iter.next().unwrap()),
                 FieldSource::Metadata => result.push(Ok(self.metadata_column_value.clone())),
+                FieldSource::Headers => result.push(Ok(self.headers_column_value.clone())),
             }
         }
 
@@ -437,7 +502,9 @@ impl Parser for JsonLinesParser {
             if prepare_plaintext_str(raw_bytes_payload).is_ok_and(|s| s == COMMIT_LITERAL) {
                 return Ok(vec![ParsedEventWithErrors::AdvanceTime]);
             }
-            self.prepare_json(raw_bytes_payload)?
+            let payload = self.prepare_json(raw_bytes_payload)?;
+            self.check_no_unexpected_fields(&payload)?;
+            payload
         } else {
             JsonValue::Null
         };
@@ -454,6 +521,7 @@ impl Parser for JsonLinesParser {
     fn on_new_source_started(&mut self, metadata: &SourceMetadata) {
         let metadata_serialized: JsonValue = metadata.serialize();
         self.metadata_column_value = metadata_serialized.into();
+        self.headers_column_value = metadata.message_headers().map_or(Value::None, Into::into);
     }
 
     fn column_count(&self) -> usize {
@@ -484,10 +552,49 @@ impl RegistryEncoderWrapper {
     }
 }
 
+/// How the datetime values are encoded by the `JsonLinesFormatter`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JsonDateTimeFormat {
+    /// The string representation used across the connectors, with nanosecond
+    /// precision and the offset without a colon.
+    #[default]
+    Default,
+    /// An ISO-8601 string, with the offset in the `+HH:MM` form for the
+    /// datetimes with a time zone.
+    Iso8601,
+    /// An integer number of time units since the Unix epoch. The naive
+    /// datetimes are treated as if they were in UTC.
+    EpochSeconds,
+    EpochMilliseconds,
+    EpochMicroseconds,
+    EpochNanoseconds,
+}
+
+impl JsonDateTimeFormat {
+    fn encode(self, date_time: &impl DateTime, iso8601_format: &str) -> Option<JsonValue> {
+        let nanoseconds_per_unit = match self {
+            Self::Default => return None,
+            Self::Iso8601 => return Some(json!(date_time.strftime(iso8601_format))),
+            Self::EpochSeconds => 1_000_000_000,
+            Self::EpochMilliseconds => 1_000_000,
+            Self::EpochMicroseconds => 1_000,
+            Self::EpochNanoseconds => 1,
+        };
+        Some(json!(date_time
+            .timestamp()
+            .div_euclid(nanoseconds_per_unit)))
+    }
+}
+
 #[derive(Debug)]
 pub struct JsonLinesFormatter {
     value_field_names: Vec<String>,
     schema_registry_encoder: Option<RegistryEncoderWrapper>,
+    // The location of each value field in the output object, if it differs
+    // from the top-level field named after the column
+    output_paths: Option<Vec<Vec<String>>>,
+    with_special_fields: bool,
+    datetime_format: JsonDateTimeFormat,
 }
 
 impl JsonLinesFormatter {
@@ -498,15 +605,182 @@ impl JsonLinesFormatter {
         JsonLinesFormatter {
             value_field_names,
             schema_registry_encoder,
+            output_paths: None,
+            with_special_fields: true,
+            datetime_format: JsonDateTimeFormat::Default,
+        }
+    }
+
+    /// Places the values of the given columns at the given locations of the output
+    /// objects, creating the nested objects on the way. The locations are given as
+    /// JSON Pointers (RFC 6901), so a column is renamed if its pointer has a single
+    /// component. The other columns stay in the top-level fields named after them.
+    pub fn with_field_paths(
+        mut self,
+        field_paths: &HashMap<String, String>,
+    ) -> Result<Self, FormatterError> {
+        for name in field_paths.keys() {
+            if !self.value_field_names.contains(name) {
+                return Err(FormatterError::UnknownJsonFieldPathColumn(name.clone()));
+            }
+        }
+        let mut output_paths = Vec::with_capacity(self.value_field_names.len());
+        for name in &self.value_field_names {
+            let path = match field_paths.get(name) {
+                Some(pointer) => Self::parse_json_pointer(pointer)?,
+                None => vec![name.clone()],
+            };
+            output_paths.push(path);
+        }
+        self.output_paths = Some(output_paths);
+        self.ensure_output_paths_consistent()?;
+        Ok(self)
+    }
+
+    /// Sets whether the `diff` and `time` fields are added to the output objects.
+    pub fn with_special_fields(
+        mut self,
+        with_special_fields: bool,
+    ) -> Result<Self, FormatterError> {
+        self.with_special_fields = with_special_fields;
+        self.ensure_output_paths_consistent()?;
+        Ok(self)
+    }
+
+    #[must_use]
+    pub fn with_datetime_format(mut self, datetime_format: JsonDateTimeFormat) -> Self {
+        self.datetime_format = datetime_format;
+        self
+    }
+
+    fn parse_json_pointer(pointer: &str) -> Result<Vec<String>, FormatterError> {
+        let Some(components) = pointer.strip_prefix('/') else {
+            return Err(FormatterError::InvalidJsonFieldPath(pointer.to_string()));
+        };
+        components
+            .split('/')
+            .map(|component| {
+                if component.is_empty() {
+                    Err(FormatterError::InvalidJsonFieldPath(pointer.to_string()))
+                } else {
+                    Ok(component.replace("~1", "/").replace("~0", "~"))
+                }
+            })
+            .collect()
+    }
+
+    fn ensure_output_paths_consistent(&self) -> Result<(), FormatterError> {
+        let Some(output_paths) = &self.output_paths else {
+            return Ok(());
+        };
+        let special_paths = [
+            vec![SPECIAL_FIELD_DIFF.to_string()],
+            vec![SPECIAL_FIELD_TIME.to_string()],
+        ];
+        let special_paths = if self.with_special_fields {
+            &special_paths[..]
+        } else {
+            &[]
+        };
+        let all_paths: Vec<_> = output_paths.iter().chain(special_paths).collect();
+        for (index, path) in all_paths.iter().enumerate() {
+            for other_path in &all_paths[index + 1..] {
+                // A field can't be both a value and an object holding other values
+                if path.starts_with(other_path) || other_path.starts_with(path) {
+                    return Err(FormatterError::ConflictingJsonFieldPaths(
+                        format!("/{}", path.join("/")),
+                        format!("/{}", other_path.join("/")),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn value_to_json(&self, value: &Value) -> Result<JsonValue, FormatterError> {
+        let encoded_date_time = match value {
+            Value::DateTimeNaive(dt) => self.datetime_format.encode(dt, "%Y-%m-%dT%H:%M:%S%.9f"),
+            Value::DateTimeUtc(dt) => self.datetime_format.encode(dt, "%Y-%m-%dT%H:%M:%S%.9f%:z"),
+            Value::DateTimeZoned(dt) => self.datetime_format.encode(dt, "%Y-%m-%dT%H:%M:%S%.9f%:z"),
+            _ => None,
+        };
+        match encoded_date_time {
+            Some(encoded_date_time) => Ok(encoded_date_time),
+            None => serialize_value_to_json(value),
         }
     }
 
+    fn is_shaped(&self) -> bool {
+        self.output_paths.is_some()
+            || !self.with_special_fields
+            || self.datetime_format != JsonDateTimeFormat::Default
+    }
+
+    fn construct_json_object(&self, values: &[Value]) -> Result<JsonMap, FormatterError> {
+        let mut root = JsonMap::with_capacity(values.len() + 2);
+        for (index, (name, value)) in zip(&self.value_field_names, values).enumerate() {
+            let value = self.value_to_json(value)?;
+            let Some(output_paths) = &self.output_paths else {
+                root.insert(name.clone(), value);
+                continue;
+            };
+            let (field_name, parents) = output_paths[index]
+                .split_last()
+                .expect("output paths are non-empty");
+            let mut object = &mut root;
+            for parent in parents {
+                object = object
+                    .entry(parent.clone())
+                    .or_insert_with(|| JsonValue::Object(JsonMap::new()))
+                    .as_object_mut()
+                    .expect("output paths are checked not to conflict");
+            }
+            object.insert(field_name.clone(), value);
+        }
+        Ok(root)
+    }
+
+    fn construct_json_value(
+        &self,
+        values: &[Value],
+        time: Timestamp,
+        diff: isize,
+    ) -> Result<JsonValue, FormatterError> {
+        let mut root = self.construct_json_object(values)?;
+        if self.with_special_fields {
+            root.insert(SPECIAL_FIELD_DIFF.to_string(), json!(diff));
+            root.insert(SPECIAL_FIELD_TIME.to_string(), json!(time));
+        }
+        Ok(JsonValue::Object(root))
+    }
+
+    // An update is told apart from an insertion by the `diff` field and the `before`
+    // field holding the previous values, so the latter can't be taken by a column.
+    fn can_format_updates(&self) -> bool {
+        let is_before_taken = match &self.output_paths {
+            Some(output_paths) => output_paths
+                .iter()
+                .any(|path| path[0] == SPECIAL_FIELD_BEFORE),
+            None => self
+                .value_field_names
+                .iter()
+                .any(|name| name == SPECIAL_FIELD_BEFORE),
+        };
+        self.with_special_fields && self.schema_registry_encoder.is_none() && !is_before_taken
+    }
+
     fn construct_json_as_raw_bytes(
         &mut self,
         values: &[Value],
         time: Timestamp,
         diff: isize,
     ) -> Result<Vec<u8>, FormatterError> {
+        if self.is_shaped() {
+            let json_value = self.construct_json_value(values, time, diff)?;
+            return Ok(
+                serde_json::to_vec(&json_value).expect("JSON value serialization can't fail")
+            );
+        }
         let mut serializer = serde_json::Serializer::new(Vec::<u8>::new());
         let mut map = serializer
             .serialize_map(Some(self.value_field_names.len() + 2))
@@ -522,21 +796,16 @@ impl JsonLinesFormatter {
     }
 
     fn construct_json_with_encoder(
-        encoder: &mut RegistryEncoderWrapper,
-        value_field_names: &[String],
+        &mut self,
         values: &[Value],
         time: Timestamp,
         diff: isize,
     ) -> Result<Vec<u8>, FormatterError> {
-        let mut json_payload = json!({
-            SPECIAL_FIELD_DIFF: diff,
-            SPECIAL_FIELD_TIME: time,
-        });
-        let json_payload_map = json_payload.as_object_mut().unwrap();
-        for (key, value) in zip(value_field_names.iter(), values) {
-            json_payload_map.insert(key.clone(), serialize_value_to_json(value)?);
-        }
-        encoder.encode(&json_payload)
+        let json_payload = self.construct_json_value(values, time, diff)?;
+        self.schema_registry_encoder
+            .as_mut()
+            .expect("schema registry encoder must be present")
+            .encode(&json_payload)
     }
 }
 
@@ -548,15 +817,10 @@ impl Formatter for JsonLinesFormatter {
         time: Timestamp,
         diff: isize,
     ) -> Result<FormatterContext, FormatterError> {
-        let raw_bytes = match self.schema_registry_encoder.as_mut() {
-            Some(encoder) => Self::construct_json_with_encoder(
-                encoder,
-                &self.value_field_names,
-                values,
-                time,
-                diff,
-            ),
-            None => self.construct_json_as_raw_bytes(values, time, diff),
+        let raw_bytes = if self.schema_registry_encoder.is_some() {
+            self.construct_json_with_encoder(values, time, diff)
+        } else {
+            self.construct_json_as_raw_bytes(values, time, diff)
         }?;
 
         Ok(FormatterContext::new_single_payload(
@@ -567,4 +831,29 @@ impl Formatter for JsonLinesFormatter {
             diff,
         ))
     }
+
+    fn format_update(
+        &mut self,
+        key: &Key,
+        old_values: &[Value],
+        new_values: &[Value],
+        time: Timestamp,
+    ) -> Result<Option<FormatterContext>, FormatterError> {
+        if !self.can_format_updates() {
+            return Ok(None);
+        }
+        let mut root = self.construct_json_object(new_values)?;
+        root.insert(
+            SPECIAL_FIELD_BEFORE.to_string(),
+            JsonValue::Object(self.construct_json_object(old_values)?),
+        );
+        root.insert(SPECIAL_FIELD_DIFF.to_string(), json!(1));
+        root.insert(SPECIAL_FIELD_TIME.to_string(), json!(time));
+        let raw_bytes = serde_json::to_vec(&JsonValue::Object(root))
+            .expect("JSON value serialization can't fail");
+        Ok(Some(
+            FormatterContext::new_single_payload(raw_bytes, *key, new_values.to_vec(), time, 1)
+                .with_old_values(old_values.to_vec()),
+        ))
+    }
 }
@@ -0,0 +1,135 @@
+// Copyright © 2026 Pathway
+
+//! Engine metadata columns appended to the rows of an output connector.
+//!
+//! A sink may request some of the engine-level details of each change to be written
+//! alongside the columns of the table: the time of the change, its diff, the index of
+//! the worker that processed it and the lineage of the pipeline run, that is, the run id
+//! and the graph version (see [`RunLineage`]). The values are appended after the columns
+//! of the table, in the order in which the metadata columns are listed, before the row
+//! is passed to the formatter of the sink. Hence they are handled by the formatter and
+//! by the writer exactly like the regular columns.
+
+use std::borrow::Cow;
+
+use crate::engine::{Key, Result, RunLineage, Timestamp, Type, Value};
+
+use super::{Formatter, FormatterContext, FormatterError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetadataField {
+    Time,
+    Diff,
+    Worker,
+    RunId,
+    GraphVersion,
+}
+
+impl MetadataField {
+    pub fn type_(self) -> Type {
+        match self {
+            Self::Time | Self::Diff | Self::Worker => Type::Int,
+            Self::RunId => Type::String,
+            Self::GraphVersion => Type::Optional(Type::String.into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SinkMetadataColumn {
+    pub name: String,
+    pub field: MetadataField,
+}
+
+impl SinkMetadataColumn {
+    pub fn new(name: String, field: MetadataField) -> Self {
+        Self { name, field }
+    }
+}
+
+pub struct MetadataColumnsFormatter {
+    inner: Box<dyn Formatter>,
+    fields: Vec<MetadataField>,
+    worker: Value,
+    run_id: Value,
+    graph_version: Value,
+}
+
+impl MetadataColumnsFormatter {
+    pub fn new(
+        inner: Box<dyn Formatter>,
+        columns: &[SinkMetadataColumn],
+        worker_index: usize,
+        lineage: &RunLineage,
+    ) -> Self {
+        Self {
+            inner,
+            fields: columns.iter().map(|column| column.field).collect(),
+            worker: Value::Int(worker_index.try_into().unwrap()),
+            run_id: Value::from(lineage.run_id.as_str()),
+            graph_version: lineage
+                .graph_version
+                .as_deref()
+                .map_or(Value::None, Value::from),
+        }
+    }
+
+    fn extend_values(&self, values: &[Value], time: Timestamp, diff: isize) -> Vec<Value> {
+        let mut extended = Vec::with_capacity(values.len() + self.fields.len());
+        extended.extend_from_slice(values);
+        extended.extend(self.fields.iter().map(|field| match field {
+            MetadataField::Time => Value::Int(time.0.try_into().unwrap_or(i64::MAX)),
+            MetadataField::Diff => Value::Int(diff.try_into().unwrap()),
+            MetadataField::Worker => self.worker.clone(),
+            MetadataField::RunId => self.run_id.clone(),
+            MetadataField::GraphVersion => self.graph_version.clone(),
+        }));
+        extended
+    }
+}
+
+impl Formatter for MetadataColumnsFormatter {
+    fn format(
+        &mut self,
+        key: &Key,
+        values: &[Value],
+        time: Timestamp,
+        diff: isize,
+    ) -> Result<FormatterContext, FormatterError> {
+        let extended = self.extend_values(values, time, diff);
+        self.inner.format(key, &extended, time, diff)
+    }
+
+    fn format_batch(
+        &mut self,
+        entries: &[(Key, &[Value], isize)],
+        time: Timestamp,
+    ) -> Result<Vec<FormatterContext>, FormatterError> {
+        let extended: Vec<_> = entries
+            .iter()
+            .map(|(key, values, diff)| (*key, self.extend_values(values, time, *diff), *diff))
+            .collect();
+        let entries: Vec<_> = extended
+            .iter()
+            .map(|(key, values, diff)| (*key, values.as_slice(), *diff))
+            .collect();
+        self.inner.format_batch(&entries, time)
+    }
+
+    fn format_update(
+        &mut self,
+        key: &Key,
+        old_values: &[Value],
+        new_values: &[Value],
+        time: Timestamp,
+    ) -> Result<Option<FormatterContext>, FormatterError> {
+        let old_extended = self.extend_values(old_values, time, -1);
+        let new_extended = self.extend_values(new_values, time, 1);
+        self.inner
+            .format_update(key, &old_extended, &new_extended, time)
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        self.inner.short_description()
+    }
+}
@@ -3,7 +3,7 @@
 use std::any::type_name;
 use std::borrow::Cow;
 use std::clone::Clone;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::str::{from_utf8, Utf8Error};
 
 use crate::connectors::metadata::SourceMetadata;
@@ -11,13 +11,15 @@ use crate::connectors::{DataEventType, Offset, ReaderContext, SessionType, Snaps
 use crate::engine::error::{limit_length, DynError, DynResult, STANDARD_OBJECT_LENGTH_LIMIT};
 use crate::engine::{
     value::parse_pathway_pointer, value::Kind as ValueKind, DateTimeNaive, DateTimeUtc,
-    Duration as EngineDuration, Error, Key, Result, Timestamp, Type, Value,
+    DateTimeZoned, Duration as EngineDuration, Error, Key, Result, Timestamp, Type, Value,
 };
 
+use arcstr::ArcStr;
 use async_nats::header::HeaderMap as NatsHeaders;
 use base64::engine::general_purpose::STANDARD as base64encoder;
 use base64::Engine;
 use bincode::ErrorKind as BincodeError;
+use deltalake::arrow::error::ArrowError;
 use itertools::Itertools;
 use mongodb::bson::Document as BsonDocument;
 use ndarray::ArrayD;
@@ -33,19 +35,24 @@ pub mod debezium;
 pub mod dsv;
 pub mod identity;
 pub mod json;
+pub mod metadata_columns;
 pub mod null;
 pub mod single_column;
+pub mod template;
 pub mod transparent;
 
 pub use bson::{BsonFormatter, BsonParser};
 pub use debezium::{DebeziumDBType, DebeziumMessageParser};
-pub use dsv::{DsvFormatter, DsvParser, DsvSettings};
+pub use dsv::{DsvDialect, DsvFormatter, DsvParser, DsvQuoting, DsvSettings};
 pub use identity::{IdentityFormatter, IdentityParser, KeyGenerationPolicy};
 pub use json::{
-    FieldSource, FieldSourceLists, JsonLinesFormatter, JsonLinesParser, RegistryEncoderWrapper,
+    FieldSource, FieldSourceLists, JsonDateTimeFormat, JsonLinesFormatter, JsonLinesParser,
+    RegistryEncoderWrapper,
 };
+pub use metadata_columns::{MetadataColumnsFormatter, MetadataField, SinkMetadataColumn};
 pub use null::NullFormatter;
 pub use single_column::SingleColumnFormatter;
+pub use template::{TemplateError, TemplateEscaping, TemplateFormatter, TextTemplate};
 pub use transparent::TransparentParser;
 
 pub const COMMIT_LITERAL: &str = "*COMMIT*";
@@ -199,6 +206,9 @@ pub enum ParseError {
     #[error("no value for {field_name:?} field and no default specified")]
     NoDefault { field_name: String },
 
+    #[error("fields {fields:?} aren't present in the schema")]
+    UnexpectedFields { fields: Vec<String> },
+
     #[error(transparent)]
     Bincode(#[from] BincodeError),
 
@@ -263,6 +273,68 @@ impl InnerSchemaField {
     }
 }
 
+/// How a parser handles the data whose fields don't match the schema, e.g. because the
+/// producer of the data has added or removed a field.
+///
+/// A field missing from the data always takes the default value declared in the schema,
+/// if there is one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SchemaEvolutionPolicy {
+    /// The fields not present in the schema are ignored. A missing field without a
+    /// default is handled as by the parser without a policy.
+    #[default]
+    IgnoreNewFields,
+    /// Like `IgnoreNewFields`, but a missing field without a default is `None`.
+    FillMissing,
+    /// The fields not present in the schema and the missing fields without a default
+    /// are errors.
+    Fail,
+}
+
+impl SchemaEvolutionPolicy {
+    /// The value of `field` when it's missing from the data, `None` if it's an error.
+    pub fn missing_field_value(self, field: &InnerSchemaField) -> Option<Value> {
+        match (&field.default, self) {
+            (Some(default), _) => Some(default.clone()),
+            (None, Self::FillMissing) => Some(Value::None),
+            (None, Self::IgnoreNewFields | Self::Fail) => None,
+        }
+    }
+
+    pub fn allows_new_fields(self) -> bool {
+        self != Self::Fail
+    }
+}
+
+/// A field of the schema of a connector.
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "pathway.engine", get_all))]
+#[derive(Clone)]
+pub struct ValueField {
+    pub name: String,
+    pub type_: Type,
+    pub source: FieldSource,
+    pub default: Option<Value>,
+    pub metadata: Option<String>,
+}
+
+impl ValueField {
+    pub fn new(name: String, type_: Type, source: FieldSource) -> Self {
+        let effective_source = if name == METADATA_FIELD_NAME {
+            FieldSource::Metadata
+        } else {
+            source
+        };
+
+        ValueField {
+            name,
+            type_,
+            source: effective_source,
+            default: None,
+            metadata: None,
+        }
+    }
+}
+
 fn prepare_plaintext_string(bytes: &[u8]) -> PrepareStringResult {
     Ok(prepare_plaintext_str(bytes)?.to_string())
 }
@@ -346,9 +418,23 @@ pub struct FormatterContext {
     pub values: Vec<Value>,
     pub time: Timestamp,
     pub diff: isize,
+    /// The previous values of the row, if the context is an update of the row, that is,
+    /// an insertion of `values` replacing them.
+    pub old_values: Option<Vec<Value>>,
 }
 
 impl FormatterContext {
+    /// Returns the number of bytes of the raw payloads.
+    pub fn payload_size(&self) -> usize {
+        self.payloads
+            .iter()
+            .map(|payload| match payload {
+                FormattedDocument::RawBytes(bytes) => bytes.len(),
+                FormattedDocument::Bson(_) => 0,
+            })
+            .sum()
+    }
+
     pub fn new(
         payloads: Vec<impl Into<FormattedDocument>>,
         key: Key,
@@ -366,6 +452,7 @@ impl FormatterContext {
             values,
             time,
             diff,
+            old_values: None,
         }
     }
 
@@ -382,9 +469,17 @@ impl FormatterContext {
             values,
             time,
             diff,
+            old_values: None,
         }
     }
 
+    /// Makes the context an update of the row from `old_values` to its values.
+    #[must_use]
+    pub fn with_old_values(mut self, old_values: Vec<Value>) -> FormatterContext {
+        self.old_values = Some(old_values);
+        self
+    }
+
     fn construct_message_headers(
         &self,
         header_fields: &[(String, usize)],
@@ -438,6 +533,24 @@ impl FormatterContext {
         }
         nats_headers
     }
+
+    pub fn construct_string_headers(
+        &self,
+        header_fields: &[(String, usize)],
+    ) -> HashMap<String, String> {
+        self.construct_message_headers(header_fields, true)
+            .into_iter()
+            .map(|header| {
+                let value = if let Some(value) = header.value {
+                    String::from_utf8(value)
+                        .expect("all prepared headers must be UTF-8 serializable")
+                } else {
+                    Value::None.to_string()
+                };
+                (header.key, value)
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -464,9 +577,24 @@ pub enum FormatterError {
     #[error(transparent)]
     Csv(#[from] csv::Error),
 
+    #[error(transparent)]
+    Arrow(#[from] ArrowError),
+
     #[error("CSV separator must be a 8-bit character, but '{0}' is provided")]
     UnsupportedCsvSeparator(char),
 
+    #[error("CSV field {0:?} can't be written without quoting, since no escape character is set")]
+    UnescapedCsvField(String),
+
+    #[error("invalid JSON Pointer {0:?}: it must start with '/' and have non-empty components")]
+    InvalidJsonFieldPath(String),
+
+    #[error("field path is given for column '{0}', which is not present in the table")]
+    UnknownJsonFieldPathColumn(String),
+
+    #[error("output JSON fields {0:?} and {1:?} conflict: a field can't hold both a value and other fields")]
+    ConflictingJsonFieldPaths(String, String),
+
     #[error(transparent)]
     SchemaRepository(#[from] SchemaRepositoryError),
 
@@ -483,6 +611,37 @@ pub trait Formatter: Send {
         diff: isize,
     ) -> Result<FormatterContext, FormatterError>;
 
+    /// Formats all entries of an output batch sharing the same `time`.
+    ///
+    /// The returned contexts correspond to the entries one-to-one and in the same order.
+    /// The default implementation formats the entries one by one, formatters that can
+    /// render a batch at once more efficiently should override it.
+    fn format_batch(
+        &mut self,
+        entries: &[(Key, &[Value], isize)],
+        time: Timestamp,
+    ) -> Result<Vec<FormatterContext>, FormatterError> {
+        entries
+            .iter()
+            .map(|(key, values, diff)| self.format(key, values, time, *diff))
+            .collect()
+    }
+
+    /// Formats the deletion of the row `old_values` and the insertion of the row
+    /// `new_values` with the same `key` and `time` as a single update record.
+    ///
+    /// Returns `None` if the format has no representation of an update, then the
+    /// deletion and the insertion are formatted separately, which is the default.
+    fn format_update(
+        &mut self,
+        _key: &Key,
+        _old_values: &[Value],
+        _new_values: &[Value],
+        _time: Timestamp,
+    ) -> Result<Option<FormatterContext>, FormatterError> {
+        Ok(None)
+    }
+
     fn short_description(&self) -> Cow<'static, str> {
         type_name::<Self>().into()
     }
@@ -528,10 +687,13 @@ fn parse_str_with_type(raw_value: &str, type_: &Type) -> Result<Value, DynError>
             | Type::Pointer
             | Type::DateTimeUtc
             | Type::DateTimeNaive
+            | Type::DateTimeZoned
             | Type::Duration
             | Type::Array(_, _)
             | Type::List(_)
-            | Type::Tuple(_) => return Ok(Value::None),
+            | Type::Tuple(_)
+            | Type::Struct(_)
+            | Type::Map(_) => return Ok(Value::None),
             // "null" is ambiguous, since it can also correspond to a serialized JSON
             // Anything else can be safely treated as a `Value::None`
             Type::Json if raw_value != "null" => return Ok(Value::None),
@@ -563,6 +725,7 @@ fn parse_str_with_type(raw_value: &str, type_: &Type) -> Result<Value, DynError>
             let dt = DateTimeNaive::strptime(raw_value, "%Y-%m-%dT%H:%M:%S%.f")?;
             Ok(dt.into())
         }
+        Type::DateTimeZoned => Ok(DateTimeZoned::parse(raw_value)?.into()),
         Type::Duration => {
             let duration_ns: i64 = raw_value.parse()?;
             let engine_duration = EngineDuration::new_with_unit(duration_ns, "ns")
@@ -573,7 +736,7 @@ fn parse_str_with_type(raw_value: &str, type_: &Type) -> Result<Value, DynError>
             let bytes = base64::engine::general_purpose::STANDARD.decode(raw_value)?;
             Ok(Value::Bytes(bytes.into()))
         }
-        Type::Array(_, _) | Type::List(_) | Type::Tuple(_) => {
+        Type::Array(_, _) | Type::List(_) | Type::Tuple(_) | Type::Struct(_) | Type::Map(_) => {
             let json: JsonValue = serde_json::from_str(raw_value)?;
             let value =
                 parse_value_from_json(&json, type_).ok_or(ParseError::MalformedComplexField)?;
@@ -627,6 +790,10 @@ pub fn ensure_all_fields_in_schema(
 /// "magic field" containing the metadata
 pub const METADATA_FIELD_NAME: &str = "_metadata";
 
+/// "magic field" containing the headers of the message, for the formats that don't
+/// read the fields by their sources
+pub const HEADERS_FIELD_NAME: &str = "_headers";
+
 fn value_from_bytes(bytes: &[u8], parse_utf8: bool) -> DynResult<Value> {
     if parse_utf8 {
         Ok(Value::String(prepare_plaintext_string(bytes)?.into()))
@@ -654,6 +821,29 @@ fn parse_tuple_from_json(values: &[JsonValue], dtypes: &[Type]) -> Option<Value>
     Some(Value::from(tuple))
 }
 
+fn parse_struct_from_json(
+    values: &JsonMap<String, JsonValue>,
+    fields: &[(ArcStr, Type)],
+) -> Option<Value> {
+    let mut parsed_fields = Vec::with_capacity(fields.len());
+    for (name, dtype) in fields {
+        let value = values.get(name.as_str()).unwrap_or(&JsonValue::Null);
+        parsed_fields.push((name.clone(), parse_value_from_json(value, dtype)?));
+    }
+    Some(Value::from(parsed_fields))
+}
+
+fn parse_map_from_json(values: &JsonMap<String, JsonValue>, value_type: &Type) -> Option<Value> {
+    let mut map = BTreeMap::new();
+    for (key, value) in values {
+        map.insert(
+            ArcStr::from(key.as_str()),
+            parse_value_from_json(value, value_type)?,
+        );
+    }
+    Some(Value::from(map))
+}
+
 fn parse_ndarray_from_json(value: &JsonMap<String, JsonValue>, dtype: &Type) -> Option<Value> {
     let JsonValue::Array(ref elements) = value[NDARRAY_ELEMENTS_FIELD_NAME] else {
         return None;
@@ -779,6 +969,9 @@ pub fn parse_value_from_json(value: &JsonValue, dtype: &Type) -> Option<Value> {
                 None
             }
         }
+        (Type::DateTimeZoned, JsonValue::String(s)) => {
+            DateTimeZoned::parse(s).ok().map(Value::DateTimeZoned)
+        }
         (Type::DateTimeNaive, JsonValue::String(s)) => {
             let engine_datetime = DateTimeNaive::strptime(s, "%Y-%m-%dT%H:%M:%S%.f");
             if let Ok(engine_datetime) = engine_datetime {
@@ -790,6 +983,8 @@ pub fn parse_value_from_json(value: &JsonValue, dtype: &Type) -> Option<Value> {
         (Type::Json, value) => Some(Value::from(value.clone())),
         (Type::Tuple(dtypes), JsonValue::Array(v)) => parse_tuple_from_json(v, dtypes),
         (Type::List(arg), JsonValue::Array(v)) => parse_list_from_json(v, arg),
+        (Type::Struct(fields), JsonValue::Object(v)) => parse_struct_from_json(v, fields),
+        (Type::Map(value_type), JsonValue::Object(v)) => parse_map_from_json(v, value_type),
         (Type::Array(_, nested_type), JsonValue::Object(v)) => {
             parse_ndarray_from_json(v, nested_type.as_ref())
         }
@@ -812,6 +1007,20 @@ pub fn serialize_value_to_json(value: &Value) -> Result<JsonValue, FormatterErro
             }
             Ok(JsonValue::Array(items))
         }
+        Value::Struct(fields) => {
+            let mut items = JsonMap::with_capacity(fields.len());
+            for (name, item) in fields.iter() {
+                items.insert(name.to_string(), serialize_value_to_json(item)?);
+            }
+            Ok(JsonValue::Object(items))
+        }
+        Value::Map(map) => {
+            let mut items = JsonMap::with_capacity(map.len());
+            for (key, item) in map.iter() {
+                items.insert(key.to_string(), serialize_value_to_json(item)?);
+            }
+            Ok(JsonValue::Object(items))
+        }
         Value::Bytes(b) => {
             let encoded = base64::engine::general_purpose::STANDARD.encode(b);
             Ok(json!(encoded))
@@ -840,6 +1049,7 @@ pub fn serialize_value_to_json(value: &Value) -> Result<JsonValue, FormatterErro
         }
         Value::DateTimeNaive(dt) => Ok(json!(dt.to_string())),
         Value::DateTimeUtc(dt) => Ok(json!(dt.to_string())),
+        Value::DateTimeZoned(dt) => Ok(json!(dt.to_string())),
         Value::Duration(d) => Ok(json!(d.nanoseconds())),
         Value::Json(j) => Ok((**j).clone()),
         Value::PyObjectWrapper(_) => {
@@ -0,0 +1,589 @@
+// Copyright © 2026 Pathway
+
+//! Rendering of rows through text templates.
+//!
+//! The syntax is a small subset of Jinja. A placeholder `{{ column }}` is replaced with
+//! the value of the column, and the value can be passed through a chain of filters, as in
+//! `{{ name | upper | ljust(10) }}`. Besides the columns of the table, a placeholder may
+//! refer to `time` and `diff` of the change, unless the table has columns with these
+//! names, and to a string literal, which is also the way to write a literal `{{`:
+//! `{{ "{{" }}`.
+//!
+//! The supported filters are:
+//! - `upper`, `lower`, `trim` change the case or strip the surrounding whitespace;
+//! - `default(text)` replaces a `None` or an empty value with `text`;
+//! - `ljust(width)`, `rjust(width)`, `center(width)` pad the value to `width` characters,
+//!   with the fill character given by the optional second argument: `rjust(8, "0")`;
+//! - `truncate(width)` cuts the value to at most `width` characters;
+//! - `json` renders the value as JSON, so that, for example, strings are quoted;
+//! - `escape(mode)` escapes the value in one of the modes of [`TemplateEscaping`], HTML if
+//!   the mode isn't given;
+//! - `safe` exempts the value from the escaping set for the whole template.
+//!
+//! A template may escape all the values it renders, so that the text of the columns can't
+//! break the structure of the output, for instance an HTML alert message or a syslog line.
+//! The placeholders with the `escape` or the `safe` filter and the string literals are left
+//! as they are.
+//!
+//! The template is compiled when the formatter is created, so that mistakes such as a
+//! reference to an unknown column are reported before any data is processed.
+
+use std::iter::{repeat, Peekable};
+use std::str::FromStr;
+use std::vec::IntoIter;
+
+use base64::engine::general_purpose::STANDARD as base64encoder;
+use base64::Engine;
+use serde_json::Value as JsonValue;
+
+use crate::engine::{Key, Result, Timestamp, Value};
+
+use super::{serialize_value_to_json, Formatter, FormatterContext, FormatterError};
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum TemplateError {
+    #[error("placeholder opened at byte {0} of the template is never closed")]
+    UnclosedPlaceholder(usize),
+
+    #[error("template refers to an unknown column {0:?}")]
+    UnknownColumn(String),
+
+    #[error("unknown template filter {0:?}")]
+    UnknownFilter(String),
+
+    #[error("template filter {filter:?} expects {expected}")]
+    InvalidFilterArguments {
+        filter: String,
+        expected: &'static str,
+    },
+
+    #[error("invalid placeholder {0:?}: {1}")]
+    InvalidPlaceholder(String, &'static str),
+
+    #[error("unknown escaping mode {0:?}, expected one of: none, html, json, single_line")]
+    UnknownEscaping(String),
+}
+
+/// How the values rendered by a template are escaped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TemplateEscaping {
+    /// The values are rendered as they are.
+    #[default]
+    None,
+    /// The characters having a special meaning in HTML and XML are replaced with entities.
+    Html,
+    /// The values are escaped as the contents of a JSON string, without the quotes.
+    Json,
+    /// The backslashes, the line feeds and the carriage returns are escaped as `\\`,
+    /// `\n` and `\r`, so that each rendered row occupies a single line.
+    SingleLine,
+}
+
+impl FromStr for TemplateEscaping {
+    type Err = TemplateError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "none" => Ok(Self::None),
+            "html" => Ok(Self::Html),
+            "json" => Ok(Self::Json),
+            "single_line" => Ok(Self::SingleLine),
+            _ => Err(TemplateError::UnknownEscaping(name.to_string())),
+        }
+    }
+}
+
+impl TemplateEscaping {
+    fn escape(self, text: String) -> String {
+        match self {
+            Self::None => text,
+            Self::Html => {
+                let mut escaped = String::with_capacity(text.len());
+                for c in text.chars() {
+                    match c {
+                        '&' => escaped.push_str("&amp;"),
+                        '<' => escaped.push_str("&lt;"),
+                        '>' => escaped.push_str("&gt;"),
+                        '"' => escaped.push_str("&quot;"),
+                        '\'' => escaped.push_str("&#39;"),
+                        c => escaped.push(c),
+                    }
+                }
+                escaped
+            }
+            Self::Json => {
+                let quoted = JsonValue::from(text).to_string();
+                quoted[1..quoted.len() - 1].to_string()
+            }
+            Self::SingleLine => {
+                let mut escaped = String::with_capacity(text.len());
+                for c in text.chars() {
+                    match c {
+                        '\\' => escaped.push_str("\\\\"),
+                        '\n' => escaped.push_str("\\n"),
+                        '\r' => escaped.push_str("\\r"),
+                        c => escaped.push(c),
+                    }
+                }
+                escaped
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Identifier(String),
+    Str(String),
+    Int(usize),
+    Pipe,
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+#[derive(Debug)]
+enum Source {
+    Column(usize),
+    Time,
+    Diff,
+    Literal(String),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Alignment {
+    Left,
+    Right,
+    Center,
+}
+
+#[derive(Debug)]
+enum Filter {
+    Upper,
+    Lower,
+    Trim,
+    Default(String),
+    Pad {
+        alignment: Alignment,
+        width: usize,
+        fill: char,
+    },
+    Truncate(usize),
+    Json,
+    Escape(TemplateEscaping),
+    Safe,
+}
+
+#[derive(Debug)]
+struct Placeholder {
+    source: Source,
+    filters: Vec<Filter>,
+}
+
+#[derive(Debug)]
+enum Segment {
+    Text(String),
+    Placeholder(Placeholder),
+}
+
+/// The intermediate result of a placeholder. The value is kept as is until a filter
+/// needs its text, so that `default` and `json` can tell `None` and non-string values
+/// apart.
+enum Rendered<'a> {
+    Value(&'a Value),
+    Text(String),
+}
+
+impl Rendered<'_> {
+    fn into_text(self) -> Result<String, FormatterError> {
+        match self {
+            Self::Value(value) => value_to_text(value),
+            Self::Text(text) => Ok(text),
+        }
+    }
+}
+
+fn value_to_text(value: &Value) -> Result<String, FormatterError> {
+    let text = match value {
+        Value::None => String::new(),
+        Value::String(s) => s.to_string(),
+        Value::Bytes(b) => base64encoder.encode(b),
+        Value::Duration(d) => d.nanoseconds().to_string(),
+        Value::Json(_)
+        | Value::IntArray(_)
+        | Value::FloatArray(_)
+        | Value::Tuple(_)
+        | Value::Struct(_)
+        | Value::Map(_) => serialize_value_to_json(value)?.to_string(),
+        _ => value.to_string(),
+    };
+    Ok(text)
+}
+
+fn pad(text: String, alignment: Alignment, width: usize, fill: char) -> String {
+    let length = text.chars().count();
+    if length >= width {
+        return text;
+    }
+    let missing = width - length;
+    let (left, right) = match alignment {
+        Alignment::Left => (0, missing),
+        Alignment::Right => (missing, 0),
+        Alignment::Center => (missing / 2, missing - missing / 2),
+    };
+    let mut padded = String::with_capacity(text.len() + missing * fill.len_utf8());
+    padded.extend(repeat(fill).take(left));
+    padded.push_str(&text);
+    padded.extend(repeat(fill).take(right));
+    padded
+}
+
+enum Argument {
+    Int(usize),
+    Str(String),
+}
+
+impl Filter {
+    fn new(name: &str, arguments: &[Argument]) -> Result<Self, TemplateError> {
+        let invalid_arguments = |expected| TemplateError::InvalidFilterArguments {
+            filter: name.to_string(),
+            expected,
+        };
+        match (name, arguments) {
+            ("upper", []) => Ok(Self::Upper),
+            ("lower", []) => Ok(Self::Lower),
+            ("trim", []) => Ok(Self::Trim),
+            ("json", []) => Ok(Self::Json),
+            ("safe", []) => Ok(Self::Safe),
+            ("escape", []) => Ok(Self::Escape(TemplateEscaping::Html)),
+            ("escape", [Argument::Str(mode)]) => Ok(Self::Escape(mode.parse()?)),
+            ("default", [Argument::Str(text)]) => Ok(Self::Default(text.clone())),
+            ("truncate", [Argument::Int(width)]) => Ok(Self::Truncate(*width)),
+            ("ljust" | "rjust" | "center", [Argument::Int(width), rest @ ..]) => {
+                let fill = match rest {
+                    [] => Some(' '),
+                    [Argument::Str(fill)] if fill.chars().count() == 1 => fill.chars().next(),
+                    _ => None,
+                }
+                .ok_or_else(|| invalid_arguments("a width and an optional fill character"))?;
+                let alignment = match name {
+                    "ljust" => Alignment::Left,
+                    "rjust" => Alignment::Right,
+                    _ => Alignment::Center,
+                };
+                Ok(Self::Pad {
+                    alignment,
+                    width: *width,
+                    fill,
+                })
+            }
+            ("upper" | "lower" | "trim" | "json" | "safe", _) => {
+                Err(invalid_arguments("no arguments"))
+            }
+            ("escape", _) => Err(invalid_arguments("an optional escaping mode")),
+            ("default", _) => Err(invalid_arguments("a single string argument")),
+            ("truncate", _) => Err(invalid_arguments("a single width argument")),
+            ("ljust" | "rjust" | "center", _) => {
+                Err(invalid_arguments("a width and an optional fill character"))
+            }
+            _ => Err(TemplateError::UnknownFilter(name.to_string())),
+        }
+    }
+
+    fn apply<'a>(&self, input: Rendered<'a>) -> Result<Rendered<'a>, FormatterError> {
+        let rendered = match (self, input) {
+            (Self::Default(default), Rendered::Value(Value::None)) => default.clone(),
+            (Self::Json, Rendered::Value(value)) => serialize_value_to_json(value)?.to_string(),
+            (filter, input) => {
+                let text = input.into_text()?;
+                match filter {
+                    Self::Upper => text.to_uppercase(),
+                    Self::Lower => text.to_lowercase(),
+                    Self::Trim => text.trim().to_string(),
+                    Self::Default(default) => {
+                        if text.is_empty() {
+                            default.clone()
+                        } else {
+                            text
+                        }
+                    }
+                    Self::Pad {
+                        alignment,
+                        width,
+                        fill,
+                    } => pad(text, *alignment, *width, *fill),
+                    Self::Truncate(width) => text.chars().take(*width).collect(),
+                    Self::Json => JsonValue::from(text).to_string(),
+                    Self::Escape(escaping) => escaping.escape(text),
+                    Self::Safe => text,
+                }
+            }
+        };
+        Ok(Rendered::Text(rendered))
+    }
+}
+
+impl Placeholder {
+    fn parse(placeholder: &str, column_names: &[String]) -> Result<Self, TemplateError> {
+        let invalid =
+            |reason| TemplateError::InvalidPlaceholder(placeholder.trim().to_string(), reason);
+        let mut tokens = tokenize(placeholder)
+            .ok_or_else(|| invalid("can't split into tokens"))?
+            .into_iter()
+            .peekable();
+        let source = match tokens.next() {
+            Some(Token::Identifier(name)) => {
+                if let Some(index) = column_names.iter().position(|column| *column == name) {
+                    Source::Column(index)
+                } else if name == "time" {
+                    Source::Time
+                } else if name == "diff" {
+                    Source::Diff
+                } else {
+                    return Err(TemplateError::UnknownColumn(name));
+                }
+            }
+            Some(Token::Str(literal)) => Source::Literal(literal),
+            _ => return Err(invalid("expected a column name or a string literal")),
+        };
+        let mut filters = Vec::new();
+        while let Some(token) = tokens.next() {
+            if token != Token::Pipe {
+                return Err(invalid("expected '|' before a filter"));
+            }
+            let Some(Token::Identifier(name)) = tokens.next() else {
+                return Err(invalid("expected a filter name after '|'"));
+            };
+            let arguments = Self::parse_arguments(&mut tokens).ok_or_else(|| {
+                invalid("filter arguments must be numbers or strings separated by commas")
+            })?;
+            filters.push(Filter::new(&name, &arguments)?);
+        }
+        Ok(Self { source, filters })
+    }
+
+    fn is_escaped(&self) -> bool {
+        matches!(self.source, Source::Literal(_))
+            || self
+                .filters
+                .iter()
+                .any(|filter| matches!(filter, Filter::Escape(_) | Filter::Safe))
+    }
+
+    fn parse_arguments(tokens: &mut Peekable<IntoIter<Token>>) -> Option<Vec<Argument>> {
+        let mut arguments = Vec::new();
+        if tokens.next_if_eq(&Token::LeftParen).is_none() {
+            return Some(arguments);
+        }
+        if tokens.next_if_eq(&Token::RightParen).is_some() {
+            return Some(arguments);
+        }
+        loop {
+            match tokens.next()? {
+                Token::Int(value) => arguments.push(Argument::Int(value)),
+                Token::Str(value) => arguments.push(Argument::Str(value)),
+                _ => return None,
+            }
+            match tokens.next()? {
+                Token::Comma => {}
+                Token::RightParen => return Some(arguments),
+                _ => return None,
+            }
+        }
+    }
+
+    fn render(
+        &self,
+        values: &[Value],
+        time: Timestamp,
+        diff: isize,
+        escaping: TemplateEscaping,
+        output: &mut String,
+    ) -> Result<(), FormatterError> {
+        let mut rendered = match &self.source {
+            Source::Column(index) => Rendered::Value(
+                values
+                    .get(*index)
+                    .ok_or(FormatterError::IncorrectColumnIndex)?,
+            ),
+            Source::Time => Rendered::Text(time.to_string()),
+            Source::Diff => Rendered::Text(diff.to_string()),
+            Source::Literal(text) => Rendered::Text(text.clone()),
+        };
+        for filter in &self.filters {
+            rendered = filter.apply(rendered)?;
+        }
+        let text = rendered.into_text()?;
+        if self.is_escaped() {
+            output.push_str(&text);
+        } else {
+            output.push_str(&escaping.escape(text));
+        }
+        Ok(())
+    }
+}
+
+fn tokenize(placeholder: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = placeholder.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '|' => Token::Pipe,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            ',' => Token::Comma,
+            '"' | '\'' => {
+                let mut literal = String::new();
+                loop {
+                    match chars.next()? {
+                        '\\' => literal.push(chars.next()?),
+                        next if next == c => break,
+                        next => literal.push(next),
+                    }
+                }
+                Token::Str(literal)
+            }
+            c if c.is_ascii_digit() => {
+                let mut digits = String::from(c);
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(digit);
+                }
+                Token::Int(digits.parse().ok()?)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut identifier = String::from(c);
+                while let Some(next) = chars.next_if(|next| next.is_alphanumeric() || *next == '_')
+                {
+                    identifier.push(next);
+                }
+                Token::Identifier(identifier)
+            }
+            _ => return None,
+        };
+        tokens.push(token);
+    }
+    Some(tokens)
+}
+
+/// Returns the position of the `}}` closing a placeholder, skipping the string literals.
+fn find_placeholder_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    let mut escaped = false;
+    for (position, c) in text.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if text[position..].starts_with("}}") => return Some(position),
+            None => {}
+        }
+    }
+    None
+}
+
+#[derive(Debug)]
+pub struct TextTemplate {
+    segments: Vec<Segment>,
+    escaping: TemplateEscaping,
+}
+
+impl TextTemplate {
+    pub fn compile(template: &str, column_names: &[String]) -> Result<Self, TemplateError> {
+        let mut segments = Vec::new();
+        let mut rest = template;
+        let mut offset = 0;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Text(rest[..start].to_string()));
+            }
+            let inner = &rest[start + 2..];
+            let length = find_placeholder_end(inner)
+                .ok_or(TemplateError::UnclosedPlaceholder(offset + start))?;
+            segments.push(Segment::Placeholder(Placeholder::parse(
+                &inner[..length],
+                column_names,
+            )?));
+            let consumed = start + length + 4;
+            offset += consumed;
+            rest = &rest[consumed..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Text(rest.to_string()));
+        }
+        Ok(Self {
+            segments,
+            escaping: TemplateEscaping::None,
+        })
+    }
+
+    /// Sets the escaping of the values rendered by the placeholders.
+    #[must_use]
+    pub fn with_escaping(mut self, escaping: TemplateEscaping) -> Self {
+        self.escaping = escaping;
+        self
+    }
+
+    pub fn render(
+        &self,
+        values: &[Value],
+        time: Timestamp,
+        diff: isize,
+    ) -> Result<String, FormatterError> {
+        let mut rendered = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => rendered.push_str(text),
+                Segment::Placeholder(placeholder) => {
+                    placeholder.render(values, time, diff, self.escaping, &mut rendered)?;
+                }
+            }
+        }
+        Ok(rendered)
+    }
+}
+
+pub struct TemplateFormatter {
+    template: TextTemplate,
+    value_field_count: usize,
+}
+
+impl TemplateFormatter {
+    pub fn new(template: &str, value_field_names: &[String]) -> Result<Self, TemplateError> {
+        Ok(Self {
+            template: TextTemplate::compile(template, value_field_names)?,
+            value_field_count: value_field_names.len(),
+        })
+    }
+
+    #[must_use]
+    pub fn with_escaping(mut self, escaping: TemplateEscaping) -> Self {
+        self.template = self.template.with_escaping(escaping);
+        self
+    }
+}
+
+impl Formatter for TemplateFormatter {
+    fn format(
+        &mut self,
+        key: &Key,
+        values: &[Value],
+        time: Timestamp,
+        diff: isize,
+    ) -> Result<FormatterContext, FormatterError> {
+        if values.len() != self.value_field_count {
+            return Err(FormatterError::ColumnsValuesCountMismatch);
+        }
+        let payload = self.template.render(values, time, diff)?;
+        Ok(FormatterContext::new_single_payload(
+            payload.into_bytes(),
+            *key,
+            values.to_vec(),
+            time,
+            diff,
+        ))
+    }
+}
@@ -3,14 +3,16 @@
 use std::clone::Clone;
 use std::collections::HashMap;
 
+use serde_json::Value as JsonValue;
+
 use crate::connectors::metadata::SourceMetadata;
 use crate::connectors::ReaderContext::{Diff, Empty};
 use crate::connectors::{ReaderContext, SessionType};
-use crate::engine::Result;
+use crate::engine::{Result, Value};
 
 use super::{
     ensure_all_fields_in_schema, InnerSchemaField, ParseError, ParseResult, ParsedEventWithErrors,
-    Parser,
+    Parser, METADATA_FIELD_NAME,
 };
 use crate::connectors::data_storage::SpecialEvent;
 
@@ -18,11 +20,15 @@ use crate::connectors::data_storage::SpecialEvent;
 /// further only making adjustments according to the schema.
 ///
 /// It is useful when no raw values parsing is needed.
+///
+/// If the reader doesn't provide the metadata column itself, it is filled
+/// with the metadata of the source the values come from.
 pub struct TransparentParser {
     key_field_names: Option<Vec<String>>,
     value_field_names: Vec<String>,
     schema: HashMap<String, InnerSchemaField>,
     session_type: SessionType,
+    metadata_column_value: Option<Value>,
 }
 
 impl TransparentParser {
@@ -38,6 +44,7 @@ impl TransparentParser {
             value_field_names,
             schema,
             session_type,
+            metadata_column_value: None,
         })
     }
 }
@@ -68,8 +75,15 @@ impl Parser for TransparentParser {
             .value_field_names
             .iter()
             .map(|name| {
+                let value = match values.get(name) {
+                    Some(value) => Some(value.clone()),
+                    None if name == METADATA_FIELD_NAME => {
+                        self.metadata_column_value.clone().map(Ok)
+                    }
+                    None => None,
+                };
                 self.schema[name] // ensure_all_fields_in_schema in new() makes sure that all keys are in the schema
-                    .maybe_use_default(name, values.get(name).cloned())
+                    .maybe_use_default(name, value)
             })
             .collect();
 
@@ -78,7 +92,10 @@ impl Parser for TransparentParser {
         Ok(vec![event])
     }
 
-    fn on_new_source_started(&mut self, _metadata: &SourceMetadata) {}
+    fn on_new_source_started(&mut self, metadata: &SourceMetadata) {
+        let metadata_serialized: JsonValue = metadata.serialize();
+        self.metadata_column_value = Some(metadata_serialized.into());
+    }
 
     fn column_count(&self) -> usize {
         self.value_field_names.len()
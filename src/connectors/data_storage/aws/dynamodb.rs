@@ -1,6 +1,7 @@
-use log::error;
+use log::{error, warn};
 use std::collections::HashMap;
 use std::mem::take;
+use std::time::Duration;
 
 use aws_sdk_dynamodb::error::SdkError;
 use aws_sdk_dynamodb::operation::batch_write_item::BatchWriteItemError;
@@ -18,19 +19,29 @@ use ndarray::ArrayD;
 use tokio::runtime::Runtime as TokioRuntime;
 
 use crate::connectors::data_format::{
-    FormatterContext, FormatterError, NDARRAY_ELEMENTS_FIELD_NAME, NDARRAY_SHAPE_FIELD_NAME,
+    FormatterContext, FormatterError, ValueField, NDARRAY_ELEMENTS_FIELD_NAME,
+    NDARRAY_SHAPE_FIELD_NAME,
 };
 use crate::connectors::data_storage::TableWriterInitMode;
 use crate::connectors::{WriteError, Writer};
 use crate::engine::{Type, Value};
-use crate::python_api::ValueField;
 use crate::retry::RetryConfig;
 
 // No more than 25 items can be sent in a single batch
 // There is no public constant for that, so we create our own
 // https://docs.rs/aws-sdk-dynamodb/latest/aws_sdk_dynamodb/operation/batch_write_item/builders/struct.BatchWriteItemFluentBuilder.html
 pub const MAX_BATCH_WRITE_SIZE: usize = 25;
-pub const N_SEND_ATTEMPTS: usize = 5;
+pub const N_SEND_ATTEMPTS: usize = 10;
+
+// The items left unprocessed by `BatchWriteItem` are the ones throttled by the table, so
+// they are retried with an exponential backoff, as recommended by AWS:
+// https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Programming.Errors.html#Programming.Errors.BatchOperations
+const THROTTLING_INITIAL_BACKOFF: Duration = Duration::from_millis(50);
+const THROTTLING_BACKOFF_FACTOR: f64 = 2.0;
+const THROTTLING_BACKOFF_JITTER: Duration = Duration::from_millis(50);
+
+// The error code of a request rejected because of the account's request rate
+const THROTTLING_ERROR_CODE: &str = "ThrottlingException";
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -114,9 +125,12 @@ impl DynamoDBWriter {
         // outputs of `value_to_attribute`.
         match ty {
             Type::Int | Type::Float | Type::Duration => Ok(ScalarAttributeType::N),
-            Type::String | Type::Pointer | Type::DateTimeNaive | Type::DateTimeUtc | Type::Json => {
-                Ok(ScalarAttributeType::S)
-            }
+            Type::String
+            | Type::Pointer
+            | Type::DateTimeNaive
+            | Type::DateTimeUtc
+            | Type::DateTimeZoned
+            | Type::Json => Ok(ScalarAttributeType::S),
             Type::Bytes | Type::PyObjectWrapper => Ok(ScalarAttributeType::B),
             _ => Err(WriteError::NotIndexType(ty.clone())),
         }
@@ -251,10 +265,25 @@ impl DynamoDBWriter {
                     .collect::<Result<Vec<_>, _>>()?;
                 Ok(AttributeValue::L(list))
             }
+            Value::Struct(fields) => {
+                let map = fields
+                    .iter()
+                    .map(|(name, value)| Ok((name.to_string(), Self::value_to_attribute(value)?)))
+                    .collect::<Result<HashMap<_, _>, WriteError>>()?;
+                Ok(AttributeValue::M(map))
+            }
+            Value::Map(map) => {
+                let map = map
+                    .iter()
+                    .map(|(key, value)| Ok((key.to_string(), Self::value_to_attribute(value)?)))
+                    .collect::<Result<HashMap<_, _>, WriteError>>()?;
+                Ok(AttributeValue::M(map))
+            }
             Value::IntArray(arr) => Ok(Self::array_to_attribute_value(arr)),
             Value::FloatArray(arr) => Ok(Self::array_to_attribute_value(arr)),
             Value::DateTimeNaive(dt) => Ok(AttributeValue::S(dt.to_string())),
             Value::DateTimeUtc(dt) => Ok(AttributeValue::S(dt.to_string())),
+            Value::DateTimeZoned(dt) => Ok(AttributeValue::S(dt.to_string())),
             Value::Duration(d) => Ok(AttributeValue::N(d.nanoseconds().to_string())),
             Value::Json(j) => Ok(AttributeValue::S(j.to_string())),
             Value::PyObjectWrapper(v) => Ok(AttributeValue::B(
@@ -309,6 +338,69 @@ impl DynamoDBWriter {
         let sort_key = self.sort_key_index.map(|index| data.values[index].clone());
         (partition_key, sort_key)
     }
+
+    fn is_throttling_error(error: &SdkError<BatchWriteItemError, AwsHttpResponse>) -> bool {
+        match error {
+            SdkError::ServiceError(service_error) => match service_error.err() {
+                BatchWriteItemError::ProvisionedThroughputExceededException(_)
+                | BatchWriteItemError::RequestLimitExceeded(_) => true,
+                other => other.meta().code() == Some(THROTTLING_ERROR_CODE),
+            },
+            // The request didn't reach the service or the response didn't come back
+            // in time, so it can be safely retried.
+            SdkError::TimeoutError(_) | SdkError::DispatchFailure(_) => true,
+            _ => false,
+        }
+    }
+
+    async fn send_batch(&self, requests: Vec<WriteRequest>) -> Result<(), WriteError> {
+        let mut request_items = HashMap::from([(self.table_name.clone(), requests)]);
+        let mut retry = RetryConfig::new(
+            THROTTLING_INITIAL_BACKOFF,
+            THROTTLING_BACKOFF_FACTOR,
+            THROTTLING_BACKOFF_JITTER,
+        );
+
+        for _ in 0..N_SEND_ATTEMPTS {
+            let response = self
+                .client
+                .batch_write_item()
+                .set_request_items(Some(request_items.clone()))
+                .send()
+                .await;
+
+            match response {
+                Ok(response) => {
+                    // Only the unprocessed items are sent again. If there are none,
+                    // the whole batch has been written.
+                    request_items = response.unprocessed_items.unwrap_or_default();
+                    let n_unprocessed_requests =
+                        request_items.get(&self.table_name).map_or(0, Vec::len);
+                    if n_unprocessed_requests == 0 {
+                        return Ok(());
+                    }
+                    warn!("{n_unprocessed_requests} items were throttled by the table {} and require retry. If you see this message often, consider increasing the write capacity of the table.", self.table_name);
+                }
+                Err(e) if Self::is_throttling_error(&e) => {
+                    warn!(
+                        "An attempt to save item batch to the table {} has failed: {}",
+                        self.table_name,
+                        Error::from(e)
+                    );
+                }
+                Err(e) => return Err(Error::from(e).into()),
+            }
+
+            retry.sleep_after_error_async().await;
+        }
+
+        let n_undelivered_requests = request_items.get(&self.table_name).map_or(0, Vec::len);
+        error!(
+            "{n_undelivered_requests} items weren't saved to the table {} after {N_SEND_ATTEMPTS} attempts",
+            self.table_name
+        );
+        Err(WriteError::SomeItemsNotDelivered(n_undelivered_requests))
+    }
 }
 
 impl Writer for DynamoDBWriter {
@@ -335,69 +427,21 @@ impl Writer for DynamoDBWriter {
             }
             _ => unreachable!("diff can only be 1 or -1"),
         }
-        if self.write_requests.len() >= MAX_BATCH_WRITE_SIZE {
-            self.flush(false)?;
-        }
         Ok(())
     }
 
     fn flush(&mut self, _forced: bool) -> Result<(), WriteError> {
-        if self.write_requests.is_empty() {
-            return Ok(());
-        }
-        let requests: Vec<WriteRequest> = take(&mut self.write_requests).into_values().collect();
-        let mut request_items = HashMap::with_capacity(1);
-        request_items.insert(self.table_name.clone(), requests);
-
+        // The requests are only sent on commit, so that the insertions and the deletions
+        // of the same key within a minibatch are always resolved into a single request.
+        let mut requests: Vec<WriteRequest> =
+            take(&mut self.write_requests).into_values().collect();
         self.runtime.block_on(async {
-            let mut retry = RetryConfig::default();
-
-            for _ in 0..N_SEND_ATTEMPTS {
-                let response = self
-                    .client
-                    .batch_write_item()
-                    .set_request_items(Some(request_items.clone()))
-                    .send()
-                    .await;
-
-                match response {
-                    Ok(response) => {
-                        // If there are unprocessed items in the response, save them for the next request.
-                        // Otherwise the request has succeeded, and has no items to process further.
-                        if let Some(unprocessed_items) = response.unprocessed_items {
-                            request_items = unprocessed_items;
-                        } else {
-                            request_items.clear();
-                        }
-
-                        if let Some(unprocessed_requests) = request_items.get(&self.table_name) {
-                            // If there's a non-empty array with unprocessed items, it must be retried.
-                            // Otherwise, the method may terminate.
-                            if unprocessed_requests.is_empty() {
-                                return Ok(());
-                            }
-                        } else {
-                            // If there's no vector with the items waiting for submission, it means that
-                            // everything has been sent
-                            return Ok(());
-                        }
-                    }
-                    Err(e) => {
-                        error!(
-                            "An attempt to save item batch has failed: {}",
-                            Error::from(e)
-                        );
-                    }
-                }
-
-                retry.sleep_after_error();
-            }
-            let unprocessed_items = request_items.remove(&self.table_name);
-            if let Some(unprocessed_items) = unprocessed_items {
-                Err(WriteError::SomeItemsNotDelivered(unprocessed_items.len()))
-            } else {
-                Ok(())
+            while !requests.is_empty() {
+                let batch_size = requests.len().min(MAX_BATCH_WRITE_SIZE);
+                let batch: Vec<_> = requests.drain(..batch_size).collect();
+                self.send_batch(batch).await?;
             }
+            Ok(())
         })
     }
 
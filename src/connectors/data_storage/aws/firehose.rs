@@ -0,0 +1,238 @@
+use log::{error, warn};
+use std::collections::HashMap;
+use std::mem::take;
+
+use aws_sdk_firehose::operation::describe_delivery_stream::DescribeDeliveryStreamError;
+use aws_sdk_firehose::operation::list_delivery_streams::ListDeliveryStreamsError;
+use aws_sdk_firehose::operation::put_record_batch::PutRecordBatchError;
+use aws_sdk_firehose::primitives::Blob;
+use aws_sdk_firehose::types::{DeliveryStreamStatus, Record};
+use aws_sdk_firehose::Client;
+use aws_smithy_runtime_api::client::result::SdkError;
+use aws_smithy_runtime_api::http::Response as AwsHttpResponse;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use crate::connectors::data_format::FormatterContext;
+use crate::connectors::data_storage::MessageQueueTopic;
+use crate::connectors::{WriteError, Writer};
+use crate::retry::RetryConfig;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Describe delivery stream error, error details: {0:?}")]
+    DescribeDeliveryStream(#[from] SdkError<DescribeDeliveryStreamError, AwsHttpResponse>),
+
+    #[error("List delivery streams error, error details: {0:?}")]
+    ListDeliveryStreams(#[from] SdkError<ListDeliveryStreamsError, AwsHttpResponse>),
+
+    #[error("Put record batch error, error details: {0:?}")]
+    PutRecordBatch(#[from] SdkError<PutRecordBatchError, AwsHttpResponse>),
+
+    #[error(
+        "The delivery stream '{0}' is not ready to accept data. Status: '{1}', but 'Active' was expected"
+    )]
+    DeliveryStreamNotReady(String, DeliveryStreamStatus),
+
+    #[error("The record of {0} bytes exceeds the limit of 1000 KiB")]
+    RecordTooLarge(usize),
+}
+
+// https://docs.aws.amazon.com/firehose/latest/APIReference/API_PutRecordBatch.html
+const MAX_FIREHOSE_BATCH_RECORDS: usize = 500;
+const MAX_FIREHOSE_BATCH_LENGTH: usize = 4 * 1024 * 1024;
+const MAX_FIREHOSE_RECORD_LENGTH: usize = 1000 * 1024;
+const N_SEND_ATTEMPTS: usize = 5;
+
+// The error code of the records rejected because the delivery stream is over its quota
+const THROTTLING_ERROR_CODE: &str = "ServiceUnavailableException";
+
+#[derive(Default, Debug)]
+struct BufferedRecords {
+    total_length: usize,
+    records: Vec<Record>,
+}
+
+impl BufferedRecords {
+    fn add_record(
+        &mut self,
+        runtime: &TokioRuntime,
+        client: &Client,
+        delivery_stream: &str,
+        payload: Vec<u8>,
+    ) -> Result<(), WriteError> {
+        let record_length = payload.len();
+        if record_length > MAX_FIREHOSE_RECORD_LENGTH {
+            return Err(Error::RecordTooLarge(record_length).into());
+        }
+        let record_limit_reached = self.records.len() == MAX_FIREHOSE_BATCH_RECORDS;
+        let size_limit_reached = self.total_length + record_length > MAX_FIREHOSE_BATCH_LENGTH;
+        if record_limit_reached || size_limit_reached {
+            runtime.block_on(async { self.flush(client, delivery_stream).await })?;
+        }
+        self.total_length += record_length;
+        self.records
+            .push(Record::builder().data(Blob::new(payload)).build()?);
+        Ok(())
+    }
+
+    async fn flush(&mut self, client: &Client, delivery_stream: &str) -> Result<(), WriteError> {
+        if self.records.is_empty() {
+            return Ok(());
+        }
+
+        let mut retry = RetryConfig::default();
+
+        for _ in 0..N_SEND_ATTEMPTS {
+            let response = client
+                .put_record_batch()
+                .delivery_stream_name(delivery_stream)
+                .set_records(Some(self.records.clone()))
+                .send()
+                .await;
+
+            match response {
+                Ok(response) => {
+                    if response.failed_put_count() == 0 {
+                        self.records.clear();
+                        break;
+                    }
+
+                    // The responses are in the order of the records, only the failed ones
+                    // are sent again.
+                    let mut total_records_to_retry = 0;
+                    let mut total_throttled_records = 0;
+                    for (index, record_response) in response.request_responses().iter().enumerate()
+                    {
+                        if let Some(error_code) = record_response.error_code() {
+                            if error_code == THROTTLING_ERROR_CODE {
+                                total_throttled_records += 1;
+                            }
+                            self.records.swap(total_records_to_retry, index);
+                            total_records_to_retry += 1;
+                        }
+                    }
+                    self.records.truncate(total_records_to_retry);
+                    if total_throttled_records > 0 {
+                        warn!("{total_throttled_records} records were throttled by the delivery stream {delivery_stream} and require retry. If you see this message often, consider requesting a higher quota for the stream.");
+                    }
+                    if total_records_to_retry > total_throttled_records {
+                        warn!(
+                            "{} records were rejected by the delivery stream {delivery_stream} and require retry.",
+                            total_records_to_retry - total_throttled_records
+                        );
+                    }
+                }
+                Err(e) => {
+                    error!(
+                        "An attempt to save record batch for delivery stream {delivery_stream} has failed: {}",
+                        Error::from(e)
+                    );
+                }
+            }
+
+            retry.sleep_after_error_async().await;
+        }
+
+        self.total_length = 0;
+        let undelivered_records = take(&mut self.records).len();
+        if undelivered_records == 0 {
+            Ok(())
+        } else {
+            Err(WriteError::SomeItemsNotDelivered(undelivered_records))
+        }
+    }
+}
+
+/// Writes the formatted entries to the Amazon Data Firehose delivery streams. Unlike a
+/// Kinesis data stream, a delivery stream doesn't have partitions, so the records aren't
+/// given the partition keys.
+pub struct FirehoseWriter {
+    runtime: TokioRuntime,
+    client: Client,
+    delivery_stream_name: MessageQueueTopic,
+    buffered_records: HashMap<String, BufferedRecords>,
+}
+
+impl FirehoseWriter {
+    pub fn new(
+        runtime: TokioRuntime,
+        client: Client,
+        delivery_stream_name: MessageQueueTopic,
+    ) -> Result<Self, WriteError> {
+        runtime.block_on(async {
+            match delivery_stream_name {
+                MessageQueueTopic::Fixed(ref delivery_stream_name) => {
+                    let delivery_stream = client
+                        .describe_delivery_stream()
+                        .delivery_stream_name(delivery_stream_name)
+                        .send()
+                        .await
+                        .map_err(Error::DescribeDeliveryStream)?;
+                    let status = delivery_stream
+                        .delivery_stream_description()
+                        .map(|description| description.delivery_stream_status());
+                    if let Some(status) = status {
+                        if status != &DeliveryStreamStatus::Active {
+                            return Err(Error::DeliveryStreamNotReady(
+                                delivery_stream_name.clone(),
+                                status.clone(),
+                            ));
+                        }
+                    }
+                }
+                MessageQueueTopic::Dynamic(_) => {
+                    let _ = client
+                        .list_delivery_streams()
+                        .limit(1)
+                        .send()
+                        .await
+                        .map_err(Error::ListDeliveryStreams)?;
+                }
+            }
+            Ok::<_, Error>(())
+        })?;
+
+        Ok(Self {
+            runtime,
+            client,
+            delivery_stream_name,
+            buffered_records: HashMap::new(),
+        })
+    }
+}
+
+impl Writer for FirehoseWriter {
+    fn write(&mut self, data: FormatterContext) -> Result<(), WriteError> {
+        for payload in data.payloads {
+            let payload = payload.into_raw_bytes()?;
+            let effective_stream = self.delivery_stream_name.get_for_posting(&data.values)?;
+            self.buffered_records
+                .entry(effective_stream.clone())
+                .or_default()
+                .add_record(&self.runtime, &self.client, &effective_stream, payload)?;
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self, _forced: bool) -> Result<(), WriteError> {
+        let mut global_result = Ok(());
+        self.runtime.block_on(async {
+            for (stream_name, mut buffered_records) in self.buffered_records.drain() {
+                let stream_result = buffered_records.flush(&self.client, &stream_name).await;
+                if stream_result.is_err() && global_result.is_ok() {
+                    global_result = stream_result;
+                }
+            }
+            global_result
+        })
+    }
+
+    fn name(&self) -> String {
+        format!("Firehose({})", self.delivery_stream_name)
+    }
+
+    fn single_threaded(&self) -> bool {
+        false
+    }
+}
@@ -542,11 +542,11 @@ impl BufferedShardEntries {
         }
 
         self.total_length = 0;
-        if self.entries.is_empty() {
+        let undelivered_entries = take(&mut self.entries).len();
+        if undelivered_entries == 0 {
             Ok(())
         } else {
-            self.entries.clear();
-            Err(WriteError::SomeItemsNotDelivered(self.entries.len()))
+            Err(WriteError::SomeItemsNotDelivered(undelivered_entries))
         }
     }
 }
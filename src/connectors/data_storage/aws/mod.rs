@@ -1,5 +1,9 @@
 pub mod dynamodb;
+pub mod firehose;
 pub mod kinesis;
+pub mod sqs;
 
 pub use dynamodb::DynamoDBWriter;
+pub use firehose::FirehoseWriter;
 pub use kinesis::{KinesisReader, KinesisWriter};
+pub use sqs::SqsReader;
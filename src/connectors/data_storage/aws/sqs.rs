@@ -0,0 +1,521 @@
+use log::warn;
+use std::borrow::Cow;
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, Instant};
+
+use aws_sdk_sqs::error::BuildError;
+use aws_sdk_sqs::operation::change_message_visibility_batch::ChangeMessageVisibilityBatchError;
+use aws_sdk_sqs::operation::delete_message_batch::DeleteMessageBatchError;
+use aws_sdk_sqs::operation::get_queue_attributes::GetQueueAttributesError;
+use aws_sdk_sqs::operation::receive_message::ReceiveMessageError;
+use aws_sdk_sqs::types::{
+    ChangeMessageVisibilityBatchRequestEntry, DeleteMessageBatchRequestEntry, Message,
+    MessageSystemAttributeName, QueueAttributeName,
+};
+use aws_sdk_sqs::Client;
+use aws_smithy_runtime_api::client::result::SdkError;
+use aws_smithy_runtime_api::http::Response as AwsHttpResponse;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use crate::connectors::data_storage::sharding::ShardSelector;
+use crate::connectors::data_storage::{CommittedFrontier, ReaderContext};
+use crate::connectors::{OffsetKey, OffsetValue};
+use crate::connectors::{ReadError, ReadResult, Reader, StorageType};
+use crate::persistence::frontier::OffsetAntichain;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Get queue attributes error, error details: {0:?}")]
+    GetQueueAttributes(#[from] SdkError<GetQueueAttributesError, AwsHttpResponse>),
+
+    #[error("Receive message error, error details: {0:?}")]
+    ReceiveMessage(#[from] SdkError<ReceiveMessageError, AwsHttpResponse>),
+
+    #[error("Delete message batch error, error details: {0:?}")]
+    DeleteMessageBatch(#[from] SdkError<DeleteMessageBatchError, AwsHttpResponse>),
+
+    #[error("Change message visibility batch error, error details: {0:?}")]
+    ChangeMessageVisibilityBatch(
+        #[from] SdkError<ChangeMessageVisibilityBatchError, AwsHttpResponse>,
+    ),
+
+    #[error(transparent)]
+    BuildRequest(#[from] BuildError),
+
+    #[error(
+        "The queue '{0}' is not a FIFO queue, so its messages can't be sharded by the message group"
+    )]
+    NotFifoQueue(String),
+}
+
+// https://docs.aws.amazon.com/AWSSimpleQueueService/latest/APIReference/API_ReceiveMessage.html
+// The same limit applies to the number of entries in the batch requests.
+const MAX_SQS_BATCH_SIZE: usize = 10;
+
+struct PendingMessage {
+    // The number of entries read by the worker, including this message. It's the offset
+    // of the message, so it's deleted once the committed offset reaches it.
+    entry_index: usize,
+    receipt_handle: String,
+    visible_until: Instant,
+}
+
+impl PendingMessage {
+    fn batch_entry_id(&self) -> String {
+        self.entry_index.to_string()
+    }
+}
+
+struct QueueConsumer {
+    client: Client,
+    queue_url: String,
+    worker_index: usize,
+    wait_time_s: i32,
+    visibility_timeout_s: i32,
+    group_selector: Option<ShardSelector>,
+    total_entries_read: usize,
+    entries_read: VecDeque<ReadResult>,
+    pending_messages: VecDeque<PendingMessage>,
+    next_extension_at: Option<Instant>,
+    committed_frontier: CommittedFrontier,
+}
+
+/// Reads the messages from an Amazon SQS queue with long polling.
+///
+/// A message is deleted from the queue only once the connector commits it. Until then,
+/// its visibility timeout is extended so that it isn't delivered to another consumer. If
+/// the program stops before the commit, the message becomes visible again and is
+/// redelivered.
+///
+/// When the message group sharding is enabled, each message group of a FIFO queue is
+/// processed by a single worker, so that the order of the messages within a group is
+/// preserved. The messages of the groups owned by other workers are made visible again
+/// as soon as they are received.
+#[allow(clippy::module_name_repetitions)]
+pub struct SqsReader {
+    runtime: TokioRuntime,
+    consumer: QueueConsumer,
+}
+
+impl SqsReader {
+    pub fn new(
+        runtime: TokioRuntime,
+        client: Client,
+        queue_url: String,
+        worker_index: usize,
+        wait_time_s: i32,
+        visibility_timeout_s: i32,
+        group_selector: Option<ShardSelector>,
+    ) -> Result<Self, ReadError> {
+        let queue_attributes = runtime.block_on(async {
+            client
+                .get_queue_attributes()
+                .queue_url(&queue_url)
+                .attribute_names(QueueAttributeName::FifoQueue)
+                .send()
+                .await
+                .map_err(Error::GetQueueAttributes)
+        })?;
+        let is_fifo_queue = queue_attributes
+            .attributes()
+            .and_then(|attributes| attributes.get(&QueueAttributeName::FifoQueue))
+            .is_some_and(|is_fifo_queue| is_fifo_queue == "true");
+        if group_selector.is_some() && !is_fifo_queue {
+            return Err(Error::NotFifoQueue(queue_url).into());
+        }
+
+        Ok(Self {
+            runtime,
+            consumer: QueueConsumer {
+                client,
+                queue_url,
+                worker_index,
+                wait_time_s,
+                visibility_timeout_s,
+                group_selector,
+                total_entries_read: 0,
+                entries_read: VecDeque::new(),
+                pending_messages: VecDeque::new(),
+                next_extension_at: None,
+                committed_frontier: CommittedFrontier::new(),
+            },
+        })
+    }
+}
+
+impl QueueConsumer {
+    fn visibility_timeout(&self) -> Duration {
+        Duration::from_secs(self.visibility_timeout_s.unsigned_abs().into())
+    }
+
+    fn committed_entries(&self) -> usize {
+        match self
+            .committed_frontier
+            .get_offset(&OffsetKey::Sqs(self.worker_index))
+        {
+            Some(OffsetValue::SqsReadEntriesCount(committed_entries)) => committed_entries,
+            _ => 0,
+        }
+    }
+
+    // The number of the committed messages at the front of the pending ones, up to the
+    // size of a batch.
+    fn committed_batch_len(&self) -> usize {
+        let committed_entries = self.committed_entries();
+        self.pending_messages
+            .iter()
+            .take(MAX_SQS_BATCH_SIZE)
+            .take_while(|message| message.entry_index <= committed_entries)
+            .count()
+    }
+
+    async fn delete_committed_messages(&mut self) -> Result<(), Error> {
+        loop {
+            let n_committed_messages = self.committed_batch_len();
+            if n_committed_messages == 0 {
+                return Ok(());
+            }
+
+            let mut entries = Vec::with_capacity(n_committed_messages);
+            for message in self.pending_messages.iter().take(n_committed_messages) {
+                entries.push(
+                    DeleteMessageBatchRequestEntry::builder()
+                        .id(message.batch_entry_id())
+                        .receipt_handle(&message.receipt_handle)
+                        .build()?,
+                );
+            }
+            let response = self
+                .client
+                .delete_message_batch()
+                .queue_url(&self.queue_url)
+                .set_entries(Some(entries))
+                .send()
+                .await?;
+            for failed_entry in response.failed() {
+                // The visibility timeout of the message may have expired, then it's
+                // delivered again.
+                warn!(
+                    "Failed to delete the committed message from the queue {}: {}",
+                    self.queue_url,
+                    failed_entry.message().unwrap_or(failed_entry.code())
+                );
+            }
+            self.pending_messages.drain(..n_committed_messages);
+        }
+    }
+
+    async fn change_visibility(
+        &self,
+        receipt_handles: Vec<(String, String)>,
+        visibility_timeout_s: i32,
+    ) -> Result<HashSet<String>, Error> {
+        let mut failed_ids = HashSet::new();
+        for chunk in receipt_handles.chunks(MAX_SQS_BATCH_SIZE) {
+            let mut entries = Vec::with_capacity(chunk.len());
+            for (id, receipt_handle) in chunk {
+                entries.push(
+                    ChangeMessageVisibilityBatchRequestEntry::builder()
+                        .id(id)
+                        .receipt_handle(receipt_handle)
+                        .visibility_timeout(visibility_timeout_s)
+                        .build()?,
+                );
+            }
+            let response = self
+                .client
+                .change_message_visibility_batch()
+                .queue_url(&self.queue_url)
+                .set_entries(Some(entries))
+                .send()
+                .await?;
+            for failed_entry in response.failed() {
+                warn!(
+                    "Failed to change the visibility timeout of a message from the queue {}: {}",
+                    self.queue_url,
+                    failed_entry.message().unwrap_or(failed_entry.code())
+                );
+                failed_ids.insert(failed_entry.id().to_string());
+            }
+        }
+        Ok(failed_ids)
+    }
+
+    // The visibility timeout of the uncommitted messages is extended when half of it
+    // has passed, so that they aren't delivered again to another consumer.
+    async fn extend_visibility(&mut self) -> Result<(), Error> {
+        let now = Instant::now();
+        if self.next_extension_at.is_none_or(|at| at > now) {
+            return Ok(());
+        }
+
+        let half_timeout = self.visibility_timeout() / 2;
+        let expires_soon = |message: &PendingMessage| message.visible_until <= now + half_timeout;
+        let expiring_messages = self
+            .pending_messages
+            .iter()
+            .filter(|message| expires_soon(message))
+            .map(|message| (message.batch_entry_id(), message.receipt_handle.clone()))
+            .collect();
+        let failed_ids = self
+            .change_visibility(expiring_messages, self.visibility_timeout_s)
+            .await?;
+
+        let visible_until = now + self.visibility_timeout();
+        for message in &mut self.pending_messages {
+            if expires_soon(message) && !failed_ids.contains(&message.batch_entry_id()) {
+                message.visible_until = visible_until;
+            }
+        }
+        self.next_extension_at = self
+            .pending_messages
+            .iter()
+            .map(|message| message.visible_until)
+            .min()
+            .map(|visible_until| visible_until.checked_sub(half_timeout).unwrap_or(now));
+        Ok(())
+    }
+
+    // Queues the received messages for reading and returns the batch entry ids and the
+    // receipt handles of the ones from the message groups owned by other workers.
+    fn accept_messages(
+        &mut self,
+        messages: Vec<Message>,
+        visible_until: Instant,
+    ) -> Vec<(String, String)> {
+        let mut released_messages = Vec::new();
+        for message in messages {
+            let Some(receipt_handle) = message.receipt_handle else {
+                continue;
+            };
+            let group_id = message.attributes.and_then(|mut attributes| {
+                attributes.remove(&MessageSystemAttributeName::MessageGroupId)
+            });
+            if let (Some(group_selector), Some(group_id)) = (&self.group_selector, &group_id) {
+                if !group_selector.owns(group_id.as_bytes()) {
+                    released_messages.push((released_messages.len().to_string(), receipt_handle));
+                    continue;
+                }
+            }
+
+            self.total_entries_read += 1;
+            let context = ReaderContext::from_key_value(
+                group_id.map(String::into_bytes),
+                Some(message.body.unwrap_or_default().into_bytes()),
+            );
+            let offset = (
+                OffsetKey::Sqs(self.worker_index),
+                OffsetValue::SqsReadEntriesCount(self.total_entries_read),
+            );
+            self.entries_read
+                .push_back(ReadResult::Data(context, offset));
+            self.pending_messages.push_back(PendingMessage {
+                entry_index: self.total_entries_read,
+                receipt_handle,
+                visible_until,
+            });
+        }
+        released_messages
+    }
+
+    async fn receive_messages(&mut self) -> Result<(), Error> {
+        // The visibility timeout starts when the messages are returned, so the time of the
+        // request gives a safe estimate of its end.
+        let requested_at = Instant::now();
+        let response = self
+            .client
+            .receive_message()
+            .queue_url(&self.queue_url)
+            .max_number_of_messages(MAX_SQS_BATCH_SIZE.try_into().unwrap())
+            .wait_time_seconds(self.wait_time_s)
+            .visibility_timeout(self.visibility_timeout_s)
+            .message_system_attribute_names(MessageSystemAttributeName::MessageGroupId)
+            .send()
+            .await?;
+
+        let visible_until = requested_at + self.visibility_timeout();
+        let released_messages =
+            self.accept_messages(response.messages.unwrap_or_default(), visible_until);
+
+        if !self.pending_messages.is_empty() && self.next_extension_at.is_none() {
+            self.next_extension_at = visible_until.checked_sub(self.visibility_timeout() / 2);
+        }
+        // The messages of the groups owned by other workers become visible right away
+        self.change_visibility(released_messages, 0).await?;
+        Ok(())
+    }
+}
+
+impl Reader for SqsReader {
+    fn read(&mut self) -> Result<ReadResult, ReadError> {
+        let consumer = &mut self.consumer;
+        loop {
+            self.runtime.block_on(async {
+                consumer.delete_committed_messages().await?;
+                consumer.extend_visibility().await
+            })?;
+            if let Some(entry) = consumer.entries_read.pop_front() {
+                return Ok(entry);
+            }
+            self.runtime.block_on(consumer.receive_messages())?;
+        }
+    }
+
+    fn seek(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        // The uncommitted messages of the previous run are delivered again once their
+        // visibility timeout expires, so only the numbering of the entries continues.
+        let offset_value = frontier.get_offset(&OffsetKey::Sqs(self.consumer.worker_index));
+        if let Some(offset) = offset_value {
+            if let OffsetValue::SqsReadEntriesCount(last_run_entries_read) = offset {
+                self.consumer.total_entries_read = *last_run_entries_read;
+            } else {
+                warn!("Unexpected offset type for SQS reader: {offset:?}");
+            }
+        }
+        Ok(())
+    }
+
+    fn storage_type(&self) -> StorageType {
+        StorageType::Sqs
+    }
+
+    fn max_allowed_consecutive_errors(&self) -> usize {
+        32
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        format!("Sqs({})", self.consumer.queue_url).into()
+    }
+
+    fn committed_frontier(&self) -> Option<CommittedFrontier> {
+        Some(self.consumer.committed_frontier.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_sqs::config::{BehaviorVersion, Region};
+
+    fn consumer(worker_index: usize, group_selector: Option<ShardSelector>) -> QueueConsumer {
+        // the client is never used to send a request
+        let config = aws_sdk_sqs::Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new("us-east-1"))
+            .build();
+        QueueConsumer {
+            client: Client::from_conf(config),
+            queue_url: "https://sqs.us-east-1.amazonaws.com/0/queue.fifo".to_string(),
+            worker_index,
+            wait_time_s: 20,
+            visibility_timeout_s: 30,
+            group_selector,
+            total_entries_read: 0,
+            entries_read: VecDeque::new(),
+            pending_messages: VecDeque::new(),
+            next_extension_at: None,
+            committed_frontier: CommittedFrontier::new(),
+        }
+    }
+
+    fn message(receipt_handle: &str, group_id: &str) -> Message {
+        Message::builder()
+            .receipt_handle(receipt_handle)
+            .body(format!("body of {receipt_handle}"))
+            .attributes(MessageSystemAttributeName::MessageGroupId, group_id)
+            .build()
+    }
+
+    fn commit(consumer: &QueueConsumer, entries_read: usize) {
+        let mut frontier = OffsetAntichain::new();
+        frontier.advance_offset(
+            OffsetKey::Sqs(consumer.worker_index),
+            OffsetValue::SqsReadEntriesCount(entries_read),
+        );
+        consumer.committed_frontier.advance(&frontier);
+    }
+
+    #[test]
+    fn committed_messages_are_deleted_in_batches() {
+        let mut consumer = consumer(0, None);
+        let messages = (0..12)
+            .map(|index| message(&format!("receipt {index}"), "group"))
+            .collect();
+        let released_messages = consumer.accept_messages(messages, Instant::now());
+        assert!(released_messages.is_empty());
+        assert_eq!(consumer.committed_batch_len(), 0);
+
+        // only the committed messages are deleted, at most a batch at a time
+        commit(&consumer, 11);
+        assert_eq!(consumer.committed_batch_len(), MAX_SQS_BATCH_SIZE);
+        consumer.pending_messages.drain(..MAX_SQS_BATCH_SIZE);
+        assert_eq!(consumer.committed_batch_len(), 1);
+        assert_eq!(consumer.pending_messages[0].batch_entry_id(), "11");
+        consumer.pending_messages.drain(..1);
+        assert_eq!(consumer.committed_batch_len(), 0);
+        assert_eq!(consumer.pending_messages.len(), 1);
+    }
+
+    #[test]
+    fn committed_offsets_of_other_workers_are_ignored() {
+        let mut consumer = consumer(1, None);
+        consumer.accept_messages(vec![message("receipt", "group")], Instant::now());
+        let mut frontier = OffsetAntichain::new();
+        frontier.advance_offset(OffsetKey::Sqs(0), OffsetValue::SqsReadEntriesCount(1));
+        consumer.committed_frontier.advance(&frontier);
+        assert_eq!(consumer.committed_batch_len(), 0);
+    }
+
+    #[test]
+    fn each_message_group_is_read_by_one_worker() {
+        let messages = || {
+            (0..20)
+                .map(|index| message(&format!("receipt {index}"), &format!("group {}", index % 5)))
+                .collect::<Vec<_>>()
+        };
+
+        let mut n_messages_read = 0;
+        let mut groups_of_workers = Vec::new();
+        for worker_index in 0..2 {
+            let mut consumer = consumer(worker_index, Some(ShardSelector::new(worker_index, 2)));
+            let released_messages = consumer.accept_messages(messages(), Instant::now());
+            assert_eq!(released_messages.len() + consumer.entries_read.len(), 20);
+            n_messages_read += consumer.entries_read.len();
+
+            let mut groups = HashSet::new();
+            for (index, entry) in consumer.entries_read.iter().enumerate() {
+                let ReadResult::Data(ReaderContext::KeyValue((Some(group_id), _)), offset) = entry
+                else {
+                    panic!("unexpected entry: {entry:?}");
+                };
+                // the entries read by a worker are numbered without gaps
+                assert_eq!(
+                    offset,
+                    &(
+                        OffsetKey::Sqs(worker_index),
+                        OffsetValue::SqsReadEntriesCount(index + 1)
+                    )
+                );
+                groups.insert(group_id.clone());
+            }
+            groups_of_workers.push(groups);
+        }
+
+        // every message is read once, with all the messages of a group by the same worker
+        assert_eq!(n_messages_read, 20);
+        assert!(groups_of_workers[0].is_disjoint(&groups_of_workers[1]));
+        assert_eq!(groups_of_workers[0].len() + groups_of_workers[1].len(), 5);
+    }
+
+    #[test]
+    fn messages_without_a_group_are_always_read() {
+        let mut consumer = consumer(1, Some(ShardSelector::new(1, 2)));
+        let message = Message::builder()
+            .receipt_handle("receipt")
+            .body("body")
+            .build();
+        let released_messages = consumer.accept_messages(vec![message], Instant::now());
+        assert!(released_messages.is_empty());
+        assert_eq!(consumer.entries_read.len(), 1);
+    }
+}
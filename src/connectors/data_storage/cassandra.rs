@@ -0,0 +1,345 @@
+// Copyright © 2026 Pathway
+
+use std::collections::HashMap;
+use std::mem::take;
+
+use futures::future::try_join_all;
+use scylla::client::session::Session;
+use scylla::errors::{ExecutionError, NewSessionError, PrepareError};
+use scylla::statement::batch::{Batch, BatchType};
+use scylla::statement::prepared::{PartitionKeyError, PreparedStatement};
+use scylla::value::{CqlDuration, CqlTimestamp, CqlValue};
+use tokio::runtime::Runtime as TokioRuntime;
+
+use crate::connectors::data_format::{FormatterContext, ValueField};
+use crate::connectors::{WriteError, Writer};
+use crate::engine::time::DateTime as DateTimeTrait;
+use crate::engine::Value;
+
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum CassandraError {
+    #[error("failed to connect to the cluster: {0}")]
+    NewSession(#[from] NewSessionError),
+
+    #[error("failed to prepare the statement: {0}")]
+    Prepare(#[from] PrepareError),
+
+    #[error("failed to execute the batch: {0}")]
+    Execution(#[from] ExecutionError),
+
+    #[error("failed to compute the partition token: {0}")]
+    PartitionKey(#[from] PartitionKeyError),
+
+    #[error("the value {0:?} can't be written to a CQL column")]
+    UnsupportedValue(Value),
+
+    #[error("the primary key column '{0}' isn't among the table columns")]
+    PrimaryKeyColumnNotFound(String),
+}
+
+// Batching many statements is only efficient when they target a single partition, so
+// the batches are formed per partition token and are kept small.
+const MAX_STATEMENTS_PER_BATCH: usize = 100;
+
+type CqlRow = Vec<Option<CqlValue>>;
+
+enum PendingOperation {
+    Insert(CqlRow),
+    Delete(CqlRow),
+}
+
+/// Maintains the rows of a table in a Cassandra or `ScyllaDB` table.
+///
+/// An insertion is written with a prepared `INSERT`, which replaces the row with the same
+/// primary key, and a deletion with a prepared `DELETE` by the primary key. If a key is
+/// both deleted and inserted within a minibatch, the insertion wins regardless of the
+/// order, since it's the update of the row. On flush, the statements are grouped by the
+/// token of their partition into unlogged batches, so that each batch is handled by the
+/// replicas of a single partition, and the batches are sent concurrently.
+#[allow(clippy::module_name_repetitions)]
+pub struct CassandraWriter {
+    runtime: TokioRuntime,
+    session: Session,
+    table_name: String,
+    insert_statement: PreparedStatement,
+    delete_statement: PreparedStatement,
+    pending_operations: PendingOperations,
+    max_pending_operations: Option<usize>,
+}
+
+impl CassandraWriter {
+    pub fn new(
+        runtime: TokioRuntime,
+        session: Session,
+        table_name: String,
+        value_fields: &[ValueField],
+        key_field_names: &[String],
+        max_pending_operations: Option<usize>,
+    ) -> Result<Self, WriteError> {
+        let column_names: Vec<_> = value_fields
+            .iter()
+            .map(|field| field.name.clone())
+            .collect();
+        let key_field_indices = key_field_indices(&column_names, key_field_names)?;
+        let insert_query = insert_query(&table_name, &column_names);
+        let delete_query = delete_query(&table_name, key_field_names);
+
+        let (insert_statement, delete_statement) = runtime.block_on(async {
+            let insert_statement = session.prepare(insert_query).await?;
+            let delete_statement = session.prepare(delete_query).await?;
+            Ok::<_, CassandraError>((insert_statement, delete_statement))
+        })?;
+
+        Ok(Self {
+            runtime,
+            session,
+            table_name,
+            insert_statement,
+            delete_statement,
+            pending_operations: PendingOperations::new(key_field_indices),
+            max_pending_operations,
+        })
+    }
+
+    fn statement_and_token(
+        &self,
+        operation: &PendingOperation,
+    ) -> Result<(&PreparedStatement, Option<i64>), CassandraError> {
+        let (statement, row) = match operation {
+            PendingOperation::Insert(row) => (&self.insert_statement, row),
+            PendingOperation::Delete(row) => (&self.delete_statement, row),
+        };
+        let token = statement.calculate_token(row)?;
+        Ok((statement, token.map(|token| token.value())))
+    }
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+// The positions of the primary key columns among the columns of the table.
+fn key_field_indices(
+    column_names: &[String],
+    key_field_names: &[String],
+) -> Result<Vec<usize>, CassandraError> {
+    key_field_names
+        .iter()
+        .map(|key_field_name| {
+            column_names
+                .iter()
+                .position(|name| name == key_field_name)
+                .ok_or_else(|| CassandraError::PrimaryKeyColumnNotFound(key_field_name.clone()))
+        })
+        .collect()
+}
+
+fn insert_query(table_name: &str, column_names: &[String]) -> String {
+    let quoted_names: Vec<_> = column_names
+        .iter()
+        .map(|name| quote_identifier(name))
+        .collect();
+    format!(
+        "INSERT INTO {table_name} ({}) VALUES ({})",
+        quoted_names.join(", "),
+        vec!["?"; quoted_names.len()].join(", ")
+    )
+}
+
+fn delete_query(table_name: &str, key_field_names: &[String]) -> String {
+    let key_conditions: Vec<_> = key_field_names
+        .iter()
+        .map(|name| format!("{} = ?", quote_identifier(name)))
+        .collect();
+    format!(
+        "DELETE FROM {table_name} WHERE {}",
+        key_conditions.join(" AND ")
+    )
+}
+
+// The operations of a minibatch, at most one per primary key.
+struct PendingOperations {
+    key_field_indices: Vec<usize>,
+    operations: HashMap<Vec<Value>, PendingOperation>,
+}
+
+impl PendingOperations {
+    fn new(key_field_indices: Vec<usize>) -> Self {
+        Self {
+            key_field_indices,
+            operations: HashMap::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    fn add(&mut self, values: &[Value], diff: isize) -> Result<(), CassandraError> {
+        self.pending_operations.add(&data.values, data.diff)?;
+        if self
+            .max_pending_operations
+            .is_some_and(|max_pending_operations| {
+                self.pending_operations.len() >= max_pending_operations
+            })
+        {
+            self.flush(false)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, _forced: bool) -> Result<(), WriteError> {
+        let pending_operations = self.pending_operations.take();
+        let mut operations_by_token: HashMap<Option<i64>, Vec<PendingOperation>> = HashMap::new();
+        for operation in pending_operations {
+            let (_, token) = self.statement_and_token(&operation)?;
+            operations_by_token
+                .entry(token)
+                .or_default()
+                .push(operation);
+        }
+
+        let mut batches = Vec::new();
+        for operations in operations_by_token.values() {
+            for chunk in operations.chunks(MAX_STATEMENTS_PER_BATCH) {
+                let mut batch = Batch::new(BatchType::Unlogged);
+                let mut batch_values: Vec<&CqlRow> = Vec::with_capacity(chunk.len());
+                for operation in chunk {
+                    let (statement, _) = self.statement_and_token(operation)?;
+                    batch.append_statement(statement.clone());
+                    batch_values.push(match operation {
+                        PendingOperation::Insert(row) | PendingOperation::Delete(row) => row,
+                    });
+                }
+                batches.push((batch, batch_values));
+            }
+        }
+
+        self.runtime.block_on(async {
+            try_join_all(
+                batches
+                    .iter()
+                    .map(|(batch, batch_values)| self.session.batch(batch, batch_values)),
+            )
+            .await
+            .map_err(CassandraError::from)
+        })?;
+        Ok(())
+    }
+
+    fn name(&self) -> String {
+        format!("Cassandra({})", self.table_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    fn row(operation: &PendingOperation) -> (&'static str, &CqlRow) {
+        match operation {
+            PendingOperation::Insert(row) => ("insert", row),
+            PendingOperation::Delete(row) => ("delete", row),
+        }
+    }
+
+    #[test]
+    fn statements_quote_the_column_names() {
+        let column_names = names(&["id", "region", "Total \"amount\""]);
+        assert_eq!(
+            insert_query("shop.orders", &column_names),
+            r#"INSERT INTO shop.orders ("id", "region", "Total ""amount""") VALUES (?, ?, ?)"#
+        );
+        assert_eq!(
+            delete_query("shop.orders", &names(&["region", "id"])),
+            r#"DELETE FROM shop.orders WHERE "region" = ? AND "id" = ?"#
+        );
+    }
+
+    #[test]
+    fn primary_key_columns_are_found_by_name() {
+        let column_names = names(&["id", "region", "amount"]);
+        assert_eq!(
+            key_field_indices(&column_names, &names(&["region", "id"])).unwrap(),
+            vec![1, 0]
+        );
+        assert!(matches!(
+            key_field_indices(&column_names, &names(&["customer"])),
+            Err(CassandraError::PrimaryKeyColumnNotFound(name)) if name == "customer"
+        ));
+    }
+
+    #[test]
+    fn deletion_is_bound_to_the_primary_key_values() {
+        let mut operations = PendingOperations::new(vec![1, 0]);
+        operations
+            .add(&[Value::Int(7), Value::from("eu"), Value::from(2.5)], -1)
+            .unwrap();
+        let operations = operations.take();
+        assert_eq!(operations.len(), 1);
+        assert_eq!(
+            row(&operations[0]),
+            (
+                "delete",
+                &vec![
+                    Some(CqlValue::Text("eu".to_string())),
+                    Some(CqlValue::BigInt(7))
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn insertion_wins_over_deletion_of_the_same_key() {
+        let old_values = [Value::Int(7), Value::from("eu"), Value::from(2.5)];
+        let new_values = [Value::Int(7), Value::from("eu"), Value::None];
+        let expected_row = vec![
+            Some(CqlValue::BigInt(7)),
+            Some(CqlValue::Text("eu".to_string())),
+            None,
+        ];
+        for deletion_first in [true, false] {
+            let mut operations = PendingOperations::new(vec![0, 1]);
+            if deletion_first {
+                operations.add(&old_values, -1).unwrap();
+                operations.add(&new_values, 1).unwrap();
+            } else {
+                operations.add(&new_values, 1).unwrap();
+                operations.add(&old_values, -1).unwrap();
+            }
+            assert_eq!(operations.len(), 1);
+            let operations = operations.take();
+            assert_eq!(row(&operations[0]), ("insert", &expected_row));
+        }
+    }
+
+    #[test]
+    fn rows_with_different_keys_are_kept_apart() {
+        let mut operations = PendingOperations::new(vec![0]);
+        operations
+            .add(&[Value::Int(1), Value::from("a")], 1)
+            .unwrap();
+        operations
+            .add(&[Value::Int(2), Value::from("b")], -1)
+            .unwrap();
+        assert_eq!(operations.len(), 2);
+        assert_eq!(operations.take().len(), 2);
+        assert_eq!(operations.len(), 0);
+    }
+
+    #[test]
+    fn unsupported_values_are_rejected() {
+        let mut operations = PendingOperations::new(vec![0]);
+        let tuple_with_null = Value::Tuple([Value::Int(1), Value::None].into());
+        assert!(matches!(
+            operations.add(&[Value::Int(1), tuple_with_null], 1),
+            Err(CassandraError::UnsupportedValue(_))
+        ));
+        assert_eq!(operations.len(), 0);
+    }
+}
@@ -9,12 +9,11 @@ use clickhouse_rs::{errors::Error as ClickHouseClientError, Block, ClientHandle,
 use tokio::runtime::Runtime as TokioRuntime;
 
 use crate::async_runtime::create_async_tokio_runtime;
-use crate::connectors::data_format::{create_bincoded_value, FormatterContext};
+use crate::connectors::data_format::{create_bincoded_value, FormatterContext, ValueField};
 use crate::connectors::data_storage::TableWriterInitMode;
 use crate::connectors::{SPECIAL_FIELD_DIFF, SPECIAL_FIELD_TIME};
 use crate::engine::time::DateTime as DateTimeTrait;
 use crate::engine::{Type, Value};
-use crate::python_api::ValueField;
 
 use super::{WriteError, Writer};
 
@@ -238,7 +237,9 @@ fn clickhouse_data_type(type_: &Type) -> Result<String, WriteError> {
         Type::Bool => "Bool",
         Type::Int | Type::Duration => "Int64",
         Type::Float => "Float64",
-        Type::String | Type::Pointer | Type::Json | Type::PyObjectWrapper => "String",
+        Type::String | Type::Pointer | Type::Json | Type::PyObjectWrapper | Type::DateTimeZoned => {
+            "String"
+        }
         Type::DateTimeNaive | Type::DateTimeUtc => "DateTime64(9, 'UTC')",
         Type::Bytes => return Ok("Nullable(String)".to_string()),
         Type::List(element) => {
@@ -413,6 +414,7 @@ fn value_as_f64(value: &Value) -> Option<f64> {
 }
 
 /// Converts a string-like value to the `String` the connector stores for it. A
+/// zoned datetime is stored as its RFC 9557 text so the zone name survives. A
 /// `PyObjectWrapper` is stored as the base64-encoded bincode of its `Value`,
 /// matching `pw.serialize`, so it round-trips back through `pw.deserialize` after
 /// base64 decoding.
@@ -421,12 +423,14 @@ fn value_as_string(value: &Value) -> Option<String> {
         Value::String(s) => Some(s.to_string()),
         Value::Pointer(p) => Some(p.to_string()),
         Value::Json(j) => Some(j.to_string()),
+        Value::DateTimeZoned(dt) => Some(dt.to_string()),
         Value::PyObjectWrapper(_) => create_bincoded_value(value).ok(),
         _ => None,
     }
 }
 
 impl ClickHouseWriter {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         connection_string: &str,
         table_name: &str,
@@ -435,6 +439,7 @@ impl ClickHouseWriter {
         mode: TableWriterInitMode,
         snapshot_mode: bool,
         key_field_names: Option<Vec<String>>,
+        dry_run: bool,
     ) -> Result<ClickHouseWriter, WriteError> {
         // Validate every column type up front, independent of `init_mode`, so an
         // unsupported type is reported at construction rather than on the first
@@ -467,13 +472,20 @@ impl ClickHouseWriter {
             // Surface a misconfigured connection (bad host, credentials, …)
             // eagerly at construction time rather than on the first flush.
             client.ping().await.map_err(ClickHouseError::Driver)?;
-            for query in Self::init_queries(
+            let init_queries = Self::init_queries(
                 &quoted_table_name,
                 &value_fields,
                 mode,
                 snapshot_mode,
                 &key_field_names,
-            )? {
+            )?;
+            // A dry run only checks that the writer can be created, so it must not
+            // create or replace the destination table. The table it would write to
+            // is then only known for `Default`.
+            if dry_run && mode != TableWriterInitMode::Default {
+                return Ok(0);
+            }
+            for query in init_queries {
                 client
                     .execute(query.as_str())
                     .await
@@ -740,7 +752,11 @@ impl ClickHouseWriter {
                 Self::append_scalar(block, name, values, optional, value_as_duration_nanos)?
             }
             Type::Float => Self::append_scalar(block, name, values, optional, value_as_f64)?,
-            Type::String | Type::Pointer | Type::Json | Type::PyObjectWrapper => {
+            Type::String
+            | Type::Pointer
+            | Type::Json
+            | Type::PyObjectWrapper
+            | Type::DateTimeZoned => {
                 Self::append_scalar(block, name, values, optional, value_as_string)?
             }
             Type::DateTimeNaive | Type::DateTimeUtc => {
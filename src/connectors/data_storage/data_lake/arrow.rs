@@ -5,7 +5,8 @@ use deltalake::arrow::array::Array as ArrowArray;
 use deltalake::arrow::array::{
     BinaryArray as ArrowBinaryArray, BooleanArray as ArrowBooleanArray, BooleanBufferBuilder,
     Date32Array as ArrowDate32Array, Decimal128Array as ArrowDecimal128Array,
-    FixedSizeBinaryArray as ArrowFixedSizeBinaryArray, Float32Array as ArrowFloat32Array,
+    FixedSizeBinaryArray as ArrowFixedSizeBinaryArray,
+    FixedSizeListArray as ArrowFixedSizeListArray, Float32Array as ArrowFloat32Array,
     Float64Array as ArrowFloat64Array, Int32Array as ArrowInt32Array,
     Int64Array as ArrowInt64Array, LargeBinaryArray as ArrowLargeBinaryArray,
     LargeListArray as ArrowLargeListArray, ListArray as ArrowListArray,
@@ -24,15 +25,16 @@ use uuid::Uuid;
 
 use super::{LakeWriterSettings, MaintenanceMode};
 use crate::connectors::data_format::{
-    NDARRAY_ELEMENTS_FIELD_NAME, NDARRAY_SHAPE_FIELD_NAME, NDARRAY_SINGLE_ELEMENT_FIELD_NAME,
+    ValueField, NDARRAY_ELEMENTS_FIELD_NAME, NDARRAY_SHAPE_FIELD_NAME,
+    NDARRAY_SINGLE_ELEMENT_FIELD_NAME,
 };
 use crate::connectors::data_storage::data_lake::iceberg::IcebergError;
+use crate::connectors::data_storage::data_lake::lance::LanceError;
 use crate::connectors::data_storage::data_lake::LakeBatchWriter;
 use crate::connectors::WriteError;
 use crate::engine::time::DateTime as EngineDateTime;
 use crate::engine::value::Handle;
 use crate::engine::{Type, Value};
-use crate::python_api::ValueField;
 
 #[allow(clippy::too_many_lines)] // one match arm per supported Arrow type — reads naturally as a long table.
 pub fn array_for_type(
@@ -102,6 +104,7 @@ pub fn array_for_type(
                 Value::String(s) => Ok(s.to_string()),
                 Value::Pointer(p) => Ok(p.to_string()),
                 Value::Json(j) => Ok(j.to_string()),
+                Value::DateTimeZoned(dt) => Ok(dt.to_string()),
                 _ => Err(WriteError::TypeMismatchWithSchema(v.clone(), type_.clone())),
             })?;
             Ok(Arc::new(ArrowStringArray::from(v)))
@@ -286,6 +289,11 @@ pub fn array_for_type(
         }
         ArrowDataType::List(nested_type) => array_of_lists(values, nested_type, false),
         ArrowDataType::LargeList(nested_type) => array_of_lists(values, nested_type, true),
+        // The vectors stored in Lance, coming from either a list or a one-dimensional
+        // array of floats.
+        ArrowDataType::FixedSizeList(nested_type, size) => {
+            array_of_fixed_size_lists(values, nested_type, *size, type_)
+        }
         ArrowDataType::Struct(nested_struct) => {
             array_of_structs(values, nested_struct.as_ref(), type_)
         }
@@ -427,6 +435,15 @@ fn array_of_structs(
                     struct_columns[index].push(field.clone());
                 }
             }
+            Value::Struct(fields) => {
+                for (struct_column, arrow_field) in struct_columns.iter_mut().zip(nested_types) {
+                    let field = fields
+                        .iter()
+                        .find(|(name, _)| name.as_str() == arrow_field.name().as_str())
+                        .map_or(Value::None, |(_, field)| field.clone());
+                    struct_column.push(field);
+                }
+            }
             _ => {
                 return Err(WriteError::TypeMismatchWithSchema(
                     value.clone(),
@@ -519,7 +536,55 @@ fn array_of_lists(
     Ok(list_array)
 }
 
-fn arrow_data_type(
+fn array_of_fixed_size_lists(
+    values: &[Value],
+    nested_type: &Arc<ArrowField>,
+    size: i32,
+    list_type: &ArrowDataType,
+) -> Result<Arc<dyn ArrowArray>, WriteError> {
+    let expected_len = usize::try_from(size).expect("list size can't be negative");
+    let mut flat_values = Vec::with_capacity(values.len() * expected_len);
+
+    let mut defined_fields_map = BooleanBufferBuilder::new(values.len());
+    defined_fields_map.resize(values.len());
+    for (index, value) in values.iter().enumerate() {
+        let elements: Vec<Value> = match value {
+            Value::None => {
+                // A null list still occupies its slots in the flat values
+                flat_values.extend(std::iter::repeat_n(Value::None, expected_len));
+                continue;
+            }
+            Value::Tuple(list) => list.to_vec(),
+            Value::FloatArray(array) => array.iter().map(|v| Value::from(*v)).collect(),
+            _ => {
+                return Err(WriteError::TypeMismatchWithSchema(
+                    value.clone(),
+                    list_type.clone(),
+                ))
+            }
+        };
+        if elements.len() != expected_len {
+            return Err(LanceError::VectorLengthMismatch {
+                value_len: elements.len(),
+                expected: expected_len,
+            }
+            .into());
+        }
+        defined_fields_map.set_bit(index, true);
+        flat_values.extend(elements);
+    }
+
+    let flat_values = array_for_type(nested_type.data_type(), &flat_values)?;
+    let list_array = ArrowFixedSizeListArray::try_new(
+        nested_type.clone(),
+        size,
+        flat_values,
+        Some(NullBuffer::new(defined_fields_map.finish())),
+    )?;
+    Ok(Arc::new(list_array))
+}
+
+pub(crate) fn arrow_data_type(
     type_: &Type,
     settings: &LakeWriterSettings,
 ) -> Result<ArrowDataType, WriteError> {
@@ -527,7 +592,7 @@ fn arrow_data_type(
         Type::Bool => ArrowDataType::Boolean,
         Type::Int | Type::Duration => ArrowDataType::Int64,
         Type::Float => ArrowDataType::Float64,
-        Type::String | Type::Json | Type::Pointer => ArrowDataType::Utf8,
+        Type::String | Type::Json | Type::Pointer | Type::DateTimeZoned => ArrowDataType::Utf8,
         Type::Bytes | Type::PyObjectWrapper => {
             if settings.use_64bit_size_type {
                 ArrowDataType::LargeBinary
@@ -603,7 +668,20 @@ fn arrow_data_type(
             let struct_descriptor = ArrowFields::from(struct_fields);
             ArrowDataType::Struct(struct_descriptor)
         }
-        Type::Any | Type::Future(_) => return Err(WriteError::UnsupportedType(type_.clone())),
+        Type::Struct(fields) => {
+            let mut struct_fields = Vec::with_capacity(fields.len());
+            for (name, field_type) in fields.iter() {
+                struct_fields.push(ArrowField::new(
+                    name.as_str(),
+                    arrow_data_type(field_type, settings)?,
+                    field_type.is_optional(),
+                ));
+            }
+            ArrowDataType::Struct(ArrowFields::from(struct_fields))
+        }
+        Type::Any | Type::Future(_) | Type::Map(_) => {
+            return Err(WriteError::UnsupportedType(type_.clone()))
+        }
     })
 }
 
@@ -9,16 +9,21 @@ use super::{
     construct_column_order, construct_column_types_map, delta::open_and_read_delta_table,
     MaintenanceMode, SPECIAL_FIELD_ID,
 };
-use crate::connectors::data_format::FormatterContext;
+use crate::connectors::data_format::{FormatterContext, ValueField};
 use crate::connectors::data_storage::data_lake::arrow::array_for_type as arrow_array_for_type;
 use crate::connectors::WriteError;
 use crate::engine::{Key, Value};
-use crate::python_api::ValueField;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PayloadType {
     FullSnapshot,
     Diff,
+    /// The first `n_upserted_rows` rows are the current versions of the rows changed
+    /// since the last commit, and the remaining ones are the removed versions, whose
+    /// keys must be deleted from the table before the current versions are added.
+    KeyedChanges {
+        n_upserted_rows: usize,
+    },
 }
 
 pub trait ColumnBuffer: Send {
@@ -233,6 +238,90 @@ impl ColumnBuffer for SnapshotColumnBuffer {
     }
 }
 
+/// Collects the changes since the last commit without keeping the state of the
+/// whole table, for the storages that can delete the rows by key, such as
+/// Iceberg with its equality deletes.
+pub struct KeyedChangesColumnBuffer {
+    schema: Arc<ArrowSchema>,
+    upserted_rows: HashMap<Key, Vec<Value>>,
+    deleted_rows: HashMap<Key, Vec<Value>>,
+}
+
+impl KeyedChangesColumnBuffer {
+    pub fn new(schema: Arc<ArrowSchema>) -> Result<Self, WriteError> {
+        let id_field_index = schema
+            .fields()
+            .iter()
+            .position(|field| field.name() == SPECIAL_FIELD_ID);
+        if id_field_index != Some(schema.fields().len() - 1) {
+            return Err(WriteError::IncorrectInitialSnapshot(
+                IncorrectSnapshotError::NoIdField,
+            ));
+        }
+        Ok(Self {
+            schema,
+            upserted_rows: HashMap::new(),
+            deleted_rows: HashMap::new(),
+        })
+    }
+}
+
+impl ColumnBuffer for KeyedChangesColumnBuffer {
+    fn add_event(&mut self, data: FormatterContext) -> Result<(), WriteError> {
+        if data.diff == 1 {
+            self.upserted_rows.insert(data.key, data.values);
+        } else if data.diff == -1 {
+            // The events of a single minibatch may come in any order, so the
+            // removal only cancels the addition if it removes the same version.
+            // Otherwise, the removed version was committed earlier.
+            if self.upserted_rows.get(&data.key) == Some(&data.values) {
+                self.upserted_rows.remove(&data.key);
+            } else {
+                self.deleted_rows.insert(data.key, data.values);
+            }
+        } else {
+            panic!("Unexpected value of diff: {}", data.diff);
+        }
+        Ok(())
+    }
+
+    fn build_update_record_batch(&mut self) -> Result<(ArrowRecordBatch, PayloadType), WriteError> {
+        let num_fields = self.schema.fields().len();
+        let id_column_index = num_fields - 1;
+        let num_rows = self.upserted_rows.len() + self.deleted_rows.len();
+        let mut buffered_columns = vec![Vec::with_capacity(num_rows); num_fields];
+        for (key, values) in self.upserted_rows.iter().chain(self.deleted_rows.iter()) {
+            for (index, value) in values.iter().enumerate() {
+                buffered_columns[index].push(value.clone());
+            }
+            buffered_columns[id_column_index].push(Value::Pointer(*key));
+        }
+
+        let arrow_columns = buffered_columns
+            .into_iter()
+            .enumerate()
+            .map(|(index, column)| {
+                arrow_array_for_type(self.schema.field(index).data_type(), &column)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((
+            ArrowRecordBatch::try_new(self.schema.clone(), arrow_columns)?,
+            PayloadType::KeyedChanges {
+                n_upserted_rows: self.upserted_rows.len(),
+            },
+        ))
+    }
+
+    fn on_changes_written(&mut self) {
+        self.upserted_rows.clear();
+        self.deleted_rows.clear();
+    }
+
+    fn has_updates(&self) -> bool {
+        !self.upserted_rows.is_empty() || !self.deleted_rows.is_empty()
+    }
+}
+
 fn build_append_record_batch(
     buffered_columns: &[Vec<Value>],
     schema: &Arc<ArrowSchema>,
@@ -50,7 +50,7 @@ use super::{
 };
 use crate::async_runtime::create_async_tokio_runtime;
 use crate::connectors::data_format::{
-    parse_bool_advanced, NDARRAY_ELEMENTS_FIELD_NAME, NDARRAY_SHAPE_FIELD_NAME,
+    parse_bool_advanced, ValueField, NDARRAY_ELEMENTS_FIELD_NAME, NDARRAY_SHAPE_FIELD_NAME,
 };
 use crate::connectors::data_storage::data_lake::buffering::PayloadType;
 use crate::connectors::data_storage::data_lake::ArrowDataType;
@@ -63,11 +63,10 @@ use crate::connectors::{
     DataEventType, OffsetKey, OffsetValue, ReadError, ReadResult, Reader, ReaderContext,
     StorageType, WriteError, SPECIAL_FIELD_TIME,
 };
-use crate::engine::time::{DateTime, DateTimeNaive};
+use crate::engine::time::{DateTime, DateTimeNaive, DateTimeZoned};
 use crate::engine::value::parse_pathway_pointer;
 use crate::engine::{Duration as EngineDuration, Type, Value};
 use crate::persistence::frontier::OffsetAntichain;
-use crate::python_api::{BackfillingThreshold, ValueField};
 use crate::retry::{execute_with_retries, RetryConfig};
 use crate::timestamp::current_unix_timestamp_ms;
 
@@ -497,7 +496,7 @@ impl DeltaBatchWriter {
         let delta_type = match type_ {
             Type::Bool => DeltaTableKernelType::Primitive(DeltaTablePrimitiveType::Boolean),
             Type::Float => DeltaTableKernelType::Primitive(DeltaTablePrimitiveType::Double),
-            Type::String | Type::Json | Type::Pointer => {
+            Type::String | Type::Json | Type::Pointer | Type::DateTimeZoned => {
                 DeltaTableKernelType::Primitive(DeltaTablePrimitiveType::String)
             }
             Type::PyObjectWrapper | Type::Bytes => {
@@ -562,8 +561,25 @@ impl DeltaBatchWriter {
                     DeltaTableStructType::try_new(struct_fields).map_err(DeltaTableError::from)?;
                 DeltaTableKernelType::Struct(struct_descriptor.into())
             }
+            Type::Struct(fields) => {
+                let mut struct_fields = Vec::new();
+                for (name, nested_type) in fields.iter() {
+                    let nested_type_is_optional = nested_type.is_optional();
+                    let nested_delta_type = Self::delta_table_type(nested_type)?;
+                    struct_fields.push(DeltaTableStructField::new(
+                        name.to_string(),
+                        nested_delta_type,
+                        nested_type_is_optional,
+                    ));
+                }
+                let struct_descriptor =
+                    DeltaTableStructType::try_new(struct_fields).map_err(DeltaTableError::from)?;
+                DeltaTableKernelType::Struct(struct_descriptor.into())
+            }
             Type::Optional(wrapped) => return Self::delta_table_type(wrapped),
-            Type::Any | Type::Future(_) => return Err(WriteError::UnsupportedType(type_.clone())),
+            Type::Any | Type::Future(_) | Type::Map(_) => {
+                return Err(WriteError::UnsupportedType(type_.clone()))
+            }
         };
         Ok(delta_type)
     }
@@ -688,6 +704,9 @@ impl LakeBatchWriter for DeltaBatchWriter {
                         }
                         .await
                     }
+                    PayloadType::KeyedChanges { .. } => {
+                        unreachable!("Delta Lake snapshots are maintained by rewriting the table")
+                    }
                 };
                 match attempt_result {
                     Ok(()) => {
@@ -925,6 +944,18 @@ pub fn read_delta_table<S: std::hash::BuildHasher>(
     Ok(result)
 }
 
+/// A condition on a field, selecting the rows of the table to backfill.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "pathway.engine", frozen, get_all)
+)]
+pub struct BackfillingThreshold {
+    pub field: String,
+    pub threshold: Value,
+    pub comparison_op: String, // TODO: enum?
+}
+
 #[allow(clippy::module_name_repetitions)]
 pub struct DeltaTableReader {
     table: DeltaTable,
@@ -1521,6 +1552,9 @@ impl DeltaTableReader {
                 // decimal text of a microsecond Long (Duration); decode them with the same
                 // routines used for non-partition columns so writes round-trip on read.
                 Type::Pointer => parse_pathway_pointer(serialized_value).ok(),
+                Type::DateTimeZoned => DateTimeZoned::parse(serialized_value)
+                    .ok()
+                    .map(Value::DateTimeZoned),
                 Type::Json => serde_json::from_str::<serde_json::Value>(serialized_value)
                     .ok()
                     .map(Value::from),
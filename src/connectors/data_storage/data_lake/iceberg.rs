@@ -11,16 +11,20 @@ use deltalake::datafusion::parquet::arrow::PARQUET_FIELD_ID_META_KEY;
 use deltalake::parquet::file::properties::WriterProperties;
 use futures::{stream, StreamExt, TryStreamExt};
 use iceberg::arrow::type_to_arrow_type as iceberg_type_to_arrow_type;
-use iceberg::arrow::FieldMatchMode;
+use iceberg::arrow::{arrow_schema_to_schema, FieldMatchMode};
 use iceberg::scan::{FileScanTask, FileScanTaskStream};
 use iceberg::spec::{
-    ListType as IcebergListType, NestedField, PrimitiveType as IcebergPrimitiveType,
-    Schema as IcebergSchema, StructType as IcebergStructType, Type as IcebergType,
+    DataFile, FormatVersion, ListType as IcebergListType, NestedField,
+    PrimitiveType as IcebergPrimitiveType, Schema as IcebergSchema,
+    StructType as IcebergStructType, Type as IcebergType,
 };
 use iceberg::table::Table as IcebergTable;
 use iceberg::transaction::ApplyTransactionAction;
 use iceberg::transaction::Transaction;
 use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+use iceberg::writer::base_writer::equality_delete_writer::{
+    EqualityDeleteFileWriterBuilder, EqualityDeleteWriterConfig,
+};
 use iceberg::writer::file_writer::location_generator::{
     DefaultFileNameGenerator, DefaultLocationGenerator,
 };
@@ -35,10 +39,12 @@ use tokio::runtime::Runtime as TokioRuntime;
 use uuid::Uuid;
 
 use super::{
-    columns_into_pathway_values, LakeBatchWriter, LakeWriterSettings, SPECIAL_OUTPUT_FIELDS,
+    columns_into_pathway_values, LakeBatchWriter, LakeWriterSettings, MaintenanceMode,
+    SPECIAL_FIELD_ID,
 };
 use crate::connectors::data_format::{
-    NDARRAY_ELEMENTS_FIELD_NAME, NDARRAY_SHAPE_FIELD_NAME, NDARRAY_SINGLE_ELEMENT_FIELD_NAME,
+    ValueField, NDARRAY_ELEMENTS_FIELD_NAME, NDARRAY_SHAPE_FIELD_NAME,
+    NDARRAY_SINGLE_ELEMENT_FIELD_NAME,
 };
 use crate::connectors::data_storage::data_lake::buffering::PayloadType;
 use crate::connectors::data_storage::data_lake::MetadataPerColumn;
@@ -50,7 +56,6 @@ use crate::connectors::{
 };
 use crate::engine::Type;
 use crate::persistence::frontier::OffsetAntichain;
-use crate::python_api::ValueField;
 use crate::retry::{execute_with_retries, execute_with_retries_async, RetryConfig};
 use crate::timestamp::current_unix_timestamp_ms;
 
@@ -144,6 +149,12 @@ pub enum IcebergError {
 
     #[error("date value {value} doesn't fit into an Iceberg date column (i32 days since epoch)")]
     DateOutOfRange { value: String },
+
+    // ---- Snapshot maintenance ----
+    #[error(
+        "Iceberg table uses format version {format_version}, which doesn't support delete files; the snapshot output requires format version 2 or newer"
+    )]
+    DeleteFilesNotSupported { format_version: String },
 }
 
 /// True when an iceberg commit error means the data file we tried to append is
@@ -182,6 +193,7 @@ pub struct IcebergTableParams {
     schema: IcebergSchema,
     metadata_per_column: MetadataPerColumn,
     timestamp_unit: ArrowTimeUnit,
+    mode: MaintenanceMode,
     /// Pathway type the user declared for each user-supplied column. Used at
     /// `IcebergBatchWriter::new` time to reconcile against an existing table's
     /// schema and emit Arrow-type overrides where the existing column is
@@ -195,8 +207,9 @@ impl IcebergTableParams {
         name: String,
         fields: &[ValueField],
         timestamp_unit: ArrowTimeUnit,
+        mode: MaintenanceMode,
     ) -> Result<Self, WriteError> {
-        let schema = Self::build_schema(fields, timestamp_unit)?;
+        let schema = Self::build_schema(fields, timestamp_unit, mode)?;
         let mut metadata_per_column = MetadataPerColumn::new();
         for field in schema.as_struct().fields() {
             let mut metadata = HashMap::with_capacity(1);
@@ -212,6 +225,7 @@ impl IcebergTableParams {
             schema,
             metadata_per_column,
             timestamp_unit,
+            mode,
             user_pathway_types,
         })
     }
@@ -250,6 +264,7 @@ impl IcebergTableParams {
     fn build_schema(
         fields: &[ValueField],
         timestamp_unit: ArrowTimeUnit,
+        mode: MaintenanceMode,
     ) -> Result<IcebergSchema, WriteError> {
         let mut field_id_counter: i32 = 0;
         let mut next_field_id = || {
@@ -267,7 +282,7 @@ impl IcebergTableParams {
                 false, // No optional fields
             )));
         }
-        for (name, type_) in SPECIAL_OUTPUT_FIELDS {
+        for (name, type_) in mode.additional_output_fields() {
             let id = next_field_id();
             let nested_type = Self::iceberg_type(&type_, timestamp_unit, &mut next_field_id)?;
             nested_fields.push(Arc::new(NestedField::new(id, name, nested_type, false)));
@@ -287,7 +302,7 @@ impl IcebergTableParams {
         let iceberg_type = match type_ {
             Type::Bool => IcebergType::Primitive(IcebergPrimitiveType::Boolean),
             Type::Float => IcebergType::Primitive(IcebergPrimitiveType::Double),
-            Type::String | Type::Json | Type::Pointer => {
+            Type::String | Type::Json | Type::Pointer | Type::DateTimeZoned => {
                 IcebergType::Primitive(IcebergPrimitiveType::String)
             }
             Type::Bytes | Type::PyObjectWrapper => {
@@ -408,7 +423,7 @@ impl IcebergTableParams {
                 }
                 IcebergType::Struct(IcebergStructType::new(struct_fields))
             }
-            Type::Any | Type::Future(_) => {
+            Type::Any | Type::Future(_) | Type::Struct(_) | Type::Map(_) => {
                 return Err(WriteError::UnsupportedType(type_.clone()));
             }
         };
@@ -426,6 +441,9 @@ pub struct IcebergBatchWriter {
     table_ident: TableIdent,
     metadata_per_column: MetadataPerColumn,
     timestamp_unit: ArrowTimeUnit,
+    /// Field id of the `_id` column in the snapshot mode, where the retracted
+    /// rows are removed with the equality deletes on this column.
+    id_field_id: Option<i32>,
     /// Per-column Arrow-type overrides discovered when reconciling the user's
     /// Pathway types against an existing table's Iceberg schema. The wider
     /// arrow schema constructed from the user's Pathway types is rewritten at
@@ -467,12 +485,13 @@ impl IcebergBatchWriter {
 
         // Preflight: every required (non-null) column the destination table
         // declares must be either produced by the user's Pathway table or
-        // one of the special columns Pathway itself appends (`time`,
-        // `diff`). Without this check, a missing required column surfaces
-        // at the first flush as the deep-stack iceberg-rust error
-        // `DataInvalid => Field id N not found in struct array`, with no
-        // hint as to which column the user has to add.
-        let pathway_appended: std::collections::HashSet<&str> = SPECIAL_OUTPUT_FIELDS
+        // one of the special columns Pathway itself appends (`time` and
+        // `diff`, or `_id` for the snapshot). Without this check, a missing
+        // required column surfaces at the first flush as the deep-stack
+        // iceberg-rust error `DataInvalid => Field id N not found in struct
+        // array`, with no hint as to which column the user has to add.
+        let additional_output_fields = table_params.mode.additional_output_fields();
+        let pathway_appended: std::collections::HashSet<&str> = additional_output_fields
             .iter()
             .map(|(name, _)| *name)
             .collect();
@@ -620,6 +639,36 @@ impl IcebergBatchWriter {
             }
         }
 
+        // Preflight: the snapshot is maintained by deleting the previous
+        // versions of the changed rows by their `_id`, which needs both the
+        // column itself and a format version that has delete files at all.
+        let id_field_id = match table_params.mode {
+            MaintenanceMode::StreamOfChanges => None,
+            MaintenanceMode::Snapshot => {
+                let format_version = table.metadata().format_version();
+                if format_version == FormatVersion::V1 {
+                    return Err(IcebergError::DeleteFilesNotSupported {
+                        format_version: format!("{format_version}"),
+                    }
+                    .into());
+                }
+                let Some(id_field) = table
+                    .metadata()
+                    .current_schema()
+                    .field_by_name(SPECIAL_FIELD_ID)
+                else {
+                    let mut available: Vec<&str> = iceberg_column_names.iter().copied().collect();
+                    available.sort_unstable();
+                    return Err(IcebergError::WriteColumnNotInTable {
+                        column: SPECIAL_FIELD_ID.to_string(),
+                        available: available.join(", "),
+                    }
+                    .into());
+                };
+                Some(id_field.id)
+            }
+        };
+
         let arrow_type_overrides = compute_arrow_type_overrides(
             table.metadata().current_schema(),
             &table_params.user_pathway_types,
@@ -641,22 +690,13 @@ impl IcebergBatchWriter {
             table_ident: TableIdent::new(namespace.name().clone(), table_params.name.clone()),
             metadata_per_column,
             timestamp_unit: table_params.timestamp_unit,
+            id_field_id,
             arrow_type_overrides,
         })
     }
 
-    fn create_writer_builder(
-        table: &IcebergTable,
-    ) -> Result<
-        DataFileWriterBuilder<
-            ParquetWriterBuilder,
-            DefaultLocationGenerator,
-            DefaultFileNameGenerator,
-        >,
-        WriteError,
-    > {
-        let location_generator = DefaultLocationGenerator::new(table.metadata().clone())?;
-        let file_name_generator = DefaultFileNameGenerator::new(
+    fn create_file_name_generator(prefix: &str) -> DefaultFileNameGenerator {
+        DefaultFileNameGenerator::new(
             // Include a random uuid so two batches flushed within the same
             // millisecond can never generate the same parquet path. The
             // generator's own file counter resets to 0 for every writer (a
@@ -666,10 +706,28 @@ impl IcebergBatchWriter {
             // already-referenced data file path, which iceberg rejects with
             // `DataInvalid => Cannot add files that are already referenced by
             // table`.
-            format!("block-{}-{}", current_unix_timestamp_ms(), Uuid::new_v4()),
+            format!(
+                "{prefix}-{}-{}",
+                current_unix_timestamp_ms(),
+                Uuid::new_v4()
+            ),
             None,
             iceberg::spec::DataFileFormat::Parquet,
-        );
+        )
+    }
+
+    fn create_writer_builder(
+        table: &IcebergTable,
+    ) -> Result<
+        DataFileWriterBuilder<
+            ParquetWriterBuilder,
+            DefaultLocationGenerator,
+            DefaultFileNameGenerator,
+        >,
+        WriteError,
+    > {
+        let location_generator = DefaultLocationGenerator::new(table.metadata().clone())?;
+        let file_name_generator = Self::create_file_name_generator("block");
         // Use name-based matching between the Arrow batch and the iceberg
         // schema. Pathway's writer already aligns names at every level —
         // top-level columns use the user's names, struct children use the
@@ -693,6 +751,70 @@ impl IcebergBatchWriter {
         );
         Ok(DataFileWriterBuilder::new(rolling_file_writer_builder))
     }
+
+    /// Creates the writer of the equality delete files that remove the rows
+    /// by their `_id`. The delete files only contain the `_id` column, so the
+    /// batches passed to the writer must be projected onto it.
+    fn create_equality_delete_writer_builder(
+        table: &IcebergTable,
+        id_field_id: i32,
+    ) -> Result<
+        EqualityDeleteFileWriterBuilder<
+            ParquetWriterBuilder,
+            DefaultLocationGenerator,
+            DefaultFileNameGenerator,
+        >,
+        WriteError,
+    > {
+        let table_schema = table.metadata().current_schema();
+        let id_field = table_schema
+            .field_by_id(id_field_id)
+            .expect("the _id field is checked to be present on the writer creation");
+        let id_schema = Arc::new(
+            IcebergSchema::builder()
+                .with_schema_id(table_schema.schema_id())
+                .with_fields(vec![id_field.clone()])
+                .build()?,
+        );
+        let config = EqualityDeleteWriterConfig::new(vec![id_field_id], id_schema)?;
+        let delete_schema = arrow_schema_to_schema(config.projected_arrow_schema_ref())?;
+        let parquet_writer_builder = ParquetWriterBuilder::new_with_match_mode(
+            WriterProperties::default(),
+            Arc::new(delete_schema),
+            FieldMatchMode::Name,
+        );
+        let rolling_file_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+            parquet_writer_builder,
+            table.file_io().clone(),
+            DefaultLocationGenerator::new(table.metadata().clone())?,
+            Self::create_file_name_generator("delete"),
+        );
+        Ok(EqualityDeleteFileWriterBuilder::new(
+            rolling_file_writer_builder,
+            config,
+        ))
+    }
+
+    async fn write_equality_deletes(
+        table: &IcebergTable,
+        id_field_id: Option<i32>,
+        deleted_rows: ArrowRecordBatch,
+    ) -> Result<Vec<DataFile>, WriteError> {
+        if deleted_rows.num_rows() == 0 {
+            return Ok(Vec::new());
+        }
+        let id_field_id =
+            id_field_id.expect("the keyed changes are only produced in the snapshot mode");
+        let id_column_index = deleted_rows
+            .schema()
+            .index_of(SPECIAL_FIELD_ID)
+            .expect("the keyed changes must contain the _id column");
+        let deleted_keys = deleted_rows.project(&[id_column_index])?;
+        let writer_builder = Self::create_equality_delete_writer_builder(table, id_field_id)?;
+        let mut delete_file_writer = writer_builder.build(None).await?;
+        delete_file_writer.write(deleted_keys).await?;
+        Ok(delete_file_writer.close().await?)
+    }
 }
 
 impl LakeBatchWriter for IcebergBatchWriter {
@@ -701,16 +823,41 @@ impl LakeBatchWriter for IcebergBatchWriter {
         batch: ArrowRecordBatch,
         payload_type: PayloadType,
     ) -> Result<(), WriteError> {
-        assert_eq!(payload_type, PayloadType::Diff);
+        let (batch, deleted_rows) = match payload_type {
+            PayloadType::Diff => (batch, None),
+            PayloadType::KeyedChanges { n_upserted_rows } => {
+                let deleted_rows = batch.slice(n_upserted_rows, batch.num_rows() - n_upserted_rows);
+                (batch.slice(0, n_upserted_rows), Some(deleted_rows))
+            }
+            PayloadType::FullSnapshot => {
+                unreachable!("Iceberg snapshots are maintained with equality deletes")
+            }
+        };
         let writer_builder = Self::create_writer_builder(&self.table)?;
         self.runtime.block_on(async {
             // Prepare a new data block once. The file write is expensive
             // (parquet encoding + storage I/O) and the data file produced
             // here is just bytes on disk — uncommitted, so safe to keep
             // around across multiple commit attempts.
-            let mut data_file_writer = writer_builder.build(None).await?;
-            data_file_writer.write(batch).await?;
-            let data_file = data_file_writer.close().await?;
+            let mut data_file = Vec::new();
+            if deleted_rows.is_none() || batch.num_rows() > 0 {
+                let mut data_file_writer = writer_builder.build(None).await?;
+                data_file_writer.write(batch).await?;
+                data_file = data_file_writer.close().await?;
+            }
+
+            // In the snapshot mode, the previous versions of the changed rows
+            // are removed by the equality deletes on `_id`, committed in the
+            // same snapshot as the new versions. An equality delete only
+            // applies to the data files with a smaller sequence number, so it
+            // never removes the rows it's committed together with.
+            let delete_file = match deleted_rows {
+                Some(deleted_rows) => {
+                    Self::write_equality_deletes(&self.table, self.id_field_id, deleted_rows)
+                        .await?
+                }
+                None => Vec::new(),
+            };
 
             // Retry only the catalog commit portion. Iceberg uses optimistic
             // concurrency: a concurrent writer (or an external compaction)
@@ -740,7 +887,7 @@ impl LakeBatchWriter for IcebergBatchWriter {
             // table`. That rejection is proof the write is already durable, so
             // we treat it as success instead of letting it burn the retry
             // budget and surface as a hard error. Because every data file path
-            // is unique (see the uuid in `create_writer_builder`), an
+            // is unique (see the uuid in `create_file_name_generator`), an
             // "already referenced" verdict can only be the echo of this
             // batch's own earlier, apparently-failed commit — never a
             // collision with an unrelated write.
@@ -748,8 +895,16 @@ impl LakeBatchWriter for IcebergBatchWriter {
                 async || {
                     self.table = self.catalog.load_table(&self.table_ident).await?;
                     let tx = Transaction::new(&self.table);
-                    let append_action = tx.fast_append().add_data_files(data_file.clone());
-                    let tx = append_action.apply(tx)?;
+                    let tx = if delete_file.is_empty() {
+                        let append_action = tx.fast_append().add_data_files(data_file.clone());
+                        append_action.apply(tx)?
+                    } else {
+                        let row_delta_action = tx
+                            .row_delta()
+                            .add_data_files(data_file.clone())
+                            .add_delete_files(delete_file.clone());
+                        row_delta_action.apply(tx)?
+                    };
                     match tx.commit(self.catalog.as_ref()).await {
                         Ok(_) => Ok(()),
                         Err(e) if iceberg_error_is_already_committed(&e) => Ok(()),
@@ -855,7 +1010,7 @@ fn iceberg_to_pathway_compatible(iceberg_type: &IcebergType, user_type: &Type) -
             | (P::Time, Type::Duration)
             | (P::Timestamp | P::TimestampNs, Type::DateTimeNaive)
             | (P::Timestamptz | P::TimestamptzNs, Type::DateTimeUtc)
-            | (P::String, Type::String | Type::Json | Type::Pointer)
+            | (P::String, Type::String | Type::Json | Type::Pointer | Type::DateTimeZoned)
             | (P::Uuid, Type::String | Type::Bytes)
             | (P::Fixed(_) | P::Binary, Type::Bytes | Type::PyObjectWrapper) => true,
             _ => false,
@@ -898,7 +1053,7 @@ fn pathway_to_iceberg_compatible(user_type: &Type, iceberg_type: &IcebergType) -
             (Type::Bool, P::Boolean)
             | (Type::Int | Type::Duration, P::Long)
             | (Type::Float, P::Double)
-            | (Type::String | Type::Json | Type::Pointer, P::String)
+            | (Type::String | Type::Json | Type::Pointer | Type::DateTimeZoned, P::String)
             | (Type::Bytes | Type::PyObjectWrapper, P::Binary)
             | (Type::DateTimeNaive, P::Timestamp | P::TimestampNs)
             | (Type::DateTimeUtc, P::Timestamptz | P::TimestamptzNs)
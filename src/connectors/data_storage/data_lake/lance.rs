@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use deltalake::arrow::datatypes::{
+    DataType as ArrowDataType, Field as ArrowField, TimeUnit as ArrowTimeUnit,
+};
+use deltalake::arrow::record_batch::{RecordBatch as ArrowRecordBatch, RecordBatchIterator};
+use lance::dataset::{Dataset, WriteMode, WriteParams};
+use lance::io::ObjectStoreParams;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use super::{LakeBatchWriter, LakeWriterSettings};
+use crate::async_runtime::create_async_tokio_runtime;
+use crate::connectors::data_format::ValueField;
+use crate::connectors::data_storage::data_lake::buffering::PayloadType;
+use crate::connectors::WriteError;
+use crate::engine::Type;
+
+/// Name of the element field of the fixed-size lists holding the vectors,
+/// the one Lance and `PyArrow` use by default.
+const VECTOR_ELEMENT_FIELD_NAME: &str = "item";
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum LanceError {
+    #[error(transparent)]
+    Library(#[from] lance::Error),
+
+    #[error("vector column '{0}' is not present in the table")]
+    VectorColumnNotFound(String),
+
+    #[error(
+        "column '{column}' has type {type_}, but only the lists and the one-dimensional arrays of floats can be stored as vectors"
+    )]
+    UnsupportedVectorColumnType { column: String, type_: Type },
+
+    #[error("vector column '{column}' has an invalid dimension {dimension}")]
+    InvalidVectorDimension { column: String, dimension: usize },
+
+    #[error("vector has {value_len} elements, expected exactly {expected}")]
+    VectorLengthMismatch { value_len: usize, expected: usize },
+}
+
+/// Appends the stream of changes to a Lance dataset, creating the dataset on
+/// the first commit if it doesn't exist yet. Each commit adds a new version of
+/// the dataset.
+///
+/// The columns listed in `vector_columns` are stored as fixed-size lists of
+/// `float32` values of the given dimension, which is the layout the Lance
+/// vector indices are built on, so the dataset can be indexed and searched
+/// without converting it first.
+pub struct LanceBatchWriter {
+    runtime: TokioRuntime,
+    uri: String,
+    storage_options: HashMap<String, String>,
+    vector_columns: HashMap<String, i32>,
+}
+
+impl LanceBatchWriter {
+    pub fn new(
+        uri: &str,
+        value_fields: &[ValueField],
+        vector_columns: HashMap<String, usize>,
+        storage_options: HashMap<String, String>,
+    ) -> Result<Self, WriteError> {
+        let mut vector_dimensions = HashMap::with_capacity(vector_columns.len());
+        for (column, dimension) in vector_columns {
+            let Some(field) = value_fields.iter().find(|field| field.name == column) else {
+                return Err(LanceError::VectorColumnNotFound(column).into());
+            };
+            let is_vector_type = match field.type_.unoptionalize() {
+                Type::List(element_type) => matches!(element_type.unoptionalize(), Type::Float),
+                Type::Array(_, element_type) => matches!(element_type.as_ref(), Type::Float),
+                _ => false,
+            };
+            if !is_vector_type {
+                return Err(LanceError::UnsupportedVectorColumnType {
+                    column,
+                    type_: field.type_.clone(),
+                }
+                .into());
+            }
+            let Some(dimension) = i32::try_from(dimension).ok().filter(|d| *d > 0) else {
+                return Err(LanceError::InvalidVectorDimension { column, dimension }.into());
+            };
+            vector_dimensions.insert(column, dimension);
+        }
+
+        Ok(Self {
+            runtime: create_async_tokio_runtime()?,
+            uri: uri.to_string(),
+            storage_options,
+            vector_columns: vector_dimensions,
+        })
+    }
+}
+
+impl LakeBatchWriter for LanceBatchWriter {
+    fn write_batch(
+        &mut self,
+        batch: ArrowRecordBatch,
+        payload_type: PayloadType,
+    ) -> Result<(), WriteError> {
+        assert_eq!(payload_type, PayloadType::Diff);
+        let schema = batch.schema();
+        let batches = RecordBatchIterator::new(vec![Ok(batch)], schema);
+        let params = WriteParams {
+            mode: WriteMode::Append,
+            store_params: Some(ObjectStoreParams {
+                storage_options: Some(self.storage_options.clone()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        self.runtime
+            .block_on(Dataset::write(batches, &self.uri, Some(params)))
+            .map_err(LanceError::from)?;
+        Ok(())
+    }
+
+    fn settings(&self) -> LakeWriterSettings {
+        LakeWriterSettings {
+            use_64bit_size_type: false,
+            utc_timezone_name: "UTC".into(),
+            timestamp_unit: ArrowTimeUnit::Microsecond,
+        }
+    }
+
+    fn name(&self) -> String {
+        format!("Lance({})", self.uri)
+    }
+
+    fn arrow_type_overrides(&self) -> HashMap<String, ArrowDataType> {
+        self.vector_columns
+            .iter()
+            .map(|(name, dimension)| {
+                let element_field =
+                    ArrowField::new(VECTOR_ELEMENT_FIELD_NAME, ArrowDataType::Float32, true);
+                (
+                    name.clone(),
+                    ArrowDataType::FixedSizeList(Arc::new(element_field), *dimension),
+                )
+            })
+            .collect()
+    }
+}
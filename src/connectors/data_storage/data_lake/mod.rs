@@ -27,28 +27,31 @@ use ndarray::ArrayD;
 use once_cell::sync::Lazy;
 use uuid::Uuid;
 
-use crate::connectors::data_format::{NDARRAY_ELEMENTS_FIELD_NAME, NDARRAY_SHAPE_FIELD_NAME};
+use crate::connectors::data_format::{
+    ValueField, NDARRAY_ELEMENTS_FIELD_NAME, NDARRAY_SHAPE_FIELD_NAME,
+};
 use crate::connectors::data_storage::data_lake::buffering::PayloadType;
 use crate::connectors::data_storage::ConversionError;
 use crate::connectors::data_storage::ValuesMap;
 use crate::connectors::{WriteError, SPECIAL_FIELD_DIFF, SPECIAL_FIELD_TIME};
 use crate::engine::error::{limit_length, STANDARD_OBJECT_LENGTH_LIMIT};
 use crate::engine::{
-    value::parse_pathway_pointer, value::Kind, DateTimeNaive, DateTimeUtc,
+    value::parse_pathway_pointer, value::Kind, DateTimeNaive, DateTimeUtc, DateTimeZoned,
     Duration as EngineDuration, Type, Value,
 };
-use crate::python_api::ValueField;
 
 pub mod arrow;
 pub mod buffering;
 pub mod delta;
 pub mod iceberg;
 pub mod iceberg_storage;
+pub mod lance;
 pub mod writer;
 
 pub use delta::DeltaBatchWriter;
 pub use iceberg::IcebergBatchWriter;
 pub use iceberg_storage::PathwayStorageFactory;
+pub use lance::LanceBatchWriter;
 pub use writer::LakeWriter;
 
 const SPECIAL_FIELD_ID: &str = "_id";
@@ -183,6 +186,9 @@ pub fn parquet_value_into_pathway_value(
         }
         (ParquetValue::Str(s), Type::String | Type::Any) => Some(Value::String(s.into())),
         (ParquetValue::Str(s), Type::Pointer) => parse_pathway_pointer(s).ok(),
+        (ParquetValue::Str(s), Type::DateTimeZoned) => {
+            DateTimeZoned::parse(s).ok().map(Value::DateTimeZoned)
+        }
         (ParquetValue::Str(s), Type::Json) => serde_json::from_str::<serde_json::Value>(s)
             .ok()
             .map(Value::from),
@@ -235,6 +241,9 @@ pub fn parquet_value_into_pathway_value(
         (ParquetValue::Group(row), Type::Tuple(nested_types)) => {
             parse_pathway_tuple_from_row(row, nested_types)
         }
+        (ParquetValue::Group(row), Type::Struct(fields)) => {
+            parse_pathway_struct_from_row(row, fields)
+        }
         _ => None,
     };
 
@@ -274,6 +283,30 @@ pub fn parse_pathway_tuple_from_row(row: &ParquetRow, nested_types: &[Type]) ->
     Some(Value::Tuple(tuple_values.into()))
 }
 
+pub fn parse_pathway_struct_from_row(row: &ParquetRow, fields: &[(ArcStr, Type)]) -> Option<Value> {
+    let mut struct_contents: Vec<Option<Value>> = vec![None; fields.len()];
+    for (column_name, parquet_value) in row.get_column_iter() {
+        let Some(index) = fields
+            .iter()
+            .position(|(name, _)| name.as_str() == column_name.as_str())
+        else {
+            continue;
+        };
+        struct_contents[index] =
+            parquet_value_into_pathway_value(parquet_value, &fields[index].1, column_name).ok();
+    }
+    let mut struct_values = Vec::with_capacity(fields.len());
+    for ((name, type_), value) in fields.iter().zip(struct_contents) {
+        let value = match value {
+            Some(value) => value,
+            None if type_.is_optional() => Value::None,
+            None => return None,
+        };
+        struct_values.push((name.clone(), value));
+    }
+    Some(Value::from(struct_values))
+}
+
 pub fn parse_pathway_array_from_parquet_row(row: &ParquetRow, array_type: &Type) -> Option<Value> {
     let shape_i64 = parse_int_array_from_parquet_row(row, NDARRAY_SHAPE_FIELD_NAME)?;
     let mut shape: Vec<usize> = Vec::new();
@@ -465,15 +498,18 @@ fn column_into_pathway_values(
                 .collect()
         }
         (ArrowDataType::Boolean, Type::Bool | Type::Any) => convert_arrow_boolean_array(column),
-        (ArrowDataType::Utf8, Type::String | Type::Json | Type::Pointer | Type::Any) => {
-            convert_arrow_string_array::<i32>(column, column_name, expected_type_unopt)
-        }
-        (ArrowDataType::LargeUtf8, Type::String | Type::Json | Type::Pointer | Type::Any) => {
-            convert_arrow_string_array::<i64>(column, column_name, expected_type_unopt)
-        }
-        (ArrowDataType::Utf8View, Type::String | Type::Json | Type::Pointer | Type::Any) => {
-            convert_arrow_string_view_array(column, column_name, expected_type_unopt)
-        }
+        (
+            ArrowDataType::Utf8,
+            Type::String | Type::Json | Type::Pointer | Type::DateTimeZoned | Type::Any,
+        ) => convert_arrow_string_array::<i32>(column, column_name, expected_type_unopt),
+        (
+            ArrowDataType::LargeUtf8,
+            Type::String | Type::Json | Type::Pointer | Type::DateTimeZoned | Type::Any,
+        ) => convert_arrow_string_array::<i64>(column, column_name, expected_type_unopt),
+        (
+            ArrowDataType::Utf8View,
+            Type::String | Type::Json | Type::Pointer | Type::DateTimeZoned | Type::Any,
+        ) => convert_arrow_string_view_array(column, column_name, expected_type_unopt),
         (ArrowDataType::Binary, Type::Bytes | Type::PyObjectWrapper | Type::Any) => {
             convert_arrow_bytes_array::<i32>(column, column_name, expected_type_unopt)
         }
@@ -587,6 +623,11 @@ fn column_into_pathway_values(
         (ArrowDataType::Struct(_), Type::Tuple(nested_types)) => {
             convert_arrow_struct_array(column, nested_types, column_name)
         }
+        // Pathway structs are matched by field name, so the order of the
+        // fields in the file doesn't need to follow the declared one.
+        (ArrowDataType::Struct(_), Type::Struct(fields)) => {
+            convert_arrow_struct_array_to_struct(column, fields, column_name)
+        }
         // Pathway `Array(_, T)` (numpy ndarray) is encoded as
         // `struct<shape: list<long>, elements: list<T>>` on write (mirroring
         // `delta_table_type::Type::Array`). On read, walk the struct's named
@@ -699,6 +740,57 @@ fn convert_arrow_dictionary_array<
     Some(result)
 }
 
+/// Convert an arrow `Struct` column into a column of Pathway structs. The
+/// nested fields are looked up by name; a field absent from the arrow struct
+/// is read as `None` if its declared type is optional and is a conversion
+/// error otherwise.
+fn convert_arrow_struct_array_to_struct(
+    column: &Arc<dyn ArrowArray>,
+    fields: &[(ArcStr, Type)],
+    column_name: &str,
+) -> Vec<ParsedValue> {
+    let struct_array = column.as_struct();
+    let n_rows = struct_array.len();
+    let mut converted_columns: Vec<Vec<ParsedValue>> = Vec::with_capacity(fields.len());
+    for (name, expected) in fields {
+        if let Some(child) = struct_array.column_by_name(name) {
+            converted_columns.push(column_into_pathway_values(
+                child,
+                expected,
+                column_name,
+                n_rows,
+            ));
+        } else if expected.is_optional() {
+            converted_columns.push(vec![Ok(Value::None); n_rows]);
+        } else {
+            let missing_field_msg = format!("struct has no field {name:?}");
+            return vec![
+                Err(Box::new(conversion_error(
+                    &missing_field_msg,
+                    column_name,
+                    &Type::Struct(fields.to_vec().into()),
+                )));
+                n_rows
+            ];
+        }
+    }
+    let nulls = struct_array.nulls();
+    let mut result = Vec::with_capacity(n_rows);
+    for row_index in 0..n_rows {
+        if nulls.is_some_and(|n| n.is_null(row_index)) {
+            result.push(Ok(Value::None));
+            continue;
+        }
+        let row_value: Result<Vec<_>, Box<ConversionError>> = fields
+            .iter()
+            .zip(&converted_columns)
+            .map(|((name, _), col)| Ok((name.clone(), col[row_index].clone()?)))
+            .collect();
+        result.push(row_value.map(Value::from));
+    }
+    result
+}
+
 /// Convert an arrow `Struct` column into a column of Pathway tuples by
 /// position. The nested fields are converted recursively using the
 /// user-declared `nested_types`; their names are ignored. Mismatches in
@@ -920,6 +1012,13 @@ fn convert_arrow_string_array<OffsetType: OffsetSizeTrait>(
                         expected_type,
                     ))
                 }),
+                Type::DateTimeZoned => DateTimeZoned::parse(v).map(Into::into).map_err(|_| {
+                    Box::new(conversion_error(
+                        &limit_length(v.to_string(), STANDARD_OBJECT_LENGTH_LIMIT),
+                        name,
+                        expected_type,
+                    ))
+                }),
                 _ => unreachable!("must not be used for type {expected_type}"),
             },
             None => Ok(Value::None),
@@ -954,6 +1053,13 @@ fn convert_arrow_string_view_array(
                         expected_type,
                     ))
                 }),
+                Type::DateTimeZoned => DateTimeZoned::parse(v).map(Into::into).map_err(|_| {
+                    Box::new(conversion_error(
+                        &limit_length(v.to_string(), STANDARD_OBJECT_LENGTH_LIMIT),
+                        name,
+                        expected_type,
+                    ))
+                }),
                 _ => unreachable!("must not be used for type {expected_type}"),
             },
             None => Ok(Value::None),
@@ -12,12 +12,11 @@ use itertools::Itertools;
 use log::warn;
 use serde_json::{json, Value as JsonValue};
 
-use crate::connectors::data_format::{create_bincoded_value, FormatterContext};
+use crate::connectors::data_format::{create_bincoded_value, FormatterContext, ValueField};
 use crate::connectors::data_storage::{SqlQueryTemplate, TableContext, TableWriterInitMode};
 use crate::connectors::{WriteError, Writer};
 use crate::engine::time::DateTime as DateTimeTrait;
 use crate::engine::{Type, Value};
-use crate::python_api::ValueField;
 
 /// Errors specific to the `DuckDB` output connector.
 ///
@@ -904,7 +903,7 @@ fn duckdb_data_type(type_: &Type, is_nested: bool) -> Result<String, WriteError>
         Type::Bool => "BOOLEAN".to_string(),
         Type::Int => "BIGINT".to_string(),
         Type::Float => "DOUBLE".to_string(),
-        Type::String | Type::Pointer => "VARCHAR".to_string(),
+        Type::String | Type::Pointer | Type::DateTimeZoned => "VARCHAR".to_string(),
         Type::Bytes | Type::PyObjectWrapper => "BLOB".to_string(),
         // DateTimeUtc is stored as a UTC wall-clock TIMESTAMP (microsecond
         // resolution), matching how the value is bound; this is deterministic
@@ -935,6 +934,17 @@ fn duckdb_data_type(type_: &Type, is_nested: bool) -> Result<String, WriteError>
                 "JSON".to_string()
             }
         }
+        Type::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|(name, field_type)| {
+                    let field_type = duckdb_data_type(field_type, true)?;
+                    Ok(format!("\"{}\" {field_type}", name.replace('"', "\"\"")))
+                })
+                .collect::<Result<Vec<_>, WriteError>>()?;
+            format!("STRUCT({})", fields.join(", "))
+        }
+        Type::Map(value_type) => format!("MAP(VARCHAR, {})", duckdb_data_type(value_type, true)?),
         Type::Optional(_) => unreachable!("handled above"),
         Type::Any | Type::Future(_) => return Err(WriteError::UnsupportedType(type_.clone())),
     };
@@ -948,7 +958,12 @@ fn duckdb_data_type(type_: &Type, is_nested: bool) -> Result<String, WriteError>
 fn needs_json_cast(type_: &Type) -> bool {
     matches!(
         type_.unoptionalize(),
-        Type::Json | Type::List(_) | Type::Array(_, _) | Type::Tuple(_)
+        Type::Json
+            | Type::List(_)
+            | Type::Array(_, _)
+            | Type::Tuple(_)
+            | Type::Struct(_)
+            | Type::Map(_)
     )
 }
 
@@ -961,9 +976,12 @@ fn needs_json_cast(type_: &Type) -> bool {
 fn is_json_encodable(type_: &Type) -> bool {
     match type_ {
         Type::Bool | Type::Int | Type::Float | Type::String | Type::Pointer | Type::Json => true,
-        Type::Optional(inner) | Type::List(inner) => is_json_encodable(inner),
+        Type::Optional(inner) | Type::List(inner) | Type::Map(inner) => is_json_encodable(inner),
         Type::Array(_, wrapped) => matches!(wrapped.as_ref(), Type::Int | Type::Float | Type::Any),
         Type::Tuple(args) => args.iter().all(is_json_encodable),
+        Type::Struct(fields) => fields
+            .iter()
+            .all(|(_, field_type)| is_json_encodable(field_type)),
         _ => false,
     }
 }
@@ -1028,13 +1046,18 @@ fn encode_value(value: &Value) -> Result<DuckValue, WriteError> {
         Value::DateTimeUtc(dt) => {
             DuckValue::Timestamp(TimeUnit::Microsecond, dt.timestamp() / 1000)
         }
+        Value::DateTimeZoned(dt) => DuckValue::Text(dt.to_string()),
         Value::Duration(d) => DuckValue::Interval {
             months: 0,
             days: 0,
             nanos: d.nanoseconds(),
         },
         Value::Json(j) => DuckValue::Text(j.to_string()),
-        Value::Tuple(_) | Value::IntArray(_) | Value::FloatArray(_) => DuckValue::Text(
+        Value::Tuple(_)
+        | Value::IntArray(_)
+        | Value::FloatArray(_)
+        | Value::Struct(_)
+        | Value::Map(_) => DuckValue::Text(
             serde_json::to_string(&value_to_json(value)?).expect("JSON is serializable"),
         ),
         Value::PyObjectWrapper(_) => DuckValue::Blob(create_bincoded_value(value)?.into_bytes()),
@@ -1078,6 +1101,20 @@ fn value_to_json(value: &Value) -> Result<JsonValue, WriteError> {
             }
             JsonValue::Array(array)
         }
+        Value::Struct(fields) => {
+            let mut object = serde_json::Map::with_capacity(fields.len());
+            for (name, item) in fields.iter() {
+                object.insert(name.to_string(), value_to_json(item)?);
+            }
+            JsonValue::Object(object)
+        }
+        Value::Map(map) => {
+            let mut object = serde_json::Map::with_capacity(map.len());
+            for (key, item) in map.iter() {
+                object.insert(key.to_string(), value_to_json(item)?);
+            }
+            JsonValue::Object(object)
+        }
         Value::IntArray(a) => {
             let flat: Vec<JsonValue> = a.iter().map(|item| json!(item)).collect();
             nest_by_shape(a.shape(), &flat, &mut 0)
@@ -0,0 +1,443 @@
+use log::error;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::mem::take;
+use std::sync::Arc;
+
+use arcstr::ArcStr;
+use azure_core_eventhubs::Error as AzureError;
+use azure_messaging_eventhubs::models::{EventData, ReceivedEventData};
+use azure_messaging_eventhubs::{
+    ConsumerClient, EventDataBatchOptions, OpenReceiverOptions, ProducerClient, StartLocation,
+    StartPosition,
+};
+use futures::StreamExt;
+use tokio::runtime::Runtime as TokioRuntime;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::connectors::data_format::FormatterContext;
+use crate::connectors::data_storage::sharding::ShardSelector;
+use crate::connectors::data_storage::ReaderContext;
+use crate::connectors::{
+    Offset, OffsetKey, OffsetValue, ReadError, ReadResult, Reader, StorageType, WriteError, Writer,
+};
+use crate::engine::Value;
+use crate::persistence::frontier::OffsetAntichain;
+
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum EventHubsError {
+    #[error("Event Hubs request failed: {0}")]
+    Azure(#[from] AzureError),
+
+    #[error("The event received from the partition {0} has no sequence number")]
+    NoSequenceNumber(ArcStr),
+
+    #[error("The event of {0} bytes doesn't fit into an empty batch")]
+    EventTooLarge(usize),
+}
+
+// The number of events received from the partitions and not yet returned by the reader.
+const EVENTS_CHANNEL_CAPACITY: usize = 1024;
+
+type PartitionEvent = (ArcStr, Result<ReceivedEventData, AzureError>);
+
+/// Reads the events from the partitions of an Azure Event Hub over AMQP.
+///
+/// The partitions are distributed between the workers. The position of the reader in each
+/// partition is the sequence number of the last event read, which is stored in the
+/// frontier, so that after a restart the partitions are read from the next events.
+#[allow(clippy::module_name_repetitions)]
+pub struct EventHubsReader {
+    runtime: TokioRuntime,
+    consumer: Arc<ConsumerClient>,
+    eventhub_name: String,
+    assigned_partitions: Vec<ArcStr>,
+    positions: PartitionPositions,
+    partition_receivers: Vec<JoinHandle<()>>,
+    events: Option<mpsc::Receiver<PartitionEvent>>,
+}
+
+// The sequence numbers of the last events read from the partitions, the reading of
+// each partition continues after its one.
+#[derive(Default)]
+struct PartitionPositions {
+    last_sequence_numbers: HashMap<ArcStr, i64>,
+}
+
+impl PartitionPositions {
+    fn start_position(&self, partition_id: &ArcStr) -> StartPosition {
+        let location = match self.last_sequence_numbers.get(partition_id) {
+            Some(sequence_number) => StartLocation::SequenceNumber(*sequence_number),
+            None => StartLocation::Earliest,
+        };
+        StartPosition {
+            location,
+            inclusive: false,
+        }
+    }
+
+    fn advance(&mut self, partition_id: ArcStr, sequence_number: i64) -> Offset {
+        self.last_sequence_numbers
+            .insert(partition_id.clone(), sequence_number);
+        (
+            OffsetKey::EventHubs(partition_id),
+            OffsetValue::EventHubsSequenceNumber(sequence_number),
+        )
+    }
+
+    fn seek(&mut self, frontier: &OffsetAntichain) {
+        self.last_sequence_numbers.clear();
+        for (offset_key, offset_value) in frontier {
+            let OffsetKey::EventHubs(partition_id) = offset_key else {
+                error!("Unexpected key type in Event Hubs offset: {offset_key:?}");
+                continue;
+            };
+            let OffsetValue::EventHubsSequenceNumber(sequence_number) = offset_value else {
+                error!("Unexpected value type in Event Hubs offset: {offset_value:?}");
+                continue;
+            };
+            self.last_sequence_numbers
+                .insert(partition_id.clone(), *sequence_number);
+        }
+    }
+}
+
+impl EventHubsReader {
+    pub fn new(
+        runtime: TokioRuntime,
+        consumer: ConsumerClient,
+        eventhub_name: String,
+        partition_selector: ShardSelector,
+    ) -> Result<Self, ReadError> {
+        let properties = runtime
+            .block_on(consumer.get_eventhub_properties())
+            .map_err(EventHubsError::from)?;
+        let assigned_partitions = properties
+            .partition_ids
+            .into_iter()
+            .filter(|partition_id| partition_selector.owns(partition_id.as_bytes()))
+            .map(ArcStr::from)
+            .collect();
+
+        Ok(Self {
+            runtime,
+            consumer: Arc::new(consumer),
+            eventhub_name,
+            assigned_partitions,
+            positions: PartitionPositions::default(),
+            partition_receivers: Vec::new(),
+            events: None,
+        })
+    }
+
+    fn stop_partition_receivers(&mut self) {
+        for partition_receiver in take(&mut self.partition_receivers) {
+            partition_receiver.abort();
+        }
+        self.events = None;
+    }
+
+    fn start_partition_receivers(&mut self) -> mpsc::Receiver<PartitionEvent> {
+        let (sender, receiver) = mpsc::channel(EVENTS_CHANNEL_CAPACITY);
+        for partition_id in &self.assigned_partitions {
+            let options = OpenReceiverOptions {
+                start_position: Some(self.positions.start_position(partition_id)),
+                ..OpenReceiverOptions::default()
+            };
+            let consumer = self.consumer.clone();
+            let partition_id = partition_id.clone();
+            let sender = sender.clone();
+            self.partition_receivers
+                .push(self.runtime.spawn(async move {
+                    let partition_receiver = match consumer
+                        .open_receiver_on_partition(partition_id.to_string(), Some(options))
+                        .await
+                    {
+                        Ok(partition_receiver) => partition_receiver,
+                        Err(e) => {
+                            let _ = sender.send((partition_id, Err(e))).await;
+                            return;
+                        }
+                    };
+                    let mut events = partition_receiver.stream_events();
+                    while let Some(event) = events.next().await {
+                        if sender.send((partition_id.clone(), event)).await.is_err() {
+                            // The reader has been stopped or restarted from another position
+                            break;
+                        }
+                    }
+                }));
+        }
+        receiver
+    }
+}
+
+impl Reader for EventHubsReader {
+    fn read(&mut self) -> Result<ReadResult, ReadError> {
+        if self.events.is_none() {
+            self.events = Some(self.start_partition_receivers());
+        }
+        let events = self.events.as_mut().expect("the receivers must be started");
+        let Some((partition_id, event)) = self.runtime.block_on(events.recv()) else {
+            return Ok(ReadResult::Finished);
+        };
+
+        let event = event.map_err(EventHubsError::from)?;
+        let sequence_number = event
+            .sequence_number()
+            .ok_or_else(|| EventHubsError::NoSequenceNumber(partition_id.clone()))?;
+        let offset = self.positions.advance(partition_id, sequence_number);
+
+        let key = event.partition_key().map(|key| key.as_bytes().to_vec());
+        let body = event
+            .event_data()
+            .body()
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+        let context = ReaderContext::from_key_value(key, Some(body));
+        Ok(ReadResult::Data(context, offset))
+    }
+
+    fn seek(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        self.stop_partition_receivers();
+        self.positions.seek(frontier);
+        Ok(())
+    }
+
+    fn storage_type(&self) -> StorageType {
+        StorageType::EventHubs
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        format!("EventHubs({})", self.eventhub_name).into()
+    }
+}
+
+impl Drop for EventHubsReader {
+    fn drop(&mut self) {
+        self.stop_partition_receivers();
+    }
+}
+
+#[derive(Default)]
+struct BufferedEvents {
+    events: Vec<EventData>,
+    lengths: Vec<usize>,
+}
+
+/// Sends the formatted entries to an Azure Event Hub over AMQP.
+///
+/// If the key field is given, its value is used as the partition key of the events, so
+/// that the events with the same key are sent to the same partition and are read in the
+/// order they were written. The events are buffered and sent in batches on flush.
+#[allow(clippy::module_name_repetitions)]
+pub struct EventHubsWriter {
+    runtime: TokioRuntime,
+    producer: ProducerClient,
+    eventhub_name: String,
+    key_field_index: Option<usize>,
+    header_fields: Vec<(String, usize)>,
+    buffered_events: HashMap<Option<String>, BufferedEvents>,
+}
+
+impl EventHubsWriter {
+    pub fn new(
+        runtime: TokioRuntime,
+        producer: ProducerClient,
+        eventhub_name: String,
+        key_field_index: Option<usize>,
+        header_fields: Vec<(String, usize)>,
+    ) -> Self {
+        Self {
+            runtime,
+            producer,
+            eventhub_name,
+            key_field_index,
+            header_fields,
+            buffered_events: HashMap::new(),
+        }
+    }
+
+    async fn send_events(
+        producer: &ProducerClient,
+        partition_key: Option<String>,
+        buffered_events: BufferedEvents,
+    ) -> Result<(), EventHubsError> {
+        let batch_options = || {
+            Some(EventDataBatchOptions {
+                partition_key: partition_key.clone(),
+                ..EventDataBatchOptions::default()
+            })
+        };
+        let mut batch = producer.create_batch(batch_options()).await?;
+        let mut batch_is_empty = true;
+        for (event, length) in buffered_events
+            .events
+            .into_iter()
+            .zip(buffered_events.lengths)
+        {
+            if batch.try_add_event_data(event.clone(), None)? {
+                batch_is_empty = false;
+                continue;
+            }
+            if batch_is_empty {
+                return Err(EventHubsError::EventTooLarge(length));
+            }
+            producer.send_batch(&batch, None).await?;
+            batch = producer.create_batch(batch_options()).await?;
+            if !batch.try_add_event_data(event, None)? {
+                return Err(EventHubsError::EventTooLarge(length));
+            }
+        }
+        if !batch_is_empty {
+            producer.send_batch(&batch, None).await?;
+        }
+        Ok(())
+    }
+}
+
+impl Writer for EventHubsWriter {
+    fn write(&mut self, data: FormatterContext) -> Result<(), WriteError> {
+        let partition_key = self.key_field_index.map(|index| match &data.values[index] {
+            Value::String(key) => key.to_string(),
+            Value::Bytes(key) => String::from_utf8_lossy(key).into_owned(),
+            key => key.to_string(),
+        });
+        let properties = data.construct_string_headers(&self.header_fields);
+        let buffered_events = self.buffered_events.entry(partition_key).or_default();
+
+        for payload in data.payloads {
+            let body = payload.into_raw_bytes()?;
+            let mut event = EventData::builder();
+            for (name, value) in &properties {
+                event = event.add_property(name.clone(), value.clone());
+            }
+            buffered_events.lengths.push(body.len());
+            buffered_events.events.push(event.with_body(body).build());
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self, _forced: bool) -> Result<(), WriteError> {
+        let buffered_events = take(&mut self.buffered_events);
+        self.runtime.block_on(async {
+            for (partition_key, buffered_events) in buffered_events {
+                Self::send_events(&self.producer, partition_key, buffered_events).await?;
+            }
+            Ok::<(), WriteError>(())
+        })
+    }
+
+    fn name(&self) -> String {
+        format!("EventHubs({})", self.eventhub_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partitions_are_read_after_the_last_events() {
+        let mut positions = PartitionPositions::default();
+        let first = ArcStr::from("0");
+        let second = ArcStr::from("1");
+        assert!(matches!(
+            positions.start_position(&first),
+            StartPosition {
+                location: StartLocation::Earliest,
+                ..
+            }
+        ));
+
+        let offset = positions.advance(first.clone(), 41);
+        assert_eq!(
+            offset,
+            (
+                OffsetKey::EventHubs(first.clone()),
+                OffsetValue::EventHubsSequenceNumber(41)
+            )
+        );
+        positions.advance(first.clone(), 42);
+        // the event with the sequence number of the last one read isn't read again
+        assert!(matches!(
+            positions.start_position(&first),
+            StartPosition {
+                location: StartLocation::SequenceNumber(42),
+                inclusive: false,
+            }
+        ));
+        assert!(matches!(
+            positions.start_position(&second),
+            StartPosition {
+                location: StartLocation::Earliest,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn seek_restores_the_persisted_positions() {
+        let mut positions = PartitionPositions::default();
+        let first = ArcStr::from("0");
+        let second = ArcStr::from("1");
+        let mut frontier = OffsetAntichain::new();
+        for (partition_id, sequence_number) in [(&first, 7), (&second, 3)] {
+            let (offset_key, offset_value) =
+                positions.advance(partition_id.clone(), sequence_number);
+            frontier.advance_offset(offset_key, offset_value);
+        }
+        // the offsets of the other connectors are skipped
+        frontier.advance_offset(
+            OffsetKey::Kafka("topic".into(), 0),
+            OffsetValue::KafkaOffset(5),
+        );
+
+        let mut restarted = PartitionPositions::default();
+        restarted.advance(ArcStr::from("2"), 10);
+        restarted.seek(&frontier);
+        assert!(matches!(
+            restarted.start_position(&first),
+            StartPosition {
+                location: StartLocation::SequenceNumber(7),
+                inclusive: false,
+            }
+        ));
+        assert!(matches!(
+            restarted.start_position(&second),
+            StartPosition {
+                location: StartLocation::SequenceNumber(3),
+                inclusive: false,
+            }
+        ));
+        // the positions from before the seek are forgotten
+        assert!(matches!(
+            restarted.start_position(&ArcStr::from("2")),
+            StartPosition {
+                location: StartLocation::Earliest,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn merged_frontier_keeps_the_later_events() {
+        let partition_id = ArcStr::from("0");
+        let frontier = |sequence_number| {
+            let mut frontier = OffsetAntichain::new();
+            frontier.advance_offset(
+                OffsetKey::EventHubs(partition_id.clone()),
+                OffsetValue::EventHubsSequenceNumber(sequence_number),
+            );
+            frontier
+        };
+        let merged = EventHubsReader::merge_two_frontiers(&frontier(5), &frontier(9));
+        assert_eq!(
+            merged.get_offset(&OffsetKey::EventHubs(partition_id.clone())),
+            Some(&OffsetValue::EventHubsSequenceNumber(9))
+        );
+    }
+}
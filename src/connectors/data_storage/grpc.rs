@@ -0,0 +1,493 @@
+// Copyright © 2026 Pathway
+
+use log::{info, warn};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use prost::Message;
+use prost_reflect::{
+    DescriptorError, DescriptorPool, DynamicMessage, MessageDescriptor, MethodDescriptor,
+    SerializeOptions,
+};
+use prost_types::FileDescriptorProto;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use tokio::runtime::Runtime as TokioRuntime;
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::metadata::MetadataMap;
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+use tonic::{Request, Status, Streaming};
+use tonic_reflection::pb::v1::server_reflection_client::ServerReflectionClient;
+use tonic_reflection::pb::v1::server_reflection_request::MessageRequest;
+use tonic_reflection::pb::v1::server_reflection_response::MessageResponse;
+use tonic_reflection::pb::v1::ServerReflectionRequest;
+
+use crate::connectors::data_storage::ReaderContext;
+use crate::connectors::{OffsetKey, OffsetValue, ReadError, ReadResult, Reader, StorageType};
+use crate::persistence::frontier::OffsetAntichain;
+
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum GrpcError {
+    #[error("failed to connect to the server: {0}")]
+    Transport(#[from] tonic::transport::Error),
+
+    #[error("the call failed: {0}")]
+    Status(Box<Status>),
+
+    #[error("failed to read the descriptor set: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("invalid protobuf descriptors: {0}")]
+    Descriptor(#[from] DescriptorError),
+
+    #[error("failed to decode a file descriptor: {0}")]
+    Decode(#[from] prost::DecodeError),
+
+    #[error("failed to convert a message to or from JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("the server reflection failed: {0}")]
+    Reflection(String),
+
+    #[error("the server reflection returned no file descriptors")]
+    EmptyReflectionResponse,
+
+    #[error("the files {0:?} depend on files missing from the descriptors")]
+    MissingDependencies(Vec<String>),
+
+    #[error("method name {0:?} must have the form 'package.Service/Method'")]
+    InvalidMethodName(String),
+
+    #[error("service {0:?} is not present in the descriptors")]
+    UnknownService(String),
+
+    #[error("service {service:?} has no method {method:?}")]
+    UnknownMethod { service: String, method: String },
+
+    #[error("method {0:?} is not a server-streaming method")]
+    NotServerStreaming(String),
+
+    #[error("message {message:?} has no field {field:?}")]
+    UnknownField { message: String, field: String },
+
+    #[error("the request must be a JSON object, got {0}")]
+    RequestNotAnObject(String),
+}
+
+impl From<Status> for GrpcError {
+    fn from(status: Status) -> Self {
+        GrpcError::Status(Box::new(status))
+    }
+}
+
+/// Loads the descriptors of the services from an encoded `FileDescriptorSet`, as written
+/// by `protoc --descriptor_set_out --include_imports`.
+pub fn descriptor_pool_from_file(path: &str) -> Result<DescriptorPool, GrpcError> {
+    let bytes = std::fs::read(path)?;
+    Ok(DescriptorPool::decode(bytes.as_slice())?)
+}
+
+/// Builds a descriptor pool from the given files, adding each file after the files it
+/// depends on, since the files returned by the server reflection come in no particular
+/// order.
+pub fn descriptor_pool_from_files(
+    files: Vec<FileDescriptorProto>,
+) -> Result<DescriptorPool, GrpcError> {
+    let mut pool = DescriptorPool::new();
+    let mut pending_files: HashMap<String, FileDescriptorProto> = files
+        .into_iter()
+        .map(|file| (file.name().to_string(), file))
+        .collect();
+    while !pending_files.is_empty() {
+        let ready_files: Vec<String> = pending_files
+            .iter()
+            .filter(|(_, file)| {
+                file.dependency
+                    .iter()
+                    .all(|dependency| pool.get_file_by_name(dependency).is_some())
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        if ready_files.is_empty() {
+            let mut missing: Vec<_> = pending_files.into_keys().collect();
+            missing.sort();
+            return Err(GrpcError::MissingDependencies(missing));
+        }
+        for name in ready_files {
+            let file = pending_files
+                .remove(&name)
+                .expect("the file must be pending");
+            if pool.get_file_by_name(&name).is_none() {
+                pool.add_file_descriptor_proto(file)?;
+            }
+        }
+    }
+    Ok(pool)
+}
+
+async fn request_reflection(
+    channel: Channel,
+    request: MessageRequest,
+) -> Result<Vec<FileDescriptorProto>, GrpcError> {
+    let mut client = ServerReflectionClient::new(channel);
+    let request = ServerReflectionRequest {
+        host: String::new(),
+        message_request: Some(request),
+    };
+    let mut responses = client
+        .server_reflection_info(futures::stream::iter([request]))
+        .await?
+        .into_inner();
+    let response = responses
+        .message()
+        .await?
+        .ok_or(GrpcError::EmptyReflectionResponse)?;
+    match response.message_response {
+        Some(MessageResponse::FileDescriptorResponse(response)) => response
+            .file_descriptor_proto
+            .iter()
+            .map(|file| Ok(FileDescriptorProto::decode(file.as_slice())?))
+            .collect(),
+        Some(MessageResponse::ErrorResponse(error)) => {
+            Err(GrpcError::Reflection(error.error_message))
+        }
+        _ => Err(GrpcError::EmptyReflectionResponse),
+    }
+}
+
+/// Fetches the descriptors of a service and of all the files it depends on with the
+/// `grpc.reflection.v1` server reflection.
+pub async fn descriptor_pool_from_reflection(
+    channel: Channel,
+    service_name: &str,
+) -> Result<DescriptorPool, GrpcError> {
+    let mut files: HashMap<String, FileDescriptorProto> = HashMap::new();
+    let mut requested_files = HashSet::new();
+    let mut requests = vec![MessageRequest::FileContainingSymbol(
+        service_name.to_string(),
+    )];
+    while let Some(request) = requests.pop() {
+        for file in request_reflection(channel.clone(), request).await? {
+            files.insert(file.name().to_string(), file);
+        }
+        for file in files.values() {
+            for dependency in &file.dependency {
+                if !files.contains_key(dependency) && requested_files.insert(dependency.clone()) {
+                    requests.push(MessageRequest::FileByFilename(dependency.clone()));
+                }
+            }
+        }
+    }
+    descriptor_pool_from_files(files.into_values().collect())
+}
+
+fn split_method_name(method_name: &str) -> Result<(&str, &str), GrpcError> {
+    method_name
+        .trim_start_matches('/')
+        .split_once('/')
+        .filter(|(service, method)| !service.is_empty() && !method.is_empty())
+        .ok_or_else(|| GrpcError::InvalidMethodName(method_name.to_string()))
+}
+
+/// Finds a server-streaming method by its full name, `package.Service/Method`.
+pub fn find_server_streaming_method(
+    pool: &DescriptorPool,
+    method_name: &str,
+) -> Result<MethodDescriptor, GrpcError> {
+    let (service_name, method_short_name) = split_method_name(method_name)?;
+    let service = pool
+        .get_service_by_name(service_name)
+        .ok_or_else(|| GrpcError::UnknownService(service_name.to_string()))?;
+    let method = service
+        .methods()
+        .find(|method| method.name() == method_short_name)
+        .ok_or_else(|| GrpcError::UnknownMethod {
+            service: service_name.to_string(),
+            method: method_short_name.to_string(),
+        })?;
+    if !method.is_server_streaming() || method.is_client_streaming() {
+        return Err(GrpcError::NotServerStreaming(method_name.to_string()));
+    }
+    Ok(method)
+}
+
+fn check_field(message: &MessageDescriptor, field: &str) -> Result<(), GrpcError> {
+    if message.get_field_by_name(field).is_none() {
+        return Err(GrpcError::UnknownField {
+            message: message.full_name().to_string(),
+            field: field.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Encodes and decodes the messages of a method known only at runtime.
+#[derive(Clone)]
+struct DynamicCodec {
+    response_descriptor: MessageDescriptor,
+}
+
+struct DynamicEncoder;
+
+struct DynamicDecoder {
+    response_descriptor: MessageDescriptor,
+}
+
+impl Codec for DynamicCodec {
+    type Encode = DynamicMessage;
+    type Decode = DynamicMessage;
+    type Encoder = DynamicEncoder;
+    type Decoder = DynamicDecoder;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        DynamicEncoder
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        DynamicDecoder {
+            response_descriptor: self.response_descriptor.clone(),
+        }
+    }
+}
+
+impl Encoder for DynamicEncoder {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        item.encode(dst)
+            .map_err(|e| Status::internal(format!("failed to encode the request: {e}")))
+    }
+}
+
+impl Decoder for DynamicDecoder {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        let message = DynamicMessage::decode(self.response_descriptor.clone(), src)
+            .map_err(|e| Status::internal(format!("failed to decode the response: {e}")))?;
+        Ok(Some(message))
+    }
+}
+
+/// The fields bookmarking the position of the reader in the stream: the value of
+/// `offset_field` of the last message read is sent in `resume_field` of the request
+/// when the call is opened again.
+#[derive(Clone, Debug)]
+pub struct GrpcBookmarkFields {
+    pub offset_field: String,
+    pub resume_field: String,
+}
+
+/// Converts a response message to the JSON object parsed into a row. The fields are
+/// named as in the `.proto` file, the fields with the default values are kept, so that
+/// each message has all the columns, and the 64-bit integers are kept as numbers.
+pub fn message_to_json(message: &DynamicMessage) -> Result<JsonValue, GrpcError> {
+    let options = SerializeOptions::new()
+        .use_proto_field_name(true)
+        .skip_default_fields(false)
+        .stringify_64_bit_integers(false);
+    Ok(message.serialize_with_options(serde_json::value::Serializer, &options)?)
+}
+
+/// Reads the messages of a server-streaming gRPC call.
+///
+/// The method is resolved at runtime, from a descriptor set file or with the server
+/// reflection, and the request is given as its JSON representation. Each response
+/// message becomes an entry in JSON, parsed into a row.
+///
+/// If the call fails, for example because the server restarts, it's opened again on
+/// the next read. When the bookmark fields are given, the value of `offset_field` of the
+/// last message read is then sent in `resume_field` of the request, so that the server
+/// continues the stream after it. The value is also stored in the frontier, to resume
+/// the stream after a restart. Without the bookmark fields, the call is opened again
+/// with the original request.
+#[allow(clippy::module_name_repetitions)]
+pub struct GrpcReader {
+    runtime: TokioRuntime,
+    channel: Channel,
+    method: MethodDescriptor,
+    path: PathAndQuery,
+    request: JsonMap<String, JsonValue>,
+    metadata: MetadataMap,
+    bookmark_fields: Option<GrpcBookmarkFields>,
+    last_bookmark: Option<JsonValue>,
+    entries_read: usize,
+    stream: Option<Streaming<DynamicMessage>>,
+}
+
+impl GrpcReader {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        runtime: TokioRuntime,
+        url: &str,
+        method_name: &str,
+        descriptor_set_path: Option<&str>,
+        request: &str,
+        metadata: MetadataMap,
+        bookmark_fields: Option<GrpcBookmarkFields>,
+    ) -> Result<Self, GrpcError> {
+        let mut endpoint = Endpoint::from_shared(url.to_string())?;
+        if url.starts_with("https://") {
+            endpoint = endpoint.tls_config(ClientTlsConfig::new().with_enabled_roots())?;
+        }
+        let (channel, pool) = runtime.block_on(async {
+            let channel = endpoint.connect().await?;
+            let pool = match descriptor_set_path {
+                Some(path) => descriptor_pool_from_file(path)?,
+                None => {
+                    let (service_name, _) = split_method_name(method_name)?;
+                    descriptor_pool_from_reflection(channel.clone(), service_name).await?
+                }
+            };
+            Ok::<_, GrpcError>((channel, pool))
+        })?;
+
+        let method = find_server_streaming_method(&pool, method_name)?;
+        if let Some(bookmark_fields) = &bookmark_fields {
+            check_field(&method.output(), &bookmark_fields.offset_field)?;
+            check_field(&method.input(), &bookmark_fields.resume_field)?;
+        }
+        let request = match serde_json::from_str(request)? {
+            JsonValue::Object(request) => request,
+            other => return Err(GrpcError::RequestNotAnObject(other.to_string())),
+        };
+        let path = format!("/{}/{}", method.parent_service().full_name(), method.name())
+            .parse()
+            .expect("the path of a method must be valid");
+
+        let reader = Self {
+            runtime,
+            channel,
+            method,
+            path,
+            request,
+            metadata,
+            bookmark_fields,
+            last_bookmark: None,
+            entries_read: 0,
+            stream: None,
+        };
+        // Fail early if the request doesn't match the input message
+        reader.request_message()?;
+        Ok(reader)
+    }
+
+    fn request_message(&self) -> Result<DynamicMessage, GrpcError> {
+        let mut request = self.request.clone();
+        if let (Some(bookmark_fields), Some(bookmark)) =
+            (&self.bookmark_fields, &self.last_bookmark)
+        {
+            request.insert(bookmark_fields.resume_field.clone(), bookmark.clone());
+        }
+        Ok(DynamicMessage::deserialize(
+            self.method.input(),
+            JsonValue::Object(request),
+        )?)
+    }
+
+    fn open_stream(&self) -> Result<Streaming<DynamicMessage>, GrpcError> {
+        let mut request = Request::new(self.request_message()?);
+        *request.metadata_mut() = self.metadata.clone();
+        let codec = DynamicCodec {
+            response_descriptor: self.method.output(),
+        };
+        let mut client = tonic::client::Grpc::new(self.channel.clone());
+        let stream = self.runtime.block_on(async {
+            client
+                .ready()
+                .await
+                .map_err(|e| Status::unavailable(format!("the service is not ready: {e}")))?;
+            client
+                .server_streaming(request, self.path.clone(), codec)
+                .await
+        })?;
+        info!(
+            "Opened the call {} with the bookmark {:?}",
+            self.path, self.last_bookmark
+        );
+        Ok(stream.into_inner())
+    }
+
+    fn offset_value(&self) -> OffsetValue {
+        OffsetValue::GrpcBookmark {
+            bookmark: self.last_bookmark.as_ref().map(ToString::to_string),
+            entries_read: self.entries_read,
+        }
+    }
+}
+
+impl Reader for GrpcReader {
+    fn read(&mut self) -> Result<ReadResult, ReadError> {
+        if self.stream.is_none() {
+            self.stream = Some(self.open_stream()?);
+        }
+        let stream = self.stream.as_mut().expect("the call must be opened");
+        let message = match self.runtime.block_on(stream.message()) {
+            Ok(Some(message)) => message,
+            Ok(None) => {
+                info!("The server finished the call {}", self.path);
+                return Ok(ReadResult::Finished);
+            }
+            Err(status) => {
+                // The call is opened again on the next read
+                self.stream = None;
+                return Err(GrpcError::from(status).into());
+            }
+        };
+
+        let values = message_to_json(&message)?;
+        if let Some(bookmark_fields) = &self.bookmark_fields {
+            self.last_bookmark = values.get(&bookmark_fields.offset_field).cloned();
+        }
+        self.entries_read += 1;
+        let payload = serde_json::to_vec(&values).map_err(GrpcError::from)?;
+        let context = ReaderContext::from_key_value(None, Some(payload));
+        Ok(ReadResult::Data(
+            context,
+            (OffsetKey::Grpc, self.offset_value()),
+        ))
+    }
+
+    fn seek(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        let Some(offset) = frontier.get_offset(&OffsetKey::Grpc) else {
+            return Ok(());
+        };
+        let OffsetValue::GrpcBookmark {
+            bookmark,
+            entries_read,
+        } = offset
+        else {
+            warn!("Unexpected offset type for the gRPC reader: {offset:?}");
+            return Ok(());
+        };
+        if self.bookmark_fields.is_none() {
+            warn!(
+                "The gRPC reader has no bookmark fields, so the call {} is read from the start",
+                self.path
+            );
+        }
+        self.last_bookmark = bookmark
+            .as_deref()
+            .map(serde_json::from_str::<JsonValue>)
+            .transpose()
+            .map_err(GrpcError::from)?;
+        self.entries_read = *entries_read;
+        self.stream = None;
+        Ok(())
+    }
+
+    fn storage_type(&self) -> StorageType {
+        StorageType::Grpc
+    }
+
+    fn max_allowed_consecutive_errors(&self) -> usize {
+        32
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        format!("Grpc({})", self.path).into()
+    }
+}
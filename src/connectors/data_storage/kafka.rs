@@ -84,8 +84,63 @@ pub enum KafkaReaderError {
 
     #[error("Subscription to Kafka topic failed: {0}")]
     Subscribe(KafkaError),
+
+    #[error("topic pattern '{0}' can only be read in the streaming mode")]
+    TopicPatternInStaticMode(String),
+
+    #[error("topic pattern '{0}' can't be combined with a starting position")]
+    TopicPatternWithStartPosition(String),
+
+    #[error("the starting position is given for the partition {partition}, but the topic '{topic}' has {total_partitions} partitions")]
+    StartPositionForUnknownPartition {
+        topic: String,
+        partition: i32,
+        total_partitions: usize,
+    },
+
+    #[error("topic pattern '{0}' can't be read with the partitions aligned to the workers")]
+    PartitionAlignedTopicPattern(String),
+}
+
+/// Where the reading of a partition without persisted offsets starts, regardless of
+/// the offsets committed by the consumer group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KafkaStartPosition {
+    /// The earliest message of the partition.
+    Beginning,
+
+    /// The messages produced after the reader starts.
+    End,
+
+    /// The earliest message with a timestamp at or after the given Unix timestamp in
+    /// milliseconds.
+    Timestamp(i64),
+}
+
+/// The starting positions of the partitions of a topic. The positions given for the
+/// individual partitions take precedence over the default one, and the partitions
+/// without any are read from the position of the consumer group.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KafkaStartPositions {
+    pub default: Option<KafkaStartPosition>,
+    pub partitions: HashMap<i32, KafkaStartPosition>,
 }
 
+impl KafkaStartPositions {
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.partitions.is_empty()
+    }
+
+    pub fn for_partition(&self, partition: i32) -> Option<KafkaStartPosition> {
+        self.partitions.get(&partition).copied().or(self.default)
+    }
+}
+
+/// The prefix marking a topic name as a regular expression, as in librdkafka:
+/// the consumer is then subscribed to all the matching topics, including the
+/// ones created while it runs.
+pub const KAFKA_TOPIC_PATTERN_PREFIX: char = '^';
+
 /// How long the start-up metadata probes keep retrying transient errors before
 /// giving up.
 const METADATA_PROBE_RETRY_TIMEOUT: Duration = Duration::from_secs(30);
@@ -172,22 +227,17 @@ fn partition_watermarks(
     Ok(next_used_offset_per_partition)
 }
 
-fn seek_positions_for_timestamp(
+fn seek_positions_for_timestamps(
     consumer: &BaseConsumer<DefaultConsumerContext>,
     topic: &str,
-    total_partitions: usize,
-    start_from_timestamp_ms: i64,
+    timestamps: &[(i32, i64)],
     watermarks: &[RdkafkaWatermark],
 ) -> Result<HashMap<i32, KafkaOffset>, KafkaReaderError> {
     let mut seek_positions = HashMap::new();
     let mut tpl = TopicPartitionList::new();
-    for partition_idx in 0..total_partitions {
-        tpl.add_partition_offset(
-            topic,
-            partition_idx.try_into().unwrap(),
-            KafkaOffset::Offset(start_from_timestamp_ms),
-        )
-        .expect("Failed to add partition offset");
+    for &(partition, timestamp_ms) in timestamps {
+        tpl.add_partition_offset(topic, partition, KafkaOffset::Offset(timestamp_ms))
+            .expect("Failed to add partition offset");
     }
 
     let offsets = consumer
@@ -204,7 +254,7 @@ fn seek_positions_for_timestamp(
             }
             KafkaOffset::End => {
                 let partition_idx: usize = element.partition().try_into().unwrap();
-                warn!("Partition {partition_idx} has no message with a timestamp >= {start_from_timestamp_ms}: the requested start is at or past the end of the partition (offset {}), so none of its already-written data will be read. In static mode this partition yields no rows; in streaming mode only messages produced after the start will be read.", watermarks[partition_idx].high);
+                warn!("Partition {partition_idx} has no message at or after the requested timestamp: the requested start is at or past the end of the partition (offset {}), so none of its already-written data will be read. In static mode this partition yields no rows; in streaming mode only messages produced after the start will be read.", watermarks[partition_idx].high);
                 KafkaOffset::Offset(watermarks[partition_idx].high)
             }
             offset => offset,
@@ -219,10 +269,72 @@ fn seek_positions_for_timestamp(
     Ok(seek_positions)
 }
 
+// The offsets the partitions with a starting position are read from. The beginning and
+// the end are resolved with the watermarks, and the timestamps with the broker.
+fn seek_positions_for_start_positions(
+    consumer: &BaseConsumer<DefaultConsumerContext>,
+    topic: &str,
+    start_positions: &KafkaStartPositions,
+    watermarks: &[RdkafkaWatermark],
+) -> Result<HashMap<i32, KafkaOffset>, KafkaReaderError> {
+    if let Some(&partition) = start_positions
+        .partitions
+        .keys()
+        .find(|partition| usize::try_from(**partition).map_or(true, |p| p >= watermarks.len()))
+    {
+        return Err(KafkaReaderError::StartPositionForUnknownPartition {
+            topic: topic.to_string(),
+            partition,
+            total_partitions: watermarks.len(),
+        });
+    }
+
+    let mut seek_positions = HashMap::new();
+    let mut timestamps = Vec::new();
+    for (partition_idx, watermark) in watermarks.iter().enumerate() {
+        let partition: i32 = partition_idx
+            .try_into()
+            .expect("kafka partition must fit 32-bit signed integer");
+        match start_positions.for_partition(partition) {
+            None => {}
+            Some(KafkaStartPosition::Beginning) => {
+                seek_positions.insert(partition, KafkaOffset::Offset(watermark.low));
+            }
+            Some(KafkaStartPosition::End) => {
+                seek_positions.insert(partition, KafkaOffset::Offset(watermark.high));
+            }
+            Some(KafkaStartPosition::Timestamp(timestamp_ms)) => {
+                timestamps.push((partition, timestamp_ms));
+            }
+        }
+    }
+
+    if !timestamps.is_empty() {
+        let current_timestamp = current_unix_timestamp_ms();
+        let latest_timestamp_ms = timestamps
+            .iter()
+            .map(|(_, timestamp_ms)| *timestamp_ms)
+            .max();
+        if let Some(latest_timestamp_ms) = latest_timestamp_ms {
+            if latest_timestamp_ms > current_timestamp.try_into().unwrap() {
+                warn!("The timestamp {latest_timestamp_ms} is greater than the current timestamp {current_timestamp}. All new entries will be read.");
+            }
+        }
+        seek_positions.extend(seek_positions_for_timestamps(
+            consumer,
+            topic,
+            &timestamps,
+            watermarks,
+        )?);
+    }
+    Ok(seek_positions)
+}
+
 pub struct KafkaReader {
     consumer: BaseConsumer<DefaultConsumerContext>,
+    // The topic name, or the topic pattern if it starts with `KAFKA_TOPIC_PATTERN_PREFIX`
     topic: ArcStr,
-    positions_for_seek: HashMap<i32, KafkaOffset>,
+    positions_for_seek: HashMap<(ArcStr, i32), KafkaOffset>,
     watermarks: Vec<RdkafkaWatermark>,
     deferred_read_result: Option<ReadResult>,
     mode: ConnectorMode,
@@ -233,6 +345,7 @@ pub struct KafkaReader {
     // are read back from `consumer.position()` when needed, so only the "owns
     // something" bit is kept here.
     has_assigned_partitions: bool,
+    partition_aligned: bool,
 }
 
 impl Reader for KafkaReader {
@@ -257,9 +370,14 @@ impl Reader for KafkaReader {
             };
             let message_key = kafka_message.key().map(<[u8]>::to_vec);
             let message_payload = kafka_message.payload().map(<[u8]>::to_vec);
+            let topic = if self.is_topic_pattern() {
+                ArcStr::from(kafka_message.topic())
+            } else {
+                self.topic.clone()
+            };
+            let topic_partition = (topic, kafka_message.partition());
 
-            if let Some(lazy_seek_offset) = self.positions_for_seek.get(&kafka_message.partition())
-            {
+            if let Some(lazy_seek_offset) = self.positions_for_seek.get(&topic_partition) {
                 info!(
                     "Performing Kafka topic seek for ({}, {}) to {:?}",
                     kafka_message.topic(),
@@ -280,13 +398,14 @@ impl Reader for KafkaReader {
                         lazy_seek_offset,
                     );
                 } else {
-                    self.positions_for_seek.remove(&kafka_message.partition());
+                    self.positions_for_seek.remove(&topic_partition);
                 }
                 continue;
             }
 
             let offset = {
-                let offset_key = OffsetKey::Kafka(self.topic.clone(), kafka_message.partition());
+                let (topic, partition) = topic_partition;
+                let offset_key = OffsetKey::Kafka(topic, partition);
                 let offset_value = OffsetValue::KafkaOffset(kafka_message.offset());
                 (offset_key, offset_value)
             };
@@ -306,7 +425,8 @@ impl Reader for KafkaReader {
                 continue;
             };
             if let OffsetKey::Kafka(topic, partition) = offset_key {
-                if self.topic != *topic {
+                // With a pattern, the frontier holds the offsets of all the topics read
+                if !self.is_topic_pattern() && self.topic != *topic {
                     warn!(
                         "Unexpected topic name. Expected: {}, Got: {topic}",
                         self.topic
@@ -322,8 +442,10 @@ impl Reader for KafkaReader {
                     to be done on behalf of rdkafka client, taking account of other
                     members in its' consumer group.
                 */
-                self.positions_for_seek
-                    .insert(*partition, KafkaOffset::Offset(*position + 1));
+                self.positions_for_seek.insert(
+                    (topic.clone(), *partition),
+                    KafkaOffset::Offset(*position + 1),
+                );
             } else {
                 error!("Unexpected offset in Kafka frontier: ({offset_key:?}, {offset_value:?})");
             }
@@ -332,6 +454,10 @@ impl Reader for KafkaReader {
         Ok(())
     }
 
+    fn start_from(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        self.seek(frontier)
+    }
+
     fn short_description(&self) -> Cow<'static, str> {
         format!("Kafka({})", self.topic).into()
     }
@@ -340,6 +466,10 @@ impl Reader for KafkaReader {
         StorageType::Kafka
     }
 
+    fn is_partition_aligned(&self) -> bool {
+        self.partition_aligned
+    }
+
     fn max_allowed_consecutive_errors(&self) -> usize {
         32
     }
@@ -349,10 +479,11 @@ impl KafkaReader {
     fn new(
         consumer: BaseConsumer<DefaultConsumerContext>,
         topic: String,
-        positions_for_seek: HashMap<i32, KafkaOffset>,
+        positions_for_seek: HashMap<(ArcStr, i32), KafkaOffset>,
         watermarks: Vec<RdkafkaWatermark>,
         mode: ConnectorMode,
         has_assigned_partitions: bool,
+        partition_aligned: bool,
     ) -> KafkaReader {
         KafkaReader {
             consumer,
@@ -361,6 +492,7 @@ impl KafkaReader {
             watermarks,
             mode,
             has_assigned_partitions,
+            partition_aligned,
             deferred_read_result: None,
         }
     }
@@ -372,28 +504,45 @@ impl KafkaReader {
     /// readers: `worker_index` is this worker's index and `reader_count` is how
     /// many workers actually run a reader (the caller computes it — see
     /// `construct_kafka_reader`).
+    ///
+    /// The `start_positions` only apply to the partitions without persisted offsets.
+    ///
+    /// If `topic` starts with [`KAFKA_TOPIC_PATTERN_PREFIX`], it's a regular
+    /// expression and the reader subscribes to all the matching topics. The
+    /// topics created later are picked up when the consumer refreshes the
+    /// metadata of the cluster, which is controlled by the
+    /// `topic.metadata.refresh.interval.ms` setting. Such a reader can only
+    /// work in the streaming mode.
+    ///
+    /// If `partition_aligned` is set, the partitions are assigned to the readers
+    /// in the streaming mode the same way as in the static one, instead of being
+    /// balanced by the consumer group, so that the rows can stay on the worker
+    /// reading them (see `Reader::is_partition_aligned`). The partitions added to
+    /// the topic later aren't read then.
     pub fn build(
         consumer: BaseConsumer<DefaultConsumerContext>,
         topic: String,
         mode: ConnectorMode,
-        start_from_timestamp_ms: Option<i64>,
+        start_positions: &KafkaStartPositions,
         worker_index: usize,
         reader_count: usize,
+        partition_aligned: bool,
     ) -> Result<KafkaReader, KafkaReaderError> {
+        if topic.starts_with(KAFKA_TOPIC_PATTERN_PREFIX) {
+            if partition_aligned {
+                return Err(KafkaReaderError::PartitionAlignedTopicPattern(topic));
+            }
+            return Self::build_for_topic_pattern(consumer, topic, mode, start_positions);
+        }
         let total_partitions = total_partitions_for_topic(&consumer, &topic)?;
         let mut watermarks = partition_watermarks(&consumer, &topic, total_partitions)?;
 
         let mut seek_positions = HashMap::new();
-        if let Some(start_from_timestamp_ms) = start_from_timestamp_ms {
-            let current_timestamp = current_unix_timestamp_ms();
-            if start_from_timestamp_ms > current_timestamp.try_into().unwrap() {
-                warn!("The timestamp {start_from_timestamp_ms} is greater than the current timestamp {current_timestamp}. All new entries will be read.");
-            }
-            seek_positions = seek_positions_for_timestamp(
+        if !start_positions.is_empty() {
+            seek_positions = seek_positions_for_start_positions(
                 &consumer,
                 &topic,
-                total_partitions,
-                start_from_timestamp_ms,
+                start_positions,
                 &watermarks,
             )?;
             // The lazy seek only fires once the consumer actually receives a
@@ -430,6 +579,20 @@ impl KafkaReader {
         //     each partition's lower boundary. This talks straight to the partition
         //     leaders, with no coordinator dependency.
         let has_assigned_partitions = match mode {
+            ConnectorMode::Streaming if partition_aligned => {
+                // Unlike in the static mode, the reading starts from the committed
+                // offsets of the consumer group, and the seek, if any, stays lazy
+                let mut tpl = TopicPartitionList::new();
+                for partition_idx in (worker_index..total_partitions).step_by(reader_count) {
+                    let partition: i32 = partition_idx
+                        .try_into()
+                        .expect("kafka partition must fit 32-bit signed integer");
+                    tpl.add_partition_offset(topic.as_str(), partition, KafkaOffset::Stored)
+                        .expect("adding a partition to the assignment list must not fail");
+                }
+                consumer.assign(&tpl).map_err(KafkaReaderError::Assign)?;
+                tpl.count() > 0
+            }
             ConnectorMode::Static => {
                 // Static reads don't use a consumer group to spread partitions
                 // across workers, so we shard them by hand: reader `worker_index`
@@ -467,6 +630,11 @@ impl KafkaReader {
             }
         };
 
+        let topic_name = ArcStr::from(topic.as_str());
+        let seek_positions = seek_positions
+            .into_iter()
+            .map(|(partition, offset)| ((topic_name.clone(), partition), offset))
+            .collect();
         Ok(KafkaReader::new(
             consumer,
             topic,
@@ -474,9 +642,44 @@ impl KafkaReader {
             watermarks,
             mode,
             has_assigned_partitions,
+            partition_aligned,
+        ))
+    }
+
+    fn build_for_topic_pattern(
+        consumer: BaseConsumer<DefaultConsumerContext>,
+        topic_pattern: String,
+        mode: ConnectorMode,
+        start_positions: &KafkaStartPositions,
+    ) -> Result<KafkaReader, KafkaReaderError> {
+        // The set of topics isn't known in advance, so neither are the partitions
+        // whose boundaries a static read or a timestamp seek would need
+        if mode == ConnectorMode::Static {
+            return Err(KafkaReaderError::TopicPatternInStaticMode(topic_pattern));
+        }
+        if !start_positions.is_empty() {
+            return Err(KafkaReaderError::TopicPatternWithStartPosition(
+                topic_pattern,
+            ));
+        }
+        consumer
+            .subscribe(&[topic_pattern.as_str()])
+            .map_err(KafkaReaderError::Subscribe)?;
+        Ok(KafkaReader::new(
+            consumer,
+            topic_pattern,
+            HashMap::new(),
+            Vec::new(),
+            mode,
+            false,
+            false,
         ))
     }
 
+    fn is_topic_pattern(&self) -> bool {
+        self.topic.starts_with(KAFKA_TOPIC_PATTERN_PREFIX)
+    }
+
     fn poll_duration_for_static_mode() -> Duration {
         Duration::from_millis(500)
     }
@@ -580,11 +783,16 @@ impl KafkaReader {
     }
 }
 
+/// The time the transactional operations of [`KafkaWriter`] are given to complete.
+const TRANSACTION_TIMEOUT: Duration = Duration::from_secs(60);
+
 pub struct KafkaWriter {
     producer: ThreadedProducer<DefaultProducerContext>,
     topic: MessageQueueTopic,
     header_fields: Vec<(String, usize)>,
     key_field_index: Option<usize>,
+    transactional: bool,
+    in_transaction: bool,
 }
 
 impl KafkaWriter {
@@ -599,12 +807,30 @@ impl KafkaWriter {
             topic,
             header_fields,
             key_field_index,
+            transactional: false,
+            in_transaction: false,
         }
     }
+
+    /// Makes the writer send the messages in transactions, which requires the producer to
+    /// have a `transactional.id`. The messages of a transaction are shown to the consumers
+    /// reading only the committed messages once it is committed.
+    pub fn with_transactions(mut self) -> Result<Self, KafkaError> {
+        self.producer.init_transactions(TRANSACTION_TIMEOUT)?;
+        self.transactional = true;
+        Ok(self)
+    }
 }
 
 impl Drop for KafkaWriter {
     fn drop(&mut self) {
+        if self.in_transaction {
+            // the messages of the transaction are never shown to the consumers
+            if let Err(e) = self.producer.abort_transaction(TRANSACTION_TIMEOUT) {
+                error!("Failed to abort the Kafka transaction: {e}");
+            }
+            return;
+        }
         self.producer.flush(None).expect("kafka commit should work");
     }
 }
@@ -624,6 +850,10 @@ impl Writer for KafkaWriter {
             None => data.key.0.to_le_bytes().to_vec(),
         };
 
+        if self.transactional && !self.in_transaction {
+            self.producer.begin_transaction()?;
+            self.in_transaction = true;
+        }
         let headers = data.construct_kafka_headers(&self.header_fields);
         for payload in data.payloads {
             let payload = payload.into_raw_bytes()?;
@@ -649,6 +879,34 @@ impl Writer for KafkaWriter {
         Ok(())
     }
 
+    fn flush(&mut self, _forced: bool) -> Result<(), WriteError> {
+        // outside of a commit group, each flush commits a transaction of its own
+        if self.transactional {
+            self.prepare()?;
+            self.commit()?;
+        }
+        Ok(())
+    }
+
+    fn supports_two_phase_commit(&self) -> bool {
+        self.transactional
+    }
+
+    fn prepare(&mut self) -> Result<(), WriteError> {
+        // the messages are stored in the topics, but the consumers reading only the
+        // committed messages don't see them until the transaction is committed
+        self.producer.flush(TRANSACTION_TIMEOUT)?;
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<(), WriteError> {
+        if self.in_transaction {
+            self.producer.commit_transaction(TRANSACTION_TIMEOUT)?;
+            self.in_transaction = false;
+        }
+        Ok(())
+    }
+
     fn name(&self) -> String {
         format!("Kafka({})", self.topic)
     }
@@ -1,12 +1,15 @@
 // Copyright © 2026 Pathway
 
 pub mod aws;
+pub mod cassandra;
 pub mod chroma;
 pub mod clickhouse;
 pub mod data_lake;
 pub mod duckdb;
 pub mod elasticsearch;
+pub mod eventhubs;
 pub mod file;
+pub mod grpc;
 pub mod kafka;
 pub mod mongodb;
 pub mod mqtt;
@@ -17,42 +20,55 @@ pub mod null;
 pub mod pinecone;
 pub mod polling;
 pub mod postgres;
+pub mod pubsub;
+#[cfg(feature = "python")]
 pub mod python;
 pub mod qdrant;
 pub mod questdb;
 pub mod rabbitmq;
+pub mod redis;
 pub mod scanner;
 pub mod sharding;
+pub mod sql_polling;
 pub mod sqlite;
+pub mod sse;
 pub mod vectors;
 pub mod weaviate;
+pub mod webhook;
 
 pub use file::FileWriter;
-pub use kafka::{KafkaReader, KafkaReaderError, KafkaWriter, RdkafkaWatermark};
+pub use kafka::{
+    KafkaReader, KafkaReaderError, KafkaStartPosition, KafkaStartPositions, KafkaWriter,
+    RdkafkaWatermark,
+};
 pub use mqtt::{MqttReader, MqttWriter, MQTT_CLIENT_MAX_CHANNEL_SIZE, MQTT_MAX_MESSAGES_IN_QUEUE};
 pub use null::NullWriter;
+#[cfg(feature = "python")]
 pub use python::{PythonReader, PythonReaderBuilder};
 pub use qdrant::QdrantWriter;
 pub use questdb::{QuestDBAtColumnPolicy, QuestDBWriter};
 
+pub use self::cassandra::{CassandraError, CassandraWriter};
 pub use self::chroma::{ChromaError, ChromaWriter};
 pub use self::clickhouse::{ClickHouseError, ClickHouseWriter};
 
 use s3::error::S3Error;
 use std::any::type_name;
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fmt::{Debug, Display};
 use std::io;
 use std::io::BufRead;
 use std::str::Utf8Error;
+use std::sync::{Arc, Mutex};
 
 use ::mongodb::bson::Document as BsonDocument;
 use ::questdb::Error as QuestDBError;
 use async_nats::jetstream::consumer::pull::MessagesErrorKind;
 use async_nats::jetstream::consumer::StreamErrorKind;
 use aws_sdk_dynamodb::error::BuildError as AwsBuildError;
+use azure_storage::Error as AzureStorageError;
 use deltalake::arrow::datatypes::DataType as ArrowDataType;
 use deltalake::arrow::error::ArrowError;
 use deltalake::datafusion::common::DataFusionError;
@@ -60,24 +76,31 @@ use deltalake::datafusion::parquet::record::Field as ParquetValue;
 use deltalake::parquet::errors::ParquetError;
 use itertools::Itertools;
 use log::error;
+use orc_rust::error::OrcError;
 use rumqttc::{ClientError as MqttClientError, ConnectionError as MqttConnectionError};
 
-use crate::connectors::data_format::{FormatterContext, FormatterError, COMMIT_LITERAL};
+use crate::connectors::data_format::{
+    FormatterContext, FormatterError, ValueField, COMMIT_LITERAL,
+};
 use crate::connectors::data_storage::aws::dynamodb::Error as AwsDynamoDBError;
+use crate::connectors::data_storage::aws::firehose::Error as AwsFirehoseError;
 use crate::connectors::data_storage::aws::kinesis::Error as AwsKinesisError;
 use crate::connectors::data_storage::aws::kinesis::KinesisReader;
+use crate::connectors::data_storage::aws::sqs::Error as AwsSqsError;
+use crate::connectors::data_storage::aws::sqs::SqsReader;
 use crate::connectors::data_storage::data_lake::buffering::IncorrectSnapshotError;
+use crate::connectors::data_storage::scanner::hdfs::HdfsError;
 use crate::connectors::data_storage::scanner::s3::S3CommandName;
+use crate::connectors::data_storage::scanner::sftp::SftpError;
 use crate::connectors::metadata::SourceMetadata;
 use crate::connectors::posix_like::PosixLikeReader;
-use crate::connectors::{Offset, OffsetValue};
+use crate::connectors::{Offset, OffsetKey, OffsetValue};
 use crate::engine::error::DynResult;
 use crate::engine::{Key, Type, Value};
 use crate::persistence::backends::Error as PersistenceBackendError;
 use crate::persistence::frontier::OffsetAntichain;
 use crate::persistence::tracker::WorkerPersistentStorage;
 use crate::persistence::{PersistentId, UniqueName};
-use crate::python_api::ValueField;
 
 use async_nats::client::FlushError as NatsFlushError;
 use async_nats::client::PublishError as NatsPublishError;
@@ -85,15 +108,17 @@ use async_nats::error::Error as NatsError;
 use async_nats::jetstream::context::PublishErrorKind as JetStreamPublishError;
 use bincode::ErrorKind as BincodeError;
 use glob::PatternError as GlobPatternError;
-use pyo3::prelude::*;
 use rdkafka::error::KafkaError;
 use serde::{Deserialize, Serialize};
 
 pub use self::data_lake::delta::{DeltaError, DeltaTableReader, ObjectDownloader};
 pub use self::data_lake::iceberg::{IcebergError, IcebergReader};
+pub use self::data_lake::lance::LanceError;
 pub use self::data_lake::LakeWriter;
 pub use self::duckdb::{DuckDbError, DuckDbWriter};
 pub use self::elasticsearch::{ElasticSearchError, ElasticSearchReader, ElasticSearchWriter};
+pub use self::eventhubs::{EventHubsError, EventHubsReader, EventHubsWriter};
+pub use self::grpc::{GrpcBookmarkFields, GrpcError, GrpcReader};
 pub use self::mongodb::{MongoReader, MongoWriter};
 pub use self::mssql::{MssqlError, MssqlReader};
 pub use self::mysql::{MysqlError, MysqlReader, MysqlReaderError};
@@ -104,9 +129,20 @@ pub use self::polling::{LiveState, PolledRow, PollingDataSource, PollingReader};
 pub use self::postgres::{
     PostgresError, PsqlReader, PsqlWriter, ReplicationError as PostgresReplicationError, SslError,
 };
+pub use self::pubsub::{PubSubError, PubSubReader, PubSubWriter};
 pub use self::rabbitmq::{RabbitmqError, RabbitmqReader, RabbitmqWriter};
-pub use self::sqlite::{SqliteError, SqliteReader, SqliteWriter};
+pub use self::redis::{RedisError, RedisKeyValueWriter, RedisStreamReader, RedisValueLayout};
+pub use self::sql_polling::{
+    build_sql_cursor_reader, SqlCursorReader, SqlPollingConnection, SqlPollingError,
+    SqlPollingSource, SqlPollingTarget,
+};
+pub use self::sqlite::{
+    build_sqlite_cursor_reader, SqliteCursorReader, SqliteError, SqlitePollingTarget, SqliteReader,
+    SqliteWriter,
+};
+pub use self::sse::{SseError, SseEvent, SseEventParser, SseReader};
 pub use self::weaviate::{WeaviateError, WeaviateWriter};
+pub use self::webhook::{WebhookBatchFormat, WebhookError, WebhookRetryPolicy, WebhookWriter};
 
 #[derive(Clone, Debug, Eq, PartialEq, Copy)]
 pub enum DataEventType {
@@ -114,6 +150,25 @@ pub enum DataEventType {
     Delete,
 }
 
+/// The keys of the rows a CDC reader has seen since it started from an explicit position,
+/// skipping the snapshot. The rows inserted before that position were never read, so
+/// their deletions are dropped instead of being passed on.
+#[derive(Debug, Default)]
+pub struct KeysSinceStart(HashSet<Vec<Value>>);
+
+impl KeysSinceStart {
+    /// Records a change of the row with the given key and returns whether to pass it on.
+    pub fn admit(&mut self, event_type: DataEventType, key: &[Value]) -> bool {
+        match event_type {
+            DataEventType::Insert => {
+                self.0.insert(key.to_vec());
+                true
+            }
+            DataEventType::Delete => self.0.remove(key),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Copy)]
 pub enum PythonConnectorEventType {
     Insert,
@@ -265,6 +320,20 @@ impl ReaderContext {
     pub fn from_key_value(key: Option<Vec<u8>>, value: Option<Vec<u8>>) -> ReaderContext {
         ReaderContext::KeyValue((key, value))
     }
+
+    /// The size of the raw payload of the message. It's zero for the already parsed
+    /// values.
+    pub fn payload_size(&self) -> usize {
+        match self {
+            ReaderContext::RawBytes(_, bytes) => bytes.len(),
+            ReaderContext::TokenizedEntries(_, entries) => entries.iter().map(String::len).sum(),
+            ReaderContext::CsvRecord(_, record) => record.as_slice().len(),
+            ReaderContext::KeyValue((key, value)) => {
+                key.as_ref().map_or(0, Vec::len) + value.as_ref().map_or(0, Vec::len)
+            }
+            ReaderContext::Diff(_) | ReaderContext::Bson(_) | ReaderContext::Empty => 0,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -331,7 +400,14 @@ pub enum ReadError {
     Parquet(#[from] ParquetError),
 
     #[error(transparent)]
-    Py(#[from] PyErr),
+    Orc(#[from] OrcError),
+
+    #[error(transparent)]
+    Arrow(#[from] ArrowError),
+
+    #[cfg(feature = "python")]
+    #[error(transparent)]
+    Py(#[from] pyo3::PyErr),
 
     #[error(transparent)]
     GlobPattern(#[from] GlobPatternError),
@@ -363,9 +439,24 @@ pub enum ReadError {
     #[error("persistence is not supported for storage '{0:?}'")]
     PersistenceNotSupported(StorageType),
 
+    #[error("starting from explicit offsets is not supported for storage '{0:?}'")]
+    StartFromNotSupported(StorageType),
+
     #[error(transparent)]
     Kinesis(#[from] Box<AwsKinesisError>),
 
+    #[error(transparent)]
+    Sqs(#[from] Box<AwsSqsError>),
+
+    #[error(transparent)]
+    PubSub(#[from] Box<PubSubError>),
+
+    #[error(transparent)]
+    EventHubs(#[from] Box<EventHubsError>),
+
+    #[error(transparent)]
+    Redis(#[from] Box<RedisError>),
+
     #[error("malformed data")]
     MalformedData,
 
@@ -395,6 +486,24 @@ pub enum ReadError {
 
     #[error(transparent)]
     ElasticSearch(#[from] ElasticSearchError),
+
+    #[error(transparent)]
+    SqlPolling(#[from] SqlPollingError),
+
+    #[error(transparent)]
+    Sse(#[from] SseError),
+
+    #[error(transparent)]
+    Grpc(#[from] Box<GrpcError>),
+
+    #[error(transparent)]
+    Sftp(#[from] SftpError),
+
+    #[error(transparent)]
+    AzureBlob(#[from] Box<AzureStorageError>),
+
+    #[error(transparent)]
+    Hdfs(#[from] HdfsError),
 }
 
 // Allow `?` on unboxed `AwsKinesisError` in functions returning `Result<_, ReadError>`.
@@ -405,6 +514,42 @@ impl From<AwsKinesisError> for ReadError {
     }
 }
 
+impl From<AwsSqsError> for ReadError {
+    fn from(e: AwsSqsError) -> Self {
+        ReadError::Sqs(Box::new(e))
+    }
+}
+
+impl From<PubSubError> for ReadError {
+    fn from(e: PubSubError) -> Self {
+        ReadError::PubSub(Box::new(e))
+    }
+}
+
+impl From<EventHubsError> for ReadError {
+    fn from(e: EventHubsError) -> Self {
+        ReadError::EventHubs(Box::new(e))
+    }
+}
+
+impl From<RedisError> for ReadError {
+    fn from(e: RedisError) -> Self {
+        ReadError::Redis(Box::new(e))
+    }
+}
+
+impl From<GrpcError> for ReadError {
+    fn from(e: GrpcError) -> Self {
+        ReadError::Grpc(Box::new(e))
+    }
+}
+
+impl From<AzureStorageError> for ReadError {
+    fn from(e: AzureStorageError) -> Self {
+        ReadError::AzureBlob(Box::new(e))
+    }
+}
+
 // Allow `?` on `mongodb::error::Error` in functions returning `Result<_, ReadError>`.
 // Routes through `MongoDbError::Driver` so the full chain is `ReadError::MongoDb`.
 impl From<::mongodb::error::Error> for ReadError {
@@ -481,6 +626,12 @@ pub enum StorageType {
     Rabbitmq,
     Mysql,
     ElasticSearch,
+    Sqs,
+    PubSub,
+    EventHubs,
+    RedisStream,
+    Sse,
+    Grpc,
 }
 
 impl StorageType {
@@ -496,7 +647,12 @@ impl StorageType {
             | StorageType::S3Lines
             | StorageType::PosixLike => PosixLikeReader::merge_two_frontiers(lhs, rhs),
             StorageType::Kafka => KafkaReader::merge_two_frontiers(lhs, rhs),
+            #[cfg(feature = "python")]
             StorageType::Python => PythonReader::merge_two_frontiers(lhs, rhs),
+            #[cfg(not(feature = "python"))]
+            StorageType::Python => {
+                unreachable!("Python connectors are only available with the python feature")
+            }
             StorageType::Sqlite => SqliteReader::merge_two_frontiers(lhs, rhs),
             StorageType::DeltaLake => DeltaTableReader::merge_two_frontiers(lhs, rhs),
             StorageType::Nats => NatsReader::merge_two_frontiers(lhs, rhs),
@@ -509,16 +665,51 @@ impl StorageType {
             StorageType::Rabbitmq => RabbitmqReader::merge_two_frontiers(lhs, rhs),
             StorageType::Mysql => MysqlReader::merge_two_frontiers(lhs, rhs),
             StorageType::ElasticSearch => ElasticSearchReader::merge_two_frontiers(lhs, rhs),
+            StorageType::Sqs => SqsReader::merge_two_frontiers(lhs, rhs),
+            StorageType::PubSub => PubSubReader::merge_two_frontiers(lhs, rhs),
+            StorageType::EventHubs => EventHubsReader::merge_two_frontiers(lhs, rhs),
+            StorageType::RedisStream => RedisStreamReader::merge_two_frontiers(lhs, rhs),
+            StorageType::Sse => SseReader::merge_two_frontiers(lhs, rhs),
+            StorageType::Grpc => GrpcReader::merge_two_frontiers(lhs, rhs),
         }
     }
 }
 
+/// The frontier of the offsets committed by the connector, shared with its reader.
+///
+/// The reader runs in a separate thread and doesn't know when the entries it has read
+/// are committed. The sources that acknowledge the delivered messages, like the queues,
+/// use it to do so only once the messages are committed.
+#[derive(Clone, Debug, Default)]
+pub struct CommittedFrontier(Arc<Mutex<OffsetAntichain>>);
+
+impl CommittedFrontier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn advance(&self, frontier: &OffsetAntichain) {
+        frontier.clone_into(&mut self.0.lock().unwrap());
+    }
+
+    pub fn get_offset(&self, offset_key: &OffsetKey) -> Option<OffsetValue> {
+        self.0.lock().unwrap().get_offset(offset_key).cloned()
+    }
+}
+
 pub trait Reader {
     fn read(&mut self) -> Result<ReadResult, ReadError>;
 
     #[allow(clippy::missing_errors_doc)]
     fn seek(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError>;
 
+    /// Makes the reader start from offsets given by the user instead of from the
+    /// beginning of the source. Unlike `seek`, there is no persisted state behind them.
+    #[allow(clippy::missing_errors_doc)]
+    fn start_from(&mut self, _frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        Err(ReadError::StartFromNotSupported(self.storage_type()))
+    }
+
     fn short_description(&self) -> Cow<'static, str> {
         type_name::<Self>().into()
     }
@@ -640,9 +831,35 @@ pub trait Reader {
                     (OffsetValue::MssqlCdcLsn(a), OffsetValue::MssqlCdcLsn(b)) if b > a => {
                         result.advance_offset(offset_key.clone(), other_value.clone());
                     }
+                    (
+                        OffsetValue::EventHubsSequenceNumber(a),
+                        OffsetValue::EventHubsSequenceNumber(b),
+                    ) if b > a => {
+                        result.advance_offset(offset_key.clone(), other_value.clone());
+                    }
+                    (
+                        OffsetValue::RedisStreamEntryId {
+                            milliseconds_time: a_time,
+                            sequence_number: a_sequence,
+                        },
+                        OffsetValue::RedisStreamEntryId {
+                            milliseconds_time: b_time,
+                            sequence_number: b_sequence,
+                        },
+                    ) if (b_time, b_sequence) > (a_time, a_sequence) => {
+                        result.advance_offset(offset_key.clone(), other_value.clone());
+                    }
                     (
                         OffsetValue::NatsReadEntriesCount(offset_entries_read),
                         OffsetValue::NatsReadEntriesCount(other_entries_read),
+                    )
+                    | (
+                        OffsetValue::SqsReadEntriesCount(offset_entries_read),
+                        OffsetValue::SqsReadEntriesCount(other_entries_read),
+                    )
+                    | (
+                        OffsetValue::PubSubReadEntriesCount(offset_entries_read),
+                        OffsetValue::PubSubReadEntriesCount(other_entries_read),
                     ) => {
                         if other_entries_read > offset_entries_read {
                             result.advance_offset(offset_key.clone(), other_value.clone());
@@ -662,6 +879,32 @@ pub trait Reader {
                     {
                         result.advance_offset(offset_key.clone(), other_value.clone());
                     }
+                    (
+                        OffsetValue::SseEventId {
+                            entries_read: offset_entries_read,
+                            ..
+                        },
+                        OffsetValue::SseEventId {
+                            entries_read: other_entries_read,
+                            ..
+                        },
+                    )
+                    | (
+                        OffsetValue::GrpcBookmark {
+                            entries_read: offset_entries_read,
+                            ..
+                        },
+                        OffsetValue::GrpcBookmark {
+                            entries_read: other_entries_read,
+                            ..
+                        },
+                    ) => {
+                        // The event ids and the bookmarks are opaque, so only the
+                        // number of the entries read orders the frontiers.
+                        if other_entries_read > offset_entries_read {
+                            result.advance_offset(offset_key.clone(), other_value.clone());
+                        }
+                    }
                     (
                         OffsetValue::PollingWatermark {
                             entries_read: offset_entries_read,
@@ -695,6 +938,20 @@ pub trait Reader {
     fn max_allowed_consecutive_errors(&self) -> usize {
         0
     }
+
+    /// The frontier to be advanced by the connector on each commit, if the reader
+    /// needs to know which of the entries read are committed.
+    fn committed_frontier(&self) -> Option<CommittedFrontier> {
+        None
+    }
+
+    /// Whether the source partitions are assigned to the workers statically, the
+    /// partition `p` being read by the worker `p % worker_count`. The rows of such a
+    /// reader are placed in the shard of their partition, so they don't need to be
+    /// exchanged between the workers after being read.
+    fn is_partition_aligned(&self) -> bool {
+        false
+    }
 }
 
 pub trait ReaderBuilder: Send + 'static {
@@ -707,6 +964,14 @@ pub trait ReaderBuilder: Send + 'static {
     fn storage_type(&self) -> StorageType;
     fn short_description(&self) -> Cow<'static, str>;
     fn name(&self, unique_name: Option<&UniqueName>) -> String;
+
+    fn committed_frontier(&self) -> Option<CommittedFrontier> {
+        None
+    }
+
+    fn is_partition_aligned(&self) -> bool {
+        false
+    }
 }
 
 impl<T> ReaderBuilder for T
@@ -725,6 +990,14 @@ where
         Reader::short_description(self)
     }
 
+    fn committed_frontier(&self) -> Option<CommittedFrontier> {
+        Reader::committed_frontier(self)
+    }
+
+    fn is_partition_aligned(&self) -> bool {
+        Reader::is_partition_aligned(self)
+    }
+
     fn name(&self, unique_name: Option<&UniqueName>) -> String {
         if let Some(unique_name) = unique_name {
             unique_name.clone()
@@ -806,6 +1079,9 @@ pub enum WriteError {
     #[error(transparent)]
     Iceberg(#[from] IcebergError),
 
+    #[error(transparent)]
+    Lance(#[from] LanceError),
+
     #[error(transparent)]
     QuestDB(#[from] QuestDBError),
 
@@ -836,6 +1112,9 @@ pub enum WriteError {
     #[error(transparent)]
     Weaviate(#[from] WeaviateError),
 
+    #[error(transparent)]
+    Webhook(#[from] WebhookError),
+
     #[error(transparent)]
     Persistence(#[from] PersistenceBackendError),
 
@@ -884,6 +1163,21 @@ pub enum WriteError {
     #[error(transparent)]
     Kinesis(#[from] AwsKinesisError),
 
+    #[error(transparent)]
+    Firehose(#[from] AwsFirehoseError),
+
+    #[error(transparent)]
+    PubSub(#[from] PubSubError),
+
+    #[error(transparent)]
+    EventHubs(#[from] EventHubsError),
+
+    #[error(transparent)]
+    Redis(#[from] RedisError),
+
+    #[error(transparent)]
+    Cassandra(#[from] CassandraError),
+
     #[error("after several retried attempts, {0} items haven't been saved")]
     SomeItemsNotDelivered(usize),
 
@@ -898,6 +1192,9 @@ pub enum WriteError {
 
     #[error(transparent)]
     Pinecone(#[from] PineconeError),
+
+    #[error("{0} can't commit in two phases")]
+    TwoPhaseCommitUnsupported(String),
 }
 
 // Allow `?` on `mongodb::error::Error` in functions returning `Result<_, WriteError>`.
@@ -963,6 +1260,26 @@ pub trait Writer: Send {
         true
     }
 
+    /// Whether the writer can commit in two phases, with [`Writer::prepare`] and
+    /// [`Writer::commit`], which is needed to join a commit group
+    /// (see [`crate::connectors::sink_commit`]).
+    fn supports_two_phase_commit(&self) -> bool {
+        false
+    }
+
+    /// Sends the entries written since the last commit to the destination without making
+    /// them visible to its readers, so that only making them visible is left to
+    /// [`Writer::commit`].
+    fn prepare(&mut self) -> Result<(), WriteError> {
+        Err(WriteError::TwoPhaseCommitUnsupported(self.name()))
+    }
+
+    /// Makes the entries sent by [`Writer::prepare`] visible to the readers of the
+    /// destination.
+    fn commit(&mut self) -> Result<(), WriteError> {
+        Err(WriteError::TwoPhaseCommitUnsupported(self.name()))
+    }
+
     fn name(&self) -> String {
         let short_description: Cow<'static, str> = type_name::<Self>().into();
         short_description.split("::").last().unwrap().to_string()
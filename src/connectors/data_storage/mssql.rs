@@ -14,10 +14,10 @@ use tokio::runtime::Runtime as TokioRuntime;
 use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
 
 use crate::async_runtime::create_async_tokio_runtime;
-use crate::connectors::data_format::FormatterContext;
+use crate::connectors::data_format::{FormatterContext, ValueField};
 use crate::connectors::data_storage::{
-    CommitPossibility, ConnectorMode, ConversionError, SqlQueryTemplate, TableContext,
-    TableWriterInitMode, ValuesMap,
+    CommitPossibility, ConnectorMode, ConversionError, KeysSinceStart, SqlQueryTemplate,
+    TableContext, TableWriterInitMode, ValuesMap,
 };
 use crate::connectors::metadata::MssqlMetadata;
 use crate::connectors::offset::{Offset, OffsetKey, OffsetValue, EMPTY_OFFSET};
@@ -27,7 +27,6 @@ use crate::engine::error::{limit_length, STANDARD_OBJECT_LENGTH_LIMIT};
 use crate::engine::time::DateTime as DateTimeTrait;
 use crate::engine::{Type, Value};
 use crate::persistence::frontier::OffsetAntichain;
-use crate::python_api::ValueField;
 use crate::retry::{execute_with_retries_if, RetryConfig};
 
 const MAX_MSSQL_RETRIES: usize = 3;
@@ -396,13 +395,9 @@ pub struct MssqlReader {
     /// `initialize()` skips the full table snapshot and instead replays CDC
     /// changes in (`saved_last_lsn`, current max LSN] as one block.
     saved_last_lsn: Option<Vec<u8>>,
-    /// `true` once `seek()` has run, which the engine only does when the
-    /// pipeline has a `persistence_config` attached.  Used by
-    /// `check_schema_columns_exist` to decide whether the connector will
-    /// ever read from the CDC change tables (so the schema must match
-    /// `cdc.captured_columns`) or only from the source table (so matching
-    /// `sys.columns` is enough).
-    persistence_enabled: bool,
+    /// Set by `start_from()`: the snapshot is skipped, so the deletions of the
+    /// rows inserted before the requested LSN are dropped.
+    keys_since_start: Option<KeysSinceStart>,
 }
 
 impl MssqlReader {
@@ -433,7 +428,7 @@ impl MssqlReader {
             snapshot_version: 0,
             tracks_lsn: false,
             saved_last_lsn: None,
-            persistence_enabled: false,
+            keys_since_start: None,
         })
     }
 
@@ -668,18 +663,24 @@ impl MssqlReader {
             (Type::Pointer, ColumnData::String(Some(s))) => {
                 crate::engine::value::parse_pathway_pointer(s).ok()
             }
+            (Type::DateTimeZoned, ColumnData::String(Some(s))) => {
+                crate::engine::DateTimeZoned::parse(s)
+                    .ok()
+                    .map(Value::DateTimeZoned)
+            }
             (Type::Duration, ColumnData::I64(Some(v))) => {
                 crate::engine::Duration::new_with_unit(*v, "us")
                     .map(Value::Duration)
                     .ok()
             }
-            (Type::List(_) | Type::Tuple(_) | Type::Array(..), ColumnData::String(Some(s))) => {
-                serde_json::from_str::<serde_json::Value>(s)
-                    .ok()
-                    .and_then(|json_val| {
-                        crate::connectors::data_format::parse_value_from_json(&json_val, dtype)
-                    })
-            }
+            (
+                Type::List(_) | Type::Tuple(_) | Type::Array(..) | Type::Struct(_) | Type::Map(_),
+                ColumnData::String(Some(s)),
+            ) => serde_json::from_str::<serde_json::Value>(s)
+                .ok()
+                .and_then(|json_val| {
+                    crate::connectors::data_format::parse_value_from_json(&json_val, dtype)
+                }),
             (Type::PyObjectWrapper, ColumnData::Binary(Some(b))) => {
                 bincode::deserialize::<Value>(b.as_ref()).ok()
             }
@@ -838,12 +839,13 @@ impl MssqlReader {
         let qualified_name = qualified_table_name(&schema_name, &table_name);
         let qualified_lit = n_string_literal(&qualified_name);
         // We will read from the CDC change tables iff the connector ever
-        // calls `load_resume_delta` (persistence) or `poll_cdc_changes`
-        // (streaming).  Static-mode without persistence reads only from
-        // the source table, so checking against `cdc.captured_columns`
+        // calls `load_resume_delta` (resuming from a persisted or requested
+        // LSN) or `poll_cdc_changes` (streaming).  Otherwise the rows are read
+        // from the source table, so checking against `cdc.captured_columns`
         // there would be a false positive when CDC was enabled with a
         // partial `@captured_column_list`.
-        let cdc = self.tracks_lsn && (self.mode.is_polling_enabled() || self.persistence_enabled);
+        let cdc =
+            self.tracks_lsn && (self.mode.is_polling_enabled() || self.saved_last_lsn.is_some());
         let capture_instance = self.capture_instance.clone();
         let schema = self.schema.clone();
         let pathway_cols: Vec<String> = schema.iter().map(|(n, _)| n.clone()).collect();
@@ -1118,6 +1120,7 @@ impl MssqlReader {
                 &schema,
                 &key_column_names,
                 &block_offset,
+                self.keys_since_start.as_mut(),
             ));
             results.push(ReadResult::FinishedSource {
                 commit_possibility: CommitPossibility::Possible,
@@ -1377,6 +1380,7 @@ impl MssqlReader {
         schema: &[(String, Type)],
         key_column_names: &[String],
         offset: &Offset,
+        mut keys_since_start: Option<&mut KeysSinceStart>,
     ) -> Vec<ReadResult> {
         gap_changes
             .iter()
@@ -1398,6 +1402,11 @@ impl MssqlReader {
                             .unwrap_or(Value::None)
                     })
                     .collect();
+                if let Some(keys_since_start) = keys_since_start.as_deref_mut() {
+                    if !keys_since_start.admit(event_type, &key) {
+                        return None;
+                    }
+                }
                 let values: ValuesMap = row_values.into();
                 Some(ReadResult::Data(
                     ReaderContext::from_diff(event_type, Some(key), values),
@@ -1537,6 +1546,7 @@ impl MssqlReader {
             &schema,
             &key_column_names,
             &block_offset,
+            None,
         ));
 
         results.push(ReadResult::FinishedSource {
@@ -1628,6 +1638,7 @@ impl MssqlReader {
                 &schema,
                 &key_column_names,
                 &block_offset,
+                self.keys_since_start.as_mut(),
             ));
             results.push(ReadResult::FinishedSource {
                 commit_possibility: CommitPossibility::Possible,
@@ -1657,7 +1668,6 @@ impl Reader for MssqlReader {
         // This also populates `self.capture_instance`, which
         // `load_resume_delta` needs below.  `initialize()` will notice that
         // `tracks_lsn` is already set and skip a redundant probe.
-        self.persistence_enabled = true;
         self.probe_cdc_availability(true)?;
         if let Some(OffsetValue::MssqlCdcLsn(bytes)) = frontier.get_offset(&OffsetKey::Mssql) {
             self.saved_last_lsn = Some(bytes.clone());
@@ -1665,6 +1675,12 @@ impl Reader for MssqlReader {
         Ok(())
     }
 
+    fn start_from(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        // Starting from an LSN also needs CDC, and goes through the same resume path
+        self.keys_since_start = Some(KeysSinceStart::default());
+        self.seek(frontier)
+    }
+
     fn read(&mut self) -> Result<ReadResult, ReadError> {
         loop {
             if let Some(result) = self.snapshot.pop() {
@@ -2023,6 +2039,7 @@ impl MssqlWriter {
         snapshot_mode: bool,
         table_ctx: &TableContext,
         mode: TableWriterInitMode,
+        dry_run: bool,
     ) -> Result<MssqlWriter, WriteError> {
         let TableContext {
             schema_name,
@@ -2080,12 +2097,20 @@ impl MssqlWriter {
         // index covers the configured `primary_key` columns: without that
         // the MERGE statement happily upserts the wrong rows.
         let preflight_default = matches!(mode, TableWriterInitMode::Default);
-        let preflight_snapshot_pk = snapshot_mode && !matches!(mode, TableWriterInitMode::Replace);
+        // A dry run only checks that the writer can be created, so it must
+        // not create or replace the destination table.  The table it would
+        // write to is then only known for `Default`.
+        if dry_run {
+            init_queries.clear();
+        }
+        let preflight_existing = !dry_run || preflight_default;
+        let preflight_snapshot_pk =
+            snapshot_mode && !matches!(mode, TableWriterInitMode::Replace) && preflight_existing;
         // `Replace` rebuilds the table from our schema, so column existence
         // matches by construction.  For `Default` and `CreateIfNotExists`
         // (when the table already existed) the destination's columns might
         // not match — validate before any data is sent.
-        let preflight_columns = !matches!(mode, TableWriterInitMode::Replace);
+        let preflight_columns = !matches!(mode, TableWriterInitMode::Replace) && preflight_existing;
         let mut client = execute_with_retries_if(
             || {
                 runtime.block_on(async {
@@ -2500,9 +2525,13 @@ impl MssqlWriter {
                     format!("VARBINARY(MAX){not_null_suffix}")
                 }
             }
-            Type::Json | Type::Tuple(_) | Type::List(_) | Type::Array(_, _) => {
-                format!("NVARCHAR(MAX){not_null_suffix}")
-            }
+            Type::Json
+            | Type::DateTimeZoned
+            | Type::Tuple(_)
+            | Type::List(_)
+            | Type::Array(_, _)
+            | Type::Struct(_)
+            | Type::Map(_) => format!("NVARCHAR(MAX){not_null_suffix}"),
             Type::DateTimeNaive => format!("DATETIME2(6){not_null_suffix}"),
             Type::DateTimeUtc => format!("DATETIMEOFFSET(6){not_null_suffix}"),
             Type::Optional(wrapped) => {
@@ -2801,13 +2830,18 @@ fn value_to_column_data(value: &Value, dtype: &Type) -> Result<ColumnData<'stati
                 );
             utc_dt.into_sql()
         }
+        Value::DateTimeZoned(dt) => dt.to_string().into_sql(),
         Value::Duration(d) => d.microseconds().into_sql(),
         Value::Json(j) => j.to_string().into_sql(),
         Value::PyObjectWrapper(_) => {
             let bytes = bincode::serialize(value).map_err(|e| *e)?;
             bytes.into_sql()
         }
-        Value::Tuple(_) | Value::IntArray(_) | Value::FloatArray(_) => {
+        Value::Tuple(_)
+        | Value::IntArray(_)
+        | Value::FloatArray(_)
+        | Value::Struct(_)
+        | Value::Map(_) => {
             let json_val = crate::connectors::data_format::serialize_value_to_json(value)
                 .map_err(WriteError::from)?;
             json_val.to_string().into_sql()
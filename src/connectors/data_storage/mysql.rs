@@ -21,10 +21,11 @@ use mysql::{
     Value as MysqlValue,
 };
 
-use crate::connectors::data_format::{FormatterContext, FormatterError};
+use crate::connectors::data_format::{FormatterContext, FormatterError, ValueField};
+use crate::connectors::data_storage::sql_polling::PolledValue;
 use crate::connectors::data_storage::{
-    CommitPossibility, ConnectorMode, ConversionError, SqlQueryTemplate, TableWriterInitMode,
-    ValuesMap,
+    CommitPossibility, ConnectorMode, ConversionError, KeysSinceStart, SqlPollingConnection,
+    SqlQueryTemplate, TableWriterInitMode, ValuesMap,
 };
 use crate::connectors::metadata::MysqlMetadata;
 use crate::connectors::offset::{Offset, OffsetKey, OffsetValue, EMPTY_OFFSET};
@@ -34,9 +35,10 @@ use crate::connectors::{
 use crate::engine::error::{limit_length, STANDARD_OBJECT_LENGTH_LIMIT};
 use crate::engine::time::DateTime as DateTimeTrait;
 use crate::engine::value::parse_pathway_pointer;
-use crate::engine::{DateTimeNaive, DateTimeUtc, Duration as EngineDuration, Type, Value};
+use crate::engine::{
+    DateTimeNaive, DateTimeUtc, DateTimeZoned, Duration as EngineDuration, Type, Value,
+};
 use crate::persistence::frontier::OffsetAntichain;
-use crate::python_api::ValueField;
 use crate::retry::{execute_with_retries_if, RetryConfig};
 
 const MAX_MYSQL_RETRIES: usize = 3;
@@ -225,6 +227,7 @@ fn serialize_load_data_value(out: &mut Vec<u8>, value: &Value) -> Result<(), Wri
                 .to_string();
             out.extend_from_slice(formatted.as_bytes());
         }
+        Value::DateTimeZoned(dt) => escape_load_data_field(out, dt.to_string().as_bytes()),
         Value::Duration(d) => out.extend_from_slice(duration_to_time_literal(*d).as_bytes()),
         Value::PyObjectWrapper(_) => {
             let bytes = bincode::serialize(value).map_err(|e| *e)?;
@@ -233,6 +236,8 @@ fn serialize_load_data_value(out: &mut Vec<u8>, value: &Value) -> Result<(), Wri
         Value::IntArray(_)
         | Value::FloatArray(_)
         | Value::Tuple(_)
+        | Value::Struct(_)
+        | Value::Map(_)
         | Value::Error
         | Value::Pending => Err(FormatterError::ValueNonSerializable(value.kind(), "MySQL"))?,
     }
@@ -448,6 +453,7 @@ fn is_transient_mysql_write(error: &WriteError) -> bool {
 }
 
 impl MysqlWriter {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         pool: MysqlConnectionPool,
         max_batch_size: Option<usize>,
@@ -456,27 +462,32 @@ impl MysqlWriter {
         value_fields: &[ValueField],
         key_field_names: Option<&[String]>,
         mode: TableWriterInitMode,
+        dry_run: bool,
     ) -> Result<MysqlWriter, WriteError> {
         // Interpolating `table_name` raw would reject reserved words and
         // characters requiring backticks; pre-quote once so the shared
         // template builders see the safe form, mirroring how the
         // Postgres writer handles it.
         let quoted_table_name = mysql_quote_identifier(table_name);
-        let mut connection = pool.get_conn()?;
-        let mut transaction = connection.start_transaction(MysqlTxOpts::default())?;
-        mode.initialize(
-            &quoted_table_name,
-            value_fields,
-            key_field_names,
-            !snapshot_mode,
-            |query| {
-                transaction.query_drop(query)?;
-                Ok(())
-            },
-            Self::mysql_data_type,
-            mysql_quote_identifier,
-        )?;
-        transaction.commit()?;
+        // A dry run only checks that the writer can be created, so it
+        // must not create or replace the destination table.
+        if !dry_run {
+            let mut connection = pool.get_conn()?;
+            let mut transaction = connection.start_transaction(MysqlTxOpts::default())?;
+            mode.initialize(
+                &quoted_table_name,
+                value_fields,
+                key_field_names,
+                !snapshot_mode,
+                |query| {
+                    transaction.query_drop(query)?;
+                    Ok(())
+                },
+                Self::mysql_data_type,
+                mysql_quote_identifier,
+            )?;
+            transaction.commit()?;
+        }
 
         let batch = BatchContext::new(
             quoted_table_name,
@@ -553,7 +564,7 @@ impl MysqlWriter {
             Type::Bool => format!("BOOLEAN{not_null_suffix}"),
             Type::Int => format!("BIGINT{not_null_suffix}"),
             Type::Float => format!("DOUBLE{not_null_suffix}"),
-            Type::Pointer | Type::String => format!("TEXT{not_null_suffix}"),
+            Type::Pointer | Type::String | Type::DateTimeZoned => format!("TEXT{not_null_suffix}"),
             Type::Bytes | Type::PyObjectWrapper => format!("BLOB{not_null_suffix}"),
             Type::Json => format!("JSON{not_null_suffix}"),
             Type::Duration => format!("TIME(6){not_null_suffix}"),
@@ -565,9 +576,13 @@ impl MysqlWriter {
                 let wrapped = Self::mysql_data_type(wrapped, true)?;
                 return Ok(wrapped);
             }
-            Type::Any | Type::Tuple(_) | Type::List(_) | Type::Array(_, _) | Type::Future(_) => {
-                return Err(WriteError::UnsupportedType(type_.clone()))
-            }
+            Type::Any
+            | Type::Tuple(_)
+            | Type::List(_)
+            | Type::Array(_, _)
+            | Type::Future(_)
+            | Type::Struct(_)
+            | Type::Map(_) => return Err(WriteError::UnsupportedType(type_.clone())),
         })
     }
 
@@ -598,6 +613,7 @@ impl MysqlWriter {
             Value::DateTimeUtc(dt) => {
                 Ok(Self::to_mysql_date(dt.to_naive_in_timezone("UTC").unwrap()))
             }
+            Value::DateTimeZoned(dt) => Ok(MysqlValue::Bytes(dt.to_string().into())),
             Value::Duration(d) => {
                 let is_negative = d < &EngineDuration::new(0);
                 let mut total_microseconds = d.microseconds();
@@ -641,6 +657,8 @@ impl MysqlWriter {
             Value::IntArray(_)
             | Value::FloatArray(_)
             | Value::Tuple(_)
+            | Value::Struct(_)
+            | Value::Map(_)
             | Value::Error
             | Value::Pending => Err(FormatterError::ValueNonSerializable(value.kind(), "MySQL"))?,
         }
@@ -1273,6 +1291,10 @@ fn convert_mysql_value(
             .map(Value::from),
         Type::DateTimeNaive => mysql_value_to_naive(value),
         Type::DateTimeUtc => mysql_value_to_utc(value),
+        Type::DateTimeZoned => value_bytes(value)
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .and_then(|string| DateTimeZoned::parse(string).ok())
+            .map(Value::DateTimeZoned),
         Type::Duration => mysql_value_to_duration(value),
         // PyObjectWrapper values are written as a bincode-serialized `Value`
         // (see `MysqlWriter::to_mysql_value`); deserialize the BLOB back.
@@ -1318,6 +1340,9 @@ pub struct MysqlReader {
     /// Persisted coordinates to resume from, populated by `seek()` when
     /// persistence is enabled.
     saved_position: Option<(String, u64)>,
+    /// Set by `start_from()`: the snapshot is skipped, so the deletions of the
+    /// rows inserted before the requested coordinates are dropped.
+    keys_since_start: Option<KeysSinceStart>,
 }
 
 impl MysqlReader {
@@ -1352,6 +1377,7 @@ impl MysqlReader {
             binlog_filename: String::new(),
             binlog_position: 0,
             saved_position: None,
+            keys_since_start: None,
         })
     }
 
@@ -1654,6 +1680,11 @@ impl MysqlReader {
             MysqlMetadata::new(self.snapshot_version).into(),
         ));
         for (event_type, key, values) in changes {
+            if let Some(keys_since_start) = &mut self.keys_since_start {
+                if !keys_since_start.admit(event_type, &key) {
+                    continue;
+                }
+            }
             results.push(ReadResult::Data(
                 ReaderContext::from_diff(event_type, Some(key), values),
                 offset.clone(),
@@ -1929,6 +1960,11 @@ impl Reader for MysqlReader {
         Ok(())
     }
 
+    fn start_from(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        self.keys_since_start = Some(KeysSinceStart::default());
+        self.seek(frontier)
+    }
+
     fn max_allowed_consecutive_errors(&self) -> usize {
         MAX_MYSQL_READ_RETRIES
     }
@@ -1946,6 +1982,56 @@ impl Reader for MysqlReader {
     }
 }
 
+impl SqlPollingConnection for MysqlRawConn {
+    fn quote_identifier(&self, identifier: &str) -> String {
+        mysql_quote_identifier(identifier)
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    fn fetch_rows(
+        &mut self,
+        query: &str,
+        params: &[i64],
+        fields: &[ValueField],
+    ) -> Result<Vec<Vec<PolledValue>>, ReadError> {
+        let params = MysqlParams::Positional(params.iter().copied().map(MysqlValue::Int).collect());
+        let rows: Vec<MysqlQueryRow> = self.exec(query, params).map_err(mysql_read_err)?;
+        Ok(rows
+            .iter()
+            .map(|row| {
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| match row.as_ref(index) {
+                        Some(value) => convert_mysql_value(value, &field.name, &field.type_),
+                        None => Err(Box::new(ConversionError::new(
+                            "<missing column in SELECT result>".to_owned(),
+                            field.name.clone(),
+                            field.type_.clone(),
+                            None,
+                        ))),
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn storage_type(&self) -> StorageType {
+        StorageType::Mysql
+    }
+
+    fn offset_key(&self) -> OffsetKey {
+        OffsetKey::Mysql
+    }
+
+    fn engine_name(&self) -> &'static str {
+        "MySQL"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::cmp::Ordering;
@@ -6,11 +6,10 @@ use log::error;
 use reqwest::blocking::Client;
 use serde_json::{json, Map as JsonMap, Value as JsonValue};
 
-use crate::connectors::data_format::FormatterContext;
+use crate::connectors::data_format::{FormatterContext, ValueField};
 use crate::connectors::data_storage::vectors::{vector_kind_of, VectorKind};
 use crate::connectors::{WriteError, Writer};
 use crate::engine::{Key, Value};
-use crate::python_api::ValueField;
 use crate::retry::{execute_with_retries_if, RetryConfig};
 
 // Pinecone REST API version pinned in the request header. Matches the version
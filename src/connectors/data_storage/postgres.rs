@@ -26,10 +26,11 @@ use tokio::runtime::Runtime as TokioRuntime;
 use uuid::Uuid;
 
 use crate::async_runtime::create_async_tokio_runtime;
-use crate::connectors::data_format::FormatterContext;
+use crate::connectors::data_format::{parse_value_from_json, FormatterContext, ValueField};
+use crate::connectors::data_storage::sql_polling::PolledValue;
 use crate::connectors::data_storage::{
-    format_error_chain, CommitPossibility, ConversionError, SqlQueryTemplate, TableContext,
-    TableWriterInitMode, ValuesMap,
+    format_error_chain, CommitPossibility, ConversionError, SqlPollingConnection, SqlQueryTemplate,
+    TableContext, TableWriterInitMode, ValuesMap,
 };
 use crate::connectors::metadata::PostgresMetadata;
 use crate::connectors::{
@@ -37,9 +38,10 @@ use crate::connectors::{
     StorageType, WriteError, Writer,
 };
 use crate::engine::value::parse_pathway_pointer;
-use crate::engine::{DateTimeNaive, DateTimeUtc, Duration as EngineDuration, Type, Value};
+use crate::engine::{
+    DateTimeNaive, DateTimeUtc, DateTimeZoned, Duration as EngineDuration, Type, Value,
+};
 use crate::persistence::frontier::OffsetAntichain;
-use crate::python_api::ValueField;
 use crate::retry::execute_with_retries_if;
 
 #[derive(Debug, thiserror::Error)]
@@ -555,12 +557,12 @@ mod to_sql {
     use bytes::{BufMut, BytesMut};
     use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
     use half::f16;
-    use ndarray::ArrayD;
-    use numpy::Ix1;
+    use ndarray::{ArrayD, Ix1};
     use ordered_float::OrderedFloat;
     use pgvector::{HalfVector, Vector};
     use postgres::types::{to_sql_checked, Format, IsNull, ToSql, Type};
 
+    use crate::connectors::data_format::serialize_value_to_json;
     use crate::engine::time::DateTime as _;
     use crate::engine::Value;
 
@@ -1054,6 +1056,14 @@ mod to_sql {
                     try_forward!(DateTime<Utc>, dt.as_chrono_datetime().and_utc());
                     "UTC date/time"
                 }
+                Self::DateTimeZoned(dt) => {
+                    // TIMESTAMPTZ keeps only the instant; text columns keep
+                    // the zone name too, so the value can be read back as is.
+                    try_forward!(DateTime<Utc>, dt.to_utc().as_chrono_datetime().and_utc());
+                    let formatted = dt.to_string();
+                    try_forward!(&str, formatted.as_str());
+                    "zoned date/time"
+                }
                 Self::Duration(dr) => {
                     // Emit PostgreSQL's INTERVAL binary layout when
                     // the destination column is INTERVAL:
@@ -1087,6 +1097,16 @@ mod to_sql {
                     try_forward!(&serde_json::Value, &**j);
                     "JSON"
                 }
+                Self::Struct(_) => {
+                    let json = serialize_value_to_json(self)?;
+                    try_forward!(&serde_json::Value, &json);
+                    "struct"
+                }
+                Self::Map(_) => {
+                    let json = serialize_value_to_json(self)?;
+                    try_forward!(&serde_json::Value, &json);
+                    "map"
+                }
                 Self::Error => "error",
                 Self::PyObjectWrapper(_) => {
                     try_forward!(Vec<u8>, bincode::serialize(self).map_err(|e| *e)?);
@@ -1430,7 +1450,7 @@ impl PsqlWriter {
         Ok(())
     }
 
-    #[allow(clippy::too_many_lines)]
+    #[allow(clippy::too_many_lines, clippy::fn_params_excessive_bools)]
     pub fn new(
         connection_config: PsqlConnectionConfig,
         max_batch_size: Option<usize>,
@@ -1438,6 +1458,7 @@ impl PsqlWriter {
         table_ctx: &TableContext,
         init_mode: TableWriterInitMode,
         legacy_mode: bool,
+        dry_run: bool,
     ) -> Result<PsqlWriter, WriteError> {
         let mut client = connection_config.connect()?;
         let TableContext {
@@ -1577,20 +1598,24 @@ impl PsqlWriter {
                 }
             }
         }
-        let mut transaction = client.transaction()?;
-        init_mode.initialize(
-            &quoted_table_name,
-            value_fields,
-            key_field_names,
-            !snapshot_mode || legacy_mode,
-            |query| {
-                transaction.execute(query, &[])?;
-                Ok(())
-            },
-            Self::postgres_data_type,
-            quote_identifier,
-        )?;
-        transaction.commit()?;
+        // A dry run only checks that the writer can be created, so it
+        // must not create or replace the destination table.
+        if !dry_run {
+            let mut transaction = client.transaction()?;
+            init_mode.initialize(
+                &quoted_table_name,
+                value_fields,
+                key_field_names,
+                !snapshot_mode || legacy_mode,
+                |query| {
+                    transaction.execute(query, &[])?;
+                    Ok(())
+                },
+                Self::postgres_data_type,
+                quote_identifier,
+            )?;
+            transaction.commit()?;
+        }
 
         let query = SqlQueryTemplate::new(
             snapshot_mode,
@@ -1607,8 +1632,9 @@ impl PsqlWriter {
         // snapshot mode (the deprecated `write_snapshot`, which still
         // appends `time`/`diff` to the snapshot table) is the one
         // exception: it is not supported here and falls back to the
-        // INSERT loop.
-        let copy_ctx = if legacy_mode {
+        // INSERT loop. In a dry run the destination table is only known
+        // to exist for `Default`, and the writer never writes anyway.
+        let copy_ctx = if legacy_mode || (dry_run && init_mode != TableWriterInitMode::Default) {
             None
         } else {
             Some(CopyContext::new(
@@ -2108,9 +2134,11 @@ impl PsqlWriter {
             Type::Float => format!("DOUBLE PRECISION{not_null_suffix}"),
             Type::Pointer | Type::String => format!("TEXT{not_null_suffix}"),
             Type::Bytes | Type::PyObjectWrapper => format!("BYTEA{not_null_suffix}"),
-            Type::Json => format!("JSONB{not_null_suffix}"),
+            Type::Json | Type::Struct(_) | Type::Map(_) => format!("JSONB{not_null_suffix}"),
             Type::DateTimeNaive => format!("TIMESTAMP{not_null_suffix}"),
             Type::DateTimeUtc => format!("TIMESTAMPTZ{not_null_suffix}"),
+            // TIMESTAMPTZ would drop the zone name, so zoned values go to text
+            Type::DateTimeZoned => format!("TEXT{not_null_suffix}"),
             Type::Optional(wrapped) => {
                 if let Type::Any = **wrapped {
                     return Err(WriteError::UnsupportedType(type_.clone()));
@@ -2967,12 +2995,21 @@ impl WalReader {
                 .map_err(|e| err(format!("Cannot parse Pointer '{s}': {e}")))?,
             Type::DateTimeNaive => Self::parse_datetime_naive(s, err)?,
             Type::DateTimeUtc => Self::parse_datetime_utc(s, err)?,
+            Type::DateTimeZoned => DateTimeZoned::parse(s)
+                .map(Value::DateTimeZoned)
+                .map_err(|e| err(format!("Cannot parse DateTimeZoned '{s}': {e}")))?,
             Type::Duration => Self::parse_duration_from_str(s, err)?,
             Type::Json => {
                 let json: JsonValue = serde_json::from_str(s)
                     .map_err(|e| err(format!("Cannot parse JSON '{s}': {e}")))?;
                 json.into()
             }
+            Type::Struct(_) | Type::Map(_) => {
+                let json: JsonValue = serde_json::from_str(s)
+                    .map_err(|e| err(format!("Cannot parse JSON '{s}': {e}")))?;
+                parse_value_from_json(&json, type_)
+                    .ok_or_else(|| err(format!("Cannot parse {type_} from '{s}'")))?
+            }
             Type::List(element_type) => {
                 return Self::parse_pg_array_string(s, element_type, field_name)
                     .map_err(|e| err(format!("Cannot parse list '{s}': {e}")));
@@ -3819,9 +3856,12 @@ fn is_pathway_type_compatible_with_pg_udt(
         ),
         Type::Pointer => actual_udt == "text",
         Type::Bytes | Type::PyObjectWrapper => actual_udt == "bytea",
-        Type::Json => matches!(actual_udt, "json" | "jsonb"),
+        Type::Json | Type::Struct(_) | Type::Map(_) => matches!(actual_udt, "json" | "jsonb"),
         Type::DateTimeNaive => matches!(actual_udt, "timestamp" | "date"),
         Type::DateTimeUtc => actual_udt == "timestamptz",
+        // Stored in text form to keep the zone name. On read, ``timestamptz``
+        // is accepted as well and gets the UTC zone.
+        Type::DateTimeZoned => actual_udt == "text" || (is_read && actual_udt == "timestamptz"),
         // ``time`` / ``timetz`` both reach the reader via a dedicated
         // binary branch. The writer only encodes INTERVAL and TIME —
         // TIMETZ would need a session-offset parameter we don't carry
@@ -4207,8 +4247,12 @@ impl PsqlReader {
             Type::PyObjectWrapper => Self::precise_py_object_wrapper(&value),
             Type::Duration => Self::precise_duration(value),
             Type::Pointer => Self::precise_pointer(&value),
+            Type::DateTimeZoned => Self::precise_date_time_zoned(&value),
             Type::List(element_type) => Self::precise_list(value, element_type),
             Type::Tuple(element_types) => Self::precise_tuple(value, element_types),
+            Type::Struct(_) | Type::Map(_) => {
+                Self::precise_json_object(&value, type_.unoptionalize())
+            }
             Type::Array(ndim, element_type) => {
                 Self::precise_array(value, ndim.as_ref(), element_type)
             }
@@ -4235,6 +4279,26 @@ impl PsqlReader {
             .map_err(|e| e.to_string())
     }
 
+    fn precise_date_time_zoned(value: &Value) -> Result<Value, String> {
+        match value {
+            Value::DateTimeZoned(_) => Ok(value.clone()),
+            Value::DateTimeUtc(dt) => Ok(Value::DateTimeZoned((*dt).into())),
+            Value::String(s) => DateTimeZoned::parse(s)
+                .map(Value::DateTimeZoned)
+                .map_err(|e| e.to_string()),
+            _ => Err(format!("unexpected type for DateTimeZoned: {value:?}")),
+        }
+    }
+
+    fn precise_json_object(value: &Value, type_: &Type) -> Result<Value, String> {
+        match value {
+            Value::Struct(_) | Value::Map(_) => Ok(value.clone()),
+            Value::Json(json) => parse_value_from_json(json, type_)
+                .ok_or_else(|| format!("JSON {json} doesn't match the type {type_}")),
+            _ => Err(format!("unexpected type for {type_}: {value:?}")),
+        }
+    }
+
     fn precise_pointer(value: &Value) -> Result<Value, String> {
         let Value::String(s) = value else {
             return Err(format!("unexpected type for Pointer: {value:?}"));
@@ -4531,3 +4595,60 @@ impl Reader for PsqlReader {
         StorageType::Postgres
     }
 }
+
+impl SqlPollingConnection for PsqlClient {
+    fn quote_identifier(&self, identifier: &str) -> String {
+        quote_identifier(identifier)
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        // Without the cast, the type of the parameter is inferred from the
+        // cursor column, and an `i64` can't be bound to e.g. an `integer`.
+        format!("${index}::bigint")
+    }
+
+    fn fetch_rows(
+        &mut self,
+        query: &str,
+        params: &[i64],
+        fields: &[ValueField],
+    ) -> Result<Vec<Vec<PolledValue>>, ReadError> {
+        let params: Vec<&(dyn ToSql + Sync)> = params
+            .iter()
+            .map(|param| param as &(dyn ToSql + Sync))
+            .collect();
+        let rows = self.query(query, &params)?;
+        Ok(rows
+            .iter()
+            .map(|row| {
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        let parsed_value = row.try_get(index).map_err(|err| {
+                            Box::new(ConversionError::new(
+                                format!("{row:?}"),
+                                field.name.clone(),
+                                field.type_.clone(),
+                                Some(err.to_string()),
+                            ))
+                        });
+                        PsqlReader::precise_parsed_value(field, parsed_value)
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn storage_type(&self) -> StorageType {
+        StorageType::Postgres
+    }
+
+    fn offset_key(&self) -> OffsetKey {
+        OffsetKey::Postgres
+    }
+
+    fn engine_name(&self) -> &'static str {
+        "Postgres"
+    }
+}
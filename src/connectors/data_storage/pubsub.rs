@@ -0,0 +1,408 @@
+use log::{error, warn};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::mem::take;
+use std::time::Duration;
+
+use futures::StreamExt;
+use google_cloud_gax::grpc::Status as GrpcStatus;
+use google_cloud_googleapis::pubsub::v1::PubsubMessage;
+use google_cloud_pubsub::publisher::{Awaiter, Publisher};
+use google_cloud_pubsub::subscription::{MessageStream, Subscription};
+use google_cloud_pubsub::topic::Topic;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use crate::connectors::data_format::FormatterContext;
+use crate::connectors::data_storage::{CommittedFrontier, ReaderContext};
+use crate::connectors::{
+    OffsetKey, OffsetValue, ReadError, ReadResult, Reader, StorageType, WriteError, Writer,
+};
+use crate::engine::Value;
+use crate::persistence::frontier::OffsetAntichain;
+
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum PubSubError {
+    #[error("Pub/Sub request failed: {0}")]
+    Grpc(#[from] GrpcStatus),
+
+    #[error("Subscription '{0}' does not exist")]
+    SubscriptionDoesntExist(String),
+
+    #[error("Topic '{0}' does not exist")]
+    TopicDoesntExist(String),
+}
+
+// While there are no new messages, the committed ones are still acknowledged with
+// this interval, so that their acknowledgement deadline doesn't expire.
+const ACK_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// https://cloud.google.com/pubsub/quotas#resource_limits
+const MAX_ACK_IDS_PER_REQUEST: usize = 2500;
+
+/// Reads the messages from a Google Cloud Pub/Sub subscription with a streaming pull.
+///
+/// A message is acknowledged only once the connector commits it. If the program stops
+/// before that, or if the commit takes longer than the acknowledgement deadline of the
+/// subscription, the message is delivered again.
+#[allow(clippy::module_name_repetitions)]
+pub struct PubSubReader {
+    runtime: TokioRuntime,
+    subscription: Subscription,
+    messages: MessageStream,
+    acks: PendingAcks,
+}
+
+// The messages read by a worker, waiting for the commit to be acknowledged.
+struct PendingAcks {
+    worker_index: usize,
+    total_entries_read: usize,
+    // The acknowledgement ids of the messages read, with the number of entries read
+    // by the worker including the message.
+    pending_ack_ids: VecDeque<(usize, String)>,
+    committed_frontier: CommittedFrontier,
+}
+
+impl PendingAcks {
+    fn new(worker_index: usize) -> Self {
+        Self {
+            worker_index,
+            total_entries_read: 0,
+            pending_ack_ids: VecDeque::new(),
+            committed_frontier: CommittedFrontier::new(),
+        }
+    }
+
+    fn on_message(&mut self, ack_id: String, message: PubsubMessage) -> ReadResult {
+        self.total_entries_read += 1;
+        self.pending_ack_ids
+            .push_back((self.total_entries_read, ack_id));
+        let key = (!message.ordering_key.is_empty()).then(|| message.ordering_key.into_bytes());
+        let context = ReaderContext::from_key_value(key, Some(message.data));
+        let offset = (
+            OffsetKey::PubSub(self.worker_index),
+            OffsetValue::PubSubReadEntriesCount(self.total_entries_read),
+        );
+        ReadResult::Data(context, offset)
+    }
+
+    // Removes the acknowledgement ids of the committed messages from the pending ones and
+    // returns them.
+    fn take_committed_ack_ids(&mut self) -> Vec<String> {
+        let committed_entries = match self
+            .committed_frontier
+            .get_offset(&OffsetKey::PubSub(self.worker_index))
+        {
+            Some(OffsetValue::PubSubReadEntriesCount(committed_entries)) => committed_entries,
+            _ => return Vec::new(),
+        };
+        let n_committed_messages = self
+            .pending_ack_ids
+            .iter()
+            .take_while(|(entry_index, _)| *entry_index <= committed_entries)
+            .count();
+        self.pending_ack_ids
+            .drain(..n_committed_messages)
+            .map(|(_, ack_id)| ack_id)
+            .collect()
+    }
+}
+
+impl PubSubReader {
+    pub fn new(
+        runtime: TokioRuntime,
+        subscription: Subscription,
+        worker_index: usize,
+    ) -> Result<Self, ReadError> {
+        let messages = runtime.block_on(async {
+            if !subscription.exists(None).await? {
+                return Err(PubSubError::SubscriptionDoesntExist(
+                    subscription.fully_qualified_name().to_string(),
+                ));
+            }
+            Ok(subscription.subscribe(None).await?)
+        })?;
+
+        Ok(Self {
+            runtime,
+            subscription,
+            messages,
+            acks: PendingAcks::new(worker_index),
+        })
+    }
+
+    fn acknowledge_committed_messages(&mut self) {
+        let ack_ids = self.acks.take_committed_ack_ids();
+        for chunk in ack_ids.chunks(MAX_ACK_IDS_PER_REQUEST) {
+            if let Err(e) = self.runtime.block_on(self.subscription.ack(chunk.to_vec())) {
+                // The messages are delivered again once their deadline expires
+                error!(
+                    "Failed to acknowledge {} committed messages of the subscription {}: {e}",
+                    chunk.len(),
+                    self.subscription.fully_qualified_name()
+                );
+            }
+        }
+    }
+}
+
+impl Reader for PubSubReader {
+    fn read(&mut self) -> Result<ReadResult, ReadError> {
+        loop {
+            self.acknowledge_committed_messages();
+            let next_message = self.runtime.block_on(tokio::time::timeout(
+                ACK_POLL_INTERVAL,
+                self.messages.next(),
+            ));
+            let message = match next_message {
+                Ok(Some(message)) => message,
+                Ok(None) => return Ok(ReadResult::Finished),
+                Err(_) => continue,
+            };
+
+            let ack_id = message.ack_id().to_string();
+            return Ok(self.acks.on_message(ack_id, message.message));
+        }
+    }
+
+    fn seek(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        // The messages that weren't acknowledged are delivered again by the
+        // subscription, so only the numbering of the entries continues.
+        let offset_value = frontier.get_offset(&OffsetKey::PubSub(self.acks.worker_index));
+        if let Some(offset) = offset_value {
+            if let OffsetValue::PubSubReadEntriesCount(last_run_entries_read) = offset {
+                self.acks.total_entries_read = *last_run_entries_read;
+            } else {
+                error!("Unexpected offset type for Pub/Sub reader: {offset:?}");
+            }
+        }
+        Ok(())
+    }
+
+    fn storage_type(&self) -> StorageType {
+        StorageType::PubSub
+    }
+
+    fn max_allowed_consecutive_errors(&self) -> usize {
+        32
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        format!("PubSub({})", self.subscription.fully_qualified_name()).into()
+    }
+
+    fn committed_frontier(&self) -> Option<CommittedFrontier> {
+        Some(self.acks.committed_frontier.clone())
+    }
+}
+
+/// Publishes the formatted entries to a Google Cloud Pub/Sub topic.
+///
+/// If the key field is given, its value is used as the ordering key of the messages, so
+/// that the messages with the same key are delivered in the order they were published
+/// to the subscriptions with the message ordering enabled.
+#[allow(clippy::module_name_repetitions)]
+pub struct PubSubWriter {
+    runtime: TokioRuntime,
+    publisher: Publisher,
+    topic_name: String,
+    key_field_index: Option<usize>,
+    header_fields: Vec<(String, usize)>,
+    awaiters: Vec<Awaiter>,
+}
+
+impl PubSubWriter {
+    pub fn new(
+        runtime: TokioRuntime,
+        topic: &Topic,
+        key_field_index: Option<usize>,
+        header_fields: Vec<(String, usize)>,
+    ) -> Result<Self, WriteError> {
+        let topic_name = topic.fully_qualified_name().to_string();
+        runtime.block_on(async {
+            if topic.exists(None).await? {
+                Ok(())
+            } else {
+                Err(PubSubError::TopicDoesntExist(topic_name.clone()))
+            }
+        })?;
+
+        Ok(Self {
+            publisher: topic.new_publisher(None),
+            runtime,
+            topic_name,
+            key_field_index,
+            header_fields,
+            awaiters: Vec::new(),
+        })
+    }
+}
+
+// The messages with the payloads of the entry, with the key as the ordering key and the
+// time, the diff and the header fields as the attributes.
+fn format_messages(
+    data: FormatterContext,
+    key_field_index: Option<usize>,
+    header_fields: &[(String, usize)],
+) -> Result<Vec<PubsubMessage>, WriteError> {
+    let ordering_key = key_field_index
+        .map(|index| match &data.values[index] {
+            Value::String(key) => key.to_string(),
+            Value::Bytes(key) => String::from_utf8_lossy(key).into_owned(),
+            key => key.to_string(),
+        })
+        .unwrap_or_default();
+    let attributes = data.construct_string_headers(header_fields);
+
+    let mut messages = Vec::with_capacity(data.payloads.len());
+    for payload in data.payloads {
+        messages.push(PubsubMessage {
+            data: payload.into_raw_bytes()?,
+            attributes: attributes.clone(),
+            ordering_key: ordering_key.clone(),
+            ..PubsubMessage::default()
+        });
+    }
+    Ok(messages)
+}
+
+impl Writer for PubSubWriter {
+    fn write(&mut self, data: FormatterContext) -> Result<(), WriteError> {
+        for message in format_messages(data, self.key_field_index, &self.header_fields)? {
+            let awaiter = self.runtime.block_on(self.publisher.publish(message));
+            self.awaiters.push(awaiter);
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, _forced: bool) -> Result<(), WriteError> {
+        let awaiters = take(&mut self.awaiters);
+        let undelivered_messages = self.runtime.block_on(async {
+            let mut undelivered_messages = 0;
+            for awaiter in awaiters {
+                if let Err(e) = awaiter.get().await {
+                    warn!(
+                        "Failed to publish a message to the topic {}: {e}",
+                        self.topic_name
+                    );
+                    undelivered_messages += 1;
+                }
+            }
+            undelivered_messages
+        });
+        if undelivered_messages == 0 {
+            Ok(())
+        } else {
+            Err(WriteError::SomeItemsNotDelivered(undelivered_messages))
+        }
+    }
+
+    fn name(&self) -> String {
+        format!("PubSub({})", self.topic_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{Key, Timestamp};
+
+    fn message(data: &str, ordering_key: &str) -> PubsubMessage {
+        PubsubMessage {
+            data: data.as_bytes().to_vec(),
+            ordering_key: ordering_key.to_string(),
+            ..PubsubMessage::default()
+        }
+    }
+
+    fn commit(acks: &PendingAcks, worker_index: usize, entries_read: usize) {
+        let mut frontier = OffsetAntichain::new();
+        frontier.advance_offset(
+            OffsetKey::PubSub(worker_index),
+            OffsetValue::PubSubReadEntriesCount(entries_read),
+        );
+        acks.committed_frontier.advance(&frontier);
+    }
+
+    #[test]
+    fn message_is_read_with_its_ordering_key() {
+        let mut acks = PendingAcks::new(3);
+        let entry = acks.on_message("ack 1".to_string(), message("payload", "user-1"));
+        let ReadResult::Data(ReaderContext::KeyValue((key, value)), offset) = entry else {
+            panic!("unexpected entry: {entry:?}");
+        };
+        assert_eq!(key, Some(b"user-1".to_vec()));
+        assert_eq!(value, Some(b"payload".to_vec()));
+        assert_eq!(
+            offset,
+            (OffsetKey::PubSub(3), OffsetValue::PubSubReadEntriesCount(1))
+        );
+
+        // the messages published without an ordering key are read without a key
+        let entry = acks.on_message("ack 2".to_string(), message("payload", ""));
+        let ReadResult::Data(ReaderContext::KeyValue((key, _)), offset) = entry else {
+            panic!("unexpected entry: {entry:?}");
+        };
+        assert_eq!(key, None);
+        assert_eq!(
+            offset,
+            (OffsetKey::PubSub(3), OffsetValue::PubSubReadEntriesCount(2))
+        );
+    }
+
+    #[test]
+    fn messages_are_acknowledged_once_committed() {
+        let mut acks = PendingAcks::new(0);
+        for index in 1..=3 {
+            acks.on_message(format!("ack {index}"), message("payload", ""));
+        }
+        assert!(acks.take_committed_ack_ids().is_empty());
+
+        // the commits of the other workers don't acknowledge the messages of this one
+        commit(&acks, 1, 3);
+        assert!(acks.take_committed_ack_ids().is_empty());
+
+        commit(&acks, 0, 2);
+        assert_eq!(acks.take_committed_ack_ids(), vec!["ack 1", "ack 2"]);
+        // a message is acknowledged once
+        assert!(acks.take_committed_ack_ids().is_empty());
+
+        commit(&acks, 0, 3);
+        assert_eq!(acks.take_committed_ack_ids(), vec!["ack 3"]);
+    }
+
+    #[test]
+    fn entries_are_published_with_the_key_and_the_attributes() -> Result<(), WriteError> {
+        let values = vec![Value::from("user-1"), Value::Int(42)];
+        let data = FormatterContext::new(
+            vec![b"first".to_vec(), b"second".to_vec()],
+            Key::for_value(&values[0]),
+            values,
+            Timestamp(10),
+            -1,
+        );
+        let messages = format_messages(data, Some(0), &[("amount".to_string(), 1)])?;
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].data, b"first");
+        assert_eq!(messages[1].data, b"second");
+        for message in &messages {
+            assert_eq!(message.ordering_key, "user-1");
+            assert_eq!(message.attributes["pathway_time"], "10");
+            assert_eq!(message.attributes["pathway_diff"], "-1");
+            assert_eq!(message.attributes["amount"], "42");
+        }
+
+        // without the key field, the messages have no ordering key
+        let data = FormatterContext::new_single_payload(
+            b"payload".to_vec(),
+            Key::for_value(&Value::Int(1)),
+            vec![Value::Int(1)],
+            Timestamp(10),
+            1,
+        );
+        let messages = format_messages(data, None, &[])?;
+        assert_eq!(messages[0].ordering_key, "");
+        Ok(())
+    }
+}
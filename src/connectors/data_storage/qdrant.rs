@@ -12,11 +12,10 @@ use serde_json::{Map as JsonMap, Value as JsonValue};
 use tokio::runtime::Runtime as TokioRuntime;
 use uuid::Uuid;
 
-use crate::connectors::data_format::{serialize_value_to_json, FormatterContext};
+use crate::connectors::data_format::{serialize_value_to_json, FormatterContext, ValueField};
 use crate::connectors::data_storage::vectors::{vector_kind_of, VectorKind};
 use crate::connectors::{WriteError, Writer};
 use crate::engine::{Key, Value};
-use crate::python_api::ValueField;
 
 /// Errors specific to the Qdrant output connector. Surfaced to the engine
 /// through the single transparent [`WriteError::Qdrant`] variant, so `?` works
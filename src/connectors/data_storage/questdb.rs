@@ -91,6 +91,7 @@ impl QuestDBWriter {
                 column_name,
                 QuestDBTimestamp::Nanos(QuestDBTimestampNanos::new(dt.timestamp())),
             )?,
+            Value::DateTimeZoned(dt) => buffer.column_str(column_name, dt.to_string())?,
             Value::Duration(d) => buffer.column_i64(column_name, d.nanoseconds())?,
             Value::Json(j) => buffer.column_str(column_name, j.to_string())?,
             Value::PyObjectWrapper(_) => {
@@ -101,7 +102,11 @@ impl QuestDBWriter {
                 let encoded = base64::engine::general_purpose::STANDARD.encode(b);
                 buffer.column_str(column_name, encoded)?
             }
-            Value::IntArray(_) | Value::FloatArray(_) | Value::Tuple(_) => {
+            Value::IntArray(_)
+            | Value::FloatArray(_)
+            | Value::Tuple(_)
+            | Value::Struct(_)
+            | Value::Map(_) => {
                 let json_value = serialize_value_to_json(&value)?;
                 buffer.column_str(column_name, json_value.to_string())?
             }
@@ -0,0 +1,535 @@
+use log::{error, info};
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::mem::take;
+use std::str::FromStr;
+use std::time::Duration;
+
+use redis::streams::{StreamId, StreamReadOptions, StreamReadReply};
+use redis::{Commands, Connection, RedisError as RedisClientError};
+
+use crate::connectors::data_format::{serialize_value_to_json, FormatterContext, ValueField};
+use crate::connectors::data_storage::{CommittedFrontier, ReaderContext};
+use crate::connectors::{
+    OffsetKey, OffsetValue, ReadError, ReadResult, Reader, StorageType, WriteError, Writer,
+};
+use crate::engine::Value;
+use crate::persistence::frontier::OffsetAntichain;
+
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum RedisError {
+    #[error(transparent)]
+    Client(#[from] RedisClientError),
+
+    #[error("malformed stream entry id: '{0}'")]
+    MalformedEntryId(String),
+
+    #[error("the stream entry '{entry_id}' has no field '{field}'")]
+    MissingField { entry_id: String, field: String },
+}
+
+// While there are no new entries, the committed ones are still acknowledged with
+// this interval.
+const BLOCK_DURATION: Duration = Duration::from_secs(1);
+const MAX_ENTRIES_PER_READ: usize = 1024;
+
+// The id of a freshly created group makes it deliver the whole stream.
+const NEW_GROUP_START_ID: &str = "0";
+
+// The special id of XREADGROUP, which requests the entries never delivered to the group.
+const UNDELIVERED_ENTRIES_ID: &str = ">";
+
+/// The id of a stream entry: the milliseconds time part and the sequence number part.
+/// The ids of the entries of a stream are increasing in this order.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct StreamEntryId {
+    pub milliseconds_time: u64,
+    pub sequence_number: u64,
+}
+
+impl FromStr for StreamEntryId {
+    type Err = RedisError;
+
+    fn from_str(entry_id: &str) -> Result<Self, Self::Err> {
+        let malformed = || RedisError::MalformedEntryId(entry_id.to_string());
+        let (milliseconds_time, sequence_number) =
+            entry_id.split_once('-').ok_or_else(malformed)?;
+        Ok(Self {
+            milliseconds_time: milliseconds_time.parse().map_err(|_| malformed())?,
+            sequence_number: sequence_number.parse().map_err(|_| malformed())?,
+        })
+    }
+}
+
+impl fmt::Display for StreamEntryId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.milliseconds_time, self.sequence_number)
+    }
+}
+
+impl From<StreamEntryId> for OffsetValue {
+    fn from(entry_id: StreamEntryId) -> Self {
+        OffsetValue::RedisStreamEntryId {
+            milliseconds_time: entry_id.milliseconds_time,
+            sequence_number: entry_id.sequence_number,
+        }
+    }
+}
+
+/// Reads the entries of a Redis stream as a member of a consumer group.
+///
+/// Each worker is a separate consumer of the group, so the entries are distributed
+/// between the workers by Redis. An entry is acknowledged with `XACK` once the connector
+/// commits it. The entries delivered to the consumer but not acknowledged, for example
+/// because the program stopped before the commit, stay in its pending entries list and
+/// are read again first on restart. If persistence is enabled, the id of the last
+/// committed entry is stored in the frontier, and the pending entries up to it are only
+/// acknowledged, not read again.
+#[allow(clippy::module_name_repetitions)]
+pub struct RedisStreamReader {
+    connection: Connection,
+    stream_key: String,
+    group_name: String,
+    consumer_name: String,
+    worker_index: usize,
+    data_field: String,
+
+    // The pending entries list of the consumer is read after this id, until it's
+    // exhausted. Then only the entries not yet delivered to the group are read.
+    pending_entries_cursor: StreamEntryId,
+    reading_pending_entries: bool,
+    last_persisted_entry_id: Option<StreamEntryId>,
+
+    entries_read: VecDeque<Result<ReadResult, ReadError>>,
+    unacknowledged_entry_ids: VecDeque<StreamEntryId>,
+    committed_frontier: CommittedFrontier,
+}
+
+impl RedisStreamReader {
+    pub fn new(
+        mut connection: Connection,
+        stream_key: String,
+        group_name: String,
+        consumer_name_prefix: &str,
+        worker_index: usize,
+        data_field: String,
+    ) -> Result<Self, ReadError> {
+        let group_creation: Result<(), RedisClientError> =
+            connection.xgroup_create_mkstream(&stream_key, &group_name, NEW_GROUP_START_ID);
+        match group_creation {
+            Ok(()) => {
+                info!("Created the consumer group '{group_name}' of the stream '{stream_key}'");
+            }
+            Err(e) if e.code() == Some("BUSYGROUP") => {}
+            Err(e) => return Err(RedisError::from(e).into()),
+        }
+
+        Ok(Self {
+            connection,
+            stream_key,
+            group_name,
+            consumer_name: format!("{consumer_name_prefix}-{worker_index}"),
+            worker_index,
+            data_field,
+            pending_entries_cursor: StreamEntryId::default(),
+            reading_pending_entries: true,
+            last_persisted_entry_id: None,
+            entries_read: VecDeque::new(),
+            unacknowledged_entry_ids: VecDeque::new(),
+            committed_frontier: CommittedFrontier::new(),
+        })
+    }
+
+    fn offset_key(&self) -> OffsetKey {
+        OffsetKey::RedisStream(self.worker_index)
+    }
+
+    fn acknowledge(&mut self, entry_ids: &[StreamEntryId]) -> Result<(), RedisError> {
+        if entry_ids.is_empty() {
+            return Ok(());
+        }
+        let entry_ids: Vec<_> = entry_ids.iter().map(ToString::to_string).collect();
+        let _: usize = self
+            .connection
+            .xack(&self.stream_key, &self.group_name, &entry_ids)?;
+        Ok(())
+    }
+
+    fn acknowledge_committed_entries(&mut self) -> Result<(), RedisError> {
+        let Some(OffsetValue::RedisStreamEntryId {
+            milliseconds_time,
+            sequence_number,
+        }) = self.committed_frontier.get_offset(&self.offset_key())
+        else {
+            return Ok(());
+        };
+        let committed_entry_id = StreamEntryId {
+            milliseconds_time,
+            sequence_number,
+        };
+        let n_committed_entries = self
+            .unacknowledged_entry_ids
+            .iter()
+            .take_while(|entry_id| **entry_id <= committed_entry_id)
+            .count();
+        let committed_entry_ids: Vec<_> = self
+            .unacknowledged_entry_ids
+            .drain(..n_committed_entries)
+            .collect();
+        self.acknowledge(&committed_entry_ids)
+    }
+
+    fn parse_entry(
+        &self,
+        entry_id: StreamEntryId,
+        entry: &StreamId,
+    ) -> Result<ReadResult, ReadError> {
+        let payload: Vec<u8> =
+            entry
+                .get(&self.data_field)
+                .ok_or_else(|| RedisError::MissingField {
+                    entry_id: entry.id.clone(),
+                    field: self.data_field.clone(),
+                })?;
+        let context = ReaderContext::from_key_value(None, Some(payload));
+        Ok(ReadResult::Data(
+            context,
+            (self.offset_key(), entry_id.into()),
+        ))
+    }
+
+    fn read_entries(&mut self) -> Result<(), RedisError> {
+        let mut options = StreamReadOptions::default()
+            .group(&self.group_name, &self.consumer_name)
+            .count(MAX_ENTRIES_PER_READ);
+        let start_id = if self.reading_pending_entries {
+            self.pending_entries_cursor.to_string()
+        } else {
+            options = options.block(
+                BLOCK_DURATION
+                    .as_millis()
+                    .try_into()
+                    .expect("block duration must fit usize"),
+            );
+            UNDELIVERED_ENTRIES_ID.to_string()
+        };
+        let reply: Option<StreamReadReply> =
+            self.connection
+                .xread_options(&[&self.stream_key], &[start_id], &options)?;
+        let entries: Vec<StreamId> = reply
+            .into_iter()
+            .flat_map(|reply| reply.keys)
+            .flat_map(|stream| stream.ids)
+            .collect();
+
+        if self.reading_pending_entries && entries.is_empty() {
+            self.reading_pending_entries = false;
+            return Ok(());
+        }
+
+        let mut already_committed_entry_ids = Vec::new();
+        for entry in entries {
+            let entry_id: StreamEntryId = entry.id.parse()?;
+            if self.reading_pending_entries {
+                self.pending_entries_cursor = entry_id;
+                if self
+                    .last_persisted_entry_id
+                    .is_some_and(|last_persisted_entry_id| entry_id <= last_persisted_entry_id)
+                {
+                    // The entry was committed in the previous run, which stopped before
+                    // acknowledging it.
+                    already_committed_entry_ids.push(entry_id);
+                    continue;
+                }
+            }
+            // The entries that can't be parsed are acknowledged along with the next ones
+            self.unacknowledged_entry_ids.push_back(entry_id);
+            let entry = self.parse_entry(entry_id, &entry);
+            self.entries_read.push_back(entry);
+        }
+        self.acknowledge(&already_committed_entry_ids)
+    }
+}
+
+impl Reader for RedisStreamReader {
+    fn read(&mut self) -> Result<ReadResult, ReadError> {
+        loop {
+            if let Some(entry) = self.entries_read.pop_front() {
+                return entry;
+            }
+            self.acknowledge_committed_entries()?;
+            self.read_entries()?;
+        }
+    }
+
+    fn seek(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        let offset_value = frontier.get_offset(&self.offset_key());
+        if let Some(offset) = offset_value {
+            if let OffsetValue::RedisStreamEntryId {
+                milliseconds_time,
+                sequence_number,
+            } = offset
+            {
+                self.last_persisted_entry_id = Some(StreamEntryId {
+                    milliseconds_time: *milliseconds_time,
+                    sequence_number: *sequence_number,
+                });
+            } else {
+                error!("Unexpected offset type for Redis stream reader: {offset:?}");
+            }
+        }
+        self.pending_entries_cursor = StreamEntryId::default();
+        self.reading_pending_entries = true;
+        self.entries_read.clear();
+        self.unacknowledged_entry_ids.clear();
+        Ok(())
+    }
+
+    fn storage_type(&self) -> StorageType {
+        StorageType::RedisStream
+    }
+
+    fn max_allowed_consecutive_errors(&self) -> usize {
+        32
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        format!("RedisStream({})", self.stream_key).into()
+    }
+
+    fn committed_frontier(&self) -> Option<CommittedFrontier> {
+        Some(self.committed_frontier.clone())
+    }
+}
+
+/// How the rows are stored under their keys.
+#[derive(Clone, Debug)]
+pub enum RedisValueLayout {
+    /// Each row is a hash, whose fields are the non-null values of the given fields of
+    /// the row.
+    Hash(Vec<ValueField>),
+
+    /// Each row is a string, which is the formatted payload of the row.
+    String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum PendingOperation {
+    SetHash(Vec<(String, Vec<u8>)>),
+    SetString(Vec<u8>),
+    Delete,
+}
+
+// The changes of the keys in a minibatch, at most one per key. They are only sent once
+// the whole minibatch is known: a key may move between the rows, in which case its
+// deletion may come after the insertion of the row taking it over, and applying them
+// separately would delete that row.
+#[derive(Default)]
+struct PendingOperations(HashMap<Vec<u8>, PendingOperation>);
+
+impl PendingOperations {
+    fn upsert(&mut self, redis_key: Vec<u8>, operation: PendingOperation) {
+        self.0.insert(redis_key, operation);
+    }
+
+    // The insertion wins over the deletion regardless of the order, since together they
+    // are the update of the row under the key.
+    fn delete(&mut self, redis_key: Vec<u8>) {
+        self.0.entry(redis_key).or_insert(PendingOperation::Delete);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn take(&mut self) -> HashMap<Vec<u8>, PendingOperation> {
+        take(&mut self.0)
+    }
+}
+
+/// Maintains the rows of a table as Redis keys, so that Redis can serve its current
+/// state.
+///
+/// A row is stored under the key made of the prefix and the value of the key field. An
+/// insertion replaces the value under the key, and a deletion removes the key. If a key
+/// is both deleted and inserted within a minibatch, the insertion wins regardless of the
+/// order, since it's the update of the row. The changes of a minibatch are buffered and
+/// sent in a single transaction once it ends, so that a replaced hash is never seen
+/// partially updated and a key moved between the rows is never seen deleted.
+#[allow(clippy::module_name_repetitions)]
+pub struct RedisKeyValueWriter {
+    connection: Connection,
+    key_prefix: String,
+    key_field_index: usize,
+    layout: RedisValueLayout,
+    ttl: Option<Duration>,
+    pending_operations: PendingOperations,
+}
+
+impl RedisKeyValueWriter {
+    pub fn new(
+        connection: Connection,
+        key_prefix: String,
+        key_field_index: usize,
+        layout: RedisValueLayout,
+        ttl: Option<Duration>,
+    ) -> Self {
+        Self {
+            connection,
+            key_prefix,
+            key_field_index,
+            layout,
+            ttl,
+            pending_operations: PendingOperations::default(),
+        }
+    }
+
+    fn redis_key(&self, data: &FormatterContext) -> Vec<u8> {
+        let mut redis_key = self.key_prefix.as_bytes().to_vec();
+        match &data.values[self.key_field_index] {
+            Value::String(key) => redis_key.extend_from_slice(key.as_bytes()),
+            Value::Bytes(key) => redis_key.extend_from_slice(key),
+            key => redis_key.extend_from_slice(key.to_string().as_bytes()),
+        }
+        redis_key
+    }
+
+    fn upsert_operation(&self, data: FormatterContext) -> Result<PendingOperation, WriteError> {
+        match &self.layout {
+            RedisValueLayout::Hash(value_fields) => {
+                let mut hash_fields = Vec::with_capacity(value_fields.len());
+                for (field, value) in value_fields.iter().zip(&data.values) {
+                    let value = match value {
+                        Value::None => continue,
+                        Value::String(value) => value.as_bytes().to_vec(),
+                        Value::Bytes(value) => value.to_vec(),
+                        value => serialize_value_to_json(value)?.to_string().into_bytes(),
+                    };
+                    hash_fields.push((field.name.clone(), value));
+                }
+                Ok(PendingOperation::SetHash(hash_fields))
+            }
+            RedisValueLayout::String => {
+                let payload = data
+                    .payloads
+                    .into_iter()
+                    .next()
+                    .expect("the formatter must produce a payload for each row");
+                Ok(PendingOperation::SetString(payload.into_raw_bytes()?))
+            }
+        }
+    }
+}
+
+impl Writer for RedisKeyValueWriter {
+    fn write(&mut self, data: FormatterContext) -> Result<(), WriteError> {
+        let redis_key = self.redis_key(&data);
+        match data.diff {
+            1 => {
+                let operation = self.upsert_operation(data)?;
+                self.pending_operations.upsert(redis_key, operation);
+            }
+            -1 => self.pending_operations.delete(redis_key),
+            _ => unreachable!("diff can only be 1 or -1"),
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, _forced: bool) -> Result<(), WriteError> {
+        if self.pending_operations.is_empty() {
+            return Ok(());
+        }
+        let ttl_ms = self
+            .ttl
+            .map(|ttl| u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX));
+
+        let mut pipeline = redis::pipe();
+        pipeline.atomic();
+        for (redis_key, operation) in self.pending_operations.take() {
+            match operation {
+                PendingOperation::Delete => {
+                    pipeline.del(&redis_key).ignore();
+                }
+                PendingOperation::SetHash(hash_fields) => {
+                    // The fields that became null must not be left from the former row
+                    pipeline.del(&redis_key).ignore();
+                    if !hash_fields.is_empty() {
+                        pipeline.hset_multiple(&redis_key, &hash_fields).ignore();
+                        if let Some(ttl_ms) = ttl_ms {
+                            pipeline
+                                .pexpire(&redis_key, i64::try_from(ttl_ms).unwrap_or(i64::MAX))
+                                .ignore();
+                        }
+                    }
+                }
+                PendingOperation::SetString(value) => {
+                    if let Some(ttl_ms) = ttl_ms {
+                        pipeline.pset_ex(&redis_key, value, ttl_ms).ignore();
+                    } else {
+                        pipeline.set(&redis_key, value).ignore();
+                    }
+                }
+            }
+        }
+        pipeline
+            .query::<()>(&mut self.connection)
+            .map_err(RedisError::from)?;
+        Ok(())
+    }
+
+    fn name(&self) -> String {
+        format!("Redis({})", self.key_prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(value: &str) -> PendingOperation {
+        PendingOperation::SetString(value.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn key_moved_between_rows_is_not_deleted() {
+        // the row of `alice` is renamed to `bob`, and another row takes over `alice`, with
+        // many other changes in the same minibatch between the insertion and the deletion
+        let mut operations = PendingOperations::default();
+        operations.upsert(b"alice".to_vec(), set("new row"));
+        operations.upsert(b"bob".to_vec(), set("renamed row"));
+        for index in 0..5000 {
+            operations.upsert(format!("other:{index}").into_bytes(), set("other row"));
+        }
+        operations.delete(b"alice".to_vec());
+
+        let operations = operations.take();
+        assert_eq!(operations.len(), 5002);
+        assert_eq!(operations[b"alice".as_slice()], set("new row"));
+        assert_eq!(operations[b"bob".as_slice()], set("renamed row"));
+    }
+
+    #[test]
+    fn insertion_wins_over_deletion_in_any_order() {
+        let mut operations = PendingOperations::default();
+        operations.delete(b"first".to_vec());
+        operations.upsert(b"first".to_vec(), set("first row"));
+        operations.upsert(b"second".to_vec(), set("second row"));
+        operations.delete(b"second".to_vec());
+        operations.delete(b"third".to_vec());
+
+        let operations = operations.take();
+        assert_eq!(operations[b"first".as_slice()], set("first row"));
+        assert_eq!(operations[b"second".as_slice()], set("second row"));
+        assert_eq!(operations[b"third".as_slice()], PendingOperation::Delete);
+    }
+
+    #[test]
+    fn operations_are_sent_once() {
+        let mut operations = PendingOperations::default();
+        operations.upsert(b"key".to_vec(), set("row"));
+        assert_eq!(operations.take().len(), 1);
+        assert!(operations.is_empty());
+    }
+}
@@ -0,0 +1,405 @@
+// Copyright © 2026 Pathway
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::env;
+use std::str::from_utf8;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+use azure_core::auth::{AccessToken, TokenCredential};
+use azure_core::error::{Error as AzureError, ErrorKind as AzureErrorKind};
+use azure_core::{Result as AzureResult, StatusCode};
+use azure_core_eventhubs::credentials::{
+    Secret as IdentitySecret, TokenCredential as IdentityTokenCredential,
+};
+use azure_identity::{
+    AzureCliCredential, ClientSecretCredential, ManagedIdentityCredential,
+    WorkloadIdentityCredential,
+};
+use azure_storage::Error as AzureStorageError;
+use azure_storage_blobs::prelude::ContainerClient;
+use futures::stream::{self, StreamExt};
+use glob::Pattern as GlobPattern;
+use log::{info, warn};
+use tokio::runtime::Runtime as TokioRuntime;
+
+use crate::async_runtime::create_async_tokio_runtime;
+use crate::connectors::data_storage::scanner::{PosixLikeScanner, QueuedAction};
+use crate::connectors::metadata::FileLikeMetadata;
+use crate::connectors::ReadError;
+use crate::persistence::cached_object_storage::CachedObjectStorage;
+
+const MAX_OBJECTS_IN_BULK_DOWNLOAD: usize = 20_000;
+const MAX_BYTES_IN_BULK_DOWNLOAD: u64 = 500_000_000;
+
+// Set by ADLS Gen2 on the placeholder blobs standing for the directories
+const DIRECTORY_METADATA_KEY: &str = "hdi_isfolder";
+
+#[allow(clippy::module_name_repetitions)]
+pub struct AzureBlobScanner {
+    /*
+        Lists the blobs under the given prefix and downloads the new and the
+        changed ones in bulks, in the same way as `S3Scanner` does.
+    */
+    container_client: ContainerClient,
+    objects_prefix: String,
+    object_pattern: GlobPattern,
+    only_provide_metadata: bool,
+
+    // Ordered so that the blob modified the earliest is at the end, where
+    // the tasks are taken from.
+    pending_modification_download_tasks: Vec<FileLikeMetadata>,
+    pending_modifications: HashMap<String, Vec<u8>>,
+    downloader_concurrency: usize,
+    runtime: TokioRuntime,
+}
+
+impl PosixLikeScanner for AzureBlobScanner {
+    fn object_metadata(
+        &mut self,
+        object_path: &[u8],
+    ) -> Result<Option<FileLikeMetadata>, ReadError> {
+        let path = from_utf8(object_path).expect("blob names are expected to be UTF-8 strings");
+        let blob_client = self.container_client.blob_client(path);
+        match self.runtime.block_on(blob_client.get_properties()) {
+            Ok(response) => Ok(Some(FileLikeMetadata::from_azure_blob(&response.blob))),
+            Err(e) if Self::is_not_found(&e) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn read_object(&mut self, object_path: &[u8]) -> Result<Vec<u8>, ReadError> {
+        let path = from_utf8(object_path).expect("blob names are expected to be UTF-8 strings");
+        if let Some(prepared_object) = self.pending_modifications.remove(path) {
+            Ok(prepared_object)
+        } else {
+            let blob_client = self.container_client.blob_client(path);
+            Ok(self.runtime.block_on(blob_client.get_content())?)
+        }
+    }
+
+    fn next_scanner_actions(
+        &mut self,
+        are_deletions_enabled: bool,
+        cached_object_storage: &CachedObjectStorage,
+    ) -> Result<Vec<QueuedAction>, ReadError> {
+        let mut result = Vec::new();
+        if self.pending_modification_download_tasks.is_empty() {
+            let listing = self.runtime.block_on(self.list_objects())?;
+            let (tasks, mut deletions) =
+                Self::plan_listing(listing, are_deletions_enabled, cached_object_storage);
+            info!(
+                "New pending download tasks have been built: {}",
+                tasks.len()
+            );
+            self.pending_modification_download_tasks = tasks.into_iter().rev().collect();
+            result.append(&mut deletions);
+        }
+
+        // As in `S3Scanner`, the listing already carries the full metadata, so
+        // the contents aren't downloaded when only the metadata is requested.
+        if self.only_provide_metadata {
+            while let Some(task) = self.pending_modification_download_tasks.pop() {
+                result.push(Self::action_for_task(task, cached_object_storage));
+            }
+            return Ok(result);
+        }
+
+        let mut bulk_for_download = Vec::new();
+        let mut total_bulk_size = 0;
+        while let Some(pending_task) = self.pending_modification_download_tasks.pop_if(|task| {
+            (bulk_for_download.len() < MAX_OBJECTS_IN_BULK_DOWNLOAD
+                && total_bulk_size + task.size <= MAX_BYTES_IN_BULK_DOWNLOAD)
+                || bulk_for_download.is_empty()
+        }) {
+            total_bulk_size += pending_task.size;
+            bulk_for_download.push(pending_task);
+        }
+
+        for (task, contents) in self.download_bulk(bulk_for_download) {
+            match contents {
+                Ok(contents) => {
+                    self.pending_modifications
+                        .insert(task.path.clone(), contents);
+                    result.push(Self::action_for_task(task, cached_object_storage));
+                }
+                Err(e) => {
+                    warn!("Failed to fetch the modified version of the blob {}: {e}. It will be retried with the next bulk of updates.", task.path);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn has_pending_actions(&self) -> bool {
+        !self.pending_modification_download_tasks.is_empty()
+    }
+
+    fn short_description(&self) -> String {
+        format!(
+            "AzureBlob({}/{})",
+            self.container_client.container_name(),
+            self.objects_prefix
+        )
+    }
+}
+
+impl AzureBlobScanner {
+    pub fn new(
+        container_client: ContainerClient,
+        objects_prefix: impl Into<String>,
+        object_pattern: impl Into<String>,
+        downloader_threads_count: usize,
+        is_polling_enabled: bool,
+        only_provide_metadata: bool,
+    ) -> Result<Self, ReadError> {
+        let objects_prefix = objects_prefix.into();
+        let object_pattern = object_pattern.into();
+        let runtime = create_async_tokio_runtime()?;
+
+        let has_objects = runtime.block_on(async {
+            let mut pages = container_client
+                .list_blobs()
+                .prefix(objects_prefix.clone())
+                .into_stream();
+            match pages.next().await {
+                Some(page) => Ok::<_, AzureStorageError>(page?.blobs.blobs().next().is_some()),
+                None => Ok(false),
+            }
+        })?;
+        if !has_objects {
+            if !is_polling_enabled {
+                return Err(ReadError::NoObjectsToRead);
+            }
+            warn!("No blobs found under the path prefix {objects_prefix}");
+        }
+
+        Ok(Self {
+            container_client,
+            objects_prefix,
+            object_pattern: GlobPattern::new(&object_pattern)?,
+            only_provide_metadata,
+            pending_modification_download_tasks: Vec::new(),
+            pending_modifications: HashMap::new(),
+            downloader_concurrency: downloader_threads_count.max(1),
+            runtime,
+        })
+    }
+
+    /// Splits a listing of the blobs into the blobs to be downloaded and the
+    /// deletions to be reported.
+    ///
+    /// The blobs not read yet, and, when deletions are enabled, the changed
+    /// ones, are returned in the order of their modification time. The blobs
+    /// present in `cached_object_storage` but absent from the listing are
+    /// reported as deleted if deletions are enabled.
+    pub fn plan_listing(
+        listing: Vec<FileLikeMetadata>,
+        are_deletions_enabled: bool,
+        cached_object_storage: &CachedObjectStorage,
+    ) -> (Vec<FileLikeMetadata>, Vec<QueuedAction>) {
+        let mut seen_object_keys = HashSet::with_capacity(listing.len());
+        let mut tasks = Vec::new();
+        for metadata in listing {
+            seen_object_keys.insert(metadata.path.clone());
+            let needs_download = match cached_object_storage.stored_tag(metadata.path.as_bytes()) {
+                Some(stored_tag) => {
+                    are_deletions_enabled && cached_object_storage.is_changed(stored_tag, &metadata)
+                }
+                None => true,
+            };
+            if needs_download {
+                tasks.push(metadata);
+            }
+        }
+        tasks.sort_by(|lhs, rhs| {
+            lhs.modified_at
+                .cmp(&rhs.modified_at)
+                .then_with(|| lhs.path.cmp(&rhs.path))
+        });
+
+        let mut deletions = Vec::new();
+        if are_deletions_enabled {
+            for (object_path, _) in cached_object_storage.get_iter() {
+                let object_path =
+                    from_utf8(object_path).expect("blob names must be UTF8-compatible");
+                if !seen_object_keys.contains(object_path) {
+                    deletions.push(QueuedAction::Delete(object_path.as_bytes().into()));
+                }
+            }
+        }
+        (tasks, deletions)
+    }
+
+    fn action_for_task(
+        task: FileLikeMetadata,
+        cached_object_storage: &CachedObjectStorage,
+    ) -> QueuedAction {
+        let object_key = task.path.as_bytes().to_vec();
+        if cached_object_storage.contains_object(&object_key) {
+            QueuedAction::Update(object_key, task)
+        } else {
+            QueuedAction::Read(object_key, task)
+        }
+    }
+
+    // Walks the virtual directories one level at a time, which also works for
+    // the accounts with the hierarchical namespace. The pages of each level are
+    // requested with the continuation token returned with the previous page.
+    async fn list_objects(&self) -> Result<Vec<FileLikeMetadata>, AzureStorageError> {
+        let mut result = Vec::new();
+        let mut pending_prefixes = vec![self.objects_prefix.clone()];
+        while let Some(prefix) = pending_prefixes.pop() {
+            let mut pages = self
+                .container_client
+                .list_blobs()
+                .prefix(prefix)
+                .delimiter("/")
+                .include_metadata(true)
+                .into_stream();
+            while let Some(page) = pages.next().await {
+                let page = page?;
+                for blob_prefix in page.blobs.prefixes() {
+                    pending_prefixes.push(blob_prefix.name.clone());
+                }
+                for blob in page.blobs.blobs() {
+                    let is_directory = blob.metadata.as_ref().is_some_and(|metadata| {
+                        metadata
+                            .get(DIRECTORY_METADATA_KEY)
+                            .is_some_and(|value| value == "true")
+                    });
+                    if blob.deleted.unwrap_or(false)
+                        || is_directory
+                        || !self.object_pattern.matches(&blob.name)
+                    {
+                        continue;
+                    }
+                    result.push(FileLikeMetadata::from_azure_blob(blob));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn download_bulk(
+        &self,
+        tasks: Vec<FileLikeMetadata>,
+    ) -> Vec<(FileLikeMetadata, Result<Vec<u8>, AzureStorageError>)> {
+        if tasks.is_empty() {
+            return Vec::with_capacity(0);
+        }
+        info!(
+            "Downloading a bulk of {} blobs. {} are still in the queue.",
+            tasks.len(),
+            self.pending_modification_download_tasks.len()
+        );
+        let downloading_started_at = SystemTime::now();
+        let container_client = &self.container_client;
+        let downloaded = self.runtime.block_on(
+            stream::iter(tasks)
+                .map(|task| async move {
+                    let contents = container_client.blob_client(&task.path).get_content().await;
+                    (task, contents)
+                })
+                .buffered(self.downloader_concurrency)
+                .collect(),
+        );
+        info!("Downloading done in {:?}", downloading_started_at.elapsed());
+        downloaded
+    }
+
+    fn is_not_found(error: &AzureStorageError) -> bool {
+        error
+            .as_http_error()
+            .is_some_and(|e| e.status() == StatusCode::NotFound)
+    }
+}
+
+/// The identity of the environment, for the storage clients authenticating without
+/// a key: a service principal given by `AZURE_TENANT_ID`, `AZURE_CLIENT_ID` and
+/// `AZURE_CLIENT_SECRET`, a workload identity, a managed identity, or the Azure CLI
+/// login, tried in this order.
+///
+/// The tokens are obtained with `azure_identity`, which is built on a newer
+/// `azure_core` than the storage crates, and handed to them through the
+/// `TokenCredential` of their version. The first source that provides a token is
+/// used from then on.
+#[derive(Debug)]
+pub struct AzureIdentityCredential {
+    sources: Vec<Arc<dyn IdentityTokenCredential>>,
+    used_source: Mutex<Option<usize>>,
+}
+
+impl AzureIdentityCredential {
+    pub fn new() -> AzureResult<Self> {
+        let mut sources: Vec<Arc<dyn IdentityTokenCredential>> = Vec::new();
+        if let (Ok(tenant_id), Ok(client_id), Ok(client_secret)) = (
+            env::var("AZURE_TENANT_ID"),
+            env::var("AZURE_CLIENT_ID"),
+            env::var("AZURE_CLIENT_SECRET"),
+        ) {
+            let credential = ClientSecretCredential::new(
+                &tenant_id,
+                client_id,
+                IdentitySecret::new(client_secret),
+                None,
+            )
+            .map_err(|e| AzureError::new(AzureErrorKind::Credential, e))?;
+            sources.push(credential);
+        }
+        // Fails when the environment of a workload identity isn't set up
+        if let Ok(credential) = WorkloadIdentityCredential::new(None) {
+            sources.push(credential);
+        }
+        sources.push(
+            ManagedIdentityCredential::new(None)
+                .map_err(|e| AzureError::new(AzureErrorKind::Credential, e))?,
+        );
+        sources.push(
+            AzureCliCredential::new(None)
+                .map_err(|e| AzureError::new(AzureErrorKind::Credential, e))?,
+        );
+        Ok(Self {
+            sources,
+            used_source: Mutex::new(None),
+        })
+    }
+}
+
+#[async_trait]
+impl TokenCredential for AzureIdentityCredential {
+    async fn get_token(&self, scopes: &[&str]) -> AzureResult<AccessToken> {
+        let used_source = *self.used_source.lock().unwrap();
+        let candidates: Vec<usize> = match used_source {
+            Some(index) => vec![index],
+            None => (0..self.sources.len()).collect(),
+        };
+        let mut errors = Vec::new();
+        for index in candidates {
+            match self.sources[index].get_token(scopes, None).await {
+                Ok(token) => {
+                    *self.used_source.lock().unwrap() = Some(index);
+                    return Ok(AccessToken::new(
+                        token.token.secret().to_owned(),
+                        token.expires_on,
+                    ));
+                }
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+        Err(AzureError::message(
+            AzureErrorKind::Credential,
+            format!(
+                "no identity of the environment provided a token: {}",
+                errors.join("; ")
+            ),
+        ))
+    }
+
+    async fn clear_cache(&self) -> AzureResult<()> {
+        *self.used_source.lock().unwrap() = None;
+        Ok(())
+    }
+}
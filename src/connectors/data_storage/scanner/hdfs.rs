@@ -0,0 +1,307 @@
+// Copyright © 2026 Pathway
+
+use std::str::from_utf8;
+use std::time::Duration;
+
+use glob::Pattern as GlobPattern;
+use reqwest::blocking::{Client, Response};
+use reqwest::{StatusCode, Url};
+use serde::Deserialize;
+
+use crate::connectors::data_storage::scanner::{
+    actions_for_listing, PosixLikeScanner, QueuedAction,
+};
+use crate::connectors::metadata::FileLikeMetadata;
+use crate::connectors::ReadError;
+use crate::persistence::cached_object_storage::CachedObjectStorage;
+
+const WEBHDFS_PATH_PREFIX: &str = "webhdfs/v1";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+#[allow(clippy::module_name_repetitions)]
+pub enum HdfsError {
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("invalid WebHDFS URL {0:?}")]
+    InvalidUrl(String),
+
+    #[error("{exception}: {message}")]
+    Remote { exception: String, message: String },
+
+    #[error("the server responded with the status {0}")]
+    UnexpectedStatus(StatusCode),
+
+    #[error("path {0:?} isn't valid UTF-8")]
+    NonUnicodePath(String),
+}
+
+/// The status of a file or a directory, as returned by the `GETFILESTATUS`
+/// and `LISTSTATUS` operations of the WebHDFS REST API.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct HdfsFileStatus {
+    pub path_suffix: String,
+    #[serde(rename = "type")]
+    pub file_type: String,
+    pub length: u64,
+    pub owner: Option<String>,
+    pub modification_time: u64,
+}
+
+impl HdfsFileStatus {
+    pub fn is_directory(&self) -> bool {
+        self.file_type == "DIRECTORY"
+    }
+
+    pub fn is_file(&self) -> bool {
+        self.file_type == "FILE"
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct FileStatusResponse {
+    file_status: HdfsFileStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct FileStatusesResponse {
+    file_statuses: FileStatuses,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct FileStatuses {
+    file_status: Vec<HdfsFileStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RemoteExceptionResponse {
+    remote_exception: RemoteException,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteException {
+    exception: String,
+    message: String,
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct HdfsScanner {
+    base_url: Url,
+    user: Option<String>,
+    delegation_token: Option<String>,
+    path: String,
+    object_pattern: GlobPattern,
+
+    // Created on the first request, since the blocking client can't be
+    // created in an asynchronous context. Only the connection has a timeout,
+    // because downloading a large file may take arbitrarily long.
+    client: Option<Client>,
+}
+
+impl PosixLikeScanner for HdfsScanner {
+    fn object_metadata(
+        &mut self,
+        object_path: &[u8],
+    ) -> Result<Option<FileLikeMetadata>, ReadError> {
+        let path = Self::decode_path(object_path)?;
+        Ok(self
+            .file_status(path)?
+            .map(|status| FileLikeMetadata::from_hdfs_file_status(path, &status)))
+    }
+
+    fn read_object(&mut self, object_path: &[u8]) -> Result<Vec<u8>, ReadError> {
+        let path = Self::decode_path(object_path)?;
+        // The name node redirects the request to a data node holding the file
+        let response = self.request(path, "OPEN")?;
+        let response = Self::check_status(response)?;
+        Ok(response.bytes().map_err(HdfsError::from)?.to_vec())
+    }
+
+    fn next_scanner_actions(
+        &mut self,
+        are_deletions_enabled: bool,
+        cached_object_storage: &CachedObjectStorage,
+    ) -> Result<Vec<QueuedAction>, ReadError> {
+        let listing = self.list_matching_objects()?;
+        Ok(actions_for_listing(
+            listing,
+            are_deletions_enabled,
+            cached_object_storage,
+        ))
+    }
+
+    fn has_pending_actions(&self) -> bool {
+        false // The whole listing is turned into actions at once
+    }
+
+    fn short_description(&self) -> String {
+        format!("Hdfs({}, {})", self.base_url, self.path)
+    }
+}
+
+impl HdfsScanner {
+    pub fn new(
+        url: &str,
+        user: Option<String>,
+        delegation_token: Option<String>,
+        path: &str,
+        object_pattern: &str,
+    ) -> Result<Self, ReadError> {
+        Ok(Self {
+            base_url: Self::webhdfs_base_url(url)?,
+            user,
+            delegation_token,
+            path: Self::normalize_path(path),
+            object_pattern: GlobPattern::new(object_pattern)?,
+            client: None,
+        })
+    }
+
+    /// Builds the root of the WebHDFS REST API from the address of the name
+    /// node or of an `HttpFS` gateway, such as `http://namenode:9870`. The
+    /// `webhdfs://` and `swebhdfs://` schemes are accepted as the aliases of
+    /// `http://` and `https://` respectively.
+    pub fn webhdfs_base_url(url: &str) -> Result<Url, HdfsError> {
+        let url = if let Some(address) = url.strip_prefix("webhdfs://") {
+            format!("http://{address}")
+        } else if let Some(address) = url.strip_prefix("swebhdfs://") {
+            format!("https://{address}")
+        } else {
+            url.to_string()
+        };
+        let mut base_url = Url::parse(&url).map_err(|_| HdfsError::InvalidUrl(url.clone()))?;
+        if base_url.cannot_be_a_base() || !matches!(base_url.scheme(), "http" | "https") {
+            return Err(HdfsError::InvalidUrl(url));
+        }
+        {
+            let mut segments = base_url
+                .path_segments_mut()
+                .map_err(|()| HdfsError::InvalidUrl(url.clone()))?;
+            segments.pop_if_empty();
+            if !url.trim_end_matches('/').ends_with(WEBHDFS_PATH_PREFIX) {
+                segments.extend(WEBHDFS_PATH_PREFIX.split('/'));
+            }
+        }
+        Ok(base_url)
+    }
+
+    /// Builds the URL of a WebHDFS operation on the given path.
+    pub fn operation_url(&self, path: &str, operation: &str) -> Url {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .expect("the base URL is checked on creation")
+            .extend(path.split('/').filter(|segment| !segment.is_empty()));
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("op", operation);
+            if let Some(user) = &self.user {
+                query.append_pair("user.name", user);
+            }
+            if let Some(delegation_token) = &self.delegation_token {
+                query.append_pair("delegation", delegation_token);
+            }
+        }
+        url
+    }
+
+    fn normalize_path(path: &str) -> String {
+        let trimmed_path = path.trim_end_matches('/');
+        if trimmed_path.starts_with('/') {
+            trimmed_path.to_string()
+        } else {
+            format!("/{trimmed_path}")
+        }
+    }
+
+    fn request(&mut self, path: &str, operation: &str) -> Result<Response, HdfsError> {
+        let url = self.operation_url(path, operation);
+        if self.client.is_none() {
+            self.client = Some(
+                Client::builder()
+                    .connect_timeout(CONNECT_TIMEOUT)
+                    .timeout(None)
+                    .build()?,
+            );
+        }
+        let client = self.client.as_ref().expect("the client must be created");
+        Ok(client.get(url).send()?)
+    }
+
+    fn check_status(response: Response) -> Result<Response, HdfsError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        match response.json::<RemoteExceptionResponse>() {
+            Ok(RemoteExceptionResponse {
+                remote_exception: RemoteException { exception, message },
+            }) => Err(HdfsError::Remote { exception, message }),
+            Err(_) => Err(HdfsError::UnexpectedStatus(status)),
+        }
+    }
+
+    fn file_status(&mut self, path: &str) -> Result<Option<HdfsFileStatus>, HdfsError> {
+        let response = self.request(path, "GETFILESTATUS")?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response: FileStatusResponse = Self::check_status(response)?.json()?;
+        Ok(Some(response.file_status))
+    }
+
+    fn list_directory(&mut self, path: &str) -> Result<Option<Vec<HdfsFileStatus>>, HdfsError> {
+        let response = self.request(path, "LISTSTATUS")?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response: FileStatusesResponse = Self::check_status(response)?.json()?;
+        Ok(Some(response.file_statuses.file_status))
+    }
+
+    fn list_matching_objects(&mut self) -> Result<Vec<FileLikeMetadata>, HdfsError> {
+        let mut result = Vec::new();
+        let root = self.path.clone();
+        let Some(root_status) = self.file_status(&root)? else {
+            // The landing directory may be created later
+            return Ok(result);
+        };
+        if !root_status.is_directory() {
+            result.push(FileLikeMetadata::from_hdfs_file_status(&root, &root_status));
+            return Ok(result);
+        }
+
+        let mut pending_directories = vec![root];
+        while let Some(directory) = pending_directories.pop() {
+            // The directory may have been removed after being listed
+            let Some(entries) = self.list_directory(&directory)? else {
+                continue;
+            };
+            for entry in entries {
+                let entry_path = if directory == "/" {
+                    format!("/{}", entry.path_suffix)
+                } else {
+                    format!("{directory}/{}", entry.path_suffix)
+                };
+                if entry.is_directory() {
+                    pending_directories.push(entry_path);
+                } else if entry.is_file() && self.object_pattern.matches(&entry.path_suffix) {
+                    result.push(FileLikeMetadata::from_hdfs_file_status(&entry_path, &entry));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn decode_path(object_path: &[u8]) -> Result<&str, HdfsError> {
+        from_utf8(object_path)
+            .map_err(|_| HdfsError::NonUnicodePath(String::from_utf8_lossy(object_path).into()))
+    }
+}
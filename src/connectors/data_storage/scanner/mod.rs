@@ -1,16 +1,31 @@
+use std::collections::HashSet;
+use std::str::from_utf8;
+
 use crate::connectors::metadata::FileLikeMetadata;
 use crate::connectors::ReadError;
 use crate::persistence::cached_object_storage::CachedObjectStorage;
 
+pub mod azure;
 pub mod filesystem;
+pub mod hdfs;
 pub mod s3;
+pub mod sftp;
+
+#[allow(clippy::module_name_repetitions)]
+pub use azure::AzureBlobScanner;
 
 #[allow(clippy::module_name_repetitions)]
 pub use filesystem::FilesystemScanner;
 
+#[allow(clippy::module_name_repetitions)]
+pub use hdfs::HdfsScanner;
+
 #[allow(clippy::module_name_repetitions)]
 pub use s3::S3Scanner;
 
+#[allow(clippy::module_name_repetitions)]
+pub use sftp::SftpScanner;
+
 #[derive(Clone, Debug)]
 pub enum QueuedAction {
     Read(Vec<u8>, FileLikeMetadata),
@@ -41,3 +56,55 @@ pub trait PosixLikeScanner: Send {
     fn has_pending_actions(&self) -> bool;
     fn short_description(&self) -> String;
 }
+
+/// Turns a listing of the watched objects into the actions for the reader.
+///
+/// The objects that haven't been read yet are queued in the order of their
+/// modification time, so the files dropped earlier are processed first. The
+/// objects already present in `cached_object_storage` are only reported if
+/// they were changed or, when deletions are enabled, removed.
+pub fn actions_for_listing(
+    listing: Vec<FileLikeMetadata>,
+    are_deletions_enabled: bool,
+    cached_object_storage: &CachedObjectStorage,
+) -> Vec<QueuedAction> {
+    let mut result = Vec::new();
+    let mut new_objects = Vec::new();
+    let mut listed_objects = HashSet::with_capacity(listing.len());
+    for metadata in listing {
+        let object_key = metadata.path.as_bytes();
+        match cached_object_storage.stored_tag(object_key) {
+            None => new_objects.push(metadata),
+            Some(stored_tag) => {
+                if are_deletions_enabled && cached_object_storage.is_changed(stored_tag, &metadata)
+                {
+                    result.push(QueuedAction::Update(object_key.to_vec(), metadata.clone()));
+                }
+                listed_objects.insert(metadata.path.clone());
+            }
+        }
+    }
+
+    if are_deletions_enabled {
+        for (object_key, _) in cached_object_storage.get_iter() {
+            let is_listed =
+                from_utf8(object_key).is_ok_and(|object_path| listed_objects.contains(object_path));
+            if !is_listed {
+                result.push(QueuedAction::Delete(object_key.to_vec()));
+            }
+        }
+    }
+
+    new_objects.sort_by(|lhs, rhs| {
+        lhs.modified_at
+            .cmp(&rhs.modified_at)
+            .then_with(|| lhs.path.cmp(&rhs.path))
+    });
+    for metadata in new_objects {
+        result.push(QueuedAction::Read(
+            metadata.path.as_bytes().to_vec(),
+            metadata,
+        ));
+    }
+    result
+}
@@ -0,0 +1,296 @@
+// Copyright © 2026 Pathway
+
+use std::fmt::Debug;
+use std::io::Read;
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::str::from_utf8;
+use std::time::Duration;
+
+use glob::Pattern as GlobPattern;
+use log::{error, info};
+use ssh2::{CheckResult, ErrorCode, KnownHostFileKind, Session, Sftp};
+
+use crate::connectors::data_storage::scanner::{
+    actions_for_listing, PosixLikeScanner, QueuedAction,
+};
+use crate::connectors::metadata::FileLikeMetadata;
+use crate::connectors::ReadError;
+use crate::persistence::cached_object_storage::CachedObjectStorage;
+
+// `LIBSSH2_FX_NO_SUCH_FILE`, the SFTP status of a missing path
+const SFTP_NO_SUCH_FILE: i32 = 2;
+const SESSION_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+#[allow(clippy::module_name_repetitions)]
+pub enum SftpError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Ssh(#[from] ssh2::Error),
+
+    #[error("the server didn't provide a host key")]
+    NoHostKey,
+
+    #[error("the host key of {0} doesn't match the known hosts file")]
+    HostKeyMismatch(String),
+
+    #[error("{0} isn't present in the known hosts file")]
+    UnknownHost(String),
+
+    #[error("authentication as {0} failed")]
+    AuthenticationFailed(String),
+
+    #[error("path {0:?} isn't valid UTF-8")]
+    NonUnicodePath(PathBuf),
+}
+
+#[derive(Clone, Debug)]
+pub enum SftpAuthentication {
+    Password(String),
+    PrivateKey {
+        path: PathBuf,
+        passphrase: Option<String>,
+    },
+    Agent,
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SftpConnectionSettings {
+    pub host: String,
+    pub port: u16,
+    pub user: String,
+    pub authentication: SftpAuthentication,
+    pub known_hosts_path: Option<PathBuf>,
+}
+
+impl SftpConnectionSettings {
+    fn connect(&self) -> Result<(Session, Sftp), SftpError> {
+        let tcp = TcpStream::connect((self.host.as_str(), self.port))?;
+        let mut session = Session::new()?;
+        session.set_tcp_stream(tcp);
+        session.set_timeout(SESSION_TIMEOUT.as_millis().try_into().unwrap_or(u32::MAX));
+        session.handshake()?;
+        if let Some(known_hosts_path) = &self.known_hosts_path {
+            self.verify_host_key(&session, known_hosts_path)?;
+        }
+
+        match &self.authentication {
+            SftpAuthentication::Password(password) => {
+                session.userauth_password(&self.user, password)?;
+            }
+            SftpAuthentication::PrivateKey { path, passphrase } => {
+                session.userauth_pubkey_file(&self.user, None, path, passphrase.as_deref())?;
+            }
+            SftpAuthentication::Agent => session.userauth_agent(&self.user)?,
+        }
+        if !session.authenticated() {
+            return Err(SftpError::AuthenticationFailed(self.user.clone()));
+        }
+
+        let sftp = session.sftp()?;
+        info!(
+            "Connected to the SFTP server {}:{} as {}",
+            self.host, self.port, self.user
+        );
+        Ok((session, sftp))
+    }
+
+    fn verify_host_key(&self, session: &Session, known_hosts_path: &Path) -> Result<(), SftpError> {
+        let mut known_hosts = session.known_hosts()?;
+        known_hosts.read_file(known_hosts_path, KnownHostFileKind::OpenSSH)?;
+        let (host_key, _) = session.host_key().ok_or(SftpError::NoHostKey)?;
+        match known_hosts.check_port(&self.host, self.port, host_key) {
+            CheckResult::Match => Ok(()),
+            CheckResult::Mismatch => Err(SftpError::HostKeyMismatch(self.host.clone())),
+            CheckResult::NotFound | CheckResult::Failure => {
+                Err(SftpError::UnknownHost(self.host.clone()))
+            }
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct SftpScanner {
+    settings: SftpConnectionSettings,
+    path: String,
+    object_pattern: GlobPattern,
+
+    // The session must outlive the SFTP channel, so both are kept together.
+    // They're dropped after a connection failure and reestablished on the
+    // next request.
+    connection: Option<(Session, Sftp)>,
+}
+
+impl Debug for SftpScanner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SftpScanner")
+            .field("host", &self.settings.host)
+            .field("port", &self.settings.port)
+            .field("path", &self.path)
+            .field("object_pattern", &self.object_pattern)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PosixLikeScanner for SftpScanner {
+    fn object_metadata(
+        &mut self,
+        object_path: &[u8],
+    ) -> Result<Option<FileLikeMetadata>, ReadError> {
+        let path = Self::decode_path(object_path)?;
+        match self.with_sftp(|sftp| Ok(sftp.stat(Path::new(path))?)) {
+            Ok(stat) => Ok(Some(FileLikeMetadata::from_sftp_stat(path, &stat))),
+            Err(SftpError::Ssh(e)) if Self::is_not_found(&e) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn read_object(&mut self, object_path: &[u8]) -> Result<Vec<u8>, ReadError> {
+        let path = Self::decode_path(object_path)?;
+        let contents = self.with_sftp(|sftp| {
+            let mut file = sftp.open(Path::new(path))?;
+            let mut contents = Vec::new();
+            file.read_to_end(&mut contents)?;
+            Ok(contents)
+        })?;
+        Ok(contents)
+    }
+
+    fn next_scanner_actions(
+        &mut self,
+        are_deletions_enabled: bool,
+        cached_object_storage: &CachedObjectStorage,
+    ) -> Result<Vec<QueuedAction>, ReadError> {
+        let listing = self.list_matching_objects()?;
+        Ok(actions_for_listing(
+            listing,
+            are_deletions_enabled,
+            cached_object_storage,
+        ))
+    }
+
+    fn has_pending_actions(&self) -> bool {
+        false // The whole listing is turned into actions at once
+    }
+
+    fn short_description(&self) -> String {
+        format!(
+            "Sftp({}:{}, {})",
+            self.settings.host, self.settings.port, self.path
+        )
+    }
+}
+
+impl SftpScanner {
+    pub fn new(
+        settings: SftpConnectionSettings,
+        path: &str,
+        object_pattern: &str,
+    ) -> Result<SftpScanner, ReadError> {
+        let object_pattern = GlobPattern::new(object_pattern)?;
+        let trimmed_path = path.trim_end_matches('/');
+        let mut scanner = Self {
+            settings,
+            path: if trimmed_path.is_empty() {
+                path
+            } else {
+                trimmed_path
+            }
+            .to_string(),
+            object_pattern,
+            connection: None,
+        };
+        // Connect eagerly so that misconfiguration is reported on start
+        scanner.with_sftp(|_| Ok(()))?;
+        Ok(scanner)
+    }
+
+    fn list_matching_objects(&mut self) -> Result<Vec<FileLikeMetadata>, SftpError> {
+        let root = PathBuf::from(&self.path);
+        let object_pattern = self.object_pattern.clone();
+        self.with_sftp(|sftp| {
+            let mut result = Vec::new();
+            let root_stat = match sftp.stat(&root) {
+                Ok(stat) => stat,
+                // The drop directory may be created later
+                Err(e) if Self::is_not_found(&e) => return Ok(result),
+                Err(e) => return Err(e.into()),
+            };
+            if !root_stat.is_dir() {
+                if let Some(path) = root.to_str() {
+                    result.push(FileLikeMetadata::from_sftp_stat(path, &root_stat));
+                }
+                return Ok(result);
+            }
+
+            let mut pending_directories = vec![root];
+            while let Some(directory) = pending_directories.pop() {
+                let entries = match sftp.readdir(&directory) {
+                    Ok(entries) => entries,
+                    // The directory was removed after being listed
+                    Err(e) if Self::is_not_found(&e) => continue,
+                    Err(e) => return Err(e.into()),
+                };
+                for (entry_path, stat) in entries {
+                    if stat.is_dir() {
+                        pending_directories.push(entry_path);
+                        continue;
+                    }
+                    if !stat.is_file() {
+                        continue;
+                    }
+                    let Some(path) = entry_path.to_str() else {
+                        error!(
+                            "Non-unicode paths are not supported. Ignoring: {}",
+                            entry_path.display()
+                        );
+                        continue;
+                    };
+                    let is_matching = entry_path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| object_pattern.matches(name));
+                    if is_matching {
+                        result.push(FileLikeMetadata::from_sftp_stat(path, &stat));
+                    }
+                }
+            }
+            Ok(result)
+        })
+    }
+
+    fn with_sftp<T>(
+        &mut self,
+        operation: impl FnOnce(&Sftp) -> Result<T, SftpError>,
+    ) -> Result<T, SftpError> {
+        if self.connection.is_none() {
+            self.connection = Some(self.settings.connect()?);
+        }
+        let (_, sftp) = self
+            .connection
+            .as_ref()
+            .expect("the connection must be established");
+        operation(sftp).inspect_err(|e| {
+            // SFTP statuses are reported for a single request, while the other
+            // errors mean that the session can't be used anymore
+            if !matches!(e, SftpError::Ssh(e) if matches!(e.code(), ErrorCode::SFTP(_))) {
+                self.connection = None;
+            }
+        })
+    }
+
+    fn is_not_found(error: &ssh2::Error) -> bool {
+        matches!(error.code(), ErrorCode::SFTP(SFTP_NO_SUCH_FILE))
+    }
+
+    fn decode_path(object_path: &[u8]) -> Result<&str, SftpError> {
+        from_utf8(object_path).map_err(|_| {
+            SftpError::NonUnicodePath(String::from_utf8_lossy(object_path).into_owned().into())
+        })
+    }
+}
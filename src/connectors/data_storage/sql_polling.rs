@@ -0,0 +1,336 @@
+// Copyright © 2026 Pathway
+
+//! Polling of SQL databases by a cursor column.
+//!
+//! The CDC readers of `PostgreSQL` and `MySQL` need the logical replication or
+//! the binary log to be enabled on the server, along with the privileges to
+//! read them. Many databases, e.g. the managed read replicas, provide neither.
+//! For an append-only table, the new rows can instead be found by running a
+//! plain `SELECT` with an integer column growing with the inserted rows, such as
+//! an autoincremented id or a Unix timestamp, as a cursor.
+//!
+//! [`SqlPollingSource`] builds the queries for the generic [`PollingReader`],
+//! which keeps the watermark, deduplicates the rows re-read in the overlap
+//! window, and persists its position. The database-specific parts — quoting,
+//! query parameters, and parsing the column values — are supplied by a
+//! [`SqlPollingConnection`], implemented for the `PostgreSQL` and `MySQL`
+//! clients in their modules.
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use serde_json::Value as JsonValue;
+
+use crate::connectors::data_format::{serialize_value_to_json, FieldSource, ValueField};
+use crate::connectors::data_storage::{
+    ConnectorMode, ConversionError, LiveState, PolledRow, PollingDataSource, PollingReader,
+    ReadError, StorageType,
+};
+use crate::connectors::offset::OffsetKey;
+use crate::engine::error::{limit_length, STANDARD_OBJECT_LENGTH_LIMIT};
+use crate::engine::{Type, Value};
+
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum SqlPollingError {
+    #[error(
+        "cursor column {column:?} holds {value}, which is not an integer. \
+         The reader orders the rows by the cursor column, so it must hold \
+         integers, e.g. an autoincremented id or a Unix timestamp."
+    )]
+    InvalidCursorValue { column: String, value: String },
+
+    #[error(
+        "id column {column:?} holds {value}, which can't identify a row. The \
+         reader deduplicates the rows by the id column, so it must not hold NULLs."
+    )]
+    InvalidIdValue { column: String, value: String },
+}
+
+/// The result of converting a single column of a polled row.
+pub type PolledValue = Result<Value, Box<ConversionError>>;
+
+/// A connection to a database that can be polled by [`SqlPollingSource`].
+pub trait SqlPollingConnection: Send {
+    fn quote_identifier(&self, identifier: &str) -> String;
+
+    /// The placeholder of an integer query parameter, numbered from 1.
+    fn placeholder(&self, index: usize) -> String;
+
+    /// Run a `SELECT` with integer parameters, converting the returned columns
+    /// to the types of the given fields, in order.
+    fn fetch_rows(
+        &mut self,
+        query: &str,
+        params: &[i64],
+        fields: &[ValueField],
+    ) -> Result<Vec<Vec<PolledValue>>, ReadError>;
+
+    fn storage_type(&self) -> StorageType;
+
+    fn offset_key(&self) -> OffsetKey;
+
+    /// The name of the database engine, used in the connector's description.
+    fn engine_name(&self) -> &'static str;
+}
+
+/// What the cursor reader polls: a table (or view) by name, or the rows
+/// returned by an arbitrary `SELECT`.
+#[derive(Debug)]
+pub enum SqlPollingTarget {
+    Table {
+        schema_name: Option<String>,
+        table_name: String,
+    },
+    Query(String),
+}
+
+/// A [`PollingDataSource`] reading an append-only table or query by an
+/// integer cursor column.
+///
+/// The rows are handed to the parser as JSON objects, with the values
+/// serialized the way the JSON-lines formatter does, so that the `jsonlines`
+/// parser reconstructs them. A value that can't be converted to its schema
+/// type is passed on as its textual representation, so that the parser
+/// reports the error for the row.
+pub struct SqlPollingSource<Connection: SqlPollingConnection> {
+    connection: Connection,
+    target: SqlPollingTarget,
+    fields: Vec<ValueField>,
+    cursor_column: String,
+    cursor_position: usize,
+    id_column: String,
+    id_position: usize,
+    fetch_query: String,
+    max_cursor_query: String,
+    overlap_count_query: String,
+}
+
+impl<Connection: SqlPollingConnection> SqlPollingSource<Connection> {
+    pub fn new(
+        connection: Connection,
+        target: SqlPollingTarget,
+        fields: Vec<ValueField>,
+        cursor_column: String,
+        id_column: String,
+    ) -> Result<Self, ReadError> {
+        let position = |column: &str| {
+            fields
+                .iter()
+                .position(|field| field.name == column)
+                .unwrap_or_else(|| {
+                    panic!("column {column:?} is not present in the SQL reader's schema")
+                })
+        };
+        let cursor_position = position(&cursor_column);
+        let id_position = position(&id_column);
+
+        let from_clause = match &target {
+            SqlPollingTarget::Table {
+                schema_name: Some(schema_name),
+                table_name,
+            } => format!(
+                "{}.{}",
+                connection.quote_identifier(schema_name),
+                connection.quote_identifier(table_name)
+            ),
+            SqlPollingTarget::Table {
+                schema_name: None,
+                table_name,
+            } => connection.quote_identifier(table_name),
+            // Both PostgreSQL and MySQL require an alias for a derived table
+            SqlPollingTarget::Query(query) => format!("({query}) AS pathway_polled_rows"),
+        };
+        let column_list = fields
+            .iter()
+            .map(|field| connection.quote_identifier(&field.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let quoted_cursor = connection.quote_identifier(&cursor_column);
+        let fetch_query = format!(
+            "SELECT {column_list} FROM {from_clause} WHERE {quoted_cursor} >= {} \
+             ORDER BY {quoted_cursor}, {} LIMIT {}",
+            connection.placeholder(1),
+            connection.quote_identifier(&id_column),
+            connection.placeholder(2),
+        );
+        let max_cursor_query = format!("SELECT MAX({quoted_cursor}) FROM {from_clause}");
+        let overlap_count_query = format!(
+            "SELECT COUNT(*) FROM {from_clause} WHERE {quoted_cursor} >= {}",
+            connection.placeholder(1),
+        );
+
+        let mut source = Self {
+            connection,
+            target,
+            fields,
+            cursor_column,
+            cursor_position,
+            id_column,
+            id_position,
+            fetch_query,
+            max_cursor_query,
+            overlap_count_query,
+        };
+        // Preflight: an empty fetch fails if the target or any of the schema
+        // columns doesn't exist, which is better reported now than on every poll.
+        source.fetch(i64::MAX, 0)?;
+        Ok(source)
+    }
+
+    fn query_scalar(
+        connection: &mut Connection,
+        query: &str,
+        params: &[i64],
+        field: ValueField,
+    ) -> Result<PolledValue, ReadError> {
+        let rows = connection.fetch_rows(query, params, &[field])?;
+        Ok(rows
+            .into_iter()
+            .next()
+            .and_then(|row| row.into_iter().next())
+            .unwrap_or(Ok(Value::None)))
+    }
+
+    fn parse_cursor(&self, value: &PolledValue) -> Result<i64, SqlPollingError> {
+        match value {
+            Ok(Value::Int(cursor)) => Ok(*cursor),
+            Ok(other) => Err(SqlPollingError::InvalidCursorValue {
+                column: self.cursor_column.clone(),
+                value: limit_length(format!("{other}"), STANDARD_OBJECT_LENGTH_LIMIT),
+            }),
+            Err(error) => Err(SqlPollingError::InvalidCursorValue {
+                column: self.cursor_column.clone(),
+                value: error.value_repr.clone(),
+            }),
+        }
+    }
+
+    fn canonicalize_id(&self, value: &PolledValue) -> Result<String, SqlPollingError> {
+        match value {
+            Ok(Value::String(id)) => Ok(id.to_string()),
+            Ok(Value::None) => Err(SqlPollingError::InvalidIdValue {
+                column: self.id_column.clone(),
+                value: "NULL".to_string(),
+            }),
+            Ok(id) => Ok(id.to_string()),
+            Err(error) => Err(SqlPollingError::InvalidIdValue {
+                column: self.id_column.clone(),
+                value: error.value_repr.clone(),
+            }),
+        }
+    }
+
+    fn value_to_json(value: &PolledValue) -> JsonValue {
+        match value {
+            Ok(value) => serialize_value_to_json(value)
+                .unwrap_or_else(|_| JsonValue::String(value.to_string())),
+            Err(error) => JsonValue::String(error.value_repr.clone()),
+        }
+    }
+}
+
+impl<Connection: SqlPollingConnection> PollingDataSource for SqlPollingSource<Connection> {
+    fn fetch(&mut self, min_watermark: i64, limit: usize) -> Result<Vec<PolledRow>, ReadError> {
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let rows =
+            self.connection
+                .fetch_rows(&self.fetch_query, &[min_watermark, limit], &self.fields)?;
+
+        let mut polled_rows = Vec::with_capacity(rows.len());
+        for row in rows {
+            let timestamp = self.parse_cursor(&row[self.cursor_position])?;
+            let id = self.canonicalize_id(&row[self.id_position])?;
+            let values: serde_json::Map<_, _> = self
+                .fields
+                .iter()
+                .zip(&row)
+                .map(|(field, value)| (field.name.clone(), Self::value_to_json(value)))
+                .collect();
+            let raw = serde_json::to_vec(&JsonValue::Object(values))
+                .expect("serializing a JSON object can't fail");
+            polled_rows.push(PolledRow { id, timestamp, raw });
+        }
+        Ok(polled_rows)
+    }
+
+    fn live_state(
+        &mut self,
+        max_transaction_duration: i64,
+    ) -> Result<Option<LiveState>, ReadError> {
+        let max_cursor = Self::query_scalar(
+            &mut self.connection,
+            &self.max_cursor_query,
+            &[],
+            ValueField::new(
+                self.cursor_column.clone(),
+                Type::Optional(Type::Int.into()),
+                FieldSource::Payload,
+            ),
+        )?;
+        if matches!(max_cursor, Ok(Value::None)) {
+            return Ok(None);
+        }
+        let max_timestamp = self.parse_cursor(&max_cursor)?;
+
+        let overlap_count = Self::query_scalar(
+            &mut self.connection,
+            &self.overlap_count_query,
+            &[max_timestamp.saturating_sub(max_transaction_duration)],
+            ValueField::new("count".to_string(), Type::Int, FieldSource::Payload),
+        )?;
+        let Ok(Value::Int(overlap_count)) = overlap_count else {
+            unreachable!("COUNT(*) always returns an integer, got {overlap_count:?}");
+        };
+        Ok(Some(LiveState {
+            max_timestamp,
+            overlap_count: overlap_count.try_into().unwrap_or_default(),
+        }))
+    }
+
+    fn storage_type(&self) -> StorageType {
+        self.connection.storage_type()
+    }
+
+    fn offset_key(&self) -> OffsetKey {
+        self.connection.offset_key()
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        let engine_name = self.connection.engine_name();
+        match &self.target {
+            SqlPollingTarget::Table { table_name, .. } => {
+                format!("{engine_name}({table_name})").into()
+            }
+            SqlPollingTarget::Query(_) => format!("{engine_name}(query)").into(),
+        }
+    }
+}
+
+/// An input connector polling a table or query of an SQL database by a
+/// cursor column.
+pub type SqlCursorReader<Connection> = PollingReader<SqlPollingSource<Connection>>;
+
+/// Build a [`SqlCursorReader`] from an opened connection and the polling
+/// parameters supplied through the Python API.
+#[allow(clippy::too_many_arguments)]
+pub fn build_sql_cursor_reader<Connection: SqlPollingConnection>(
+    connection: Connection,
+    target: SqlPollingTarget,
+    fields: Vec<ValueField>,
+    cursor_column: String,
+    id_column: String,
+    mode: ConnectorMode,
+    max_cursor_lag: i64,
+    read_batch_size: usize,
+    poll_interval: Duration,
+) -> Result<SqlCursorReader<Connection>, ReadError> {
+    let source = SqlPollingSource::new(connection, target, fields, cursor_column, id_column)?;
+    Ok(PollingReader::new(
+        source,
+        mode,
+        max_cursor_lag,
+        read_batch_size,
+        poll_interval,
+    ))
+}
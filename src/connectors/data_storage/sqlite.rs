@@ -10,14 +10,15 @@ use std::time::Duration;
 
 use crate::connectors::data_format::{
     create_bincoded_value, parse_bool_advanced, parse_value_from_json, serialize_value_to_json,
-    FormatterContext,
+    FormatterContext, ValueField,
 };
 use crate::connectors::data_storage::{
-    CommitPossibility, ConversionError, SqlQueryTemplate, TableContext, TableWriterInitMode,
-    ValuesMap, WriteError, Writer,
+    CommitPossibility, ConnectorMode, ConversionError, LiveState, PolledRow, PollingDataSource,
+    PollingReader, SqlQueryTemplate, TableContext, TableWriterInitMode, ValuesMap, WriteError,
+    Writer,
 };
 use crate::connectors::metadata::SQLiteMetadata;
-use crate::connectors::offset::EMPTY_OFFSET;
+use crate::connectors::offset::{OffsetKey, EMPTY_OFFSET};
 use crate::connectors::{
     DataEventType, ReadError, ReadResult, Reader, ReaderContext, StorageType, SPECIAL_FIELD_DIFF,
     SPECIAL_FIELD_TIME,
@@ -25,7 +26,6 @@ use crate::connectors::{
 use crate::engine::error::{limit_length, STANDARD_OBJECT_LENGTH_LIMIT};
 use crate::engine::{Duration as EngineDuration, Type, Value};
 use crate::persistence::frontier::OffsetAntichain;
-use crate::python_api::ValueField;
 
 use rusqlite::params_from_iter;
 use rusqlite::types::{ToSqlOutput, Value as SqliteOwnedValue, ValueRef as SqliteValue};
@@ -153,6 +153,25 @@ pub enum SqliteError {
         columns: Vec<String>,
     },
 
+    /// The cursor column of a polled row doesn't hold an integer. The
+    /// cursor reader orders the rows and keeps its watermark by this
+    /// column, so every value has to be an `INTEGER`.
+    #[error(
+        "cursor column {column:?} holds {value}, which is not an integer. \
+         The reader orders the rows by the cursor column, so it must hold \
+         integers, e.g. an autoincremented id or a Unix timestamp."
+    )]
+    InvalidCursorValue { column: String, value: String },
+
+    /// The id column of a polled row is `NULL`. The cursor reader
+    /// deduplicates the rows re-read in the overlap window by this
+    /// column, so a `NULL` can't identify a row.
+    #[error(
+        "id column {0:?} is NULL in one of the polled rows. The reader \
+         deduplicates the rows by the id column, so it must not hold NULLs."
+    )]
+    NullIdValue(String),
+
     /// Writer with `init_mode="default"` was pointed at a table that
     /// doesn't exist. Switching the init mode to `"create_if_not_exists"`
     /// or `"replace"` lets the writer create it.
@@ -985,13 +1004,21 @@ impl SqliteReader {
                 parse_value_from_json(&JsonValue::String(normalized), dtype)
             }),
 
+            // Zoned datetimes are stored as RFC 9557 text (offset plus the
+            // bracketed zone name), exactly as the writer emits them.
+            Type::DateTimeZoned => orig_value.as_str().ok().and_then(|parsed_string| {
+                parse_value_from_json(&JsonValue::String(parsed_string.to_owned()), dtype)
+            }),
+
             // Complex types whose jsonlines representation is a JSON array or
             // object — the TEXT column is expected to hold that JSON.
-            Type::Tuple(_) | Type::List(_) | Type::Array(_, _) => orig_value
-                .as_str()
-                .ok()
-                .and_then(|parsed_string| serde_json::from_str::<JsonValue>(parsed_string).ok())
-                .and_then(|json_value| parse_value_from_json(&json_value, dtype)),
+            Type::Tuple(_) | Type::List(_) | Type::Array(_, _) | Type::Struct(_) | Type::Map(_) => {
+                orig_value
+                    .as_str()
+                    .ok()
+                    .and_then(|parsed_string| serde_json::from_str::<JsonValue>(parsed_string).ok())
+                    .and_then(|json_value| parse_value_from_json(&json_value, dtype))
+            }
 
             // Future is a type-system placeholder for async results; it is
             // unwrapped before rows reach any connector, so it should not
@@ -1245,6 +1272,237 @@ impl Reader for SqliteReader {
     }
 }
 
+/// What the cursor reader polls: a table (or view) by name, or the rows
+/// returned by an arbitrary `SELECT`.
+#[derive(Debug)]
+pub enum SqlitePollingTarget {
+    Table(String),
+    Query(String),
+}
+
+impl SqlitePollingTarget {
+    fn from_clause(&self) -> String {
+        match self {
+            Self::Table(table_name) => quote_sqlite_identifier(table_name),
+            Self::Query(query) => format!("({query})"),
+        }
+    }
+}
+
+/// A [`PollingDataSource`] reading an append-only `SQLite` table or query
+/// by an integer cursor column.
+///
+/// Unlike [`SqliteReader`], which re-reads the whole table whenever the
+/// database changes and diffs it against the previous state, this source
+/// only reads the rows at or after the watermark of the generic
+/// [`PollingReader`], so the cost of a poll doesn't grow with the size of
+/// the table, and the watermark can be persisted. Updates and deletions
+/// of the rows already read aren't observed.
+///
+/// The rows are handed to the parser as JSON objects: the values are
+/// parsed with the same rules as in [`SqliteReader`] and serialized the
+/// way the JSON-lines formatter does, so that the `jsonlines` parser
+/// reconstructs them.
+pub struct SqlitePollingSource {
+    connection: SqliteConnection,
+    target: SqlitePollingTarget,
+    schema: Vec<(String, Type)>,
+    cursor_column: String,
+    cursor_position: usize,
+    id_column: String,
+    id_position: usize,
+}
+
+impl SqlitePollingSource {
+    pub fn new(
+        connection: SqliteConnection,
+        target: SqlitePollingTarget,
+        schema: Vec<(String, Type)>,
+        cursor_column: String,
+        id_column: String,
+    ) -> Result<Self, ReadError> {
+        verify_is_sqlite_database(&connection)?;
+
+        let position = |column: &str| {
+            schema
+                .iter()
+                .position(|(name, _)| name == column)
+                .unwrap_or_else(|| {
+                    panic!("column {column:?} is not present in the SQLite reader's schema")
+                })
+        };
+        let cursor_position = position(&cursor_column);
+        let id_position = position(&id_column);
+
+        // Preflight: preparing the polling query fails if the target or any
+        // of the schema columns doesn't exist, which is better reported now
+        // than on every poll.
+        let source = Self {
+            connection,
+            target,
+            schema,
+            cursor_column,
+            cursor_position,
+            id_column,
+            id_position,
+        };
+        source.connection.prepare(&source.fetch_query())?;
+        Ok(source)
+    }
+
+    fn fetch_query(&self) -> String {
+        let column_list = self
+            .schema
+            .iter()
+            .map(|(name, _dtype)| quote_sqlite_identifier(name))
+            .collect::<Vec<_>>()
+            .join(",");
+        let cursor_column = quote_sqlite_identifier(&self.cursor_column);
+        format!(
+            "SELECT {column_list} FROM {} WHERE {cursor_column} >= ?1 \
+             ORDER BY {cursor_column}, {} LIMIT ?2",
+            self.target.from_clause(),
+            quote_sqlite_identifier(&self.id_column),
+        )
+    }
+
+    fn parse_cursor(&self, value: SqliteValue<'_>) -> Result<i64, SqliteError> {
+        value.as_i64().map_err(|_| SqliteError::InvalidCursorValue {
+            column: self.cursor_column.clone(),
+            value: limit_length(format!("{value:?}"), STANDARD_OBJECT_LENGTH_LIMIT),
+        })
+    }
+
+    fn canonicalize_id(&self, value: SqliteValue<'_>) -> Result<String, SqliteError> {
+        match value {
+            SqliteValue::Null => Err(SqliteError::NullIdValue(self.id_column.clone())),
+            SqliteValue::Integer(id) => Ok(id.to_string()),
+            SqliteValue::Real(id) => Ok(id.to_string()),
+            SqliteValue::Text(id) => Ok(String::from_utf8_lossy(id).into_owned()),
+            SqliteValue::Blob(id) => Ok(hex::encode(id)),
+        }
+    }
+
+    /// Represent a single value the way the `jsonlines` parser expects it.
+    /// A value that can't be parsed as the schema type is passed on as is,
+    /// so that the parser reports the error for the row.
+    fn value_to_json(value: SqliteValue<'_>, dtype: &Type) -> JsonValue {
+        if let Some(json_value) = SqliteReader::parse_sqlite_value(value, dtype)
+            .and_then(|parsed| serialize_value_to_json(&parsed).ok())
+        {
+            return json_value;
+        }
+        match value {
+            SqliteValue::Null => JsonValue::Null,
+            SqliteValue::Integer(value) => JsonValue::from(value),
+            SqliteValue::Real(value) => JsonValue::from(value),
+            SqliteValue::Text(value) => JsonValue::from(String::from_utf8_lossy(value)),
+            SqliteValue::Blob(value) => JsonValue::from(hex::encode(value)),
+        }
+    }
+}
+
+impl PollingDataSource for SqlitePollingSource {
+    fn fetch(&mut self, min_watermark: i64, limit: usize) -> Result<Vec<PolledRow>, ReadError> {
+        let query = self.fetch_query();
+        let mut statement = self.connection.prepare_cached(&query)?;
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let mut rows = statement.query((min_watermark, limit))?;
+
+        let mut polled_rows = Vec::new();
+        while let Some(row) = rows.next()? {
+            let timestamp = self.parse_cursor(row.get_ref(self.cursor_position)?)?;
+            let id = self.canonicalize_id(row.get_ref(self.id_position)?)?;
+            let mut values = serde_json::Map::with_capacity(self.schema.len());
+            for (column_idx, (column_name, column_dtype)) in self.schema.iter().enumerate() {
+                values.insert(
+                    column_name.clone(),
+                    Self::value_to_json(row.get_ref(column_idx)?, column_dtype),
+                );
+            }
+            let raw = serde_json::to_vec(&JsonValue::Object(values))
+                .expect("serializing a JSON object can't fail");
+            polled_rows.push(PolledRow { id, timestamp, raw });
+        }
+        Ok(polled_rows)
+    }
+
+    fn live_state(
+        &mut self,
+        max_transaction_duration: i64,
+    ) -> Result<Option<LiveState>, ReadError> {
+        let from_clause = self.target.from_clause();
+        let cursor_column = quote_sqlite_identifier(&self.cursor_column);
+        let max_cursor: Option<SqliteOwnedValue> = self.connection.query_row(
+            &format!("SELECT MAX({cursor_column}) FROM {from_clause}"),
+            [],
+            |row| row.get(0),
+        )?;
+        let max_timestamp = match max_cursor {
+            None | Some(SqliteOwnedValue::Null) => return Ok(None),
+            Some(SqliteOwnedValue::Integer(max_timestamp)) => max_timestamp,
+            Some(other) => {
+                return Err(SqliteError::InvalidCursorValue {
+                    column: self.cursor_column.clone(),
+                    value: limit_length(format!("{other:?}"), STANDARD_OBJECT_LENGTH_LIMIT),
+                }
+                .into())
+            }
+        };
+        let overlap_count: i64 = self.connection.query_row(
+            &format!("SELECT COUNT(*) FROM {from_clause} WHERE {cursor_column} >= ?1"),
+            [max_timestamp.saturating_sub(max_transaction_duration)],
+            |row| row.get(0),
+        )?;
+        Ok(Some(LiveState {
+            max_timestamp,
+            overlap_count: overlap_count.try_into().unwrap_or_default(),
+        }))
+    }
+
+    fn storage_type(&self) -> StorageType {
+        StorageType::Sqlite
+    }
+
+    fn offset_key(&self) -> OffsetKey {
+        OffsetKey::Sqlite
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        match &self.target {
+            SqlitePollingTarget::Table(table_name) => format!("SQLite({table_name})").into(),
+            SqlitePollingTarget::Query(_) => "SQLite(query)".into(),
+        }
+    }
+}
+
+/// A `SQLite` input connector polling a table or query by a cursor column.
+pub type SqliteCursorReader = PollingReader<SqlitePollingSource>;
+
+/// Build a [`SqliteCursorReader`] from an opened connection and the polling
+/// parameters supplied through the Python API.
+#[allow(clippy::too_many_arguments)]
+pub fn build_sqlite_cursor_reader(
+    connection: SqliteConnection,
+    target: SqlitePollingTarget,
+    schema: Vec<(String, Type)>,
+    cursor_column: String,
+    id_column: String,
+    mode: ConnectorMode,
+    max_cursor_lag: i64,
+    read_batch_size: usize,
+    poll_interval: Duration,
+) -> Result<SqliteCursorReader, ReadError> {
+    let source = SqlitePollingSource::new(connection, target, schema, cursor_column, id_column)?;
+    Ok(PollingReader::new(
+        source,
+        mode,
+        max_cursor_lag,
+        read_batch_size,
+        poll_interval,
+    ))
+}
+
 /// Writer counterpart to [`SqliteReader`].
 ///
 /// Values are serialized using the same storage layout that
@@ -1279,6 +1537,7 @@ impl SqliteWriter {
         snapshot_mode: bool,
         init_mode: TableWriterInitMode,
         max_batch_size: Option<usize>,
+        dry_run: bool,
     ) -> Result<Self, WriteError> {
         // `TableContext::schema_name` carries the schema for connectors
         // that have a schema concept (PostgreSQL, SQL Server). SQLite
@@ -1373,18 +1632,23 @@ impl SqliteWriter {
             }
         }
 
-        init_mode.initialize(
-            &quoted_table_name,
-            value_fields,
-            key_field_names,
-            !snapshot_mode,
-            |query| {
-                connection.execute(query, [])?;
-                Ok(())
-            },
-            Self::sqlite_data_type,
-            quote_sqlite_identifier,
-        )?;
+        // A dry run only checks that the writer can be created, so it
+        // must not create or replace the destination table. The table
+        // it would write to is then only known for `Default`.
+        if !dry_run {
+            init_mode.initialize(
+                &quoted_table_name,
+                value_fields,
+                key_field_names,
+                !snapshot_mode,
+                |query| {
+                    connection.execute(query, [])?;
+                    Ok(())
+                },
+                Self::sqlite_data_type,
+                quote_sqlite_identifier,
+            )?;
+        }
 
         // After init_mode has had its say, verify the destination table
         // really exists and carries every column the writer plans to
@@ -1394,12 +1658,14 @@ impl SqliteWriter {
         // shape would only surface as a driver-level panic at flush
         // time. `Replace` always just created the table so the check
         // is also safe there (it'll trivially pass).
-        Self::check_destination_compatibility(
-            &connection,
-            &table_name,
-            value_fields,
-            snapshot_mode,
-        )?;
+        if !dry_run || init_mode == TableWriterInitMode::Default {
+            Self::check_destination_compatibility(
+                &connection,
+                &table_name,
+                value_fields,
+                snapshot_mode,
+            )?;
+        }
 
         let query_template = SqlQueryTemplate::new(
             snapshot_mode,
@@ -1579,10 +1845,13 @@ impl SqliteWriter {
             | Type::Pointer
             | Type::DateTimeNaive
             | Type::DateTimeUtc
+            | Type::DateTimeZoned
             | Type::Json
             | Type::Tuple(_)
             | Type::List(_)
             | Type::Array(_, _)
+            | Type::Struct(_)
+            | Type::Map(_)
             | Type::PyObjectWrapper => "TEXT",
             Type::Any | Type::Future(_) => return Err(WriteError::UnsupportedType(type_.clone())),
         };
@@ -1602,8 +1871,14 @@ impl SqliteWriter {
             Value::Pointer(k) => SqliteOwnedValue::Text(k.to_string()),
             Value::DateTimeNaive(dt) => SqliteOwnedValue::Text(dt.to_string()),
             Value::DateTimeUtc(dt) => SqliteOwnedValue::Text(dt.to_string()),
+            Value::DateTimeZoned(dt) => SqliteOwnedValue::Text(dt.to_string()),
             Value::Duration(d) => SqliteOwnedValue::Integer(d.nanoseconds()),
-            Value::Json(_) | Value::Tuple(_) | Value::IntArray(_) | Value::FloatArray(_) => {
+            Value::Json(_)
+            | Value::Tuple(_)
+            | Value::IntArray(_)
+            | Value::FloatArray(_)
+            | Value::Struct(_)
+            | Value::Map(_) => {
                 let json = serialize_value_to_json(value)?;
                 SqliteOwnedValue::Text(json.to_string())
             }
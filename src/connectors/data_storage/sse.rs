@@ -0,0 +1,307 @@
+// Copyright © 2026 Pathway
+
+use log::{info, warn};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader};
+use std::mem::take;
+use std::time::Duration;
+
+use reqwest::blocking::{Client, Response};
+use reqwest::header::{HeaderMap, ACCEPT, CACHE_CONTROL};
+use reqwest::StatusCode;
+
+use crate::connectors::data_storage::ReaderContext;
+use crate::connectors::{OffsetKey, OffsetValue, ReadError, ReadResult, Reader, StorageType};
+use crate::persistence::frontier::OffsetAntichain;
+
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum SseError {
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("failed to read the event stream: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("the server responded with the status {0} instead of an event stream")]
+    UnexpectedStatus(StatusCode),
+}
+
+const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+
+// https://html.spec.whatwg.org/multipage/server-sent-events.html#dispatchMessage
+const DEFAULT_EVENT_TYPE: &str = "message";
+
+/// An event dispatched from a Server-Sent Events stream.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SseEvent {
+    pub event_type: String,
+    pub data: String,
+    pub last_event_id: String,
+}
+
+/// Interprets the lines of a Server-Sent Events stream as described in
+/// <https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation>.
+///
+/// The lines are given without the terminating line feed or carriage return and
+/// line feed. An event is dispatched on an empty line. The id of the last event
+/// persists across the events that don't set it, so it's what the client sends
+/// as `Last-Event-ID` when it reconnects.
+#[derive(Debug, Default)]
+pub struct SseEventParser {
+    data: String,
+    event_type: String,
+    last_event_id: String,
+    reconnection_time: Option<Duration>,
+    is_first_line: bool,
+}
+
+impl SseEventParser {
+    pub fn new(last_event_id: String) -> Self {
+        Self {
+            last_event_id,
+            is_first_line: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        let line = if take(&mut self.is_first_line) {
+            line.strip_prefix('\u{feff}').unwrap_or(line)
+        } else {
+            line
+        };
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => value.clone_into(&mut self.event_type),
+            "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            }
+            "id" if !value.contains('\0') => value.clone_into(&mut self.last_event_id),
+            "retry" if !value.is_empty() && value.bytes().all(|c| c.is_ascii_digit()) => {
+                if let Ok(milliseconds) = value.parse() {
+                    self.reconnection_time = Some(Duration::from_millis(milliseconds));
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// The reconnection time set by the server since the last call, if any.
+    pub fn take_reconnection_time(&mut self) -> Option<Duration> {
+        self.reconnection_time.take()
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let mut data = take(&mut self.data);
+        let event_type = take(&mut self.event_type);
+        if data.is_empty() {
+            return None;
+        }
+        data.pop();
+        Some(SseEvent {
+            event_type: if event_type.is_empty() {
+                DEFAULT_EVENT_TYPE.to_string()
+            } else {
+                event_type
+            },
+            data,
+            last_event_id: self.last_event_id.clone(),
+        })
+    }
+}
+
+struct EventStream {
+    lines: BufReader<Response>,
+    parser: SseEventParser,
+}
+
+/// Reads the events of a Server-Sent Events stream over HTTP.
+///
+/// The data of each event becomes an entry. When the server closes the stream,
+/// the reader reconnects after the reconnection time, which the server can change
+/// with the `retry` field, and sends the id of the last event received in the
+/// `Last-Event-ID` header, so that a server supporting it resumes the stream
+/// after that event. The id is also stored in the frontier, so that it's sent on
+/// the first connection after a restart as well.
+///
+/// A response with the status 204 No Content tells the client not to reconnect,
+/// so the reading finishes.
+#[allow(clippy::module_name_repetitions)]
+pub struct SseReader {
+    client: Option<Client>,
+    url: String,
+    headers: HeaderMap,
+    event_types: Option<HashSet<String>>,
+    reconnection_time: Duration,
+    stream: Option<EventStream>,
+    last_event_id: String,
+    entries_read: usize,
+}
+
+impl SseReader {
+    pub fn new(
+        url: String,
+        headers: HeaderMap,
+        event_types: Option<HashSet<String>>,
+        reconnection_time: Duration,
+    ) -> Self {
+        Self {
+            client: None,
+            url,
+            headers,
+            event_types,
+            reconnection_time,
+            stream: None,
+            last_event_id: String::new(),
+            entries_read: 0,
+        }
+    }
+
+    fn connect(&mut self) -> Result<Option<EventStream>, SseError> {
+        // The blocking client runs its own runtime, so it's created in the reader's
+        // thread, not in the one holding the GIL.
+        if self.client.is_none() {
+            self.client = Some(Client::builder().timeout(None).build()?);
+        }
+        let client = self.client.as_ref().expect("the client must be created");
+        let mut request = client
+            .get(&self.url)
+            .headers(self.headers.clone())
+            .header(ACCEPT, "text/event-stream")
+            .header(CACHE_CONTROL, "no-cache");
+        if !self.last_event_id.is_empty() {
+            request = request.header(LAST_EVENT_ID_HEADER, &self.last_event_id);
+        }
+        let response = request.send()?;
+        let status = response.status();
+        if status == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(SseError::UnexpectedStatus(status));
+        }
+        info!(
+            "Connected to the event stream {} after the event id {:?}",
+            self.url, self.last_event_id
+        );
+        Ok(Some(EventStream {
+            lines: BufReader::new(response),
+            parser: SseEventParser::new(self.last_event_id.clone()),
+        }))
+    }
+
+    fn read_line(&mut self) -> Result<Option<Vec<u8>>, SseError> {
+        let stream = self.stream.as_mut().expect("the stream must be connected");
+        let mut line = Vec::new();
+        if stream.lines.read_until(b'\n', &mut line)? == 0 {
+            return Ok(None);
+        }
+        if line.last() == Some(&b'\n') {
+            line.pop();
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+        }
+        Ok(Some(line))
+    }
+}
+
+impl Reader for SseReader {
+    fn read(&mut self) -> Result<ReadResult, ReadError> {
+        loop {
+            if self.stream.is_none() {
+                match self.connect()? {
+                    Some(stream) => self.stream = Some(stream),
+                    None => return Ok(ReadResult::Finished),
+                }
+            }
+
+            let line = match self.read_line() {
+                Ok(Some(line)) => line,
+                Ok(None) => {
+                    warn!(
+                        "The event stream {} was closed, reconnecting in {:?}",
+                        self.url, self.reconnection_time
+                    );
+                    self.stream = None;
+                    std::thread::sleep(self.reconnection_time);
+                    continue;
+                }
+                Err(e) => {
+                    self.stream = None;
+                    return Err(e.into());
+                }
+            };
+
+            let stream = self.stream.as_mut().expect("the stream must be connected");
+            let event = stream.parser.process_line(&String::from_utf8_lossy(&line));
+            if let Some(reconnection_time) = stream.parser.take_reconnection_time() {
+                self.reconnection_time = reconnection_time;
+            }
+            let Some(event) = event else {
+                continue;
+            };
+            self.last_event_id.clone_from(&event.last_event_id);
+            if self
+                .event_types
+                .as_ref()
+                .is_some_and(|event_types| !event_types.contains(&event.event_type))
+            {
+                continue;
+            }
+
+            self.entries_read += 1;
+            let context = ReaderContext::from_key_value(None, Some(event.data.into_bytes()));
+            let offset = (
+                OffsetKey::Sse,
+                OffsetValue::SseEventId {
+                    last_event_id: event.last_event_id,
+                    entries_read: self.entries_read,
+                },
+            );
+            return Ok(ReadResult::Data(context, offset));
+        }
+    }
+
+    fn seek(&mut self, frontier: &OffsetAntichain) -> Result<(), ReadError> {
+        if let Some(offset) = frontier.get_offset(&OffsetKey::Sse) {
+            if let OffsetValue::SseEventId {
+                last_event_id,
+                entries_read,
+            } = offset
+            {
+                self.last_event_id.clone_from(last_event_id);
+                self.entries_read = *entries_read;
+                self.stream = None;
+            } else {
+                warn!("Unexpected offset type for the Server-Sent Events reader: {offset:?}");
+            }
+        }
+        Ok(())
+    }
+
+    fn storage_type(&self) -> StorageType {
+        StorageType::Sse
+    }
+
+    fn max_allowed_consecutive_errors(&self) -> usize {
+        32
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        format!("Sse({})", self.url).into()
+    }
+}
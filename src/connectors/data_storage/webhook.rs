@@ -0,0 +1,375 @@
+// Copyright © 2026 Pathway
+
+use std::collections::HashMap;
+use std::mem::take;
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+use log::warn;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT};
+use reqwest::{Client as HttpClient, Method, StatusCode, Url};
+use tokio::runtime::Runtime as TokioRuntime;
+
+use crate::async_runtime::create_async_tokio_runtime;
+use crate::connectors::data_format::{FormatterContext, TemplateError, TextTemplate};
+use crate::connectors::{WriteError, Writer};
+use crate::retry::RetryConfig;
+
+// Upper bound on the payloads buffered before a mid-minibatch flush, so that a
+// huge minibatch doesn't make the memory usage grow unbounded.
+const MAX_BUFFERED_PAYLOADS: usize = 10_000;
+
+// Only the beginning of a rejected request's response is kept for the error message
+const MAX_ERROR_BODY_LENGTH: usize = 1024;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum WebhookError {
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+
+    #[error(transparent)]
+    Template(#[from] TemplateError),
+
+    #[error("invalid HTTP method {0:?}")]
+    InvalidMethod(String),
+
+    #[error("invalid request URL {0:?}")]
+    InvalidUrl(String),
+
+    #[error("invalid header name {0:?}")]
+    InvalidHeaderName(String),
+
+    #[error("invalid value of the header {0:?}")]
+    InvalidHeaderValue(String),
+
+    #[error("request to {url} was rejected with the status {status}: {body}")]
+    UnexpectedStatus {
+        url: String,
+        status: StatusCode,
+        body: String,
+    },
+}
+
+/// The way the changes sharing the same URL and headers are put into a single
+/// request body.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WebhookBatchFormat {
+    /// One payload per line.
+    NdJson,
+    /// A JSON array of the payloads, which must be JSON documents themselves.
+    JsonArray,
+}
+
+impl WebhookBatchFormat {
+    fn build_body(self, payloads: Vec<Vec<u8>>) -> Vec<u8> {
+        let mut body = Vec::with_capacity(payloads.iter().map(|p| p.len() + 1).sum::<usize>() + 1);
+        match self {
+            Self::NdJson => {
+                for payload in payloads {
+                    body.extend_from_slice(&payload);
+                    body.push(b'\n');
+                }
+            }
+            Self::JsonArray => {
+                body.push(b'[');
+                for (index, payload) in payloads.into_iter().enumerate() {
+                    if index > 0 {
+                        body.push(b',');
+                    }
+                    body.extend_from_slice(&payload);
+                }
+                body.push(b']');
+            }
+        }
+        body
+    }
+}
+
+/// Defines which failed requests are repeated and how long to wait between the attempts.
+/// The requests that failed before a response was received, for example because of a
+/// timeout, are always retried.
+#[derive(Clone, Debug)]
+pub struct WebhookRetryPolicy {
+    pub n_retries: usize,
+    pub first_delay: Duration,
+    pub backoff_factor: f64,
+    pub jitter: Duration,
+    pub retry_codes: Vec<u16>,
+}
+
+impl WebhookRetryPolicy {
+    fn retry_config(&self) -> RetryConfig {
+        RetryConfig::new(self.first_delay, self.backoff_factor, self.jitter)
+    }
+
+    fn is_retriable(&self, status: StatusCode) -> bool {
+        self.retry_codes.contains(&status.as_u16())
+    }
+}
+
+struct PendingRequest {
+    url: Url,
+    headers: HeaderMap,
+    payloads: Vec<Vec<u8>>,
+}
+
+/// Sends the formatted changes to an HTTP endpoint. The URL and the header
+/// names and values are templates rendered from the values of each change, see
+/// [`TextTemplate`] for the syntax.
+///
+/// Without a batch format, every change is sent in its own request. Otherwise,
+/// the changes of a minibatch that resolve to the same URL and headers are
+/// sent together, at most `max_batch_size` of them per request. The requests
+/// of a minibatch are issued at the flush, with up to `max_concurrent_requests`
+/// of them in flight at once, and the flush only completes once all of them are
+/// delivered, so the requests of different minibatches never overlap.
+pub struct WebhookWriter {
+    runtime: TokioRuntime,
+    client: HttpClient,
+    method: Method,
+    url_template_source: String,
+    url_template: TextTemplate,
+    header_templates: Vec<(TextTemplate, TextTemplate)>,
+    batch_format: Option<WebhookBatchFormat>,
+    max_batch_size: usize,
+    retry_policy: WebhookRetryPolicy,
+    max_concurrent_requests: usize,
+
+    pending_requests: Vec<PendingRequest>,
+    // Positions of the requests still accepting payloads in `pending_requests`,
+    // keyed by the rendered URL and headers. Only used when batching.
+    open_batches: HashMap<(String, Vec<(String, String)>), usize>,
+    n_buffered_payloads: usize,
+}
+
+impl WebhookWriter {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        method: &str,
+        url_template: &str,
+        header_templates: &[(String, String)],
+        value_field_names: &[String],
+        content_type: Option<&str>,
+        batch_format: Option<WebhookBatchFormat>,
+        max_batch_size: Option<usize>,
+        retry_policy: WebhookRetryPolicy,
+        max_concurrent_requests: usize,
+        connect_timeout: Option<Duration>,
+        request_timeout: Option<Duration>,
+    ) -> Result<Self, WriteError> {
+        let method = Method::from_bytes(method.to_uppercase().as_bytes())
+            .map_err(|_| WebhookError::InvalidMethod(method.to_string()))?;
+        let url_template_source = url_template.to_string();
+        let url_template =
+            TextTemplate::compile(url_template, value_field_names).map_err(WebhookError::from)?;
+        let header_templates = header_templates
+            .iter()
+            .map(|(name, value)| {
+                Ok((
+                    TextTemplate::compile(name, value_field_names)?,
+                    TextTemplate::compile(value, value_field_names)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, TemplateError>>()
+            .map_err(WebhookError::from)?;
+
+        // The client's default headers apply unless a request sets them itself,
+        // so the configured headers take precedence over these.
+        let mut default_headers = HeaderMap::new();
+        default_headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(&format!("pathway/{}", env!("CARGO_PKG_VERSION")))
+                .expect("the version must be a valid header value"),
+        );
+        if let Some(content_type) = content_type {
+            default_headers.insert(
+                CONTENT_TYPE,
+                HeaderValue::from_str(content_type)
+                    .map_err(|_| WebhookError::InvalidHeaderValue(CONTENT_TYPE.to_string()))?,
+            );
+        }
+        let mut client = HttpClient::builder().default_headers(default_headers);
+        if let Some(connect_timeout) = connect_timeout {
+            client = client.connect_timeout(connect_timeout);
+        }
+        if let Some(request_timeout) = request_timeout {
+            client = client.timeout(request_timeout);
+        }
+        let client = client.build().map_err(WebhookError::from)?;
+
+        Ok(Self {
+            runtime: create_async_tokio_runtime()?,
+            client,
+            method,
+            url_template_source,
+            url_template,
+            header_templates,
+            batch_format,
+            max_batch_size: max_batch_size.unwrap_or(usize::MAX).max(1),
+            retry_policy,
+            max_concurrent_requests: max_concurrent_requests.max(1),
+            pending_requests: Vec::new(),
+            open_batches: HashMap::new(),
+            n_buffered_payloads: 0,
+        })
+    }
+
+    fn render_headers(
+        &self,
+        data: &FormatterContext,
+    ) -> Result<(Vec<(String, String)>, HeaderMap), WriteError> {
+        let mut rendered = Vec::with_capacity(self.header_templates.len());
+        let mut headers = HeaderMap::with_capacity(self.header_templates.len());
+        for (name_template, value_template) in &self.header_templates {
+            let name = name_template.render(&data.values, data.time, data.diff)?;
+            let value = value_template.render(&data.values, data.time, data.diff)?;
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| WebhookError::InvalidHeaderName(name.clone()))?;
+            let header_value = HeaderValue::from_str(&value)
+                .map_err(|_| WebhookError::InvalidHeaderValue(name.clone()))?;
+            headers.append(header_name, header_value);
+            rendered.push((name, value));
+        }
+        Ok((rendered, headers))
+    }
+}
+
+async fn send_request(
+    client: &HttpClient,
+    method: Method,
+    url: Url,
+    headers: HeaderMap,
+    body: Vec<u8>,
+    retry_policy: &WebhookRetryPolicy,
+) -> Result<(), WebhookError> {
+    let mut retry = retry_policy.retry_config();
+    let mut n_attempt = 0;
+    loop {
+        let response = client
+            .request(method.clone(), url.clone())
+            .headers(headers.clone())
+            .body(body.clone())
+            .send()
+            .await;
+        let error = match response {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) => {
+                let status = response.status();
+                let mut body = response.text().await.unwrap_or_default();
+                if body.len() > MAX_ERROR_BODY_LENGTH {
+                    let mut end = MAX_ERROR_BODY_LENGTH;
+                    while !body.is_char_boundary(end) {
+                        end -= 1;
+                    }
+                    body.truncate(end);
+                }
+                let error = WebhookError::UnexpectedStatus {
+                    url: url.to_string(),
+                    status,
+                    body,
+                };
+                if !retry_policy.is_retriable(status) {
+                    return Err(error);
+                }
+                error
+            }
+            Err(e) => e.into(),
+        };
+        if n_attempt == retry_policy.n_retries {
+            return Err(error);
+        }
+        n_attempt += 1;
+        warn!(
+            "Request to {url} failed: {error}. Retrying, attempt {n_attempt} of {}.",
+            retry_policy.n_retries
+        );
+        retry.sleep_after_error_async().await;
+    }
+}
+
+impl Writer for WebhookWriter {
+    fn write(&mut self, data: FormatterContext) -> Result<(), WriteError> {
+        let url = self
+            .url_template
+            .render(&data.values, data.time, data.diff)?;
+        let parsed_url = Url::parse(&url).map_err(|_| WebhookError::InvalidUrl(url.clone()))?;
+        let (rendered_headers, headers) = self.render_headers(&data)?;
+
+        for payload in data.payloads {
+            let payload = payload.into_raw_bytes()?;
+            self.n_buffered_payloads += 1;
+            if self.batch_format.is_none() {
+                self.pending_requests.push(PendingRequest {
+                    url: parsed_url.clone(),
+                    headers: headers.clone(),
+                    payloads: vec![payload],
+                });
+                continue;
+            }
+
+            let batch_key = (url.clone(), rendered_headers.clone());
+            let open_batch =
+                self.open_batches.get(&batch_key).copied().filter(|index| {
+                    self.pending_requests[*index].payloads.len() < self.max_batch_size
+                });
+            if let Some(index) = open_batch {
+                self.pending_requests[index].payloads.push(payload);
+            } else {
+                self.open_batches
+                    .insert(batch_key, self.pending_requests.len());
+                self.pending_requests.push(PendingRequest {
+                    url: parsed_url.clone(),
+                    headers: headers.clone(),
+                    payloads: vec![payload],
+                });
+            }
+        }
+
+        if self.n_buffered_payloads >= MAX_BUFFERED_PAYLOADS {
+            self.flush(false)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, _forced: bool) -> Result<(), WriteError> {
+        if self.pending_requests.is_empty() {
+            return Ok(());
+        }
+        let pending_requests = take(&mut self.pending_requests);
+        self.open_batches.clear();
+        self.n_buffered_payloads = 0;
+
+        let client = &self.client;
+        let method = &self.method;
+        let retry_policy = &self.retry_policy;
+        let batch_format = self.batch_format;
+        let results: Vec<Result<(), WebhookError>> = self.runtime.block_on(
+            stream::iter(pending_requests)
+                .map(|request| {
+                    let body = match batch_format {
+                        Some(batch_format) => batch_format.build_body(request.payloads),
+                        None => request.payloads.into_iter().next().unwrap_or_default(),
+                    };
+                    send_request(
+                        client,
+                        method.clone(),
+                        request.url,
+                        request.headers,
+                        body,
+                        retry_policy,
+                    )
+                })
+                .buffer_unordered(self.max_concurrent_requests)
+                .collect(),
+        );
+        for result in results {
+            result?;
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> String {
+        format!("Webhook({})", self.url_template_source)
+    }
+}
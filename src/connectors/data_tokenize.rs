@@ -1,14 +1,22 @@
 // Copyright © 2026 Pathway
 
+use std::collections::HashMap;
 use std::io::BufReader;
 use std::io::Read;
 use std::mem::take;
 
+use bytes::Bytes;
 use csv::Reader as CsvReader;
 use csv::ReaderBuilder as CsvReaderBuilder;
+use orc_rust::arrow_reader::{
+    ArrowReader as OrcArrowReader, ArrowReaderBuilder as OrcReaderBuilder,
+};
+use orc_rust::projection::ProjectionMask as OrcProjectionMask;
 
-use crate::connectors::data_storage::ReadMethod;
+use crate::connectors::data_storage::data_lake::columns_into_pathway_values;
+use crate::connectors::data_storage::{ReadMethod, ValuesMap};
 use crate::connectors::{DataEventType, ReadError, ReaderContext};
+use crate::engine::Type;
 
 type TokenizedEntry = (ReaderContext, u64); // The second value is a position of the record within the object read
 
@@ -126,3 +134,89 @@ impl Tokenize for BufReaderTokenizer {
         }
     }
 }
+
+/// Decodes ORC files into rows, which are passed further as `Diff` entries
+/// for the `TransparentParser`.
+///
+/// ORC keeps the file metadata in the footer, so the object is read into
+/// memory in full before decoding. Only the columns present in the schema are
+/// decoded, and the stripes are converted into rows one batch at a time.
+pub struct OrcTokenizer {
+    column_types: HashMap<String, Type>,
+    batch_size: usize,
+    current_event_type: DataEventType,
+    reader: Option<OrcArrowReader<Bytes>>,
+    pending_rows: std::vec::IntoIter<ValuesMap>,
+    rows_read: u64,
+}
+
+impl OrcTokenizer {
+    pub const DEFAULT_BATCH_SIZE: usize = 8192;
+
+    pub fn new(column_types: HashMap<String, Type>, batch_size: usize) -> Self {
+        Self {
+            column_types,
+            batch_size,
+            current_event_type: DataEventType::Insert,
+            reader: None,
+            pending_rows: Vec::new().into_iter(),
+            rows_read: 0,
+        }
+    }
+}
+
+impl Tokenize for OrcTokenizer {
+    fn set_new_reader(
+        &mut self,
+        mut source: Box<dyn Read + Send + 'static>,
+        data_event_type: DataEventType,
+    ) -> Result<(), ReadError> {
+        let mut contents = Vec::new();
+        source.read_to_end(&mut contents)?;
+        let builder = OrcReaderBuilder::try_new(Bytes::from(contents))?;
+        let projected_columns: Vec<&str> = builder
+            .file_metadata()
+            .root_data_type()
+            .children()
+            .iter()
+            .map(|column| column.name())
+            .filter(|name| self.column_types.contains_key(*name))
+            .collect();
+        let projection = OrcProjectionMask::named_roots(
+            builder.file_metadata().root_data_type(),
+            &projected_columns,
+        );
+        self.reader = Some(
+            builder
+                .with_projection(projection)
+                .with_batch_size(self.batch_size)
+                .build(),
+        );
+        self.pending_rows = Vec::new().into_iter();
+        self.current_event_type = data_event_type;
+        self.rows_read = 0;
+        Ok(())
+    }
+
+    fn next_entry(&mut self) -> Result<Option<(ReaderContext, u64)>, ReadError> {
+        loop {
+            if let Some(values) = self.pending_rows.next() {
+                self.rows_read += 1;
+                return Ok(Some((
+                    ReaderContext::from_diff(self.current_event_type, None, values),
+                    self.rows_read,
+                )));
+            }
+            let Some(ref mut reader) = self.reader else {
+                return Ok(None);
+            };
+            if let Some(batch) = reader.next() {
+                self.pending_rows =
+                    columns_into_pathway_values(&batch?, &self.column_types).into_iter();
+            } else {
+                self.reader = None;
+                return Ok(None);
+            }
+        }
+    }
+}
@@ -5,10 +5,13 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use azure_storage_blobs::blob::Blob as AzureBlob;
 use chrono::DateTime;
 use s3::serde_types::Object as S3Object;
 use serde::{Deserialize, Serialize};
+use ssh2::FileStat as SftpFileStat;
 
+use crate::connectors::data_storage::scanner::hdfs::HdfsFileStatus;
 use crate::timestamp::current_unix_timestamp_secs;
 
 /// Basic metadata for a file-like object
@@ -81,6 +84,49 @@ impl FileLikeMetadata {
         }
     }
 
+    pub fn from_azure_blob(blob: &AzureBlob) -> Self {
+        Self {
+            created_at: blob
+                .properties
+                .creation_time
+                .unix_timestamp()
+                .try_into()
+                .ok(),
+            modified_at: blob
+                .properties
+                .last_modified
+                .unix_timestamp()
+                .try_into()
+                .ok(),
+            owner: None,
+            path: blob.name.clone(),
+            size: blob.properties.content_length,
+            seen_at: current_unix_timestamp_secs(),
+        }
+    }
+
+    pub fn from_hdfs_file_status(path: &str, status: &HdfsFileStatus) -> Self {
+        Self {
+            created_at: None,
+            modified_at: Some(status.modification_time / 1000),
+            owner: status.owner.clone(),
+            path: path.to_string(),
+            size: status.length,
+            seen_at: current_unix_timestamp_secs(),
+        }
+    }
+
+    pub fn from_sftp_stat(path: &str, stat: &SftpFileStat) -> Self {
+        Self {
+            created_at: None,
+            modified_at: stat.mtime,
+            owner: stat.uid.map(|uid| uid.to_string()),
+            path: path.to_string(),
+            size: stat.size.unwrap_or(0),
+            seen_at: current_unix_timestamp_secs(),
+        }
+    }
+
     /// Checks if file contents could have been changed.
     ///
     /// `ScannerTag` and `OwnerInterner::is_changed` mirror exactly the fields
@@ -4,6 +4,7 @@ use base64::engine::general_purpose;
 use base64::Engine;
 use rdkafka::message::{BorrowedMessage as KafkaMessage, Headers, Message};
 use serde::Serialize;
+use serde_json::{Map as JsonMap, Value as JsonValue};
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Serialize)]
@@ -13,6 +14,10 @@ pub struct KafkaMetadata {
     partition: i32,
     offset: i64,
     headers: Vec<(String, Option<String>)>,
+
+    // The headers decoded as UTF-8 and keyed by their names, for the `_headers` column
+    #[serde(skip)]
+    headers_by_name: JsonMap<String, JsonValue>,
 }
 
 impl KafkaMetadata {
@@ -20,6 +25,7 @@ impl KafkaMetadata {
     // that the deletion uses the same metadata entry as the one used
     // during the row insertion.
     pub fn from_rdkafka_message(message: &KafkaMessage) -> Self {
+        let mut headers_by_name = JsonMap::new();
         let headers = if let Some(message_headers) = message.headers() {
             let mut headers = Vec::with_capacity(message_headers.count());
             for header in message_headers.iter() {
@@ -27,6 +33,13 @@ impl KafkaMetadata {
                     header.key.to_string(),
                     header.value.map(|v| general_purpose::STANDARD.encode(v)),
                 ));
+                // If a header is repeated, the last value wins, as in the Kafka clients
+                headers_by_name.insert(
+                    header.key.to_string(),
+                    header.value.map_or(JsonValue::Null, |v| {
+                        JsonValue::String(String::from_utf8_lossy(v).into_owned())
+                    }),
+                );
             }
             headers
         } else {
@@ -39,6 +52,11 @@ impl KafkaMetadata {
             partition: message.partition(),
             offset: message.offset(),
             headers,
+            headers_by_name,
         }
     }
+
+    pub fn headers_by_name(&self) -> JsonValue {
+        JsonValue::Object(self.headers_by_name.clone())
+    }
 }
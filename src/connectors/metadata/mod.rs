@@ -143,6 +143,24 @@ impl SourceMetadata {
         .expect("Internal JSON serialization error")
     }
 
+    /// The headers of the message the entries come from, as a JSON object mapping
+    /// the header names to their values, if the source has message headers.
+    pub fn message_headers(&self) -> Option<serde_json::Value> {
+        match self {
+            Self::Kafka(meta) => Some(meta.headers_by_name()),
+            Self::Polling(_)
+            | Self::FileLike(_)
+            | Self::MongoDb(_)
+            | Self::Mssql(_)
+            | Self::Mysql(_)
+            | Self::SQLite(_)
+            | Self::Iceberg(_)
+            | Self::Parquet(_)
+            | Self::Postgres(_)
+            | Self::Rabbitmq(_) => None,
+        }
+    }
+
     pub fn commits_allowed_in_between(&self) -> bool {
         match self {
             Self::Polling(_)
@@ -18,6 +18,7 @@ use timely::dataflow::operators::probe::Handle;
 
 pub mod adaptors;
 pub mod backlog;
+pub mod control;
 pub mod data_format;
 pub mod data_storage;
 pub mod data_tokenize;
@@ -25,13 +26,19 @@ pub mod metadata;
 pub mod monitoring;
 pub mod offset;
 pub mod posix_like;
+pub mod rate_limit;
+pub mod sink_alignment;
+pub mod sink_commit;
 pub mod synchronization;
+pub mod watermark;
 
 use crate::connectors::monitoring::ConnectorMonitor;
+use crate::engine::clock;
 use crate::engine::error::{DynError, Trace};
 use crate::engine::report_error::{
     LogError, ReportError, SpawnWithReporter, UnwrapWithErrorLogger,
 };
+use crate::engine::shutdown::GracefulShutdown;
 use crate::engine::{DataError, Key, Value};
 
 use crate::connectors::synchronization::{ConnectorGroupAccessor, EntrySendApproval};
@@ -46,16 +53,21 @@ use crate::retry::RetryConfig;
 
 use data_format::{ParseError, ParseResult, ParsedEvent, ParsedEventWithErrors, Parser};
 use data_storage::{
-    DataEventType, ReadError, ReadResult, Reader, ReaderBuilder, ReaderContext, WriteError, Writer,
+    CommittedFrontier, DataEventType, ReadError, ReadResult, Reader, ReaderBuilder, ReaderContext,
+    WriteError, Writer,
 };
 
 pub use adaptors::SessionType;
-use backlog::BacklogTracker;
+use backlog::{AdaptiveBacklogLimit, BacklogLimit, BacklogTracker};
+use control::{register_connector_control, ConnectorControl, ConnectorState};
 pub use data_storage::StorageType;
 pub use offset::{Offset, OffsetKey, OffsetValue};
+use rate_limit::{sleep_unless_stopped, RateLimit, RateLimiter};
+use watermark::{WatermarkStrategy, WatermarkTracker};
 
 const SPECIAL_FIELD_TIME: &str = "time";
 const SPECIAL_FIELD_DIFF: &str = "diff";
+const SPECIAL_FIELD_BEFORE: &str = "before";
 const MAX_EVENTS_BETWEEN_TWO_TIMELY_STEPS: usize = 100_000;
 
 /*
@@ -118,6 +130,13 @@ pub struct Connector {
     n_parse_attempts: usize,
     n_parse_errors_in_log: usize,
     backlog_tracker: BacklogTracker,
+    // Explicit offsets to start reading from, see `with_start_from`.
+    start_from: Option<OffsetAntichain>,
+    rate_limit: RateLimit,
+    watermark_tracker: Option<WatermarkTracker>,
+    // Advanced on each commit if the reader acknowledges the committed entries.
+    committed_frontier: Option<CommittedFrontier>,
+    graceful_shutdown: Option<Arc<GracefulShutdown>>,
 }
 
 #[derive(Debug)]
@@ -255,9 +274,48 @@ impl Connector {
             n_parse_attempts: 0,
             n_parse_errors_in_log: 0,
             backlog_tracker: BacklogTracker::new(),
+            start_from: None,
+            rate_limit: RateLimit::default(),
+            watermark_tracker: None,
+            committed_frontier: None,
+            graceful_shutdown: None,
         }
     }
 
+    /// Makes the reader start from the given offsets, e.g. Kafka partition offsets or
+    /// a CDC log position, instead of from the beginning of the source. The connector
+    /// must not be persisted then, as its persisted state would describe a different
+    /// starting point.
+    #[must_use]
+    pub fn with_start_from(mut self, start_from: OffsetAntichain) -> Self {
+        self.start_from = Some(start_from);
+        self
+    }
+
+    /// Limits the rate at which the reader reads from the source in realtime. The limit
+    /// is for this reader only, the callers share it among the parallel readers.
+    #[must_use]
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    /// Makes the connector also commit the rows it has read when the watermark of the
+    /// source advances or when the source becomes idle, see [`WatermarkStrategy`].
+    #[must_use]
+    pub fn with_watermark_strategy(mut self, strategy: WatermarkStrategy) -> Self {
+        self.watermark_tracker = Some(WatermarkTracker::new(strategy));
+        self
+    }
+
+    /// Makes the connector commit the rows it has read and stop once a graceful shutdown
+    /// of the computation is requested, see [`GracefulShutdown`].
+    #[must_use]
+    pub fn with_graceful_shutdown(mut self, graceful_shutdown: Arc<GracefulShutdown>) -> Self {
+        self.graceful_shutdown = Some(graceful_shutdown);
+        self
+    }
+
     /// The optimization method. Used when streaming objects that are
     /// tied into atomic batches. Each batch must end up in a single
     /// Pathway minibatch, but the reverse is not necessarily true:
@@ -274,6 +332,39 @@ impl Connector {
         self.backlog_tracker.last_timestamp_with_data() == Some(self.current_timestamp)
     }
 
+    fn commit_on_watermark<F>(&mut self, ctx: &mut ParseContext<'_, F>)
+    where
+        F: FnMut(Option<&Vec<Value>>, Option<&Offset>) -> Key,
+    {
+        if !self.current_minibatch_has_data() {
+            return;
+        }
+        let Some(watermark_tracker) = &mut self.watermark_tracker else {
+            return;
+        };
+        if watermark_tracker.should_commit(Instant::now()) {
+            let parsed_entries = vec![ParsedEventWithErrors::AdvanceTime];
+            self.on_parsed_data(parsed_entries, None, ctx);
+        }
+    }
+
+    // The next time the poller needs to be called, either for an autocommit or to
+    // notice that the source has become idle.
+    fn next_wakeup(&self, next_commit_at: Option<SystemTime>) -> Option<SystemTime> {
+        let now = Instant::now();
+        let idle_at = self
+            .watermark_tracker
+            .as_ref()
+            .and_then(WatermarkTracker::idle_deadline)
+            // once the source is idle, there's nothing to wait for
+            .filter(|deadline| *deadline > now)
+            .map(|deadline| clock::now() + (deadline - now));
+        match (next_commit_at, idle_at) {
+            (Some(commit_at), Some(idle_at)) => Some(commit_at.min(idle_at)),
+            (commit_at, idle_at) => commit_at.or(idle_at),
+        }
+    }
+
     fn advance_time(&mut self, input_session: &mut dyn InputAdaptor<Timestamp>) -> Timestamp {
         self.advance_time_to(input_session, Timestamp::new_from_current_time())
     }
@@ -386,6 +477,7 @@ impl Connector {
     }
 
     #[allow(clippy::too_many_lines)]
+    #[allow(clippy::too_many_arguments)]
     pub fn read_realtime_updates(
         reader: &mut dyn Reader,
         parser: &mut dyn Parser,
@@ -393,6 +485,9 @@ impl Connector {
         main_thread: &Thread,
         error_reporter: &(impl ReportError + 'static),
         mut group: Option<ConnectorGroupAccessor>,
+        rate_limit: RateLimit,
+        control: Option<&ConnectorControl>,
+        graceful_shutdown: Option<&GracefulShutdown>,
     ) {
         let use_rare_wakeup = env::var("PATHWAY_YOLO_RARE_WAKEUPS") == Ok("1".to_string());
         let mut amt_send = 0;
@@ -407,7 +502,15 @@ impl Connector {
         // reset after any successful read so an isolated error doesn't slow
         // down steady reading.
         let mut error_backoff = RetryConfig::default();
+        let mut rate_limiter = (!rate_limit.is_unlimited()).then(|| RateLimiter::new(rate_limit));
         loop {
+            if control.is_some_and(ConnectorControl::wait_until_resumed) {
+                info!("The input connector has been drained, reading from the source stopped");
+                sender
+                    .send(Entry::RealtimeEvent(ReadResult::Finished))
+                    .unwrap_or(());
+                break;
+            }
             let row_read_result = reader.read();
             let finished = matches!(row_read_result, Ok(ReadResult::Finished));
             let is_read_error = row_read_result.is_err();
@@ -416,6 +519,30 @@ impl Connector {
                 Ok(ReadResult::Data(reader_context, offset)) => {
                     match parser.parse(&reader_context) {
                         Ok(entries) => {
+                            if let Some(rate_limiter) = rate_limiter.as_mut() {
+                                let n_rows = entries
+                                    .iter()
+                                    .filter(|entry| {
+                                        !matches!(entry, ParsedEventWithErrors::AdvanceTime)
+                                    })
+                                    .count();
+                                let pause = rate_limiter.on_read(
+                                    n_rows,
+                                    reader_context.payload_size(),
+                                    Instant::now(),
+                                );
+                                if !pause.is_zero() {
+                                    // let the worker process what has been sent so far
+                                    main_thread.unpark();
+                                    sleep_unless_stopped(pause, || {
+                                        graceful_shutdown
+                                            .is_some_and(GracefulShutdown::is_requested)
+                                            || control.is_some_and(|control| {
+                                                control.state() == ConnectorState::Drained
+                                            })
+                                    });
+                                }
+                            }
                             if let Some(group) = group.as_mut() {
                                 let mut entries_for_sending = Vec::new();
                                 let mut approvals = Vec::new();
@@ -625,14 +752,14 @@ impl Connector {
         persistence_mode: PersistenceMode,
         snapshot_access: SnapshotAccess,
         error_reporter: impl ReportError + 'static,
-        max_backlog_size: Option<usize>,
+        backlog_limit: BacklogLimit,
         timestamp_at_start: Timestamp,
     ) -> Result<StartedConnectorState, EngineError> {
         assert_eq!(self.num_columns, parser.column_count());
         self.timestamp_at_start = timestamp_at_start;
 
         let main_thread = thread::current();
-        let (sender, receiver) = match max_backlog_size {
+        let (sender, receiver) = match backlog_limit.max_size {
             Some(size) => channel::bounded(size),
             None => channel::unbounded(),
         };
@@ -656,6 +783,19 @@ impl Connector {
         .map_err(|e| EngineError::SnapshotWriter(Box::new(e)))?;
 
         let realtime_reader_group = self.group.clone();
+        self.committed_frontier = reader.committed_frontier();
+        let rate_limit = self.rate_limit;
+        let graceful_shutdown = self.graceful_shutdown.clone();
+        let control = unique_name.map(|unique_name| {
+            let control = Arc::new(ConnectorControl::new());
+            register_connector_control(unique_name, &control);
+            control
+        });
+        let start_from = self.start_from.take();
+        assert!(
+            start_from.is_none() || persistent_id.is_none(),
+            "a connector starting from explicit offsets can't be persisted"
+        );
         let input_thread_handle = thread::Builder::new()
             .name(thread_name)
             .spawn_with_reporter(error_reporter, move |reporter| {
@@ -667,6 +807,12 @@ impl Connector {
                 });
 
                 let mut reader = reader.build()?;
+                if let Some(start_from) = start_from {
+                    info!("Start the data source from the requested offsets {start_from:?}");
+                    reader
+                        .start_from(&start_from)
+                        .map_err(|e| EngineError::ReaderFailed(Box::new(e)))?;
+                }
                 Self::read_snapshot(
                     &mut *reader,
                     persistent_storage.as_ref(),
@@ -686,6 +832,9 @@ impl Connector {
                         &main_thread,
                         reporter,
                         realtime_reader_group,
+                        rate_limit,
+                        control.as_deref(),
+                        graceful_shutdown.as_deref(),
                     );
                 }
 
@@ -693,7 +842,7 @@ impl Connector {
             })
             .expect("connector thread creation failed");
 
-        let mut next_commit_at = self.commit_duration.map(|x| SystemTime::now() + x);
+        let mut next_commit_at = self.commit_duration.map(|x| clock::now() + x);
         let mut backfilling_finished = false;
 
         let connector_monitor = Rc::new(RefCell::new(ConnectorMonitor::new(reader_name)));
@@ -701,8 +850,10 @@ impl Connector {
         let mut commit_allowed = true;
         let mut deferred_events = Vec::new();
         let mut idleness_started_at = Instant::now();
+        let mut adaptive_backlog_limit =
+            backlog_limit.target_latency.map(AdaptiveBacklogLimit::new);
         let poller = Box::new(move || {
-            let iteration_start = SystemTime::now();
+            let iteration_start = clock::now();
             if matches!(persistence_mode, PersistenceMode::SpeedrunReplay)
                 && !backfilling_finished
                 && output_probe.less_than(input_session.time())
@@ -718,6 +869,21 @@ impl Connector {
                 connector_monitor: connector_monitor.clone(),
             };
 
+            let shutdown_requested = self
+                .graceful_shutdown
+                .as_ref()
+                .is_some_and(|shutdown| shutdown.is_requested());
+            // An atomic batch in progress is completed first, it can't be split.
+            if shutdown_requested && commit_allowed {
+                if backfilling_finished && self.current_minibatch_has_data() {
+                    let parsed_entries = vec![ParsedEventWithErrors::AdvanceTime];
+                    self.on_parsed_data(parsed_entries, None, &mut parse_context);
+                }
+                info!("Graceful shutdown requested, the input connector stops reading");
+                (*connector_monitor).borrow_mut().finish();
+                return ControlFlow::Break(());
+            }
+
             if let Some(next_commit_at_timestamp) = next_commit_at {
                 if next_commit_at_timestamp <= iteration_start {
                     if backfilling_finished && commit_allowed {
@@ -736,6 +902,23 @@ impl Connector {
             }
 
             self.backlog_tracker.advance_with_probe(&output_probe);
+            if let Some(adaptive_backlog_limit) = &mut adaptive_backlog_limit {
+                let now = Instant::now();
+                adaptive_backlog_limit.update(
+                    self.backlog_tracker.oldest_entry_age(now),
+                    self.backlog_tracker.backlog_size(),
+                    now,
+                );
+            }
+            // The entries beyond the adaptive limit stay in the channel, which is
+            // bounded by the hard limit only, so the reading thread isn't paused by them.
+            let adaptive_limit = adaptive_backlog_limit
+                .as_ref()
+                .map(AdaptiveBacklogLimit::limit);
+            let max_backlog_size = match (backlog_limit.max_size, adaptive_limit) {
+                (Some(max_size), Some(adaptive_limit)) => Some(max_size.min(adaptive_limit)),
+                (max_size, adaptive_limit) => max_size.or(adaptive_limit),
+            };
             let mut n_entries_in_batch = 0;
             loop {
                 if let Some(max_backlog_size) = max_backlog_size {
@@ -764,7 +947,10 @@ impl Connector {
                 // yield to timely to perform the work. That may or may not lead to time advancement.
                 n_entries_in_batch += 1;
                 if n_entries_in_batch == MAX_EVENTS_BETWEEN_TWO_TIMELY_STEPS {
-                    return ControlFlow::Continue(next_commit_at);
+                    if backfilling_finished && commit_allowed {
+                        self.commit_on_watermark(&mut parse_context);
+                    }
+                    return ControlFlow::Continue(self.next_wakeup(next_commit_at));
                 }
                 match receiver.try_recv() {
                     Ok(Entry::RealtimeEvent(ReadResult::Finished)) => {
@@ -857,7 +1043,10 @@ impl Connector {
                                 }
                             }
                         }
-                        return ControlFlow::Continue(next_commit_at);
+                        if backfilling_finished && commit_allowed {
+                            self.commit_on_watermark(&mut parse_context);
+                        }
+                        return ControlFlow::Continue(self.next_wakeup(next_commit_at));
                     }
                     Err(TryRecvError::Disconnected) => {
                         (*connector_monitor).borrow_mut().finish();
@@ -927,6 +1116,10 @@ impl Connector {
                 let (offset_key, offset_value) = offset;
                 if ctx.snapshot_writer.is_some() {
                     assert!(*backfilling_finished);
+                }
+                // Without persistence, the frontier is only needed by the readers
+                // acknowledging the committed entries
+                if ctx.snapshot_writer.is_some() || self.committed_frontier.is_some() {
                     self.current_frontier
                         .advance_offset(offset_key, offset_value);
                 }
@@ -945,6 +1138,7 @@ impl Connector {
                 assert!(!*backfilling_finished);
                 *backfilling_finished = true;
                 self.current_frontier = restored_frontier;
+                ctx.connector_monitor.borrow_mut().finish_replay();
 
                 if needs_time_advancement {
                     // Open the start-up batch at the shared `timestamp_at_start`,
@@ -1053,6 +1247,9 @@ impl Connector {
                         error!("There are {} tokens in the entry, but the expected number of tokens was {}", values.len(), self.num_columns);
                         continue;
                     }
+                    if let Some(watermark_tracker) = &mut self.watermark_tracker {
+                        watermark_tracker.on_row(&values, Instant::now());
+                    }
                     Self::on_insert(key.expect("No key"), values, ctx.input_session);
                     self.backlog_tracker.on_event(self.current_timestamp);
                 }
@@ -1069,6 +1266,9 @@ impl Connector {
                 ParsedEvent::AdvanceTime => {
                     let time_advanced = self.advance_time(ctx.input_session);
                     ctx.connector_monitor.borrow_mut().commit();
+                    if let Some(committed_frontier) = &self.committed_frontier {
+                        committed_frontier.advance(&self.current_frontier);
+                    }
                     if let Some(snapshot_writer) = ctx.snapshot_writer {
                         snapshot_writer
                             .lock()
@@ -1,21 +1,19 @@
 // Copyright © 2026 Pathway
 
 use std::collections::VecDeque;
+use std::mem;
+use std::sync::{Arc, Mutex, Weak};
 use std::time::{Duration, Instant};
 
 use log::{info, warn};
-use pyo3::pyclass;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy)]
-#[pyclass]
+#[derive(Debug, Clone, Copy, Serialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
 pub struct ConnectorStats {
-    #[pyo3(get, set)]
     pub num_messages_from_start: usize,
-    #[pyo3(get, set)]
     pub num_messages_in_last_minute: usize,
-    #[pyo3(get, set)]
     pub num_messages_recently_committed: usize,
-    #[pyo3(get, set)]
     pub finished: bool,
 }
 
@@ -89,6 +87,7 @@ pub struct ConnectorMonitor {
     last_minute_queue: VecDeque<(usize, Instant)>,
     current_num_messages: usize,
     logger: ConnectorLogger,
+    replay_finished: bool,
 }
 
 impl ConnectorMonitor {
@@ -104,6 +103,7 @@ impl ConnectorMonitor {
             last_minute_queue: VecDeque::new(),
             current_num_messages: 0,
             logger: ConnectorLogger::new(name),
+            replay_finished: false,
         }
     }
 
@@ -118,6 +118,15 @@ impl ConnectorMonitor {
         self.logger.on_finished();
     }
 
+    pub fn finish_replay(&mut self) {
+        self.replay_finished = true;
+    }
+
+    /// Whether the connector has replayed its persisted snapshot, if it had one.
+    pub fn is_replay_finished(&self) -> bool {
+        self.replay_finished || self.stats.finished
+    }
+
     pub fn commit(&mut self) {
         self.stats.num_messages_recently_committed = self.current_num_messages;
         let now = Instant::now();
@@ -145,6 +154,105 @@ impl ConnectorMonitor {
     }
 }
 
+// the period over which the throughput of the output connectors is measured
+const THROUGHPUT_WINDOW: Duration = Duration::from_mins(1);
+
+/// The throughput of an output connector over the last minute, or since it has started
+/// if it has been running for a shorter time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct OutputConnectorThroughput {
+    pub rows_per_second: f64,
+    /// The size of the payloads passed to the writer. The writers sending the values
+    /// directly, like the ones of the databases, don't report it.
+    pub bytes_per_second: f64,
+    /// The mean time taken by a flush of the writer, `None` if it hasn't been flushed.
+    pub mean_flush_latency_ms: Option<f64>,
+    pub max_flush_latency_ms: Option<f64>,
+}
+
+#[derive(Debug)]
+struct ThroughputWindow {
+    started_at: Instant,
+    // the numbers of rows and bytes written at the given moments
+    writes: VecDeque<(Instant, usize, usize)>,
+    flushes: VecDeque<(Instant, Duration)>,
+}
+
+impl ThroughputWindow {
+    fn new(now: Instant) -> Self {
+        Self {
+            started_at: now,
+            writes: VecDeque::new(),
+            flushes: VecDeque::new(),
+        }
+    }
+
+    fn evict(&mut self, now: Instant) {
+        let expired = |moment: Instant| now.duration_since(moment) >= THROUGHPUT_WINDOW;
+        while self.writes.front().is_some_and(|write| expired(write.0)) {
+            self.writes.pop_front();
+        }
+        while self.flushes.front().is_some_and(|flush| expired(flush.0)) {
+            self.flushes.pop_front();
+        }
+    }
+
+    fn on_write(&mut self, now: Instant, n_rows: usize, n_bytes: usize) {
+        self.evict(now);
+        self.writes.push_back((now, n_rows, n_bytes));
+    }
+
+    fn on_flush(&mut self, now: Instant, duration: Duration) {
+        self.evict(now);
+        self.flushes.push_back((now, duration));
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn throughput(&mut self, now: Instant) -> OutputConnectorThroughput {
+        self.evict(now);
+        let window = now
+            .saturating_duration_since(self.started_at)
+            .min(THROUGHPUT_WINDOW)
+            .as_secs_f64();
+        let per_second = |total: usize| {
+            if window > 0.0 {
+                total as f64 / window
+            } else {
+                0.0
+            }
+        };
+        let flush_latencies_ms = self
+            .flushes
+            .iter()
+            .map(|(_moment, duration)| duration.as_secs_f64() * 1000.0);
+        OutputConnectorThroughput {
+            rows_per_second: per_second(self.writes.iter().map(|write| write.1).sum()),
+            bytes_per_second: per_second(self.writes.iter().map(|write| write.2).sum()),
+            mean_flush_latency_ms: (!self.flushes.is_empty())
+                .then(|| flush_latencies_ms.clone().sum::<f64>() / self.flushes.len() as f64),
+            max_flush_latency_ms: flush_latencies_ms.reduce(f64::max),
+        }
+    }
+}
+
+// the throughputs of the output connectors of the computations running in this process
+static THROUGHPUTS: Mutex<Vec<(String, Weak<Mutex<ThroughputWindow>>)>> = Mutex::new(Vec::new());
+
+/// Returns the throughputs of the output connectors running in this process. A sink
+/// written by several workers or in several partitions has an entry for each writer.
+pub fn output_connector_throughputs() -> Vec<(String, OutputConnectorThroughput)> {
+    let now = Instant::now();
+    THROUGHPUTS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|(name, window)| {
+            let throughput = window.upgrade()?.lock().unwrap().throughput(now);
+            Some((name.clone(), throughput))
+        })
+        .collect()
+}
+
 // TODO: incorporate in monitor (?)
 pub struct OutputConnectorStats {
     name: String,
@@ -154,13 +262,20 @@ pub struct OutputConnectorStats {
     current_writes_duration: Duration,
     messages_written_in_batch: usize,
     messages_written_in_total: usize,
+    bytes_written_in_batch: usize,
+    throughput: Arc<Mutex<ThroughputWindow>>,
 }
 
 impl OutputConnectorStats {
     pub fn new(name: String) -> Self {
+        let now = Instant::now();
+        let throughput = Arc::new(Mutex::new(ThroughputWindow::new(now)));
+        let mut throughputs = THROUGHPUTS.lock().unwrap();
+        throughputs.retain(|(_name, window)| window.strong_count() > 0);
+        throughputs.push((name.clone(), Arc::downgrade(&throughput)));
         Self {
             name,
-            batch_start_time: Instant::now(),
+            batch_start_time: now,
             last_reported_timestamp: None,
 
             total_writes_duration: Duration::ZERO,
@@ -168,9 +283,16 @@ impl OutputConnectorStats {
 
             messages_written_in_batch: 0,
             messages_written_in_total: 0,
+            bytes_written_in_batch: 0,
+            throughput,
         }
     }
 
+    /// Returns the throughput of the connector so far.
+    pub fn throughput(&self) -> OutputConnectorThroughput {
+        self.throughput.lock().unwrap().throughput(Instant::now())
+    }
+
     pub fn on_time_committed(&mut self, t: Option<u64>) {
         let current_timestamp = Instant::now();
 
@@ -203,6 +325,23 @@ impl OutputConnectorStats {
     pub fn on_batch_entries_written(&mut self, n_entries: usize) {
         self.messages_written_in_batch += n_entries;
         self.messages_written_in_total += n_entries;
+        let n_bytes = mem::take(&mut self.bytes_written_in_batch);
+        self.throughput
+            .lock()
+            .unwrap()
+            .on_write(Instant::now(), n_entries, n_bytes);
+    }
+
+    /// Counts the bytes of the payloads written in the current batch.
+    pub fn on_bytes_written(&mut self, n_bytes: usize) {
+        self.bytes_written_in_batch += n_bytes;
+    }
+
+    pub fn on_flush(&mut self, duration: Duration) {
+        self.throughput
+            .lock()
+            .unwrap()
+            .on_flush(Instant::now(), duration);
     }
 
     pub fn on_batch_finished(&mut self) {
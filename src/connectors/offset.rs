@@ -36,6 +36,14 @@ pub enum OffsetKey {
     Mssql,
     Mysql,
     ElasticSearch,
+    Sqs(usize),
+    PubSub(usize),
+    EventHubs(ArcStr),
+    RedisStream(usize),
+    Sqlite,
+    Postgres,
+    Sse,
+    Grpc,
 }
 
 impl HashInto for OffsetKey {
@@ -45,18 +53,27 @@ impl HashInto for OffsetKey {
                 hasher.update(topic_name.as_bytes());
                 partition.hash_into(hasher);
             }
-            OffsetKey::Nats(worker_index) => {
+            OffsetKey::Nats(worker_index)
+            | OffsetKey::Sqs(worker_index)
+            | OffsetKey::PubSub(worker_index)
+            | OffsetKey::RedisStream(worker_index) => {
                 worker_index.hash_into(hasher);
             }
             OffsetKey::Rabbitmq(RabbitmqStreamType::Stream(stream_name)) => {
                 hasher.update(stream_name.as_bytes());
             }
-            OffsetKey::Kinesis(shard) => hasher.update(shard.as_bytes()),
+            OffsetKey::Kinesis(shard) | OffsetKey::EventHubs(shard) => {
+                hasher.update(shard.as_bytes());
+            }
             OffsetKey::Empty
             | OffsetKey::MongoDb
             | OffsetKey::Mssql
             | OffsetKey::Mysql
-            | OffsetKey::ElasticSearch => {}
+            | OffsetKey::ElasticSearch
+            | OffsetKey::Sqlite
+            | OffsetKey::Postgres
+            | OffsetKey::Sse
+            | OffsetKey::Grpc => {}
         }
     }
 }
@@ -141,6 +158,29 @@ pub enum OffsetValue {
         entries_read: u64,
         pending: Vec<(String, i64)>,
     },
+    SqsReadEntriesCount(usize),
+    PubSubReadEntriesCount(usize),
+    EventHubsSequenceNumber(i64),
+    /// The id of a Redis stream entry. The entries of a stream are ordered by the
+    /// milliseconds time part of the id first and by the sequence number part then.
+    RedisStreamEntryId {
+        milliseconds_time: u64,
+        sequence_number: u64,
+    },
+    /// The id of the last event read from a Server-Sent Events stream, sent as
+    /// `Last-Event-ID` on reconnection. The ids are opaque strings, so the number of
+    /// the events read is kept to order the frontiers.
+    SseEventId {
+        last_event_id: String,
+        entries_read: usize,
+    },
+    /// The JSON value of the bookmark field of the last message read from a gRPC
+    /// stream, if the reader has one, with the number of the messages read to order
+    /// the frontiers.
+    GrpcBookmark {
+        bookmark: Option<String>,
+        entries_read: usize,
+    },
 }
 
 impl OffsetValue {
@@ -176,7 +216,9 @@ impl OffsetValue {
 impl HashInto for OffsetValue {
     fn hash_into(&self, hasher: &mut Hasher) {
         match self {
-            OffsetValue::KafkaOffset(offset) => offset.hash_into(hasher),
+            OffsetValue::KafkaOffset(offset) | OffsetValue::EventHubsSequenceNumber(offset) => {
+                offset.hash_into(hasher);
+            }
             OffsetValue::FilePosition {
                 path, bytes_offset, ..
             } => {
@@ -210,6 +252,8 @@ impl HashInto for OffsetValue {
                 rows_read_within_version.hash_into(hasher);
             }
             OffsetValue::NatsReadEntriesCount(count)
+            | OffsetValue::SqsReadEntriesCount(count)
+            | OffsetValue::PubSubReadEntriesCount(count)
             | OffsetValue::MqttReadEntriesCount(count)
             | OffsetValue::PostgresReadEntriesCount(count) => {
                 count.hash_into(hasher);
@@ -238,6 +282,30 @@ impl HashInto for OffsetValue {
                 watermark.hash_into(hasher);
                 entries_read.hash_into(hasher);
             }
+            OffsetValue::RedisStreamEntryId {
+                milliseconds_time,
+                sequence_number,
+            } => {
+                milliseconds_time.hash_into(hasher);
+                sequence_number.hash_into(hasher);
+            }
+            OffsetValue::SseEventId {
+                last_event_id,
+                entries_read,
+            } => {
+                hasher.update(last_event_id.as_bytes());
+                entries_read.hash_into(hasher);
+            }
+            OffsetValue::GrpcBookmark {
+                bookmark,
+                entries_read,
+            } => {
+                bookmark.is_some().hash_into(hasher);
+                if let Some(bookmark) = bookmark {
+                    hasher.update(bookmark.as_bytes());
+                }
+                entries_read.hash_into(hasher);
+            }
             OffsetValue::Empty => {}
         }
     }
@@ -0,0 +1,128 @@
+// Copyright © 2026 Pathway
+
+//! Limiting the rate at which an input connector reads from its source.
+//!
+//! A connector with a [`RateLimit`] pauses its reading thread once it gets ahead of the
+//! limit, so that a backfill of a large source doesn't take all the processing time of
+//! the workers from the other sources. The limit applies to the realtime reading only,
+//! the replay of the persisted snapshot isn't limited.
+//!
+//! A message is read whole even if it's larger than the limit allows, but the debt it
+//! leaves is capped at one second of reading, so a single large message never pauses
+//! the reading for longer than that.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The maximum rate of reading of an input connector. `None` means no limit.
+///
+/// The size in bytes of a message is the size of its raw payload, so the limit on bytes
+/// doesn't apply to the sources returning already parsed values, such as databases.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max_rows_per_second: Option<u64>,
+    pub max_bytes_per_second: Option<u64>,
+}
+
+impl RateLimit {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_rows_per_second.is_none() && self.max_bytes_per_second.is_none()
+    }
+
+    /// The limit for each of `parallel_readers` readers sharing this limit.
+    #[must_use]
+    pub fn per_reader(&self, parallel_readers: usize) -> Self {
+        let parallel_readers = u64::try_from(parallel_readers.max(1)).unwrap();
+        let share = |limit: u64| (limit / parallel_readers).max(1);
+        Self {
+            max_rows_per_second: self.max_rows_per_second.map(share),
+            max_bytes_per_second: self.max_bytes_per_second.map(share),
+        }
+    }
+}
+
+// the longest pause is split into slices of this length, so that a stopping connector
+// doesn't wait for its end
+const PAUSE_SLICE: Duration = Duration::from_millis(50);
+
+// The budget of a single limited quantity. It's refilled at the limit rate, up to the
+// amount allowed in one second, and may go below zero as the messages are read whole,
+// down to minus that amount.
+struct Budget {
+    per_second: f64,
+    available: f64,
+}
+
+impl Budget {
+    #[allow(clippy::cast_precision_loss)]
+    fn new(per_second: u64) -> Self {
+        let per_second = per_second as f64;
+        Self {
+            per_second,
+            available: per_second,
+        }
+    }
+
+    fn refill(&mut self, elapsed: Duration) {
+        self.available =
+            (self.available + elapsed.as_secs_f64() * self.per_second).min(self.per_second);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn consume(&mut self, amount: usize) -> Duration {
+        self.available = (self.available - amount as f64).max(-self.per_second);
+        if self.available < 0.0 {
+            Duration::from_secs_f64(-self.available / self.per_second)
+        } else {
+            Duration::ZERO
+        }
+    }
+}
+
+pub struct RateLimiter {
+    rows: Option<Budget>,
+    bytes: Option<Budget>,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            rows: limit.max_rows_per_second.map(Budget::new),
+            bytes: limit.max_bytes_per_second.map(Budget::new),
+            refilled_at: Instant::now(),
+        }
+    }
+
+    /// Accounts for `rows` rows of `bytes` bytes read at `now` and returns for how long
+    /// the reading has to pause to stay within the limit.
+    pub fn on_read(&mut self, rows: usize, bytes: usize, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.refilled_at = self.refilled_at.max(now);
+        let mut pause = Duration::ZERO;
+        if let Some(budget) = &mut self.rows {
+            budget.refill(elapsed);
+            pause = pause.max(budget.consume(rows));
+        }
+        if let Some(budget) = &mut self.bytes {
+            budget.refill(elapsed);
+            pause = pause.max(budget.consume(bytes));
+        }
+        pause
+    }
+}
+
+/// Pauses the reading for `pause`, returning earlier once `is_stopped` returns `true`.
+pub fn sleep_unless_stopped(pause: Duration, is_stopped: impl Fn() -> bool) {
+    let until = Instant::now() + pause;
+    loop {
+        if is_stopped() {
+            break;
+        }
+        let left = until.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            break;
+        }
+        thread::sleep(left.min(PAUSE_SLICE));
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright © 2026 Pathway
+
+//! Alignment of output connectors, so that a group of sinks never gets too far
+//! apart in the times it has written.
+//!
+//! Each output connector runs its writes in a separate thread, consuming the batches
+//! in the order of their times. Without any coordination, a fast sink may be many
+//! minibatches ahead of a slow one, which is a problem for the consumers that join the
+//! outputs of several sinks: they observe the state where one table is already updated,
+//! while another one is still stale.
+//!
+//! An alignment group keeps the position of each member sink - the time before which
+//! everything has been passed to the sink. The position is advanced when a sink starts
+//! writing a batch (since batches arrive ordered by time, all earlier times are done)
+//! and when a time is committed. A sink may start writing a batch with time `t` only if
+//! `t` doesn't exceed the smallest position among the other members plus the allowed
+//! skew. Otherwise, the sink thread is blocked until the lagging sinks catch up.
+//!
+//! The sink with the smallest position is never blocked, so the group can't deadlock.
+//! A sink that has finished (or whose thread has terminated) leaves the group and stops
+//! holding the others back.
+//!
+//! The groups are shared by all the workers of a process, so that a sink is aligned with
+//! the ones running in the other workers as well. The part of a sink in each worker is a
+//! separate member of the group. The groups can't be used in a computation running in more
+//! than one process, as the sinks of different processes couldn't be aligned.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+
+use crate::engine::Timestamp;
+
+#[derive(Clone, Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("maximum skew differs for two definitions of the same sink alignment group")]
+    InconsistentMaxSkew,
+}
+
+#[derive(Clone, Debug)]
+pub struct SinkAlignmentGroupDescriptor {
+    pub name: String,
+    pub max_skew: u64,
+}
+
+impl SinkAlignmentGroupDescriptor {
+    pub fn new(name: String, max_skew: u64) -> Self {
+        Self { name, max_skew }
+    }
+}
+
+#[derive(Debug, Default)]
+struct GroupState {
+    positions: HashMap<usize, Timestamp>,
+    next_sink_id: usize,
+}
+
+impl GroupState {
+    fn min_position_of_others(&self, sink_id: usize) -> Option<Timestamp> {
+        self.positions
+            .iter()
+            .filter(|(id, _)| **id != sink_id)
+            .map(|(_, position)| *position)
+            .min()
+    }
+
+    fn advance(&mut self, sink_id: usize, time: Timestamp) {
+        if let Some(position) = self.positions.get_mut(&sink_id) {
+            *position = (*position).max(time);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SinkAlignmentGroup {
+    max_skew: u64,
+    state: Mutex<GroupState>,
+    progress_changed: Condvar,
+}
+
+impl SinkAlignmentGroup {
+    fn new(max_skew: u64) -> Self {
+        Self {
+            max_skew,
+            state: Mutex::new(GroupState::default()),
+            progress_changed: Condvar::new(),
+        }
+    }
+
+    fn register_sink(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        let sink_id = state.next_sink_id;
+        state.next_sink_id += 1;
+        state.positions.insert(sink_id, Timestamp(0));
+        sink_id
+    }
+
+    fn can_write(&self, state: &GroupState, sink_id: usize, time: Timestamp) -> bool {
+        state
+            .min_position_of_others(sink_id)
+            .is_none_or(|min_position| time.0 <= min_position.0.saturating_add(self.max_skew))
+    }
+}
+
+/// A handle of a single sink within an alignment group.
+///
+/// Dropping the accessor removes the sink from the group.
+#[derive(Debug)]
+pub struct SinkAlignmentAccessor {
+    group: Arc<SinkAlignmentGroup>,
+    sink_id: usize,
+}
+
+impl SinkAlignmentAccessor {
+    fn new(group: Arc<SinkAlignmentGroup>) -> Self {
+        let sink_id = group.register_sink();
+        Self { group, sink_id }
+    }
+
+    /// Blocks until a batch with the given time can be written without getting
+    /// ahead of the other sinks in the group by more than the allowed skew.
+    pub fn wait_for_turn(&self, time: Timestamp) {
+        let mut state = self.group.state.lock().unwrap();
+        state.advance(self.sink_id, time);
+        self.group.progress_changed.notify_all();
+        while !self.group.can_write(&state, self.sink_id, time) {
+            state = self.group.progress_changed.wait(state).unwrap();
+        }
+    }
+
+    /// Reports that all times before `frontier` have been written.
+    /// `None` means that the sink has finished and no longer takes part in the group.
+    pub fn report_progress(&self, frontier: Option<Timestamp>) {
+        let mut state = self.group.state.lock().unwrap();
+        match frontier {
+            Some(frontier) => state.advance(self.sink_id, frontier),
+            None => {
+                state.positions.remove(&self.sink_id);
+            }
+        }
+        self.group.progress_changed.notify_all();
+    }
+
+    pub fn can_write_now(&self, time: Timestamp) -> bool {
+        let state = self.group.state.lock().unwrap();
+        self.group.can_write(&state, self.sink_id, time)
+    }
+}
+
+impl Drop for SinkAlignmentAccessor {
+    fn drop(&mut self) {
+        self.report_progress(None);
+    }
+}
+
+pub type SharedSinkAligner = Arc<Mutex<SinkAligner>>;
+
+#[derive(Debug, Default)]
+pub struct SinkAligner {
+    groups: HashMap<String, Arc<SinkAlignmentGroup>>,
+}
+
+impl SinkAligner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ensure_alignment_group(
+        &mut self,
+        desc: &SinkAlignmentGroupDescriptor,
+    ) -> Result<SinkAlignmentAccessor, Error> {
+        let group = self
+            .groups
+            .entry(desc.name.clone())
+            .or_insert_with(|| Arc::new(SinkAlignmentGroup::new(desc.max_skew)));
+        if group.max_skew != desc.max_skew {
+            return Err(Error::InconsistentMaxSkew);
+        }
+        Ok(SinkAlignmentAccessor::new(group.clone()))
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright © 2026 Pathway
+
+//! Coordinated commits of output connectors, so that a group of sinks either all
+//! commit a time or none of them does.
+//!
+//! Each output connector commits its minibatches independently: it flushes its writer,
+//! which makes the written entries visible in the destination, and reports the time as
+//! finalized. If one of the destinations fails, the others may already have committed
+//! the time, and the downstream systems reading them diverge.
+//!
+//! A commit group runs a two-phase commit for each time:
+//! - in the first phase, each member prepares the entries with the times before the
+//!   committed frontier: its writer sends them to the destination without making them
+//!   visible (see [`crate::connectors::data_storage::Writer::prepare`]), and the member
+//!   votes that the frontier is prepared;
+//! - in the second phase, once all the members have prepared the frontier, each of them
+//!   commits its writer, which makes the entries visible, and votes that the frontier is
+//!   committed. The times before the frontier are reported as finalized only once all
+//!   the members have committed them.
+//!
+//! If a member fails before voting, the group is aborted and the other members fail
+//! instead of committing, so none of them commits the time. If a member fails to commit,
+//! the others may have committed already, but none of them reports the time as
+//! finalized, so a restarted computation writes it again. Only the writers able to
+//! commit in two phases can join a group.
+//!
+//! Like the finalized time of the persisted state, a group coordinates the sinks of a
+//! single worker. A sink that has finished leaves the group.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+
+use crate::engine::{Timestamp, TotalFrontier};
+
+#[derive(Clone, Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("another sink of the commit group has failed, the time isn't committed")]
+    Aborted,
+
+    #[error("output connector {0} can't commit in two phases, so it can't join a commit group")]
+    TwoPhaseCommitNotSupported(String),
+}
+
+#[derive(Debug, Default)]
+struct GroupState {
+    // the frontiers the members have prepared
+    prepared: HashMap<usize, TotalFrontier<Timestamp>>,
+    // the frontiers the members have committed
+    committed: HashMap<usize, TotalFrontier<Timestamp>>,
+    next_sink_id: usize,
+    aborted: bool,
+}
+
+impl GroupState {
+    fn all_prepared(&self, frontier: TotalFrontier<Timestamp>) -> bool {
+        self.prepared.values().all(|prepared| *prepared >= frontier)
+    }
+
+    fn all_committed(&self, frontier: TotalFrontier<Timestamp>) -> bool {
+        self.committed
+            .values()
+            .all(|committed| *committed >= frontier)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SinkCommitGroup {
+    state: Mutex<GroupState>,
+    vote_changed: Condvar,
+}
+
+impl SinkCommitGroup {
+    fn register_sink(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        let sink_id = state.next_sink_id;
+        state.next_sink_id += 1;
+        state
+            .prepared
+            .insert(sink_id, TotalFrontier::At(Timestamp(0)));
+        state
+            .committed
+            .insert(sink_id, TotalFrontier::At(Timestamp(0)));
+        sink_id
+    }
+}
+
+/// A handle of a single sink within a commit group.
+///
+/// Dropping the participant before it has committed the end of its output aborts the
+/// group.
+#[derive(Debug)]
+pub struct SinkCommitParticipant {
+    group: Arc<SinkCommitGroup>,
+    sink_id: usize,
+    finished: bool,
+}
+
+impl SinkCommitParticipant {
+    fn new(group: Arc<SinkCommitGroup>) -> Self {
+        let sink_id = group.register_sink();
+        Self {
+            group,
+            sink_id,
+            finished: false,
+        }
+    }
+
+    /// Votes that the writer has prepared all the times before `frontier`, and blocks
+    /// until all the other sinks of the group have prepared them too. `None` means that
+    /// the whole output has been prepared. Afterwards, the writer can commit the times.
+    pub fn prepare(&mut self, frontier: Option<Timestamp>) -> Result<(), Error> {
+        let frontier = frontier.map_or(TotalFrontier::Done, TotalFrontier::At);
+        let mut state = self.group.state.lock().unwrap();
+        if let Some(prepared) = state.prepared.get_mut(&self.sink_id) {
+            *prepared = (*prepared).max(frontier);
+        }
+        self.group.vote_changed.notify_all();
+        while !state.aborted && !state.all_prepared(frontier) {
+            state = self.group.vote_changed.wait(state).unwrap();
+        }
+        if state.aborted {
+            return Err(Error::Aborted);
+        }
+        Ok(())
+    }
+
+    /// Votes that the writer has committed all the times before `frontier`, and blocks
+    /// until all the other sinks of the group have committed them too. Afterwards, the
+    /// times can be reported as finalized.
+    pub fn commit(&mut self, frontier: Option<Timestamp>) -> Result<(), Error> {
+        let frontier = frontier.map_or(TotalFrontier::Done, TotalFrontier::At);
+        let mut state = self.group.state.lock().unwrap();
+        if let Some(committed) = state.committed.get_mut(&self.sink_id) {
+            *committed = (*committed).max(frontier);
+        }
+        self.group.vote_changed.notify_all();
+        while !state.aborted && !state.all_committed(frontier) {
+            state = self.group.vote_changed.wait(state).unwrap();
+        }
+        if state.aborted {
+            return Err(Error::Aborted);
+        }
+        if frontier == TotalFrontier::Done {
+            // the whole output is committed, the sink no longer takes part in the votes
+            state.prepared.remove(&self.sink_id);
+            state.committed.remove(&self.sink_id);
+            self.finished = true;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for SinkCommitParticipant {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let mut state = self.group.state.lock().unwrap();
+        state.prepared.remove(&self.sink_id);
+        state.committed.remove(&self.sink_id);
+        state.aborted = true;
+        self.group.vote_changed.notify_all();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SinkCommitter {
+    groups: HashMap<String, Arc<SinkCommitGroup>>,
+}
+
+impl SinkCommitter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn join_commit_group(&mut self, name: &str) -> SinkCommitParticipant {
+        let group = self.groups.entry(name.to_string()).or_default();
+        SinkCommitParticipant::new(group.clone())
+    }
+}
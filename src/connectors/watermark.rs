@@ -0,0 +1,159 @@
+// Copyright © 2026 Pathway
+
+//! Watermark-driven commits of input connectors.
+//!
+//! By default, a connector commits the rows it has read every `commit_duration`. With a
+//! [`WatermarkStrategy`], it also commits them as soon as the watermark of the source
+//! advances, that is, as soon as the rows with earlier event times are considered
+//! complete, and when the source has been idle for the configured time. The watermarks
+//! are computed by a [`WatermarkGenerator`] from the rows read.
+
+use std::time::{Duration, Instant};
+
+use crate::engine::time::DateTime;
+use crate::engine::Value;
+
+/// Computes the watermark of a source from the rows read from it. The watermarks are
+/// in the units of the event times, e.g. nanoseconds for the datetime columns.
+pub trait WatermarkGenerator: Send {
+    /// Updates the generator with the values of a row read by the connector.
+    fn on_row(&mut self, values: &[Value]);
+
+    /// The current watermark: no more rows with the event times up to it are expected.
+    fn watermark(&self) -> Option<i64>;
+
+    /// The watermark once the source has been idle for the idle timeout of the
+    /// strategy.
+    fn idle_watermark(&self) -> Option<i64> {
+        self.watermark()
+    }
+}
+
+/// Assumes that the rows arrive at most `max_out_of_orderness` later than the rows with
+/// the greatest event time read so far. The event time is taken from the column
+/// `time_column`, of the type int or datetime. Once the source is idle, no more late
+/// rows are expected.
+pub struct BoundedOutOfOrderness {
+    time_column: usize,
+    max_out_of_orderness: i64,
+    max_event_time: Option<i64>,
+}
+
+impl BoundedOutOfOrderness {
+    pub fn new(time_column: usize, max_out_of_orderness: i64) -> Self {
+        Self {
+            time_column,
+            max_out_of_orderness,
+            max_event_time: None,
+        }
+    }
+}
+
+fn event_time(value: &Value) -> Option<i64> {
+    match value {
+        Value::Int(time) => Some(*time),
+        Value::DateTimeNaive(time) => Some(time.timestamp()),
+        Value::DateTimeUtc(time) => Some(time.timestamp()),
+        // rows without a valid event time don't move the watermark
+        _ => None,
+    }
+}
+
+impl WatermarkGenerator for BoundedOutOfOrderness {
+    fn on_row(&mut self, values: &[Value]) {
+        if let Some(time) = values.get(self.time_column).and_then(event_time) {
+            self.max_event_time = self.max_event_time.max(Some(time));
+        }
+    }
+
+    fn watermark(&self) -> Option<i64> {
+        self.max_event_time
+            .map(|time| time.saturating_sub(self.max_out_of_orderness))
+    }
+
+    fn idle_watermark(&self) -> Option<i64> {
+        self.max_event_time
+    }
+}
+
+/// When the rows read by a connector are committed besides the `commit_duration` ticks.
+#[derive(Default)]
+pub struct WatermarkStrategy {
+    generator: Option<Box<dyn WatermarkGenerator>>,
+    idle_timeout: Option<Duration>,
+}
+
+impl WatermarkStrategy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Commits the rows each time the watermark computed by `generator` advances.
+    #[must_use]
+    pub fn with_generator(mut self, generator: Box<dyn WatermarkGenerator>) -> Self {
+        self.generator = Some(generator);
+        self
+    }
+
+    /// Commits the rows once no row has been read for `idle_timeout`.
+    #[must_use]
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+}
+
+/// Tracks the watermark of a source to decide when the rows read are to be committed.
+pub struct WatermarkTracker {
+    strategy: WatermarkStrategy,
+    last_row_at: Instant,
+    committed_watermark: Option<i64>,
+}
+
+impl WatermarkTracker {
+    pub fn new(strategy: WatermarkStrategy) -> Self {
+        Self {
+            strategy,
+            last_row_at: Instant::now(),
+            committed_watermark: None,
+        }
+    }
+
+    pub fn on_row(&mut self, values: &[Value], now: Instant) {
+        self.last_row_at = now;
+        if let Some(generator) = &mut self.strategy.generator {
+            generator.on_row(values);
+        }
+    }
+
+    fn is_idle(&self, now: Instant) -> bool {
+        self.strategy
+            .idle_timeout
+            .is_some_and(|timeout| now.saturating_duration_since(self.last_row_at) >= timeout)
+    }
+
+    /// Returns whether the rows read so far are to be committed at `now`: either the
+    /// watermark has advanced since the last commit or the source is idle.
+    pub fn should_commit(&mut self, now: Instant) -> bool {
+        let is_idle = self.is_idle(now);
+        let watermark = self.strategy.generator.as_ref().and_then(|generator| {
+            if is_idle {
+                generator.idle_watermark()
+            } else {
+                generator.watermark()
+            }
+        });
+        if watermark > self.committed_watermark {
+            self.committed_watermark = watermark;
+            return true;
+        }
+        is_idle
+    }
+
+    /// The time at which the source becomes idle if no more rows are read.
+    pub fn idle_deadline(&self) -> Option<Instant> {
+        self.strategy
+            .idle_timeout
+            .map(|timeout| self.last_row_at + timeout)
+    }
+}
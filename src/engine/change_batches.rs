@@ -0,0 +1,124 @@
+// Copyright © 2026 Pathway
+
+//! Changes of exported tables as Arrow record batches, as they are sent to the external
+//! consumers by [`super::flight_server`] and [`super::shared_memory_export`].
+//!
+//! A batch has the `id` column with the keys of the rows, followed by the columns of the
+//! table and the `time` and `diff` columns. The columns of types with no Arrow
+//! counterpart hold the string representations of the values and the errors are
+//! replaced with nulls.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use deltalake::arrow::array::{ArrayRef, Int64Array, StringArray, UInt64Array};
+use deltalake::arrow::datatypes::{
+    DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema, SchemaRef,
+    TimeUnit as ArrowTimeUnit,
+};
+use deltalake::arrow::record_batch::RecordBatch;
+
+use crate::connectors::data_storage::data_lake::arrow::{array_for_type, arrow_data_type};
+use crate::connectors::data_storage::data_lake::LakeWriterSettings;
+use crate::connectors::WriteError;
+
+use super::{DataRow, TableProperties, Type, Value};
+
+pub(crate) struct ChangeBatchSchema {
+    pub schema: SchemaRef,
+    // whether the values of the column are sent as strings, by column index
+    stringified: Vec<bool>,
+}
+
+impl ChangeBatchSchema {
+    pub fn new(column_names: &[String], properties: &TableProperties) -> Self {
+        let settings = LakeWriterSettings {
+            use_64bit_size_type: false,
+            utc_timezone_name: "UTC".into(),
+            timestamp_unit: ArrowTimeUnit::Nanosecond,
+        };
+        let column_types = match properties {
+            TableProperties::Table(columns, _trace) => columns
+                .iter()
+                .map(|column| match column {
+                    TableProperties::Column(column) => column.dtype.clone(),
+                    _ => Type::Any,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let mut fields = Vec::with_capacity(column_names.len() + 3);
+        fields.push(ArrowField::new("id", ArrowDataType::Utf8, false));
+        let mut stringified = Vec::with_capacity(column_names.len());
+        for (index, name) in column_names.iter().enumerate() {
+            let type_ = column_types.get(index).unwrap_or(&Type::Any);
+            let arrow_type = arrow_data_type(type_, &settings).ok();
+            stringified.push(arrow_type.is_none());
+            fields.push(ArrowField::new(
+                name.clone(),
+                arrow_type.unwrap_or(ArrowDataType::Utf8),
+                true,
+            ));
+        }
+        fields.push(ArrowField::new("time", ArrowDataType::UInt64, false));
+        fields.push(ArrowField::new("diff", ArrowDataType::Int64, false));
+        Self {
+            schema: Arc::new(ArrowSchema::new(fields)),
+            stringified,
+        }
+    }
+
+    pub fn record_batch(&self, rows: &[DataRow]) -> Result<RecordBatch, WriteError> {
+        let mut columns: Vec<ArrayRef> = Vec::with_capacity(self.schema.fields().len());
+        columns.push(Arc::new(StringArray::from_iter_values(
+            rows.iter().map(|row| row.key.to_string()),
+        )));
+        for (index, stringified) in self.stringified.iter().enumerate() {
+            let values: Vec<Value> = rows
+                .iter()
+                .map(|row| match &row.values[index] {
+                    Value::Error | Value::None => Value::None,
+                    value if *stringified => Value::from(value.to_string().as_str()),
+                    value => value.clone(),
+                })
+                .collect();
+            columns.push(array_for_type(
+                self.schema.field(index + 1).data_type(),
+                &values,
+            )?);
+        }
+        columns.push(Arc::new(UInt64Array::from_iter_values(
+            rows.iter().map(|row| row.time.0),
+        )));
+        columns.push(Arc::new(Int64Array::from_iter_values(
+            rows.iter().map(|row| row.diff as i64),
+        )));
+        Ok(RecordBatch::try_new(self.schema.clone(), columns)?)
+    }
+}
+
+// Sums the diffs of the equal rows and drops the ones that cancel out. The rows of
+// different times are merged only if `merge_times` is set, keeping the latest time.
+pub(crate) fn consolidate(mut rows: Vec<DataRow>, merge_times: bool) -> Vec<DataRow> {
+    rows.sort_unstable_by(|a, b| {
+        let by_time = if merge_times {
+            Ordering::Equal
+        } else {
+            a.time.cmp(&b.time)
+        };
+        by_time
+            .then_with(|| a.key.cmp(&b.key))
+            .then_with(|| a.values.cmp(&b.values))
+    });
+    rows.dedup_by(|new, old| {
+        if new.key == old.key && new.values == old.values && (merge_times || new.time == old.time) {
+            old.diff += new.diff;
+            old.time = old.time.max(new.time);
+            true
+        } else {
+            false
+        }
+    });
+    rows.retain(|row| row.diff != 0);
+    rows
+}
@@ -0,0 +1,174 @@
+// Copyright © 2026 Pathway
+
+//! The clock of the engine.
+//!
+//! The engine reads the current time through [`now`]: the timestamps of the input data
+//! ([`Timestamp::new_from_current_time`]), the flushing of the error log and the commit
+//! timers of the input connectors all use it. By default it's the system clock, but in
+//! the tests and demos it can be replaced with a synthetic one that is frozen and only
+//! moves when [`advance`]d, or runs faster than the system clock. This makes the
+//! time-dependent behavior, such as the commits every `commit_duration`, deterministic.
+//!
+//! The clock is global to the process. The engine parks its workers for the real time
+//! returned by [`real_duration`], so that the synthetic deadlines are met.
+//!
+//! [`Timestamp::new_from_current_time`]: super::Timestamp::new_from_current_time
+
+use std::sync::RwLock;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the workers check a frozen clock, as it may be advanced by another thread.
+pub const FROZEN_CLOCK_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, Clone, Copy)]
+enum ClockState {
+    System,
+    Frozen(SystemTime),
+    Running {
+        // the time of the clock at `anchored_at`
+        origin: SystemTime,
+        anchored_at: Instant,
+        speedup: f64,
+    },
+}
+
+impl ClockState {
+    fn now(&self) -> SystemTime {
+        match self {
+            Self::System => SystemTime::now(),
+            Self::Frozen(time) => *time,
+            Self::Running {
+                origin,
+                anchored_at,
+                speedup,
+            } => *origin + anchored_at.elapsed().mul_f64(*speedup),
+        }
+    }
+}
+
+/// A clock that is either the system clock or a synthetic one.
+#[derive(Debug)]
+pub struct Clock {
+    state: RwLock<ClockState>,
+}
+
+impl Clock {
+    pub const fn system() -> Self {
+        Self {
+            state: RwLock::new(ClockState::System),
+        }
+    }
+
+    pub fn now(&self) -> SystemTime {
+        self.state.read().unwrap().now()
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        matches!(*self.state.read().unwrap(), ClockState::Frozen(_))
+    }
+
+    /// Stops the clock at `at`, or at the current time of the clock if `None`.
+    pub fn freeze(&self, at: Option<SystemTime>) {
+        let mut state = self.state.write().unwrap();
+        *state = ClockState::Frozen(at.unwrap_or_else(|| state.now()));
+    }
+
+    /// Makes the clock run `speedup` times faster than the system clock, starting from its
+    /// current time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speedup` isn't a positive finite number.
+    pub fn accelerate(&self, speedup: f64) {
+        assert!(
+            speedup.is_finite() && speedup > 0.0,
+            "speedup has to be a positive finite number"
+        );
+        let mut state = self.state.write().unwrap();
+        *state = ClockState::Running {
+            origin: state.now(),
+            anchored_at: Instant::now(),
+            speedup,
+        };
+    }
+
+    /// Moves the clock forward by `by`. The system clock becomes a synthetic clock running
+    /// at the speed of the system clock, ahead of it by `by`.
+    pub fn advance(&self, by: Duration) {
+        let mut state = self.state.write().unwrap();
+        *state = match *state {
+            ClockState::System => ClockState::Running {
+                origin: SystemTime::now() + by,
+                anchored_at: Instant::now(),
+                speedup: 1.0,
+            },
+            ClockState::Frozen(time) => ClockState::Frozen(time + by),
+            ClockState::Running {
+                origin,
+                anchored_at,
+                speedup,
+            } => ClockState::Running {
+                origin: origin + by,
+                anchored_at,
+                speedup,
+            },
+        };
+    }
+
+    /// Goes back to the system clock.
+    pub fn reset(&self) {
+        *self.state.write().unwrap() = ClockState::System;
+    }
+
+    /// The real time it takes for the clock to move by `duration`. For a frozen clock,
+    /// it's at most [`FROZEN_CLOCK_POLL_INTERVAL`].
+    pub fn real_duration(&self, duration: Duration) -> Duration {
+        match *self.state.read().unwrap() {
+            ClockState::System => duration,
+            ClockState::Frozen(_) => duration.min(FROZEN_CLOCK_POLL_INTERVAL),
+            ClockState::Running { speedup, .. } => duration.div_f64(speedup),
+        }
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::system()
+    }
+}
+
+static CLOCK: Clock = Clock::system();
+
+/// The current time of the engine clock.
+pub fn now() -> SystemTime {
+    CLOCK.now()
+}
+
+pub fn is_frozen() -> bool {
+    CLOCK.is_frozen()
+}
+
+/// Stops the engine clock, see [`Clock::freeze`].
+pub fn freeze(at: Option<SystemTime>) {
+    CLOCK.freeze(at);
+}
+
+/// Speeds up the engine clock, see [`Clock::accelerate`].
+pub fn accelerate(speedup: f64) {
+    CLOCK.accelerate(speedup);
+}
+
+/// Moves the engine clock forward, see [`Clock::advance`].
+pub fn advance(by: Duration) {
+    CLOCK.advance(by);
+}
+
+/// Makes the engine clock the system clock again.
+pub fn reset() {
+    CLOCK.reset();
+}
+
+/// The real time it takes for the engine clock to move by `duration`.
+pub fn real_duration(duration: Duration) -> Duration {
+    CLOCK.real_duration(duration)
+}
@@ -0,0 +1,347 @@
+// Copyright © 2026 Pathway
+
+//! Columnar batches for the evaluation of expressions.
+//!
+//! A [`ColumnarBatch`] stores values column by column. The columns of ints, floats and
+//! bools are stored as Arrow arrays (with `None` values kept as nulls), the remaining
+//! ones as plain vectors of values. The conversion is lossless, that is,
+//! [`ColumnarBatch::value`] returns exactly the value that was put in the batch.
+//!
+//! When a table is extended with expressions, the arguments of the expressions are
+//! gathered into a [`ColumnarBatch`] if some of the expressions can be evaluated
+//! column-wise, so that the evaluator reads the columns directly. The results of the
+//! expressions are collected in [`ColumnarRows`] along with the keys, times and diffs of
+//! their rows. This is limited to the evaluation of expressions: the tables passed
+//! between the operators still consist of rows, and the results are converted into
+//! `Value::Tuple`s once, when the extended table is first used.
+
+use std::cell::OnceCell;
+use std::sync::Arc;
+
+use deltalake::arrow::array::{
+    Array as ArrowArray, BooleanArray as ArrowBooleanArray, Float64Array as ArrowFloat64Array,
+    Int64Array as ArrowInt64Array,
+};
+use ordered_float::OrderedFloat;
+
+use super::{ColumnPath, Error, Key, Result, Value};
+
+#[derive(Debug, Clone)]
+pub enum ColumnarColumn {
+    Int(ArrowInt64Array),
+    Float(ArrowFloat64Array),
+    Bool(ArrowBooleanArray),
+    Values(Arc<[Value]>),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ColumnKind {
+    Int,
+    Float,
+    Bool,
+}
+
+impl ColumnKind {
+    fn of(value: &Value) -> Option<Self> {
+        match value {
+            Value::Int(_) => Some(Self::Int),
+            Value::Float(_) => Some(Self::Float),
+            Value::Bool(_) => Some(Self::Bool),
+            _ => None,
+        }
+    }
+}
+
+impl ColumnarColumn {
+    /// Stores the values in an Arrow array if all of them (except for `None`s) are of
+    /// the same primitive type. Otherwise, the values are kept as they are.
+    pub fn from_values(values: Vec<Value>) -> Self {
+        let mut kinds = values
+            .iter()
+            .filter(|value| !matches!(value, Value::None))
+            .map(ColumnKind::of);
+        let kind = match kinds.next() {
+            Some(Some(kind)) if kinds.all(|other| other == Some(kind)) => kind,
+            _ => return Self::Values(values.into()),
+        };
+        match kind {
+            ColumnKind::Int => Self::Int(values.iter().map(|value| value.as_int().ok()).collect()),
+            ColumnKind::Float => {
+                Self::Float(values.iter().map(|value| value.as_float().ok()).collect())
+            }
+            ColumnKind::Bool => {
+                Self::Bool(values.iter().map(|value| value.as_bool().ok()).collect())
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Int(array) => array.len(),
+            Self::Float(array) => array.len(),
+            Self::Bool(array) => array.len(),
+            Self::Values(values) => values.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `len` values starting at `offset`.
+    #[must_use]
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        match self {
+            Self::Int(array) => Self::Int(array.slice(offset, len)),
+            Self::Float(array) => Self::Float(array.slice(offset, len)),
+            Self::Bool(array) => Self::Bool(array.slice(offset, len)),
+            Self::Values(values) => Self::Values(values[offset..offset + len].into()),
+        }
+    }
+
+    pub fn value(&self, row: usize) -> Value {
+        match self {
+            Self::Int(array) if array.is_valid(row) => Value::Int(array.value(row)),
+            Self::Float(array) if array.is_valid(row) => {
+                Value::Float(OrderedFloat(array.value(row)))
+            }
+            Self::Bool(array) if array.is_valid(row) => Value::Bool(array.value(row)),
+            Self::Int(_) | Self::Float(_) | Self::Bool(_) => Value::None,
+            Self::Values(values) => values[row].clone(),
+        }
+    }
+}
+
+/// A batch of values, stored column by column.
+#[derive(Debug, Clone)]
+pub struct ColumnarBatch {
+    len: usize,
+    columns: Vec<ColumnarColumn>,
+    // the rows, materialized on the first access
+    rows: OnceCell<Vec<Vec<Value>>>,
+}
+
+impl ColumnarBatch {
+    /// Creates a batch from its columns. All the columns must have the same length,
+    /// equal to `len`.
+    pub fn from_columns(len: usize, columns: Vec<ColumnarColumn>) -> Self {
+        assert!(
+            columns.iter().all(|column| column.len() == len),
+            "columns of a batch have to be of equal length"
+        );
+        Self {
+            len,
+            columns,
+            rows: OnceCell::new(),
+        }
+    }
+
+    /// Creates a batch from rows of `width` values each.
+    pub fn from_rows(rows: &[impl AsRef<[Value]>], width: usize) -> Self {
+        let mut builder = ColumnarBatchBuilder::new(width, rows.len());
+        for row in rows {
+            builder.push_row(row.as_ref().iter().cloned());
+        }
+        builder.finish()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn column(&self, index: usize) -> Option<&ColumnarColumn> {
+        self.columns.get(index)
+    }
+
+    pub fn value(&self, row: usize, column: usize) -> Value {
+        self.columns[column].value(row)
+    }
+
+    /// Returns the rows `offset..offset + len` of the batch.
+    #[must_use]
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| column.slice(offset, len))
+            .collect();
+        Self::from_columns(len, columns)
+    }
+
+    /// Splits the batch into batches of at most `max_len` rows.
+    pub fn split(&self, max_len: usize) -> Vec<Self> {
+        if self.len <= max_len {
+            return vec![self.clone()];
+        }
+        (0..self.len)
+            .step_by(max_len)
+            .map(|offset| self.slice(offset, max_len.min(self.len - offset)))
+            .collect()
+    }
+
+    /// Returns the batch as rows. They are computed once and reused by the next calls.
+    pub fn rows(&self) -> &[Vec<Value>] {
+        self.rows.get_or_init(|| {
+            (0..self.len)
+                .map(|row| {
+                    self.columns
+                        .iter()
+                        .map(|column| column.value(row))
+                        .collect()
+                })
+                .collect()
+        })
+    }
+}
+
+/// Collects the arguments of a batch row by row without allocating anything per row.
+#[derive(Debug)]
+pub struct ColumnarBatchBuilder {
+    columns: Vec<Vec<Value>>,
+    len: usize,
+}
+
+impl ColumnarBatchBuilder {
+    pub fn new(width: usize, capacity: usize) -> Self {
+        Self {
+            columns: (0..width).map(|_| Vec::with_capacity(capacity)).collect(),
+            len: 0,
+        }
+    }
+
+    /// Appends a row. It has to have exactly as many values as there are columns.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = Value>) {
+        let mut width = 0;
+        for (column, value) in self.columns.iter_mut().zip(row) {
+            column.push(value);
+            width += 1;
+        }
+        assert_eq!(width, self.columns.len(), "row width mismatch");
+        self.len += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn finish(self) -> ColumnarBatch {
+        let columns = self
+            .columns
+            .into_iter()
+            .map(ColumnarColumn::from_values)
+            .collect();
+        ColumnarBatch::from_columns(self.len, columns)
+    }
+}
+
+/// The results of expressions for a batch of rows, with the keys, times and diffs of the
+/// rows, stored column by column until they are converted into the rows of a table.
+#[derive(Debug, Clone)]
+pub struct ColumnarRows<T> {
+    keys: Vec<Key>,
+    times: Vec<T>,
+    diffs: Vec<isize>,
+    columns: ColumnarBatch,
+}
+
+impl<T> ColumnarRows<T> {
+    /// Creates the rows from their keys, times and diffs and the columns of their values,
+    /// all of the same length.
+    pub fn new(keys: Vec<Key>, times: Vec<T>, diffs: Vec<isize>, columns: ColumnarBatch) -> Self {
+        assert!(
+            keys.len() == columns.len() && times.len() == keys.len() && diffs.len() == keys.len(),
+            "keys, times, diffs and columns of rows have to be of equal length"
+        );
+        Self {
+            keys,
+            times,
+            diffs,
+            columns,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    pub fn columns(&self) -> &ColumnarBatch {
+        &self.columns
+    }
+
+    /// Replaces the values of the rows, keeping their keys, times and diffs.
+    #[must_use]
+    pub fn with_columns(self, columns: ColumnarBatch) -> Self {
+        Self::new(self.keys, self.times, self.diffs, columns)
+    }
+
+    /// Extracts the columns given by `paths` from the rows. A column of the values is
+    /// shared and not copied.
+    pub fn extract(&self, paths: &[ColumnPath]) -> Result<ColumnarBatch> {
+        let columns = paths
+            .iter()
+            .map(|path| match path.split_first() {
+                None if *path == ColumnPath::Key => Ok(ColumnarColumn::Values(
+                    self.keys.iter().map(|key| Value::from(*key)).collect(),
+                )),
+                None => Ok(ColumnarColumn::Values(
+                    (0..self.len())
+                        .map(|row| path.extract(&self.keys[row], &self.value(row)))
+                        .collect::<Result<_>>()?,
+                )),
+                Some((index, rest)) => {
+                    let column = self
+                        .columns
+                        .column(index)
+                        .ok_or_else(|| Error::InvalidColumnPath(path.clone()))?;
+                    if rest.is_whole_value() {
+                        return Ok(column.clone());
+                    }
+                    let values = (0..self.len())
+                        .map(|row| rest.extract_from_value(&column.value(row)))
+                        .collect::<Result<_>>()?;
+                    Ok(ColumnarColumn::from_values(values))
+                }
+            })
+            .collect::<Result<_>>()?;
+        Ok(ColumnarBatch::from_columns(self.len(), columns))
+    }
+
+    // the values of a row, as they are stored in the rows of a table
+    fn value(&self, row: usize) -> Value {
+        Value::Tuple(
+            (0..self.columns.width())
+                .map(|column| self.columns.value(row, column))
+                .collect(),
+        )
+    }
+
+    /// Converts the rows to the ones of a table, with their values as `Value::Tuple`s.
+    pub fn into_rows(self) -> impl Iterator<Item = ((Key, Value), T, isize)> {
+        let values: Vec<_> = (0..self.len()).map(|row| self.value(row)).collect();
+        self.keys
+            .into_iter()
+            .zip(values)
+            .zip(self.times.into_iter().zip(self.diffs))
+            .map(|((key, value), (time, diff))| ((key, value), time, diff))
+    }
+}
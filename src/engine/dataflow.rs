@@ -18,17 +18,32 @@ mod variable;
 mod windows;
 
 use crate::connectors::adaptors::{InputAdaptor, UpsertSession};
-use crate::connectors::data_format::{Formatter, Parser};
+use crate::connectors::backlog::BacklogLimit;
+use crate::connectors::data_format::{Formatter, FormatterContext, FormatterError, Parser};
 use crate::connectors::data_storage::{ReaderBuilder, Writer};
 use crate::connectors::monitoring::{ConnectorMonitor, OutputConnectorStats};
+use crate::connectors::offset::OffsetKey;
+use crate::connectors::rate_limit::RateLimit;
+use crate::connectors::sink_alignment::{
+    SharedSinkAligner, SinkAligner, SinkAlignmentAccessor, SinkAlignmentGroupDescriptor,
+};
+use crate::connectors::sink_commit::{
+    Error as SinkCommitError, SinkCommitParticipant, SinkCommitter,
+};
 use crate::connectors::synchronization::{
     ConnectorGroupDescriptor, ConnectorSynchronizer, SharedConnectorSynchronizer,
 };
+use crate::connectors::watermark::WatermarkStrategy;
 use crate::connectors::{Connector, PersistenceMode, SessionType, SnapshotAccess};
-use crate::engine::dataflow::monitoring::{OperatorProbe, Prober, ProberStats};
+use crate::engine::dataflow::monitoring::{
+    table_gauges_enabled, OperatorProbe, Prober, ProberStats, TableGauge,
+};
 use crate::engine::dataflow::operators::external_index::UseExternalIndexAsOfNow;
 use crate::engine::dataflow::operators::gradual_broadcast::GradualBroadcast;
-use crate::engine::dataflow::operators::time_column::{TimeColumnForget, TimeColumnFreeze};
+use crate::engine::dataflow::operators::monotonic::check_monotonic;
+use crate::engine::dataflow::operators::time_column::{
+    retain_last_versions, TimeColumnForget, TimeColumnFreeze,
+};
 use crate::engine::dataflow::operators::ExtendedProbeWith;
 use crate::engine::graph::JoinExactlyOnce;
 use crate::engine::reduce::{
@@ -36,10 +51,16 @@ use crate::engine::reduce::{
     AppendOnlyMinState, ArraySumState, CountDistinctApproximateReducer, CountDistinctReducer,
     ErrorStateWrapper, FloatSumState, IntSumState, SemigroupReducer, SemigroupState,
 };
+use crate::engine::shutdown::{set_active_shutdown, GracefulShutdown};
+use crate::engine::stepping::{start_accepting_commands, StepController, StepGate, StepMode};
 use crate::engine::telemetry::Config as TelemetryConfig;
-use crate::engine::value::HashInto;
+use crate::engine::value::{HashInto, KeyImpl};
 use crate::engine::workload_tracker::{Advice as ScalingAdvice, WorkloadTracker};
 use crate::persistence::config::PersistenceManagerOuterConfig;
+use crate::persistence::frontier::OffsetAntichain;
+use crate::persistence::savepoint::{
+    set_active_coordinator, SavepointCoordinator, SavepointExchange,
+};
 use crate::persistence::tracker::{RequiredPersistenceMode, SharedWorkerPersistentStorage};
 use crate::persistence::{
     IntoPersistentId, PersistenceTime, SharedOperatorSnapshotWriter, UniqueName,
@@ -50,25 +71,28 @@ use std::borrow::{Borrow, Cow};
 use std::cell::RefCell;
 use std::cmp::min;
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fmt::Write;
 use std::hash::Hash;
 use std::iter::once;
 use std::marker::PhantomData;
+use std::num::NonZeroUsize;
 use std::ops::{ControlFlow, Deref};
-use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe, Location};
 use std::path::PathBuf;
 use std::process::exit;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{mpsc, Arc, Mutex};
-use std::thread::{Builder, JoinHandle};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, Builder, JoinHandle};
 use std::time::{Duration, Instant, SystemTime};
-use std::{env, slice};
+use std::{env, mem, slice};
 
 use arcstr;
 use arcstr::ArcStr;
-use crossbeam_channel::{bounded, never, select, Receiver, RecvError, Sender};
+use crossbeam_channel::{
+    bounded, never, select, unbounded, Receiver, RecvError, RecvTimeoutError, Sender,
+};
 use differential_dataflow::collection::concatenate;
 use differential_dataflow::difference::{Multiply, Semigroup};
 use differential_dataflow::hashable::Hashable;
@@ -85,22 +109,25 @@ use differential_dataflow::trace::implementations::spine_fueled::Spine;
 use differential_dataflow::{AsCollection as _, Data};
 use differential_dataflow::{Collection, ExchangeData};
 use hyperloglogplus::{HyperLogLog, HyperLogLogPlus};
-use id_arena::Arena;
+use id_arena::{Arena, ArenaBehavior};
+use indexmap::IndexSet;
 use itertools::{chain, process_results, Itertools};
-use log::{error, info};
+use log::{error, info, warn};
+use lru::LruCache;
 use ndarray::ArrayD;
 use once_cell::unsync::OnceCell;
 use persist::{
-    effective_persistent_id, persist_state, read_persisted_state, EmptyPersistenceWrapper,
-    OldOrNew, PersistableCollection, PersistedStatefulReduce, PersistenceWrapper,
-    TimestampBasedPersistenceWrapper,
+    effective_persistent_id, operator_persistence_enabled, persist_state, read_persisted_state,
+    EmptyPersistenceWrapper, OldOrNew, PersistableCollection, PersistedStatefulReduce,
+    PersistenceWrapper, TimestampBasedPersistenceWrapper,
 };
-use pyo3::PyObject;
 use serde::{Deserialize, Serialize};
+use timely::dataflow::channels::pact::Pipeline;
 use timely::dataflow::operators::probe::Handle as ProbeHandle;
-use timely::dataflow::operators::{Filter, Inspect, Probe};
+use timely::dataflow::operators::{Filter, Inspect, Operator, Probe};
 use timely::dataflow::operators::{Map, ToStream as _};
 use timely::dataflow::scopes::Child;
+use timely::dataflow::Stream;
 use timely::execute;
 use timely::order::{Product, TotalOrder};
 use timely::progress::timestamp::Refines;
@@ -116,21 +143,29 @@ use self::operators::output::{ConsolidateForOutput, OutputBatch};
 use self::operators::prev_next::add_prev_next_pointers;
 use self::operators::stateful_reduce::StatefulReduce;
 use self::operators::time_column::TimeColumnBuffer;
+use self::operators::{ArrangeWithPlacement, MaybeTotal, Placement, Reshard, ReshardBalanced};
 use self::operators::{ArrangeWithTypes, FlatMapBatchedWithDeletionsFirst, MapWrapped};
-use self::operators::{MaybeTotal, Reshard};
 use self::shard::Shard;
 use self::time::{Epsilon, MaybeEpsilon, OriginalOrRetraction};
 use self::variable::SafeVariable;
 use self::windows::assign_windows;
+use super::clock;
+use super::columnar::{ColumnarBatch, ColumnarBatchBuilder, ColumnarColumn, ColumnarRows};
+use super::dry_run::{ConnectorCheck, ConnectorDirection, DryRunReport};
 use super::error::{register_custom_panic_hook, DataError, DataResult, DynError, DynResult, Trace};
+use super::explain::{ExplainPlan, OperatorPlan};
 use super::expression::AnyExpression;
 use super::external_index_wrappers::{ExternalIndexData, ExternalIndexQuery};
+use super::frontier::TotalFrontier;
 use super::graph::{
-    DataRow, ExportedTable, OperatorProperties, SubscribeCallbacks, SubscribeConfig,
+    DataRow, DeliverySemantics, ExportedTable, FlightEndpoint, OperatorProperties, OutputBatching,
+    OutputMode, OutputWriters, SortColumn, SubscribeCallbacks, SubscribeConfig,
 };
 use super::http_server::maybe_run_http_server_thread;
+use super::key_collisions::{key_collision_detection_enabled, KeyCollisionDetector};
 use super::license::License;
-use super::progress_reporter::{maybe_run_reporter, MonitoringLevel};
+use super::lifecycle::{RunCallbacks, RunLifecycle};
+use super::progress_reporter::{maybe_run_reporter, MonitoringLevel, StatsMonitor};
 use super::reduce::{
     AnyReducer, ArgMaxReducer, ArgMinReducer, ArraySumReducer, CountReducer, EarliestReducer,
     FloatSumReducer, LatestReducer, MaxReducer, MinReducer, ReducerImpl, SortedTupleReducer,
@@ -144,8 +179,8 @@ use super::telemetry::maybe_run_telemetry_thread;
 use super::{
     BatchWrapper, ColumnHandle, ColumnPath, ColumnProperties, ComplexColumn, Error, ErrorLogHandle,
     Expression, ExpressionData, Graph, IterationLogic, IxKeyPolicy, JoinData, JoinType, Key,
-    LegacyTable, Reducer, ReducerData, Result, ShardPolicy, TableHandle, TableProperties,
-    Timestamp, UniverseHandle, Value, WindowProperties,
+    LegacyTable, OrderingViolationPolicy, Reducer, ReducerData, Result, RunLineage, ShardPolicy,
+    TableHandle, TableProperties, Timestamp, UniverseHandle, Value, WindowProperties,
 };
 use crate::external_integration::{
     make_accessor, make_option_accessor, ExternalIndex, IndexDerivedImpl,
@@ -202,6 +237,7 @@ type KeysArranged<S> = ArrangedBySelf<S, Key>;
 type KeysVar<S> = Var<S, Key>;
 type ValuesArranged<S> = ArrangedByKey<S, Key, Value>;
 type ValuesVar<S> = Var<S, (Key, Value)>;
+type ColumnarValues<S> = Stream<S, ColumnarRows<<S as MaybeTotalScope>::MaybeTotalTimestamp>>;
 
 #[derive(Clone)]
 enum Values<S: MaybeTotalScope> {
@@ -216,6 +252,10 @@ enum Values<S: MaybeTotalScope> {
     Generic {
         generic_collection: Collection<S, (Key, Value)>,
     },
+    Columnar {
+        columnar_rows: ColumnarValues<S>,
+        generic_collection: OnceCell<Collection<S, (Key, Value)>>,
+    },
 }
 
 impl<S: MaybeTotalScope> Deref for Values<S> {
@@ -250,6 +290,14 @@ impl<S: MaybeTotalScope> Values<S> {
             Self::Generic {
                 generic_collection, ..
             } => generic_collection,
+            Self::Columnar {
+                columnar_rows,
+                generic_collection,
+            } => generic_collection.get_or_init(|| {
+                columnar_rows
+                    .flat_map(ColumnarRows::into_rows)
+                    .as_collection()
+            }),
         }
     }
 }
@@ -278,6 +326,15 @@ impl<S: MaybeTotalScope> From<Collection<S, (Key, Value)>> for Values<S> {
     }
 }
 
+impl<S: MaybeTotalScope> From<ColumnarValues<S>> for Values<S> {
+    fn from(columnar_rows: ColumnarValues<S>) -> Self {
+        Values::Columnar {
+            columnar_rows,
+            generic_collection: OnceCell::new(),
+        }
+    }
+}
+
 enum UniverseData<S: MaybeTotalScope> {
     FromCollection {
         collection: Keys<S>,
@@ -632,7 +689,7 @@ impl ErrorLogInner {
     }
     fn maybe_flush(&mut self) -> SystemTime {
         // returns time of the next flush
-        let now = SystemTime::now();
+        let now = clock::now();
         let flush = self
             .last_flush
             .is_none_or(|last_flush| last_flush + ERROR_LOG_FLUSH_PERIOD <= now);
@@ -649,13 +706,18 @@ impl ErrorLogInner {
 #[derive(Clone)]
 struct ErrorLog {
     inner: Rc<RefCell<ErrorLogInner>>,
+    run_id: ArcStr,
 }
 
 impl ErrorLog {
-    fn new(input_session: InputSession<Timestamp, (Key, Value), isize>) -> ErrorLog {
+    fn new(
+        input_session: InputSession<Timestamp, (Key, Value), isize>,
+        run_id: ArcStr,
+    ) -> ErrorLog {
         let inner = ErrorLogInner::new(input_session);
         ErrorLog {
             inner: Rc::new(RefCell::new(inner)),
+            run_id,
         }
     }
 
@@ -687,6 +749,7 @@ impl ErrorLogger {
                     Value::from(self.operator_id),
                     Value::from(ArcStr::from(error)),
                     Value::from(ArcStr::from(trace)),
+                    Value::from(error_log.run_id.clone()),
                 ]
                 .as_slice(),
             ));
@@ -749,9 +812,17 @@ struct DataflowGraphInner<S: MaybeTotalScope> {
     current_operator_properties: Option<OperatorProperties>,
     reducer_factory: Box<dyn CreateDataflowReducer<S>>,
     connector_synchronizer: SharedConnectorSynchronizer,
+    sink_aligner: SharedSinkAligner,
+    sink_committer: SinkCommitter,
     max_expression_batch_size: usize,
+    deterministic_expression_cache_size: usize,
     udf_cache_directory: Option<PathBuf>,
     expression_cache_counter: usize,
+    lineage: Arc<RunLineage>,
+    dry_run_report: Option<Arc<DryRunReport>>,
+    graceful_shutdown: Option<Arc<GracefulShutdown>>,
+    step_mode_enabled: bool,
+    explain_plan: ExplainPlan,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -972,6 +1043,12 @@ where
     Self: Sized,
 {
     fn maybe_persist(&self, graph: &mut DataflowGraphInner<S>, name: &str) -> Result<Self> {
+        if operator_persistence_enabled(
+            graph.persistence_wrapper.as_ref(),
+            RequiredPersistenceMode::OperatorPersistence,
+        ) {
+            graph.expect_persistence();
+        }
         self.maybe_persist_internal(
             &mut graph.persistence_wrapper,
             &mut graph.pollers,
@@ -1005,7 +1082,6 @@ where
         connector_threads: &mut Vec<JoinHandle<()>>,
         name: &str,
     ) -> Result<Self> {
-        // TODO: generate better unique names that can be used even if graph changes
         let effective_persistent_id = effective_persistent_id(
             persistence_wrapper,
             false,
@@ -1061,6 +1137,8 @@ where
     }
 }
 
+// The persisted state is always placed by key, so the rows can be reduced in place only if the
+// operator persistence is disabled.
 trait MaybePersistedStatefulReduce<S, K, V, R>
 where
     S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>,
@@ -1074,6 +1152,7 @@ where
         name: &str,
         unique_name: Option<&UniqueName>,
         required_persistence_mode: RequiredPersistenceMode,
+        placement: Placement,
         logic: impl FnMut(Option<&V2>, Vec<(V, R)>) -> Option<V2> + 'static,
     ) -> Result<Collection<S, (K, V2), R>>
     where
@@ -1095,6 +1174,7 @@ where
         name: &str,
         unique_name: Option<&UniqueName>,
         required_persistence_mode: RequiredPersistenceMode,
+        placement: Placement,
         logic: impl FnMut(Option<&V2>, Vec<(V, R)>) -> Option<V2> + 'static,
     ) -> Result<Collection<S, (K, V2), R>>
     where
@@ -1113,6 +1193,9 @@ where
                 generated_external_id
             },
         );
+        if effective_persistent_id.is_some() {
+            graph.expect_persistence();
+        }
         let persistent_id = effective_persistent_id
             .clone()
             .map(IntoPersistentId::into_persistent_id);
@@ -1132,7 +1215,9 @@ where
             graph.connector_threads.push(thread_handle);
             Ok(persisted_collection)
         } else {
-            Ok(self.stateful_reduce_named(name, logic))
+            let arranged: ArrangedByKey<S, K, V, R> =
+                self.arrange_placed_named(&format!("Arrange: {name}"), placement);
+            Ok(arranged.stateful_reduce_named(name, logic))
         }
     }
 }
@@ -1174,6 +1259,9 @@ impl<S: MaybeTotalScope> MaybePersistedMapWithDeletionsFirst<S>
                 generated_external_id
             },
         );
+        if effective_persistent_id.is_some() {
+            graph.expect_persistence();
+        }
         let persistent_id = effective_persistent_id
             .clone()
             .map(IntoPersistentId::into_persistent_id);
@@ -1198,6 +1286,89 @@ impl<S: MaybeTotalScope> MaybePersistedMapWithDeletionsFirst<S>
     }
 }
 
+/// Evaluates the expressions for each of the rows of arguments, returning the columns of
+/// their results.
+fn evaluate_expressions(
+    expressions: &[ExpressionData],
+    args_count: usize,
+    rows: impl ExactSizeIterator<Item = impl Iterator<Item = Value>>,
+    columnar_arguments: bool,
+    max_expression_batch_size: usize,
+    error_logger: &dyn LogError,
+) -> ColumnarBatch {
+    let len = rows.len();
+    if columnar_arguments {
+        let mut batches = Vec::new();
+        let batch_capacity = max_expression_batch_size.min(len);
+        let new_batch_builder = || ColumnarBatchBuilder::new(args_count, batch_capacity);
+        let mut batch_builder = new_batch_builder();
+        for args_i in rows {
+            batch_builder.push_row(args_i);
+            if batch_builder.len() == max_expression_batch_size {
+                batches.push(mem::replace(&mut batch_builder, new_batch_builder()).finish());
+            }
+        }
+        if !batch_builder.is_empty() {
+            batches.push(batch_builder.finish());
+        }
+        return evaluate_expressions_columnar(expressions, len, &batches, error_logger);
+    }
+
+    let args: Vec<Vec<Value>> = rows.map(Iterator::collect).collect();
+    let args: Vec<&[Value]> = args.iter().map(|a| -> &[Value] { a }).collect();
+    // if a better behavior for append only is needed (then only output has to be append only, not input):
+    // split this closure here into two - first part (extraction from paths) before consolidation
+    // and second part (evals) after consolidation
+    let columns = expressions
+        .iter()
+        .map(|expression_data| {
+            let results = args
+                .chunks(max_expression_batch_size)
+                .flat_map(|args| expression_data.expression.eval(args));
+            results_column(expression_data, results, error_logger)
+        })
+        .collect();
+    ColumnarBatch::from_columns(len, columns)
+}
+
+/// Evaluates the expressions on the batches of arguments, of `len` rows in total,
+/// returning the columns of their results.
+fn evaluate_expressions_columnar(
+    expressions: &[ExpressionData],
+    len: usize,
+    batches: &[ColumnarBatch],
+    error_logger: &dyn LogError,
+) -> ColumnarBatch {
+    let columns = expressions
+        .iter()
+        .map(|expression_data| {
+            let results = batches
+                .iter()
+                .flat_map(|batch| expression_data.expression.eval_columnar(batch));
+            results_column(expression_data, results, error_logger)
+        })
+        .collect();
+    ColumnarBatch::from_columns(len, columns)
+}
+
+// the column of the results of an expression, with the errors logged and replaced
+fn results_column(
+    expression_data: &ExpressionData,
+    results: impl Iterator<Item = DynResult<Value>>,
+    error_logger: &dyn LogError,
+) -> ColumnarColumn {
+    let values = results
+        .map(|result| {
+            result.unwrap_or_log_with_trace(
+                error_logger,
+                expression_data.properties.trace().as_ref(),
+                Value::Error,
+            )
+        })
+        .collect();
+    ColumnarColumn::from_values(values)
+}
+
 #[allow(clippy::unnecessary_wraps)] // we want to always return Result for symmetry
 impl<S: MaybeTotalScope> DataflowGraphInner<S> {
     #[allow(clippy::too_many_arguments)]
@@ -1211,8 +1382,14 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         default_error_log: Option<ErrorLog>,
         reducer_factory: Box<dyn CreateDataflowReducer<S>>,
         connector_synchronizer: SharedConnectorSynchronizer,
+        sink_aligner: SharedSinkAligner,
         max_expression_batch_size: usize,
+        deterministic_expression_cache_size: usize,
         udf_cache_directory: Option<PathBuf>,
+        lineage: Arc<RunLineage>,
+        dry_run_report: Option<Arc<DryRunReport>>,
+        graceful_shutdown: Option<Arc<GracefulShutdown>>,
+        step_mode_enabled: bool,
     ) -> Result<Self> {
         Ok(Self {
             scope,
@@ -1238,9 +1415,17 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
             current_operator_properties: None,
             reducer_factory,
             connector_synchronizer,
+            sink_aligner,
+            sink_committer: SinkCommitter::new(),
             max_expression_batch_size,
+            deterministic_expression_cache_size,
             udf_cache_directory,
             expression_cache_counter: 0,
+            lineage,
+            dry_run_report,
+            graceful_shutdown,
+            step_mode_enabled,
+            explain_plan: ExplainPlan::new(),
         })
     }
 
@@ -1248,6 +1433,30 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         self.scope.index()
     }
 
+    fn current_operator_id(&self) -> Option<usize> {
+        self.current_operator_properties
+            .as_ref()
+            .map(|operator_properties| operator_properties.id)
+    }
+
+    fn expect_exchange(&mut self) {
+        if let Some(operator_id) = self.current_operator_id() {
+            self.explain_plan.record_exchange(operator_id);
+        }
+    }
+
+    fn expect_arrangement(&mut self) {
+        if let Some(operator_id) = self.current_operator_id() {
+            self.explain_plan.record_arrangement(operator_id);
+        }
+    }
+
+    fn expect_persistence(&mut self) {
+        if let Some(operator_id) = self.current_operator_id() {
+            self.explain_plan.record_persistence(operator_id);
+        }
+    }
+
     fn worker_count(&self) -> usize {
         self.scope.peers()
     }
@@ -1559,7 +1768,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         table_handle: TableHandle,
         column_paths: Vec<ColumnPath>,
         expressions: Vec<ExpressionData>,
-    ) -> Result<Collection<S, (Key, Value)>> {
+    ) -> Result<Values<S>> {
         let table = self
             .tables
             .get(table_handle)
@@ -1568,51 +1777,135 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         let error_reporter = self.error_reporter.clone();
         let error_logger = self.create_error_logger()?;
         let max_expression_batch_size = self.max_expression_batch_size;
+        // The arguments of the expressions are gathered into columnar batches for the
+        // evaluation if some expression can make use of it. Otherwise, they are kept as
+        // rows, so that the expressions that need them don't pay for the conversion.
+        // Either way, the results are collected in columns and turned into rows once.
+        let columnar_arguments = expressions
+            .iter()
+            .any(|expression_data| expression_data.expression.benefits_from_columnar());
 
-        Ok(table.values_consolidated().map_wrapped_batched_named(
-            "expression_table::evaluate_expression",
-            move |data| {
-                let mut results = Vec::with_capacity(data.len());
-                let mut args = Vec::with_capacity(data.len());
-                let mut keys = Vec::with_capacity(data.len());
-                for (key, values) in data {
-                    let args_i: Vec<Value> = column_paths
-                        .iter()
-                        .map(|path| path.extract(&key, &values))
-                        .collect::<Result<_>>()
-                        .unwrap_with_reporter(&error_reporter);
-                    args.push(args_i);
-                    keys.push(key);
-                    results.push(vec![Value::None; expressions.len()]);
-                }
+        // The results of deterministic expressions depend only on their arguments, so they
+        // can be reused for all the rows with the same arguments, whatever their keys.
+        let result_cache = NonZeroUsize::new(self.deterministic_expression_cache_size)
+            .filter(|_| {
+                expressions
+                    .iter()
+                    .all(|expression_data| expression_data.deterministic)
+            })
+            .map(LruCache::new);
+        let args_count = column_paths.len();
+        let Some(mut result_cache) = result_cache else {
+            return Ok(Self::evaluate_expressions_to_columns(
+                table,
+                column_paths,
+                error_reporter,
+                move |args| {
+                    evaluate_expressions(
+                        &expressions,
+                        args_count,
+                        args.into_iter().map(Vec::into_iter),
+                        columnar_arguments,
+                        max_expression_batch_size,
+                        error_logger.as_ref(),
+                    )
+                },
+            ));
+        };
 
-                let args: Vec<&[Value]> = args.iter().map(|a| -> &[Value] { a }).collect();
-                // if a better behavior for append only is needed (then only output has to be append only, not input):
-                // split this closure here into two - first part (extraction from paths) before consolidation
-                // and second part (evals) after consolidation
-                for (i, expression_data) in expressions.iter().enumerate() {
-                    let result_for_expression: Vec<_> = args
-                        .chunks(max_expression_batch_size)
-                        .flat_map(|args| expression_data.expression.eval(args))
-                        .collect();
-                    for (j, result_i) in result_for_expression.into_iter().enumerate() {
-                        let result_i = result_i.unwrap_or_log_with_trace(
-                            error_logger.as_ref(),
-                            expression_data.properties.trace().as_ref(),
-                            Value::Error,
-                        );
-                        results[j][i] = result_i;
-                    }
-                }
-                results
+        Ok(Self::evaluate_expressions_to_columns(
+            table,
+            column_paths,
+            error_reporter,
+            move |args| {
+                // Each distinct tuple of arguments missing from the cache is evaluated once.
+                let mut missing_args = IndexSet::new();
+                let positions: Vec<_> = args
                     .into_iter()
-                    .zip_eq(keys)
-                    .map(|(result_i, key)| (key, Value::Tuple(result_i.into())))
-                    .collect()
+                    .map(|args| match result_cache.get(&args) {
+                        Some(result) => Ok(Arc::clone(result)),
+                        None => Err(missing_args.insert_full(args).0),
+                    })
+                    .collect();
+                let results = evaluate_expressions(
+                    &expressions,
+                    args_count,
+                    missing_args.iter().map(|args| args.iter().cloned()),
+                    columnar_arguments,
+                    max_expression_batch_size,
+                    error_logger.as_ref(),
+                );
+                let computed: Vec<Arc<[Value]>> = missing_args
+                    .into_iter()
+                    .enumerate()
+                    .map(|(j, args)| {
+                        let result_j: Arc<[Value]> =
+                            (0..results.width()).map(|i| results.value(j, i)).collect();
+                        // errors are not cached so that they are logged again for later rows
+                        if !result_j.contains(&Value::Error) {
+                            result_cache.put(args, Arc::clone(&result_j));
+                        }
+                        result_j
+                    })
+                    .collect();
+                let rows: Vec<Arc<[Value]>> = positions
+                    .into_iter()
+                    .map(|result| result.unwrap_or_else(|position| Arc::clone(&computed[position])))
+                    .collect();
+                ColumnarBatch::from_rows(&rows, expressions.len())
             },
         ))
     }
 
+    /// Evaluates the expressions on the consolidated values of a table. The results are
+    /// kept in columns and converted into rows when the new table is first used. The
+    /// `evaluate` function gets the arguments of a batch of rows and returns the columns
+    /// of the results for them.
+    #[track_caller]
+    fn evaluate_expressions_to_columns(
+        table: &Table<S>,
+        column_paths: Vec<ColumnPath>,
+        error_reporter: ErrorReporter,
+        mut evaluate: impl FnMut(Vec<Vec<Value>>) -> ColumnarBatch + 'static,
+    ) -> Values<S> {
+        let caller = Location::caller();
+        let name = format!("expression_table::evaluate_expression at {caller}");
+        table
+            .values_consolidated()
+            .inner
+            .unary(Pipeline, &name, move |_capability, _info| {
+                let mut buffer = Vec::new();
+                move |input, output| {
+                    input.for_each(|cap, data| {
+                        data.swap(&mut buffer);
+                        let mut keys = Vec::with_capacity(buffer.len());
+                        let mut times = Vec::with_capacity(buffer.len());
+                        let mut diffs = Vec::with_capacity(buffer.len());
+                        let args: Vec<Vec<Value>> = buffer
+                            .drain(..)
+                            .map(|((key, values), time, diff)| {
+                                keys.push(key);
+                                times.push(time);
+                                diffs.push(diff);
+                                column_paths
+                                    .iter()
+                                    .map(|path| {
+                                        path.extract(&key, &values)
+                                            .unwrap_with_reporter(&error_reporter)
+                                    })
+                                    .collect()
+                            })
+                            .collect();
+                        let results = evaluate(args);
+                        output
+                            .session(&cap)
+                            .give(ColumnarRows::new(keys, times, diffs, results));
+                    });
+                }
+            })
+            .into()
+    }
+
     /// Creates the memoization storage for the non-deterministic expressions
     /// of a single `expression_table` operator.
     fn create_expression_cache(
@@ -1664,6 +1957,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
             move |collection| {
                 collection.flat_map_batched_named_with_deletions_first(
                     "expression_table::evaluate_expression",
+                    Placement::ByKey,
                     move |data_with_diffs| {
                         caches.begin_batch();
                         let mut results = vec![None; data_with_diffs.len()];
@@ -1796,10 +2090,11 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
             TableProperties::Table(properties.as_slice().into(), Arc::new(Trace::Empty));
 
         let new_values = if append_only_or_deterministic {
-            self.expression_table_deterministic(table_handle, column_paths, expressions)
+            self.expression_table_deterministic(table_handle, column_paths, expressions)?
         } else {
-            self.expression_table_non_deterministic(table_handle, column_paths, expressions)
-        }?;
+            self.expression_table_non_deterministic(table_handle, column_paths, expressions)?
+                .into()
+        };
 
         Ok(self
             .tables
@@ -1986,6 +2281,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         table_handle: TableHandle,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_exchange();
         let table = self
             .tables
             .get(table_handle)
@@ -2018,6 +2314,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
     where
         S::MaybeTotalTimestamp: Epsilon,
     {
+        self.expect_arrangement();
         let table = self
             .tables
             .get(table_handle)
@@ -2037,11 +2334,49 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
             .alloc(Table::from_collection(on_time).with_properties(table_properties)))
     }
 
+    fn retain_last_versions(
+        &mut self,
+        table_handle: TableHandle,
+        current_time_column_path: ColumnPath,
+        instance_column_path: ColumnPath,
+        max_versions: usize,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        self.expect_arrangement();
+        let table = self
+            .tables
+            .get(table_handle)
+            .ok_or(Error::InvalidTableHandle)?;
+
+        let error_reporter_1 = self.error_reporter.clone();
+        let error_reporter_2 = self.error_reporter.clone();
+
+        let new_table = retain_last_versions(
+            table.values(),
+            move |val| {
+                current_time_column_path
+                    .extract_from_value(val)
+                    .unwrap_with_reporter(&error_reporter_1)
+            },
+            move |val| {
+                instance_column_path
+                    .extract_from_value(val)
+                    .unwrap_with_reporter(&error_reporter_2)
+            },
+            max_versions,
+        );
+
+        Ok(self
+            .tables
+            .alloc(Table::from_collection(new_table).with_properties(table_properties)))
+    }
+
     fn restrict_column(
         &mut self,
         universe_handle: UniverseHandle,
         column_handle: ColumnHandle,
     ) -> Result<ColumnHandle> {
+        self.expect_arrangement();
         let universe = self
             .universes
             .get(universe_handle)
@@ -2073,6 +2408,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         same_universes: bool,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         let original_values_arranged =
             self.get_table_values_persisted_arranged(original_table_handle)?;
         let new_values_arranged = self.get_table_values_persisted_arranged(new_table_handle)?;
@@ -2113,6 +2449,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         other_table_handles: Vec<TableHandle>,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         let mut restricted_keys: Option<KeysArranged<S>> = None;
         for other_table_handle in other_table_handles {
             let other_table_keys_arranged =
@@ -2182,6 +2519,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         right_table_handle: TableHandle,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         let left_values_arranged = self.get_table_values_persisted_arranged(left_table_handle)?;
         let right_keys_arranged = self.get_table_keys_persisted_arranged(right_table_handle)?;
         let left_table = self
@@ -2303,6 +2641,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
     where
         <S as MaybeTotalScope>::MaybeTotalTimestamp: TotalOrder,
     {
+        self.expect_arrangement();
         let table = self
             .tables
             .get(table_handle)
@@ -2414,6 +2753,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         update_handle: TableHandle,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         let error_logger = self.create_error_logger()?;
         let trace = table_properties.trace();
         let both_arranged = self.update_rows_arrange(table_handle, update_handle)?;
@@ -2453,6 +2793,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         update_paths: Vec<ColumnPath>,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         let error_logger = self.create_error_logger()?;
         let both_arranged = self.update_rows_arrange(table_handle, update_handle)?;
 
@@ -2518,6 +2859,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         upper_path: ColumnPath,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         let table = self
             .tables
             .get(input_table_handle)
@@ -2578,6 +2920,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         ix_key_policy: IxKeyPolicy,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         let key_table = self
             .tables
             .get(key_handle)
@@ -2678,6 +3021,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         table_properties: Arc<TableProperties>,
         external_index: Box<dyn ExternalIndex>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         let index = self
             .tables
             .get(index_stream.table)
@@ -2726,6 +3070,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         join_exactly_once: JoinExactlyOnce,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         fn extract_join_key(
             key: &Key,
             values: &Value,
@@ -3151,7 +3496,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
             .get(table_handle)
             .ok_or(Error::InvalidTableHandle)?;
         let error_reporter = self.error_reporter.clone();
-        table.values().inspect(move |((key, values), time, diff)| {
+        let printed = table.values().inspect(move |((key, values), time, diff)| {
             let mut values_str = String::new();
             for (name, column_path) in &columns {
                 let column_value = column_path
@@ -3161,6 +3506,11 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
             }
             println!("[{worker}][{tag}] @{time:?} {diff:+} id={key}{values_str}");
         });
+        if self.step_mode_enabled {
+            // the debugged tables are outputs too, so that all their changes up to the time
+            // at which the step mode pauses are printed before the pause
+            printed.inner.probe_with(&self.output_probe);
+        }
         Ok(())
     }
 
@@ -3169,9 +3519,18 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
             .tables
             .get(table_handle)
             .ok_or(Error::InvalidTableHandle)?;
-        table
-            .values()
-            .extended_probe_with(self.probes.entry(operator_id).or_default());
+        let probe = self.probes.entry(operator_id).or_default();
+        table.values().extended_probe_with(probe);
+        if table_gauges_enabled() {
+            let gauge = probe
+                .table_gauges
+                .entry(<TableHandle as ArenaBehavior>::index(table_handle))
+                .or_insert_with(|| Rc::new(RefCell::new(TableGauge::new(operator_id))))
+                .clone();
+            table.values().inspect(move |((_key, value), _time, diff)| {
+                gauge.borrow_mut().update(value, *diff);
+            });
+        }
         Ok(())
     }
 
@@ -3200,6 +3559,9 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
     }
 
     fn set_operator_properties(&mut self, operator_properties: OperatorProperties) -> Result<()> {
+        self.explain_plan.record_operator(operator_properties.id);
+        self.persistence_wrapper
+            .set_operator_identity(operator_properties.stable_id.clone());
         self.current_operator_properties = Some(operator_properties);
         Ok(())
     }
@@ -3242,6 +3604,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         table_handle: TableHandle,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_exchange();
         let table = self
             .tables
             .get(table_handle)
@@ -3295,6 +3658,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
         column_paths: Vec<ColumnPath>,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_exchange();
         let error_reporter = self.error_reporter.clone();
         let trace = table_properties.trace();
         let new_values = self
@@ -3323,6 +3687,7 @@ trait DataflowReducer<S: MaybeTotalScope> {
     fn reduce(
         self: Rc<Self>,
         values: &Collection<S, (Key, Key, Vec<Value>)>,
+        placement: Placement,
         error_logger: Rc<dyn LogError>,
         trace: Trace,
         graph: &mut DataflowGraphInner<S>,
@@ -3337,11 +3702,12 @@ where
     fn reduce(
         self: Rc<Self>,
         values: &Collection<S, (Key, Key, Vec<Value>)>,
+        placement: Placement,
         error_logger: Rc<dyn LogError>,
         _trace: Trace,
         graph: &mut DataflowGraphInner<S>,
     ) -> Result<Values<S>> {
-        Ok(values
+        let states: ArrangedByKey<S, Key, Option<<R as ReducerImpl>::State>> = values
             .map_named("DataFlowReducer::reduce::init", {
                 let self_ = self.clone();
                 let error_logger = error_logger.clone();
@@ -3357,6 +3723,8 @@ where
                 }
             })
             .maybe_persist(graph, "DataFlowReducer::reduce")?
+            .arrange_placed_named("Arrange: DataFlowReducer::reduce", placement);
+        Ok(states
             .reduce({
                 let self_ = self.clone();
                 move |_key, input, output| {
@@ -3392,20 +3760,25 @@ impl<S: MaybeTotalScope> DataflowReducer<S> for CountDistinctReducer {
     fn reduce(
         self: Rc<Self>,
         values: &Collection<S, (Key, Key, Vec<Value>)>,
+        placement: Placement,
         _error_logger: Rc<dyn LogError>,
         _trace: Trace,
         graph: &mut DataflowGraphInner<S>,
     ) -> Result<Values<S>> {
-        Ok(values
+        let distinct_values: ArrangedBySelf<S, (Key, Key)> = values
             .map_named("CountDistinctReducer::init", {
                 move |(_source_key, result_key, values)| (result_key, Key::for_values(&values))
             })
             .maybe_persist(graph, "CountDistinctReducer::reduce")?
+            .arrange_placed_named("Arrange: CountDistinctReducer::distinct", placement);
+        let result_keys: ArrangedBySelf<S, Key> = distinct_values
             .distinct()
             .map_named(
                 "CountDistinctReducer::intermediate",
                 |(result_key, _values_hash)| result_key,
             )
+            .arrange_placed_named("Arrange: CountDistinctReducer::count", placement);
+        Ok(result_keys
             .count()
             .map_named("CountDistinctReducer::reduce", |(key, count)| {
                 (key, Value::from(count as i64))
@@ -3418,6 +3791,7 @@ impl<S: MaybeTotalScope> DataflowReducer<S> for CountDistinctApproximateReducer
     fn reduce(
         self: Rc<Self>,
         values: &Collection<S, (Key, Key, Vec<Value>)>,
+        placement: Placement,
         _error_logger: Rc<dyn LogError>,
         _trace: Trace,
         graph: &mut DataflowGraphInner<S>,
@@ -3440,6 +3814,7 @@ impl<S: MaybeTotalScope> DataflowReducer<S> for CountDistinctApproximateReducer
             .maybe_persist(graph, "CountDistinctApproximate::reduce")?
             .flat_map_batched_named_with_deletions_first(
                 "CountDistinctApproximate::main",
+                placement,
                 move |mut data_with_diffs| {
                     data_with_diffs
                         .sort_unstable_by_key(|((result_key, _value_key), _diff)| *result_key);
@@ -3484,11 +3859,12 @@ where
     fn reduce(
         self: Rc<Self>,
         values: &Collection<S, (Key, Key, Vec<Value>)>,
+        placement: Placement,
         error_logger: Rc<dyn LogError>,
         _trace: Trace,
         graph: &mut DataflowGraphInner<S>,
     ) -> Result<Values<S>> {
-        Ok(values
+        let states: ArrangedBySelf<S, Key, ErrorStateWrapper<State>> = values
             .map_named("SemigroupReducer::reduce::init", {
                 move |(source_key, result_key, values)| {
                     let state = if values.contains(&Value::Error) {
@@ -3504,6 +3880,8 @@ where
             })
             .explode(|(key, state)| once((key, state)))
             .maybe_persist(graph, "SemigroupReducer::reduce")?
+            .arrange_placed_named("Arrange: SemigroupReducer::reduce", placement);
+        Ok(states
             .count()
             .map_named("SemigroupReducer::reduce", move |(key, state)| {
                 (key, state.finish())
@@ -3516,16 +3894,19 @@ impl<S: MaybeTotalScope> DataflowReducer<S> for CountReducer {
     fn reduce(
         self: Rc<Self>,
         values: &Collection<S, (Key, Key, Vec<Value>)>,
+        placement: Placement,
         _error_logger: Rc<dyn LogError>,
         _trace: Trace,
         graph: &mut DataflowGraphInner<S>,
     ) -> Result<Values<S>> {
-        Ok(values
+        let result_keys: ArrangedBySelf<S, Key> = values
             .map_named(
                 "CountReducer::reduce::init",
                 |(_source_key, result_key, _values)| result_key,
             )
             .maybe_persist(graph, "CountReducer::reduce")?
+            .arrange_placed_named("Arrange: CountReducer::reduce", placement);
+        Ok(result_keys
             .count()
             .map_named("CountReducer::reduce", |(key, count)| {
                 (key, Value::from(count as i64))
@@ -3541,6 +3922,7 @@ where
     fn reduce(
         self: Rc<Self>,
         values: &Collection<S, (Key, Key, Vec<Value>)>,
+        placement: Placement,
         error_logger: Rc<dyn LogError>,
         trace: Trace,
         graph: &mut DataflowGraphInner<S>,
@@ -3555,6 +3937,7 @@ where
                 "StatefulReducer::reduce::reduce",
                 None,
                 RequiredPersistenceMode::OperatorPersistence,
+                placement,
                 move |state, values| {
                     let contains_errors = state == Some(&Value::Error)
                         || values.iter().any(|(row, _cnt)| row.contains(&Value::Error));
@@ -3580,6 +3963,7 @@ where
     fn reduce(
         self: Rc<Self>,
         values: &Collection<S, (Key, Key, Vec<Value>)>,
+        placement: Placement,
         _error_logger: Rc<dyn LogError>,
         _trace: Trace,
         graph: &mut DataflowGraphInner<S>,
@@ -3594,6 +3978,7 @@ where
                 "LatestReducer::reduce::reduce",
                 None,
                 RequiredPersistenceMode::OperatorPersistence,
+                placement,
                 move |_state, values| {
                     let (_result_key, result_value) = values
                         .into_iter()
@@ -3617,6 +4002,7 @@ where
     fn reduce(
         self: Rc<Self>,
         values: &Collection<S, (Key, Key, Vec<Value>)>,
+        placement: Placement,
         _error_logger: Rc<dyn LogError>,
         _trace: Trace,
         graph: &mut DataflowGraphInner<S>,
@@ -3631,6 +4017,7 @@ where
                 "EarliestReducer::reduce::reduce",
                 None,
                 RequiredPersistenceMode::OperatorPersistence,
+                placement,
                 move |state, values| {
                     if state.is_some() {
                         return state.cloned();
@@ -3744,15 +4131,19 @@ where
 }
 
 impl<S: MaybeTotalScope> DataflowGraphInner<S> {
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::too_many_lines)]
     fn group_by_table(
         &mut self,
         table_handle: TableHandle,
         grouping_columns_paths: Vec<ColumnPath>,
         shard_policy: ShardPolicy,
+        shard_column: Option<usize>,
         reducers: Vec<ReducerData>,
         set_id: bool,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         if set_id {
             assert!(grouping_columns_paths.len() == 1);
         }
@@ -3770,9 +4161,28 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
             })
             .try_collect()?;
 
+        // With a `shard_column`, the rows are sent to the worker of the shard of its value and
+        // their groups are reduced there in place, so that the groups with the same value of it
+        // are reduced by the same worker and still get the keys of all the grouping columns.
+        // With more than one logical shard per worker, the groups are reduced in place as well,
+        // on the workers their logical shards are currently assigned to.
+        let operator_persistence = operator_persistence_enabled(
+            self.persistence_wrapper.as_ref(),
+            RequiredPersistenceMode::OperatorPersistence,
+        );
+        if shard_column.is_some() && operator_persistence {
+            return Err(Error::ShardedGroupByWithOperatorPersistence);
+        }
+        let balanced = self.config.shards_per_worker() > 1 && !operator_persistence;
+        let placement = if shard_column.is_some() || balanced {
+            Placement::InPlace
+        } else {
+            Placement::ByKey
+        };
+
         let error_logger = self.create_error_logger()?;
         let trace = table_properties.trace();
-        let with_new_key = table.values().flat_map(move |(key, values)| {
+        let with_keys = table.values().flat_map(move |(key, values)| {
             let new_key_parts: Vec<Value> = grouping_columns_paths
                 .iter()
                 .map(|path| path.extract(&key, &values))
@@ -3792,8 +4202,24 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
             } else {
                 Some(shard_policy.generate_key(&new_key_parts))
             };
-            Some((key, new_key?, values))
+            let new_key = new_key?;
+            let shard = match shard_column {
+                Some(column) => Key::for_value(&new_key_parts[column]),
+                None => new_key,
+            };
+            Some((shard, key, new_key, values))
         });
+        let with_keys = match placement {
+            Placement::ByKey => with_keys,
+            Placement::InPlace if balanced => {
+                with_keys.reshard_balanced(self.config.threads(), self.config.shards_per_worker())
+            }
+            Placement::InPlace => with_keys.reshard(),
+        };
+        let with_new_key = with_keys.map_named(
+            "group_by_table::with_new_key",
+            |(_shard, key, new_key, values)| (key, new_key, values),
+        );
         let reduced_columns: Vec<_> = reducer_impls
             .iter()
             .zip(reducers)
@@ -3814,6 +4240,7 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
                 });
                 reducer_impl.clone().reduce(
                     &with_extracted_value,
+                    placement,
                     self.create_error_logger()?.into(),
                     data.trace,
                     self,
@@ -3826,8 +4253,10 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
                     (key, Arc::from([value].as_slice()))
                 });
             for column in reduced_columns.iter().skip(1) {
-                let joined_arranged: ArrangedByKey<S, Key, Arc<[Value]>> = joined.arrange();
-                let column_arranged: ArrangedByKey<S, Key, Value> = column.arrange();
+                let joined_arranged: ArrangedByKey<S, Key, Arc<[Value]>> =
+                    joined.arrange_placed_named("Arrange", placement);
+                let column_arranged: ArrangedByKey<S, Key, Value> =
+                    column.arrange_placed_named("Arrange", placement);
                 joined = joined_arranged.join_core(&column_arranged, |key, values, value| {
                     let new_values: Arc<[Value]> = values.iter().chain([value]).cloned().collect();
                     once((*key, new_values))
@@ -3839,11 +4268,13 @@ impl<S: MaybeTotalScope> DataflowGraphInner<S> {
                 })
                 .filter_out_persisted(&mut self.persistence_wrapper)?
         } else {
-            with_new_key
+            let new_keys: ArrangedBySelf<S, (Key, Value)> = with_new_key
                 .map_named("group_by_table::empty", |(_key, new_key, _values)| {
                     (new_key, Value::Tuple(Arc::from([])))
                 })
                 .maybe_persist(self, "groupby")?
+                .arrange_placed_named("Arrange: group_by_table::empty", placement);
+            new_keys
                 .distinct()
                 .filter_out_persisted(&mut self.persistence_wrapper)?
         };
@@ -3864,6 +4295,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         unique_name: Option<&UniqueName>,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_arrangement();
         let table = self
             .tables
             .get(table_handle)
@@ -3905,6 +4337,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                 "deduplicate::reduce",
                 unique_name,
                 RequiredPersistenceMode::InputOrOperatorPersistence,
+                Placement::ByKey,
                 move |state, values| match (combine_fn)(state, values) {
                     Ok(new_state) => new_state,
                     Err(error) => {
@@ -3919,6 +4352,132 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
             .tables
             .alloc(Table::from_collection(new_values).with_properties(table_properties)))
     }
+
+    fn version_rows(
+        &mut self,
+        table_handle: TableHandle,
+        column_paths: Vec<ColumnPath>,
+        unique_name: Option<&UniqueName>,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        self.expect_arrangement();
+        let rows = self
+            .extract_columns(table_handle, column_paths)?
+            .as_collection()
+            .map_named("version_rows::rows", |(key, tuple)| {
+                (key, Value::from(tuple.as_value_slice()))
+            })
+            .filter_out_persisted(&mut self.persistence_wrapper)?; // needed if used with regular persistence
+
+        // The state of a key is its last version together with the information whether
+        // the row is present. It is kept after the deletion of the row, so that a row
+        // inserted again with the same key gets a larger version.
+        let versions = rows
+            .maybe_persisted_stateful_reduce(
+                self,
+                "version_rows::reduce",
+                unique_name,
+                RequiredPersistenceMode::InputOrOperatorPersistence,
+                Placement::ByKey,
+                |state: Option<&Value>, data: Vec<(Value, isize)>| {
+                    let version = match state.map(Value::as_tuple) {
+                        Some(Ok(state)) => state[0].as_int().expect("version should be an int"),
+                        _ => 0,
+                    };
+                    let is_present = data.iter().any(|(_row, diff)| *diff > 0);
+                    Some(Value::Tuple(Arc::from([
+                        Value::Int(version + 1),
+                        Value::Bool(is_present),
+                    ])))
+                },
+            )?
+            .filter_out_persisted(&mut self.persistence_wrapper)?;
+
+        let new_values = versions.flat_map(|(key, state)| {
+            let state = state.as_tuple().expect("state should be a tuple");
+            (state[1] == Value::Bool(true)).then(|| (key, Value::from(&state[..1])))
+        });
+        Ok(self
+            .tables
+            .alloc(Table::from_collection(new_values).with_properties(table_properties)))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn assert_monotonic(
+        &mut self,
+        table_handle: TableHandle,
+        column_paths: Vec<ColumnPath>,
+        sequence_path: ColumnPath,
+        instance_path: Option<ColumnPath>,
+        strict: bool,
+        on_violation: OrderingViolationPolicy,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        self.expect_exchange();
+        let table = self
+            .tables
+            .get(table_handle)
+            .ok_or(Error::InvalidTableHandle)?;
+
+        let error_reporter = self.error_reporter.clone();
+        let sequence_by_instance =
+            table
+                .values()
+                .map_named("assert_monotonic::extract", move |(key, values)| {
+                    let extract = |path: &ColumnPath| {
+                        path.extract(&key, &values)
+                            .unwrap_with_reporter(&error_reporter)
+                    };
+                    let sequence = extract(&sequence_path);
+                    let instance = match &instance_path {
+                        Some(path) => Key::for_value(&extract(path)),
+                        None => key,
+                    };
+                    let output_values: Vec<Value> = column_paths.iter().map(extract).collect();
+                    (
+                        instance,
+                        ((key, Value::from(output_values.as_slice())), sequence),
+                    )
+                });
+
+        let error_reporter = self.error_reporter.clone();
+        let trace = table_properties.trace();
+        let expected_order = if strict {
+            "increasing"
+        } else {
+            "non-decreasing"
+        };
+        let new_values = check_monotonic(
+            &sequence_by_instance,
+            "assert_monotonic::check",
+            move |previous, current| {
+                if strict {
+                    current > previous
+                } else {
+                    current >= previous
+                }
+            },
+            move |(key, _values), previous, current| {
+                let error = DataError::OrderingViolation {
+                    key: *key,
+                    previous: previous.clone(),
+                    current: current.clone(),
+                    expected_order,
+                };
+                match on_violation {
+                    OrderingViolationPolicy::Fail => {
+                        error_reporter.report_and_panic_with_trace(error, &trace);
+                    }
+                    OrderingViolationPolicy::Log => {
+                        warn!("{}", Error::with_trace(error, (*trace).clone()));
+                    }
+                }
+            },
+        );
+        Ok(self
+            .tables
+            .alloc(Table::from_collection(new_values).with_properties(table_properties)))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -3927,6 +4486,97 @@ enum OutputEvent {
     Batch(OutputBatch<Timestamp, (Key, Tuple), isize>),
 }
 
+// An entry of an output batch: a change of a row or, if its deletion and insertion are
+// collapsed, an update of the row from the old values to the new ones.
+#[derive(Debug, Clone, Copy)]
+enum OutputEntry<'a> {
+    Change(Key, &'a [Value], isize),
+    Update(Key, &'a [Value], &'a [Value]),
+}
+
+impl OutputEntry<'_> {
+    fn format(
+        self,
+        formatter: &mut dyn Formatter,
+        time: Timestamp,
+    ) -> Result<FormatterContext, FormatterError> {
+        match self {
+            Self::Change(key, values, diff) => formatter.format(&key, values, time, diff),
+            Self::Update(key, old_values, new_values) => Ok(formatter
+                .format_update(&key, old_values, new_values, time)?
+                .expect("an update that was formatted once should be formatted again")),
+        }
+    }
+}
+
+// The output written to a sink since it was last flushed.
+#[derive(Debug, Default)]
+struct PendingOutput {
+    entries: usize,
+    // The latest time committed since the last flush
+    commit: Option<Option<Timestamp>>,
+    since: Option<Instant>,
+}
+
+impl PendingOutput {
+    fn on_entry_written(&mut self) {
+        self.entries += 1;
+        self.since.get_or_insert_with(Instant::now);
+    }
+
+    fn on_commit(&mut self, t: Option<Timestamp>) {
+        self.commit = Some(t);
+        self.since.get_or_insert_with(Instant::now);
+    }
+
+    // The entries are flushed, but the commit is delayed until the next full flush
+    fn on_entries_flushed(&mut self) {
+        self.entries = 0;
+        if self.commit.is_none() {
+            self.since = None;
+        }
+    }
+
+    fn deadline(&self, batching: &OutputBatching) -> Option<Instant> {
+        Some(self.since? + batching.linger?)
+    }
+
+    fn is_due(&self, batching: &OutputBatching) -> bool {
+        let Some(linger) = batching.linger else {
+            return true;
+        };
+        self.commit == Some(None)
+            || batching
+                .max_batch_size
+                .is_some_and(|max_batch_size| self.entries >= max_batch_size)
+            || self.since.is_some_and(|since| since.elapsed() >= linger)
+    }
+}
+
+// The batches of a watermark-atomic output whose times the output frontier hasn't
+// passed yet.
+#[derive(Debug, Default)]
+struct WithheldOutput {
+    batches: Vec<OutputBatch<Timestamp, (Key, Tuple), isize>>,
+}
+
+impl WithheldOutput {
+    // Returns the batches with the times before `frontier`, which are complete
+    fn release(
+        &mut self,
+        frontier: Option<Timestamp>,
+    ) -> Vec<OutputBatch<Timestamp, (Key, Tuple), isize>> {
+        let Some(frontier) = frontier else {
+            return mem::take(&mut self.batches);
+        };
+        let (released, withheld) = mem::take(&mut self.batches)
+            .into_iter()
+            .partition(|batch| batch.time < frontier);
+        self.batches = withheld;
+        released
+    }
+}
+
 #[allow(clippy::unnecessary_wraps)] // we want to always return Result for symmetry
 impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S> {
     fn empty_table(&mut self, table_properties: Arc<TableProperties>) -> Result<TableHandle> {
@@ -4028,30 +4678,52 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         table_properties: Arc<TableProperties>,
         unique_name: Option<&UniqueName>,
         synchronization_group: Option<&ConnectorGroupDescriptor>,
-        max_backlog_size: Option<usize>,
+        backlog_limit: BacklogLimit,
+        rate_limit: RateLimit,
+        watermark_strategy: Option<WatermarkStrategy>,
         timestamp_at_start: Timestamp,
+        start_from: Option<OffsetAntichain>,
     ) -> Result<TableHandle> {
-        let effective_persistent_id = effective_persistent_id(
-            &mut self.persistence_wrapper,
-            reader.is_internal(),
-            unique_name,
-            RequiredPersistenceMode::InputOrOperatorPersistence,
-            |_| {
-                let generated_external_id = format!("{}-{}", reader.name(None), self.pollers.len());
+        let effective_persistent_id = if start_from.is_some() {
+            // the persisted state describes a different starting point
+            if self.persistence_wrapper.get_persistence_config().is_some() {
                 info!(
-                    "Unique name autogenerated for a {:?} reader because persistence is enabled: {generated_external_id}",
+                    "The {:?} reader starts from explicit offsets, its persisted state is not used",
                     reader.storage_type()
                 );
-                generated_external_id
-            },
-        );
+            }
+            None
+        } else {
+            effective_persistent_id(
+                &mut self.persistence_wrapper,
+                reader.is_internal(),
+                unique_name,
+                RequiredPersistenceMode::InputOrOperatorPersistence,
+                |_| {
+                    let generated_external_id =
+                        format!("{}-{}", reader.name(None), self.pollers.len());
+                    info!(
+                        "Unique name autogenerated for a {:?} reader because persistence is enabled: {generated_external_id}",
+                        reader.storage_type()
+                    );
+                    generated_external_id
+                },
+            )
+        };
         let internal_persistent_id = effective_persistent_id
             .clone()
             .map(IntoPersistentId::into_persistent_id);
 
         let (input_session, table_values) = self.new_collection(parser.session_type())?;
 
-        let table_values = table_values.reshard();
+        // The rows of a partition-aligned reader are already on their shard's worker
+        let partition_aligned = reader.is_partition_aligned();
+        let table_values = if partition_aligned {
+            table_values
+        } else {
+            self.expect_exchange();
+            table_values.reshard()
+        };
         table_values.probe_with(&mut self.input_probe);
 
         let realtime_reader_needed = self.scope.index() < parallel_readers
@@ -4064,6 +4736,25 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                 .persistence_wrapper
                 .get_worker_persistent_storage()
                 .is_some();
+        if persisted_table {
+            self.expect_persistence();
+        }
+
+        if let Some(dry_run_report) = &self.dry_run_report {
+            if realtime_reader_needed && !dry_run_report.skips_connectors() {
+                let name = reader.name(unique_name);
+                let error = reader.build().err().map(|e| e.to_string());
+                dry_run_report.record_connector(ConnectorCheck {
+                    worker_index: self.scope.index(),
+                    direction: ConnectorDirection::Input,
+                    name,
+                    error,
+                });
+            }
+            return Ok(self
+                .tables
+                .alloc(Table::from_collection(table_values).with_properties(table_properties)));
+        }
 
         let group = match synchronization_group {
             Some(synchronization_group) if realtime_reader_needed => Some(
@@ -4086,7 +4777,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                 .get_persistence_config()
                 .map_or(SnapshotAccess::Full, |config| config.snapshot_access);
 
-            let connector = Connector::new(
+            let mut connector = Connector::new(
                 commit_duration,
                 parser.column_count(),
                 self.terminate_on_error,
@@ -4094,27 +4785,56 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                 group,
                 parallel_readers,
             );
+            if let Some(start_from) = start_from {
+                connector = connector.with_start_from(start_from);
+            }
+            if !rate_limit.is_unlimited() {
+                connector = connector.with_rate_limit(rate_limit.per_reader(parallel_readers));
+            }
+            if let Some(watermark_strategy) = watermark_strategy {
+                connector = connector.with_watermark_strategy(watermark_strategy);
+            }
+            if let Some(graceful_shutdown) = &self.graceful_shutdown {
+                connector = connector.with_graceful_shutdown(graceful_shutdown.clone());
+            }
+            let mut key_collision_detector = if key_collision_detection_enabled() {
+                Some(KeyCollisionDetector::new(self.create_error_logger()?))
+            } else {
+                None
+            };
             let state = connector.run(
                 reader,
                 parser,
                 input_session,
-                move |values, offset| match values {
-                    None => {
-                        let (offset_key, offset_value) =
-                            offset.expect("offset is required for key generation");
-                        let mut hasher = Hasher::default();
-                        offset_key.hash_into(&mut hasher);
-                        offset_value.hash_into(&mut hasher);
-                        Key::from_hasher(&hasher)
-                    }
-                    Some(values) => {
-                        // do not hash again if this is pointer already
-                        if values.len() == 1 {
-                            if let Value::Pointer(key) = values[0] {
-                                return key;
+                move |values, offset| {
+                    let key = match values {
+                        None => {
+                            let (offset_key, offset_value) =
+                                offset.expect("offset is required for key generation");
+                            let mut hasher = Hasher::default();
+                            offset_key.hash_into(&mut hasher);
+                            offset_value.hash_into(&mut hasher);
+                            Key::from_hasher(&hasher)
+                        }
+                        Some(values) => match values.as_slice() {
+                            // do not hash again if this is pointer already
+                            [Value::Pointer(key)] => *key,
+                            values => {
+                                let key = Key::for_values(values);
+                                if let Some(detector) = &mut key_collision_detector {
+                                    detector.check(key, values);
+                                }
+                                key
                             }
+                        },
+                    };
+                    match offset {
+                        Some((OffsetKey::Kafka(_, partition), _)) if partition_aligned => {
+                            let partition = usize::try_from(*partition)
+                                .expect("kafka partition can't be negative");
+                            key.with_shard(partition)
                         }
-                        Key::for_values(values)
+                        _ => key,
                     }
                 },
                 self.output_probe.clone(),
@@ -4127,7 +4847,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                 persistence_mode,
                 snapshot_access,
                 self.error_reporter.clone(),
-                max_backlog_size,
+                backlog_limit,
                 timestamp_at_start,
             )?;
 
@@ -4158,7 +4878,9 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         current_time_column_path: ColumnPath,
         instance_column_path: ColumnPath,
         table_properties: Arc<TableProperties>,
-    ) -> Result<TableHandle> {
+        late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)> {
+        self.expect_arrangement();
         let table = self
             .tables
             .get(table_handle)
@@ -4168,37 +4890,55 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         let error_reporter_2 = self.error_reporter.clone();
         let error_reporter_3 = self.error_reporter.clone();
 
-        let new_table = table
-            .values()
-            .clone()
-            .postpone(
+        let threshold_time_extractor = move |val: &Value| {
+            threshold_time_column_path
+                .extract_from_value(val)
+                .unwrap_with_reporter(&error_reporter_1)
+        };
+        let current_time_extractor = move |val: &Value| {
+            current_time_column_path
+                .extract_from_value(val)
+                .unwrap_with_reporter(&error_reporter_2)
+        };
+        let instance_extractor = move |val: &Value| {
+            instance_column_path
+                .extract_from_value(val)
+                .unwrap_with_reporter(&error_reporter_3)
+        };
+
+        let (new_table, late_table) = if late_table_properties.is_some() {
+            let (new_table, late_table) = table.values().clone().postpone_with_late(
                 table.values().scope(),
-                move |val| {
-                    threshold_time_column_path
-                        .extract_from_value(val)
-                        .unwrap_with_reporter(&error_reporter_1)
-                },
-                move |val| {
-                    current_time_column_path
-                        .extract_from_value(val)
-                        .unwrap_with_reporter(&error_reporter_2)
-                },
-                move |val| {
-                    instance_column_path
-                        .extract_from_value(val)
-                        .unwrap_with_reporter(&error_reporter_3)
-                },
+                threshold_time_extractor,
+                current_time_extractor,
+                instance_extractor,
                 true,
                 true,
                 |collection| collection.maybe_persist(self, "buffer"),
-            )?
-            .filter_out_persisted(&mut self.persistence_wrapper)?;
+            )?;
+            (new_table, Some(late_table))
+        } else {
+            let new_table = table.values().clone().postpone(
+                table.values().scope(),
+                threshold_time_extractor,
+                current_time_extractor,
+                instance_extractor,
+                true,
+                true,
+                |collection| collection.maybe_persist(self, "buffer"),
+            )?;
+            (new_table, None)
+        };
+        let new_table = new_table.filter_out_persisted(&mut self.persistence_wrapper)?;
 
-        Ok(self
-            .tables
-            .alloc(Table::from_collection(new_table).with_properties(table_properties)))
+        Ok(self.alloc_tables_with_late(
+            new_table,
+            table_properties,
+            late_table.zip(late_table_properties),
+        ))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn forget(
         &mut self,
         table_handle: TableHandle,
@@ -4207,7 +4947,9 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         instance_column_path: ColumnPath,
         mark_forgetting_records: bool,
         table_properties: Arc<TableProperties>,
-    ) -> Result<TableHandle> {
+        late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)> {
+        self.expect_arrangement();
         let table = self
             .tables
             .get(table_handle)
@@ -4217,33 +4959,66 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         let error_reporter_2 = self.error_reporter.clone();
         let error_reporter_3 = self.error_reporter.clone();
 
-        let new_table = table
-            .values()
-            .clone()
-            .forget(
-                move |val| {
-                    threshold_time_column_path
-                        .extract_from_value(val)
-                        .unwrap_with_reporter(&error_reporter_1)
-                },
-                move |val| {
-                    current_time_column_path
-                        .extract_from_value(val)
-                        .unwrap_with_reporter(&error_reporter_2)
-                },
-                move |val| {
-                    instance_column_path
-                        .extract_from_value(val)
-                        .unwrap_with_reporter(&error_reporter_3)
-                },
+        let threshold_time_extractor = move |val: &Value| {
+            threshold_time_column_path
+                .extract_from_value(val)
+                .unwrap_with_reporter(&error_reporter_1)
+        };
+        let current_time_extractor = move |val: &Value| {
+            current_time_column_path
+                .extract_from_value(val)
+                .unwrap_with_reporter(&error_reporter_2)
+        };
+        let instance_extractor = move |val: &Value| {
+            instance_column_path
+                .extract_from_value(val)
+                .unwrap_with_reporter(&error_reporter_3)
+        };
+
+        let (new_table, late_table) = if late_table_properties.is_some() {
+            let (new_table, late_table) = table.values().clone().forget_with_late(
+                threshold_time_extractor,
+                current_time_extractor,
+                instance_extractor,
                 mark_forgetting_records,
                 |collection| collection.maybe_persist(self, "forget"),
-            )?
-            .filter_out_persisted(&mut self.persistence_wrapper)?;
+            )?;
+            (new_table, Some(late_table))
+        } else {
+            let new_table = table.values().clone().forget(
+                threshold_time_extractor,
+                current_time_extractor,
+                instance_extractor,
+                mark_forgetting_records,
+                |collection| collection.maybe_persist(self, "forget"),
+            )?;
+            (new_table, None)
+        };
+        let new_table = new_table.filter_out_persisted(&mut self.persistence_wrapper)?;
 
-        Ok(self
+        Ok(self.alloc_tables_with_late(
+            new_table,
+            table_properties,
+            late_table.zip(late_table_properties),
+        ))
+    }
+
+    // The late entries aren't a part of the persisted state of the operator, so they
+    // aren't filtered like its main output.
+    fn alloc_tables_with_late(
+        &mut self,
+        new_table: Collection<S, (Key, Value)>,
+        table_properties: Arc<TableProperties>,
+        late_table: Option<(Collection<S, (Key, Value)>, Arc<TableProperties>)>,
+    ) -> (TableHandle, Option<TableHandle>) {
+        let new_table_handle = self
             .tables
-            .alloc(Table::from_collection(new_table).with_properties(table_properties)))
+            .alloc(Table::from_collection(new_table).with_properties(table_properties));
+        let late_table_handle = late_table.map(|(late_table, late_table_properties)| {
+            self.tables
+                .alloc(Table::from_collection(late_table).with_properties(late_table_properties))
+        });
+        (new_table_handle, late_table_handle)
     }
 
     fn forget_immediately(
@@ -4283,6 +5058,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         ensure_consistency: bool,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_exchange();
         let table = self
             .tables
             .get(table_handle)
@@ -4324,10 +5100,13 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
             .alloc(Table::from_collection(new_table).with_properties(table_properties)))
     }
 
-    fn prepare_batch_for_output(batch: &mut [((Key, Tuple), isize)], sort_by_indices: &[usize]) {
+    fn prepare_batch_for_output(
+        batch: &mut [((Key, Tuple), isize)],
+        sort_by_indices: &[SortColumn],
+    ) {
         batch.sort_by(|((_, lhs), _), ((_, rhs), _)| {
-            for index in sort_by_indices {
-                let order = lhs[*index].cmp(&rhs[*index]);
+            for column in sort_by_indices {
+                let order = column.compare(lhs.as_value_slice(), rhs.as_value_slice());
                 if order != std::cmp::Ordering::Equal {
                     return order;
                 }
@@ -4336,13 +5115,49 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         });
     }
 
+    // The lower bits of the keys decide on the worker, so they are the same for all the
+    // rows of a worker and the partitions are chosen by the higher bits.
+    #[allow(clippy::cast_possible_truncation)]
+    fn partition_output_batch(
+        batch: &OutputBatch<Timestamp, (Key, Tuple), isize>,
+        partitions: usize,
+        partition_by_indices: Option<&[usize]>,
+    ) -> Vec<OutputBatch<Timestamp, (Key, Tuple), isize>> {
+        let mut result: Vec<_> = (0..partitions)
+            .map(|_| OutputBatch {
+                time: batch.time,
+                data: Vec::new(),
+            })
+            .collect();
+        for entry in &batch.data {
+            let ((key, values), _) = entry;
+            let partition_key = match partition_by_indices {
+                Some(indices) => Key::for_values(
+                    &indices
+                        .iter()
+                        .map(|index| values[*index].clone())
+                        .collect::<Vec<_>>(),
+                ),
+                None => *key,
+            };
+            // the high half of the key, as the shard of the worker is in the low bits
+            let partition = (partition_key.0 >> (KeyImpl::BITS / 2)) as usize % partitions;
+            result[partition].data.push(entry.clone());
+        }
+        result
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn output_batch(
         stats: &mut OutputConnectorStats,
         mut batch: OutputBatch<Timestamp, (Key, Tuple), isize>,
         data_sink: &mut Box<dyn Writer>,
         data_formatter: &mut Box<dyn Formatter>,
-        worker_persistent_storage: Option<&SharedWorkerPersistentStorage>,
-        sort_by_indices: Option<&Vec<usize>>,
+        skips_persisted_batches: bool,
+        sort_by_indices: Option<&Vec<SortColumn>>,
+        max_batch_size: Option<usize>,
+        collapse_updates: bool,
+        pending: &mut PendingOutput,
     ) -> Result<(), DynError> {
         stats.on_batch_started();
         let time = batch.time;
@@ -4350,11 +5165,29 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         if let Some(sort_by_indices) = sort_by_indices {
             Self::prepare_batch_for_output(&mut batch.data, sort_by_indices);
         }
-        for ((key, values), diff) in batch.data {
-            if time.is_from_persistence() && worker_persistent_storage.is_some() {
-                // Ignore entries, which had been written before
-                continue;
-            }
+        // Ignore entries, which had been written before
+        if !(time.is_from_persistence() && skips_persisted_batches) {
+            let entries: Vec<_> = batch
+                .data
+                .iter()
+                .map(|((key, values), diff)| (*key, values.as_value_slice(), *diff))
+                .collect();
+            let (formatted_entries, entries) = if collapse_updates {
+                Self::format_entries_with_updates(&entries, data_formatter.as_mut(), time)?
+            } else {
+                let formatted_entries = data_formatter.format_batch(&entries, time)?;
+                let entries = entries
+                    .iter()
+                    .map(|(key, values, diff)| OutputEntry::Change(*key, *values, *diff))
+                    .collect();
+                (formatted_entries, entries)
+            };
+            stats.on_bytes_written(
+                formatted_entries
+                    .iter()
+                    .map(FormatterContext::payload_size)
+                    .sum(),
+            );
 
             // TODO: provide a way to configure it individually per connector maybe?
             let retries = if data_sink.retriable() {
@@ -4363,23 +5196,162 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                 1
             };
 
-            execute_with_retries(
-                || {
-                    let formatted = data_formatter
-                        .format(&key, &values, time, diff)
-                        .map_err(DynError::from)?;
-                    data_sink.write(formatted).map_err(DynError::from)
-                },
-                RetryConfig::default(),
-                retries,
-            )?;
+            for (formatted, entry) in formatted_entries.into_iter().zip(entries) {
+                // The batch is formatted once, an entry is formatted again only if
+                // its write has failed and is retried
+                let mut formatted = Some(formatted);
+                execute_with_retries(
+                    || {
+                        let formatted = match formatted.take() {
+                            Some(formatted) => formatted,
+                            None => entry
+                                .format(data_formatter.as_mut(), time)
+                                .map_err(DynError::from)?,
+                        };
+                        data_sink.write(formatted).map_err(DynError::from)
+                    },
+                    RetryConfig::default(),
+                    retries,
+                )?;
+                pending.on_entry_written();
+                if max_batch_size.is_some_and(|max_batch_size| pending.entries >= max_batch_size) {
+                    Self::flush_writer(stats, data_sink, false)?;
+                    pending.on_entries_flushed();
+                }
+            }
         }
         stats.on_batch_entries_written(batch_size);
         stats.on_batch_finished();
 
-        // This line can be removed. In this case, flush will happen on the next time advancement.
-        data_sink.flush(false).map_err(DynError::from)?;
+        Ok(())
+    }
+
+    // Pairs the deletion and the insertion of each row that is changed only this way in
+    // the batch. Returns the indices of the deletions by the indices of the insertions.
+    fn find_updates(entries: &[(Key, &[Value], isize)]) -> BTreeMap<usize, usize> {
+        let mut changes_by_key: HashMap<Key, Vec<usize>> = HashMap::new();
+        for (index, (key, _values, _diff)) in entries.iter().enumerate() {
+            changes_by_key.entry(*key).or_default().push(index);
+        }
+        changes_by_key
+            .into_values()
+            .filter_map(|indices| {
+                let [first, second] = indices[..] else {
+                    return None;
+                };
+                match (entries[first].2, entries[second].2) {
+                    (DIFF_DELETION, DIFF_INSERTION) => Some((second, first)),
+                    (DIFF_INSERTION, DIFF_DELETION) => Some((first, second)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    // Formats the entries of the batch, collapsing the deletion and the insertion of a
+    // row into an update if the formatter can represent it. The update takes the place
+    // of the insertion.
+    fn format_entries_with_updates<'a>(
+        entries: &[(Key, &'a [Value], isize)],
+        data_formatter: &mut dyn Formatter,
+        time: Timestamp,
+    ) -> Result<(Vec<FormatterContext>, Vec<OutputEntry<'a>>), FormatterError> {
+        let mut formatted_updates = HashMap::new();
+        let mut collapsed_deletions = HashSet::new();
+        for (insertion, deletion) in Self::find_updates(entries) {
+            let (key, new_values, _diff) = entries[insertion];
+            let old_values = entries[deletion].1;
+            if let Some(formatted) =
+                data_formatter.format_update(&key, old_values, new_values, time)?
+            {
+                let update = OutputEntry::Update(key, old_values, new_values);
+                formatted_updates.insert(insertion, (formatted, update));
+                collapsed_deletions.insert(deletion);
+            }
+        }
+        let is_change = |index: usize| {
+            !formatted_updates.contains_key(&index) && !collapsed_deletions.contains(&index)
+        };
+        let changes: Vec<_> = (0..entries.len())
+            .filter(|index| is_change(*index))
+            .map(|index| entries[index])
+            .collect();
+        let mut formatted_changes = data_formatter
+            .format_batch(&changes, time)?
+            .into_iter()
+            .zip(changes);
+
+        let mut formatted_entries = Vec::with_capacity(entries.len());
+        let mut output_entries = Vec::with_capacity(entries.len());
+        for index in 0..entries.len() {
+            if collapsed_deletions.contains(&index) {
+                continue;
+            }
+            let (formatted, entry) = match formatted_updates.remove(&index) {
+                Some(formatted_update) => formatted_update,
+                None => {
+                    let (formatted, (key, values, diff)) = formatted_changes
+                        .next()
+                        .expect("each change should be formatted");
+                    (formatted, OutputEntry::Change(key, values, diff))
+                }
+            };
+            formatted_entries.push(formatted);
+            output_entries.push(entry);
+        }
+        Ok((formatted_entries, output_entries))
+    }
+
+    fn flush_writer(
+        stats: &mut OutputConnectorStats,
+        data_sink: &mut Box<dyn Writer>,
+        forced: bool,
+    ) -> Result<(), DynError> {
+        let flush_started_at = Instant::now();
+        data_sink.flush(forced).map_err(DynError::from)?;
+        stats.on_flush(flush_started_at.elapsed());
+        Ok(())
+    }
 
+    // Flushes the sink and commits the latest time committed since the last flush
+    #[allow(clippy::too_many_arguments)]
+    fn flush_output(
+        stats: &mut OutputConnectorStats,
+        pending: &mut PendingOutput,
+        data_sink: &mut Box<dyn Writer>,
+        sink_id: Option<usize>,
+        worker_persistent_storage: Option<&SharedWorkerPersistentStorage>,
+        alignment: Option<&SinkAlignmentAccessor>,
+        commit_participant: Option<&mut SinkCommitParticipant>,
+    ) -> Result<(), DynError> {
+        let commit = mem::take(pending).commit;
+        let Some(t) = commit else {
+            // the entries of a time voted on are flushed only once the vote ends
+            if commit_participant.is_none() {
+                Self::flush_writer(stats, data_sink, false)?;
+            }
+            return Ok(());
+        };
+        if let Some(commit_participant) = commit_participant {
+            // the sinks aligned with this one may have to write the prepared times first
+            if let Some(alignment) = alignment {
+                alignment.report_progress(t);
+            }
+            let commit_started_at = Instant::now();
+            data_sink.prepare().map_err(DynError::from)?;
+            commit_participant.prepare(t)?;
+            data_sink.commit().map_err(DynError::from)?;
+            stats.on_flush(commit_started_at.elapsed());
+            // the time is finalized only once all the sinks of the group have committed it
+            commit_participant.commit(t)?;
+            Self::commit_output_time(stats, t, sink_id, worker_persistent_storage)?;
+        } else {
+            Self::commit_output_time(stats, t, sink_id, worker_persistent_storage)?;
+            Self::flush_writer(stats, data_sink, t.is_none())?;
+        }
+        if let Some(alignment) = alignment {
+            alignment.report_progress(t);
+        }
         Ok(())
     }
 
@@ -4402,84 +5374,216 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::too_many_lines)]
     fn output_table(
         &mut self,
-        mut data_sink: Box<dyn Writer>,
-        mut data_formatter: Box<dyn Formatter>,
+        output_writers: OutputWriters,
         table_handle: TableHandle,
         column_paths: Vec<ColumnPath>,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
+        alignment_group: Option<&SinkAlignmentGroupDescriptor>,
     ) -> Result<()> {
+        let OutputWriters {
+            writers,
+            partition_by_indices,
+            batching,
+            delivery_semantics,
+            output_mode,
+            commit_group,
+            queue_capacity,
+            collapse_updates,
+        } = output_writers;
+        if commit_group.is_some() {
+            if let Some((writer, _)) = writers
+                .iter()
+                .find(|(writer, _)| !writer.supports_two_phase_commit())
+            {
+                return Err(SinkCommitError::TwoPhaseCommitNotSupported(writer.name()).into());
+            }
+        }
+        if queue_capacity == Some(0) {
+            return Err(Error::ZeroQueueCapacity);
+        }
+        if queue_capacity.is_some() && (alignment_group.is_some() || commit_group.is_some()) {
+            // a writer waiting for the other sinks could block the dataflow feeding them
+            return Err(Error::QueueCapacityWithSynchronizedSinks);
+        }
+        if alignment_group.is_some() && self.config.processes() > 1 {
+            // the groups are shared by the workers of a single process only
+            return Err(Error::SinkAlignmentWithManyProcesses);
+        }
         let worker_index = self.scope.index();
         let error_logger = self.create_error_logger()?;
         let output_columns = self
             .extract_columns(table_handle, column_paths)?
             .as_collection()
             .filter_out_errors(Some(error_logger));
-        let single_threaded = data_sink.single_threaded();
+        let sink_name = writers[0].0.name();
+        // a watermark-atomic output gathers each minibatch at a single worker
+        let single_threaded =
+            writers[0].0.single_threaded() || output_mode == OutputMode::WatermarkAtomic;
         let connector_does_output = !single_threaded || worker_index == 0;
 
+        if let Some(dry_run_report) = &self.dry_run_report {
+            if connector_does_output && !dry_run_report.skips_connectors() {
+                dry_run_report.record_connector(ConnectorCheck {
+                    worker_index,
+                    direction: ConnectorDirection::Output,
+                    name: unique_name.unwrap_or(sink_name),
+                    error: None,
+                });
+            }
+            return Ok(());
+        }
+
         let output = output_columns.consolidate_for_output(single_threaded);
 
-        let sink_id = self
-            .persistence_wrapper
-            .get_worker_persistent_storage()
-            .map(|storage| storage.lock().unwrap().register_sink());
+        let partitions = writers.len();
+        let stats_name = unique_name.unwrap_or(sink_name);
+        let mut senders = Vec::with_capacity(partitions);
+        for (partition, (mut data_sink, mut data_formatter)) in writers.into_iter().enumerate() {
+            // A bounded queue makes a slow writer hold the dataflow back
+            let (sender, receiver) = match queue_capacity {
+                Some(capacity) => bounded(capacity),
+                None => unbounded(),
+            };
 
-        let sender = {
-            let (sender, receiver) = mpsc::channel();
+            // Each partition is committed separately, so that a time is finalized only
+            // when all the partitions have written it.
+            let sink_id = self
+                .persistence_wrapper
+                .get_worker_persistent_storage()
+                .map(|storage| storage.lock().unwrap().register_sink());
 
-            let thread_name = format!(
+            let mut thread_name = format!(
                 "pathway:output_table-{}-{}",
                 data_sink.name(),
                 data_formatter.short_description()
             );
+            let mut stats_name = stats_name.clone();
+            if partitions > 1 {
+                write!(thread_name, "-{partition}").unwrap();
+                write!(stats_name, "-{partition}").unwrap();
+            }
 
             let worker_persistent_storage = self
                 .persistence_wrapper
                 .get_worker_persistent_storage()
                 .cloned();
+            let skips_persisted_batches = worker_persistent_storage.is_some()
+                && delivery_semantics == DeliverySemantics::ExactlyOnce;
 
-            let stats_name = unique_name.unwrap_or(data_sink.name());
+            // The partitions are aligned with each other as the separate sinks of the group
+            let alignment = alignment_group
+                .map(|group| {
+                    self.sink_aligner
+                        .lock()
+                        .unwrap()
+                        .ensure_alignment_group(group)
+                })
+                .transpose()?;
+            let mut commit_participant = commit_group
+                .as_deref()
+                .map(|name| self.sink_committer.join_commit_group(name));
+            let sort_by_indices = sort_by_indices.clone();
             let mut stats = OutputConnectorStats::new(stats_name);
             let output_joiner_handle = Builder::new()
                 .name(thread_name)
                 .spawn_with_reporter(
                     self.error_reporter.clone().with_extra(receiver),
-                    move |error_reporter_with_receiver| loop {
-                        let receiver = error_reporter_with_receiver.get();
-                        match receiver.recv() {
-                            Ok(OutputEvent::Batch(batch)) => {
+                    move |error_reporter_with_receiver| {
+                        let mut pending = PendingOutput::default();
+                        let mut withheld = (output_mode == OutputMode::WatermarkAtomic)
+                            .then(WithheldOutput::default);
+                        // neither a withheld minibatch nor a minibatch whose commit is voted
+                        // on can be split into several flushes
+                        let max_batch_size = batching
+                            .max_batch_size
+                            .filter(|_| withheld.is_none() && commit_participant.is_none());
+                        loop {
+                            let receiver = error_reporter_with_receiver.get();
+                            // Without a deadline, there's nothing to flush until the next event
+                            let event = match pending.deadline(&batching) {
+                                Some(deadline) => match receiver.recv_timeout(
+                                    deadline.saturating_duration_since(Instant::now()),
+                                ) {
+                                    Ok(event) => Some(event),
+                                    Err(RecvTimeoutError::Timeout) => None,
+                                    Err(RecvTimeoutError::Disconnected) => break Ok(()),
+                                },
+                                None => match receiver.recv() {
+                                    Ok(event) => Some(event),
+                                    Err(RecvError) => break Ok(()),
+                                },
+                            };
+                            let (batches, commit) = match (event, &mut withheld) {
+                                (Some(OutputEvent::Batch(batch)), Some(withheld)) => {
+                                    withheld.batches.push(batch);
+                                    continue;
+                                }
+                                (Some(OutputEvent::Batch(batch)), None) => (vec![batch], None),
+                                (Some(OutputEvent::Commit(t)), Some(withheld)) => {
+                                    (withheld.release(t), Some(t))
+                                }
+                                (Some(OutputEvent::Commit(t)), None) => (Vec::new(), Some(t)),
+                                // the linger time of the pending output has passed
+                                (None, _) => (Vec::new(), None),
+                            };
+                            for batch in batches {
+                                if let Some(alignment) = &alignment {
+                                    // the other sinks may be waiting for the pending commit
+                                    if !alignment.can_write_now(batch.time) {
+                                        Self::flush_output(
+                                            &mut stats,
+                                            &mut pending,
+                                            &mut data_sink,
+                                            sink_id,
+                                            worker_persistent_storage.as_ref(),
+                                            Some(alignment),
+                                            commit_participant.as_mut(),
+                                        )?;
+                                    }
+                                    alignment.wait_for_turn(batch.time);
+                                }
                                 Self::output_batch(
                                     &mut stats,
                                     batch,
                                     &mut data_sink,
                                     &mut data_formatter,
-                                    worker_persistent_storage.as_ref(),
+                                    skips_persisted_batches,
                                     sort_by_indices.as_ref(),
+                                    max_batch_size,
+                                    collapse_updates,
+                                    &mut pending,
                                 )?;
                             }
-                            Ok(OutputEvent::Commit(t)) => {
-                                Self::commit_output_time(
+                            if let Some(t) = commit {
+                                pending.on_commit(t);
+                            }
+                            let is_finished = commit == Some(None);
+                            if pending.is_due(&batching) {
+                                Self::flush_output(
                                     &mut stats,
-                                    t,
+                                    &mut pending,
+                                    &mut data_sink,
                                     sink_id,
                                     worker_persistent_storage.as_ref(),
+                                    alignment.as_ref(),
+                                    commit_participant.as_mut(),
                                 )?;
-                                data_sink.flush(t.is_none()).map_err(DynError::from)?;
-                                if t.is_none() {
-                                    break Ok(());
-                                }
                             }
-                            Err(mpsc::RecvError) => break Ok(()),
+                            if is_finished {
+                                break Ok(());
+                            }
                         }
                     },
                 )
                 .expect("output thread creation failed");
             self.connector_threads.push(output_joiner_handle);
-            sender
-        };
+            senders.push(sender);
+        }
 
         output
             .inspect_core(move |event| {
@@ -4487,16 +5591,33 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                     Ok((_time, batches)) => {
                         assert!(connector_does_output || batches.is_empty());
                         for batch in batches {
-                            sender
-                                .send(OutputEvent::Batch(batch.clone()))
-                                .expect("sending output batch should not fail");
+                            if let [sender] = senders.as_slice() {
+                                sender
+                                    .send(OutputEvent::Batch(batch.clone()))
+                                    .expect("sending output batch should not fail");
+                                continue;
+                            }
+                            let partitioned_batches = Self::partition_output_batch(
+                                batch,
+                                senders.len(),
+                                partition_by_indices.as_deref(),
+                            );
+                            for (sender, batch) in senders.iter().zip(partitioned_batches) {
+                                if !batch.data.is_empty() {
+                                    sender
+                                        .send(OutputEvent::Batch(batch))
+                                        .expect("sending output batch should not fail");
+                                }
+                            }
                         } // TODO commit all timestamps
                     }
                     Err(frontier) => {
                         assert!(frontier.len() <= 1);
-                        sender
-                            .send(OutputEvent::Commit(frontier.first().copied()))
-                            .expect("sending output commit should not fail");
+                        for sender in &senders {
+                            sender
+                                .send(OutputEvent::Commit(frontier.first().copied()))
+                                .expect("sending output commit should not fail");
+                        }
                     }
                 }
             })
@@ -4514,7 +5635,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         callbacks: SubscribeCallbacks,
         config: SubscribeConfig,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
         logic: impl FnOnce(
             &mut DataflowGraphInner<S>,
             Collection<S, (Key, Tuple)>,
@@ -4544,6 +5665,8 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
             mut on_time_end,
             mut on_end,
             mut on_data_async,
+            mut on_batch,
+            max_batch_size,
             mut on_frontier,
         } = callbacks;
         let wrapper_2 = wrapper.clone();
@@ -4591,6 +5714,24 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                                     on_data_async(*key, values, batch.time, *diff)
                                 },
                             )));
+                        } else if let Some(on_batch) = on_batch.as_mut() {
+                            let sorted_data;
+                            let data = if let Some(sort_by_indices) = &sort_by_indices {
+                                let mut data = batch.data.clone();
+                                Self::prepare_batch_for_output(&mut data, sort_by_indices);
+                                sorted_data = data;
+                                &sorted_data
+                            } else {
+                                &batch.data
+                            };
+                            let changes: Vec<_> = data
+                                .iter()
+                                .map(|((key, values), diff)| (*key, &**values, *diff))
+                                .collect();
+                            let chunk_size = max_batch_size.unwrap_or(changes.len()).max(1);
+                            for chunk in changes.chunks(chunk_size) {
+                                on_batch(chunk, batch.time)?;
+                            }
                         }
 
                         if let Some(on_time_end) = on_time_end.as_mut() {
@@ -5097,8 +6238,11 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                     self.terminate_on_error,
                     self.current_error_log.clone(),
                     Arc::new(Mutex::new(ConnectorSynchronizer::new())),
+                    self.sink_aligner.clone(),
                     self.max_expression_batch_size,
+                    self.deterministic_expression_cache_size,
                     self.udf_cache_directory.clone(),
+                    self.lineage.clone(),
                 )?;
                 let mut subgraph_ref = subgraph.0.borrow_mut();
                 let mut state = BeforeIterate::new(
@@ -5125,6 +6269,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
                     extra_handles,
                 )?;
                 let subgraph_ref = subgraph.0.borrow();
+                self.explain_plan.merge(&subgraph_ref.explain_plan);
                 let mut state = AfterIterate::new(self, &subgraph_ref, limit);
                 let result = result
                     .into_iter()
@@ -5242,7 +6387,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         let table_handle = self
             .tables
             .alloc(Table::from_collection(collection).with_properties(table_properties));
-        let error_log = ErrorLog::new(input_session);
+        let error_log = ErrorLog::new(input_session, self.lineage.run_id.as_str().into());
         let error_log_2 = error_log.clone();
         self.flushers
             .push(Box::new(move || error_log_2.maybe_flush()));
@@ -5268,8 +6413,9 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         &mut self,
         table_handle: TableHandle,
         column_paths: Vec<ColumnPath>,
+        flight_endpoint: Option<FlightEndpoint>,
     ) -> Result<Arc<dyn ExportedTable>> {
-        export_table(self, table_handle, column_paths)
+        export_table(self, table_handle, column_paths, flight_endpoint)
     }
 
     fn import_table(&mut self, table: Arc<dyn ExportedTable>) -> Result<TableHandle> {
@@ -5373,6 +6519,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> DataflowGraphInner<S>
         table_handle: TableHandle,
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
+        self.expect_exchange();
         let table = self
             .tables
             .get(table_handle)
@@ -5896,8 +7043,11 @@ impl<S: MaybeTotalScope> InnerDataflowGraph<S> {
         terminate_on_error: bool,
         default_error_log: Option<ErrorLog>,
         connector_synchronizer: SharedConnectorSynchronizer,
+        sink_aligner: SharedSinkAligner,
         max_expression_batch_size: usize,
+        deterministic_expression_cache_size: usize,
         udf_cache_directory: Option<PathBuf>,
+        lineage: Arc<RunLineage>,
     ) -> Result<Self> {
         Ok(Self(RefCell::new(DataflowGraphInner::new(
             scope,
@@ -5909,8 +7059,14 @@ impl<S: MaybeTotalScope> InnerDataflowGraph<S> {
             default_error_log,
             Box::new(NotTotalReducerFactory),
             connector_synchronizer,
+            sink_aligner,
             max_expression_batch_size,
+            deterministic_expression_cache_size,
             udf_cache_directory,
+            lineage,
+            None,
+            None,
+            false,
         )?)))
     }
 }
@@ -6059,7 +7215,7 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
         _callbacks: SubscribeCallbacks,
         _config: SubscribeConfig,
         _unique_name: Option<UniqueName>,
-        _sort_by_indices: Option<Vec<usize>>,
+        _sort_by_indices: Option<Vec<SortColumn>>,
     ) -> Result<()> {
         Err(Error::IoNotPossible)
     }
@@ -6093,7 +7249,8 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
         _instance_column_path: ColumnPath,
         _mark_forgetting_records: bool,
         _table_properties: Arc<TableProperties>,
-    ) -> Result<TableHandle> {
+        _late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)> {
         Err(Error::NotSupportedInIteration)
     }
 
@@ -6132,6 +7289,18 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
         _current_time_column_path: ColumnPath,
         _instance_column_path: ColumnPath,
         _table_properties: Arc<TableProperties>,
+        _late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)> {
+        Err(Error::NotSupportedInIteration)
+    }
+
+    fn retain_last_versions(
+        &self,
+        _table_handle: TableHandle,
+        _current_time_column_path: ColumnPath,
+        _instance_column_path: ColumnPath,
+        _max_versions: usize,
+        _table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle> {
         Err(Error::NotSupportedInIteration)
     }
@@ -6268,11 +7437,13 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn group_by_table(
         &self,
         table_handle: TableHandle,
         grouping_columns_paths: Vec<ColumnPath>,
         shard_policy: ShardPolicy,
+        shard_column: Option<usize>,
         reducers: Vec<ReducerData>,
         set_id: bool,
         table_properties: Arc<TableProperties>,
@@ -6281,6 +7452,7 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
             table_handle,
             grouping_columns_paths,
             shard_policy,
+            shard_column,
             reducers,
             set_id,
             table_properties,
@@ -6299,6 +7471,16 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
         Err(Error::NotSupportedInIteration)
     }
 
+    fn version_rows(
+        &self,
+        _table_handle: TableHandle,
+        _column_paths: Vec<ColumnPath>,
+        _unique_name: Option<&UniqueName>,
+        _table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        Err(Error::NotSupportedInIteration)
+    }
+
     fn gradual_broadcast(
         &self,
         input_table_handle: TableHandle,
@@ -6402,20 +7584,23 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
         _table_properties: Arc<TableProperties>,
         _unique_name: Option<&UniqueName>,
         _synchronization_group: Option<&ConnectorGroupDescriptor>,
-        _max_backlog_size: Option<usize>,
+        _backlog_limit: BacklogLimit,
+        _rate_limit: RateLimit,
+        _watermark_strategy: Option<WatermarkStrategy>,
         _timestamp_at_start: Timestamp,
+        _start_from: Option<OffsetAntichain>,
     ) -> Result<TableHandle> {
         Err(Error::IoNotPossible)
     }
 
     fn output_table(
         &self,
-        mut _data_sink: Box<dyn Writer>,
-        mut _data_formatter: Box<dyn Formatter>,
+        _output_writers: OutputWriters,
         _table_handle: TableHandle,
         _column_paths: Vec<ColumnPath>,
         _unique_name: Option<UniqueName>,
-        _sort_by_indices: Option<Vec<usize>>,
+        _sort_by_indices: Option<Vec<SortColumn>>,
+        _alignment_group: Option<&SinkAlignmentGroupDescriptor>,
     ) -> Result<()> {
         Err(Error::IoNotPossible)
     }
@@ -6426,6 +7611,10 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
             .set_operator_properties(operator_properties)
     }
 
+    fn explain(&self) -> Result<Vec<OperatorPlan>> {
+        Ok(self.0.borrow().explain_plan.operators())
+    }
+
     fn set_error_log(&self, _error_log_handle: Option<ErrorLogHandle>) -> Result<()> {
         Err(Error::NotSupportedInIteration)
     }
@@ -6454,6 +7643,7 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
         &self,
         _table_handle: TableHandle,
         _column_paths: Vec<ColumnPath>,
+        _flight_endpoint: Option<FlightEndpoint>,
     ) -> Result<Arc<dyn ExportedTable>> {
         Err(Error::IoNotPossible)
     }
@@ -6530,6 +7720,19 @@ impl<S: MaybeTotalScope> Graph for InnerDataflowGraph<S> {
             .assert_append_only(table_handle, column_paths, table_properties)
     }
 
+    fn assert_monotonic(
+        &self,
+        _table_handle: TableHandle,
+        _column_paths: Vec<ColumnPath>,
+        _sequence_path: ColumnPath,
+        _instance_path: Option<ColumnPath>,
+        _strict: bool,
+        _on_violation: OrderingViolationPolicy,
+        _table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        Err(Error::NotSupportedInIteration)
+    }
+
     fn unpack_snapshots(
         &self,
         _table_handle: TableHandle,
@@ -6553,8 +7756,14 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> OuterDataflowGraph<S>
         config: Arc<Config>,
         terminate_on_error: bool,
         connector_synchronizer: SharedConnectorSynchronizer,
+        sink_aligner: SharedSinkAligner,
         max_expression_batch_size: usize,
+        deterministic_expression_cache_size: usize,
         udf_cache_directory: Option<PathBuf>,
+        lineage: Arc<RunLineage>,
+        dry_run_report: Option<Arc<DryRunReport>>,
+        graceful_shutdown: Arc<GracefulShutdown>,
+        step_mode_enabled: bool,
     ) -> Result<Self> {
         let worker_idx = scope.index();
         let total_workers = scope.peers();
@@ -6575,8 +7784,14 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> OuterDataflowGraph<S>
             None,
             Box::new(TimestampReducerFactory),
             connector_synchronizer,
+            sink_aligner,
             max_expression_batch_size,
+            deterministic_expression_cache_size,
             udf_cache_directory,
+            lineage,
+            dry_run_report,
+            Some(graceful_shutdown),
+            step_mode_enabled,
         )?)))
     }
 }
@@ -6721,7 +7936,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
         callbacks: SubscribeCallbacks,
         config: SubscribeConfig,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
     ) -> Result<()> {
         self.0.borrow_mut().subscribe_table(
             table_handle,
@@ -6763,7 +7978,8 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
         instance_column_path: ColumnPath,
         mark_forgetting_records: bool,
         table_properties: Arc<TableProperties>,
-    ) -> Result<TableHandle> {
+        late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)> {
         self.0.borrow_mut().forget(
             table_handle,
             threshold_time_column_path,
@@ -6771,6 +7987,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
             instance_column_path,
             mark_forgetting_records,
             table_properties,
+            late_table_properties,
         )
     }
 
@@ -6821,13 +8038,32 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
         current_time_column_path: ColumnPath,
         instance_column_path: ColumnPath,
         table_properties: Arc<TableProperties>,
-    ) -> Result<TableHandle> {
+        late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)> {
         self.0.borrow_mut().buffer(
             table_handle,
             threshold_time_column_path,
             current_time_column_path,
             instance_column_path,
             table_properties,
+            late_table_properties,
+        )
+    }
+
+    fn retain_last_versions(
+        &self,
+        table_handle: TableHandle,
+        current_time_column_path: ColumnPath,
+        instance_column_path: ColumnPath,
+        max_versions: usize,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        self.0.borrow_mut().retain_last_versions(
+            table_handle,
+            current_time_column_path,
+            instance_column_path,
+            max_versions,
+            table_properties,
         )
     }
 
@@ -6968,11 +8204,13 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn group_by_table(
         &self,
         table_handle: TableHandle,
         grouping_columns_paths: Vec<ColumnPath>,
         shard_policy: ShardPolicy,
+        shard_column: Option<usize>,
         reducers: Vec<ReducerData>,
         set_id: bool,
         table_properties: Arc<TableProperties>,
@@ -6981,6 +8219,7 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
             table_handle,
             grouping_columns_paths,
             shard_policy,
+            shard_column,
             reducers,
             set_id,
             table_properties,
@@ -7006,6 +8245,18 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
         )
     }
 
+    fn version_rows(
+        &self,
+        table_handle: TableHandle,
+        column_paths: Vec<ColumnPath>,
+        unique_name: Option<&UniqueName>,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        self.0
+            .borrow_mut()
+            .version_rows(table_handle, column_paths, unique_name, table_properties)
+    }
+
     fn gradual_broadcast(
         &self,
         input_table_handle: TableHandle,
@@ -7111,8 +8362,11 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
         table_properties: Arc<TableProperties>,
         unique_name: Option<&UniqueName>,
         synchronization_group: Option<&ConnectorGroupDescriptor>,
-        max_backlog_size: Option<usize>,
+        backlog_limit: BacklogLimit,
+        rate_limit: RateLimit,
+        watermark_strategy: Option<WatermarkStrategy>,
         timestamp_at_start: Timestamp,
+        start_from: Option<OffsetAntichain>,
     ) -> Result<TableHandle> {
         self.0.borrow_mut().connector_table(
             reader,
@@ -7122,27 +8376,30 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
             table_properties,
             unique_name,
             synchronization_group,
-            max_backlog_size,
+            backlog_limit,
+            rate_limit,
+            watermark_strategy,
             timestamp_at_start,
+            start_from,
         )
     }
 
     fn output_table(
         &self,
-        data_sink: Box<dyn Writer>,
-        data_formatter: Box<dyn Formatter>,
+        output_writers: OutputWriters,
         table_handle: TableHandle,
         column_paths: Vec<ColumnPath>,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
+        alignment_group: Option<&SinkAlignmentGroupDescriptor>,
     ) -> Result<()> {
         self.0.borrow_mut().output_table(
-            data_sink,
-            data_formatter,
+            output_writers,
             table_handle,
             column_paths,
             unique_name,
             sort_by_indices,
+            alignment_group,
         )
     }
 
@@ -7152,6 +8409,10 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
             .set_operator_properties(operator_properties)
     }
 
+    fn explain(&self) -> Result<Vec<OperatorPlan>> {
+        Ok(self.0.borrow().explain_plan.operators())
+    }
+
     fn set_error_log(&self, error_log_handle: Option<ErrorLogHandle>) -> Result<()> {
         self.0.borrow_mut().set_error_log(error_log_handle)
     }
@@ -7184,8 +8445,11 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
         &self,
         table_handle: TableHandle,
         column_paths: Vec<ColumnPath>,
+        flight_endpoint: Option<FlightEndpoint>,
     ) -> Result<Arc<dyn ExportedTable>> {
-        self.0.borrow_mut().export_table(table_handle, column_paths)
+        self.0
+            .borrow_mut()
+            .export_table(table_handle, column_paths, flight_endpoint)
     }
 
     fn import_table(&self, table: Arc<dyn ExportedTable>) -> Result<TableHandle> {
@@ -7276,6 +8540,27 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> Graph for OuterDataflo
             .assert_append_only(table_handle, column_paths, table_properties)
     }
 
+    fn assert_monotonic(
+        &self,
+        table_handle: TableHandle,
+        column_paths: Vec<ColumnPath>,
+        sequence_path: ColumnPath,
+        instance_path: Option<ColumnPath>,
+        strict: bool,
+        on_violation: OrderingViolationPolicy,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        self.0.borrow_mut().assert_monotonic(
+            table_handle,
+            column_paths,
+            sequence_path,
+            instance_path,
+            strict,
+            on_violation,
+            table_properties,
+        )
+    }
+
     fn unpack_snapshots(
         &self,
         table_handle: TableHandle,
@@ -7294,16 +8579,21 @@ pub fn run_with_new_dataflow_graph<R, R2>(
     finish: impl Fn(R) -> R2 + Send + Sync + 'static,
     config: Config,
     mut wakeup_receiver: Option<WakeupReceiver>,
-    stats_monitor: Option<PyObject>,
+    stats_monitor: Option<Box<dyn StatsMonitor>>,
     ignore_asserts: bool,
     monitoring_level: MonitoringLevel,
     with_http_server: bool,
     persistence_config: Option<PersistenceManagerOuterConfig>,
     #[allow(unused)] license: &License,
     telemetry_config: TelemetryConfig,
+    lineage: Arc<RunLineage>,
     terminate_on_error: bool,
     max_expression_batch_size: usize,
+    deterministic_expression_cache_size: usize,
     udf_cache_directory: Option<PathBuf>,
+    step_mode: Option<StepMode>,
+    dry_run_report: Option<Arc<DryRunReport>>,
+    run_callbacks: RunCallbacks,
 ) -> Result<Vec<R2>>
 where
     R: 'static,
@@ -7325,6 +8615,7 @@ where
     let failed = Arc::new(AtomicBool::new(false));
     let failed_2 = failed.clone();
     let connector_synchronizer = Arc::new(Mutex::new(ConnectorSynchronizer::new()));
+    let sink_aligner = Arc::new(Mutex::new(SinkAligner::new()));
     let stats_monitor = Arc::new(Mutex::new(stats_monitor));
 
     config.check_scopes(license)?;
@@ -7337,6 +8628,26 @@ where
     let downscaling_allowed = scaling_allowed && config.is_downscaling_possible();
     let upscaling_allowed = scaling_allowed && config.is_upscaling_possible();
 
+    let step_controller = step_mode
+        .filter(|_| dry_run_report.is_none())
+        .map(|mode| Arc::new(StepController::new(mode)));
+    let _step_commands_runner = step_controller
+        .as_ref()
+        .map(|controller| start_accepting_commands(controller, config.process_id()))
+        .transpose()?
+        .flatten();
+    let graceful_shutdown = Arc::new(GracefulShutdown::new(persistence_config.is_some()));
+    set_active_shutdown(&graceful_shutdown);
+    let savepoint_coordinator = persistence_config.as_ref().map(|_| {
+        let coordinator = Arc::new(SavepointCoordinator::new(config.threads()));
+        set_active_coordinator(&coordinator);
+        coordinator
+    });
+    // the callbacks aren't called in a dry run, as the dataflow isn't run
+    let dry_run = dry_run_report.is_some();
+    let lifecycle = Arc::new(RunLifecycle::new(run_callbacks, config.threads()));
+    let lifecycle_2 = lifecycle.clone();
+
     let guards = execute(config.to_timely_config(), move |worker| {
         catch_unwind(AssertUnwindSafe(|| {
             if let Ok(addr) = env::var("DIFFERENTIAL_LOG_ADDR") {
@@ -7361,6 +8672,8 @@ where
                 progress_reporter_runner,
                 http_server_runner,
                 telemetry_runner,
+                worker_persistent_storage,
+                savepoint_exchange,
             ) = worker.dataflow::<Timestamp, _, _>(|scope| {
                 let graph = OuterDataflowGraph::new(
                     scope.clone(),
@@ -7370,8 +8683,14 @@ where
                     config.clone(),
                     terminate_on_error,
                     connector_synchronizer.clone(),
+                    sink_aligner.clone(),
                     max_expression_batch_size,
+                    deterministic_expression_cache_size,
                     udf_cache_directory.clone(),
+                    lineage.clone(),
+                    dry_run_report.clone(),
+                    graceful_shutdown.clone(),
+                    step_controller.is_some(),
                 )
                 .unwrap_with_reporter(&error_reporter);
                 let telemetry_runner = maybe_run_telemetry_thread(
@@ -7380,17 +8699,43 @@ where
                     graph.worker_index(),
                 );
                 let res = logic(&graph).unwrap_with_reporter(&error_reporter);
+                if let Some(dry_run_report) = &dry_run_report {
+                    if graph.worker_index() == 0 {
+                        dry_run_report.record_operators(
+                            graph.explain().unwrap_with_reporter(&error_reporter),
+                        );
+                    }
+                }
                 let stats_monitor_local = if graph.worker_index() == 0 {
                     let mut stats_monitor = stats_monitor.lock().unwrap();
                     std::mem::take(&mut *stats_monitor)
                 } else {
                     None
                 };
-                let progress_reporter_runner =
-                    maybe_run_reporter(monitoring_level, &graph, stats_monitor_local);
-                let http_server_runner =
-                    maybe_run_http_server_thread(with_http_server, &graph, config.process_id());
+                let is_dry_run = dry_run_report.is_some();
+                let progress_reporter_runner = maybe_run_reporter(
+                    if is_dry_run {
+                        MonitoringLevel::None
+                    } else {
+                        monitoring_level
+                    },
+                    &graph,
+                    stats_monitor_local,
+                );
+                let http_server_runner = maybe_run_http_server_thread(
+                    with_http_server && !is_dry_run,
+                    &graph,
+                    config.process_id(),
+                );
                 let graph = graph.0.into_inner();
+                let worker_persistent_storage = graph
+                    .persistence_wrapper
+                    .get_worker_persistent_storage()
+                    .cloned();
+                let savepoint_exchange = savepoint_coordinator
+                    .clone()
+                    .filter(|_| worker_persistent_storage.is_some())
+                    .map(|coordinator| SavepointExchange::new(scope, coordinator));
                 (
                     res,
                     graph.flushers,
@@ -7404,15 +8749,47 @@ where
                     progress_reporter_runner,
                     http_server_runner,
                     telemetry_runner,
+                    worker_persistent_storage,
+                    savepoint_exchange,
                 )
             });
 
+            if dry_run_report.is_some() {
+                // nothing is read or written, the dataflow is dropped without being run
+                for dataflow_index in worker.installed_dataflows() {
+                    worker.drop_dataflow(dataflow_index);
+                }
+                return finish(res);
+            }
+
+            let local_worker_index = worker.index() % config.threads();
+            lifecycle
+                .worker_started()
+                .unwrap_with_reporter(&error_reporter);
+            let mut snapshot_replayed = false;
             let mut workload_tracker = WorkloadTracker::new(workload_tracking_window);
+            let mut step_gate = step_controller.clone().map(StepGate::new);
+            let mut savepoints = worker_persistent_storage.zip(savepoint_exchange);
+            graceful_shutdown.register_worker(thread::current());
             loop {
                 if failed.load(Ordering::SeqCst) {
                     resume_unwind(Box::new("other worker panicked"));
                 }
 
+                if let Some((storage, exchange)) = &mut savepoints {
+                    exchange.process(
+                        || storage.lock().unwrap().savepoint_frontier(),
+                        |time| {
+                            storage
+                                .lock()
+                                .unwrap()
+                                .commit_savepoint(TotalFrontier::At(time))
+                                .map(|_committed| ())
+                                .map_err(|e| e.to_string())
+                        },
+                    );
+                }
+
                 for prober in &mut probers {
                     prober.update(
                         &input_probe,
@@ -7424,7 +8801,7 @@ where
 
                 let mut next_step_duration = None;
 
-                let iteration_start = SystemTime::now();
+                let iteration_start = clock::now();
 
                 let next_step_duration_computer =
                     |next_commit_at: SystemTime, next_step_duration: Option<Duration>| {
@@ -7459,11 +8836,36 @@ where
                 }
 
                 let started_at = Instant::now();
-                let step_stats = worker.step_or_park(next_step_duration);
+                let step_stats = worker.step_or_park(next_step_duration.map(clock::real_duration));
+
+                if !snapshot_replayed
+                    && connector_monitors
+                        .iter()
+                        .all(|monitor| monitor.borrow().is_replay_finished())
+                {
+                    snapshot_replayed = true;
+                    lifecycle
+                        .worker_replayed_snapshot()
+                        .unwrap_with_reporter(&error_reporter);
+                }
+                let output_frontier = output_probe.with_frontier(|frontier| {
+                    frontier
+                        .first()
+                        .map_or(TotalFrontier::Done, |time| TotalFrontier::At(*time))
+                });
+                lifecycle
+                    .worker_frontier(local_worker_index, output_frontier)
+                    .unwrap_with_reporter(&error_reporter);
+
                 if !step_stats.has_more_work {
                     break;
                 }
 
+                if let Some(step_gate) = &mut step_gate {
+                    let frontier = output_probe.with_frontier(|frontier| frontier.first().copied());
+                    step_gate.on_output_frontier(frontier, || failed.load(Ordering::SeqCst));
+                }
+
                 if let Some(next_step_duration) = next_step_duration {
                     let advice = workload_tracker.add_point(
                         started_at,
@@ -7484,7 +8886,33 @@ where
                 }
             }
 
+            // dropping the savepoint channel makes the savepoints no longer wait for it
+            let persistent_storage = savepoints.map(|(storage, _exchange)| storage);
+
+            let shut_down = graceful_shutdown.is_requested();
+            if shut_down {
+                if let Some(storage) = &persistent_storage {
+                    let mut storage = storage.lock().unwrap();
+                    let frontier = storage.savepoint_frontier();
+                    match storage.commit_savepoint(frontier) {
+                        Ok(time) => info!("Committed the final persisted state up to {time:?}"),
+                        // a suspended computation has to be resumable, so it fails instead
+                        Err(error) if graceful_shutdown.is_suspended() => {
+                            Err::<(), _>(Error::from(error)).unwrap_with_reporter(&error_reporter);
+                        }
+                        Err(error) => {
+                            error!("Committing the final persisted state failed: {error}")
+                        }
+                    }
+                }
+            }
+
             for connector_thread in connector_threads {
+                // The readers stopped by a shutdown may be waiting for their sources and
+                // notice that their connectors are gone only once the sources return.
+                if shut_down && !connector_thread.is_finished() {
+                    continue;
+                }
                 connector_thread
                     .join()
                     .expect("connector thread should not panic");
@@ -7541,6 +8969,12 @@ where
                 handle.thread().unpark();
             }
             catch_unwind(AssertUnwindSafe(|| drop(guards.join()))).unwrap_or(());
+            if !dry_run {
+                // the run has already failed, so the error of the callback is only logged
+                if let Err(shutdown_error) = lifecycle_2.shutdown() {
+                    error!("Shutdown callback failed: {shutdown_error}");
+                }
+            }
             return Err(error);
         }
     }
@@ -7549,6 +8983,17 @@ where
         .join()
         .into_iter()
         .map(|res| res.map_err(Error::WorkerPanic))
-        .collect::<Result<Vec<_>>>()?;
-    Ok(res)
+        .collect::<Result<Vec<_>>>();
+    if !dry_run {
+        match (&res, lifecycle_2.shutdown()) {
+            (_, Ok(())) => {}
+            // the error of a worker is the cause of the failure, so the one of the callback
+            // is only logged
+            (Err(_), Err(shutdown_error)) => {
+                error!("Shutdown callback failed: {shutdown_error}");
+            }
+            (Ok(_), Err(shutdown_error)) => return Err(Error::from(shutdown_error)),
+        }
+    }
+    res
 }
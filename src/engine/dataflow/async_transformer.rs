@@ -52,6 +52,7 @@ use timely::dataflow::operators::{Exchange, Inspect, Map};
 use timely::progress::Timestamp as _;
 
 use crate::connectors::adaptors::InputAdaptor;
+use crate::connectors::backlog::BacklogLimit;
 use crate::connectors::data_format::Parser;
 use crate::connectors::data_storage::ReaderBuilder;
 use crate::connectors::{Connector, PersistenceMode, SnapshotAccess};
@@ -173,7 +174,7 @@ where
         PersistenceMode::Batch, // default value from connector_table
         SnapshotAccess::Full,   // default value from connector_table
         graph.error_reporter.clone(),
-        None,
+        BacklogLimit::default(),
         Timestamp::new_from_current_time(),
     )?;
 
@@ -325,6 +326,7 @@ where
     let values_currently_processed: Rc<RefCell<HashMap<Key, (Key, Tuple)>>> =
         Rc::new(RefCell::new(HashMap::new()));
 
+    graph.expect_exchange();
     let values = python_input_values
         .reshard_to_first_worker()
         .inner
@@ -23,6 +23,9 @@ pub enum Error {
     #[error("can't run with no processes")]
     NeedsProcesses,
 
+    #[error("can't run with no shards per worker")]
+    NeedsShards,
+
     #[error("invalid process ID {0}")]
     InvalidId(usize),
 
@@ -43,6 +46,7 @@ pub struct Config {
     processes: Processes,
     process_id: usize,
     fixed_pool: bool,
+    shards_per_worker: usize,
 }
 
 impl Config {
@@ -106,6 +110,12 @@ impl Config {
         self.process_id
     }
 
+    /// The number of logical shards per worker. With more than one, the groups reduced by
+    /// a groupby are moved between the workers of a process based on their load.
+    pub fn shards_per_worker(&self) -> usize {
+        self.shards_per_worker
+    }
+
     pub fn to_timely_config(&self) -> TimelyConfig {
         let mut result = match &self.processes {
             Processes::Single => {
@@ -156,6 +166,10 @@ impl Config {
         }
         let workers = threads * processes;
         assert!(workers <= MAX_WORKERS);
+        let shards_per_worker: usize = parse_env_var("PATHWAY_SHARDS_PER_WORKER")?.unwrap_or(1);
+        if shards_per_worker == 0 {
+            return Err(Error::NeedsShards);
+        }
         let (process_id, processes, fixed_pool) = if processes > 1 {
             let process_id: usize = parse_env_var_required("PATHWAY_PROCESS_ID")?;
             if process_id >= processes {
@@ -183,6 +197,7 @@ impl Config {
             processes,
             process_id,
             fixed_pool,
+            shards_per_worker,
         })
     }
 }
@@ -10,10 +10,11 @@ use timely::dataflow::operators::{Inspect as _, Probe as _};
 use timely::progress::Timestamp as _;
 
 use crate::engine::error::Trace;
+use crate::engine::flight_server::register_flight_table;
 use crate::engine::report_error::ReportErrorExt as _;
 use crate::engine::{
-    ColumnPath, DataRow, Error, ExportedTable as ExportedTableTrait, ExportedTableCallback, Result,
-    TableHandle, TableProperties, Timestamp, TotalFrontier, Value,
+    ColumnPath, DataRow, Error, ExportedTable as ExportedTableTrait, ExportedTableCallback,
+    FlightEndpoint, Result, TableHandle, TableProperties, Timestamp, TotalFrontier, Value,
 };
 
 use super::maybe_total::MaybeTotalScope;
@@ -112,6 +113,7 @@ pub fn export_table<S>(
     graph: &mut DataflowGraphInner<S>,
     table_handle: TableHandle,
     column_paths: Vec<ColumnPath>,
+    flight_endpoint: Option<FlightEndpoint>,
 ) -> Result<Arc<dyn ExportedTableTrait>>
 where
     S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>,
@@ -129,6 +131,15 @@ where
     ));
 
     let exported_table = Arc::new(ExportedTable::new(properties));
+    if let Some(flight_endpoint) = flight_endpoint {
+        let exported_table: Arc<dyn ExportedTableTrait> = exported_table.clone();
+        register_flight_table(
+            flight_endpoint,
+            graph.worker_index(),
+            graph.thread_count(),
+            &exported_table,
+        )?;
+    }
 
     graph
         .extract_columns(table_handle, column_paths)?
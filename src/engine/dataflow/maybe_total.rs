@@ -1,14 +1,17 @@
 // Copyright © 2026 Pathway
 
-use differential_dataflow::difference::Semigroup;
+use differential_dataflow::difference::{Abelian, Semigroup};
 use differential_dataflow::lattice::Lattice;
 use differential_dataflow::operators::arrange::Arranged;
 use differential_dataflow::trace::TraceReader;
-use differential_dataflow::{operators, Collection, Data};
+use differential_dataflow::{operators, Collection, Data, ExchangeData};
 use timely::dataflow::Scope;
 use timely::order::{Product, TotalOrder};
 use timely::progress::Timestamp;
 
+use crate::engine::dataflow::operators::shard_balancing::reshard_balanced;
+use crate::engine::dataflow::operators::Reshard;
+use crate::engine::dataflow::shard::Shard;
 use crate::engine::dataflow::time::{MaybeEpsilon, OriginalOrRetraction};
 
 pub trait MaybeTotalTimestamp: Timestamp + Lattice {
@@ -32,6 +35,16 @@ where
         T: TraceReader<Val = (), Time = Time> + Clone + 'static,
         T::Key: Data,
         T::R: Semigroup;
+
+    fn reshard_balanced<S, D, R>(
+        collection: &Collection<S, D, R>,
+        threads: usize,
+        shards_per_worker: usize,
+    ) -> Collection<S, D, R>
+    where
+        S: MaybeTotalScope<MaybeTotalTimestamp = Time>,
+        D: ExchangeData + Shard,
+        R: ExchangeData + Abelian;
 }
 
 pub struct Total;
@@ -60,6 +73,19 @@ where
     {
         operators::ThresholdTotal::distinct_total(arranged)
     }
+
+    fn reshard_balanced<S, D, R>(
+        collection: &Collection<S, D, R>,
+        threads: usize,
+        shards_per_worker: usize,
+    ) -> Collection<S, D, R>
+    where
+        S: MaybeTotalScope<MaybeTotalTimestamp = Time>,
+        D: ExchangeData + Shard,
+        R: ExchangeData + Abelian,
+    {
+        reshard_balanced(collection, threads, shards_per_worker)
+    }
 }
 
 impl<Time> MaybeTotalSwitch<Time> for NotTotal
@@ -87,6 +113,21 @@ where
         #[allow(clippy::disallowed_methods)]
         operators::Threshold::distinct(arranged)
     }
+
+    fn reshard_balanced<S, D, R>(
+        collection: &Collection<S, D, R>,
+        _threads: usize,
+        _shards_per_worker: usize,
+    ) -> Collection<S, D, R>
+    where
+        S: MaybeTotalScope<MaybeTotalTimestamp = Time>,
+        D: ExchangeData + Shard,
+        R: ExchangeData + Abelian,
+    {
+        // the shards can only be moved at a time all the workers agree on if the times
+        // are totally ordered
+        collection.reshard()
+    }
 }
 
 impl MaybeTotalTimestamp for u64 {
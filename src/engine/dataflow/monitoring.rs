@@ -1,23 +1,22 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc, time::SystemTime};
+use std::{cell::RefCell, collections::HashMap, env, rc::Rc, sync::Arc, time::SystemTime};
 
+use arc_swap::ArcSwapOption;
+use arcstr::ArcStr;
 use once_cell::unsync::Lazy;
-use pyo3::pyclass;
+use serde::Serialize;
 use timely::dataflow::ProbeHandle;
 use timely::progress::Timestamp as TimelyTimestamp;
 
 use crate::{
     connectors::monitoring::{ConnectorMonitor, ConnectorStats},
-    engine::Timestamp,
+    engine::{Graph, Key, Result, Timestamp, Value},
 };
 
-#[derive(Debug, Clone, Copy)]
-#[pyclass]
+#[derive(Debug, Clone, Copy, Serialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
 pub struct OperatorStats {
-    #[pyo3(get, set)]
     pub time: Option<Timestamp>,
-    #[pyo3(get, set)]
     pub lag: Option<u64>,
-    #[pyo3(get, set)]
     pub done: bool,
 }
 
@@ -41,27 +40,21 @@ impl OperatorStats {
     }
 }
 
-#[derive(Debug, Clone)]
-#[pyclass]
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
 pub struct ProberStats {
-    #[pyo3(get, set)]
     pub input_stats: OperatorStats,
-    #[pyo3(get, set)]
     pub output_stats: OperatorStats,
-    #[pyo3(get, set)]
     pub operators_stats: HashMap<usize, OperatorStats>,
-    #[pyo3(get, set)]
     pub connector_stats: Vec<(String, ConnectorStats)>,
-    #[pyo3(get)]
     pub row_counts: HashMap<usize, CountStats>,
+    pub table_gauges: HashMap<usize, TableGauge>,
 }
 
-#[derive(Debug, Default, Clone, Copy)]
-#[pyclass]
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
 pub struct CountStats {
-    #[pyo3(get)]
     total_rows: isize,
-    #[pyo3(get)]
     current_rows: isize,
 }
 
@@ -81,9 +74,116 @@ impl CountStats {
     }
 }
 
+/// Returns whether the row count and memory gauges of the tables are enabled for this
+/// process, with `PATHWAY_TABLE_GAUGES=1`.
+///
+/// The gauges track each table probed by the monitoring: the number of rows it holds
+/// once its updates are consolidated, and an estimate of the memory its arrangement
+/// takes. They are reported in [`ProberStats::table_gauges`], keyed by the index of the
+/// table handle, which shows which intermediate tables dominate the memory used. Keeping
+/// them up to date inspects every update of every probed table, so they are off by
+/// default.
+pub fn table_gauges_enabled() -> bool {
+    env::var("PATHWAY_TABLE_GAUGES") == Ok("1".to_string())
+}
+
+// the memory taken by a single entry of an arrangement besides the heap data of its value
+const ARRANGEMENT_ENTRY_SIZE: usize = size_of::<(Key, Value, Timestamp, isize)>();
+
+/// Estimates the heap memory owned by `value`. The memory shared between values, like
+/// the same string referenced from many rows, is counted once per value, and the
+/// Python objects and the JSON values are only counted by their inline size.
+fn estimated_heap_size(value: &Value) -> usize {
+    match value {
+        Value::String(string) => string.len(),
+        Value::Bytes(bytes) => bytes.len(),
+        Value::Tuple(values) => values
+            .iter()
+            .map(|value| size_of::<Value>() + estimated_heap_size(value))
+            .sum(),
+        Value::IntArray(array) => array.len() * size_of::<i64>(),
+        Value::FloatArray(array) => array.len() * size_of::<f64>(),
+        Value::Struct(fields) => fields
+            .iter()
+            .map(|(name, value)| {
+                size_of::<(ArcStr, Value)>() + name.len() + estimated_heap_size(value)
+            })
+            .sum(),
+        Value::Map(entries) => entries
+            .iter()
+            .map(|(name, value)| {
+                size_of::<(ArcStr, Value)>() + name.len() + estimated_heap_size(value)
+            })
+            .sum(),
+        _ => 0,
+    }
+}
+
+/// The row count and memory gauges of a single table.
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct TableGauge {
+    pub operator_id: usize,
+    pub rows: isize,
+    pub estimated_bytes: isize,
+}
+
+impl TableGauge {
+    pub fn new(operator_id: usize) -> Self {
+        Self {
+            operator_id,
+            ..Self::default()
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn update(&mut self, value: &Value, diff: isize) {
+        let entry_size = (ARRANGEMENT_ENTRY_SIZE + estimated_heap_size(value)) as isize;
+        self.rows += diff;
+        self.estimated_bytes += diff * entry_size;
+    }
+}
+
+/// Keeps the latest [`ProberStats`] of a computation, so that an application embedding
+/// the engine can poll them, e.g. to ship them to its own metrics system. The stats are
+/// serializable with `serde`.
+///
+/// The poller is attached to the graph of each worker while the dataflow is built. Like
+/// the monitoring HTTP server, it only collects the stats of the worker 0 of the
+/// process, which include the per-operator probes and the input connectors of that
+/// worker. The stats are updated whenever the progress of the computation changes.
+#[derive(Debug, Clone, Default)]
+pub struct StatsPoller {
+    latest: Arc<ArcSwapOption<ProberStats>>,
+}
+
+impl StatsPoller {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn attach(&self, graph: &dyn Graph) -> Result<()> {
+        if graph.worker_index() != 0 {
+            return Ok(());
+        }
+        let latest = self.latest.clone();
+        graph.attach_prober(
+            Box::new(move |prober_stats| latest.store(Some(Arc::new(prober_stats)))),
+            true,
+            false,
+        )
+    }
+
+    /// Returns the latest stats, or `None` if none have been collected yet.
+    pub fn latest(&self) -> Option<Arc<ProberStats>> {
+        self.latest.load_full()
+    }
+}
+
 pub struct OperatorProbe<T: TimelyTimestamp> {
     pub frontier: ProbeHandle<T>,
     pub counter: Rc<RefCell<CountStats>>,
+    pub table_gauges: HashMap<usize, Rc<RefCell<TableGauge>>>,
 }
 
 impl<T: TimelyTimestamp> Default for OperatorProbe<T> {
@@ -91,6 +191,7 @@ impl<T: TimelyTimestamp> Default for OperatorProbe<T> {
         Self {
             frontier: ProbeHandle::default(),
             counter: Rc::new(RefCell::new(CountStats::default())),
+            table_gauges: HashMap::new(),
         }
     }
 }
@@ -214,6 +315,11 @@ impl Prober {
                     row_counts.insert(*id, *probe.counter.borrow());
                 }
             }
+            let table_gauges = intermediate_probes
+                .values()
+                .flat_map(|probe| probe.table_gauges.iter())
+                .map(|(table_index, gauge)| (*table_index, *gauge.borrow()))
+                .collect();
 
             let prober_stats = ProberStats {
                 input_stats: Self::create_stats(input_probe, self.input_time),
@@ -221,6 +327,7 @@ impl Prober {
                 operators_stats: self.stats.clone(),
                 connector_stats,
                 row_counts,
+                table_gauges,
             };
 
             (self.callback)(prober_stats);
@@ -2,8 +2,10 @@
 
 pub mod external_index;
 pub mod gradual_broadcast;
+pub mod monotonic;
 pub mod output;
 pub mod prev_next;
+pub mod shard_balancing;
 pub mod stateful_reduce;
 pub mod time_column;
 mod utils;
@@ -11,7 +13,7 @@ mod utils;
 use std::any::type_name;
 use std::panic::Location;
 
-use differential_dataflow::difference::{Monoid, Semigroup};
+use differential_dataflow::difference::{Abelian, Monoid, Semigroup};
 use differential_dataflow::operators::arrange::{Arranged, TraceAgent};
 use differential_dataflow::trace::{Batch, Trace, TraceReader};
 use differential_dataflow::{AsCollection, Collection, Data, ExchangeData};
@@ -23,7 +25,7 @@ use timely::dataflow::operators::Operator;
 use crate::engine::dataflow::operators::output::OutputBatch;
 use crate::engine::BatchWrapper;
 
-use self::output::ConsolidateForOutput;
+use self::output::ConsolidateForOutputMap;
 
 use super::maybe_total::{MaybeTotalScope, MaybeTotalSwitch};
 use super::monitoring::OperatorProbe;
@@ -79,6 +81,13 @@ where
     where
         Tr: Trace + TraceReader<Key = K, Val = V, Time = S::Timestamp, R = R> + 'static,
         Tr::Batch: Batch;
+
+    /// Arranges the collection on the workers its rows already are, so it can only be used if
+    /// all the rows with the same key are on the same worker.
+    fn arrange_in_place_named<Tr>(&self, name: &str) -> Arranged<S, TraceAgent<Tr>>
+    where
+        Tr: Trace + TraceReader<Key = K, Val = V, Time = S::Timestamp, R = R> + 'static,
+        Tr::Batch: Batch;
 }
 
 impl<T, S, K, V, R> ArrangeWithTypes<S, K, V, R> for T
@@ -130,6 +139,75 @@ where
             self, exchange, &name,
         )
     }
+
+    #[track_caller]
+    fn arrange_in_place_named<Tr>(&self, name: &str) -> Arranged<S, TraceAgent<Tr>>
+    where
+        Tr: Trace + TraceReader<Key = K, Val = V, Time = S::Timestamp, R = R> + 'static,
+        Tr::Batch: Batch,
+    {
+        let caller = Location::caller();
+        let name = format!(
+            "{name} [{key}, {value}] in place at {caller}",
+            key = type_name::<K>(),
+            value = type_name::<V>()
+        );
+        #[allow(clippy::disallowed_methods)]
+        differential_dataflow::operators::arrange::arrangement::Arrange::arrange_core(
+            self, Pipeline, &name,
+        )
+    }
+}
+
+/// The workers the rows of an arrangement are placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// The worker of the shard of their key.
+    ByKey,
+    /// The workers they already are on, as the rows with the same key are already together.
+    InPlace,
+}
+
+pub trait ArrangeWithPlacement<S, K, V, R>
+where
+    S: MaybeTotalScope,
+    K: ExchangeData,
+    V: ExchangeData,
+    R: Semigroup + ExchangeData,
+{
+    fn arrange_placed_named<Tr>(
+        &self,
+        name: &str,
+        placement: Placement,
+    ) -> Arranged<S, TraceAgent<Tr>>
+    where
+        Tr: Trace + TraceReader<Key = K, Val = V, Time = S::Timestamp, R = R> + 'static,
+        Tr::Batch: Batch;
+}
+
+impl<T, S, K, V, R> ArrangeWithPlacement<S, K, V, R> for T
+where
+    T: ArrangeWithTypes<S, K, V, R> + ArrangeWithTypesSharded<S, K, V, R>,
+    S: MaybeTotalScope,
+    K: ExchangeData,
+    V: ExchangeData,
+    R: Semigroup + ExchangeData,
+{
+    #[track_caller]
+    fn arrange_placed_named<Tr>(
+        &self,
+        name: &str,
+        placement: Placement,
+    ) -> Arranged<S, TraceAgent<Tr>>
+    where
+        Tr: Trace + TraceReader<Key = K, Val = V, Time = S::Timestamp, R = R> + 'static,
+        Tr::Batch: Batch,
+    {
+        match placement {
+            Placement::ByKey => self.arrange_named(name),
+            Placement::InPlace => self.arrange_in_place_named(name),
+        }
+    }
 }
 
 pub trait MaybeTotal<S, K, R>
@@ -294,6 +372,7 @@ where
     fn flat_map_batched_named_with_deletions_first<D2: Data>(
         &self,
         name: &str,
+        placement: Placement,
         logic: impl FnMut(Vec<(D, R)>) -> Vec<(D2, R)> + 'static,
     ) -> Collection<S, D2, R>;
 }
@@ -308,11 +387,17 @@ where
     fn flat_map_batched_named_with_deletions_first<D2: Data>(
         &self,
         name: &str,
+        placement: Placement,
         mut logic: impl FnMut(Vec<(D, R)>) -> Vec<(D2, R)> + 'static,
     ) -> Collection<S, D2, R> {
         let caller = Location::caller();
         let name = format!("{name} at {caller}");
-        self.consolidate_for_output_named(&format!("ConsolidateForOutput: {name}"), false)
+        let arranged: ArrangedBySelf<S, D, R> =
+            self.arrange_placed_named(&format!("Arrange: ConsolidateForOutput: {name}"), placement);
+        arranged
+            .consolidate_for_output_map_named(&format!("ConsolidateForOutput: {name}"), |k, ()| {
+                k.clone()
+            })
             .unary(Pipeline, &name, move |_, _| {
                 let mut vector = Vec::new();
                 move |input, output| {
@@ -360,6 +445,30 @@ where
     }
 }
 
+pub trait ReshardBalanced<S, D, R>
+where
+    S: MaybeTotalScope,
+    D: Data,
+    R: Semigroup,
+{
+    /// Sends the rows to the workers of their logical shards, with `shards_per_worker`
+    /// logical shards per worker reassigned between the `threads` workers of each process
+    /// based on their load. Outside of a totally ordered scope, the rows are sent to the
+    /// workers of their shards, as by [`Reshard::reshard`].
+    fn reshard_balanced(&self, threads: usize, shards_per_worker: usize) -> Collection<S, D, R>;
+}
+
+impl<S, D, R> ReshardBalanced<S, D, R> for Collection<S, D, R>
+where
+    S: MaybeTotalScope,
+    D: ExchangeData + Shard,
+    R: ExchangeData + Abelian,
+{
+    fn reshard_balanced(&self, threads: usize, shards_per_worker: usize) -> Collection<S, D, R> {
+        S::IsTotal::reshard_balanced(self, threads, shards_per_worker)
+    }
+}
+
 pub trait ExtendedProbeWith<S, D>
 where
     S: MaybeTotalScope,
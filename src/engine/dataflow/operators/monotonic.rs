@@ -0,0 +1,105 @@
+// Copyright © 2026 Pathway
+
+//! Checking that the values of a sequence grow with time.
+//!
+//! The rows are sent to the worker of their instance and held back until no more rows
+//! can arrive at their time. Then the times are checked in order, and the rows of a time
+//! are passed on only after they are checked, so that a row out of order never reaches
+//! the next operators before it is reported. For each instance, only the value it has
+//! reached so far is kept.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::panic::Location;
+
+use differential_dataflow::{AsCollection, Collection, ExchangeData};
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::{Capability, Operator};
+
+use crate::engine::dataflow::maybe_total::MaybeTotalScope;
+use crate::engine::dataflow::shard::Shard;
+use crate::engine::Value;
+
+// the rows of a time, as `(instance, (data, value))` with their diffs
+type PendingRows<T, I, D> = BTreeMap<T, (Capability<T>, Vec<((I, (D, Value)), isize)>)>;
+
+/// Checks, for every instance, that the values inserted at a time are in order after the
+/// values inserted at the earlier times, calling `on_violation` with the data, the previous
+/// and the current value for each value that isn't. The values inserted at the same time
+/// are not ordered with respect to each other, so each of them is only compared with the
+/// largest value inserted at the last earlier time with insertions. Deletions and errors
+/// are not checked.
+#[track_caller]
+pub fn check_monotonic<S, I, D>(
+    collection: &Collection<S, (I, (D, Value))>,
+    name: &str,
+    is_in_order: impl Fn(&Value, &Value) -> bool + 'static,
+    mut on_violation: impl FnMut(&D, &Value, &Value) + 'static,
+) -> Collection<S, D>
+where
+    S: MaybeTotalScope,
+    I: ExchangeData + Shard + Hash,
+    D: ExchangeData,
+{
+    let caller = Location::caller();
+    let name = format!("{name} at {caller}");
+    let exchange = Exchange::new(
+        |((instance, _row), _time, _diff): &((I, (D, Value)), S::Timestamp, isize)| {
+            instance.shard()
+        },
+    );
+    collection
+        .inner
+        .unary_frontier(exchange, &name, move |_capability, _info| {
+            let mut reached: HashMap<I, Value> = HashMap::new();
+            let mut pending: PendingRows<S::Timestamp, I, D> = BTreeMap::new();
+            let mut buffer = Vec::new();
+            move |input, output| {
+                input.for_each(|cap, data| {
+                    data.swap(&mut buffer);
+                    for (row, time, diff) in buffer.drain(..) {
+                        pending
+                            .entry(time.clone())
+                            .or_insert_with(|| (cap.delayed(&time), Vec::new()))
+                            .1
+                            .push((row, diff));
+                    }
+                });
+
+                // the rows of a time are checked once no more rows can arrive at it,
+                // in the order of the times
+                while let Some(first_time) = pending.keys().next() {
+                    if input.frontier().less_equal(first_time) {
+                        break;
+                    }
+                    let (time, (cap, rows)) = pending.pop_first().unwrap();
+                    let mut inserted: HashMap<I, Value> = HashMap::new();
+                    let mut session = output.session(&cap);
+                    for ((instance, (data, value)), diff) in rows {
+                        // errors can't be compared with other values, they are reported elsewhere
+                        if diff > 0 && value != Value::Error {
+                            if let Some(previous) = reached.get(&instance) {
+                                if !is_in_order(previous, &value) {
+                                    on_violation(&data, previous, &value);
+                                }
+                            }
+                            match inserted.entry(instance) {
+                                Entry::Occupied(mut entry) => {
+                                    if value > *entry.get() {
+                                        entry.insert(value);
+                                    }
+                                }
+                                Entry::Vacant(entry) => {
+                                    entry.insert(value);
+                                }
+                            }
+                        }
+                        session.give((data, time.clone(), diff));
+                    }
+                    reached.extend(inserted);
+                }
+            }
+        })
+        .as_collection()
+}
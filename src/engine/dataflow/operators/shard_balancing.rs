@@ -0,0 +1,221 @@
+// Copyright © 2026 Pathway
+
+//! Resharding by logical shards reassigned between the workers based on their load.
+//!
+//! Every worker counts the rows of each logical shard it receives and broadcasts the
+//! counts, so that all the workers have the same counts. They apply them in the order of
+//! their times, once all the counts of a time are known, which makes them reassign the
+//! shards in the same way and at the same time. A row at time `t` is routed with the
+//! assignment resulting from the counts of the times before `t`.
+//!
+//! When a shard moves at time `t`, the rows routed to it before are moved along with it:
+//! every worker sends the rows it has routed to the shard, retracted to the old worker and
+//! inserted to the new one, both at time `t`. The stateful operator reading the rows in
+//! place then sees the shard disappear from one worker and appear on the other one, so the
+//! sum of their outputs stays the same. For this, every worker keeps the multiplicities of
+//! the rows it has routed to each shard, so a row inserted many times takes a single entry
+//! and a retracted row none. The rows of a later time are held back until the moves of all
+//! the earlier times are sent, so that they never reach a worker the shard has left.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use differential_dataflow::difference::{Abelian, Semigroup};
+use differential_dataflow::{AsCollection, Collection, ExchangeData};
+use timely::dataflow::channels::pact::Pipeline;
+use timely::dataflow::operators::{Broadcast, Capability, Exchange, Map, Operator};
+use timely::order::TotalOrder;
+use timely::progress::Timestamp;
+
+use crate::engine::dataflow::maybe_total::MaybeTotalScope;
+use crate::engine::dataflow::shard::{Shard, ShardAssignment, ShardMove};
+
+// the rows waiting for the counts of the earlier times, with the capabilities for them
+type PendingRows<D, T, R> = Vec<(Capability<T>, Vec<(D, T, R)>)>;
+
+// the counts of the rows of each time, with the capabilities for moving the shards then
+type PendingLoads<T> = BTreeMap<T, (Capability<T>, Vec<(usize, u64)>)>;
+
+// the multiplicities of the rows of a logical shard routed by this worker, without the
+// rows whose multiplicities add up to zero
+type RoutedRows<D, R> = BTreeMap<D, R>;
+
+struct Router<D, R> {
+    assignment: ShardAssignment,
+    routed: Vec<RoutedRows<D, R>>,
+}
+
+impl<D, R> Router<D, R>
+where
+    D: ExchangeData + Shard,
+    R: ExchangeData + Abelian,
+{
+    fn new(assignment: ShardAssignment) -> Self {
+        let routed = (0..assignment.logical_shards())
+            .map(|_| RoutedRows::new())
+            .collect();
+        Self { assignment, routed }
+    }
+
+    fn route<T>(&mut self, rows: Vec<(D, T, R)>) -> Vec<((u64, D), T, R)> {
+        rows.into_iter()
+            .map(|(data, time, diff)| {
+                let shard = self.assignment.logical_shard(data.shard());
+                match self.routed[shard].entry(data.clone()) {
+                    Entry::Occupied(mut entry) => {
+                        entry.get_mut().plus_equals(&diff);
+                        if entry.get().is_zero() {
+                            entry.remove();
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(diff.clone());
+                    }
+                }
+                ((self.assignment.worker(shard) as u64, data), time, diff)
+            })
+            .collect()
+    }
+
+    // Counts the rows of the shards at `time` and returns the rows moved with the shards
+    // reassigned because of them. The counts are received in a different order by each
+    // worker, so the shards are only rebalanced after all of them are counted.
+    fn count<T: Clone>(&mut self, loads: Vec<(usize, u64)>, time: &T) -> Vec<((u64, D), T, R)> {
+        for (shard, load) in loads {
+            self.assignment.count(shard, load);
+        }
+        let mut moved = Vec::new();
+        for ShardMove { shard, from, to } in self.assignment.rebalance_if_due() {
+            for (data, diff) in &self.routed[shard] {
+                moved.push((
+                    (from as u64, data.clone()),
+                    time.clone(),
+                    diff.clone().negate(),
+                ));
+                moved.push(((to as u64, data.clone()), time.clone(), diff.clone()));
+            }
+        }
+        moved
+    }
+}
+
+/// Sends the rows to the workers of their logical shards, reassigned between the workers
+/// of each process based on their load. All the rows with the same shard are on the same
+/// worker at any time, so they can be arranged in place.
+pub fn reshard_balanced<S, D, R>(
+    collection: &Collection<S, D, R>,
+    threads: usize,
+    shards_per_worker: usize,
+) -> Collection<S, D, R>
+where
+    S: MaybeTotalScope,
+    S::Timestamp: TotalOrder,
+    D: ExchangeData + Shard,
+    R: ExchangeData + Abelian,
+{
+    let assignment = ShardAssignment::new(threads, collection.scope().peers(), shards_per_worker);
+    let loads = {
+        let assignment = assignment.clone();
+        collection
+            .inner
+            .unary(Pipeline, "ReshardBalanced::loads", move |_, _| {
+                let mut buffer = Vec::new();
+                move |input, output| {
+                    input.for_each(|cap, data| {
+                        data.swap(&mut buffer);
+                        let mut loads: BTreeMap<S::Timestamp, HashMap<usize, u64>> =
+                            BTreeMap::new();
+                        for (data, time, _diff) in buffer.drain(..) {
+                            *loads
+                                .entry(time)
+                                .or_default()
+                                .entry(assignment.logical_shard(data.shard()))
+                                .or_default() += 1;
+                        }
+                        for (time, loads) in loads {
+                            output
+                                .session(&cap.delayed(&time))
+                                .give_iterator(loads.into_iter());
+                        }
+                    });
+                }
+            })
+            .broadcast()
+    };
+    collection
+        .inner
+        .binary_frontier(
+            &loads,
+            Pipeline,
+            Pipeline,
+            "ReshardBalanced",
+            move |_capability, _info| {
+                let mut router = Router::new(assignment);
+                let mut pending_rows: PendingRows<D, S::Timestamp, R> = Vec::new();
+                let mut pending_loads: PendingLoads<S::Timestamp> = BTreeMap::new();
+                move |rows_input, loads_input, output| {
+                    rows_input.for_each(|cap, data| {
+                        pending_rows.push((cap.retain(), data.replace(Vec::new())));
+                    });
+                    loads_input.for_each(|cap, data| {
+                        pending_loads
+                            .entry(cap.time().clone())
+                            .or_insert_with(|| (cap.retain(), Vec::new()))
+                            .1
+                            .extend(data.replace(Vec::new()));
+                    });
+
+                    // the counts of a time are applied once no more rows or counts can
+                    // arrive at it, in the order of the times
+                    while let Some(first_time) = pending_loads.keys().next() {
+                        if rows_input.frontier().less_equal(first_time)
+                            || loads_input.frontier().less_equal(first_time)
+                        {
+                            break;
+                        }
+                        let (time, (cap, loads)) = pending_loads.pop_first().unwrap();
+                        for (cap, rows) in take_rows(&mut pending_rows, |t| t <= &time) {
+                            output.session(&cap).give_vec(&mut router.route(rows));
+                        }
+                        output
+                            .session(&cap)
+                            .give_vec(&mut router.count(loads, &time));
+                    }
+
+                    // the rows are routed once the counts of all the earlier times are applied,
+                    // so also after the shards they move are sent
+                    let loads_frontier = loads_input.frontier();
+                    let first_pending_time = pending_loads.keys().next();
+                    for (cap, rows) in take_rows(&mut pending_rows, |t| {
+                        !loads_frontier.less_than(t)
+                            && first_pending_time.map_or(true, |first_time| t <= first_time)
+                    }) {
+                        output.session(&cap).give_vec(&mut router.route(rows));
+                    }
+                }
+            },
+        )
+        .exchange(|((worker, _data), _time, _diff)| *worker)
+        .map(|((_worker, data), time, diff)| (data, time, diff))
+        .as_collection()
+}
+
+// Takes the pending rows whose times are ready, along with the capabilities for them.
+fn take_rows<D, T: Timestamp, R>(
+    pending_rows: &mut PendingRows<D, T, R>,
+    is_ready: impl Fn(&T) -> bool,
+) -> PendingRows<D, T, R> {
+    let mut ready = Vec::new();
+    pending_rows.retain_mut(|(cap, rows)| {
+        let (ready_rows, other_rows): (Vec<_>, Vec<_>) = rows
+            .drain(..)
+            .partition(|(_data, time, _diff)| is_ready(time));
+        *rows = other_rows;
+        if !ready_rows.is_empty() {
+            ready.push((cap.clone(), ready_rows));
+        }
+        !rows.is_empty()
+    });
+    ready
+}
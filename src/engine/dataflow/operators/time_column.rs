@@ -9,7 +9,7 @@ use crate::engine::dataflow::time::{Epsilon, OriginalOrRetraction};
 use crate::engine::dataflow::{ArrangedBySelf, Shard};
 use crate::engine::error::Result;
 use crate::engine::Timestamp;
-use differential_dataflow::difference::{Abelian, Semigroup};
+use differential_dataflow::difference::{Abelian, Monoid, Semigroup};
 use differential_dataflow::lattice::Lattice;
 use differential_dataflow::operators::arrange::{Arranged, TraceAgent};
 use differential_dataflow::operators::iterate::Variable;
@@ -21,7 +21,7 @@ use differential_dataflow::{AsCollection, Collection, Data, Diff, ExchangeData,
 use serde::{Deserialize, Serialize};
 use std::cmp::Ord;
 use std::collections::hash_map::{Entry, Keys};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::Hash;
 use std::rc::Rc;
 use timely::communication::Push;
@@ -189,6 +189,27 @@ pub trait TimeColumnBuffer<
         TTE: Fn(&V) -> CT + 'static,
         CTE: Fn(&V) -> CT + 'static,
         IE: Fn(&V) -> I + 'static;
+
+    /// Like `postpone`, but the entries that are already late when they arrive, that is,
+    /// with the threshold time not greater than the current time of their instance, are
+    /// not passed through. They are returned in the second collection instead.
+    #[allow(clippy::too_many_arguments)]
+    fn postpone_with_late(
+        &self,
+        scope: G,
+        threshold_time_extractor: TTE,
+        current_time_extractor: CTE,
+        instance_extractor: IE,
+        flush_on_end: bool,
+        update_time_before_emitting: bool,
+        additional_logic: impl FnOnce(Collection<G, (K, V), R>) -> Result<Collection<G, (K, V), R>>,
+    ) -> Result<(Collection<G, (K, V), R>, Collection<G, (K, V), R>)>
+    where
+        G: MaybeTotalScope,
+        G::Timestamp: Epsilon + MaxTimestamp,
+        TTE: Fn(&V) -> CT + 'static,
+        CTE: Fn(&V) -> CT + 'static,
+        IE: Fn(&V) -> I + 'static;
 }
 
 impl<G, K, V, R, CT, TTE, CTE, I, IE> TimeColumnBuffer<G, K, V, R, CT, TTE, CTE, I, IE>
@@ -234,6 +255,51 @@ where
         retractions.set(&ret_in_buffer_timespace_col);
         Ok(ret_in_buffer_timespace_col)
     }
+
+    fn postpone_with_late(
+        &self,
+        scope: G,
+        threshold_time_extractor: TTE,
+        current_time_extractor: CTE,
+        instance_extractor: IE,
+        flush_on_end: bool,
+        update_time_before_emitting: bool,
+        additional_logic: impl FnOnce(Collection<G, (K, V), R>) -> Result<Collection<G, (K, V), R>>,
+    ) -> Result<(Collection<G, (K, V), R>, Collection<G, (K, V), R>)>
+    where
+        G::Timestamp: Epsilon + MaxTimestamp,
+        TTE: Fn(&V) -> CT + 'static,
+        CTE: Fn(&V) -> CT + 'static,
+        IE: Fn(&V) -> I + 'static,
+    {
+        let threshold_time_extractor = Rc::new(threshold_time_extractor);
+        let current_time_extractor = Rc::new(current_time_extractor);
+        let instance_extractor = Rc::new(instance_extractor);
+        let (on_time, late) = ignore_late(
+            self,
+            shared_extractor(&threshold_time_extractor),
+            shared_extractor(&current_time_extractor),
+            shared_extractor(&instance_extractor),
+        );
+        let on_time = on_time.postpone(
+            scope,
+            shared_extractor(&threshold_time_extractor),
+            shared_extractor(&current_time_extractor),
+            shared_extractor(&instance_extractor),
+            flush_on_end,
+            update_time_before_emitting,
+            additional_logic,
+        )?;
+        Ok((on_time, late))
+    }
+}
+
+// Allows using an extractor in more than one operator.
+fn shared_extractor<V, T>(
+    extractor: &Rc<impl Fn(&V) -> T + 'static>,
+) -> impl Fn(&V) -> T + 'static {
+    let extractor = Rc::clone(extractor);
+    move |val: &V| extractor(val)
 }
 
 fn push_key_values_to_output<K, C: Cursor, P>(
@@ -534,6 +600,25 @@ pub trait TimeColumnForget<
         TTE: Fn(&V) -> CT + 'static,
         CTE: Fn(&V) -> CT + 'static,
         IE: Fn(&V) -> I + 'static;
+
+    /// Like `forget`, but the entries that are already late when they arrive, that is,
+    /// with the threshold time not greater than the current time of their instance, are
+    /// not passed through to be forgotten right away. They are returned in the second
+    /// collection instead.
+    fn forget_with_late(
+        &self,
+        threshold_time_extractor: TTE,
+        current_time_extractor: CTE,
+        instance_extractor: IE,
+        mark_forgetting_records: bool,
+        additional_logic: impl FnOnce(Collection<G, (K, V), R>) -> Result<Collection<G, (K, V), R>>,
+    ) -> Result<(Collection<G, (K, V), R>, Collection<G, (K, V), R>)>
+    where
+        G: MaybeTotalScope,
+        G::Timestamp: Epsilon + MaxTimestamp,
+        TTE: Fn(&V) -> CT + 'static,
+        CTE: Fn(&V) -> CT + 'static,
+        IE: Fn(&V) -> I + 'static;
 }
 
 impl<G, K, V, R, CT, TTE, CTE, I, IE> TimeColumnForget<G, K, V, R, CT, TTE, CTE, I, IE>
@@ -598,6 +683,39 @@ where
             .as_collection()
             .concat(&forgetting_stream))
     }
+
+    fn forget_with_late(
+        &self,
+        threshold_time_extractor: TTE,
+        current_time_extractor: CTE,
+        instance_extractor: IE,
+        mark_forgetting_records: bool,
+        additional_logic: impl FnOnce(Collection<G, (K, V), R>) -> Result<Collection<G, (K, V), R>>,
+    ) -> Result<(Collection<G, (K, V), R>, Collection<G, (K, V), R>)>
+    where
+        G::Timestamp: Epsilon + MaxTimestamp,
+        TTE: Fn(&V) -> CT + 'static,
+        CTE: Fn(&V) -> CT + 'static,
+        IE: Fn(&V) -> I + 'static,
+    {
+        let threshold_time_extractor = Rc::new(threshold_time_extractor);
+        let current_time_extractor = Rc::new(current_time_extractor);
+        let instance_extractor = Rc::new(instance_extractor);
+        let (on_time, late) = ignore_late(
+            self,
+            shared_extractor(&threshold_time_extractor),
+            shared_extractor(&current_time_extractor),
+            shared_extractor(&instance_extractor),
+        );
+        let on_time = on_time.forget(
+            shared_extractor(&threshold_time_extractor),
+            shared_extractor(&current_time_extractor),
+            shared_extractor(&instance_extractor),
+            mark_forgetting_records,
+            additional_logic,
+        )?;
+        Ok((on_time, late))
+    }
 }
 pub trait TimeColumnFreeze<
     G: Scope,
@@ -725,3 +843,89 @@ where
 
     (stream.as_collection(), late_stream.as_collection())
 }
+
+/// Keeps at most `max_versions` entries per instance, the ones with the greatest current
+/// times. When an entry arrives to an instance that already has `max_versions` entries,
+/// the oldest one is removed from the output, and it isn't restored if a newer entry
+/// is deleted later. Only the retained entries are stored.
+pub fn retain_last_versions<G, CT, K, V, R, CTE, I, IE>(
+    input_collection: &Collection<G, (K, V), R>,
+    current_time_extractor: CTE,
+    instance_extractor: IE,
+    max_versions: usize,
+) -> Collection<G, (K, V), R>
+where
+    G: MaybeTotalScope,
+    G::Timestamp: Lattice + Ord,
+    CT: ExchangeData,
+    K: ExchangeData + Shard,
+    V: ExchangeData,
+    R: ExchangeData + Abelian,
+    CTE: Fn(&V) -> CT + 'static,
+    I: Eq + Hash + 'static,
+    IE: Fn(&V) -> I + 'static,
+{
+    let input_arrangement: ArrangedBySelf<G, (K, V), R> = input_collection.arrange_sharded(|_| 0);
+    let mut builder = OperatorBuilder::new(
+        "retain_last_versions".to_owned(),
+        input_collection.inner.scope(),
+    );
+
+    let mut input = builder.new_input(&input_arrangement.stream, Pipeline);
+    let (mut output, stream) = builder.new_output();
+
+    builder.build(move |_| {
+        let mut input_buffer = Vec::new();
+        let mut retained: HashMap<I, BTreeMap<(CT, K, V), R>> = HashMap::new();
+
+        move |_frontiers| {
+            let mut output_handle = output.activate();
+            input.for_each(|capability, batch| {
+                batch.swap(&mut input_buffer);
+                let grouped = batch_by_time(&input_buffer, |key_val, (), time, diff| {
+                    (key_val.clone(), time.clone(), diff.clone())
+                });
+                for data in grouped.into_values() {
+                    let mut session = output_handle.session(&capability);
+                    // the deletions go first, so that the entries they replace don't
+                    // cause evictions
+                    let (deletions, insertions): (Vec<_>, Vec<_>) = data
+                        .into_iter()
+                        .partition(|(_, _, weight)| *weight < R::zero());
+                    for ((key, val), time, weight) in deletions.into_iter().chain(insertions) {
+                        let instance_retained =
+                            retained.entry(instance_extractor(&val)).or_default();
+                        let version = (current_time_extractor(&val), key, val);
+                        if weight < R::zero() {
+                            // the deletions of the entries that are no longer retained
+                            // were already emitted
+                            let Some(retained_weight) = instance_retained.get_mut(&version) else {
+                                continue;
+                            };
+                            retained_weight.plus_equals(&weight);
+                            if *retained_weight <= R::zero() {
+                                instance_retained.remove(&version);
+                            }
+                            let (_time, key, val) = version;
+                            session.give(((key, val), time, weight));
+                            continue;
+                        }
+                        instance_retained
+                            .entry(version.clone())
+                            .or_insert_with(R::zero)
+                            .plus_equals(&weight);
+                        let (_time, key, val) = version;
+                        session.give(((key, val), time.clone(), weight));
+                        while instance_retained.len() > max_versions {
+                            let ((_time, key, val), retained_weight) =
+                                instance_retained.pop_first().unwrap();
+                            session.give(((key, val), time.clone(), retained_weight.negate()));
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    stream.as_collection()
+}
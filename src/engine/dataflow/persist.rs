@@ -31,6 +31,7 @@ use crate::engine::reduce::{
 use crate::engine::{Key, Result, Timestamp, Value};
 use crate::persistence::config::PersistenceManagerConfig;
 use crate::persistence::operator_snapshot::{OperatorSnapshotReader, OperatorSnapshotWriter};
+use crate::persistence::state::OperatorIdScheme;
 use crate::persistence::tracker::{
     RequiredPersistenceMode, SharedWorkerPersistentStorage, WorkerPersistentStorage,
 };
@@ -41,7 +42,7 @@ pub(super) fn effective_persistent_id<S>(
     reader_is_internal: bool,
     unique_name: Option<&UniqueName>,
     required_persistence_mode: RequiredPersistenceMode,
-    logic: impl FnOnce(u64) -> String,
+    logic: impl FnOnce(String) -> String,
 ) -> Option<UniqueName>
 where
     S: MaybeTotalScope,
@@ -74,6 +75,23 @@ where
     }
 }
 
+/// Returns whether the state of the operators without a unique name is persisted.
+pub(super) fn operator_persistence_enabled<S>(
+    persistence_wrapper: &dyn PersistenceWrapper<S>,
+    required_persistence_mode: RequiredPersistenceMode,
+) -> bool
+where
+    S: MaybeTotalScope,
+{
+    persistence_wrapper
+        .get_worker_persistent_storage()
+        .is_some_and(|worker_persistent_storage| {
+            let worker_persistent_storage = worker_persistent_storage.lock().unwrap();
+            worker_persistent_storage.persistent_id_generation_enabled(required_persistence_mode)
+                && worker_persistent_storage.table_persistence_enabled()
+        })
+}
+
 pub(super) trait PersistenceWrapper<S>
 where
     S: MaybeTotalScope,
@@ -112,11 +130,18 @@ where
         Option<Poller>,
         Option<std::thread::JoinHandle<()>>,
     )>;
-    fn next_state_id(&mut self) -> u64;
+    /// Sets the identity of the operator being built, derived from the structure of
+    /// the graph. The ids of its persisted states are derived from it.
+    fn set_operator_identity(&mut self, stable_id: Option<String>);
+    /// The id of the next persisted state of the operator being built. With
+    /// [`OperatorIdScheme::Structural`], it's the identity of the operator followed by
+    /// the index of the state within the operator, if the identity is set. Otherwise,
+    /// it's the number of the state in the whole graph.
+    fn next_state_id(&mut self) -> String;
     /// Separate counter for iterate's extra-table snapshots: consuming ids from
     /// `next_state_id` would shift the persistent ids of every operator built
     /// after an iterate, orphaning snapshots of pre-existing deployments.
-    fn next_extra_state_id(&mut self) -> u64;
+    fn next_extra_state_id(&mut self) -> String;
 }
 
 pub struct EmptyPersistenceWrapper;
@@ -160,12 +185,14 @@ where
         generic_keep_nothing(collection)
     }
 
-    fn next_state_id(&mut self) -> u64 {
-        0
+    fn set_operator_identity(&mut self, _stable_id: Option<String>) {}
+
+    fn next_state_id(&mut self) -> String {
+        "0".to_string()
     }
 
-    fn next_extra_state_id(&mut self) -> u64 {
-        0
+    fn next_extra_state_id(&mut self) -> String {
+        "0".to_string()
     }
 
     fn maybe_persist_with_logic(
@@ -455,23 +482,38 @@ macro_rules! dispatch_persistable_collection {
 pub struct TimestampBasedPersistenceWrapper {
     persistence_config: PersistenceManagerConfig,
     worker_persistent_storage: SharedWorkerPersistentStorage,
+    operator_id_scheme: OperatorIdScheme,
     persisted_states_count: u64,
     extra_states_count: u64,
+    operator_stable_id: Option<String>,
+    operator_states_count: u64,
 }
 
 impl TimestampBasedPersistenceWrapper {
     pub fn new(persistence_config: PersistenceManagerConfig) -> Result<Self> {
-        let worker_persistent_storage = Arc::new(Mutex::new(WorkerPersistentStorage::new(
-            persistence_config.clone(),
-        )?));
+        let worker_persistent_storage = WorkerPersistentStorage::new(persistence_config.clone())?;
+        let operator_id_scheme = worker_persistent_storage.operator_id_scheme();
         Ok(Self {
             persistence_config,
-            worker_persistent_storage,
+            worker_persistent_storage: Arc::new(Mutex::new(worker_persistent_storage)),
+            operator_id_scheme,
             persisted_states_count: 0,
             extra_states_count: 0,
+            operator_stable_id: None,
+            operator_states_count: 0,
         })
     }
 
+    fn next_structural_state_id(&mut self) -> Option<String> {
+        if self.operator_id_scheme != OperatorIdScheme::Structural {
+            return None;
+        }
+        let operator_stable_id = self.operator_stable_id.as_ref()?;
+        let state_id = format!("{operator_stable_id}-{}", self.operator_states_count);
+        self.operator_states_count += 1;
+        Some(state_id)
+    }
+
     fn generic_maybe_persist<S, D, R>(
         &mut self,
         collection: &Collection<S, D, R>,
@@ -627,14 +669,23 @@ impl<S: MaybeTotalScope<MaybeTotalTimestamp = Timestamp>> PersistenceWrapper<S>
         Ok((collection_after_saving, Some(poller), Some(thread_handle)))
     }
 
-    fn next_state_id(&mut self) -> u64 {
+    fn set_operator_identity(&mut self, stable_id: Option<String>) {
+        self.operator_stable_id = stable_id;
+        self.operator_states_count = 0;
+    }
+
+    fn next_state_id(&mut self) -> String {
+        // the counter advances in both schemes, so that the operators without an identity
+        // get the same ids whether the other ones have it or not
         self.persisted_states_count += 1;
-        self.persisted_states_count
+        self.next_structural_state_id()
+            .unwrap_or_else(|| self.persisted_states_count.to_string())
     }
 
-    fn next_extra_state_id(&mut self) -> u64 {
+    fn next_extra_state_id(&mut self) -> String {
         self.extra_states_count += 1;
-        self.extra_states_count
+        self.next_structural_state_id()
+            .unwrap_or_else(|| self.extra_states_count.to_string())
     }
 }
 
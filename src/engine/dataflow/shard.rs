@@ -1,8 +1,17 @@
 // Copyright © 2026 Pathway
+use std::cmp::Reverse;
+
 use crate::engine::value::SHARD_MASK;
 
 use crate::engine::Key;
 
+// number of counted rows between the attempts to rebalance the shards
+const REBALANCE_PERIOD: u64 = 1 << 16;
+
+// the shards are only moved if the most loaded worker of a process has more than
+// IMBALANCE_TOLERANCE_PERCENT percent of the average load of the workers of the process
+const IMBALANCE_TOLERANCE_PERCENT: u64 = 125;
+
 pub trait Shard {
     fn shard(&self) -> u64;
 
@@ -43,3 +52,135 @@ impl Shard for i32 {
         *self as u64
     }
 }
+
+/// A logical shard moved from one worker to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShardMove {
+    pub shard: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Assignment of logical shards to workers.
+///
+/// There are `shards_per_worker` times more logical shards than workers. Initially, the
+/// logical shard `s` is assigned to the worker `s % workers`, so that the rows are placed
+/// as without the assignment. The rows of each logical shard are counted and after every
+/// `rebalance_period` rows, if the workers of a process are unevenly loaded, the least
+/// loaded of them takes over the shards of the most loaded one, the heaviest first, as
+/// long as this makes their loads closer. A shard never moves to another process.
+///
+/// The assignment only depends on the counts it is given, so the workers counting the
+/// same rows in the same order make the same decisions.
+#[derive(Debug, Clone)]
+pub struct ShardAssignment {
+    threads: usize,
+    workers: usize,
+    shard_workers: Vec<usize>,
+    // the rows counted for each logical shard since the last rebalancing
+    loads: Vec<u64>,
+    counted: u64,
+    rebalance_period: u64,
+}
+
+impl ShardAssignment {
+    pub fn new(threads: usize, workers: usize, shards_per_worker: usize) -> Self {
+        assert_eq!(workers % threads, 0);
+        let logical_shards = workers * shards_per_worker;
+        Self {
+            threads,
+            workers,
+            shard_workers: (0..logical_shards).map(|shard| shard % workers).collect(),
+            loads: vec![0; logical_shards],
+            counted: 0,
+            rebalance_period: REBALANCE_PERIOD,
+        }
+    }
+
+    #[must_use]
+    pub fn with_rebalance_period(mut self, rebalance_period: u64) -> Self {
+        self.rebalance_period = rebalance_period;
+        self
+    }
+
+    pub fn logical_shards(&self) -> usize {
+        self.shard_workers.len()
+    }
+
+    /// Returns the logical shard of a row with the given shard.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn logical_shard(&self, shard: u64) -> usize {
+        (shard % self.logical_shards() as u64) as usize
+    }
+
+    /// Returns the index of the worker the logical shard is assigned to.
+    pub fn worker(&self, logical_shard: usize) -> usize {
+        self.shard_workers[logical_shard]
+    }
+
+    /// Counts `rows` rows of the logical shard.
+    pub fn count(&mut self, logical_shard: usize, rows: u64) {
+        self.loads[logical_shard] += rows;
+        self.counted += rows;
+    }
+
+    /// Rebalances the shards if `rebalance_period` rows have been counted since the last
+    /// rebalancing. Returns the shards that moved.
+    pub fn rebalance_if_due(&mut self) -> Vec<ShardMove> {
+        if self.counted < self.rebalance_period {
+            return Vec::new();
+        }
+        self.rebalance()
+    }
+
+    /// Rebalances the shards of the unevenly loaded processes based on the rows counted
+    /// since the last rebalancing. Returns the shards that moved.
+    pub fn rebalance(&mut self) -> Vec<ShardMove> {
+        let mut moves = Vec::new();
+        for process in 0..self.workers / self.threads {
+            let first_worker = process * self.threads;
+            let mut worker_loads = vec![0; self.threads];
+            for (shard, worker) in self.shard_workers.iter().enumerate() {
+                if worker / self.threads == process {
+                    worker_loads[worker - first_worker] += self.loads[shard];
+                }
+            }
+            let total_load: u64 = worker_loads.iter().sum();
+            let max_load = worker_loads.iter().max().copied().unwrap_or_default();
+            if max_load * self.threads as u64 * 100 <= total_load * IMBALANCE_TOLERANCE_PERCENT {
+                continue;
+            }
+            loop {
+                let busiest = (0..self.threads)
+                    .max_by_key(|worker| (worker_loads[*worker], Reverse(*worker)))
+                    .expect("a process should have at least one worker");
+                let idlest = (0..self.threads)
+                    .min_by_key(|worker| (worker_loads[*worker], *worker))
+                    .expect("a process should have at least one worker");
+                let gap = worker_loads[busiest] - worker_loads[idlest];
+                // moving a shard lighter than the gap brings the two loads closer
+                let Some(shard) = (0..self.logical_shards())
+                    .filter(|shard| {
+                        self.shard_workers[*shard] == first_worker + busiest
+                            && self.loads[*shard] > 0
+                            && self.loads[*shard] < gap
+                    })
+                    .max_by_key(|shard| (self.loads[*shard], Reverse(*shard)))
+                else {
+                    break;
+                };
+                worker_loads[busiest] -= self.loads[shard];
+                worker_loads[idlest] += self.loads[shard];
+                self.shard_workers[shard] = first_worker + idlest;
+                moves.push(ShardMove {
+                    shard,
+                    from: first_worker + busiest,
+                    to: first_worker + idlest,
+                });
+            }
+        }
+        self.loads.fill(0);
+        self.counted = 0;
+        moves
+    }
+}
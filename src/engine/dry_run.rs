@@ -0,0 +1,154 @@
+// Copyright © 2026 Pathway
+
+//! Dry runs of a pipeline.
+//!
+//! In a dry run, the dataflow is built as in a regular run, so the types and the
+//! properties of the tables are validated as usual and an invalid pipeline fails with
+//! the same error. The connectors are set up too, which for most of them means
+//! connecting to the source or the destination and checking the credentials and the
+//! existence of the used objects. Then the dataflow is dropped without being run, so no
+//! data is read or written.
+//!
+//! The outcome of setting up each connector is gathered in a [`DryRunReport`]. A
+//! connector that can't be set up doesn't fail the dry run. An input connector is then
+//! replaced with an empty table, so that the rest of the pipeline is checked too.
+//!
+//! The report also contains the explain plan of the dataflow (see [`super::explain`]),
+//! as built by the first worker. When only the plan is needed, the connectors aren't set
+//! up at all: each reader is replaced with a [`PlannedReader`], telling the properties
+//! of the reader the dataflow depends on, and no writer is created.
+
+use std::borrow::Cow;
+use std::io;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use serde_json::{json, Value as JsonValue};
+
+use super::explain::OperatorPlan;
+use crate::connectors::data_storage::{ReadError, Reader, ReaderBuilder, StorageType};
+use crate::persistence::UniqueName;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectorDirection {
+    Input,
+    Output,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ConnectorCheck {
+    pub worker_index: usize,
+    pub direction: ConnectorDirection,
+    pub name: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct DryRunReport {
+    skips_connectors: bool,
+    connectors: Mutex<Vec<ConnectorCheck>>,
+    operators: Mutex<Vec<OperatorPlan>>,
+}
+
+impl DryRunReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a report of a dry run that only builds the explain plan, without setting
+    /// up the connectors.
+    pub fn without_connectors() -> Self {
+        Self {
+            skips_connectors: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn skips_connectors(&self) -> bool {
+        self.skips_connectors
+    }
+
+    pub fn record_connector(&self, check: ConnectorCheck) {
+        self.connectors.lock().unwrap().push(check);
+    }
+
+    /// Returns the checks of the connectors ordered by worker and, within a worker, in
+    /// the order in which the connectors were created.
+    pub fn connectors(&self) -> Vec<ConnectorCheck> {
+        let mut connectors = self.connectors.lock().unwrap().clone();
+        connectors.sort_by_key(|check| check.worker_index);
+        connectors
+    }
+
+    pub fn record_operators(&self, operators: Vec<OperatorPlan>) {
+        *self.operators.lock().unwrap() = operators;
+    }
+
+    pub fn operators(&self) -> Vec<OperatorPlan> {
+        self.operators.lock().unwrap().clone()
+    }
+
+    /// Returns whether all the connectors were set up successfully.
+    pub fn is_ok(&self) -> bool {
+        self.connectors
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|check| check.error.is_none())
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        json!({
+            "ok": self.is_ok(),
+            "connectors": self.connectors(),
+            "operators": self.operators(),
+        })
+    }
+}
+
+/// Stands for a reader in a dry run that doesn't set up the connectors. It can't be
+/// built.
+pub struct PlannedReader {
+    storage_type: StorageType,
+    is_internal: bool,
+    is_partition_aligned: bool,
+}
+
+impl PlannedReader {
+    pub fn new(storage_type: StorageType, is_internal: bool, is_partition_aligned: bool) -> Self {
+        Self {
+            storage_type,
+            is_internal,
+            is_partition_aligned,
+        }
+    }
+}
+
+impl ReaderBuilder for PlannedReader {
+    fn build(self: Box<Self>) -> Result<Box<dyn Reader>, ReadError> {
+        Err(ReadError::Io(io::Error::other(
+            "the readers are not set up when only the plan is built",
+        )))
+    }
+
+    fn is_internal(&self) -> bool {
+        self.is_internal
+    }
+
+    fn storage_type(&self) -> StorageType {
+        self.storage_type
+    }
+
+    fn short_description(&self) -> Cow<'static, str> {
+        format!("Planned({:?})", self.storage_type).into()
+    }
+
+    fn name(&self, unique_name: Option<&UniqueName>) -> String {
+        unique_name.map_or_else(|| format!("{:?}", self.storage_type), Clone::clone)
+    }
+
+    fn is_partition_aligned(&self) -> bool {
+        self.is_partition_aligned
+    }
+}
@@ -3,10 +3,15 @@
 use std::any::Any;
 use std::error;
 use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::result;
 
 use super::ColumnPath;
 use super::{Key, Value};
+use crate::connectors::sink_alignment::Error as SinkAlignmentError;
+use crate::connectors::sink_commit::Error as SinkCommitError;
 use crate::connectors::synchronization::Error as InputSynchronizationError;
 use crate::engine::license::Error as LicenseError;
 use crate::persistence::Error as PersistenceBackendError;
@@ -63,6 +68,18 @@ pub enum Error {
     #[error("it is not allowed to use ids when creating table properties")]
     IdInTableProperties,
 
+    #[error("queue_capacity must be a positive number")]
+    ZeroQueueCapacity,
+
+    #[error("queue_capacity can't be used with aligned or commit-grouped sinks")]
+    QueueCapacityWithSynchronizedSinks,
+
+    #[error("output alignment groups can't be used with more than one process")]
+    SinkAlignmentWithManyProcesses,
+
+    #[error("the step mode port {0} of process {1} is out of range")]
+    StepModePortOutOfRange(u16, usize),
+
     #[error("graph not in scope")]
     GraphNotInScope,
 
@@ -90,6 +107,9 @@ pub enum Error {
     #[error("different lengths of join condition")]
     DifferentJoinConditionLengths,
 
+    #[error("groupby with shard_by can't be used with operator persistence")]
+    ShardedGroupByWithOperatorPersistence,
+
     #[error("universe mismatch")]
     UniverseMismatch,
 
@@ -130,6 +150,21 @@ pub enum Error {
     #[error("computation of imported table failed")]
     ImportedTableFailed,
 
+    #[error("invalid table route {0:?}, it must be non-empty and must not contain '/'")]
+    InvalidTableRoute(String),
+
+    #[error("route {0:?} is already used by another table")]
+    TableRouteAlreadyUsed(String),
+
+    #[error("name {0:?} is already used by another table served over Arrow Flight")]
+    FlightTableNameAlreadyUsed(String),
+
+    #[error("cannot start the Arrow Flight server at {0}: {1}")]
+    FlightServerFailed(SocketAddr, #[source] io::Error),
+
+    #[error("cannot export the table to shared memory at {0:?}: {1}")]
+    SharedMemoryExportFailed(PathBuf, #[source] io::Error),
+
     #[error("operator_id not set")]
     OperatorIdNotSet,
 
@@ -145,6 +180,12 @@ pub enum Error {
     #[error("input synchronization failed: {0}")]
     InputSynchronization(#[from] InputSynchronizationError),
 
+    #[error("output alignment failed: {0}")]
+    SinkAlignment(#[from] SinkAlignmentError),
+
+    #[error("output commit failed: {0}")]
+    SinkCommit(#[from] SinkCommitError),
+
     #[error("indexing has failed: {0}")]
     Indexing(#[from] IndexingError),
 
@@ -299,9 +340,21 @@ pub enum DataError {
     #[error("index out of bounds")]
     IndexOutOfBounds,
 
+    #[error("key {0:?} not found in map")]
+    MissingMapKey(String),
+
     #[error("division by zero")]
     DivisionByZero,
 
+    #[error("integer overflow")]
+    IntegerOverflow,
+
+    #[error("invalid regular expression: {0}")]
+    InvalidRegex(String),
+
+    #[error("invalid JSONPath query: {0}")]
+    InvalidJsonPath(String),
+
     #[error("parse error: {0}")]
     ParseError(String),
 
@@ -360,9 +413,22 @@ pub enum DataError {
     #[error("Expected table to be append-only, but got diff={1} for key: {0}.")]
     AppendOnlyViolation(Key, isize),
 
+    #[error(
+        "Expected {expected_order} values, but got {current} after {previous} for key: {key}."
+    )]
+    OrderingViolation {
+        key: Key,
+        previous: Value,
+        current: Value,
+        expected_order: &'static str,
+    },
+
     #[error("Repeated entry in a batch.")]
     RepeatedEntryInBatch,
 
+    #[error("key collision: primary key {values} has the same id {key} as a different primary key read before")]
+    KeyCollision { key: Key, values: Value },
+
     #[error(transparent)]
     Other(DynError),
 }
@@ -0,0 +1,82 @@
+// Copyright © 2026 Pathway
+
+//! Explaining the cost of a pipeline before running it.
+//!
+//! While the dataflow is built, each operator of the pipeline records in an
+//! [`ExplainPlan`] what it is expected to cost when it runs:
+//! - whether it induces a data exchange, that is, its input is shuffled between the
+//!   workers, so each row may be sent to another worker or process;
+//! - whether it creates an arrangement, that is, it keeps an indexed copy of its input
+//!   in memory (an arrangement always requires an exchange);
+//! - whether its state is persisted, so it is written to the persistent storage and
+//!   restored on restart.
+//!
+//! The operators are identified by the ids set with
+//! [`Graph::set_operator_properties`](super::Graph::set_operator_properties). Nothing is
+//! recorded for the parts of the dataflow built without an operator id.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct OperatorPlan {
+    pub operator_id: usize,
+    pub exchange: bool,
+    pub arrangement: bool,
+    pub persisted: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct ExplainPlan {
+    operators: BTreeMap<usize, OperatorPlan>,
+}
+
+impl ExplainPlan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn operator(&mut self, operator_id: usize) -> &mut OperatorPlan {
+        self.operators
+            .entry(operator_id)
+            .or_insert_with(|| OperatorPlan {
+                operator_id,
+                ..Default::default()
+            })
+    }
+
+    /// Records an operator, so that it is reported even if it is cheap.
+    pub fn record_operator(&mut self, operator_id: usize) {
+        self.operator(operator_id);
+    }
+
+    pub fn record_exchange(&mut self, operator_id: usize) {
+        self.operator(operator_id).exchange = true;
+    }
+
+    pub fn record_arrangement(&mut self, operator_id: usize) {
+        let operator = self.operator(operator_id);
+        operator.exchange = true;
+        operator.arrangement = true;
+    }
+
+    pub fn record_persistence(&mut self, operator_id: usize) {
+        self.operator(operator_id).persisted = true;
+    }
+
+    /// Adds the operators recorded in `other`, e.g. in the body of an iteration.
+    pub fn merge(&mut self, other: &ExplainPlan) {
+        for plan in other.operators.values() {
+            let operator = self.operator(plan.operator_id);
+            operator.exchange |= plan.exchange;
+            operator.arrangement |= plan.arrangement;
+            operator.persisted |= plan.persisted;
+        }
+    }
+
+    /// Returns the plans of all recorded operators, ordered by their ids.
+    pub fn operators(&self) -> Vec<OperatorPlan> {
+        self.operators.values().copied().collect()
+    }
+}
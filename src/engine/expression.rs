@@ -5,24 +5,36 @@
 use arcstr::ArcStr;
 use log::warn;
 use ndarray::{ArrayD, Axis, LinalgScalar};
-use num_integer::Integer;
 use ordered_float::OrderedFloat;
+use regex::Regex;
+use serde_json_path::JsonPath;
+use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::ops::{Deref, Range};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::vec::IntoIter;
 
 use derivative::Derivative;
 use itertools::Itertools;
 use smallvec::SmallVec;
 
+use super::columnar::ColumnarBatch;
 use super::error::{DataError, DynError, DynResult};
-use super::time::{DateTime, DateTimeNaive, DateTimeUtc, Duration};
+use super::time::{DateTime, DateTimeNaive, DateTimeUtc, DateTimeZoned, Duration};
 use super::value::Kind;
 use super::{Key, Type, Value};
 use crate::engine::ShardPolicy;
 use crate::mat_mul::mat_mul;
 
+pub mod digest;
+pub mod encoding;
+pub mod ip;
+pub mod string_similarity;
+pub mod url;
+mod vectorized;
+use vectorized::Arguments;
+
 #[derive(Debug)]
 pub enum Expressions {
     Explicit(SmallVec<[Arc<Expression>; 2]>),
@@ -133,6 +145,26 @@ pub enum AnyExpression {
     CastToOptionalFloatFromOptionalInt(Arc<Expression>),
     MatMul(Arc<Expression>, Arc<Expression>),
     FillError(Arc<Expression>, Arc<Expression>),
+    MapGetItemChecked(Arc<Expression>, Arc<Expression>, Arc<Expression>),
+    MapGetItemUnchecked(Arc<Expression>, Arc<Expression>),
+    MapKeys(Arc<Expression>),
+    MapValues(Arc<Expression>),
+    MapItems(Arc<Expression>),
+    RegexExtract(
+        Arc<Expression>,
+        Arc<Expression>,
+        Arc<Expression>,
+        RegexCache,
+    ),
+    RegexSplit(Arc<Expression>, Arc<Expression>, RegexCache),
+    JsonQuery(Arc<Expression>, Arc<Expression>, JsonPathCache),
+    Base64Decode(Arc<Expression>),
+    HexDecode(Arc<Expression>),
+    Digest(Arc<Expression>, Arc<Expression>),
+    ParseIpAddress(Arc<Expression>, bool),
+    IpAddressVersion(Arc<Expression>),
+    UrlPart(Arc<Expression>, url::UrlPart),
+    UrlQueryParam(Arc<Expression>, Arc<Expression>),
 }
 
 #[derive(Debug)]
@@ -202,6 +234,9 @@ pub enum BoolExpression {
     CastFromFloat(Arc<Expression>),
     CastFromInt(Arc<Expression>),
     CastFromString(Arc<Expression>),
+    MapContains(Arc<Expression>, Arc<Expression>),
+    RegexMatch(Arc<Expression>, Arc<Expression>, RegexCache),
+    IpInNetwork(Arc<Expression>, Arc<Expression>),
 }
 
 #[derive(Debug)]
@@ -231,6 +266,9 @@ pub enum IntExpression {
     DateTimeNaiveYear(Arc<Expression>),
     DateTimeNaiveTimestampNs(Arc<Expression>),
     DateTimeNaiveWeekday(Arc<Expression>),
+    DateTimeNaiveQuarter(Arc<Expression>),
+    DateTimeNaiveDayOfYear(Arc<Expression>),
+    DateTimeNaiveIsoWeek(Arc<Expression>),
     DateTimeUtcNanosecond(Arc<Expression>),
     DateTimeUtcMicrosecond(Arc<Expression>),
     DateTimeUtcMillisecond(Arc<Expression>),
@@ -242,6 +280,9 @@ pub enum IntExpression {
     DateTimeUtcYear(Arc<Expression>),
     DateTimeUtcTimestampNs(Arc<Expression>),
     DateTimeUtcWeekday(Arc<Expression>),
+    DateTimeUtcQuarter(Arc<Expression>),
+    DateTimeUtcDayOfYear(Arc<Expression>),
+    DateTimeUtcIsoWeek(Arc<Expression>),
     DurationFloorDiv(Arc<Expression>, Arc<Expression>),
     DurationNanoseconds(Arc<Expression>),
     DurationMicroseconds(Arc<Expression>),
@@ -254,6 +295,7 @@ pub enum IntExpression {
     CastFromBool(Arc<Expression>),
     CastFromFloat(Arc<Expression>),
     CastFromString(Arc<Expression>),
+    Levenshtein(Arc<Expression>, Arc<Expression>),
 }
 
 #[derive(Debug)]
@@ -275,6 +317,8 @@ pub enum FloatExpression {
     CastFromBool(Arc<Expression>),
     CastFromInt(Arc<Expression>),
     CastFromString(Arc<Expression>),
+    JaroWinkler(Arc<Expression>, Arc<Expression>),
+    TrigramSimilarity(Arc<Expression>, Arc<Expression>),
 }
 
 #[derive(Debug)]
@@ -286,7 +330,22 @@ pub enum StringExpression {
     CastFromInt(Arc<Expression>),
     DateTimeNaiveStrftime(Arc<Expression>, Arc<Expression>),
     DateTimeUtcStrftime(Arc<Expression>, Arc<Expression>),
+    DateTimeZonedStrftime(Arc<Expression>, Arc<Expression>),
+    DateTimeZonedTimezone(Arc<Expression>),
+    DurationFormat(Arc<Expression>, Arc<Expression>),
     ToString(Arc<Expression>),
+    RegexReplace(
+        Arc<Expression>,
+        Arc<Expression>,
+        Arc<Expression>,
+        RegexCache,
+    ),
+    Base64Encode(Arc<Expression>),
+    HexEncode(Arc<Expression>),
+    UrlEncode(Arc<Expression>),
+    UrlDecode(Arc<Expression>),
+    HexDigest(Arc<Expression>, Arc<Expression>),
+    IpNetwork(Arc<Expression>, Arc<Expression>),
 }
 
 #[derive(Debug)]
@@ -303,8 +362,11 @@ pub enum DateTimeNaiveExpression {
     FromUtc(Arc<Expression>, Arc<Expression>),
     Round(Arc<Expression>, Arc<Expression>),
     Floor(Arc<Expression>, Arc<Expression>),
+    TruncateToUnit(Arc<Expression>, Arc<Expression>),
+    AddCalendarUnits(Arc<Expression>, Arc<Expression>, Arc<Expression>),
     FromTimestamp(Arc<Expression>, Arc<Expression>),
     FromFloatTimestamp(Arc<Expression>, Arc<Expression>),
+    FromZoned(Arc<Expression>),
 }
 
 #[derive(Debug)]
@@ -315,6 +377,18 @@ pub enum DateTimeUtcExpression {
     FromNaive(Arc<Expression>, Arc<Expression>),
     Round(Arc<Expression>, Arc<Expression>),
     Floor(Arc<Expression>, Arc<Expression>),
+    TruncateToUnit(Arc<Expression>, Arc<Expression>),
+    AddCalendarUnits(Arc<Expression>, Arc<Expression>, Arc<Expression>),
+    FromZoned(Arc<Expression>),
+}
+
+#[derive(Debug)]
+pub enum DateTimeZonedExpression {
+    AddDuration(Arc<Expression>, Arc<Expression>),
+    SubDuration(Arc<Expression>, Arc<Expression>),
+    FromUtc(Arc<Expression>, Arc<Expression>),
+    FromNaive(Arc<Expression>, Arc<Expression>),
+    ConvertTimezone(Arc<Expression>, Arc<Expression>),
 }
 
 #[derive(Debug)]
@@ -331,6 +405,7 @@ pub enum DurationExpression {
     Mod(Arc<Expression>, Arc<Expression>),
     DateTimeNaiveSub(Arc<Expression>, Arc<Expression>),
     DateTimeUtcSub(Arc<Expression>, Arc<Expression>),
+    DateTimeZonedSub(Arc<Expression>, Arc<Expression>),
 }
 
 #[derive(Derivative)]
@@ -343,6 +418,7 @@ pub enum Expression {
     String(StringExpression),
     DateTimeNaive(DateTimeNaiveExpression),
     DateTimeUtc(DateTimeUtcExpression),
+    DateTimeZoned(DateTimeZonedExpression),
     Duration(DurationExpression),
     Any(AnyExpression),
 }
@@ -405,6 +481,126 @@ fn get_json_item(value: &Value, index: Value) -> DynResult<Option<Value>> {
     Ok(json.map(|json| Value::from(json.clone())))
 }
 
+fn get_map_item(value: &Value, key: &str) -> DynResult<Option<Value>> {
+    if *value == Value::None {
+        return Ok(None);
+    }
+    Ok(value.as_map()?.get(key).cloned())
+}
+
+fn map_items(value: &Value) -> DynResult<Value> {
+    let items: Vec<_> = value
+        .as_map()?
+        .iter()
+        .map(|(key, value)| Value::from([Value::String(key.clone()), value.clone()].as_slice()))
+        .collect();
+    Ok(Value::from(items))
+}
+
+const PATTERN_CACHE_CAPACITY: usize = 64;
+
+/// Compiled patterns (regular expressions, JSONPath queries) of a single expression,
+/// keyed by their source text.
+///
+/// Patterns are usually constant, so each of them is compiled once and reused for
+/// all rows. Patterns computed from the data may be all different, so the cache is
+/// cleared when it reaches its capacity instead of growing without bounds.
+#[derive(Debug)]
+pub struct PatternCache<T> {
+    compiled: Mutex<HashMap<ArcStr, Arc<T>>>,
+}
+
+impl<T> Default for PatternCache<T> {
+    fn default() -> Self {
+        Self {
+            compiled: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T> PatternCache<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn get_or_compile(
+        &self,
+        pattern: &ArcStr,
+        compile: impl FnOnce(&str) -> Result<T, DataError>,
+    ) -> DynResult<Arc<T>> {
+        let mut compiled = self.compiled.lock().unwrap();
+        if let Some(entry) = compiled.get(pattern) {
+            return Ok(entry.clone());
+        }
+        let entry = Arc::new(compile(pattern.as_str())?);
+        if compiled.len() >= PATTERN_CACHE_CAPACITY {
+            compiled.clear();
+        }
+        compiled.insert(pattern.clone(), entry.clone());
+        Ok(entry)
+    }
+}
+
+pub type RegexCache = PatternCache<Regex>;
+pub type JsonPathCache = PatternCache<JsonPath>;
+
+impl PatternCache<Regex> {
+    pub fn get(&self, pattern: &ArcStr) -> DynResult<Arc<Regex>> {
+        self.get_or_compile(pattern, |pattern| {
+            Regex::new(pattern).map_err(|e| DataError::InvalidRegex(e.to_string()))
+        })
+    }
+}
+
+impl PatternCache<JsonPath> {
+    pub fn get(&self, path: &ArcStr) -> DynResult<Arc<JsonPath>> {
+        self.get_or_compile(path, |path| {
+            JsonPath::parse(path).map_err(|e| DataError::InvalidJsonPath(e.to_string()))
+        })
+    }
+}
+
+fn json_query(value: &Value, path: &JsonPath) -> DynResult<Value> {
+    if *value == Value::None {
+        return Ok(Value::None);
+    }
+    let matches: Vec<_> = path
+        .query(value.as_json()?)
+        .all()
+        .into_iter()
+        .map(|node| Value::from(node.clone()))
+        .collect();
+    Ok(Value::from(matches))
+}
+
+fn regex_extract(regex: &Regex, value: &str, group: i64) -> DynResult<Value> {
+    let group = usize::try_from(group)
+        .ok()
+        .filter(|group| *group < regex.captures_len())
+        .ok_or(DataError::IndexOutOfBounds)?;
+    Ok(regex
+        .captures(value)
+        .and_then(|captures| captures.get(group))
+        .map_or(Value::None, |matched| Value::from(matched.as_str())))
+}
+
+fn regex_split(regex: &Regex, value: &str) -> Value {
+    let parts: Vec<_> = regex.split(value).map(Value::from).collect();
+    Value::from(parts)
+}
+
+// the bytes of a string or of a bytes value, the ones the encoding expressions work on
+fn string_or_bytes(value: &Value) -> DynResult<&[u8]> {
+    match value {
+        Value::String(s) => Ok(s.as_bytes()),
+        Value::Bytes(b) => Ok(b),
+        _ => Err(DynError::from(DataError::TypeMismatch {
+            expected: "string or bytes",
+            value: value.clone(),
+        })),
+    }
+}
+
 fn mat_mul_wrapper<T>(lhs: &ArrayD<T>, rhs: &ArrayD<T>) -> DynResult<Value>
 where
     T: LinalgScalar,
@@ -494,6 +690,101 @@ fn unwrap(val: Value) -> DynResult<Value> {
     }
 }
 
+fn int_neg(v: i64) -> DynResult<i64> {
+    v.checked_neg()
+        .ok_or_else(|| DynError::from(DataError::IntegerOverflow))
+}
+
+fn int_abs(v: i64) -> DynResult<i64> {
+    v.checked_abs()
+        .ok_or_else(|| DynError::from(DataError::IntegerOverflow))
+}
+
+fn int_add(l: i64, r: i64) -> DynResult<i64> {
+    l.checked_add(r)
+        .ok_or_else(|| DynError::from(DataError::IntegerOverflow))
+}
+
+fn int_sub(l: i64, r: i64) -> DynResult<i64> {
+    l.checked_sub(r)
+        .ok_or_else(|| DynError::from(DataError::IntegerOverflow))
+}
+
+fn int_mul(l: i64, r: i64) -> DynResult<i64> {
+    l.checked_mul(r)
+        .ok_or_else(|| DynError::from(DataError::IntegerOverflow))
+}
+
+/// Divides with the quotient rounded towards negative infinity, as Python does, so
+/// that the remainder has the sign of the divisor. The quotient is `None` if it
+/// overflows, which happens only for `i64::MIN // -1`.
+fn int_div_mod_floor(l: i64, r: i64) -> DynResult<(Option<i64>, i64)> {
+    if r == 0 {
+        return Err(DynError::from(DataError::DivisionByZero));
+    }
+    // the remainder overflows only for `i64::MIN % -1`, which is 0
+    let remainder = l.checked_rem(r).unwrap_or(0);
+    let quotient = l.checked_div(r);
+    if remainder != 0 && (remainder < 0) != (r < 0) {
+        Ok((quotient.map(|quotient| quotient - 1), remainder + r))
+    } else {
+        Ok((quotient, remainder))
+    }
+}
+
+fn int_floor_div(l: i64, r: i64) -> DynResult<i64> {
+    int_div_mod_floor(l, r)?
+        .0
+        .ok_or_else(|| DynError::from(DataError::IntegerOverflow))
+}
+
+fn int_mod(l: i64, r: i64) -> DynResult<i64> {
+    Ok(int_div_mod_floor(l, r)?.1)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn int_true_div(l: i64, r: i64) -> DynResult<f64> {
+    if r == 0 {
+        Err(DynError::from(DataError::DivisionByZero))
+    } else {
+        Ok((l as f64) / (r as f64))
+    }
+}
+
+fn float_floor_div(l: f64, r: f64) -> DynResult<f64> {
+    if r == 0.0f64 {
+        Err(DynError::from(DataError::DivisionByZero))
+    } else {
+        Ok((l / r).floor())
+    }
+}
+
+fn float_true_div(l: f64, r: f64) -> DynResult<f64> {
+    if r == 0.0f64 {
+        Err(DynError::from(DataError::DivisionByZero))
+    } else {
+        Ok(l / r)
+    }
+}
+
+fn float_mod(l: f64, r: f64) -> DynResult<f64> {
+    /*
+    Implementation the same as the one in Cpython
+    https://github.com/python/cpython/blob/main/Objects/floatobject.c#L640
+    */
+    if r == 0.0f64 {
+        Err(DynError::from(DataError::DivisionByZero))
+    } else {
+        let mut modulo = l % r;
+        if modulo == 0.0f64 {
+            modulo = modulo.copysign(r);
+        } else if (r < 0.0f64) != (modulo < 0.0f64) {
+            modulo += r;
+        }
+        Ok(modulo)
+    }
+}
+
 fn nullary_expr<F, T>(values: &[&[Value]], f: &F) -> Vec<DynResult<T>>
 where
     F: Fn() -> T,
@@ -566,7 +857,7 @@ where
     Expression: EvalAs<T>,
     Expression: EvalAs<U>,
     Expression: EvalAs<V>,
-    F: Fn(T, U, V) -> DynResult<W> + 'static,
+    F: Fn(T, U, V) -> DynResult<W>,
 {
     let expr_1_values = expr_1.eval_as(values);
     let expr_2_values = expr_2.eval_as(values);
@@ -716,6 +1007,82 @@ impl AnyExpression {
                     Ok(get_json_item(&tuple, index)?.unwrap_or(default))
                 })
             }
+            Self::MapGetItemChecked(map, key, default) => ternary_expr_err(
+                map,
+                key,
+                default,
+                values,
+                |map: Value, key: ArcStr, default: Value| {
+                    Ok(get_map_item(&map, &key)?.unwrap_or(default))
+                },
+            ),
+            Self::MapGetItemUnchecked(map, key) => {
+                binary_expr_err(map, key, values, |map: Value, key: ArcStr| {
+                    get_map_item(&map, &key)?
+                        .ok_or_else(|| DynError::from(DataError::MissingMapKey(key.to_string())))
+                })
+            }
+            Self::MapKeys(map) => unary_expr_err(map, values, &|map: Value| {
+                let keys: Vec<_> = map.as_map()?.keys().cloned().map(Value::String).collect();
+                Ok(Value::from(keys))
+            }),
+            Self::MapValues(map) => unary_expr_err(map, values, &|map: Value| {
+                let map_values: Vec<_> = map.as_map()?.values().cloned().collect();
+                Ok(Value::from(map_values))
+            }),
+            Self::MapItems(map) => unary_expr_err(map, values, &|map: Value| map_items(&map)),
+            Self::RegexExtract(e, pattern, group, cache) => ternary_expr_err(
+                e,
+                pattern,
+                group,
+                values,
+                |e: ArcStr, pattern: ArcStr, group: i64| {
+                    regex_extract(&cache.get(&pattern)?, &e, group)
+                },
+            ),
+            Self::RegexSplit(e, pattern, cache) => {
+                binary_expr_err(e, pattern, values, |e: ArcStr, pattern: ArcStr| {
+                    Ok(regex_split(&cache.get(&pattern)?, &e))
+                })
+            }
+            Self::JsonQuery(json, path, cache) => {
+                binary_expr_err(json, path, values, |json: Value, path: ArcStr| {
+                    json_query(&json, &cache.get(&path)?)
+                })
+            }
+            Self::Base64Decode(e) => unary_expr_err(e, values, &|v: ArcStr| {
+                Ok(Value::from(encoding::base64_decode(&v)?.as_slice()))
+            }),
+            Self::HexDecode(e) => unary_expr_err(e, values, &|v: ArcStr| {
+                Ok(Value::from(encoding::hex_decode(&v)?.as_slice()))
+            }),
+            Self::ParseIpAddress(e, optional) => unary_expr_err(e, values, &|v: ArcStr| {
+                let parse_result =
+                    ip::canonical_address(&v).map(|address| Value::from(address.as_str()));
+                if *optional {
+                    Ok(parse_result.unwrap_or(Value::None))
+                } else {
+                    Ok(parse_result?)
+                }
+            }),
+            Self::IpAddressVersion(e) => unary_expr(e, values, |v: ArcStr| {
+                ip::address_version(&v).map_or(Value::None, Value::Int)
+            }),
+            Self::UrlPart(e, part) => {
+                unary_expr_err(e, values, &|v: ArcStr| Ok(url::url_part(&v, *part)?))
+            }
+            Self::UrlQueryParam(e, name) => {
+                binary_expr_err(e, name, values, |e: ArcStr, name: ArcStr| {
+                    let param = url::url_query_param(&e, &name)?;
+                    Ok(param.map_or(Value::None, |param| Value::from(param.as_str())))
+                })
+            }
+            Self::Digest(e, algorithm) => {
+                binary_expr_err(e, algorithm, values, |v: Value, algorithm: ArcStr| {
+                    let digest = digest::digest(string_or_bytes(&v)?, &algorithm)?;
+                    Ok(Value::from(digest.as_slice()))
+                })
+            }
             Self::ParseStringToInt(e, optional) => unary_expr_err(e, values, &|v: ArcStr| {
                 let parse_result = v.trim().parse().map(Value::Int);
                 if *optional {
@@ -885,6 +1252,9 @@ fn logical_operator(
 impl BoolExpression {
     #[allow(clippy::too_many_lines)]
     pub fn eval(&self, values: &[&[Value]]) -> Vec<DynResult<bool>> {
+        if let Some(column) = vectorized::eval_bool(self, &Arguments::Rows(values)) {
+            return column.into_results();
+        }
         match self {
             Self::Const(c) => nullary_expr(values, &|| *c),
             Self::IsNone(e) => unary_expr(e, values, |v| matches!(v, Value::None)),
@@ -1045,33 +1415,39 @@ impl BoolExpression {
             Self::CastFromInt(e) => unary_expr(e, values, |v: i64| v != 0),
             Self::CastFromFloat(e) => unary_expr(e, values, |v: f64| v != 0.0),
             Self::CastFromString(e) => unary_expr(e, values, |v: ArcStr| !v.is_empty()),
+            Self::MapContains(map, key) => {
+                binary_expr_err(map, key, values, |map: Value, key: ArcStr| {
+                    Ok(get_map_item(&map, &key)?.is_some())
+                })
+            }
+            Self::RegexMatch(e, pattern, cache) => {
+                binary_expr_err(e, pattern, values, |e: ArcStr, pattern: ArcStr| {
+                    Ok(cache.get(&pattern)?.is_match(&e))
+                })
+            }
+            Self::IpInNetwork(e, network) => {
+                binary_expr_err(e, network, values, |e: ArcStr, network: ArcStr| {
+                    Ok(ip::is_in_network(&e, &network)?)
+                })
+            }
         }
     }
 }
 
 impl IntExpression {
     pub fn eval(&self, values: &[&[Value]]) -> Vec<DynResult<i64>> {
+        if let Some(column) = vectorized::eval_int(self, &Arguments::Rows(values)) {
+            return column.into_results();
+        }
         match self {
             Self::Const(c) => nullary_expr(values, &|| *c),
-            Self::Neg(e) => unary_expr(e, values, |v: i64| -v),
-            Self::Abs(e) => unary_expr(e, values, |v: i64| v.abs()),
-            Self::Add(lhs, rhs) => binary_expr(lhs, rhs, values, |l: i64, r: i64| l + r),
-            Self::Sub(lhs, rhs) => binary_expr(lhs, rhs, values, |l: i64, r: i64| l - r),
-            Self::Mul(lhs, rhs) => binary_expr(lhs, rhs, values, |l: i64, r: i64| l * r),
-            Self::FloorDiv(lhs, rhs) => binary_expr_err(lhs, rhs, values, |l: i64, r: i64| {
-                if r == 0 {
-                    Err(DynError::from(DataError::DivisionByZero))
-                } else {
-                    Ok(Integer::div_floor(&l, &r))
-                }
-            }),
-            Self::Mod(lhs, rhs) => binary_expr_err(lhs, rhs, values, |l: i64, r: i64| {
-                if r == 0 {
-                    Err(DynError::from(DataError::DivisionByZero))
-                } else {
-                    Ok(Integer::mod_floor(&l, &r))
-                }
-            }),
+            Self::Neg(e) => unary_expr_err(e, values, &int_neg),
+            Self::Abs(e) => unary_expr_err(e, values, &int_abs),
+            Self::Add(lhs, rhs) => binary_expr_err(lhs, rhs, values, int_add),
+            Self::Sub(lhs, rhs) => binary_expr_err(lhs, rhs, values, int_sub),
+            Self::Mul(lhs, rhs) => binary_expr_err(lhs, rhs, values, int_mul),
+            Self::FloorDiv(lhs, rhs) => binary_expr_err(lhs, rhs, values, int_floor_div),
+            Self::Mod(lhs, rhs) => binary_expr_err(lhs, rhs, values, int_mod),
             #[allow(clippy::cast_possible_truncation)]
             #[allow(clippy::cast_sign_loss)]
             Self::Pow(lhs, rhs) => binary_expr(lhs, rhs, values, |l: i64, r: i64| l.pow(r as u32)),
@@ -1099,6 +1475,11 @@ impl IntExpression {
                 unary_expr(e, values, |v: DateTimeNaive| v.timestamp())
             }
             Self::DateTimeNaiveWeekday(e) => unary_expr(e, values, |v: DateTimeNaive| v.weekday()),
+            Self::DateTimeNaiveQuarter(e) => unary_expr(e, values, |v: DateTimeNaive| v.quarter()),
+            Self::DateTimeNaiveDayOfYear(e) => {
+                unary_expr(e, values, |v: DateTimeNaive| v.day_of_year())
+            }
+            Self::DateTimeNaiveIsoWeek(e) => unary_expr(e, values, |v: DateTimeNaive| v.iso_week()),
             Self::DateTimeUtcNanosecond(e) => {
                 unary_expr(e, values, |v: DateTimeUtc| v.nanosecond())
             }
@@ -1118,6 +1499,11 @@ impl IntExpression {
                 unary_expr(e, values, |v: DateTimeUtc| v.timestamp())
             }
             Self::DateTimeUtcWeekday(e) => unary_expr(e, values, |v: DateTimeUtc| v.weekday()),
+            Self::DateTimeUtcQuarter(e) => unary_expr(e, values, |v: DateTimeUtc| v.quarter()),
+            Self::DateTimeUtcDayOfYear(e) => {
+                unary_expr(e, values, |v: DateTimeUtc| v.day_of_year())
+            }
+            Self::DateTimeUtcIsoWeek(e) => unary_expr(e, values, |v: DateTimeUtc| v.iso_week()),
             Self::DurationFloorDiv(lhs, rhs) => {
                 binary_expr_err(lhs, rhs, values, |l: Duration, r: Duration| {
                     if r.is_zero() {
@@ -1145,12 +1531,19 @@ impl IntExpression {
                     )))
                 })
             }),
+            #[allow(clippy::cast_possible_wrap)]
+            Self::Levenshtein(lhs, rhs) => binary_expr(lhs, rhs, values, |l: ArcStr, r: ArcStr| {
+                string_similarity::levenshtein(&l, &r) as i64
+            }),
         }
     }
 }
 
 impl FloatExpression {
     pub fn eval(&self, values: &[&[Value]]) -> Vec<DynResult<f64>> {
+        if let Some(column) = vectorized::eval_float(self, &Arguments::Rows(values)) {
+            return column.into_results();
+        }
         match self {
             Self::Const(c) => nullary_expr(values, &|| *c),
             Self::Neg(e) => unary_expr(e, values, |v: f64| -v),
@@ -1158,39 +1551,9 @@ impl FloatExpression {
             Self::Add(lhs, rhs) => binary_expr(lhs, rhs, values, |l: f64, r: f64| l + r),
             Self::Sub(lhs, rhs) => binary_expr(lhs, rhs, values, |l: f64, r: f64| l - r),
             Self::Mul(lhs, rhs) => binary_expr(lhs, rhs, values, |l: f64, r: f64| l * r),
-            Self::FloorDiv(lhs, rhs) => binary_expr_err(lhs, rhs, values, |l: f64, r: f64| {
-                if r == 0.0f64 {
-                    Err(DynError::from(DataError::DivisionByZero))
-                } else {
-                    Ok((l / r).floor())
-                }
-            }),
-            Self::TrueDiv(lhs, rhs) => binary_expr_err(lhs, rhs, values, |l: f64, r: f64| {
-                if r == 0.0f64 {
-                    Err(DynError::from(DataError::DivisionByZero))
-                } else {
-                    Ok(l / r)
-                }
-            }),
-            Self::Mod(lhs, rhs) => {
-                binary_expr_err(lhs, rhs, values, |l: f64, r: f64| {
-                    /*
-                    Implementation the same as the one in Cpython
-                    https://github.com/python/cpython/blob/main/Objects/floatobject.c#L640
-                    */
-                    if r == 0.0f64 {
-                        Err(DynError::from(DataError::DivisionByZero))
-                    } else {
-                        let mut modulo = l % r;
-                        if modulo == 0.0f64 {
-                            modulo = modulo.copysign(r);
-                        } else if (r < 0.0f64) != (modulo < 0.0f64) {
-                            modulo += r;
-                        }
-                        Ok(modulo)
-                    }
-                })
-            }
+            Self::FloorDiv(lhs, rhs) => binary_expr_err(lhs, rhs, values, float_floor_div),
+            Self::TrueDiv(lhs, rhs) => binary_expr_err(lhs, rhs, values, float_true_div),
+            Self::Mod(lhs, rhs) => binary_expr_err(lhs, rhs, values, float_mod),
             Self::Pow(lhs, rhs) => binary_expr(lhs, rhs, values, |l: f64, r: f64| {
                 let result = l.powf(r);
                 if result.is_infinite() {
@@ -1198,14 +1561,7 @@ impl FloatExpression {
                 }
                 result
             }),
-            #[allow(clippy::cast_precision_loss)]
-            Self::IntTrueDiv(lhs, rhs) => binary_expr_err(lhs, rhs, values, |l: i64, r: i64| {
-                if r == 0 {
-                    Err(DynError::from(DataError::DivisionByZero))
-                } else {
-                    Ok((l as f64) / (r as f64))
-                }
-            }),
+            Self::IntTrueDiv(lhs, rhs) => binary_expr_err(lhs, rhs, values, int_true_div),
             Self::DateTimeNaiveTimestamp(e, unit) => {
                 binary_expr_err(e, unit, values, |e: DateTimeNaive, unit: ArcStr| {
                     Ok(e.timestamp_in_unit(&unit)?)
@@ -1235,6 +1591,14 @@ impl FloatExpression {
                     )))
                 })
             }),
+            Self::JaroWinkler(lhs, rhs) => binary_expr(lhs, rhs, values, |l: ArcStr, r: ArcStr| {
+                string_similarity::jaro_winkler(&l, &r)
+            }),
+            Self::TrigramSimilarity(lhs, rhs) => {
+                binary_expr(lhs, rhs, values, |l: ArcStr, r: ArcStr| {
+                    string_similarity::trigram_similarity(&l, &r)
+                })
+            }
         }
     }
 }
@@ -1298,10 +1662,59 @@ impl StringExpression {
                     ArcStr::from(e.strftime(&fmt))
                 })
             }
+            Self::DateTimeZonedStrftime(e, fmt) => {
+                binary_expr(e, fmt, values, |e: DateTimeZoned, fmt: ArcStr| {
+                    ArcStr::from(e.strftime(&fmt))
+                })
+            }
+            Self::DateTimeZonedTimezone(e) => unary_expr(e, values, |e: DateTimeZoned| {
+                ArcStr::from(e.timezone_name())
+            }),
+            Self::DurationFormat(e, fmt) => {
+                binary_expr_err(e, fmt, values, |e: Duration, fmt: ArcStr| {
+                    Ok(ArcStr::from(e.format(&fmt)?))
+                })
+            }
             Self::ToString(e) => unary_expr(e, values, |v| match v {
                 Value::String(s) => s,
                 v => v.to_string().into(),
             }),
+            Self::RegexReplace(e, pattern, replacement, cache) => ternary_expr_err(
+                e,
+                pattern,
+                replacement,
+                values,
+                |e: ArcStr, pattern: ArcStr, replacement: ArcStr| {
+                    let regex = cache.get(&pattern)?;
+                    Ok(match regex.replace_all(&e, replacement.as_str()) {
+                        Cow::Borrowed(_) => e.clone(),
+                        Cow::Owned(replaced) => ArcStr::from(replaced),
+                    })
+                },
+            ),
+            Self::Base64Encode(e) => unary_expr_err(e, values, &|v: Value| {
+                Ok(encoding::base64_encode(string_or_bytes(&v)?).into())
+            }),
+            Self::HexEncode(e) => unary_expr_err(e, values, &|v: Value| {
+                Ok(encoding::hex_encode(string_or_bytes(&v)?).into())
+            }),
+            Self::UrlEncode(e) => unary_expr_err(e, values, &|v: Value| {
+                Ok(encoding::url_encode(string_or_bytes(&v)?).into())
+            }),
+            Self::UrlDecode(e) => {
+                unary_expr_err(e, values, &|v: ArcStr| Ok(encoding::url_decode(&v)?.into()))
+            }
+            Self::IpNetwork(e, prefix_len) => {
+                binary_expr_err(e, prefix_len, values, |e: ArcStr, prefix_len: i64| {
+                    Ok(ip::network_of(&e, prefix_len)?.into())
+                })
+            }
+            Self::HexDigest(e, algorithm) => {
+                binary_expr_err(e, algorithm, values, |v: Value, algorithm: ArcStr| {
+                    let digest = digest::digest(string_or_bytes(&v)?, &algorithm)?;
+                    Ok(encoding::hex_encode(&digest).into())
+                })
+            }
         }
     }
 }
@@ -1336,6 +1749,20 @@ impl DateTimeNaiveExpression {
                 values,
                 |expr: DateTimeNaive, duration: Duration| expr.truncate(duration),
             ),
+            Self::TruncateToUnit(expr, unit) => {
+                binary_expr_err(expr, unit, values, |expr: DateTimeNaive, unit: ArcStr| {
+                    Ok(expr.truncate_to_unit(&unit)?)
+                })
+            }
+            Self::AddCalendarUnits(expr, amount, unit) => ternary_expr_err(
+                expr,
+                amount,
+                unit,
+                values,
+                |expr: DateTimeNaive, amount: i64, unit: ArcStr| {
+                    Ok(expr.add_calendar_units(amount, &unit)?)
+                },
+            ),
             Self::FromTimestamp(expr, unit) => {
                 binary_expr_err(expr, unit, values, |expr: i64, unit: ArcStr| {
                     Ok(DateTimeNaive::from_timestamp(expr, &unit)?)
@@ -1346,6 +1773,9 @@ impl DateTimeNaiveExpression {
                     Ok(DateTimeNaive::from_timestamp_f64(expr, &unit)?)
                 })
             }
+            Self::FromZoned(expr) => unary_expr_err(expr, values, &|expr: DateTimeZoned| {
+                Ok(expr.to_naive_local()?)
+            }),
         }
     }
 }
@@ -1382,6 +1812,54 @@ impl DateTimeUtcExpression {
                 values,
                 |expr: DateTimeUtc, duration: Duration| expr.truncate(duration),
             ),
+            Self::TruncateToUnit(expr, unit) => {
+                binary_expr_err(expr, unit, values, |expr: DateTimeUtc, unit: ArcStr| {
+                    Ok(expr.truncate_to_unit(&unit)?)
+                })
+            }
+            Self::AddCalendarUnits(expr, amount, unit) => ternary_expr_err(
+                expr,
+                amount,
+                unit,
+                values,
+                |expr: DateTimeUtc, amount: i64, unit: ArcStr| {
+                    Ok(expr.add_calendar_units(amount, &unit)?)
+                },
+            ),
+            Self::FromZoned(expr) => unary_expr(expr, values, |expr: DateTimeZoned| expr.to_utc()),
+        }
+    }
+}
+
+impl DateTimeZonedExpression {
+    pub fn eval(&self, values: &[&[Value]]) -> Vec<DynResult<DateTimeZoned>> {
+        match self {
+            Self::AddDuration(lhs, rhs) => {
+                binary_expr(lhs, rhs, values, |l: DateTimeZoned, r: Duration| l + r)
+            }
+            Self::SubDuration(lhs, rhs) => {
+                binary_expr(lhs, rhs, values, |l: DateTimeZoned, r: Duration| l - r)
+            }
+            Self::FromUtc(expr, timezone) => binary_expr_err(
+                expr,
+                timezone,
+                values,
+                |expr: DateTimeUtc, timezone: ArcStr| Ok(DateTimeZoned::from_utc(expr, &timezone)?),
+            ),
+            Self::FromNaive(expr, timezone) => binary_expr_err(
+                expr,
+                timezone,
+                values,
+                |expr: DateTimeNaive, timezone: ArcStr| {
+                    Ok(DateTimeZoned::from_naive(expr, &timezone)?)
+                },
+            ),
+            Self::ConvertTimezone(expr, timezone) => binary_expr_err(
+                expr,
+                timezone,
+                values,
+                |expr: DateTimeZoned, timezone: ArcStr| Ok(expr.with_timezone(&timezone)?),
+            ),
         }
     }
 }
@@ -1439,6 +1917,9 @@ impl DurationExpression {
             Self::DateTimeUtcSub(lhs, rhs) => {
                 binary_expr(lhs, rhs, values, |l: DateTimeUtc, r: DateTimeUtc| l - r)
             }
+            Self::DateTimeZonedSub(lhs, rhs) => {
+                binary_expr(lhs, rhs, values, |l: DateTimeZoned, r: DateTimeZoned| l - r)
+            }
         }
     }
 }
@@ -1448,6 +1929,36 @@ impl Expression {
         self.eval_as(values)
     }
 
+    /// Evaluates the expression on a columnar batch. The results are the same as the
+    /// ones of [`Expression::eval`] on the rows of the batch.
+    pub fn eval_columnar(&self, batch: &ColumnarBatch) -> Vec<DynResult<Value>> {
+        let args = Arguments::Columnar(batch);
+        let results = match self {
+            Self::Int(expression) => {
+                vectorized::eval_int(expression, &args).map(vectorized::Column::into_values)
+            }
+            Self::Float(expression) => {
+                vectorized::eval_float(expression, &args).map(vectorized::Column::into_values)
+            }
+            Self::Bool(expression) => {
+                vectorized::eval_bool(expression, &args).map(vectorized::Column::into_values)
+            }
+            Self::Any(AnyExpression::Argument(index)) if *index < batch.width() => Some(
+                (0..batch.len())
+                    .map(|row| batch.value(row, *index).into_result())
+                    .collect(),
+            ),
+            _ => None,
+        };
+        results.unwrap_or_else(|| args.with_rows(|values| self.eval(values)))
+    }
+
+    /// Tells if evaluating the expression on a [`ColumnarBatch`] is faster than
+    /// evaluating it on rows.
+    pub fn benefits_from_columnar(&self) -> bool {
+        vectorized::is_supported(self)
+    }
+
     #[cold]
     #[inline(never)]
     fn type_error(&self, expected: &'static str) -> DynError {
@@ -1459,6 +1970,7 @@ impl Expression {
             Self::String(_) => "string",
             Self::DateTimeNaive(_) => "DateTimeNaive",
             Self::DateTimeUtc(_) => "DateTimeUtc",
+            Self::DateTimeZoned(_) => "DateTimeZoned",
             Self::Duration(_) => "Duration",
             Self::Any(_) => "unknown type",
         };
@@ -1489,6 +2001,7 @@ impl EvalAs<Value> for Expression {
             Self::String(_) => unary_expr(self, values, |v: ArcStr| Value::from(v)),
             Self::DateTimeNaive(_) => unary_expr(self, values, |v: DateTimeNaive| Value::from(v)),
             Self::DateTimeUtc(_) => unary_expr(self, values, |v: DateTimeUtc| Value::from(v)),
+            Self::DateTimeZoned(_) => unary_expr(self, values, |v: DateTimeZoned| Value::from(v)),
             Self::Duration(_) => unary_expr(self, values, |v: Duration| Value::from(v)),
             Self::Any(expr) => expr.eval(values),
         }
@@ -1583,6 +2096,19 @@ impl EvalAs<DateTimeUtc> for Expression {
     }
 }
 
+impl EvalAs<DateTimeZoned> for Expression {
+    fn eval_as(&self, values: &[&[Value]]) -> Vec<DynResult<DateTimeZoned>> {
+        match self {
+            Self::DateTimeZoned(expr) => expr.eval(values),
+            Self::Any(_) => unary_expr_err(self, values, &|v: Value| v.as_date_time_zoned()),
+            _ => values
+                .iter()
+                .map(|_| Err(self.type_error("DateTimeZoned")))
+                .collect(),
+        }
+    }
+}
+
 impl EvalAs<Duration> for Expression {
     fn eval_as(&self, values: &[&[Value]]) -> Vec<DynResult<Duration>> {
         match self {
@@ -1650,6 +2176,12 @@ impl From<DateTimeUtcExpression> for Expression {
     }
 }
 
+impl From<DateTimeZonedExpression> for Expression {
+    fn from(expr: DateTimeZonedExpression) -> Self {
+        Self::DateTimeZoned(expr)
+    }
+}
+
 impl From<DurationExpression> for Expression {
     fn from(expr: DurationExpression) -> Self {
         Self::Duration(expr)
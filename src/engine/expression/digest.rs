@@ -0,0 +1,29 @@
+// Copyright © 2026 Pathway
+
+//! Digests of strings and bytes, used for the pseudonymization of identifiers and for
+//! bucketing.
+//!
+//! Only `sha256` and `blake3` are cryptographic hashes. `md5` is there for the
+//! compatibility with the existing systems and `xxh3`, the 64-bit XXH3, is the fastest
+//! one, suitable for bucketing but not for hiding the hashed values.
+
+use md5::{Digest, Md5};
+use sha2::Sha256;
+use xxhash_rust::xxh3::xxh3_64;
+
+use crate::engine::error::DataError;
+
+/// Computes the digest of `value` with the hash function named `algorithm`, one of
+/// `"sha256"`, `"md5"`, `"blake3"` and `"xxh3"`. The XXH3 hash is returned as eight
+/// big-endian bytes, as in its canonical representation.
+pub fn digest(value: &[u8], algorithm: &str) -> Result<Vec<u8>, DataError> {
+    match algorithm {
+        "sha256" => Ok(Sha256::digest(value).to_vec()),
+        "md5" => Ok(Md5::digest(value).to_vec()),
+        "blake3" => Ok(blake3::hash(value).as_bytes().to_vec()),
+        "xxh3" => Ok(xxh3_64(value).to_be_bytes().to_vec()),
+        _ => Err(DataError::ValueError(format!(
+            "unknown hash algorithm {algorithm:?}, it has to be one of sha256, md5, blake3 and xxh3"
+        ))),
+    }
+}
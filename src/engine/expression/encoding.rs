@@ -0,0 +1,67 @@
+// Copyright © 2026 Pathway
+
+//! Textual encodings of binary data: base64, hex and the percent-encoding of URLs.
+
+use std::fmt::Write;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+
+use crate::engine::error::DataError;
+
+pub fn base64_encode(value: &[u8]) -> String {
+    BASE64_STANDARD.encode(value)
+}
+
+/// Decodes the standard, padded base64.
+pub fn base64_decode(value: &str) -> Result<Vec<u8>, DataError> {
+    BASE64_STANDARD
+        .decode(value)
+        .map_err(|e| DataError::ValueError(format!("cannot decode {value:?} as base64: {e}")))
+}
+
+/// Encodes the bytes as lowercase hex digits.
+pub fn hex_encode(value: &[u8]) -> String {
+    hex::encode(value)
+}
+
+/// Decodes the hex digits of either case.
+pub fn hex_decode(value: &str) -> Result<Vec<u8>, DataError> {
+    hex::decode(value)
+        .map_err(|e| DataError::ValueError(format!("cannot decode {value:?} as hex: {e}")))
+}
+
+/// Percent-encodes all the bytes but the unreserved characters of RFC 3986, i.e. ASCII
+/// letters, digits, `-`, `.`, `_` and `~`, so that the result can be used in any part
+/// of a URL.
+pub fn url_encode(value: &[u8]) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(*byte));
+        } else {
+            write!(encoded, "%{byte:02X}").expect("writing to a string should not fail");
+        }
+    }
+    encoded
+}
+
+/// Decodes the percent-encoded bytes, which have to form a valid UTF-8 string. A `+` is
+/// left as is, as in the paths of URLs.
+pub fn url_decode(value: &str) -> Result<String, DataError> {
+    let error =
+        |reason: &str| DataError::ValueError(format!("cannot decode {value:?} as a URL: {reason}"));
+    let mut decoded = Vec::with_capacity(value.len());
+    let mut bytes = value.bytes();
+    while let Some(byte) = bytes.next() {
+        if byte != b'%' {
+            decoded.push(byte);
+            continue;
+        }
+        let mut digit = || char::from(bytes.next()?).to_digit(16);
+        let escaped = digit().zip(digit()).map(|(high, low)| high * 16 + low);
+        let escaped = escaped.ok_or_else(|| error("invalid percent-encoded byte"))?;
+        decoded.push(u8::try_from(escaped).expect("two hex digits should fit in a byte"));
+    }
+    String::from_utf8(decoded).map_err(|_| error("the decoded bytes are not valid UTF-8"))
+}
@@ -0,0 +1,104 @@
+// Copyright © 2026 Pathway
+
+//! IPv4 and IPv6 addresses and networks given as strings.
+//!
+//! The addresses are written as usual, e.g. `192.168.0.1` or `2001:db8::1`, and the
+//! networks in the CIDR notation, e.g. `192.168.0.0/16`. An IPv4 address and an IPv6
+//! one never belong to the same network, an IPv4-mapped IPv6 address like
+//! `::ffff:192.168.0.1` is treated as an IPv6 one.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::engine::error::DataError;
+
+pub fn parse_address(value: &str) -> Result<IpAddr, DataError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| DataError::ParseError(format!("cannot parse {value:?} as an IP address")))
+}
+
+fn max_prefix_len(address: IpAddr) -> u8 {
+    match address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// Parses a network in the CIDR notation into its address and the length of its
+/// prefix. A single address is a network with the full-length prefix. The bits of the
+/// address after the prefix don't have to be zero.
+pub fn parse_network(value: &str) -> Result<(IpAddr, u8), DataError> {
+    let error = || DataError::ParseError(format!("cannot parse {value:?} as an IP network"));
+    let (address, prefix_len) = match value.trim().split_once('/') {
+        Some((address, prefix_len)) => (address, Some(prefix_len)),
+        None => (value, None),
+    };
+    let address = parse_address(address).map_err(|_| error())?;
+    let max_prefix_len = max_prefix_len(address);
+    let prefix_len = match prefix_len {
+        Some(prefix_len) => prefix_len.parse().map_err(|_| error())?,
+        None => max_prefix_len,
+    };
+    if prefix_len > max_prefix_len {
+        return Err(error());
+    }
+    Ok((address, prefix_len))
+}
+
+/// Zeroes the bits of the address after the first `prefix_len` ones.
+fn mask(address: IpAddr, prefix_len: u8) -> IpAddr {
+    match address {
+        IpAddr::V4(address) => {
+            let mask = u32::MAX
+                .checked_shl(32 - u32::from(prefix_len))
+                .unwrap_or(0);
+            IpAddr::V4(Ipv4Addr::from(u32::from(address) & mask))
+        }
+        IpAddr::V6(address) => {
+            let mask = u128::MAX
+                .checked_shl(128 - u32::from(prefix_len))
+                .unwrap_or(0);
+            IpAddr::V6(Ipv6Addr::from(u128::from(address) & mask))
+        }
+    }
+}
+
+/// Returns the canonical form of the address, e.g. with the zeros of an IPv6 address
+/// compressed.
+pub fn canonical_address(value: &str) -> Result<String, DataError> {
+    Ok(parse_address(value)?.to_string())
+}
+
+/// Returns 4 or 6 for the valid IPv4 and IPv6 addresses, respectively, and `None`
+/// otherwise.
+pub fn address_version(value: &str) -> Option<i64> {
+    match parse_address(value).ok()? {
+        IpAddr::V4(_) => Some(4),
+        IpAddr::V6(_) => Some(6),
+    }
+}
+
+/// Checks whether the address belongs to the network given in the CIDR notation.
+pub fn is_in_network(address: &str, network: &str) -> Result<bool, DataError> {
+    let address = parse_address(address)?;
+    let (network, prefix_len) = parse_network(network)?;
+    Ok(address.is_ipv4() == network.is_ipv4()
+        && mask(address, prefix_len) == mask(network, prefix_len))
+}
+
+/// Returns the network with the prefix of length `prefix_len` the address belongs to,
+/// in the CIDR notation.
+pub fn network_of(address: &str, prefix_len: i64) -> Result<String, DataError> {
+    let address = parse_address(address)?;
+    let max_prefix_len = max_prefix_len(address);
+    let prefix_len = u8::try_from(prefix_len)
+        .ok()
+        .filter(|prefix_len| *prefix_len <= max_prefix_len)
+        .ok_or_else(|| {
+            DataError::ValueError(format!(
+                "prefix length {prefix_len} is out of range for {address}"
+            ))
+        })?;
+    Ok(format!("{}/{prefix_len}", mask(address, prefix_len)))
+}
@@ -0,0 +1,129 @@
+// Copyright © 2026 Pathway
+
+//! Similarity measures of strings, used for the fuzzy matching of text columns.
+//!
+//! All the measures work on Unicode scalar values, so a multibyte character counts as a
+//! single one.
+
+use std::collections::HashSet;
+
+/// The minimal number of single-character insertions, deletions and substitutions
+/// turning `lhs` into `rhs`.
+pub fn levenshtein(lhs: &str, rhs: &str) -> usize {
+    let rhs: Vec<char> = rhs.chars().collect();
+    // distances between the processed prefix of `lhs` and all the prefixes of `rhs`
+    let mut distances: Vec<usize> = (0..=rhs.len()).collect();
+    for (i, lhs_char) in lhs.chars().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, rhs_char) in rhs.iter().enumerate() {
+            let substitution = diagonal + usize::from(lhs_char != *rhs_char);
+            diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(distances[j + 1] + 1);
+        }
+    }
+    distances[rhs.len()]
+}
+
+fn jaro(lhs: &[char], rhs: &[char]) -> f64 {
+    if lhs.is_empty() && rhs.is_empty() {
+        return 1.0;
+    }
+    if lhs.is_empty() || rhs.is_empty() {
+        return 0.0;
+    }
+    let window = (lhs.len().max(rhs.len()) / 2).saturating_sub(1);
+    let mut rhs_matched = vec![false; rhs.len()];
+    let mut lhs_matches = Vec::new();
+    for (i, lhs_char) in lhs.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(rhs.len());
+        for j in start..end {
+            if !rhs_matched[j] && rhs[j] == *lhs_char {
+                rhs_matched[j] = true;
+                lhs_matches.push(*lhs_char);
+                break;
+            }
+        }
+    }
+    if lhs_matches.is_empty() {
+        return 0.0;
+    }
+    let rhs_matches = rhs
+        .iter()
+        .zip(rhs_matched)
+        .filter_map(|(c, matched)| matched.then_some(c));
+    let transpositions = lhs_matches
+        .iter()
+        .zip(rhs_matches)
+        .filter(|(l, r)| l != r)
+        .count()
+        / 2;
+    #[allow(clippy::cast_precision_loss)]
+    let (matches, transpositions, lhs_len, rhs_len) = (
+        lhs_matches.len() as f64,
+        transpositions as f64,
+        lhs.len() as f64,
+        rhs.len() as f64,
+    );
+    (matches / lhs_len + matches / rhs_len + (matches - transpositions) / matches) / 3.0
+}
+
+/// The Jaro-Winkler similarity of the strings, from 0.0 for the strings with no common
+/// characters to 1.0 for the equal ones. The strings sharing a prefix of up to four
+/// characters are rated as more similar.
+pub fn jaro_winkler(lhs: &str, rhs: &str) -> f64 {
+    const PREFIX_SCALE: f64 = 0.1;
+    const MAX_PREFIX: usize = 4;
+
+    let lhs: Vec<char> = lhs.chars().collect();
+    let rhs: Vec<char> = rhs.chars().collect();
+    let jaro = jaro(&lhs, &rhs);
+    let prefix = lhs
+        .iter()
+        .zip(&rhs)
+        .take(MAX_PREFIX)
+        .take_while(|(l, r)| l == r)
+        .count();
+    #[allow(clippy::cast_precision_loss)]
+    let prefix = prefix as f64;
+    jaro + prefix * PREFIX_SCALE * (1.0 - jaro)
+}
+
+// The trigrams of the words, as defined by the `pg_trgm` extension of PostgreSQL: the
+// words are the maximal runs of alphanumeric characters, lowercased and padded with two
+// spaces in front and one at the end.
+fn trigrams(value: &str) -> HashSet<[char; 3]> {
+    let mut trigrams = HashSet::new();
+    for word in value
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        let padded: Vec<char> = "  "
+            .chars()
+            .chain(word.chars().flat_map(char::to_lowercase))
+            .chain(" ".chars())
+            .collect();
+        trigrams.extend(
+            padded
+                .windows(3)
+                .map(|window| [window[0], window[1], window[2]]),
+        );
+    }
+    trigrams
+}
+
+/// The trigram similarity of the strings, compatible with the `similarity` function of
+/// the `pg_trgm` extension of PostgreSQL: the number of trigrams shared by the strings
+/// divided by the number of distinct trigrams in both of them. It's 0.0 if either of
+/// the strings has no alphanumeric characters.
+#[allow(clippy::cast_precision_loss)]
+pub fn trigram_similarity(lhs: &str, rhs: &str) -> f64 {
+    let lhs = trigrams(lhs);
+    let rhs = trigrams(rhs);
+    if lhs.is_empty() || rhs.is_empty() {
+        return 0.0;
+    }
+    let common = lhs.intersection(&rhs).count();
+    common as f64 / (lhs.len() + rhs.len() - common) as f64
+}
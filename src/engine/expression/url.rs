@@ -0,0 +1,74 @@
+// Copyright © 2026 Pathway
+
+//! Components of absolute URLs, parsed as defined by the WHATWG URL standard, the same
+//! way web browsers do.
+
+use std::str::FromStr;
+
+use url::Url;
+
+use crate::engine::error::DataError;
+use crate::engine::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlPart {
+    Scheme,
+    Username,
+    Host,
+    Port,
+    Path,
+    Query,
+    Fragment,
+}
+
+impl FromStr for UrlPart {
+    type Err = DataError;
+
+    fn from_str(part: &str) -> Result<Self, Self::Err> {
+        match part {
+            "scheme" => Ok(Self::Scheme),
+            "username" => Ok(Self::Username),
+            "host" => Ok(Self::Host),
+            "port" => Ok(Self::Port),
+            "path" => Ok(Self::Path),
+            "query" => Ok(Self::Query),
+            "fragment" => Ok(Self::Fragment),
+            _ => Err(DataError::ValueError(format!("unknown URL part {part:?}"))),
+        }
+    }
+}
+
+pub fn parse_url(value: &str) -> Result<Url, DataError> {
+    Url::parse(value)
+        .map_err(|e| DataError::ParseError(format!("cannot parse {value:?} as a URL: {e}")))
+}
+
+/// Returns the given part of the URL, or `None` if the URL doesn't have it. The scheme
+/// and the host are lowercased, the port is only returned if it's given explicitly and
+/// is not the default one of the scheme, and the query and the fragment are returned
+/// without the leading `?` and `#`. The path is always present, e.g. `/` for
+/// `https://pathway.com`.
+pub fn url_part(value: &str, part: UrlPart) -> Result<Value, DataError> {
+    let url = parse_url(value)?;
+    let non_empty = |part: &str| (!part.is_empty()).then(|| Value::from(part));
+    let part = match part {
+        UrlPart::Scheme => Some(Value::from(url.scheme())),
+        UrlPart::Username => non_empty(url.username()),
+        UrlPart::Host => url.host_str().map(Value::from),
+        UrlPart::Port => url.port().map(|port| Value::Int(port.into())),
+        UrlPart::Path => Some(Value::from(url.path())),
+        UrlPart::Query => url.query().map(Value::from),
+        UrlPart::Fragment => url.fragment().map(Value::from),
+    };
+    Ok(part.unwrap_or(Value::None))
+}
+
+/// Returns the first value of the query parameter `name`, decoded, or `None` if the
+/// query has no such parameter.
+pub fn url_query_param(value: &str, name: &str) -> Result<Option<String>, DataError> {
+    let url = parse_url(value)?;
+    Ok(url
+        .query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned()))
+}
@@ -0,0 +1,408 @@
+// Copyright © 2026 Pathway
+
+//! Column-wise evaluation of numeric expressions.
+//!
+//! The generic evaluation materializes a `Vec<DynResult<T>>` at every node of the
+//! expression tree and unwraps the results of the children row by row. For chains of
+//! arithmetic operations and comparisons on ints and floats, this bookkeeping dominates
+//! the actual work. Here, such chains are evaluated over the whole batch into plain
+//! vectors of numbers, so that each operation is a tight loop over slices that the
+//! compiler can turn into SIMD instructions. Errors are tracked separately and only for
+//! the batches in which they occur.
+//!
+//! The results are exactly the same as the ones of the row-wise evaluation. An error in
+//! a row is propagated the same way (the error of the left operand wins) and the
+//! fallible operations (divisions and the integer operations that may overflow) are only
+//! applied to the rows without errors. The subexpressions that can't be evaluated
+//! column-wise are evaluated row-wise and their results are converted into columns.
+//!
+//! The arguments are given either as rows or as a [`ColumnarBatch`]. In the latter
+//! case, the int, float and bool columns are read directly from their Arrow arrays
+//! and the rows are only materialized if some subexpression needs them.
+
+use super::{
+    float_floor_div, float_mod, float_true_div, int_abs, int_add, int_floor_div, int_mod, int_mul,
+    int_neg, int_sub, int_true_div, AnyExpression, BoolExpression, EvalAs, Expression,
+    FloatExpression, IntExpression,
+};
+use crate::engine::columnar::{ColumnarBatch, ColumnarColumn};
+use crate::engine::error::{DataError, DynError, DynResult};
+use crate::engine::Value;
+
+/// The arguments of an expression evaluated over a batch.
+pub(super) enum Arguments<'a> {
+    Rows(&'a [&'a [Value]]),
+    Columnar(&'a ColumnarBatch),
+}
+
+impl Arguments<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Rows(rows) => rows.len(),
+            Self::Columnar(batch) => batch.len(),
+        }
+    }
+
+    pub(super) fn with_rows<R>(&self, f: impl FnOnce(&[&[Value]]) -> R) -> R {
+        match self {
+            Self::Rows(rows) => f(rows),
+            Self::Columnar(batch) => {
+                let rows: Vec<&[Value]> = batch.rows().iter().map(Vec::as_slice).collect();
+                f(&rows)
+            }
+        }
+    }
+
+    fn argument<T: Primitive>(&self, index: usize) -> Column<T> {
+        match self {
+            Self::Rows(rows) => {
+                Column::from_values(rows.iter().map(|row| row.get(index)), rows.len())
+            }
+            Self::Columnar(batch) => match batch.column(index) {
+                Some(ColumnarColumn::Values(values)) => {
+                    Column::from_values(values.iter().map(Some), values.len())
+                }
+                Some(column) => T::from_columnar(column).unwrap_or_else(|| {
+                    let values: Vec<_> = (0..column.len()).map(|row| column.value(row)).collect();
+                    Column::from_values(values.iter().map(Some), values.len())
+                }),
+                None => Column::from_values(std::iter::repeat_n(None, batch.len()), batch.len()),
+            },
+        }
+    }
+}
+
+/// A type of values that can be stored in a [`Column`].
+trait Primitive: Copy + Default {
+    fn extract(value: &Value) -> DynResult<Self>;
+
+    /// Reads the column directly if it is stored in an Arrow array of this type.
+    fn from_columnar(column: &ColumnarColumn) -> Option<Column<Self>>;
+}
+
+impl Primitive for i64 {
+    fn extract(value: &Value) -> DynResult<Self> {
+        value.as_int()
+    }
+
+    fn from_columnar(column: &ColumnarColumn) -> Option<Column<Self>> {
+        match column {
+            ColumnarColumn::Int(array) => Some(Column::from_nullable(array.iter(), array.len())),
+            _ => None,
+        }
+    }
+}
+
+impl Primitive for f64 {
+    fn extract(value: &Value) -> DynResult<Self> {
+        value.as_float()
+    }
+
+    fn from_columnar(column: &ColumnarColumn) -> Option<Column<Self>> {
+        match column {
+            ColumnarColumn::Float(array) => Some(Column::from_nullable(array.iter(), array.len())),
+            _ => None,
+        }
+    }
+}
+
+impl Primitive for bool {
+    fn extract(value: &Value) -> DynResult<Self> {
+        value.as_bool()
+    }
+
+    fn from_columnar(column: &ColumnarColumn) -> Option<Column<Self>> {
+        match column {
+            ColumnarColumn::Bool(array) => Some(Column::from_nullable(array.iter(), array.len())),
+            _ => None,
+        }
+    }
+}
+
+pub(super) struct Column<T> {
+    values: Vec<T>,
+    // empty if there are no errors in the column
+    errors: Vec<Option<DynError>>,
+}
+
+impl<T: Copy + Default> Column<T> {
+    fn constant(value: T, len: usize) -> Self {
+        Self {
+            values: vec![value; len],
+            errors: Vec::new(),
+        }
+    }
+
+    fn from_results(results: Vec<DynResult<T>>) -> Self {
+        let mut column = Self::constant(T::default(), results.len());
+        for (i, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(value) => column.values[i] = value,
+                Err(error) => column.set_error(i, error),
+            }
+        }
+        column
+    }
+
+    fn from_values<'v>(values: impl Iterator<Item = Option<&'v Value>>, len: usize) -> Self
+    where
+        T: Primitive,
+    {
+        let mut column = Self::constant(T::default(), len);
+        for (i, value) in values.enumerate() {
+            let value = match value {
+                None => Err(DataError::IndexOutOfBounds.into()),
+                Some(Value::Error) => Err(DataError::ErrorInValue.into()),
+                Some(value) => T::extract(value),
+            };
+            match value {
+                Ok(value) => column.values[i] = value,
+                Err(error) => column.set_error(i, error),
+            }
+        }
+        column
+    }
+
+    // a null is an error, just like a `None` value in a row
+    fn from_nullable(values: impl Iterator<Item = Option<T>>, len: usize) -> Self
+    where
+        T: Primitive,
+    {
+        let mut column = Self::constant(T::default(), len);
+        for (i, value) in values.enumerate() {
+            match value.map_or_else(|| T::extract(&Value::None), Ok) {
+                Ok(value) => column.values[i] = value,
+                Err(error) => column.set_error(i, error),
+            }
+        }
+        column
+    }
+
+    pub(super) fn into_results(self) -> Vec<DynResult<T>> {
+        if self.errors.is_empty() {
+            self.values.into_iter().map(Ok).collect()
+        } else {
+            self.values
+                .into_iter()
+                .zip(self.errors)
+                .map(|(value, error)| error.map_or(Ok(value), Err))
+                .collect()
+        }
+    }
+
+    pub(super) fn into_values(self) -> Vec<DynResult<Value>>
+    where
+        Value: From<T>,
+    {
+        self.into_results()
+            .into_iter()
+            .map(|result| result.map(Value::from))
+            .collect()
+    }
+
+    fn is_valid(&self, i: usize) -> bool {
+        self.errors.is_empty() || self.errors[i].is_none()
+    }
+
+    fn set_error(&mut self, i: usize, error: DynError) {
+        if self.errors.is_empty() {
+            self.errors.resize_with(self.values.len(), || None);
+        }
+        self.errors[i].get_or_insert(error);
+    }
+
+    fn map<U: Copy + Default>(self, f: impl Fn(T) -> U) -> Column<U> {
+        Column {
+            values: self.values.into_iter().map(f).collect(),
+            errors: self.errors,
+        }
+    }
+
+    fn map_err<U: Copy + Default>(self, f: impl Fn(T) -> DynResult<U>) -> Column<U> {
+        let mut result = Column {
+            values: vec![U::default(); self.values.len()],
+            errors: self.errors,
+        };
+        for (i, value) in self.values.into_iter().enumerate() {
+            if !result.is_valid(i) {
+                continue;
+            }
+            match f(value) {
+                Ok(value) => result.values[i] = value,
+                Err(error) => result.set_error(i, error),
+            }
+        }
+        result
+    }
+
+    fn zip_with<U: Copy + Default, V: Copy + Default>(
+        self,
+        other: Column<U>,
+        f: impl Fn(T, U) -> V,
+    ) -> Column<V> {
+        let values = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .map(|(l, r)| f(*l, *r))
+            .collect();
+        Column {
+            values,
+            errors: merge_errors(self.errors, other.errors),
+        }
+    }
+
+    fn zip_with_err<U: Copy + Default, V: Copy + Default>(
+        self,
+        other: Column<U>,
+        f: impl Fn(T, U) -> DynResult<V>,
+    ) -> Column<V> {
+        let mut result = Column {
+            values: vec![V::default(); self.values.len()],
+            errors: merge_errors(self.errors, other.errors),
+        };
+        for (i, (l, r)) in self.values.iter().zip(&other.values).enumerate() {
+            if !result.is_valid(i) {
+                continue;
+            }
+            match f(*l, *r) {
+                Ok(value) => result.values[i] = value,
+                Err(error) => result.set_error(i, error),
+            }
+        }
+        result
+    }
+}
+
+fn merge_errors(lhs: Vec<Option<DynError>>, rhs: Vec<Option<DynError>>) -> Vec<Option<DynError>> {
+    if lhs.is_empty() {
+        rhs
+    } else if rhs.is_empty() {
+        lhs
+    } else {
+        lhs.into_iter().zip(rhs).map(|(l, r)| l.or(r)).collect()
+    }
+}
+
+fn int_column(expression: &Expression, args: &Arguments) -> Column<i64> {
+    match expression {
+        Expression::Int(expression) => eval_int(expression, args).unwrap_or_else(|| {
+            Column::from_results(args.with_rows(|values| expression.eval(values)))
+        }),
+        Expression::Any(AnyExpression::Argument(index)) => args.argument(*index),
+        _ => Column::from_results(args.with_rows(|values| expression.eval_as(values))),
+    }
+}
+
+fn float_column(expression: &Expression, args: &Arguments) -> Column<f64> {
+    match expression {
+        Expression::Float(expression) => eval_float(expression, args).unwrap_or_else(|| {
+            Column::from_results(args.with_rows(|values| expression.eval(values)))
+        }),
+        Expression::Any(AnyExpression::Argument(index)) => args.argument(*index),
+        _ => Column::from_results(args.with_rows(|values| expression.eval_as(values))),
+    }
+}
+
+fn bool_column(expression: &Expression, args: &Arguments) -> Column<bool> {
+    match expression {
+        Expression::Bool(expression) => eval_bool(expression, args).unwrap_or_else(|| {
+            Column::from_results(args.with_rows(|values| expression.eval(values)))
+        }),
+        Expression::Any(AnyExpression::Argument(index)) => args.argument(*index),
+        _ => Column::from_results(args.with_rows(|values| expression.eval_as(values))),
+    }
+}
+
+/// Evaluates an int expression column-wise. Returns `None` if the operation at the
+/// root of the expression is not supported.
+pub(super) fn eval_int(expression: &IntExpression, args: &Arguments) -> Option<Column<i64>> {
+    let int = |e: &Expression| int_column(e, args);
+    let column = match expression {
+        IntExpression::Const(c) => Column::constant(*c, args.len()),
+        IntExpression::Neg(e) => int(e).map_err(int_neg),
+        IntExpression::Abs(e) => int(e).map_err(int_abs),
+        IntExpression::Add(lhs, rhs) => int(lhs).zip_with_err(int(rhs), int_add),
+        IntExpression::Sub(lhs, rhs) => int(lhs).zip_with_err(int(rhs), int_sub),
+        IntExpression::Mul(lhs, rhs) => int(lhs).zip_with_err(int(rhs), int_mul),
+        IntExpression::FloorDiv(lhs, rhs) => int(lhs).zip_with_err(int(rhs), int_floor_div),
+        IntExpression::Mod(lhs, rhs) => int(lhs).zip_with_err(int(rhs), int_mod),
+        IntExpression::And(lhs, rhs) => int(lhs).zip_with(int(rhs), |l, r| l & r),
+        IntExpression::Or(lhs, rhs) => int(lhs).zip_with(int(rhs), |l, r| l | r),
+        IntExpression::Xor(lhs, rhs) => int(lhs).zip_with(int(rhs), |l, r| l ^ r),
+        #[allow(clippy::cast_possible_truncation)]
+        IntExpression::CastFromFloat(e) => float_column(e, args).map(|v| v as i64),
+        IntExpression::CastFromBool(e) => bool_column(e, args).map(i64::from),
+        _ => return None,
+    };
+    Some(column)
+}
+
+/// Evaluates a float expression column-wise. Returns `None` if the operation at the
+/// root of the expression is not supported.
+pub(super) fn eval_float(expression: &FloatExpression, args: &Arguments) -> Option<Column<f64>> {
+    let float = |e: &Expression| float_column(e, args);
+    let column = match expression {
+        FloatExpression::Const(c) => Column::constant(*c, args.len()),
+        FloatExpression::Neg(e) => float(e).map(|v| -v),
+        FloatExpression::Abs(e) => float(e).map(f64::abs),
+        FloatExpression::Add(lhs, rhs) => float(lhs).zip_with(float(rhs), |l, r| l + r),
+        FloatExpression::Sub(lhs, rhs) => float(lhs).zip_with(float(rhs), |l, r| l - r),
+        FloatExpression::Mul(lhs, rhs) => float(lhs).zip_with(float(rhs), |l, r| l * r),
+        FloatExpression::FloorDiv(lhs, rhs) => float(lhs).zip_with_err(float(rhs), float_floor_div),
+        FloatExpression::TrueDiv(lhs, rhs) => float(lhs).zip_with_err(float(rhs), float_true_div),
+        FloatExpression::Mod(lhs, rhs) => float(lhs).zip_with_err(float(rhs), float_mod),
+        FloatExpression::IntTrueDiv(lhs, rhs) => {
+            int_column(lhs, args).zip_with_err(int_column(rhs, args), int_true_div)
+        }
+        #[allow(clippy::cast_precision_loss)]
+        FloatExpression::CastFromInt(e) => int_column(e, args).map(|v| v as f64),
+        FloatExpression::CastFromBool(e) => bool_column(e, args).map(|v| if v { 1.0 } else { 0.0 }),
+        _ => return None,
+    };
+    Some(column)
+}
+
+/// Evaluates a bool expression column-wise. Returns `None` if the operation at the
+/// root of the expression is not supported.
+#[allow(clippy::float_cmp)]
+pub(super) fn eval_bool(expression: &BoolExpression, args: &Arguments) -> Option<Column<bool>> {
+    let int = |e: &Expression| int_column(e, args);
+    let float = |e: &Expression| float_column(e, args);
+    let bool_ = |e: &Expression| bool_column(e, args);
+    let column = match expression {
+        BoolExpression::Const(c) => Column::constant(*c, args.len()),
+        BoolExpression::Not(e) => bool_(e).map(|v| !v),
+        BoolExpression::Xor(lhs, rhs) => bool_(lhs).zip_with(bool_(rhs), |l, r| l ^ r),
+        BoolExpression::BoolEq(lhs, rhs) => bool_(lhs).zip_with(bool_(rhs), |l, r| l == r),
+        BoolExpression::BoolNe(lhs, rhs) => bool_(lhs).zip_with(bool_(rhs), |l, r| l != r),
+        BoolExpression::IntEq(lhs, rhs) => int(lhs).zip_with(int(rhs), |l, r| l == r),
+        BoolExpression::IntNe(lhs, rhs) => int(lhs).zip_with(int(rhs), |l, r| l != r),
+        BoolExpression::IntLt(lhs, rhs) => int(lhs).zip_with(int(rhs), |l, r| l < r),
+        BoolExpression::IntLe(lhs, rhs) => int(lhs).zip_with(int(rhs), |l, r| l <= r),
+        BoolExpression::IntGt(lhs, rhs) => int(lhs).zip_with(int(rhs), |l, r| l > r),
+        BoolExpression::IntGe(lhs, rhs) => int(lhs).zip_with(int(rhs), |l, r| l >= r),
+        BoolExpression::FloatEq(lhs, rhs) => float(lhs).zip_with(float(rhs), |l, r| l == r),
+        BoolExpression::FloatNe(lhs, rhs) => float(lhs).zip_with(float(rhs), |l, r| l != r),
+        BoolExpression::FloatLt(lhs, rhs) => float(lhs).zip_with(float(rhs), |l, r| l < r),
+        BoolExpression::FloatLe(lhs, rhs) => float(lhs).zip_with(float(rhs), |l, r| l <= r),
+        BoolExpression::FloatGt(lhs, rhs) => float(lhs).zip_with(float(rhs), |l, r| l > r),
+        BoolExpression::FloatGe(lhs, rhs) => float(lhs).zip_with(float(rhs), |l, r| l >= r),
+        BoolExpression::CastFromInt(e) => int(e).map(|v| v != 0),
+        BoolExpression::CastFromFloat(e) => float(e).map(|v| v != 0.0),
+        _ => return None,
+    };
+    Some(column)
+}
+
+/// Tells if the operation at the root of the expression can be evaluated column-wise.
+pub(super) fn is_supported(expression: &Expression) -> bool {
+    // evaluating an empty batch costs nothing and tells which operations are supported
+    let args = Arguments::Rows(&[]);
+    match expression {
+        Expression::Int(expression) => eval_int(expression, &args).is_some(),
+        Expression::Float(expression) => eval_float(expression, &args).is_some(),
+        Expression::Bool(expression) => eval_bool(expression, &args).is_some(),
+        _ => false,
+    }
+}
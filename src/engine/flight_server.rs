@@ -0,0 +1,397 @@
+// Copyright © 2026 Pathway
+
+//! Exported tables served over Arrow Flight.
+//!
+//! An exported table can be registered under a name on an Arrow Flight server with
+//! [`register_flight_table`]. A `DoGet` request with the name of the table as its ticket
+//! streams the table as Arrow record batches:
+//! - the first batch is the state of the table at the frontier of the exported table,
+//!   taken when the request is received;
+//! - each next batch contains the changes done before the new frontier, sent once the
+//!   frontier advances.
+//!
+//! The batches have the `id` column with the keys of the rows, followed by the columns
+//! of the table and the `time` and `diff` columns. In the first batch `time` is the time
+//! of the last change of the row and `diff` is always 1. The stream ends once the table
+//! is finished. If the table is computed by many workers, the smallest of their
+//! frontiers is used. The columns of types with no Arrow counterpart are sent as
+//! strings and the errors are sent as nulls. `ListFlights`, `GetFlightInfo` and
+//! `GetSchema` describe the served tables, the other methods are not supported.
+//!
+//! A server is started on the first registration of a table at its address and runs
+//! until the process exits. Each process serves the rows computed by its own workers,
+//! on the port of the address increased by the index of the process. A name is
+//! released once the computation that registered it is dropped.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::io;
+use std::mem::take;
+use std::net::{SocketAddr, TcpListener};
+use std::ops::ControlFlow;
+use std::sync::{Arc, LazyLock, Mutex, Weak};
+use std::thread;
+
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor,
+    FlightEndpoint as ArrowFlightEndpoint, FlightInfo, HandshakeRequest, HandshakeResponse,
+    PollInfo, PutResult, SchemaAsIpc, SchemaResult, Ticket,
+};
+use async_trait::async_trait;
+use deltalake::arrow::ipc::writer::IpcWriteOptions;
+use deltalake::arrow::record_batch::RecordBatch;
+use futures::stream::{self, BoxStream};
+use futures::{StreamExt, TryStreamExt};
+use log::error;
+use tokio::sync::Notify;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status, Streaming};
+
+use super::change_batches::{consolidate, ChangeBatchSchema};
+use super::{DataRow, Error, ExportedTable, FlightEndpoint, Result, Timestamp, TotalFrontier};
+
+struct FlightTable {
+    column_names: Vec<String>,
+    // exported tables of the workers computing the table, by worker index
+    parts: HashMap<usize, Weak<dyn ExportedTable>>,
+}
+
+impl FlightTable {
+    fn live_parts(&self) -> Vec<Arc<dyn ExportedTable>> {
+        self.parts.values().filter_map(Weak::upgrade).collect()
+    }
+}
+
+type FlightTables = Arc<Mutex<HashMap<String, FlightTable>>>;
+
+// tables of the servers started by this process, by the addresses of the servers
+static FLIGHT_SERVERS: LazyLock<Mutex<HashMap<SocketAddr, FlightTables>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Registers the part of a table exported by the worker `worker_index` on the Arrow
+/// Flight server of its process, starting the server if it's not running yet. All the
+/// workers computing the table have to register their parts with the same endpoint.
+pub fn register_flight_table(
+    endpoint: FlightEndpoint,
+    worker_index: usize,
+    thread_count: usize,
+    table: &Arc<dyn ExportedTable>,
+) -> Result<()> {
+    let FlightEndpoint {
+        mut address,
+        name,
+        column_names,
+    } = endpoint;
+    let process_index = worker_index / thread_count;
+    let port = u16::try_from(process_index)
+        .ok()
+        .and_then(|process_index| address.port().checked_add(process_index))
+        .ok_or_else(|| {
+            Error::FlightServerFailed(
+                address,
+                io::Error::new(io::ErrorKind::InvalidInput, "port out of range"),
+            )
+        })?;
+    address.set_port(port);
+
+    let tables = match FLIGHT_SERVERS.lock().unwrap().entry(address) {
+        Entry::Occupied(entry) => entry.get().clone(),
+        Entry::Vacant(entry) => {
+            let tables = FlightTables::default();
+            start_flight_server(address, tables.clone())?;
+            entry.insert(tables).clone()
+        }
+    };
+    let mut tables = tables.lock().unwrap();
+    let flight_table = tables.entry(name.clone()).or_insert_with(|| FlightTable {
+        column_names: column_names.clone(),
+        parts: HashMap::new(),
+    });
+    // parts left by the computations that have already finished
+    flight_table.parts.retain(|_, part| part.strong_count() > 0);
+    if flight_table.parts.is_empty() {
+        flight_table.column_names = column_names;
+    } else if flight_table.parts.contains_key(&worker_index)
+        || flight_table.column_names != column_names
+    {
+        return Err(Error::FlightTableNameAlreadyUsed(name));
+    }
+    flight_table
+        .parts
+        .insert(worker_index, Arc::downgrade(table));
+    Ok(())
+}
+
+fn start_flight_server(address: SocketAddr, tables: FlightTables) -> Result<()> {
+    // bound here, so that an address in use is reported to the registering worker
+    let listener = TcpListener::bind(address)
+        .and_then(|listener| {
+            listener.set_nonblocking(true)?;
+            Ok(listener)
+        })
+        .map_err(|e| Error::FlightServerFailed(address, e))?;
+    thread::Builder::new()
+        .name("pathway:flight_server".to_string())
+        .spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(async move {
+                    let listener = match tokio::net::TcpListener::from_std(listener) {
+                        Ok(listener) => listener,
+                        Err(e) => {
+                            error!("Failed to start the Arrow Flight server at {address}: {e}");
+                            return;
+                        }
+                    };
+                    let service = FlightServiceServer::new(FlightTableService { tables });
+                    if let Err(e) = Server::builder()
+                        .add_service(service)
+                        .serve_with_incoming(TcpIncoming::from(listener))
+                        .await
+                    {
+                        error!("The Arrow Flight server at {address} has failed: {e}");
+                    }
+                });
+        })
+        .map_err(|e| Error::FlightServerFailed(address, e))?;
+    Ok(())
+}
+
+struct TableStream {
+    schema: Arc<ChangeBatchSchema>,
+    parts: Vec<Arc<dyn ExportedTable>>,
+    offsets: Vec<usize>,
+    // rows of the times that are not finished yet
+    pending: Vec<DataRow>,
+    // the frontier of the last sent batch, `None` before the first one
+    frontier: Option<TotalFrontier<Timestamp>>,
+    changed: Arc<Notify>,
+}
+
+impl TableStream {
+    fn new(schema: Arc<ChangeBatchSchema>, parts: Vec<Arc<dyn ExportedTable>>) -> Self {
+        let changed = Arc::new(Notify::new());
+        for part in &parts {
+            let changed = Arc::downgrade(&changed);
+            part.subscribe(Box::new(move || match changed.upgrade() {
+                Some(changed) => {
+                    changed.notify_one();
+                    ControlFlow::Continue(())
+                }
+                None => ControlFlow::Break(()),
+            }));
+        }
+        Self {
+            schema,
+            offsets: vec![0; parts.len()],
+            parts,
+            pending: Vec::new(),
+            frontier: None,
+            changed,
+        }
+    }
+
+    fn take_finished(&mut self, frontier: TotalFrontier<Timestamp>) -> Vec<DataRow> {
+        let (finished, pending) = take(&mut self.pending)
+            .into_iter()
+            .partition(|row| frontier.is_time_done(&row.time));
+        self.pending = pending;
+        consolidate(finished, self.frontier.is_none())
+    }
+
+    /// Waits for the next batch of the table, returns `None` once it's finished.
+    async fn next_batch(&mut self) -> Option<std::result::Result<RecordBatch, Status>> {
+        loop {
+            if self.parts.iter().any(|part| part.failed()) {
+                return Some(Err(Status::aborted(
+                    "the computation of the table has failed",
+                )));
+            }
+            // read before the data, so that all the rows of the finished times are read
+            let frontier = self
+                .parts
+                .iter()
+                .map(|part| part.frontier())
+                .min()
+                .unwrap_or(TotalFrontier::Done);
+            for (part, offset) in self.parts.iter().zip(&mut self.offsets) {
+                let (data, new_offset) = part.data_from_offset(*offset);
+                self.pending.extend(data);
+                *offset = new_offset;
+            }
+            if self.frontier.is_none_or(|sent| frontier > sent) {
+                let is_first = self.frontier.is_none();
+                let rows = self.take_finished(frontier);
+                self.frontier = Some(frontier);
+                if is_first || !rows.is_empty() {
+                    let batch = self.schema.record_batch(&rows);
+                    return Some(batch.map_err(|e| Status::internal(e.to_string())));
+                }
+            }
+            if frontier.is_done() {
+                return None;
+            }
+            self.changed.notified().await;
+        }
+    }
+}
+
+struct FlightTableService {
+    tables: FlightTables,
+}
+
+impl FlightTableService {
+    fn table(
+        &self,
+        name: &str,
+    ) -> std::result::Result<(Arc<ChangeBatchSchema>, Vec<Arc<dyn ExportedTable>>), Status> {
+        let tables = self.tables.lock().unwrap();
+        let table = tables
+            .get(name)
+            .ok_or_else(|| Status::not_found(format!("no table served as {name:?}")))?;
+        let parts = table.live_parts();
+        let Some(first_part) = parts.first() else {
+            return Err(Status::not_found(format!("no table served as {name:?}")));
+        };
+        let schema = ChangeBatchSchema::new(&table.column_names, &first_part.properties());
+        Ok((Arc::new(schema), parts))
+    }
+
+    fn flight_info(
+        name: &str,
+        schema: &ChangeBatchSchema,
+    ) -> std::result::Result<FlightInfo, Status> {
+        Ok(FlightInfo::new()
+            .try_with_schema(&schema.schema)
+            .map_err(|e| Status::internal(e.to_string()))?
+            .with_descriptor(FlightDescriptor::new_path(vec![name.to_string()]))
+            .with_endpoint(ArrowFlightEndpoint::new().with_ticket(Ticket::new(name.to_string()))))
+    }
+}
+
+fn descriptor_name(descriptor: &FlightDescriptor) -> std::result::Result<&str, Status> {
+    match descriptor.path.as_slice() {
+        [name] => Ok(name),
+        _ => Err(Status::invalid_argument(
+            "the descriptor has to be a path consisting of the name of a table",
+        )),
+    }
+}
+
+#[async_trait]
+impl FlightService for FlightTableService {
+    type HandshakeStream = BoxStream<'static, std::result::Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = BoxStream<'static, std::result::Result<FlightInfo, Status>>;
+    type DoGetStream = BoxStream<'static, std::result::Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, std::result::Result<PutResult, Status>>;
+    type DoExchangeStream = BoxStream<'static, std::result::Result<FlightData, Status>>;
+    type DoActionStream = BoxStream<'static, std::result::Result<arrow_flight::Result, Status>>;
+    type ListActionsStream = BoxStream<'static, std::result::Result<ActionType, Status>>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> std::result::Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("handshakes are not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> std::result::Result<Response<Self::ListFlightsStream>, Status> {
+        let mut names: Vec<String> = self.tables.lock().unwrap().keys().cloned().collect();
+        names.sort();
+        let mut infos = Vec::with_capacity(names.len());
+        for name in names {
+            if let Ok((schema, _parts)) = self.table(&name) {
+                infos.push(Self::flight_info(&name, &schema));
+            }
+        }
+        Ok(Response::new(stream::iter(infos).boxed()))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<FlightInfo>, Status> {
+        let name = descriptor_name(request.get_ref())?;
+        let (schema, _parts) = self.table(name)?;
+        Ok(Response::new(Self::flight_info(name, &schema)?))
+    }
+
+    async fn poll_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<PollInfo>, Status> {
+        Err(Status::unimplemented(
+            "polling flight info is not supported",
+        ))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<SchemaResult>, Status> {
+        let name = descriptor_name(request.get_ref())?;
+        let (schema, _parts) = self.table(name)?;
+        let schema = SchemaAsIpc::new(&schema.schema, &IpcWriteOptions::default());
+        let schema = SchemaResult::try_from(schema).map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(schema))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> std::result::Result<Response<Self::DoGetStream>, Status> {
+        let name = String::from_utf8(request.into_inner().ticket.to_vec())
+            .map_err(|_| Status::invalid_argument("the ticket has to be the name of a table"))?;
+        let (schema, parts) = self.table(&name)?;
+        let table_stream = TableStream::new(schema.clone(), parts);
+        // the stream ends after the first error
+        let batches = stream::unfold(Some(table_stream), |table_stream| async move {
+            let mut table_stream = table_stream?;
+            let batch = table_stream.next_batch().await?;
+            let table_stream = batch.is_ok().then_some(table_stream);
+            Some((batch, table_stream))
+        });
+        let flight_data = FlightDataEncoderBuilder::new()
+            .with_schema(schema.schema.clone())
+            .build(batches.map_err(FlightError::from))
+            .map_err(Status::from);
+        Ok(Response::new(flight_data.boxed()))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> std::result::Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("the served tables are read-only"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> std::result::Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("the served tables are read-only"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> std::result::Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("actions are not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> std::result::Result<Response<Self::ListActionsStream>, Status> {
+        Ok(Response::new(stream::empty().boxed()))
+    }
+}
@@ -2,28 +2,31 @@
 
 use std::any::Any;
 use std::cell::Cell;
+use std::cmp::Ordering;
+use std::net::SocketAddr;
 use std::ops::ControlFlow;
 use std::sync::Arc;
 use std::time::Duration;
 
+use arcstr::ArcStr;
 use futures::future::BoxFuture;
 use id_arena::ArenaBehavior;
-use itertools::Itertools;
-use pyo3::exceptions::{PyTypeError, PyValueError};
-use pyo3::prelude::pymethods;
-use pyo3::pyclass::CompareOp;
-use pyo3::{pyclass, Bound, PyAny, PyResult, Python};
 use scopeguard::defer;
 
+use crate::connectors::backlog::BacklogLimit;
 use crate::connectors::data_format::{Formatter, Parser};
 use crate::connectors::data_storage::{ReaderBuilder, Writer};
+use crate::connectors::rate_limit::RateLimit;
+use crate::connectors::sink_alignment::SinkAlignmentGroupDescriptor;
 use crate::connectors::synchronization::ConnectorGroupDescriptor;
+use crate::connectors::watermark::WatermarkStrategy;
 use crate::engine::dataflow::monitoring::ProberStats;
 use crate::external_integration::ExternalIndex;
+use crate::persistence::frontier::OffsetAntichain;
 use crate::persistence::UniqueName;
-use crate::python_api::extract_value;
 
 use super::error::{DynResult, Trace};
+use super::explain::OperatorPlan;
 use super::external_index_wrappers::{ExternalIndexData, ExternalIndexQuery};
 use super::reduce::StatefulCombineFn;
 use super::{
@@ -124,6 +127,8 @@ impl Default for ScopedContext {
 pub enum ColumnPath {
     Key,
     ValuePath(Vec<usize>),
+    /// A path to a column followed by the names of the nested struct fields.
+    FieldPath(Vec<usize>, Vec<ArcStr>),
 }
 
 impl ColumnPath {
@@ -135,22 +140,40 @@ impl ColumnPath {
         self.extract_inner(None, value)
     }
 
+    /// Splits the path into the index of the column it starts with and the path within
+    /// the value of this column. Returns `None` if the path doesn't start with a column.
+    pub fn split_first(&self) -> Option<(usize, ColumnPath)> {
+        match self {
+            Self::Key => None,
+            Self::ValuePath(path) => path
+                .split_first()
+                .map(|(first, rest)| (*first, Self::ValuePath(rest.to_vec()))),
+            Self::FieldPath(path, fields) => path
+                .split_first()
+                .map(|(first, rest)| (*first, Self::FieldPath(rest.to_vec(), fields.clone()))),
+        }
+    }
+
+    /// Checks if the path leads to the whole value it is extracted from.
+    pub fn is_whole_value(&self) -> bool {
+        matches!(self, Self::ValuePath(path) if path.is_empty())
+    }
+
     fn extract_inner(&self, key: Option<&Key>, value: &Value) -> Result<Value> {
         match self {
             Self::Key => match key {
                 Some(key) => Ok(Value::from(*key)),
                 None => Err(Error::ExtractFromValueNotSupportedForKey),
             },
-            Self::ValuePath(path) => {
-                let mut value = value;
-                for i in path {
-                    if *value == Value::None || *value == Value::Error || *value == Value::Pending {
+            Self::ValuePath(path) => Ok(self.extract_by_indices(path, value)?.clone()),
+            Self::FieldPath(path, fields) => {
+                let mut value = self.extract_by_indices(path, value)?;
+                for name in fields {
+                    if Self::is_absent(value) {
                         break;
-                        // needed in outer joins and replacing rows with duplicated ids with error
                     }
                     value = value
-                        .as_tuple()?
-                        .get(*i)
+                        .struct_field(name)?
                         .ok_or_else(|| Error::InvalidColumnPath(self.clone()))?;
                 }
                 Ok(value.clone())
@@ -158,32 +181,80 @@ impl ColumnPath {
         }
     }
 
+    fn is_absent(value: &Value) -> bool {
+        // needed in outer joins and replacing rows with duplicated ids with error
+        *value == Value::None || *value == Value::Error || *value == Value::Pending
+    }
+
+    fn extract_by_indices<'a>(&self, path: &[usize], value: &'a Value) -> Result<&'a Value> {
+        let mut value = value;
+        for i in path {
+            if Self::is_absent(value) {
+                break;
+            }
+            value = value
+                .as_tuple()?
+                .get(*i)
+                .ok_or_else(|| Error::InvalidColumnPath(self.clone()))?;
+        }
+        Ok(value)
+    }
+
     pub fn extract_properties(
         &self,
         table_properties: &TableProperties,
     ) -> Result<TableProperties> {
         match self {
             ColumnPath::Key => Ok(TableProperties::Empty),
-            ColumnPath::ValuePath(path) => {
-                let mut table_properties = table_properties;
-                for i in path {
-                    match table_properties {
-                        TableProperties::Table(inner, _) => {
-                            table_properties = inner
-                                .get(*i)
-                                .ok_or_else(|| Error::InvalidColumnPath(self.clone()))?;
-                        }
-                        _ => return Err(Error::InvalidColumnPath(self.clone())),
-                    }
+            ColumnPath::ValuePath(path) => Ok(self
+                .extract_properties_by_indices(path, table_properties)?
+                .clone()),
+            ColumnPath::FieldPath(path, fields) => {
+                let TableProperties::Column(column_properties) =
+                    self.extract_properties_by_indices(path, table_properties)?
+                else {
+                    return Err(Error::InvalidColumnPath(self.clone()));
+                };
+                let mut dtype = column_properties.dtype.clone();
+                for name in fields {
+                    dtype = dtype
+                        .struct_field_type(name)
+                        .ok_or_else(|| Error::InvalidColumnPath(self.clone()))?;
+                }
+                Ok(TableProperties::Column(Arc::new(ColumnProperties {
+                    dtype,
+                    append_only: column_properties.append_only,
+                    trace: column_properties.trace.clone(),
+                })))
+            }
+        }
+    }
+
+    fn extract_properties_by_indices<'a>(
+        &self,
+        path: &[usize],
+        table_properties: &'a TableProperties,
+    ) -> Result<&'a TableProperties> {
+        let mut table_properties = table_properties;
+        for i in path {
+            match table_properties {
+                TableProperties::Table(inner, _) => {
+                    table_properties = inner
+                        .get(*i)
+                        .ok_or_else(|| Error::InvalidColumnPath(self.clone()))?;
                 }
-                Ok(table_properties.clone())
+                _ => return Err(Error::InvalidColumnPath(self.clone())),
             }
         }
+        Ok(table_properties)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[pyclass(module = "pathway.engine", frozen, get_all)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "pathway.engine", frozen, get_all)
+)]
 pub struct DataRow {
     pub key: Key,
     pub values: Vec<Value>,
@@ -192,65 +263,6 @@ pub struct DataRow {
     pub shard: Option<usize>,
 }
 
-#[pymethods]
-impl DataRow {
-    #[new]
-    #[pyo3(signature = (
-        key,
-        values,
-        *,
-        time = Timestamp(0),
-        diff = 1,
-        shard = None,
-        dtypes,
-    ))]
-    #[allow(clippy::needless_pass_by_value)] // can't use &[Type] with pyo3
-    pub fn new(
-        key: Key,
-        values: Vec<Bound<PyAny>>,
-        time: Timestamp,
-        diff: isize,
-        shard: Option<usize>,
-        dtypes: Vec<Type>,
-    ) -> PyResult<Self> {
-        if values.len() != dtypes.len() {
-            let message = format!(
-                "Length of values ({}) should be equal to the length of dtypes ({}).",
-                values.len(),
-                dtypes.len()
-            );
-            return Err(PyValueError::new_err(message));
-        }
-        let extracted_values: Vec<_> = values
-            .into_iter()
-            .zip(dtypes)
-            .map(|(ob, dtype)| extract_value(&ob, &dtype))
-            .try_collect()?;
-        Ok(Self {
-            key,
-            values: extracted_values,
-            time,
-            diff,
-            shard,
-        })
-    }
-
-    fn __repr__(&self) -> String {
-        format!("{self:?}")
-    }
-
-    fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
-        // TODO: replace with __eq__ when pyo3 is updated to 0.20
-        match op {
-            CompareOp::Eq => Ok(self == other),
-            CompareOp::Ne => Ok(self != other),
-            _ => Err(PyTypeError::new_err(format!(
-                "{op:?} not supported between instances of DataRow and DataRow"
-            ))),
-        }
-    }
-}
-
 impl DataRow {
     pub fn from_engine(key: Key, values: Vec<Value>, time: Timestamp, diff: isize) -> Self {
         Self {
@@ -412,6 +424,7 @@ impl TableProperties {
             .map(|(path, props)| match path {
                 ColumnPath::ValuePath(path) => Ok((path, props)),
                 ColumnPath::Key => Err(Error::IdInTableProperties),
+                path @ ColumnPath::FieldPath(_, _) => Err(Error::InvalidColumnPath(path)),
             })
             .collect::<Result<_>>()?;
 
@@ -440,6 +453,211 @@ pub struct WindowProperties {
 pub struct OperatorProperties {
     pub id: usize,
     pub depends_on_error_log: bool,
+    /// The identity of the operator derived from the structure of the graph. Unlike
+    /// `id`, it doesn't change when unrelated operators are added to the graph.
+    pub stable_id: Option<String>,
+}
+
+/// How the entries of an output table are grouped into the batches its writers flush.
+/// By default, the writers are flushed after each minibatch, whatever its size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputBatching {
+    /// The maximal number of entries written between two flushes. The larger minibatches
+    /// are split into several flushes.
+    pub max_batch_size: Option<usize>,
+
+    /// For how long the flush of the written entries can be delayed, so that the next
+    /// minibatches are flushed together with them. The minibatches are committed only
+    /// when they are flushed.
+    pub linger: Option<Duration>,
+}
+
+impl OutputBatching {
+    pub fn new(max_batch_size: Option<usize>, linger: Option<Duration>) -> Self {
+        Self {
+            max_batch_size,
+            linger,
+        }
+    }
+}
+
+/// What an output does with the minibatches replayed from the persisted state after a
+/// restart, that is, the ones with the times not later than the time finalized by the
+/// sinks before the program stopped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeliverySemantics {
+    /// The replayed minibatches are written again, so that the destination gets all the
+    /// changes even if it has lost some of them. The changes made after the finalized
+    /// time are written again in either case.
+    AtLeastOnce,
+
+    /// The replayed minibatches are skipped, as they were written before the restart.
+    /// Together with the idempotent writes of the changes after the finalized time, each
+    /// change is effectively delivered once.
+    #[default]
+    ExactlyOnce,
+}
+
+/// When the entries of an output table are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    /// The entries of a minibatch are written as soon as the minibatch is produced.
+    #[default]
+    Streaming,
+
+    /// The entries are withheld until the output frontier passes their time. Then all
+    /// the entries of the time are written by a single worker and flushed together, in a
+    /// single transaction if the writer supports it, so that the destination never holds
+    /// a part of a minibatch.
+    WatermarkAtomic,
+}
+
+/// A column by which the entries of a minibatch are sorted before they are output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortColumn {
+    pub index: usize,
+    pub descending: bool,
+    /// Whether the `None` values go before all the others, in either direction.
+    pub nulls_first: bool,
+}
+
+impl SortColumn {
+    /// Sorts by the column in ascending order. The `None` values, being the smallest
+    /// ones, go first.
+    pub fn ascending(index: usize) -> Self {
+        Self {
+            index,
+            descending: false,
+            nulls_first: true,
+        }
+    }
+
+    /// Sorts by the column in descending order, with the `None` values last.
+    pub fn descending(index: usize) -> Self {
+        Self {
+            index,
+            descending: true,
+            nulls_first: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_nulls_first(mut self, nulls_first: bool) -> Self {
+        self.nulls_first = nulls_first;
+        self
+    }
+
+    pub fn compare(&self, lhs: &[Value], rhs: &[Value]) -> Ordering {
+        let (lhs, rhs) = (&lhs[self.index], &rhs[self.index]);
+        let nulls_order = if self.nulls_first {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        match (lhs, rhs) {
+            (Value::None, Value::None) => Ordering::Equal,
+            (Value::None, _) => nulls_order,
+            (_, Value::None) => nulls_order.reverse(),
+            _ if self.descending => rhs.cmp(lhs),
+            _ => lhs.cmp(rhs),
+        }
+    }
+}
+
+/// The writers of an output table, each with its own formatter, running in their own
+/// threads. The rows are partitioned between them by the values in the columns
+/// `partition_by_indices`, or by their keys if there are no such columns, so that the
+/// changes of a row, or of the rows with the same values in these columns, are written
+/// by the same writer, in order.
+pub struct OutputWriters {
+    pub writers: Vec<(Box<dyn Writer>, Box<dyn Formatter>)>,
+    pub partition_by_indices: Option<Vec<usize>>,
+    pub batching: OutputBatching,
+    pub delivery_semantics: DeliverySemantics,
+    pub output_mode: OutputMode,
+    pub commit_group: Option<String>,
+    /// The maximal number of minibatches and commits waiting for each writer. When the
+    /// queue is full, the dataflow waits for the writer instead of buffering more
+    /// output in memory. The queue is unbounded if it's `None`.
+    pub queue_capacity: Option<usize>,
+    /// Whether the deletion and the insertion of a row in a minibatch are passed to the
+    /// formatter as a single update, if it can represent one
+    /// (see [`Formatter::format_update`]).
+    pub collapse_updates: bool,
+}
+
+impl OutputWriters {
+    pub fn single(writer: Box<dyn Writer>, formatter: Box<dyn Formatter>) -> Self {
+        Self {
+            writers: vec![(writer, formatter)],
+            partition_by_indices: None,
+            batching: OutputBatching::default(),
+            delivery_semantics: DeliverySemantics::default(),
+            output_mode: OutputMode::default(),
+            commit_group: None,
+            queue_capacity: None,
+            collapse_updates: false,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if there are no writers.
+    pub fn partitioned(
+        writers: Vec<(Box<dyn Writer>, Box<dyn Formatter>)>,
+        partition_by_indices: Option<Vec<usize>>,
+    ) -> Self {
+        assert!(!writers.is_empty(), "an output needs at least one writer");
+        Self {
+            writers,
+            partition_by_indices,
+            batching: OutputBatching::default(),
+            delivery_semantics: DeliverySemantics::default(),
+            output_mode: OutputMode::default(),
+            commit_group: None,
+            queue_capacity: None,
+            collapse_updates: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_batching(mut self, batching: OutputBatching) -> Self {
+        self.batching = batching;
+        self
+    }
+
+    #[must_use]
+    pub fn with_delivery_semantics(mut self, delivery_semantics: DeliverySemantics) -> Self {
+        self.delivery_semantics = delivery_semantics;
+        self
+    }
+
+    #[must_use]
+    pub fn with_output_mode(mut self, output_mode: OutputMode) -> Self {
+        self.output_mode = output_mode;
+        self
+    }
+
+    /// Makes the writers join the commit group `commit_group`, so that they commit each
+    /// time together with the other sinks of the group
+    /// (see [`crate::connectors::sink_commit`]).
+    #[must_use]
+    pub fn with_commit_group(mut self, commit_group: Option<String>) -> Self {
+        self.commit_group = commit_group;
+        self
+    }
+
+    #[must_use]
+    pub fn with_queue_capacity(mut self, queue_capacity: Option<usize>) -> Self {
+        self.queue_capacity = queue_capacity;
+        self
+    }
+
+    #[must_use]
+    pub fn with_collapsed_updates(mut self, collapse_updates: bool) -> Self {
+        self.collapse_updates = collapse_updates;
+        self
+    }
 }
 
 pub type IterationLogic<'a> = Box<
@@ -456,6 +674,7 @@ pub type IterationLogic<'a> = Box<
 pub enum BatchWrapper {
     // TODO remove BatchWrapper completely
     None,
+    #[cfg(feature = "python")]
     WithGil,
 }
 
@@ -463,7 +682,8 @@ impl BatchWrapper {
     pub fn run<R>(&self, logic: impl FnOnce() -> R) -> R {
         match self {
             BatchWrapper::None => logic(),
-            BatchWrapper::WithGil => Python::with_gil(|_| logic()),
+            #[cfg(feature = "python")]
+            BatchWrapper::WithGil => pyo3::Python::with_gil(|_| logic()),
         }
     }
 }
@@ -504,6 +724,13 @@ impl JoinExactlyOnce {
     }
 }
 
+/// What to do when `assert_monotonic` finds a value out of order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderingViolationPolicy {
+    Fail,
+    Log,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum IxKeyPolicy {
     FailMissing,
@@ -527,11 +754,16 @@ pub type OnTimeEndFn = Box<dyn FnMut(Timestamp) -> DynResult<()>>;
 pub type OnEndFn = Box<dyn FnMut() -> DynResult<()>>;
 pub type OnDataAsyncFn =
     Box<dyn Fn(Key, &[Value], Timestamp, isize) -> BoxFuture<'static, DynResult<()>>>;
+pub type OnBatchFn = Box<dyn FnMut(&[(Key, &[Value], isize)], Timestamp) -> DynResult<()>>;
 
 pub struct SubscribeCallbacks {
     pub wrapper: BatchWrapper,
     pub on_data: Option<OnDataFn>,
     pub on_data_async: Option<OnDataAsyncFn>,
+    /// Called with the changes of a minibatch at once, split into chunks of at most
+    /// `max_batch_size` changes if it is set.
+    pub on_batch: Option<OnBatchFn>,
+    pub max_batch_size: Option<usize>,
     pub on_time_end: Option<OnTimeEndFn>,
     pub on_end: Option<OnEndFn>,
     pub on_frontier: Option<OnTimeEndFn>,
@@ -548,6 +780,8 @@ impl SubscribeCallbacksBuilder {
                 wrapper: BatchWrapper::None,
                 on_data: None,
                 on_data_async: None,
+                on_batch: None,
+                max_batch_size: None,
                 on_time_end: None,
                 on_end: None,
                 on_frontier: None,
@@ -572,6 +806,10 @@ impl SubscribeCallbacksBuilder {
             self.inner.on_data_async.is_none(),
             "Cannot set both on_data and on_data_async callbacks"
         );
+        assert!(
+            self.inner.on_batch.is_none(),
+            "Cannot set both on_data and on_batch callbacks"
+        );
         self.inner.on_data = Some(on_data);
         self
     }
@@ -582,10 +820,29 @@ impl SubscribeCallbacksBuilder {
             self.inner.on_data.is_none(),
             "Cannot set both on_data and on_data_async callbacks"
         );
+        assert!(
+            self.inner.on_batch.is_none(),
+            "Cannot set both on_data_async and on_batch callbacks"
+        );
         self.inner.on_data_async = Some(on_data_async);
         self
     }
 
+    #[must_use]
+    pub fn on_batch(mut self, on_batch: OnBatchFn, max_batch_size: Option<usize>) -> Self {
+        assert!(
+            self.inner.on_data.is_none() && self.inner.on_data_async.is_none(),
+            "Cannot set both on_data and on_batch callbacks"
+        );
+        assert!(
+            max_batch_size != Some(0),
+            "The maximal batch size has to be positive"
+        );
+        self.inner.on_batch = Some(on_batch);
+        self.inner.max_batch_size = max_batch_size;
+        self
+    }
+
     #[must_use]
     pub fn on_time_end(mut self, on_time_end: OnTimeEndFn) -> Self {
         self.inner.on_time_end = Some(on_time_end);
@@ -614,6 +871,17 @@ pub struct SubscribeConfig {
 
 pub type ExportedTableCallback = Box<dyn FnMut() -> ControlFlow<()> + Send>;
 
+/// An Arrow Flight endpoint serving an exported table, see [`super::flight_server`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlightEndpoint {
+    /// The address of the server of the first process, the next processes listen on the
+    /// consecutive ports.
+    pub address: SocketAddr,
+    /// The name of the table on the server, used as the ticket of its stream.
+    pub name: String,
+    pub column_names: Vec<String>,
+}
+
 pub trait ExportedTable: Send + Sync + Any {
     fn failed(&self) -> bool;
 
@@ -734,7 +1002,7 @@ pub trait Graph {
         callbacks: SubscribeCallbacks,
         config: SubscribeConfig,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
     ) -> Result<()>;
 
     fn filter_table(
@@ -750,6 +1018,9 @@ pub trait Graph {
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle>;
 
+    /// With `late_table_properties`, the entries that are already late when they arrive
+    /// are not passed through but returned in a separate table.
+    #[allow(clippy::too_many_arguments)]
     fn forget(
         &self,
         table_handle: TableHandle,
@@ -758,7 +1029,8 @@ pub trait Graph {
         instance_column_path: ColumnPath,
         mark_forgetting_records: bool,
         table_properties: Arc<TableProperties>,
-    ) -> Result<TableHandle>;
+        late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)>;
 
     fn forget_immediately(
         &self,
@@ -782,6 +1054,8 @@ pub trait Graph {
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle>;
 
+    /// With `late_table_properties`, the entries that are already late when they arrive
+    /// are not passed through but returned in a separate table.
     fn buffer(
         &self,
         table_handle: TableHandle,
@@ -789,6 +1063,18 @@ pub trait Graph {
         current_time_column_path: ColumnPath,
         instance_column_path: ColumnPath,
         table_properties: Arc<TableProperties>,
+        late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)>;
+
+    /// Keeps at most `max_versions` rows per instance, the ones with the greatest
+    /// current times. The older rows are removed.
+    fn retain_last_versions(
+        &self,
+        table_handle: TableHandle,
+        current_time_column_path: ColumnPath,
+        instance_column_path: ColumnPath,
+        max_versions: usize,
+        table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle>;
 
     fn restrict_column(
@@ -871,11 +1157,13 @@ pub trait Graph {
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle>;
 
+    #[allow(clippy::too_many_arguments)]
     fn group_by_table(
         &self,
         table_handle: TableHandle,
         grouping_columns_paths: Vec<ColumnPath>,
         shard_policy: ShardPolicy,
+        shard_column: Option<usize>,
         reducers: Vec<ReducerData>,
         set_id: bool,
         table_properties: Arc<TableProperties>,
@@ -891,6 +1179,17 @@ pub trait Graph {
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle>;
 
+    /// Assigns each row a version number that starts at 1 and grows by one whenever
+    /// the columns under `column_paths` of the row with the same key change or the row
+    /// is deleted. The resulting table holds only the version.
+    fn version_rows(
+        &self,
+        table_handle: TableHandle,
+        column_paths: Vec<ColumnPath>,
+        unique_name: Option<&UniqueName>,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle>;
+
     fn gradual_broadcast(
         &self,
         input_table_handle: TableHandle,
@@ -956,22 +1255,30 @@ pub trait Graph {
         table_properties: Arc<TableProperties>,
         unique_name: Option<&UniqueName>,
         synchronization_group: Option<&ConnectorGroupDescriptor>,
-        max_backlog_size: Option<usize>,
+        backlog_limit: BacklogLimit,
+        rate_limit: RateLimit,
+        watermark_strategy: Option<WatermarkStrategy>,
         timestamp_at_start: Timestamp,
+        start_from: Option<OffsetAntichain>,
     ) -> Result<TableHandle>;
 
+    #[allow(clippy::too_many_arguments)]
     fn output_table(
         &self,
-        data_sink: Box<dyn Writer>,
-        data_formatter: Box<dyn Formatter>,
+        output_writers: OutputWriters,
         table_handle: TableHandle,
         column_paths: Vec<ColumnPath>,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
+        alignment_group: Option<&SinkAlignmentGroupDescriptor>,
     ) -> Result<()>;
 
     fn set_operator_properties(&self, operator_properties: OperatorProperties) -> Result<()>;
 
+    /// Returns, for each operator built so far, whether it is expected to induce a data
+    /// exchange, create an arrangement or persist its state when run.
+    fn explain(&self) -> Result<Vec<OperatorPlan>>;
+
     fn set_error_log(&self, error_log_handle: Option<ErrorLogHandle>) -> Result<()>;
 
     fn error_log(
@@ -992,6 +1299,7 @@ pub trait Graph {
         &self,
         table_handle: TableHandle,
         column_paths: Vec<ColumnPath>,
+        flight_endpoint: Option<FlightEndpoint>,
     ) -> Result<Arc<dyn ExportedTable>>;
 
     fn import_table(&self, table: Arc<dyn ExportedTable>) -> Result<TableHandle>;
@@ -1044,6 +1352,18 @@ pub trait Graph {
         table_properties: Arc<TableProperties>,
     ) -> Result<TableHandle>;
 
+    #[allow(clippy::too_many_arguments)]
+    fn assert_monotonic(
+        &self,
+        table_handle: TableHandle,
+        column_paths: Vec<ColumnPath>,
+        sequence_path: ColumnPath,
+        instance_path: Option<ColumnPath>,
+        strict: bool,
+        on_violation: OrderingViolationPolicy,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle>;
+
     fn unpack_snapshots(
         &self,
         table_handle: TableHandle,
@@ -1228,7 +1548,7 @@ impl Graph for ScopedGraph {
         callbacks: SubscribeCallbacks,
         config: SubscribeConfig,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
     ) -> Result<()> {
         self.try_with(|g| {
             g.subscribe_table(
@@ -1267,7 +1587,8 @@ impl Graph for ScopedGraph {
         instance_column_path: ColumnPath,
         mark_forgetting_records: bool,
         table_properties: Arc<TableProperties>,
-    ) -> Result<TableHandle> {
+        late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)> {
         self.try_with(|g| {
             g.forget(
                 table_handle,
@@ -1276,6 +1597,7 @@ impl Graph for ScopedGraph {
                 instance_column_path,
                 mark_forgetting_records,
                 table_properties,
+                late_table_properties,
             )
         })
     }
@@ -1341,7 +1663,8 @@ impl Graph for ScopedGraph {
         current_time_column_path: ColumnPath,
         instance_column_path: ColumnPath,
         table_properties: Arc<TableProperties>,
-    ) -> Result<TableHandle> {
+        late_table_properties: Option<Arc<TableProperties>>,
+    ) -> Result<(TableHandle, Option<TableHandle>)> {
         self.try_with(|g| {
             g.buffer(
                 table_handle,
@@ -1349,6 +1672,26 @@ impl Graph for ScopedGraph {
                 current_time_column_path,
                 instance_column_path,
                 table_properties,
+                late_table_properties,
+            )
+        })
+    }
+
+    fn retain_last_versions(
+        &self,
+        table_handle: TableHandle,
+        current_time_column_path: ColumnPath,
+        instance_column_path: ColumnPath,
+        max_versions: usize,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        self.try_with(|g| {
+            g.retain_last_versions(
+                table_handle,
+                current_time_column_path,
+                instance_column_path,
+                max_versions,
+                table_properties,
             )
         })
     }
@@ -1484,11 +1827,13 @@ impl Graph for ScopedGraph {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn group_by_table(
         &self,
         table_handle: TableHandle,
         grouping_columns_paths: Vec<ColumnPath>,
         shard_policy: ShardPolicy,
+        shard_column: Option<usize>,
         reducers: Vec<ReducerData>,
         set_id: bool,
         table_properties: Arc<TableProperties>,
@@ -1498,6 +1843,7 @@ impl Graph for ScopedGraph {
                 table_handle,
                 grouping_columns_paths,
                 shard_policy,
+                shard_column,
                 reducers,
                 set_id,
                 table_properties,
@@ -1526,6 +1872,16 @@ impl Graph for ScopedGraph {
         })
     }
 
+    fn version_rows(
+        &self,
+        table_handle: TableHandle,
+        column_paths: Vec<ColumnPath>,
+        unique_name: Option<&UniqueName>,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        self.try_with(|g| g.version_rows(table_handle, column_paths, unique_name, table_properties))
+    }
+
     fn gradual_broadcast(
         &self,
         input_table_handle: TableHandle,
@@ -1620,8 +1976,11 @@ impl Graph for ScopedGraph {
         table_properties: Arc<TableProperties>,
         unique_name: Option<&UniqueName>,
         synchronization_group: Option<&ConnectorGroupDescriptor>,
-        max_backlog_size: Option<usize>,
+        backlog_limit: BacklogLimit,
+        rate_limit: RateLimit,
+        watermark_strategy: Option<WatermarkStrategy>,
         timestamp_at_start: Timestamp,
+        start_from: Option<OffsetAntichain>,
     ) -> Result<TableHandle> {
         self.try_with(|g| {
             g.connector_table(
@@ -1632,29 +1991,32 @@ impl Graph for ScopedGraph {
                 table_properties,
                 unique_name,
                 synchronization_group,
-                max_backlog_size,
+                backlog_limit,
+                rate_limit,
+                watermark_strategy,
                 timestamp_at_start,
+                start_from,
             )
         })
     }
 
     fn output_table(
         &self,
-        data_sink: Box<dyn Writer>,
-        data_formatter: Box<dyn Formatter>,
+        output_writers: OutputWriters,
         table_handle: TableHandle,
         column_paths: Vec<ColumnPath>,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
+        alignment_group: Option<&SinkAlignmentGroupDescriptor>,
     ) -> Result<()> {
         self.try_with(|g| {
             g.output_table(
-                data_sink,
-                data_formatter,
+                output_writers,
                 table_handle,
                 column_paths,
                 unique_name,
                 sort_by_indices,
+                alignment_group,
             )
         })
     }
@@ -1663,6 +2025,10 @@ impl Graph for ScopedGraph {
         self.try_with(|g| g.set_operator_properties(operator_properties))
     }
 
+    fn explain(&self) -> Result<Vec<OperatorPlan>> {
+        self.try_with(|g| g.explain())
+    }
+
     fn set_error_log(&self, error_log_handle: Option<ErrorLogHandle>) -> Result<()> {
         self.try_with(|g| g.set_error_log(error_log_handle))
     }
@@ -1693,8 +2059,9 @@ impl Graph for ScopedGraph {
         &self,
         table_handle: TableHandle,
         column_paths: Vec<ColumnPath>,
+        flight_endpoint: Option<FlightEndpoint>,
     ) -> Result<Arc<dyn ExportedTable>> {
-        self.try_with(|g| g.export_table(table_handle, column_paths))
+        self.try_with(|g| g.export_table(table_handle, column_paths, flight_endpoint))
     }
 
     fn import_table(&self, table: Arc<dyn ExportedTable>) -> Result<TableHandle> {
@@ -1779,6 +2146,29 @@ impl Graph for ScopedGraph {
         self.try_with(|g| g.assert_append_only(table_handle, column_paths, table_properties))
     }
 
+    fn assert_monotonic(
+        &self,
+        table_handle: TableHandle,
+        column_paths: Vec<ColumnPath>,
+        sequence_path: ColumnPath,
+        instance_path: Option<ColumnPath>,
+        strict: bool,
+        on_violation: OrderingViolationPolicy,
+        table_properties: Arc<TableProperties>,
+    ) -> Result<TableHandle> {
+        self.try_with(|g| {
+            g.assert_monotonic(
+                table_handle,
+                column_paths,
+                sequence_path,
+                instance_path,
+                strict,
+                on_violation,
+                table_properties,
+            )
+        })
+    }
+
     fn unpack_snapshots(
         &self,
         table_handle: TableHandle,
@@ -3,7 +3,7 @@
 use std::env;
 use std::sync::Arc;
 use std::thread::{Builder, JoinHandle};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use arc_swap::ArcSwapOption;
 use hyper::service::{make_service_fn, service_fn};
@@ -12,14 +12,24 @@ use log::{error, info};
 use prometheus_client::encoding::text::encode;
 use prometheus_client::metrics::gauge::Gauge;
 use prometheus_client::registry::Registry;
+use serde_json::{json, Value as JsonValue};
 use tokio::sync::oneshot::Sender;
 
+use crate::connectors::control::{
+    connector_states, control_connector, ConnectorCommand, ConnectorControlError,
+};
+use crate::connectors::monitoring::output_connector_throughputs;
 use crate::engine::dataflow::monitoring::ProberStats;
+use crate::engine::served_tables::query_served_tables;
+use crate::engine::shutdown::request_suspend;
+use crate::engine::websocket::handle_websocket_request;
+use crate::persistence::savepoint::{request_savepoint, SavepointError};
 
 use super::Error;
 use super::Graph;
 
 const DEFAULT_MONITORING_HTTP_PORT: u16 = 20000;
+const DEFAULT_SAVEPOINT_TIMEOUT: Duration = Duration::from_secs(60);
 
 /// Retrieves metrics from prober stats in the `OpenMetrics` format
 /// See <https://github.com/OpenObservability/OpenMetrics>
@@ -78,6 +88,28 @@ fn metrics_from_stats(stats: &Arc<ArcSwapOption<ProberStats>>) -> String {
                 );
             });
 
+        for (table_index, table_gauge) in &stats_owned.table_gauges {
+            let operator_id = table_gauge.operator_id;
+            let rows: Gauge = Gauge::default();
+            rows.set(table_gauge.rows as i64);
+            registry.register(
+                format!("table_{table_index}_rows").as_str(),
+                format!("Number of rows of table {table_index} of operator {operator_id}").as_str(),
+                rows,
+            );
+            let estimated_bytes: Gauge = Gauge::default();
+            estimated_bytes.set(table_gauge.estimated_bytes as i64);
+            registry.register(
+                format!("table_{table_index}_estimated_bytes").as_str(),
+                format!(
+                    "Estimated size of the arrangement of table {table_index} of operator \
+                     {operator_id} in bytes"
+                )
+                .as_str(),
+                estimated_bytes,
+            );
+        }
+
         // iterate over all stats_owned.operators_stats and register a gauge for each operator
         for (operator_name, operator_stats) in &stats_owned.operators_stats {
             let gauge: Gauge = Gauge::default();
@@ -99,9 +131,108 @@ fn metrics_from_stats(stats: &Arc<ArcSwapOption<ProberStats>>) -> String {
     metrics_text
 }
 
+/// Requests a savepoint, waiting for it at most for `timeout_ms` milliseconds given in
+/// the query. The result is the status code of the response and its JSON body.
+async fn savepoint_response(query: Option<&str>) -> (StatusCode, JsonValue) {
+    let timeout_ms = form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+        .find(|(name, _value)| name == "timeout_ms")
+        .map(|(_name, value)| value.parse::<u64>());
+    let timeout = match timeout_ms {
+        None => DEFAULT_SAVEPOINT_TIMEOUT,
+        Some(Ok(timeout_ms)) => Duration::from_millis(timeout_ms),
+        Some(Err(e)) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                json!({ "error": format!("invalid timeout_ms: {e}") }),
+            );
+        }
+    };
+    // waiting for the workers would block the server otherwise
+    let result = tokio::task::spawn_blocking(move || request_savepoint(timeout))
+        .await
+        .unwrap_or_else(|e| Err(SavepointError::Failed(e.to_string())));
+    match result {
+        Ok(time) => (StatusCode::OK, json!({ "savepoint": time.0 })),
+        Err(error) => {
+            let status = match error {
+                SavepointError::NoPersistence => StatusCode::NOT_FOUND,
+                SavepointError::TimedOut(_) => StatusCode::GATEWAY_TIMEOUT,
+                SavepointError::Finished => StatusCode::CONFLICT,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, json!({ "error": error.to_string() }))
+        }
+    }
+}
+
+/// Requests a suspension of the computation for `POST /suspend`. The response is sent
+/// before the computation finishes.
+fn suspend_response() -> (StatusCode, JsonValue) {
+    match request_suspend() {
+        Ok(()) => (StatusCode::ACCEPTED, json!({ "status": "suspending" })),
+        // a computation without persistence has nothing to suspend
+        Err(error) => (StatusCode::NOT_FOUND, json!({ "error": error.to_string() })),
+    }
+}
+
+/// Lists the states of the controlled input connectors for `GET /connectors`.
+fn connector_states_response() -> (StatusCode, JsonValue) {
+    let states: serde_json::Map<_, _> = connector_states()
+        .into_iter()
+        .map(|(name, state)| (name, JsonValue::from(state.to_string())))
+        .collect();
+    (StatusCode::OK, json!({ "connectors": states }))
+}
+
+/// Lists the throughputs of the output connectors for `GET /sinks`.
+fn sink_throughputs_response() -> (StatusCode, JsonValue) {
+    let sinks: Vec<_> = output_connector_throughputs()
+        .into_iter()
+        .map(|(name, throughput)| json!({ "name": name, "throughput": throughput }))
+        .collect();
+    (StatusCode::OK, json!({ "sinks": sinks }))
+}
+
+/// Sends a command to an input connector for `POST /connectors/<name>/<command>`, with
+/// `path` being the part after `/connectors/`.
+fn control_connector_response(path: &str) -> (StatusCode, JsonValue) {
+    let Some((name, command)) = path.rsplit_once('/') else {
+        return (
+            StatusCode::NOT_FOUND,
+            json!({ "error": "no such resource" }),
+        );
+    };
+    let result = command
+        .parse::<ConnectorCommand>()
+        .and_then(|command| control_connector(name, command));
+    match result {
+        Ok(state) => (
+            StatusCode::OK,
+            json!({ "connector": name, "state": state.to_string() }),
+        ),
+        Err(error) => {
+            let status = match error {
+                ConnectorControlError::UnknownConnector(_) => StatusCode::NOT_FOUND,
+                ConnectorControlError::Drained(_) => StatusCode::CONFLICT,
+                _ => StatusCode::BAD_REQUEST,
+            };
+            (status, json!({ "error": error.to_string() }))
+        }
+    }
+}
+
 /// Starts a lightweight http server allowing monitoring.
 /// Available at: http://localhost:PORT/status
 /// where PORT is `PATHWAY_MONITORING_HTTP_PORT + process_id`
+/// The tables registered with [`register_served_table`](super::served_tables::register_served_table)
+/// are served under `/tables` and the WebSocket channels of the subscribed tables
+/// under `/subscriptions`. `POST /savepoint` commits the persisted state right away
+/// (see [`crate::persistence::savepoint`]) and `POST /suspend` commits it and stops the
+/// computation (see [`crate::engine::shutdown`]). `GET /connectors` lists the states of the
+/// input connectors with unique names, which can be paused, resumed and drained with
+/// `POST /connectors/<name>/<command>` (see [`crate::connectors::control`]). `GET /sinks`
+/// lists the rows and bytes written per second and the flush latencies of the output
+/// connectors over the last minute.
 /// It uses tokio and hyper. The status is passed using arcswap to avoid mutexes.
 pub fn start_http_server_thread(
     process_id: u16,
@@ -132,6 +263,12 @@ pub fn start_http_server_thread(
                                 let stats = stats.clone();
 
                                 async move {
+                                    if let Some(channel_name) =
+                                        req.uri().path().strip_prefix("/subscriptions/")
+                                    {
+                                        let channel_name = channel_name.to_string();
+                                        return Ok(handle_websocket_request(req, &channel_name));
+                                    }
                                     let mut response = Response::new(Body::empty());
                                     let stats = stats.clone();
 
@@ -156,6 +293,88 @@ pub fn start_http_server_thread(
                                             );
                                         }
 
+                                        (&Method::GET, path)
+                                            if path == "/tables" || path.starts_with("/tables/") =>
+                                        {
+                                            let (status, body) = query_served_tables(
+                                                &path["/tables".len()..],
+                                                req.uri().query(),
+                                            );
+                                            *response.status_mut() = status;
+                                            *response.body_mut() = Body::from(body.to_string());
+                                            response.headers_mut().insert(
+                                                header::CONTENT_TYPE,
+                                                header::HeaderValue::from_static(
+                                                    "application/json",
+                                                ),
+                                            );
+                                        }
+
+                                        (&Method::POST, "/savepoint") => {
+                                            let (status, body) =
+                                                savepoint_response(req.uri().query()).await;
+                                            *response.status_mut() = status;
+                                            *response.body_mut() = Body::from(body.to_string());
+                                            response.headers_mut().insert(
+                                                header::CONTENT_TYPE,
+                                                header::HeaderValue::from_static(
+                                                    "application/json",
+                                                ),
+                                            );
+                                        }
+
+                                        (&Method::POST, "/suspend") => {
+                                            let (status, body) = suspend_response();
+                                            *response.status_mut() = status;
+                                            *response.body_mut() = Body::from(body.to_string());
+                                            response.headers_mut().insert(
+                                                header::CONTENT_TYPE,
+                                                header::HeaderValue::from_static(
+                                                    "application/json",
+                                                ),
+                                            );
+                                        }
+
+                                        (&Method::GET, "/connectors") => {
+                                            let (status, body) = connector_states_response();
+                                            *response.status_mut() = status;
+                                            *response.body_mut() = Body::from(body.to_string());
+                                            response.headers_mut().insert(
+                                                header::CONTENT_TYPE,
+                                                header::HeaderValue::from_static(
+                                                    "application/json",
+                                                ),
+                                            );
+                                        }
+
+                                        (&Method::GET, "/sinks") => {
+                                            let (status, body) = sink_throughputs_response();
+                                            *response.status_mut() = status;
+                                            *response.body_mut() = Body::from(body.to_string());
+                                            response.headers_mut().insert(
+                                                header::CONTENT_TYPE,
+                                                header::HeaderValue::from_static(
+                                                    "application/json",
+                                                ),
+                                            );
+                                        }
+
+                                        (&Method::POST, path)
+                                            if path.starts_with("/connectors/") =>
+                                        {
+                                            let (status, body) = control_connector_response(
+                                                &path["/connectors/".len()..],
+                                            );
+                                            *response.status_mut() = status;
+                                            *response.body_mut() = Body::from(body.to_string());
+                                            response.headers_mut().insert(
+                                                header::CONTENT_TYPE,
+                                                header::HeaderValue::from_static(
+                                                    "application/json",
+                                                ),
+                                            );
+                                        }
+
                                         _ => {
                                             *response.status_mut() = StatusCode::NOT_FOUND;
                                         }
@@ -0,0 +1,82 @@
+// Copyright © 2026 Pathway
+
+//! Detection of hash collisions of the primary keys of input tables.
+//!
+//! The ids of input rows are hashes of their primary key values, so two different
+//! primary keys may get the same id. With the default 128-bit ids this is practically
+//! impossible, but it becomes a real concern with the smaller ids enabled by the
+//! `yolo-id64` and `yolo-id32` features.
+//!
+//! When `PATHWAY_DETECT_KEY_COLLISIONS=1` is set, each input connector keeps a 64-bit
+//! fingerprint of the primary key values of every id it has produced, computed with a
+//! hash function independent from the one used for ids. A row whose values have a
+//! different fingerprint than the ones seen before under the same id is reported in the
+//! error log. The fingerprints are never released, so the memory used grows with the
+//! number of distinct ids read by the connector.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::env;
+
+use xxhash_rust::xxh3::Xxh3 as Hasher;
+
+use super::report_error::LogError;
+use super::value::HashInto;
+use super::{DataError, Key, Value};
+
+// any seed other than the default one makes the fingerprints independent from the ids
+const FINGERPRINT_SEED: u64 = 0x7061_7468_7761_7921;
+
+/// Returns whether the detection of key collisions is enabled for this process.
+pub fn key_collision_detection_enabled() -> bool {
+    env::var("PATHWAY_DETECT_KEY_COLLISIONS") == Ok("1".to_string())
+}
+
+fn fingerprint(values: &[Value]) -> u64 {
+    let mut hasher = Hasher::with_seed(FINGERPRINT_SEED);
+    for value in values {
+        value.hash_into(&mut hasher);
+    }
+    hasher.digest()
+}
+
+pub struct KeyCollisionDetector {
+    fingerprints: HashMap<Key, u64>,
+    error_logger: Box<dyn LogError>,
+}
+
+impl KeyCollisionDetector {
+    pub fn new(error_logger: Box<dyn LogError>) -> Self {
+        Self {
+            fingerprints: HashMap::new(),
+            error_logger,
+        }
+    }
+
+    /// Checks that `key`, computed from the primary key `values`, hasn't been computed
+    /// before from different values. A collision is reported in the error log.
+    pub fn check(&mut self, key: Key, values: &[Value]) {
+        let fingerprint = fingerprint(values);
+        match self.fingerprints.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(fingerprint);
+            }
+            Entry::Occupied(entry) => {
+                if *entry.get() != fingerprint {
+                    self.error_logger.log_error(DataError::KeyCollision {
+                        key,
+                        values: Value::from(values),
+                    });
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.fingerprints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fingerprints.is_empty()
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright © 2026 Pathway
+
+//! Callbacks notified about the progress of a run.
+//!
+//! [`RunCallbacks`] are passed to [`run_with_new_dataflow_graph`] so that an embedder can
+//! coordinate external systems with the computation. Each callback is called once per
+//! process, not once per worker:
+//! - `on_start` when all the workers of the process have built the dataflow and start
+//!   processing data;
+//! - `on_snapshot_replay_done` when all the input connectors of the process have
+//!   replayed their persisted snapshots. The connectors without persistence have
+//!   nothing to replay, so without persistence it's called right after `on_start`;
+//! - `on_frontier` each time the output frontier of the process advances, that is,
+//!   when the outputs computed by the process are complete for all times before the
+//!   given one;
+//! - `on_shutdown` when the computation has stopped, whether it has finished or failed.
+//!
+//! All but `on_shutdown` are called from the worker threads, never concurrently and
+//! without blocking the other workers: an event happening while a callback runs is
+//! passed on once it returns. An error returned by a callback makes the run fail.
+//!
+//! [`run_with_new_dataflow_graph`]: super::run_with_new_dataflow_graph
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use super::error::DynResult;
+use super::{Timestamp, TotalFrontier};
+
+pub type OnLifecycleEventFn = Box<dyn Fn() -> DynResult<()> + Send + Sync>;
+pub type OnRunFrontierFn = Box<dyn Fn(Timestamp) -> DynResult<()> + Send + Sync>;
+
+#[derive(Default)]
+pub struct RunCallbacks {
+    pub on_start: Option<OnLifecycleEventFn>,
+    pub on_snapshot_replay_done: Option<OnLifecycleEventFn>,
+    pub on_frontier: Option<OnRunFrontierFn>,
+    pub on_shutdown: Option<OnLifecycleEventFn>,
+}
+
+pub struct RunCallbacksBuilder {
+    inner: RunCallbacks,
+}
+
+impl RunCallbacksBuilder {
+    pub fn new() -> Self {
+        Self {
+            inner: RunCallbacks::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn build(self) -> RunCallbacks {
+        self.inner
+    }
+
+    #[must_use]
+    pub fn on_start(mut self, on_start: OnLifecycleEventFn) -> Self {
+        self.inner.on_start = Some(on_start);
+        self
+    }
+
+    #[must_use]
+    pub fn on_snapshot_replay_done(mut self, on_snapshot_replay_done: OnLifecycleEventFn) -> Self {
+        self.inner.on_snapshot_replay_done = Some(on_snapshot_replay_done);
+        self
+    }
+
+    #[must_use]
+    pub fn on_frontier(mut self, on_frontier: OnRunFrontierFn) -> Self {
+        self.inner.on_frontier = Some(on_frontier);
+        self
+    }
+
+    #[must_use]
+    pub fn on_shutdown(mut self, on_shutdown: OnLifecycleEventFn) -> Self {
+        self.inner.on_shutdown = Some(on_shutdown);
+        self
+    }
+}
+
+impl Default for RunCallbacksBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+enum LifecycleEvent {
+    Start,
+    SnapshotReplayDone,
+    Frontier(Timestamp),
+}
+
+struct LifecycleState {
+    started: usize,
+    replayed: usize,
+    // the last output frontier of each worker of the process, `None` until reported
+    frontiers: Vec<Option<TotalFrontier<Timestamp>>>,
+    reported_frontier: Timestamp,
+    // the events not yet passed to the callbacks, in the order they happened
+    pending: VecDeque<LifecycleEvent>,
+    // whether some worker is calling the callbacks for the pending events
+    delivering: bool,
+}
+
+impl LifecycleState {
+    fn push(&mut self, event: LifecycleEvent) {
+        // only the latest frontier is worth reporting if the earlier one wasn't yet
+        if let LifecycleEvent::Frontier(time) = event {
+            if let Some(LifecycleEvent::Frontier(pending)) = self.pending.back_mut() {
+                *pending = time;
+                return;
+            }
+        }
+        self.pending.push_back(event);
+    }
+}
+
+/// Combines the events of the workers of a process to call the [`RunCallbacks`] once
+/// per process. The workers are identified by their index within the process.
+pub struct RunLifecycle {
+    callbacks: RunCallbacks,
+    workers: usize,
+    // the callbacks are called without the lock held, by one worker at a time, so that
+    // they are never called concurrently and the frontiers are passed in order, while
+    // the other workers only queue their events and go on
+    state: Mutex<LifecycleState>,
+}
+
+impl RunLifecycle {
+    pub fn new(callbacks: RunCallbacks, workers: usize) -> Self {
+        Self {
+            callbacks,
+            workers,
+            state: Mutex::new(LifecycleState {
+                started: 0,
+                replayed: 0,
+                frontiers: vec![None; workers],
+                reported_frontier: Timestamp(0),
+                pending: VecDeque::new(),
+                delivering: false,
+            }),
+        }
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, LifecycleState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Calls the callbacks for the pending events, unless another worker is already doing
+    // it, in which case it also handles the events queued by this one.
+    fn deliver(&self, mut state: MutexGuard<'_, LifecycleState>) -> DynResult<()> {
+        if state.delivering {
+            return Ok(());
+        }
+        state.delivering = true;
+        while let Some(event) = state.pending.pop_front() {
+            drop(state);
+            let result = self.call(&event);
+            state = self.lock_state();
+            if let Err(error) = result {
+                state.delivering = false;
+                return Err(error);
+            }
+        }
+        state.delivering = false;
+        Ok(())
+    }
+
+    fn call(&self, event: &LifecycleEvent) -> DynResult<()> {
+        match event {
+            LifecycleEvent::Start => {
+                if let Some(on_start) = &self.callbacks.on_start {
+                    on_start()?;
+                }
+            }
+            LifecycleEvent::SnapshotReplayDone => {
+                if let Some(on_snapshot_replay_done) = &self.callbacks.on_snapshot_replay_done {
+                    on_snapshot_replay_done()?;
+                }
+            }
+            LifecycleEvent::Frontier(time) => {
+                if let Some(on_frontier) = &self.callbacks.on_frontier {
+                    on_frontier(*time)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Notes that a worker has started processing data. The last one calls `on_start`.
+    pub fn worker_started(&self) -> DynResult<()> {
+        let mut state = self.lock_state();
+        state.started += 1;
+        if state.started == self.workers {
+            state.push(LifecycleEvent::Start);
+        }
+        self.deliver(state)
+    }
+
+    /// Notes that all the input connectors of a worker have replayed their snapshots.
+    /// The last worker to do so calls `on_snapshot_replay_done`.
+    pub fn worker_replayed_snapshot(&self) -> DynResult<()> {
+        let mut state = self.lock_state();
+        state.replayed += 1;
+        if state.replayed == self.workers {
+            state.push(LifecycleEvent::SnapshotReplayDone);
+        }
+        self.deliver(state)
+    }
+
+    /// Updates the output frontier of the worker `worker`. `on_frontier` is called if
+    /// the minimum of the frontiers of all workers has advanced.
+    pub fn worker_frontier(
+        &self,
+        worker: usize,
+        frontier: TotalFrontier<Timestamp>,
+    ) -> DynResult<()> {
+        let mut state = self.lock_state();
+        if state.frontiers[worker] == Some(frontier) {
+            return Ok(());
+        }
+        state.frontiers[worker] = Some(frontier);
+        let Some(TotalFrontier::At(time)) = state.frontiers.iter().min().copied().flatten() else {
+            return Ok(());
+        };
+        if time > state.reported_frontier {
+            state.reported_frontier = time;
+            state.push(LifecycleEvent::Frontier(time));
+        }
+        self.deliver(state)
+    }
+
+    /// Calls `on_shutdown`, once all the workers have stopped.
+    pub fn shutdown(&self) -> DynResult<()> {
+        if let Some(on_shutdown) = &self.callbacks.on_shutdown {
+            on_shutdown()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright © 2026 Pathway
+
+//! Identification of the engine run that produced the data.
+//!
+//! Every run of a pipeline gets a run id, which is either given by the user
+//! with the `PATHWAY_RUN_ID` environment variable, derived from the location of the
+//! persisted data if the run is persisted, or generated randomly, and a graph
+//! version: a hash of the structure of the dataflow, which stays the same across
+//! restarts as long as the pipeline isn't changed. The pair is attached to the
+//! telemetry, to the rows of the error log and, on request, to the output rows.
+//!
+//! If the run is persisted, the lineage is also stored with the persisted metadata,
+//! so that a restarted run knows which run and graph version have produced the state
+//! it resumes from.
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunLineage {
+    pub run_id: String,
+    pub graph_version: Option<String>,
+}
+
+impl RunLineage {
+    pub fn new(run_id: String, graph_version: Option<String>) -> Self {
+        Self {
+            run_id,
+            graph_version,
+        }
+    }
+
+    /// Reports the relation between this run and the run whose persisted state
+    /// it resumes from.
+    pub fn log_resumed_from(&self, previous: &Self) {
+        info!(
+            "Run {} resumes the state persisted by run {} (graph version {})",
+            self.run_id,
+            previous.run_id,
+            previous.graph_version.as_deref().unwrap_or("unknown"),
+        );
+        if let (Some(current), Some(previous_version)) =
+            (&self.graph_version, &previous.graph_version)
+        {
+            if current != previous_version {
+                warn!("The graph version has changed since the previous run: {previous_version} -> {current}. The persisted state may be incompatible with the modified pipeline.");
+            }
+        }
+    }
+}
@@ -12,6 +12,8 @@ pub mod report_error;
 pub mod value;
 pub use self::value::{Key, KeyImpl, ShardPolicy, Type, Value};
 
+pub mod key_collisions;
+
 pub mod reduce;
 pub use reduce::Reducer;
 
@@ -19,40 +21,72 @@ pub mod graph;
 pub use graph::{
     BatchWrapper, ColumnHandle, ColumnPath, ColumnProperties, ComplexColumn, Computer,
     ConcatHandle, Context, DataRow, ErrorLogHandle, ExportedTable, ExportedTableCallback,
-    ExpressionData, Graph, IterationLogic, IxKeyPolicy, IxerHandle, JoinData, JoinType,
-    LegacyTable, ReducerData, ScopedGraph, TableHandle, TableProperties, UniverseHandle,
-    WindowProperties,
+    ExpressionData, FlightEndpoint, Graph, IterationLogic, IxKeyPolicy, IxerHandle, JoinData,
+    JoinType, LegacyTable, OrderingViolationPolicy, ReducerData, ScopedGraph, TableHandle,
+    TableProperties, UniverseHandle, WindowProperties,
 };
 
 pub mod http_server;
 pub use http_server::maybe_run_http_server_thread;
 
+pub mod served_tables;
+
+pub mod change_batches;
+pub mod flight_server;
+pub mod shared_memory_export;
+
+pub mod websocket;
+
+pub mod clock;
+
+pub mod columnar;
+pub use columnar::{ColumnarBatch, ColumnarBatchBuilder, ColumnarColumn, ColumnarRows};
+
 pub mod dataflow;
+pub mod dry_run;
+pub mod explain;
+pub mod lifecycle;
 pub use dataflow::{run_with_new_dataflow_graph, WakeupReceiver};
 
 pub mod expression;
 pub use expression::{
     AnyExpression, BoolExpression, DateTimeNaiveExpression, DateTimeUtcExpression,
-    DurationExpression, Expression, Expressions, FloatExpression, IntExpression, PointerExpression,
-    StringExpression,
+    DateTimeZonedExpression, DurationExpression, Expression, Expressions, FloatExpression,
+    IntExpression, JsonPathCache, PatternCache, PointerExpression, RegexCache, StringExpression,
 };
 
 pub mod progress_reporter;
 pub mod time;
-pub use time::{DateTimeNaive, DateTimeUtc, Duration};
+pub use time::{DateTimeNaive, DateTimeUtc, DateTimeZoned, Duration};
 
 pub mod frontier;
 pub use frontier::TotalFrontier;
 
+pub mod lineage;
+pub use lineage::RunLineage;
+
+pub mod shutdown;
+
+pub mod stepping;
+pub use stepping::StepMode;
+
 pub mod telemetry;
 pub use telemetry::Config;
 
+pub mod user_metrics;
+
 pub mod external_index_wrappers;
 
 pub mod timestamp;
 pub use timestamp::Timestamp;
 
+#[cfg(not(feature = "python"))]
+mod opaque_py_object;
+#[cfg(not(feature = "python"))]
+pub use opaque_py_object::PyObjectWrapper;
+#[cfg(feature = "python")]
 pub mod py_object_wrapper;
+#[cfg(feature = "python")]
 pub use py_object_wrapper::PyObjectWrapper;
 
 pub mod workload_tracker;
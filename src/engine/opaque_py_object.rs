@@ -0,0 +1,33 @@
+// Copyright © 2026 Pathway
+
+//! [`PyObjectWrapper`] for the builds without Python.
+//!
+//! Python objects can't be created without Python, but the values containing them may
+//! still come from persisted data written by a build with Python. Such objects are kept
+//! serialized, so that they can be passed through the computation and written back
+//! unchanged.
+
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use super::error::DynResult;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PyObjectWrapper {
+    // the same layout as the serialized form of the wrapper with Python
+    object: Vec<u8>,
+    serializer: Vec<u8>,
+}
+
+impl PyObjectWrapper {
+    pub fn as_bytes(&self) -> DynResult<Vec<u8>> {
+        Ok(self.object.clone())
+    }
+}
+
+impl Display for PyObjectWrapper {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "PyObjectWrapper(<{} bytes>)", self.object.len())
+    }
+}
@@ -6,15 +6,20 @@ use std::thread::{self, JoinHandle};
 use std::time::{Duration, SystemTime};
 
 use arc_swap::ArcSwapOption;
-use pyo3::{PyObject, Python};
 
 use crate::engine::dataflow::monitoring::ProberStats;
-use crate::python_api::threads::PythonThreadState;
 
+use super::error::DynResult;
 use super::Graph;
 
 const PROGRESS_REPORTING_PERIOD: Duration = Duration::from_millis(200);
 
+/// Receives the statistics of the computation, periodically, in a dedicated thread.
+pub trait StatsMonitor: Send {
+    /// `now` is the current time in milliseconds since the Unix epoch.
+    fn update_monitoring(&self, stats: &ProberStats, now: u64) -> DynResult<()>;
+}
+
 pub struct Runner {
     should_finish: Arc<AtomicBool>,
     reporting_thread_handle: Option<JoinHandle<()>>,
@@ -24,7 +29,7 @@ impl Runner {
     fn run(
         printing_period: Duration,
         stats: &Arc<ArcSwapOption<ProberStats>>,
-        stats_monitor: PyObject,
+        stats_monitor: Box<dyn StatsMonitor>,
     ) -> Runner {
         let should_finish = Arc::new(AtomicBool::new(false));
         let thread_handle = {
@@ -33,8 +38,6 @@ impl Runner {
             thread::Builder::new()
                 .name("pathway:progress_reporting".to_owned())
                 .spawn(move || {
-                    let thread_state = PythonThreadState::new();
-
                     while !should_finish.load(Ordering::Relaxed) {
                         if let Some(ref stats) = *stats.load() {
                             let now = SystemTime::now();
@@ -44,21 +47,11 @@ impl Runner {
                                     .as_millis(),
                             )
                             .unwrap();
-                            Python::with_gil(|py| {
-                                stats_monitor
-                                    .call_method1(
-                                        py,
-                                        "update_monitoring",
-                                        ((**stats).clone(), duration),
-                                    )
-                                    .unwrap();
-                            });
+                            stats_monitor.update_monitoring(stats, duration).unwrap();
                         }
 
                         thread::park_timeout(printing_period);
                     }
-
-                    drop(thread_state);
                 })
                 .expect("progress reporting thread creation failed")
         };
@@ -83,7 +76,7 @@ impl Drop for Runner {
 pub fn maybe_run_reporter(
     monitoring_level: MonitoringLevel,
     graph: &dyn Graph,
-    stats_monitor: Option<PyObject>,
+    stats_monitor: Option<Box<dyn StatsMonitor>>,
 ) -> Option<Runner> {
     if monitoring_level != MonitoringLevel::None {
         if let Some(stats_monitor) = stats_monitor {
@@ -0,0 +1,329 @@
+// Copyright © 2026 Pathway
+
+//! Exported tables served over HTTP.
+//!
+//! An exported table can be registered under a route with [`register_served_table`].
+//! Its current state can then be queried on the monitoring HTTP server of the process
+//! (see [`super::http_server`]):
+//! - `GET /tables` lists the served routes together with their columns;
+//! - `GET /tables/<route>` returns all rows of the table, optionally filtered by
+//!   equality on columns, e.g. `GET /tables/users?city=Paris&age=30`;
+//! - `GET /tables/<route>/<id>` returns the row with the given id, if it passes the
+//!   filters given in the same way.
+//!
+//! The answers are always computed from a consistent state of the table, that is, the
+//! state containing all the changes done before the frontier of the exported table and
+//! none after it. If the table is computed by many workers, the smallest of their
+//! frontiers is used. The filter values are parsed as JSON and taken as plain strings
+//! if they are not valid JSON, so `?age=30` matches the integer `30` and `?city=Paris`
+//! matches the string `"Paris"`.
+//!
+//! The routes are registered process-wide and each process serves the rows computed by
+//! its own workers. A route is released once the computation that registered it is
+//! dropped.
+//!
+//! The state of each route is kept as a map from the ids to the rows, updated with the
+//! changes that arrived since the previous request. A request takes a snapshot of the
+//! map under the lock of its route and builds the answer after releasing it, so the
+//! requests for different routes don't wait for each other.
+
+use std::collections::HashMap;
+use std::mem::take;
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard, PoisonError, Weak};
+
+use hyper::StatusCode;
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+use crate::connectors::data_format::serialize_value_to_json;
+
+use super::value::parse_pathway_pointer;
+use super::{DataRow, Error, ExportedTable, Key, Result, Timestamp, TotalFrontier, Value};
+
+#[derive(Clone)]
+struct Snapshot {
+    frontier: TotalFrontier<Timestamp>,
+    rows: HashMap<Key, Vec<Value>>,
+}
+
+struct RouteState {
+    // offsets up to which the data of the parts has been read, by worker index
+    offsets: HashMap<usize, usize>,
+    // rows read from the parts that are not yet done at the frontier of the snapshot
+    pending: Vec<DataRow>,
+    snapshot: Arc<Snapshot>,
+}
+
+impl RouteState {
+    fn new() -> Self {
+        Self {
+            offsets: HashMap::new(),
+            pending: Vec::new(),
+            snapshot: Arc::new(Snapshot {
+                frontier: TotalFrontier::At(Timestamp(0)),
+                rows: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Applies the changes of the parts done at `frontier` and returns the snapshot of
+    /// the table at it. The frontiers have to be passed in a non-decreasing order.
+    fn advance(
+        &mut self,
+        parts: &[(usize, Arc<dyn ExportedTable>)],
+        frontier: TotalFrontier<Timestamp>,
+    ) -> Arc<Snapshot> {
+        for (worker_index, part) in parts {
+            let offset = self.offsets.entry(*worker_index).or_default();
+            let (data, new_offset) = part.data_from_offset(*offset);
+            *offset = new_offset;
+            self.pending.extend(data);
+        }
+        let (mut done, pending): (Vec<_>, Vec<_>) = take(&mut self.pending)
+            .into_iter()
+            .partition(|row| frontier.is_time_done(&row.time));
+        self.pending = pending;
+        if done.is_empty() && self.snapshot.frontier == frontier {
+            return self.snapshot.clone();
+        }
+
+        done.sort_unstable_by(|a, b| a.key.cmp(&b.key).then_with(|| a.values.cmp(&b.values)));
+        done.dedup_by(|new, old| {
+            if new.key == old.key && new.values == old.values {
+                old.diff += new.diff;
+                true
+            } else {
+                false
+            }
+        });
+        // copies the rows only if an earlier snapshot is still used by a request
+        let snapshot = Arc::make_mut(&mut self.snapshot);
+        snapshot.frontier = frontier;
+        // the deletions go first, so that a row replaced at the same time is kept
+        for row in done.iter().filter(|row| row.diff < 0) {
+            if snapshot.rows.get(&row.key) == Some(&row.values) {
+                snapshot.rows.remove(&row.key);
+            }
+        }
+        for row in done.into_iter().filter(|row| row.diff > 0) {
+            snapshot.rows.insert(row.key, row.values);
+        }
+        self.snapshot.clone()
+    }
+}
+
+struct ServedTable {
+    column_names: Vec<String>,
+    // exported tables of the workers computing the table, by worker index
+    parts: HashMap<usize, Weak<dyn ExportedTable>>,
+    state: Arc<Mutex<RouteState>>,
+}
+
+impl ServedTable {
+    fn live_parts(&self) -> Vec<(usize, Arc<dyn ExportedTable>)> {
+        self.parts
+            .iter()
+            .filter_map(|(worker_index, part)| Some((*worker_index, part.upgrade()?)))
+            .collect()
+    }
+}
+
+static SERVED_TABLES: LazyLock<Mutex<HashMap<String, ServedTable>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// a request that panicked can't leave the maps in an inconsistent state, so the poisoning
+// is ignored instead of making all the later requests fail
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Registers the part of a table exported by the worker `worker_index` under `route`.
+/// All the workers computing the table have to register their parts under the same
+/// route and with the same column names.
+pub fn register_served_table(
+    route: String,
+    column_names: Vec<String>,
+    worker_index: usize,
+    table: &Arc<dyn ExportedTable>,
+) -> Result<()> {
+    if route.is_empty() || route.contains('/') {
+        return Err(Error::InvalidTableRoute(route));
+    }
+    let mut served_tables = lock(&SERVED_TABLES);
+    let served_table = served_tables
+        .entry(route.clone())
+        .or_insert_with(|| ServedTable {
+            column_names: column_names.clone(),
+            parts: HashMap::new(),
+            state: Arc::new(Mutex::new(RouteState::new())),
+        });
+    // parts left by the computations that have already finished
+    served_table.parts.retain(|_, part| part.strong_count() > 0);
+    if served_table.parts.is_empty() {
+        served_table.column_names = column_names;
+    } else if served_table.parts.contains_key(&worker_index)
+        || served_table.column_names != column_names
+    {
+        return Err(Error::TableRouteAlreadyUsed(route));
+    }
+    served_table
+        .parts
+        .insert(worker_index, Arc::downgrade(table));
+    // the state is rebuilt from the start, with the data of the new part
+    served_table.state = Arc::new(Mutex::new(RouteState::new()));
+    Ok(())
+}
+
+fn error_response(status: StatusCode, message: impl Into<String>) -> (StatusCode, JsonValue) {
+    (status, json!({ "error": message.into() }))
+}
+
+pub(crate) fn value_to_json(value: &Value) -> JsonValue {
+    serialize_value_to_json(value).unwrap_or_else(|_| JsonValue::String(value.to_string()))
+}
+
+fn values_equal(value: &JsonValue, expected: &JsonValue) -> bool {
+    match (value, expected) {
+        (JsonValue::Number(value), JsonValue::Number(expected)) => {
+            value.as_f64() == expected.as_f64()
+        }
+        _ => value == expected,
+    }
+}
+
+fn row_to_json(column_names: &[String], key: Key, values: &[Value]) -> JsonValue {
+    let mut row = JsonMap::with_capacity(column_names.len() + 1);
+    row.insert("id".to_string(), JsonValue::String(key.to_string()));
+    for (name, value) in column_names.iter().zip(values) {
+        row.insert(name.clone(), value_to_json(value));
+    }
+    JsonValue::Object(row)
+}
+
+fn frontier_to_json(frontier: TotalFrontier<Timestamp>) -> JsonValue {
+    match frontier {
+        TotalFrontier::At(time) => json!(time.0),
+        TotalFrontier::Done => JsonValue::Null,
+    }
+}
+
+fn parse_filters(
+    column_names: &[String],
+    query: Option<&str>,
+) -> std::result::Result<Vec<(usize, JsonValue)>, String> {
+    form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+        .map(|(name, value)| {
+            let index = column_names
+                .iter()
+                .position(|column_name| *column_name == name)
+                .ok_or_else(|| format!("unknown column {name:?}"))?;
+            let value = serde_json::from_str(&value)
+                .unwrap_or_else(|_| JsonValue::String(value.into_owned()));
+            Ok((index, value))
+        })
+        .collect()
+}
+
+fn parse_key(key: &str) -> Option<Key> {
+    // the leading `^` of an id may come percent-encoded
+    let key = ["^", "%5E", "%5e"]
+        .iter()
+        .find_map(|prefix| key.strip_prefix(prefix))
+        .unwrap_or(key);
+    match parse_pathway_pointer(&format!("^{key}")) {
+        Ok(Value::Pointer(key)) => Some(key),
+        _ => None,
+    }
+}
+
+/// Answers a request for `/tables` followed by `path`, with an optional `query`. The
+/// result is the status code of the response and its JSON body.
+pub fn query_served_tables(path: &str, query: Option<&str>) -> (StatusCode, JsonValue) {
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+    let (route, key) = match (segments.next(), segments.next(), segments.next()) {
+        (None, _, _) => {
+            let served_tables = lock(&SERVED_TABLES);
+            let mut tables: Vec<_> = served_tables
+                .iter()
+                .filter(|(_route, table)| !table.live_parts().is_empty())
+                .map(|(route, table)| json!({ "route": route, "columns": table.column_names }))
+                .collect();
+            tables.sort_by(|a, b| a["route"].as_str().cmp(&b["route"].as_str()));
+            return (StatusCode::OK, json!({ "tables": tables }));
+        }
+        (Some(route), key, None) => (route, key),
+        (Some(_), _, Some(_)) => {
+            return error_response(StatusCode::NOT_FOUND, "no such resource");
+        }
+    };
+    let (column_names, parts, state) = {
+        let served_tables = lock(&SERVED_TABLES);
+        let Some(table) = served_tables.get(route) else {
+            return error_response(
+                StatusCode::NOT_FOUND,
+                format!("no table served at {route:?}"),
+            );
+        };
+        (
+            table.column_names.clone(),
+            table.live_parts(),
+            table.state.clone(),
+        )
+    };
+    let Some(frontier) = parts.iter().map(|(_, part)| part.frontier()).min() else {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            format!("no table served at {route:?}"),
+        );
+    };
+    if parts.iter().any(|(_, part)| part.failed()) {
+        return error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "the computation of the table has failed",
+        );
+    }
+    let filters = match parse_filters(&column_names, query) {
+        Ok(filters) => filters,
+        Err(message) => return error_response(StatusCode::BAD_REQUEST, message),
+    };
+    let matches_filters = |values: &[Value]| {
+        filters
+            .iter()
+            .all(|(index, expected)| values_equal(&value_to_json(&values[*index]), expected))
+    };
+    let snapshot = lock(&state).advance(&parts, frontier);
+
+    if let Some(key) = key {
+        let row = parse_key(key).and_then(|key| {
+            let values = snapshot.rows.get(&key)?;
+            matches_filters(values).then_some((key, values))
+        });
+        return match row {
+            Some((key, values)) => (
+                StatusCode::OK,
+                json!({
+                    "frontier": frontier_to_json(frontier),
+                    "row": row_to_json(&column_names, key, values),
+                }),
+            ),
+            None => error_response(StatusCode::NOT_FOUND, format!("no row with id {key}")),
+        };
+    }
+
+    let mut rows: Vec<_> = snapshot
+        .rows
+        .iter()
+        .filter(|(_key, values)| matches_filters(values))
+        .collect();
+    rows.sort_unstable_by_key(|(key, _values)| **key);
+    let rows: Vec<_> = rows
+        .into_iter()
+        .map(|(key, values)| row_to_json(&column_names, *key, values))
+        .collect();
+    (
+        StatusCode::OK,
+        json!({
+            "frontier": frontier_to_json(frontier),
+            "rows": rows,
+        }),
+    )
+}
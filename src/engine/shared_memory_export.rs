@@ -0,0 +1,330 @@
+// Copyright © 2026 Pathway
+
+//! Exported tables written to shared memory, for the consumers running on the same host.
+//!
+//! [`SharedMemoryExportedTable`] wraps the part of a table exported by a worker and
+//! writes its changes to a ring buffer in a memory-mapped file,
+//! `<directory>/<worker index>.ring`, which the consumers map and read without any
+//! system calls. Each time the frontier of the table advances, the changes done before
+//! it are written as a single record with an Arrow record batch of the consolidated
+//! changes (see [`super::change_batches`]). The consumers of a table computed by many
+//! workers have to read the files of all of them.
+//!
+//! The file starts with a header of 64 bytes, followed by the data area holding the
+//! records. All the numbers are little-endian:
+//! - bytes 0..8: the magic `PWRING01`;
+//! - bytes 8..16: the size of the data area in bytes;
+//! - bytes 16..20 and 20..24: the index of the worker and the number of workers;
+//! - bytes 24..32: the write position, the number of bytes written to the data area
+//!   since its creation, updated after each record;
+//! - bytes 32..40: the frontier, all the changes done before it are written, updated
+//!   after the write position. It's `u64::MAX` once the table is finished;
+//! - bytes 40..48: the state, 0 while the table is computed, 1 once it's finished and
+//!   2 if its computation or the writing of the records has failed;
+//! - bytes 48..56: the reserved position, the write position the record being written
+//!   ends at, updated before its bytes are written.
+//!
+//! A record starts in the data area at the write position modulo the size of the data
+//! area, at an offset aligned to 8 bytes. It's made of the length of its payload (u32),
+//! its kind (u32), 1 for a batch and 0 for a padding filling the end of the data area,
+//! and the payload, which is an Arrow IPC stream with a single record batch.
+//!
+//! The writer doesn't wait for the consumers, so a record can be overwritten while a
+//! consumer reads it. Like in a seqlock, the consumer detects it afterwards. It keeps
+//! its own read position and reads the records up to the write position, loaded with the
+//! acquire semantics. Having copied a record, it issues an acquire fence and loads the
+//! reserved position. If the reserved position is more than the size of the data area
+//! past the read position, the writer may have overwritten the record while it was
+//! copied, and the consumer has to drop the copy and skip to the current write position.
+
+use std::fs::{create_dir_all, OpenOptions};
+use std::io;
+use std::mem::take;
+use std::ops::ControlFlow;
+use std::path::Path;
+use std::sync::atomic::{fence, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+
+use deltalake::arrow::ipc::writer::StreamWriter;
+use log::error;
+use memmap2::MmapMut;
+use timely::progress::Timestamp as _;
+
+use crate::connectors::WriteError;
+
+use super::change_batches::{consolidate, ChangeBatchSchema};
+use super::{
+    DataRow, Error, ExportedTable, ExportedTableCallback, Key, Result, TableProperties, Timestamp,
+    TotalFrontier, Value,
+};
+
+const MAGIC: &[u8; 8] = b"PWRING01";
+const HEADER_SIZE: usize = 64;
+const WRITE_POSITION_OFFSET: usize = 24;
+const FRONTIER_OFFSET: usize = 32;
+const STATE_OFFSET: usize = 40;
+const RESERVED_POSITION_OFFSET: usize = 48;
+
+const RECORD_HEADER_SIZE: usize = 8;
+const RECORD_ALIGNMENT: usize = 8;
+const RECORD_KIND_PADDING: u32 = 0;
+const RECORD_KIND_BATCH: u32 = 1;
+
+const STATE_FINISHED: u64 = 1;
+const STATE_FAILED: u64 = 2;
+
+struct RingBuffer {
+    mmap: MmapMut,
+    capacity: usize,
+    write_position: u64,
+}
+
+impl RingBuffer {
+    fn create(
+        path: &Path,
+        capacity: usize,
+        worker_index: usize,
+        worker_count: usize,
+    ) -> io::Result<Self> {
+        if capacity == 0 || capacity % RECORD_ALIGNMENT != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("the capacity has to be a positive multiple of {RECORD_ALIGNMENT}"),
+            ));
+        }
+        let out_of_range = || io::Error::new(io::ErrorKind::InvalidInput, "value out of range");
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len(u64::try_from(HEADER_SIZE + capacity).map_err(|_| out_of_range())?)?;
+        // SAFETY: the file has just been created by this writer and the consumers only
+        // read it, so it isn't modified or truncated behind its back
+        let mut mmap = unsafe { MmapMut::map_mut(&file)? };
+        mmap[0..8].copy_from_slice(MAGIC);
+        mmap[8..16].copy_from_slice(&(capacity as u64).to_le_bytes());
+        let worker_index = u32::try_from(worker_index).map_err(|_| out_of_range())?;
+        mmap[16..20].copy_from_slice(&worker_index.to_le_bytes());
+        let worker_count = u32::try_from(worker_count).map_err(|_| out_of_range())?;
+        mmap[20..24].copy_from_slice(&worker_count.to_le_bytes());
+        mmap.flush()?;
+        Ok(Self {
+            mmap,
+            capacity,
+            write_position: 0,
+        })
+    }
+
+    fn store(&mut self, field_offset: usize, value: u64) {
+        // SAFETY: the mapping is page-aligned and the fields of the header are aligned
+        // to 8 bytes, the only other accesses to them are the atomic loads of the
+        // consumers
+        #[allow(clippy::cast_ptr_alignment)]
+        let field = unsafe { &*self.mmap.as_mut_ptr().add(field_offset).cast::<AtomicU64>() };
+        field.store(value, Ordering::Release);
+    }
+
+    fn set_frontier(&mut self, frontier: TotalFrontier<Timestamp>) {
+        let frontier = match frontier {
+            TotalFrontier::At(time) => time.0,
+            TotalFrontier::Done => u64::MAX,
+        };
+        self.store(FRONTIER_OFFSET, frontier);
+    }
+
+    fn set_state(&mut self, state: u64) {
+        self.store(STATE_OFFSET, state);
+    }
+
+    /// Publishes that the data area is going to be written up to the write position
+    /// `end`, before any of its bytes are overwritten.
+    fn reserve(&mut self, end: u64) {
+        self.store(RESERVED_POSITION_OFFSET, end);
+        // keeps the writes of the record from becoming visible before the reservation
+        fence(Ordering::Release);
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // the offset is smaller than the capacity
+    fn write_offset(&self) -> usize {
+        (self.write_position % self.capacity as u64) as usize
+    }
+
+    fn write_record_at(&mut self, offset: usize, kind: u32, length: usize, payload: &[u8]) {
+        let start = HEADER_SIZE + offset;
+        let length = u32::try_from(length).expect("the record should fit in the buffer");
+        self.mmap[start..start + 4].copy_from_slice(&length.to_le_bytes());
+        self.mmap[start + 4..start + 8].copy_from_slice(&kind.to_le_bytes());
+        let payload_start = start + RECORD_HEADER_SIZE;
+        self.mmap[payload_start..payload_start + payload.len()].copy_from_slice(payload);
+    }
+
+    fn write_record(&mut self, payload: &[u8]) -> io::Result<()> {
+        let size = (RECORD_HEADER_SIZE + payload.len()).next_multiple_of(RECORD_ALIGNMENT);
+        if size > self.capacity || u32::try_from(payload.len()).is_err() {
+            return Err(io::Error::other(format!(
+                "a batch of {} bytes doesn't fit in the buffer of {} bytes",
+                payload.len(),
+                self.capacity
+            )));
+        }
+        let mut offset = self.write_offset();
+        let padding_size = if offset + size > self.capacity {
+            self.capacity - offset
+        } else {
+            0
+        };
+        self.reserve(self.write_position + (padding_size + size) as u64);
+        if padding_size > 0 {
+            self.write_record_at(
+                offset,
+                RECORD_KIND_PADDING,
+                padding_size - RECORD_HEADER_SIZE,
+                &[],
+            );
+            self.write_position += padding_size as u64;
+            offset = 0;
+        }
+        self.write_record_at(offset, RECORD_KIND_BATCH, payload.len(), payload);
+        self.write_position += size as u64;
+        self.store(WRITE_POSITION_OFFSET, self.write_position);
+        Ok(())
+    }
+}
+
+struct Writer {
+    table: Weak<dyn ExportedTable>,
+    schema: ChangeBatchSchema,
+    ring_buffer: RingBuffer,
+    offset: usize,
+    // rows of the times that are not finished yet
+    pending: Vec<DataRow>,
+    frontier: TotalFrontier<Timestamp>,
+    failed: bool,
+}
+
+impl Writer {
+    fn write_batch(&mut self, rows: &[DataRow]) -> std::result::Result<(), WriteError> {
+        let batch = self.schema.record_batch(rows)?;
+        let mut stream_writer = StreamWriter::try_new(Vec::new(), &self.schema.schema)?;
+        stream_writer.write(&batch)?;
+        stream_writer.finish()?;
+        self.ring_buffer
+            .write_record(&stream_writer.into_inner()?)?;
+        Ok(())
+    }
+
+    fn fail(&mut self) -> ControlFlow<()> {
+        self.failed = true;
+        self.ring_buffer.set_state(STATE_FAILED);
+        ControlFlow::Break(())
+    }
+
+    /// Writes the changes done before the current frontier of the table. Breaks once
+    /// there is nothing more to write.
+    fn update(&mut self) -> ControlFlow<()> {
+        let Some(table) = self.table.upgrade() else {
+            return ControlFlow::Break(());
+        };
+        if self.failed {
+            return ControlFlow::Break(());
+        }
+        if table.failed() {
+            return self.fail();
+        }
+        // read before the data, so that all the rows of the finished times are read
+        let frontier = table.frontier();
+        let (data, offset) = table.data_from_offset(self.offset);
+        self.pending.extend(data);
+        self.offset = offset;
+        if frontier > self.frontier {
+            let (finished, pending) = take(&mut self.pending)
+                .into_iter()
+                .partition(|row| frontier.is_time_done(&row.time));
+            self.pending = pending;
+            let rows = consolidate(finished, false);
+            if !rows.is_empty() {
+                if let Err(e) = self.write_batch(&rows) {
+                    error!("Failed to write the changes of a table to shared memory: {e}");
+                    return self.fail();
+                }
+            }
+            self.frontier = frontier;
+            self.ring_buffer.set_frontier(frontier);
+        }
+        if frontier.is_done() {
+            self.ring_buffer.set_state(STATE_FINISHED);
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+/// The part of an exported table computed by one worker, with its changes written to a
+/// ring buffer in shared memory as they are finished. It fails if writing them fails.
+pub struct SharedMemoryExportedTable {
+    table: Arc<dyn ExportedTable>,
+    writer: Arc<Mutex<Writer>>,
+}
+
+impl SharedMemoryExportedTable {
+    /// Writes the changes of `table`, exported by the worker `worker_index`, to
+    /// `<directory>/<worker_index>.ring`, with a data area of `capacity` bytes.
+    pub fn new(
+        table: Arc<dyn ExportedTable>,
+        directory: &Path,
+        capacity: usize,
+        column_names: &[String],
+        worker_index: usize,
+        worker_count: usize,
+    ) -> Result<Arc<Self>> {
+        let path = directory.join(format!("{worker_index}.ring"));
+        let ring_buffer = create_dir_all(directory)
+            .and_then(|()| RingBuffer::create(&path, capacity, worker_index, worker_count))
+            .map_err(|e| Error::SharedMemoryExportFailed(path, e))?;
+        let writer = Arc::new(Mutex::new(Writer {
+            table: Arc::downgrade(&table),
+            schema: ChangeBatchSchema::new(column_names, &table.properties()),
+            ring_buffer,
+            offset: 0,
+            pending: Vec::new(),
+            frontier: TotalFrontier::At(Timestamp::minimum()),
+            failed: false,
+        }));
+        table.subscribe({
+            let writer = writer.clone();
+            Box::new(move || writer.lock().unwrap().update())
+        });
+        // the changes done before the subscription
+        let _ = writer.lock().unwrap().update();
+        Ok(Arc::new(Self { table, writer }))
+    }
+}
+
+impl ExportedTable for SharedMemoryExportedTable {
+    fn failed(&self) -> bool {
+        self.table.failed() || self.writer.lock().unwrap().failed
+    }
+
+    fn properties(&self) -> Arc<TableProperties> {
+        self.table.properties()
+    }
+
+    fn frontier(&self) -> TotalFrontier<Timestamp> {
+        self.table.frontier()
+    }
+
+    fn data_from_offset(&self, offset: usize) -> (Vec<DataRow>, usize) {
+        self.table.data_from_offset(offset)
+    }
+
+    fn subscribe(&self, callback: ExportedTableCallback) {
+        self.table.subscribe(callback);
+    }
+
+    fn snapshot_at(&self, frontier: TotalFrontier<Timestamp>) -> Vec<(Key, Vec<Value>)> {
+        self.table.snapshot_at(frontier)
+    }
+}
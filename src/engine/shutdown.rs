@@ -0,0 +1,122 @@
+// Copyright © 2026 Pathway
+
+//! Graceful shutdown of a running computation.
+//!
+//! A computation reading from streaming sources runs until it's stopped. Stopping it
+//! by terminating the process loses the data that has been read but not yet passed to
+//! the outputs, and the persisted state committed last may be well behind. A graceful
+//! shutdown stops it cooperatively instead:
+//! - every input connector commits the minibatch it has been collecting and stops
+//!   reading, as if its source had ended;
+//! - the workers keep processing until all the minibatches already read reach all the
+//!   outputs, which are then flushed;
+//! - with persistence enabled, the state is committed one last time, so a computation
+//!   restarted later continues right where this one has stopped.
+//!
+//! The shutdown is requested with [`request_graceful_shutdown`], either directly or by
+//! sending `SIGTERM` to a process run from Python. In a multi-process run, each process
+//! has to be asked.
+//!
+//! A suspension, requested with [`request_suspend`], is a graceful shutdown of a
+//! computation with persistence enabled whose final commit is guaranteed: if the state
+//! can't be committed, the computation fails instead of finishing. The same computation
+//! started later with the same persistence config then resumes from the committed input
+//! offsets and operator state, and produces the same output, byte for byte, as if it
+//! had never been stopped. Only the data read by the connectors with persisted offsets
+//! is covered, and the computation has to be deterministic.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread::Thread;
+
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ShutdownError {
+    #[error("no computation is running")]
+    NotRunning,
+
+    #[error("the running computation has no persistence enabled, it can't be suspended")]
+    NoPersistence,
+}
+
+/// Passes the shutdown request to the connectors and the workers of a process.
+#[derive(Debug)]
+pub struct GracefulShutdown {
+    persistence_enabled: bool,
+    requested: AtomicBool,
+    suspended: AtomicBool,
+    workers: Mutex<Vec<Thread>>,
+}
+
+impl GracefulShutdown {
+    pub fn new(persistence_enabled: bool) -> Self {
+        Self {
+            persistence_enabled,
+            requested: AtomicBool::new(false),
+            suspended: AtomicBool::new(false),
+            workers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Registers the thread of a worker, which is woken up when the shutdown is
+    /// requested, so that its connectors notice the request right away.
+    pub fn register_worker(&self, thread: Thread) {
+        self.workers.lock().unwrap().push(thread);
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if the final commit of the shutdown has to succeed.
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.load(Ordering::Acquire)
+    }
+
+    pub fn request(&self) {
+        self.requested.store(true, Ordering::Release);
+        for thread in self.workers.lock().unwrap().iter() {
+            thread.unpark();
+        }
+    }
+
+    pub fn suspend(&self) -> Result<(), ShutdownError> {
+        if !self.persistence_enabled {
+            return Err(ShutdownError::NoPersistence);
+        }
+        self.suspended.store(true, Ordering::Release);
+        self.request();
+        Ok(())
+    }
+}
+
+// the shutdown of the computation currently running in this process
+static ACTIVE_SHUTDOWN: Mutex<Weak<GracefulShutdown>> = Mutex::new(Weak::new());
+
+/// Makes `shutdown` receive the shutdown requests of this process until it is dropped.
+pub fn set_active_shutdown(shutdown: &Arc<GracefulShutdown>) {
+    *ACTIVE_SHUTDOWN.lock().unwrap() = Arc::downgrade(shutdown);
+}
+
+/// Requests a graceful shutdown of the computation running in this process. Returns
+/// right away, the computation finishes once everything it has read is processed.
+pub fn request_graceful_shutdown() -> Result<(), ShutdownError> {
+    let shutdown = ACTIVE_SHUTDOWN
+        .lock()
+        .unwrap()
+        .upgrade()
+        .ok_or(ShutdownError::NotRunning)?;
+    shutdown.request();
+    Ok(())
+}
+
+/// Requests a suspension of the computation running in this process. Returns right
+/// away, the computation finishes once its whole state is committed.
+pub fn request_suspend() -> Result<(), ShutdownError> {
+    ACTIVE_SHUTDOWN
+        .lock()
+        .unwrap()
+        .upgrade()
+        .ok_or(ShutdownError::NotRunning)?
+        .suspend()
+}
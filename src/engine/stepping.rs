@@ -0,0 +1,365 @@
+// Copyright © 2026 Pathway
+
+//! Interactive single-step execution.
+//!
+//! In the step mode, every worker pauses each time its output frontier advances, that
+//! is, after all the changes up to some time have been passed to the outputs, and waits
+//! for a command from the user before processing the next commit. This makes it possible
+//! to follow the computation one commit at a time and to look at the tables marked with
+//! `Table.debug` (their changes are printed as they come) between the steps.
+//!
+//! The commands are read either from the standard input or from a small HTTP server:
+//! - `step [n]` (or `s [n]`, or an empty line on the standard input) lets the workers
+//!   process `n` more commits, one by default;
+//! - `continue` (or `c`) leaves the step mode, so that the rest of the computation runs
+//!   without pauses.
+//!
+//! Over HTTP, these are `POST /step?count=n` and `POST /continue`, while `GET /status`
+//! returns the current state. Closing the standard input in the stdin mode is treated
+//! as `continue`.
+
+use std::env;
+use std::io::BufRead;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{Builder, JoinHandle};
+use std::time::Duration;
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{header, Body, Method, Request, Response, Server, StatusCode};
+use log::{error, info, warn};
+use serde_json::json;
+use tokio::sync::oneshot::Sender;
+
+use super::{Error, Timestamp};
+
+const DEFAULT_STEP_HTTP_PORT: u16 = 20100;
+const ABORT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepMode {
+    Stdin,
+    Http,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum StepCommandError {
+    #[error("unknown command {0:?}, the supported ones are \"step [n]\" and \"continue\"")]
+    UnknownCommand(String),
+
+    #[error("the number of steps must be a positive integer, got {0:?}")]
+    InvalidStepCount(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepCommand {
+    Step(u64),
+    Continue,
+}
+
+impl StepCommand {
+    pub fn parse(command: &str) -> Result<Self, StepCommandError> {
+        let mut parts = command.split_whitespace();
+        let (name, argument) = (parts.next(), parts.next());
+        if parts.next().is_some() {
+            return Err(StepCommandError::UnknownCommand(command.to_string()));
+        }
+        match (name, argument) {
+            (None, _) => Ok(Self::Step(1)),
+            (Some("s" | "step"), None) => Ok(Self::Step(1)),
+            (Some("s" | "step"), Some(count)) => match count.parse::<u64>() {
+                Ok(count) if count > 0 => Ok(Self::Step(count)),
+                _ => Err(StepCommandError::InvalidStepCount(count.to_string())),
+            },
+            (Some("c" | "continue"), None) => Ok(Self::Continue),
+            _ => Err(StepCommandError::UnknownCommand(command.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct StepState {
+    released_steps: u64,
+    free_running: bool,
+    paused_at: Option<Timestamp>,
+}
+
+/// The state of the step mode shared by all workers of a process.
+#[derive(Debug)]
+pub struct StepController {
+    mode: StepMode,
+    state: Mutex<StepState>,
+    changed: Condvar,
+}
+
+impl StepController {
+    pub fn new(mode: StepMode) -> Self {
+        Self {
+            mode,
+            state: Mutex::new(StepState::default()),
+            changed: Condvar::new(),
+        }
+    }
+
+    pub fn apply(&self, command: StepCommand) {
+        let mut state = self.state.lock().unwrap();
+        match command {
+            StepCommand::Step(count) => {
+                state.released_steps = state.released_steps.saturating_add(count);
+            }
+            StepCommand::Continue => state.free_running = true,
+        }
+        state.paused_at = None;
+        self.changed.notify_all();
+    }
+
+    pub fn is_free_running(&self) -> bool {
+        self.state.lock().unwrap().free_running
+    }
+
+    pub fn status(&self) -> serde_json::Value {
+        let state = self.state.lock().unwrap();
+        json!({
+            "paused": state.paused_at.is_some(),
+            "frontier": state.paused_at.map(|time| time.0),
+            "released_steps": state.released_steps,
+            "free_running": state.free_running,
+        })
+    }
+
+    /// Blocks a worker that has completed `completed_steps` steps until the next step
+    /// is released. Returns `false` if the wait was interrupted by `should_abort`.
+    fn wait_for_step(
+        &self,
+        completed_steps: u64,
+        frontier: Timestamp,
+        should_abort: impl Fn() -> bool,
+    ) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.free_running || completed_steps < state.released_steps {
+            return true;
+        }
+        if state.paused_at.is_none() {
+            state.paused_at = Some(frontier);
+            info!("{}", self.pause_message(frontier));
+        }
+        while !state.free_running && completed_steps >= state.released_steps {
+            if should_abort() {
+                return false;
+            }
+            state = self
+                .changed
+                .wait_timeout(state, ABORT_CHECK_INTERVAL)
+                .unwrap()
+                .0;
+        }
+        true
+    }
+
+    fn pause_message(&self, frontier: Timestamp) -> String {
+        let hint = match self.mode {
+            StepMode::Stdin => {
+                "Type \"step [n]\" (or press Enter) to continue by n commits, or \"continue\" to leave the step mode."
+            }
+            StepMode::Http => {
+                "Send POST /step?count=n to continue by n commits, or POST /continue to leave the step mode."
+            }
+        };
+        format!(
+            "Paused: all changes with times earlier than {} have been processed. {hint}",
+            frontier.0
+        )
+    }
+}
+
+/// The per-worker side of the step mode, pausing the worker whenever its output
+/// frontier advances.
+pub struct StepGate {
+    controller: Arc<StepController>,
+    completed_steps: u64,
+    last_frontier: Option<Timestamp>,
+}
+
+impl StepGate {
+    pub fn new(controller: Arc<StepController>) -> Self {
+        Self {
+            controller,
+            completed_steps: 0,
+            last_frontier: None,
+        }
+    }
+
+    pub fn on_output_frontier(
+        &mut self,
+        frontier: Option<Timestamp>,
+        should_abort: impl Fn() -> bool,
+    ) {
+        let Some(frontier) = frontier else {
+            // The outputs are finished, there's nothing to step through anymore.
+            return;
+        };
+        if self.last_frontier == Some(frontier) {
+            return;
+        }
+        if self
+            .controller
+            .wait_for_step(self.completed_steps, frontier, should_abort)
+        {
+            self.last_frontier = Some(frontier);
+            self.completed_steps += 1;
+        }
+    }
+}
+
+fn read_stdin_commands(controller: Arc<StepController>) {
+    Builder::new()
+        .name("pathway:step_commands".to_string())
+        .spawn(move || {
+            for line in std::io::stdin().lock().lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                match StepCommand::parse(&line) {
+                    Ok(command) => controller.apply(command),
+                    Err(error) => warn!("{error}"),
+                }
+                if controller.is_free_running() {
+                    return;
+                }
+            }
+            // No more commands can come, so don't keep the computation paused forever.
+            info!("Standard input closed, leaving the step mode");
+            controller.apply(StepCommand::Continue);
+        })
+        .expect("step commands thread creation failed");
+}
+
+async fn handle_step_request(
+    controller: Arc<StepController>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Error> {
+    let command = match (request.method(), request.uri().path()) {
+        (&Method::GET, "/status") => None,
+        (&Method::POST, "/step") => {
+            let count = request
+                .uri()
+                .query()
+                .and_then(|query| query.strip_prefix("count="))
+                .unwrap_or("");
+            Some(StepCommand::parse(&format!("step {count}")))
+        }
+        (&Method::POST, "/continue") => Some(Ok(StepCommand::Continue)),
+        _ => {
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = StatusCode::NOT_FOUND;
+            return Ok(response);
+        }
+    };
+    let mut response = match command {
+        Some(Err(error)) => {
+            let mut response = Response::new(Body::from(error.to_string()));
+            *response.status_mut() = StatusCode::BAD_REQUEST;
+            return Ok(response);
+        }
+        Some(Ok(command)) => {
+            controller.apply(command);
+            Response::new(Body::from(controller.status().to_string()))
+        }
+        None => Response::new(Body::from(controller.status().to_string())),
+    };
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    Ok(response)
+}
+
+pub struct Runner {
+    http_server_thread_handle: Option<JoinHandle<()>>,
+    http_terminate_transmitter: Option<Sender<()>>,
+}
+
+impl Runner {
+    fn run_http_server(controller: Arc<StepController>, port: u16, process_id: usize) -> Self {
+        let (http_terminate_transmitter, http_terminate_receiver) =
+            tokio::sync::oneshot::channel::<()>();
+        let http_server_thread_handle = Builder::new()
+            .name("pathway:step_http".to_string())
+            .spawn(move || {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_io()
+                    .build()
+                    .unwrap()
+                    .block_on(async {
+                        let addr = ([127, 0, 0, 1], port).into();
+                        let make_service = make_service_fn(move |_| {
+                            let controller = controller.clone();
+                            async move {
+                                Ok::<_, Error>(service_fn(move |request| {
+                                    handle_step_request(controller.clone(), request)
+                                }))
+                            }
+                        });
+                        let server = Server::bind(&addr)
+                            .serve(make_service)
+                            .with_graceful_shutdown(async move {
+                                http_terminate_receiver.await.unwrap();
+                            });
+                        info!("Step mode commands are accepted at http://{addr}");
+                        if let Err(e) = server.await {
+                            error!("step mode http server error for process {process_id}: {e}");
+                        }
+                    });
+            })
+            .expect("step mode http thread creation failed");
+        Self {
+            http_server_thread_handle: Some(http_server_thread_handle),
+            http_terminate_transmitter: Some(http_terminate_transmitter),
+        }
+    }
+}
+
+impl Drop for Runner {
+    fn drop(&mut self) {
+        self.http_terminate_transmitter
+            .take()
+            .unwrap()
+            .send(())
+            .expect("couldn't send terminate message to step mode http server");
+        self.http_server_thread_handle
+            .take()
+            .unwrap()
+            .join()
+            .expect("step mode http thread failed");
+    }
+}
+
+/// Starts accepting the step mode commands. The returned runner, if any, has to be
+/// kept alive for the duration of the computation.
+pub fn start_accepting_commands(
+    controller: &Arc<StepController>,
+    process_id: usize,
+) -> Result<Option<Runner>, Error> {
+    match controller.mode {
+        StepMode::Stdin => {
+            read_stdin_commands(controller.clone());
+            Ok(None)
+        }
+        StepMode::Http => {
+            let base_port: u16 = env::var("PATHWAY_STEP_HTTP_PORT")
+                .ok()
+                .and_then(|port| port.parse().ok())
+                .unwrap_or(DEFAULT_STEP_HTTP_PORT);
+            // each process listens on its own port
+            let port = u16::try_from(process_id)
+                .ok()
+                .and_then(|process_id| base_port.checked_add(process_id))
+                .ok_or(Error::StepModePortOutOfRange(base_port, process_id))?;
+            Ok(Some(Runner::run_http_server(
+                controller.clone(),
+                port,
+                process_id,
+            )))
+        }
+    }
+}
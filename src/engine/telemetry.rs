@@ -1,12 +1,17 @@
 use opentelemetry::{trace::noop::NoopTracerProvider, InstrumentationScope};
 use std::{
+    collections::HashMap,
     sync::Arc,
     thread::{Builder, JoinHandle},
     time::{Duration, SystemTime},
 };
 
 use super::{error::DynError, license::License, Graph, Result};
-use crate::{engine::dataflow::monitoring::ProberStats, env::parse_env_var};
+use crate::{
+    engine::dataflow::monitoring::ProberStats,
+    engine::user_metrics::{UserMetricsExport, UserMetricsUpdate},
+    env::parse_env_var,
+};
 use arc_swap::ArcSwapOption;
 use itertools::Itertools;
 use log::{debug, error, info};
@@ -16,7 +21,7 @@ use nix::sys::{
 };
 use opentelemetry::{
     global,
-    metrics::Gauge,
+    metrics::{Counter, Gauge, Histogram},
     metrics::{Meter, MeterProvider},
     KeyValue,
 };
@@ -50,6 +55,7 @@ const OPERATOR_DELETIONS: &str = "operator.deletions";
 
 const ROOT_TRACE_ID: &str = "root.trace.id";
 const RUN_ID: &str = "run.id";
+const GRAPH_VERSION: &str = "graph.version";
 const LICENSE_KEY: &str = "license.key";
 const WORKER_ID: &str = "worker.id";
 
@@ -79,6 +85,10 @@ impl Telemetry {
                 KeyValue::new(SERVICE_NAMESPACE, self.config.service_namespace.clone()),
                 KeyValue::new(ROOT_TRACE_ID, root_trace_id.to_string()),
                 KeyValue::new(RUN_ID, self.config.run_id.clone()),
+                KeyValue::new(
+                    GRAPH_VERSION,
+                    self.config.graph_version.clone().unwrap_or_default(),
+                ),
                 KeyValue::new(LICENSE_KEY, self.config.license_key.clone()),
                 KeyValue::new(WORKER_ID, self.worker_id.to_string()),
             ])
@@ -119,20 +129,7 @@ impl Telemetry {
         let mut provider_builder = SdkMeterProvider::builder().with_resource(self.resource());
 
         for endpoint in &self.config.metrics_servers {
-            let exporter = opentelemetry_otlp::MetricExporter::builder()
-                .with_tonic()
-                .with_protocol(Protocol::Grpc)
-                .with_endpoint(endpoint)
-                .with_timeout(OPENTELEMETRY_EXPORT_TIMEOUT)
-                .with_tls_config(ClientTlsConfig::new().with_enabled_roots())
-                .build()
-                .expect("exporter initialization should not fail");
-
-            let reader = PeriodicReader::builder(exporter)
-                .with_interval(self.config.periodic_reader_interval)
-                .build();
-
-            provider_builder = provider_builder.with_reader(reader);
+            provider_builder = provider_builder.with_reader(self.otlp_metric_reader(endpoint));
         }
 
         let meter_provider = provider_builder.build();
@@ -140,6 +137,34 @@ impl Telemetry {
         Some(meter_provider)
     }
 
+    fn otlp_metric_reader(&self, endpoint: &str) -> PeriodicReader {
+        let exporter = opentelemetry_otlp::MetricExporter::builder()
+            .with_tonic()
+            .with_protocol(Protocol::Grpc)
+            .with_endpoint(endpoint)
+            .with_timeout(OPENTELEMETRY_EXPORT_TIMEOUT)
+            .with_tls_config(ClientTlsConfig::new().with_enabled_roots())
+            .build()
+            .expect("exporter initialization should not fail");
+
+        PeriodicReader::builder(exporter)
+            .with_interval(self.config.periodic_reader_interval)
+            .build()
+    }
+
+    // The user metrics are sent to the monitoring server only, and once per process
+    fn init_user_meter_provider(&self) -> Option<SdkMeterProvider> {
+        if self.worker_id != 0 {
+            return None;
+        }
+        let endpoint = self.config.monitoring_server.as_ref()?;
+        let meter_provider = SdkMeterProvider::builder()
+            .with_resource(self.resource())
+            .with_reader(self.otlp_metric_reader(endpoint))
+            .build();
+        Some(meter_provider)
+    }
+
     fn init_detailed_meter_provider(&self) -> Option<SdkMeterProvider> {
         let output_directory = self.config.detailed_metrics_dir.as_ref()?;
 
@@ -168,11 +193,13 @@ impl Telemetry {
 
         let meter_provider = self.init_meter_provider();
         let detailed_meter_provider = self.init_detailed_meter_provider();
+        let user_meter_provider = self.init_user_meter_provider();
         let tracer_provider = self.init_tracer_provider();
 
         TelemetryObserver {
             meter_provider,
             detailed_meter_provider,
+            user_meter_provider,
             tracer_provider,
             noop_meter_provider,
         }
@@ -193,6 +220,7 @@ impl MeterProvider for MeterProviderWrapper {
 struct TelemetryObserver {
     meter_provider: Option<SdkMeterProvider>,
     detailed_meter_provider: Option<SdkMeterProvider>,
+    user_meter_provider: Option<SdkMeterProvider>,
     tracer_provider: Option<SdkTracerProvider>,
     noop_meter_provider: MeterProviderWrapper,
 }
@@ -218,13 +246,23 @@ impl TelemetryObserver {
             .as_ref()
             .map(|provider| provider.meter("pathway-detailed-stats"))
             .map(|m| Gauges::new(&m));
+        let mut user_instruments = self
+            .user_meter_provider
+            .as_ref()
+            .map(|provider| UserInstruments::new(provider.meter("pathway-user-metrics")));
 
         loop {
             tokio::select! {
                 _ = shutdown.recv() => {
+                    if let Some(instruments) = user_instruments.as_mut() {
+                        instruments.record();
+                    }
                     break;
                 }
                 _ = interval.tick() => {
+                    if let Some(instruments) = user_instruments.as_mut() {
+                        instruments.record();
+                    }
                     if global_gauges.is_some() || detailed_gauges.is_some() {
                         match SystemMetrics::new(&mut sys, pid) {
                             Ok(system_metrics) => {
@@ -265,6 +303,10 @@ impl Drop for TelemetryObserver {
             provider.force_flush().unwrap_or(());
             provider.shutdown().unwrap_or(());
         }
+        if let Some(provider) = self.user_meter_provider.take() {
+            provider.force_flush().unwrap_or(());
+            provider.shutdown().unwrap_or(());
+        }
         if let Some(provider) = self.tracer_provider.take() {
             provider.force_flush().unwrap_or(());
             provider.shutdown().unwrap_or(());
@@ -305,6 +347,7 @@ pub struct TelemetryEnabled {
     pub service_namespace: String,
     pub service_instance_id: String,
     pub run_id: String,
+    pub graph_version: Option<String>,
     pub trace_parent: Option<String>,
     pub license_key: String,
     pub periodic_reader_interval: Duration,
@@ -321,6 +364,7 @@ impl Config {
     pub fn create(
         license: &License,
         run_id: Option<String>,
+        graph_version: Option<String>,
         monitoring_server: Option<String>,
         detailed_metrics_dir: Option<String>,
         trace_parent: Option<String>,
@@ -368,6 +412,7 @@ impl Config {
             License::NoLicenseKey => Ok(Config::Disabled),
             _ => Config::create_enabled(
                 run_id,
+                graph_version,
                 telemetry_server,
                 monitoring_server,
                 detailed_metrics_dir,
@@ -382,6 +427,7 @@ impl Config {
     #[allow(clippy::too_many_arguments)]
     fn create_enabled(
         run_id: String,
+        graph_version: Option<String>,
         telemetry_server: Option<String>,
         monitoring_server: Option<String>,
         detailed_metrics_dir: Option<String>,
@@ -414,6 +460,7 @@ impl Config {
             service_namespace,
             service_instance_id,
             run_id,
+            graph_version,
             trace_parent,
             license_key: license.shortcut(),
             periodic_reader_interval,
@@ -558,6 +605,47 @@ impl Gauges {
     }
 }
 
+// The instruments of the user metrics, created once a metric is first recorded
+struct UserInstruments {
+    meter: Meter,
+    export: UserMetricsExport,
+    counters: HashMap<String, Counter<u64>>,
+    histograms: HashMap<String, Histogram<f64>>,
+}
+
+impl UserInstruments {
+    fn new(meter: Meter) -> Self {
+        Self {
+            meter,
+            export: UserMetricsExport::start(),
+            counters: HashMap::new(),
+            histograms: HashMap::new(),
+        }
+    }
+
+    fn record(&mut self) {
+        let UserMetricsUpdate {
+            counters,
+            histograms,
+        } = self.export.take();
+        for (name, increment) in counters {
+            self.counters
+                .entry(name)
+                .or_insert_with_key(|name| self.meter.u64_counter(name.clone()).build())
+                .add(increment, &[]);
+        }
+        for (name, values) in histograms {
+            let histogram = self
+                .histograms
+                .entry(name)
+                .or_insert_with_key(|name| self.meter.f64_histogram(name.clone()).build());
+            for value in values {
+                histogram.record(value, &[]);
+            }
+        }
+    }
+}
+
 fn start_telemetry_thread(
     telemetry: Telemetry,
     start_sender: mpsc::Sender<mpsc::Sender<()>>,
@@ -4,7 +4,7 @@ use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 use std::str::FromStr;
 
 use chrono::offset::FixedOffset;
-use chrono::{self, DurationRound, LocalResult, TimeZone};
+use chrono::{self, DurationRound, LocalResult, Offset, TimeZone};
 use chrono::{Datelike, Timelike};
 use chrono_tz::Tz;
 use num_integer::Integer;
@@ -121,6 +121,94 @@ pub trait DateTime {
             .num_days_from_monday()
             .into()
     }
+
+    fn quarter(&self) -> i64 {
+        (self.month() - 1) / 3 + 1
+    }
+
+    fn day_of_year(&self) -> i64 {
+        self.as_chrono_datetime().ordinal().into()
+    }
+
+    fn iso_week(&self) -> i64 {
+        self.as_chrono_datetime().iso_week().week().into()
+    }
+
+    /// Truncates the date and time to the start of the calendar `unit` it's in. The
+    /// weeks start on Mondays.
+    fn truncated_to_unit(&self, unit: &str) -> DataResult<chrono::NaiveDateTime> {
+        let datetime = self.as_chrono_datetime();
+        let date = datetime.date();
+        let first_day_of_month = |month| {
+            chrono::NaiveDate::from_ymd_opt(date.year(), month, 1)
+                .expect("the first day of a month should exist")
+        };
+        let truncated = match unit {
+            "year" | "years" => first_day_of_month(1).and_time(chrono::NaiveTime::MIN),
+            "quarter" | "quarters" => {
+                first_day_of_month((date.month() - 1) / 3 * 3 + 1).and_time(chrono::NaiveTime::MIN)
+            }
+            "month" | "months" => first_day_of_month(date.month()).and_time(chrono::NaiveTime::MIN),
+            "week" | "weeks" => (date
+                - chrono::Days::new(date.weekday().num_days_from_monday().into()))
+            .and_time(chrono::NaiveTime::MIN),
+            "day" | "days" => date.and_time(chrono::NaiveTime::MIN),
+            "hour" | "hours" => datetime.with_minute(0).unwrap().with_second(0).unwrap(),
+            "minute" | "minutes" => datetime.with_second(0).unwrap(),
+            "second" | "seconds" => datetime,
+            _ => {
+                return Err(DataError::ValueError(format!(
+                    "unit has to be a valid calendar unit but is {unit:?}"
+                )))
+            }
+        };
+        Ok(truncated.with_nanosecond(0).unwrap())
+    }
+
+    /// Adds `amount` calendar `unit`s to the date and time. When a month has no such
+    /// day, the result is moved to the last day of the month, e.g. one month after
+    /// January 31 is the last day of February.
+    fn with_calendar_units_added(
+        &self,
+        amount: i64,
+        unit: &str,
+    ) -> DataResult<chrono::NaiveDateTime> {
+        let datetime = self.as_chrono_datetime();
+        let out_of_range = || {
+            DataError::ValueError(format!(
+                "adding {amount} {unit} to {datetime} is out of the representable range"
+            ))
+        };
+        let (months, days) = match unit {
+            "year" | "years" => (amount.checked_mul(12), Some(0)),
+            "quarter" | "quarters" => (amount.checked_mul(3), Some(0)),
+            "month" | "months" => (Some(amount), Some(0)),
+            "week" | "weeks" => (Some(0), amount.checked_mul(7)),
+            "day" | "days" => (Some(0), Some(amount)),
+            _ => {
+                return Err(DataError::ValueError(format!(
+                    "unit has to be a valid calendar unit but is {unit:?}"
+                )))
+            }
+        };
+        let months = months.ok_or_else(out_of_range)?;
+        let days = days.ok_or_else(out_of_range)?;
+        let abs_months =
+            chrono::Months::new(u32::try_from(months.unsigned_abs()).map_err(|_| out_of_range())?);
+        let shifted = if months >= 0 {
+            datetime.checked_add_months(abs_months)
+        } else {
+            datetime.checked_sub_months(abs_months)
+        };
+        let shifted = shifted.and_then(|shifted| {
+            if days >= 0 {
+                shifted.checked_add_days(chrono::Days::new(days.unsigned_abs()))
+            } else {
+                shifted.checked_sub_days(chrono::Days::new(days.unsigned_abs()))
+            }
+        });
+        shifted.ok_or_else(out_of_range)
+    }
 }
 
 fn get_unit_multiplier(unit: &str) -> DataResult<i64> {
@@ -184,6 +272,14 @@ impl DateTimeNaive {
         Self::new(self.get_truncated_timestamp(duration))
     }
 
+    pub fn truncate_to_unit(self, unit: &str) -> DataResult<DateTimeNaive> {
+        self.truncated_to_unit(unit)?.try_into()
+    }
+
+    pub fn add_calendar_units(self, amount: i64, unit: &str) -> DataResult<DateTimeNaive> {
+        self.with_calendar_units_added(amount, unit)?.try_into()
+    }
+
     pub fn from_timestamp(timestamp: i64, unit: &str) -> Result<Self> {
         let mult = get_unit_multiplier(unit)?;
         Ok(Self::new(mult * timestamp))
@@ -293,6 +389,16 @@ impl DateTimeUtc {
         Self::new(self.get_truncated_timestamp(duration))
     }
 
+    pub fn truncate_to_unit(self, unit: &str) -> DataResult<DateTimeUtc> {
+        self.truncated_to_unit(unit)?.and_utc().try_into()
+    }
+
+    pub fn add_calendar_units(self, amount: i64, unit: &str) -> DataResult<DateTimeUtc> {
+        self.with_calendar_units_added(amount, unit)?
+            .and_utc()
+            .try_into()
+    }
+
     pub fn from_timestamp(timestamp: i64, unit: &str) -> Result<Self> {
         let mult = get_unit_multiplier(unit)?;
         Ok(Self::new(mult * timestamp))
@@ -361,6 +467,190 @@ impl Display for DateTimeUtc {
     }
 }
 
+/// A point in time together with the time zone it was observed in.
+///
+/// Unlike `DateTimeUtc`, which only keeps the instant, this keeps the zone itself
+/// (not just its current UTC offset), so calendar computations on it follow
+/// the zone's daylight saving rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DateTimeZoned {
+    timestamp: i64,
+    #[serde(with = "timezone_serde")]
+    timezone: Tz,
+}
+
+mod timezone_serde {
+    use chrono_tz::Tz;
+    use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(timezone: &Tz, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(timezone.name())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Tz, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(D::Error::custom)
+    }
+}
+
+impl DateTimeZoned {
+    pub fn new(timestamp: i64, timezone: Tz) -> Self {
+        Self {
+            timestamp,
+            timezone,
+        }
+    }
+
+    pub fn from_utc(date_time: DateTimeUtc, timezone: &str) -> DataResult<Self> {
+        Ok(Self::new(date_time.timestamp(), parse_timezone(timezone)?))
+    }
+
+    pub fn from_naive(date_time: DateTimeNaive, timezone: &str) -> DataResult<Self> {
+        let utc = to_utc_from_timezone::<Tz>(date_time.as_chrono_datetime(), timezone)?;
+        Self::from_utc(utc, timezone)
+    }
+
+    /// Parses a datetime with an offset and an optional bracketed zone name
+    /// suffix, such as `2024-03-31T02:30:00+02:00[Europe/Warsaw]` (RFC 9557).
+    /// Without the suffix the zone is set to UTC. With it, the offset has to be the one
+    /// the zone has at that time.
+    pub fn parse(date_string: &str) -> DataResult<Self> {
+        let (date_part, timezone) = match date_string.strip_suffix(']') {
+            Some(rest) => match rest.rsplit_once('[') {
+                Some((date_part, timezone)) => (date_part, Some(parse_timezone(timezone)?)),
+                None => {
+                    return Err(DataError::ParseError(format!(
+                        "cannot parse zoned date {date_string:?}: unmatched ']'"
+                    )))
+                }
+            },
+            None => (date_string, None),
+        };
+        let utc = DateTimeUtc::strptime(date_part, "%Y-%m-%dT%H:%M:%S%.f%z")?;
+        let Some(timezone) = timezone else {
+            return Ok(Self::new(utc.timestamp(), Tz::UTC));
+        };
+        let date_time = Self::new(utc.timestamp(), timezone);
+        let offset = chrono::DateTime::parse_from_str(date_part, "%Y-%m-%dT%H:%M:%S%.f%z")
+            .map_err(|e| {
+                DataError::ParseError(format!("cannot parse zoned date {date_string:?}: {e}"))
+            })?
+            .offset()
+            .local_minus_utc();
+        let zone_offset = date_time.as_chrono_local().offset().fix();
+        if offset != zone_offset.local_minus_utc() {
+            return Err(DataError::ParseError(format!(
+                "cannot parse zoned date {date_string:?}: the offset of {} at that time is {}",
+                timezone.name(),
+                zone_offset
+            )));
+        }
+        Ok(date_time)
+    }
+
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    pub fn timezone_name(&self) -> &'static str {
+        self.timezone.name()
+    }
+
+    pub fn to_utc(self) -> DateTimeUtc {
+        DateTimeUtc::new(self.timestamp)
+    }
+
+    pub fn to_naive_local(self) -> DataResult<DateTimeNaive> {
+        self.as_chrono_local().naive_local().try_into()
+    }
+
+    pub fn with_timezone(self, timezone: &str) -> DataResult<Self> {
+        Ok(Self::new(self.timestamp, parse_timezone(timezone)?))
+    }
+
+    fn as_chrono_local(self) -> chrono::DateTime<Tz> {
+        self.timezone.timestamp_nanos(self.timestamp)
+    }
+}
+
+impl From<DateTimeUtc> for DateTimeZoned {
+    fn from(date_time: DateTimeUtc) -> Self {
+        Self::new(date_time.timestamp, Tz::UTC)
+    }
+}
+
+fn parse_timezone(timezone: &str) -> DataResult<Tz> {
+    timezone
+        .parse::<Tz>()
+        .map_err(|e| DataError::ParseError(format!("cannot parse time zone {timezone:?}: {e}")))
+}
+
+impl DateTime for DateTimeZoned {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    // Calendar parts of a zoned datetime are the ones of its local wall time.
+    fn as_chrono_datetime(&self) -> chrono::NaiveDateTime {
+        self.as_chrono_local().naive_local()
+    }
+
+    fn strftime(&self, format: &str) -> String {
+        self.as_chrono_local().format(format).to_string()
+    }
+}
+
+impl PartialOrd for DateTimeZoned {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTimeZoned {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.timestamp
+            .cmp(&other.timestamp)
+            .then_with(|| self.timezone.name().cmp(other.timezone.name()))
+    }
+}
+
+impl Sub for DateTimeZoned {
+    type Output = Duration;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Duration {
+            duration: self.timestamp - other.timestamp,
+        }
+    }
+}
+
+impl Add<Duration> for DateTimeZoned {
+    type Output = Self;
+
+    fn add(self, other: Duration) -> Self::Output {
+        Self::new(self.timestamp + other.duration, self.timezone)
+    }
+}
+
+impl Sub<Duration> for DateTimeZoned {
+    type Output = Self;
+
+    fn sub(self, other: Duration) -> Self::Output {
+        Self::new(self.timestamp - other.duration, self.timezone)
+    }
+}
+
+impl Display for DateTimeZoned {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "{}[{}]",
+            self.strftime("%Y-%m-%dT%H:%M:%S%.9f%:z"),
+            self.timezone.name()
+        )
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Duration {
     duration: i64,
@@ -426,6 +716,55 @@ impl Duration {
     pub fn true_div_by_i64(self, other: i64) -> Self {
         Self::new(self.duration / other)
     }
+
+    /// Formats the duration according to `format`, which can use the directives:
+    /// - `%d` for the number of whole days,
+    /// - `%H`, `%M` and `%S` for the hours, minutes and seconds after the whole days,
+    ///   hours and minutes, respectively, padded to two digits,
+    /// - `%f` for the nanoseconds after the whole seconds, padded to nine digits,
+    /// - `%h`, `%m` and `%s` for the total number of whole hours, minutes and seconds,
+    /// - `%%` for a literal `%`.
+    ///
+    /// A negative duration is formatted as its absolute value preceded by a `-`.
+    pub fn format(self, format: &str) -> DataResult<String> {
+        let abs = self.duration.unsigned_abs();
+        let nanos_in_second = 1_000_000_000;
+        let seconds = abs / nanos_in_second;
+        let mut result = String::new();
+        if self.duration < 0 {
+            result.push('-');
+        }
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.push(c);
+                continue;
+            }
+            let part = match chars.next() {
+                Some('d') => (seconds / 86_400).to_string(),
+                Some('H') => format!("{:02}", seconds / 3_600 % 24),
+                Some('M') => format!("{:02}", seconds / 60 % 60),
+                Some('S') => format!("{:02}", seconds % 60),
+                Some('f') => format!("{:09}", abs % nanos_in_second),
+                Some('h') => (seconds / 3_600).to_string(),
+                Some('m') => (seconds / 60).to_string(),
+                Some('s') => seconds.to_string(),
+                Some('%') => "%".to_string(),
+                Some(directive) => {
+                    return Err(DataError::ValueError(format!(
+                        "cannot use format {format:?}: unknown directive \"%{directive}\""
+                    )))
+                }
+                None => {
+                    return Err(DataError::ValueError(format!(
+                        "cannot use format {format:?}: it ends with an unfinished directive"
+                    )))
+                }
+            };
+            result.push_str(&part);
+        }
+        Ok(result)
+    }
 }
 
 impl Neg for Duration {
@@ -137,6 +137,7 @@ impl PathSummary<Timestamp> for Summary {
 }
 
 // XXX
+#[cfg(feature = "python")]
 mod python_conversions {
     use pyo3::{prelude::*, IntoPyObjectExt};
 
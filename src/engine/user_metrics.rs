@@ -0,0 +1,164 @@
+// Copyright © 2026 Pathway
+
+//! Counters and histograms defined by the user.
+//!
+//! The code run by the computation, like the UDFs or the callbacks of the subscribed
+//! tables, can increment named counters with [`increment_counter`] and record values of
+//! named histograms with [`record_histogram`]. The metrics are shipped to the monitoring
+//! server through the same OTLP pipeline as the metrics of the engine, so the business
+//! metrics and the engine metrics share one export path.
+//!
+//! The metrics are only collected while a computation with a monitoring server runs in
+//! this process, the ones recorded at other times are dropped. They are exported once per
+//! process, by the telemetry of the worker 0, with the period of the other metrics. A
+//! name identifies a single metric, so it can't be used for both a counter and a
+//! histogram.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::mem;
+use std::sync::Mutex;
+
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UserMetricsError {
+    #[error("invalid metric name {0:?}, it has to start with a letter, consist of letters, digits, '_', '.', '-' and '/', and be at most 255 characters long")]
+    InvalidName(String),
+
+    #[error("metric {name:?} is a {registered}, it can't be used as a {requested}")]
+    KindMismatch {
+        name: String,
+        registered: MetricKind,
+        requested: MetricKind,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    Counter,
+    Histogram,
+}
+
+impl fmt::Display for MetricKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Counter => write!(f, "counter"),
+            Self::Histogram => write!(f, "histogram"),
+        }
+    }
+}
+
+/// The metrics recorded since the previous export.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UserMetricsUpdate {
+    pub counters: HashMap<String, u64>,
+    pub histograms: HashMap<String, Vec<f64>>,
+}
+
+#[derive(Debug, Default)]
+struct UserMetrics {
+    kinds: HashMap<String, MetricKind>,
+    // the number of the running exports
+    exports: usize,
+    pending: UserMetricsUpdate,
+}
+
+impl UserMetrics {
+    fn check_kind(&mut self, name: &str, requested: MetricKind) -> Result<(), UserMetricsError> {
+        if let Some(registered) = self.kinds.get(name) {
+            if *registered != requested {
+                return Err(UserMetricsError::KindMismatch {
+                    name: name.to_string(),
+                    registered: *registered,
+                    requested,
+                });
+            }
+            return Ok(());
+        }
+        if !is_valid_name(name) {
+            return Err(UserMetricsError::InvalidName(name.to_string()));
+        }
+        self.kinds.insert(name.to_string(), requested);
+        Ok(())
+    }
+}
+
+static USER_METRICS: Mutex<Option<UserMetrics>> = Mutex::new(None);
+
+// the instrument name syntax of OpenTelemetry
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    name.len() <= 255
+        && chars
+            .next()
+            .is_some_and(|first| first.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-' | '/'))
+}
+
+fn with_user_metrics<T>(logic: impl FnOnce(&mut UserMetrics) -> T) -> T {
+    logic(
+        USER_METRICS
+            .lock()
+            .unwrap()
+            .get_or_insert_with(Default::default),
+    )
+}
+
+/// Adds `value` to the counter `name`.
+pub fn increment_counter(name: &str, value: u64) -> Result<(), UserMetricsError> {
+    with_user_metrics(|metrics| {
+        metrics.check_kind(name, MetricKind::Counter)?;
+        if metrics.exports > 0 {
+            *metrics
+                .pending
+                .counters
+                .entry(name.to_string())
+                .or_default() += value;
+        }
+        Ok(())
+    })
+}
+
+/// Records `value` in the histogram `name`.
+pub fn record_histogram(name: &str, value: f64) -> Result<(), UserMetricsError> {
+    with_user_metrics(|metrics| {
+        metrics.check_kind(name, MetricKind::Histogram)?;
+        if metrics.exports > 0 {
+            metrics
+                .pending
+                .histograms
+                .entry(name.to_string())
+                .or_default()
+                .push(value);
+        }
+        Ok(())
+    })
+}
+
+/// Collects the user metrics until dropped, so that they can be exported.
+#[derive(Debug)]
+#[must_use]
+pub struct UserMetricsExport(());
+
+impl UserMetricsExport {
+    pub fn start() -> Self {
+        with_user_metrics(|metrics| metrics.exports += 1);
+        Self(())
+    }
+
+    /// Returns the metrics recorded since the previous call.
+    pub fn take(&self) -> UserMetricsUpdate {
+        with_user_metrics(|metrics| mem::take(&mut metrics.pending))
+    }
+}
+
+impl Drop for UserMetricsExport {
+    fn drop(&mut self) {
+        with_user_metrics(|metrics| {
+            metrics.exports -= 1;
+            if metrics.exports == 0 {
+                metrics.pending = UserMetricsUpdate::default();
+            }
+        });
+    }
+}
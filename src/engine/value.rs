@@ -2,13 +2,14 @@
 
 #![allow(clippy::non_canonical_partial_ord_impl)] // False positive with Derivative
 
+use std::collections::BTreeMap;
 use std::fmt::{self, Debug, Display};
 use std::mem::{align_of, size_of};
 use std::ops::Deref;
 use std::sync::Arc;
 
 use super::error::{DataError, DynError, DynResult};
-use super::time::{DateTime, DateTimeNaive, DateTimeUtc, Duration};
+use super::time::{DateTime, DateTimeNaive, DateTimeUtc, DateTimeZoned, Duration};
 use super::PyObjectWrapper;
 
 use arcstr::ArcStr;
@@ -77,6 +78,12 @@ impl Key {
     pub fn with_shard_of(self, other: Key) -> Self {
         Self((self.0 & (!SHARD_MASK)) | (other.0 & SHARD_MASK))
     }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn with_shard(self, shard: usize) -> Self {
+        Self((self.0 & (!SHARD_MASK)) | (shard as KeyImpl & SHARD_MASK))
+    }
 }
 
 impl Display for Key {
@@ -96,6 +103,9 @@ impl Debug for Key {
 pub enum ShardPolicy {
     WholeKey,
     LastKeyColumn,
+    /// The keys are placed in the shard of the column, so that e.g. the rows of a tenant
+    /// are processed by the same worker.
+    KeyColumn(usize),
 }
 
 impl ShardPolicy {
@@ -113,6 +123,9 @@ impl ShardPolicy {
             Self::LastKeyColumn => {
                 Key::for_values(values).with_shard_of(Key::for_value(values.last().unwrap()))
             }
+            Self::KeyColumn(column) => {
+                Key::for_values(values).with_shard_of(Key::for_value(&values[column]))
+            }
         }
     }
 }
@@ -228,6 +241,9 @@ pub enum Value {
     Error,
     PyObjectWrapper(Handle<PyObjectWrapper>),
     Pending,
+    DateTimeZoned(DateTimeZoned),
+    Struct(Arc<[(ArcStr, Self)]>),
+    Map(Arc<BTreeMap<ArcStr, Self>>),
 }
 
 const _: () = assert!(align_of::<Value>() <= 16);
@@ -306,6 +322,31 @@ impl Value {
         }
     }
 
+    pub fn as_struct(&self) -> DynResult<&Arc<[(ArcStr, Self)]>> {
+        if let Self::Struct(fields) = self {
+            Ok(fields)
+        } else {
+            Err(self.type_mismatch("struct"))
+        }
+    }
+
+    /// Returns the value of the field `name` of a struct, `None` if there is no such field.
+    pub fn struct_field(&self, name: &str) -> DynResult<Option<&Self>> {
+        Ok(self
+            .as_struct()?
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, value)| value))
+    }
+
+    pub fn as_map(&self) -> DynResult<&Arc<BTreeMap<ArcStr, Self>>> {
+        if let Self::Map(map) = self {
+            Ok(map)
+        } else {
+            Err(self.type_mismatch("map"))
+        }
+    }
+
     pub fn as_date_time_naive(&self) -> DynResult<DateTimeNaive> {
         if let Self::DateTimeNaive(dt) = self {
             Ok(*dt)
@@ -322,6 +363,14 @@ impl Value {
         }
     }
 
+    pub fn as_date_time_zoned(&self) -> DynResult<DateTimeZoned> {
+        if let Self::DateTimeZoned(dt) = self {
+            Ok(*dt)
+        } else {
+            Err(self.type_mismatch("DateTimeZoned"))
+        }
+    }
+
     pub fn as_duration(&self) -> DynResult<Duration> {
         if let Self::Duration(d) = self {
             Ok(*d)
@@ -366,6 +415,20 @@ impl Display for Value {
             Self::Error => write!(fmt, "Error"),
             Self::PyObjectWrapper(ob) => write!(fmt, "{ob}"),
             Self::Pending => write!(fmt, "Pending"),
+            Self::DateTimeZoned(date_time) => write!(fmt, "{date_time}"),
+            Self::Struct(fields) => write!(
+                fmt,
+                "{{{}}}",
+                fields
+                    .iter()
+                    .format_with(", ", |(name, value), f| f(&format_args!("{name}: {value}")))
+            ),
+            Self::Map(map) => write!(
+                fmt,
+                "{{{}}}",
+                map.iter()
+                    .format_with(", ", |(key, value), f| f(&format_args!("{key:?}: {value}")))
+            ),
         }
     }
 }
@@ -430,6 +493,18 @@ impl From<Vec<Value>> for Value {
     }
 }
 
+impl From<Vec<(ArcStr, Value)>> for Value {
+    fn from(fields: Vec<(ArcStr, Value)>) -> Self {
+        Self::Struct(fields.into())
+    }
+}
+
+impl From<BTreeMap<ArcStr, Value>> for Value {
+    fn from(map: BTreeMap<ArcStr, Value>) -> Self {
+        Self::Map(map.into())
+    }
+}
+
 impl From<ArrayD<i64>> for Value {
     fn from(a: ArrayD<i64>) -> Self {
         Self::IntArray(Handle::new(a))
@@ -466,6 +541,12 @@ impl From<DateTimeUtc> for Value {
     }
 }
 
+impl From<DateTimeZoned> for Value {
+    fn from(dt: DateTimeZoned) -> Self {
+        Self::DateTimeZoned(dt)
+    }
+}
+
 impl From<Duration> for Value {
     fn from(dt: Duration) -> Self {
         Self::Duration(dt)
@@ -506,6 +587,9 @@ pub enum Kind {
     Error,
     PyObjectWrapper,
     Pending,
+    DateTimeZoned,
+    Struct,
+    Map,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -527,6 +611,9 @@ pub enum Type {
     PyObjectWrapper,
     Optional(Arc<Type>),
     Future(Arc<Type>),
+    DateTimeZoned,
+    Struct(Arc<[(ArcStr, Type)]>),
+    Map(Arc<Type>),
 }
 
 impl Type {
@@ -544,6 +631,20 @@ impl Type {
     pub fn is_optional(&self) -> bool {
         matches!(self, Self::Optional(_))
     }
+
+    /// Returns the type of the field `name` of a struct type, `None` if the type
+    /// isn't a struct or has no such field. The field of an optional struct is optional.
+    pub fn struct_field_type(&self, name: &str) -> Option<Self> {
+        let Self::Struct(fields) = self.unoptionalize() else {
+            return None;
+        };
+        let (_, field_type) = fields.iter().find(|(field_name, _)| field_name == name)?;
+        if self.is_optional() && !field_type.can_be_none() {
+            Some(Self::Optional(field_type.clone().into()))
+        } else {
+            Some(field_type.clone())
+        }
+    }
 }
 
 impl Display for Type {
@@ -572,6 +673,15 @@ impl Display for Type {
             Type::PyObjectWrapper => write!(f, "PyObjectWrapper"),
             Type::Optional(arg) => write!(f, "{arg} | None"),
             Type::Future(arg) => write!(f, "Future[{arg}]"),
+            Type::DateTimeZoned => write!(f, "DateTimeZoned"),
+            Type::Struct(fields) => write!(
+                f,
+                "struct[{}]",
+                fields
+                    .iter()
+                    .format_with(", ", |(name, type_), f| f(&format_args!("{name}: {type_}")))
+            ),
+            Type::Map(arg) => write!(f, "dict[str, {arg}]"),
         }
     }
 }
@@ -597,6 +707,9 @@ impl Value {
             Self::Error => Kind::Error,
             Self::PyObjectWrapper(_) => Kind::PyObjectWrapper,
             Self::Pending => Kind::Pending,
+            Self::DateTimeZoned(_) => Kind::DateTimeZoned,
+            Self::Struct(_) => Kind::Struct,
+            Self::Map(_) => Kind::Map,
         }
     }
 }
@@ -717,6 +830,13 @@ impl HashInto for DateTimeUtc {
     }
 }
 
+impl HashInto for DateTimeZoned {
+    fn hash_into(&self, hasher: &mut Hasher) {
+        self.timestamp().hash_into(hasher);
+        self.timezone_name().hash_into(hasher);
+    }
+}
+
 impl HashInto for Duration {
     fn hash_into(&self, hasher: &mut Hasher) {
         self.nanoseconds().hash_into(hasher);
@@ -744,6 +864,21 @@ impl HashInto for Value {
             Self::Error => panic!("trying to hash error"), // FIXME
             Self::PyObjectWrapper(ob) => ob.hash_into(hasher),
             Self::Pending => panic!("trying to hash pending"), // FIXME
+            Self::DateTimeZoned(date_time) => date_time.hash_into(hasher),
+            Self::Struct(fields) => {
+                fields.len().hash_into(hasher);
+                for (name, value) in fields.iter() {
+                    name.hash_into(hasher);
+                    value.hash_into(hasher);
+                }
+            }
+            Self::Map(map) => {
+                map.len().hash_into(hasher);
+                for (key, value) in map.iter() {
+                    key.hash_into(hasher);
+                    value.hash_into(hasher);
+                }
+            }
         }
     }
 }
@@ -0,0 +1,270 @@
+// Copyright © 2026 Pathway
+
+//! Streaming the changes of subscribed tables over WebSocket.
+//!
+//! A table subscribed with [`websocket_callbacks`] publishes its changes on a channel
+//! named after the unique name of the subscription. Clients connect to the channel on
+//! the monitoring HTTP server of the process (see [`super::http_server`]), at
+//! `ws://localhost:PORT/subscriptions/<name>`, and receive a text message with a JSON
+//! object for each closed time:
+//!
+//! ```json
+//! {"time": 4, "changes": [{"id": "^X5...", "diff": 1, "values": {"owner": "Alice"}}]}
+//! ```
+//!
+//! Each worker publishes the changes it has computed, so there may be several messages
+//! with the same time. When all the workers of the process have finished the table,
+//! `{"end": true}` is sent once and the channel is closed. A client only receives the
+//! changes published after it has connected. If it can't keep up with the changes, its
+//! connection is closed, as it would miss some of them. Connecting to a channel that
+//! isn't published by a running computation is rejected.
+
+use std::cell::RefCell;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::mem::take;
+use std::rc::Rc;
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard, PoisonError};
+
+use futures::{SinkExt, StreamExt};
+use hyper::{header, Body, Request, Response, StatusCode};
+use log::{error, warn};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::{CloseFrame, Role};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+use super::graph::{SubscribeCallbacks, SubscribeCallbacksBuilder};
+use super::served_tables::value_to_json;
+
+// number of messages kept for the clients that are behind the others
+const CHANNEL_CAPACITY: usize = 1024;
+
+struct Channel {
+    sender: broadcast::Sender<Arc<str>>,
+    // workers of the process that still publish on the channel
+    running_workers: usize,
+    // whether some worker stopped publishing without finishing the table
+    failed: bool,
+}
+
+static CHANNELS: LazyLock<Mutex<HashMap<String, Channel>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn channels() -> MutexGuard<'static, HashMap<String, Channel>> {
+    CHANNELS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns a receiver of the messages published on the channel `channel_name` from now
+/// on, or `None` if no computation publishes on it.
+pub fn subscribe_to_channel(channel_name: &str) -> Option<broadcast::Receiver<Arc<str>>> {
+    channels()
+        .get(channel_name)
+        .map(|channel| channel.sender.subscribe())
+}
+
+// The part of a channel owned by one worker. The last worker to leave the channel
+// removes it, sending the end message first if all the workers have finished the table.
+struct Publisher {
+    channel_name: String,
+    sender: broadcast::Sender<Arc<str>>,
+    finished: bool,
+}
+
+impl Publisher {
+    fn join(channel_name: &str, worker_count: usize) -> Self {
+        let sender = channels()
+            .entry(channel_name.to_string())
+            .or_insert_with(|| Channel {
+                sender: broadcast::channel(CHANNEL_CAPACITY).0,
+                running_workers: worker_count,
+                failed: false,
+            })
+            .sender
+            .clone();
+        Self {
+            channel_name: channel_name.to_string(),
+            sender,
+            finished: false,
+        }
+    }
+
+    fn send(&self, message: &JsonValue) {
+        // no connected clients is not an error
+        let _ = self.sender.send(message.to_string().into());
+    }
+
+    fn leave(&mut self, finished: bool) {
+        self.finished = true;
+        let mut channels = channels();
+        let Entry::Occupied(mut entry) = channels.entry(self.channel_name.clone()) else {
+            return;
+        };
+        let channel = entry.get_mut();
+        channel.running_workers -= 1;
+        channel.failed |= !finished;
+        if channel.running_workers == 0 {
+            if !channel.failed {
+                self.send(&json!({ "end": true }));
+            }
+            entry.remove();
+        }
+    }
+}
+
+impl Drop for Publisher {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.leave(false);
+        }
+    }
+}
+
+/// Creates the subscription callbacks publishing the changes of a table with the given
+/// column names on the channel `channel_name`. Each of the `worker_count` workers of the
+/// process computing the table has to create its own callbacks.
+pub fn websocket_callbacks(
+    channel_name: &str,
+    column_names: Vec<String>,
+    worker_count: usize,
+) -> SubscribeCallbacks {
+    let publisher = Rc::new(RefCell::new(Publisher::join(channel_name, worker_count)));
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    SubscribeCallbacksBuilder::new()
+        .on_data({
+            let changes = changes.clone();
+            Box::new(move |key, values, _time, diff| {
+                let values: JsonMap<_, _> = column_names
+                    .iter()
+                    .cloned()
+                    .zip(values.iter().map(value_to_json))
+                    .collect();
+                changes.borrow_mut().push(json!({
+                    "id": key.to_string(),
+                    "diff": diff,
+                    "values": values,
+                }));
+                Ok(())
+            })
+        })
+        .on_time_end({
+            let publisher = publisher.clone();
+            Box::new(move |time| {
+                let changes = take(&mut *changes.borrow_mut());
+                if !changes.is_empty() {
+                    publisher
+                        .borrow()
+                        .send(&json!({ "time": time.0, "changes": changes }));
+                }
+                Ok(())
+            })
+        })
+        .on_end(Box::new(move || {
+            publisher.borrow_mut().leave(true);
+            Ok(())
+        }))
+        .build()
+}
+
+fn is_websocket_upgrade(request: &Request<Body>) -> bool {
+    request
+        .headers()
+        .get(header::UPGRADE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.eq_ignore_ascii_case("websocket"))
+}
+
+async fn forward_messages(
+    request: Request<Body>,
+    mut receiver: broadcast::Receiver<Arc<str>>,
+    channel_name: String,
+) {
+    let upgraded = match hyper::upgrade::on(request).await {
+        Ok(upgraded) => upgraded,
+        Err(e) => {
+            error!("WebSocket upgrade for channel {channel_name:?} failed: {e}");
+            return;
+        }
+    };
+    let socket = WebSocketStream::from_raw_socket(upgraded, Role::Server, None).await;
+    let (mut sink, mut stream) = socket.split();
+    loop {
+        tokio::select! {
+            message = receiver.recv() => match message {
+                Ok(message) => {
+                    if sink.send(Message::Text(message.to_string())).await.is_err() {
+                        return;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        "WebSocket client of channel {channel_name:?} missed {skipped} messages, closing the connection"
+                    );
+                    let close_frame = CloseFrame {
+                        code: CloseCode::Again,
+                        reason: "client too slow".into(),
+                    };
+                    let _ = sink.send(Message::Close(Some(close_frame))).await;
+                    return;
+                }
+                Err(RecvError::Closed) => {
+                    let _ = sink.close().await;
+                    return;
+                }
+            },
+            incoming = stream.next() => match incoming {
+                None | Some(Err(_) | Ok(Message::Close(_))) => return,
+                // the messages from the client are ignored
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+/// Accepts a WebSocket connection to the channel `channel_name`. The messages published
+/// on the channel from now on are forwarded to the client.
+pub fn handle_websocket_request(request: Request<Body>, channel_name: &str) -> Response<Body> {
+    let accept_key = request
+        .headers()
+        .get(header::SEC_WEBSOCKET_KEY)
+        .filter(|_| is_websocket_upgrade(&request))
+        .map(|key| derive_accept_key(key.as_bytes()));
+    let Some(accept_key) = accept_key else {
+        let mut response = Response::new(Body::from("expected a WebSocket upgrade request"));
+        *response.status_mut() = StatusCode::BAD_REQUEST;
+        return response;
+    };
+    // subscribe before answering, so that no message sent after the handshake is lost
+    let Some(receiver) = subscribe_to_channel(channel_name) else {
+        let mut response = Response::new(Body::from(format!(
+            "no table published on channel {channel_name:?}"
+        )));
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        return response;
+    };
+    tokio::spawn(forward_messages(
+        request,
+        receiver,
+        channel_name.to_string(),
+    ));
+
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = StatusCode::SWITCHING_PROTOCOLS;
+    let headers = response.headers_mut();
+    headers.insert(
+        header::UPGRADE,
+        header::HeaderValue::from_static("websocket"),
+    );
+    headers.insert(
+        header::CONNECTION,
+        header::HeaderValue::from_static("Upgrade"),
+    );
+    headers.insert(
+        header::SEC_WEBSOCKET_ACCEPT,
+        header::HeaderValue::from_str(&accept_key).unwrap(),
+    );
+    response
+}
@@ -8,11 +8,14 @@
 #![allow(clippy::missing_errors_doc)]
 #![allow(clippy::missing_panics_doc)]
 
+#[cfg(feature = "c-api")]
+pub mod c_api;
 pub mod connectors;
 pub mod deepcopy;
 pub mod engine;
 pub mod external_integration;
 pub mod persistence;
+#[cfg(feature = "python")]
 pub mod python_api;
 
 pub mod async_runtime;
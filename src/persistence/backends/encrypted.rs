@@ -0,0 +1,176 @@
+// Copyright © 2026 Pathway
+
+//! Encryption at rest of the persisted snapshots.
+//!
+//! [`EncryptedKVStorage`] wraps another backend and encrypts the values with AES-256-GCM
+//! before they are stored. Each value is stored as a header, a random nonce and the
+//! ciphertext followed by the authentication tag. The key of the entry is used as the
+//! associated data, so that an entry moved under another key isn't accepted either.
+//!
+//! The values without the header are rejected, as they could have been planted by anyone
+//! with access to the storage. When the encryption is enabled for the existing snapshots,
+//! they can be accepted as a migration, see
+//! [`EncryptedKVStorage::with_unencrypted_values_accepted`]. The values written before are
+//! then returned as they are and replaced by the encrypted ones as the snapshots get
+//! compacted.
+
+use std::fmt::{self, Debug};
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use futures::channel::oneshot;
+use log::warn;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::persistence::backends::PersistenceBackend;
+use crate::persistence::Error;
+
+use super::BackendPutFuture;
+
+const ENCRYPTED_VALUE_HEADER: &[u8] = b"PWAESGCM";
+
+/// A 256-bit key encrypting the snapshots. It isn't printed in the debug output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionKey([u8; Self::LEN]);
+
+impl EncryptionKey {
+    pub const LEN: usize = 32;
+
+    /// The environment variable the key is taken from if it isn't given explicitly,
+    /// encoded in base64.
+    pub const ENV_VAR: &'static str = "PATHWAY_PERSISTENCE_ENCRYPTION_KEY";
+
+    pub fn new(key: &[u8]) -> Result<Self, Error> {
+        let key = key
+            .try_into()
+            .map_err(|_| Error::InvalidEncryptionKeyLength {
+                expected: Self::LEN,
+                actual: key.len(),
+            })?;
+        Ok(Self(key))
+    }
+
+    pub fn from_base64(encoded_key: &str) -> Result<Self, Error> {
+        let key = BASE64_STANDARD
+            .decode(encoded_key.trim())
+            .map_err(Error::InvalidEncryptionKeyEncoding)?;
+        Self::new(&key)
+    }
+
+    /// The key from the environment variable [`Self::ENV_VAR`], if it's set.
+    pub fn from_env() -> Result<Option<Self>, Error> {
+        match std::env::var(Self::ENV_VAR) {
+            Ok(encoded_key) => Ok(Some(Self::from_base64(&encoded_key)?)),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+impl Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EncryptionKey(..)")
+    }
+}
+
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct EncryptedKVStorage {
+    backend: Box<dyn PersistenceBackend>,
+    key: LessSafeKey,
+    rng: SystemRandom,
+    accept_unencrypted_values: bool,
+}
+
+impl EncryptedKVStorage {
+    pub fn new(backend: Box<dyn PersistenceBackend>, key: &EncryptionKey) -> Self {
+        let key = UnboundKey::new(&AES_256_GCM, &key.0).expect("the key length is checked");
+        Self {
+            backend,
+            key: LessSafeKey::new(key),
+            rng: SystemRandom::new(),
+            accept_unencrypted_values: false,
+        }
+    }
+
+    /// Makes the storage return the values written before the encryption was enabled
+    /// as they are, instead of failing to decrypt them.
+    #[must_use]
+    pub fn with_unencrypted_values_accepted(mut self) -> Self {
+        self.accept_unencrypted_values = true;
+        self
+    }
+
+    fn encrypt(&self, key: &str, mut value: Vec<u8>) -> Result<Vec<u8>, Error> {
+        let mut nonce = [0; NONCE_LEN];
+        self.rng
+            .fill(&mut nonce)
+            .map_err(|_| Error::Encryption(key.to_string()))?;
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(key.as_bytes()),
+                &mut value,
+            )
+            .map_err(|_| Error::Encryption(key.to_string()))?;
+        let mut result = Vec::with_capacity(ENCRYPTED_VALUE_HEADER.len() + NONCE_LEN + value.len());
+        result.extend_from_slice(ENCRYPTED_VALUE_HEADER);
+        result.extend_from_slice(&nonce);
+        result.append(&mut value);
+        Ok(result)
+    }
+
+    fn decrypt(&self, key: &str, mut value: Vec<u8>) -> Result<Vec<u8>, Error> {
+        let Some(encrypted) = value.strip_prefix(ENCRYPTED_VALUE_HEADER) else {
+            if !self.accept_unencrypted_values {
+                return Err(Error::Decryption(key.to_string()));
+            }
+            warn!("The persisted entry {key:?} isn't encrypted, it was written before the encryption was enabled");
+            return Ok(value);
+        };
+        if encrypted.len() < NONCE_LEN {
+            return Err(Error::Decryption(key.to_string()));
+        }
+        let mut nonce = [0; NONCE_LEN];
+        nonce.copy_from_slice(&encrypted[..NONCE_LEN]);
+        let mut ciphertext = value.split_off(ENCRYPTED_VALUE_HEADER.len() + NONCE_LEN);
+        let plaintext_len = self
+            .key
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(key.as_bytes()),
+                &mut ciphertext,
+            )
+            .map_err(|_| Error::Decryption(key.to_string()))?
+            .len();
+        ciphertext.truncate(plaintext_len);
+        Ok(ciphertext)
+    }
+}
+
+impl PersistenceBackend for EncryptedKVStorage {
+    fn list_keys(&self) -> Result<Vec<String>, Error> {
+        self.backend.list_keys()
+    }
+
+    fn get_value(&self, key: &str) -> Result<Vec<u8>, Error> {
+        self.decrypt(key, self.backend.get_value(key)?)
+    }
+
+    fn put_value(&self, key: &str, value: Vec<u8>) -> BackendPutFuture {
+        match self.encrypt(key, value) {
+            Ok(encrypted) => self.backend.put_value(key, encrypted),
+            Err(e) => {
+                let (sender, receiver) = oneshot::channel();
+                sender
+                    .send(Err(e))
+                    .expect("The receiver must still be listening for the result of the put_value");
+                receiver
+            }
+        }
+    }
+
+    fn remove_key(&self, key: &str) -> Result<(), Error> {
+        self.backend.remove_key(key)
+    }
+}
@@ -8,9 +8,11 @@ use std::str::Utf8Error;
 use std::sync::mpsc;
 use std::sync::mpsc::Sender;
 use std::thread;
+use std::time::Duration;
 
 use ::s3::error::S3Error;
 use azure_storage::Error as AzureStorageError;
+use base64::DecodeError as Base64DecodeError;
 use bincode::ErrorKind as BincodeError;
 use futures::channel::oneshot;
 use futures::channel::oneshot::Receiver as OneShotReceiver;
@@ -21,11 +23,13 @@ use rusqlite::Error as SqliteError;
 use serde_json::Error as JsonParseError;
 
 pub use azure::AzureKVStorage;
+pub use encrypted::{EncryptedKVStorage, EncryptionKey};
 pub use file::FilesystemKVStorage;
 pub use mock::MockKVStorage;
 pub use s3::S3KVStorage;
 
 pub mod azure;
+pub mod encrypted;
 pub mod file;
 pub mod mock;
 pub mod s3;
@@ -69,6 +73,9 @@ pub enum Error {
     #[error("metadata entry {0:?} incorrectly formatted: {1}")]
     IncorrectMetadataFormat(String, #[source] JsonParseError),
 
+    #[error("metadata format version {found} is newer than the version {supported} supported by this version of Pathway, upgrade Pathway to use the persisted state")]
+    UnsupportedMetadataFormatVersion { found: u32, supported: u32 },
+
     // Without the chunk name and its size, a truncated chunk and a chunk that
     // was overwritten by unrelated data look exactly the same downstream.
     #[error("snapshot chunk {chunk:?} of {size} bytes can't be deserialized: {source}")]
@@ -85,6 +92,21 @@ pub enum Error {
         expected: usize,
         actual: usize,
     },
+
+    #[error("the encryption key must be {expected} bytes long, got {actual} bytes")]
+    InvalidEncryptionKeyLength { expected: usize, actual: usize },
+
+    #[error("the encryption key isn't valid base64: {0}")]
+    InvalidEncryptionKeyEncoding(#[source] Base64DecodeError),
+
+    #[error("failed to encrypt the persisted entry {0:?}")]
+    Encryption(String),
+
+    #[error("failed to decrypt the persisted entry {0:?}, it's corrupted, not encrypted or the encryption key is different")]
+    Decryption(String),
+
+    #[error("the run {owner} hasn't acquired the lease on the persistent storage within {timeout:?} after it was free")]
+    LeaseNotAcquiredByRun { owner: String, timeout: Duration },
 }
 
 pub type BackendPutFuture = OneShotReceiver<Result<(), Error>>;
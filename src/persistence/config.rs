@@ -18,26 +18,30 @@ use crate::connectors::{PersistenceMode, SnapshotAccess};
 use crate::deepcopy::DeepCopy;
 use crate::engine::error::DynError;
 use crate::engine::license::License;
-use crate::engine::{Result, Timestamp, TotalFrontier};
+use crate::engine::{Result, RunLineage, Timestamp, TotalFrontier};
 use crate::fs_helpers::ensure_directory;
 use crate::persistence::backends::{
-    AzureKVStorage, FilesystemKVStorage, MockKVStorage, PersistenceBackend, S3KVStorage,
+    AzureKVStorage, EncryptedKVStorage, EncryptionKey, FilesystemKVStorage, MockKVStorage,
+    PersistenceBackend, S3KVStorage,
 };
 use crate::persistence::cached_object_storage::CachedObjectStorage;
 use crate::persistence::input_snapshot::{
     Event, InputSnapshotReader, InputSnapshotWriter, MockSnapshotReader, ReadInputSnapshot,
     SnapshotMode,
 };
+use crate::persistence::lock::{PersistenceLock, SharedRunLease};
 use crate::persistence::operator_snapshot::{
     ConcreteSnapshotMerger, ConcreteSnapshotReader, ConcreteSnapshotWriter,
-    MultiConcreteSnapshotReader,
+    MultiConcreteSnapshotReader, SnapshotShard,
 };
+use crate::persistence::retention::{GarbageCollector, RetentionPolicy};
 use crate::persistence::state::FinalizedTimeQuerier;
 use crate::persistence::state::MetadataAccessor;
 use crate::persistence::Error as PersistenceBackendError;
 use crate::persistence::{PersistentId, SharedSnapshotWriter};
 
 const STREAMS_DIRECTORY_NAME: &str = "streams";
+const LOCKS_DIRECTORY_NAME: &str = "locks";
 
 pub type ConnectorWorkerPair = (PersistentId, usize);
 
@@ -96,6 +100,12 @@ pub struct PersistenceManagerOuterConfig {
     pub worker_scaling_enabled: bool,
     pub workload_tracking_window: Duration,
     run_start_timestamp: Option<Timestamp>,
+    run_lineage: Option<Arc<RunLineage>>,
+    retention_policy: Option<RetentionPolicy>,
+    encryption_key: Option<EncryptionKey>,
+    accept_unencrypted_snapshots: bool,
+    full_snapshot_interval: Option<Duration>,
+    run_lease: Option<SharedRunLease>,
 }
 
 impl PersistenceManagerOuterConfig {
@@ -117,6 +127,12 @@ impl PersistenceManagerOuterConfig {
             worker_scaling_enabled,
             workload_tracking_window,
             run_start_timestamp: None,
+            run_lineage: None,
+            retention_policy: None,
+            encryption_key: None,
+            accept_unencrypted_snapshots: false,
+            full_snapshot_interval: None,
+            run_lease: None,
         }
     }
 
@@ -129,6 +145,54 @@ impl PersistenceManagerOuterConfig {
         self
     }
 
+    /// The lineage of the current run. It is stored with the persisted metadata, so
+    /// that the next runs know which run has produced the state they resume from.
+    #[must_use]
+    pub fn with_run_lineage(mut self, run_lineage: Arc<RunLineage>) -> Self {
+        self.run_lineage = Some(run_lineage);
+        self
+    }
+
+    /// Makes the workers remove the obsolete persisted data while the program runs, not
+    /// only when it restarts.
+    #[must_use]
+    pub fn with_retention_policy(mut self, retention_policy: RetentionPolicy) -> Self {
+        self.retention_policy = Some(retention_policy);
+        self
+    }
+
+    /// Makes the workers encrypt the input and operator snapshots with `encryption_key`.
+    #[must_use]
+    pub fn with_encryption_key(mut self, encryption_key: EncryptionKey) -> Self {
+        self.encryption_key = Some(encryption_key);
+        self
+    }
+
+    /// Makes the workers read the snapshots written before the encryption was enabled,
+    /// which are rejected otherwise. It's meant for migrating the existing snapshots.
+    #[must_use]
+    pub fn with_unencrypted_snapshots_accepted(mut self) -> Self {
+        self.accept_unencrypted_snapshots = true;
+        self
+    }
+
+    /// Makes the operator snapshot mergers compact all the chunks of a snapshot into a
+    /// single full snapshot every `full_snapshot_interval`.
+    #[must_use]
+    pub fn with_full_snapshot_interval(mut self, full_snapshot_interval: Duration) -> Self {
+        self.full_snapshot_interval = Some(full_snapshot_interval);
+        self
+    }
+
+    /// Makes the run hold `run_lease` on the persistent storage, so that the other runs
+    /// using the same storage wait until it stops. The lease is shared by the workers of
+    /// the process, which wait until it's acquired before using the storage.
+    #[must_use]
+    pub fn with_run_lease(mut self, run_lease: SharedRunLease) -> Self {
+        self.run_lease = Some(run_lease);
+        self
+    }
+
     pub fn into_inner(self, worker_id: usize, total_workers: usize) -> PersistenceManagerConfig {
         PersistenceManagerConfig::new(self, worker_id, total_workers)
     }
@@ -159,12 +223,19 @@ pub struct PersistenceManagerConfig {
     pub worker_id: usize,
     pub snapshot_interval: Duration,
     pub run_start_timestamp: Option<Timestamp>,
+    pub run_lineage: Option<Arc<RunLineage>>,
+    pub retention_policy: Option<RetentionPolicy>,
+    encryption_key: Option<EncryptionKey>,
+    accept_unencrypted_snapshots: bool,
+    pub full_snapshot_interval: Option<Duration>,
+    run_lease: Option<SharedRunLease>,
     total_workers: usize,
 }
 
 #[derive(Copy, Clone, Debug)]
 pub enum ReadersQueryPurpose {
     ReadSnapshot,
+    ReshardSnapshot,
     ReconstructFrontier,
 }
 
@@ -188,13 +259,13 @@ impl ReadersQueryPurpose {
                     false
                 }
             }
-            ReadersQueryPurpose::ReconstructFrontier => true,
+            ReadersQueryPurpose::ReshardSnapshot | ReadersQueryPurpose::ReconstructFrontier => true,
         }
     }
 
     pub fn truncate_at_end(self) -> bool {
         match self {
-            ReadersQueryPurpose::ReadSnapshot => true,
+            ReadersQueryPurpose::ReadSnapshot | ReadersQueryPurpose::ReshardSnapshot => true,
             ReadersQueryPurpose::ReconstructFrontier => false,
         }
     }
@@ -215,6 +286,12 @@ impl PersistenceManagerConfig {
             continue_after_replay: outer_config.continue_after_replay,
             snapshot_interval: outer_config.snapshot_interval,
             run_start_timestamp: outer_config.run_start_timestamp,
+            run_lineage: outer_config.run_lineage,
+            retention_policy: outer_config.retention_policy,
+            encryption_key: outer_config.encryption_key,
+            accept_unencrypted_snapshots: outer_config.accept_unencrypted_snapshots,
+            full_snapshot_interval: outer_config.full_snapshot_interval,
+            run_lease: outer_config.run_lease,
             worker_id,
             total_workers,
         }
@@ -262,24 +339,75 @@ impl PersistenceManagerConfig {
         CachedObjectStorage::new(backend)
     }
 
+    /// Waits until the run holds the lease on the persistent storage, if the locking is
+    /// enabled. Returns the lock shared by the workers of the process.
+    pub fn wait_for_lock(&self) -> Result<Option<Arc<PersistenceLock>>, PersistenceBackendError> {
+        let Some(run_lease) = &self.run_lease else {
+            return Ok(None);
+        };
+        if matches!(self.backend, PersistentStorageConfig::Mock(_)) {
+            return Ok(None);
+        }
+        Ok(Some(run_lease.wait(|| self.create_locks_backend())?))
+    }
+
+    fn create_locks_backend(&self) -> Result<Box<dyn PersistenceBackend>, PersistenceBackendError> {
+        let backend: Box<dyn PersistenceBackend> = match &self.backend {
+            PersistentStorageConfig::Filesystem(root_path) => Box::new(FilesystemKVStorage::new(
+                &root_path.join(LOCKS_DIRECTORY_NAME),
+            )?),
+            PersistentStorageConfig::S3 { bucket, root_path } => Box::new(S3KVStorage::new(
+                bucket.deep_copy(),
+                &Self::cloud_locks_root_path(root_path),
+            )),
+            PersistentStorageConfig::Azure {
+                account,
+                credentials,
+                container,
+                root_path,
+            } => Box::new(AzureKVStorage::new(
+                &Self::cloud_locks_root_path(root_path),
+                account.clone(),
+                container.clone(),
+                credentials.clone(),
+            )?),
+            PersistentStorageConfig::Mock(_) => Box::new(MockKVStorage {}),
+        };
+        Ok(backend)
+    }
+
+    fn cloud_locks_root_path(root_path: &str) -> String {
+        format!(
+            "{}/{LOCKS_DIRECTORY_NAME}",
+            root_path.strip_suffix('/').unwrap_or(root_path)
+        )
+    }
+
     pub fn create_metadata_storage(&self) -> Result<MetadataAccessor, PersistenceBackendError> {
         let backend = self.backend.create()?;
-        MetadataAccessor::new(backend, self.worker_id, self.total_workers)
+        let mut accessor = MetadataAccessor::new(backend, self.worker_id, self.total_workers)?;
+        if let Some(run_lineage) = &self.run_lineage {
+            if let (0, Some(past_runs_lineage)) = (self.worker_id, accessor.past_runs_lineage()) {
+                run_lineage.log_resumed_from(past_runs_lineage);
+            }
+            accessor.set_lineage(run_lineage.as_ref().clone());
+        }
+        Ok(accessor)
     }
 
     fn get_readers_backends(
         &self,
         persistent_id: PersistentId,
         query_purpose: ReadersQueryPurpose,
-    ) -> Result<Vec<Box<dyn PersistenceBackend>>, PersistenceBackendError> {
-        let mut result: Vec<Box<dyn PersistenceBackend>> = Vec::new();
+    ) -> Result<Vec<(usize, Box<dyn PersistenceBackend>)>, PersistenceBackendError> {
+        let mut result: Vec<(usize, Box<dyn PersistenceBackend>)> = Vec::new();
         match &self.backend {
             PersistentStorageConfig::Filesystem(root_path) => {
                 let assigned_snapshot_paths =
                     self.assigned_local_snapshot_paths(root_path, persistent_id, query_purpose)?;
-                for (_, path) in assigned_snapshot_paths {
+                for (former_worker_id, path) in assigned_snapshot_paths {
                     let backend = FilesystemKVStorage::new(&path)?;
-                    result.push(Box::new(backend));
+                    result.push((former_worker_id, self.encrypted(Box::new(backend))));
                 }
                 Ok(result)
             }
@@ -292,9 +420,9 @@ impl PersistenceManagerConfig {
                     persistent_id,
                     query_purpose,
                 )?;
-                for (_, path) in assigned_snapshot_paths {
+                for (former_worker_id, path) in assigned_snapshot_paths {
                     let backend = S3KVStorage::new(bucket.deep_copy(), &path);
-                    result.push(Box::new(backend));
+                    result.push((former_worker_id, self.encrypted(Box::new(backend))));
                 }
                 Ok(result)
             }
@@ -317,14 +445,14 @@ impl PersistenceManagerConfig {
                     persistent_id,
                     query_purpose,
                 )?;
-                for (_, path) in assigned_snapshot_paths {
+                for (former_worker_id, path) in assigned_snapshot_paths {
                     let backend = AzureKVStorage::new(
                         &path,
                         account.clone(),
                         container.clone(),
                         credentials.clone(),
                     )?;
-                    result.push(Box::new(backend));
+                    result.push((former_worker_id, self.encrypted(Box::new(backend))));
                 }
                 Ok(result)
             }
@@ -332,6 +460,22 @@ impl PersistenceManagerConfig {
         }
     }
 
+    // The snapshots are encrypted if there's an encryption key, the metadata isn't as it
+    // only holds the times and the numbers of workers.
+    fn encrypted(&self, backend: Box<dyn PersistenceBackend>) -> Box<dyn PersistenceBackend> {
+        match &self.encryption_key {
+            Some(encryption_key) => {
+                let storage = EncryptedKVStorage::new(backend, encryption_key);
+                if self.accept_unencrypted_snapshots {
+                    Box::new(storage.with_unencrypted_values_accepted())
+                } else {
+                    Box::new(storage)
+                }
+            }
+            None => backend,
+        }
+    }
+
     pub fn create_snapshot_readers(
         &self,
         persistent_id: PersistentId,
@@ -350,7 +494,7 @@ impl PersistenceManagerConfig {
             Ok(result)
         } else {
             let backends = self.get_readers_backends(persistent_id, query_purpose)?;
-            for backend in backends {
+            for (_, backend) in backends {
                 let reader = InputSnapshotReader::new(
                     backend,
                     threshold_time,
@@ -371,46 +515,80 @@ impl PersistenceManagerConfig {
         persistent_id: PersistentId,
     ) -> Result<Box<dyn PersistenceBackend>, PersistenceBackendError> {
         match &self.backend {
-            PersistentStorageConfig::Filesystem(root_path) => Ok(Box::new(
+            PersistentStorageConfig::Filesystem(root_path) => Ok(self.encrypted(Box::new(
                 FilesystemKVStorage::new(&self.snapshot_writer_path(root_path, persistent_id)?)?,
-            )),
-            PersistentStorageConfig::S3 { bucket, root_path } => Ok(Box::new(S3KVStorage::new(
-                bucket.deep_copy(),
-                &self.cloud_snapshot_path(root_path, persistent_id),
             ))),
+            PersistentStorageConfig::S3 { bucket, root_path } => {
+                Ok(self.encrypted(Box::new(S3KVStorage::new(
+                    bucket.deep_copy(),
+                    &self.cloud_snapshot_path(root_path, persistent_id),
+                ))))
+            }
             PersistentStorageConfig::Azure {
                 root_path,
                 account,
                 container,
                 credentials,
-            } => Ok(Box::new(AzureKVStorage::new(
+            } => Ok(self.encrypted(Box::new(AzureKVStorage::new(
                 &self.cloud_snapshot_path(root_path, persistent_id),
                 account.clone(),
                 container.clone(),
                 credentials.clone(),
-            )?)),
+            )?))),
             PersistentStorageConfig::Mock(_) => {
                 unreachable!()
             }
         }
     }
 
+    /// The mode in which the input snapshots are written: with the operator persistence,
+    /// the operators restore the state, so only the offsets are needed.
+    pub fn snapshot_mode(&self, snapshot_mode: SnapshotMode) -> SnapshotMode {
+        if matches!(self.persistence_mode, PersistenceMode::OperatorPersisting) {
+            SnapshotMode::OffsetsOnly
+        } else {
+            snapshot_mode
+        }
+    }
+
     pub fn create_snapshot_writer(
         &mut self,
         persistent_id: PersistentId,
         snapshot_mode: SnapshotMode,
     ) -> Result<SharedSnapshotWriter, PersistenceBackendError> {
         let backend = self.get_writer_backend(persistent_id)?;
-        let snapshot_mode = if matches!(self.persistence_mode, PersistenceMode::OperatorPersisting)
-        {
-            SnapshotMode::OffsetsOnly
-        } else {
-            snapshot_mode
-        };
-        let snapshot_writer = InputSnapshotWriter::new(backend, snapshot_mode);
+        let snapshot_writer = InputSnapshotWriter::new(backend, self.snapshot_mode(snapshot_mode));
         Ok(Arc::new(Mutex::new(snapshot_writer?)))
     }
 
+    /// Creates the garbage collector of the worker, if there is a retention policy.
+    pub fn create_garbage_collector(
+        &self,
+        lock: Option<Arc<PersistenceLock>>,
+    ) -> Result<Option<GarbageCollector>, PersistenceBackendError> {
+        let Some(retention_policy) = self.retention_policy else {
+            return Ok(None);
+        };
+        if matches!(self.backend, PersistentStorageConfig::Mock(_)) {
+            return Ok(None);
+        }
+        let time_querier = FinalizedTimeQuerier::new(self.backend.create()?, self.total_workers);
+        Ok(Some(GarbageCollector::new(
+            retention_policy,
+            time_querier,
+            self.worker_id == 0,
+            lock,
+        )))
+    }
+
+    /// The backend of the input snapshot written by the worker, for the garbage collector.
+    pub fn create_snapshot_gc_backend(
+        &mut self,
+        persistent_id: PersistentId,
+    ) -> Result<Box<dyn PersistenceBackend>, PersistenceBackendError> {
+        self.get_writer_backend(persistent_id)
+    }
+
     fn snapshot_writer_path(
         &self,
         root_path: &Path,
@@ -545,27 +723,50 @@ impl PersistenceManagerConfig {
         let merger = ConcreteSnapshotMerger::new::<D, R>(
             merger_backend,
             adjusted_merging_interval,
+            self.full_snapshot_interval,
             time_querier,
             receiver,
         );
         Ok(merger)
     }
 
+    /// Creates the readers of the operator snapshot. If the number of workers has changed
+    /// since the run that has written it, the snapshot is re-sharded: each worker reads
+    /// the snapshots of all the former workers and loads its share of the entries.
+    /// Otherwise, each worker reads its own snapshot.
     pub fn create_operator_snapshot_readers<D, R>(
         &mut self,
         persistent_id: PersistentId,
         threshold_time: TotalFrontier<Timestamp>,
+        past_runs_total_workers: Option<usize>,
     ) -> Result<(MultiConcreteSnapshotReader, ConcreteSnapshotMerger), PersistenceBackendError>
     where
         D: ExchangeData,
         R: ExchangeData + Semigroup,
     {
         info!("Using threshold time: {threshold_time:?} to create operator snapshot readers");
+        let shard = past_runs_total_workers
+            .filter(|past_runs_total_workers| *past_runs_total_workers != self.total_workers)
+            .map(|past_runs_total_workers| {
+                info!(
+                    "Re-sharding the snapshot {persistent_id} of {past_runs_total_workers} workers between {} workers",
+                    self.total_workers
+                );
+                SnapshotShard::new(self.worker_id, self.total_workers)
+            });
+        let query_purpose = if shard.is_some() {
+            ReadersQueryPurpose::ReshardSnapshot
+        } else {
+            ReadersQueryPurpose::ReadSnapshot
+        };
         let mut readers: Vec<ConcreteSnapshotReader> = Vec::new();
-        let backends =
-            self.get_readers_backends(persistent_id, ReadersQueryPurpose::ReadSnapshot)?;
-        for backend in backends {
-            let reader = ConcreteSnapshotReader::new(backend, threshold_time);
+        let backends = self.get_readers_backends(persistent_id, query_purpose)?;
+        for (former_worker_id, backend) in backends {
+            let mut reader = ConcreteSnapshotReader::new(backend, threshold_time);
+            if let Some(shard) = shard {
+                let is_owner = former_worker_id % self.total_workers == self.worker_id;
+                reader = reader.with_shard(shard, is_owner);
+            }
             readers.push(reader);
         }
         let (sender, receiver) = mpsc::channel(); // pair used to block merger until reader finishes
@@ -1,4 +1,5 @@
 use log::{error, info, warn};
+use std::collections::HashSet;
 use std::io::{BufReader, Cursor, ErrorKind as IoErrorKind, Read, Seek, SeekFrom};
 use std::mem::take;
 
@@ -38,6 +39,97 @@ fn get_chunk_ids_with_backend(
     Ok(chunk_ids)
 }
 
+fn read_chunk_events(
+    backend: &dyn PersistenceBackend,
+    chunk_id: ChunkId,
+) -> Result<impl Iterator<Item = Result<Event, Error>>, Error> {
+    let contents = backend.get_value(&chunk_id.to_string())?;
+    let mut reader = Cursor::new(decompress_size_prepended(&contents)?);
+    Ok(std::iter::from_fn(move || {
+        match deserialize_from(&mut reader) {
+            Ok(event) => Some(Ok(event)),
+            Err(e) => match *e {
+                BincodeError::Io(e) if matches!(e.kind(), IoErrorKind::UnexpectedEof) => None,
+                BincodeError::Io(e) => Some(Err(Error::Io(e))),
+                e => Some(Err(Error::Bincode(e))),
+            },
+        }
+    }))
+}
+
+/// Removes the chunks of an input snapshot that are superseded by a later chunk starting
+/// before the threshold time: the rewind passes through them and only keeps the frontier
+/// from the later chunk. The chunks containing data are kept.
+///
+/// The chunks without data are the ones written in the `SnapshotMode::OffsetsOnly` mode,
+/// which otherwise accumulate for as long as the program runs. The chunks found to
+/// contain data are remembered, so that the later passes don't download them again.
+pub struct SupersededChunksRemover {
+    backend: Box<dyn PersistenceBackend>,
+    chunks_with_data: HashSet<ChunkId>,
+}
+
+impl SupersededChunksRemover {
+    pub fn new(backend: Box<dyn PersistenceBackend>) -> Self {
+        Self {
+            backend,
+            chunks_with_data: HashSet::new(),
+        }
+    }
+
+    /// Removes the chunks superseded by a chunk starting before `threshold_time`.
+    /// Returns the number of removed chunks.
+    pub fn remove_superseded_chunks(
+        &mut self,
+        threshold_time: TotalFrontier<Timestamp>,
+    ) -> Result<usize, Error> {
+        let backend = self.backend.as_ref();
+        let mut chunk_ids = get_chunk_ids_with_backend(backend, None)?;
+        chunk_ids.sort_unstable();
+        self.chunks_with_data
+            .retain(|chunk_id| chunk_ids.binary_search(chunk_id).is_ok());
+
+        // The chunks are checked from the latest one, which is usually the only one needed.
+        let mut superseding_chunk_idx = None;
+        for (idx, chunk_id) in chunk_ids.iter().enumerate().rev() {
+            let starts_before_threshold = matches!(
+                read_chunk_events(backend, *chunk_id)?.next().transpose()?,
+                Some(Event::AdvanceTime(time, _)) if TotalFrontier::At(time) < threshold_time
+            );
+            if starts_before_threshold {
+                superseding_chunk_idx = Some(idx);
+                break;
+            }
+        }
+        let Some(superseding_chunk_idx) = superseding_chunk_idx else {
+            return Ok(0);
+        };
+
+        let mut removed_chunks = 0;
+        for chunk_id in &chunk_ids[..superseding_chunk_idx] {
+            if self.chunks_with_data.contains(chunk_id) {
+                continue;
+            }
+            let mut has_data = false;
+            for event in read_chunk_events(backend, *chunk_id)? {
+                if matches!(event?, Event::Insert(_, _) | Event::Delete(_, _)) {
+                    has_data = true;
+                    break;
+                }
+            }
+            if has_data {
+                // the chunks before the superseding one aren't written anymore
+                self.chunks_with_data.insert(*chunk_id);
+            } else {
+                info!("Removing a superseded chunk: {chunk_id}");
+                backend.remove_key(&chunk_id.to_string())?;
+                removed_chunks += 1;
+            }
+        }
+        Ok(removed_chunks)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Event {
     Insert(Key, Vec<Value>),
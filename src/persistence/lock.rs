@@ -0,0 +1,328 @@
+// Copyright © 2026 Pathway
+
+//! Coordination of the runs sharing a persistent storage.
+//!
+//! When several runs are started with the same persistent storage, for instance a new
+//! deployment while the former one is still running, they would write and remove each
+//! other's metadata. With a [`RunLease`], the process 0 of a run first acquires a lease
+//! stored next to the metadata, and the other runs wait until it's released or expires.
+//! The owner renews the lease in the background, so a waiting run takes over only when
+//! the owner has stopped, including when it has crashed without releasing it.
+//!
+//! The lease is owned by the run as a whole, identified by its id. The other processes
+//! of the run wait until the record of the lease names their run and then keep checking
+//! that it still does. All the workers of a process share its [`PersistenceLock`], and
+//! none of them touches the metadata before it's acquired or after it's lost.
+//!
+//! The object stores can't write an object only if it doesn't exist yet, so the lease is
+//! acquired optimistically: a run writes its record and reads it back after a while. If
+//! several runs write the record at once, only the one whose record was written last
+//! finds it there. The expiration times are compared across the machines, so the time
+//! to live of the lease has to exceed the skew of their clocks by far.
+
+use std::mem::take;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::persistence::backends::PersistenceBackend;
+use crate::persistence::Error;
+
+const LEASE_KEY: &str = "lease";
+
+// The part of the time to live of the lease after which it's renewed. The same part is
+// waited for between the attempts to acquire it.
+const RENEWALS_PER_TTL: u32 = 3;
+
+// The number of the times to live of the lease a following process waits for its run to
+// acquire the lease, once no other run holds it.
+const FOLLOW_TIMEOUT_TTLS: u32 = 5;
+
+/// The record of the lease, as stored in the backend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaseRecord {
+    pub owner: String,
+
+    /// Milliseconds since the Unix epoch, after which the lease can be taken over.
+    pub expires_at_ms: u64,
+}
+
+impl LeaseRecord {
+    pub fn new(owner: &str, ttl: Duration) -> Self {
+        Self {
+            owner: owner.to_string(),
+            expires_at_ms: wall_clock_ms().saturating_add(duration_ms(ttl)),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        wall_clock_ms() >= self.expires_at_ms
+    }
+
+    /// Reads the record from `backend`, `None` if there's no lease.
+    pub fn read(backend: &dyn PersistenceBackend) -> Result<Option<Self>, Error> {
+        if !backend.list_keys()?.iter().any(|key| key == LEASE_KEY) {
+            return Ok(None);
+        }
+        let raw_record = backend.get_value(LEASE_KEY)?;
+        let record = serde_json::from_slice(&raw_record)
+            .map_err(|e| Error::IncorrectMetadataFormat(LEASE_KEY.to_string(), e))?;
+        Ok(Some(record))
+    }
+
+    pub fn write(&self, backend: &dyn PersistenceBackend) -> Result<(), Error> {
+        let raw_record = serde_json::to_vec(self).expect("lease record should be serializable");
+        futures::executor::block_on(backend.put_value(LEASE_KEY, raw_record))
+            .expect("unexpected future cancelling")
+    }
+}
+
+// The leases are compared across the processes, so it's the time of the system, even if
+// the engine clock is synthetic.
+fn wall_clock_ms() -> u64 {
+    duration_ms(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Failed to get the current timestamp"),
+    )
+}
+
+fn duration_ms(duration: Duration) -> u64 {
+    duration.as_millis().try_into().unwrap_or(u64::MAX)
+}
+
+/// The lease on the persistent storage held by a run, as seen by one of its processes.
+/// The process acquiring the lease renews it in the background and releases it when
+/// dropped, the other ones only check that it's still held by their run.
+#[derive(Debug)]
+pub struct PersistenceLock {
+    is_held: Arc<AtomicBool>,
+    sender: mpsc::Sender<()>,
+    thread_handle: Option<thread::JoinHandle<()>>,
+}
+
+impl PersistenceLock {
+    /// Acquires the lease stored in `backend`, waiting until the current owner releases
+    /// it or stops renewing it.
+    pub fn acquire(backend: Box<dyn PersistenceBackend>, ttl: Duration) -> Result<Self, Error> {
+        Self::acquire_for(backend, uuid::Uuid::new_v4().to_string(), ttl)
+    }
+
+    /// Acquires the lease stored in `backend` for `owner`, like [`Self::acquire`].
+    pub fn acquire_for(
+        backend: Box<dyn PersistenceBackend>,
+        owner: String,
+        ttl: Duration,
+    ) -> Result<Self, Error> {
+        let retry_interval = ttl / RENEWALS_PER_TTL;
+        while !Self::try_acquire(backend.as_ref(), &owner, ttl)? {
+            thread::sleep(retry_interval);
+        }
+        info!("Acquired the lease on the persistent storage as {owner}");
+        Ok(Self::start(backend, owner, ttl, true))
+    }
+
+    /// Waits until the lease stored in `backend` is acquired by `owner` in another
+    /// process, then keeps checking that it's not taken over, without renewing it.
+    ///
+    /// While another run holds the lease, the process acquiring it waits as well. Once
+    /// the lease is released or expired, it fails if `owner` doesn't acquire it within a
+    /// few times to live, for instance because the acquiring process has crashed.
+    pub fn follow(
+        backend: Box<dyn PersistenceBackend>,
+        owner: String,
+        ttl: Duration,
+    ) -> Result<Self, Error> {
+        let retry_interval = ttl / RENEWALS_PER_TTL;
+        let timeout = ttl * FOLLOW_TIMEOUT_TTLS;
+        let mut deadline = Instant::now() + timeout;
+        loop {
+            match LeaseRecord::read(backend.as_ref())? {
+                Some(record) if record.owner == owner => break,
+                Some(record) if !record.is_expired() => deadline = Instant::now() + timeout,
+                _ if Instant::now() >= deadline => {
+                    return Err(Error::LeaseNotAcquiredByRun { owner, timeout });
+                }
+                _ => {}
+            }
+            thread::sleep(retry_interval);
+        }
+        info!("The lease on the persistent storage is held by the run {owner}");
+        Ok(Self::start(backend, owner, ttl, false))
+    }
+
+    fn start(
+        backend: Box<dyn PersistenceBackend>,
+        owner: String,
+        ttl: Duration,
+        renews: bool,
+    ) -> Self {
+        let is_held = Arc::new(AtomicBool::new(true));
+        let (sender, receiver) = mpsc::channel();
+        let thread_is_held = is_held.clone();
+        let thread_handle = thread::Builder::new()
+            .name("PersistenceLockRenewal".to_string())
+            .spawn(move || {
+                Self::run(
+                    backend.as_ref(),
+                    &owner,
+                    ttl,
+                    renews,
+                    &receiver,
+                    &thread_is_held,
+                );
+            })
+            .expect("persistence lock renewal thread creation should succeed");
+        Self {
+            is_held,
+            sender,
+            thread_handle: Some(thread_handle),
+        }
+    }
+
+    /// Makes a single attempt to acquire the lease for `owner`, returns whether it
+    /// has succeeded.
+    pub fn try_acquire(
+        backend: &dyn PersistenceBackend,
+        owner: &str,
+        ttl: Duration,
+    ) -> Result<bool, Error> {
+        if let Some(record) = LeaseRecord::read(backend)? {
+            if record.owner != owner {
+                if !record.is_expired() {
+                    info!(
+                        "The persistent storage is used by {}, waiting for its lease to be released or to expire",
+                        record.owner
+                    );
+                    return Ok(false);
+                }
+                warn!(
+                    "Taking over the expired lease on the persistent storage from {}",
+                    record.owner
+                );
+            }
+        }
+        LeaseRecord::new(owner, ttl).write(backend)?;
+        // another run may have written its record at the same time
+        thread::sleep(ttl / (RENEWALS_PER_TTL * 2));
+        Ok(LeaseRecord::read(backend)?.is_some_and(|record| record.owner == owner))
+    }
+
+    /// Whether the lease is still held. It's lost if it hasn't been renewed in time and
+    /// another run has taken it over.
+    pub fn is_held(&self) -> bool {
+        self.is_held.load(Ordering::Acquire)
+    }
+
+    fn run(
+        backend: &dyn PersistenceBackend,
+        owner: &str,
+        ttl: Duration,
+        renews: bool,
+        receiver: &mpsc::Receiver<()>,
+        is_held: &AtomicBool,
+    ) {
+        loop {
+            match receiver.recv_timeout(ttl / RENEWALS_PER_TTL) {
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+            match LeaseRecord::read(backend) {
+                Ok(Some(record)) if record.owner != owner => {
+                    error!(
+                        "The lease on the persistent storage has been taken over by {}, the metadata won't be saved anymore",
+                        record.owner
+                    );
+                    is_held.store(false, Ordering::Release);
+                    return;
+                }
+                // the lease may be already released by the process 0 finishing earlier,
+                // it's not lost until another run takes it over
+                Ok(_) if !renews => {}
+                Ok(_) => {
+                    if let Err(e) = LeaseRecord::new(owner, ttl).write(backend) {
+                        error!("Failed to renew the lease on the persistent storage: {e}");
+                    }
+                }
+                Err(e) => error!("Failed to read the lease on the persistent storage: {e}"),
+            }
+        }
+        if !renews {
+            return;
+        }
+        // release the lease, so that the next run doesn't wait for it to expire
+        match LeaseRecord::read(backend) {
+            Ok(Some(record)) if record.owner == owner => {
+                if let Err(e) = backend.remove_key(LEASE_KEY) {
+                    error!("Failed to release the lease on the persistent storage: {e}");
+                }
+            }
+            Ok(_) => {}
+            Err(e) => error!("Failed to read the lease on the persistent storage: {e}"),
+        }
+    }
+}
+
+impl Drop for PersistenceLock {
+    fn drop(&mut self) {
+        // the thread may have already stopped, then there's nobody to notify
+        let _ = self.sender.send(());
+        if let Some(thread_handle) = take(&mut self.thread_handle) {
+            if let Err(e) = thread_handle.join() {
+                error!("Failed to join persistence lock renewal thread: {e:?}");
+            }
+        }
+    }
+}
+
+/// The lease of a run on the persistent storage, shared by the workers of a process.
+/// The first worker to need it acquires it, or waits for the process 0 to acquire it,
+/// and the other workers wait until that's done.
+#[derive(Debug)]
+pub struct RunLease {
+    run_id: String,
+    ttl: Duration,
+    acquires: bool,
+    process_lock: Mutex<Option<Arc<PersistenceLock>>>,
+}
+
+pub type SharedRunLease = Arc<RunLease>;
+
+impl RunLease {
+    /// The lease of the run `run_id`, acquired by the process for which `acquires` is
+    /// set and followed by the other ones. The id has to be the same in all the
+    /// processes of the run.
+    pub fn new(run_id: String, ttl: Duration, acquires: bool) -> Self {
+        Self {
+            run_id,
+            ttl,
+            acquires,
+            process_lock: Mutex::new(None),
+        }
+    }
+
+    /// Returns the lock of the process, waiting until the lease is held by the run.
+    pub fn wait(
+        &self,
+        create_backend: impl FnOnce() -> Result<Box<dyn PersistenceBackend>, Error>,
+    ) -> Result<Arc<PersistenceLock>, Error> {
+        // held while waiting, so that the other workers wait too
+        let mut process_lock = self.process_lock.lock().unwrap();
+        if let Some(lock) = &*process_lock {
+            return Ok(lock.clone());
+        }
+        let backend = create_backend()?;
+        let owner = self.run_id.clone();
+        let new_lock = Arc::new(if self.acquires {
+            PersistenceLock::acquire_for(backend, owner, self.ttl)?
+        } else {
+            PersistenceLock::follow(backend, owner, self.ttl)?
+        });
+        *process_lock = Some(new_lock.clone());
+        Ok(new_lock)
+    }
+}
@@ -15,7 +15,10 @@ pub mod cached_object_storage;
 pub mod config;
 pub mod frontier;
 pub mod input_snapshot;
+pub mod lock;
 pub mod operator_snapshot;
+pub mod retention;
+pub mod savepoint;
 pub mod state;
 pub mod tracker;
 
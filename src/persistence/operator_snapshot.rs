@@ -10,7 +10,9 @@ use std::time::{Duration, Instant};
 use bincode::{deserialize, serialize};
 use differential_dataflow::ExchangeData;
 use differential_dataflow::{consolidation::consolidate, difference::Semigroup};
-use log::error;
+use log::{error, warn};
+use serde::Serialize;
+use xxhash_rust::xxh3::xxh3_64;
 
 use crate::engine::{Timestamp, TotalFrontier};
 use crate::persistence::backends::{BackendPutFuture, Error as BackendError, PersistenceBackend};
@@ -169,9 +171,41 @@ where
     Ok(result)
 }
 
+// The chunks of a snapshot read by several workers may be merged by its owner while
+// being read. Then the reading is repeated, with the merged chunk.
+const MAX_SHARED_SNAPSHOT_READ_ATTEMPTS: usize = 5;
+
+/// The share of the persisted state loaded by a worker when the number of workers has
+/// changed since the run that has written it.
+///
+/// The entries are assigned to the workers by the hash of their serialized form, so
+/// that each entry is loaded by exactly one worker, no matter which of the former
+/// workers has persisted it.
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotShard {
+    worker_id: u64,
+    total_workers: u64,
+}
+
+impl SnapshotShard {
+    pub fn new(worker_id: usize, total_workers: usize) -> Self {
+        Self {
+            worker_id: u64::try_from(worker_id).unwrap(),
+            total_workers: u64::try_from(total_workers).unwrap(),
+        }
+    }
+
+    pub fn contains<D: Serialize>(&self, data: &D) -> bool {
+        let serialized_data = serialize(data).expect("entry should be serializable");
+        xxh3_64(&serialized_data) % self.total_workers == self.worker_id
+    }
+}
+
 pub struct ConcreteSnapshotReader {
     backend: Box<dyn PersistenceBackend>,
     threshold_time: TotalFrontier<Timestamp>,
+    shard: Option<SnapshotShard>,
+    is_owner: bool,
 }
 
 impl ConcreteSnapshotReader {
@@ -182,8 +216,20 @@ impl ConcreteSnapshotReader {
         Self {
             backend,
             threshold_time,
+            shard: None,
+            is_owner: true,
         }
     }
+
+    /// Loads only the entries of `shard`, as the snapshot is read by all the workers.
+    /// The obsolete chunks are removed only by the owner of the snapshot, the other
+    /// workers leave the snapshot intact.
+    #[must_use]
+    pub fn with_shard(mut self, shard: SnapshotShard, is_owner: bool) -> Self {
+        self.shard = Some(shard);
+        self.is_owner = is_owner;
+        self
+    }
 }
 
 impl<D, R> OperatorSnapshotReader<D, R> for ConcreteSnapshotReader
@@ -192,12 +238,29 @@ where
     R: ExchangeData,
 {
     fn load_persisted(&mut self) -> Result<Vec<(D, R)>, BackendError> {
-        let keys = self.backend.list_keys()?;
-        let chunks = get_chunks(keys, self.threshold_time);
-        for chunk in itertools::chain(chunks.too_old.iter(), chunks.too_new.iter()) {
-            self.backend.remove_key(&chunk.to_string())?;
+        let mut attempt = 1;
+        loop {
+            let keys = self.backend.list_keys()?;
+            let chunks = get_chunks(keys, self.threshold_time);
+            if self.is_owner {
+                for chunk in itertools::chain(chunks.too_old.iter(), chunks.too_new.iter()) {
+                    self.backend.remove_key(&chunk.to_string())?;
+                }
+            }
+            match read_chunks(&chunks.current, self.backend.as_ref()) {
+                Ok(mut data) => {
+                    if let Some(shard) = self.shard {
+                        data.retain(|(entry, _diff)| shard.contains(entry));
+                    }
+                    return Ok(data);
+                }
+                Err(e) if !self.is_owner && attempt < MAX_SHARED_SNAPSHOT_READ_ATTEMPTS => {
+                    warn!("Failed to read the snapshot of another worker, retrying: {e}");
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
         }
-        read_chunks(&chunks.current, self.backend.as_ref())
     }
 }
 
@@ -317,7 +380,9 @@ where
         // applies to both operator and input snapshot writers
         if let Some(max_time) = self.max_time {
             if TotalFrontier::At(max_time) < time {
-                self.maybe_save(time);
+                // all the buffered entries are finalized, even if `time` is not at the
+                // boundary of a snapshot interval, as it is for savepoints
+                self.save(max_time);
                 if let TotalFrontier::At(time) = time {
                     // only update max_time if TotalFrontier has some value
                     // if time is finished, there's no need to update max_time
@@ -345,6 +410,13 @@ where
 
 const MINIMAL_MERGE_WAIT_TIME: core::time::Duration = core::time::Duration::from_secs(1);
 
+/// Compacts the chunks of an operator snapshot in the background.
+///
+/// The writer only saves the changes of the state since the previous chunk, so the merger
+/// keeps the number of chunks logarithmic in the size of the state by merging them into
+/// leveled chunks. The large chunks are rewritten only once enough changes accumulate.
+/// If `full_snapshot_interval` is set, all the chunks are also periodically merged into a
+/// single full snapshot, so that the changes cancelling each other don't pile up.
 pub struct ConcreteSnapshotMerger {
     finish_sender: mpsc::Sender<()>,
     thread_handle: Option<thread::JoinHandle<()>>,
@@ -354,6 +426,7 @@ impl ConcreteSnapshotMerger {
     pub fn new<D, R>(
         backend: Box<dyn PersistenceBackend>,
         snapshot_interval: core::time::Duration,
+        full_snapshot_interval: Option<core::time::Duration>,
         time_querier: FinalizedTimeQuerier,
         receiver: mpsc::Receiver<()>,
     ) -> Self
@@ -361,8 +434,13 @@ impl ConcreteSnapshotMerger {
         D: ExchangeData,
         R: ExchangeData + Semigroup,
     {
-        let (finish_sender, thread_handle) =
-            Self::start::<D, R>(backend, snapshot_interval, time_querier, receiver);
+        let (finish_sender, thread_handle) = Self::start::<D, R>(
+            backend,
+            snapshot_interval,
+            full_snapshot_interval,
+            time_querier,
+            receiver,
+        );
         Self {
             finish_sender,
             thread_handle: Some(thread_handle),
@@ -379,6 +457,33 @@ impl ConcreteSnapshotMerger {
         backend: &mut dyn PersistenceBackend,
         time_querier: &mut FinalizedTimeQuerier,
     ) -> Result<(), BackendError>
+    where
+        D: ExchangeData,
+        R: ExchangeData + Semigroup,
+    {
+        Self::merge::<D, R>(backend, time_querier, false).map(|_| ())
+    }
+
+    /// Like [`Self::maybe_merge`], but merges the fully committed chunks at all levels
+    /// into a single full snapshot. It is saved at the lowest level it fits in that is not
+    /// lower than the level of the largest merged chunk. Returns whether it was saved, which
+    /// doesn't happen if there are no new fully committed chunks.
+    pub fn merge_all<D, R>(
+        backend: &mut dyn PersistenceBackend,
+        time_querier: &mut FinalizedTimeQuerier,
+    ) -> Result<bool, BackendError>
+    where
+        D: ExchangeData,
+        R: ExchangeData + Semigroup,
+    {
+        Self::merge::<D, R>(backend, time_querier, true)
+    }
+
+    fn merge<D, R>(
+        backend: &mut dyn PersistenceBackend,
+        time_querier: &mut FinalizedTimeQuerier,
+        merges_all_levels: bool,
+    ) -> Result<bool, BackendError>
     where
         D: ExchangeData,
         R: ExchangeData + Semigroup,
@@ -407,7 +512,7 @@ impl ConcreteSnapshotMerger {
             }
         }
         if unmerged_chunks.is_empty() {
-            return Ok(());
+            return Ok(false);
         }
         let mut buffer = read_chunks::<D, R>(&unmerged_chunks, backend)?;
         let mut max_allowed_size = 2;
@@ -420,13 +525,24 @@ impl ConcreteSnapshotMerger {
                 }
                 buffer.append(&mut v);
             }
-            if buffer.len() <= max_allowed_size {
+            if buffer.len() <= max_allowed_size && !merges_all_levels {
                 break;
             }
             max_allowed_size *= 2;
             level += 1;
         }
         consolidate(&mut buffer);
+        if merges_all_levels {
+            // The merged chunk has a later time than all the merged chunks, so it replaces
+            // them as long as its level is not lower than any of theirs.
+            let top_level = std::cmp::max(chunk_at_level.len().saturating_sub(1), 1);
+            level = 1;
+            max_allowed_size = 2;
+            while level < top_level {
+                max_allowed_size *= 2;
+                level += 1;
+            }
+        }
         // loop in case we have no bigger chunks yet
         while buffer.len() > max_allowed_size {
             max_allowed_size *= 2;
@@ -440,13 +556,15 @@ impl ConcreteSnapshotMerger {
         let serialized_data = serialize(&buffer).expect("entry should be serializable");
         let future = backend.put_value(&chunk.to_string(), serialized_data);
         // Can't start new round if future not finished.
-        futures::executor::block_on(future).expect("unexpected future cancelling")
+        futures::executor::block_on(future).expect("unexpected future cancelling")?;
+        Ok(merges_all_levels)
     }
 
     fn run<D, R>(
         mut backend: Box<dyn PersistenceBackend>,
         receiver: &mpsc::Receiver<()>,
         timeout: core::time::Duration,
+        full_snapshot_interval: Option<core::time::Duration>,
         time_querier: &mut FinalizedTimeQuerier,
         reader_finished_receiver: &mpsc::Receiver<()>,
     ) where
@@ -457,6 +575,7 @@ impl ConcreteSnapshotMerger {
             error!("Can't start snapshot merger as snapshot reader didn't finish gracefully");
         }
         let mut next_try_at = Instant::now();
+        let mut last_full_snapshot_at = Instant::now();
         loop {
             let now = Instant::now();
             let duration = next_try_at
@@ -467,8 +586,13 @@ impl ConcreteSnapshotMerger {
                 .expect("now with added timeout should fit into Instant");
             match receiver.recv_timeout(duration) {
                 Err(mpsc::RecvTimeoutError::Timeout) => {
-                    if let Err(e) = Self::maybe_merge::<D, R>(backend.as_mut(), time_querier) {
-                        error!("Error while trying to merge persisted data: {e}");
+                    let merges_all_levels = full_snapshot_interval
+                        .is_some_and(|interval| last_full_snapshot_at.elapsed() >= interval);
+                    // the full snapshot is only due later if it has been saved now
+                    match Self::merge::<D, R>(backend.as_mut(), time_querier, merges_all_levels) {
+                        Ok(true) => last_full_snapshot_at = Instant::now(),
+                        Ok(false) => {}
+                        Err(e) => error!("Error while trying to merge persisted data: {e}"),
                     }
                 }
                 Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => break,
@@ -479,6 +603,7 @@ impl ConcreteSnapshotMerger {
     fn start<D, R>(
         backend: Box<dyn PersistenceBackend>,
         timeout: core::time::Duration,
+        full_snapshot_interval: Option<core::time::Duration>,
         mut time_querier: FinalizedTimeQuerier,
         reader_finished_receiver: mpsc::Receiver<()>,
     ) -> (mpsc::Sender<()>, thread::JoinHandle<()>)
@@ -495,6 +620,7 @@ impl ConcreteSnapshotMerger {
                     backend,
                     &receiver,
                     timeout,
+                    full_snapshot_interval,
                     &mut time_querier,
                     &reader_finished_receiver,
                 );
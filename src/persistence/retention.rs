@@ -0,0 +1,152 @@
+// Copyright © 2026 Pathway
+
+//! Garbage collection of the persisted data.
+//!
+//! Without a [`RetentionPolicy`], the obsolete persisted data is removed only when the
+//! program restarts: the metadata of the former runs and the chunks of the input
+//! snapshots the rewind has passed through. With it, each worker runs a
+//! [`GarbageCollector`] that periodically removes the data superseded by the checkpoints
+//! that are already durable, so that the storage doesn't grow for as long as the
+//! program runs. The chunks of the operator snapshots are compacted by their mergers.
+
+use std::mem::take;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::{error, info};
+
+use crate::persistence::backends::PersistenceBackend;
+use crate::persistence::input_snapshot::SupersededChunksRemover;
+use crate::persistence::lock::PersistenceLock;
+use crate::persistence::state::FinalizedTimeQuerier;
+use crate::persistence::Error;
+
+/// How the obsolete persisted data is removed while the program runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// How often the obsolete data is looked for.
+    pub gc_interval: Duration,
+}
+
+impl RetentionPolicy {
+    pub fn new(gc_interval: Duration) -> Self {
+        Self { gc_interval }
+    }
+}
+
+enum Message {
+    AddInputSnapshot(Box<dyn PersistenceBackend>),
+    Finish,
+}
+
+/// The background task removing the obsolete persisted data of a worker. The worker 0
+/// also removes the metadata of the versions older than the latest stable one. Nothing
+/// is removed once the `lock` of the run on the storage is lost.
+pub struct GarbageCollector {
+    sender: mpsc::Sender<Message>,
+    thread_handle: Option<thread::JoinHandle<()>>,
+}
+
+impl GarbageCollector {
+    pub fn new(
+        retention_policy: RetentionPolicy,
+        time_querier: FinalizedTimeQuerier,
+        removes_metadata: bool,
+        lock: Option<Arc<PersistenceLock>>,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let thread_handle = thread::Builder::new()
+            .name("PersistenceGarbageCollector".to_string())
+            .spawn(move || {
+                Self::run(
+                    &receiver,
+                    retention_policy.gc_interval,
+                    time_querier,
+                    removes_metadata,
+                    lock.as_deref(),
+                );
+            })
+            .expect("persistence garbage collector thread creation should succeed");
+        Self {
+            sender,
+            thread_handle: Some(thread_handle),
+        }
+    }
+
+    /// Makes the collector remove the chunks of the input snapshot stored in `backend`
+    /// once they are superseded by a durable checkpoint.
+    pub fn add_input_snapshot(&self, backend: Box<dyn PersistenceBackend>) {
+        if self
+            .sender
+            .send(Message::AddInputSnapshot(backend))
+            .is_err()
+        {
+            error!("Persistence garbage collector has stopped, the input snapshot won't be pruned");
+        }
+    }
+
+    /// Removes the obsolete data once, returns the number of removed input snapshot chunks.
+    pub fn collect(
+        time_querier: &mut FinalizedTimeQuerier,
+        removes_metadata: bool,
+        input_snapshots: &mut [SupersededChunksRemover],
+    ) -> Result<usize, Error> {
+        let threshold_time = if removes_metadata {
+            time_querier.remove_obsolete_versions()?
+        } else {
+            time_querier.last_finalized_timestamp()?
+        };
+        let mut removed_chunks = 0;
+        for input_snapshot in input_snapshots {
+            removed_chunks += input_snapshot.remove_superseded_chunks(threshold_time)?;
+        }
+        Ok(removed_chunks)
+    }
+
+    fn run(
+        receiver: &mpsc::Receiver<Message>,
+        gc_interval: Duration,
+        mut time_querier: FinalizedTimeQuerier,
+        removes_metadata: bool,
+        lock: Option<&PersistenceLock>,
+    ) {
+        let mut input_snapshots = Vec::new();
+        let mut next_collection_at = Instant::now() + gc_interval;
+        loop {
+            let timeout = next_collection_at.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(timeout) {
+                Ok(Message::AddInputSnapshot(backend)) => {
+                    input_snapshots.push(SupersededChunksRemover::new(backend));
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) if lock.is_some_and(|l| !l.is_held()) => {
+                    error!("The lease on the persistent storage is lost, the obsolete persisted data isn't removed anymore");
+                    break;
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    match Self::collect(&mut time_querier, removes_metadata, &mut input_snapshots) {
+                        Ok(0) => {}
+                        Ok(removed_chunks) => {
+                            info!("Removed {removed_chunks} superseded input snapshot chunks");
+                        }
+                        Err(e) => error!("Error while removing obsolete persisted data: {e}"),
+                    }
+                    next_collection_at = Instant::now() + gc_interval;
+                }
+                Ok(Message::Finish) | Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
+    }
+}
+
+impl Drop for GarbageCollector {
+    fn drop(&mut self) {
+        // the thread may have already stopped, then there's nobody to notify
+        let _ = self.sender.send(Message::Finish);
+        if let Some(thread_handle) = take(&mut self.thread_handle) {
+            if let Err(e) = thread_handle.join() {
+                error!("Failed to join persistence garbage collector thread: {e:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright © 2026 Pathway
+
+//! On-demand savepoints.
+//!
+//! The persisted state is normally committed each time the output of a worker crosses
+//! the boundary of a snapshot interval. A savepoint commits it right away instead: every
+//! worker of the computation flushes its snapshots and commits the state up to the time
+//! its sinks have already finalized, without waiting for the next boundary. This makes it
+//! possible to take a snapshot just before a deployment.
+//!
+//! A savepoint is requested with [`request_savepoint`], either directly or through
+//! `POST /savepoint` on the monitoring HTTP server, in any of the processes of the
+//! computation. The first worker of that process leads it over a timely channel
+//! connecting all the workers, of all the processes, in two rounds. First, it asks every
+//! worker for the time its sinks have finalized and takes the smallest of them as the
+//! time of the savepoint. Then, it asks every worker to commit its state up to that
+//! common time and collects their answers. The savepoint is identified by this time,
+//! which is the time a computation restarted from it continues from.
+
+use std::cmp::min;
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::thread::{self, Thread};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use timely::communication::{Message, Pull, Push};
+use timely::dataflow::Scope;
+use timely::worker::AsWorker;
+
+use crate::engine::{Timestamp, TotalFrontier};
+
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SavepointError {
+    #[error("no computation with persistence enabled is running")]
+    NoPersistence,
+
+    #[error("the savepoint wasn't committed within {0:?}")]
+    TimedOut(Duration),
+
+    #[error("the computation has already finished, its whole state is committed")]
+    Finished,
+
+    #[error("committing the savepoint failed: {0}")]
+    Failed(String),
+}
+
+#[derive(Debug)]
+struct State {
+    leader: Option<Thread>,
+    requested: u64,
+    started: u64,
+    completed: u64,
+    result: Result<Timestamp, SavepointError>,
+    finished: bool,
+}
+
+/// Passes the savepoint requests of a process to its first worker, which leads them, and
+/// the results back.
+#[derive(Debug)]
+pub struct SavepointCoordinator {
+    worker_count: usize,
+    state: Mutex<State>,
+    savepoint_done: Condvar,
+}
+
+impl SavepointCoordinator {
+    pub fn new(worker_count: usize) -> Self {
+        Self {
+            worker_count,
+            state: Mutex::new(State {
+                leader: None,
+                requested: 0,
+                started: 0,
+                completed: 0,
+                result: Err(SavepointError::Finished),
+                finished: false,
+            }),
+            savepoint_done: Condvar::new(),
+        }
+    }
+
+    /// Checks if the worker `worker_index` leads the savepoints requested in its process.
+    pub fn is_leader(&self, worker_index: usize) -> bool {
+        worker_index % self.worker_count == 0
+    }
+
+    /// Registers the thread of the leading worker, which is woken up when a savepoint is
+    /// requested.
+    pub fn register_leader(&self, thread: Thread) {
+        self.state.lock().unwrap().leader = Some(thread);
+    }
+
+    /// Returns the number of the savepoint the leading worker has to start, if one has
+    /// been requested since the last one was started. The requests made in the meantime
+    /// are served by a single savepoint.
+    pub fn start_savepoint(&self) -> Option<u64> {
+        let mut state = self.state.lock().unwrap();
+        (state.started < state.requested).then(|| {
+            state.started = state.requested;
+            state.started
+        })
+    }
+
+    /// Records the result of the savepoint `number`, committed by all the workers.
+    pub fn savepoint_completed(&self, number: u64, result: Result<Timestamp, SavepointError>) {
+        let mut state = self.state.lock().unwrap();
+        state.completed = number;
+        state.result = result;
+        self.savepoint_done.notify_all();
+    }
+
+    /// Marks the leading worker as finished, so that the savepoints no longer wait for it.
+    pub fn leader_finished(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.finished = true;
+        self.savepoint_done.notify_all();
+    }
+
+    /// Requests a savepoint and waits for all the workers to commit it. Returns the
+    /// identifier of the savepoint.
+    pub fn request(&self, timeout: Duration) -> Result<Timestamp, SavepointError> {
+        let mut state = self.state.lock().unwrap();
+        state.requested += 1;
+        let number = state.requested;
+        if let Some(leader) = &state.leader {
+            leader.unpark();
+        }
+        let (state, wait_result) = self
+            .savepoint_done
+            .wait_timeout_while(state, timeout, |state| {
+                state.completed < number && !state.finished
+            })
+            .unwrap();
+        if state.completed >= number {
+            // a later savepoint also commits everything requested by this one
+            state.result.clone()
+        } else if wait_result.timed_out() {
+            Err(SavepointError::TimedOut(timeout))
+        } else {
+            Err(SavepointError::Finished)
+        }
+    }
+}
+
+/// The messages the workers exchange to commit a savepoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum SavepointMessage {
+    /// The worker `origin` asks every worker for the time up to which it can commit its
+    /// state for the savepoint `number`.
+    Prepare { origin: usize, number: u64 },
+
+    /// A worker can commit its state up to `time` for the savepoint `number`.
+    Prepared {
+        number: u64,
+        time: TotalFrontier<Timestamp>,
+    },
+
+    /// The worker `origin` asks every worker to commit its state up to `time`, the
+    /// common time of the savepoint `number`.
+    Commit {
+        origin: usize,
+        number: u64,
+        time: Timestamp,
+    },
+
+    /// A worker has committed its state for the savepoint `number`.
+    Committed {
+        number: u64,
+        result: Result<(), String>,
+    },
+}
+
+#[derive(Debug)]
+enum Round {
+    // the smallest of the times the workers can commit their state up to
+    Preparing {
+        time: TotalFrontier<Timestamp>,
+    },
+    Committing {
+        time: Timestamp,
+        error: Option<String>,
+    },
+}
+
+#[derive(Debug)]
+struct Answers {
+    count: usize,
+    round: Round,
+}
+
+/// The end of the savepoint channel connecting a worker to all the workers of the
+/// computation, including those of the other processes.
+pub struct SavepointExchange {
+    coordinator: Arc<SavepointCoordinator>,
+    is_leader: bool,
+    worker_index: usize,
+    pushers: Vec<Box<dyn Push<Message<SavepointMessage>>>>,
+    puller: Box<dyn Pull<Message<SavepointMessage>>>,
+    answers: HashMap<u64, Answers>,
+}
+
+impl SavepointExchange {
+    /// Allocates the savepoint channel of the worker building the dataflow in `scope`.
+    /// Every worker has to allocate it at the same point of building its dataflow.
+    pub fn new<S: Scope>(scope: &mut S, coordinator: Arc<SavepointCoordinator>) -> Self {
+        let identifier = scope.new_identifier();
+        let address = scope.addr();
+        let (pushers, puller) = scope.allocate(identifier, &address);
+        let worker_index = scope.index();
+        let is_leader = coordinator.is_leader(worker_index);
+        if is_leader {
+            coordinator.register_leader(thread::current());
+        }
+        Self {
+            coordinator,
+            is_leader,
+            worker_index,
+            pushers,
+            puller,
+            answers: HashMap::new(),
+        }
+    }
+
+    fn send(&mut self, worker_index: usize, message: SavepointMessage) {
+        let pusher = &mut self.pushers[worker_index];
+        pusher.send(Message::from_typed(message));
+        pusher.done();
+    }
+
+    fn send_to_all(&mut self, message: &SavepointMessage) {
+        for worker_index in 0..self.pushers.len() {
+            self.send(worker_index, message.clone());
+        }
+    }
+
+    /// Starts the savepoint requested in the process, if this worker leads them, and
+    /// handles the messages received from the other workers. `prepare` returns the time
+    /// up to which the state of this worker can be committed and `commit` commits it up
+    /// to the given time, not later than the one returned by `prepare`.
+    pub fn process(
+        &mut self,
+        mut prepare: impl FnMut() -> TotalFrontier<Timestamp>,
+        mut commit: impl FnMut(Timestamp) -> Result<(), String>,
+    ) {
+        if self.is_leader {
+            if let Some(number) = self.coordinator.start_savepoint() {
+                self.answers.insert(
+                    number,
+                    Answers {
+                        count: 0,
+                        round: Round::Preparing {
+                            time: TotalFrontier::Done,
+                        },
+                    },
+                );
+                let origin = self.worker_index;
+                self.send_to_all(&SavepointMessage::Prepare { origin, number });
+            }
+        }
+        while let Some(message) = self.puller.recv() {
+            match message.into_typed() {
+                SavepointMessage::Prepare { origin, number } => {
+                    let time = prepare();
+                    self.send(origin, SavepointMessage::Prepared { number, time });
+                }
+                SavepointMessage::Prepared { number, time } => {
+                    self.accept_prepared(number, time);
+                }
+                SavepointMessage::Commit {
+                    origin,
+                    number,
+                    time,
+                } => {
+                    let result = commit(time);
+                    self.send(origin, SavepointMessage::Committed { number, result });
+                }
+                SavepointMessage::Committed { number, result } => {
+                    self.accept_committed(number, result);
+                }
+            }
+        }
+    }
+
+    // Counts the answer to a round of the savepoint `number`. Returns the answers of
+    // the round once all the workers have sent them.
+    fn count_answer(&mut self, number: u64, update: impl FnOnce(&mut Round)) -> Option<Answers> {
+        let answers = self.answers.get_mut(&number)?;
+        answers.count += 1;
+        update(&mut answers.round);
+        (answers.count == self.pushers.len()).then(|| self.answers.remove(&number).unwrap())
+    }
+
+    fn accept_prepared(&mut self, number: u64, time: TotalFrontier<Timestamp>) {
+        let answers = self.count_answer(number, |round| {
+            if let Round::Preparing { time: common_time } = round {
+                *common_time = min(*common_time, time);
+            }
+        });
+        let Some(Answers {
+            round: Round::Preparing { time },
+            ..
+        }) = answers
+        else {
+            return;
+        };
+        let TotalFrontier::At(time) = time else {
+            self.coordinator
+                .savepoint_completed(number, Err(SavepointError::Finished));
+            return;
+        };
+        self.answers.insert(
+            number,
+            Answers {
+                count: 0,
+                round: Round::Committing { time, error: None },
+            },
+        );
+        let origin = self.worker_index;
+        self.send_to_all(&SavepointMessage::Commit {
+            origin,
+            number,
+            time,
+        });
+    }
+
+    fn accept_committed(&mut self, number: u64, result: Result<(), String>) {
+        let answers = self.count_answer(number, |round| {
+            if let (Round::Committing { error, .. }, Err(new_error)) = (round, result) {
+                error.get_or_insert(new_error);
+            }
+        });
+        let Some(Answers {
+            round: Round::Committing { time, error },
+            ..
+        }) = answers
+        else {
+            return;
+        };
+        let result = match error {
+            Some(error) => Err(SavepointError::Failed(error)),
+            None => Ok(time),
+        };
+        self.coordinator.savepoint_completed(number, result);
+    }
+}
+
+impl Drop for SavepointExchange {
+    fn drop(&mut self) {
+        if self.is_leader {
+            self.coordinator.leader_finished();
+        }
+    }
+}
+
+// the coordinator of the computation currently running in this process
+static ACTIVE_COORDINATOR: Mutex<Weak<SavepointCoordinator>> = Mutex::new(Weak::new());
+
+/// Makes `coordinator` receive the savepoint requests of this process until it is
+/// dropped.
+pub fn set_active_coordinator(coordinator: &Arc<SavepointCoordinator>) {
+    *ACTIVE_COORDINATOR.lock().unwrap() = Arc::downgrade(coordinator);
+}
+
+/// Requests a savepoint of the computation running in this process and waits at most
+/// `timeout` for it to be committed by all its workers. Returns the identifier of the
+/// savepoint.
+pub fn request_savepoint(timeout: Duration) -> Result<Timestamp, SavepointError> {
+    let coordinator = ACTIVE_COORDINATOR
+        .lock()
+        .unwrap()
+        .upgrade()
+        .ok_or(SavepointError::NoPersistence)?;
+    coordinator.request(timeout)
+}
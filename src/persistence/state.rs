@@ -7,14 +7,43 @@ use std::mem::swap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::engine::{Timestamp, TotalFrontier};
+use crate::engine::{RunLineage, Timestamp, TotalFrontier};
 use crate::persistence::backends::PersistenceBackend;
 use crate::persistence::Error;
 
 const EXPECTED_KEY_PARTS: usize = 3;
 
+/// The version of the format of the metadata blocks written by this version of the
+/// engine. It's increased whenever the format changes in a way that needs a migration.
+///
+/// Version 0 is the format of the blocks written before the format was versioned. They
+/// may have no number of workers specified. The blocks older than version 2 come from the
+/// runs that named the persisted operator states with [`OperatorIdScheme::Sequential`].
+pub const METADATA_FORMAT_VERSION: u32 = 2;
+
+/// How the persisted states of the operators without a unique name are named.
+///
+/// The scheme is kept across the runs resuming from the same persisted state, so that
+/// the state written under the older scheme is still found. The new persisted states are
+/// named with [`OperatorIdScheme::Structural`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OperatorIdScheme {
+    /// The states are numbered in the order the operators are built, so inserting an
+    /// operator shifts the names of the states of all the operators built after it.
+    #[default]
+    Sequential,
+
+    /// The states are named after the identities of their operators, derived from the
+    /// structure of the graph, so they don't depend on the unrelated operators.
+    Structural,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StoredMetadata {
+    // Unspecified in the blocks written before the format was versioned.
+    #[serde(default)]
+    pub format_version: u32,
+
     pub last_advanced_timestamp: TotalFrontier<Timestamp>,
 
     // Workers count from the latest run is required to determine
@@ -29,6 +58,14 @@ pub struct StoredMetadata {
     // better than to use the current number of workers.
     #[serde(default)]
     pub total_workers: usize,
+
+    // The run that has written the metadata. Unspecified in the blocks
+    // written by the older versions.
+    #[serde(default)]
+    pub lineage: Option<RunLineage>,
+
+    #[serde(default)]
+    pub operator_id_scheme: OperatorIdScheme,
 }
 
 #[derive(Debug)]
@@ -36,6 +73,8 @@ pub struct MetadataAccessor {
     backend: Box<dyn PersistenceBackend>,
     internal_state: StoredMetadata,
     past_runs_threshold_time: TotalFrontier<Timestamp>,
+    past_runs_lineage: Option<RunLineage>,
+    past_runs_total_workers: Option<usize>,
 
     current_key_to_use: String,
     next_key_to_use: String,
@@ -44,21 +83,44 @@ pub struct MetadataAccessor {
 impl StoredMetadata {
     pub fn new(total_workers: usize) -> Self {
         Self {
+            format_version: METADATA_FORMAT_VERSION,
             last_advanced_timestamp: TotalFrontier::At(Timestamp(0)),
             total_workers,
+            lineage: None,
+            operator_id_scheme: OperatorIdScheme::Structural,
         }
     }
 
+    /// Parses a block of the current or an older format and brings it to the current one.
+    /// The blocks of the newer formats are rejected, as they can't be interpreted reliably.
     pub fn parse(bytes: &[u8], default_total_workers: usize) -> Result<Self, Error> {
+        let mut result = Self::parse_stored(bytes)?;
+        result.upgrade(default_total_workers);
+        Ok(result)
+    }
+
+    fn parse_stored(bytes: &[u8]) -> Result<Self, Error> {
         let data = std::str::from_utf8(bytes)?;
-        let mut result = serde_json::from_str::<StoredMetadata>(data.trim_end())
+        let result = serde_json::from_str::<StoredMetadata>(data.trim_end())
             .map_err(|e| Error::IncorrectMetadataFormat(data.to_string(), e))?;
+        if result.format_version > METADATA_FORMAT_VERSION {
+            return Err(Error::UnsupportedMetadataFormatVersion {
+                found: result.format_version,
+                supported: METADATA_FORMAT_VERSION,
+            });
+        }
+        Ok(result)
+    }
 
+    fn upgrade(&mut self, default_total_workers: usize) {
         // The block comes from an older version and has no number of workers specified.
-        if result.total_workers == 0 {
-            result.total_workers = default_total_workers;
+        if self.total_workers == 0 {
+            self.total_workers = default_total_workers;
         }
-        Ok(result)
+        if self.format_version < 2 {
+            self.operator_id_scheme = OperatorIdScheme::Sequential;
+        }
+        self.format_version = METADATA_FORMAT_VERSION;
     }
 
     pub fn serialize(&self) -> String {
@@ -123,12 +185,16 @@ impl Display for MetadataKey {
 
 struct VersionInformation {
     worker_finalized_times: Vec<Option<TotalFrontier<Timestamp>>>,
+    lineage: Option<RunLineage>,
+    operator_id_scheme: OperatorIdScheme,
 }
 
 impl VersionInformation {
     pub fn new(total_workers: usize) -> Self {
         Self {
             worker_finalized_times: vec![None; total_workers],
+            lineage: None,
+            operator_id_scheme: OperatorIdScheme::default(),
         }
     }
 
@@ -147,6 +213,10 @@ impl VersionInformation {
         }
     }
 
+    pub fn total_workers(&self) -> usize {
+        self.worker_finalized_times.len()
+    }
+
     pub fn threshold_time(&self) -> Option<TotalFrontier<Timestamp>> {
         if self.worker_finalized_times.contains(&None) {
             // Not all workers reported their threshold times
@@ -157,11 +227,20 @@ impl VersionInformation {
     }
 }
 
+struct PastRunsInformation {
+    threshold_time: TotalFrontier<Timestamp>,
+    current_version: u128,
+    latest_stable_version: Option<u128>,
+    lineage: Option<RunLineage>,
+    total_workers: Option<usize>,
+    operator_id_scheme: Option<OperatorIdScheme>,
+}
+
 fn compute_threshold_time_and_versions(
     backend: &mut dyn PersistenceBackend,
     should_remove: bool,
     total_workers: usize,
-) -> Result<(TotalFrontier<Timestamp>, u128, Option<u128>), Error> {
+) -> Result<PastRunsInformation, Error> {
     // We want to start from the latest version that has metadata for all its workers.
     // In the code, we call it the latest stable version.
     // Only top-level keys are needed for the metadata reconstruction.
@@ -184,11 +263,17 @@ fn compute_threshold_time_and_versions(
         };
         let block_result = StoredMetadata::parse(&raw_block, total_workers);
         match block_result {
+            // Written by a newer version of the engine, it mustn't be removed as broken
+            Err(e @ Error::UnsupportedMetadataFormatVersion { .. }) => return Err(e),
             Ok(block) => {
-                version_information
+                let version = version_information
                     .entry(metadata_key.version)
-                    .or_insert(VersionInformation::new(block.total_workers))
-                    .update_worker_time(metadata_key.worker_id, block.last_advanced_timestamp);
+                    .or_insert(VersionInformation::new(block.total_workers));
+                version.update_worker_time(metadata_key.worker_id, block.last_advanced_timestamp);
+                if block.lineage.is_some() {
+                    version.lineage = block.lineage;
+                }
+                version.operator_id_scheme = block.operator_id_scheme;
             }
             Err(e) => {
                 warn!("Broken metadata block for key {key}. Error: {e}");
@@ -203,6 +288,9 @@ fn compute_threshold_time_and_versions(
     }
 
     let mut past_runs_threshold_time = TotalFrontier::At(Timestamp(0));
+    let mut past_runs_lineage = None;
+    let mut past_runs_total_workers = None;
+    let mut past_runs_operator_id_scheme = None;
     let mut latest_stable_version = None;
     for (version_number, version_data) in &version_information {
         let threshold_time = version_data.threshold_time();
@@ -212,6 +300,9 @@ fn compute_threshold_time_and_versions(
         if latest_stable_version.is_none_or(|current_version| current_version < *version_number) {
             latest_stable_version = Some(*version_number);
             past_runs_threshold_time = threshold_time;
+            past_runs_lineage.clone_from(&version_data.lineage);
+            past_runs_total_workers = Some(version_data.total_workers());
+            past_runs_operator_id_scheme = Some(version_data.operator_id_scheme);
         }
     }
 
@@ -237,11 +328,59 @@ fn compute_threshold_time_and_versions(
         }
     }
 
-    Ok((
-        past_runs_threshold_time,
+    Ok(PastRunsInformation {
+        threshold_time: past_runs_threshold_time,
         current_version,
         latest_stable_version,
-    ))
+        lineage: past_runs_lineage,
+        total_workers: past_runs_total_workers,
+        operator_id_scheme: past_runs_operator_id_scheme,
+    })
+}
+
+/// Rewrites the metadata blocks of the older formats in the current one, so that the
+/// persisted state doesn't depend on the compatibility code of the engine anymore.
+/// Returns the number of rewritten blocks.
+///
+/// The blocks without the number of workers are assigned the number of workers that
+/// have written the blocks of the same version. The broken blocks are left as they are.
+pub fn migrate_metadata(backend: &dyn PersistenceBackend) -> Result<usize, Error> {
+    let mut blocks = Vec::new();
+    let mut workers_per_version: HashMap<u128, usize> = HashMap::new();
+    for key in backend.list_keys()? {
+        if key.contains('/') {
+            continue;
+        }
+        let Some(metadata_key) = MetadataKey::from_str(&key) else {
+            continue;
+        };
+        let raw_block = backend.get_value(&key)?;
+        let block = match StoredMetadata::parse_stored(&raw_block) {
+            Ok(block) => block,
+            Err(e @ Error::UnsupportedMetadataFormatVersion { .. }) => return Err(e),
+            Err(e) => {
+                warn!("Broken metadata block for key {key} won't be migrated. Error: {e}");
+                continue;
+            }
+        };
+        let workers = workers_per_version.entry(metadata_key.version).or_default();
+        *workers = (*workers).max(metadata_key.worker_id + 1);
+        if block.format_version < METADATA_FORMAT_VERSION {
+            blocks.push((key, metadata_key.version, block));
+        }
+    }
+
+    let migrated_blocks = blocks.len();
+    for (key, version, mut block) in blocks {
+        info!(
+            "Migrating metadata block {key} from format version {} to {METADATA_FORMAT_VERSION}",
+            block.format_version
+        );
+        block.upgrade(workers_per_version[&version]);
+        futures::executor::block_on(backend.put_value(&key, block.serialize().into()))
+            .expect("unexpected future cancelling")?;
+    }
+    Ok(migrated_blocks)
 }
 
 impl MetadataAccessor {
@@ -250,9 +389,18 @@ impl MetadataAccessor {
         worker_id: usize,
         total_workers: usize,
     ) -> Result<Self, Error> {
-        let internal_state = StoredMetadata::new(total_workers);
-        let (past_runs_threshold_time, current_version, latest_stable_version) =
-            compute_threshold_time_and_versions(backend.as_mut(), worker_id == 0, total_workers)?;
+        let mut internal_state = StoredMetadata::new(total_workers);
+        let PastRunsInformation {
+            threshold_time: past_runs_threshold_time,
+            current_version,
+            latest_stable_version,
+            lineage: past_runs_lineage,
+            total_workers: past_runs_total_workers,
+            operator_id_scheme: past_runs_operator_id_scheme,
+        } = compute_threshold_time_and_versions(backend.as_mut(), worker_id == 0, total_workers)?;
+        if let Some(operator_id_scheme) = past_runs_operator_id_scheme {
+            internal_state.operator_id_scheme = operator_id_scheme;
+        }
         info!("Worker {worker_id} is on the version {current_version}. The latest stable metadata version is {latest_stable_version:?}");
         let current_key_to_use =
             MetadataKey::from_components(current_version, worker_id, 0).to_string();
@@ -263,6 +411,8 @@ impl MetadataAccessor {
             backend,
             internal_state,
             past_runs_threshold_time,
+            past_runs_lineage,
+            past_runs_total_workers,
             current_key_to_use,
             next_key_to_use,
         })
@@ -272,6 +422,28 @@ impl MetadataAccessor {
         self.past_runs_threshold_time
     }
 
+    /// The lineage of the run that has written the state the current run resumes from.
+    pub fn past_runs_lineage(&self) -> Option<&RunLineage> {
+        self.past_runs_lineage.as_ref()
+    }
+
+    /// The number of workers of the run that has written the state the current run
+    /// resumes from, if there is such a run.
+    pub fn past_runs_total_workers(&self) -> Option<usize> {
+        self.past_runs_total_workers
+    }
+
+    /// How the persisted operator states of the current run are named. It's the scheme of
+    /// the run it resumes from, if there's such a run.
+    pub fn operator_id_scheme(&self) -> OperatorIdScheme {
+        self.internal_state.operator_id_scheme
+    }
+
+    /// Sets the lineage of the current run, to be stored with its metadata.
+    pub fn set_lineage(&mut self, lineage: RunLineage) {
+        self.internal_state.lineage = Some(lineage);
+    }
+
     pub fn accept_finalized_timestamp(&mut self, timestamp: TotalFrontier<Timestamp>) {
         self.internal_state.last_advanced_timestamp = timestamp;
     }
@@ -309,7 +481,16 @@ impl FinalizedTimeQuerier {
     pub fn last_finalized_timestamp(&mut self) -> Result<TotalFrontier<Timestamp>, Error> {
         Ok(
             compute_threshold_time_and_versions(self.backend.as_mut(), false, self.total_workers)?
-                .0,
+                .threshold_time,
+        )
+    }
+
+    /// Same as [`Self::last_finalized_timestamp`], but also removes the metadata of the
+    /// versions older than the latest stable one. Should be called by a single worker.
+    pub fn remove_obsolete_versions(&mut self) -> Result<TotalFrontier<Timestamp>, Error> {
+        Ok(
+            compute_threshold_time_and_versions(self.backend.as_mut(), true, self.total_workers)?
+                .threshold_time,
         )
     }
 }
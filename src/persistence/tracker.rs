@@ -3,6 +3,7 @@
 use differential_dataflow::difference::Semigroup;
 use differential_dataflow::ExchangeData;
 use log::error;
+use std::cmp::max;
 use std::collections::{HashMap, HashSet};
 use std::mem::take;
 use std::sync::{Arc, Mutex};
@@ -15,10 +16,12 @@ use crate::persistence::cached_object_storage::{
 };
 use crate::persistence::config::{PersistenceManagerConfig, ReadersQueryPurpose};
 use crate::persistence::input_snapshot::{ReadInputSnapshot, SnapshotMode};
+use crate::persistence::lock::PersistenceLock;
 use crate::persistence::operator_snapshot::{
     ConcreteSnapshotMerger, Flushable, OperatorSnapshotReader,
 };
-use crate::persistence::state::MetadataAccessor;
+use crate::persistence::retention::GarbageCollector;
+use crate::persistence::state::{MetadataAccessor, OperatorIdScheme};
 use crate::persistence::Error as PersistenceBackendError;
 use crate::persistence::{
     PersistenceTime, PersistentId, SharedOperatorSnapshotWriter, SharedSnapshotWriter,
@@ -58,6 +61,10 @@ pub struct WorkerPersistentStorage {
     sink_threshold_times: Vec<TotalFrontier<Timestamp>>,
     registered_persistent_ids: HashSet<PersistentId>,
     cached_object_accessors: Vec<SharedCachedObjectsExternalAccessor>,
+    garbage_collector: Option<GarbageCollector>,
+
+    // Dropped last, so that the lease is released after everything else is saved.
+    lock: Option<Arc<PersistenceLock>>,
 }
 
 pub type SharedWorkerPersistentStorage = Arc<Mutex<WorkerPersistentStorage>>;
@@ -94,8 +101,13 @@ impl LogicalTimeCommitData {
 
 impl WorkerPersistentStorage {
     pub fn new(config: PersistenceManagerConfig) -> Result<Self, PersistenceBackendError> {
+        // The lease has to be held by the run before the metadata of the former runs is
+        // read, as the worker 0 removes the obsolete part of it, so all the workers wait.
+        let lock = config.wait_for_lock()?;
         Ok(Self {
             metadata_storage: config.create_metadata_storage()?,
+            garbage_collector: config.create_garbage_collector(lock.clone())?,
+            lock,
             config,
 
             snapshot_writers: HashMap::new(),
@@ -145,6 +157,10 @@ impl WorkerPersistentStorage {
         self.metadata_storage.last_advanced_timestamp()
     }
 
+    pub fn operator_id_scheme(&self) -> OperatorIdScheme {
+        self.metadata_storage.operator_id_scheme()
+    }
+
     pub fn register_input_source(&mut self, persistent_id: PersistentId) {
         assert!(
             !self.registered_persistent_ids.contains(&persistent_id),
@@ -191,16 +207,58 @@ impl WorkerPersistentStorage {
                 return Ok(());
             }
         }
-        let timestamp_updated = normalized_finalized_timestamp != self.last_finalized_timestamp();
+        // a savepoint may have already committed a later time within the same interval
+        let timestamp_updated = normalized_finalized_timestamp > self.last_finalized_timestamp();
         if timestamp_updated {
-            let mut commit_data =
-                self.accept_finalized_timestamp(normalized_finalized_timestamp)?;
-            commit_data.prepare()?;
-            self.commit_finalized_timestamp(&commit_data)?;
+            self.commit(normalized_finalized_timestamp)?;
         }
         Ok(())
     }
 
+    /// Returns the time up to which the state of the worker can be committed right away,
+    /// that is, the time finalized by all its sinks, not rounded down to the snapshot
+    /// interval. If it can't be committed, because it is before the start of this run,
+    /// the last committed time is returned instead.
+    pub fn savepoint_frontier(&self) -> TotalFrontier<Timestamp> {
+        let last_finalized_timestamp = self.last_finalized_timestamp();
+        let Some(worker_finalized_timestamp) = self.sink_threshold_times.iter().min().copied()
+        else {
+            return last_finalized_timestamp;
+        };
+        let after_run_start = match (worker_finalized_timestamp, self.config.run_start_timestamp) {
+            (TotalFrontier::At(timestamp), Some(run_start_timestamp)) => {
+                timestamp >= run_start_timestamp
+            }
+            _ => true,
+        };
+        if after_run_start {
+            max(worker_finalized_timestamp, last_finalized_timestamp)
+        } else {
+            last_finalized_timestamp
+        }
+    }
+
+    /// Commits the state up to `frontier`, which can't be later than the one returned by
+    /// [`Self::savepoint_frontier`]. Returns the last committed time.
+    pub fn commit_savepoint(
+        &mut self,
+        frontier: TotalFrontier<Timestamp>,
+    ) -> Result<TotalFrontier<Timestamp>, PersistenceBackendError> {
+        if frontier > self.last_finalized_timestamp() {
+            self.commit(frontier)?;
+        }
+        Ok(self.last_finalized_timestamp())
+    }
+
+    fn commit(
+        &mut self,
+        finalized_timestamp: TotalFrontier<Timestamp>,
+    ) -> Result<(), PersistenceBackendError> {
+        let mut commit_data = self.accept_finalized_timestamp(finalized_timestamp)?;
+        commit_data.prepare()?;
+        self.commit_finalized_timestamp(&commit_data)
+    }
+
     /// This method is called when the worker has finished the processing of time `timestamp`.
     /// If `timestamp` is `None` it means that all output within this worker is finished.
     fn accept_finalized_timestamp(
@@ -252,7 +310,9 @@ impl WorkerPersistentStorage {
             accessor.lock().unwrap().wait_for_all_uploads()?;
         }
 
-        if let Err(e) = self.metadata_storage.save_current_state() {
+        if self.lock.as_ref().is_some_and(|lock| !lock.is_held()) {
+            error!("The lease on the persistent storage is lost, the current state isn't saved");
+        } else if let Err(e) = self.metadata_storage.save_current_state() {
             // The data dump isn't corrupt, so we can continue execution.
             error!("Failed to save the current state, the data may duplicate in the re-run: {e}");
         }
@@ -284,6 +344,16 @@ impl WorkerPersistentStorage {
                 .config
                 .create_snapshot_writer(persistent_id, snapshot_mode)?;
             self.snapshot_writers.insert(persistent_id, writer.clone());
+            if let Some(garbage_collector) = &self.garbage_collector {
+                // the snapshots with data are needed in full for the rewind
+                if matches!(
+                    self.config.snapshot_mode(snapshot_mode),
+                    SnapshotMode::OffsetsOnly
+                ) {
+                    garbage_collector
+                        .add_input_snapshot(self.config.create_snapshot_gc_backend(persistent_id)?);
+                }
+            }
             Ok(writer)
         }
     }
@@ -299,6 +369,7 @@ impl WorkerPersistentStorage {
         let (reader, merger) = self.config.create_operator_snapshot_readers::<D, R>(
             persistent_id,
             self.metadata_storage.past_runs_threshold_time(),
+            self.metadata_storage.past_runs_total_workers(),
         )?;
         self.operator_snapshot_mergers.push(merger);
         Ok(Box::new(reader))
@@ -9,23 +9,44 @@ use crate::connectors::data_storage::postgres::{
     SslMode,
 };
 use crate::engine::graph::{
-    ErrorLogHandle, ExportedTable, JoinExactlyOnce, OperatorProperties, SubscribeCallbacks,
+    DeliverySemantics, ErrorLogHandle, ExportedTable, FlightEndpoint, JoinExactlyOnce,
+    OperatorProperties, OutputBatching, OutputMode, OutputWriters, SortColumn, SubscribeCallbacks,
     SubscribeCallbacksBuilder, SubscribeConfig,
 };
 use crate::engine::license::{Error as LicenseError, License};
+use crate::engine::served_tables::register_served_table;
+use crate::engine::shared_memory_export::SharedMemoryExportedTable;
+use crate::engine::shutdown::request_graceful_shutdown as request_graceful_shutdown_in_process;
+use crate::engine::shutdown::request_suspend as request_suspend_in_process;
+use crate::engine::user_metrics;
+use crate::engine::websocket::websocket_callbacks;
 use crate::engine::{
-    Computer as EngineComputer, Expressions, PyObjectWrapper as InternalPyObjectWrapper,
-    ShardPolicy, TotalFrontier, WindowProperties,
+    Computer as EngineComputer, Expressions, OrderingViolationPolicy,
+    PyObjectWrapper as InternalPyObjectWrapper, RunLineage, ShardPolicy, StepMode, TotalFrontier,
+    WindowProperties,
 };
 use crate::persistence::frontier::OffsetAntichain;
 
+use arcstr::ArcStr;
 use async_nats::connect as nats_connect;
 use async_nats::jetstream;
 use async_nats::Client as NatsClient;
 use async_nats::Subscriber as NatsSubscriber;
 use aws_sdk_dynamodb::Client as DynamoDBClient;
+use aws_sdk_firehose::Client as FirehoseClient;
 use aws_sdk_kinesis::Client as KinesisClient;
+use aws_sdk_sqs::Client as SqsClient;
+use azure_core::{
+    ExponentialRetryOptions as AzureExponentialRetryOptions, RetryOptions as AzureRetryOptions,
+};
+use azure_identity::DefaultAzureCredential;
+use azure_messaging_eventhubs::{
+    ConsumerClient as EventHubsConsumer, ProducerClient as EventHubsProducer,
+};
 use azure_storage::StorageCredentials as AzureStorageCredentials;
+use azure_storage_blobs::prelude::{
+    ClientBuilder as AzureClientBuilder, ContainerClient as AzureContainerClient,
+};
 use csv::ReaderBuilder as CsvReaderBuilder;
 use deltalake::arrow::datatypes::TimeUnit as ArrowTimeUnit;
 use elasticsearch::{
@@ -39,21 +60,23 @@ use elasticsearch::{
 use futures::future;
 use futures::stream::FuturesOrdered;
 use futures::StreamExt;
+use google_cloud_pubsub::client::{Client as PubSubClient, ClientConfig as PubSubClientConfig};
 use iceberg::{Catalog as IcebergCatalog, CatalogBuilder};
 use itertools::Itertools;
 use log::{info, warn};
 use mongodb::sync::Client as MongoClient;
 use mongodb::Namespace as MongoNamespace;
 use mysql::{
-    Opts as MysqlOpts, OptsBuilder as MysqlOptsBuilder, Pool as MysqlConnectionPool,
-    PoolConstraints as MysqlPoolConstraints, PoolOpts as MysqlPoolOpts,
+    Conn as MysqlRawConn, Opts as MysqlOpts, OptsBuilder as MysqlOptsBuilder,
+    Pool as MysqlConnectionPool, PoolConstraints as MysqlPoolConstraints,
+    PoolOpts as MysqlPoolOpts,
 };
 use ndarray;
 use numpy::{PyArray, PyReadonlyArrayDyn};
 use once_cell::sync::Lazy;
 use pyo3::exceptions::{
     PyBaseException, PyException, PyIOError, PyIndexError, PyKeyError, PyNotImplementedError,
-    PyRuntimeError, PyTypeError, PyValueError, PyZeroDivisionError,
+    PyRuntimeError, PyTimeoutError, PyTypeError, PyValueError, PyZeroDivisionError,
 };
 use pyo3::pyclass::CompareOp;
 use pyo3::sync::{GILOnceCell, GILProtected};
@@ -65,6 +88,8 @@ use questdb::ingress::Sender as QuestDBSender;
 use rdkafka::consumer::BaseConsumer;
 use rdkafka::producer::{DefaultProducerContext, ThreadedProducer};
 use rdkafka::ClientConfig;
+use redis::Client as RedisClient;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use rumqttc::{
     mqttbytes::QoS as MqttQoS, Client as MqttClient, Event as MqttEvent, MqttOptions,
     Packet as MqttPacket,
@@ -76,45 +101,56 @@ use schema_registry_converter::blocking::json::JsonDecoder as RegistryJsonDecode
 use schema_registry_converter::blocking::json::JsonEncoder as RegistryJsonEncoder;
 use schema_registry_converter::blocking::schema_registry::SrSettings as SchemaRegistrySettings;
 use scopeguard::defer;
+use scylla::client::session_builder::SessionBuilder;
 use send_wrapper::SendWrapper;
 use serde_json::Value as JsonValue;
 use std::borrow::Borrow;
 use std::cell::RefCell;
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ffi::CString;
-use std::fs::File;
+use std::fs::{File, OpenOptions};
 use std::future::Future;
 use std::io::{BufWriter, Read};
 use std::mem::take;
+use std::net::ToSocketAddrs;
 use std::os::unix::prelude::*;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time;
 use tokio::runtime::Runtime as TokioRuntime;
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
 
 use self::external_index_wrappers::{
     PyBruteForceKnnMetricKind, PyExternalIndexData, PyExternalIndexQuery, PyUSearchMetricKind,
 };
 use self::threads::PythonThreadState;
 
+use crate::connectors::backlog::BacklogLimit;
+use crate::connectors::control::{
+    control_connector as control_connector_in_process, ConnectorCommand, ConnectorControlError,
+};
 use crate::connectors::data_format::bson::BsonFormatter;
 use crate::connectors::data_format::{
-    BsonParser, DebeziumDBType, DebeziumMessageParser, DsvSettings, FieldSource, Formatter,
-    IdentityFormatter, IdentityParser, InnerSchemaField, JsonLinesFormatter, JsonLinesParser,
-    KeyGenerationPolicy, NullFormatter, Parser, RegistryEncoderWrapper, SingleColumnFormatter,
-    TransparentParser, METADATA_FIELD_NAME,
+    BsonParser, DebeziumDBType, DebeziumMessageParser, DsvDialect, DsvQuoting, DsvSettings,
+    FieldSource, Formatter, IdentityFormatter, IdentityParser, InnerSchemaField,
+    JsonDateTimeFormat, JsonLinesFormatter, JsonLinesParser, KeyGenerationPolicy,
+    MetadataColumnsFormatter, MetadataField, NullFormatter, Parser, RegistryEncoderWrapper,
+    SchemaEvolutionPolicy, SingleColumnFormatter, SinkMetadataColumn, TemplateEscaping,
+    TemplateFormatter, TransparentParser, ValueField,
+};
+use crate::connectors::data_storage::aws::{
+    DynamoDBWriter, FirehoseWriter, KinesisReader, KinesisWriter, SqsReader,
 };
-use crate::connectors::data_storage::aws::{DynamoDBWriter, KinesisReader, KinesisWriter};
 use crate::connectors::data_storage::data_lake::arrow::construct_schema as construct_arrow_schema;
 use crate::connectors::data_storage::data_lake::buffering::{
-    AppendOnlyColumnBuffer, ColumnBuffer, SnapshotColumnBuffer,
+    AppendOnlyColumnBuffer, ColumnBuffer, KeyedChangesColumnBuffer, SnapshotColumnBuffer,
 };
-use crate::connectors::data_storage::data_lake::delta::DeltaOptimizerRule;
+use crate::connectors::data_storage::data_lake::delta::{BackfillingThreshold, DeltaOptimizerRule};
 use crate::connectors::data_storage::data_lake::iceberg::{IcebergBatchWriter, IcebergTableParams};
 use crate::connectors::data_storage::data_lake::{
-    DeltaBatchWriter, MaintenanceMode, PathwayStorageFactory,
+    DeltaBatchWriter, LanceBatchWriter, MaintenanceMode, PathwayStorageFactory,
 };
 use crate::connectors::data_storage::elasticsearch::build_elasticsearch_reader;
 use crate::connectors::data_storage::mssql::MssqlWriter;
@@ -122,47 +158,77 @@ use crate::connectors::data_storage::mysql::{MysqlReader, MysqlWriter};
 use crate::connectors::data_storage::nats;
 use crate::connectors::data_storage::pinecone::PineconeWriter;
 use crate::connectors::data_storage::qdrant::QdrantWriteError;
-use crate::connectors::data_storage::scanner::{FilesystemScanner, S3Scanner};
+use crate::connectors::data_storage::scanner::azure::AzureIdentityCredential;
+use crate::connectors::data_storage::scanner::sftp::{SftpAuthentication, SftpConnectionSettings};
+use crate::connectors::data_storage::scanner::{
+    AzureBlobScanner, FilesystemScanner, HdfsScanner, S3Scanner, SftpScanner,
+};
 use crate::connectors::data_storage::sharding::ShardSelector;
+use crate::connectors::data_storage::sql_polling::build_sql_cursor_reader;
+use crate::connectors::data_storage::sqlite::build_sqlite_cursor_reader;
 use crate::connectors::data_storage::{
-    ChromaWriter, ClickHouseWriter, ConnectorMode, DeltaError, DeltaTableReader, DuckDbWriter,
-    ElasticSearchWriter, FileWriter, IcebergReader, KafkaReader, KafkaWriter, LakeWriter,
-    MessageQueueTopic, MongoReader, MongoWriter, MqttReader, MqttWriter, MssqlReader, NatsReader,
-    NatsWriter, NullWriter, ObjectDownloader, PsqlReader, PsqlWriter, PythonConnectorEventType,
+    CassandraWriter, ChromaWriter, ClickHouseWriter, ConnectorMode, DeltaError, DeltaTableReader,
+    DuckDbWriter, ElasticSearchWriter, EventHubsReader, EventHubsWriter, FileWriter,
+    GrpcBookmarkFields, GrpcReader, IcebergReader, KafkaReader, KafkaStartPosition,
+    KafkaStartPositions, KafkaWriter, LakeWriter, MessageQueueTopic, MongoReader, MongoWriter,
+    MqttReader, MqttWriter, MssqlReader, NatsReader, NatsWriter, NullWriter, ObjectDownloader,
+    PsqlReader, PsqlWriter, PubSubReader, PubSubWriter, PythonConnectorEventType,
     PythonReaderBuilder, QdrantWriter, QuestDBAtColumnPolicy, QuestDBWriter, RabbitmqReader,
-    RabbitmqWriter, ReadError, ReadMethod, ReaderBuilder, SqliteReader, SqliteWriter, TableContext,
-    TableWriterInitMode, WeaviateWriter, WriteError, Writer, MQTT_CLIENT_MAX_CHANNEL_SIZE,
+    RabbitmqWriter, ReadError, ReadMethod, ReaderBuilder, RedisKeyValueWriter, RedisStreamReader,
+    RedisValueLayout, SqlCursorReader, SqlPollingConnection, SqlPollingTarget, SqlitePollingTarget,
+    SqliteReader, SqliteWriter, SseReader, StorageType, TableContext, TableWriterInitMode,
+    WeaviateWriter, WebhookBatchFormat, WebhookRetryPolicy, WebhookWriter, WriteError, Writer,
+    MQTT_CLIENT_MAX_CHANNEL_SIZE,
 };
-use crate::connectors::data_tokenize::{BufReaderTokenizer, CsvTokenizer, Tokenize};
+use crate::connectors::data_tokenize::{BufReaderTokenizer, CsvTokenizer, OrcTokenizer, Tokenize};
 use crate::connectors::posix_like::PosixLikeReader;
+use crate::connectors::rate_limit::RateLimit;
+use crate::connectors::sink_alignment::SinkAlignmentGroupDescriptor;
 use crate::connectors::synchronization::ConnectorGroupDescriptor;
-use crate::connectors::{PersistenceMode, SessionType, SnapshotAccess};
+use crate::connectors::watermark::{BoundedOutOfOrderness, WatermarkStrategy};
+use crate::connectors::{OffsetKey, OffsetValue, PersistenceMode, SessionType, SnapshotAccess};
+use crate::engine::clock;
+use crate::engine::dataflow::monitoring::ProberStats;
 use crate::engine::dataflow::Config;
+use crate::engine::dry_run::{ConnectorCheck, ConnectorDirection, DryRunReport, PlannedReader};
 use crate::engine::error::{DataError, DynError, DynResult, Trace as EngineTrace};
+use crate::engine::expression::url::UrlPart;
 use crate::engine::graph::ScopedContext;
-use crate::engine::progress_reporter::MonitoringLevel;
+use crate::engine::lifecycle::RunCallbacks;
+use crate::engine::progress_reporter::{MonitoringLevel, StatsMonitor};
 use crate::engine::reduce::StatefulCombineFn;
 use crate::engine::time::DateTime;
 use crate::engine::Config as EngineTelemetryConfig;
 use crate::engine::Timestamp;
 use crate::engine::{
     run_with_new_dataflow_graph, BatchWrapper, ColumnHandle, ColumnPath,
-    ColumnProperties as EngineColumnProperties, DataRow, DateTimeNaive, DateTimeUtc, Duration,
-    ExpressionData, IxKeyPolicy, JoinData, JoinType, Key, KeyImpl, PointerExpression, Reducer,
-    ReducerData, ScopedGraph, TableHandle, TableProperties as EngineTableProperties, Type,
+    ColumnProperties as EngineColumnProperties, DataRow, DateTimeNaive, DateTimeUtc, DateTimeZoned,
+    Duration, ExpressionData, IxKeyPolicy, JoinData, JoinType, Key, KeyImpl, PointerExpression,
+    Reducer, ReducerData, ScopedGraph, TableHandle, TableProperties as EngineTableProperties, Type,
     UniverseHandle, Value,
 };
 use crate::engine::{AnyExpression, Context as EngineContext};
 use crate::engine::{BoolExpression, Error as EngineError};
 use crate::engine::{ComplexColumn as EngineComplexColumn, WakeupReceiver};
-use crate::engine::{DateTimeNaiveExpression, DateTimeUtcExpression, DurationExpression};
+use crate::engine::{
+    DateTimeNaiveExpression, DateTimeUtcExpression, DateTimeZonedExpression, DurationExpression,
+};
 use crate::engine::{Expression, IntExpression};
 use crate::engine::{FloatExpression, Graph};
-use crate::engine::{LegacyTable as EngineLegacyTable, StringExpression};
+use crate::engine::{
+    JsonPathCache, LegacyTable as EngineLegacyTable, RegexCache, StringExpression,
+};
+use crate::persistence::backends::EncryptionKey;
 use crate::persistence::config::{
     ConnectorWorkerPair, PersistenceManagerOuterConfig, PersistentStorageConfig,
 };
 use crate::persistence::input_snapshot::Event as SnapshotEvent;
+use crate::persistence::lock::RunLease;
+use crate::persistence::retention::RetentionPolicy;
+use crate::persistence::savepoint::{
+    request_savepoint as request_savepoint_in_process, SavepointError,
+};
+use crate::persistence::state::migrate_metadata;
 use crate::persistence::{IntoPersistentId, UniqueName};
 use crate::pipe::{pipe, ReaderType, WriterType};
 use crate::python_api::external_index_wrappers::PyExternalIndexFactory;
@@ -243,6 +309,18 @@ async fn build_rabbitmq_environment(
         .map_err(|e| PyIOError::new_err(format!("Failed to connect to RabbitMQ: {e}")))
 }
 
+/// Connects to Google Cloud Pub/Sub. The credentials are retrieved from the environment,
+/// unless `PUBSUB_EMULATOR_HOST` is set, in which case the emulator is used.
+async fn build_pubsub_client() -> PyResult<PubSubClient> {
+    let config = PubSubClientConfig::default()
+        .with_auth()
+        .await
+        .map_err(|e| PyIOError::new_err(format!("Failed to authenticate in Pub/Sub: {e}")))?;
+    PubSubClient::new(config)
+        .await
+        .map_err(|e| PyIOError::new_err(format!("Failed to connect to Pub/Sub: {e}")))
+}
+
 use crate::connectors::data_storage::rabbitmq::probe_last_offset;
 use crate::external_integration::qdrant_integration::build_qdrant_client;
 
@@ -295,6 +373,19 @@ fn value_from_python_datetime(ob: &Bound<PyAny>) -> PyResult<Value> {
     }
 }
 
+fn value_from_python_zoned_datetime(ob: &Bound<PyAny>) -> PyResult<Value> {
+    let py = ob.py();
+    let Value::DateTimeUtc(date_time) = extract_datetime(ob, &Type::DateTimeUtc)? else {
+        unreachable!("extract_datetime returns DateTimeUtc for Type::DateTimeUtc")
+    };
+    let timezone = get_convert_python_module(py)
+        .call_method1(intern!(py, "_datetime_timezone_name"), (ob,))?
+        .extract::<String>()?;
+    let date_time = DateTimeZoned::from_utc(date_time, &timezone)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(Value::DateTimeZoned(date_time))
+}
+
 fn value_from_python_timedelta(ob: &Bound<PyAny>) -> PyResult<Value> {
     let py = ob.py();
     let duration_ns = get_convert_python_module(py)
@@ -360,6 +451,20 @@ impl<'py> IntoPyObject<'py> for DateTimeUtc {
     }
 }
 
+impl<'py> IntoPyObject<'py> for DateTimeZoned {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        get_convert_python_module(py)
+            .call_method1(
+                intern!(py, "_pd_timestamp_from_zoned_ns"),
+                (self.timestamp(), self.timezone_name()),
+            )?
+            .into_bound_py_any(py)
+    }
+}
+
 impl<'py> IntoPyObject<'py> for Duration {
     type Target = PyAny;
     type Output = Bound<'py, Self::Target>;
@@ -471,6 +576,7 @@ pub fn extract_value(ob: &Bound<PyAny>, type_: &Type) -> PyResult<Value> {
             .ok()
             .map(|b| Value::from(b.as_bytes())),
         Type::DateTimeNaive | Type::DateTimeUtc => Some(extract_datetime(ob, type_)?),
+        Type::DateTimeZoned => Some(value_from_python_zoned_datetime(ob)?),
         Type::Duration => {
             // XXX: check types, not names
             let type_name_bound = ob.get_type().qualname()?;
@@ -521,6 +627,45 @@ pub fn extract_value(ob: &Bound<PyAny>, type_: &Type) -> PyResult<Value> {
                 .try_collect()?;
             Some(Value::from(values.as_slice()))
         }
+        Type::Struct(fields) => {
+            let dict = ob
+                .downcast::<PyDict>()
+                .map_err(|_| py_type_error(ob, type_))?;
+            let values: Vec<_> = fields
+                .iter()
+                .map(|(name, type_)| {
+                    let value = match dict.get_item(name.as_str())? {
+                        Some(ob) => extract_value(&ob, type_)?,
+                        None if type_.is_optional() => Value::None,
+                        None => {
+                            return Err(PyValueError::new_err(format!(
+                                "missing field {name:?} of a struct"
+                            )))
+                        }
+                    };
+                    Ok::<_, PyErr>((name.clone(), value))
+                })
+                .try_collect()?;
+            Some(Value::from(values))
+        }
+        Type::Map(value_type) => {
+            let dict = ob
+                .downcast::<PyDict>()
+                .map_err(|_| py_type_error(ob, type_))?;
+            let map: BTreeMap<_, _> = dict
+                .iter()
+                .map(|(key, value)| {
+                    let key = key
+                        .downcast::<PyString>()
+                        .map_err(|_| PyTypeError::new_err("keys of a map have to be strings"))?;
+                    Ok::<_, PyErr>((
+                        ArcStr::from(key.to_str()?),
+                        extract_value(&value, value_type)?,
+                    ))
+                })
+                .try_collect()?;
+            Some(Value::from(map))
+        }
         Type::PyObjectWrapper => {
             let value = if let Ok(ob) = ob.downcast::<PyObjectWrapper>() {
                 ob.get().as_internal(ob.py())
@@ -655,6 +800,7 @@ impl<'py> IntoPyObject<'py> for &Value {
             Value::FloatArray(a) => Ok(PyArray::from_array(py, a).into_any()),
             Value::DateTimeNaive(dt) => dt.into_bound_py_any(py),
             Value::DateTimeUtc(dt) => dt.into_bound_py_any(py),
+            Value::DateTimeZoned(dt) => dt.into_bound_py_any(py),
             Value::Duration(d) => d.into_bound_py_any(py),
             Value::Json(j) => json_to_py_object(py, j),
             Value::Error => ERROR.clone_ref(py).into_bound_py_any(py),
@@ -662,6 +808,20 @@ impl<'py> IntoPyObject<'py> for &Value {
                 PyObjectWrapper::from_internal(py, op).into_bound_py_any(py)
             }
             Value::Pending => PENDING.clone_ref(py).into_bound_py_any(py),
+            Value::Struct(fields) => {
+                let dict = PyDict::new(py);
+                for (name, value) in fields.iter() {
+                    dict.set_item(name.as_str(), value)?;
+                }
+                Ok(dict.into_any())
+            }
+            Value::Map(map) => {
+                let dict = PyDict::new(py);
+                for (key, value) in map.iter() {
+                    dict.set_item(key.as_str(), value)?;
+                }
+                Ok(dict.into_any())
+            }
         }
     }
 }
@@ -810,6 +970,21 @@ impl<'py> IntoPyObject<'py> for KeyGenerationPolicy {
     }
 }
 
+impl<'py> FromPyObject<'py> for SchemaEvolutionPolicy {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(ob.extract::<PyRef<PySchemaEvolutionPolicy>>()?.0)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for SchemaEvolutionPolicy {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        PySchemaEvolutionPolicy(self).into_bound_py_any(py)
+    }
+}
+
 impl<'py> FromPyObject<'py> for MonitoringLevel {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         Ok(ob.extract::<PyRef<PyMonitoringLevel>>()?.0)
@@ -825,6 +1000,36 @@ impl<'py> IntoPyObject<'py> for MonitoringLevel {
     }
 }
 
+impl<'py> FromPyObject<'py> for DeliverySemantics {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(ob.extract::<PyRef<PyDeliverySemantics>>()?.0)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for DeliverySemantics {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        PyDeliverySemantics(self).into_bound_py_any(py)
+    }
+}
+
+impl<'py> FromPyObject<'py> for OutputMode {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(ob.extract::<PyRef<PyOutputMode>>()?.0)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for OutputMode {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        PyOutputMode(self).into_bound_py_any(py)
+    }
+}
+
 impl<'py> FromPyObject<'py> for TableWriterInitMode {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         Ok(ob.extract::<PyRef<PyTableWriterInitMode>>()?.0)
@@ -867,10 +1072,13 @@ impl From<EngineError> for PyErr {
                     DataError::DuplicateKey(_)
                     | DataError::ValueMissing
                     | DataError::KeyMissingInOutputTable(_)
-                    | DataError::KeyMissingInInputTable(_) => PyKeyError::type_object(py),
+                    | DataError::KeyMissingInInputTable(_)
+                    | DataError::MissingMapKey(_) => PyKeyError::type_object(py),
                     DataError::DivisionByZero => PyZeroDivisionError::type_object(py),
                     DataError::ParseError(_)
                     | DataError::ValueError(_)
+                    | DataError::InvalidRegex(_)
+                    | DataError::InvalidJsonPath(_)
                     | DataError::AppendOnlyViolation(_, _)
                     | DataError::RepeatedEntryInBatch => PyValueError::type_object(py),
                     DataError::IndexOutOfBounds => PyIndexError::type_object(py),
@@ -878,7 +1086,14 @@ impl From<EngineError> for PyErr {
                 },
                 EngineError::IterationLimitTooSmall
                 | EngineError::InconsistentColumnProperties
-                | EngineError::IdInTableProperties => PyValueError::type_object(py),
+                | EngineError::IdInTableProperties
+                | EngineError::ZeroQueueCapacity
+                | EngineError::QueueCapacityWithSynchronizedSinks
+                | EngineError::SinkAlignmentWithManyProcesses
+                | EngineError::StepModePortOutOfRange(..)
+                | EngineError::ShardedGroupByWithOperatorPersistence => {
+                    PyValueError::type_object(py)
+                }
                 EngineError::OtherWorkerPanic => OTHER_WORKER_ERROR.bind(py).clone(),
                 _ => ENGINE_ERROR_TYPE.bind(py).clone(),
             };
@@ -1170,6 +1385,116 @@ impl<'py> IntoPyObject<'py> for ConnectorGroupDescriptor {
     }
 }
 
+#[pyclass(
+    module = "pathway.engine",
+    frozen,
+    name = "SinkAlignmentGroupDescriptor"
+)]
+struct PySinkAlignmentGroupDescriptor(SinkAlignmentGroupDescriptor);
+
+#[pymethods]
+impl PySinkAlignmentGroupDescriptor {
+    #[new]
+    fn new(name: String, max_skew_ms: u64) -> Self {
+        Self(SinkAlignmentGroupDescriptor::new(name, max_skew_ms))
+    }
+}
+
+impl<'py> FromPyObject<'py> for SinkAlignmentGroupDescriptor {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(ob
+            .extract::<PyRef<PySinkAlignmentGroupDescriptor>>()?
+            .0
+            .clone())
+    }
+}
+
+#[pyclass(module = "pathway.engine", frozen, name = "SinkMetadataColumn")]
+struct PySinkMetadataColumn(SinkMetadataColumn);
+
+#[pymethods]
+impl PySinkMetadataColumn {
+    #[new]
+    fn new(name: String, field: &str) -> PyResult<Self> {
+        let field = match field {
+            "time" => MetadataField::Time,
+            "diff" => MetadataField::Diff,
+            "worker" => MetadataField::Worker,
+            "run_id" => MetadataField::RunId,
+            "graph_version" => MetadataField::GraphVersion,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown sink metadata field: {field:?}"
+                )))
+            }
+        };
+        Ok(Self(SinkMetadataColumn::new(name, field)))
+    }
+}
+
+impl<'py> FromPyObject<'py> for SinkMetadataColumn {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(ob.extract::<PyRef<PySinkMetadataColumn>>()?.0.clone())
+    }
+}
+
+#[pyclass(module = "pathway.engine", frozen, name = "SortColumn")]
+struct PySortColumn(SortColumn);
+
+#[pymethods]
+impl PySortColumn {
+    #[new]
+    #[pyo3(signature = (index, descending=false, nulls_first=None))]
+    fn new(index: usize, descending: bool, nulls_first: Option<bool>) -> Self {
+        let column = if descending {
+            SortColumn::descending(index)
+        } else {
+            SortColumn::ascending(index)
+        };
+        Self(match nulls_first {
+            Some(nulls_first) => column.with_nulls_first(nulls_first),
+            None => column,
+        })
+    }
+}
+
+// A plain index sorts by the column in ascending order
+impl<'py> FromPyObject<'py> for SortColumn {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(index) = ob.extract::<usize>() {
+            return Ok(SortColumn::ascending(index));
+        }
+        Ok(ob.extract::<PyRef<PySortColumn>>()?.0)
+    }
+}
+
+#[pyclass(module = "pathway.engine", frozen, name = "FlightEndpoint")]
+struct PyFlightEndpoint(FlightEndpoint);
+
+#[pymethods]
+impl PyFlightEndpoint {
+    #[new]
+    #[pyo3(signature = (address, name, column_names))]
+    fn new(address: &str, name: String, column_names: Vec<String>) -> PyResult<Self> {
+        let address = address
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addresses| addresses.next())
+            .ok_or_else(|| PyValueError::new_err(format!("invalid address {address:?}")))?;
+        Ok(Self(FlightEndpoint {
+            address,
+            name,
+            column_names,
+        }))
+    }
+}
+
+impl<'py> FromPyObject<'py> for FlightEndpoint {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(ob.extract::<PyRef<PyFlightEndpoint>>()?.0.clone())
+    }
+}
+
 #[pyclass(module = "pathway.engine", frozen, name = "ReducerData")]
 struct PyReducerData(ReducerData);
 
@@ -1804,6 +2129,15 @@ impl PyExpression {
             (Op::Sub, Tp::DateTimeUtc, Tp::Duration) => {
                 Some(binary_op!(DateTimeUtcExpression::SubDuration, lhs, rhs))
             }
+            (Op::Sub, Tp::DateTimeZoned, Tp::DateTimeZoned) => {
+                Some(binary_op!(DurationExpression::DateTimeZonedSub, lhs, rhs))
+            }
+            (Op::Add, Tp::DateTimeZoned, Tp::Duration) => {
+                Some(binary_op!(DateTimeZonedExpression::AddDuration, lhs, rhs))
+            }
+            (Op::Sub, Tp::DateTimeZoned, Tp::Duration) => {
+                Some(binary_op!(DateTimeZonedExpression::SubDuration, lhs, rhs))
+            }
             (Op::Eq, Tp::Duration, Tp::Duration) => Some(binary_op!(BoolE::DurationEq, lhs, rhs)),
             (Op::Ne, Tp::Duration, Tp::Duration) => Some(binary_op!(BoolE::DurationNe, lhs, rhs)),
             (Op::Lt, Tp::Duration, Tp::Duration) => Some(binary_op!(BoolE::DurationLt, lhs, rhs)),
@@ -1818,6 +2152,9 @@ impl PyExpression {
             (Op::Add, Tp::Duration, Tp::DateTimeUtc) => {
                 Some(binary_op!(DateTimeUtcExpression::AddDuration, rhs, lhs))
             }
+            (Op::Add, Tp::Duration, Tp::DateTimeZoned) => {
+                Some(binary_op!(DateTimeZonedExpression::AddDuration, rhs, lhs))
+            }
             (Op::Mul, Tp::Duration, Tp::Int) => Some(binary_op!(DurationE::MulByInt, lhs, rhs)),
             (Op::Mul, Tp::Int, Tp::Duration) => Some(binary_op!(DurationE::MulByInt, rhs, lhs)),
             (Op::FloorDiv, Tp::Duration, Tp::Int) => {
@@ -2009,6 +2346,138 @@ impl PyExpression {
         )
     }
 
+    #[staticmethod]
+    fn map_get_item_checked(
+        expr: &PyExpression,
+        key: &PyExpression,
+        default: &PyExpression,
+    ) -> Self {
+        Self::new(
+            Arc::new(Expression::Any(AnyExpression::MapGetItemChecked(
+                expr.inner.clone(),
+                key.inner.clone(),
+                default.inner.clone(),
+            ))),
+            expr.gil || key.gil || default.gil,
+        )
+    }
+
+    #[staticmethod]
+    fn regex_match(expr: &PyExpression, pattern: &PyExpression) -> Self {
+        Self::new(
+            Arc::new(Expression::Bool(BoolExpression::RegexMatch(
+                expr.inner.clone(),
+                pattern.inner.clone(),
+                RegexCache::new(),
+            ))),
+            expr.gil || pattern.gil,
+        )
+    }
+
+    #[staticmethod]
+    fn regex_extract(expr: &PyExpression, pattern: &PyExpression, group: &PyExpression) -> Self {
+        Self::new(
+            Arc::new(Expression::Any(AnyExpression::RegexExtract(
+                expr.inner.clone(),
+                pattern.inner.clone(),
+                group.inner.clone(),
+                RegexCache::new(),
+            ))),
+            expr.gil || pattern.gil || group.gil,
+        )
+    }
+
+    #[staticmethod]
+    fn regex_replace(
+        expr: &PyExpression,
+        pattern: &PyExpression,
+        replacement: &PyExpression,
+    ) -> Self {
+        Self::new(
+            Arc::new(Expression::String(StringExpression::RegexReplace(
+                expr.inner.clone(),
+                pattern.inner.clone(),
+                replacement.inner.clone(),
+                RegexCache::new(),
+            ))),
+            expr.gil || pattern.gil || replacement.gil,
+        )
+    }
+
+    #[staticmethod]
+    fn date_time_naive_add_calendar_units(
+        expr: &PyExpression,
+        amount: &PyExpression,
+        unit: &PyExpression,
+    ) -> Self {
+        Self::new(
+            Arc::new(Expression::DateTimeNaive(
+                DateTimeNaiveExpression::AddCalendarUnits(
+                    expr.inner.clone(),
+                    amount.inner.clone(),
+                    unit.inner.clone(),
+                ),
+            )),
+            expr.gil || amount.gil || unit.gil,
+        )
+    }
+
+    #[staticmethod]
+    fn date_time_utc_add_calendar_units(
+        expr: &PyExpression,
+        amount: &PyExpression,
+        unit: &PyExpression,
+    ) -> Self {
+        Self::new(
+            Arc::new(Expression::DateTimeUtc(
+                DateTimeUtcExpression::AddCalendarUnits(
+                    expr.inner.clone(),
+                    amount.inner.clone(),
+                    unit.inner.clone(),
+                ),
+            )),
+            expr.gil || amount.gil || unit.gil,
+        )
+    }
+
+    #[staticmethod]
+    fn url_part(expr: &PyExpression, part: &str) -> PyResult<Self> {
+        let part: UrlPart = part
+            .parse()
+            .map_err(|e: DataError| PyValueError::new_err(e.to_string()))?;
+        Ok(Self::new(
+            Arc::new(Expression::Any(AnyExpression::UrlPart(
+                expr.inner.clone(),
+                part,
+            ))),
+            expr.gil,
+        ))
+    }
+
+    #[staticmethod]
+    fn json_query(expr: &PyExpression, path: &PyExpression) -> Self {
+        Self::new(
+            Arc::new(Expression::Any(AnyExpression::JsonQuery(
+                expr.inner.clone(),
+                path.inner.clone(),
+                JsonPathCache::new(),
+            ))),
+            expr.gil || path.gil,
+        )
+    }
+
+    #[staticmethod]
+    fn regex_split(expr: &PyExpression, pattern: &PyExpression) -> Self {
+        Self::new(
+            Arc::new(Expression::Any(AnyExpression::RegexSplit(
+                expr.inner.clone(),
+                pattern.inner.clone(),
+                RegexCache::new(),
+            ))),
+            expr.gil || pattern.gil,
+        )
+    }
+
     #[staticmethod]
     fn json_get_item_unchecked(expr: &PyExpression, index: &PyExpression) -> Self {
         Self::new(
@@ -2076,6 +2545,19 @@ binary_expr!(
 binary_expr!(date_time_naive_to_utc, DateTimeUtcExpression::FromNaive);
 binary_expr!(date_time_naive_round, DateTimeNaiveExpression::Round);
 binary_expr!(date_time_naive_floor, DateTimeNaiveExpression::Floor);
+binary_expr!(
+    date_time_naive_truncate_to_unit,
+    DateTimeNaiveExpression::TruncateToUnit
+);
+unary_expr!(date_time_naive_quarter, IntExpression::DateTimeNaiveQuarter);
+unary_expr!(
+    date_time_naive_day_of_year,
+    IntExpression::DateTimeNaiveDayOfYear
+);
+unary_expr!(
+    date_time_naive_iso_week,
+    IntExpression::DateTimeNaiveIsoWeek
+);
 unary_expr!(
     date_time_utc_nanosecond,
     IntExpression::DateTimeUtcNanosecond
@@ -2111,6 +2593,32 @@ binary_expr!(
 binary_expr!(date_time_utc_to_naive, DateTimeNaiveExpression::FromUtc);
 binary_expr!(date_time_utc_round, DateTimeUtcExpression::Round);
 binary_expr!(date_time_utc_floor, DateTimeUtcExpression::Floor);
+binary_expr!(
+    date_time_utc_truncate_to_unit,
+    DateTimeUtcExpression::TruncateToUnit
+);
+unary_expr!(date_time_utc_quarter, IntExpression::DateTimeUtcQuarter);
+unary_expr!(
+    date_time_utc_day_of_year,
+    IntExpression::DateTimeUtcDayOfYear
+);
+unary_expr!(date_time_utc_iso_week, IntExpression::DateTimeUtcIsoWeek);
+binary_expr!(date_time_utc_to_zoned, DateTimeZonedExpression::FromUtc);
+binary_expr!(date_time_naive_to_zoned, DateTimeZonedExpression::FromNaive);
+binary_expr!(
+    date_time_zoned_convert_timezone,
+    DateTimeZonedExpression::ConvertTimezone
+);
+binary_expr!(
+    date_time_zoned_strftime,
+    StringExpression::DateTimeZonedStrftime
+);
+unary_expr!(
+    date_time_zoned_timezone,
+    StringExpression::DateTimeZonedTimezone
+);
+unary_expr!(date_time_zoned_to_utc, DateTimeUtcExpression::FromZoned);
+unary_expr!(date_time_zoned_to_naive, DateTimeNaiveExpression::FromZoned);
 binary_expr!(to_duration, DurationExpression::FromTimeUnit);
 unary_expr!(duration_nanoseconds, IntExpression::DurationNanoseconds);
 unary_expr!(duration_microseconds, IntExpression::DurationMicroseconds);
@@ -2120,6 +2628,27 @@ unary_expr!(duration_minutes, IntExpression::DurationMinutes);
 unary_expr!(duration_hours, IntExpression::DurationHours);
 unary_expr!(duration_days, IntExpression::DurationDays);
 unary_expr!(duration_weeks, IntExpression::DurationWeeks);
+binary_expr!(duration_format, StringExpression::DurationFormat);
+binary_expr!(levenshtein, IntExpression::Levenshtein);
+binary_expr!(jaro_winkler, FloatExpression::JaroWinkler);
+binary_expr!(trigram_similarity, FloatExpression::TrigramSimilarity);
+unary_expr!(base64_encode, StringExpression::Base64Encode);
+unary_expr!(base64_decode, AnyExpression::Base64Decode);
+unary_expr!(hex_encode, StringExpression::HexEncode);
+unary_expr!(hex_decode, AnyExpression::HexDecode);
+unary_expr!(url_encode, StringExpression::UrlEncode);
+unary_expr!(url_decode, StringExpression::UrlDecode);
+binary_expr!(digest, AnyExpression::Digest);
+binary_expr!(hex_digest, StringExpression::HexDigest);
+unary_expr!(
+    parse_ip_address,
+    AnyExpression::ParseIpAddress,
+    optional: bool
+);
+unary_expr!(ip_address_version, AnyExpression::IpAddressVersion);
+binary_expr!(ip_in_network, BoolExpression::IpInNetwork);
+binary_expr!(ip_network, StringExpression::IpNetwork);
+binary_expr!(url_query_param, AnyExpression::UrlQueryParam);
 unary_expr!(unwrap, AnyExpression::Unwrap);
 unary_expr!(to_string, StringExpression::ToString);
 unary_expr!(parse_int, AnyExpression::ParseStringToInt, optional: bool);
@@ -2132,6 +2661,11 @@ unary_expr!(
     optional: bool
 );
 binary_expr!(fill_error, AnyExpression::FillError);
+binary_expr!(map_contains, BoolExpression::MapContains);
+binary_expr!(map_get_item_unchecked, AnyExpression::MapGetItemUnchecked);
+unary_expr!(map_keys, AnyExpression::MapKeys);
+unary_expr!(map_values, AnyExpression::MapValues);
+unary_expr!(map_items, AnyExpression::MapItems);
 
 #[pyclass(module = "pathway.engine", frozen, name = "PathwayType")]
 pub struct PathwayType(Type);
@@ -2155,6 +2689,8 @@ impl PathwayType {
     #[classattr]
     pub const DATE_TIME_UTC: Type = Type::DateTimeUtc;
     #[classattr]
+    pub const DATE_TIME_ZONED: Type = Type::DateTimeZoned;
+    #[classattr]
     pub const DURATION: Type = Type::Duration;
     #[staticmethod]
     #[pyo3(signature = (dim, wrapped))]
@@ -2184,6 +2720,20 @@ impl PathwayType {
     pub fn future(wrapped: Type) -> Type {
         Type::Future(wrapped.into())
     }
+    #[staticmethod]
+    pub fn map(value_type: Type) -> Type {
+        Type::Map(value_type.into())
+    }
+    #[staticmethod]
+    #[pyo3(name = "struct")]
+    pub fn struct_(fields: Vec<(String, Type)>) -> Type {
+        Type::Struct(
+            fields
+                .into_iter()
+                .map(|(name, type_)| (name.into(), type_))
+                .collect(),
+        )
+    }
 }
 
 #[pyclass(module = "pathway.engine", frozen, name = "ReadMethod")]
@@ -2206,6 +2756,8 @@ impl PyFieldSource {
     pub const KEY: FieldSource = FieldSource::Key;
     #[classattr]
     pub const PAYLOAD: FieldSource = FieldSource::Payload;
+    #[classattr]
+    pub const HEADERS: FieldSource = FieldSource::Headers;
 }
 
 impl<'py> FromPyObject<'py> for FieldSource {
@@ -2366,6 +2918,19 @@ impl PyKeyGenerationPolicy {
     pub const PREFER_MESSAGE_KEY: KeyGenerationPolicy = KeyGenerationPolicy::PreferMessageKey;
 }
 
+#[pyclass(module = "pathway.engine", frozen, name = "SchemaEvolutionPolicy")]
+pub struct PySchemaEvolutionPolicy(SchemaEvolutionPolicy);
+
+#[pymethods]
+impl PySchemaEvolutionPolicy {
+    #[classattr]
+    pub const IGNORE_NEW_FIELDS: SchemaEvolutionPolicy = SchemaEvolutionPolicy::IgnoreNewFields;
+    #[classattr]
+    pub const FILL_MISSING: SchemaEvolutionPolicy = SchemaEvolutionPolicy::FillMissing;
+    #[classattr]
+    pub const FAIL: SchemaEvolutionPolicy = SchemaEvolutionPolicy::Fail;
+}
+
 #[pyclass(module = "pathway.engine", frozen, name = "MonitoringLevel")]
 pub struct PyMonitoringLevel(MonitoringLevel);
 
@@ -2394,6 +2959,28 @@ impl PyTableWriterInitMode {
     pub const REPLACE: TableWriterInitMode = TableWriterInitMode::Replace;
 }
 
+#[pyclass(module = "pathway.engine", frozen, name = "DeliverySemantics")]
+pub struct PyDeliverySemantics(DeliverySemantics);
+
+#[pymethods]
+impl PyDeliverySemantics {
+    #[classattr]
+    pub const AT_LEAST_ONCE: DeliverySemantics = DeliverySemantics::AtLeastOnce;
+    #[classattr]
+    pub const EXACTLY_ONCE: DeliverySemantics = DeliverySemantics::ExactlyOnce;
+}
+
+#[pyclass(module = "pathway.engine", frozen, name = "OutputMode")]
+pub struct PyOutputMode(OutputMode);
+
+#[pymethods]
+impl PyOutputMode {
+    #[classattr]
+    pub const STREAMING: OutputMode = OutputMode::Streaming;
+    #[classattr]
+    pub const WATERMARK_ATOMIC: OutputMode = OutputMode::WatermarkAtomic;
+}
+
 #[pyclass(module = "pathway.engine", frozen)]
 pub struct Universe {
     scope: Py<Scope>,
@@ -2698,8 +3285,19 @@ impl<'py> FromPyObject<'py> for ColumnPath {
             .getattr(intern!(py, "path"))
             .and_then(|path| path.extract())
         {
-            Ok(Self::ValuePath(path))
-        } else {
+            let fields: Vec<String> = ob
+                .getattr(intern!(py, "fields"))
+                .and_then(|fields| fields.extract())
+                .unwrap_or_default();
+            if fields.is_empty() {
+                Ok(Self::ValuePath(path))
+            } else {
+                Ok(Self::FieldPath(
+                    path,
+                    fields.into_iter().map(ArcStr::from).collect(),
+                ))
+            }
+        } else {
             Err(PyTypeError::new_err(format!(
                 "can't convert {} to ColumnPath",
                 ob.get_type().name()?
@@ -2934,6 +3532,8 @@ pub struct Scope {
     graph: SendWrapper<ScopedGraph>,
     is_persisted: bool,
     timestamp_at_start: Timestamp,
+    lineage: Arc<RunLineage>,
+    dry_run_report: Option<Arc<DryRunReport>>,
 
     // empty_universe: Lazy<Py<Universe>>,
     universes: GILProtected<RefCell<HashMap<UniverseHandle, Py<Universe>>>>,
@@ -2952,11 +3552,15 @@ impl Scope {
         license: Option<License>,
         is_persisted: bool,
         timestamp_at_start: Timestamp,
+        lineage: Arc<RunLineage>,
+        dry_run_report: Option<Arc<DryRunReport>>,
     ) -> Self {
         Scope {
             parent,
             license,
             is_persisted,
+            lineage,
+            dry_run_report,
             graph: SendWrapper::new(ScopedGraph::new()),
             universes: GILProtected::new(RefCell::new(HashMap::new())),
             columns: GILProtected::new(RefCell::new(HashMap::new())),
@@ -3085,16 +3689,51 @@ impl Scope {
             .borrow()
             .register_unique_name(unique_name.as_ref(), py)?;
         *self_.borrow().total_connectors.get(py).borrow_mut() += 1;
-        let (reader_impl, parallel_readers) = data_source.borrow().construct_reader(
-            py,
-            &data_format.borrow(),
-            &self_.borrow(),
-            &properties.borrow(),
-        )?;
+        // only the Kafka, MySQL and MSSQL readers can be positioned by the user
+        let storage_type = data_source.borrow().storage_type.clone();
+        if !properties.borrow().start_from.is_empty()
+            && !matches!(storage_type.as_str(), "kafka" | "mysql" | "mssql")
+        {
+            return Err(PyValueError::new_err(format!(
+                "The {storage_type:?} connector can't start from explicit offsets"
+            )));
+        }
+        let dry_run_report = self_.borrow().dry_run_report.clone();
+        let reader = if dry_run_report
+            .as_ref()
+            .is_some_and(|report| report.skips_connectors())
+        {
+            data_source.borrow().construct_planned_reader()
+        } else {
+            data_source.borrow().construct_reader(
+                py,
+                &data_format.borrow(),
+                &self_.borrow(),
+                &properties.borrow(),
+            )
+        };
+        let column_properties = properties.borrow().column_properties();
+        let (reader_impl, parallel_readers) = match (reader, dry_run_report) {
+            (Err(error), Some(dry_run_report)) => {
+                dry_run_report.record_connector(ConnectorCheck {
+                    worker_index: self_.borrow().worker_index(),
+                    direction: ConnectorDirection::Input,
+                    name: unique_name.unwrap_or_else(|| data_source.borrow().storage_type.clone()),
+                    error: Some(error.to_string()),
+                });
+                // an empty table in place of the input, so that the rest of the
+                // pipeline can be checked too
+                let table_handle = self_.borrow().graph.static_table(
+                    Vec::new(),
+                    Arc::new(EngineTableProperties::flat(column_properties)),
+                )?;
+                return Table::new(self_, table_handle);
+            }
+            (reader, _) => reader?,
+        };
 
         let parser_impl = data_format.borrow().construct_parser(py)?;
 
-        let column_properties = properties.borrow().column_properties();
         let table_handle = self_.borrow().graph.connector_table(
             reader_impl,
             parser_impl,
@@ -3106,8 +3745,15 @@ impl Scope {
             Arc::new(EngineTableProperties::flat(column_properties)),
             unique_name.as_ref(),
             properties.borrow().synchronization_group.borrow().as_ref(),
-            properties.borrow().max_backlog_size,
+            properties.borrow().backlog_limit(),
+            properties.borrow().rate_limit(),
+            properties
+                .borrow()
+                .watermark_strategy
+                .as_ref()
+                .map(PyWatermarkStrategy::build),
             self_.borrow().timestamp_at_start,
+            properties.borrow().start_from(),
         )?;
         Table::new(self_, table_handle)
     }
@@ -3139,6 +3785,8 @@ impl Scope {
                         None,
                         false,
                         Timestamp::new_from_current_time(),
+                        self_.borrow().lineage.clone(),
+                        None,
                     ),
                 )?;
                 scope.borrow().graph.scoped(graph, || {
@@ -3319,6 +3967,16 @@ impl Scope {
         Table::new(self_, new_table_handle)
     }
 
+    #[pyo3(signature = (
+        table,
+        threshold_column_path,
+        current_time_column_path,
+        instance_column_path,
+        mark_forgetting_records,
+        table_properties,
+        late_table_properties = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn forget(
         self_: &Bound<Self>,
         table: PyRef<Table>,
@@ -3327,16 +3985,23 @@ impl Scope {
         instance_column_path: ColumnPath,
         mark_forgetting_records: bool,
         table_properties: TableProperties,
-    ) -> PyResult<Py<Table>> {
-        let new_table_handle = self_.borrow().graph.forget(
+        late_table_properties: Option<TableProperties>,
+    ) -> PyResult<(Py<Table>, Option<Py<Table>>)> {
+        let (new_table_handle, late_table_handle) = self_.borrow().graph.forget(
             table.handle,
             threshold_column_path,
             current_time_column_path,
             instance_column_path,
             mark_forgetting_records,
             table_properties.0,
+            late_table_properties.map(|properties| properties.0),
         )?;
-        Table::new(self_, new_table_handle)
+        Ok((
+            Table::new(self_, new_table_handle)?,
+            late_table_handle
+                .map(|handle| Table::new(self_, handle))
+                .transpose()?,
+        ))
     }
 
     pub fn forget_immediately(
@@ -3419,6 +4084,14 @@ impl Scope {
         Table::new(self_, new_table_handle)
     }
 
+    #[pyo3(signature = (
+        table,
+        threshold_column_path,
+        current_time_column_path,
+        instance_column_path,
+        table_properties,
+        late_table_properties = None,
+    ))]
     pub fn buffer(
         self_: &Bound<Self>,
         table: PyRef<Table>,
@@ -3426,13 +4099,41 @@ impl Scope {
         current_time_column_path: ColumnPath,
         instance_column_path: ColumnPath,
         table_properties: TableProperties,
-    ) -> PyResult<Py<Table>> {
-        let new_table_handle = self_.borrow().graph.buffer(
+        late_table_properties: Option<TableProperties>,
+    ) -> PyResult<(Py<Table>, Option<Py<Table>>)> {
+        let (new_table_handle, late_table_handle) = self_.borrow().graph.buffer(
             table.handle,
             threshold_column_path,
             current_time_column_path,
             instance_column_path,
             table_properties.0,
+            late_table_properties.map(|properties| properties.0),
+        )?;
+        Ok((
+            Table::new(self_, new_table_handle)?,
+            late_table_handle
+                .map(|handle| Table::new(self_, handle))
+                .transpose()?,
+        ))
+    }
+
+    pub fn retain_last_versions(
+        self_: &Bound<Self>,
+        table: PyRef<Table>,
+        current_time_column_path: ColumnPath,
+        instance_column_path: ColumnPath,
+        max_versions: usize,
+        table_properties: TableProperties,
+    ) -> PyResult<Py<Table>> {
+        if max_versions == 0 {
+            return Err(PyValueError::new_err("max_versions must be positive"));
+        }
+        let new_table_handle = self_.borrow().graph.retain_last_versions(
+            table.handle,
+            current_time_column_path,
+            instance_column_path,
+            max_versions,
+            table_properties.0,
         )?;
         Table::new(self_, new_table_handle)
     }
@@ -3611,7 +4312,8 @@ impl Scope {
         LegacyTable::new(universe.clone(), columns)
     }
 
-    #[pyo3(signature = (table, grouping_columns_paths, last_column_is_instance, reducers, set_id, table_properties))]
+    #[pyo3(signature = (table, grouping_columns_paths, last_column_is_instance, reducers, set_id, table_properties, *, shard_column = None))]
+    #[allow(clippy::too_many_arguments)]
     pub fn group_by_table(
         self_: &Bound<Self>,
         table: PyRef<Table>,
@@ -3620,11 +4322,13 @@ impl Scope {
         #[pyo3(from_py_with = from_py_iterable)] reducers: Vec<ReducerData>,
         set_id: bool,
         table_properties: TableProperties,
+        shard_column: Option<usize>,
     ) -> PyResult<Py<Table>> {
         let table_handle = self_.borrow().graph.group_by_table(
             table.handle,
             grouping_columns_paths,
             ShardPolicy::from_last_column_is_instance(last_column_is_instance),
+            shard_column,
             reducers,
             set_id,
             table_properties.0,
@@ -3653,6 +4357,23 @@ impl Scope {
         Table::new(self_, table_handle)
     }
 
+    #[pyo3(signature = (table, column_paths, unique_name, table_properties))]
+    pub fn version_rows(
+        self_: &Bound<Self>,
+        table: PyRef<Table>,
+        #[pyo3(from_py_with = from_py_iterable)] column_paths: Vec<ColumnPath>,
+        unique_name: Option<UniqueName>,
+        table_properties: TableProperties,
+    ) -> PyResult<Py<Table>> {
+        let table_handle = self_.borrow().graph.version_rows(
+            table.handle,
+            column_paths,
+            unique_name.as_ref(),
+            table_properties.0,
+        )?;
+        Table::new(self_, table_handle)
+    }
+
     pub fn ix_table(
         self_: &Bound<Self>,
         to_ix_table: PyRef<Table>,
@@ -3675,7 +4396,8 @@ impl Scope {
 
     #[pyo3(signature = (left_table, right_table, left_column_paths, right_column_paths, *,
         last_column_is_instance, table_properties, assign_id = false, left_ear = false,
-        right_ear = false, left_exactly_once = false, right_exactly_once = false))]
+        right_ear = false, left_exactly_once = false, right_exactly_once = false,
+        shard_column = None))]
     #[allow(clippy::too_many_arguments)]
     #[allow(clippy::fn_params_excessive_bools)]
     pub fn join_tables(
@@ -3691,13 +4413,18 @@ impl Scope {
         right_ear: bool,
         left_exactly_once: bool,
         right_exactly_once: bool,
+        shard_column: Option<usize>,
     ) -> PyResult<Py<Table>> {
         let join_type = JoinType::from_assign_left_right(assign_id, left_ear, right_ear)?;
         let join_exactly_once = JoinExactlyOnce::new(left_exactly_once, right_exactly_once);
+        let shard_policy = match shard_column {
+            Some(column) => ShardPolicy::KeyColumn(column),
+            None => ShardPolicy::from_last_column_is_instance(last_column_is_instance),
+        };
         let table_handle = self_.borrow().graph.join_tables(
             JoinData::new(left_table.handle, left_column_paths),
             JoinData::new(right_table.handle, right_column_paths),
-            ShardPolicy::from_last_column_is_instance(last_column_is_instance),
+            shard_policy,
             join_type,
             join_exactly_once,
             table_properties.0,
@@ -3774,7 +4501,8 @@ impl Scope {
         Table::new(self_, result_table_handle)
     }
 
-    #[pyo3(signature = (table, column_paths, data_sink, data_format, unique_name=None, sort_by_indices=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (table, column_paths, data_sink, data_format, unique_name=None, sort_by_indices=None, alignment_group=None, metadata_columns=Vec::new(), parallel_writers=1, partition_by_indices=None, max_batch_size=None, linger_ms=None, delivery_semantics=None, output_mode=None, commit_group=None, queue_capacity=None, collapse_updates=false))]
     pub fn output_table(
         self_: &Bound<Self>,
         table: PyRef<Table>,
@@ -3782,69 +4510,178 @@ impl Scope {
         data_sink: &Bound<DataStorage>,
         data_format: &Bound<DataFormat>,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
+        alignment_group: Option<SinkAlignmentGroupDescriptor>,
+        metadata_columns: Vec<SinkMetadataColumn>,
+        parallel_writers: usize,
+        partition_by_indices: Option<Vec<usize>>,
+        max_batch_size: Option<usize>,
+        linger_ms: Option<u64>,
+        delivery_semantics: Option<DeliverySemantics>,
+        output_mode: Option<OutputMode>,
+        commit_group: Option<String>,
+        queue_capacity: Option<usize>,
+        collapse_updates: bool,
     ) -> PyResult<()> {
         let py = self_.py();
+        if parallel_writers == 0 {
+            return Err(PyValueError::new_err(
+                "parallel_writers must be a positive number",
+            ));
+        }
+        let output_mode = output_mode.unwrap_or_default();
+        if output_mode == OutputMode::WatermarkAtomic && parallel_writers > 1 {
+            // the writers would commit their parts of a minibatch separately
+            return Err(PyValueError::new_err(
+                "the watermark-atomic output mode requires a single writer",
+            ));
+        }
+        if max_batch_size == Some(0) {
+            return Err(PyValueError::new_err(
+                "max_batch_size must be a positive number",
+            ));
+        }
+        let batching =
+            OutputBatching::new(max_batch_size, linger_ms.map(time::Duration::from_millis));
 
         self_
             .borrow()
             .register_unique_name(unique_name.as_ref(), py)?;
         let worker_index = self_.borrow().worker_index();
+        let extended_data_format;
+        let data_format = if metadata_columns.is_empty() {
+            data_format.borrow()
+        } else {
+            extended_data_format = Bound::new(
+                py,
+                data_format
+                    .borrow()
+                    .with_metadata_columns(py, &metadata_columns)?,
+            )?;
+            extended_data_format.borrow()
+        };
         // Whether the output requests a global within-minibatch order. A writer
         // that needs a single worker to honor that order (e.g. MongoDB) reads
         // this when deciding `single_threaded()`; writers that don't care ignore it.
         let sorted_output = sort_by_indices.is_some();
-        let sink_impl = data_sink.borrow().construct_writer(
-            py,
-            &data_format.borrow(),
-            self_.borrow().license.as_ref(),
-            worker_index,
-            sorted_output,
-        )?;
-        let format_impl = data_format.borrow().construct_formatter(py)?;
+        let dry_run_report = self_.borrow().dry_run_report.clone();
+        if dry_run_report
+            .as_ref()
+            .is_some_and(|report| report.skips_connectors())
+        {
+            // only the plan is built, in which the writers take no part
+            return Ok(());
+        }
+        let is_dry_run = dry_run_report.is_some();
+        let construct_writer = |writer_index| {
+            data_sink.borrow().construct_writer(
+                py,
+                &data_format,
+                self_.borrow().license.as_ref(),
+                worker_index,
+                writer_index,
+                sorted_output,
+                is_dry_run,
+            )
+        };
+        let sink_impl = construct_writer(0);
+        let sink_impl = match (sink_impl, dry_run_report) {
+            (Err(error), Some(dry_run_report)) => {
+                dry_run_report.record_connector(ConnectorCheck {
+                    worker_index,
+                    direction: ConnectorDirection::Output,
+                    name: unique_name.unwrap_or_else(|| data_sink.borrow().storage_type.clone()),
+                    error: Some(error.to_string()),
+                });
+                return Ok(());
+            }
+            (sink_impl, _) => sink_impl?,
+        };
+        let construct_formatter = || -> PyResult<Box<dyn Formatter>> {
+            let mut format_impl = data_format.construct_formatter(py)?;
+            if !metadata_columns.is_empty() {
+                format_impl = Box::new(MetadataColumnsFormatter::new(
+                    format_impl,
+                    &metadata_columns,
+                    worker_index,
+                    &self_.borrow().lineage,
+                ));
+            }
+            Ok(format_impl)
+        };
+        let mut writers = vec![(sink_impl, construct_formatter()?)];
+        // The dry run only checks that a writer can be created
+        if !is_dry_run {
+            for writer_index in 1..parallel_writers {
+                writers.push((construct_writer(writer_index)?, construct_formatter()?));
+            }
+        }
 
         self_.borrow().graph.output_table(
-            sink_impl,
-            format_impl,
+            OutputWriters::partitioned(writers, partition_by_indices)
+                .with_batching(batching)
+                .with_delivery_semantics(delivery_semantics.unwrap_or_default())
+                .with_output_mode(output_mode)
+                .with_commit_group(commit_group)
+                .with_queue_capacity(queue_capacity)
+                .with_collapsed_updates(collapse_updates),
             table.handle,
             column_paths,
             unique_name,
             sort_by_indices,
+            alignment_group.as_ref(),
         )?;
 
         Ok(())
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (table, column_paths, skip_persisted_batch, skip_errors, on_change, on_time_end, on_end, unique_name=None, sort_by_indices=None))]
+    #[pyo3(signature = (table, column_paths, skip_persisted_batch, skip_errors, on_change, on_time_end, on_end, unique_name=None, sort_by_indices=None, on_batch=None, max_batch_size=None))]
     pub fn subscribe_table(
         self_: &Bound<Self>,
         table: PyRef<Table>,
         #[pyo3(from_py_with = from_py_iterable)] column_paths: Vec<ColumnPath>,
         skip_persisted_batch: bool,
         skip_errors: bool,
-        on_change: Py<PyAny>,
+        on_change: Option<Py<PyAny>>,
         on_time_end: Py<PyAny>,
         on_end: Py<PyAny>,
         unique_name: Option<UniqueName>,
-        sort_by_indices: Option<Vec<usize>>,
+        sort_by_indices: Option<Vec<SortColumn>>,
+        on_batch: Option<Py<PyAny>>,
+        max_batch_size: Option<usize>,
     ) -> PyResult<()> {
         let py = self_.py();
         self_
             .borrow()
             .register_unique_name(unique_name.as_ref(), py)?;
 
-        let event_loop = if is_async_function(py, on_change.clone_ref(py))? {
-            if sort_by_indices.is_some() {
+        let callbacks = match (on_change, on_batch) {
+            (Some(on_change), None) => {
+                let event_loop = if is_async_function(py, on_change.clone_ref(py))? {
+                    if sort_by_indices.is_some() {
+                        return Err(PyValueError::new_err(
+                            "Using sort_by with async observer is not supported",
+                        ));
+                    }
+                    Some(self_.borrow().event_loop(py))
+                } else {
+                    None
+                };
+                build_subscribe_callback(on_change, on_time_end, on_end, event_loop)
+            }
+            (None, Some(on_batch)) => {
+                if max_batch_size == Some(0) {
+                    return Err(PyValueError::new_err("max_batch_size has to be positive"));
+                }
+                build_subscribe_batch_callback(on_batch, max_batch_size, on_time_end, on_end)
+            }
+            _ => {
                 return Err(PyValueError::new_err(
-                    "Using sort_by with async observer is not supported",
-                ));
+                    "exactly one of on_change and on_batch has to be set",
+                ))
             }
-            Some(self_.borrow().event_loop(py))
-        } else {
-            None
         };
-        let callbacks = build_subscribe_callback(on_change, on_time_end, on_end, event_loop);
 
         self_.borrow().graph.subscribe_table(
             table.handle,
@@ -3861,10 +4698,46 @@ impl Scope {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (table, column_paths, column_names, skip_persisted_batch, skip_errors, unique_name, sort_by_indices=None))]
+    pub fn subscribe_table_websocket(
+        self_: &Bound<Self>,
+        table: PyRef<Table>,
+        #[pyo3(from_py_with = from_py_iterable)] column_paths: Vec<ColumnPath>,
+        column_names: Vec<String>,
+        skip_persisted_batch: bool,
+        skip_errors: bool,
+        unique_name: UniqueName,
+        sort_by_indices: Option<Vec<SortColumn>>,
+    ) -> PyResult<()> {
+        let py = self_.py();
+        self_
+            .borrow()
+            .register_unique_name(Some(&unique_name), py)?;
+        let worker_count = self_.borrow().thread_count();
+        let callbacks = websocket_callbacks(&unique_name, column_names, worker_count);
+
+        self_.borrow().graph.subscribe_table(
+            table.handle,
+            column_paths,
+            callbacks,
+            SubscribeConfig {
+                skip_persisted_batch,
+                skip_errors,
+                skip_pending: true,
+            },
+            Some(unique_name),
+            sort_by_indices,
+        )?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (operator_id, depends_on_error_log, stable_id = None))]
     pub fn set_operator_properties(
         self_: &Bound<Self>,
         operator_id: usize,
         depends_on_error_log: bool,
+        stable_id: Option<String>,
     ) -> PyResult<()> {
         Ok(self_
             .borrow()
@@ -3872,6 +4745,7 @@ impl Scope {
             .set_operator_properties(OperatorProperties {
                 id: operator_id,
                 depends_on_error_log,
+                stable_id,
             })?)
     }
 
@@ -3913,15 +4787,48 @@ impl Scope {
         Ok(())
     }
 
+    #[pyo3(signature = (table, column_paths, flight_endpoint=None))]
     pub fn export_table(
         self_: &Bound<Self>,
         table: PyRef<Table>,
         column_paths: Vec<ColumnPath>,
+        flight_endpoint: Option<FlightEndpoint>,
     ) -> PyResult<PyExportedTable> {
-        let exported_table = self_
-            .borrow()
-            .graph
-            .export_table(table.handle, column_paths)?;
+        let exported_table =
+            self_
+                .borrow()
+                .graph
+                .export_table(table.handle, column_paths, flight_endpoint)?;
+        Ok(PyExportedTable::new(exported_table))
+    }
+
+    pub fn serve_table(
+        self_: &Bound<Self>,
+        table: &PyExportedTable,
+        route: String,
+        column_names: Vec<String>,
+    ) -> PyResult<()> {
+        let worker_index = self_.borrow().worker_index();
+        register_served_table(route, column_names, worker_index, &table.inner)?;
+        Ok(())
+    }
+
+    pub fn export_to_shared_memory(
+        self_: &Bound<Self>,
+        table: &PyExportedTable,
+        directory: PathBuf,
+        capacity: usize,
+        column_names: Vec<String>,
+    ) -> PyResult<PyExportedTable> {
+        let self_ref = self_.borrow();
+        let exported_table = SharedMemoryExportedTable::new(
+            table.inner.clone(),
+            &directory,
+            capacity,
+            &column_names,
+            self_ref.worker_index(),
+            self_ref.worker_count(),
+        )?;
         Ok(PyExportedTable::new(exported_table))
     }
 
@@ -4044,6 +4951,38 @@ impl Scope {
         Table::new(self_, table_handle)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn assert_monotonic(
+        self_: &Bound<Self>,
+        table_handle: PyRef<Table>,
+        #[pyo3(from_py_with = from_py_iterable)] column_paths: Vec<ColumnPath>,
+        sequence_path: ColumnPath,
+        instance_path: Option<ColumnPath>,
+        strict: bool,
+        on_violation: &str,
+        table_properties: TableProperties,
+    ) -> PyResult<Py<Table>> {
+        let on_violation = match on_violation {
+            "fail" => OrderingViolationPolicy::Fail,
+            "log" => OrderingViolationPolicy::Log,
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown on_violation value {other:?}, expected \"fail\" or \"log\""
+                )))
+            }
+        };
+        let table_handle = self_.borrow().graph.assert_monotonic(
+            table_handle.handle,
+            column_paths,
+            sequence_path,
+            instance_path,
+            strict,
+            on_violation,
+            table_properties.0,
+        )?;
+        Table::new(self_, table_handle)
+    }
+
     pub fn unpack_snapshots(
         self_: &Bound<Self>,
         table: PyRef<Table>,
@@ -4088,6 +5027,38 @@ fn build_subscribe_callback(
             }))
     };
 
+    with_end_callbacks(builder, on_time_end, on_end)
+}
+
+fn build_subscribe_batch_callback(
+    on_batch: Py<PyAny>,
+    max_batch_size: Option<usize>,
+    on_time_end: Py<PyAny>,
+    on_end: Py<PyAny>,
+) -> SubscribeCallbacks {
+    let builder = SubscribeCallbacksBuilder::new()
+        .wrapper(BatchWrapper::WithGil)
+        .on_batch(
+            Box::new(move |changes, time| {
+                Python::with_gil(|py| {
+                    let changes: Vec<_> = changes
+                        .iter()
+                        .map(|(key, values, diff)| Ok((*key, PyTuple::new(py, *values)?, *diff)))
+                        .collect::<PyResult<_>>()?;
+                    on_batch.call1(py, (changes, time))?;
+                    Ok(())
+                })
+            }),
+            max_batch_size,
+        );
+    with_end_callbacks(builder, on_time_end, on_end)
+}
+
+fn with_end_callbacks(
+    builder: SubscribeCallbacksBuilder,
+    on_time_end: Py<PyAny>,
+    on_end: Py<PyAny>,
+) -> SubscribeCallbacks {
     builder
         .on_time_end(Box::new(move |new_time| {
             Python::with_gil(|py| {
@@ -4149,6 +5120,65 @@ fn capture_table_data(
     Ok(table_data)
 }
 
+#[pymethods]
+impl DataRow {
+    #[new]
+    #[pyo3(signature = (
+        key,
+        values,
+        *,
+        time = Timestamp(0),
+        diff = 1,
+        shard = None,
+        dtypes,
+    ))]
+    #[allow(clippy::needless_pass_by_value)] // can't use &[Type] with pyo3
+    pub fn new(
+        key: Key,
+        values: Vec<Bound<PyAny>>,
+        time: Timestamp,
+        diff: isize,
+        shard: Option<usize>,
+        dtypes: Vec<Type>,
+    ) -> PyResult<Self> {
+        if values.len() != dtypes.len() {
+            let message = format!(
+                "Length of values ({}) should be equal to the length of dtypes ({}).",
+                values.len(),
+                dtypes.len()
+            );
+            return Err(PyValueError::new_err(message));
+        }
+        let extracted_values: Vec<_> = values
+            .into_iter()
+            .zip(dtypes)
+            .map(|(ob, dtype)| extract_value(&ob, &dtype))
+            .try_collect()?;
+        Ok(Self {
+            key,
+            values: extracted_values,
+            time,
+            diff,
+            shard,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
+        // TODO: replace with __eq__ when pyo3 is updated to 0.20
+        match op {
+            CompareOp::Eq => Ok(self == other),
+            CompareOp::Ne => Ok(self != other),
+            _ => Err(PyTypeError::new_err(format!(
+                "{op:?} not supported between instances of DataRow and DataRow"
+            ))),
+        }
+    }
+}
+
 pub fn make_captured_table(table_data: Vec<CapturedTableData>) -> Vec<DataRow> {
     let mut combined_table_data = Vec::new();
     for single_table_data in table_data {
@@ -4157,6 +5187,18 @@ pub fn make_captured_table(table_data: Vec<CapturedTableData>) -> Vec<DataRow> {
     combined_table_data
 }
 
+struct PyStatsMonitor(PyObject);
+
+impl StatsMonitor for PyStatsMonitor {
+    fn update_monitoring(&self, stats: &ProberStats, now: u64) -> DynResult<()> {
+        Python::with_gil(|py| {
+            self.0
+                .call_method1(py, "update_monitoring", (stats.clone(), now))?;
+            Ok(())
+        })
+    }
+}
+
 #[pyfunction]
 #[allow(clippy::too_many_arguments)]
 #[pyo3(signature = (
@@ -4172,7 +5214,10 @@ pub fn make_captured_table(table_data: Vec<CapturedTableData>) -> Vec<DataRow> {
     telemetry_config = TelemetryConfig::default(),
     terminate_on_error = true,
     max_expression_batch_size = 1024,
+    deterministic_expression_cache_size = 0,
     udf_cache_directory = None,
+    step_mode = None,
+    dry_run_report = None,
 ))]
 pub fn run_with_new_graph(
     py: Python,
@@ -4187,8 +5232,21 @@ pub fn run_with_new_graph(
     telemetry_config: TelemetryConfig,
     terminate_on_error: bool,
     max_expression_batch_size: usize,
+    deterministic_expression_cache_size: usize,
     udf_cache_directory: Option<PathBuf>,
+    step_mode: Option<&str>,
+    dry_run_report: Option<Py<PyDryRunReport>>,
 ) -> PyResult<Vec<Vec<DataRow>>> {
+    let step_mode = match step_mode {
+        None => None,
+        Some("stdin") => Some(StepMode::Stdin),
+        Some("http") => Some(StepMode::Http),
+        Some(other) => {
+            return Err(PyValueError::new_err(format!(
+                "unknown step mode {other:?}, the supported ones are \"stdin\" and \"http\""
+            )))
+        }
+    };
     LOGGING_RESET_HANDLE.reset();
     defer! {
         log::logger().flush();
@@ -4207,11 +5265,41 @@ pub fn run_with_new_graph(
         .map_or_else(Timestamp::new_from_current_time, |ms| {
             Timestamp((ms / 2) * 2)
         });
+    let run_id = if telemetry_config.run_id.is_empty() {
+        uuid::Uuid::new_v4().to_string()
+    } else {
+        telemetry_config.run_id
+    };
+    let lineage = Arc::new(RunLineage::new(
+        run_id.clone(),
+        telemetry_config.graph_version.clone(),
+    ));
+    let scope_lineage = lineage.clone();
+    let dry_run_report = dry_run_report.map(|report| report.get().inner.clone());
+    let scope_dry_run_report = dry_run_report.clone();
     let persistence_config = {
         if let Some(persistence_config) = persistence_config {
-            let persistence_config = persistence_config
+            let lock_ttl = persistence_config.lock_ttl;
+            let mut persistence_config = persistence_config
                 .prepare()?
-                .with_run_start_timestamp(timestamp_at_start);
+                .with_run_start_timestamp(timestamp_at_start)
+                .with_run_lineage(lineage.clone());
+            if let Some(lock_ttl) = lock_ttl {
+                // the processes of the run find out that it holds the lease by the id
+                // given by `pathway spawn`; the run id of a persisted pipeline is the
+                // same across restarts, so it can't tell concurrent runs apart
+                let lease_owner = match ::std::env::var(PATHWAY_RUN_ID_ENV_VAR) {
+                    Ok(lease_owner) => lease_owner,
+                    Err(_) if config.processes() > 1 => {
+                        return Err(PyValueError::new_err(
+                            "The lease on the persistent storage in a run with many processes requires `pathway spawn`",
+                        ));
+                    }
+                    Err(_) => uuid::Uuid::new_v4().to_string(),
+                };
+                let run_lease = RunLease::new(lease_owner, lock_ttl, config.process_id() == 0);
+                persistence_config = persistence_config.with_run_lease(Arc::new(run_lease));
+            }
             persistence_config.validate(&license)?;
             Some(persistence_config)
         } else {
@@ -4222,7 +5310,8 @@ pub fn run_with_new_graph(
 
     let telemetry_config = EngineTelemetryConfig::create(
         &license,
-        Some(telemetry_config.run_id),
+        Some(run_id),
+        telemetry_config.graph_version,
         telemetry_config.monitoring_server,
         telemetry_config.detailed_metrics_dir,
         telemetry_config.trace_parent,
@@ -4246,6 +5335,8 @@ pub fn run_with_new_graph(
                                 Some(scope_license.clone()),
                                 is_persisted,
                                 timestamp_at_start,
+                                scope_lineage.clone(),
+                                scope_dry_run_report.clone(),
                             ),
                         )?;
                         let tables: Vec<(PyRef<Table>, Vec<ColumnPath>)> =
@@ -4263,16 +5354,22 @@ pub fn run_with_new_graph(
                 |(_thread_state, captured_tables)| captured_tables,
                 config,
                 wakeup_receiver,
-                stats_monitor,
+                stats_monitor
+                    .map(|monitor| Box::new(PyStatsMonitor(monitor)) as Box<dyn StatsMonitor>),
                 ignore_asserts,
                 monitoring_level,
                 with_http_server,
                 persistence_config,
                 &license,
                 telemetry_config,
+                lineage,
                 terminate_on_error,
                 max_expression_batch_size,
+                deterministic_expression_cache_size,
                 udf_cache_directory,
+                step_mode,
+                dry_run_report,
+                RunCallbacks::default(),
             )
         })
     })??;
@@ -4340,26 +5437,65 @@ pub fn deserialize(bytes: &[u8]) -> PyResult<Value> {
 #[pyclass(module = "pathway.engine", frozen)]
 pub struct AzureBlobStorageSettings {
     account: String,
-    password: String,
+    password: Option<String>,
     container: String,
+    sas_token: Option<String>,
 }
 
 #[pymethods]
 impl AzureBlobStorageSettings {
     #[new]
-    #[pyo3(signature = (account, password, container))]
-    fn new(account: String, password: String, container: String) -> Self {
-        Self {
+    #[pyo3(signature = (account, password, container, sas_token = None))]
+    fn new(
+        account: String,
+        password: Option<String>,
+        container: String,
+        sas_token: Option<String>,
+    ) -> PyResult<Self> {
+        if password.is_some() && sas_token.is_some() {
+            return Err(PyValueError::new_err(
+                "password and sas_token can't be specified together",
+            ));
+        }
+        Ok(Self {
             account,
             password,
             container,
-        }
+            sas_token,
+        })
     }
 }
 
 impl AzureBlobStorageSettings {
-    fn credentials(&self) -> AzureStorageCredentials {
-        AzureStorageCredentials::access_key(self.account.clone(), self.password.clone())
+    // Without the account key or a SAS token, the identity of the environment
+    // is used: a service principal, a managed identity, or the Azure CLI login.
+    fn credentials(&self) -> PyResult<AzureStorageCredentials> {
+        if let Some(password) = &self.password {
+            Ok(AzureStorageCredentials::access_key(
+                self.account.clone(),
+                password.clone(),
+            ))
+        } else if let Some(sas_token) = &self.sas_token {
+            AzureStorageCredentials::sas_token(sas_token.as_str())
+                .map_err(|e| PyValueError::new_err(format!("Invalid SAS token: {e}")))
+        } else {
+            let credential = AzureIdentityCredential::new().map_err(|e| {
+                PyRuntimeError::new_err(format!("Failed to obtain the Azure credential: {e}"))
+            })?;
+            Ok(AzureStorageCredentials::token_credential(Arc::new(
+                credential,
+            )))
+        }
+    }
+
+    fn container_client(&self) -> PyResult<AzureContainerClient> {
+        Ok(
+            AzureClientBuilder::new(self.account.clone(), self.credentials()?)
+                .retry(AzureRetryOptions::exponential(
+                    AzureExponentialRetryOptions::default(),
+                ))
+                .container_client(self.container.clone()),
+        )
     }
 }
 
@@ -4947,19 +6083,473 @@ impl MqttSettings {
 }
 
 #[derive(Clone, Debug)]
-#[pyclass(module = "pathway.engine", frozen)]
-pub struct PsqlReplicationSettings {
-    connection_string: String,
-    publication_name: String,
-    replication_slot_name: Option<String>,
-    snapshot_name: Option<String>,
+#[pyclass(module = "pathway.engine", frozen, name = "SqsSettings")]
+pub struct SqsSettings {
+    wait_time_s: i32,
+    visibility_timeout_s: i32,
+    shard_by_message_group: bool,
 }
 
 #[pymethods]
-impl PsqlReplicationSettings {
+impl SqsSettings {
     #[new]
     #[pyo3(signature = (
-        connection_string,
+        wait_time_s,
+        visibility_timeout_s,
+        shard_by_message_group,
+    ))]
+    pub fn new(
+        wait_time_s: i32,
+        visibility_timeout_s: i32,
+        shard_by_message_group: bool,
+    ) -> PyResult<Self> {
+        if !(0..=20).contains(&wait_time_s) {
+            return Err(PyValueError::new_err(format!(
+                "SQS wait time must be between 0 and 20 seconds. Specified value: {wait_time_s}"
+            )));
+        }
+        // The visibility of the uncommitted messages is extended when half of the
+        // timeout has passed, which must not fall within a single long poll.
+        if visibility_timeout_s <= 2 * wait_time_s || visibility_timeout_s > 43200 {
+            return Err(PyValueError::new_err(format!(
+                "SQS visibility timeout must be greater than twice the wait time and at most 43200 seconds. Specified value: {visibility_timeout_s}"
+            )));
+        }
+        Ok(Self {
+            wait_time_s,
+            visibility_timeout_s,
+            shard_by_message_group,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "RedisStreamSettings")]
+pub struct RedisStreamSettings {
+    consumer_group: String,
+    consumer_name: String,
+    data_field: String,
+}
+
+#[pymethods]
+impl RedisStreamSettings {
+    #[new]
+    #[pyo3(signature = (
+        consumer_group,
+        consumer_name,
+        data_field,
+    ))]
+    pub fn new(consumer_group: String, consumer_name: String, data_field: String) -> Self {
+        Self {
+            consumer_group,
+            consumer_name,
+            data_field,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "RedisKeyValueSettings")]
+pub struct RedisKeyValueSettings {
+    key_prefix: String,
+    use_hashes: bool,
+    ttl_ms: Option<u64>,
+}
+
+#[pymethods]
+impl RedisKeyValueSettings {
+    #[new]
+    #[pyo3(signature = (
+        key_prefix,
+        use_hashes,
+        ttl_ms = None,
+    ))]
+    pub fn new(key_prefix: String, use_hashes: bool, ttl_ms: Option<u64>) -> PyResult<Self> {
+        if ttl_ms == Some(0) {
+            return Err(PyValueError::new_err("Redis key TTL must be positive"));
+        }
+        Ok(Self {
+            key_prefix,
+            use_hashes,
+            ttl_ms,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "SqlCursorSettings")]
+pub struct SqlCursorSettings {
+    cursor_column: String,
+    id_column: String,
+    query: Option<String>,
+    max_cursor_lag: i64,
+    read_batch_size: usize,
+    poll_interval_ms: u64,
+}
+
+#[pymethods]
+impl SqlCursorSettings {
+    #[new]
+    #[pyo3(signature = (
+        cursor_column,
+        id_column,
+        query = None,
+        max_cursor_lag = 0,
+        read_batch_size = 10_000,
+        poll_interval_ms = 1000,
+    ))]
+    pub fn new(
+        cursor_column: String,
+        id_column: String,
+        query: Option<String>,
+        max_cursor_lag: i64,
+        read_batch_size: usize,
+        poll_interval_ms: u64,
+    ) -> PyResult<Self> {
+        if max_cursor_lag < 0 {
+            return Err(PyValueError::new_err("max_cursor_lag must be non-negative"));
+        }
+        if read_batch_size == 0 {
+            return Err(PyValueError::new_err("read_batch_size must be positive"));
+        }
+        Ok(Self {
+            cursor_column,
+            id_column,
+            query,
+            max_cursor_lag,
+            read_batch_size,
+            poll_interval_ms,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "SseSettings")]
+pub struct SseSettings {
+    headers: Vec<(String, String)>,
+    event_types: Option<Vec<String>>,
+    reconnection_time_ms: u64,
+}
+
+#[pymethods]
+impl SseSettings {
+    #[new]
+    #[pyo3(signature = (
+        headers = Vec::new(),
+        event_types = None,
+        reconnection_time_ms = 3000,
+    ))]
+    pub fn new(
+        headers: Vec<(String, String)>,
+        event_types: Option<Vec<String>>,
+        reconnection_time_ms: u64,
+    ) -> Self {
+        Self {
+            headers,
+            event_types,
+            reconnection_time_ms,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "GrpcSettings")]
+pub struct GrpcSettings {
+    method: String,
+    request: String,
+    descriptor_set_path: Option<String>,
+    metadata: Vec<(String, String)>,
+    offset_field: Option<String>,
+    resume_field: Option<String>,
+}
+
+#[pymethods]
+impl GrpcSettings {
+    #[new]
+    #[pyo3(signature = (
+        method,
+        request = "{}".to_string(),
+        descriptor_set_path = None,
+        metadata = Vec::new(),
+        offset_field = None,
+        resume_field = None,
+    ))]
+    pub fn new(
+        method: String,
+        request: String,
+        descriptor_set_path: Option<String>,
+        metadata: Vec<(String, String)>,
+        offset_field: Option<String>,
+        resume_field: Option<String>,
+    ) -> PyResult<Self> {
+        if offset_field.is_some() != resume_field.is_some() {
+            return Err(PyValueError::new_err(
+                "offset_field and resume_field must be specified together",
+            ));
+        }
+        Ok(Self {
+            method,
+            request,
+            descriptor_set_path,
+            metadata,
+            offset_field,
+            resume_field,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "SftpSettings")]
+pub struct SftpSettings {
+    host: String,
+    port: u16,
+    user: String,
+    password: Option<String>,
+    private_key_path: Option<String>,
+    private_key_passphrase: Option<String>,
+    known_hosts_path: Option<String>,
+}
+
+#[pymethods]
+impl SftpSettings {
+    #[new]
+    #[pyo3(signature = (
+        host,
+        user,
+        port = 22,
+        password = None,
+        private_key_path = None,
+        private_key_passphrase = None,
+        known_hosts_path = None,
+    ))]
+    pub fn new(
+        host: String,
+        user: String,
+        port: u16,
+        password: Option<String>,
+        private_key_path: Option<String>,
+        private_key_passphrase: Option<String>,
+        known_hosts_path: Option<String>,
+    ) -> PyResult<Self> {
+        if password.is_some() && private_key_path.is_some() {
+            return Err(PyValueError::new_err(
+                "password and private_key_path can't be specified together",
+            ));
+        }
+        if private_key_passphrase.is_some() && private_key_path.is_none() {
+            return Err(PyValueError::new_err(
+                "private_key_passphrase requires private_key_path to be specified",
+            ));
+        }
+        Ok(Self {
+            host,
+            port,
+            user,
+            password,
+            private_key_path,
+            private_key_passphrase,
+            known_hosts_path,
+        })
+    }
+}
+
+impl SftpSettings {
+    fn connection_settings(&self) -> SftpConnectionSettings {
+        let authentication = if let Some(password) = &self.password {
+            SftpAuthentication::Password(password.clone())
+        } else if let Some(private_key_path) = &self.private_key_path {
+            SftpAuthentication::PrivateKey {
+                path: private_key_path.into(),
+                passphrase: self.private_key_passphrase.clone(),
+            }
+        } else {
+            SftpAuthentication::Agent
+        };
+        SftpConnectionSettings {
+            host: self.host.clone(),
+            port: self.port,
+            user: self.user.clone(),
+            authentication,
+            known_hosts_path: self.known_hosts_path.as_ref().map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "HdfsSettings")]
+pub struct HdfsSettings {
+    url: String,
+    user: Option<String>,
+    delegation_token: Option<String>,
+}
+
+#[pymethods]
+impl HdfsSettings {
+    #[new]
+    #[pyo3(signature = (url, user = None, delegation_token = None))]
+    pub fn new(url: String, user: Option<String>, delegation_token: Option<String>) -> Self {
+        Self {
+            url,
+            user,
+            delegation_token,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "WebhookSettings")]
+pub struct WebhookSettings {
+    url: String,
+    method: String,
+    headers: Vec<(String, String)>,
+    content_type: Option<String>,
+    batch_format: Option<WebhookBatchFormat>,
+    max_batch_size: Option<usize>,
+    retry_policy: WebhookRetryPolicy,
+    max_concurrent_requests: usize,
+    connect_timeout_ms: Option<u64>,
+    request_timeout_ms: Option<u64>,
+}
+
+#[pymethods]
+impl WebhookSettings {
+    #[new]
+    #[pyo3(signature = (
+        url,
+        method = "POST".to_string(),
+        headers = None,
+        content_type = None,
+        batch_format = None,
+        max_batch_size = None,
+        n_retries = 0,
+        first_retry_delay_ms = 1000,
+        retry_backoff_factor = 1.5,
+        retry_jitter_ms = 300,
+        retry_codes = None,
+        max_concurrent_requests = 1,
+        connect_timeout_ms = None,
+        request_timeout_ms = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        url: String,
+        method: String,
+        headers: Option<HashMap<String, String>>,
+        content_type: Option<String>,
+        batch_format: Option<String>,
+        max_batch_size: Option<usize>,
+        n_retries: usize,
+        first_retry_delay_ms: u64,
+        retry_backoff_factor: f64,
+        retry_jitter_ms: u64,
+        retry_codes: Option<Vec<u16>>,
+        max_concurrent_requests: usize,
+        connect_timeout_ms: Option<u64>,
+        request_timeout_ms: Option<u64>,
+    ) -> PyResult<Self> {
+        let batch_format = match batch_format.as_deref() {
+            None => None,
+            Some("ndjson") => Some(WebhookBatchFormat::NdJson),
+            Some("json_array") => Some(WebhookBatchFormat::JsonArray),
+            Some(other) => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown batch format: {other:?}"
+                )))
+            }
+        };
+        let mut headers: Vec<_> = headers.unwrap_or_default().into_iter().collect();
+        headers.sort();
+        Ok(Self {
+            url,
+            method,
+            headers,
+            content_type,
+            batch_format,
+            max_batch_size,
+            retry_policy: WebhookRetryPolicy {
+                n_retries,
+                first_delay: time::Duration::from_millis(first_retry_delay_ms),
+                backoff_factor: retry_backoff_factor,
+                jitter: time::Duration::from_millis(retry_jitter_ms),
+                retry_codes: retry_codes.unwrap_or_default(),
+            },
+            max_concurrent_requests,
+            connect_timeout_ms,
+            request_timeout_ms,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "LanceSettings")]
+pub struct LanceSettings {
+    vector_columns: HashMap<String, usize>,
+}
+
+#[pymethods]
+impl LanceSettings {
+    #[new]
+    #[pyo3(signature = (vector_columns = None))]
+    pub fn new(vector_columns: Option<HashMap<String, usize>>) -> Self {
+        Self {
+            vector_columns: vector_columns.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen, name = "CassandraSettings")]
+pub struct CassandraSettings {
+    hosts: Vec<String>,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+#[pymethods]
+impl CassandraSettings {
+    #[new]
+    #[pyo3(signature = (
+        hosts,
+        username = None,
+        password = None,
+    ))]
+    pub fn new(
+        hosts: Vec<String>,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> PyResult<Self> {
+        if hosts.is_empty() {
+            return Err(PyValueError::new_err(
+                "At least one Cassandra host must be specified",
+            ));
+        }
+        if username.is_some() != password.is_some() {
+            return Err(PyValueError::new_err(
+                "Cassandra username and password must be specified together",
+            ));
+        }
+        Ok(Self {
+            hosts,
+            username,
+            password,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen)]
+pub struct PsqlReplicationSettings {
+    connection_string: String,
+    publication_name: String,
+    replication_slot_name: Option<String>,
+    snapshot_name: Option<String>,
+}
+
+#[pymethods]
+impl PsqlReplicationSettings {
+    #[new]
+    #[pyo3(signature = (
+        connection_string,
         publication_name,
         replication_slot_name,
         snapshot_name,
@@ -5019,6 +6609,17 @@ pub struct DataStorage {
     azure_blob_storage_settings: Option<AzureBlobStorageSettings>,
     delta_optimizer_rule: Option<PyDeltaOptimizerRule>,
     mqtt_settings: Option<MqttSettings>,
+    sqs_settings: Option<SqsSettings>,
+    redis_stream_settings: Option<RedisStreamSettings>,
+    redis_key_value_settings: Option<RedisKeyValueSettings>,
+    cassandra_settings: Option<CassandraSettings>,
+    sql_cursor_settings: Option<SqlCursorSettings>,
+    sse_settings: Option<SseSettings>,
+    grpc_settings: Option<GrpcSettings>,
+    sftp_settings: Option<SftpSettings>,
+    hdfs_settings: Option<HdfsSettings>,
+    webhook_settings: Option<WebhookSettings>,
+    lance_settings: Option<LanceSettings>,
     only_provide_metadata: bool,
     sort_key_index: Option<usize>,
     legacy_mode: bool,
@@ -5033,6 +6634,9 @@ pub struct DataStorage {
     qdrant_params: Option<Arc<Py<QdrantParams>>>,
     pinecone_params: Option<Arc<Py<PineconeParams>>>,
     detach_between_batches: bool,
+    partition_aligned: bool,
+    start_position: Option<String>,
+    partition_start_positions: Option<HashMap<i32, String>>,
 }
 
 #[allow(clippy::doc_markdown)]
@@ -5129,6 +6733,11 @@ pub struct PersistenceConfig {
     continue_after_replay: bool,
     worker_scaling_enabled: bool,
     workload_tracking_window: ::std::time::Duration,
+    gc_interval: Option<::std::time::Duration>,
+    encryption_key: Option<Vec<u8>>,
+    accept_unencrypted_snapshots: bool,
+    full_snapshot_interval: Option<::std::time::Duration>,
+    lock_ttl: Option<::std::time::Duration>,
 }
 
 #[pymethods]
@@ -5143,7 +6752,13 @@ impl PersistenceConfig {
         continue_after_replay = true,
         worker_scaling_enabled = false,
         workload_tracking_window_ms = 120_000,
+        gc_interval_ms = None,
+        encryption_key = None,
+        accept_unencrypted_snapshots = false,
+        full_snapshot_interval_ms = None,
+        lock_ttl_ms = None,
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         snapshot_interval_ms: u64,
         backend: DataStorage,
@@ -5152,6 +6767,11 @@ impl PersistenceConfig {
         continue_after_replay: bool,
         worker_scaling_enabled: bool,
         workload_tracking_window_ms: u64,
+        gc_interval_ms: Option<u64>,
+        encryption_key: Option<Vec<u8>>,
+        accept_unencrypted_snapshots: bool,
+        full_snapshot_interval_ms: Option<u64>,
+        lock_ttl_ms: Option<u64>,
     ) -> Self {
         Self {
             snapshot_interval: ::std::time::Duration::from_millis(snapshot_interval_ms),
@@ -5163,6 +6783,12 @@ impl PersistenceConfig {
             workload_tracking_window: ::std::time::Duration::from_millis(
                 workload_tracking_window_ms,
             ),
+            gc_interval: gc_interval_ms.map(::std::time::Duration::from_millis),
+            encryption_key,
+            accept_unencrypted_snapshots,
+            full_snapshot_interval: full_snapshot_interval_ms
+                .map(::std::time::Duration::from_millis),
+            lock_ttl: lock_ttl_ms.map(::std::time::Duration::from_millis),
         }
     }
 }
@@ -5180,7 +6806,7 @@ impl PersistenceConfig {
             }
         }
 
-        Ok(PersistenceManagerOuterConfig::new(
+        let mut config = PersistenceManagerOuterConfig::new(
             self.snapshot_interval,
             self.backend.construct_persistent_storage_config()?,
             self.snapshot_access,
@@ -5188,7 +6814,26 @@ impl PersistenceConfig {
             self.continue_after_replay,
             self.worker_scaling_enabled,
             self.workload_tracking_window,
-        ))
+        );
+        if let Some(gc_interval) = self.gc_interval {
+            config = config.with_retention_policy(RetentionPolicy::new(gc_interval));
+        }
+        if let Some(full_snapshot_interval) = self.full_snapshot_interval {
+            config = config.with_full_snapshot_interval(full_snapshot_interval);
+        }
+        let encryption_key = match self.encryption_key {
+            Some(encryption_key) => Some(EncryptionKey::new(&encryption_key)),
+            None => EncryptionKey::from_env().transpose(),
+        }
+        .transpose()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        if let Some(encryption_key) = encryption_key {
+            config = config.with_encryption_key(encryption_key);
+        }
+        if self.accept_unencrypted_snapshots {
+            config = config.with_unencrypted_snapshots_accepted();
+        }
+        Ok(config)
     }
 }
 
@@ -5205,6 +6850,7 @@ pub struct TelemetryConfig {
     service_namespace: Option<String>,
     service_instance_id: Option<String>,
     run_id: String,
+    graph_version: Option<String>,
     license_key: Option<String>,
     trace_parent: Option<String>,
     metrics_reader_interval_secs: Option<u64>,
@@ -5217,6 +6863,7 @@ impl TelemetryConfig {
     #[pyo3(signature = (
         *,
         run_id = None,
+        graph_version = None,
         license_key = None,
         monitoring_server = None,
         detailed_metrics_dir = None,
@@ -5225,6 +6872,7 @@ impl TelemetryConfig {
     ))]
     fn create(
         run_id: Option<String>,
+        graph_version: Option<String>,
         license_key: Option<String>,
         monitoring_server: Option<String>,
         detailed_metrics_dir: Option<String>,
@@ -5232,16 +6880,24 @@ impl TelemetryConfig {
         graph: Option<String>,
     ) -> PyResult<TelemetryConfig> {
         let license = License::new(license_key)?;
+        let run_id = run_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
         let config = EngineTelemetryConfig::create(
             &license,
-            run_id,
+            Some(run_id.clone()),
+            graph_version.clone(),
             monitoring_server,
             detailed_metrics_dir,
             None,
             metrics_reader_interval_secs,
             graph,
         )?;
-        Ok(config.into())
+        // The lineage of the run is kept even if the telemetry is disabled, as it is
+        // also used outside of it, e.g. in the metadata columns of the output connectors.
+        Ok(TelemetryConfig {
+            run_id,
+            graph_version,
+            ..config.into()
+        })
     }
 
     #[pyo3(signature = (trace_parent))]
@@ -5266,6 +6922,7 @@ impl From<EngineTelemetryConfig> for TelemetryConfig {
                 service_namespace: Some(config.service_namespace),
                 service_instance_id: Some(config.service_instance_id),
                 run_id: config.run_id,
+                graph_version: config.graph_version,
                 license_key: Some(config.license_key),
                 trace_parent: config.trace_parent,
                 metrics_reader_interval_secs: Some(config.periodic_reader_interval.as_secs()),
@@ -5348,21 +7005,6 @@ impl PythonSubject {
     }
 }
 
-#[pyclass(module = "pathway.engine")]
-#[derive(Clone)]
-pub struct ValueField {
-    #[pyo3(get)]
-    pub name: String,
-    #[pyo3(get)]
-    pub type_: Type,
-    #[pyo3(get)]
-    pub source: FieldSource,
-    #[pyo3(get)]
-    pub default: Option<Value>,
-    #[pyo3(get)]
-    pub metadata: Option<String>,
-}
-
 impl ValueField {
     fn as_inner_schema_field(&self) -> InnerSchemaField {
         InnerSchemaField::new(self.type_.clone(), self.default.clone())
@@ -5373,20 +7015,8 @@ impl ValueField {
 impl ValueField {
     #[new]
     #[pyo3(signature = (name, type_, source = FieldSource::Payload))]
-    pub fn new(name: String, type_: Type, source: FieldSource) -> Self {
-        let effective_source = if name == METADATA_FIELD_NAME {
-            FieldSource::Metadata
-        } else {
-            source
-        };
-
-        ValueField {
-            name,
-            type_,
-            source: effective_source,
-            default: None,
-            metadata: None,
-        }
+    fn py_new(name: String, type_: Type, source: FieldSource) -> Self {
+        Self::new(name, type_, source)
     }
 
     fn set_default(&mut self, ob: &Bound<PyAny>) -> PyResult<()> {
@@ -5400,14 +7030,6 @@ impl ValueField {
     }
 }
 
-#[derive(Clone, Debug)]
-#[pyclass(module = "pathway.engine", frozen, get_all)]
-pub struct BackfillingThreshold {
-    pub field: String,
-    pub threshold: Value,
-    pub comparison_op: String, // TODO: enum?
-}
-
 #[pymethods]
 impl BackfillingThreshold {
     #[new]
@@ -5547,6 +7169,11 @@ pub struct DataFormat {
     timestamp_unit: Option<String>,
     message_queue_key_field: Option<String>,
     with_special_fields: bool,
+    template: Option<String>,
+    template_escaping: Option<String>,
+    schema_evolution_policy: SchemaEvolutionPolicy,
+    csv_formatter_settings: Option<CsvFormatterSettings>,
+    json_formatter_settings: Option<JsonFormatterSettings>,
 }
 
 #[pymethods]
@@ -5588,6 +7215,17 @@ impl DataStorage {
         azure_blob_storage_settings = None,
         delta_optimizer_rule = None,
         mqtt_settings = None,
+        sqs_settings = None,
+        redis_stream_settings = None,
+        redis_key_value_settings = None,
+        cassandra_settings = None,
+        sql_cursor_settings = None,
+        sse_settings = None,
+        grpc_settings = None,
+        sftp_settings = None,
+        hdfs_settings = None,
+        webhook_settings = None,
+        lance_settings = None,
         only_provide_metadata = false,
         sort_key_index = None,
         legacy_mode = false,
@@ -5602,6 +7240,9 @@ impl DataStorage {
         qdrant_params = None,
         pinecone_params = None,
         detach_between_batches = false,
+        partition_aligned = false,
+        start_position = None,
+        partition_start_positions = None,
     ))]
     #[allow(clippy::too_many_arguments)]
     #[allow(clippy::fn_params_excessive_bools)]
@@ -5641,6 +7282,17 @@ impl DataStorage {
         azure_blob_storage_settings: Option<AzureBlobStorageSettings>,
         delta_optimizer_rule: Option<PyDeltaOptimizerRule>,
         mqtt_settings: Option<MqttSettings>,
+        sqs_settings: Option<SqsSettings>,
+        redis_stream_settings: Option<RedisStreamSettings>,
+        redis_key_value_settings: Option<RedisKeyValueSettings>,
+        cassandra_settings: Option<CassandraSettings>,
+        sql_cursor_settings: Option<SqlCursorSettings>,
+        sse_settings: Option<SseSettings>,
+        grpc_settings: Option<GrpcSettings>,
+        sftp_settings: Option<SftpSettings>,
+        hdfs_settings: Option<HdfsSettings>,
+        webhook_settings: Option<WebhookSettings>,
+        lance_settings: Option<LanceSettings>,
         only_provide_metadata: bool,
         sort_key_index: Option<usize>,
         legacy_mode: bool,
@@ -5655,6 +7307,9 @@ impl DataStorage {
         qdrant_params: Option<Py<QdrantParams>>,
         pinecone_params: Option<Py<PineconeParams>>,
         detach_between_batches: bool,
+        partition_aligned: bool,
+        start_position: Option<String>,
+        partition_start_positions: Option<HashMap<i32, String>>,
     ) -> PyResult<Self> {
         // ``max_batch_size`` is the buffer threshold at which the
         // size-based output writers (Postgres, MySQL, MSSQL, MongoDB,
@@ -5711,6 +7366,17 @@ impl DataStorage {
             azure_blob_storage_settings,
             delta_optimizer_rule,
             mqtt_settings,
+            sqs_settings,
+            redis_stream_settings,
+            redis_key_value_settings,
+            cassandra_settings,
+            sql_cursor_settings,
+            sse_settings,
+            grpc_settings,
+            sftp_settings,
+            hdfs_settings,
+            webhook_settings,
+            lance_settings,
             only_provide_metadata,
             sort_key_index,
             legacy_mode,
@@ -5725,6 +7391,9 @@ impl DataStorage {
             qdrant_params: qdrant_params.map(Into::into),
             pinecone_params: pinecone_params.map(Into::into),
             detach_between_batches,
+            partition_aligned,
+            start_position,
+            partition_start_positions,
         })
     }
 
@@ -5822,6 +7491,11 @@ impl DataFormat {
         timestamp_unit = None,
         message_queue_key_field = None,
         with_special_fields = true,
+        template = None,
+        template_escaping = None,
+        schema_evolution_policy = SchemaEvolutionPolicy::IgnoreNewFields,
+        csv_formatter_settings = None,
+        json_formatter_settings = None,
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -5844,6 +7518,11 @@ impl DataFormat {
         timestamp_unit: Option<String>,
         message_queue_key_field: Option<String>,
         with_special_fields: bool,
+        template: Option<String>,
+        template_escaping: Option<String>,
+        schema_evolution_policy: SchemaEvolutionPolicy,
+        csv_formatter_settings: Option<CsvFormatterSettings>,
+        json_formatter_settings: Option<JsonFormatterSettings>,
     ) -> Self {
         DataFormat {
             format_type,
@@ -5865,6 +7544,11 @@ impl DataFormat {
             timestamp_unit,
             message_queue_key_field,
             with_special_fields,
+            template,
+            template_escaping,
+            schema_evolution_policy,
+            csv_formatter_settings,
+            json_formatter_settings,
         }
     }
 
@@ -6089,10 +7773,97 @@ impl CsvParserSettings {
     }
 }
 
-impl DataStorage {
-    fn extract_string_field<'a>(
-        field: Option<&'a String>,
-        error_message: &'static str,
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen)]
+pub struct CsvFormatterSettings {
+    dialect: DsvDialect,
+}
+
+#[pymethods]
+impl CsvFormatterSettings {
+    #[new]
+    #[pyo3(signature = (
+        quoting = "always",
+        escape = None,
+        write_header = true,
+        null_value = None,
+    ))]
+    pub fn new(
+        quoting: &str,
+        escape: Option<char>,
+        write_header: bool,
+        null_value: Option<String>,
+    ) -> PyResult<CsvFormatterSettings> {
+        let quoting = match quoting {
+            "always" => DsvQuoting::Always,
+            "necessary" => DsvQuoting::Necessary,
+            "non_numeric" => DsvQuoting::NonNumeric,
+            "never" => DsvQuoting::Never,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown quoting policy: {quoting}"
+                )))
+            }
+        };
+        let escape = escape
+            .map(|escape| {
+                u8::try_from(escape).map_err(|_| {
+                    PyValueError::new_err(
+                        "Escape character, if specified, should be an ASCII character",
+                    )
+                })
+            })
+            .transpose()?;
+        Ok(CsvFormatterSettings {
+            dialect: DsvDialect {
+                quoting,
+                escape,
+                write_header,
+                null_value,
+            },
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "pathway.engine", frozen)]
+pub struct JsonFormatterSettings {
+    field_paths: HashMap<String, String>,
+    datetime_format: JsonDateTimeFormat,
+}
+
+#[pymethods]
+impl JsonFormatterSettings {
+    #[new]
+    #[pyo3(signature = (field_paths = HashMap::new(), datetime_format = "default"))]
+    pub fn new(
+        field_paths: HashMap<String, String>,
+        datetime_format: &str,
+    ) -> PyResult<JsonFormatterSettings> {
+        let datetime_format = match datetime_format {
+            "default" => JsonDateTimeFormat::Default,
+            "iso8601" => JsonDateTimeFormat::Iso8601,
+            "epoch_seconds" => JsonDateTimeFormat::EpochSeconds,
+            "epoch_milliseconds" => JsonDateTimeFormat::EpochMilliseconds,
+            "epoch_microseconds" => JsonDateTimeFormat::EpochMicroseconds,
+            "epoch_nanoseconds" => JsonDateTimeFormat::EpochNanoseconds,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown datetime format: {datetime_format}"
+                )))
+            }
+        };
+        Ok(JsonFormatterSettings {
+            field_paths,
+            datetime_format,
+        })
+    }
+}
+
+impl DataStorage {
+    fn extract_string_field<'a>(
+        field: Option<&'a String>,
+        error_message: &'static str,
     ) -> PyResult<&'a str> {
         let value = field
             .as_ref()
@@ -6168,6 +7939,76 @@ impl DataStorage {
             .cloned()
     }
 
+    fn sqs_settings(&self) -> PyResult<SqsSettings> {
+        self.sqs_settings
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("For SQS, sqs_settings must be specified"))
+            .cloned()
+    }
+
+    fn redis_stream_settings(&self) -> PyResult<RedisStreamSettings> {
+        self.redis_stream_settings
+            .as_ref()
+            .ok_or_else(|| {
+                PyValueError::new_err("For Redis streams, redis_stream_settings must be specified")
+            })
+            .cloned()
+    }
+
+    fn sse_settings(&self) -> PyResult<SseSettings> {
+        self.sse_settings
+            .as_ref()
+            .ok_or_else(|| {
+                PyValueError::new_err("For Server-Sent Events, sse_settings must be specified")
+            })
+            .cloned()
+    }
+
+    fn grpc_settings(&self) -> PyResult<GrpcSettings> {
+        self.grpc_settings
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("For gRPC, grpc_settings must be specified"))
+            .cloned()
+    }
+
+    fn sftp_settings(&self) -> PyResult<&SftpSettings> {
+        self.sftp_settings
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("For SFTP, sftp_settings must be specified"))
+    }
+
+    fn hdfs_settings(&self) -> PyResult<&HdfsSettings> {
+        self.hdfs_settings
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("For HDFS, hdfs_settings must be specified"))
+    }
+
+    fn webhook_settings(&self) -> PyResult<&WebhookSettings> {
+        self.webhook_settings.as_ref().ok_or_else(|| {
+            PyValueError::new_err("For webhook output, webhook_settings must be specified")
+        })
+    }
+
+    fn redis_key_value_settings(&self) -> PyResult<RedisKeyValueSettings> {
+        self.redis_key_value_settings
+            .as_ref()
+            .ok_or_else(|| {
+                PyValueError::new_err(
+                    "For Redis output, redis_key_value_settings must be specified",
+                )
+            })
+            .cloned()
+    }
+
+    fn cassandra_settings(&self) -> PyResult<CassandraSettings> {
+        self.cassandra_settings
+            .as_ref()
+            .ok_or_else(|| {
+                PyValueError::new_err("For Cassandra, cassandra_settings must be specified")
+            })
+            .cloned()
+    }
+
     fn downloader_threads_count(&self) -> PyResult<usize> {
         if let Some(count) = self.downloader_threads_count {
             Ok(count)
@@ -6204,15 +8045,53 @@ impl DataStorage {
             client_config.set(key, value);
         }
 
-        // If the starting timestamp is given, the positions
+        // If the starting positions are given, the positions
         // within the topic partitions will be reset lazily
-        if self.start_from_timestamp_ms.is_some() {
+        if self.start_from_timestamp_ms.is_some()
+            || self.start_position.is_some()
+            || self.partition_start_positions.is_some()
+        {
             client_config.set("auto.offset.reset", "earliest");
         }
 
         Ok(client_config)
     }
 
+    fn kafka_start_position(&self, start_position: &str) -> PyResult<KafkaStartPosition> {
+        match start_position {
+            "beginning" => Ok(KafkaStartPosition::Beginning),
+            "end" => Ok(KafkaStartPosition::End),
+            "timestamp" => self
+                .start_from_timestamp_ms
+                .map(KafkaStartPosition::Timestamp)
+                .ok_or_else(|| {
+                    PyValueError::new_err(
+                        "The 'timestamp' starting position requires 'start_from_timestamp_ms'",
+                    )
+                }),
+            other => Err(PyValueError::new_err(format!(
+                "Unknown starting position: {other:?}"
+            ))),
+        }
+    }
+
+    fn kafka_start_positions(&self) -> PyResult<KafkaStartPositions> {
+        let default = match &self.start_position {
+            Some(start_position) => Some(self.kafka_start_position(start_position)?),
+            None => self
+                .start_from_timestamp_ms
+                .map(KafkaStartPosition::Timestamp),
+        };
+        let mut partitions = HashMap::new();
+        for (partition, start_position) in self.partition_start_positions.iter().flatten() {
+            partitions.insert(*partition, self.kafka_start_position(start_position)?);
+        }
+        Ok(KafkaStartPositions {
+            default,
+            partitions,
+        })
+    }
+
     fn message_queue_topic(&self) -> PyResult<MessageQueueTopic> {
         if let Some(topic) = &self.topic {
             if self.topic_name_index.is_some() {
@@ -6254,6 +8133,10 @@ impl DataStorage {
     fn build_tokenizer_for_posix_like_read(&self, data_format: &DataFormat) -> Box<dyn Tokenize> {
         match data_format.format_type.as_ref() {
             "dsv" => Box::new(CsvTokenizer::new(self.build_csv_parser_settings())),
+            "orc" => Box::new(OrcTokenizer::new(
+                Python::with_gil(|py| data_format.value_fields_type_map(py)),
+                OrcTokenizer::DEFAULT_BATCH_SIZE,
+            )),
             _ => Box::new(BufReaderTokenizer::new(self.read_method)),
         }
     }
@@ -6325,12 +8208,94 @@ impl DataStorage {
         Ok((Box::new(storage), 1))
     }
 
+    fn construct_azure_blob_reader(
+        &self,
+        scope: &Scope,
+        data_format: &DataFormat,
+    ) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
+        let scanner = AzureBlobScanner::new(
+            self.azure_blob_storage_settings()?.container_client()?,
+            self.path()?,
+            self.object_pattern.clone(),
+            self.downloader_threads_count()?,
+            self.mode.is_polling_enabled(),
+            self.only_provide_metadata,
+        )
+        .map_err(|e| {
+            PyIOError::new_err(format!(
+                "Failed to initialize Azure Blob Storage scanner: {e}"
+            ))
+        })?;
+        let storage = PosixLikeReader::new(
+            Box::new(scanner),
+            self.build_tokenizer_for_posix_like_read(data_format),
+            self.mode,
+            self.only_provide_metadata,
+            scope.is_persisted,
+        )
+        .map_err(|e| {
+            PyRuntimeError::new_err(format!("Creating Azure Blob Storage reader failed: {e}"))
+        })?;
+        Ok((Box::new(storage), 1))
+    }
+
+    fn construct_hdfs_reader(
+        &self,
+        scope: &Scope,
+        data_format: &DataFormat,
+    ) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
+        let settings = self.hdfs_settings()?;
+        let scanner = HdfsScanner::new(
+            &settings.url,
+            settings.user.clone(),
+            settings.delegation_token.clone(),
+            self.path()?,
+            &self.object_pattern,
+        )
+        .map_err(|e| PyIOError::new_err(format!("Failed to initialize HDFS scanner: {e}")))?;
+        let storage = PosixLikeReader::new(
+            Box::new(scanner),
+            self.build_tokenizer_for_posix_like_read(data_format),
+            self.mode,
+            self.only_provide_metadata,
+            scope.is_persisted,
+        )
+        .map_err(|e| PyIOError::new_err(format!("Failed to initialize HDFS reader: {e}")))?;
+        Ok((Box::new(storage), 1))
+    }
+
+    fn construct_sftp_reader(
+        &self,
+        scope: &Scope,
+        data_format: &DataFormat,
+    ) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
+        let scanner = SftpScanner::new(
+            self.sftp_settings()?.connection_settings(),
+            self.path()?,
+            &self.object_pattern,
+        )
+        .map_err(|e| PyIOError::new_err(format!("Failed to initialize SFTP scanner: {e}")))?;
+        let storage = PosixLikeReader::new(
+            Box::new(scanner),
+            self.build_tokenizer_for_posix_like_read(data_format),
+            self.mode,
+            self.only_provide_metadata,
+            scope.is_persisted,
+        )
+        .map_err(|e| PyIOError::new_err(format!("Failed to initialize SFTP reader: {e}")))?;
+        Ok((Box::new(storage), 1))
+    }
+
     fn construct_kafka_reader(
         &self,
         scope: &Scope,
         properties: &ConnectorProperties,
     ) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
-        let client_config = self.kafka_client_config()?;
+        let mut client_config = self.kafka_client_config()?;
+        // As with the starting timestamp, the positions are reset lazily
+        if !properties.start_from.is_empty() {
+            client_config.set("auto.offset.reset", "earliest");
+        }
         let consumer: BaseConsumer = client_config
             .create()
             .map_err(|e| PyValueError::new_err(format!("Creating Kafka consumer failed: {e}")))?;
@@ -6350,6 +8315,14 @@ impl DataStorage {
         // readers; sharding by the raw worker count would assign partitions to
         // workers that never run a reader, silently dropping their data.
         let reader_count = std::cmp::min(scope.worker_count(), parallel_readers);
+        // The rows of a partition-aligned reader stay on the worker reading them,
+        // which is only their shard's worker if every worker runs a reader
+        if self.partition_aligned && reader_count != scope.worker_count() {
+            return Err(PyValueError::new_err(format!(
+                "Partition-aligned reading requires a reader on each of the {} workers, but only {reader_count} readers can be started",
+                scope.worker_count()
+            )));
+        }
 
         // The Kafka-specific machinery (fetching partitions/watermarks, resolving
         // timestamp seeks, sharding partitions and acquiring them) lives in
@@ -6358,9 +8331,10 @@ impl DataStorage {
             consumer,
             topic,
             self.mode,
-            self.start_from_timestamp_ms,
+            &self.kafka_start_positions()?,
             scope.worker_index(),
             reader_count,
+            self.partition_aligned,
         )
         .map_err(|e| PyIOError::new_err(e.to_string()))?;
 
@@ -6400,6 +8374,32 @@ impl DataStorage {
             SqliteOpenFlags::SQLITE_OPEN_READ_ONLY | SqliteOpenFlags::SQLITE_OPEN_NO_MUTEX,
         )
         .map_err(|e| PyRuntimeError::new_err(format!("Failed to open Sqlite connection: {e}")))?;
+
+        if let Some(settings) = &self.sql_cursor_settings {
+            let target = match (&settings.query, &self.table_name) {
+                (Some(query), _) => SqlitePollingTarget::Query(query.clone()),
+                (None, Some(table_name)) => SqlitePollingTarget::Table(table_name.clone()),
+                (None, None) => {
+                    return Err(PyValueError::new_err(
+                        "For Sqlite connector, either table_name or query should be specified",
+                    ))
+                }
+            };
+            let reader = build_sqlite_cursor_reader(
+                connection,
+                target,
+                data_format.value_fields_type_map(py).into_iter().collect(),
+                settings.cursor_column.clone(),
+                settings.id_column.clone(),
+                self.mode,
+                settings.max_cursor_lag,
+                settings.read_batch_size,
+                time::Duration::from_millis(settings.poll_interval_ms),
+            )
+            .map_err(|e| PyValueError::new_err(format!("{e}")))?;
+            return Ok((Box::new(reader), 1));
+        }
+
         let table_name = self.table_name.clone().ok_or_else(|| {
             PyValueError::new_err("For Sqlite connector, table_name should be specified")
         })?;
@@ -6418,6 +8418,7 @@ impl DataStorage {
         &self,
         py: pyo3::Python,
         data_format: &DataFormat,
+        dry_run: bool,
     ) -> PyResult<Box<dyn Writer>> {
         let connection = SqliteConnection::open(self.path()?).map_err(|e| {
             PyRuntimeError::new_err(format!("Failed to open Sqlite connection: {e}"))
@@ -6437,6 +8438,7 @@ impl DataStorage {
             self.snapshot_maintenance_on_output,
             self.table_writer_init_mode,
             self.max_batch_size,
+            dry_run,
         )
         .map_err(|e| PyRuntimeError::new_err(format!("Unable to initialize SQLite table: {e}")))?;
         Ok(Box::new(writer))
@@ -6518,6 +8520,13 @@ impl DataStorage {
         let connection_string = self.connection_string()?;
         let opts = MysqlOpts::from_url(connection_string)
             .map_err(|e| PyValueError::new_err(format!("Invalid MySQL connection string: {e}")))?;
+        if let Some(settings) = &self.sql_cursor_settings {
+            let connection = MysqlRawConn::new(opts)
+                .map_err(|e| PyIOError::new_err(format!("Failed to connect to MySQL: {e}")))?;
+            let reader =
+                self.construct_sql_cursor_reader(py, connection, settings, data_format, None)?;
+            return Ok((Box::new(reader), 1));
+        }
         let table_name = self.table_name.clone().ok_or_else(|| {
             PyValueError::new_err("For MySQL connector, table_name should be specified")
         })?;
@@ -6795,6 +8804,7 @@ impl DataStorage {
             table_name.to_string(),
             &value_fields,
             ArrowTimeUnit::Microsecond, // used only for schema generation, can be arbitrary here
+            MaintenanceMode::StreamOfChanges,
         )
         .map_err(|e| {
             PyIOError::new_err(format!(
@@ -6889,22 +8899,182 @@ impl DataStorage {
         Ok((Box::new(reader), properties.max_parallel_readers(scope)))
     }
 
-    fn construct_postgres_reader(
+    fn construct_sqs_reader(
         &self,
-        py: pyo3::Python,
-        data_format: &DataFormat,
         scope: &Scope,
         properties: &ConnectorProperties,
     ) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
         if let Some(license) = scope.license.as_ref() {
-            license.check_entitlements(["postgres-wal-reader"])?;
+            license.check_entitlements(["sqs"])?;
+        }
+
+        let runtime = create_async_tokio_runtime()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to create async runtime: {e}")))?;
+        let config = runtime.block_on(async { ::aws_config::load_from_env().await });
+        let queue_url = self.message_queue_fixed_topic()?;
+        let client = SqsClient::new(&config);
+        let settings = self.sqs_settings()?;
+        let group_selector = settings
+            .shard_by_message_group
+            .then(|| ShardSelector::new(scope.worker_index(), scope.worker_count()));
+
+        let reader = SqsReader::new(
+            runtime,
+            client,
+            queue_url,
+            scope.worker_index(),
+            settings.wait_time_s,
+            settings.visibility_timeout_s,
+            group_selector,
+        )
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create SQS reader: {e}")))?;
+
+        Ok((Box::new(reader), properties.max_parallel_readers(scope)))
+    }
+
+    fn construct_pubsub_reader(
+        &self,
+        scope: &Scope,
+        properties: &ConnectorProperties,
+    ) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
+        let runtime = create_async_tokio_runtime()?;
+        let client = runtime.block_on(build_pubsub_client())?;
+        let subscription = client.subscription(&self.message_queue_fixed_topic()?);
+        let reader = PubSubReader::new(runtime, subscription, scope.worker_index())
+            .map_err(|e| PyIOError::new_err(format!("Failed to create Pub/Sub reader: {e}")))?;
+        Ok((Box::new(reader), properties.max_parallel_readers(scope)))
+    }
+
+    fn construct_eventhubs_reader(
+        &self,
+        scope: &Scope,
+        properties: &ConnectorProperties,
+    ) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
+        let namespace = self.path()?;
+        let eventhub_name = self.message_queue_fixed_topic()?;
+        let runtime = create_async_tokio_runtime()?;
+        let credential = DefaultAzureCredential::new()
+            .map_err(|e| PyIOError::new_err(format!("Failed to obtain Azure credentials: {e}")))?;
+        let consumer = runtime
+            .block_on(EventHubsConsumer::builder().open(
+                namespace,
+                eventhub_name.clone(),
+                credential,
+            ))
+            .map_err(|e| PyIOError::new_err(format!("Failed to connect to Event Hubs: {e}")))?;
+        let partition_selector = ShardSelector::new(scope.worker_index(), scope.worker_count());
+        let reader = EventHubsReader::new(runtime, consumer, eventhub_name, partition_selector)
+            .map_err(|e| PyIOError::new_err(format!("Failed to create Event Hubs reader: {e}")))?;
+        Ok((Box::new(reader), properties.max_parallel_readers(scope)))
+    }
+
+    fn construct_redis_stream_reader(
+        &self,
+        scope: &Scope,
+        properties: &ConnectorProperties,
+    ) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
+        let settings = self.redis_stream_settings()?;
+        let connection = RedisClient::open(self.path()?)
+            .and_then(|client| client.get_connection())
+            .map_err(|e| PyIOError::new_err(format!("Failed to connect to Redis: {e}")))?;
+        let reader = RedisStreamReader::new(
+            connection,
+            self.message_queue_fixed_topic()?,
+            settings.consumer_group,
+            &settings.consumer_name,
+            scope.worker_index(),
+            settings.data_field,
+        )
+        .map_err(|e| PyIOError::new_err(format!("Failed to create Redis stream reader: {e}")))?;
+        Ok((Box::new(reader), properties.max_parallel_readers(scope)))
+    }
+
+    fn construct_sse_reader(&self) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
+        let settings = self.sse_settings()?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &settings.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| PyValueError::new_err(format!("Invalid header name {name:?}: {e}")))?;
+            let value = HeaderValue::from_str(value).map_err(|e| {
+                PyValueError::new_err(format!("Invalid value of the header {name}: {e}"))
+            })?;
+            headers.append(name, value);
         }
+        let reader = SseReader::new(
+            self.path()?.to_string(),
+            headers,
+            settings
+                .event_types
+                .map(|event_types| event_types.into_iter().collect()),
+            time::Duration::from_millis(settings.reconnection_time_ms),
+        );
+        // The stream is delivered once per connection, so it's read by a single worker
+        Ok((Box::new(reader), 1))
+    }
 
+    fn construct_grpc_reader(&self) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
+        let settings = self.grpc_settings()?;
+        let mut metadata = MetadataMap::new();
+        for (key, value) in &settings.metadata {
+            let key = AsciiMetadataKey::from_bytes(key.as_bytes())
+                .map_err(|e| PyValueError::new_err(format!("Invalid metadata key {key:?}: {e}")))?;
+            let value = AsciiMetadataValue::try_from(value.as_str()).map_err(|e| {
+                PyValueError::new_err(format!(
+                    "Invalid value of the metadata key {}: {e}",
+                    key.as_str()
+                ))
+            })?;
+            metadata.append(key, value);
+        }
+        let bookmark_fields =
+            settings
+                .offset_field
+                .zip(settings.resume_field)
+                .map(|(offset_field, resume_field)| GrpcBookmarkFields {
+                    offset_field,
+                    resume_field,
+                });
+        let reader = GrpcReader::new(
+            create_async_tokio_runtime()?,
+            self.path()?,
+            &settings.method,
+            settings.descriptor_set_path.as_deref(),
+            &settings.request,
+            metadata,
+            bookmark_fields,
+        )
+        .map_err(|e| PyIOError::new_err(format!("Failed to create gRPC reader: {e}")))?;
+        // A call delivers its messages once, so it's read by a single worker
+        Ok((Box::new(reader), 1))
+    }
+
+    fn construct_postgres_reader(
+        &self,
+        py: pyo3::Python,
+        data_format: &DataFormat,
+        scope: &Scope,
+        properties: &ConnectorProperties,
+    ) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
         let connection_string = self.connection_string()?;
         let tls = self.tls_settings.clone().unwrap_or_default();
         let client = create_psql_client(connection_string, tls.mode, tls.root_cert_path)
             .map_err(|e| PyIOError::new_err(format!("Failed to create Postgres client: {e}")))?;
 
+        // Polling by a cursor column doesn't use the write-ahead log
+        if let Some(settings) = &self.sql_cursor_settings {
+            let reader = self.construct_sql_cursor_reader(
+                py,
+                client,
+                settings,
+                data_format,
+                self.schema_name.clone(),
+            )?;
+            return Ok((Box::new(reader), 1));
+        }
+        if let Some(license) = scope.license.as_ref() {
+            license.check_entitlements(["postgres-wal-reader"])?;
+        }
+
         let settings = self
             .psql_replication
             .clone()
@@ -6933,6 +9103,41 @@ impl DataStorage {
         Ok((Box::new(reader), 1))
     }
 
+    /// Build a reader polling the query from the settings or, if there's
+    /// none, the table by a cursor column.
+    fn construct_sql_cursor_reader<Connection: SqlPollingConnection>(
+        &self,
+        py: pyo3::Python,
+        connection: Connection,
+        settings: &SqlCursorSettings,
+        data_format: &DataFormat,
+        schema_name: Option<String>,
+    ) -> PyResult<SqlCursorReader<Connection>> {
+        let target =
+            match (&settings.query, &self.table_name) {
+                (Some(query), _) => SqlPollingTarget::Query(query.clone()),
+                (None, Some(table_name)) => SqlPollingTarget::Table {
+                    schema_name,
+                    table_name: table_name.clone(),
+                },
+                (None, None) => return Err(PyValueError::new_err(
+                    "For a cursor column reader, either table_name or query should be specified",
+                )),
+            };
+        build_sql_cursor_reader(
+            connection,
+            target,
+            data_format.value_fields_vec(py),
+            settings.cursor_column.clone(),
+            settings.id_column.clone(),
+            self.mode,
+            settings.max_cursor_lag,
+            settings.read_batch_size,
+            time::Duration::from_millis(settings.poll_interval_ms),
+        )
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create SQL cursor reader: {e}")))
+    }
+
     fn construct_elasticsearch_reader(
         &self,
         py: pyo3::Python,
@@ -7011,6 +9216,9 @@ impl DataStorage {
         match self.storage_type.as_ref() {
             "fs" => self.construct_fs_reader(scope, data_format),
             "s3" => self.construct_s3_reader(scope, data_format),
+            "sftp" => self.construct_sftp_reader(scope, data_format),
+            "azure" => self.construct_azure_blob_reader(scope, data_format),
+            "hdfs" => self.construct_hdfs_reader(scope, data_format),
             "kafka" => self.construct_kafka_reader(scope, properties),
             "python" => self.construct_python_reader(py, data_format),
             "mssql" => self.construct_mssql_reader(py, data_format, scope),
@@ -7021,6 +9229,12 @@ impl DataStorage {
             "iceberg" => self.construct_iceberg_reader(py, data_format, scope),
             "mqtt" => self.construct_mqtt_reader(),
             "kinesis" => self.construct_kinesis_reader(scope, properties),
+            "sqs" => self.construct_sqs_reader(scope, properties),
+            "pubsub" => self.construct_pubsub_reader(scope, properties),
+            "eventhubs" => self.construct_eventhubs_reader(scope, properties),
+            "redis" => self.construct_redis_stream_reader(scope, properties),
+            "sse" => self.construct_sse_reader(),
+            "grpc" => self.construct_grpc_reader(),
             "postgres" => self.construct_postgres_reader(py, data_format, scope, properties),
             "mongodb" => self.construct_mongodb_reader(scope),
             "mysql" => self.construct_mysql_reader(py, data_format, scope),
@@ -7031,6 +9245,43 @@ impl DataStorage {
         }
     }
 
+    fn construct_planned_reader(&self) -> PyResult<(Box<dyn ReaderBuilder>, usize)> {
+        let storage_type = match self.storage_type.as_ref() {
+            "fs" | "s3" | "sftp" | "azure" | "hdfs" => StorageType::PosixLike,
+            "kafka" => StorageType::Kafka,
+            "python" => StorageType::Python,
+            "mssql" => StorageType::Mssql,
+            "sqlite" => StorageType::Sqlite,
+            "deltalake" => StorageType::DeltaLake,
+            "nats" => StorageType::Nats,
+            "rabbitmq" => StorageType::Rabbitmq,
+            "iceberg" => StorageType::Iceberg,
+            "mqtt" => StorageType::Mqtt,
+            "kinesis" => StorageType::Kinesis,
+            "sqs" => StorageType::Sqs,
+            "pubsub" => StorageType::PubSub,
+            "eventhubs" => StorageType::EventHubs,
+            "redis" => StorageType::RedisStream,
+            "sse" => StorageType::Sse,
+            "grpc" => StorageType::Grpc,
+            "postgres" => StorageType::Postgres,
+            "mongodb" => StorageType::MongoDb,
+            "mysql" => StorageType::Mysql,
+            "elasticsearch" => StorageType::ElasticSearch,
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown data source {other:?}"
+                )))
+            }
+        };
+        let is_internal = self
+            .python_subject
+            .as_ref()
+            .is_some_and(|subject| subject.get().is_internal);
+        let reader = PlannedReader::new(storage_type, is_internal, self.partition_aligned);
+        Ok((Box::new(reader), 1))
+    }
+
     fn construct_persistent_storage_config(&self) -> PyResult<PersistentStorageConfig> {
         match self.storage_type.as_ref() {
             "fs" => Ok(PersistentStorageConfig::Filesystem(self.path()?.into())),
@@ -7046,7 +9297,7 @@ impl DataStorage {
                 let path = self.path()?;
                 let azure_settings = self.azure_blob_storage_settings()?;
                 Ok(PersistentStorageConfig::Azure {
-                    credentials: azure_settings.credentials(),
+                    credentials: azure_settings.credentials()?,
                     account: azure_settings.account,
                     container: azure_settings.container,
                     root_path: path.into(),
@@ -7066,10 +9317,15 @@ impl DataStorage {
         }
     }
 
-    fn construct_fs_writer(&self) -> PyResult<Box<dyn Writer>> {
+    fn construct_fs_writer(&self, dry_run: bool) -> PyResult<Box<dyn Writer>> {
         let path = self.path()?;
         let storage = {
-            let file = File::create(path);
+            // a dry run checks that the file can be written without truncating it
+            let file = if dry_run {
+                OpenOptions::new().append(true).create(true).open(path)
+            } else {
+                File::create(path)
+            };
             match file {
                 Ok(f) => {
                     let buf_writer = BufWriter::new(f);
@@ -7085,8 +9341,21 @@ impl DataStorage {
         Ok(Box::new(storage))
     }
 
-    fn construct_kafka_writer(&self) -> PyResult<Box<dyn Writer>> {
-        let client_config = self.kafka_client_config()?;
+    fn construct_kafka_writer(
+        &self,
+        worker_index: usize,
+        writer_index: usize,
+    ) -> PyResult<Box<dyn Writer>> {
+        let mut client_config = self.kafka_client_config()?;
+        // every producer of the sink needs its own transactional id, as a producer
+        // fences the earlier ones with the same id
+        let transactional_id = client_config.get("transactional.id").map(str::to_string);
+        if let Some(transactional_id) = &transactional_id {
+            client_config.set(
+                "transactional.id",
+                format!("{transactional_id}-{worker_index}-{writer_index}"),
+            );
+        }
 
         let producer: ThreadedProducer<DefaultProducerContext> = match client_config.create() {
             Ok(producer) => producer,
@@ -7094,12 +9363,17 @@ impl DataStorage {
         };
 
         let topic = self.message_queue_topic()?;
-        let writer = KafkaWriter::new(
+        let mut writer = KafkaWriter::new(
             producer,
             topic,
             self.header_fields.clone(),
             self.key_field_index,
         );
+        if transactional_id.is_some() {
+            writer = writer.with_transactions().map_err(|e| {
+                PyIOError::new_err(format!("Kafka transactions initialization failed: {e}"))
+            })?;
+        }
 
         Ok(Box::new(writer))
     }
@@ -7108,6 +9382,7 @@ impl DataStorage {
         &self,
         py: pyo3::Python,
         data_format: &DataFormat,
+        dry_run: bool,
     ) -> PyResult<Box<dyn Writer>> {
         let tls = self.tls_settings.clone().unwrap_or_default();
         let connection_config = PsqlConnectionConfig {
@@ -7135,6 +9410,7 @@ impl DataStorage {
             &table_ctx,
             self.table_writer_init_mode,
             self.legacy_mode,
+            dry_run,
         )
         .map_err(|e| {
             PyValueError::new_err(format!("Unable to initialize PostgreSQL writer: {e}"))
@@ -7306,7 +9582,64 @@ impl DataStorage {
             params.batch_size,
             params.concurrency,
         )
-        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create Weaviate writer: {e}")))?;
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create Weaviate writer: {e}")))?;
+        Ok(Box::new(writer))
+    }
+
+    fn construct_webhook_writer(
+        &self,
+        py: pyo3::Python,
+        data_format: &DataFormat,
+    ) -> PyResult<Box<dyn Writer>> {
+        let settings = self.webhook_settings()?;
+        let writer = WebhookWriter::new(
+            &settings.method,
+            &settings.url,
+            &settings.headers,
+            &data_format.value_field_names(py),
+            settings.content_type.as_deref(),
+            settings.batch_format,
+            settings.max_batch_size,
+            settings.retry_policy.clone(),
+            settings.max_concurrent_requests,
+            settings.connect_timeout_ms.map(time::Duration::from_millis),
+            settings.request_timeout_ms.map(time::Duration::from_millis),
+        )
+        .map_err(|e| PyValueError::new_err(format!("Failed to create webhook writer: {e}")))?;
+        Ok(Box::new(writer))
+    }
+
+    fn construct_lance_writer(
+        &self,
+        py: pyo3::Python,
+        data_format: &DataFormat,
+    ) -> PyResult<Box<dyn Writer>> {
+        let value_fields = data_format.value_fields_vec(py);
+        let vector_columns = self
+            .lance_settings
+            .as_ref()
+            .map(|settings| settings.vector_columns.clone())
+            .unwrap_or_default();
+        // Lance accesses the object stores with the same `object_store` options as Delta Lake
+        let batch_writer = LanceBatchWriter::new(
+            self.path()?,
+            &value_fields,
+            vector_columns,
+            self.delta_storage_options()?,
+        )
+        .map_err(|e| PyValueError::new_err(format!("Failed to create Lance writer: {e}")))?;
+        let schema = construct_arrow_schema(
+            &value_fields,
+            &batch_writer,
+            MaintenanceMode::StreamOfChanges,
+        )
+        .map_err(|e| PyIOError::new_err(format!("Failed to construct table schema: {e}")))?;
+        let buffer = AppendOnlyColumnBuffer::new(Arc::new(schema));
+        let writer = LakeWriter::new(
+            Box::new(batch_writer),
+            Box::new(buffer),
+            self.min_commit_frequency.map(time::Duration::from_millis),
+        );
         Ok(Box::new(writer))
     }
 
@@ -7384,12 +9717,6 @@ impl DataStorage {
             license.check_entitlements(["iceberg"])?;
         }
 
-        if self.snapshot_maintenance_on_output {
-            return Err(PyNotImplementedError::new_err(
-                "Snapshot mode is not implemented for Apache Iceberg output",
-            ));
-        }
-
         let table_name = self.table_name()?;
         let namespace = self
             .namespace
@@ -7400,10 +9727,16 @@ impl DataStorage {
         let runtime = create_async_tokio_runtime()
             .map_err(|e| PyRuntimeError::new_err(format!("Failed to create async runtime: {e}")))?;
         let catalog = self.create_iceberg_catalog(&runtime)?;
+        let table_type = if self.snapshot_maintenance_on_output {
+            MaintenanceMode::Snapshot
+        } else {
+            MaintenanceMode::StreamOfChanges
+        };
         let table_params = IcebergTableParams::new(
             table_name.to_string(),
             &value_fields,
             data_format.arrow_time_unit()?,
+            table_type,
         )
         .map_err(|e| {
             PyIOError::new_err(format!(
@@ -7416,16 +9749,20 @@ impl DataStorage {
                     "Unable to create batch writer for Iceberg writer: {e}"
                 ))
             })?;
-        let schema = construct_arrow_schema(
-            &value_fields,
-            &batch_writer,
-            MaintenanceMode::StreamOfChanges, // Snapshot mode is not implemented for Iceberg
-        )
-        .map_err(|e| PyIOError::new_err(format!("Failed to construct table schema: {e}")))?;
-        let buffer = AppendOnlyColumnBuffer::new(Arc::new(schema));
+        let schema = construct_arrow_schema(&value_fields, &batch_writer, table_type)
+            .map_err(|e| PyIOError::new_err(format!("Failed to construct table schema: {e}")))?;
+        let buffer: Box<dyn ColumnBuffer> = if self.snapshot_maintenance_on_output {
+            Box::new(
+                KeyedChangesColumnBuffer::new(Arc::new(schema)).map_err(|e| {
+                    PyIOError::new_err(format!("Failed to create snapshot writer: {e}"))
+                })?,
+            )
+        } else {
+            Box::new(AppendOnlyColumnBuffer::new(Arc::new(schema)))
+        };
         let writer = LakeWriter::new(
             Box::new(batch_writer),
-            Box::new(buffer),
+            buffer,
             self.min_commit_frequency.map(time::Duration::from_millis),
         );
         Ok(Box::new(writer))
@@ -7611,6 +9948,7 @@ impl DataStorage {
         py: pyo3::Python,
         data_format: &DataFormat,
         license: Option<&License>,
+        dry_run: bool,
     ) -> PyResult<Box<dyn Writer>> {
         if let Some(license) = license {
             license.check_entitlements(["clickhouse"])?;
@@ -7624,6 +9962,7 @@ impl DataStorage {
             self.table_writer_init_mode,
             self.snapshot_maintenance_on_output,
             data_format.key_field_names.clone(),
+            dry_run,
         )
         .map_err(|e| {
             PyValueError::new_err(format!("Failed to initialize ClickHouse writer: {e}"))
@@ -7679,11 +10018,126 @@ impl DataStorage {
         Ok(Box::new(writer))
     }
 
+    fn construct_firehose_writer(&self, license: Option<&License>) -> PyResult<Box<dyn Writer>> {
+        if let Some(license) = license {
+            license.check_entitlements(["kinesis"])?;
+        }
+
+        let runtime = create_async_tokio_runtime()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to create async runtime: {e}")))?;
+        let config = runtime.block_on(async { ::aws_config::load_from_env().await });
+        let client = FirehoseClient::new(&config);
+        let delivery_stream_name = self.message_queue_topic()?;
+
+        let writer = FirehoseWriter::new(runtime, client, delivery_stream_name)
+            .map_err(|e| PyValueError::new_err(format!("Failed to create Firehose writer: {e}")))?;
+
+        Ok(Box::new(writer))
+    }
+
+    fn construct_pubsub_writer(&self) -> PyResult<Box<dyn Writer>> {
+        let runtime = create_async_tokio_runtime()?;
+        let client = runtime.block_on(build_pubsub_client())?;
+        let topic = client.topic(&self.message_queue_fixed_topic()?);
+        let writer = PubSubWriter::new(
+            runtime,
+            &topic,
+            self.key_field_index,
+            self.header_fields.clone(),
+        )
+        .map_err(|e| PyIOError::new_err(format!("Failed to create Pub/Sub writer: {e}")))?;
+        Ok(Box::new(writer))
+    }
+
+    fn construct_eventhubs_writer(&self) -> PyResult<Box<dyn Writer>> {
+        let namespace = self.path()?;
+        let eventhub_name = self.message_queue_fixed_topic()?;
+        let runtime = create_async_tokio_runtime()?;
+        let credential = DefaultAzureCredential::new()
+            .map_err(|e| PyIOError::new_err(format!("Failed to obtain Azure credentials: {e}")))?;
+        let producer = runtime
+            .block_on(EventHubsProducer::builder().open(
+                namespace,
+                eventhub_name.clone(),
+                credential,
+            ))
+            .map_err(|e| PyIOError::new_err(format!("Failed to connect to Event Hubs: {e}")))?;
+        let writer = EventHubsWriter::new(
+            runtime,
+            producer,
+            eventhub_name,
+            self.key_field_index,
+            self.header_fields.clone(),
+        );
+        Ok(Box::new(writer))
+    }
+
+    fn construct_redis_writer(
+        &self,
+        py: pyo3::Python,
+        data_format: &DataFormat,
+    ) -> PyResult<Box<dyn Writer>> {
+        let settings = self.redis_key_value_settings()?;
+        let connection = RedisClient::open(self.path()?)
+            .and_then(|client| client.get_connection())
+            .map_err(|e| PyIOError::new_err(format!("Failed to connect to Redis: {e}")))?;
+        let layout = if settings.use_hashes {
+            RedisValueLayout::Hash(data_format.value_fields_vec(py))
+        } else {
+            RedisValueLayout::String
+        };
+        let writer = RedisKeyValueWriter::new(
+            connection,
+            settings.key_prefix,
+            self.key_field_index
+                .ok_or_else(|| PyValueError::new_err("'key_field_index' must be specified"))?,
+            layout,
+            settings.ttl_ms.map(time::Duration::from_millis),
+        );
+        Ok(Box::new(writer))
+    }
+
+    fn construct_cassandra_writer(
+        &self,
+        py: pyo3::Python,
+        data_format: &DataFormat,
+    ) -> PyResult<Box<dyn Writer>> {
+        let settings = self.cassandra_settings()?;
+        let key_field_names = data_format
+            .key_field_names
+            .as_deref()
+            .filter(|names| !names.is_empty())
+            .ok_or_else(|| {
+                PyValueError::new_err("For Cassandra, the primary key must be specified")
+            })?;
+        let runtime = create_async_tokio_runtime()?;
+        let mut session_builder = SessionBuilder::new()
+            .known_nodes(&settings.hosts)
+            .use_keyspace(self.database()?, false);
+        if let (Some(username), Some(password)) = (settings.username, settings.password) {
+            session_builder = session_builder.user(username, password);
+        }
+        let session = runtime
+            .block_on(session_builder.build())
+            .map_err(|e| PyIOError::new_err(format!("Failed to connect to Cassandra: {e}")))?;
+        let writer = CassandraWriter::new(
+            runtime,
+            session,
+            self.table_name()?.to_string(),
+            &data_format.value_fields_vec(py),
+            key_field_names,
+            self.max_batch_size,
+        )
+        .map_err(|e| PyValueError::new_err(format!("Failed to create Cassandra writer: {e}")))?;
+        Ok(Box::new(writer))
+    }
+
     fn construct_mssql_writer(
         &self,
         py: pyo3::Python,
         data_format: &DataFormat,
         license: Option<&License>,
+        dry_run: bool,
     ) -> PyResult<Box<dyn Writer>> {
         if let Some(license) = license {
             license.check_entitlements(["mssql"])?;
@@ -7703,6 +10157,7 @@ impl DataStorage {
             self.snapshot_maintenance_on_output,
             &table_ctx,
             self.table_writer_init_mode,
+            dry_run,
         )
         .map_err(|e| PyValueError::new_err(format!("Failed to initialize MSSQL writer: {e}")))?;
 
@@ -7714,6 +10169,7 @@ impl DataStorage {
         py: pyo3::Python,
         data_format: &DataFormat,
         license: Option<&License>,
+        dry_run: bool,
     ) -> PyResult<Box<dyn Writer>> {
         if let Some(license) = license {
             license.check_entitlements(["mysql"])?;
@@ -7746,28 +10202,34 @@ impl DataStorage {
             &data_format.value_fields_vec(py),
             data_format.key_field_names.as_deref(),
             self.table_writer_init_mode,
+            dry_run,
         )
         .map_err(|e| PyValueError::new_err(format!("Failed to initialize MySQL writer: {e}")))?;
 
         Ok(Box::new(writer))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn construct_writer(
         &self,
         py: pyo3::Python,
         data_format: &DataFormat,
         license: Option<&License>,
         worker_index: usize,
+        writer_index: usize,
         sorted_output: bool,
+        dry_run: bool,
     ) -> PyResult<Box<dyn Writer>> {
         match self.storage_type.as_ref() {
-            "fs" => self.construct_fs_writer(),
-            "kafka" => self.construct_kafka_writer(),
-            "postgres" => self.construct_postgres_writer(py, data_format),
+            "fs" => self.construct_fs_writer(dry_run),
+            "kafka" => self.construct_kafka_writer(worker_index, writer_index),
+            "postgres" => self.construct_postgres_writer(py, data_format, dry_run),
             "elasticsearch" => self.construct_elasticsearch_writer(py, license),
             "chroma" => self.construct_chroma_writer(py, data_format, license),
             "weaviate" => self.construct_weaviate_writer(py, data_format, license),
+            "webhook" => self.construct_webhook_writer(py, data_format),
             "deltalake" => self.construct_deltalake_writer(py, data_format, license),
+            "lance" => self.construct_lance_writer(py, data_format),
             "mongodb" => self.construct_mongodb_writer(sorted_output),
             "qdrant" => self.construct_qdrant_writer(py, data_format, license),
             "null" => Ok(Box::new(NullWriter::new())),
@@ -7776,12 +10238,17 @@ impl DataStorage {
             "iceberg" => self.construct_iceberg_writer(py, data_format, license),
             "mqtt" => self.construct_mqtt_writer(),
             "questdb" => self.construct_questdb_writer(py, data_format, license),
-            "clickhouse" => self.construct_clickhouse_writer(py, data_format, license),
+            "clickhouse" => self.construct_clickhouse_writer(py, data_format, license, dry_run),
             "dynamodb" => self.construct_dynamodb_writer(py, data_format, license),
             "kinesis" => self.construct_kinesis_writer(license),
-            "mssql" => self.construct_mssql_writer(py, data_format, license),
-            "mysql" => self.construct_mysql_writer(py, data_format, license),
-            "sqlite" => self.construct_sqlite_writer(py, data_format),
+            "firehose" => self.construct_firehose_writer(license),
+            "pubsub" => self.construct_pubsub_writer(),
+            "eventhubs" => self.construct_eventhubs_writer(),
+            "redis" => self.construct_redis_writer(py, data_format),
+            "cassandra" => self.construct_cassandra_writer(py, data_format),
+            "mssql" => self.construct_mssql_writer(py, data_format, license, dry_run),
+            "mysql" => self.construct_mysql_writer(py, data_format, license, dry_run),
+            "sqlite" => self.construct_sqlite_writer(py, data_format, dry_run),
             "duckdb" => {
                 // Only the output-owning worker (index 0) creates/replaces the
                 // destination table on an empty output; the rest must not contend
@@ -7855,6 +10322,73 @@ impl DataFormat {
             .collect()
     }
 
+    /// Returns a copy of the format that shares the value fields with this one.
+    fn clone_ref(&self, py: pyo3::Python) -> Self {
+        Self {
+            format_type: self.format_type.clone(),
+            key_field_names: self.key_field_names.clone(),
+            value_fields: self
+                .value_fields
+                .iter()
+                .map(|field| field.clone_ref(py))
+                .collect(),
+            delimiter: self.delimiter,
+            table_name: self.table_name.clone(),
+            column_paths: self.column_paths.clone(),
+            field_absence_is_error: self.field_absence_is_error,
+            parse_utf8: self.parse_utf8,
+            debezium_db_type: self.debezium_db_type,
+            session_type: self.session_type,
+            value_field_index: self.value_field_index,
+            key_generation_policy: self.key_generation_policy,
+            schema_registry_settings: self.schema_registry_settings.clone(),
+            subject: self.subject.clone(),
+            designated_timestamp_policy: self.designated_timestamp_policy.clone(),
+            external_diff_column_index: self.external_diff_column_index,
+            timestamp_unit: self.timestamp_unit.clone(),
+            message_queue_key_field: self.message_queue_key_field.clone(),
+            with_special_fields: self.with_special_fields,
+            template: self.template.clone(),
+            template_escaping: self.template_escaping.clone(),
+            schema_evolution_policy: self.schema_evolution_policy,
+            csv_formatter_settings: self.csv_formatter_settings.clone(),
+            json_formatter_settings: self.json_formatter_settings.clone(),
+        }
+    }
+
+    /// Returns a copy of the format, in which the given metadata columns follow
+    /// the value fields, so that both the writer and the formatter are aware of them.
+    fn with_metadata_columns(
+        &self,
+        py: pyo3::Python,
+        metadata_columns: &[SinkMetadataColumn],
+    ) -> PyResult<Self> {
+        let value_field_names = self.value_field_names(py);
+        let mut value_fields: Vec<_> = self
+            .value_fields
+            .iter()
+            .map(|field| field.clone_ref(py))
+            .collect();
+        for column in metadata_columns {
+            if value_field_names.contains(&column.name) {
+                return Err(PyValueError::new_err(format!(
+                    "metadata column {:?} clashes with a column of the table",
+                    column.name
+                )));
+            }
+            let field = ValueField::new(
+                column.name.clone(),
+                column.field.type_(),
+                FieldSource::Payload,
+            );
+            value_fields.push(Py::new(py, field)?);
+        }
+        Ok(Self {
+            value_fields,
+            ..self.clone_ref(py)
+        })
+    }
+
     fn construct_dsv_settings(&self, py: pyo3::Python) -> PyResult<DsvSettings> {
         let Some(delimiter) = &self.delimiter else {
             return Err(PyValueError::new_err(
@@ -7862,11 +10396,18 @@ impl DataFormat {
             ));
         };
 
+        let dialect = self
+            .csv_formatter_settings
+            .as_ref()
+            .map(|settings| settings.dialect.clone())
+            .unwrap_or_default();
         Ok(DsvSettings::new(
             self.key_field_names.clone(),
             self.value_field_names(py),
             *delimiter,
-        ))
+        )
+        .with_schema_evolution_policy(self.schema_evolution_policy)
+        .with_dialect(dialect))
     }
 
     fn schema(&self, py: pyo3::Python) -> PyResult<HashMap<String, InnerSchemaField>> {
@@ -7932,7 +10473,8 @@ impl DataFormat {
                         .clone()
                         .map(PySchemaRegistrySettings::build_decoder)
                         .transpose()?,
-                )?;
+                )?
+                .with_schema_evolution_policy(self.schema_evolution_policy);
                 Ok(Box::new(parser))
             }
             "identity" => Ok(Box::new(IdentityParser::new(
@@ -7942,7 +10484,7 @@ impl DataFormat {
                 self.key_generation_policy,
                 self.session_type,
             ))),
-            "transparent" => Ok(Box::new(TransparentParser::new(
+            "transparent" | "orc" => Ok(Box::new(TransparentParser::new(
                 self.key_field_names.clone(),
                 self.value_field_names(py),
                 self.schema(py)?,
@@ -7979,8 +10521,16 @@ impl DataFormat {
                     } else {
                         None
                     };
-                let formatter =
-                    JsonLinesFormatter::new(self.value_field_names(py), schema_registry_settings);
+                let mut formatter =
+                    JsonLinesFormatter::new(self.value_field_names(py), schema_registry_settings)
+                        .with_special_fields(self.with_special_fields)
+                        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+                if let Some(settings) = &self.json_formatter_settings {
+                    formatter = formatter
+                        .with_field_paths(&settings.field_paths)
+                        .map_err(|e| PyValueError::new_err(e.to_string()))?
+                        .with_datetime_format(settings.datetime_format);
+                }
                 Ok(Box::new(formatter))
             }
             "null" => {
@@ -8003,6 +10553,22 @@ impl DataFormat {
                     BsonFormatter::new(self.value_field_names(py), self.with_special_fields);
                 Ok(Box::new(formatter))
             }
+            "template" => {
+                let template = self
+                    .template
+                    .as_ref()
+                    .ok_or_else(|| PyValueError::new_err("Template not specified"))?;
+                let escaping = match &self.template_escaping {
+                    Some(escaping) => escaping
+                        .parse::<TemplateEscaping>()
+                        .map_err(|e| PyValueError::new_err(e.to_string()))?,
+                    None => TemplateEscaping::None,
+                };
+                let formatter = TemplateFormatter::new(template, &self.value_field_names(py))
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+                    .with_escaping(escaping);
+                Ok(Box::new(formatter))
+            }
             _ => Err(PyValueError::new_err("Unknown data format")),
         }
     }
@@ -8101,6 +10667,16 @@ pub struct ConnectorProperties {
     synchronization_group: Option<ConnectorGroupDescriptor>,
     #[pyo3(get)]
     max_backlog_size: Option<usize>,
+    #[pyo3(get)]
+    start_from: Vec<ConnectorOffset>,
+    #[pyo3(get)]
+    max_rows_per_second: Option<u64>,
+    #[pyo3(get)]
+    max_bytes_per_second: Option<u64>,
+    #[pyo3(get)]
+    watermark_strategy: Option<PyWatermarkStrategy>,
+    #[pyo3(get)]
+    backlog_target_latency: Option<std::time::Duration>,
 }
 
 #[pymethods]
@@ -8113,7 +10689,13 @@ impl ConnectorProperties {
         unique_name = None,
         synchronization_group = None,
         max_backlog_size = None,
+        start_from = vec![],
+        max_rows_per_second = None,
+        max_bytes_per_second = None,
+        watermark_strategy = None,
+        backlog_target_latency = None,
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         commit_duration_ms: Option<u64>,
         unsafe_trusted_ids: bool,
@@ -8121,15 +10703,140 @@ impl ConnectorProperties {
         unique_name: Option<String>,
         synchronization_group: Option<ConnectorGroupDescriptor>,
         max_backlog_size: Option<usize>,
-    ) -> Self {
-        Self {
+        #[pyo3(from_py_with = from_py_iterable)] start_from: Vec<ConnectorOffset>,
+        max_rows_per_second: Option<u64>,
+        max_bytes_per_second: Option<u64>,
+        watermark_strategy: Option<PyWatermarkStrategy>,
+        backlog_target_latency: Option<std::time::Duration>,
+    ) -> PyResult<Self> {
+        if max_rows_per_second == Some(0) || max_bytes_per_second == Some(0) {
+            return Err(PyValueError::new_err(
+                "the rate limit of a connector must be positive",
+            ));
+        }
+        Ok(Self {
             commit_duration_ms,
             unsafe_trusted_ids,
             column_properties,
             unique_name,
             synchronization_group,
             max_backlog_size,
+            start_from,
+            max_rows_per_second,
+            max_bytes_per_second,
+            watermark_strategy,
+            backlog_target_latency,
+        })
+    }
+}
+
+/// When a connector commits the rows it has read besides the autocommit ticks, see
+/// [`WatermarkStrategy`].
+#[pyclass(module = "pathway.engine", frozen, name = "WatermarkStrategy")]
+#[derive(Clone)]
+pub struct PyWatermarkStrategy {
+    #[pyo3(get)]
+    time_column: Option<usize>,
+    #[pyo3(get)]
+    max_out_of_orderness: i64,
+    #[pyo3(get)]
+    idle_timeout: Option<std::time::Duration>,
+}
+
+#[pymethods]
+impl PyWatermarkStrategy {
+    #[new]
+    #[pyo3(signature = (time_column = None, max_out_of_orderness = 0, idle_timeout = None))]
+    fn new(
+        time_column: Option<usize>,
+        max_out_of_orderness: i64,
+        idle_timeout: Option<std::time::Duration>,
+    ) -> PyResult<Self> {
+        if max_out_of_orderness < 0 {
+            return Err(PyValueError::new_err(
+                "the maximum out-of-orderness can't be negative",
+            ));
+        }
+        if time_column.is_none() && idle_timeout.is_none() {
+            return Err(PyValueError::new_err(
+                "a watermark strategy needs a time column or an idle timeout",
+            ));
+        }
+        Ok(Self {
+            time_column,
+            max_out_of_orderness,
+            idle_timeout,
+        })
+    }
+}
+
+impl PyWatermarkStrategy {
+    fn build(&self) -> WatermarkStrategy {
+        let mut strategy = WatermarkStrategy::new();
+        if let Some(time_column) = self.time_column {
+            strategy = strategy.with_generator(Box::new(BoundedOutOfOrderness::new(
+                time_column,
+                self.max_out_of_orderness,
+            )));
+        }
+        if let Some(idle_timeout) = self.idle_timeout {
+            strategy = strategy.with_idle_timeout(idle_timeout);
+        }
+        strategy
+    }
+}
+
+/// A position in an input source from which a connector starts reading.
+#[pyclass(module = "pathway.engine", frozen)]
+#[derive(Clone)]
+pub struct ConnectorOffset {
+    key: OffsetKey,
+    value: OffsetValue,
+}
+
+#[pymethods]
+impl ConnectorOffset {
+    /// The offset of the first message to be read from a Kafka topic partition.
+    #[staticmethod]
+    fn kafka(topic: String, partition: i32, offset: i64) -> PyResult<Self> {
+        if offset < 0 {
+            return Err(PyValueError::new_err(format!(
+                "Kafka offset must be non-negative, got {offset}"
+            )));
+        }
+        // the frontier keeps the offset of the last message read
+        Ok(Self {
+            key: OffsetKey::Kafka(topic.into(), partition),
+            value: OffsetValue::KafkaOffset(offset - 1),
+        })
+    }
+
+    /// The position in the MySQL binary log from which the changes are read.
+    #[staticmethod]
+    fn mysql_binlog(filename: String, position: u64) -> Self {
+        Self {
+            key: OffsetKey::Mysql,
+            value: OffsetValue::MysqlBinlogPos { filename, position },
+        }
+    }
+
+    /// The SQL Server CDC log sequence number after which the changes are read.
+    #[staticmethod]
+    fn mssql_lsn(lsn: Vec<u8>) -> PyResult<Self> {
+        if lsn.len() != 10 {
+            return Err(PyValueError::new_err(format!(
+                "SQL Server LSN must be 10 bytes long, got {}",
+                lsn.len()
+            )));
         }
+        Ok(Self {
+            key: OffsetKey::Mssql,
+            value: OffsetValue::MssqlCdcLsn(lsn),
+        })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("ConnectorOffset({:?}, {:?})", self.key, self.value)
     }
 }
 
@@ -8141,6 +10848,31 @@ impl ConnectorProperties {
         self.column_properties.iter().map(|p| p.0.clone()).collect()
     }
 
+    fn start_from(&self) -> Option<OffsetAntichain> {
+        if self.start_from.is_empty() {
+            return None;
+        }
+        let mut frontier = OffsetAntichain::new();
+        for offset in &self.start_from {
+            frontier.advance_offset(offset.key.clone(), offset.value.clone());
+        }
+        Some(frontier)
+    }
+
+    fn backlog_limit(&self) -> BacklogLimit {
+        BacklogLimit {
+            max_size: self.max_backlog_size,
+            target_latency: self.backlog_target_latency,
+        }
+    }
+
+    fn rate_limit(&self) -> RateLimit {
+        RateLimit {
+            max_rows_per_second: self.max_rows_per_second,
+            max_bytes_per_second: self.max_bytes_per_second,
+        }
+    }
+
     fn max_parallel_readers(&self, scope: &Scope) -> usize {
         if self.synchronization_group.is_some() {
             // Synchronization groups rely on a mutex-guarded structure that must be queried
@@ -8323,6 +11055,36 @@ impl PyExportedTable {
     }
 }
 
+#[pyclass(module = "pathway.engine", frozen, name = "DryRunReport")]
+pub struct PyDryRunReport {
+    inner: Arc<DryRunReport>,
+}
+
+#[pymethods]
+impl PyDryRunReport {
+    #[new]
+    #[pyo3(signature = (*, check_connectors = true))]
+    fn new(check_connectors: bool) -> Self {
+        let report = if check_connectors {
+            DryRunReport::new()
+        } else {
+            DryRunReport::without_connectors()
+        };
+        Self {
+            inner: Arc::new(report),
+        }
+    }
+
+    #[getter]
+    fn ok(&self) -> bool {
+        self.inner.is_ok()
+    }
+
+    fn to_json(&self) -> String {
+        self.inner.to_json().to_string()
+    }
+}
+
 #[allow(clippy::struct_field_names)]
 struct WakeupHandler<'py> {
     _fd: OwnedFd,
@@ -8413,6 +11175,92 @@ fn check_entitlements(license_key: Option<String>, entitlements: Vec<String>) ->
     Ok(())
 }
 
+#[pyfunction]
+#[pyo3(signature = (*, timeout))]
+fn request_savepoint(py: Python, timeout: f64) -> PyResult<u64> {
+    let timeout = ::std::time::Duration::try_from_secs_f64(timeout)
+        .map_err(|e| PyValueError::new_err(format!("invalid timeout: {e}")))?;
+    match py.allow_threads(|| request_savepoint_in_process(timeout)) {
+        Ok(time) => Ok(time.0),
+        Err(error @ SavepointError::TimedOut(_)) => Err(PyTimeoutError::new_err(error.to_string())),
+        Err(error) => Err(PyRuntimeError::new_err(error.to_string())),
+    }
+}
+
+#[pyfunction]
+fn request_graceful_shutdown() -> PyResult<()> {
+    request_graceful_shutdown_in_process().map_err(|e| PyRuntimeError::new_err(e.to_string()))
+}
+
+#[pyfunction]
+fn request_suspend() -> PyResult<()> {
+    request_suspend_in_process().map_err(|e| PyRuntimeError::new_err(e.to_string()))
+}
+
+#[pyfunction]
+#[pyo3(signature = (name, value = 1))]
+fn increment_counter(name: &str, value: u64) -> PyResult<()> {
+    user_metrics::increment_counter(name, value).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+#[pyfunction]
+fn record_histogram(name: &str, value: f64) -> PyResult<()> {
+    user_metrics::record_histogram(name, value).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+#[pyfunction]
+#[pyo3(signature = (unique_name, *, command))]
+fn control_connector(unique_name: &str, command: &str) -> PyResult<String> {
+    match command
+        .parse::<ConnectorCommand>()
+        .and_then(|command| control_connector_in_process(unique_name, command))
+    {
+        Ok(state) => Ok(state.to_string()),
+        Err(error @ ConnectorControlError::Drained(_)) => {
+            Err(PyRuntimeError::new_err(error.to_string()))
+        }
+        Err(error) => Err(PyValueError::new_err(error.to_string())),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (*, backend))]
+fn migrate_persistence_metadata(py: Python, backend: DataStorage) -> PyResult<usize> {
+    let storage_config = backend.construct_persistent_storage_config()?;
+    py.allow_threads(|| migrate_metadata(storage_config.create()?.as_ref()))
+        .map_err(|e| PyIOError::new_err(format!("Failed to migrate persistence metadata: {e}")))
+}
+
+#[pyfunction]
+#[pyo3(signature = (*, at = None))]
+fn freeze_clock(at: Option<::std::time::SystemTime>) {
+    clock::freeze(at);
+}
+
+#[pyfunction]
+#[pyo3(signature = (*, speedup))]
+fn accelerate_clock(speedup: f64) -> PyResult<()> {
+    if speedup.is_finite() && speedup > 0.0 {
+        clock::accelerate(speedup);
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(
+            "speedup has to be a positive finite number",
+        ))
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (*, by))]
+fn advance_clock(by: ::std::time::Duration) {
+    clock::advance(by);
+}
+
+#[pyfunction]
+fn reset_clock() {
+    clock::reset();
+}
+
 #[pymodule]
 #[pyo3(name = "engine")]
 fn engine(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
@@ -8439,10 +11287,13 @@ fn engine(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyPythonConnectorEventType>()?;
     m.add_class::<PyDebeziumDBType>()?;
     m.add_class::<PyKeyGenerationPolicy>()?;
+    m.add_class::<PySchemaEvolutionPolicy>()?;
     m.add_class::<PyReadMethod>()?;
     m.add_class::<PyFieldSource>()?;
     m.add_class::<PyMonitoringLevel>()?;
     m.add_class::<PyTableWriterInitMode>()?;
+    m.add_class::<PyDeliverySemantics>()?;
+    m.add_class::<PyOutputMode>()?;
     m.add_class::<Universe>()?;
     m.add_class::<Column>()?;
     m.add_class::<LegacyTable>()?;
@@ -8462,6 +11313,8 @@ fn engine(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<ElasticSearchAuth>()?;
     m.add_class::<QdrantParams>()?;
     m.add_class::<CsvParserSettings>()?;
+    m.add_class::<CsvFormatterSettings>()?;
+    m.add_class::<JsonFormatterSettings>()?;
     m.add_class::<ValueField>()?;
     m.add_class::<DataStorage>()?;
     m.add_class::<DataFormat>()?;
@@ -8471,20 +11324,38 @@ fn engine(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PySnapshotAccess>()?;
     m.add_class::<PySnapshotEvent>()?;
     m.add_class::<PyConnectorGroupDescriptor>()?;
+    m.add_class::<PySinkAlignmentGroupDescriptor>()?;
+    m.add_class::<PySinkMetadataColumn>()?;
+    m.add_class::<PySortColumn>()?;
+    m.add_class::<PyFlightEndpoint>()?;
     m.add_class::<TelemetryConfig>()?;
     m.add_class::<BackfillingThreshold>()?;
     m.add_class::<PyDeltaOptimizerRule>()?;
     m.add_class::<MqttSettings>()?;
+    m.add_class::<SqsSettings>()?;
+    m.add_class::<RedisStreamSettings>()?;
+    m.add_class::<RedisKeyValueSettings>()?;
+    m.add_class::<CassandraSettings>()?;
+    m.add_class::<SqlCursorSettings>()?;
+    m.add_class::<SseSettings>()?;
+    m.add_class::<GrpcSettings>()?;
+    m.add_class::<SftpSettings>()?;
+    m.add_class::<HdfsSettings>()?;
+    m.add_class::<WebhookSettings>()?;
+    m.add_class::<LanceSettings>()?;
     m.add_class::<PySchemaRegistrySettings>()?;
     m.add_class::<IcebergCatalogSettings>()?;
     m.add_class::<PsqlReplicationSettings>()?;
 
     m.add_class::<ConnectorProperties>()?;
+    m.add_class::<ConnectorOffset>()?;
+    m.add_class::<PyWatermarkStrategy>()?;
     m.add_class::<ColumnProperties>()?;
     m.add_class::<TableProperties>()?;
     m.add_class::<Trace>()?;
     m.add_class::<Done>()?;
     m.add_class::<PyExportedTable>()?;
+    m.add_class::<PyDryRunReport>()?;
     m.add_class::<Error>()?;
     m.add_class::<Pending>()?;
 
@@ -8500,6 +11371,17 @@ fn engine(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     #[allow(clippy::unsafe_removed_from_name)] // false positive
     m.add_function(wrap_pyfunction!(unsafe_make_pointer, m)?)?;
     m.add_function(wrap_pyfunction!(check_entitlements, m)?)?;
+    m.add_function(wrap_pyfunction!(request_savepoint, m)?)?;
+    m.add_function(wrap_pyfunction!(control_connector, m)?)?;
+    m.add_function(wrap_pyfunction!(request_graceful_shutdown, m)?)?;
+    m.add_function(wrap_pyfunction!(request_suspend, m)?)?;
+    m.add_function(wrap_pyfunction!(increment_counter, m)?)?;
+    m.add_function(wrap_pyfunction!(record_histogram, m)?)?;
+    m.add_function(wrap_pyfunction!(migrate_persistence_metadata, m)?)?;
+    m.add_function(wrap_pyfunction!(freeze_clock, m)?)?;
+    m.add_function(wrap_pyfunction!(accelerate_clock, m)?)?;
+    m.add_function(wrap_pyfunction!(advance_clock, m)?)?;
+    m.add_function(wrap_pyfunction!(reset_clock, m)?)?;
     m.add_function(wrap_pyfunction!(deserialize, m)?)?;
     m.add_function(wrap_pyfunction!(serialize, m)?)?;
 
@@ -34,8 +34,13 @@ impl RetryConfig {
     }
 
     fn advance_backoff(&mut self) {
-        self.sleep_duration = self.sleep_duration.mul_f64(self.backoff_factor)
-            + rng().random_range(Duration::ZERO..self.jitter);
+        // An empty range can't be sampled, so a zero jitter is applied as is
+        let jitter = if self.jitter.is_zero() {
+            Duration::ZERO
+        } else {
+            rng().random_range(Duration::ZERO..self.jitter)
+        };
+        self.sleep_duration = self.sleep_duration.mul_f64(self.backoff_factor) + jitter;
     }
 }
 
@@ -1,16 +1,18 @@
 // Copyright © 2026 Pathway
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::UNIX_EPOCH;
+
+use crate::engine::clock;
 
 pub fn current_unix_timestamp_ms() -> u128 {
-    SystemTime::now()
+    clock::now()
         .duration_since(UNIX_EPOCH)
         .expect("Failed to get the current timestamp")
         .as_millis()
 }
 
 pub fn current_unix_timestamp_secs() -> u64 {
-    SystemTime::now()
+    clock::now()
         .duration_since(UNIX_EPOCH)
         .expect("Failed to get the current timestamp")
         .as_secs()
@@ -7,15 +7,21 @@ use std::thread;
 use std::time::Duration;
 
 use crossbeam_channel::{self as channel, Receiver};
+use deltalake::arrow::array::{Array, Int64Array, StringArray, UInt64Array};
+use deltalake::arrow::record_batch::RecordBatch;
 
-use pathway_engine::engine::error::DynError;
-use pathway_engine::engine::{report_error::ReportError, Error};
+use pathway_engine::engine::error::{DynError, Trace};
+use pathway_engine::engine::{
+    report_error::ReportError, AnyExpression, ColumnProperties, DataRow, Error, ExportedTable,
+    ExportedTableCallback, Expression, FloatExpression, IntExpression, TableProperties,
+};
+use pathway_engine::persistence::backends::PersistenceBackend;
 use pathway_engine::persistence::config::{PersistenceManagerOuterConfig, PersistentStorageConfig};
 use pathway_engine::persistence::tracker::WorkerPersistentStorage;
 
 use pathway_engine::connectors::data_format::{
     ErrorRemovalLogic, FieldSource, FormattedDocument, KeyFieldsWithErrors, ParseResult,
-    ParsedEvent, ParsedEventWithErrors, Parser, ValueFieldsWithErrors,
+    ParsedEvent, ParsedEventWithErrors, Parser, ValueField, ValueFieldsWithErrors,
 };
 use pathway_engine::connectors::data_storage::scanner::FilesystemScanner;
 use pathway_engine::connectors::data_storage::sharding::ShardSelector;
@@ -25,12 +31,12 @@ use pathway_engine::connectors::data_storage::{
 };
 use pathway_engine::connectors::data_tokenize::{BufReaderTokenizer, CsvTokenizer};
 use pathway_engine::connectors::posix_like::PosixLikeReader;
+use pathway_engine::connectors::rate_limit::RateLimit;
 use pathway_engine::connectors::{Connector, Entry, PersistenceMode, SnapshotAccess};
 use pathway_engine::engine::{Key, Timestamp, TotalFrontier, Type, Value};
 use pathway_engine::persistence::frontier::OffsetAntichain;
 use pathway_engine::persistence::input_snapshot::Event as SnapshotEvent;
 use pathway_engine::persistence::PersistentId;
-use pathway_engine::python_api::ValueField;
 
 #[derive(Debug)]
 pub struct FullReadResult {
@@ -122,6 +128,8 @@ pub fn full_cycle_read(
         &main_thread,
         &reporter,
         None,
+        RateLimit::default(),
+        None,
     );
     let result = get_entries_in_receiver(receiver);
 
@@ -464,3 +472,125 @@ pub fn new_csv_filesystem_reader(
 pub fn value_field(name: &str) -> ValueField {
     ValueField::new(name.to_string(), Type::Any, FieldSource::Payload)
 }
+
+pub fn argument(index: usize) -> Arc<Expression> {
+    Arc::new(Expression::Any(AnyExpression::Argument(index)))
+}
+
+pub fn eval_rows(expression: &Expression, rows: &[Vec<Value>]) -> Vec<Result<Value, String>> {
+    let rows: Vec<&[Value]> = rows.iter().map(Vec::as_slice).collect();
+    expression
+        .eval(&rows)
+        .into_iter()
+        .map(|result| result.map_err(|error| error.to_string()))
+        .collect()
+}
+
+pub fn column_names(names: &[&str]) -> Vec<String> {
+    names.iter().map(ToString::to_string).collect()
+}
+
+pub fn int(expression: IntExpression) -> Arc<Expression> {
+    Arc::new(Expression::Int(expression))
+}
+
+pub fn float(expression: FloatExpression) -> Arc<Expression> {
+    Arc::new(Expression::Float(expression))
+}
+
+/// An exported table with the columns `owner: str` and `age: int | None`, advanced
+/// by hand.
+pub struct TestExportedTable {
+    frontier: Mutex<TotalFrontier<Timestamp>>,
+    data: Mutex<Vec<DataRow>>,
+    consumers: Mutex<Vec<ExportedTableCallback>>,
+}
+
+impl TestExportedTable {
+    pub fn new(data: Vec<DataRow>, frontier: TotalFrontier<Timestamp>) -> Arc<Self> {
+        Arc::new(Self {
+            frontier: Mutex::new(frontier),
+            data: Mutex::new(data),
+            consumers: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn advance(&self, data: Vec<DataRow>, frontier: TotalFrontier<Timestamp>) {
+        self.data.lock().unwrap().extend(data);
+        *self.frontier.lock().unwrap() = frontier;
+        self.consumers
+            .lock()
+            .unwrap()
+            .retain_mut(|consumer| consumer().is_continue());
+    }
+}
+
+impl ExportedTable for TestExportedTable {
+    fn failed(&self) -> bool {
+        false
+    }
+
+    fn properties(&self) -> Arc<TableProperties> {
+        let column = |dtype| {
+            Arc::new(ColumnProperties {
+                dtype,
+                append_only: false,
+                trace: Arc::new(Trace::Empty),
+            })
+        };
+        Arc::new(TableProperties::flat(vec![
+            column(Type::String),
+            column(Type::Optional(Type::Int.into())),
+        ]))
+    }
+
+    fn frontier(&self) -> TotalFrontier<Timestamp> {
+        *self.frontier.lock().unwrap()
+    }
+
+    fn data_from_offset(&self, offset: usize) -> (Vec<DataRow>, usize) {
+        let data = self.data.lock().unwrap();
+        (data[offset..].to_vec(), data.len())
+    }
+
+    fn subscribe(&self, callback: ExportedTableCallback) {
+        self.consumers.lock().unwrap().push(callback);
+    }
+}
+
+/// A row of a [`TestExportedTable`].
+pub fn row(key: i64, owner: &str, age: Option<i64>, time: u64, diff: isize) -> DataRow {
+    DataRow::from_engine(
+        Key::for_value(&Value::Int(key)),
+        vec![Value::from(owner), age.map_or(Value::None, Value::Int)],
+        Timestamp(time),
+        diff,
+    )
+}
+
+pub fn put_value_blocking(backend: &dyn PersistenceBackend, key: &str, value: &[u8]) {
+    futures::executor::block_on(backend.put_value(key, value.to_vec()))
+        .unwrap()
+        .unwrap();
+}
+
+pub fn string_column(batch: &RecordBatch, column: &str) -> Vec<Option<String>> {
+    let array = batch.column_by_name(column).unwrap();
+    let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+    array
+        .iter()
+        .map(|value| value.map(str::to_string))
+        .collect()
+}
+
+pub fn int_column(batch: &RecordBatch, column: &str) -> Vec<Option<i64>> {
+    let array = batch.column_by_name(column).unwrap();
+    let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+    array.iter().collect()
+}
+
+pub fn time_column(batch: &RecordBatch) -> Vec<u64> {
+    let array = batch.column_by_name("time").unwrap();
+    let array = array.as_any().downcast_ref::<UInt64Array>().unwrap();
+    array.values().to_vec()
+}
@@ -6,34 +6,86 @@ mod helpers;
 mod operator_test_utils;
 
 mod test_arrow;
+mod test_azure_blob;
+mod test_backlog;
 mod test_bson;
 mod test_bytes;
+#[cfg(feature = "c-api")]
+mod test_c_api;
 mod test_cached_object_storage;
 mod test_clickhouse;
+mod test_clock;
+mod test_columnar;
+mod test_connector_control;
 mod test_connector_field_defaults;
 mod test_connector_sync;
 mod test_dd_distinct_total;
 mod test_debezium;
 mod test_deltalake;
+mod test_digest;
+mod test_dry_run;
 mod test_dsv;
 mod test_dsv_dir;
 mod test_dsv_output;
+mod test_encoding;
+mod test_explain;
 mod test_file_kv;
+mod test_flight_server;
 mod test_gradual_broadcast;
+mod test_grpc;
+mod test_hdfs;
+mod test_ip;
 mod test_json_output;
+mod test_json_query;
 mod test_jsonlines;
+mod test_key_collisions;
+mod test_lance;
+mod test_lifecycle;
+mod test_map;
 mod test_metadata;
 mod test_null_writer;
 mod test_offsets_storage;
 mod test_operator_persistence;
+mod test_orc;
 mod test_parser;
 mod test_parser_errors;
+mod test_persistence_gc;
+mod test_persistence_lock;
 mod test_polling;
 mod test_prev_next;
+mod test_rate_limit;
+mod test_redis;
+mod test_regex;
+mod test_savepoints;
+mod test_schema_evolution;
 mod test_seek;
+mod test_served_tables;
+mod test_sftp;
+mod test_shard_balancing;
+mod test_shared_memory_export;
+mod test_shutdown;
+mod test_sink_alignment;
+mod test_sink_commit;
+mod test_sink_metadata;
+mod test_sink_throughput;
+mod test_snapshot_encryption;
+mod test_sort_column;
 mod test_sqlite;
+mod test_sse;
+mod test_stats_poller;
+mod test_stepping;
 mod test_stream_snapshot;
+mod test_string_similarity;
+mod test_struct;
+mod test_table_gauges;
+mod test_template;
 mod test_time;
 mod test_time_column;
 mod test_types;
+mod test_url;
+mod test_user_metrics;
 mod test_value_to_sql;
+mod test_vectorized_expressions;
+mod test_watermark;
+mod test_webhook;
+mod test_websocket;
@@ -3,14 +3,14 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crossbeam_channel::{self as channel, Sender};
-use deltalake::arrow::array::RecordBatch as ArrowRecordBatch;
+use deltalake::arrow::array::{Array, RecordBatch as ArrowRecordBatch, StringArray};
 use deltalake::arrow::datatypes::TimeUnit as ArrowTimeUnit;
 use serde_json::json;
 
-use pathway_engine::connectors::data_format::{FieldSource, FormatterContext};
+use pathway_engine::connectors::data_format::{FieldSource, FormatterContext, ValueField};
 use pathway_engine::connectors::data_storage::data_lake::arrow::construct_schema as construct_arrow_schema;
 use pathway_engine::connectors::data_storage::data_lake::buffering::{
-    AppendOnlyColumnBuffer, PayloadType,
+    AppendOnlyColumnBuffer, ColumnBuffer, KeyedChangesColumnBuffer, PayloadType,
 };
 use pathway_engine::connectors::data_storage::data_lake::{
     columns_into_pathway_values, LakeBatchWriter, LakeWriterSettings, MaintenanceMode,
@@ -19,7 +19,6 @@ use pathway_engine::connectors::data_storage::{LakeWriter, WriteError, Writer};
 use pathway_engine::engine::{
     DateTimeNaive, DateTimeUtc, Duration as EngineDuration, Key, Timestamp, Type, Value,
 };
-use pathway_engine::python_api::ValueField;
 
 struct ArrowBatchWriter {
     sender: Sender<ArrowRecordBatch>,
@@ -267,3 +266,95 @@ fn test_save_any_is_unsupported() -> eyre::Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_keyed_changes_buffer() -> eyre::Result<()> {
+    let value_fields = vec![ValueField {
+        name: "value".to_string(),
+        type_: Type::String,
+        source: FieldSource::Payload,
+        default: None,
+        metadata: None,
+    }];
+    let (sender, _receiver) = channel::unbounded();
+    let batch_writer = ArrowBatchWriter::new(sender);
+    let schema = construct_arrow_schema(&value_fields, &batch_writer, MaintenanceMode::Snapshot)?;
+    let mut buffer = KeyedChangesColumnBuffer::new(Arc::new(schema))?;
+    assert!(!buffer.has_updates());
+
+    let inserted_key = Key::random();
+    let updated_key = Key::random();
+    let short_lived_key = Key::random();
+    for (key, value, diff) in [
+        (inserted_key, "a", 1),
+        (updated_key, "b", -1),
+        (updated_key, "c", 1),
+        (short_lived_key, "d", 1),
+        (short_lived_key, "d", -1),
+    ] {
+        buffer.add_event(FormatterContext::new_single_payload(
+            vec![],
+            key,
+            vec![Value::from(value)],
+            Timestamp(0),
+            diff,
+        ))?;
+    }
+    assert!(buffer.has_updates());
+
+    let (batch, payload_type) = buffer.build_update_record_batch()?;
+    assert_eq!(
+        payload_type,
+        PayloadType::KeyedChanges { n_upserted_rows: 2 }
+    );
+    let column_values = |name: &str| -> Vec<String> {
+        let column = batch.column_by_name(name).unwrap();
+        let column = column.as_any().downcast_ref::<StringArray>().unwrap();
+        (0..column.len())
+            .map(|index| column.value(index).to_string())
+            .collect()
+    };
+    let values = column_values("value");
+    let ids = column_values("_id");
+    let mut upserted: Vec<_> = values[..2]
+        .iter()
+        .cloned()
+        .zip(ids[..2].iter().cloned())
+        .collect();
+    upserted.sort();
+    let mut expected_upserted = vec![
+        ("a".to_string(), inserted_key.to_string()),
+        ("c".to_string(), updated_key.to_string()),
+    ];
+    expected_upserted.sort();
+    assert_eq!(upserted, expected_upserted);
+    assert_eq!(values[2..], ["b".to_string()]);
+    assert_eq!(ids[2..], [updated_key.to_string()]);
+
+    buffer.on_changes_written();
+    assert!(!buffer.has_updates());
+    Ok(())
+}
+
+#[test]
+fn test_keyed_changes_buffer_requires_id_field() {
+    let value_fields = vec![ValueField {
+        name: "value".to_string(),
+        type_: Type::String,
+        source: FieldSource::Payload,
+        default: None,
+        metadata: None,
+    }];
+    let (sender, _receiver) = channel::unbounded();
+    let batch_writer = ArrowBatchWriter::new(sender);
+    let schema = construct_arrow_schema(
+        &value_fields,
+        &batch_writer,
+        MaintenanceMode::StreamOfChanges,
+    )
+    .unwrap();
+    assert_matches!(
+        KeyedChangesColumnBuffer::new(Arc::new(schema)),
+        Err(WriteError::IncorrectInitialSnapshot(_))
+    );
+}
@@ -0,0 +1,94 @@
+// Copyright © 2026 Pathway
+
+use serde_json::json;
+use tempfile::tempdir;
+
+use pathway_engine::connectors::data_storage::scanner::{AzureBlobScanner, QueuedAction};
+use pathway_engine::connectors::metadata::FileLikeMetadata;
+use pathway_engine::persistence::backends::FilesystemKVStorage;
+use pathway_engine::persistence::cached_object_storage::CachedObjectStorage;
+
+fn blob(name: &str, size: u64, modified_at: u64) -> FileLikeMetadata {
+    serde_json::from_value(json!({
+        "created_at": 1,
+        "modified_at": modified_at,
+        "owner": null,
+        "path": name,
+        "size": size,
+        "seen_at": 1_000,
+    }))
+    .unwrap()
+}
+
+fn task_paths(tasks: &[FileLikeMetadata]) -> Vec<&str> {
+    tasks.iter().map(|task| task.path.as_str()).collect()
+}
+
+fn deleted_paths(actions: &[QueuedAction]) -> Vec<String> {
+    actions
+        .iter()
+        .map(|action| {
+            assert!(matches!(action, QueuedAction::Delete(_)));
+            String::from_utf8(action.path().to_vec()).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_new_blobs_are_ordered_by_modification_time() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let backend = FilesystemKVStorage::new(test_storage.path())?;
+    let storage = CachedObjectStorage::new(Box::new(backend))?;
+
+    let listing = vec![
+        blob("data/2024/03.csv", 10, 300),
+        blob("data/2024/01.csv", 10, 100),
+        blob("data/b.csv", 10, 200),
+        blob("data/a.csv", 10, 200),
+    ];
+    let (tasks, deletions) = AzureBlobScanner::plan_listing(listing, true, &storage);
+    assert_eq!(
+        task_paths(&tasks),
+        vec![
+            "data/2024/01.csv",
+            "data/a.csv",
+            "data/b.csv",
+            "data/2024/03.csv"
+        ]
+    );
+    assert!(deletions.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_read_blobs_are_tracked() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let backend = FilesystemKVStorage::new(test_storage.path())?;
+    let mut storage = CachedObjectStorage::new(Box::new(backend))?;
+    for metadata in [
+        blob("data/kept.csv", 10, 100),
+        blob("data/modified.csv", 10, 100),
+        blob("data/deleted.csv", 10, 100),
+    ] {
+        let path = metadata.path.clone();
+        storage.place_object(path.as_bytes(), b"contents", metadata)?;
+    }
+
+    let listing = vec![
+        blob("data/modified.csv", 20, 150),
+        blob("data/kept.csv", 10, 100),
+        blob("data/new.csv", 10, 120),
+    ];
+    let (tasks, deletions) = AzureBlobScanner::plan_listing(listing.clone(), true, &storage);
+    assert_eq!(
+        task_paths(&tasks),
+        vec!["data/new.csv", "data/modified.csv"]
+    );
+    assert_eq!(deleted_paths(&deletions), vec!["data/deleted.csv"]);
+
+    // Without deletions, only the blobs not read yet are downloaded
+    let (tasks, deletions) = AzureBlobScanner::plan_listing(listing, false, &storage);
+    assert_eq!(task_paths(&tasks), vec!["data/new.csv"]);
+    assert!(deletions.is_empty());
+    Ok(())
+}
@@ -0,0 +1,75 @@
+// Copyright © 2026 Pathway
+
+use std::time::{Duration, Instant};
+
+use pathway_engine::connectors::backlog::{AdaptiveBacklogLimit, BacklogTracker};
+use pathway_engine::engine::Timestamp;
+
+#[test]
+fn test_oldest_entry_age() {
+    let mut tracker = BacklogTracker::new();
+    let now = Instant::now();
+    assert_eq!(tracker.oldest_entry_age(now), None);
+
+    tracker.on_event(Timestamp(2));
+    tracker.on_event(Timestamp(2));
+    tracker.on_event(Timestamp(4));
+    assert_eq!(tracker.backlog_size(), 3);
+    let later = Instant::now() + Duration::from_secs(10);
+    assert!(tracker.oldest_entry_age(later).unwrap() >= Duration::from_secs(10));
+}
+
+#[test]
+fn test_limit_is_halved_when_latency_exceeds_target() {
+    let target_latency = Duration::from_secs(1);
+    let mut limit = AdaptiveBacklogLimit::new(target_latency);
+    let initial_limit = limit.limit();
+    let now = Instant::now();
+
+    limit.update(Some(Duration::from_secs(2)), initial_limit, now);
+    assert_eq!(limit.limit(), initial_limit / 2);
+
+    // the previous decrease needs some time to take effect
+    limit.update(Some(Duration::from_secs(2)), initial_limit, now);
+    assert_eq!(limit.limit(), initial_limit / 2);
+
+    limit.update(
+        Some(Duration::from_secs(2)),
+        initial_limit,
+        now + target_latency,
+    );
+    assert_eq!(limit.limit(), initial_limit / 4);
+}
+
+#[test]
+fn test_limit_doesnt_go_below_minimum() {
+    let target_latency = Duration::from_millis(100);
+    let mut limit = AdaptiveBacklogLimit::new(target_latency);
+    let mut now = Instant::now();
+    for _ in 0..100 {
+        limit.update(Some(Duration::from_secs(1)), 0, now);
+        now += target_latency;
+    }
+    let min_limit = limit.limit();
+    assert!(min_limit > 0);
+    limit.update(Some(Duration::from_secs(1)), 0, now);
+    assert_eq!(limit.limit(), min_limit);
+}
+
+#[test]
+fn test_limit_grows_when_reached_within_target() {
+    let target_latency = Duration::from_secs(1);
+    let mut limit = AdaptiveBacklogLimit::new(target_latency);
+    let initial_limit = limit.limit();
+    let now = Instant::now();
+
+    // the backlog is below the limit, there's no reason to raise it
+    limit.update(Some(Duration::from_millis(10)), initial_limit / 2, now);
+    assert_eq!(limit.limit(), initial_limit);
+
+    limit.update(Some(Duration::from_millis(10)), initial_limit, now);
+    assert!(limit.limit() > initial_limit);
+    let raised_limit = limit.limit();
+    limit.update(None, raised_limit, now);
+    assert!(limit.limit() > raised_limit);
+}
@@ -0,0 +1,151 @@
+// Copyright © 2026 Pathway
+
+use std::collections::HashSet;
+use std::ffi::{c_void, CStr, CString};
+use std::fs;
+use std::ptr;
+
+use serde_json::Value as JsonValue;
+use tempfile::tempdir;
+
+use pathway_engine::c_api::{
+    pw_connector_table_jsonlines, pw_expression_argument, pw_expression_binary, pw_expression_int,
+    pw_expression_table, pw_group_by_table, pw_last_error_message, pw_output_table_jsonlines,
+    pw_run, PwGraph, PwStatus, PwTable, PW_ERROR, PW_OK,
+};
+
+struct Paths {
+    input: CString,
+    output: CString,
+}
+
+macro_rules! check {
+    ($status:expr) => {
+        if $status != PW_OK {
+            return PW_ERROR;
+        }
+    };
+}
+
+unsafe extern "C" fn build_totals(graph: *const PwGraph<'_>, user_data: *mut c_void) -> PwStatus {
+    let paths = &*user_data.cast::<Paths>();
+    let input_names = [c"user".as_ptr(), c"amount".as_ptr()];
+    let input_types = [4, 2];
+    let mut orders: PwTable = 0;
+    check!(pw_connector_table_jsonlines(
+        graph,
+        paths.input.as_ptr(),
+        input_names.as_ptr(),
+        input_types.as_ptr(),
+        2,
+        false,
+        0,
+        &mut orders,
+    ));
+
+    let doubled = pw_expression_binary(2, 2, pw_expression_argument(1), pw_expression_int(2));
+    let expressions = [pw_expression_argument(0), doubled];
+    let mut projected: PwTable = 0;
+    check!(pw_expression_table(
+        graph,
+        orders,
+        [0, 1].as_ptr(),
+        2,
+        expressions.as_ptr(),
+        2,
+        &mut projected,
+    ));
+
+    // any(user), int_sum(amount), count()
+    let mut totals: PwTable = 0;
+    check!(pw_group_by_table(
+        graph,
+        projected,
+        [0].as_ptr(),
+        1,
+        [5, 1, 0].as_ptr(),
+        [0, 1, 0].as_ptr(),
+        3,
+        &mut totals,
+    ));
+
+    let output_names = [c"user".as_ptr(), c"total".as_ptr(), c"count".as_ptr()];
+    pw_output_table_jsonlines(
+        graph,
+        totals,
+        paths.output.as_ptr(),
+        output_names.as_ptr(),
+        3,
+    )
+}
+
+#[test]
+fn test_graph_built_through_c_api() {
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("orders.jsonl");
+    let output = dir.path().join("totals.jsonl");
+    fs::write(
+        &input,
+        concat!(
+            "{\"user\": \"alice\", \"amount\": 1}\n",
+            "{\"user\": \"bob\", \"amount\": 5}\n",
+            "{\"user\": \"alice\", \"amount\": 3}\n",
+        ),
+    )
+    .unwrap();
+    let mut paths = Paths {
+        input: CString::new(input.to_str().unwrap()).unwrap(),
+        output: CString::new(output.to_str().unwrap()).unwrap(),
+    };
+
+    let status = unsafe { pw_run(Some(build_totals), ptr::from_mut(&mut paths).cast()) };
+    assert_eq!(status, PW_OK);
+
+    let rows: HashSet<_> = fs::read_to_string(&output)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let row: JsonValue = serde_json::from_str(line).unwrap();
+            (
+                row["user"].as_str().unwrap().to_string(),
+                row["total"].as_i64().unwrap(),
+                row["count"].as_i64().unwrap(),
+                row["diff"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        HashSet::from([
+            ("alice".to_string(), 8, 2, 1),
+            ("bob".to_string(), 10, 1, 1),
+        ])
+    );
+}
+
+unsafe extern "C" fn build_with_unknown_table(
+    graph: *const PwGraph<'_>,
+    _user_data: *mut c_void,
+) -> PwStatus {
+    let names = [c"a".as_ptr()];
+    pw_output_table_jsonlines(graph, 7, c"/dev/null".as_ptr(), names.as_ptr(), 1)
+}
+
+#[test]
+fn test_build_error_is_reported() {
+    let status = unsafe { pw_run(Some(build_with_unknown_table), ptr::null_mut()) };
+    assert_eq!(status, PW_ERROR);
+    let message = unsafe { CStr::from_ptr(pw_last_error_message()) };
+    assert!(message.to_str().unwrap().contains("unknown table 7"));
+}
+
+#[test]
+fn test_unsupported_operator() {
+    let lhs = pw_expression_argument(0);
+    let rhs = pw_expression_argument(1);
+    // subtraction of strings
+    let expression = unsafe { pw_expression_binary(1, 4, lhs, rhs) };
+    assert!(expression.is_null());
+    let message = unsafe { CStr::from_ptr(pw_last_error_message()) };
+    assert!(message.to_str().unwrap().contains("not supported"));
+}
@@ -0,0 +1,60 @@
+// Copyright © 2026 Pathway
+
+use std::time::{Duration, SystemTime};
+
+use pathway_engine::engine::clock::{Clock, FROZEN_CLOCK_POLL_INTERVAL};
+
+#[test]
+fn test_frozen_clock() {
+    let clock = Clock::system();
+    let frozen_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+    clock.freeze(Some(frozen_at));
+    assert!(clock.is_frozen());
+    std::thread::sleep(Duration::from_millis(5));
+    assert_eq!(clock.now(), frozen_at);
+
+    clock.advance(Duration::from_secs(10));
+    assert_eq!(clock.now(), frozen_at + Duration::from_secs(10));
+
+    // the workers check a frozen clock periodically, as it may be advanced meanwhile
+    assert_eq!(
+        clock.real_duration(Duration::from_secs(60)),
+        FROZEN_CLOCK_POLL_INTERVAL
+    );
+}
+
+#[test]
+fn test_accelerated_clock() {
+    let clock = Clock::system();
+    let frozen_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+    clock.freeze(Some(frozen_at));
+    clock.accelerate(100.0);
+    assert!(!clock.is_frozen());
+    std::thread::sleep(Duration::from_millis(10));
+    // at least 10ms of real time passed, that is, at least 1s of the clock time
+    assert!(clock.now() >= frozen_at + Duration::from_secs(1));
+    assert_eq!(
+        clock.real_duration(Duration::from_secs(1)),
+        Duration::from_millis(10)
+    );
+}
+
+#[test]
+fn test_advanced_system_clock() {
+    let clock = Clock::system();
+    clock.advance(Duration::from_secs(3600));
+    assert!(clock.now() >= SystemTime::now() + Duration::from_secs(3599));
+    assert_eq!(
+        clock.real_duration(Duration::from_secs(1)),
+        Duration::from_secs(1)
+    );
+
+    clock.reset();
+    assert!(clock.now() < SystemTime::now() + Duration::from_secs(1));
+}
+
+#[test]
+#[should_panic(expected = "speedup has to be a positive finite number")]
+fn test_accelerate_rejects_nonpositive_speedup() {
+    Clock::system().accelerate(0.0);
+}
@@ -0,0 +1,225 @@
+// Copyright © 2026 Pathway
+
+use std::sync::Arc;
+
+use assert_matches::assert_matches;
+
+use pathway_engine::engine::{
+    AnyExpression, BoolExpression, ColumnPath, ColumnarBatch, ColumnarColumn, ColumnarRows, Error,
+    Expression, FloatExpression, IntExpression, Key, Value,
+};
+
+use super::helpers::{argument, float, int};
+
+fn rows() -> Vec<Vec<Value>> {
+    vec![
+        vec![
+            Value::Int(7),
+            Value::from(2.5),
+            Value::Bool(true),
+            Value::from("a"),
+            Value::Int(1),
+        ],
+        vec![
+            Value::None,
+            Value::from(-1.0),
+            Value::None,
+            Value::from("b"),
+            Value::from(1.5),
+        ],
+        vec![
+            Value::Int(-3),
+            Value::from(0.0),
+            Value::Bool(false),
+            Value::None,
+            Value::Error,
+        ],
+        vec![
+            Value::Int(0),
+            Value::None,
+            Value::Bool(false),
+            Value::from("c"),
+            Value::Int(4),
+        ],
+    ]
+}
+
+fn errors_as_strings(results: Vec<Result<Value, impl ToString>>) -> Vec<Result<Value, String>> {
+    results
+        .into_iter()
+        .map(|result| result.map_err(|error| error.to_string()))
+        .collect()
+}
+
+fn assert_same_as_rows(expression: &Expression, rows: &[Vec<Value>]) {
+    let batch = ColumnarBatch::from_rows(rows, rows[0].len());
+    let row_refs: Vec<&[Value]> = rows.iter().map(Vec::as_slice).collect();
+    assert_eq!(
+        errors_as_strings(expression.eval_columnar(&batch)),
+        errors_as_strings(expression.eval(&row_refs)),
+    );
+}
+
+#[test]
+fn test_column_storage() {
+    let rows = rows();
+    let batch = ColumnarBatch::from_rows(&rows, 5);
+    assert_eq!(batch.len(), 4);
+    assert_eq!(batch.width(), 5);
+    assert_matches!(batch.column(0), Some(ColumnarColumn::Int(_)));
+    assert_matches!(batch.column(1), Some(ColumnarColumn::Float(_)));
+    assert_matches!(batch.column(2), Some(ColumnarColumn::Bool(_)));
+    assert_matches!(batch.column(3), Some(ColumnarColumn::Values(_)));
+    assert_matches!(batch.column(4), Some(ColumnarColumn::Values(_)));
+    assert_matches!(batch.column(5), None);
+    assert_eq!(batch.value(1, 0), Value::None);
+    assert_eq!(batch.value(2, 1), Value::from(0.0));
+    assert_eq!(batch.rows(), rows.as_slice());
+}
+
+#[test]
+fn test_column_of_nones() {
+    let column = ColumnarColumn::from_values(vec![Value::None, Value::None]);
+    assert_matches!(column, ColumnarColumn::Values(_));
+    assert_eq!(column.value(1), Value::None);
+}
+
+#[test]
+fn test_eval_int_chain() {
+    // a // (a - 7) + e
+    let expression = Expression::Int(IntExpression::Add(
+        int(IntExpression::FloorDiv(
+            argument(0),
+            int(IntExpression::Sub(
+                argument(0),
+                int(IntExpression::Const(7)),
+            )),
+        )),
+        argument(4),
+    ));
+    assert_same_as_rows(&expression, &rows());
+}
+
+#[test]
+fn test_eval_float_chain() {
+    // b / b + a
+    let expression = Expression::Float(FloatExpression::Add(
+        float(FloatExpression::TrueDiv(argument(1), argument(1))),
+        float(FloatExpression::CastFromInt(argument(0))),
+    ));
+    assert_same_as_rows(&expression, &rows());
+}
+
+#[test]
+fn test_eval_comparison() {
+    let expression = Expression::Bool(BoolExpression::BoolNe(
+        argument(2),
+        Arc::new(Expression::Bool(BoolExpression::FloatGt(
+            argument(1),
+            float(FloatExpression::Const(0.0)),
+        ))),
+    ));
+    assert_same_as_rows(&expression, &rows());
+}
+
+#[test]
+fn test_eval_type_mismatch() {
+    // reading a float column as an int
+    let expression = Expression::Int(IntExpression::Neg(argument(1)));
+    assert_same_as_rows(&expression, &rows());
+}
+
+#[test]
+fn test_eval_falls_back_to_rows() {
+    assert_same_as_rows(&Expression::Any(AnyExpression::Argument(3)), &rows());
+    assert_same_as_rows(&Expression::Any(AnyExpression::Argument(4)), &rows());
+    assert_same_as_rows(&Expression::Any(AnyExpression::Argument(7)), &rows());
+    let expression = Expression::Bool(BoolExpression::IsNone(argument(3)));
+    assert!(!expression.benefits_from_columnar());
+    assert_same_as_rows(&expression, &rows());
+}
+
+#[test]
+fn test_benefits_from_columnar() {
+    assert!(Expression::Int(IntExpression::Add(argument(0), argument(1))).benefits_from_columnar());
+    assert!(!Expression::Any(AnyExpression::Argument(0)).benefits_from_columnar());
+}
+
+fn columnar_rows() -> ColumnarRows<u64> {
+    let rows = rows();
+    let keys = (0..4).map(|row| Key::for_value(&Value::Int(row))).collect();
+    let times = vec![1, 1, 2, 3];
+    let diffs = vec![1, 1, -1, 1];
+    ColumnarRows::new(keys, times, diffs, ColumnarBatch::from_rows(&rows, 5))
+}
+
+#[test]
+fn test_columnar_rows_to_rows() {
+    let keys = columnar_rows().keys().to_vec();
+    let expected: Vec<_> = rows()
+        .into_iter()
+        .zip(keys)
+        .zip([(1, 1), (1, 1), (2, -1), (3, 1)])
+        .map(|((values, key), (time, diff))| ((key, Value::Tuple(values.into())), time, diff))
+        .collect();
+    assert_eq!(columnar_rows().into_rows().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_columnar_rows_extract() {
+    let columnar = columnar_rows();
+    let batch = columnar
+        .extract(&[
+            ColumnPath::ValuePath(vec![1]),
+            ColumnPath::Key,
+            ColumnPath::ValuePath(vec![]),
+        ])
+        .unwrap();
+    assert_eq!(batch.len(), 4);
+    // a column of the values is shared with the rows
+    assert_matches!(batch.column(0), Some(ColumnarColumn::Float(_)));
+    for (row, values) in rows().into_iter().enumerate() {
+        assert_eq!(batch.value(row, 0), values[1]);
+        assert_eq!(batch.value(row, 1), Value::from(columnar.keys()[row]));
+        assert_eq!(batch.value(row, 2), Value::Tuple(values.into()));
+    }
+    assert_matches!(
+        columnar.extract(&[ColumnPath::ValuePath(vec![5])]),
+        Err(Error::InvalidColumnPath(_))
+    );
+}
+
+#[test]
+fn test_columnar_rows_with_results() {
+    let columnar = columnar_rows();
+    let args = columnar
+        .extract(&[
+            ColumnPath::ValuePath(vec![4]),
+            ColumnPath::ValuePath(vec![3]),
+        ])
+        .unwrap();
+    let batches = args.split(3);
+    assert_eq!(
+        batches.iter().map(ColumnarBatch::len).collect::<Vec<_>>(),
+        [3, 1]
+    );
+    let expression = Expression::Any(AnyExpression::Argument(1));
+    let results: Vec<Value> = batches
+        .iter()
+        .flat_map(|batch| expression.eval_columnar(batch))
+        .map(Result::unwrap)
+        .collect();
+    let columnar = columnar.with_columns(ColumnarBatch::from_columns(
+        4,
+        vec![ColumnarColumn::from_values(results)],
+    ));
+    let values: Vec<_> = columnar
+        .into_rows()
+        .map(|((_key, values), _time, _diff)| values)
+        .collect();
+    let expected: Vec<_> = rows()
+        .into_iter()
+        .map(|values| Value::from(vec![values[3].clone()]))
+        .collect();
+    assert_eq!(values, expected);
+}
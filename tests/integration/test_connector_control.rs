@@ -0,0 +1,102 @@
+// Copyright © 2026 Pathway
+
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use pathway_engine::connectors::control::{
+    connector_states, control_connector, register_connector_control, ConnectorCommand,
+    ConnectorControl, ConnectorControlError, ConnectorState,
+};
+
+#[test]
+fn test_command_parsing() {
+    assert_eq!("pause".parse(), Ok(ConnectorCommand::Pause));
+    assert_eq!("resume".parse(), Ok(ConnectorCommand::Resume));
+    assert_eq!("drain".parse(), Ok(ConnectorCommand::Drain));
+    assert_eq!(
+        "stop".parse::<ConnectorCommand>(),
+        Err(ConnectorControlError::UnknownCommand("stop".to_string()))
+    );
+}
+
+#[test]
+fn test_commands_apply_to_all_readers() {
+    let name = "test_commands_apply_to_all_readers".to_string();
+    let readers = [
+        Arc::new(ConnectorControl::new()),
+        Arc::new(ConnectorControl::new()),
+    ];
+    for reader in &readers {
+        register_connector_control(&name, reader);
+    }
+
+    assert_eq!(
+        control_connector(&name, ConnectorCommand::Pause),
+        Ok(ConnectorState::Paused)
+    );
+    assert!(readers
+        .iter()
+        .all(|reader| reader.state() == ConnectorState::Paused));
+    assert_eq!(connector_states().get(&name), Some(&ConnectorState::Paused));
+
+    assert_eq!(
+        control_connector(&name, ConnectorCommand::Resume),
+        Ok(ConnectorState::Running)
+    );
+    assert!(readers
+        .iter()
+        .all(|reader| reader.state() == ConnectorState::Running));
+}
+
+#[test]
+fn test_drained_connector_cant_be_resumed() {
+    let name = "test_drained_connector_cant_be_resumed".to_string();
+    let reader = Arc::new(ConnectorControl::new());
+    register_connector_control(&name, &reader);
+
+    assert_eq!(
+        control_connector(&name, ConnectorCommand::Drain),
+        Ok(ConnectorState::Drained)
+    );
+    assert_eq!(
+        control_connector(&name, ConnectorCommand::Resume),
+        Err(ConnectorControlError::Drained(name.clone()))
+    );
+    assert!(reader.wait_until_resumed());
+}
+
+#[test]
+fn test_unknown_connector() {
+    let name = "test_unknown_connector".to_string();
+    assert_eq!(
+        control_connector(&name, ConnectorCommand::Pause),
+        Err(ConnectorControlError::UnknownConnector(name.clone()))
+    );
+
+    // the connectors are forgotten once their readers are gone
+    let reader = Arc::new(ConnectorControl::new());
+    register_connector_control(&name, &reader);
+    drop(reader);
+    assert_eq!(
+        control_connector(&name, ConnectorCommand::Pause),
+        Err(ConnectorControlError::UnknownConnector(name.clone()))
+    );
+}
+
+#[test]
+fn test_paused_reader_waits_for_resume() {
+    let name = "test_paused_reader_waits_for_resume".to_string();
+    let reader = Arc::new(ConnectorControl::new());
+    register_connector_control(&name, &reader);
+    assert!(!reader.wait_until_resumed());
+
+    control_connector(&name, ConnectorCommand::Pause).unwrap();
+    let waiting_reader = reader.clone();
+    let handle = thread::spawn(move || waiting_reader.wait_until_resumed());
+    thread::sleep(Duration::from_millis(100));
+    assert!(!handle.is_finished());
+
+    control_connector(&name, ConnectorCommand::Drain).unwrap();
+    assert!(handle.join().unwrap());
+}
@@ -12,6 +12,7 @@ use tempfile::tempdir;
 
 use pathway_engine::connectors::data_format::{
     FieldSource, Formatter, IdentityFormatter, InnerSchemaField, ParsedEvent, TransparentParser,
+    ValueField,
 };
 use pathway_engine::connectors::data_storage::data_lake::arrow::construct_schema as construct_arrow_schema;
 use pathway_engine::connectors::data_storage::data_lake::buffering::{
@@ -31,7 +32,6 @@ use pathway_engine::engine::{
     DateTimeNaive, DateTimeUtc, Duration, Duration as EngineDuration, Key, Result, Timestamp, Type,
     Value,
 };
-use pathway_engine::python_api::ValueField;
 
 use crate::helpers::read_data_from_reader;
 
@@ -0,0 +1,54 @@
+// Copyright © 2026 Pathway
+
+use pathway_engine::engine::expression::digest::digest;
+use pathway_engine::engine::expression::encoding::hex_encode;
+
+fn hex_digest(value: &[u8], algorithm: &str) -> eyre::Result<String> {
+    Ok(hex_encode(&digest(value, algorithm)?))
+}
+
+#[test]
+fn test_sha256() -> eyre::Result<()> {
+    assert_eq!(
+        hex_digest(b"", "sha256")?,
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        hex_digest(b"alice", "sha256")?,
+        "2bd806c97f0e00af1a1fc3328fa763a9269723c8db8fac4f93af71db186d6e90"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_md5() -> eyre::Result<()> {
+    assert_eq!(hex_digest(b"", "md5")?, "d41d8cd98f00b204e9800998ecf8427e");
+    assert_eq!(
+        hex_digest(b"bob", "md5")?,
+        "9f9d51bc70ef21ca5c14f307980a29d8"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_blake3() -> eyre::Result<()> {
+    assert_eq!(
+        hex_digest(b"", "blake3")?,
+        "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_xxh3() -> eyre::Result<()> {
+    assert_eq!(hex_digest(b"", "xxh3")?, "2d06800538d394c2");
+    assert_eq!(digest(b"alice", "xxh3")?.len(), 8);
+    assert_ne!(digest(b"alice", "xxh3")?, digest(b"bob", "xxh3")?);
+    Ok(())
+}
+
+#[test]
+fn test_unknown_algorithm() {
+    assert!(digest(b"alice", "sha1").is_err());
+    assert!(digest(b"alice", "SHA256").is_err());
+}
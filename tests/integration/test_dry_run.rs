@@ -0,0 +1,92 @@
+// Copyright © 2026 Pathway
+
+use serde_json::json;
+
+use pathway_engine::engine::dry_run::{ConnectorCheck, ConnectorDirection, DryRunReport};
+use pathway_engine::engine::explain::OperatorPlan;
+
+fn check(
+    worker_index: usize,
+    direction: ConnectorDirection,
+    error: Option<&str>,
+) -> ConnectorCheck {
+    ConnectorCheck {
+        worker_index,
+        direction,
+        name: format!("{direction:?}-{worker_index}"),
+        error: error.map(str::to_string),
+    }
+}
+
+#[test]
+fn test_empty_report() {
+    let report = DryRunReport::new();
+    assert!(report.is_ok());
+    assert_eq!(
+        report.to_json(),
+        json!({"ok": true, "connectors": [], "operators": []})
+    );
+}
+
+#[test]
+fn test_connectors_ordered_by_worker() {
+    let report = DryRunReport::new();
+    report.record_connector(check(1, ConnectorDirection::Input, None));
+    report.record_connector(check(0, ConnectorDirection::Input, None));
+    report.record_connector(check(1, ConnectorDirection::Output, None));
+    report.record_connector(check(0, ConnectorDirection::Output, None));
+    assert!(report.is_ok());
+    assert_eq!(
+        report.connectors(),
+        vec![
+            check(0, ConnectorDirection::Input, None),
+            check(0, ConnectorDirection::Output, None),
+            check(1, ConnectorDirection::Input, None),
+            check(1, ConnectorDirection::Output, None),
+        ]
+    );
+}
+
+#[test]
+fn test_failed_connector() {
+    let report = DryRunReport::new();
+    report.record_connector(check(0, ConnectorDirection::Input, None));
+    report.record_connector(check(
+        0,
+        ConnectorDirection::Output,
+        Some("connection refused"),
+    ));
+    assert!(!report.is_ok());
+    assert_eq!(
+        report.to_json(),
+        json!({
+            "ok": false,
+            "connectors": [
+                {"worker_index": 0, "direction": "input", "name": "Input-0", "error": null},
+                {
+                    "worker_index": 0,
+                    "direction": "output",
+                    "name": "Output-0",
+                    "error": "connection refused",
+                },
+            ],
+            "operators": [],
+        })
+    );
+}
+
+#[test]
+fn test_operators() {
+    let report = DryRunReport::new();
+    report.record_operators(vec![OperatorPlan {
+        operator_id: 4,
+        exchange: true,
+        arrangement: true,
+        persisted: false,
+    }]);
+    assert!(report.is_ok());
+    assert_eq!(
+        report.to_json()["operators"],
+        json!([{"operator_id": 4, "exchange": true, "arrangement": true, "persisted": false}])
+    );
+}
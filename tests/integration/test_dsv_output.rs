@@ -1,16 +1,20 @@
 // Copyright © 2026 Pathway
 
+use std::collections::BTreeMap;
 use std::iter::zip;
 
+use arcstr::ArcStr;
 use assert_matches::assert_matches;
 
 use pathway_engine::connectors::data_format::{
-    DsvFormatter, DsvSettings, Formatter, FormatterError,
+    DsvDialect, DsvFormatter, DsvParser, DsvQuoting, DsvSettings, Formatter, FormatterError,
+    InnerSchemaField, ParseError, ParsedEvent, Parser,
 };
+use pathway_engine::connectors::data_storage::{DataEventType, ReaderContext};
 use pathway_engine::engine::Value;
-use pathway_engine::engine::{Key, Timestamp};
+use pathway_engine::engine::{Key, Timestamp, Type};
 
-use super::helpers::assert_document_raw_byte_contents;
+use super::helpers::{assert_document_raw_byte_contents, ReplaceErrors};
 
 #[test]
 fn test_dsv_format_ok() -> eyre::Result<()> {
@@ -62,3 +66,285 @@ fn test_dsv_columns_mismatch() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_dsv_format_batch_ok() -> eyre::Result<()> {
+    let mut formatter = DsvFormatter::new(DsvSettings::new(
+        Some(vec!["a".to_string()]),
+        vec!["b".to_string(), "c".to_string()],
+        ';',
+    ));
+
+    let first_values = [Value::from("x \"quoted\""), Value::Int(1)];
+    let second_values = [Value::from("multi\nline"), Value::None];
+    let entries = [
+        (Key::for_value(&Value::from("1")), &first_values[..], 1),
+        (Key::for_value(&Value::from("2")), &second_values[..], -1),
+    ];
+    let result = formatter.format_batch(&entries, Timestamp(2))?;
+
+    let target_payloads = vec![
+        vec![
+            b"\"b\";\"c\";\"time\";\"diff\"".to_vec(),
+            b"\"x \"\"quoted\"\"\";\"1\";\"2\";\"1\"".to_vec(),
+        ],
+        vec![b"\"multi\nline\";\"None\";\"2\";\"-1\"".to_vec()],
+    ];
+    assert_eq!(result.len(), target_payloads.len());
+    for ((context, target_payloads), (key, values, diff)) in
+        zip(zip(result, target_payloads), entries)
+    {
+        assert_eq!(context.key, key);
+        assert_eq!(context.values, values);
+        assert_eq!(context.diff, diff);
+        assert_eq!(context.time, Timestamp(2));
+        assert_eq!(context.payloads.len(), target_payloads.len());
+        for (result_payload, target_payload) in zip(context.payloads, target_payloads) {
+            assert_document_raw_byte_contents(&result_payload, &target_payload);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_dsv_format_batch_matches_format() -> eyre::Result<()> {
+    let settings = || {
+        DsvSettings::new(
+            None,
+            vec!["b".to_string(), "c".to_string(), "d".to_string()],
+            ',',
+        )
+    };
+    let mut row_formatter = DsvFormatter::new(settings());
+    let mut batch_formatter = DsvFormatter::new(settings());
+
+    let rows = [
+        [Value::from(""), Value::Float(1.5), Value::Bool(false)],
+        [
+            Value::from("a,b"),
+            Value::Int(-3),
+            Value::Bytes(b"\x00\x01".as_slice().into()),
+        ],
+    ];
+    let entries: Vec<_> = rows
+        .iter()
+        .enumerate()
+        .map(|(index, values)| {
+            let index = i64::try_from(index).unwrap();
+            (Key::for_value(&Value::Int(index)), &values[..], 1)
+        })
+        .collect();
+
+    let batch_result = batch_formatter.format_batch(&entries, Timestamp(4))?;
+    assert_eq!(batch_result.len(), entries.len());
+    for (context, (key, values, diff)) in zip(batch_result, &entries) {
+        let row_context = row_formatter.format(key, values, Timestamp(4), *diff)?;
+        assert_eq!(context.payloads.len(), row_context.payloads.len());
+        for (batch_payload, row_payload) in zip(context.payloads, row_context.payloads) {
+            assert_eq!(
+                batch_payload.into_raw_bytes()?,
+                row_payload.into_raw_bytes()?
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_dsv_format_batch_columns_mismatch() -> eyre::Result<()> {
+    let mut formatter = DsvFormatter::new(DsvSettings::new(
+        None,
+        vec!["b".to_string(), "c".to_string()],
+        ';',
+    ));
+
+    let values = [Value::from("x")];
+    let result = formatter.format_batch(
+        &[(Key::for_value(&Value::from("1")), &values[..], 1)],
+        Timestamp(0),
+    );
+    assert_matches!(result, Err(FormatterError::ColumnsValuesCountMismatch));
+
+    Ok(())
+}
+
+fn format_with_dialect(dialect: DsvDialect, separator: char) -> eyre::Result<Vec<Vec<u8>>> {
+    let mut formatter = DsvFormatter::new(
+        DsvSettings::new(
+            None,
+            vec!["name".to_string(), "score".to_string(), "note".to_string()],
+            separator,
+        )
+        .with_dialect(dialect),
+    );
+    let first_values = [Value::from("Alice"), Value::Int(10), Value::from("a \"b\"")];
+    let second_values = [Value::from("Bob"), Value::Float(1.5), Value::None];
+    let entries = [
+        (Key::for_value(&Value::from("1")), &first_values[..], 1),
+        (Key::for_value(&Value::from("2")), &second_values[..], -1),
+    ];
+    let mut payloads = Vec::new();
+    for context in formatter.format_batch(&entries, Timestamp(2))? {
+        for payload in context.payloads {
+            payloads.push(payload.into_raw_bytes()?);
+        }
+    }
+    Ok(payloads)
+}
+
+#[test]
+fn test_dsv_format_necessary_quoting() -> eyre::Result<()> {
+    let payloads = format_with_dialect(
+        DsvDialect {
+            quoting: DsvQuoting::Necessary,
+            null_value: Some(String::new()),
+            ..Default::default()
+        },
+        ',',
+    )?;
+    assert_eq!(
+        payloads,
+        vec![
+            b"name,score,note,time,diff".to_vec(),
+            b"Alice,10,\"a \"\"b\"\"\",2,1".to_vec(),
+            b"Bob,1.5,,2,-1".to_vec(),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dsv_format_non_numeric_quoting_with_escape() -> eyre::Result<()> {
+    let payloads = format_with_dialect(
+        DsvDialect {
+            quoting: DsvQuoting::NonNumeric,
+            escape: Some(b'\\'),
+            write_header: false,
+            null_value: None,
+        },
+        ';',
+    )?;
+    assert_eq!(
+        payloads,
+        vec![
+            b"\"Alice\";10;\"a \\\"b\\\"\";2;1".to_vec(),
+            b"\"Bob\";1.5;\"None\";2;-1".to_vec(),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dsv_format_without_quoting() -> eyre::Result<()> {
+    let payloads = format_with_dialect(
+        DsvDialect {
+            quoting: DsvQuoting::Never,
+            escape: Some(b'\\'),
+            write_header: false,
+            null_value: Some("\\N".to_string()),
+        },
+        ' ',
+    )?;
+    assert_eq!(
+        payloads,
+        vec![
+            b"Alice 10 a\\ \"b\" 2 1".to_vec(),
+            b"Bob 1.5 \\N 2 -1".to_vec(),
+        ]
+    );
+
+    let result = format_with_dialect(
+        DsvDialect {
+            quoting: DsvQuoting::Never,
+            ..Default::default()
+        },
+        ' ',
+    );
+    let error = result.unwrap_err().downcast::<FormatterError>()?;
+    assert_matches!(error, FormatterError::UnescapedCsvField(field) if field == "a \"b\"");
+    Ok(())
+}
+
+/// Writes the value as a single column with both the row-wise and the columnar
+/// formatter and checks that a parser expecting `type_` reads it back.
+fn write_and_read(value: Value, type_: Type) -> eyre::Result<()> {
+    let settings = |columns: &[&str]| {
+        let columns = columns.iter().map(ToString::to_string).collect();
+        DsvSettings::new(None, columns, ',')
+    };
+    let values = [value.clone()];
+    let key = Key::for_value(&Value::from("1"));
+    let row_payloads = DsvFormatter::new(settings(&["value"]))
+        .format(&key, &values, Timestamp(2), 1)?
+        .payloads;
+    let batch_payloads = DsvFormatter::new(settings(&["value"]))
+        .format_batch(&[(key, &values[..], 1)], Timestamp(2))?
+        .remove(0)
+        .payloads;
+
+    for payloads in [row_payloads, batch_payloads] {
+        let mut raw_rows = Vec::new();
+        for payload in payloads {
+            raw_rows.extend(payload.into_raw_bytes()?);
+            raw_rows.push(b'\n');
+        }
+        let mut parser = DsvParser::new(
+            settings(&["value", "time", "diff"]),
+            [
+                (
+                    "value".to_string(),
+                    InnerSchemaField::new(type_.clone(), None),
+                ),
+                ("time".to_string(), InnerSchemaField::new(Type::Int, None)),
+                ("diff".to_string(), InnerSchemaField::new(Type::Int, None)),
+            ]
+            .into(),
+        )?;
+        let mut parsed_events = Vec::new();
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(raw_rows.as_slice());
+        for record in csv_reader.records() {
+            let context = ReaderContext::from_csv_record(DataEventType::Insert, record?);
+            for event in parser.parse(&context).map_err(ParseError::from)? {
+                parsed_events.push(event.replace_errors());
+            }
+        }
+        assert_eq!(
+            parsed_events,
+            vec![ParsedEvent::Insert((
+                None,
+                vec![value.clone(), Value::Int(2), Value::Int(1)]
+            ))]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dsv_map_roundtrip() -> eyre::Result<()> {
+    let value = Value::from(BTreeMap::from([
+        (ArcStr::from("one, two"), Value::Int(12)),
+        (ArcStr::from("quoted \"key\""), Value::Int(-1)),
+    ]));
+    write_and_read(value, Type::Map(Type::Int.into()))?;
+    write_and_read(Value::from(BTreeMap::new()), Type::Map(Type::Int.into()))
+}
+
+#[test]
+fn test_dsv_struct_roundtrip() -> eyre::Result<()> {
+    let value = Value::from(vec![
+        (ArcStr::from("name"), Value::from("Alice, \"the\" first")),
+        (ArcStr::from("age"), Value::Int(30)),
+    ]);
+    let type_ = Type::Struct(
+        vec![
+            (ArcStr::from("name"), Type::String),
+            (ArcStr::from("age"), Type::Int),
+        ]
+        .into(),
+    );
+    write_and_read(value, type_)
+}
@@ -0,0 +1,53 @@
+// Copyright © 2026 Pathway
+
+use pathway_engine::engine::expression::encoding::{
+    base64_decode, base64_encode, hex_decode, hex_encode, url_decode, url_encode,
+};
+
+#[test]
+fn test_base64() -> eyre::Result<()> {
+    assert_eq!(base64_encode(b"Pathway"), "UGF0aHdheQ==");
+    assert_eq!(base64_encode(b""), "");
+    assert_eq!(base64_encode(&[0xff, 0x00]), "/wA=");
+    assert_eq!(base64_decode("UGF0aHdheQ==")?, b"Pathway");
+    assert_eq!(base64_decode("/wA=")?, [0xff, 0x00]);
+    assert!(base64_decode("UGF0aHdheQ").is_err());
+    assert!(base64_decode("not base64!").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_hex() -> eyre::Result<()> {
+    assert_eq!(hex_encode(b"a+b"), "612b62");
+    assert_eq!(hex_encode(&[0xca, 0xfe]), "cafe");
+    assert_eq!(hex_decode("CAFE")?, [0xca, 0xfe]);
+    assert_eq!(hex_decode("cafe")?, [0xca, 0xfe]);
+    assert_eq!(hex_decode("")?, b"");
+    assert!(hex_decode("caf").is_err());
+    assert!(hex_decode("zz").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_url_encode() {
+    assert_eq!(url_encode(b"a+b=c d"), "a%2Bb%3Dc%20d");
+    assert_eq!(url_encode(b"AZaz09-._~"), "AZaz09-._~");
+    assert_eq!(
+        url_encode("zażółć".as_bytes()),
+        "za%C5%BC%C3%B3%C5%82%C4%87"
+    );
+    assert_eq!(url_encode(&[0xff]), "%FF");
+}
+
+#[test]
+fn test_url_decode() -> eyre::Result<()> {
+    assert_eq!(url_decode("a%2Bb%3dc%20d")?, "a+b=c d");
+    assert_eq!(url_decode("a+b")?, "a+b");
+    assert_eq!(url_decode("za%C5%BC%C3%B3%C5%82%C4%87")?, "zażółć");
+    assert!(url_decode("100%").is_err());
+    assert!(url_decode("%2").is_err());
+    assert!(url_decode("%+1").is_err());
+    assert!(url_decode("%zz").is_err());
+    assert!(url_decode("%FF").is_err());
+    Ok(())
+}
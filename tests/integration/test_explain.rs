@@ -0,0 +1,76 @@
+// Copyright © 2026 Pathway
+
+use pathway_engine::engine::explain::{ExplainPlan, OperatorPlan};
+
+#[test]
+fn test_operators_are_reported_in_order() {
+    let mut plan = ExplainPlan::new();
+    plan.record_operator(7);
+    plan.record_exchange(3);
+    plan.record_operator(5);
+    assert_eq!(
+        plan.operators(),
+        vec![
+            OperatorPlan {
+                operator_id: 3,
+                exchange: true,
+                arrangement: false,
+                persisted: false,
+            },
+            OperatorPlan {
+                operator_id: 5,
+                ..Default::default()
+            },
+            OperatorPlan {
+                operator_id: 7,
+                ..Default::default()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_arrangement_implies_exchange() {
+    let mut plan = ExplainPlan::new();
+    plan.record_operator(1);
+    plan.record_arrangement(1);
+    plan.record_persistence(1);
+    // recording the operator again doesn't reset it
+    plan.record_operator(1);
+    assert_eq!(
+        plan.operators(),
+        vec![OperatorPlan {
+            operator_id: 1,
+            exchange: true,
+            arrangement: true,
+            persisted: true,
+        }]
+    );
+}
+
+#[test]
+fn test_merge() {
+    let mut outer = ExplainPlan::new();
+    outer.record_exchange(1);
+    let mut inner = ExplainPlan::new();
+    inner.record_persistence(1);
+    inner.record_arrangement(2);
+    outer.merge(&inner);
+    assert_eq!(
+        outer.operators(),
+        vec![
+            OperatorPlan {
+                operator_id: 1,
+                exchange: true,
+                arrangement: false,
+                persisted: true,
+            },
+            OperatorPlan {
+                operator_id: 2,
+                exchange: true,
+                arrangement: true,
+                persisted: false,
+            },
+        ]
+    );
+}
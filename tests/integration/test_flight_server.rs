@@ -0,0 +1,210 @@
+// Copyright © 2026 Pathway
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use arrow_flight::{FlightClient, FlightDescriptor, Ticket};
+use assert_matches::assert_matches;
+use deltalake::arrow::record_batch::RecordBatch;
+use futures::{StreamExt, TryStreamExt};
+use tonic::transport::Channel;
+
+use pathway_engine::engine::flight_server::register_flight_table;
+use pathway_engine::engine::{
+    Error, ExportedTable, FlightEndpoint, Key, Timestamp, TotalFrontier, Value,
+};
+
+use super::helpers::{
+    column_names, int_column, row, string_column, time_column, TestExportedTable,
+};
+
+fn serve(
+    address: SocketAddr,
+    name: &str,
+    worker_index: usize,
+    table: &Arc<TestExportedTable>,
+) -> Arc<dyn ExportedTable> {
+    let table: Arc<dyn ExportedTable> = table.clone();
+    let endpoint = FlightEndpoint {
+        address,
+        name: name.to_string(),
+        column_names: column_names(&["owner", "age"]),
+    };
+    register_flight_table(endpoint, worker_index, 1, &table).expect("registration should succeed");
+    table
+}
+
+fn run<T>(future: impl std::future::Future<Output = T>) -> T {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+async fn connect(address: SocketAddr) -> FlightClient {
+    let channel = Channel::from_shared(format!("http://{address}"))
+        .unwrap()
+        .connect()
+        .await
+        .expect("connecting to the server should succeed");
+    FlightClient::new(channel)
+}
+
+#[test]
+fn test_snapshot_of_finished_table() {
+    let address: SocketAddr = "127.0.0.1:28815".parse().unwrap();
+    let table = TestExportedTable::new(
+        vec![
+            row(1, "Alice", Some(3), 0, 1),
+            row(2, "Bob", Some(5), 0, 1),
+            row(2, "Bob", Some(5), 2, -1),
+            row(2, "Bob", Some(6), 2, 1),
+            row(3, "Carol", None, 4, 1),
+        ],
+        TotalFrontier::Done,
+    );
+    let _table = serve(address, "finished", 0, &table);
+
+    let batches: Vec<RecordBatch> = run(async {
+        let mut client = connect(address).await;
+        let stream = client.do_get(Ticket::new("finished")).await.unwrap();
+        stream.try_collect().await.unwrap()
+    });
+    assert_eq!(batches.len(), 1);
+    let batch = &batches[0];
+    assert_eq!(batch.num_rows(), 3);
+    let mut rows: Vec<_> = string_column(batch, "owner")
+        .into_iter()
+        .zip(int_column(batch, "age"))
+        .zip(time_column(batch))
+        .collect();
+    rows.sort();
+    assert_eq!(
+        rows,
+        vec![
+            ((Some("Alice".to_string()), Some(3)), 0),
+            ((Some("Bob".to_string()), Some(6)), 2),
+            ((Some("Carol".to_string()), None), 4),
+        ]
+    );
+    assert_eq!(int_column(batch, "diff"), vec![Some(1); 3]);
+    assert!(string_column(batch, "id").contains(&Some(Key::for_value(&Value::Int(1)).to_string())));
+}
+
+#[test]
+fn test_changes_are_streamed_per_frontier() {
+    let address: SocketAddr = "127.0.0.1:28816".parse().unwrap();
+    let table = TestExportedTable::new(
+        vec![row(1, "Alice", Some(3), 0, 1), row(2, "Bob", Some(5), 2, 1)],
+        TotalFrontier::At(Timestamp(2)),
+    );
+    let _table = serve(address, "changes", 0, &table);
+
+    run(async {
+        let mut client = connect(address).await;
+        let mut stream = client.do_get(Ticket::new("changes")).await.unwrap();
+
+        let snapshot = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            string_column(&snapshot, "owner"),
+            vec![Some("Alice".to_string())]
+        );
+
+        table.advance(
+            vec![
+                row(1, "Alice", Some(3), 4, -1),
+                row(1, "Alice", Some(4), 4, 1),
+            ],
+            TotalFrontier::At(Timestamp(4)),
+        );
+        let changes = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            string_column(&changes, "owner"),
+            vec![Some("Bob".to_string())]
+        );
+        assert_eq!(time_column(&changes), vec![2]);
+
+        table.advance(vec![], TotalFrontier::Done);
+        let changes = stream.next().await.unwrap().unwrap();
+        assert_eq!(time_column(&changes), vec![4, 4]);
+        let mut changes: Vec<_> = int_column(&changes, "age")
+            .into_iter()
+            .zip(int_column(&changes, "diff"))
+            .collect();
+        changes.sort();
+        assert_eq!(changes, vec![(Some(3), Some(-1)), (Some(4), Some(1))]);
+
+        assert!(stream.next().await.is_none());
+    });
+}
+
+#[test]
+fn test_schema_of_served_tables() {
+    let address: SocketAddr = "127.0.0.1:28817".parse().unwrap();
+    let table = TestExportedTable::new(vec![], TotalFrontier::Done);
+    let _table = serve(address, "schema", 0, &table);
+
+    run(async {
+        let mut client = connect(address).await;
+        let schema = client
+            .get_schema(FlightDescriptor::new_path(vec!["schema".to_string()]))
+            .await
+            .unwrap();
+        let names: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect();
+        assert_eq!(names, ["id", "owner", "age", "time", "diff"]);
+
+        let flights: Vec<_> = client
+            .list_flights("")
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(flights.len(), 1);
+        assert_eq!(
+            flights[0].flight_descriptor.as_ref().unwrap().path,
+            vec!["schema".to_string()]
+        );
+
+        let missing = client
+            .get_schema(FlightDescriptor::new_path(vec!["missing".to_string()]))
+            .await;
+        assert!(missing.is_err());
+    });
+}
+
+#[test]
+fn test_registration_errors() {
+    let address: SocketAddr = "127.0.0.1:28818".parse().unwrap();
+    let table = TestExportedTable::new(vec![], TotalFrontier::Done);
+    let served = serve(address, "conflict", 0, &table);
+    let other: Arc<dyn ExportedTable> = TestExportedTable::new(vec![], TotalFrontier::Done);
+
+    let endpoint = |column_names: &[&str]| FlightEndpoint {
+        address,
+        name: "conflict".to_string(),
+        column_names: column_names.iter().map(ToString::to_string).collect(),
+    };
+    assert_matches!(
+        register_flight_table(endpoint(&["owner", "age"]), 0, 1, &other),
+        Err(Error::FlightTableNameAlreadyUsed(name)) if name == "conflict"
+    );
+    assert_matches!(
+        register_flight_table(endpoint(&["owner"]), 1, 1, &other),
+        Err(Error::FlightTableNameAlreadyUsed(_))
+    );
+    register_flight_table(endpoint(&["owner", "age"]), 1, 1, &other)
+        .expect("registering the part of another worker should succeed");
+
+    drop(served);
+    drop(table);
+    drop(other);
+    let table: Arc<dyn ExportedTable> = TestExportedTable::new(vec![], TotalFrontier::Done);
+    register_flight_table(endpoint(&["owner"]), 0, 1, &table)
+        .expect("the name should be released with the tables");
+}
@@ -0,0 +1,166 @@
+// Copyright © 2026 Pathway
+
+use prost_reflect::{DynamicMessage, Value as ProtobufValue};
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{
+    DescriptorProto, FieldDescriptorProto, FileDescriptorProto, MethodDescriptorProto,
+    ServiceDescriptorProto,
+};
+use serde_json::json;
+
+use pathway_engine::connectors::data_storage::grpc::{
+    descriptor_pool_from_files, find_server_streaming_method, message_to_json,
+};
+use pathway_engine::connectors::data_storage::{GrpcError, GrpcReader, Reader};
+use pathway_engine::connectors::{OffsetKey, OffsetValue};
+use pathway_engine::persistence::frontier::OffsetAntichain;
+
+fn field(name: &str, number: i32, type_: Type) -> FieldDescriptorProto {
+    FieldDescriptorProto {
+        name: Some(name.to_string()),
+        number: Some(number),
+        label: Some(Label::Optional as i32),
+        r#type: Some(type_ as i32),
+        ..Default::default()
+    }
+}
+
+fn messages_file() -> FileDescriptorProto {
+    FileDescriptorProto {
+        name: Some("prices/messages.proto".to_string()),
+        package: Some("prices".to_string()),
+        message_type: vec![
+            DescriptorProto {
+                name: Some("SubscribeRequest".to_string()),
+                field: vec![
+                    field("symbol", 1, Type::String),
+                    field("after_sequence", 2, Type::Int64),
+                ],
+                ..Default::default()
+            },
+            DescriptorProto {
+                name: Some("PriceUpdate".to_string()),
+                field: vec![
+                    field("sequence", 1, Type::Int64),
+                    field("symbol", 2, Type::String),
+                    field("price", 3, Type::Double),
+                ],
+                ..Default::default()
+            },
+        ],
+        syntax: Some("proto3".to_string()),
+        ..Default::default()
+    }
+}
+
+fn method(name: &str, server_streaming: bool) -> MethodDescriptorProto {
+    MethodDescriptorProto {
+        name: Some(name.to_string()),
+        input_type: Some(".prices.SubscribeRequest".to_string()),
+        output_type: Some(".prices.PriceUpdate".to_string()),
+        server_streaming: Some(server_streaming),
+        ..Default::default()
+    }
+}
+
+fn service_file() -> FileDescriptorProto {
+    FileDescriptorProto {
+        name: Some("prices/service.proto".to_string()),
+        package: Some("prices".to_string()),
+        dependency: vec!["prices/messages.proto".to_string()],
+        service: vec![ServiceDescriptorProto {
+            name: Some("Prices".to_string()),
+            method: vec![method("Subscribe", true), method("Latest", false)],
+            ..Default::default()
+        }],
+        syntax: Some("proto3".to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_descriptor_pool_adds_dependencies_first() -> eyre::Result<()> {
+    let pool = descriptor_pool_from_files(vec![service_file(), messages_file()])?;
+    let method = find_server_streaming_method(&pool, "prices.Prices/Subscribe")?;
+    assert_eq!(method.full_name(), "prices.Prices.Subscribe");
+    assert_eq!(method.input().full_name(), "prices.SubscribeRequest");
+    assert_eq!(method.output().full_name(), "prices.PriceUpdate");
+
+    // The leading slash of the method path is accepted as well
+    find_server_streaming_method(&pool, "/prices.Prices/Subscribe")?;
+    Ok(())
+}
+
+#[test]
+fn test_descriptor_pool_missing_dependency() {
+    let result = descriptor_pool_from_files(vec![service_file()]);
+    assert!(matches!(
+        result,
+        Err(GrpcError::MissingDependencies(files)) if files == ["prices/service.proto"]
+    ));
+}
+
+#[test]
+fn test_find_method_errors() -> eyre::Result<()> {
+    let pool = descriptor_pool_from_files(vec![messages_file(), service_file()])?;
+    assert!(matches!(
+        find_server_streaming_method(&pool, "prices.Prices/Latest"),
+        Err(GrpcError::NotServerStreaming(_))
+    ));
+    assert!(matches!(
+        find_server_streaming_method(&pool, "prices.Prices/History"),
+        Err(GrpcError::UnknownMethod { .. })
+    ));
+    assert!(matches!(
+        find_server_streaming_method(&pool, "prices.Quotes/Subscribe"),
+        Err(GrpcError::UnknownService(_))
+    ));
+    assert!(matches!(
+        find_server_streaming_method(&pool, "Subscribe"),
+        Err(GrpcError::InvalidMethodName(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_message_to_json_keeps_all_fields() -> eyre::Result<()> {
+    let pool = descriptor_pool_from_files(vec![messages_file(), service_file()])?;
+    let descriptor = pool
+        .get_message_by_name("prices.PriceUpdate")
+        .expect("the message must be present");
+    let mut message = DynamicMessage::new(descriptor);
+    message.set_field_by_name("sequence", ProtobufValue::I64(1 << 40));
+    message.set_field_by_name("price", ProtobufValue::F64(1.5));
+
+    assert_eq!(
+        message_to_json(&message)?,
+        json!({"sequence": 1_i64 << 40, "symbol": "", "price": 1.5})
+    );
+    Ok(())
+}
+
+fn grpc_bookmark(bookmark: Option<&str>, entries_read: usize) -> OffsetValue {
+    OffsetValue::GrpcBookmark {
+        bookmark: bookmark.map(ToString::to_string),
+        entries_read,
+    }
+}
+
+#[test]
+fn test_frontier_merge_takes_more_messages_read() {
+    let mut lhs = OffsetAntichain::new();
+    lhs.advance_offset(OffsetKey::Grpc, grpc_bookmark(Some("10"), 3));
+    let mut rhs = OffsetAntichain::new();
+    rhs.advance_offset(OffsetKey::Grpc, grpc_bookmark(Some("9"), 4));
+
+    let merged = GrpcReader::merge_two_frontiers(&lhs, &rhs);
+    assert_eq!(
+        merged.get_offset(&OffsetKey::Grpc),
+        Some(&grpc_bookmark(Some("9"), 4))
+    );
+    let merged = GrpcReader::merge_two_frontiers(&rhs, &lhs);
+    assert_eq!(
+        merged.get_offset(&OffsetKey::Grpc),
+        Some(&grpc_bookmark(Some("9"), 4))
+    );
+}
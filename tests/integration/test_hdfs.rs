@@ -0,0 +1,196 @@
+// Copyright © 2026 Pathway
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+
+use serde_json::json;
+use tempfile::tempdir;
+
+use pathway_engine::connectors::data_storage::scanner::{
+    HdfsScanner, PosixLikeScanner, QueuedAction,
+};
+use pathway_engine::persistence::backends::FilesystemKVStorage;
+use pathway_engine::persistence::cached_object_storage::CachedObjectStorage;
+
+#[test]
+fn test_webhdfs_base_url() -> eyre::Result<()> {
+    for (url, expected) in [
+        ("http://namenode:9870", "http://namenode:9870/webhdfs/v1"),
+        ("http://namenode:9870/", "http://namenode:9870/webhdfs/v1"),
+        (
+            "http://namenode:9870/webhdfs/v1/",
+            "http://namenode:9870/webhdfs/v1",
+        ),
+        ("webhdfs://namenode:9870", "http://namenode:9870/webhdfs/v1"),
+        (
+            "swebhdfs://namenode:9871",
+            "https://namenode:9871/webhdfs/v1",
+        ),
+        (
+            "https://gateway/httpfs",
+            "https://gateway/httpfs/webhdfs/v1",
+        ),
+    ] {
+        assert_eq!(HdfsScanner::webhdfs_base_url(url)?.as_str(), expected);
+    }
+    assert!(HdfsScanner::webhdfs_base_url("hdfs://namenode:8020").is_err());
+    assert!(HdfsScanner::webhdfs_base_url("namenode").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_operation_url() -> eyre::Result<()> {
+    let scanner = HdfsScanner::new(
+        "http://namenode:9870",
+        Some("etl".to_string()),
+        Some("token&value".to_string()),
+        "/landing",
+        "*",
+    )?;
+    assert_eq!(
+        scanner.operation_url("/landing/day 1/a.csv", "OPEN").as_str(),
+        "http://namenode:9870/webhdfs/v1/landing/day%201/a.csv?op=OPEN&user.name=etl&delegation=token%26value"
+    );
+    Ok(())
+}
+
+fn file_status(
+    path_suffix: &str,
+    file_type: &str,
+    length: u64,
+    modified_at_ms: u64,
+) -> serde_json::Value {
+    json!({
+        "accessTime": 0,
+        "blockSize": 134_217_728,
+        "childrenNum": 0,
+        "fileId": 16386,
+        "group": "supergroup",
+        "length": length,
+        "modificationTime": modified_at_ms,
+        "owner": "etl",
+        "pathSuffix": path_suffix,
+        "permission": "644",
+        "replication": 1,
+        "type": file_type,
+    })
+}
+
+// Serves the WebHDFS responses by the request target, one request per connection
+fn serve_webhdfs(routes: HashMap<String, (&'static str, String)>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            request.read_line(&mut request_line).unwrap();
+            loop {
+                let mut line = String::new();
+                request.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+            }
+            let target = request_line.split(' ').nth(1).unwrap().to_string();
+            let (status, body) = routes.get(&target).cloned().unwrap_or((
+                "404 Not Found",
+                json!({"RemoteException": {
+                    "exception": "FileNotFoundException",
+                    "javaClassName": "java.io.FileNotFoundException",
+                    "message": format!("File does not exist: {target}"),
+                }})
+                .to_string(),
+            ));
+            let location = if status.starts_with("307") {
+                format!("Location: http://{address}{body}\r\n")
+            } else {
+                String::new()
+            };
+            let body = if location.is_empty() {
+                body
+            } else {
+                String::new()
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Type: application/json\r\n{location}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+    format!("http://{address}")
+}
+
+#[test]
+fn test_scanner_lists_and_reads_files() -> eyre::Result<()> {
+    let routes = HashMap::from([
+        (
+            "/webhdfs/v1/landing?op=GETFILESTATUS&user.name=etl".to_string(),
+            (
+                "200 OK",
+                json!({"FileStatus": file_status("", "DIRECTORY", 0, 0)}).to_string(),
+            ),
+        ),
+        (
+            "/webhdfs/v1/landing?op=LISTSTATUS&user.name=etl".to_string(),
+            (
+                "200 OK",
+                json!({"FileStatuses": {"FileStatus": [
+                    file_status("a.csv", "FILE", 6, 2_000_000),
+                    file_status("nested", "DIRECTORY", 0, 0),
+                    file_status("a.csv._COPYING_", "FILE", 3, 500_000),
+                ]}})
+                .to_string(),
+            ),
+        ),
+        (
+            "/webhdfs/v1/landing/nested?op=LISTSTATUS&user.name=etl".to_string(),
+            (
+                "200 OK",
+                json!({"FileStatuses": {"FileStatus": [
+                    file_status("b.csv", "FILE", 4, 1_000_000),
+                ]}})
+                .to_string(),
+            ),
+        ),
+        (
+            "/webhdfs/v1/landing/a.csv?op=OPEN&user.name=etl".to_string(),
+            (
+                "307 Temporary Redirect",
+                "/datanode/a.csv?op=OPEN".to_string(),
+            ),
+        ),
+        (
+            "/datanode/a.csv?op=OPEN".to_string(),
+            ("200 OK", "a,b\n1\n".to_string()),
+        ),
+    ]);
+    let url = serve_webhdfs(routes);
+    let mut scanner = HdfsScanner::new(&url, Some("etl".to_string()), None, "/landing/", "*.csv")?;
+
+    let test_storage = tempdir()?;
+    let backend = FilesystemKVStorage::new(test_storage.path())?;
+    let storage = CachedObjectStorage::new(Box::new(backend))?;
+    let actions = scanner.next_scanner_actions(true, &storage)?;
+    let paths: Vec<_> = actions
+        .iter()
+        .map(|action| {
+            assert!(matches!(action, QueuedAction::Read(..)));
+            String::from_utf8(action.path().to_vec()).unwrap()
+        })
+        .collect();
+    assert_eq!(paths, vec!["/landing/nested/b.csv", "/landing/a.csv"]);
+    let QueuedAction::Read(_, metadata) = &actions[1] else {
+        unreachable!()
+    };
+    assert_eq!(metadata.modified_at, Some(2_000));
+    assert_eq!(metadata.size, 6);
+
+    assert_eq!(scanner.read_object(b"/landing/a.csv")?, b"a,b\n1\n");
+    assert_eq!(scanner.object_metadata(b"/landing/missing.csv")?, None);
+    Ok(())
+}
@@ -0,0 +1,83 @@
+// Copyright © 2026 Pathway
+
+use std::net::IpAddr;
+
+use pathway_engine::engine::expression::ip::{
+    address_version, canonical_address, is_in_network, network_of, parse_network,
+};
+
+fn ip(address: &str) -> IpAddr {
+    address.parse().unwrap()
+}
+
+#[test]
+fn test_canonical_address() -> eyre::Result<()> {
+    assert_eq!(canonical_address("192.168.0.1")?, "192.168.0.1");
+    assert_eq!(canonical_address(" 10.0.0.1 ")?, "10.0.0.1");
+    assert_eq!(canonical_address("2001:DB8:0:0:0:0:0:1")?, "2001:db8::1");
+    assert_eq!(
+        canonical_address("::ffff:192.168.0.1")?,
+        "::ffff:192.168.0.1"
+    );
+    assert!(canonical_address("1.2.3").is_err());
+    assert!(canonical_address("256.0.0.1").is_err());
+    assert!(canonical_address("localhost").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_address_version() {
+    assert_eq!(address_version("127.0.0.1"), Some(4));
+    assert_eq!(address_version("::1"), Some(6));
+    assert_eq!(address_version("::ffff:127.0.0.1"), Some(6));
+    assert_eq!(address_version("example.com"), None);
+}
+
+#[test]
+fn test_parse_network() -> eyre::Result<()> {
+    assert_eq!(parse_network("10.0.0.0/8")?, (ip("10.0.0.0"), 8));
+    assert_eq!(parse_network("10.1.2.3/8")?, (ip("10.1.2.3"), 8));
+    assert_eq!(parse_network("10.1.2.3")?, (ip("10.1.2.3"), 32));
+    assert_eq!(parse_network("2001:db8::/32")?, (ip("2001:db8::"), 32));
+    assert_eq!(parse_network("::1")?, (ip("::1"), 128));
+    assert!(parse_network("10.0.0.0/33").is_err());
+    assert!(parse_network("2001:db8::/129").is_err());
+    assert!(parse_network("10.0.0.0/").is_err());
+    assert!(parse_network("10.0.0.0/-1").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_is_in_network() -> eyre::Result<()> {
+    assert!(is_in_network("10.1.2.3", "10.0.0.0/8")?);
+    assert!(!is_in_network("11.1.2.3", "10.0.0.0/8")?);
+    assert!(is_in_network("192.168.1.255", "192.168.1.0/24")?);
+    assert!(!is_in_network("192.168.2.0", "192.168.1.0/24")?);
+    assert!(is_in_network("1.2.3.4", "0.0.0.0/0")?);
+    assert!(is_in_network("1.2.3.4", "1.2.3.4")?);
+    assert!(!is_in_network("1.2.3.5", "1.2.3.4")?);
+    assert!(is_in_network("2001:db8:1::1", "2001:db8::/32")?);
+    assert!(!is_in_network("2001:db9::1", "2001:db8::/32")?);
+    assert!(!is_in_network("10.1.2.3", "::/0")?);
+    assert!(!is_in_network("::ffff:10.1.2.3", "10.0.0.0/8")?);
+    assert!(is_in_network("10.1.2.3", "not a network").is_err());
+    assert!(is_in_network("not an address", "10.0.0.0/8").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_network_of() -> eyre::Result<()> {
+    assert_eq!(network_of("192.168.17.5", 24)?, "192.168.17.0/24");
+    assert_eq!(network_of("192.168.17.5", 0)?, "0.0.0.0/0");
+    assert_eq!(network_of("192.168.17.5", 32)?, "192.168.17.5/32");
+    assert_eq!(network_of("192.168.17.5", 20)?, "192.168.16.0/20");
+    assert_eq!(network_of("2001:db8:ab:cd::1", 48)?, "2001:db8:ab::/48");
+    assert_eq!(
+        network_of("2001:db8:ab:cd::1", 128)?,
+        "2001:db8:ab:cd::1/128"
+    );
+    assert!(network_of("192.168.17.5", 33).is_err());
+    assert!(network_of("192.168.17.5", -1).is_err());
+    assert!(network_of("::1", 129).is_err());
+    Ok(())
+}
@@ -1,6 +1,12 @@
 // Copyright © 2026 Pathway
 
-use pathway_engine::connectors::data_format::{Formatter, JsonLinesFormatter};
+use std::collections::HashMap;
+
+use assert_matches::assert_matches;
+
+use pathway_engine::connectors::data_format::{
+    Formatter, FormatterError, JsonDateTimeFormat, JsonLinesFormatter,
+};
 use pathway_engine::engine::DateTimeUtc;
 use pathway_engine::engine::Duration;
 use pathway_engine::engine::{DateTimeNaive, Timestamp};
@@ -238,3 +244,167 @@ fn test_json_format_timestamps() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_json_nested_and_renamed_fields() -> eyre::Result<()> {
+    let mut formatter = JsonLinesFormatter::new(
+        vec!["city".to_string(), "zip".to_string(), "name".to_string()],
+        None,
+    )
+    .with_field_paths(&HashMap::from([
+        ("city".to_string(), "/address/city".to_string()),
+        ("zip".to_string(), "/address/postal~1code".to_string()),
+        ("name".to_string(), "/full_name".to_string()),
+    ]))?;
+
+    let result = formatter.format(
+        &Key::for_value(&Value::from("1")),
+        &[
+            Value::from("Paris"),
+            Value::from("75001"),
+            Value::from("Alice"),
+        ],
+        Timestamp(2),
+        -1,
+    )?;
+    assert_eq!(result.payloads.len(), 1);
+    assert_document_raw_byte_contents(
+        &result.payloads[0],
+        r#"{"address":{"city":"Paris","postal/code":"75001"},"diff":-1,"full_name":"Alice","time":2}"#.as_bytes(),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_json_without_special_fields() -> eyre::Result<()> {
+    let mut formatter = JsonLinesFormatter::new(vec!["a".to_string()], None)
+        .with_special_fields(false)?
+        .with_field_paths(&HashMap::from([("a".to_string(), "/time".to_string())]))?;
+
+    let result = formatter.format(
+        &Key::for_value(&Value::from("1")),
+        &[Value::Int(555)],
+        Timestamp(0),
+        1,
+    )?;
+    assert_eq!(result.payloads.len(), 1);
+    assert_document_raw_byte_contents(&result.payloads[0], r#"{"time":555}"#.as_bytes());
+
+    Ok(())
+}
+
+#[test]
+fn test_json_date_time_formats() -> eyre::Result<()> {
+    let values = [
+        Value::DateTimeUtc(DateTimeUtc::from_timestamp(1738686506812, "ms")?),
+        Value::DateTimeNaive(DateTimeNaive::from_timestamp(1738686506812, "ms")?),
+    ];
+    let expected_payloads = [
+        (
+            JsonDateTimeFormat::Iso8601,
+            r#"{"naive":"2025-02-04T16:28:26.812000000","utc":"2025-02-04T16:28:26.812000000+00:00"}"#,
+        ),
+        (
+            JsonDateTimeFormat::EpochSeconds,
+            r#"{"naive":1738686506,"utc":1738686506}"#,
+        ),
+        (
+            JsonDateTimeFormat::EpochMilliseconds,
+            r#"{"naive":1738686506812,"utc":1738686506812}"#,
+        ),
+        (
+            JsonDateTimeFormat::EpochMicroseconds,
+            r#"{"naive":1738686506812000,"utc":1738686506812000}"#,
+        ),
+        (
+            JsonDateTimeFormat::EpochNanoseconds,
+            r#"{"naive":1738686506812000000,"utc":1738686506812000000}"#,
+        ),
+    ];
+    for (datetime_format, expected_payload) in expected_payloads {
+        let mut formatter =
+            JsonLinesFormatter::new(vec!["utc".to_string(), "naive".to_string()], None)
+                .with_special_fields(false)?
+                .with_datetime_format(datetime_format);
+        let result =
+            formatter.format(&Key::for_value(&Value::from("1")), &values, Timestamp(0), 1)?;
+        assert_eq!(result.payloads.len(), 1);
+        assert_document_raw_byte_contents(&result.payloads[0], expected_payload.as_bytes());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_json_invalid_field_paths() {
+    let formatter = || JsonLinesFormatter::new(vec!["a".to_string(), "b".to_string()], None);
+
+    let error = formatter()
+        .with_field_paths(&HashMap::from([("c".to_string(), "/c".to_string())]))
+        .err()
+        .unwrap();
+    assert_matches!(error, FormatterError::UnknownJsonFieldPathColumn(column) if column == "c");
+
+    for pointer in ["a", "/a//b", "/"] {
+        let error = formatter()
+            .with_field_paths(&HashMap::from([("a".to_string(), pointer.to_string())]))
+            .err()
+            .unwrap();
+        assert_matches!(error, FormatterError::InvalidJsonFieldPath(path) if path == pointer);
+    }
+
+    let error = formatter()
+        .with_field_paths(&HashMap::from([
+            ("a".to_string(), "/x".to_string()),
+            ("b".to_string(), "/x/y".to_string()),
+        ]))
+        .err()
+        .unwrap();
+    assert_matches!(error, FormatterError::ConflictingJsonFieldPaths(_, _));
+
+    let error = formatter()
+        .with_field_paths(&HashMap::from([("a".to_string(), "/diff".to_string())]))
+        .err()
+        .unwrap();
+    assert_matches!(error, FormatterError::ConflictingJsonFieldPaths(_, _));
+}
+
+#[test]
+fn test_json_update() -> eyre::Result<()> {
+    let mut formatter = JsonLinesFormatter::new(vec!["a".to_string()], None);
+
+    let result = formatter
+        .format_update(
+            &Key::for_value(&Value::from("1")),
+            &[Value::Int(1)],
+            &[Value::Int(2)],
+            Timestamp(4),
+        )?
+        .unwrap();
+    assert_eq!(result.payloads.len(), 1);
+    assert_document_raw_byte_contents(
+        &result.payloads[0],
+        r#"{"a":2,"before":{"a":1},"diff":1,"time":4}"#.as_bytes(),
+    );
+    assert_eq!(result.values, &[Value::Int(2)]);
+    assert_eq!(result.old_values, Some(vec![Value::Int(1)]));
+
+    Ok(())
+}
+
+#[test]
+fn test_json_update_not_representable() -> eyre::Result<()> {
+    let key = Key::for_value(&Value::from("1"));
+
+    let mut formatter =
+        JsonLinesFormatter::new(vec!["a".to_string()], None).with_special_fields(false)?;
+    let result = formatter.format_update(&key, &[Value::Int(1)], &[Value::Int(2)], Timestamp(4))?;
+    assert!(result.is_none());
+
+    let mut formatter = JsonLinesFormatter::new(vec!["before".to_string()], None);
+    let result = formatter.format_update(&key, &[Value::Int(1)], &[Value::Int(2)], Timestamp(4))?;
+    assert!(result.is_none());
+
+    Ok(())
+}
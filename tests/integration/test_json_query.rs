@@ -0,0 +1,98 @@
+// Copyright © 2026 Pathway
+
+use std::sync::Arc;
+
+use serde_json::json;
+
+use pathway_engine::engine::{AnyExpression, Expression, JsonPathCache, Value};
+
+use super::helpers::argument;
+
+fn query(path: &str, document: &Value) -> Vec<Value> {
+    let expression = Expression::Any(AnyExpression::JsonQuery(
+        argument(0),
+        Arc::new(Expression::Any(AnyExpression::Const(Value::from(path)))),
+        JsonPathCache::new(),
+    ));
+    let result = expression
+        .eval(&[&[document.clone()]])
+        .pop()
+        .unwrap()
+        .unwrap();
+    match result {
+        Value::Tuple(matches) => matches.to_vec(),
+        other => panic!("expected a tuple of matches, got {other:?}"),
+    }
+}
+
+fn store() -> Value {
+    Value::from(json!({
+        "store": {
+            "books": [
+                {"title": "Dune", "price": 12},
+                {"title": "Emma", "price": 5},
+                {"title": "Ulysses", "price": 20, "tags": ["classic"]},
+            ],
+            "bicycle": {"price": 100},
+        }
+    }))
+}
+
+#[test]
+fn test_json_query_child_and_index() {
+    assert_eq!(
+        query("$.store.books[0].title", &store()),
+        vec![Value::from(json!("Dune"))]
+    );
+    assert_eq!(
+        query("$.store.books[-1].tags", &store()),
+        vec![Value::from(json!(["classic"]))]
+    );
+    assert_eq!(query("$.store.missing", &store()), vec![]);
+}
+
+#[test]
+fn test_json_query_wildcard() {
+    assert_eq!(
+        query("$.store.books[*].price", &store()),
+        vec![
+            Value::from(json!(12)),
+            Value::from(json!(5)),
+            Value::from(json!(20))
+        ]
+    );
+}
+
+#[test]
+fn test_json_query_recursive_descent() {
+    let prices = query("$..price", &store());
+    assert_eq!(prices.len(), 4);
+    for price in [5, 12, 20, 100] {
+        assert!(prices.contains(&Value::from(json!(price))));
+    }
+}
+
+#[test]
+fn test_json_query_filter() {
+    assert_eq!(
+        query("$.store.books[?@.price > 10].title", &store()),
+        vec![Value::from(json!("Dune")), Value::from(json!("Ulysses"))]
+    );
+}
+
+#[test]
+fn test_json_query_none_and_errors() {
+    let expression = Expression::Any(AnyExpression::JsonQuery(
+        argument(0),
+        argument(1),
+        JsonPathCache::new(),
+    ));
+    let result = expression.eval(&[
+        &[Value::None, Value::from("$.a")],
+        &[store(), Value::from("$[?")],
+        &[Value::from("not json"), Value::from("$.a")],
+    ]);
+    assert_eq!(*result[0].as_ref().unwrap(), Value::None);
+    assert!(result[1].is_err());
+    assert!(result[2].is_err());
+}
@@ -0,0 +1,58 @@
+// Copyright © 2026 Pathway
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use assert_matches::assert_matches;
+
+use pathway_engine::engine::error::{DynError, Trace};
+use pathway_engine::engine::key_collisions::KeyCollisionDetector;
+use pathway_engine::engine::report_error::LogError;
+use pathway_engine::engine::{DataError, Key, Value};
+
+#[derive(Clone, Default)]
+struct CollectingErrorLogger {
+    errors: Rc<RefCell<Vec<DataError>>>,
+}
+
+impl LogError for CollectingErrorLogger {
+    fn log_error(&self, error: DataError) {
+        self.errors.borrow_mut().push(error);
+    }
+
+    fn log_error_with_trace(&self, error: DynError, _trace: &Trace) {
+        self.errors.borrow_mut().push(DataError::Other(error));
+    }
+}
+
+#[test]
+fn test_repeated_keys_are_not_collisions() {
+    let logger = CollectingErrorLogger::default();
+    let mut detector = KeyCollisionDetector::new(Box::new(logger.clone()));
+    let first = vec![Value::from("Alice"), Value::Int(1)];
+    let second = vec![Value::from("Bob"), Value::Int(2)];
+    for values in [&first, &second, &first] {
+        detector.check(Key::for_values(values), values);
+    }
+    assert_eq!(detector.len(), 2);
+    assert!(logger.errors.borrow().is_empty());
+}
+
+#[test]
+fn test_collision_is_logged() {
+    let logger = CollectingErrorLogger::default();
+    let mut detector = KeyCollisionDetector::new(Box::new(logger.clone()));
+    let key = Key::for_values(&[Value::from("Alice")]);
+    detector.check(key, &[Value::from("Alice")]);
+    // a different primary key getting the same id, as could happen with smaller ids
+    detector.check(key, &[Value::from("Bob")]);
+    detector.check(key, &[Value::from("Alice")]);
+
+    let errors = logger.errors.borrow();
+    assert_eq!(errors.len(), 1);
+    assert_matches!(
+        &errors[0],
+        DataError::KeyCollision { key: collided, values }
+            if *collided == key && *values == Value::from([Value::from("Bob")].as_slice())
+    );
+}
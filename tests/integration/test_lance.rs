@@ -0,0 +1,211 @@
+// Copyright © 2026 Pathway
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use assert_matches::assert_matches;
+use deltalake::arrow::array::{Array, FixedSizeListArray, Float32Array, Int64Array};
+use deltalake::arrow::datatypes::DataType as ArrowDataType;
+use deltalake::arrow::record_batch::RecordBatch as ArrowRecordBatch;
+use lance::dataset::Dataset;
+use ndarray::ArrayD;
+use tempfile::tempdir;
+
+use pathway_engine::connectors::data_format::{FieldSource, FormatterContext, ValueField};
+use pathway_engine::connectors::data_storage::data_lake::arrow::construct_schema as construct_arrow_schema;
+use pathway_engine::connectors::data_storage::data_lake::buffering::AppendOnlyColumnBuffer;
+use pathway_engine::connectors::data_storage::data_lake::{LanceBatchWriter, MaintenanceMode};
+use pathway_engine::connectors::data_storage::{LakeWriter, LanceError, WriteError, Writer};
+use pathway_engine::engine::{Key, Timestamp, Type, Value};
+
+fn document_fields(embedding_type: Type) -> Vec<ValueField> {
+    vec![
+        ValueField {
+            name: "doc_id".to_string(),
+            type_: Type::Int,
+            source: FieldSource::Payload,
+            default: None,
+            metadata: None,
+        },
+        ValueField {
+            name: "embedding".to_string(),
+            type_: embedding_type,
+            source: FieldSource::Payload,
+            default: None,
+            metadata: None,
+        },
+    ]
+}
+
+fn create_writer(
+    uri: &str,
+    value_fields: &[ValueField],
+    dimension: usize,
+) -> Result<LakeWriter, WriteError> {
+    let batch_writer = LanceBatchWriter::new(
+        uri,
+        value_fields,
+        HashMap::from([("embedding".to_string(), dimension)]),
+        HashMap::new(),
+    )?;
+    let schema = construct_arrow_schema(
+        value_fields,
+        &batch_writer,
+        MaintenanceMode::StreamOfChanges,
+    )?;
+    let buffer = AppendOnlyColumnBuffer::new(Arc::new(schema));
+    Ok(LakeWriter::new(
+        Box::new(batch_writer),
+        Box::new(buffer),
+        None,
+    ))
+}
+
+fn document(doc_id: i64, embedding: Value) -> FormatterContext {
+    FormatterContext::new_single_payload(
+        vec![],
+        Key::random(),
+        vec![Value::Int(doc_id), embedding],
+        Timestamp(0),
+        1,
+    )
+}
+
+fn read_dataset(uri: &str) -> eyre::Result<(u64, ArrowRecordBatch)> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let dataset = Dataset::open(uri).await?;
+            let batch = dataset.scan().try_into_batch().await?;
+            Ok((dataset.version().version, batch))
+        })
+}
+
+#[test]
+fn test_vectors_are_stored_as_fixed_size_lists() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let uri = test_storage.path().join("documents.lance");
+    let uri = uri.to_str().unwrap();
+    let value_fields = document_fields(Type::List(Type::Float.into()));
+
+    let mut writer = create_writer(uri, &value_fields, 3)?;
+    writer.write(document(
+        1,
+        Value::Tuple(vec![Value::from(0.5), Value::from(1.0), Value::from(-2.0)].into()),
+    ))?;
+    writer.flush(true)?;
+    writer.write(document(2, Value::None))?;
+    writer.flush(true)?;
+
+    let (version, batch) = read_dataset(uri)?;
+    assert_eq!(version, 2);
+    let mut doc_ids: Vec<_> = batch
+        .column_by_name("doc_id")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .iter()
+        .collect();
+    doc_ids.sort();
+    assert_eq!(doc_ids, vec![Some(1), Some(2)]);
+
+    let embeddings = batch.column_by_name("embedding").unwrap();
+    assert_matches!(
+        embeddings.data_type(),
+        ArrowDataType::FixedSizeList(element, 3) if element.data_type() == &ArrowDataType::Float32
+    );
+    let embeddings = embeddings
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    assert_eq!(embeddings.null_count(), 1);
+    let vector_index = (0..embeddings.len())
+        .find(|index| embeddings.is_valid(*index))
+        .unwrap();
+    let vector = embeddings.value(vector_index);
+    let vector = vector.as_any().downcast_ref::<Float32Array>().unwrap();
+    assert_eq!(vector.values().to_vec(), vec![0.5, 1.0, -2.0]);
+    Ok(())
+}
+
+#[test]
+fn test_one_dimensional_arrays_as_vectors() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let uri = test_storage.path().join("documents.lance");
+    let uri = uri.to_str().unwrap();
+    let value_fields = document_fields(Type::Array(Some(1), Type::Float.into()));
+
+    let mut writer = create_writer(uri, &value_fields, 2)?;
+    let embedding = ArrayD::<f64>::from_shape_vec(vec![2], vec![0.25, 0.75]).unwrap();
+    writer.write(document(1, Value::from(embedding)))?;
+    writer.flush(true)?;
+
+    let (_, batch) = read_dataset(uri)?;
+    let embeddings = batch
+        .column_by_name("embedding")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    let vector = embeddings.value(0);
+    let vector = vector.as_any().downcast_ref::<Float32Array>().unwrap();
+    assert_eq!(vector.values().to_vec(), vec![0.25, 0.75]);
+    Ok(())
+}
+
+#[test]
+fn test_vector_of_wrong_length() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let uri = test_storage.path().join("documents.lance");
+    let value_fields = document_fields(Type::List(Type::Float.into()));
+
+    let mut writer = create_writer(uri.to_str().unwrap(), &value_fields, 3)?;
+    writer.write(document(
+        1,
+        Value::Tuple(vec![Value::from(0.5), Value::from(1.0)].into()),
+    ))?;
+    assert_matches!(
+        writer.flush(true),
+        Err(WriteError::Lance(LanceError::VectorLengthMismatch {
+            value_len: 2,
+            expected: 3
+        }))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_vector_columns_are_checked_on_creation() {
+    let test_storage = tempdir().unwrap();
+    let uri = test_storage.path().join("documents.lance");
+    let uri = uri.to_str().unwrap();
+
+    let value_fields = document_fields(Type::List(Type::Float.into()));
+    let error = LanceBatchWriter::new(
+        uri,
+        &value_fields,
+        HashMap::from([("vector".to_string(), 3)]),
+        HashMap::new(),
+    )
+    .err()
+    .unwrap();
+    assert_matches!(
+        error,
+        WriteError::Lance(LanceError::VectorColumnNotFound(column)) if column == "vector"
+    );
+    let error = create_writer(uri, &value_fields, 0).err().unwrap();
+    assert_matches!(
+        error,
+        WriteError::Lance(LanceError::InvalidVectorDimension { dimension: 0, .. })
+    );
+
+    let value_fields = document_fields(Type::List(Type::Int.into()));
+    let error = create_writer(uri, &value_fields, 3).err().unwrap();
+    assert_matches!(
+        error,
+        WriteError::Lance(LanceError::UnsupportedVectorColumnType { .. })
+    );
+}
@@ -0,0 +1,145 @@
+// Copyright © 2026 Pathway
+
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use pathway_engine::engine::lifecycle::{RunCallbacksBuilder, RunLifecycle};
+use pathway_engine::engine::{Timestamp, TotalFrontier};
+
+fn recording_lifecycle(workers: usize) -> (RunLifecycle, Arc<Mutex<Vec<String>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let callbacks = RunCallbacksBuilder::new()
+        .on_start({
+            let events = events.clone();
+            Box::new(move || {
+                events.lock().unwrap().push("start".to_string());
+                Ok(())
+            })
+        })
+        .on_snapshot_replay_done({
+            let events = events.clone();
+            Box::new(move || {
+                events.lock().unwrap().push("replay done".to_string());
+                Ok(())
+            })
+        })
+        .on_frontier({
+            let events = events.clone();
+            Box::new(move |time| {
+                events.lock().unwrap().push(format!("frontier {}", time.0));
+                Ok(())
+            })
+        })
+        .on_shutdown({
+            let events = events.clone();
+            Box::new(move || {
+                events.lock().unwrap().push("shutdown".to_string());
+                Ok(())
+            })
+        })
+        .build();
+    (RunLifecycle::new(callbacks, workers), events)
+}
+
+#[test]
+fn test_events_are_reported_once_per_process() {
+    let (lifecycle, events) = recording_lifecycle(2);
+    lifecycle.worker_started().unwrap();
+    assert!(events.lock().unwrap().is_empty());
+    lifecycle.worker_started().unwrap();
+    lifecycle.worker_replayed_snapshot().unwrap();
+    lifecycle.worker_replayed_snapshot().unwrap();
+    lifecycle.shutdown().unwrap();
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec!["start", "replay done", "shutdown"]
+    );
+}
+
+#[test]
+fn test_frontier_is_the_minimum_of_workers() {
+    let (lifecycle, events) = recording_lifecycle(2);
+    lifecycle
+        .worker_frontier(0, TotalFrontier::At(Timestamp(4)))
+        .unwrap();
+    // the other worker hasn't reported its frontier yet
+    assert!(events.lock().unwrap().is_empty());
+    lifecycle
+        .worker_frontier(1, TotalFrontier::At(Timestamp(2)))
+        .unwrap();
+    lifecycle
+        .worker_frontier(1, TotalFrontier::At(Timestamp(2)))
+        .unwrap();
+    lifecycle
+        .worker_frontier(1, TotalFrontier::At(Timestamp(6)))
+        .unwrap();
+    lifecycle.worker_frontier(0, TotalFrontier::Done).unwrap();
+    lifecycle.worker_frontier(1, TotalFrontier::Done).unwrap();
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec!["frontier 2", "frontier 4", "frontier 6"]
+    );
+}
+
+#[test]
+fn test_initial_frontier_is_not_reported() {
+    let (lifecycle, events) = recording_lifecycle(1);
+    lifecycle
+        .worker_frontier(0, TotalFrontier::At(Timestamp(0)))
+        .unwrap();
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_callback_error_is_returned() {
+    let callbacks = RunCallbacksBuilder::new()
+        .on_start(Box::new(|| Err("warmup failed".into())))
+        .build();
+    let lifecycle = RunLifecycle::new(callbacks, 1);
+    let error = lifecycle.worker_started().unwrap_err();
+    assert_eq!(error.to_string(), "warmup failed");
+}
+
+#[test]
+fn test_events_during_callback_are_passed_on_after_it() {
+    let (entered_sender, entered_receiver) = mpsc::channel();
+    let (release_sender, release_receiver) = mpsc::channel::<()>();
+    let release_receiver = Mutex::new(release_receiver);
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let callbacks = RunCallbacksBuilder::new()
+        .on_frontier({
+            let events = events.clone();
+            Box::new(move |time| {
+                if time == Timestamp(2) {
+                    entered_sender.send(()).unwrap();
+                    release_receiver.lock().unwrap().recv().unwrap();
+                }
+                events.lock().unwrap().push(time.0);
+                Ok(())
+            })
+        })
+        .build();
+    let lifecycle = Arc::new(RunLifecycle::new(callbacks, 2));
+    lifecycle
+        .worker_frontier(0, TotalFrontier::At(Timestamp(6)))
+        .unwrap();
+    let delivering = thread::spawn({
+        let lifecycle = lifecycle.clone();
+        move || lifecycle.worker_frontier(1, TotalFrontier::At(Timestamp(2)))
+    });
+    entered_receiver.recv().unwrap();
+
+    // the callback running for the other worker doesn't block this one
+    lifecycle
+        .worker_frontier(1, TotalFrontier::At(Timestamp(4)))
+        .unwrap();
+    lifecycle
+        .worker_frontier(1, TotalFrontier::At(Timestamp(5)))
+        .unwrap();
+    assert!(events.lock().unwrap().is_empty());
+
+    release_sender.send(()).unwrap();
+    delivering.join().unwrap().unwrap();
+    // the frontiers are passed in order, skipping the ones superseded while waiting
+    assert_eq!(*events.lock().unwrap(), vec![2, 5]);
+}
@@ -0,0 +1,101 @@
+// Copyright © 2026 Pathway
+
+use std::collections::BTreeMap;
+
+use arcstr::ArcStr;
+use serde_json::json;
+
+use pathway_engine::connectors::data_format::{parse_value_from_json, serialize_value_to_json};
+use pathway_engine::engine::{AnyExpression, BoolExpression, Expression, Type, Value};
+
+use super::helpers::argument;
+
+fn attributes() -> Value {
+    Value::from(BTreeMap::from([
+        (ArcStr::from("color"), Value::from("red")),
+        (ArcStr::from("size"), Value::Int(3)),
+    ]))
+}
+
+fn eval(expression: &Expression, row: &[Value]) -> Value {
+    let mut result = expression.eval(&[row]);
+    assert_eq!(result.len(), 1);
+    result.pop().unwrap().unwrap()
+}
+
+#[test]
+fn test_map_display() {
+    assert_eq!(attributes().to_string(), r#"{"color": "red", "size": 3}"#);
+    assert_eq!(Type::Map(Type::Int.into()).to_string(), "dict[str, int]");
+}
+
+#[test]
+fn test_map_contains() {
+    let expression = Expression::Bool(BoolExpression::MapContains(argument(0), argument(1)));
+    let row = [attributes(), Value::from("size")];
+    assert_eq!(eval(&expression, &row), Value::Bool(true));
+    let row = [attributes(), Value::from("weight")];
+    assert_eq!(eval(&expression, &row), Value::Bool(false));
+    let row = [Value::None, Value::from("size")];
+    assert_eq!(eval(&expression, &row), Value::Bool(false));
+}
+
+#[test]
+fn test_map_get_item() {
+    let checked = Expression::Any(AnyExpression::MapGetItemChecked(
+        argument(0),
+        argument(1),
+        argument(2),
+    ));
+    let row = [attributes(), Value::from("size"), Value::Int(0)];
+    assert_eq!(eval(&checked, &row), Value::Int(3));
+    let row = [attributes(), Value::from("weight"), Value::Int(0)];
+    assert_eq!(eval(&checked, &row), Value::Int(0));
+    let row = [Value::None, Value::from("size"), Value::Int(0)];
+    assert_eq!(eval(&checked, &row), Value::Int(0));
+
+    let unchecked = Expression::Any(AnyExpression::MapGetItemUnchecked(argument(0), argument(1)));
+    let result = unchecked.eval(&[&[attributes(), Value::from("weight")]]);
+    assert!(result[0].is_err());
+}
+
+#[test]
+fn test_map_iteration() {
+    let keys = Expression::Any(AnyExpression::MapKeys(argument(0)));
+    assert_eq!(
+        eval(&keys, &[attributes()]),
+        Value::from(vec![Value::from("color"), Value::from("size")])
+    );
+    let values = Expression::Any(AnyExpression::MapValues(argument(0)));
+    assert_eq!(
+        eval(&values, &[attributes()]),
+        Value::from(vec![Value::from("red"), Value::Int(3)])
+    );
+    let items = Expression::Any(AnyExpression::MapItems(argument(0)));
+    assert_eq!(
+        eval(&items, &[attributes()]),
+        Value::from(vec![
+            Value::from(vec![Value::from("color"), Value::from("red")]),
+            Value::from(vec![Value::from("size"), Value::Int(3)]),
+        ])
+    );
+}
+
+#[test]
+fn test_map_json_roundtrip() -> eyre::Result<()> {
+    let map = Value::from(BTreeMap::from([
+        (ArcStr::from("a"), Value::Int(1)),
+        (ArcStr::from("b"), Value::Int(2)),
+    ]));
+    let json_value = json!({"b": 2, "a": 1});
+    assert_eq!(
+        parse_value_from_json(&json_value, &Type::Map(Type::Int.into())),
+        Some(map.clone())
+    );
+    assert_eq!(
+        parse_value_from_json(&json!({"a": "x"}), &Type::Map(Type::Int.into())),
+        None
+    );
+    assert_eq!(serialize_value_to_json(&map)?, json_value);
+    Ok(())
+}
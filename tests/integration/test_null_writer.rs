@@ -1,9 +1,11 @@
 // Copyright © 2026 Pathway
 
+use assert_matches::assert_matches;
+
 use pathway_engine::engine::{Key, Timestamp};
 
 use pathway_engine::connectors::data_format::{Formatter, FormatterContext, NullFormatter};
-use pathway_engine::connectors::data_storage::{NullWriter, Writer};
+use pathway_engine::connectors::data_storage::{NullWriter, WriteError, Writer};
 
 #[test]
 fn test_null_formatter_ok() -> eyre::Result<()> {
@@ -33,3 +35,17 @@ fn test_null_writer_ok() -> eyre::Result<()> {
     writer.write(context).unwrap();
     Ok(())
 }
+
+#[test]
+fn test_null_writer_cant_commit_in_two_phases() {
+    let mut writer = NullWriter::new();
+    assert!(!writer.supports_two_phase_commit());
+    assert_matches!(
+        writer.prepare(),
+        Err(WriteError::TwoPhaseCommitUnsupported(name)) if name == "NullWriter"
+    );
+    assert_matches!(
+        writer.commit(),
+        Err(WriteError::TwoPhaseCommitUnsupported(name)) if name == "NullWriter"
+    );
+}
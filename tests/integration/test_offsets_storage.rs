@@ -2,7 +2,9 @@
 
 use super::helpers::create_persistence_manager;
 use super::helpers::get_entries_in_receiver;
+use super::helpers::put_value_blocking;
 
+use assert_matches::assert_matches;
 use crossbeam_channel as channel;
 use std::thread::sleep;
 use std::time::Duration;
@@ -11,10 +13,13 @@ use tempfile::tempdir;
 
 use pathway_engine::connectors::{Connector, Entry, PersistenceMode};
 use pathway_engine::connectors::{OffsetKey, OffsetValue};
-use pathway_engine::engine::{Timestamp, TotalFrontier};
-use pathway_engine::persistence::backends::FilesystemKVStorage;
+use pathway_engine::engine::{RunLineage, Timestamp, TotalFrontier};
+use pathway_engine::persistence::backends::{FilesystemKVStorage, PersistenceBackend};
 use pathway_engine::persistence::frontier::OffsetAntichain;
-use pathway_engine::persistence::state::{MetadataAccessor, StoredMetadata};
+use pathway_engine::persistence::state::{
+    migrate_metadata, MetadataAccessor, OperatorIdScheme, StoredMetadata, METADATA_FORMAT_VERSION,
+};
+use pathway_engine::persistence::Error as PersistenceError;
 
 fn assert_frontiers_equal(
     mut lhs: Vec<(OffsetKey, OffsetValue)>,
@@ -359,5 +364,167 @@ fn test_legacy_state_parsing() -> eyre::Result<()> {
         state.last_advanced_timestamp,
         TotalFrontier::At(Timestamp(1730285602306))
     );
+    assert_eq!(state.lineage, None);
+    Ok(())
+}
+
+#[test]
+fn test_legacy_state_migration() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+    let bytes = std::fs::read("tests/data/legacy_persistence_state")?;
+    put_value_blocking(&backend, "1-0-0", &bytes);
+    put_value_blocking(&backend, "1-1-0", &bytes);
+
+    assert_eq!(migrate_metadata(&backend)?, 2);
+    for key in ["1-0-0", "1-1-0"] {
+        let raw_block = String::from_utf8(backend.get_value(key)?)?;
+        assert!(raw_block.contains(&format!("\"format_version\":{METADATA_FORMAT_VERSION}")));
+        // the number of workers comes from the blocks, not from the default
+        let state = StoredMetadata::parse(raw_block.as_bytes(), 16)?;
+        assert_eq!(state.total_workers, 2);
+        assert_eq!(
+            state.last_advanced_timestamp,
+            TotalFrontier::At(Timestamp(1730285602306))
+        );
+    }
+    assert_eq!(migrate_metadata(&backend)?, 0);
+
+    let accessor = MetadataAccessor::new(Box::new(backend), 0, 4)?;
+    assert_eq!(accessor.past_runs_total_workers(), Some(2));
+    assert_eq!(
+        accessor.past_runs_threshold_time(),
+        TotalFrontier::At(Timestamp(1730285602306))
+    );
+    // the operator states were named before the structural identities were introduced
+    assert_eq!(accessor.operator_id_scheme(), OperatorIdScheme::Sequential);
+    Ok(())
+}
+
+#[test]
+fn test_operator_id_scheme_kept_across_runs() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+    let mut legacy_state = StoredMetadata::new(1);
+    legacy_state.format_version = 1;
+    legacy_state.last_advanced_timestamp = TotalFrontier::At(Timestamp(50));
+    put_value_blocking(&backend, "1-0-0", legacy_state.serialize().as_bytes());
+
+    {
+        let mut ms = MetadataAccessor::new(Box::new(backend), 0, 1)?;
+        assert_eq!(ms.operator_id_scheme(), OperatorIdScheme::Sequential);
+        ms.accept_finalized_timestamp(TotalFrontier::At(Timestamp(60)));
+        ms.save_current_state()?;
+    }
+
+    {
+        let ms =
+            MetadataAccessor::new(Box::new(FilesystemKVStorage::new(test_storage_path)?), 0, 1)?;
+        assert_eq!(
+            ms.past_runs_threshold_time(),
+            TotalFrontier::At(Timestamp(60))
+        );
+        assert_eq!(ms.operator_id_scheme(), OperatorIdScheme::Sequential);
+    }
+
+    let fresh_storage = tempdir()?;
+    let ms = MetadataAccessor::new(
+        Box::new(FilesystemKVStorage::new(fresh_storage.path())?),
+        0,
+        1,
+    )?;
+    assert_eq!(ms.operator_id_scheme(), OperatorIdScheme::Structural);
+    Ok(())
+}
+
+#[test]
+fn test_newer_metadata_format_rejected() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+    let mut state = StoredMetadata::new(1);
+    state.format_version = METADATA_FORMAT_VERSION + 1;
+    put_value_blocking(&backend, "1-0-0", state.serialize().as_bytes());
+
+    assert_matches!(
+        MetadataAccessor::new(Box::new(FilesystemKVStorage::new(test_storage_path)?), 0, 1),
+        Err(PersistenceError::UnsupportedMetadataFormatVersion { found, supported })
+            if found == METADATA_FORMAT_VERSION + 1 && supported == METADATA_FORMAT_VERSION
+    );
+    assert_matches!(
+        migrate_metadata(&backend),
+        Err(PersistenceError::UnsupportedMetadataFormatVersion { .. })
+    );
+    // the block isn't removed as a broken one
+    assert_eq!(backend.list_keys()?, vec!["1-0-0"]);
+    Ok(())
+}
+
+#[test]
+fn test_run_lineage_storage() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let lineage = RunLineage::new("first-run".to_string(), Some("0123abcd".to_string()));
+
+    {
+        let mut ms =
+            MetadataAccessor::new(Box::new(FilesystemKVStorage::new(test_storage_path)?), 0, 1)?;
+        assert_eq!(ms.past_runs_lineage(), None);
+        ms.set_lineage(lineage.clone());
+        ms.accept_finalized_timestamp(TotalFrontier::At(Timestamp(50)));
+        ms.save_current_state()?;
+    }
+
+    {
+        let ms =
+            MetadataAccessor::new(Box::new(FilesystemKVStorage::new(test_storage_path)?), 0, 1)?;
+        assert_eq!(
+            ms.past_runs_threshold_time(),
+            TotalFrontier::At(Timestamp(50))
+        );
+        assert_eq!(ms.past_runs_lineage(), Some(&lineage));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_past_runs_total_workers() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+
+    {
+        let ms =
+            MetadataAccessor::new(Box::new(FilesystemKVStorage::new(test_storage_path)?), 0, 2)?;
+        assert_eq!(ms.past_runs_total_workers(), None);
+    }
+
+    {
+        let mut accessors = Vec::new();
+        for worker_id in 0..2 {
+            accessors.push(MetadataAccessor::new(
+                Box::new(FilesystemKVStorage::new(test_storage_path)?),
+                worker_id,
+                2,
+            )?);
+        }
+        for ms in &mut accessors {
+            ms.accept_finalized_timestamp(TotalFrontier::At(Timestamp(50)));
+            ms.save_current_state()?;
+        }
+    }
+
+    {
+        let ms =
+            MetadataAccessor::new(Box::new(FilesystemKVStorage::new(test_storage_path)?), 0, 3)?;
+        assert_eq!(ms.past_runs_total_workers(), Some(2));
+        assert_eq!(
+            ms.past_runs_threshold_time(),
+            TotalFrontier::At(Timestamp(50))
+        );
+    }
+
     Ok(())
 }
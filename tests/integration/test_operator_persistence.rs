@@ -16,15 +16,18 @@ use pathway_engine::persistence::backends::{
 };
 use pathway_engine::persistence::operator_snapshot::{
     ConcreteSnapshotMerger, ConcreteSnapshotReader, ConcreteSnapshotWriter,
-    MultiConcreteSnapshotReader, OperatorSnapshotReader, OperatorSnapshotWriter,
+    MultiConcreteSnapshotReader, OperatorSnapshotReader, OperatorSnapshotWriter, SnapshotShard,
+};
+use pathway_engine::persistence::state::{
+    FinalizedTimeQuerier, StoredMetadata, METADATA_FORMAT_VERSION,
 };
-use pathway_engine::persistence::state::{FinalizedTimeQuerier, StoredMetadata};
 use pathway_engine::persistence::PersistenceTime;
 use serde::Deserialize;
 
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{mpsc, Arc, Mutex, MutexGuard};
 use std::thread;
 use std::time::{Duration, SystemTime};
@@ -555,8 +558,10 @@ fn test_operator_snapshot_writer() {
 
 fn metadata_from_timestamp(timestamp: Timestamp) -> Vec<u8> {
     StoredMetadata {
+        format_version: METADATA_FORMAT_VERSION,
         last_advanced_timestamp: TotalFrontier::At(timestamp),
         total_workers: 1,
+        lineage: None,
     }
     .serialize()
     .into()
@@ -840,3 +845,154 @@ fn test_snapshot_writer_with_merger() {
     keys.sort();
     assert_eq!(keys, vec!["2-3200-3", "3-2900-4"]);
 }
+
+#[test]
+fn test_snapshot_merging_into_full_snapshot() {
+    let metadata_backend = KVBackend::new();
+    let mut time_querier = FinalizedTimeQuerier::new(Box::new(metadata_backend.clone()), 1);
+    let mut backend = KVBackend::new();
+    let mut writer: ConcreteSnapshotWriter<i64, isize> =
+        ConcreteSnapshotWriter::new(Box::new(backend.clone()), Duration::from_millis(1000));
+
+    writer.persist(Timestamp(1200), vec![(2, 1), (3, 1)]);
+    writer.persist(Timestamp(1700), vec![(4, 1), (3, 2)]);
+    let futures = writer.flush(TotalFrontier::At(Timestamp(2100)));
+    futures::executor::block_on(futures::future::try_join_all(futures)).unwrap();
+    let future = metadata_backend.put_value("1-0-0", metadata_from_timestamp(Timestamp(2100)));
+    futures::executor::block_on(future).unwrap().unwrap();
+    ConcreteSnapshotMerger::maybe_merge::<i64, isize>(&mut backend, &mut time_querier).unwrap();
+    let mut keys = backend.list_keys().unwrap();
+    keys.sort();
+    assert_eq!(keys, vec!["0-1700-3", "2-1700-3"]);
+
+    // only the changes since the previous chunk are written
+    writer.persist(Timestamp(2100), vec![(2, -1)]);
+    writer.persist(Timestamp(2900), vec![(3, -3)]);
+    let futures = writer.flush(TotalFrontier::At(Timestamp(3000)));
+    futures::executor::block_on(futures::future::try_join_all(futures)).unwrap();
+    let future = metadata_backend.put_value("1-0-1", metadata_from_timestamp(Timestamp(3000)));
+    futures::executor::block_on(future).unwrap().unwrap();
+    let data = backend.get_value("0-2900-2").unwrap();
+    assert_deserializes_to::<(i64, isize)>(&data, vec![(2, -1), (3, -3)]);
+
+    // a regular merge would only create a chunk at level 1
+    assert!(
+        ConcreteSnapshotMerger::merge_all::<i64, isize>(&mut backend, &mut time_querier).unwrap()
+    );
+    let mut keys = backend.list_keys().unwrap();
+    keys.sort();
+    assert_eq!(keys, vec!["0-2900-2", "2-1700-3", "2-2900-1"]);
+    let data = backend.get_value("2-2900-1").unwrap();
+    assert_deserializes_to::<(i64, isize)>(&data, vec![(4, 1)]);
+
+    ConcreteSnapshotMerger::maybe_merge::<i64, isize>(&mut backend, &mut time_querier).unwrap();
+    let mut keys = backend.list_keys().unwrap();
+    keys.sort();
+    assert_eq!(keys, vec!["2-2900-1"]);
+
+    // without new chunks there is nothing to merge, so no full snapshot is saved
+    assert!(
+        !ConcreteSnapshotMerger::merge_all::<i64, isize>(&mut backend, &mut time_querier).unwrap()
+    );
+    assert_eq!(backend.list_keys().unwrap(), vec!["2-2900-1"]);
+
+    let mut reader = ConcreteSnapshotReader::new(
+        Box::new(backend.clone()),
+        TotalFrontier::At(Timestamp(3000)),
+    );
+    let data: Vec<(i64, isize)> = reader.load_persisted().unwrap();
+    assert_eq!(data, vec![(4, 1)]);
+}
+
+fn put_chunk(backend: &FilesystemKVStorage, key: &str, data: &[(i64, isize)]) {
+    let future = backend.put_value(key, serialize(data).unwrap());
+    futures::executor::block_on(future).unwrap().unwrap();
+}
+
+#[test]
+fn test_resharded_snapshot_readers_split_state() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let former_worker_paths = [test_storage.path().join("0"), test_storage.path().join("1")];
+    let former_worker_0 = FilesystemKVStorage::new(&former_worker_paths[0])?;
+    put_chunk(&former_worker_0, "0-10-3", &[(1, 1), (2, 1), (3, 2)]);
+    let former_worker_1 = FilesystemKVStorage::new(&former_worker_paths[1])?;
+    put_chunk(&former_worker_1, "0-12-3", &[(4, 1), (5, 1), (6, 1)]);
+    put_chunk(&former_worker_1, "0-50-1", &[(7, 1)]);
+
+    let total_workers = 3;
+    let mut loaded = Vec::new();
+    for worker_id in 0..total_workers {
+        let shard = SnapshotShard::new(worker_id, total_workers);
+        let mut readers = Vec::new();
+        for (former_worker_id, path) in former_worker_paths.iter().enumerate() {
+            let reader = ConcreteSnapshotReader::new(
+                Box::new(FilesystemKVStorage::new(path)?),
+                TotalFrontier::At(Timestamp(20)),
+            )
+            .with_shard(shard, former_worker_id % total_workers == worker_id);
+            readers.push(reader);
+        }
+        let (sender, receiver) = mpsc::channel();
+        let mut reader = MultiConcreteSnapshotReader::new(readers, sender);
+        let data: Vec<(i64, isize)> = reader.load_persisted()?;
+        receiver.recv()?;
+        for (entry, _diff) in &data {
+            assert!(shard.contains(entry));
+        }
+        loaded.extend(data);
+    }
+    loaded.sort();
+    assert_eq!(loaded, vec![(1, 1), (2, 1), (3, 2), (4, 1), (5, 1), (6, 1)]);
+
+    // the chunk past the threshold time is removed by the owner of the snapshot
+    assert_eq!(former_worker_1.list_keys()?, vec!["0-12-3"]);
+    Ok(())
+}
+
+#[test]
+fn test_resharded_snapshot_reader_keeps_foreign_snapshot() {
+    let mut backend = MockBackend::new();
+    backend
+        .expect_list_keys()
+        .times(1)
+        .returning(|| Ok(vec!["0-10-1".to_string(), "0-30-1".to_string()]));
+    backend
+        .expect_get_value()
+        .with(eq("0-10-1"))
+        .times(1)
+        .returning(|_key| serialize(&vec![(3, 1)]).map_err(|e| BackendError::Bincode(*e)));
+    backend.expect_remove_key().times(0);
+    let mut reader =
+        ConcreteSnapshotReader::new(Box::new(backend), TotalFrontier::At(Timestamp(20)))
+            .with_shard(SnapshotShard::new(0, 1), false);
+    let data: Vec<(i64, isize)> = reader.load_persisted().unwrap();
+    assert_eq!(data, vec![(3, 1)]);
+}
+
+#[test]
+fn test_resharded_snapshot_reader_retries_merged_chunks() {
+    let listings = Arc::new(AtomicUsize::new(0));
+    let listings_2 = listings.clone();
+    let mut backend = MockBackend::new();
+    backend.expect_list_keys().times(2).returning(move || {
+        // the chunks are merged by the owner between the two listings
+        if listings_2.fetch_add(1, Ordering::SeqCst) == 0 {
+            Ok(vec!["0-10-1".to_string(), "0-12-1".to_string()])
+        } else {
+            Ok(vec!["1-12-2".to_string()])
+        }
+    });
+    backend.expect_get_value().returning(|key| match key {
+        "0-10-1" => serialize(&vec![(3, 1)]).map_err(|e| BackendError::Bincode(*e)),
+        "0-12-1" => Err(BackendError::NoCachedObject),
+        "1-12-2" => serialize(&vec![(3, 1), (4, 1)]).map_err(|e| BackendError::Bincode(*e)),
+        _ => panic!("unexpected key {key}"),
+    });
+    let mut reader =
+        ConcreteSnapshotReader::new(Box::new(backend), TotalFrontier::At(Timestamp(20)))
+            .with_shard(SnapshotShard::new(0, 1), false);
+    let mut data: Vec<(i64, isize)> = reader.load_persisted().unwrap();
+    data.sort();
+    assert_eq!(data, vec![(3, 1), (4, 1)]);
+    assert_eq!(listings.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,187 @@
+// Copyright © 2026 Pathway
+
+use crate::helpers::ReplaceErrors;
+
+use super::helpers::read_data_from_reader;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::Arc;
+
+use deltalake::arrow::array::{Float64Array, Int64Array, StringArray};
+use deltalake::arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+use deltalake::arrow::record_batch::RecordBatch;
+use orc_rust::arrow_writer::ArrowWriterBuilder;
+use tempfile::tempdir;
+
+use pathway_engine::connectors::data_format::{
+    InnerSchemaField, ParseError, ParsedEvent, Parser, TransparentParser,
+};
+use pathway_engine::connectors::data_storage::scanner::FilesystemScanner;
+use pathway_engine::connectors::data_storage::sharding::ShardSelector;
+use pathway_engine::connectors::data_storage::{ConnectorMode, DataEventType, ReaderContext};
+use pathway_engine::connectors::data_tokenize::{OrcTokenizer, Tokenize};
+use pathway_engine::connectors::posix_like::PosixLikeReader;
+use pathway_engine::connectors::SessionType;
+use pathway_engine::engine::{Type, Value};
+
+fn write_orc_file(path: &Path, ids: Vec<i64>) -> eyre::Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", ArrowDataType::Int64, false),
+        Field::new("name", ArrowDataType::Utf8, true),
+        Field::new("score", ArrowDataType::Float64, true),
+        Field::new("comment", ArrowDataType::Utf8, true),
+    ]));
+    let names: Vec<_> = ids.iter().map(|id| format!("user-{id}")).collect();
+    let scores: Vec<_> = ids.iter().map(|id| *id as f64 / 2.0).collect();
+    let comments: Vec<_> = ids.iter().map(|_| "not projected").collect();
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int64Array::from(ids)),
+            Arc::new(StringArray::from(names)),
+            Arc::new(Float64Array::from(scores)),
+            Arc::new(StringArray::from(comments)),
+        ],
+    )?;
+    let mut writer = ArrowWriterBuilder::new(File::create(path)?, schema).try_build()?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+fn column_types(with_metadata: bool) -> HashMap<String, Type> {
+    let mut types = HashMap::from([
+        ("id".to_string(), Type::Int),
+        ("name".to_string(), Type::String),
+        ("score".to_string(), Type::Float),
+    ]);
+    if with_metadata {
+        types.insert("_metadata".to_string(), Type::Json);
+    }
+    types
+}
+
+fn create_parser(with_metadata: bool) -> eyre::Result<TransparentParser> {
+    let mut value_field_names = vec!["id".to_string(), "name".to_string(), "score".to_string()];
+    if with_metadata {
+        value_field_names.push("_metadata".to_string());
+    }
+    let schema = column_types(with_metadata)
+        .into_iter()
+        .map(|(name, type_)| (name, InnerSchemaField::new(type_, None)))
+        .collect();
+    Ok(TransparentParser::new(
+        None,
+        value_field_names,
+        schema,
+        SessionType::Native,
+    )?)
+}
+
+#[test]
+fn test_orc_tokenizer_projects_schema_columns() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let path = test_storage.path().join("users.orc");
+    write_orc_file(&path, vec![1, 2, 3])?;
+
+    let mut tokenizer = OrcTokenizer::new(column_types(false), 2);
+    tokenizer.set_new_reader(
+        Box::new(Cursor::new(std::fs::read(&path)?)),
+        DataEventType::Delete,
+    )?;
+    let mut parser = create_parser(false)?;
+    let mut events = Vec::new();
+    let mut positions = Vec::new();
+    while let Some((context, position)) = tokenizer.next_entry()? {
+        let ReaderContext::Diff((_, _, ref values)) = context else {
+            panic!("unexpected reader context: {context:?}");
+        };
+        assert!(values.get("comment").is_none());
+        positions.push(position);
+        for event in parser.parse(&context).map_err(ParseError::from)? {
+            events.push(event.replace_errors());
+        }
+    }
+
+    assert_eq!(positions, vec![1, 2, 3]);
+    assert_eq!(
+        events,
+        vec![
+            ParsedEvent::Delete((
+                None,
+                vec![Value::Int(1), Value::from("user-1"), Value::from(0.5)]
+            )),
+            ParsedEvent::Delete((
+                None,
+                vec![Value::Int(2), Value::from("user-2"), Value::from(1.0)]
+            )),
+            ParsedEvent::Delete((
+                None,
+                vec![Value::Int(3), Value::from("user-3"), Value::from(1.5)]
+            )),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_orc_files_with_metadata() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    write_orc_file(&test_storage.path().join("a.orc"), vec![1, 2])?;
+    write_orc_file(&test_storage.path().join("b.orc"), vec![3])?;
+
+    let scanner = FilesystemScanner::new(
+        test_storage.path().to_str().unwrap(),
+        "*.orc",
+        ShardSelector::new(0, 1),
+    )?;
+    let reader = PosixLikeReader::new(
+        Box::new(scanner),
+        Box::new(OrcTokenizer::new(
+            column_types(true),
+            OrcTokenizer::DEFAULT_BATCH_SIZE,
+        )),
+        ConnectorMode::Static,
+        false,
+        false,
+    )?;
+    let events = read_data_from_reader(Box::new(reader), Box::new(create_parser(true)?))?;
+
+    let mut rows: Vec<_> = events
+        .into_iter()
+        .map(|event| {
+            let ParsedEvent::Insert((None, values)) = event else {
+                panic!("unexpected event: {event:?}");
+            };
+            let Value::Json(ref metadata) = values[3] else {
+                panic!("wrong type of metadata field: {values:?}");
+            };
+            let path = metadata["path"].as_str().unwrap().to_string();
+            let file_name = Path::new(&path).file_name().unwrap().to_owned();
+            (values[0].clone(), file_name.into_string().unwrap())
+        })
+        .collect();
+    rows.sort_by_key(|(id, _)| id.as_int().unwrap());
+    assert_eq!(
+        rows,
+        vec![
+            (Value::Int(1), "a.orc".to_string()),
+            (Value::Int(2), "a.orc".to_string()),
+            (Value::Int(3), "b.orc".to_string()),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_malformed_orc_file() {
+    let mut tokenizer = OrcTokenizer::new(column_types(false), OrcTokenizer::DEFAULT_BATCH_SIZE);
+    let result = tokenizer.set_new_reader(
+        Box::new(Cursor::new(b"id,name\n1,user-1\n".to_vec())),
+        DataEventType::Insert,
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,293 @@
+// Copyright © 2026 Pathway
+
+use std::path::Path;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use tempfile::tempdir;
+
+use pathway_engine::connectors::{OffsetKey, OffsetValue};
+use pathway_engine::engine::{Key, Timestamp, TotalFrontier, Value};
+use pathway_engine::persistence::backends::{FilesystemKVStorage, PersistenceBackend};
+use pathway_engine::persistence::frontier::OffsetAntichain;
+use pathway_engine::persistence::input_snapshot::{
+    Event as SnapshotEvent, InputSnapshotReader, InputSnapshotWriter, ReadInputSnapshot,
+    SnapshotMode, SupersededChunksRemover,
+};
+use pathway_engine::persistence::retention::{GarbageCollector, RetentionPolicy};
+use pathway_engine::persistence::state::{FinalizedTimeQuerier, MetadataAccessor};
+
+fn frontier(offset: i64) -> OffsetAntichain {
+    let mut frontier = OffsetAntichain::new();
+    frontier.advance_offset(OffsetKey::Empty, OffsetValue::KafkaOffset(offset));
+    frontier
+}
+
+fn advance_time(time: u64) -> SnapshotEvent {
+    SnapshotEvent::AdvanceTime(Timestamp(time), frontier(time.try_into().unwrap()))
+}
+
+// Each writer starts a new chunk, as it happens on a restart
+fn write_chunk(path: &Path, mode: SnapshotMode, events: &[SnapshotEvent]) -> eyre::Result<()> {
+    let backend = FilesystemKVStorage::new(path)?;
+    let mut writer = InputSnapshotWriter::new(Box::new(backend), mode)?;
+    for event in events {
+        writer.write(event);
+    }
+    for future in writer.flush() {
+        futures::executor::block_on(future).unwrap()?;
+    }
+    Ok(())
+}
+
+fn sorted_keys(backend: &dyn PersistenceBackend) -> eyre::Result<Vec<String>> {
+    let mut keys = backend.list_keys()?;
+    keys.sort();
+    Ok(keys)
+}
+
+fn save_metadata(path: &Path, time: u64) -> eyre::Result<()> {
+    let mut metadata = MetadataAccessor::new(Box::new(FilesystemKVStorage::new(path)?), 0, 1)?;
+    metadata.accept_finalized_timestamp(TotalFrontier::At(Timestamp(time)));
+    metadata.save_current_state()?;
+    Ok(())
+}
+
+#[test]
+fn test_superseded_offsets_chunks_removed() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    write_chunk(
+        test_storage_path,
+        SnapshotMode::OffsetsOnly,
+        &[advance_time(1), advance_time(2)],
+    )?;
+    write_chunk(
+        test_storage_path,
+        SnapshotMode::OffsetsOnly,
+        &[advance_time(3), advance_time(4)],
+    )?;
+    write_chunk(
+        test_storage_path,
+        SnapshotMode::OffsetsOnly,
+        &[advance_time(5)],
+    )?;
+
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+    let mut remover =
+        SupersededChunksRemover::new(Box::new(FilesystemKVStorage::new(test_storage_path)?));
+    assert_eq!(
+        remover.remove_superseded_chunks(TotalFrontier::At(Timestamp(4)))?,
+        1
+    );
+    assert_eq!(sorted_keys(&backend)?, vec!["2", "3"]);
+
+    // the rewind reaches the same frontier
+    let mut reader = InputSnapshotReader::new(
+        Box::new(FilesystemKVStorage::new(test_storage_path)?),
+        TotalFrontier::At(Timestamp(4)),
+        false,
+    )?;
+    while !matches!(reader.read()?, SnapshotEvent::Finished) {}
+    assert_eq!(reader.last_frontier(), &frontier(4));
+
+    assert_eq!(
+        remover.remove_superseded_chunks(TotalFrontier::At(Timestamp(6)))?,
+        1
+    );
+    assert_eq!(sorted_keys(&backend)?, vec!["3"]);
+    assert_eq!(remover.remove_superseded_chunks(TotalFrontier::Done)?, 0);
+    assert_eq!(sorted_keys(&backend)?, vec!["3"]);
+    Ok(())
+}
+
+#[test]
+fn test_chunks_not_superseded_before_threshold_kept() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    write_chunk(
+        test_storage_path,
+        SnapshotMode::OffsetsOnly,
+        &[advance_time(1)],
+    )?;
+    write_chunk(
+        test_storage_path,
+        SnapshotMode::OffsetsOnly,
+        &[advance_time(3)],
+    )?;
+
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+    let mut remover =
+        SupersededChunksRemover::new(Box::new(FilesystemKVStorage::new(test_storage_path)?));
+    assert_eq!(
+        remover.remove_superseded_chunks(TotalFrontier::At(Timestamp(3)))?,
+        0
+    );
+    assert_eq!(sorted_keys(&backend)?, vec!["1", "2"]);
+    Ok(())
+}
+
+#[test]
+fn test_chunks_with_data_kept() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    write_chunk(
+        test_storage_path,
+        SnapshotMode::Full,
+        &[
+            SnapshotEvent::Insert(Key::random(), vec![Value::Int(1)]),
+            advance_time(1),
+        ],
+    )?;
+    write_chunk(
+        test_storage_path,
+        SnapshotMode::OffsetsOnly,
+        &[advance_time(2)],
+    )?;
+
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+    let mut remover =
+        SupersededChunksRemover::new(Box::new(FilesystemKVStorage::new(test_storage_path)?));
+    assert_eq!(remover.remove_superseded_chunks(TotalFrontier::Done)?, 0);
+    assert_eq!(sorted_keys(&backend)?, vec!["1", "2"]);
+    Ok(())
+}
+
+#[test]
+fn test_chunks_with_data_not_read_again() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    write_chunk(
+        test_storage_path,
+        SnapshotMode::Full,
+        &[
+            SnapshotEvent::Insert(Key::random(), vec![Value::Int(1)]),
+            advance_time(1),
+        ],
+    )?;
+    write_chunk(
+        test_storage_path,
+        SnapshotMode::OffsetsOnly,
+        &[advance_time(2)],
+    )?;
+
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+    let mut remover =
+        SupersededChunksRemover::new(Box::new(FilesystemKVStorage::new(test_storage_path)?));
+    assert_eq!(remover.remove_superseded_chunks(TotalFrontier::Done)?, 0);
+
+    // the chunk is known to contain data, so it isn't read by the next pass
+    futures::executor::block_on(backend.put_value("1", b"not a chunk".to_vec())).unwrap()?;
+    assert_eq!(remover.remove_superseded_chunks(TotalFrontier::Done)?, 0);
+    assert_eq!(sorted_keys(&backend)?, vec!["1", "2"]);
+    Ok(())
+}
+
+#[test]
+fn test_obsolete_metadata_versions_removed() -> eyre::Result<()> {
+    let metadata_storage = tempdir()?;
+    let metadata_storage_path = metadata_storage.path();
+    save_metadata(metadata_storage_path, 10)?;
+    save_metadata(metadata_storage_path, 20)?;
+    let backend = FilesystemKVStorage::new(metadata_storage_path)?;
+    assert_eq!(sorted_keys(&backend)?, vec!["1-0-0", "2-0-0"]);
+
+    let mut time_querier = FinalizedTimeQuerier::new(
+        Box::new(FilesystemKVStorage::new(metadata_storage_path)?),
+        1,
+    );
+    assert_eq!(
+        time_querier.last_finalized_timestamp()?,
+        TotalFrontier::At(Timestamp(20))
+    );
+    assert_eq!(sorted_keys(&backend)?, vec!["1-0-0", "2-0-0"]);
+    assert_eq!(
+        time_querier.remove_obsolete_versions()?,
+        TotalFrontier::At(Timestamp(20))
+    );
+    assert_eq!(sorted_keys(&backend)?, vec!["2-0-0"]);
+    Ok(())
+}
+
+#[test]
+fn test_garbage_collection_uses_durable_threshold() -> eyre::Result<()> {
+    let metadata_storage = tempdir()?;
+    let metadata_storage_path = metadata_storage.path();
+    let snapshot_storage = tempdir()?;
+    let snapshot_storage_path = snapshot_storage.path();
+    save_metadata(metadata_storage_path, 2)?;
+    save_metadata(metadata_storage_path, 3)?;
+    for time in 1..=3 {
+        write_chunk(
+            snapshot_storage_path,
+            SnapshotMode::OffsetsOnly,
+            &[advance_time(time)],
+        )?;
+    }
+
+    let mut time_querier = FinalizedTimeQuerier::new(
+        Box::new(FilesystemKVStorage::new(metadata_storage_path)?),
+        1,
+    );
+    let mut input_snapshots = vec![SupersededChunksRemover::new(Box::new(
+        FilesystemKVStorage::new(snapshot_storage_path)?,
+    ))];
+    let snapshot_backend = FilesystemKVStorage::new(snapshot_storage_path)?;
+    // the chunk starting at 3 isn't durable yet, so only the first chunk is superseded
+    assert_eq!(
+        GarbageCollector::collect(&mut time_querier, false, &mut input_snapshots)?,
+        1
+    );
+    assert_eq!(sorted_keys(&snapshot_backend)?, vec!["2", "3"]);
+    let metadata_backend = FilesystemKVStorage::new(metadata_storage_path)?;
+    assert_eq!(sorted_keys(&metadata_backend)?, vec!["1-0-0", "2-0-0"]);
+
+    assert_eq!(
+        GarbageCollector::collect(&mut time_querier, true, &mut input_snapshots)?,
+        0
+    );
+    assert_eq!(sorted_keys(&metadata_backend)?, vec!["2-0-0"]);
+    Ok(())
+}
+
+#[test]
+fn test_garbage_collector_runs_in_background() -> eyre::Result<()> {
+    let metadata_storage = tempdir()?;
+    let metadata_storage_path = metadata_storage.path();
+    let snapshot_storage = tempdir()?;
+    let snapshot_storage_path = snapshot_storage.path();
+    save_metadata(metadata_storage_path, 10)?;
+    for time in 1..=2 {
+        write_chunk(
+            snapshot_storage_path,
+            SnapshotMode::OffsetsOnly,
+            &[advance_time(time)],
+        )?;
+    }
+
+    let time_querier = FinalizedTimeQuerier::new(
+        Box::new(FilesystemKVStorage::new(metadata_storage_path)?),
+        1,
+    );
+    let garbage_collector = GarbageCollector::new(
+        RetentionPolicy::new(Duration::from_millis(10)),
+        time_querier,
+        true,
+        None,
+    );
+    garbage_collector
+        .add_input_snapshot(Box::new(FilesystemKVStorage::new(snapshot_storage_path)?));
+
+    let backend = FilesystemKVStorage::new(snapshot_storage_path)?;
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while sorted_keys(&backend)? != vec!["2"] {
+        assert!(
+            Instant::now() < deadline,
+            "the superseded chunk hasn't been removed"
+        );
+        sleep(Duration::from_millis(10));
+    }
+    drop(garbage_collector);
+    assert_eq!(sorted_keys(&backend)?, vec!["2"]);
+    Ok(())
+}
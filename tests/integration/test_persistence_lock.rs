@@ -0,0 +1,252 @@
+// Copyright © 2026 Pathway
+
+use std::path::Path;
+use std::sync::{mpsc, Arc};
+use std::thread::{self, sleep};
+use std::time::{Duration, Instant};
+
+use assert_matches::assert_matches;
+use tempfile::tempdir;
+
+use pathway_engine::persistence::backends::{FilesystemKVStorage, PersistenceBackend};
+use pathway_engine::persistence::lock::{LeaseRecord, PersistenceLock, RunLease};
+use pathway_engine::persistence::Error;
+
+const LEASE_TTL: Duration = Duration::from_millis(300);
+
+fn lease_owner(backend: &dyn PersistenceBackend) -> eyre::Result<Option<String>> {
+    Ok(LeaseRecord::read(backend)?.map(|record| record.owner))
+}
+
+fn wait_for_run_lease(run_lease: &RunLease, path: &Path) -> Arc<PersistenceLock> {
+    run_lease
+        .wait(|| Ok(Box::new(FilesystemKVStorage::new(path)?)))
+        .unwrap()
+}
+
+fn wait_until_lost(lock: &PersistenceLock) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while lock.is_held() {
+        assert!(
+            Instant::now() < deadline,
+            "the lease loss hasn't been noticed"
+        );
+        sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn test_lease_acquired_and_released() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+
+    let lock = PersistenceLock::acquire(
+        Box::new(FilesystemKVStorage::new(test_storage_path)?),
+        LEASE_TTL,
+    )?;
+    assert!(lock.is_held());
+    let owner = lease_owner(&backend)?.expect("the lease should be stored");
+    assert!(!PersistenceLock::try_acquire(&backend, "other", LEASE_TTL)?);
+
+    // the lease is renewed, so it doesn't expire while held
+    sleep(LEASE_TTL * 2);
+    assert!(lock.is_held());
+    assert_eq!(lease_owner(&backend)?, Some(owner));
+    assert!(!LeaseRecord::read(&backend)?.unwrap().is_expired());
+
+    drop(lock);
+    assert_eq!(lease_owner(&backend)?, None);
+    Ok(())
+}
+
+#[test]
+fn test_second_run_waits_for_lease() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path().to_path_buf();
+    let first_lock = PersistenceLock::acquire(
+        Box::new(FilesystemKVStorage::new(&test_storage_path)?),
+        LEASE_TTL,
+    )?;
+
+    let (sender, receiver) = mpsc::channel();
+    let second_run = thread::spawn(move || {
+        let second_lock = PersistenceLock::acquire(
+            Box::new(FilesystemKVStorage::new(&test_storage_path).unwrap()),
+            LEASE_TTL,
+        )
+        .unwrap();
+        sender.send(()).unwrap();
+        second_lock
+    });
+
+    assert_eq!(
+        receiver.recv_timeout(LEASE_TTL * 3),
+        Err(mpsc::RecvTimeoutError::Timeout)
+    );
+    drop(first_lock);
+    receiver.recv_timeout(Duration::from_secs(10))?;
+    assert!(second_run.join().unwrap().is_held());
+    Ok(())
+}
+
+#[test]
+fn test_expired_lease_taken_over() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let backend = FilesystemKVStorage::new(test_storage.path())?;
+    // the run holding the lease has crashed without releasing it
+    LeaseRecord::new("crashed", Duration::ZERO).write(&backend)?;
+
+    assert!(PersistenceLock::try_acquire(&backend, "next", LEASE_TTL)?);
+    assert_eq!(lease_owner(&backend)?, Some("next".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_lease_lost_to_another_run() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+    let lock = PersistenceLock::acquire(
+        Box::new(FilesystemKVStorage::new(test_storage_path)?),
+        LEASE_TTL,
+    )?;
+
+    // another run has taken the lease over, e.g. after the renewals had been delayed
+    LeaseRecord::new("other", Duration::from_secs(60)).write(&backend)?;
+    wait_until_lost(&lock);
+
+    // the lease of the other run is kept
+    drop(lock);
+    assert_eq!(lease_owner(&backend)?, Some("other".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_all_workers_wait_for_run_lease() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path().to_path_buf();
+    let former_run_lock = PersistenceLock::acquire(
+        Box::new(FilesystemKVStorage::new(&test_storage_path)?),
+        LEASE_TTL,
+    )?;
+
+    // two processes of the same run, with two workers each
+    let run_leases = [
+        Arc::new(RunLease::new("run".to_string(), LEASE_TTL, true)),
+        Arc::new(RunLease::new("run".to_string(), LEASE_TTL, false)),
+    ];
+    let (sender, receiver) = mpsc::channel();
+    let mut workers = Vec::new();
+    for (process_id, run_lease) in run_leases.iter().enumerate() {
+        for _ in 0..2 {
+            let run_lease = run_lease.clone();
+            let test_storage_path = test_storage_path.clone();
+            let sender = sender.clone();
+            workers.push(thread::spawn(move || {
+                let lock = wait_for_run_lease(&run_lease, &test_storage_path);
+                sender.send(process_id).unwrap();
+                lock
+            }));
+        }
+    }
+
+    assert_eq!(
+        receiver.recv_timeout(LEASE_TTL * 3),
+        Err(mpsc::RecvTimeoutError::Timeout)
+    );
+    drop(former_run_lock);
+    let mut started_processes = Vec::new();
+    for _ in 0..4 {
+        started_processes.push(receiver.recv_timeout(Duration::from_secs(10))?);
+    }
+    started_processes.sort_unstable();
+    assert_eq!(started_processes, vec![0, 0, 1, 1]);
+
+    let locks: Vec<_> = workers
+        .into_iter()
+        .map(|worker| worker.join().unwrap())
+        .collect();
+    assert!(locks.iter().all(|lock| lock.is_held()));
+    // the workers of a process share its lock
+    assert!(Arc::ptr_eq(&locks[0], &locks[1]));
+    assert!(Arc::ptr_eq(&locks[2], &locks[3]));
+    assert!(!Arc::ptr_eq(&locks[0], &locks[2]));
+    let backend = FilesystemKVStorage::new(&test_storage_path)?;
+    assert_eq!(lease_owner(&backend)?, Some("run".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_run_lease_lost_in_all_processes() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let backend = FilesystemKVStorage::new(test_storage_path)?;
+    let acquiring_process = RunLease::new("run".to_string(), LEASE_TTL, true);
+    let following_process = RunLease::new("run".to_string(), LEASE_TTL, false);
+    let acquired = wait_for_run_lease(&acquiring_process, test_storage_path);
+    let followed = wait_for_run_lease(&following_process, test_storage_path);
+    assert!(acquired.is_held() && followed.is_held());
+
+    LeaseRecord::new("other", Duration::from_secs(60)).write(&backend)?;
+    wait_until_lost(&acquired);
+    wait_until_lost(&followed);
+
+    // the lease of the other run is kept
+    drop(acquired);
+    drop(followed);
+    drop(acquiring_process);
+    drop(following_process);
+    assert_eq!(lease_owner(&backend)?, Some("other".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_following_process_gives_up_if_the_run_doesnt_acquire_lease() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    // the acquiring process of the run has crashed before acquiring the lease
+    let started_at = Instant::now();
+    let result = PersistenceLock::follow(
+        Box::new(FilesystemKVStorage::new(test_storage_path)?),
+        "run".to_string(),
+        LEASE_TTL,
+    );
+    assert_matches!(result, Err(Error::LeaseNotAcquiredByRun { owner, .. }) if owner == "run");
+    assert!(started_at.elapsed() < Duration::from_secs(10));
+    Ok(())
+}
+
+#[test]
+fn test_following_process_waits_while_another_run_holds_lease() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path().to_path_buf();
+    let former_run_lock = PersistenceLock::acquire(
+        Box::new(FilesystemKVStorage::new(&test_storage_path)?),
+        LEASE_TTL,
+    )?;
+
+    let following_process = {
+        let test_storage_path = test_storage_path.clone();
+        thread::spawn(move || {
+            PersistenceLock::follow(
+                Box::new(FilesystemKVStorage::new(&test_storage_path).unwrap()),
+                "run".to_string(),
+                LEASE_TTL,
+            )
+        })
+    };
+    // longer than the following process waits once the lease is free
+    sleep(LEASE_TTL * 8);
+    assert!(!following_process.is_finished());
+
+    drop(former_run_lock);
+    let acquired = PersistenceLock::acquire_for(
+        Box::new(FilesystemKVStorage::new(&test_storage_path)?),
+        "run".to_string(),
+        LEASE_TTL,
+    )?;
+    let followed = following_process.join().unwrap()?;
+    assert!(acquired.is_held() && followed.is_held());
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// Copyright © 2026 Pathway
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use pathway_engine::connectors::rate_limit::{sleep_unless_stopped, RateLimit, RateLimiter};
+
+#[test]
+fn test_reading_within_the_limit() {
+    let mut limiter = RateLimiter::new(RateLimit {
+        max_rows_per_second: Some(10),
+        max_bytes_per_second: None,
+    });
+    let now = Instant::now();
+    for _ in 0..10 {
+        assert_eq!(limiter.on_read(1, 1000, now), Duration::ZERO);
+    }
+    assert_eq!(limiter.on_read(5, 0, now), Duration::from_millis(500));
+
+    // the budget is refilled as the time passes
+    let now = now + Duration::from_secs(1);
+    assert_eq!(limiter.on_read(5, 0, now), Duration::ZERO);
+}
+
+#[test]
+fn test_budget_is_capped() {
+    let mut limiter = RateLimiter::new(RateLimit {
+        max_rows_per_second: Some(10),
+        max_bytes_per_second: None,
+    });
+    // a long idle period doesn't allow a longer burst than one second of reading
+    let now = Instant::now() + Duration::from_secs(60);
+    assert_eq!(limiter.on_read(10, 0, now), Duration::ZERO);
+    assert_eq!(limiter.on_read(1, 0, now), Duration::from_millis(100));
+}
+
+#[test]
+fn test_the_stricter_limit_applies() {
+    let mut limiter = RateLimiter::new(RateLimit {
+        max_rows_per_second: Some(100),
+        max_bytes_per_second: Some(1000),
+    });
+    let now = Instant::now();
+    assert_eq!(limiter.on_read(1, 1500, now), Duration::from_millis(500));
+}
+
+#[test]
+fn test_debt_is_capped() {
+    let mut limiter = RateLimiter::new(RateLimit {
+        max_rows_per_second: None,
+        max_bytes_per_second: Some(1000),
+    });
+    // a message much larger than the limit pauses the reading for one second only
+    let now = Instant::now();
+    assert_eq!(limiter.on_read(1, 10_000, now), Duration::from_secs(1));
+    let now = now + Duration::from_secs(1);
+    assert_eq!(limiter.on_read(1, 0, now), Duration::ZERO);
+}
+
+#[test]
+fn test_pause_ends_when_stopped() {
+    let started_at = Instant::now();
+    let stopped = AtomicBool::new(false);
+    sleep_unless_stopped(Duration::from_secs(60), || {
+        stopped.swap(true, Ordering::SeqCst)
+    });
+    assert!(started_at.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn test_limit_is_shared_by_readers() {
+    let limit = RateLimit {
+        max_rows_per_second: Some(10),
+        max_bytes_per_second: Some(2),
+    };
+    assert_eq!(
+        limit.per_reader(4),
+        RateLimit {
+            max_rows_per_second: Some(2),
+            max_bytes_per_second: Some(1),
+        }
+    );
+    assert!(RateLimit::default().is_unlimited());
+    assert!(!limit.is_unlimited());
+}
@@ -0,0 +1,73 @@
+// Copyright © 2026 Pathway
+
+use pathway_engine::connectors::data_storage::redis::StreamEntryId;
+use pathway_engine::connectors::data_storage::{Reader, RedisStreamReader};
+use pathway_engine::connectors::{OffsetKey, OffsetValue};
+use pathway_engine::persistence::frontier::OffsetAntichain;
+
+#[test]
+fn test_stream_entry_id_parsing() -> eyre::Result<()> {
+    let entry_id: StreamEntryId = "1526919030474-55".parse()?;
+    assert_eq!(
+        entry_id,
+        StreamEntryId {
+            milliseconds_time: 1_526_919_030_474,
+            sequence_number: 55,
+        }
+    );
+    assert_eq!(entry_id.to_string(), "1526919030474-55");
+
+    assert!("1526919030474".parse::<StreamEntryId>().is_err());
+    assert!("1526919030474-".parse::<StreamEntryId>().is_err());
+    assert!("abc-1".parse::<StreamEntryId>().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_stream_entry_id_ordering() -> eyre::Result<()> {
+    let earlier: StreamEntryId = "999-10".parse()?;
+    let same_time_later: StreamEntryId = "999-11".parse()?;
+    let later: StreamEntryId = "1000-0".parse()?;
+    assert!(earlier < same_time_later);
+    assert!(same_time_later < later);
+    Ok(())
+}
+
+#[test]
+fn test_frontier_merge_takes_later_entry_ids() -> eyre::Result<()> {
+    let first_worker = OffsetKey::RedisStream(0);
+    let second_worker = OffsetKey::RedisStream(1);
+
+    let mut lhs = OffsetAntichain::new();
+    lhs.advance_offset(
+        first_worker.clone(),
+        "1000-0".parse::<StreamEntryId>()?.into(),
+    );
+    lhs.advance_offset(
+        second_worker.clone(),
+        "999-11".parse::<StreamEntryId>()?.into(),
+    );
+    let mut rhs = OffsetAntichain::new();
+    rhs.advance_offset(
+        first_worker.clone(),
+        "999-12".parse::<StreamEntryId>()?.into(),
+    );
+    rhs.advance_offset(
+        second_worker.clone(),
+        "999-12".parse::<StreamEntryId>()?.into(),
+    );
+
+    let merged = RedisStreamReader::merge_two_frontiers(&lhs, &rhs);
+    let expected_entry_id = |entry_id: &str| -> eyre::Result<OffsetValue> {
+        Ok(entry_id.parse::<StreamEntryId>()?.into())
+    };
+    assert_eq!(
+        merged.get_offset(&first_worker),
+        Some(&expected_entry_id("1000-0")?)
+    );
+    assert_eq!(
+        merged.get_offset(&second_worker),
+        Some(&expected_entry_id("999-12")?)
+    );
+    Ok(())
+}
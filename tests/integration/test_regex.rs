@@ -0,0 +1,128 @@
+// Copyright © 2026 Pathway
+
+use std::sync::Arc;
+
+use arcstr::ArcStr;
+
+use pathway_engine::engine::{
+    AnyExpression, BoolExpression, Expression, RegexCache, StringExpression, Value,
+};
+
+use super::helpers::{argument, eval_rows};
+
+fn constant(value: impl Into<Value>) -> Arc<Expression> {
+    Arc::new(Expression::Any(AnyExpression::Const(value.into())))
+}
+
+#[test]
+fn test_regex_cache_compiles_once() {
+    let cache = RegexCache::new();
+    let pattern = ArcStr::from(r"\d+");
+    let first = cache.get(&pattern).unwrap();
+    let second = cache.get(&pattern).unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(cache.get(&ArcStr::from("(unclosed")).is_err());
+}
+
+#[test]
+fn test_regex_match() {
+    let expression = Expression::Bool(BoolExpression::RegexMatch(
+        argument(0),
+        constant(r"^[^@]+@[^@]+$"),
+        RegexCache::new(),
+    ));
+    let result = eval_rows(
+        &expression,
+        &[
+            vec![Value::from("alice@example.com")],
+            vec![Value::from("bob")],
+        ],
+    );
+    assert_eq!(result, vec![Ok(Value::Bool(true)), Ok(Value::Bool(false))]);
+}
+
+#[test]
+fn test_regex_extract() {
+    let expression = Expression::Any(AnyExpression::RegexExtract(
+        argument(0),
+        constant(r"user=(\w+)(;id=(\d+))?"),
+        constant(Value::Int(3)),
+        RegexCache::new(),
+    ));
+    let result = eval_rows(
+        &expression,
+        &[
+            vec![Value::from("user=alice;id=17")],
+            vec![Value::from("user=bob")],
+            vec![Value::from("no_user")],
+        ],
+    );
+    assert_eq!(
+        result,
+        vec![Ok(Value::from("17")), Ok(Value::None), Ok(Value::None)]
+    );
+
+    let out_of_range = Expression::Any(AnyExpression::RegexExtract(
+        argument(0),
+        constant(r"user=(\w+)"),
+        constant(Value::Int(2)),
+        RegexCache::new(),
+    ));
+    let result = out_of_range.eval(&[&[Value::from("user=alice")]]);
+    assert!(result[0].is_err());
+}
+
+#[test]
+fn test_regex_replace() {
+    let expression = Expression::String(StringExpression::RegexReplace(
+        argument(0),
+        constant(r"(\w+)@(\w+)"),
+        constant("$2/$1"),
+        RegexCache::new(),
+    ));
+    let result = eval_rows(
+        &expression,
+        &[
+            vec![Value::from("alice@example")],
+            vec![Value::from("no match")],
+        ],
+    );
+    assert_eq!(
+        result,
+        vec![
+            Ok(Value::from("example/alice")),
+            Ok(Value::from("no match"))
+        ]
+    );
+}
+
+#[test]
+fn test_regex_split() {
+    let expression = Expression::Any(AnyExpression::RegexSplit(
+        argument(0),
+        constant("[-_]"),
+        RegexCache::new(),
+    ));
+    let result = eval_rows(&expression, &[vec![Value::from("d__e")]]);
+    assert_eq!(
+        result,
+        vec![Ok(Value::from(
+            [Value::from("d"), Value::from(""), Value::from("e")].as_slice()
+        ))]
+    );
+}
+
+#[test]
+fn test_regex_invalid_pattern() {
+    let expression = Expression::Bool(BoolExpression::RegexMatch(
+        argument(0),
+        argument(1),
+        RegexCache::new(),
+    ));
+    let result = expression.eval(&[
+        &[Value::from("abc"), Value::from("a+")],
+        &[Value::from("abc"), Value::from("(unclosed")],
+    ]);
+    assert_eq!(*result[0].as_ref().unwrap(), Value::Bool(true));
+    assert!(result[1].is_err());
+}
@@ -0,0 +1,208 @@
+// Copyright © 2026 Pathway
+
+use std::mem::take;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use timely::communication::allocator::Generic;
+use timely::communication::WorkerGuards;
+use timely::worker::Worker;
+use timely::Config;
+
+use pathway_engine::engine::{Timestamp, TotalFrontier};
+use pathway_engine::persistence::savepoint::{
+    request_savepoint, set_active_coordinator, SavepointCoordinator, SavepointError,
+    SavepointExchange,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+// the time up to which a worker can commit its state and the result of committing it
+type WorkerState = (TotalFrontier<Timestamp>, Result<(), String>);
+
+struct Computation {
+    coordinators: Vec<Arc<SavepointCoordinator>>,
+    // the times the workers committed their state up to, by worker index
+    committed: Arc<Mutex<Vec<Vec<Timestamp>>>>,
+    stopped: Arc<AtomicBool>,
+    guards: WorkerGuards<()>,
+}
+
+impl Computation {
+    // Runs a worker for each of `states`. Every worker emulates a separate process, with
+    // a coordinator of its own.
+    fn run(states: Vec<WorkerState>) -> Self {
+        let coordinators: Vec<_> = states
+            .iter()
+            .map(|_| Arc::new(SavepointCoordinator::new(1)))
+            .collect();
+        let committed = Arc::new(Mutex::new(vec![Vec::new(); states.len()]));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let guards = {
+            let coordinators = coordinators.clone();
+            let committed = committed.clone();
+            let stopped = stopped.clone();
+            timely::execute(
+                Config::process(states.len()),
+                move |worker: &mut Worker<Generic>| {
+                    let worker_index = worker.index();
+                    let coordinator = coordinators[worker_index].clone();
+                    let (frontier, result) = states[worker_index].clone();
+                    let mut exchange = worker
+                        .dataflow::<u64, _, _>(|scope| SavepointExchange::new(scope, coordinator));
+                    while !stopped.load(Ordering::SeqCst) {
+                        worker.step_or_park(Some(Duration::from_millis(10)));
+                        exchange.process(
+                            || frontier,
+                            |time| {
+                                assert!(TotalFrontier::At(time) <= frontier);
+                                committed.lock().unwrap()[worker_index].push(time);
+                                result.clone()
+                            },
+                        );
+                    }
+                },
+            )
+            .unwrap()
+        };
+        Self {
+            coordinators,
+            committed,
+            stopped,
+            guards,
+        }
+    }
+
+    fn stop(self) -> (Vec<Arc<SavepointCoordinator>>, Vec<Vec<Timestamp>>) {
+        self.stopped.store(true, Ordering::SeqCst);
+        for result in self.guards.join() {
+            result.unwrap();
+        }
+        let committed = take(&mut *self.committed.lock().unwrap());
+        (self.coordinators, committed)
+    }
+}
+
+#[test]
+fn test_savepoint_is_committed_by_the_workers_of_all_processes() {
+    let computation = Computation::run(vec![
+        (TotalFrontier::At(Timestamp(10)), Ok(())),
+        (TotalFrontier::At(Timestamp(7)), Ok(())),
+        (TotalFrontier::At(Timestamp(12)), Ok(())),
+    ]);
+    // the same savepoint is returned whichever process it is requested in
+    assert_eq!(
+        computation.coordinators[2].request(TIMEOUT),
+        Ok(Timestamp(7))
+    );
+    assert_eq!(
+        computation.coordinators[0].request(TIMEOUT),
+        Ok(Timestamp(7))
+    );
+    // all the workers commit their state up to the common time
+    let (_coordinators, committed) = computation.stop();
+    assert_eq!(committed, vec![vec![Timestamp(7), Timestamp(7)]; 3]);
+}
+
+#[test]
+fn test_failure_in_another_process_is_reported() {
+    let computation = Computation::run(vec![
+        (TotalFrontier::At(Timestamp(3)), Ok(())),
+        (
+            TotalFrontier::At(Timestamp(5)),
+            Err("disk full".to_string()),
+        ),
+    ]);
+    assert_eq!(
+        computation.coordinators[0].request(TIMEOUT),
+        Err(SavepointError::Failed("disk full".to_string()))
+    );
+    computation.stop();
+}
+
+#[test]
+fn test_finished_workers_are_skipped() {
+    let computation = Computation::run(vec![
+        (TotalFrontier::Done, Ok(())),
+        (TotalFrontier::At(Timestamp(8)), Ok(())),
+    ]);
+    assert_eq!(
+        computation.coordinators[0].request(TIMEOUT),
+        Ok(Timestamp(8))
+    );
+    let (coordinators, committed) = computation.stop();
+    assert_eq!(committed, vec![vec![Timestamp(8)]; 2]);
+    assert_eq!(
+        coordinators[1].request(TIMEOUT),
+        Err(SavepointError::Finished)
+    );
+}
+
+#[test]
+fn test_savepoint_of_finished_computation() {
+    let computation = Computation::run(vec![
+        (TotalFrontier::Done, Ok(())),
+        (TotalFrontier::Done, Ok(())),
+    ]);
+    assert_eq!(
+        computation.coordinators[1].request(TIMEOUT),
+        Err(SavepointError::Finished)
+    );
+    // nothing is left to commit
+    let (_coordinators, committed) = computation.stop();
+    assert_eq!(committed, vec![Vec::<Timestamp>::new(); 2]);
+}
+
+#[test]
+fn test_savepoints_are_led_by_the_first_worker_of_a_process() {
+    // two processes running two workers each
+    let coordinator = SavepointCoordinator::new(2);
+    assert!(coordinator.is_leader(0));
+    assert!(!coordinator.is_leader(1));
+    assert!(coordinator.is_leader(2));
+    assert!(!coordinator.is_leader(3));
+}
+
+#[test]
+fn test_savepoint_times_out() {
+    // no worker leads the savepoints
+    let coordinator = SavepointCoordinator::new(2);
+    let timeout = Duration::from_millis(100);
+    assert_eq!(
+        coordinator.request(timeout),
+        Err(SavepointError::TimedOut(timeout))
+    );
+}
+
+#[test]
+fn test_request_savepoint_of_running_computation() {
+    assert_eq!(
+        request_savepoint(TIMEOUT),
+        Err(SavepointError::NoPersistence)
+    );
+    let coordinator = Arc::new(SavepointCoordinator::new(1));
+    set_active_coordinator(&coordinator);
+    let leader = {
+        let coordinator = coordinator.clone();
+        thread::spawn(move || {
+            coordinator.register_leader(thread::current());
+            loop {
+                if let Some(number) = coordinator.start_savepoint() {
+                    coordinator.savepoint_completed(number, Ok(Timestamp(12)));
+                    break;
+                }
+                thread::park_timeout(Duration::from_millis(10));
+            }
+        })
+    };
+    assert_eq!(request_savepoint(TIMEOUT), Ok(Timestamp(12)));
+    leader.join().unwrap();
+
+    drop(coordinator);
+    assert_eq!(
+        request_savepoint(TIMEOUT),
+        Err(SavepointError::NoPersistence)
+    );
+}
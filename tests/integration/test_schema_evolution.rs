@@ -0,0 +1,198 @@
+// Copyright © 2026 Pathway
+
+use super::helpers::{assert_error_shown_for_raw_data, value_field, ErrorPlacement, ReplaceErrors};
+
+use std::collections::HashMap;
+
+use pathway_engine::connectors::data_format::{
+    DsvParser, DsvSettings, InnerSchemaField, JsonLinesParser, ParsedEvent, Parser,
+    SchemaEvolutionPolicy,
+};
+use pathway_engine::connectors::data_storage::{DataEventType, ReaderContext};
+use pathway_engine::connectors::SessionType;
+use pathway_engine::engine::{Type, Value};
+
+fn schema() -> HashMap<String, InnerSchemaField> {
+    [
+        ("a".to_string(), InnerSchemaField::new(Type::Int, None)),
+        ("b".to_string(), InnerSchemaField::new(Type::Int, None)),
+        (
+            "c".to_string(),
+            InnerSchemaField::new(Type::Int, Some(Value::Int(42))),
+        ),
+    ]
+    .into()
+}
+
+fn json_parser(policy: SchemaEvolutionPolicy) -> eyre::Result<JsonLinesParser> {
+    let parser = JsonLinesParser::new(
+        None,
+        vec![value_field("a"), value_field("b"), value_field("c")],
+        HashMap::new(),
+        true,
+        schema(),
+        SessionType::Native,
+        None,
+    )?;
+    Ok(parser.with_schema_evolution_policy(policy))
+}
+
+fn dsv_parser(policy: SchemaEvolutionPolicy) -> eyre::Result<DsvParser> {
+    let settings = DsvSettings::new(
+        None,
+        vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        ',',
+    )
+    .with_schema_evolution_policy(policy);
+    Ok(DsvParser::new(settings, schema())?)
+}
+
+fn parse_raw(parser: &mut dyn Parser, raw_data: &str) -> eyre::Result<Vec<ParsedEvent>> {
+    let context = ReaderContext::from_raw_bytes(DataEventType::Insert, raw_data.into());
+    Ok(parser
+        .parse(&context)
+        .map_err(|e| eyre::eyre!("{e}"))?
+        .into_iter()
+        .map(ReplaceErrors::replace_errors)
+        .collect())
+}
+
+#[test]
+fn test_json_new_fields_ignored() -> eyre::Result<()> {
+    let mut parser = json_parser(SchemaEvolutionPolicy::IgnoreNewFields)?;
+    assert_eq!(
+        parse_raw(&mut parser, r#"{"a": 1, "b": 2, "d": "new"}"#)?,
+        vec![ParsedEvent::Insert((
+            None,
+            vec![Value::Int(1), Value::Int(2), Value::Int(42)]
+        ))]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_json_missing_fields_filled() -> eyre::Result<()> {
+    let mut parser = json_parser(SchemaEvolutionPolicy::FillMissing)?;
+    assert_eq!(
+        parse_raw(&mut parser, r#"{"a": 1, "d": "new"}"#)?,
+        vec![ParsedEvent::Insert((
+            None,
+            vec![Value::Int(1), Value::None, Value::Int(42)]
+        ))]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_json_new_fields_fail() -> eyre::Result<()> {
+    let mut parser = json_parser(SchemaEvolutionPolicy::Fail)?;
+    assert_eq!(
+        parse_raw(&mut parser, r#"{"a": 1, "b": 2}"#)?,
+        vec![ParsedEvent::Insert((
+            None,
+            vec![Value::Int(1), Value::Int(2), Value::Int(42)]
+        ))]
+    );
+
+    assert_error_shown_for_raw_data(
+        br#"{"a": 1, "b": 2, "d": "new"}"#,
+        Box::new(json_parser(SchemaEvolutionPolicy::Fail)?),
+        r#"fields ["d"] aren't present in the schema"#,
+        ErrorPlacement::Message,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_json_new_fields_fail_with_paths() -> eyre::Result<()> {
+    let parser = JsonLinesParser::new(
+        None,
+        vec![value_field("a"), value_field("b"), value_field("c")],
+        [("b".to_string(), "/nested/b".to_string())].into(),
+        true,
+        schema(),
+        SessionType::Native,
+        None,
+    )?;
+    let mut parser = parser.with_schema_evolution_policy(SchemaEvolutionPolicy::Fail);
+    assert_eq!(
+        parse_raw(&mut parser, r#"{"a": 1, "nested": {"b": 2, "other": 3}}"#)?,
+        vec![ParsedEvent::Insert((
+            None,
+            vec![Value::Int(1), Value::Int(2), Value::Int(42)]
+        ))]
+    );
+    assert_error_shown_for_raw_data(
+        br#"{"a": 1, "b": 2, "nested": {"b": 2}}"#,
+        Box::new(parser),
+        r#"fields ["b"] aren't present in the schema"#,
+        ErrorPlacement::Message,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dsv_missing_column_with_default() -> eyre::Result<()> {
+    for policy in [
+        SchemaEvolutionPolicy::IgnoreNewFields,
+        SchemaEvolutionPolicy::FillMissing,
+        SchemaEvolutionPolicy::Fail,
+    ] {
+        let mut parser = dsv_parser(policy)?;
+        assert_eq!(parse_raw(&mut parser, "a,b")?, vec![]);
+        assert_eq!(
+            parse_raw(&mut parser, "1,2")?,
+            vec![ParsedEvent::Insert((
+                None,
+                vec![Value::Int(1), Value::Int(2), Value::Int(42)]
+            ))]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dsv_missing_columns_filled() -> eyre::Result<()> {
+    let mut parser = dsv_parser(SchemaEvolutionPolicy::FillMissing)?;
+    assert_eq!(parse_raw(&mut parser, "d,a")?, vec![]);
+    assert_eq!(
+        parse_raw(&mut parser, "new,1")?,
+        vec![ParsedEvent::Insert((
+            None,
+            vec![Value::Int(1), Value::None, Value::Int(42)]
+        ))]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dsv_missing_column_without_default() -> eyre::Result<()> {
+    assert_error_shown_for_raw_data(
+        b"d,a",
+        Box::new(dsv_parser(SchemaEvolutionPolicy::IgnoreNewFields)?),
+        r#"some fields weren't found in the header (fields present in table: ["d", "a"], fields specified in connector: ["a", "b", "c"])"#,
+        ErrorPlacement::Message,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dsv_new_columns_fail() -> eyre::Result<()> {
+    let mut parser = dsv_parser(SchemaEvolutionPolicy::IgnoreNewFields)?;
+    assert_eq!(parse_raw(&mut parser, "a,d,b")?, vec![]);
+    assert_eq!(
+        parse_raw(&mut parser, "1,new,2")?,
+        vec![ParsedEvent::Insert((
+            None,
+            vec![Value::Int(1), Value::Int(2), Value::Int(42)]
+        ))]
+    );
+
+    assert_error_shown_for_raw_data(
+        b"a,d,b",
+        Box::new(dsv_parser(SchemaEvolutionPolicy::Fail)?),
+        r#"fields ["d"] aren't present in the schema"#,
+        ErrorPlacement::Message,
+    );
+    Ok(())
+}
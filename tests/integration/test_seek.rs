@@ -15,9 +15,13 @@ use pathway_engine::connectors::data_format::{
     DsvParser, DsvSettings, InnerSchemaField, JsonLinesParser, ParsedEvent, Parser,
 };
 use pathway_engine::connectors::data_storage::ReaderBuilder;
-use pathway_engine::connectors::data_storage::{ConnectorMode, ReadMethod};
+use pathway_engine::connectors::data_storage::{
+    ConnectorMode, DataEventType, KeysSinceStart, ReadError, ReadMethod, Reader,
+};
+use pathway_engine::connectors::offset::{OffsetKey, OffsetValue};
 use pathway_engine::connectors::SessionType;
 use pathway_engine::engine::{Result, Type, Value};
+use pathway_engine::persistence::frontier::OffsetAntichain;
 use pathway_engine::persistence::tracker::WorkerPersistentStorage;
 use pathway_engine::persistence::PersistentId;
 
@@ -455,3 +459,44 @@ fn test_json_recovery_with_new_file() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_file_reader_rejects_start_from() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let mut reader = new_filesystem_reader(
+        test_storage.path().to_str().unwrap(),
+        ConnectorMode::Static,
+        ReadMethod::ByLine,
+        "*",
+        false,
+    )?;
+    let mut start_from = OffsetAntichain::new();
+    start_from.advance_offset(
+        OffsetKey::Empty,
+        OffsetValue::PosixLikeOffset {
+            total_entries_read: 10,
+            path: Arc::from(b"input.txt".as_slice()),
+            bytes_offset: 0,
+            cached_object_version: None,
+        },
+    );
+    assert!(matches!(
+        reader.start_from(&start_from),
+        Err(ReadError::StartFromNotSupported(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_deletions_of_rows_before_the_start_are_dropped() {
+    let mut keys = KeysSinceStart::default();
+    // the row was inserted before the start
+    assert!(!keys.admit(DataEventType::Delete, &[Value::Int(1)]));
+    assert!(keys.admit(DataEventType::Insert, &[Value::Int(1)]));
+    assert!(keys.admit(DataEventType::Delete, &[Value::Int(1)]));
+    // deleted twice
+    assert!(!keys.admit(DataEventType::Delete, &[Value::Int(1)]));
+    // an update of a row inserted before the start becomes an insertion
+    assert!(!keys.admit(DataEventType::Delete, &[Value::Int(2)]));
+    assert!(keys.admit(DataEventType::Insert, &[Value::Int(2)]));
+}
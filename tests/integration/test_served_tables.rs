@@ -0,0 +1,220 @@
+// Copyright © 2026 Pathway
+
+use std::sync::Arc;
+
+use assert_matches::assert_matches;
+use hyper::StatusCode;
+use serde_json::json;
+
+use pathway_engine::engine::served_tables::{query_served_tables, register_served_table};
+use pathway_engine::engine::{Error, ExportedTable, Key, Timestamp, TotalFrontier, Value};
+
+use super::helpers::{column_names, row, TestExportedTable};
+
+const COLUMNS: [&str; 2] = ["owner", "age"];
+
+fn serve(
+    route: &str,
+    worker_index: usize,
+    table: &Arc<TestExportedTable>,
+) -> Arc<dyn ExportedTable> {
+    let table: Arc<dyn ExportedTable> = table.clone();
+    register_served_table(
+        route.to_string(),
+        column_names(&COLUMNS),
+        worker_index,
+        &table,
+    )
+    .expect("registration should succeed");
+    table
+}
+
+#[test]
+fn test_scan_returns_consistent_state() {
+    let table = TestExportedTable::new(
+        vec![
+            row(1, "Alice", Some(3), 0, 1),
+            row(2, "Bob", Some(5), 0, 1),
+            row(2, "Bob", Some(5), 2, -1),
+            row(3, "Carol", Some(7), 4, 1),
+        ],
+        TotalFrontier::At(Timestamp(4)),
+    );
+    let _table = serve("scan", 0, &table);
+
+    let (status, body) = query_served_tables("/scan", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["frontier"], json!(4));
+    let rows = body["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["owner"], json!("Alice"));
+    assert_eq!(rows[0]["age"], json!(3));
+}
+
+#[test]
+fn test_scan_with_filters() {
+    let table = TestExportedTable::new(
+        vec![
+            row(1, "Alice", Some(3), 0, 1),
+            row(2, "Bob", Some(3), 0, 1),
+            row(3, "Alice", Some(5), 0, 1),
+        ],
+        TotalFrontier::Done,
+    );
+    let _table = serve("filters", 0, &table);
+
+    let (status, body) = query_served_tables("/filters", Some("owner=Alice&age=3"));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["frontier"], json!(null));
+    let rows = body["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["owner"], json!("Alice"));
+    assert_eq!(rows[0]["age"], json!(3));
+
+    let (status, body) = query_served_tables("/filters", Some("owner=%22Bob%22"));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["rows"].as_array().unwrap().len(), 1);
+
+    let (status, body) = query_served_tables("/filters", Some("name=Alice"));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], json!("unknown column \"name\""));
+}
+
+#[test]
+fn test_key_lookup() {
+    let table = TestExportedTable::new(
+        vec![row(1, "Alice", Some(3), 0, 1), row(2, "Bob", Some(5), 0, 1)],
+        TotalFrontier::Done,
+    );
+    let _table = serve("lookup", 0, &table);
+    let key = Key::for_value(&Value::Int(2)).to_string();
+
+    let (status, body) = query_served_tables(&format!("/lookup/{key}"), None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["row"]["id"], json!(key));
+    assert_eq!(body["row"]["owner"], json!("Bob"));
+
+    let (status, body) = query_served_tables(&format!("/lookup/%5E{}", &key[1..]), None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["row"]["owner"], json!("Bob"));
+
+    let missing = Key::for_value(&Value::Int(3)).to_string();
+    let (status, _body) = query_served_tables(&format!("/lookup/{missing}"), None);
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn test_parts_of_many_workers() {
+    let first = TestExportedTable::new(vec![row(1, "Alice", Some(3), 0, 1)], TotalFrontier::Done);
+    let second = TestExportedTable::new(
+        vec![row(2, "Bob", Some(5), 0, 1), row(3, "Carol", Some(7), 6, 1)],
+        TotalFrontier::At(Timestamp(6)),
+    );
+    let _first = serve("workers", 0, &first);
+    let _second = serve("workers", 1, &second);
+
+    let (status, body) = query_served_tables("/workers", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["frontier"], json!(6));
+    assert_eq!(body["rows"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_registration_errors() {
+    let table = TestExportedTable::new(vec![], TotalFrontier::Done);
+    let _table = serve("conflict", 0, &table);
+    let other: Arc<dyn ExportedTable> = TestExportedTable::new(vec![], TotalFrontier::Done);
+
+    assert_matches!(
+        register_served_table("conflict".to_string(), column_names(&COLUMNS), 0, &other),
+        Err(Error::TableRouteAlreadyUsed(route)) if route == "conflict"
+    );
+    assert_matches!(
+        register_served_table("conflict".to_string(), vec!["owner".to_string()], 1, &other),
+        Err(Error::TableRouteAlreadyUsed(_))
+    );
+    assert_matches!(
+        register_served_table("a/b".to_string(), column_names(&COLUMNS), 0, &other),
+        Err(Error::InvalidTableRoute(_))
+    );
+}
+
+#[test]
+fn test_route_released_when_table_dropped() {
+    let table = TestExportedTable::new(vec![row(1, "Alice", Some(3), 0, 1)], TotalFrontier::Done);
+    let served = serve("released", 0, &table);
+    let (status, _body) = query_served_tables("/released", None);
+    assert_eq!(status, StatusCode::OK);
+
+    drop(served);
+    drop(table);
+    let (status, _body) = query_served_tables("/released", None);
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let table = TestExportedTable::new(vec![], TotalFrontier::Done);
+    let _table = serve("released", 0, &table);
+    let (status, body) = query_served_tables("", None);
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["tables"]
+        .as_array()
+        .unwrap()
+        .contains(&json!({ "route": "released", "columns": ["owner", "age"] })));
+}
+
+#[test]
+fn test_state_follows_changes() {
+    let table = TestExportedTable::new(
+        vec![row(1, "Alice", Some(3), 0, 1), row(2, "Bob", Some(5), 0, 1)],
+        TotalFrontier::At(Timestamp(2)),
+    );
+    let _table = serve("changes", 0, &table);
+    let (_status, body) = query_served_tables("/changes", None);
+    assert_eq!(body["rows"].as_array().unwrap().len(), 2);
+
+    table.advance(
+        vec![
+            row(1, "Alice", Some(3), 2, -1),
+            row(1, "Alice", Some(4), 2, 1),
+            row(2, "Bob", Some(5), 4, -1),
+        ],
+        TotalFrontier::At(Timestamp(4)),
+    );
+    let (status, body) = query_served_tables("/changes", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["frontier"], json!(4));
+    let rows = body["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    assert!(rows.contains(&json!({
+        "id": Key::for_value(&Value::Int(1)).to_string(),
+        "owner": "Alice",
+        "age": 4,
+    })));
+
+    table.advance(vec![], TotalFrontier::Done);
+    let (_status, body) = query_served_tables("/changes", None);
+    assert_eq!(body["frontier"], json!(null));
+    let rows = body["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["owner"], json!("Alice"));
+}
+
+#[test]
+fn test_key_lookup_with_filters() {
+    let table = TestExportedTable::new(vec![row(1, "Alice", Some(3), 0, 1)], TotalFrontier::Done);
+    let _table = serve("lookup_filters", 0, &table);
+    let key = Key::for_value(&Value::Int(1)).to_string();
+
+    let (status, body) =
+        query_served_tables(&format!("/lookup_filters/{key}"), Some("owner=Alice"));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["row"]["age"], json!(3));
+
+    let (status, _body) = query_served_tables(&format!("/lookup_filters/{key}"), Some("age=4"));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _body) = query_served_tables(&format!("/lookup_filters/{key}"), Some("name=x"));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _body) = query_served_tables("/lookup_filters/^not-an-id", None);
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,108 @@
+// Copyright © 2026 Pathway
+
+use ssh2::FileStat;
+use tempfile::tempdir;
+
+use pathway_engine::connectors::data_storage::scanner::{actions_for_listing, QueuedAction};
+use pathway_engine::connectors::metadata::FileLikeMetadata;
+use pathway_engine::persistence::backends::FilesystemKVStorage;
+use pathway_engine::persistence::cached_object_storage::CachedObjectStorage;
+
+fn remote_file(path: &str, size: u64, modified_at: u64) -> FileLikeMetadata {
+    let stat = FileStat {
+        size: Some(size),
+        uid: Some(1000),
+        gid: Some(1000),
+        perm: Some(0o100_644),
+        atime: None,
+        mtime: Some(modified_at),
+    };
+    FileLikeMetadata::from_sftp_stat(path, &stat)
+}
+
+fn action_summary(actions: &[QueuedAction]) -> Vec<(&'static str, String)> {
+    actions
+        .iter()
+        .map(|action| {
+            let kind = match action {
+                QueuedAction::Read(..) => "read",
+                QueuedAction::Update(..) => "update",
+                QueuedAction::Delete(..) => "delete",
+            };
+            (kind, String::from_utf8(action.path().to_vec()).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_new_files_are_ordered_by_modification_time() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let backend = FilesystemKVStorage::new(test_storage.path())?;
+    let storage = CachedObjectStorage::new(Box::new(backend))?;
+
+    let listing = vec![
+        remote_file("/drop/c.csv", 10, 300),
+        remote_file("/drop/nested/a.csv", 10, 100),
+        remote_file("/drop/b.csv", 10, 200),
+        remote_file("/drop/a.csv", 10, 200),
+    ];
+    let actions = actions_for_listing(listing, true, &storage);
+    assert_eq!(
+        action_summary(&actions),
+        vec![
+            ("read", "/drop/nested/a.csv".to_string()),
+            ("read", "/drop/a.csv".to_string()),
+            ("read", "/drop/b.csv".to_string()),
+            ("read", "/drop/c.csv".to_string()),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_processed_files_are_tracked() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let backend = FilesystemKVStorage::new(test_storage.path())?;
+    let mut storage = CachedObjectStorage::new(Box::new(backend))?;
+    for metadata in [
+        remote_file("/drop/kept.csv", 10, 100),
+        remote_file("/drop/modified.csv", 10, 100),
+        remote_file("/drop/deleted.csv", 10, 100),
+    ] {
+        let path = metadata.path.clone();
+        storage.place_object(path.as_bytes(), b"contents", metadata)?;
+    }
+
+    let listing = vec![
+        remote_file("/drop/new.csv", 10, 50),
+        remote_file("/drop/kept.csv", 10, 100),
+        remote_file("/drop/modified.csv", 20, 150),
+    ];
+    let actions = actions_for_listing(listing.clone(), true, &storage);
+    assert_eq!(
+        action_summary(&actions),
+        vec![
+            ("update", "/drop/modified.csv".to_string()),
+            ("delete", "/drop/deleted.csv".to_string()),
+            ("read", "/drop/new.csv".to_string()),
+        ]
+    );
+
+    // Without deletions, only the files not processed yet are reported
+    let actions = actions_for_listing(listing, false, &storage);
+    assert_eq!(
+        action_summary(&actions),
+        vec![("read", "/drop/new.csv".to_string())]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sftp_metadata() {
+    let metadata = remote_file("/drop/a.csv", 42, 1_700_000_000);
+    assert_eq!(metadata.path, "/drop/a.csv");
+    assert_eq!(metadata.size, 42);
+    assert_eq!(metadata.modified_at, Some(1_700_000_000));
+    assert!(!metadata.is_changed(&remote_file("/drop/a.csv", 42, 1_700_000_000)));
+    assert!(metadata.is_changed(&remote_file("/drop/a.csv", 42, 1_700_000_001)));
+}
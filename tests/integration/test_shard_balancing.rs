@@ -0,0 +1,226 @@
+// Copyright © 2026 Pathway
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use differential_dataflow::input::InputSession;
+use timely::dataflow::operators::{Inspect, Probe};
+use timely::dataflow::ProbeHandle;
+use timely::Config;
+
+use pathway_engine::engine::dataflow::operators::ReshardBalanced;
+use pathway_engine::engine::dataflow::shard::{Shard, ShardAssignment, ShardMove};
+use pathway_engine::engine::{Key, KeyImpl};
+
+type RoutedRows = Vec<(usize, Key, u64, isize)>;
+
+#[test]
+fn test_initial_assignment_matches_static_sharding() {
+    let assignment = ShardAssignment::new(3, 6, 4);
+    assert_eq!(assignment.logical_shards(), 24);
+    for shard in 0..1000 {
+        let logical_shard = assignment.logical_shard(shard);
+        assert_eq!(assignment.worker(logical_shard) as u64, shard % 6);
+    }
+}
+
+#[test]
+fn test_heavy_shards_are_spread() {
+    let mut assignment = ShardAssignment::new(2, 2, 4);
+    // logical shards 0 and 2 start on the same worker
+    assignment.count(0, 1000);
+    assignment.count(2, 1000);
+    assignment.count(1, 1);
+    assert_eq!(
+        assignment.rebalance(),
+        vec![ShardMove {
+            shard: 0,
+            from: 0,
+            to: 1
+        }]
+    );
+    assert_eq!(assignment.worker(0), 1);
+    assert_eq!(assignment.worker(2), 0);
+}
+
+#[test]
+fn test_balanced_shards_stay() {
+    let mut assignment = ShardAssignment::new(2, 2, 4);
+    for shard in 0..8 {
+        assignment.count(shard, 100);
+    }
+    assert_eq!(assignment.rebalance(), vec![]);
+}
+
+#[test]
+fn test_shards_stay_in_their_process() {
+    // two processes with two workers each
+    let mut assignment = ShardAssignment::new(2, 4, 3);
+    // all of them are on the worker 0 initially
+    for shard in [0, 4, 8] {
+        assignment.count(shard, 1000);
+    }
+    assignment.rebalance();
+    let workers: HashSet<_> = [0, 4, 8]
+        .into_iter()
+        .map(|shard| assignment.worker(shard))
+        .collect();
+    assert_eq!(workers, HashSet::from([0, 1]));
+}
+
+#[test]
+fn test_shards_are_rebalanced_after_the_period() {
+    let mut assignment = ShardAssignment::new(2, 2, 4).with_rebalance_period(100);
+    assignment.count(0, 50);
+    assignment.count(2, 49);
+    assert_eq!(assignment.rebalance_if_due(), vec![]);
+    assignment.count(2, 1);
+    assert_eq!(assignment.rebalance_if_due().len(), 1);
+}
+
+fn key_with_logical_shard(logical_shard: u64, logical_shards: u64) -> Key {
+    (0u32..)
+        .map(|value| Key(KeyImpl::from(value)))
+        .find(|key| key.shard() % logical_shards == logical_shard)
+        .unwrap()
+}
+
+#[test]
+fn test_rows_move_with_their_shard() {
+    // two workers with four logical shards each
+    let heavy_1 = key_with_logical_shard(0, 8);
+    let heavy_2 = key_with_logical_shard(2, 8);
+    let light = key_with_logical_shard(1, 8);
+    let routed: Arc<Mutex<RoutedRows>> = Arc::default();
+
+    let guards = {
+        let routed = routed.clone();
+        timely::execute(Config::process(2), move |worker| {
+            let index = worker.index();
+            let mut input: InputSession<u64, Key, isize> = InputSession::new();
+            let mut probe = ProbeHandle::new();
+            worker.dataflow(|scope| {
+                let routed = routed.clone();
+                input
+                    .to_collection(scope)
+                    .reshard_balanced(2, 4)
+                    .inner
+                    .inspect(move |(key, time, diff)| {
+                        routed.lock().unwrap().push((index, *key, *time, *diff));
+                    })
+                    .probe_with(&mut probe);
+            });
+            if index == 0 {
+                for time in 0..10 {
+                    input.advance_to(time);
+                    for _ in 0..10_000 {
+                        input.insert(heavy_1);
+                        input.insert(heavy_2);
+                    }
+                    input.insert(light);
+                }
+            }
+            input.advance_to(10);
+            input.flush();
+            worker.step_while(|| probe.less_than(&10));
+        })
+        .unwrap()
+    };
+    for result in guards.join() {
+        result.unwrap();
+    }
+
+    let routed = routed.lock().unwrap().clone();
+    let mut counts: HashMap<(usize, Key), isize> = HashMap::new();
+    for time in 0..10 {
+        for (worker, key, _time, diff) in routed.iter().filter(|row| row.2 == time) {
+            *counts.entry((*worker, *key)).or_default() += diff;
+        }
+        // at any time, all the rows of a key are on a single worker
+        for key in [heavy_1, heavy_2, light] {
+            let workers: Vec<_> = (0..2)
+                .filter(|worker| counts.get(&(*worker, key)).copied().unwrap_or_default() != 0)
+                .collect();
+            assert_eq!(workers.len(), 1, "{key:?} is on {workers:?} at {time}");
+            assert!(counts[&(workers[0], key)] > 0);
+        }
+    }
+    // the first heavy key moved to the idle worker with all its rows
+    assert_eq!(counts[&(1, heavy_1)], 100_000);
+    assert_eq!(counts[&(0, heavy_2)], 100_000);
+    assert_eq!(counts[&(1, light)], 10);
+    assert!(routed
+        .iter()
+        .any(|(worker, key, _time, _diff)| *worker == 0 && *key == heavy_1));
+}
+
+#[test]
+fn test_retracted_rows_are_not_moved() {
+    // two workers with four logical shards each
+    let heavy_1 = key_with_logical_shard(0, 8);
+    let heavy_2 = key_with_logical_shard(2, 8);
+    let light = key_with_logical_shard(1, 8);
+    let routed: Arc<Mutex<RoutedRows>> = Arc::default();
+
+    let guards = {
+        let routed = routed.clone();
+        timely::execute(Config::process(2), move |worker| {
+            let index = worker.index();
+            let mut input: InputSession<u64, Key, isize> = InputSession::new();
+            let mut probe = ProbeHandle::new();
+            worker.dataflow(|scope| {
+                let routed = routed.clone();
+                input
+                    .to_collection(scope)
+                    .reshard_balanced(2, 4)
+                    .inner
+                    .inspect(move |(key, time, diff)| {
+                        routed.lock().unwrap().push((index, *key, *time, *diff));
+                    })
+                    .probe_with(&mut probe);
+            });
+            if index == 0 {
+                for time in 0..4 {
+                    input.advance_to(time);
+                    for _ in 0..20_000 {
+                        match time {
+                            0 => input.insert(heavy_1),
+                            1 => input.remove(heavy_1),
+                            _ => {}
+                        }
+                    }
+                    if time == 3 {
+                        input.insert(heavy_1);
+                    }
+                    if time < 3 {
+                        for _ in 0..10_000 {
+                            input.insert(heavy_2);
+                        }
+                        input.insert(light);
+                    }
+                }
+            }
+            input.advance_to(4);
+            input.flush();
+            worker.step_while(|| probe.less_than(&4));
+        })
+        .unwrap()
+    };
+    for result in guards.join() {
+        result.unwrap();
+    }
+
+    let routed = routed.lock().unwrap().clone();
+    // the first heavy key moved to the idle worker at time 2, when none of its rows were left
+    let heavy_1_on_1: Vec<_> = routed
+        .iter()
+        .filter(|(worker, key, _time, _diff)| *worker == 1 && *key == heavy_1)
+        .collect();
+    assert_eq!(heavy_1_on_1, vec![&(1, heavy_1, 3, 1)]);
+    let mut counts: HashMap<(usize, Key), isize> = HashMap::new();
+    for (worker, key, _time, diff) in &routed {
+        *counts.entry((*worker, *key)).or_default() += diff;
+    }
+    assert_eq!(counts[&(0, heavy_1)], 0);
+    assert_eq!(counts[&(0, heavy_2)], 30_000);
+}
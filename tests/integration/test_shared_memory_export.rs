@@ -0,0 +1,374 @@
+// Copyright © 2026 Pathway
+
+use std::fs::File;
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::atomic::{fence, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use assert_matches::assert_matches;
+use deltalake::arrow::ipc::reader::StreamReader;
+use deltalake::arrow::record_batch::RecordBatch;
+use memmap2::Mmap;
+use tempfile::tempdir;
+
+use pathway_engine::engine::shared_memory_export::SharedMemoryExportedTable;
+use pathway_engine::engine::{Error, ExportedTable, Timestamp, TotalFrontier};
+
+use super::helpers::{
+    column_names, int_column, row, string_column, time_column, TestExportedTable,
+};
+
+const HEADER_SIZE: usize = 64;
+
+fn export(
+    table: &Arc<TestExportedTable>,
+    directory: &Path,
+    capacity: usize,
+) -> Result<Arc<SharedMemoryExportedTable>, Error> {
+    let column_names = column_names(&["owner", "age"]);
+    SharedMemoryExportedTable::new(table.clone(), directory, capacity, &column_names, 0, 1)
+}
+
+struct Ring(Vec<u8>);
+
+impl Ring {
+    fn read(directory: &Path) -> Self {
+        Self(std::fs::read(directory.join("0.ring")).unwrap())
+    }
+
+    fn u32_at(&self, offset: usize) -> u32 {
+        u32::from_le_bytes(self.0[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn u64_at(&self, offset: usize) -> u64 {
+        u64::from_le_bytes(self.0[offset..offset + 8].try_into().unwrap())
+    }
+
+    fn capacity(&self) -> usize {
+        self.u64_at(8).try_into().unwrap()
+    }
+
+    fn write_position(&self) -> u64 {
+        self.u64_at(24)
+    }
+
+    fn frontier(&self) -> u64 {
+        self.u64_at(32)
+    }
+
+    fn state(&self) -> u64 {
+        self.u64_at(40)
+    }
+
+    fn reserved_position(&self) -> u64 {
+        self.u64_at(48)
+    }
+
+    /// Returns the kind of the record at `offset` of the data area and its payload.
+    fn record_at(&self, offset: usize) -> (u32, &[u8]) {
+        let start = HEADER_SIZE + offset;
+        let length = self.u32_at(start) as usize;
+        let kind = self.u32_at(start + 4);
+        (kind, &self.0[start + 8..start + 8 + length])
+    }
+
+    fn batch_at(&self, offset: usize) -> RecordBatch {
+        let (kind, payload) = self.record_at(offset);
+        assert_eq!(kind, 1);
+        let mut reader = StreamReader::try_new(Cursor::new(payload), None).unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert!(reader.next().is_none());
+        batch
+    }
+}
+
+#[test]
+fn test_finished_table_is_written() -> eyre::Result<()> {
+    let directory = tempdir()?;
+    let table = TestExportedTable::new(
+        vec![
+            row(1, "Alice", Some(3), 0, 1),
+            row(2, "Bob", Some(5), 0, 1),
+            row(2, "Bob", Some(5), 0, -1),
+            row(3, "Carol", None, 2, 1),
+        ],
+        TotalFrontier::Done,
+    );
+    let _exported = export(&table, directory.path(), 1 << 16).unwrap();
+
+    let ring = Ring::read(directory.path());
+    assert_eq!(ring.0.len(), HEADER_SIZE + (1 << 16));
+    assert_eq!(&ring.0[0..8], b"PWRING01");
+    assert_eq!(ring.capacity(), 1 << 16);
+    assert_eq!((ring.u32_at(16), ring.u32_at(20)), (0, 1));
+    assert_eq!(ring.frontier(), u64::MAX);
+    assert_eq!(ring.state(), 1);
+
+    let (_kind, payload) = ring.record_at(0);
+    let record_size = (8 + payload.len()).next_multiple_of(8) as u64;
+    assert_eq!(ring.write_position(), record_size);
+    let batch = ring.batch_at(0);
+    let names: Vec<_> = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .collect();
+    assert_eq!(names, ["id", "owner", "age", "time", "diff"]);
+    let mut rows: Vec<_> = string_column(&batch, "owner")
+        .into_iter()
+        .zip(int_column(&batch, "age"))
+        .zip(time_column(&batch))
+        .collect();
+    rows.sort();
+    assert_eq!(
+        rows,
+        vec![
+            ((Some("Alice".to_string()), Some(3)), 0),
+            ((Some("Carol".to_string()), None), 2),
+        ]
+    );
+    assert_eq!(int_column(&batch, "diff"), vec![Some(1); 2]);
+    Ok(())
+}
+
+#[test]
+fn test_changes_are_written_per_frontier() -> eyre::Result<()> {
+    let directory = tempdir()?;
+    let table = TestExportedTable::new(
+        vec![row(1, "Alice", Some(3), 0, 1), row(2, "Bob", Some(5), 2, 1)],
+        TotalFrontier::At(Timestamp(2)),
+    );
+    let _exported = export(&table, directory.path(), 1 << 16).unwrap();
+
+    let ring = Ring::read(directory.path());
+    assert_eq!(ring.frontier(), 2);
+    assert_eq!(ring.state(), 0);
+    let batch = ring.batch_at(0);
+    assert_eq!(
+        string_column(&batch, "owner"),
+        vec![Some("Alice".to_string())]
+    );
+    let first_end = ring.write_position();
+
+    // nothing is finished, so nothing is written
+    table.advance(
+        vec![row(3, "Carol", None, 4, 1)],
+        TotalFrontier::At(Timestamp(2)),
+    );
+    assert_eq!(Ring::read(directory.path()).write_position(), first_end);
+
+    table.advance(
+        vec![
+            row(1, "Alice", Some(3), 4, -1),
+            row(1, "Alice", Some(4), 4, 1),
+        ],
+        TotalFrontier::At(Timestamp(4)),
+    );
+    let ring = Ring::read(directory.path());
+    assert_eq!(ring.frontier(), 4);
+    let batch = ring.batch_at(first_end.try_into()?);
+    assert_eq!(
+        string_column(&batch, "owner"),
+        vec![Some("Bob".to_string())]
+    );
+    assert_eq!(time_column(&batch), vec![2]);
+    let second_end = ring.write_position();
+
+    table.advance(vec![], TotalFrontier::Done);
+    let ring = Ring::read(directory.path());
+    assert_eq!(ring.frontier(), u64::MAX);
+    assert_eq!(ring.state(), 1);
+    let batch = ring.batch_at(second_end.try_into()?);
+    assert_eq!(time_column(&batch), vec![4, 4, 4]);
+    let mut changes: Vec<_> = int_column(&batch, "age")
+        .into_iter()
+        .zip(int_column(&batch, "diff"))
+        .collect();
+    changes.sort();
+    assert_eq!(
+        changes,
+        vec![(None, Some(1)), (Some(3), Some(-1)), (Some(4), Some(1))]
+    );
+    Ok(())
+}
+
+/// The size of a record with a single row.
+fn single_row_record_size() -> eyre::Result<usize> {
+    let directory = tempdir()?;
+    let table = TestExportedTable::new(vec![row(1, "Alice", Some(3), 0, 1)], TotalFrontier::Done);
+    let _exported = export(&table, directory.path(), 1 << 16).unwrap();
+    Ok(usize::try_from(
+        Ring::read(directory.path()).write_position(),
+    )?)
+}
+
+#[test]
+fn test_records_wrap_around() -> eyre::Result<()> {
+    let record_size = single_row_record_size()?;
+    let capacity = (record_size + record_size / 2).next_multiple_of(8);
+
+    let directory = tempdir()?;
+    let table = TestExportedTable::new(
+        vec![row(1, "Alice", Some(3), 0, 1)],
+        TotalFrontier::At(Timestamp(2)),
+    );
+    let _exported = export(&table, directory.path(), capacity).unwrap();
+    table.advance(vec![row(2, "Carol", Some(5), 2, 1)], TotalFrontier::Done);
+
+    let ring = Ring::read(directory.path());
+    let (kind, padding) = ring.record_at(record_size);
+    assert_eq!(kind, 0);
+    assert_eq!(padding.len(), capacity - record_size - 8);
+    let (_kind, payload) = ring.record_at(0);
+    let wrapped_size = (8 + payload.len()).next_multiple_of(8);
+    assert_eq!(ring.write_position(), (capacity + wrapped_size) as u64);
+    let batch = ring.batch_at(0);
+    assert_eq!(
+        string_column(&batch, "owner"),
+        vec![Some("Carol".to_string())]
+    );
+    assert_eq!(ring.state(), 1);
+    assert_eq!(ring.reserved_position(), ring.write_position());
+    Ok(())
+}
+
+#[test]
+fn test_overwritten_record_is_detected() -> eyre::Result<()> {
+    let record_size = single_row_record_size()?;
+    let capacity = (record_size + record_size / 2).next_multiple_of(8);
+
+    let directory = tempdir()?;
+    let table = TestExportedTable::new(
+        vec![row(1, "Alice", Some(3), 0, 1)],
+        TotalFrontier::At(Timestamp(2)),
+    );
+    let _exported = export(&table, directory.path(), capacity).unwrap();
+
+    // a consumer copies the first record, which is valid
+    let copy = Ring::read(directory.path());
+    let read_position = 0;
+    assert!(copy.reserved_position() <= read_position + capacity as u64);
+    assert_eq!(
+        string_column(&copy.batch_at(0), "owner"),
+        vec![Some("Alice".to_string())]
+    );
+
+    // the next record wraps around and overwrites it, which the consumer detects
+    table.advance(vec![row(2, "Carol", Some(5), 2, 1)], TotalFrontier::Done);
+    let ring = Ring::read(directory.path());
+    assert!(ring.reserved_position() > read_position + capacity as u64);
+    Ok(())
+}
+
+fn header_field(mmap: &Mmap, offset: usize) -> &AtomicU64 {
+    #[allow(clippy::cast_ptr_alignment)]
+    let field = mmap.as_ptr().wrapping_add(offset).cast::<AtomicU64>();
+    // SAFETY: the mapping is page-aligned and the fields of the header are aligned to 8 bytes
+    unsafe { &*field }
+}
+
+/// Reads the records of `path` while they are written, the way the consumers do, and
+/// checks that the records that aren't reported as overwritten are consistent. Returns
+/// the numbers of the records read and of the records skipped.
+fn read_while_written(path: &Path) -> (usize, usize) {
+    let file = File::open(path).unwrap();
+    // SAFETY: the file is only written through the mapping of the exporter
+    let mmap = unsafe { Mmap::map(&file) }.unwrap();
+    let field = |offset| header_field(&mmap, offset);
+    // SAFETY: the bytes are in the mapping, the writes to them are detected below
+    let copy = |start: usize, length: usize| -> Vec<u8> {
+        (start..start + length)
+            .map(|i| unsafe { mmap.as_ptr().add(i).read_volatile() })
+            .collect()
+    };
+    let capacity = field(8).load(Ordering::Acquire);
+    let mut read_position = 0;
+    let (mut read, mut skipped) = (0, 0);
+    loop {
+        let state = field(40).load(Ordering::Acquire);
+        let write_position = field(24).load(Ordering::Acquire);
+        if read_position == write_position {
+            if state != 0 {
+                return (read, skipped);
+            }
+            thread::yield_now();
+            continue;
+        }
+        let offset = usize::try_from(read_position % capacity).unwrap();
+        let available = usize::try_from(capacity).unwrap() - offset - 8;
+        let header = copy(HEADER_SIZE + offset, 8);
+        let length = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+        let kind = u32::from_le_bytes(header[4..8].try_into().unwrap());
+        let payload = copy(HEADER_SIZE + offset + 8, length.min(available));
+        fence(Ordering::Acquire);
+        if field(48).load(Ordering::Relaxed) > read_position + capacity {
+            skipped += 1;
+            read_position = field(24).load(Ordering::Acquire);
+            continue;
+        }
+        assert!(length <= available);
+        if kind == 0 {
+            read_position += (length + 8) as u64;
+            continue;
+        }
+        assert_eq!(kind, 1);
+        let mut reader = StreamReader::try_new(Cursor::new(payload), None).unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let age = int_column(&batch, "age")[0].unwrap();
+        assert_eq!(
+            string_column(&batch, "owner"),
+            vec![Some(format!("owner-{age}"))]
+        );
+        read += 1;
+        read_position += (length + 8).next_multiple_of(8) as u64;
+    }
+}
+
+#[test]
+fn test_reader_interleaved_with_wrap_around() -> eyre::Result<()> {
+    let capacity = (2 * single_row_record_size()?).next_multiple_of(8);
+    let directory = tempdir()?;
+    let table = TestExportedTable::new(vec![], TotalFrontier::At(Timestamp(0)));
+    let _exported = export(&table, directory.path(), capacity).unwrap();
+
+    let path = directory.path().join("0.ring");
+    let reader = thread::spawn(move || read_while_written(&path));
+    for i in 0..2000 {
+        let owner = format!("owner-{i}");
+        table.advance(
+            vec![row(i, &owner, Some(i), i.try_into()?, 1)],
+            TotalFrontier::At(Timestamp((i + 1).try_into()?)),
+        );
+    }
+    table.advance(vec![], TotalFrontier::Done);
+    let (read, _skipped) = reader.join().unwrap();
+    assert!(read > 0);
+    Ok(())
+}
+
+#[test]
+fn test_export_errors() -> eyre::Result<()> {
+    let directory = tempdir()?;
+    let table = TestExportedTable::new(vec![], TotalFrontier::Done);
+    assert_matches!(
+        export(&table, directory.path(), 12),
+        Err(Error::SharedMemoryExportFailed(path, _)) if path == directory.path().join("0.ring")
+    );
+
+    // a batch larger than the buffer fails the table
+    let table = TestExportedTable::new(
+        vec![row(1, "Alice", Some(3), 0, 1)],
+        TotalFrontier::At(Timestamp(0)),
+    );
+    let exported = export(&table, directory.path(), 64).unwrap();
+    assert!(!exported.failed());
+    table.advance(vec![], TotalFrontier::Done);
+    assert!(exported.failed());
+    let ring = Ring::read(directory.path());
+    assert_eq!(ring.state(), 2);
+    assert_eq!(ring.write_position(), 0);
+    Ok(())
+}
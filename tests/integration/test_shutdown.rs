@@ -0,0 +1,44 @@
+// Copyright © 2026 Pathway
+
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use pathway_engine::engine::shutdown::{
+    request_graceful_shutdown, set_active_shutdown, GracefulShutdown, ShutdownError,
+};
+
+#[test]
+fn test_shutdown_wakes_up_workers() {
+    let shutdown = Arc::new(GracefulShutdown::new(false));
+    let worker_shutdown = shutdown.clone();
+    let worker = thread::spawn(move || {
+        worker_shutdown.register_worker(thread::current());
+        // the worker parks for long, only the request can wake it up in time
+        while !worker_shutdown.is_requested() {
+            thread::park_timeout(Duration::from_secs(60));
+        }
+    });
+    thread::sleep(Duration::from_millis(100));
+    assert!(!shutdown.is_requested());
+
+    set_active_shutdown(&shutdown);
+    request_graceful_shutdown().unwrap();
+    assert!(shutdown.is_requested());
+    worker.join().unwrap();
+
+    drop(shutdown);
+    assert_eq!(request_graceful_shutdown(), Err(ShutdownError::NotRunning));
+}
+
+#[test]
+fn test_suspend_requires_persistence() {
+    let shutdown = GracefulShutdown::new(false);
+    assert_eq!(shutdown.suspend(), Err(ShutdownError::NoPersistence));
+    assert!(!shutdown.is_requested());
+
+    let shutdown = GracefulShutdown::new(true);
+    shutdown.suspend().unwrap();
+    assert!(shutdown.is_requested());
+    assert!(shutdown.is_suspended());
+}
@@ -0,0 +1,122 @@
+// Copyright © 2026 Pathway
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use pathway_engine::connectors::sink_alignment::{
+    SharedSinkAligner, SinkAligner, SinkAlignmentGroupDescriptor,
+};
+use pathway_engine::engine::Timestamp;
+
+#[test]
+fn test_single_sink_is_never_blocked() -> eyre::Result<()> {
+    let mut aligner = SinkAligner::new();
+    let sink = aligner.ensure_alignment_group(&SinkAlignmentGroupDescriptor::new(
+        "default".to_string(),
+        10,
+    ))?;
+    assert!(sink.can_write_now(Timestamp(1_000_000)));
+    sink.wait_for_turn(Timestamp(1_000_000));
+    Ok(())
+}
+
+#[test]
+fn test_fast_sink_is_held_back() -> eyre::Result<()> {
+    let mut aligner = SinkAligner::new();
+    let descriptor = SinkAlignmentGroupDescriptor::new("default".to_string(), 10);
+    let fast = aligner.ensure_alignment_group(&descriptor)?;
+    let slow = aligner.ensure_alignment_group(&descriptor)?;
+
+    assert!(fast.can_write_now(Timestamp(10)));
+    assert!(!fast.can_write_now(Timestamp(12)));
+
+    slow.report_progress(Some(Timestamp(4)));
+    assert!(fast.can_write_now(Timestamp(14)));
+    assert!(!fast.can_write_now(Timestamp(16)));
+
+    // The lagging sink is never blocked.
+    fast.report_progress(Some(Timestamp(14)));
+    assert!(slow.can_write_now(Timestamp(6)));
+    Ok(())
+}
+
+#[test]
+fn test_finished_sink_leaves_group() -> eyre::Result<()> {
+    let mut aligner = SinkAligner::new();
+    let descriptor = SinkAlignmentGroupDescriptor::new("default".to_string(), 0);
+    let first = aligner.ensure_alignment_group(&descriptor)?;
+    let second = aligner.ensure_alignment_group(&descriptor)?;
+    assert!(!first.can_write_now(Timestamp(2)));
+
+    second.report_progress(None);
+    assert!(first.can_write_now(Timestamp(2)));
+
+    let third = aligner.ensure_alignment_group(&descriptor)?;
+    assert!(!first.can_write_now(Timestamp(2)));
+    drop(third);
+    assert!(first.can_write_now(Timestamp(2)));
+    Ok(())
+}
+
+#[test]
+fn test_blocked_sink_is_woken_up() -> eyre::Result<()> {
+    let mut aligner = SinkAligner::new();
+    let descriptor = SinkAlignmentGroupDescriptor::new("default".to_string(), 2);
+    let fast = aligner.ensure_alignment_group(&descriptor)?;
+    let slow = Arc::new(aligner.ensure_alignment_group(&descriptor)?);
+
+    let fast_thread = thread::spawn(move || {
+        fast.wait_for_turn(Timestamp(8));
+    });
+    slow.wait_for_turn(Timestamp(2));
+    slow.report_progress(Some(Timestamp(6)));
+    fast_thread.join().unwrap();
+    assert!(slow.can_write_now(Timestamp(100)));
+    Ok(())
+}
+
+#[test]
+fn test_sinks_of_all_workers_are_aligned() -> eyre::Result<()> {
+    let aligner: SharedSinkAligner = Arc::new(Mutex::new(SinkAligner::new()));
+    let descriptor = SinkAlignmentGroupDescriptor::new("default".to_string(), 2);
+
+    // each worker joins the group with its part of the sinks
+    let workers: Vec<_> = (0..2)
+        .map(|_| {
+            let aligner = aligner.clone();
+            let descriptor = descriptor.clone();
+            thread::spawn(move || aligner.lock().unwrap().ensure_alignment_group(&descriptor))
+        })
+        .collect();
+    let mut sinks = Vec::new();
+    for worker in workers {
+        sinks.push(worker.join().unwrap()?);
+    }
+
+    assert!(!sinks[0].can_write_now(Timestamp(4)));
+    sinks[1].report_progress(Some(Timestamp(2)));
+    assert!(sinks[0].can_write_now(Timestamp(4)));
+    Ok(())
+}
+
+#[test]
+fn test_groups_are_independent() -> eyre::Result<()> {
+    let mut aligner = SinkAligner::new();
+    let first = aligner
+        .ensure_alignment_group(&SinkAlignmentGroupDescriptor::new("first".to_string(), 0))?;
+    let _second = aligner
+        .ensure_alignment_group(&SinkAlignmentGroupDescriptor::new("second".to_string(), 0))?;
+    assert!(first.can_write_now(Timestamp(100)));
+    Ok(())
+}
+
+#[test]
+fn test_inconsistent_max_skew() -> eyre::Result<()> {
+    let mut aligner = SinkAligner::new();
+    let _sink =
+        aligner.ensure_alignment_group(&SinkAlignmentGroupDescriptor::new("g".to_string(), 10))?;
+    let result =
+        aligner.ensure_alignment_group(&SinkAlignmentGroupDescriptor::new("g".to_string(), 20));
+    assert!(result.is_err());
+    Ok(())
+}
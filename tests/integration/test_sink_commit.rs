@@ -0,0 +1,128 @@
+// Copyright © 2026 Pathway
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use assert_matches::assert_matches;
+
+use pathway_engine::connectors::sink_commit::{Error, SinkCommitter};
+use pathway_engine::engine::Timestamp;
+
+#[test]
+fn test_single_sink_commits_right_away() {
+    let mut committer = SinkCommitter::new();
+    let mut sink = committer.join_commit_group("default");
+    sink.prepare(Some(Timestamp(1_000_000))).unwrap();
+    sink.commit(Some(Timestamp(1_000_000))).unwrap();
+    sink.prepare(None).unwrap();
+    sink.commit(None).unwrap();
+}
+
+#[test]
+fn test_sink_waits_for_the_others_to_prepare() {
+    let mut committer = SinkCommitter::new();
+    let mut fast = committer.join_commit_group("default");
+    let mut slow = committer.join_commit_group("default");
+
+    let (sender, receiver) = mpsc::channel();
+    let fast_thread = thread::spawn(move || {
+        fast.prepare(Some(Timestamp(4))).unwrap();
+        sender.send(()).unwrap();
+        fast.commit(Some(Timestamp(4))).unwrap();
+        fast.prepare(None).unwrap();
+        fast.commit(None).unwrap();
+    });
+    thread::sleep(Duration::from_millis(100));
+    assert!(receiver.try_recv().is_err());
+
+    // a later frontier also prepares the earlier times
+    slow.prepare(Some(Timestamp(6))).unwrap();
+    receiver.recv().unwrap();
+    slow.commit(Some(Timestamp(6))).unwrap();
+    slow.prepare(None).unwrap();
+    slow.commit(None).unwrap();
+    fast_thread.join().unwrap();
+}
+
+#[test]
+fn test_sink_waits_for_the_others_to_commit() {
+    let mut committer = SinkCommitter::new();
+    let mut fast = committer.join_commit_group("default");
+    let mut slow = committer.join_commit_group("default");
+
+    let (sender, receiver) = mpsc::channel();
+    let fast_thread = thread::spawn(move || {
+        fast.prepare(Some(Timestamp(2))).unwrap();
+        fast.commit(Some(Timestamp(2))).unwrap();
+        sender.send(()).unwrap();
+        fast.prepare(None).unwrap();
+        fast.commit(None).unwrap();
+    });
+    slow.prepare(Some(Timestamp(2))).unwrap();
+    thread::sleep(Duration::from_millis(100));
+    // the time isn't finalized before every sink has committed it
+    assert!(receiver.try_recv().is_err());
+
+    slow.commit(Some(Timestamp(2))).unwrap();
+    receiver.recv().unwrap();
+    slow.prepare(None).unwrap();
+    slow.commit(None).unwrap();
+    fast_thread.join().unwrap();
+}
+
+#[test]
+fn test_failed_sink_aborts_the_group() {
+    let mut committer = SinkCommitter::new();
+    let mut healthy = committer.join_commit_group("default");
+    let failing = committer.join_commit_group("default");
+
+    let healthy_thread = thread::spawn(move || healthy.prepare(Some(Timestamp(2))));
+    thread::sleep(Duration::from_millis(100));
+    drop(failing);
+    assert_matches!(healthy_thread.join().unwrap(), Err(Error::Aborted));
+}
+
+#[test]
+fn test_sink_failing_to_commit_aborts_the_group() {
+    let mut committer = SinkCommitter::new();
+    let mut healthy = committer.join_commit_group("default");
+    let mut failing = committer.join_commit_group("default");
+
+    let healthy_thread = thread::spawn(move || {
+        healthy.prepare(Some(Timestamp(2)))?;
+        healthy.commit(Some(Timestamp(2)))
+    });
+    failing.prepare(Some(Timestamp(2))).unwrap();
+    thread::sleep(Duration::from_millis(100));
+    drop(failing);
+    assert_matches!(healthy_thread.join().unwrap(), Err(Error::Aborted));
+}
+
+#[test]
+fn test_finished_sink_leaves_the_group() {
+    let mut committer = SinkCommitter::new();
+    let mut first = committer.join_commit_group("default");
+    let mut second = committer.join_commit_group("default");
+
+    let second_thread = thread::spawn(move || {
+        second.prepare(None)?;
+        second.commit(None)
+    });
+    first.prepare(Some(Timestamp(2))).unwrap();
+    first.commit(Some(Timestamp(2))).unwrap();
+    first.prepare(None).unwrap();
+    first.commit(None).unwrap();
+    second_thread.join().unwrap().unwrap();
+}
+
+#[test]
+fn test_commit_groups_are_independent() {
+    let mut committer = SinkCommitter::new();
+    let mut first = committer.join_commit_group("first");
+    let _second = committer.join_commit_group("second");
+    first.prepare(Some(Timestamp(100))).unwrap();
+    first.commit(Some(Timestamp(100))).unwrap();
+    first.prepare(None).unwrap();
+    first.commit(None).unwrap();
+}
@@ -0,0 +1,89 @@
+// Copyright © 2026 Pathway
+
+use pathway_engine::connectors::data_format::{
+    Formatter, JsonLinesFormatter, MetadataColumnsFormatter, MetadataField, SinkMetadataColumn,
+};
+use pathway_engine::engine::{Key, RunLineage, Timestamp, Type, Value};
+
+use super::helpers::assert_document_raw_byte_contents;
+
+fn metadata_formatter(columns: &[SinkMetadataColumn]) -> MetadataColumnsFormatter {
+    let mut field_names = vec!["a".to_string()];
+    field_names.extend(columns.iter().map(|column| column.name.clone()));
+    let inner = JsonLinesFormatter::new(field_names, None);
+    let lineage = RunLineage::new("run-42".to_string(), Some("0123abcd".to_string()));
+    MetadataColumnsFormatter::new(Box::new(inner), columns, 3, &lineage)
+}
+
+#[test]
+fn test_metadata_columns_are_appended() -> eyre::Result<()> {
+    let mut formatter = metadata_formatter(&[
+        SinkMetadataColumn::new("committed_at".to_string(), MetadataField::Time),
+        SinkMetadataColumn::new("change".to_string(), MetadataField::Diff),
+        SinkMetadataColumn::new("processed_by".to_string(), MetadataField::Worker),
+        SinkMetadataColumn::new("run".to_string(), MetadataField::RunId),
+        SinkMetadataColumn::new("version".to_string(), MetadataField::GraphVersion),
+    ]);
+
+    let result = formatter.format(
+        &Key::for_value(&Value::from("1")),
+        &[Value::from("b")],
+        Timestamp(10),
+        -1,
+    )?;
+    assert_eq!(result.payloads.len(), 1);
+    assert_document_raw_byte_contents(
+        &result.payloads[0],
+        r#"{"a":"b","change":-1,"committed_at":10,"diff":-1,"processed_by":3,"run":"run-42","time":10,"version":"0123abcd"}"#.as_bytes(),
+    );
+    assert_eq!(
+        result.values,
+        &[
+            Value::from("b"),
+            Value::Int(10),
+            Value::Int(-1),
+            Value::Int(3),
+            Value::from("run-42"),
+            Value::from("0123abcd"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_metadata_columns_in_batch() -> eyre::Result<()> {
+    let mut formatter = metadata_formatter(&[SinkMetadataColumn::new(
+        "change".to_string(),
+        MetadataField::Diff,
+    )]);
+
+    let first = [Value::from("x")];
+    let second = [Value::from("y")];
+    let entries = [
+        (Key::for_value(&Value::from("1")), first.as_slice(), 1),
+        (Key::for_value(&Value::from("2")), second.as_slice(), -1),
+    ];
+    let result = formatter.format_batch(&entries, Timestamp(4))?;
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].values, &[Value::from("x"), Value::Int(1)]);
+    assert_eq!(result[1].values, &[Value::from("y"), Value::Int(-1)]);
+    assert_document_raw_byte_contents(
+        &result[1].payloads[0],
+        r#"{"a":"y","change":-1,"diff":-1,"time":4}"#.as_bytes(),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_metadata_field_types() {
+    assert_eq!(MetadataField::Time.type_(), Type::Int);
+    assert_eq!(MetadataField::Diff.type_(), Type::Int);
+    assert_eq!(MetadataField::Worker.type_(), Type::Int);
+    assert_eq!(MetadataField::RunId.type_(), Type::String);
+    assert_eq!(
+        MetadataField::GraphVersion.type_(),
+        Type::Optional(Type::String.into())
+    );
+}
@@ -0,0 +1,66 @@
+// Copyright © 2026 Pathway
+
+use std::thread;
+use std::time::Duration;
+
+use pathway_engine::connectors::monitoring::{
+    output_connector_throughputs, OutputConnectorStats, OutputConnectorThroughput,
+};
+
+fn listed_throughput(name: &str) -> Option<OutputConnectorThroughput> {
+    output_connector_throughputs()
+        .into_iter()
+        .find(|(sink_name, _throughput)| sink_name == name)
+        .map(|(_sink_name, throughput)| throughput)
+}
+
+#[test]
+fn test_idle_sink() {
+    let stats = OutputConnectorStats::new("test_idle_sink".to_string());
+    assert_eq!(stats.throughput(), OutputConnectorThroughput::default());
+}
+
+#[test]
+fn test_rows_and_bytes_are_counted() {
+    let mut stats = OutputConnectorStats::new("test_rows_and_bytes_are_counted".to_string());
+    thread::sleep(Duration::from_millis(100));
+    stats.on_batch_started();
+    stats.on_bytes_written(300);
+    stats.on_batch_entries_written(3);
+    stats.on_batch_finished();
+    stats.on_batch_started();
+    stats.on_bytes_written(100);
+    stats.on_batch_entries_written(1);
+    stats.on_batch_finished();
+
+    let throughput = stats.throughput();
+    assert!(throughput.rows_per_second > 0.0);
+    // every row takes a hundred bytes
+    assert!((throughput.bytes_per_second / throughput.rows_per_second - 100.0).abs() < 1e-6);
+    // the throughput is measured since the start of the sink
+    assert!(throughput.rows_per_second <= 4.0 / 0.1);
+}
+
+#[test]
+fn test_flush_latency() {
+    let mut stats = OutputConnectorStats::new("test_flush_latency".to_string());
+    stats.on_flush(Duration::from_millis(10));
+    stats.on_flush(Duration::from_millis(30));
+
+    let throughput = stats.throughput();
+    let mean = throughput.mean_flush_latency_ms.unwrap();
+    let max = throughput.max_flush_latency_ms.unwrap();
+    assert!((mean - 20.0).abs() < 1e-6);
+    assert!((max - 30.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_running_sinks_are_listed() {
+    let mut stats = OutputConnectorStats::new("test_running_sinks_are_listed".to_string());
+    stats.on_flush(Duration::from_millis(5));
+    let throughput = listed_throughput("test_running_sinks_are_listed").unwrap();
+    assert!((throughput.max_flush_latency_ms.unwrap() - 5.0).abs() < 1e-6);
+
+    drop(stats);
+    assert_eq!(listed_throughput("test_running_sinks_are_listed"), None);
+}
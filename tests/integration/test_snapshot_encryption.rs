@@ -0,0 +1,194 @@
+// Copyright © 2026 Pathway
+
+use assert_matches::assert_matches;
+use tempfile::tempdir;
+
+use pathway_engine::engine::{Key, Timestamp, TotalFrontier, Value};
+use pathway_engine::persistence::backends::{
+    EncryptedKVStorage, EncryptionKey, FilesystemKVStorage, PersistenceBackend,
+};
+use pathway_engine::persistence::frontier::OffsetAntichain;
+use pathway_engine::persistence::input_snapshot::{
+    Event as SnapshotEvent, InputSnapshotReader, InputSnapshotWriter, ReadInputSnapshot,
+    SnapshotMode,
+};
+use pathway_engine::persistence::Error as PersistenceError;
+
+use super::helpers::put_value_blocking;
+
+fn encrypted_storage(
+    path: &std::path::Path,
+    key: &EncryptionKey,
+) -> eyre::Result<EncryptedKVStorage> {
+    Ok(EncryptedKVStorage::new(
+        Box::new(FilesystemKVStorage::new(path)?),
+        key,
+    ))
+}
+
+#[test]
+fn test_encrypted_storage_roundtrip() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let key = EncryptionKey::new(&[7; 32])?;
+    let storage = encrypted_storage(test_storage_path, &key)?;
+    let value = b"the state of the operator".repeat(4);
+
+    put_value_blocking(&storage, "1", &value);
+    assert_eq!(storage.get_value("1")?, value);
+    assert_eq!(storage.list_keys()?, vec!["1"]);
+
+    let raw_value = FilesystemKVStorage::new(test_storage_path)?.get_value("1")?;
+    assert!(!raw_value
+        .windows(b"operator".len())
+        .any(|window| window == b"operator"));
+
+    storage.remove_key("1")?;
+    assert!(storage.list_keys()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_storage_rejects_other_key() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let storage = encrypted_storage(test_storage_path, &EncryptionKey::new(&[7; 32])?)?;
+    put_value_blocking(&storage, "1", b"secret");
+
+    let other_storage = encrypted_storage(test_storage_path, &EncryptionKey::new(&[8; 32])?)?;
+    assert_matches!(
+        other_storage.get_value("1"),
+        Err(PersistenceError::Decryption(key)) if key == "1"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_storage_rejects_moved_entry() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let storage = encrypted_storage(test_storage_path, &EncryptionKey::new(&[7; 32])?)?;
+    put_value_blocking(&storage, "1", b"secret");
+
+    let plain_storage = FilesystemKVStorage::new(test_storage_path)?;
+    let raw_value = plain_storage.get_value("1")?;
+    put_value_blocking(&plain_storage, "2", &raw_value);
+    assert_matches!(storage.get_value("2"), Err(PersistenceError::Decryption(_)));
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_storage_rejects_unencrypted_entries() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    put_value_blocking(
+        &FilesystemKVStorage::new(test_storage_path)?,
+        "1",
+        b"written before the encryption",
+    );
+
+    let storage = encrypted_storage(test_storage_path, &EncryptionKey::new(&[7; 32])?)?;
+    assert_matches!(
+        storage.get_value("1"),
+        Err(PersistenceError::Decryption(key)) if key == "1"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_storage_reads_unencrypted_entries_when_migrating() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    put_value_blocking(
+        &FilesystemKVStorage::new(test_storage_path)?,
+        "1",
+        b"written before the encryption",
+    );
+
+    let storage = encrypted_storage(test_storage_path, &EncryptionKey::new(&[7; 32])?)?
+        .with_unencrypted_values_accepted();
+    assert_eq!(storage.get_value("1")?, b"written before the encryption");
+
+    // the encrypted entries are still authenticated
+    put_value_blocking(&storage, "2", b"secret");
+    let mut raw_value = FilesystemKVStorage::new(test_storage_path)?.get_value("2")?;
+    *raw_value.last_mut().unwrap() ^= 1;
+    put_value_blocking(
+        &FilesystemKVStorage::new(test_storage_path)?,
+        "2",
+        &raw_value,
+    );
+    assert_matches!(storage.get_value("2"), Err(PersistenceError::Decryption(_)));
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_input_snapshot() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let test_storage_path = test_storage.path();
+    let key = EncryptionKey::new(&[7; 32])?;
+    let events = vec![
+        SnapshotEvent::Insert(Key::random(), vec![Value::from("secret")]),
+        SnapshotEvent::AdvanceTime(Timestamp(1), OffsetAntichain::new()),
+    ];
+
+    let mut writer = InputSnapshotWriter::new(
+        Box::new(encrypted_storage(test_storage_path, &key)?),
+        SnapshotMode::Full,
+    )?;
+    for event in &events {
+        writer.write(event);
+    }
+    for future in writer.flush() {
+        futures::executor::block_on(future).unwrap()?;
+    }
+
+    let mut reader = InputSnapshotReader::new(
+        Box::new(encrypted_storage(test_storage_path, &key)?),
+        TotalFrontier::At(Timestamp(2)),
+        false,
+    )?;
+    let mut read_events = Vec::new();
+    loop {
+        let event = reader.read()?;
+        if matches!(event, SnapshotEvent::Finished) {
+            break;
+        }
+        read_events.push(event);
+    }
+    assert_eq!(read_events, events);
+
+    // the values are compressed, but the short literals would be readable as they are
+    let plain_storage = FilesystemKVStorage::new(test_storage_path)?;
+    for chunk in plain_storage.list_keys()? {
+        let raw_chunk = plain_storage.get_value(&chunk)?;
+        assert!(!raw_chunk
+            .windows(b"secret".len())
+            .any(|window| window == b"secret"));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_encryption_key_parsing() -> eyre::Result<()> {
+    assert_eq!(
+        EncryptionKey::from_base64("BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwc=")?,
+        EncryptionKey::new(&[7; 32])?
+    );
+    assert_matches!(
+        EncryptionKey::new(&[7; 16]),
+        Err(PersistenceError::InvalidEncryptionKeyLength {
+            expected: 32,
+            actual: 16
+        })
+    );
+    assert_matches!(
+        EncryptionKey::from_base64("not base64!"),
+        Err(PersistenceError::InvalidEncryptionKeyEncoding(_))
+    );
+    assert_eq!(
+        format!("{:?}", EncryptionKey::new(&[7; 32])?),
+        "EncryptionKey(..)"
+    );
+    Ok(())
+}
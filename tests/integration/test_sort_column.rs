@@ -0,0 +1,60 @@
+// Copyright © 2026 Pathway
+
+use std::cmp::Ordering;
+
+use pathway_engine::engine::graph::SortColumn;
+use pathway_engine::engine::Value;
+
+fn sorted(mut rows: Vec<Vec<Value>>, columns: &[SortColumn]) -> Vec<Vec<Value>> {
+    rows.sort_by(|lhs, rhs| {
+        columns
+            .iter()
+            .map(|column| column.compare(lhs, rhs))
+            .find(|order| *order != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+    rows
+}
+
+fn single_column(values: &[Option<i64>]) -> Vec<Vec<Value>> {
+    values
+        .iter()
+        .map(|value| vec![value.map_or(Value::None, Value::Int)])
+        .collect()
+}
+
+#[test]
+fn test_ascending() {
+    let rows = single_column(&[Some(2), None, Some(3), Some(1)]);
+    assert_eq!(
+        sorted(rows.clone(), &[SortColumn::ascending(0)]),
+        single_column(&[None, Some(1), Some(2), Some(3)])
+    );
+    assert_eq!(
+        sorted(rows, &[SortColumn::ascending(0).with_nulls_first(false)]),
+        single_column(&[Some(1), Some(2), Some(3), None])
+    );
+}
+
+#[test]
+fn test_descending() {
+    let rows = single_column(&[Some(2), None, Some(3), Some(1)]);
+    assert_eq!(
+        sorted(rows.clone(), &[SortColumn::descending(0)]),
+        single_column(&[Some(3), Some(2), Some(1), None])
+    );
+    assert_eq!(
+        sorted(rows, &[SortColumn::descending(0).with_nulls_first(true)]),
+        single_column(&[None, Some(3), Some(2), Some(1)])
+    );
+}
+
+#[test]
+fn test_several_columns() {
+    let row = |a: i64, b: i64| vec![Value::Int(a), Value::Int(b)];
+    let rows = vec![row(1, 1), row(2, 1), row(1, 2), row(2, 2)];
+    assert_eq!(
+        sorted(rows, &[SortColumn::ascending(0), SortColumn::descending(1)]),
+        vec![row(1, 2), row(1, 1), row(2, 2), row(2, 1)]
+    );
+}
@@ -2,9 +2,12 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use assert_matches::assert_matches;
 use eyre::eyre;
+use serde_json::json;
+use tempfile::tempdir;
 
 use pathway_engine::connectors::data_format::InnerSchemaField;
 use pathway_engine::connectors::data_format::ParseError;
@@ -18,9 +21,13 @@ use rusqlite::Connection as SqliteConnection;
 use rusqlite::OpenFlags as SqliteOpenFlags;
 
 use pathway_engine::connectors::data_format::{ParsedEvent, Parser};
-use pathway_engine::connectors::data_storage::{ReadResult, Reader, SqliteReader};
-use pathway_engine::connectors::offset::EMPTY_OFFSET;
+use pathway_engine::connectors::data_storage::{
+    build_sqlite_cursor_reader, ConnectorMode, ReadError, ReadResult, Reader, SqliteCursorReader,
+    SqliteError, SqlitePollingTarget, SqliteReader,
+};
+use pathway_engine::connectors::offset::{Offset, OffsetKey, EMPTY_OFFSET};
 use pathway_engine::engine::Value;
+use pathway_engine::persistence::frontier::OffsetAntichain;
 
 use crate::helpers::assert_error_shown_for_reader_context;
 use crate::helpers::ErrorPlacement;
@@ -210,3 +217,143 @@ fn test_sqlite_read_table_nonparsable() -> eyre::Result<()> {
     );
     Ok(())
 }
+
+fn create_events_database(path: &std::path::Path) -> eyre::Result<SqliteConnection> {
+    let connection = SqliteConnection::open(path)?;
+    connection.execute_batch(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, name TEXT NOT NULL, score REAL);
+         INSERT INTO events (id, name, score) VALUES (1, 'a', 0.5), (2, 'b', NULL);",
+    )?;
+    Ok(connection)
+}
+
+fn events_schema() -> Vec<(String, Type)> {
+    vec![
+        ("id".to_string(), Type::Int),
+        ("name".to_string(), Type::String),
+        ("score".to_string(), Type::Optional(Type::Float.into())),
+    ]
+}
+
+fn new_cursor_reader(
+    path: &std::path::Path,
+    target: SqlitePollingTarget,
+    mode: ConnectorMode,
+) -> eyre::Result<SqliteCursorReader> {
+    let connection =
+        SqliteConnection::open_with_flags(path, SqliteOpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    Ok(build_sqlite_cursor_reader(
+        connection,
+        target,
+        events_schema(),
+        "id".to_string(),
+        "id".to_string(),
+        mode,
+        0,
+        1024,
+        Duration::from_millis(1),
+    )?)
+}
+
+/// Reads one block of the polled rows, returning the rows and the offset
+/// they were read with.
+fn read_cursor_block(
+    reader: &mut SqliteCursorReader,
+) -> eyre::Result<(Vec<serde_json::Value>, Option<Offset>)> {
+    let mut rows = Vec::new();
+    let mut offset = None;
+    loop {
+        match reader.read()? {
+            ReadResult::Data(ReaderContext::RawBytes(DataEventType::Insert, raw), row_offset) => {
+                rows.push(serde_json::from_slice(&raw)?);
+                offset = Some(row_offset);
+            }
+            ReadResult::NewSource(_) => {}
+            ReadResult::FinishedSource { .. } | ReadResult::Finished => break,
+            other => return Err(eyre!("unexpected read result: {other:?}")),
+        }
+    }
+    Ok((rows, offset))
+}
+
+#[test]
+fn test_sqlite_cursor_reader_reads_only_new_rows() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let path = test_storage.path().join("events.db");
+    let writer_connection = create_events_database(&path)?;
+    let mut reader = new_cursor_reader(
+        &path,
+        SqlitePollingTarget::Table("events".to_string()),
+        ConnectorMode::Streaming,
+    )?;
+
+    let (rows, _) = read_cursor_block(&mut reader)?;
+    assert_eq!(
+        rows,
+        vec![
+            json!({"id": 1, "name": "a", "score": 0.5}),
+            json!({"id": 2, "name": "b", "score": null}),
+        ]
+    );
+
+    writer_connection.execute(
+        "INSERT INTO events (id, name, score) VALUES (3, 'c', 1.5)",
+        [],
+    )?;
+    let (rows, _) = read_cursor_block(&mut reader)?;
+    assert_eq!(rows, vec![json!({"id": 3, "name": "c", "score": 1.5})]);
+
+    Ok(())
+}
+
+#[test]
+fn test_sqlite_cursor_reader_query_resumes_from_offset() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let path = test_storage.path().join("events.db");
+    let writer_connection = create_events_database(&path)?;
+    let target = || {
+        SqlitePollingTarget::Query(
+            "SELECT id, upper(name) AS name, score FROM events WHERE name != 'skip'".to_string(),
+        )
+    };
+
+    let mut reader = new_cursor_reader(&path, target(), ConnectorMode::Streaming)?;
+    let (rows, offset) = read_cursor_block(&mut reader)?;
+    assert_eq!(rows.len(), 2);
+    let (offset_key, offset_value) = offset.expect("rows must have been read");
+    assert_eq!(offset_key, OffsetKey::Sqlite);
+
+    writer_connection.execute_batch(
+        "INSERT INTO events (id, name, score) VALUES (3, 'skip', NULL), (4, 'd', 2.5);",
+    )?;
+    let mut frontier = OffsetAntichain::new();
+    frontier.advance_offset(offset_key, offset_value);
+    let mut reader = new_cursor_reader(&path, target(), ConnectorMode::Streaming)?;
+    reader.seek(&frontier)?;
+    let (rows, _) = read_cursor_block(&mut reader)?;
+    assert_eq!(rows, vec![json!({"id": 4, "name": "D", "score": 2.5})]);
+
+    Ok(())
+}
+
+#[test]
+fn test_sqlite_cursor_reader_rejects_non_integer_cursor() -> eyre::Result<()> {
+    let test_storage = tempdir()?;
+    let path = test_storage.path().join("events.db");
+    // Without the `INTEGER PRIMARY KEY`, the column accepts values of any type
+    let writer_connection = SqliteConnection::open(&path)?;
+    writer_connection.execute_batch(
+        "CREATE TABLE events (id, name TEXT NOT NULL, score REAL);
+         INSERT INTO events (id, name, score) VALUES (1, 'a', NULL), ('two', 'b', NULL);",
+    )?;
+    let mut reader = new_cursor_reader(
+        &path,
+        SqlitePollingTarget::Table("events".to_string()),
+        ConnectorMode::Static,
+    )?;
+    assert_matches!(
+        reader.read(),
+        Err(ReadError::Sqlite(SqliteError::InvalidCursorValue { .. }))
+    );
+    Ok(())
+}
@@ -0,0 +1,218 @@
+// Copyright © 2026 Pathway
+
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+
+use pathway_engine::connectors::data_storage::{
+    ReadResult, Reader, ReaderContext, SseEvent, SseEventParser, SseReader,
+};
+use pathway_engine::connectors::{OffsetKey, OffsetValue};
+use pathway_engine::persistence::frontier::OffsetAntichain;
+
+fn parse_events(lines: &[&str]) -> Vec<SseEvent> {
+    let mut parser = SseEventParser::new(String::new());
+    lines
+        .iter()
+        .filter_map(|line| parser.process_line(line))
+        .collect()
+}
+
+fn event(event_type: &str, data: &str, last_event_id: &str) -> SseEvent {
+    SseEvent {
+        event_type: event_type.to_string(),
+        data: data.to_string(),
+        last_event_id: last_event_id.to_string(),
+    }
+}
+
+#[test]
+fn test_parser_dispatches_on_empty_lines() {
+    let events = parse_events(&[
+        "\u{feff}: a comment",
+        "data: first",
+        "",
+        "event: update",
+        "data:second",
+        "data:  line",
+        "",
+        "data",
+        "",
+    ]);
+    assert_eq!(
+        events,
+        vec![
+            event("message", "first", ""),
+            event("update", "second\n line", ""),
+            event("message", "", ""),
+        ]
+    );
+}
+
+#[test]
+fn test_parser_keeps_last_event_id() {
+    let events = parse_events(&[
+        "id: 1",
+        "data: first",
+        "",
+        "data: second",
+        "",
+        "id: 3",
+        "",
+        "id: with\0null",
+        "data: third",
+        "",
+        "id",
+        "data: fourth",
+        "",
+    ]);
+    assert_eq!(
+        events,
+        vec![
+            event("message", "first", "1"),
+            event("message", "second", "1"),
+            event("message", "third", "3"),
+            event("message", "fourth", ""),
+        ]
+    );
+}
+
+#[test]
+fn test_parser_reconnection_time() {
+    let mut parser = SseEventParser::new(String::new());
+    parser.process_line("retry: 2500");
+    assert_eq!(
+        parser.take_reconnection_time(),
+        Some(Duration::from_millis(2500))
+    );
+    assert_eq!(parser.take_reconnection_time(), None);
+    parser.process_line("retry: 1s");
+    assert_eq!(parser.take_reconnection_time(), None);
+}
+
+// Serves the given HTTP responses to the consecutive connections and returns the
+// `Last-Event-ID` headers of the requests.
+fn serve_responses(responses: Vec<&'static str>) -> (String, JoinHandle<Vec<Option<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/events", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let mut last_event_ids = Vec::new();
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = BufReader::new(stream.try_clone().unwrap());
+            let mut last_event_id = None;
+            loop {
+                let mut line = String::new();
+                request.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("last-event-id") {
+                        last_event_id = Some(value.trim().to_string());
+                    }
+                }
+            }
+            last_event_ids.push(last_event_id);
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        last_event_ids
+    });
+    (url, server)
+}
+
+fn read_entry(reader: &mut SseReader) -> (String, OffsetValue) {
+    let ReadResult::Data(ReaderContext::KeyValue((None, Some(data))), (offset_key, offset_value)) =
+        reader.read().unwrap()
+    else {
+        panic!("an entry was expected");
+    };
+    assert_eq!(offset_key, OffsetKey::Sse);
+    (String::from_utf8(data).unwrap(), offset_value)
+}
+
+fn sse_event_id(last_event_id: &str, entries_read: usize) -> OffsetValue {
+    OffsetValue::SseEventId {
+        last_event_id: last_event_id.to_string(),
+        entries_read,
+    }
+}
+
+#[test]
+fn test_reader_resumes_after_last_event_id() {
+    let (url, server) = serve_responses(vec![
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n\
+         retry: 10\n\nid: 1\ndata: first\n\nevent: ignored\nid: 2\ndata: skipped\n\n\
+         data: second\n\n",
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n\
+         id: 3\r\ndata: third\r\ndata: line\r\n\r\ndata: incomplete",
+        "HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n",
+    ]);
+    let mut reader = SseReader::new(
+        url,
+        HeaderMap::new(),
+        Some(HashSet::from(["message".to_string()])),
+        Duration::from_secs(60),
+    );
+
+    assert_eq!(
+        read_entry(&mut reader),
+        ("first".to_string(), sse_event_id("1", 1))
+    );
+    assert_eq!(
+        read_entry(&mut reader),
+        ("second".to_string(), sse_event_id("2", 2))
+    );
+    assert_eq!(
+        read_entry(&mut reader),
+        ("third\nline".to_string(), sse_event_id("3", 3))
+    );
+    assert!(matches!(reader.read().unwrap(), ReadResult::Finished));
+
+    assert_eq!(
+        server.join().unwrap(),
+        vec![None, Some("2".to_string()), Some("3".to_string())]
+    );
+}
+
+#[test]
+fn test_reader_sends_persisted_event_id() {
+    let (url, server) = serve_responses(vec![
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n\
+         data: after restart\n\n",
+    ]);
+    let mut reader = SseReader::new(url, HeaderMap::new(), None, Duration::from_secs(60));
+    let mut frontier = OffsetAntichain::new();
+    frontier.advance_offset(OffsetKey::Sse, sse_event_id("41", 7));
+    reader.seek(&frontier).unwrap();
+
+    assert_eq!(
+        read_entry(&mut reader),
+        ("after restart".to_string(), sse_event_id("41", 8))
+    );
+    assert_eq!(server.join().unwrap(), vec![Some("41".to_string())]);
+}
+
+#[test]
+fn test_frontier_merge_takes_more_events_read() {
+    let mut lhs = OffsetAntichain::new();
+    lhs.advance_offset(OffsetKey::Sse, sse_event_id("b", 2));
+    let mut rhs = OffsetAntichain::new();
+    rhs.advance_offset(OffsetKey::Sse, sse_event_id("a", 5));
+
+    let merged = SseReader::merge_two_frontiers(&lhs, &rhs);
+    assert_eq!(
+        merged.get_offset(&OffsetKey::Sse),
+        Some(&sse_event_id("a", 5))
+    );
+    let merged = SseReader::merge_two_frontiers(&rhs, &lhs);
+    assert_eq!(
+        merged.get_offset(&OffsetKey::Sse),
+        Some(&sse_event_id("a", 5))
+    );
+}
@@ -0,0 +1,71 @@
+// Copyright © 2026 Pathway
+
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use pathway_engine::connectors::monitoring::ConnectorStats;
+use pathway_engine::engine::dataflow::monitoring::{
+    CountStats, OperatorStats, ProberStats, StatsPoller,
+};
+use pathway_engine::engine::Timestamp;
+
+#[test]
+fn test_poller_without_stats() {
+    let poller = StatsPoller::new();
+    assert!(poller.latest().is_none());
+    assert!(poller.clone().latest().is_none());
+}
+
+#[test]
+fn test_prober_stats_serialization() -> eyre::Result<()> {
+    let mut row_counts = CountStats::default();
+    row_counts.update(1);
+    row_counts.update(1);
+    row_counts.update(-1);
+    let stats = ProberStats {
+        input_stats: OperatorStats {
+            time: Some(Timestamp(10)),
+            lag: Some(0),
+            done: false,
+        },
+        output_stats: OperatorStats {
+            time: None,
+            lag: None,
+            done: true,
+        },
+        operators_stats: HashMap::new(),
+        connector_stats: vec![(
+            "orders".to_string(),
+            ConnectorStats {
+                num_messages_from_start: 3,
+                num_messages_in_last_minute: 2,
+                num_messages_recently_committed: 1,
+                finished: false,
+            },
+        )],
+        row_counts: HashMap::from([(4, row_counts)]),
+        table_gauges: HashMap::new(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&stats)?,
+        json!({
+            "input_stats": {"time": 10, "lag": 0, "done": false},
+            "output_stats": {"time": null, "lag": null, "done": true},
+            "operators_stats": {},
+            "connector_stats": [[
+                "orders",
+                {
+                    "num_messages_from_start": 3,
+                    "num_messages_in_last_minute": 2,
+                    "num_messages_recently_committed": 1,
+                    "finished": false,
+                },
+            ]],
+            "row_counts": {"4": {"total_rows": 3, "current_rows": 1}},
+            "table_gauges": {},
+        })
+    );
+    Ok(())
+}
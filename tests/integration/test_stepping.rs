@@ -0,0 +1,92 @@
+// Copyright © 2026 Pathway
+
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use pathway_engine::engine::stepping::{StepCommand, StepController, StepGate};
+use pathway_engine::engine::{StepMode, Timestamp};
+
+const BLOCKED_TIMEOUT: Duration = Duration::from_millis(200);
+
+#[test]
+fn test_parse_step_commands() {
+    assert_eq!(StepCommand::parse("").unwrap(), StepCommand::Step(1));
+    assert_eq!(StepCommand::parse("  ").unwrap(), StepCommand::Step(1));
+    assert_eq!(StepCommand::parse("s").unwrap(), StepCommand::Step(1));
+    assert_eq!(StepCommand::parse("step 5").unwrap(), StepCommand::Step(5));
+    assert_eq!(StepCommand::parse(" s 2 ").unwrap(), StepCommand::Step(2));
+    assert_eq!(StepCommand::parse("c").unwrap(), StepCommand::Continue);
+    assert_eq!(
+        StepCommand::parse("continue").unwrap(),
+        StepCommand::Continue
+    );
+
+    assert!(StepCommand::parse("step 0").is_err());
+    assert!(StepCommand::parse("step -1").is_err());
+    assert!(StepCommand::parse("step 1 2").is_err());
+    assert!(StepCommand::parse("continue 3").is_err());
+    assert!(StepCommand::parse("next").is_err());
+}
+
+#[test]
+fn test_worker_waits_for_steps() {
+    let controller = Arc::new(StepController::new(StepMode::Stdin));
+    let (sender, receiver) = mpsc::channel();
+    let worker = thread::spawn({
+        let controller = controller.clone();
+        move || {
+            let mut gate = StepGate::new(controller);
+            for time in [0, 2, 4, 6, 8] {
+                gate.on_output_frontier(Some(Timestamp(time)), || false);
+                sender.send(time).unwrap();
+            }
+        }
+    });
+
+    assert!(receiver.recv_timeout(BLOCKED_TIMEOUT).is_err());
+    assert_eq!(controller.status()["paused"], true);
+    assert_eq!(controller.status()["frontier"], 0);
+
+    controller.apply(StepCommand::Step(1));
+    assert_eq!(receiver.recv().unwrap(), 0);
+    assert!(receiver.recv_timeout(BLOCKED_TIMEOUT).is_err());
+    assert_eq!(controller.status()["frontier"], 2);
+
+    controller.apply(StepCommand::Step(2));
+    assert_eq!(receiver.recv().unwrap(), 2);
+    assert_eq!(receiver.recv().unwrap(), 4);
+    assert!(receiver.recv_timeout(BLOCKED_TIMEOUT).is_err());
+
+    controller.apply(StepCommand::Continue);
+    assert_eq!(receiver.recv().unwrap(), 6);
+    assert_eq!(receiver.recv().unwrap(), 8);
+    worker.join().unwrap();
+    assert_eq!(controller.status()["paused"], false);
+}
+
+#[test]
+fn test_unchanged_frontier_is_not_a_step() {
+    let controller = Arc::new(StepController::new(StepMode::Stdin));
+    controller.apply(StepCommand::Step(1));
+    let mut gate = StepGate::new(controller.clone());
+    gate.on_output_frontier(Some(Timestamp(0)), || false);
+    gate.on_output_frontier(Some(Timestamp(0)), || false);
+    // finished outputs never block
+    gate.on_output_frontier(None, || false);
+    assert_eq!(controller.status()["paused"], false);
+}
+
+#[test]
+fn test_waiting_is_aborted() {
+    let controller = Arc::new(StepController::new(StepMode::Http));
+    let mut gate = StepGate::new(controller.clone());
+    gate.on_output_frontier(Some(Timestamp(0)), || true);
+    assert_eq!(controller.status()["paused"], true);
+
+    // the aborted step is still pending
+    controller.apply(StepCommand::Step(1));
+    gate.on_output_frontier(Some(Timestamp(0)), || false);
+    assert_eq!(controller.status()["released_steps"], 1);
+}
@@ -0,0 +1,58 @@
+// Copyright © 2026 Pathway
+
+use pathway_engine::engine::expression::string_similarity::{
+    jaro_winkler, levenshtein, trigram_similarity,
+};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "{actual} is not close to {expected}"
+    );
+}
+
+#[test]
+fn test_levenshtein() {
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+    assert_eq!(levenshtein("sitting", "kitten"), 3);
+    assert_eq!(levenshtein("flaw", "lawn"), 2);
+    assert_eq!(levenshtein("", "abc"), 3);
+    assert_eq!(levenshtein("abc", ""), 3);
+    assert_eq!(levenshtein("", ""), 0);
+    assert_eq!(levenshtein("pathway", "pathway"), 0);
+}
+
+#[test]
+fn test_levenshtein_counts_characters() {
+    assert_eq!(levenshtein("żółw", "zolw"), 3);
+    assert_eq!(levenshtein("日本", "日本語"), 1);
+}
+
+#[test]
+fn test_jaro_winkler() {
+    assert_close(jaro_winkler("martha", "marhta"), 0.961_111_111_111_111);
+    assert_close(jaro_winkler("dwayne", "duane"), 0.84);
+    assert_close(jaro_winkler("dixon", "dicksonx"), 0.813_333_333_333_333);
+    assert_close(jaro_winkler("crate", "crate"), 1.0);
+    assert_close(jaro_winkler("abc", "xyz"), 0.0);
+    assert_close(jaro_winkler("", "abc"), 0.0);
+    assert_close(jaro_winkler("", ""), 1.0);
+}
+
+#[test]
+fn test_jaro_winkler_is_symmetric() {
+    for (lhs, rhs) in [("martha", "marhta"), ("dixon", "dicksonx"), ("a", "ab")] {
+        assert_close(jaro_winkler(lhs, rhs), jaro_winkler(rhs, lhs));
+    }
+}
+
+#[test]
+fn test_trigram_similarity() {
+    // the values returned by `similarity` of `pg_trgm`
+    assert_close(trigram_similarity("word", "two words"), 4.0 / 11.0);
+    assert_close(trigram_similarity("Pathway", "pathway"), 1.0);
+    assert_close(trigram_similarity("a b", "b, a!"), 1.0);
+    assert_close(trigram_similarity("crate", "trace"), 0.0);
+    assert_close(trigram_similarity("", "abc"), 0.0);
+    assert_close(trigram_similarity("--", "--"), 0.0);
+}
@@ -0,0 +1,115 @@
+// Copyright © 2026 Pathway
+
+use arcstr::ArcStr;
+use serde_json::json;
+
+use pathway_engine::connectors::data_format::{parse_value_from_json, serialize_value_to_json};
+use pathway_engine::engine::error::Trace;
+use pathway_engine::engine::{ColumnPath, ColumnProperties, Key, TableProperties, Type, Value};
+
+fn point(x: i64, y: i64) -> Value {
+    Value::from(vec![
+        (ArcStr::from("x"), Value::Int(x)),
+        (ArcStr::from("y"), Value::Int(y)),
+    ])
+}
+
+fn point_type() -> Type {
+    Type::Struct(
+        [
+            (ArcStr::from("x"), Type::Int),
+            (ArcStr::from("y"), Type::Int),
+        ]
+        .into(),
+    )
+}
+
+#[test]
+fn test_struct_display() {
+    assert_eq!(point(1, 2).to_string(), "{x: 1, y: 2}");
+    assert_eq!(point_type().to_string(), "struct[x: int, y: int]");
+}
+
+#[test]
+fn test_struct_field() {
+    let value = point(1, 2);
+    assert_eq!(value.struct_field("y").unwrap(), Some(&Value::Int(2)));
+    assert_eq!(value.struct_field("z").unwrap(), None);
+    assert!(Value::Int(1).struct_field("x").is_err());
+}
+
+#[test]
+fn test_struct_field_type() {
+    assert_eq!(point_type().struct_field_type("x"), Some(Type::Int));
+    assert_eq!(point_type().struct_field_type("z"), None);
+    assert_eq!(
+        Type::Optional(point_type().into()).struct_field_type("x"),
+        Some(Type::Optional(Type::Int.into()))
+    );
+}
+
+#[test]
+fn test_struct_json_roundtrip() -> eyre::Result<()> {
+    let json_value = json!({"y": 2, "x": 1, "z": 3});
+    let parsed = parse_value_from_json(&json_value, &point_type());
+    assert_eq!(parsed, Some(point(1, 2)));
+    assert_eq!(
+        serialize_value_to_json(&point(1, 2))?,
+        json!({"x": 1, "y": 2})
+    );
+    Ok(())
+}
+
+#[test]
+fn test_struct_json_missing_field() {
+    let optional_field_type = Type::Struct(
+        [
+            (ArcStr::from("x"), Type::Int),
+            (ArcStr::from("y"), Type::Optional(Type::Int.into())),
+        ]
+        .into(),
+    );
+    assert_eq!(
+        parse_value_from_json(&json!({"x": 1}), &optional_field_type),
+        Some(Value::from(vec![
+            (ArcStr::from("x"), Value::Int(1)),
+            (ArcStr::from("y"), Value::None),
+        ]))
+    );
+    assert_eq!(parse_value_from_json(&json!({"x": 1}), &point_type()), None);
+}
+
+#[test]
+fn test_column_path_struct_field() -> eyre::Result<()> {
+    let row = Value::from([Value::Int(0), point(3, 4)].as_slice());
+    let path = ColumnPath::FieldPath(vec![1], vec![ArcStr::from("y")]);
+    assert_eq!(path.extract(&Key::for_value(&row), &row)?, Value::Int(4));
+
+    let missing = ColumnPath::FieldPath(vec![1], vec![ArcStr::from("z")]);
+    assert!(missing.extract_from_value(&row).is_err());
+
+    let row_with_none = Value::from([Value::Int(0), Value::None].as_slice());
+    assert_eq!(path.extract_from_value(&row_with_none)?, Value::None);
+    Ok(())
+}
+
+#[test]
+fn test_column_path_struct_field_properties() -> eyre::Result<()> {
+    let column = |dtype| {
+        TableProperties::Column(
+            ColumnProperties {
+                dtype,
+                append_only: true,
+                trace: Trace::Empty.into(),
+            }
+            .into(),
+        )
+    };
+    let table = TableProperties::Table(
+        [column(Type::Int), column(point_type())].into(),
+        Trace::Empty.into(),
+    );
+    let path = ColumnPath::FieldPath(vec![1], vec![ArcStr::from("x")]);
+    assert_eq!(path.extract_properties(&table)?, column(Type::Int));
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// Copyright © 2026 Pathway
+
+use serde_json::json;
+
+use pathway_engine::engine::dataflow::monitoring::TableGauge;
+use pathway_engine::engine::Value;
+
+#[test]
+fn test_gauge_tracks_consolidated_rows() {
+    let mut gauge = TableGauge::new(7);
+    gauge.update(&Value::Int(1), 1);
+    gauge.update(&Value::Int(2), 1);
+    gauge.update(&Value::Int(1), -1);
+    gauge.update(&Value::Int(3), 2);
+    assert_eq!(gauge.operator_id, 7);
+    assert_eq!(gauge.rows, 3);
+
+    let mut single = TableGauge::new(7);
+    single.update(&Value::Int(1), 1);
+    assert_eq!(gauge.estimated_bytes, 3 * single.estimated_bytes);
+}
+
+#[test]
+fn test_gauge_counts_heap_data() {
+    let mut short = TableGauge::new(0);
+    short.update(&Value::from("a"), 1);
+    let mut long = TableGauge::new(0);
+    long.update(&Value::from("a".repeat(1001).as_str()), 1);
+    assert_eq!(long.estimated_bytes - short.estimated_bytes, 1000);
+
+    let mut inline = TableGauge::new(0);
+    inline.update(&Value::None, 1);
+    let mut tuple = TableGauge::new(0);
+    tuple.update(
+        &Value::Tuple(vec![Value::from("ab"), Value::Int(1)].into()),
+        1,
+    );
+    assert!(tuple.estimated_bytes > inline.estimated_bytes + 2);
+}
+
+#[test]
+fn test_gauge_returns_to_zero() {
+    let mut gauge = TableGauge::new(0);
+    let value = Value::from("some text");
+    gauge.update(&value, 1);
+    gauge.update(&value, -1);
+    assert_eq!(gauge.rows, 0);
+    assert_eq!(gauge.estimated_bytes, 0);
+}
+
+#[test]
+fn test_gauge_serialization() -> eyre::Result<()> {
+    let gauge = TableGauge::new(3);
+    assert_eq!(
+        serde_json::to_value(gauge)?,
+        json!({"operator_id": 3, "rows": 0, "estimated_bytes": 0}),
+    );
+    Ok(())
+}
@@ -0,0 +1,177 @@
+// Copyright © 2026 Pathway
+
+use std::collections::BTreeMap;
+
+use arcstr::ArcStr;
+use assert_matches::assert_matches;
+
+use pathway_engine::connectors::data_format::{
+    Formatter, FormatterError, TemplateError, TemplateEscaping, TemplateFormatter, TextTemplate,
+};
+use pathway_engine::engine::{Key, Timestamp, Value};
+
+use super::helpers::{assert_document_raw_byte_contents, column_names};
+
+const COLUMNS: [&str; 3] = ["owner", "age", "pet"];
+
+fn render(template: &str, values: &[Value]) -> Result<String, FormatterError> {
+    let template = TextTemplate::compile(template, &column_names(&COLUMNS)).unwrap();
+    template.render(values, Timestamp(2), 1)
+}
+
+fn alice() -> [Value; 3] {
+    [Value::from("Alice"), Value::Int(10), Value::None]
+}
+
+#[test]
+fn test_template_columns_and_special_fields() -> eyre::Result<()> {
+    let rendered = render(
+        "{{owner}} is {{ age }} years old @ {{ time }}/{{diff}}",
+        &alice(),
+    )?;
+    assert_eq!(rendered, "Alice is 10 years old @ 2/1");
+    Ok(())
+}
+
+#[test]
+fn test_template_filters() -> eyre::Result<()> {
+    let rendered = render(
+        r#"{{ owner | upper | ljust(7, ".") }}|{{ age | rjust(4, "0") }}|{{ pet | default("none") | center(8) }}|"#,
+        &alice(),
+    )?;
+    assert_eq!(rendered, "ALICE..|0010|  none  |");
+
+    let rendered = render(
+        "{{ owner | lower | truncate(3) }} {{ owner | json }} {{ pet | json }}",
+        &alice(),
+    )?;
+    assert_eq!(rendered, r#"ali "Alice" null"#);
+    Ok(())
+}
+
+#[test]
+fn test_template_literals() -> eyre::Result<()> {
+    let rendered = render(r#"{{ "{{" }}owner}} {{ 'a | b' }} {{ "}}" }}"#, &alice())?;
+    assert_eq!(rendered, "{{owner}} a | b }}");
+    Ok(())
+}
+
+#[test]
+fn test_template_composite_values() -> eyre::Result<()> {
+    let pet = Value::from(BTreeMap::from([(ArcStr::from("kind"), Value::from("dog"))]));
+    let rendered = render("{{ pet }}", &[Value::from("Alice"), Value::Int(10), pet])?;
+    assert_eq!(rendered, r#"{"kind":"dog"}"#);
+    Ok(())
+}
+
+#[test]
+fn test_template_compilation_errors() {
+    let compile = |template| TextTemplate::compile(template, &column_names(&COLUMNS));
+    assert_matches!(
+        compile("{{ owner "),
+        Err(TemplateError::UnclosedPlaceholder(0))
+    );
+    assert_matches!(
+        compile("{{ name }}"),
+        Err(TemplateError::UnknownColumn(name)) if name == "name"
+    );
+    assert_matches!(
+        compile("{{ owner | reverse }}"),
+        Err(TemplateError::UnknownFilter(name)) if name == "reverse"
+    );
+    assert_matches!(
+        compile("{{ owner | ljust }}"),
+        Err(TemplateError::InvalidFilterArguments { .. })
+    );
+    assert_matches!(
+        compile("{{ owner age }}"),
+        Err(TemplateError::InvalidPlaceholder(..))
+    );
+}
+
+#[test]
+fn test_template_formatter() -> eyre::Result<()> {
+    let mut formatter = TemplateFormatter::new(
+        "{{ owner }}: {{ pet | default(\"-\") }}",
+        &column_names(&COLUMNS),
+    )
+    .unwrap();
+    let result = formatter.format(
+        &Key::for_value(&Value::from("1")),
+        &alice(),
+        Timestamp(0),
+        1,
+    )?;
+    assert_eq!(result.payloads.len(), 1);
+    assert_document_raw_byte_contents(&result.payloads[0], b"Alice: -");
+
+    let result = formatter.format(
+        &Key::for_value(&Value::from("1")),
+        &[Value::from("Alice")],
+        Timestamp(0),
+        1,
+    );
+    assert_matches!(result, Err(FormatterError::ColumnsValuesCountMismatch));
+    Ok(())
+}
+
+#[test]
+fn test_template_escaping() -> eyre::Result<()> {
+    let values = [
+        Value::from("<b>Tom & \"Jerry\"</b>"),
+        Value::Int(10),
+        Value::from("line one\nline \\two\r"),
+    ];
+    let render_escaped = |template: &str, escaping: &str| -> eyre::Result<String> {
+        let template = TextTemplate::compile(template, &column_names(&COLUMNS))?
+            .with_escaping(escaping.parse::<TemplateEscaping>()?);
+        Ok(template.render(&values, Timestamp(0), 1)?)
+    };
+
+    assert_eq!(
+        render_escaped("<p>{{ owner }}</p>", "html")?,
+        "<p>&lt;b&gt;Tom &amp; &quot;Jerry&quot;&lt;/b&gt;</p>"
+    );
+    assert_eq!(
+        render_escaped(r#"{"text": "{{ pet }}"}"#, "json")?,
+        r#"{"text": "line one\nline \\two\r"}"#
+    );
+    assert_eq!(
+        render_escaped("pet={{ pet }}", "single_line")?,
+        r"pet=line one\nline \\two\r"
+    );
+    assert_eq!(
+        render_escaped("{{ owner }}", "none")?,
+        "<b>Tom & \"Jerry\"</b>"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_template_escaping_filters() -> eyre::Result<()> {
+    let values = [Value::from("a<b>\n"), Value::Int(10), Value::None];
+    let template = TextTemplate::compile(
+        r#"{{ owner | escape }}|{{ owner | escape("single_line") }}|{{ owner | safe }}|{{ "<" }}|{{ owner }}"#,
+        &column_names(&COLUMNS),
+    )?
+    .with_escaping(TemplateEscaping::Json);
+    assert_eq!(
+        template.render(&values, Timestamp(0), 1)?,
+        "a&lt;b&gt;\n|a<b>\\n|a<b>\n|<|a<b>\\n"
+    );
+
+    let compile = |template| TextTemplate::compile(template, &column_names(&COLUMNS));
+    assert_matches!(
+        compile(r#"{{ owner | escape("xml") }}"#),
+        Err(TemplateError::UnknownEscaping(mode)) if mode == "xml"
+    );
+    assert_matches!(
+        compile("{{ owner | safe(1) }}"),
+        Err(TemplateError::InvalidFilterArguments { .. })
+    );
+    assert_matches!(
+        "shell".parse::<TemplateEscaping>(),
+        Err(TemplateError::UnknownEscaping(_))
+    );
+    Ok(())
+}
@@ -1,6 +1,7 @@
 // Copyright © 2026 Pathway
 
-use pathway_engine::engine::Duration;
+use pathway_engine::engine::time::DateTime;
+use pathway_engine::engine::{DateTimeNaive, DateTimeUtc, DateTimeZoned, Duration};
 
 #[test]
 fn test_duration_1() -> eyre::Result<()> {
@@ -37,3 +38,130 @@ fn test_duration_zero_sec() -> eyre::Result<()> {
     assert_eq!(d.to_string(), "-13d -20h -43m");
     Ok(())
 }
+
+#[test]
+fn test_date_time_zoned_keeps_zone_across_dst() -> eyre::Result<()> {
+    let dt = DateTimeZoned::parse("2024-03-31T01:30:00+01:00[Europe/Warsaw]")?;
+    assert_eq!(dt.timezone_name(), "Europe/Warsaw");
+    assert_eq!(
+        dt.to_utc(),
+        DateTimeUtc::strptime("2024-03-31T00:30:00+00:00", "%Y-%m-%dT%H:%M:%S%z")?
+    );
+
+    let shifted = dt + Duration::new(3_600_000_000_000);
+    assert_eq!(shifted.hour(), 3);
+    assert_eq!(
+        shifted.to_string(),
+        "2024-03-31T03:30:00.000000000+02:00[Europe/Warsaw]"
+    );
+    assert_eq!(DateTimeZoned::parse(&shifted.to_string())?, shifted);
+    Ok(())
+}
+
+#[test]
+fn test_date_time_zoned_conversions() -> eyre::Result<()> {
+    let naive = DateTimeNaive::strptime("2024-07-01T12:00:00", "%Y-%m-%dT%H:%M:%S")?;
+    let dt = DateTimeZoned::from_naive(naive, "America/New_York")?;
+    assert_eq!(dt.to_naive_local()?, naive);
+    assert_eq!(
+        dt.to_utc(),
+        DateTimeUtc::strptime("2024-07-01T16:00:00+00:00", "%Y-%m-%dT%H:%M:%S%z")?
+    );
+
+    let converted = dt.with_timezone("Asia/Tokyo")?;
+    assert_eq!(converted.to_utc(), dt.to_utc());
+    assert_eq!(converted.strftime("%H:%M %Z"), "01:00 JST");
+    Ok(())
+}
+
+#[test]
+fn test_date_time_zoned_without_zone_name_is_utc() -> eyre::Result<()> {
+    let dt = DateTimeZoned::parse("2024-01-01T10:00:00+02:00")?;
+    assert_eq!(dt.timezone_name(), "UTC");
+    assert_eq!(dt.to_string(), "2024-01-01T08:00:00.000000000+00:00[UTC]");
+    assert!(DateTimeZoned::parse("2024-01-01T10:00:00+02:00[Mars/Olympus]").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_date_time_truncate_to_unit() -> eyre::Result<()> {
+    let fmt = "%Y-%m-%dT%H:%M:%S%.f";
+    let dt = DateTimeNaive::strptime("2023-08-02T13:45:10.123", fmt)?;
+    let truncated = |unit: &str| -> eyre::Result<String> {
+        Ok(dt.truncate_to_unit(unit)?.strftime("%Y-%m-%dT%H:%M:%S%.f"))
+    };
+    assert_eq!(truncated("year")?, "2023-01-01T00:00:00");
+    assert_eq!(truncated("quarters")?, "2023-07-01T00:00:00");
+    assert_eq!(truncated("month")?, "2023-08-01T00:00:00");
+    assert_eq!(truncated("week")?, "2023-07-31T00:00:00");
+    assert_eq!(truncated("day")?, "2023-08-02T00:00:00");
+    assert_eq!(truncated("hour")?, "2023-08-02T13:00:00");
+    assert_eq!(truncated("minute")?, "2023-08-02T13:45:00");
+    assert_eq!(truncated("second")?, "2023-08-02T13:45:10");
+    assert!(dt.truncate_to_unit("fortnight").is_err());
+
+    let utc = DateTimeUtc::strptime("2023-08-02T13:45:10+02:00", "%Y-%m-%dT%H:%M:%S%z")?;
+    assert_eq!(
+        utc.truncate_to_unit("day")?,
+        DateTimeUtc::strptime("2023-08-02T00:00:00+00:00", "%Y-%m-%dT%H:%M:%S%z")?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_date_time_add_calendar_units() -> eyre::Result<()> {
+    let fmt = "%Y-%m-%dT%H:%M:%S";
+    let dt = DateTimeNaive::strptime("2024-01-31T10:00:00", fmt)?;
+    let added = |amount: i64, unit: &str| -> eyre::Result<String> {
+        Ok(dt.add_calendar_units(amount, unit)?.strftime(fmt))
+    };
+    assert_eq!(added(1, "month")?, "2024-02-29T10:00:00");
+    assert_eq!(added(13, "months")?, "2025-02-28T10:00:00");
+    assert_eq!(added(-1, "quarter")?, "2023-10-31T10:00:00");
+    assert_eq!(added(1, "year")?, "2025-01-31T10:00:00");
+    assert_eq!(added(-2, "weeks")?, "2024-01-17T10:00:00");
+    assert_eq!(added(1, "day")?, "2024-02-01T10:00:00");
+    assert!(dt.add_calendar_units(1, "hour").is_err());
+    assert!(dt.add_calendar_units(i64::MAX, "years").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_date_parts() -> eyre::Result<()> {
+    let fmt = "%Y-%m-%dT%H:%M:%S";
+    let dt = DateTimeNaive::strptime("2021-01-01T10:00:00", fmt)?;
+    assert_eq!(dt.quarter(), 1);
+    assert_eq!(dt.day_of_year(), 1);
+    assert_eq!(dt.iso_week(), 53);
+    let dt = DateTimeNaive::strptime("2024-12-31T10:00:00", fmt)?;
+    assert_eq!(dt.quarter(), 4);
+    assert_eq!(dt.day_of_year(), 366);
+    assert_eq!(dt.iso_week(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_duration_format() -> eyre::Result<()> {
+    let d = Duration::new(93784987654321);
+    assert_eq!(d.format("%dd %H:%M:%S.%f")?, "1d 02:03:04.987654321");
+    assert_eq!(d.format("%h:%M")?, "26:03");
+    assert_eq!(d.format("%m min, %s s, 100%%")?, "1563 min, 93784 s, 100%");
+    let d = Duration::new(-90_000_000_000);
+    assert_eq!(d.format("%H:%M:%S")?, "-00:01:30");
+    assert!(d.format("%q").is_err());
+    assert!(d.format("%H%").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_date_time_zoned_offset_has_to_match_zone() -> eyre::Result<()> {
+    // Warsaw is at +02:00 in summer, so +01:00 is a different instant than the one meant
+    assert!(DateTimeZoned::parse("2024-07-01T12:00:00+01:00[Europe/Warsaw]").is_err());
+    let dt = DateTimeZoned::parse("2024-07-01T12:00:00+02:00[Europe/Warsaw]")?;
+    assert_eq!(dt.hour(), 12);
+    // an ambiguous time of the autumn change is resolved by the offset
+    let first = DateTimeZoned::parse("2024-10-27T02:30:00+02:00[Europe/Warsaw]")?;
+    let second = DateTimeZoned::parse("2024-10-27T02:30:00+01:00[Europe/Warsaw]")?;
+    assert_eq!(second - first, Duration::new(3_600_000_000_000));
+    Ok(())
+}
@@ -7,7 +7,8 @@ use super::operator_test_utils::run_test;
 use differential_dataflow::operators::arrange::ArrangeByKey;
 
 use pathway_engine::engine::dataflow::operators::time_column::{
-    postpone_core, MaxTimestamp, TimeColumnBuffer, TimeColumnForget, TimeColumnFreeze, TimeKey,
+    postpone_core, retain_last_versions, MaxTimestamp, TimeColumnBuffer, TimeColumnForget,
+    TimeColumnFreeze, TimeKey,
 };
 use pathway_engine::engine::Timestamp;
 
@@ -1107,3 +1108,66 @@ fn test_cutoff_late_multiple() {
             .arrange_by_key()
     });
 }
+
+#[test]
+fn test_retain_last_versions() {
+    let input = vec![
+        vec![
+            ((1, (100, 11)), Timestamp::original_from(0), 1),
+            ((2, (200, 22)), Timestamp::original_from(0), 1),
+        ],
+        vec![((3, (300, 33)), Timestamp::original_from(1), 1)],
+        vec![
+            ((2, (200, 22)), Timestamp::original_from(2), -1),
+            ((4, (50, 44)), Timestamp::original_from(2), 1),
+        ],
+    ];
+    // the deletion frees a slot for the new entry even though it's older than the others
+    let expected = vec![
+        vec![
+            ((1, (100, 11)), Timestamp::original_from(0), 1),
+            ((2, (200, 22)), Timestamp::original_from(0), 1),
+        ],
+        vec![
+            ((1, (100, 11)), Timestamp::original_from(1), -1),
+            ((3, (300, 33)), Timestamp::original_from(1), 1),
+        ],
+        vec![
+            ((2, (200, 22)), Timestamp::original_from(2), -1),
+            ((4, (50, 44)), Timestamp::original_from(2), 1),
+        ],
+    ];
+    run_test(input, expected, |coll| {
+        retain_last_versions(&coll, |(t, _d)| *t, |_| 0, 2).arrange_by_key()
+    });
+}
+
+#[test]
+fn test_retain_last_versions_per_instance() {
+    let input = vec![
+        vec![
+            ((1, (100, 11)), Timestamp::original_from(0), 1),
+            ((2, (100, 22)), Timestamp::original_from(0), 1),
+        ],
+        vec![
+            ((3, (200, 11)), Timestamp::original_from(1), 1),
+            ((4, (50, 22)), Timestamp::original_from(1), 1),
+        ],
+        vec![((1, (100, 11)), Timestamp::original_from(2), -1)],
+    ];
+    // a late entry is removed right away and the deletion of an entry that is no
+    // longer retained is ignored
+    let expected = vec![
+        vec![
+            ((1, (100, 11)), Timestamp::original_from(0), 1),
+            ((2, (100, 22)), Timestamp::original_from(0), 1),
+        ],
+        vec![
+            ((1, (100, 11)), Timestamp::original_from(1), -1),
+            ((3, (200, 11)), Timestamp::original_from(1), 1),
+        ],
+    ];
+    run_test(input, expected, |coll| {
+        retain_last_versions(&coll, |(t, _d)| *t, |(_t, d)| *d, 1).arrange_by_key()
+    });
+}
@@ -13,6 +13,7 @@ fn test_type_display() {
     assert_eq!(Type::Bytes.to_string(), "bytes");
     assert_eq!(Type::DateTimeNaive.to_string(), "DateTimeNaive");
     assert_eq!(Type::DateTimeUtc.to_string(), "DateTimeUtc");
+    assert_eq!(Type::DateTimeZoned.to_string(), "DateTimeZoned");
     assert_eq!(Type::Duration.to_string(), "Duration");
     assert_eq!(
         Type::Array(Some(2), Type::Int.into()).to_string(),
@@ -0,0 +1,68 @@
+// Copyright © 2026 Pathway
+
+use assert_matches::assert_matches;
+
+use pathway_engine::engine::user_metrics::{
+    increment_counter, record_histogram, MetricKind, UserMetricsError, UserMetricsExport,
+};
+
+#[test]
+fn test_metrics_are_collected_while_exported() {
+    increment_counter("test.collected.counter", 5).unwrap();
+    record_histogram("test.collected.histogram", 1.0).unwrap();
+
+    let export = UserMetricsExport::start();
+    increment_counter("test.collected.counter", 1).unwrap();
+    increment_counter("test.collected.counter", 2).unwrap();
+    record_histogram("test.collected.histogram", 2.5).unwrap();
+    record_histogram("test.collected.histogram", 0.5).unwrap();
+
+    let update = export.take();
+    assert_eq!(update.counters.get("test.collected.counter"), Some(&3));
+    assert_eq!(
+        update.histograms.get("test.collected.histogram"),
+        Some(&vec![2.5, 0.5])
+    );
+
+    // the metrics are taken once
+    let update = export.take();
+    assert_eq!(update.counters.get("test.collected.counter"), None);
+    assert_eq!(update.histograms.get("test.collected.histogram"), None);
+}
+
+#[test]
+fn test_invalid_metric_names() {
+    let too_long = "c".repeat(256);
+    for name in [
+        "",
+        "1counter",
+        "counter name",
+        "counter:total",
+        too_long.as_str(),
+    ] {
+        assert_matches!(
+            increment_counter(name, 1),
+            Err(UserMetricsError::InvalidName(invalid_name)) if invalid_name == name
+        );
+    }
+    increment_counter("orders.processed/total-count_1", 1).unwrap();
+}
+
+#[test]
+fn test_name_used_by_another_kind() {
+    increment_counter("test.kind.counter", 1).unwrap();
+    assert_eq!(
+        record_histogram("test.kind.counter", 1.0),
+        Err(UserMetricsError::KindMismatch {
+            name: "test.kind.counter".to_string(),
+            registered: MetricKind::Counter,
+            requested: MetricKind::Histogram,
+        })
+    );
+
+    record_histogram("test.kind.histogram", 1.0).unwrap();
+    assert_matches!(
+        increment_counter("test.kind.histogram", 1),
+        Err(UserMetricsError::KindMismatch { .. })
+    );
+}
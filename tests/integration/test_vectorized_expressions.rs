@@ -0,0 +1,247 @@
+// Copyright © 2026 Pathway
+
+use std::sync::Arc;
+
+use pathway_engine::engine::{BoolExpression, Expression, FloatExpression, IntExpression, Value};
+
+use super::helpers::{argument, eval_rows, float, int};
+
+#[test]
+fn test_int_chain() {
+    // (a + b) * 2 - a // b
+    let expression = Expression::Int(IntExpression::Sub(
+        int(IntExpression::Mul(
+            int(IntExpression::Add(argument(0), argument(1))),
+            int(IntExpression::Const(2)),
+        )),
+        int(IntExpression::FloorDiv(argument(0), argument(1))),
+    ));
+    let result = eval_rows(
+        &expression,
+        &[
+            vec![Value::Int(7), Value::Int(2)],
+            vec![Value::Int(-7), Value::Int(2)],
+            vec![Value::Int(1), Value::Int(0)],
+            vec![Value::Error, Value::Int(1)],
+            vec![Value::Int(1), Value::None],
+        ],
+    );
+    assert_eq!(result[0], Ok(Value::Int(15)));
+    assert_eq!(result[1], Ok(Value::Int(-6)));
+    assert_eq!(result[2], Err("division by zero".to_string()));
+    assert_eq!(result[3], Err("Error value in column".to_string()));
+    assert!(result[4].as_ref().unwrap_err().starts_with("type mismatch"));
+}
+
+#[test]
+fn test_left_error_wins() {
+    let expression = Expression::Int(IntExpression::Add(
+        int(IntExpression::Mod(argument(0), argument(1))),
+        argument(2),
+    ));
+    let result = eval_rows(
+        &expression,
+        &[
+            vec![Value::Int(1), Value::Int(0), Value::Error],
+            vec![Value::Int(-7), Value::Int(3), Value::Error],
+            vec![Value::Int(-7), Value::Int(3), Value::Int(10)],
+        ],
+    );
+    assert_eq!(
+        result,
+        vec![
+            Err("division by zero".to_string()),
+            Err("Error value in column".to_string()),
+            Ok(Value::Int(12)),
+        ]
+    );
+}
+
+#[test]
+fn test_float_chain() {
+    // a / b + a % b, with python semantics of the modulo
+    let expression = Expression::Float(FloatExpression::Add(
+        float(FloatExpression::TrueDiv(argument(0), argument(1))),
+        float(FloatExpression::Mod(argument(0), argument(1))),
+    ));
+    let result = eval_rows(
+        &expression,
+        &[
+            vec![Value::from(5.0), Value::from(2.0)],
+            vec![Value::from(-5.0), Value::from(2.0)],
+            vec![Value::from(5.0), Value::from(0.0)],
+        ],
+    );
+    assert_eq!(result[0], Ok(Value::from(3.5)));
+    assert_eq!(result[1], Ok(Value::from(-1.5)));
+    assert_eq!(result[2], Err("division by zero".to_string()));
+}
+
+#[test]
+fn test_mixed_int_float() {
+    let expression = Expression::Float(FloatExpression::Sub(
+        float(FloatExpression::IntTrueDiv(argument(0), argument(1))),
+        float(FloatExpression::CastFromInt(argument(1))),
+    ));
+    let result = eval_rows(
+        &expression,
+        &[
+            vec![Value::Int(3), Value::Int(2)],
+            vec![Value::Int(3), Value::Int(0)],
+        ],
+    );
+    assert_eq!(result[0], Ok(Value::from(-0.5)));
+    assert_eq!(result[1], Err("division by zero".to_string()));
+}
+
+#[test]
+fn test_comparisons() {
+    // a * 2 > b and not (a == 1), without short-circuiting
+    let expression = Expression::Bool(BoolExpression::Xor(
+        Arc::new(Expression::Bool(BoolExpression::IntGt(
+            int(IntExpression::Mul(
+                argument(0),
+                int(IntExpression::Const(2)),
+            )),
+            argument(1),
+        ))),
+        Arc::new(Expression::Bool(BoolExpression::FloatLe(
+            float(FloatExpression::CastFromInt(argument(0))),
+            float(FloatExpression::Const(1.5)),
+        ))),
+    ));
+    let result = eval_rows(
+        &expression,
+        &[
+            vec![Value::Int(1), Value::Int(1)],
+            vec![Value::Int(2), Value::Int(5)],
+            vec![Value::Int(3), Value::Int(5)],
+            vec![Value::Int(3), Value::Error],
+        ],
+    );
+    assert_eq!(
+        result,
+        vec![
+            Ok(Value::Bool(false)),
+            Ok(Value::Bool(false)),
+            Ok(Value::Bool(true)),
+            Err("Error value in column".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_unsupported_subexpression() {
+    // a ** 2 + 1, the power is evaluated row by row
+    let expression = Expression::Int(IntExpression::Add(
+        int(IntExpression::Pow(
+            argument(0),
+            int(IntExpression::Const(2)),
+        )),
+        int(IntExpression::Const(1)),
+    ));
+    let result = eval_rows(
+        &expression,
+        &[vec![Value::Int(3)], vec![Value::from("3")], vec![]],
+    );
+    assert_eq!(result[0], Ok(Value::Int(10)));
+    assert!(result[1].as_ref().unwrap_err().starts_with("type mismatch"));
+    assert_eq!(result[2], Err("index out of bounds".to_string()));
+}
+
+#[test]
+fn test_int_overflow() {
+    let rows = [
+        vec![Value::Int(i64::MAX), Value::Int(1)],
+        vec![Value::Int(i64::MIN), Value::Int(-1)],
+        vec![Value::Int(i64::MIN), Value::Int(2)],
+        vec![Value::Int(-5), Value::Int(3)],
+    ];
+    let overflow = || Err("integer overflow".to_string());
+    let add = Expression::Int(IntExpression::Add(argument(0), argument(1)));
+    assert_eq!(
+        eval_rows(&add, &rows),
+        vec![
+            overflow(),
+            overflow(),
+            Ok(Value::Int(i64::MIN + 2)),
+            Ok(Value::Int(-2))
+        ]
+    );
+    let sub = Expression::Int(IntExpression::Sub(argument(0), argument(1)));
+    assert_eq!(
+        eval_rows(&sub, &rows),
+        vec![
+            Ok(Value::Int(i64::MAX - 1)),
+            Ok(Value::Int(i64::MIN + 1)),
+            overflow(),
+            Ok(Value::Int(-8))
+        ]
+    );
+    let mul = Expression::Int(IntExpression::Mul(argument(0), argument(1)));
+    assert_eq!(
+        eval_rows(&mul, &rows),
+        vec![
+            Ok(Value::Int(i64::MAX)),
+            overflow(),
+            overflow(),
+            Ok(Value::Int(-15))
+        ]
+    );
+    let neg = Expression::Int(IntExpression::Neg(argument(0)));
+    assert_eq!(
+        eval_rows(&neg, &rows),
+        vec![
+            Ok(Value::Int(-i64::MAX)),
+            overflow(),
+            overflow(),
+            Ok(Value::Int(5))
+        ]
+    );
+    let abs = Expression::Int(IntExpression::Abs(argument(0)));
+    assert_eq!(
+        eval_rows(&abs, &rows),
+        vec![
+            Ok(Value::Int(i64::MAX)),
+            overflow(),
+            overflow(),
+            Ok(Value::Int(5))
+        ]
+    );
+    let floor_div = Expression::Int(IntExpression::FloorDiv(argument(0), argument(1)));
+    assert_eq!(
+        eval_rows(&floor_div, &rows),
+        vec![
+            Ok(Value::Int(i64::MAX)),
+            overflow(),
+            Ok(Value::Int(i64::MIN / 2)),
+            Ok(Value::Int(-2))
+        ]
+    );
+    let modulo = Expression::Int(IntExpression::Mod(argument(0), argument(1)));
+    assert_eq!(
+        eval_rows(&modulo, &rows),
+        vec![
+            Ok(Value::Int(0)),
+            Ok(Value::Int(0)),
+            Ok(Value::Int(0)),
+            Ok(Value::Int(1))
+        ]
+    );
+
+    // the overflow of the left operand wins, as any other error
+    let expression = Expression::Int(IntExpression::Add(
+        int(IntExpression::Neg(argument(0))),
+        int(IntExpression::FloorDiv(
+            argument(1),
+            int(IntExpression::Const(0)),
+        )),
+    ));
+    assert_eq!(eval_rows(&expression, &rows[1..2]), vec![overflow()]);
+}
+
+#[test]
+fn test_empty_batch() {
+    let expression = Expression::Int(IntExpression::Add(argument(0), argument(1)));
+    assert!(eval_rows(&expression, &[]).is_empty());
+}
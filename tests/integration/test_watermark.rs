@@ -0,0 +1,82 @@
+// Copyright © 2026 Pathway
+
+use std::time::{Duration, Instant};
+
+use pathway_engine::connectors::watermark::{
+    BoundedOutOfOrderness, WatermarkGenerator, WatermarkStrategy, WatermarkTracker,
+};
+use pathway_engine::engine::{DateTimeUtc, Value};
+
+#[test]
+fn test_bounded_out_of_orderness() {
+    let mut generator = BoundedOutOfOrderness::new(1, 10);
+    assert_eq!(generator.watermark(), None);
+
+    generator.on_row(&[Value::from("a"), Value::Int(100)]);
+    assert_eq!(generator.watermark(), Some(90));
+    // a late row doesn't move the watermark back
+    generator.on_row(&[Value::from("b"), Value::Int(50)]);
+    assert_eq!(generator.watermark(), Some(90));
+    // neither does a row without an event time
+    generator.on_row(&[Value::from("c"), Value::None]);
+    assert_eq!(generator.watermark(), Some(90));
+    generator.on_row(&[Value::from("d"), Value::Int(120)]);
+    assert_eq!(generator.watermark(), Some(110));
+
+    assert_eq!(generator.idle_watermark(), Some(120));
+}
+
+#[test]
+fn test_bounded_out_of_orderness_datetime() -> eyre::Result<()> {
+    let mut generator = BoundedOutOfOrderness::new(0, 1_000_000_000);
+    let time = DateTimeUtc::from_timestamp(1_767_268_800_000_000_000, "ns")?;
+    generator.on_row(&[Value::DateTimeUtc(time)]);
+    assert_eq!(generator.watermark(), Some(1_767_268_799_000_000_000));
+    Ok(())
+}
+
+#[test]
+fn test_tracker_commits_when_watermark_advances() {
+    let strategy =
+        WatermarkStrategy::new().with_generator(Box::new(BoundedOutOfOrderness::new(0, 5)));
+    let mut tracker = WatermarkTracker::new(strategy);
+    let now = Instant::now();
+    assert!(!tracker.should_commit(now));
+
+    tracker.on_row(&[Value::Int(10)], now);
+    assert!(tracker.should_commit(now));
+    assert!(!tracker.should_commit(now));
+
+    tracker.on_row(&[Value::Int(8)], now);
+    assert!(!tracker.should_commit(now));
+    tracker.on_row(&[Value::Int(11)], now);
+    assert!(tracker.should_commit(now));
+
+    // without an idle timeout, the source never becomes idle
+    assert_eq!(tracker.idle_deadline(), None);
+    assert!(!tracker.should_commit(now + Duration::from_secs(3600)));
+}
+
+#[test]
+fn test_tracker_commits_when_idle() {
+    let strategy = WatermarkStrategy::new()
+        .with_generator(Box::new(BoundedOutOfOrderness::new(0, 5)))
+        .with_idle_timeout(Duration::from_secs(1));
+    let mut tracker = WatermarkTracker::new(strategy);
+    let now = Instant::now();
+    tracker.on_row(&[Value::Int(10)], now);
+    assert!(tracker.should_commit(now));
+    assert_eq!(tracker.idle_deadline(), Some(now + Duration::from_secs(1)));
+
+    assert!(!tracker.should_commit(now + Duration::from_millis(500)));
+    assert!(tracker.should_commit(now + Duration::from_secs(1)));
+
+    // a new row resets the idle timer
+    let later = now + Duration::from_secs(2);
+    tracker.on_row(&[Value::Int(9)], later);
+    assert!(!tracker.should_commit(later));
+    assert_eq!(
+        tracker.idle_deadline(),
+        Some(later + Duration::from_secs(1))
+    );
+}
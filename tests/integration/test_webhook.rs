@@ -0,0 +1,285 @@
+// Copyright © 2026 Pathway
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use pathway_engine::connectors::data_format::FormatterContext;
+use pathway_engine::connectors::data_storage::{
+    WebhookBatchFormat, WebhookError, WebhookRetryPolicy, WebhookWriter, WriteError, Writer,
+};
+use pathway_engine::engine::{Key, Timestamp, Value};
+
+use super::helpers::column_names;
+
+const COLUMNS: [&str; 2] = ["team", "message"];
+
+#[derive(Debug)]
+struct ReceivedRequest {
+    method: String,
+    target: String,
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+type ReceivedRequests = Arc<Mutex<Vec<ReceivedRequest>>>;
+
+// Answers the requests to each target with the given statuses in turn, and with
+// `200 OK` once they run out. Only the requests answered with `200 OK` are recorded.
+fn serve_webhook(statuses: HashMap<&'static str, Vec<u16>>) -> (String, ReceivedRequests) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let received = ReceivedRequests::default();
+    let mut statuses: HashMap<String, VecDeque<u16>> = statuses
+        .into_iter()
+        .map(|(target, statuses)| (target.to_string(), statuses.into()))
+        .collect();
+    let received_by_server = received.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            request.read_line(&mut request_line).unwrap();
+            let mut headers = HashMap::new();
+            loop {
+                let mut line = String::new();
+                request.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                let (name, value) = line.split_once(':').unwrap();
+                headers.insert(name.to_lowercase(), value.trim().to_string());
+            }
+            let content_length = headers
+                .get("content-length")
+                .map_or(0, |length| length.parse().unwrap());
+            let mut body = vec![0; content_length];
+            request.read_exact(&mut body).unwrap();
+
+            let mut request_parts = request_line.split(' ');
+            let method = request_parts.next().unwrap().to_string();
+            let target = request_parts.next().unwrap().to_string();
+            let status = statuses
+                .get_mut(&target)
+                .and_then(VecDeque::pop_front)
+                .unwrap_or(200);
+            if status == 200 {
+                received_by_server.lock().unwrap().push(ReceivedRequest {
+                    method,
+                    target,
+                    headers,
+                    body: String::from_utf8(body).unwrap(),
+                });
+            }
+            let response_body = if status == 200 { "" } else { "try later" };
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response_body}",
+                response_body.len()
+            )
+            .unwrap();
+        }
+    });
+    (format!("http://{address}"), received)
+}
+
+fn retry_policy(n_retries: usize) -> WebhookRetryPolicy {
+    WebhookRetryPolicy {
+        n_retries,
+        first_delay: Duration::from_millis(10),
+        backoff_factor: 1.0,
+        jitter: Duration::ZERO,
+        retry_codes: vec![429, 503],
+    }
+}
+
+fn change(team: &str, message: &str, time: u64, diff: isize) -> FormatterContext {
+    let values = vec![Value::from(team), Value::from(message)];
+    FormatterContext::new_single_payload(
+        format!("{{\"message\":\"{message}\"}}").into_bytes(),
+        Key::for_values(&values),
+        values,
+        Timestamp(time),
+        diff,
+    )
+}
+
+fn create_writer(
+    url: &str,
+    headers: &[(String, String)],
+    batch_format: Option<WebhookBatchFormat>,
+    max_batch_size: Option<usize>,
+    n_retries: usize,
+) -> eyre::Result<WebhookWriter> {
+    Ok(WebhookWriter::new(
+        "post",
+        url,
+        headers,
+        &column_names(&COLUMNS),
+        Some("application/json"),
+        batch_format,
+        max_batch_size,
+        retry_policy(n_retries),
+        1,
+        Some(Duration::from_secs(5)),
+        Some(Duration::from_secs(5)),
+    )?)
+}
+
+#[test]
+fn test_url_and_headers_are_rendered_per_change() -> eyre::Result<()> {
+    let (url, received) = serve_webhook(HashMap::new());
+    let mut writer = create_writer(
+        &format!("{url}/teams/{{{{ team }}}}?diff={{{{ diff }}}}"),
+        &[(
+            "X-Event-Time".to_string(),
+            "{{ time | rjust(4, \"0\") }}".to_string(),
+        )],
+        None,
+        None,
+        0,
+    )?;
+    writer.write(change("payments", "slow", 2, 1))?;
+    writer.write(change("search", "lagging", 2, -1))?;
+    assert!(received.lock().unwrap().is_empty());
+    writer.flush(false)?;
+
+    let received = received.lock().unwrap();
+    let summary: Vec<_> = received
+        .iter()
+        .map(|request| {
+            (
+                request.method.as_str(),
+                request.target.as_str(),
+                request.headers["x-event-time"].as_str(),
+                request.headers["content-type"].as_str(),
+                request.body.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "POST",
+                "/teams/payments?diff=1",
+                "0002",
+                "application/json",
+                "{\"message\":\"slow\"}"
+            ),
+            (
+                "POST",
+                "/teams/search?diff=-1",
+                "0002",
+                "application/json",
+                "{\"message\":\"lagging\"}"
+            ),
+        ]
+    );
+    assert!(received[0].headers["user-agent"].starts_with("pathway/"));
+    Ok(())
+}
+
+#[test]
+fn test_batches_are_grouped_by_destination() -> eyre::Result<()> {
+    let (url, received) = serve_webhook(HashMap::new());
+    let mut writer = create_writer(
+        &format!("{url}/{{{{ team }}}}"),
+        &[],
+        Some(WebhookBatchFormat::JsonArray),
+        Some(2),
+        0,
+    )?;
+    for (team, message) in [("a", "1"), ("b", "2"), ("a", "3"), ("a", "4")] {
+        writer.write(change(team, message, 0, 1))?;
+    }
+    writer.flush(false)?;
+
+    let received = received.lock().unwrap();
+    let summary: Vec<_> = received
+        .iter()
+        .map(|request| (request.target.as_str(), request.body.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("/a", "[{\"message\":\"1\"},{\"message\":\"3\"}]"),
+            ("/b", "[{\"message\":\"2\"}]"),
+            ("/a", "[{\"message\":\"4\"}]"),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ndjson_batch() -> eyre::Result<()> {
+    let (url, received) = serve_webhook(HashMap::new());
+    let mut writer = create_writer(
+        &format!("{url}/events"),
+        &[],
+        Some(WebhookBatchFormat::NdJson),
+        None,
+        0,
+    )?;
+    writer.write(change("a", "1", 0, 1))?;
+    writer.write(change("b", "2", 0, 1))?;
+    writer.flush(false)?;
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(
+        received[0].body,
+        "{\"message\":\"1\"}\n{\"message\":\"2\"}\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_retriable_statuses_are_retried() -> eyre::Result<()> {
+    let (url, received) = serve_webhook(HashMap::from([("/flaky", vec![503, 429])]));
+    let mut writer = create_writer(&format!("{url}/flaky"), &[], None, None, 2)?;
+    writer.write(change("a", "1", 0, 1))?;
+    writer.flush(false)?;
+    assert_eq!(received.lock().unwrap().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_delivery_failures_are_reported() -> eyre::Result<()> {
+    let (url, received) = serve_webhook(HashMap::from([
+        ("/flaky", vec![503, 503, 503]),
+        ("/rejecting", vec![400]),
+    ]));
+
+    let mut writer = create_writer(&format!("{url}/flaky"), &[], None, None, 2)?;
+    writer.write(change("a", "1", 0, 1))?;
+    let error = writer.flush(false).unwrap_err();
+    assert!(matches!(
+        error,
+        WriteError::Webhook(WebhookError::UnexpectedStatus { status, .. }) if status.as_u16() == 503
+    ));
+
+    // The statuses not listed in the retry policy aren't retried
+    let mut writer = create_writer(&format!("{url}/rejecting"), &[], None, None, 2)?;
+    writer.write(change("a", "1", 0, 1))?;
+    let error = writer.flush(false).unwrap_err();
+    let WriteError::Webhook(WebhookError::UnexpectedStatus { status, body, .. }) = error else {
+        panic!("unexpected error: {error}");
+    };
+    assert_eq!(status.as_u16(), 400);
+    assert_eq!(body, "try later");
+    assert!(received.lock().unwrap().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_templates_are_checked_on_creation() {
+    let error = create_writer("http://localhost/{{ unknown }}", &[], None, None, 0)
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("unknown column"), "{error}");
+}
@@ -0,0 +1,160 @@
+// Copyright © 2026 Pathway
+
+use hyper::{header, Body, Request, StatusCode};
+use serde_json::{json, Value as JsonValue};
+use tokio::sync::broadcast::error::TryRecvError;
+
+use pathway_engine::engine::websocket::{
+    handle_websocket_request, subscribe_to_channel, websocket_callbacks,
+};
+use pathway_engine::engine::{Key, Timestamp, Value};
+
+use super::helpers::column_names;
+
+const COLUMNS: [&str; 2] = ["owner", "pet"];
+
+fn parse(message: &str) -> JsonValue {
+    serde_json::from_str(message).expect("messages should be valid JSON")
+}
+
+#[test]
+fn test_changes_are_published_per_time() {
+    let mut callbacks = websocket_callbacks("changes", column_names(&COLUMNS), 1);
+    let mut receiver = subscribe_to_channel("changes").unwrap();
+    let on_data = callbacks.on_data.as_mut().unwrap();
+    let key = Key::for_value(&Value::Int(1));
+    on_data(
+        key,
+        &[Value::from("Alice"), Value::from("dog")],
+        Timestamp(2),
+        1,
+    )
+    .unwrap();
+    on_data(
+        Key::for_value(&Value::Int(2)),
+        &[Value::from("Bob"), Value::None],
+        Timestamp(2),
+        -1,
+    )
+    .unwrap();
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+    let on_time_end = callbacks.on_time_end.as_mut().unwrap();
+    on_time_end(Timestamp(2)).unwrap();
+    let message = parse(&receiver.try_recv().unwrap());
+    assert_eq!(message["time"], json!(2));
+    assert_eq!(
+        message["changes"][0],
+        json!({"id": key.to_string(), "diff": 1, "values": {"owner": "Alice", "pet": "dog"}})
+    );
+    assert_eq!(
+        message["changes"][1]["values"],
+        json!({"owner": "Bob", "pet": null})
+    );
+    assert_eq!(message["changes"][1]["diff"], json!(-1));
+
+    // nothing is sent for a time without changes
+    on_time_end(Timestamp(4)).unwrap();
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+    let on_end = callbacks.on_end.as_mut().unwrap();
+    on_end().unwrap();
+    assert_eq!(parse(&receiver.try_recv().unwrap()), json!({"end": true}));
+    assert!(subscribe_to_channel("changes").is_none());
+}
+
+#[test]
+fn test_end_is_sent_once_after_all_workers() {
+    let mut first = websocket_callbacks("workers", column_names(&COLUMNS), 2);
+    let mut second = websocket_callbacks("workers", column_names(&COLUMNS), 2);
+    let mut receiver = subscribe_to_channel("workers").unwrap();
+
+    (first.on_end.as_mut().unwrap())().unwrap();
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+    (second.on_end.as_mut().unwrap())().unwrap();
+    assert_eq!(parse(&receiver.try_recv().unwrap()), json!({"end": true}));
+
+    drop(first);
+    drop(second);
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+    assert!(subscribe_to_channel("workers").is_none());
+}
+
+#[test]
+fn test_no_end_when_a_worker_stops_early() {
+    let mut first = websocket_callbacks("stopped", column_names(&COLUMNS), 2);
+    let second = websocket_callbacks("stopped", column_names(&COLUMNS), 2);
+    let mut receiver = subscribe_to_channel("stopped").unwrap();
+
+    drop(second);
+    (first.on_end.as_mut().unwrap())().unwrap();
+    drop(first);
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+    assert!(subscribe_to_channel("stopped").is_none());
+}
+
+#[test]
+fn test_publishing_without_clients() {
+    let mut callbacks = websocket_callbacks("no clients", column_names(&COLUMNS), 1);
+    let on_data = callbacks.on_data.as_mut().unwrap();
+    on_data(
+        Key::for_value(&Value::Int(1)),
+        &[Value::from("Alice"), Value::from("dog")],
+        Timestamp(0),
+        1,
+    )
+    .unwrap();
+    let on_time_end = callbacks.on_time_end.as_mut().unwrap();
+    on_time_end(Timestamp(0)).unwrap();
+
+    // a client connecting later only gets the later messages
+    let mut receiver = subscribe_to_channel("no clients").unwrap();
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+}
+
+#[test]
+fn test_request_without_upgrade_is_rejected() {
+    let request = Request::get("/subscriptions/rejected")
+        .body(Body::empty())
+        .unwrap();
+    let response = handle_websocket_request(request, "rejected");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn test_handshake() {
+    let _callbacks = websocket_callbacks("handshake", column_names(&COLUMNS), 1);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let response = runtime.block_on(async {
+        let request = Request::get("/subscriptions/handshake")
+            .header(header::UPGRADE, "websocket")
+            .header(header::CONNECTION, "Upgrade")
+            .header(header::SEC_WEBSOCKET_VERSION, "13")
+            // the example key from RFC 6455
+            .header(header::SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")
+            .body(Body::empty())
+            .unwrap();
+        handle_websocket_request(request, "handshake")
+    });
+    assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+    assert_eq!(
+        response.headers()[header::SEC_WEBSOCKET_ACCEPT],
+        "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+    );
+    assert_eq!(response.headers()[header::UPGRADE], "websocket");
+}
+
+#[test]
+fn test_unknown_channel_is_rejected() {
+    let request = Request::get("/subscriptions/unknown")
+        .header(header::UPGRADE, "websocket")
+        .header(header::CONNECTION, "Upgrade")
+        .header(header::SEC_WEBSOCKET_VERSION, "13")
+        .header(header::SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")
+        .body(Body::empty())
+        .unwrap();
+    let response = handle_websocket_request(request, "unknown");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}